@@ -0,0 +1,345 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::vga3d::{Bivector, Rotor, Scalar, VGA3DOps, Vector};
+
+use num_traits::Float;
+
+// The attitude error in the body frame with the shortest path
+// \[ R = R_d E \]
+fn error<F: Float>(attitude: Rotor<F>, target: Rotor<F>) -> Rotor<F> {
+    Rotor::identity().closest(target.reverse() * attitude)
+}
+
+/// # PD Controller
+/// Proportional-derivative attitude control on the logarithm of the error rotor.
+/// The attitude rotor rotates the reference frame onto the body frame, $R = R_d E$,
+/// and the angular velocity and torque are bivectors in the body frame.
+/// $$ \overset\Rightarrow{\tau} = 2 k_p \log E - k_d \overset\Rightarrow{\Omega} $$
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PdController<F: Float> {
+    proportional: F,
+    derivative: F,
+}
+
+impl<F: Float> PdController<F> {
+    /// Creates a PD controller from the proportional and derivative gains
+    pub fn new(proportional: F, derivative: F) -> Self {
+        PdController {
+            proportional,
+            derivative,
+        }
+    }
+
+    /// The torque bivector that turns the attitude towards the target attitude at rest
+    pub fn torque(
+        &self,
+        attitude: Rotor<F>,
+        angular_velocity: Bivector<F>,
+        target: Rotor<F>,
+    ) -> Bivector<F> {
+        let two = F::one() + F::one();
+        let rotation = error(attitude, target).log() * Scalar::new(two);
+        rotation * Scalar::new(self.proportional) - angular_velocity * Scalar::new(self.derivative)
+    }
+}
+
+/// # Sliding Mode Controller
+/// Quaternion style sliding mode attitude control.
+/// The sliding surface uses the bivector part of the error rotor with a positive scalar part
+/// $$ \overset\Rightarrow{s} = \overset\Rightarrow{\Omega} - \lambda \left< E \right>_2 $$
+/// On the surface the error decays exponentially.
+/// The torque is saturated in a boundary layer of width $\varepsilon$ to avoid chattering
+/// $$ \overset\Rightarrow{\tau} = -k \operatorname{sat}\left(\frac{\overset\Rightarrow{s}}{\varepsilon}\right) $$
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SlidingModeController<F: Float> {
+    slope: F,
+    gain: F,
+    boundary_layer: F,
+}
+
+impl<F: Float> SlidingModeController<F> {
+    /// Creates a sliding mode controller from the slope of the sliding surface,
+    /// the gain and the width of the boundary layer
+    pub fn new(slope: F, gain: F, boundary_layer: F) -> Self {
+        SlidingModeController {
+            slope,
+            gain,
+            boundary_layer,
+        }
+    }
+
+    /// The sliding surface bivector, zero when the error decays on the surface
+    pub fn sliding_surface(
+        &self,
+        attitude: Rotor<F>,
+        angular_velocity: Bivector<F>,
+        target: Rotor<F>,
+    ) -> Bivector<F> {
+        angular_velocity - error(attitude, target).bivector() * Scalar::new(self.slope)
+    }
+
+    /// The torque bivector that turns the attitude towards the target attitude at rest
+    pub fn torque(
+        &self,
+        attitude: Rotor<F>,
+        angular_velocity: Bivector<F>,
+        target: Rotor<F>,
+    ) -> Bivector<F> {
+        let surface = self.sliding_surface(attitude, angular_velocity, target);
+        let saturate = |component: F| {
+            let ratio = component / self.boundary_layer;
+            -self.gain * ratio.max(-F::one()).min(F::one())
+        };
+        Bivector::new(
+            saturate(surface.e12()),
+            saturate(surface.e31()),
+            saturate(surface.e23()),
+        )
+    }
+}
+
+/// # B-dot Controller
+/// Detumbling with magnetorquers.
+/// The magnetic dipole opposes the change of the magnetic field measured in the body frame
+/// $$ \vec{m} = -k \dot{\vec{b}} $$
+/// and the torque from the dipole in the field is
+/// $$ \vec{\tau} = \vec{m} \times \vec{b} $$
+/// which is returned as the torque bivector $\overset\Rightarrow{\tau} = \vec{m} \wedge \vec{b}$.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BDotController<F: Float> {
+    gain: F,
+}
+
+impl<F: Float> BDotController<F> {
+    /// Creates a B-dot controller from the gain
+    pub fn new(gain: F) -> Self {
+        BDotController { gain }
+    }
+
+    /// The dipole from two magnetometer measurements the time step apart
+    pub fn dipole(&self, previous: Vector<F>, current: Vector<F>, dt: F) -> Vector<F> {
+        (current - previous) * Scalar::new(-self.gain / dt)
+    }
+
+    /// The dipole from the field and the angular velocity bivector.
+    /// The field turns the opposite way in the body frame, $\dot{\vec{b}} = \vec{b}\times\vec{\omega}$,
+    /// where $\vec{\omega}$ is the axial vector of the angular velocity.
+    pub fn dipole_from_rate(&self, field: Vector<F>, angular_velocity: Bivector<F>) -> Vector<F> {
+        let axial = Vector::new(
+            angular_velocity.e23(),
+            angular_velocity.e31(),
+            angular_velocity.e12(),
+        );
+        field.cross(axial) * Scalar::new(-self.gain)
+    }
+
+    /// The torque bivector of the dipole in the field
+    /// $$ \overset\Rightarrow{\tau} = \vec{m} \wedge \vec{b} $$
+    pub fn torque(&self, dipole: Vector<F>, field: Vector<F>) -> Bivector<F> {
+        dipole ^ field
+    }
+}
+
+#[cfg(test)]
+mod attitude_control {
+    use super::*;
+    use crate::vga3d::{Rotatable, RotorIntegrator};
+    use approx::assert_relative_eq;
+
+    // Rigid body with the principal moments of inertia over the e12, e31, e23 planes
+    struct Plant {
+        inertia: [f64; 3],
+        attitude: Rotor<f64>,
+        angular_velocity: Bivector<f64>,
+    }
+
+    impl Plant {
+        fn momentum(&self, angular_velocity: Bivector<f64>) -> Bivector<f64> {
+            Bivector::new(
+                self.inertia[0] * angular_velocity.e12(),
+                self.inertia[1] * angular_velocity.e31(),
+                self.inertia[2] * angular_velocity.e23(),
+            )
+        }
+        // Euler's equation with the gyroscopic coupling
+        // \[ I\dot{\Omega} = \tau + \Omega \times I\Omega \]
+        fn acceleration(
+            &self,
+            angular_velocity: Bivector<f64>,
+            torque: Bivector<f64>,
+        ) -> Bivector<f64> {
+            let total = torque + angular_velocity.cross(self.momentum(angular_velocity));
+            Bivector::new(
+                total.e12() / self.inertia[0],
+                total.e31() / self.inertia[1],
+                total.e23() / self.inertia[2],
+            )
+        }
+        fn step(&mut self, torque: Bivector<f64>, dt: f64) {
+            let half = Scalar::new(0.5 * dt);
+            let k1 = self.acceleration(self.angular_velocity, torque);
+            let k2 = self.acceleration(self.angular_velocity + k1 * half, torque);
+            let k3 = self.acceleration(self.angular_velocity + k2 * half, torque);
+            let k4 = self.acceleration(self.angular_velocity + k3 * Scalar::new(dt), torque);
+            let start = self.angular_velocity;
+            self.angular_velocity = start
+                + (k1 + k2 * Scalar::new(2.0) + k3 * Scalar::new(2.0) + k4) * Scalar::new(dt / 6.0);
+            let mean = (start + self.angular_velocity) * Scalar::new(0.5);
+            self.attitude = self
+                .attitude
+                .integrate_body(mean, dt, RotorIntegrator::Exponential);
+        }
+        fn energy(&self) -> f64 {
+            let momentum = self.momentum(self.angular_velocity);
+            0.5 * (momentum.e12() * self.angular_velocity.e12()
+                + momentum.e31() * self.angular_velocity.e31()
+                + momentum.e23() * self.angular_velocity.e23())
+        }
+    }
+
+    fn plant(attitude: Rotor<f64>, angular_velocity: Bivector<f64>) -> Plant {
+        Plant {
+            inertia: [3.0, 2.0, 1.5],
+            attitude,
+            angular_velocity,
+        }
+    }
+
+    // The rotation angle between two rotors
+    fn angle(a: Rotor<f64>, b: Rotor<f64>) -> f64 {
+        2.0 * (a.reverse() * a.closest(b)).log().norm().scalar()
+    }
+
+    #[test]
+    fn plant_momentum() {
+        // Without torque the angular momentum is constant in the reference frame
+        let mut plant = plant(Rotor::identity(), Bivector::new(0.1, 0.5, -0.3));
+        let start = plant.momentum(plant.angular_velocity);
+        let energy = plant.energy();
+        for _ in 0..1000 {
+            plant.step(Bivector::zero(), 0.01);
+        }
+        let momentum = plant
+            .momentum(plant.angular_velocity)
+            .rotate(plant.attitude.reverse());
+        assert_relative_eq!(momentum.e12(), start.e12(), epsilon = 1e-4);
+        assert_relative_eq!(momentum.e31(), start.e31(), epsilon = 1e-4);
+        assert_relative_eq!(momentum.e23(), start.e23(), epsilon = 1e-4);
+        assert_relative_eq!(plant.energy(), energy, epsilon = 1e-8);
+    }
+    #[test]
+    fn pd_at_target() {
+        let controller = PdController::new(0.5, 2.0);
+        let target = Bivector::new(0.3, -0.2, 0.5).exp();
+        let torque = controller.torque(target, Bivector::zero(), target);
+        assert_relative_eq!(torque.e12(), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(torque.e31(), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(torque.e23(), 0.0, epsilon = 1e-12);
+        // R and -R is the same attitude
+        let attitude = Bivector::new(0.1, 0.1, 0.0).exp();
+        let negative = Rotor::try_from_parts(-attitude.scalar(), -attitude.bivector()).unwrap();
+        assert_eq!(
+            controller.torque(attitude, Bivector::zero(), target),
+            controller.torque(negative, Bivector::zero(), target)
+        );
+    }
+    #[test]
+    fn pd_slew() {
+        // A large slew settles on the target
+        let controller = PdController::new(0.5, 2.0);
+        let target = Bivector::new(0.3, -0.2, 0.5).exp();
+        let mut plant = plant(Bivector::new(-0.6, 0.4, 0.2).exp(), Bivector::zero());
+        assert!(angle(plant.attitude, target) > 1.5);
+        for _ in 0..6000 {
+            let torque = controller.torque(plant.attitude, plant.angular_velocity, target);
+            plant.step(torque, 0.01);
+        }
+        assert!(angle(plant.attitude, target) < 0.001);
+        assert!(plant.angular_velocity.norm().scalar() < 0.001);
+    }
+    #[test]
+    fn sliding_mode_slew() {
+        // The surface is reached and the error decays on it
+        let controller = SlidingModeController::new(0.4, 1.0, 0.05);
+        let target = Bivector::new(0.3, -0.2, 0.5).exp();
+        let mut plant = plant(
+            Bivector::new(-0.6, 0.4, 0.2).exp(),
+            Bivector::new(0.1, 0.0, -0.1),
+        );
+        for _ in 0..6000 {
+            let torque = controller.torque(plant.attitude, plant.angular_velocity, target);
+            plant.step(torque, 0.01);
+        }
+        let surface = controller.sliding_surface(plant.attitude, plant.angular_velocity, target);
+        assert!(surface.norm().scalar() < 0.001);
+        assert!(angle(plant.attitude, target) < 0.001);
+    }
+    #[test]
+    fn sliding_mode_saturation() {
+        // The torque is bounded by the gain
+        let controller = SlidingModeController::new(0.4, 0.2, 0.05);
+        let torque = controller.torque(
+            Rotor::identity(),
+            Bivector::new(5.0, -5.0, 0.01),
+            Rotor::identity(),
+        );
+        assert_relative_eq!(torque.e12(), -0.2, epsilon = 1e-12);
+        assert_relative_eq!(torque.e31(), 0.2, epsilon = 1e-12);
+        assert_relative_eq!(torque.e23(), -0.04, epsilon = 1e-12);
+    }
+    #[test]
+    fn b_dot_dipole() {
+        // The finite difference and the rate give the same dipole for a small step
+        let controller = BDotController::new(2.0);
+        let field = Vector::new(0.2, -0.5, 0.4);
+        let angular_velocity = Bivector::new(0.1, 0.3, -0.2);
+        let dt = 1e-6;
+        let next = field.rotate((angular_velocity * Scalar::new(-0.5 * dt)).exp());
+        let finite = controller.dipole(field, next, dt);
+        let rate = controller.dipole_from_rate(field, angular_velocity);
+        assert_relative_eq!(finite.e1(), rate.e1(), epsilon = 1e-5);
+        assert_relative_eq!(finite.e2(), rate.e2(), epsilon = 1e-5);
+        assert_relative_eq!(finite.e3(), rate.e3(), epsilon = 1e-5);
+        // The torque from a dipole along e1 in a field along e2 is in the e12 plane
+        let torque = controller.torque(Vector::new(1.0, 0.0, 0.0), Vector::new(0.0, 1.0, 0.0));
+        assert_eq!(torque, Bivector::new(1.0, 0.0, 0.0));
+    }
+    #[test]
+    fn b_dot_detumble() {
+        // The field turns slowly in the reference frame like along an orbit
+        let controller = BDotController::new(0.5);
+        let mut plant = plant(Rotor::identity(), Bivector::new(0.2, -0.3, 0.25));
+        let energy = plant.energy();
+        let dt = 0.1;
+        let mut previous = None;
+        for step in 0..20000 {
+            let orbit = Bivector::new(0.0, 0.01 * step as f64 * dt, 0.0).exp();
+            let field = Vector::new(1.0, 0.0, 0.5)
+                .rotate(orbit)
+                .rotate(plant.attitude);
+            let torque = match previous {
+                None => Bivector::zero(),
+                Some(previous) => controller.torque(controller.dipole(previous, field, dt), field),
+            };
+            previous = Some(field);
+            plant.step(torque, dt);
+        }
+        assert!(plant.energy() < 0.01 * energy);
+    }
+}
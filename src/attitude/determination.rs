@@ -0,0 +1,497 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::vga3d::{Bivector, Rotatable, Rotor, VGA3DOps, Vector};
+
+use crate::linear_algebra::{apply, determinant, dot, multiply, symmetric, symmetric_eigen};
+
+use num_traits::Float;
+
+/// # Vector Observation
+/// A direction known in the reference frame and measured in the body frame, like the sun or a star.
+/// The attitude rotor rotates the reference direction onto the body direction
+/// $$ \hat{b} = R^\dag \hat{r} R $$
+/// The weight is the trust in the measurement, often the inverse variance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Observation<F: Float> {
+    reference: Vector<F>,
+    body: Vector<F>,
+    weight: F,
+}
+
+impl<F: Float> Observation<F> {
+    /// Tries to create an observation. The directions are normalized.
+    /// Returns `None` if a direction is zero or the weight is not positive and finite.
+    pub fn try_new(reference: Vector<F>, body: Vector<F>, weight: F) -> Option<Self> {
+        if weight <= F::zero() || !weight.is_finite() {
+            return None;
+        }
+        Some(Observation {
+            reference: reference.try_normalize()?,
+            body: body.try_normalize()?,
+            weight,
+        })
+    }
+
+    /// The unit direction in the reference frame
+    pub fn reference(&self) -> Vector<F> {
+        self.reference
+    }
+
+    /// The unit direction in the body frame
+    pub fn body(&self) -> Vector<F> {
+        self.body
+    }
+
+    /// The weight of the observation
+    pub fn weight(&self) -> F {
+        self.weight
+    }
+
+    /// # Residual
+    /// The angle in radians between the measured body direction and the rotated reference direction.
+    /// A large residual points to a bad measurement.
+    pub fn residual(&self, rotor: Rotor<F>) -> F {
+        let estimate = self.reference.rotate(rotor);
+        self.body
+            .cross(estimate)
+            .norm()
+            .scalar()
+            .atan2((self.body | estimate).scalar())
+    }
+}
+
+/// # Wahba Solution
+/// The attitude rotor and the loss of Wahba's problem
+/// $$ L(R) = \frac{1}{2}\sum_k w_k \left| \hat{b}_k - R^\dag \hat{r}_k R \right|^2 $$
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WahbaSolution<F: Float> {
+    rotor: Rotor<F>,
+    loss: F,
+}
+
+impl<F: Float> WahbaSolution<F> {
+    fn new(rotor: Rotor<F>, observations: &[Observation<F>]) -> Self {
+        let half = F::one() / (F::one() + F::one());
+        let loss = observations.iter().fold(F::zero(), |loss, observation| {
+            let distance = (observation.body - observation.reference.rotate(rotor))
+                .norm()
+                .scalar();
+            loss + half * observation.weight * distance * distance
+        });
+        WahbaSolution { rotor, loss }
+    }
+
+    /// The attitude rotor that rotates the reference frame onto the body frame
+    pub fn rotor(&self) -> Rotor<F> {
+        self.rotor
+    }
+
+    /// The weighted loss of Wahba's problem.
+    /// It is zero when all observations agree.
+    pub fn loss(&self) -> F {
+        self.loss
+    }
+}
+
+/// # TRIAD
+/// Tries to find the attitude from two observations.
+/// The primary direction is matched exactly, and the secondary only fixes the rotation about it.
+///
+/// Returns `None` if the two directions are parallel in either frame.
+pub fn try_triad<F: Float>(
+    primary: Observation<F>,
+    secondary: Observation<F>,
+) -> Option<WahbaSolution<F>> {
+    let reference = try_triad_frame(primary.reference, secondary.reference)?;
+    let body = try_triad_frame(primary.body, secondary.body)?;
+    let rotor = Rotor::try_from_frames(reference, body)?;
+    Some(WahbaSolution::new(rotor, &[primary, secondary]))
+}
+
+// The orthonormal frame of the TRIAD
+fn try_triad_frame<F: Float>(primary: Vector<F>, secondary: Vector<F>) -> Option<[Vector<F>; 3]> {
+    let second = primary.cross(secondary).try_normalize()?;
+    Some([primary, second, primary.cross(second)])
+}
+
+/// # QUEST
+/// Tries to find the optimal attitude with the quaternion estimator.
+/// The largest eigenvalue of the Davenport matrix is found with Newton's method on the characteristic polynomial,
+/// and the rotor is found from the adjugate.
+/// The adjugate loses precision close to a half turn, so the method of sequential rotations
+/// turns the reference frame half a turn about the axis that gives the largest scalar part.
+///
+/// Returns `None` if there are no two observations with different directions.
+pub fn try_quest<F: Float>(observations: &[Observation<F>]) -> Option<WahbaSolution<F>> {
+    if !is_observable(observations) {
+        return None;
+    }
+    let (zero, one) = (F::zero(), F::one());
+    let turns = [
+        Rotor::identity(),
+        Rotor::try_from_parts(zero, Bivector::new(zero, zero, one))?,
+        Rotor::try_from_parts(zero, Bivector::new(zero, one, zero))?,
+        Rotor::try_from_parts(zero, Bivector::new(one, zero, zero))?,
+    ];
+    // The unnormalized scalar part is the scalar part of the rotor times the same factor for all turns
+    let mut best = (turns[0], quest_parts(observations, turns[0]));
+    for turn in turns.into_iter().skip(1) {
+        let parts = quest_parts(observations, turn);
+        if parts.0.abs() > best.1.0.abs() {
+            best = (turn, parts);
+        }
+    }
+    let (turn, (scalar, bivector)) = best;
+    let rotor = turn * Rotor::try_from_parts(scalar, bivector)?;
+    Some(WahbaSolution::new(rotor, observations))
+}
+
+// The unnormalized scalar and bivector parts from QUEST,
+// with the reference directions rotated by a rotor first
+fn quest_parts<F: Float>(observations: &[Observation<F>], turn: Rotor<F>) -> (F, Bivector<F>) {
+    let two = F::one() + F::one();
+    let (b, z) = attitude_profile(observations, turn);
+    let sigma = b[0][0] + b[1][1] + b[2][2];
+    let s = symmetric(b);
+    let s2 = multiply(s, s);
+    let delta = determinant(s);
+    let kappa = (s[1][1] * s[2][2] - s[1][2] * s[2][1])
+        + (s[0][0] * s[2][2] - s[0][2] * s[2][0])
+        + (s[0][0] * s[1][1] - s[0][1] * s[1][0]);
+    let a = sigma * sigma - kappa;
+    let b = sigma * sigma + dot(z, z);
+    let c = delta + dot(z, apply(s, z));
+    let d = dot(z, apply(s2, z));
+
+    // Newton's method from the sum of the weights
+    let mut lambda = observations
+        .iter()
+        .fold(F::zero(), |sum, observation| sum + observation.weight);
+    for _ in 0..32 {
+        let lambda2 = lambda * lambda;
+        let f = lambda2 * lambda2 - (a + b) * lambda2 - c * lambda + (a * b + c * sigma - d);
+        let derivative = (two + two) * lambda2 * lambda - two * (a + b) * lambda - c;
+        if derivative == F::zero() {
+            break;
+        }
+        let step = f / derivative;
+        lambda = lambda - step;
+        if step.abs() <= F::epsilon() * lambda.abs() {
+            break;
+        }
+    }
+
+    // \[ \vec{x} = \left(\alpha I + \beta S + S^2\right)\vec{z},\quad \gamma = (\lambda+\sigma)\alpha - \Delta \]
+    let alpha = lambda * lambda - sigma * sigma + kappa;
+    let beta = lambda - sigma;
+    let gamma = (lambda + sigma) * alpha - delta;
+    let sz = apply(s, z);
+    let s2z = apply(s2, z);
+    let x = [
+        alpha * z[0] + beta * sz[0] + s2z[0],
+        alpha * z[1] + beta * sz[1] + s2z[1],
+        alpha * z[2] + beta * sz[2] + s2z[2],
+    ];
+    (gamma, Bivector::new(x[2], x[1], x[0]))
+}
+
+/// # Davenport q-method
+/// Tries to find the optimal attitude as the eigenvector of the largest eigenvalue of the Davenport matrix
+/// $$ K = \begin{bmatrix} \sigma & \vec{z}^T \\ \vec{z} & S - \sigma I \end{bmatrix} $$
+/// The symmetric eigenvalue problem is solved with Jacobi rotations, so no SVD is needed.
+///
+/// Returns `None` if there are no two observations with different directions.
+pub fn try_davenport<F: Float>(observations: &[Observation<F>]) -> Option<WahbaSolution<F>> {
+    if !is_observable(observations) {
+        return None;
+    }
+    let (b, z) = attitude_profile(observations, Rotor::identity());
+    let sigma = b[0][0] + b[1][1] + b[2][2];
+    let s = symmetric(b);
+    let mut k = [[F::zero(); 4]; 4];
+    k[0][0] = sigma;
+    for i in 0..3 {
+        k[0][i + 1] = z[i];
+        k[i + 1][0] = z[i];
+        for j in 0..3 {
+            k[i + 1][j + 1] = s[i][j];
+        }
+        k[i + 1][i + 1] = s[i][i] - sigma;
+    }
+    let q = max_eigenvector(k);
+    let rotor = Rotor::try_from_parts(q[0], Bivector::new(q[3], q[2], q[1]))?;
+    Some(WahbaSolution::new(rotor, observations))
+}
+
+// The attitude profile matrix and the vector of the Davenport matrix
+// \[ B = \sum_k w_k \hat{b}_k \hat{r}_k^T,\quad \vec{z} = \sum_k w_k \hat{r}_k \times \hat{b}_k \]
+// The components are ordered e1, e2, e3, so the quaternion vector part is (e23, e31, e12)
+fn attitude_profile<F: Float>(
+    observations: &[Observation<F>],
+    turn: Rotor<F>,
+) -> ([[F; 3]; 3], [F; 3]) {
+    let mut b = [[F::zero(); 3]; 3];
+    let mut z = [F::zero(); 3];
+    for observation in observations {
+        let reference = observation.reference.rotate(turn);
+        let body = observation.body;
+        let w = observation.weight;
+        let r = [reference.e1(), reference.e2(), reference.e3()];
+        let v = [body.e1(), body.e2(), body.e3()];
+        for (row, v_i) in b.iter_mut().zip(v.iter()) {
+            for (element, r_j) in row.iter_mut().zip(r.iter()) {
+                *element = *element + w * *v_i * *r_j;
+            }
+        }
+        let cross = reference.cross(body);
+        z[0] = z[0] + w * cross.e1();
+        z[1] = z[1] + w * cross.e2();
+        z[2] = z[2] + w * cross.e3();
+    }
+    (b, z)
+}
+
+// At least two observations with different body directions
+// If all directions are parallel to the one with the largest weight, then they are all parallel
+fn is_observable<F: Float>(observations: &[Observation<F>]) -> bool {
+    let tolerance = F::epsilon().sqrt();
+    let first = observations
+        .iter()
+        .fold(None, |best: Option<&Observation<F>>, o| match best {
+            Some(best) if best.weight >= o.weight => Some(best),
+            _ => Some(o),
+        });
+    match first {
+        None => false,
+        Some(first) => observations
+            .iter()
+            .any(|o| first.body.cross(o.body).norm().scalar() > tolerance),
+    }
+}
+
+// The eigenvector of the largest eigenvalue of a symmetric 4x4 matrix
+fn max_eigenvector<F: Float>(a: [[F; 4]; 4]) -> [F; 4] {
+    let (values, vectors) = symmetric_eigen(a);
+    let mut max = 0;
+    for i in 1..4 {
+        if values[i] > values[max] {
+            max = i;
+        }
+    }
+    vectors.map(|row| row[max])
+}
+
+#[cfg(test)]
+mod wahba {
+    use super::*;
+    use crate::vga3d::Scalar;
+    use approx::assert_relative_eq;
+
+    fn assert_rotor_eq(a: Rotor<f64>, b: Rotor<f64>, epsilon: f64) {
+        // R and -R is the same rotation
+        let b = a.closest(b);
+        assert_relative_eq!(a.scalar(), b.scalar(), epsilon = epsilon);
+        assert_relative_eq!(a.e12(), b.e12(), epsilon = epsilon);
+        assert_relative_eq!(a.e31(), b.e31(), epsilon = epsilon);
+        assert_relative_eq!(a.e23(), b.e23(), epsilon = epsilon);
+    }
+
+    fn observe(reference: Vector<f64>, rotor: Rotor<f64>, weight: f64) -> Observation<f64> {
+        Observation::try_new(reference, reference.rotate(rotor), weight).unwrap()
+    }
+
+    fn observations(rotor: Rotor<f64>) -> [Observation<f64>; 3] {
+        [
+            observe(Vector::new(1.0, 0.2, -0.3), rotor, 1.0),
+            observe(Vector::new(-0.4, 1.0, 0.5), rotor, 0.5),
+            observe(Vector::new(0.1, -0.6, 1.0), rotor, 2.0),
+        ]
+    }
+
+    // The observations with a small error on the body directions
+    fn noisy_observations(rotor: Rotor<f64>) -> [Observation<f64>; 3] {
+        let errors = [
+            Vector::new(0.01, -0.02, 0.0),
+            Vector::new(0.0, 0.015, 0.01),
+            Vector::new(-0.01, 0.0, 0.005),
+        ];
+        let mut noisy = observations(rotor);
+        for (observation, error) in noisy.iter_mut().zip(errors) {
+            *observation = Observation::try_new(
+                observation.reference(),
+                observation.body() + error,
+                observation.weight(),
+            )
+            .unwrap();
+        }
+        noisy
+    }
+
+    #[test]
+    fn observation() {
+        let observation =
+            Observation::try_new(Vector::new(2.0, 0.0, 0.0), Vector::new(0.0, 3.0, 0.0), 1.0)
+                .unwrap();
+        assert_eq!(observation.reference(), Vector::new(1.0, 0.0, 0.0));
+        assert_eq!(observation.body(), Vector::new(0.0, 1.0, 0.0));
+        assert_eq!(
+            Observation::try_new(Vector::zero(), Vector::new(0.0, 1.0, 0.0), 1.0),
+            None
+        );
+        assert_eq!(
+            Observation::try_new(Vector::new(1.0, 0.0, 0.0), Vector::new(0.0, 1.0, 0.0), 0.0),
+            None
+        );
+    }
+    #[test]
+    fn residual() {
+        // A quarter turn in the e12 plane rotates e1 onto e2
+        let observation =
+            Observation::try_new(Vector::new(1.0, 0.0, 0.0), Vector::new(0.0, 1.0, 0.0), 1.0)
+                .unwrap();
+        let quarter_turn = Rotor::try_from_vectors(observation.reference(), observation.body());
+        assert_relative_eq!(
+            observation.residual(quarter_turn.unwrap()),
+            0.0,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            observation.residual(Rotor::identity()),
+            core::f64::consts::FRAC_PI_2,
+            epsilon = 1e-12
+        );
+    }
+    #[test]
+    fn triad() {
+        let rotor = Bivector::new(0.3, -0.2, 0.5).exp();
+        let [primary, secondary, _] = observations(rotor);
+        let solution = try_triad(primary, secondary).unwrap();
+        assert_rotor_eq(solution.rotor(), rotor, 1e-12);
+        assert_relative_eq!(solution.loss(), 0.0, epsilon = 1e-12);
+    }
+    #[test]
+    fn triad_primary() {
+        // The primary direction is matched exactly
+        let rotor = Bivector::new(0.3, -0.2, 0.5).exp();
+        let [primary, secondary, _] = noisy_observations(rotor);
+        let solution = try_triad(primary, secondary).unwrap();
+        assert_relative_eq!(primary.residual(solution.rotor()), 0.0, epsilon = 1e-12);
+        assert!(secondary.residual(solution.rotor()) > 0.001);
+    }
+    #[test]
+    fn triad_parallel() {
+        let rotor = Bivector::new(0.3, -0.2, 0.5).exp();
+        let primary = observe(Vector::new(1.0, 0.2, -0.3), rotor, 1.0);
+        let secondary = observe(Vector::new(-2.0, -0.4, 0.6), rotor, 1.0);
+        assert_eq!(try_triad(primary, secondary), None);
+    }
+    #[test]
+    fn quest() {
+        let rotor = Bivector::new(0.3, -0.2, 0.5).exp();
+        let solution = try_quest(&observations(rotor)).unwrap();
+        assert_rotor_eq(solution.rotor(), rotor, 1e-10);
+        assert_relative_eq!(solution.loss(), 0.0, epsilon = 1e-12);
+    }
+    #[test]
+    fn quest_half_turn() {
+        // The sequential rotation handles rotations close to and at a half turn
+        let half_pi = core::f64::consts::FRAC_PI_2;
+        for bivector in [
+            Bivector::new(0.0, 0.0, half_pi),
+            Bivector::new(0.0, half_pi, 0.0),
+            Bivector::new(half_pi, 0.0, 0.0),
+            Bivector::new(0.6, -0.8, 0.0) * Scalar::new(half_pi - 1e-9),
+            Bivector::new(0.48, -0.6, 0.64) * Scalar::new(half_pi),
+        ] {
+            let rotor = bivector.exp();
+            let solution = try_quest(&observations(rotor)).unwrap();
+            assert_rotor_eq(solution.rotor(), rotor, 1e-10);
+        }
+    }
+    #[test]
+    fn davenport() {
+        let rotor = Bivector::new(0.3, -0.2, 0.5).exp();
+        let solution = try_davenport(&observations(rotor)).unwrap();
+        assert_rotor_eq(solution.rotor(), rotor, 1e-12);
+        assert_relative_eq!(solution.loss(), 0.0, epsilon = 1e-12);
+        let rotor = Bivector::new(0.48, -0.6, 0.64)
+            .exp()
+            .closest(Bivector::new(0.0, 0.0, core::f64::consts::FRAC_PI_2).exp());
+        let solution = try_davenport(&observations(rotor)).unwrap();
+        assert_rotor_eq(solution.rotor(), rotor, 1e-12);
+    }
+    #[test]
+    fn optimal() {
+        // QUEST and Davenport find the same optimum, which is better than TRIAD
+        let rotor = Bivector::new(-0.1, 0.7, 0.4).exp();
+        let noisy = noisy_observations(rotor);
+        let quest = try_quest(&noisy).unwrap();
+        let davenport = try_davenport(&noisy).unwrap();
+        assert_rotor_eq(quest.rotor(), davenport.rotor(), 1e-10);
+        assert_relative_eq!(quest.loss(), davenport.loss(), epsilon = 1e-12);
+        assert!(quest.loss() > 0.0);
+        let triad = try_triad(noisy[0], noisy[1]).unwrap();
+        assert!(WahbaSolution::new(triad.rotor(), &noisy).loss() > quest.loss());
+        // The loss is the smallest at the optimum
+        for bivector in [
+            Bivector::new(0.001, 0.0, 0.0),
+            Bivector::new(0.0, -0.001, 0.0),
+            Bivector::new(0.0, 0.0, 0.001),
+        ] {
+            let rotor = quest.rotor() * bivector.exp();
+            assert!(WahbaSolution::new(rotor, &noisy).loss() > quest.loss());
+        }
+    }
+    #[test]
+    fn bad_measurement() {
+        // The residual of a wrong measurement with a low weight stands out
+        let rotor = Bivector::new(0.3, -0.2, 0.5).exp();
+        let [first, second, third] = observations(rotor);
+        let bad = Observation::try_new(Vector::new(0.5, 0.5, 0.5), Vector::new(1.0, 0.0, 0.0), 0.2)
+            .unwrap();
+        let observations = [first, second, third, bad];
+        let solution = try_quest(&observations).unwrap();
+        let residuals = observations.map(|observation| observation.residual(solution.rotor()));
+        assert!(residuals[3] > 5.0 * residuals[0]);
+        assert!(residuals[3] > 5.0 * residuals[1]);
+        assert!(residuals[3] > 5.0 * residuals[2]);
+    }
+    #[test]
+    fn unobservable() {
+        let rotor = Bivector::new(0.3, -0.2, 0.5).exp();
+        let observation = observe(Vector::new(1.0, 0.2, -0.3), rotor, 1.0);
+        let parallel = observe(Vector::new(-1.0, -0.2, 0.3), rotor, 2.0);
+        assert_eq!(try_quest::<f64>(&[]), None);
+        assert_eq!(try_quest(&[observation]), None);
+        assert_eq!(try_quest(&[observation, parallel]), None);
+        assert_eq!(try_davenport(&[observation, parallel]), None);
+    }
+    #[test]
+    fn single_precision() {
+        let rotor = Bivector::new(0.3f32, -0.2, 0.5).exp();
+        let references = [Vector::new(1.0, 0.2, -0.3), Vector::new(-0.4, 1.0, 0.5)];
+        let observations = references.map(|reference| {
+            Observation::try_new(reference, reference.rotate(rotor), 1.0).unwrap()
+        });
+        let quest = try_quest(&observations).unwrap().rotor();
+        let davenport = try_davenport(&observations).unwrap().rotor();
+        assert_relative_eq!(quest.closest(rotor).e12(), rotor.e12(), epsilon = 1e-5);
+        assert_relative_eq!(davenport.closest(rotor).e12(), rotor.e12(), epsilon = 1e-5);
+    }
+}
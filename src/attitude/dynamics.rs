@@ -0,0 +1,285 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::vga3d::{
+    Bivector, BivectorLinearMap, Rotatable, Rotor, RotorIntegrator, Scalar, VGA3DOps,
+};
+
+use num_traits::Float;
+
+/// # Rigid Body
+/// The inertia of a rigid body as a map from the angular velocity bivector to the angular momentum bivector
+/// $$ \overset\Rightarrow{L} = I\left(\overset\Rightarrow{\Omega}\right) $$
+/// Both are in the body frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RigidBody<F: Float> {
+    inertia: BivectorLinearMap<F>,
+    inverse_inertia: BivectorLinearMap<F>,
+}
+
+impl<F: Float> RigidBody<F> {
+    /// Tries to create a rigid body from the inertia.
+    /// Returns `None` if the inertia is singular.
+    pub fn try_new(inertia: BivectorLinearMap<F>) -> Option<Self> {
+        Some(RigidBody {
+            inertia,
+            inverse_inertia: inertia.try_inverse()?,
+        })
+    }
+
+    /// The inertia in the body frame
+    pub fn inertia(&self) -> BivectorLinearMap<F> {
+        self.inertia
+    }
+
+    /// # Euler's Equation
+    /// The angular acceleration from the torque bivector with the gyroscopic coupling
+    /// $$ I\left(\dot{\overset\Rightarrow{\Omega}}\right) = \overset\Rightarrow{\tau} + \overset\Rightarrow{\Omega} \times I\left(\overset\Rightarrow{\Omega}\right) $$
+    pub fn angular_acceleration(
+        &self,
+        angular_velocity: Bivector<F>,
+        torque: Bivector<F>,
+    ) -> Bivector<F> {
+        let momentum = self.inertia.apply(angular_velocity);
+        self.inverse_inertia
+            .apply(torque + angular_velocity.cross(momentum))
+    }
+
+    // Fourth order Runge-Kutta of Euler's equation over the time step
+    fn integrate_angular_velocity(
+        &self,
+        angular_velocity: Bivector<F>,
+        torque: Bivector<F>,
+        dt: F,
+    ) -> Bivector<F> {
+        let two = F::one() + F::one();
+        let half = Scalar::new(dt / two);
+        let k1 = self.angular_acceleration(angular_velocity, torque);
+        let k2 = self.angular_acceleration(angular_velocity + k1 * half, torque);
+        let k3 = self.angular_acceleration(angular_velocity + k2 * half, torque);
+        let k4 = self.angular_acceleration(angular_velocity + k3 * Scalar::new(dt), torque);
+        angular_velocity
+            + (k1 + (k2 + k3) * Scalar::new(two) + k4) * Scalar::new(dt / (two + two + two))
+    }
+}
+
+/// # Rigid Body State
+/// The attitude rotor that rotates the reference frame onto the body frame
+/// and the angular velocity bivector in the body frame.
+/// The attitude follows the angular velocity
+/// $$ \dot{R} = -\frac{1}{2} R \overset\Rightarrow{\Omega} $$
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RigidBodyState<F: Float> {
+    attitude: Rotor<F>,
+    angular_velocity: Bivector<F>,
+}
+
+impl<F: Float> RigidBodyState<F> {
+    /// Creates a new state from the attitude and the angular velocity
+    pub fn new(attitude: Rotor<F>, angular_velocity: Bivector<F>) -> Self {
+        RigidBodyState {
+            attitude,
+            angular_velocity,
+        }
+    }
+
+    /// The attitude rotor
+    pub fn attitude(&self) -> Rotor<F> {
+        self.attitude
+    }
+
+    /// The angular velocity bivector in the body frame
+    pub fn angular_velocity(&self) -> Bivector<F> {
+        self.angular_velocity
+    }
+
+    /// The angular momentum bivector in the body frame
+    pub fn angular_momentum(&self, body: &RigidBody<F>) -> Bivector<F> {
+        body.inertia.apply(self.angular_velocity)
+    }
+
+    /// The angular momentum bivector in the reference frame, constant without torque
+    pub fn reference_angular_momentum(&self, body: &RigidBody<F>) -> Bivector<F> {
+        self.angular_momentum(body).rotate(self.attitude.reverse())
+    }
+
+    /// The rotational kinetic energy
+    /// $$ T = -\frac{1}{2} \left< \overset\Rightarrow{\Omega} I\left(\overset\Rightarrow{\Omega}\right) \right>_0 $$
+    pub fn kinetic_energy(&self, body: &RigidBody<F>) -> F {
+        let half = F::one() / (F::one() + F::one());
+        let momentum = self.angular_momentum(body);
+        half * (self.angular_velocity.e12() * momentum.e12()
+            + self.angular_velocity.e31() * momentum.e31()
+            + self.angular_velocity.e23() * momentum.e23())
+    }
+
+    /// # Propagate
+    /// Tries to propagate the state one time step with a constant torque bivector in the body frame.
+    /// The angular velocity is integrated with fourth order Runge-Kutta,
+    /// and the attitude follows it with the body frame integration of [`Rotor::integrate_body_with`].
+    /// Returns `None` if the state is no longer finite.
+    pub fn try_propagate(self, body: &RigidBody<F>, torque: Bivector<F>, dt: F) -> Option<Self> {
+        // The angular velocity the time h into the step
+        let angular_velocity =
+            |h: F| body.integrate_angular_velocity(self.angular_velocity, torque, h);
+        let state = RigidBodyState {
+            attitude: self.attitude.integrate_body_with(
+                angular_velocity,
+                F::zero(),
+                dt,
+                RotorIntegrator::RungeKutta4,
+            ),
+            angular_velocity: angular_velocity(dt),
+        };
+        state.is_finite().then_some(state)
+    }
+
+    fn is_finite(&self) -> bool {
+        [
+            self.attitude.scalar(),
+            self.attitude.e12(),
+            self.attitude.e31(),
+            self.attitude.e23(),
+            self.angular_velocity.e12(),
+            self.angular_velocity.e31(),
+            self.angular_velocity.e23(),
+        ]
+        .iter()
+        .all(|component| component.is_finite())
+    }
+}
+
+#[cfg(test)]
+mod rigid_body {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn assert_bivector_eq(a: Bivector<f64>, b: Bivector<f64>, epsilon: f64) {
+        assert_relative_eq!(a.e12(), b.e12(), epsilon = epsilon);
+        assert_relative_eq!(a.e31(), b.e31(), epsilon = epsilon);
+        assert_relative_eq!(a.e23(), b.e23(), epsilon = epsilon);
+    }
+
+    fn assert_rotor_eq(a: Rotor<f64>, b: Rotor<f64>, epsilon: f64) {
+        let b = a.closest(b);
+        assert_relative_eq!(a.scalar(), b.scalar(), epsilon = epsilon);
+        assert_bivector_eq(a.bivector(), b.bivector(), epsilon);
+    }
+
+    fn propagate(
+        body: &RigidBody<f64>,
+        state: RigidBodyState<f64>,
+        torque: Bivector<f64>,
+        steps: usize,
+    ) -> RigidBodyState<f64> {
+        (0..steps).fold(state, |state, _| {
+            state.try_propagate(body, torque, 0.01).unwrap()
+        })
+    }
+
+    #[test]
+    fn singular() {
+        let inertia = BivectorLinearMap::from_diagonal([1.0, 0.0, 2.0]);
+        assert_eq!(RigidBody::try_new(inertia), None);
+    }
+    #[test]
+    fn not_finite() {
+        let body = RigidBody::try_new(BivectorLinearMap::from_diagonal([3.0, 2.0, 1.5])).unwrap();
+        let state = RigidBodyState::new(Rotor::identity(), Bivector::new(0.1, 0.5, -0.3));
+        let torque = Bivector::new(f64::INFINITY, 0.0, 0.0);
+        assert_eq!(state.try_propagate(&body, torque, 0.01), None);
+    }
+    #[test]
+    fn principal_spin() {
+        // A spin in a principal plane is constant
+        let body = RigidBody::try_new(BivectorLinearMap::from_diagonal([3.0, 2.0, 1.5])).unwrap();
+        let start = Bivector::new(0.3, -0.2, 0.5).exp();
+        let angular_velocity = Bivector::new(0.0, 0.8, 0.0);
+        let state = propagate(
+            &body,
+            RigidBodyState::new(start, angular_velocity),
+            Bivector::zero(),
+            1000,
+        );
+        assert_bivector_eq(state.angular_velocity(), angular_velocity, 1e-12);
+        let expected = start * (angular_velocity * Scalar::new(-0.5 * 10.0)).exp();
+        assert_rotor_eq(state.attitude(), expected, 1e-10);
+    }
+    #[test]
+    fn constant_torque() {
+        // A sphere from rest turns in the plane of the torque
+        // \[ \Omega = \frac{\tau}{I} t, \quad \theta = \frac{\tau}{2I} t^2 \]
+        let body = RigidBody::try_new(BivectorLinearMap::from_diagonal([2.0; 3])).unwrap();
+        let torque = Bivector::new(0.1, 0.2, -0.2);
+        let state = propagate(
+            &body,
+            RigidBodyState::new(Rotor::identity(), Bivector::zero()),
+            torque,
+            500,
+        );
+        assert_bivector_eq(
+            state.angular_velocity(),
+            torque * Scalar::new(5.0 / 2.0),
+            1e-12,
+        );
+        let angle = torque * Scalar::new(25.0 / 4.0);
+        assert_rotor_eq(state.attitude(), (angle * Scalar::new(-0.5)).exp(), 1e-10);
+    }
+    #[test]
+    fn torque_free() {
+        // The energy and the angular momentum in the reference frame are constant
+        let inertia = BivectorLinearMap::new([[4.0, 0.3, -0.2], [0.3, 3.0, 0.1], [-0.2, 0.1, 2.0]]);
+        let body = RigidBody::try_new(inertia).unwrap();
+        let state = RigidBodyState::new(
+            Bivector::new(0.3, -0.2, 0.5).exp(),
+            Bivector::new(0.1, 0.5, -0.3),
+        );
+        let res = propagate(&body, state, Bivector::zero(), 2000);
+        assert_relative_eq!(
+            res.kinetic_energy(&body),
+            state.kinetic_energy(&body),
+            epsilon = 1e-9
+        );
+        assert_bivector_eq(
+            res.reference_angular_momentum(&body),
+            state.reference_angular_momentum(&body),
+            1e-8,
+        );
+        // The angular velocity changes with the gyroscopic coupling
+        assert!(
+            (res.angular_velocity() - state.angular_velocity())
+                .norm()
+                .scalar()
+                > 0.01
+        );
+    }
+    #[test]
+    fn gyroscopic_coupling() {
+        // Euler's equations for a diagonal inertia
+        // \[ I_1 \dot{\omega}_1 = (I_2 - I_3) \omega_2 \omega_3 \]
+        let body = RigidBody::try_new(BivectorLinearMap::from_diagonal([3.0, 2.0, 1.5])).unwrap();
+        let angular_velocity = Bivector::new(0.1, 0.5, -0.3);
+        let res = body.angular_acceleration(angular_velocity, Bivector::zero());
+        let (z, y, x) = (0.1, 0.5, -0.3);
+        // The axial vector of e12 is e3, e31 is e2 and e23 is e1
+        let (i_z, i_y, i_x) = (3.0, 2.0, 1.5);
+        assert_relative_eq!(res.e23(), (i_y - i_z) * y * z / i_x, epsilon = 1e-12);
+        assert_relative_eq!(res.e31(), (i_z - i_x) * z * x / i_y, epsilon = 1e-12);
+        assert_relative_eq!(res.e12(), (i_x - i_y) * x * y / i_z, epsilon = 1e-12);
+    }
+}
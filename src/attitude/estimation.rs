@@ -0,0 +1,296 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::determination::Observation;
+use crate::linear_algebra::{add, identity, multiply, scale, transpose, try_inverse};
+use crate::vga3d::{Bivector, Rotatable, Rotor, RotorIntegrator, Vector};
+
+use num_traits::Float;
+
+/// # Multiplicative Extended Kalman Filter
+/// Estimates the attitude rotor from gyro rates and vector observations without allocation.
+///
+/// The reference attitude is kept as a rotor that rotates the reference frame onto the body frame,
+/// $\hat{b} = R^\dag \hat{r} R$, like the `Observation`.
+/// The error state is the small rotation bivector $\overset\Rightarrow{\delta}$ in the body frame
+/// $$ R_\text{true} = \hat{R} \mathrm{e}^{\frac{1}{2}\overset\Rightarrow{\delta}} $$
+/// The covariance of the error state is a $3\times3$ matrix over the $\mathrm{e}_{12}$, $\mathrm{e}_{31}$, $\mathrm{e}_{23}$ basis,
+/// indexed as `[row][column]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mekf<F: Float> {
+    attitude: Rotor<F>,
+    covariance: [[F; 3]; 3],
+    gyro_noise: F,
+}
+
+impl<F: Float> Mekf<F> {
+    /// Creates a new filter from the initial attitude and covariance.
+    /// The gyro noise is the angle random walk in $\mathrm{rad}/\sqrt{\mathrm{s}}$.
+    pub fn new(attitude: Rotor<F>, covariance: [[F; 3]; 3], gyro_noise: F) -> Self {
+        Mekf {
+            attitude,
+            covariance,
+            gyro_noise,
+        }
+    }
+
+    /// The estimated attitude rotor
+    pub fn attitude(&self) -> Rotor<F> {
+        self.attitude
+    }
+
+    /// The covariance of the error state over the $\mathrm{e}_{12}$, $\mathrm{e}_{31}$, $\mathrm{e}_{23}$ basis
+    pub fn covariance(&self) -> [[F; 3]; 3] {
+        self.covariance
+    }
+
+    /// # Propagate
+    /// Propagates the attitude with the angular velocity bivector measured by the gyro in the body frame.
+    /// The step is the exact body frame integration of [`Rotor::integrate_body`],
+    /// and the reference directions turn the opposite way in the body frame
+    /// $$ \hat{R}_{k+1} = \hat{R}_k \mathrm{e}^{-\frac{h}{2}\overset\Rightarrow{\Omega}} $$
+    /// The error state is rotated with the step and the gyro noise is added to the covariance
+    /// $$ P_{k+1} = \Phi P_k \Phi^T + \sigma_g^2 h I $$
+    pub fn propagate(&mut self, angular_velocity: Bivector<F>, dt: F) {
+        let step =
+            Rotor::identity().integrate_body(angular_velocity, dt, RotorIntegrator::Exponential);
+        self.attitude =
+            self.attitude
+                .integrate_body(angular_velocity, dt, RotorIntegrator::Exponential);
+        // The columns are the rotated basis bivectors
+        let transition = transpose(basis().map(|bivector| components(bivector.rotate(step))));
+        let noise = scale(identity(), self.gyro_noise * self.gyro_noise * dt);
+        self.covariance = add(
+            multiply(multiply(transition, self.covariance), transpose(transition)),
+            noise,
+        );
+    }
+
+    /// # Update
+    /// Tries to correct the attitude with a vector observation.
+    /// The variance of the measured body direction is the inverse of the weight of the observation.
+    /// The error state is zero after the update, so the correction is moved into the attitude
+    /// $$ \hat{R} \leftarrow \hat{R} \mathrm{e}^{\frac{1}{2}\overset\Rightarrow{\delta}} $$
+    /// The covariance is updated with the Joseph form, so it stays symmetric and positive definite.
+    ///
+    /// Returns the correction $\overset\Rightarrow{\delta}$,
+    /// or `None` if the innovation covariance is singular and the filter is unchanged.
+    pub fn try_update(&mut self, observation: Observation<F>) -> Option<Bivector<F>> {
+        let variance = F::one() / observation.weight();
+        let estimate = observation.reference().rotate(self.attitude);
+        // \[ \hat{b}\mathrm{e}^{\frac{1}{2}\overset\Rightarrow{\delta}} \approx \hat{b} + \hat{b} \rfloor \overset\Rightarrow{\delta} \]
+        // The columns of the measurement matrix are the contractions with the basis bivectors
+        let measurement = transpose(basis().map(|bivector| vector_components(estimate | bivector)));
+        let innovation = vector_components(observation.body() - estimate);
+
+        let covariance_measurement = multiply(self.covariance, transpose(measurement));
+        let innovation_covariance = add(
+            multiply(measurement, covariance_measurement),
+            scale(identity(), variance),
+        );
+        let gain = multiply(covariance_measurement, try_inverse(innovation_covariance)?);
+
+        let correction = gain
+            .map(|row| row[0] * innovation[0] + row[1] * innovation[1] + row[2] * innovation[2]);
+        let correction = Bivector::new(correction[0], correction[1], correction[2]);
+        // The correction is a unit step of the body rate -δ
+        self.attitude =
+            self.attitude
+                .integrate_body(-correction, F::one(), RotorIntegrator::Exponential);
+
+        // Joseph form
+        // \[ P = (I - KH) P (I - KH)^T + K R K^T \]
+        let reduction = add(identity(), scale(multiply(gain, measurement), -F::one()));
+        self.covariance = add(
+            multiply(multiply(reduction, self.covariance), transpose(reduction)),
+            scale(multiply(gain, transpose(gain)), variance),
+        );
+        Some(correction)
+    }
+}
+
+fn basis<F: Float>() -> [Bivector<F>; 3] {
+    let (zero, one) = (F::zero(), F::one());
+    [
+        Bivector::new(one, zero, zero),
+        Bivector::new(zero, one, zero),
+        Bivector::new(zero, zero, one),
+    ]
+}
+
+fn components<F: Float>(bivector: Bivector<F>) -> [F; 3] {
+    [bivector.e12(), bivector.e31(), bivector.e23()]
+}
+
+fn vector_components<F: Float>(vector: Vector<F>) -> [F; 3] {
+    [vector.e1(), vector.e2(), vector.e3()]
+}
+
+#[cfg(test)]
+mod kalman_filter {
+    use super::*;
+    use crate::vga3d::{Scalar, VGA3DOps};
+    use approx::assert_relative_eq;
+
+    // Deterministic noise from a linear congruential generator, uniform in [-1, 1)
+    struct Noise(u64);
+
+    impl Noise {
+        fn next(&mut self) -> f64 {
+            self.0 = self
+                .0
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (self.0 >> 11) as f64 / (1u64 << 52) as f64 - 1.0
+        }
+        fn vector(&mut self, scale: f64) -> Vector<f64> {
+            Vector::new(self.next(), self.next(), self.next()) * Scalar::new(scale)
+        }
+        fn bivector(&mut self, scale: f64) -> Bivector<f64> {
+            Bivector::new(self.next(), self.next(), self.next()) * Scalar::new(scale)
+        }
+    }
+
+    // The rotation angle between two rotors
+    fn angle(a: Rotor<f64>, b: Rotor<f64>) -> f64 {
+        let difference = a.reverse() * a.closest(b);
+        2.0 * difference.log().norm().scalar()
+    }
+
+    fn diagonal(variance: f64) -> [[f64; 3]; 3] {
+        [
+            [variance, 0.0, 0.0],
+            [0.0, variance, 0.0],
+            [0.0, 0.0, variance],
+        ]
+    }
+
+    fn assert_symmetric(m: [[f64; 3]; 3]) {
+        for (i, row) in m.iter().enumerate() {
+            for (j, element) in row.iter().enumerate() {
+                assert_relative_eq!(*element, m[j][i], epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn propagate_exact() {
+        // Without noise the filter follows the true attitude
+        let rate = Bivector::new(0.02, -0.01, 0.03);
+        let truth = Bivector::new(0.2, -0.1, 0.3).exp();
+        let mut filter = Mekf::new(truth, diagonal(0.01), 0.0);
+        for _ in 0..1000 {
+            filter.propagate(rate, 0.1);
+        }
+        // The constant rate in the body frame
+        let expected = truth * (rate * Scalar::new(-0.5 * 100.0)).exp();
+        assert_relative_eq!(angle(filter.attitude(), expected), 0.0, epsilon = 1e-10);
+        // A reference direction turns the opposite way in the body frame
+        let mut filter = Mekf::new(Rotor::identity(), diagonal(0.01), 0.0);
+        filter.propagate(Bivector::new(1.0, 0.0, 0.0), 0.1);
+        let body = Vector::new(1.0, 0.0, 0.0).rotate(filter.attitude());
+        assert_relative_eq!(body.e1(), 0.1f64.cos(), epsilon = 1e-12);
+        assert_relative_eq!(body.e2(), -(0.1f64.sin()), epsilon = 1e-12);
+    }
+    #[test]
+    fn covariance_growth() {
+        // The covariance grows with the gyro noise and is rotated with the attitude
+        let mut filter = Mekf::new(Rotor::identity(), diagonal(0.01), 0.001);
+        for _ in 0..100 {
+            filter.propagate(Bivector::new(0.3, -0.2, 0.1), 0.1);
+        }
+        let covariance = filter.covariance();
+        assert_symmetric(covariance);
+        for (i, row) in covariance.iter().enumerate() {
+            for (j, element) in row.iter().enumerate() {
+                let expected = if i == j {
+                    0.01 + 0.001 * 0.001 * 10.0
+                } else {
+                    0.0
+                };
+                assert_relative_eq!(*element, expected, epsilon = 1e-12);
+            }
+        }
+    }
+    #[test]
+    fn update_direction() {
+        // A direction along e3 does not tell the rotation in the e12 plane
+        let mut filter = Mekf::new(Rotor::identity(), diagonal(0.01), 0.0);
+        let e3 = Vector::new(0.0, 0.0, 1.0);
+        let observation = Observation::try_new(e3, e3, 10000.0).unwrap();
+        let correction = filter.try_update(observation).unwrap();
+        assert_eq!(correction, Bivector::zero());
+        let covariance = filter.covariance();
+        assert_symmetric(covariance);
+        assert_relative_eq!(covariance[0][0], 0.01, epsilon = 1e-12);
+        // The variance of the two other planes is 1/(1/0.01 + 10000)
+        assert_relative_eq!(covariance[1][1], 1.0 / 10100.0, epsilon = 1e-12);
+        assert_relative_eq!(covariance[2][2], 1.0 / 10100.0, epsilon = 1e-12);
+    }
+    #[test]
+    fn update_correction() {
+        // A small error in the e31 plane is seen by a direction along e1
+        let truth = Bivector::new(0.0, 0.01, 0.0).exp();
+        let mut filter = Mekf::new(Rotor::identity(), diagonal(0.01), 0.0);
+        let e1 = Vector::new(1.0, 0.0, 0.0);
+        let observation = Observation::try_new(e1, e1.rotate(truth), 1e8).unwrap();
+        let correction = filter.try_update(observation).unwrap();
+        assert_relative_eq!(correction.e31(), 0.02, epsilon = 1e-5);
+        assert_relative_eq!(angle(filter.attitude(), truth), 0.0, epsilon = 1e-5);
+    }
+    #[test]
+    fn synthetic_gyro_magnetometer() {
+        let dt = 0.1;
+        let gyro_noise = 0.001;
+        let measurement_noise = 0.005;
+        let weight = 1.0 / (measurement_noise * measurement_noise);
+        let rate = Bivector::new(0.02, -0.01, 0.03);
+        let magnetic_field = Vector::new(0.3, 0.1, 0.9);
+        let sun = Vector::new(1.0, 0.2, -0.1);
+        let mut noise = Noise(7);
+
+        // Start 10 degrees off
+        let mut truth = Bivector::new(0.2, -0.1, 0.3).exp();
+        let error = Bivector::new(0.1, 0.1, -0.1) * Scalar::new(0.5);
+        let mut filter = Mekf::new(truth * error.exp(), diagonal(0.04), gyro_noise);
+        let start_error = angle(filter.attitude(), truth);
+        assert!(start_error > 0.17);
+
+        for step in 0..600 {
+            truth = truth * (rate * Scalar::new(-0.5 * dt)).exp();
+            filter.propagate(rate + noise.bivector(gyro_noise / dt.sqrt()), dt);
+            let magnetometer = magnetic_field.rotate(truth) + noise.vector(measurement_noise);
+            let observation = Observation::try_new(magnetic_field, magnetometer, weight).unwrap();
+            filter.try_update(observation).unwrap();
+            // The sun sensor is slower
+            if step % 10 == 0 {
+                let sun_sensor = sun.rotate(truth) + noise.vector(measurement_noise);
+                let observation = Observation::try_new(sun, sun_sensor, weight).unwrap();
+                filter.try_update(observation).unwrap();
+            }
+        }
+        let covariance = filter.covariance();
+        assert_symmetric(covariance);
+        let trace = covariance[0][0] + covariance[1][1] + covariance[2][2];
+        let end_error = angle(filter.attitude(), truth);
+        assert!(end_error < 0.005);
+        assert!(trace < 0.001);
+        // The error is within three standard deviations
+        assert!(end_error < 3.0 * trace.sqrt());
+    }
+}
@@ -0,0 +1,29 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+/// Attitude control with PD, sliding mode and B-dot controllers
+pub mod control;
+
+/// Attitude determination from vector observations, the solutions to Wahba's problem
+pub mod determination;
+
+/// Rigid body dynamics with the inertia as a bivector linear map
+pub mod dynamics;
+
+/// Attitude estimation with a multiplicative extended Kalman filter
+pub mod estimation;
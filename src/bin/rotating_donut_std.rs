@@ -30,7 +30,7 @@ use core::f32::consts::TAU;
 
 // Geometric Algebra
 use ga_lib::vga3d::{
-    Bivector, Multivector, Projectable, Rejectable, Rotatable, Rotor, Scalar, Trivector, VGA3DOps,
+    Bivector, Dual, Multivector, Projectable, Rejectable, Rotatable, Rotor, Scalar, Trivector, VGA3DOps,
     VGA3DOpsRef, Vector,
 };
 
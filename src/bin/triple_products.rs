@@ -22,7 +22,7 @@
 #![allow(unused_mut)]
 #![allow(unused_variables)]
 
-use ga_lib::vga3d::{Bivector, Vector};
+use ga_lib::vga3d::{Bivector, Dual, Vector};
 
 fn main() {
     println!("No Problems");
@@ -0,0 +1,317 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::fmt;
+
+use core::ops::Neg;
+
+use num_traits::Float;
+
+use super::{CGA3DOps, pseudoscalar::Pseudoscalar, scalar::Scalar, trivector::Trivector, up};
+use crate::vga3d;
+
+/// # 3D Conformal Geometric Algebra Bivector
+/// The bivectors of the algebra.
+/// The Euclidean part $\mathrm{e}_{12}$, $\mathrm{e}_{31}$, $\mathrm{e}_{23}$ is the same as in [`vga3d::Bivector`].
+///
+/// The outer product of two points is a point pair,
+/// and the dual of a circle or a line is a bivector.
+/// Bivectors are also the generators of the versors.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Bivector<F: Float> {
+    e12: F,
+    e31: F,
+    e23: F,
+    e14: F,
+    e24: F,
+    e34: F,
+    e15: F,
+    e25: F,
+    e35: F,
+    e45: F,
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + fmt::Display> fmt::Display for Bivector<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Bivector {{")?;
+        write!(f, " {}e12", self.e12)?;
+
+        // Add the appropriate sign to the remaining components
+        for (value, name) in [
+            (self.e31, "e31"),
+            (self.e23, "e23"),
+            (self.e14, "e14"),
+            (self.e24, "e24"),
+            (self.e34, "e34"),
+            (self.e15, "e15"),
+            (self.e25, "e25"),
+            (self.e35, "e35"),
+            (self.e45, "e45"),
+        ] {
+            if value >= F::zero() {
+                write!(f, " + {}{}", value, name)?;
+            } else {
+                write!(f, " - {}{}", value.abs(), name)?;
+            }
+        }
+        write!(f, " }}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F: Float + defmt::Format> defmt::Format for Bivector<F> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Bivector {{");
+        defmt::write!(f, " {}e12", self.e12);
+
+        // Add the appropriate sign to the remaining components
+        for (value, name) in [
+            (self.e31, "e31"),
+            (self.e23, "e23"),
+            (self.e14, "e14"),
+            (self.e24, "e24"),
+            (self.e34, "e34"),
+            (self.e15, "e15"),
+            (self.e25, "e25"),
+            (self.e35, "e35"),
+            (self.e45, "e45"),
+        ] {
+            if value >= F::zero() {
+                defmt::write!(f, " + {}{}", value, name);
+            } else {
+                defmt::write!(f, " - {}{}", value.abs(), name);
+            }
+        }
+        defmt::write!(f, " }}");
+    }
+}
+
+impl<F: Float> Bivector<F> {
+    /// The zero bivector
+    pub fn zero() -> Self {
+        Self {
+            e12: F::zero(),
+            e31: F::zero(),
+            e23: F::zero(),
+            e14: F::zero(),
+            e24: F::zero(),
+            e34: F::zero(),
+            e15: F::zero(),
+            e25: F::zero(),
+            e35: F::zero(),
+            e45: F::zero(),
+        }
+    }
+
+    /// Create new bivector from linear combination of the basis blades
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        e12: F,
+        e31: F,
+        e23: F,
+        e14: F,
+        e24: F,
+        e34: F,
+        e15: F,
+        e25: F,
+        e35: F,
+        e45: F,
+    ) -> Self {
+        Self {
+            e12,
+            e31,
+            e23,
+            e14,
+            e24,
+            e34,
+            e15,
+            e25,
+            e35,
+            e45,
+        }
+    }
+
+    /// Create the point pair through two points
+    /// $$ P_a \wedge P_b $$
+    pub fn point_pair(a: vga3d::Vector<F>, b: vga3d::Vector<F>) -> Self {
+        up(a) ^ up(b)
+    }
+
+    /// Get bivector
+    pub fn bivector(self) -> Self {
+        self
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2$ part
+    pub fn e12(&self) -> F {
+        self.e12
+    }
+
+    /// Get the $\mathrm{e}_3\mathrm{e}_1$ part
+    pub fn e31(&self) -> F {
+        self.e31
+    }
+
+    /// Get the $\mathrm{e}_2\mathrm{e}_3$ part
+    pub fn e23(&self) -> F {
+        self.e23
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_4$ part
+    pub fn e14(&self) -> F {
+        self.e14
+    }
+
+    /// Get the $\mathrm{e}_2\mathrm{e}_4$ part
+    pub fn e24(&self) -> F {
+        self.e24
+    }
+
+    /// Get the $\mathrm{e}_3\mathrm{e}_4$ part
+    pub fn e34(&self) -> F {
+        self.e34
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_5$ part
+    pub fn e15(&self) -> F {
+        self.e15
+    }
+
+    /// Get the $\mathrm{e}_2\mathrm{e}_5$ part
+    pub fn e25(&self) -> F {
+        self.e25
+    }
+
+    /// Get the $\mathrm{e}_3\mathrm{e}_5$ part
+    pub fn e35(&self) -> F {
+        self.e35
+    }
+
+    /// Get the $\mathrm{e}_4\mathrm{e}_5$ part
+    pub fn e45(&self) -> F {
+        self.e45
+    }
+
+    /// # Dual
+    /// The dual of a bivector is a trivector
+    /// $$ A^* = A I^{-1} $$
+    pub fn dual(self) -> Trivector<F> {
+        self * Pseudoscalar::new(-F::one())
+    }
+
+    /// # Undual
+    /// The inverse of the dual
+    /// $$ A = A^* I $$
+    pub fn undual(self) -> Trivector<F> {
+        self * Pseudoscalar::new(F::one())
+    }
+}
+
+// Negation
+impl<F: Float> Neg for Bivector<F> {
+    type Output = Bivector<F>;
+    fn neg(self) -> Bivector<F> {
+        Bivector::new(
+            -self.e12, -self.e31, -self.e23, -self.e14, -self.e24, -self.e34, -self.e15, -self.e25,
+            -self.e35, -self.e45,
+        )
+    }
+}
+
+impl<F: Float> CGA3DOps<F> for Bivector<F> {
+    fn reverse(self) -> Self {
+        -self
+    }
+
+    fn conjugate(self) -> Self {
+        -self
+    }
+
+    fn involute(self) -> Self {
+        self
+    }
+
+    fn norm(self) -> Scalar<F> {
+        Scalar(
+            (self.e12 * self.e12
+                + self.e31 * self.e31
+                + self.e23 * self.e23
+                + self.e14 * self.e14
+                + self.e24 * self.e24
+                + self.e34 * self.e34
+                - self.e15 * self.e15
+                - self.e25 * self.e25
+                - self.e35 * self.e35
+                - self.e45 * self.e45)
+                .abs()
+                .sqrt(),
+        )
+    }
+
+    fn try_normalize(self) -> Option<Self> {
+        self.norm().try_inverse().map(|norm_inverse| {
+            let norm_inverse = norm_inverse.0;
+            Bivector::new(
+                self.e12 * norm_inverse,
+                self.e31 * norm_inverse,
+                self.e23 * norm_inverse,
+                self.e14 * norm_inverse,
+                self.e24 * norm_inverse,
+                self.e34 * norm_inverse,
+                self.e15 * norm_inverse,
+                self.e25 * norm_inverse,
+                self.e35 * norm_inverse,
+                self.e45 * norm_inverse,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod bivector_ops {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn bivector_point_pair() {
+        let a = vga3d::Vector::new(1.0, 2.0, 3.0);
+        let b = vga3d::Vector::new(-1.0, 0.5, 2.0);
+        let point_pair = Bivector::point_pair(a, b);
+        // Both points are part of the point pair
+        for point in [up(a), up(b)] {
+            let res = point ^ point_pair;
+            assert_relative_eq!(res.norm().0, 0.0, epsilon = 0.000001);
+        }
+        let res = up(vga3d::Vector::new(0.0, 0.0, 0.0)) ^ point_pair;
+        assert!(res.norm().0 > 0.1);
+    }
+
+    #[test]
+    fn bivector_dual() {
+        let bivector = Bivector::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0);
+        assert_eq!(bivector.dual().undual(), bivector);
+    }
+}
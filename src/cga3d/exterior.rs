@@ -0,0 +1,2135 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    bivector::Bivector, multivector::Multivector, pseudoscalar::Pseudoscalar,
+    quadvector::Quadvector, scalar::Scalar, trivector::Trivector, vector::Vector, versor::Versor,
+};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::BitXor;
+
+// Exterior Product
+
+// Scalar-Scalar
+impl<F: Float> BitXor for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, b: Scalar<F>) -> Scalar<F> {
+        Scalar(self.0 * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Scalar<F>);
+
+// Scalar-Vector
+impl<F: Float> BitXor<Vector<F>> for Scalar<F> {
+    type Output = Vector<F>;
+    fn bitxor(self, b: Vector<F>) -> Vector<F> {
+        Vector::new(
+            self.0 * b.e1(),
+            self.0 * b.e2(),
+            self.0 * b.e3(),
+            self.0 * b.e4(),
+            self.0 * b.e5(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Vector<F>);
+
+// Vector-Scalar
+impl<F: Float> BitXor<Scalar<F>> for Vector<F> {
+    type Output = Vector<F>;
+    fn bitxor(self, b: Scalar<F>) -> Vector<F> {
+        Vector::new(
+            self.e1() * b.0,
+            self.e2() * b.0,
+            self.e3() * b.0,
+            self.e4() * b.0,
+            self.e5() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Vector<F>, Scalar<F>);
+
+// Scalar-Bivector
+impl<F: Float> BitXor<Bivector<F>> for Scalar<F> {
+    type Output = Bivector<F>;
+    fn bitxor(self, b: Bivector<F>) -> Bivector<F> {
+        Bivector::new(
+            self.0 * b.e12(),
+            self.0 * b.e31(),
+            self.0 * b.e23(),
+            self.0 * b.e14(),
+            self.0 * b.e24(),
+            self.0 * b.e34(),
+            self.0 * b.e15(),
+            self.0 * b.e25(),
+            self.0 * b.e35(),
+            self.0 * b.e45(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Bivector<F>);
+
+// Bivector-Scalar
+impl<F: Float> BitXor<Scalar<F>> for Bivector<F> {
+    type Output = Bivector<F>;
+    fn bitxor(self, b: Scalar<F>) -> Bivector<F> {
+        Bivector::new(
+            self.e12() * b.0,
+            self.e31() * b.0,
+            self.e23() * b.0,
+            self.e14() * b.0,
+            self.e24() * b.0,
+            self.e34() * b.0,
+            self.e15() * b.0,
+            self.e25() * b.0,
+            self.e35() * b.0,
+            self.e45() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Bivector<F>, Scalar<F>);
+
+// Scalar-Trivector
+impl<F: Float> BitXor<Trivector<F>> for Scalar<F> {
+    type Output = Trivector<F>;
+    fn bitxor(self, b: Trivector<F>) -> Trivector<F> {
+        Trivector::new(
+            self.0 * b.e123(),
+            self.0 * b.e124(),
+            self.0 * b.e314(),
+            self.0 * b.e234(),
+            self.0 * b.e125(),
+            self.0 * b.e315(),
+            self.0 * b.e235(),
+            self.0 * b.e145(),
+            self.0 * b.e245(),
+            self.0 * b.e345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Trivector<F>);
+
+// Trivector-Scalar
+impl<F: Float> BitXor<Scalar<F>> for Trivector<F> {
+    type Output = Trivector<F>;
+    fn bitxor(self, b: Scalar<F>) -> Trivector<F> {
+        Trivector::new(
+            self.e123() * b.0,
+            self.e124() * b.0,
+            self.e314() * b.0,
+            self.e234() * b.0,
+            self.e125() * b.0,
+            self.e315() * b.0,
+            self.e235() * b.0,
+            self.e145() * b.0,
+            self.e245() * b.0,
+            self.e345() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Trivector<F>, Scalar<F>);
+
+// Scalar-Quadvector
+impl<F: Float> BitXor<Quadvector<F>> for Scalar<F> {
+    type Output = Quadvector<F>;
+    fn bitxor(self, b: Quadvector<F>) -> Quadvector<F> {
+        Quadvector::new(
+            self.0 * b.e1234(),
+            self.0 * b.e1235(),
+            self.0 * b.e1245(),
+            self.0 * b.e3145(),
+            self.0 * b.e2345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Quadvector<F>);
+
+// Quadvector-Scalar
+impl<F: Float> BitXor<Scalar<F>> for Quadvector<F> {
+    type Output = Quadvector<F>;
+    fn bitxor(self, b: Scalar<F>) -> Quadvector<F> {
+        Quadvector::new(
+            self.e1234() * b.0,
+            self.e1235() * b.0,
+            self.e1245() * b.0,
+            self.e3145() * b.0,
+            self.e2345() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Quadvector<F>, Scalar<F>);
+
+// Scalar-Pseudoscalar
+impl<F: Float> BitXor<Pseudoscalar<F>> for Scalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Pseudoscalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.0 * b.e12345())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Scalar
+impl<F: Float> BitXor<Scalar<F>> for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Scalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.e12345() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Pseudoscalar<F>, Scalar<F>);
+
+// Scalar-Versor
+impl<F: Float> BitXor<Versor<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 * b.scalar(),
+            Vector::zero(),
+            Bivector::new(
+                self.0 * b.e12(),
+                self.0 * b.e31(),
+                self.0 * b.e23(),
+                self.0 * b.e14(),
+                self.0 * b.e24(),
+                self.0 * b.e34(),
+                self.0 * b.e15(),
+                self.0 * b.e25(),
+                self.0 * b.e35(),
+                self.0 * b.e45(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.0 * b.e1234(),
+                self.0 * b.e1235(),
+                self.0 * b.e1245(),
+                self.0 * b.e3145(),
+                self.0 * b.e2345(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Versor<F>);
+
+// Versor-Scalar
+impl<F: Float> BitXor<Scalar<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.0,
+            Vector::zero(),
+            Bivector::new(
+                self.e12() * b.0,
+                self.e31() * b.0,
+                self.e23() * b.0,
+                self.e14() * b.0,
+                self.e24() * b.0,
+                self.e34() * b.0,
+                self.e15() * b.0,
+                self.e25() * b.0,
+                self.e35() * b.0,
+                self.e45() * b.0,
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e1234() * b.0,
+                self.e1235() * b.0,
+                self.e1245() * b.0,
+                self.e3145() * b.0,
+                self.e2345() * b.0,
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Versor<F>, Scalar<F>);
+
+// Scalar-Multivector
+impl<F: Float> BitXor<Multivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 * b.scalar(),
+            Vector::new(
+                self.0 * b.e1(),
+                self.0 * b.e2(),
+                self.0 * b.e3(),
+                self.0 * b.e4(),
+                self.0 * b.e5(),
+            ),
+            Bivector::new(
+                self.0 * b.e12(),
+                self.0 * b.e31(),
+                self.0 * b.e23(),
+                self.0 * b.e14(),
+                self.0 * b.e24(),
+                self.0 * b.e34(),
+                self.0 * b.e15(),
+                self.0 * b.e25(),
+                self.0 * b.e35(),
+                self.0 * b.e45(),
+            ),
+            Trivector::new(
+                self.0 * b.e123(),
+                self.0 * b.e124(),
+                self.0 * b.e314(),
+                self.0 * b.e234(),
+                self.0 * b.e125(),
+                self.0 * b.e315(),
+                self.0 * b.e235(),
+                self.0 * b.e145(),
+                self.0 * b.e245(),
+                self.0 * b.e345(),
+            ),
+            Quadvector::new(
+                self.0 * b.e1234(),
+                self.0 * b.e1235(),
+                self.0 * b.e1245(),
+                self.0 * b.e3145(),
+                self.0 * b.e2345(),
+            ),
+            self.0 * b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Multivector<F>);
+
+// Multivector-Scalar
+impl<F: Float> BitXor<Scalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.0,
+            Vector::new(
+                self.e1() * b.0,
+                self.e2() * b.0,
+                self.e3() * b.0,
+                self.e4() * b.0,
+                self.e5() * b.0,
+            ),
+            Bivector::new(
+                self.e12() * b.0,
+                self.e31() * b.0,
+                self.e23() * b.0,
+                self.e14() * b.0,
+                self.e24() * b.0,
+                self.e34() * b.0,
+                self.e15() * b.0,
+                self.e25() * b.0,
+                self.e35() * b.0,
+                self.e45() * b.0,
+            ),
+            Trivector::new(
+                self.e123() * b.0,
+                self.e124() * b.0,
+                self.e314() * b.0,
+                self.e234() * b.0,
+                self.e125() * b.0,
+                self.e315() * b.0,
+                self.e235() * b.0,
+                self.e145() * b.0,
+                self.e245() * b.0,
+                self.e345() * b.0,
+            ),
+            Quadvector::new(
+                self.e1234() * b.0,
+                self.e1235() * b.0,
+                self.e1245() * b.0,
+                self.e3145() * b.0,
+                self.e2345() * b.0,
+            ),
+            self.e12345() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Scalar<F>);
+
+// Vector-Vector
+impl<F: Float> BitXor for Vector<F> {
+    type Output = Bivector<F>;
+    fn bitxor(self, b: Vector<F>) -> Bivector<F> {
+        Bivector::new(
+            self.e1() * b.e2() - self.e2() * b.e1(),
+            -self.e1() * b.e3() + self.e3() * b.e1(),
+            self.e2() * b.e3() - self.e3() * b.e2(),
+            self.e1() * b.e4() - self.e4() * b.e1(),
+            self.e2() * b.e4() - self.e4() * b.e2(),
+            self.e3() * b.e4() - self.e4() * b.e3(),
+            self.e1() * b.e5() - self.e5() * b.e1(),
+            self.e2() * b.e5() - self.e5() * b.e2(),
+            self.e3() * b.e5() - self.e5() * b.e3(),
+            self.e4() * b.e5() - self.e5() * b.e4(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Vector<F>, Vector<F>);
+
+// Vector-Bivector
+impl<F: Float> BitXor<Bivector<F>> for Vector<F> {
+    type Output = Trivector<F>;
+    fn bitxor(self, b: Bivector<F>) -> Trivector<F> {
+        Trivector::new(
+            self.e1() * b.e23() + self.e2() * b.e31() + self.e3() * b.e12(),
+            self.e1() * b.e24() - self.e2() * b.e14() + self.e4() * b.e12(),
+            -self.e1() * b.e34() + self.e3() * b.e14() + self.e4() * b.e31(),
+            self.e2() * b.e34() - self.e3() * b.e24() + self.e4() * b.e23(),
+            self.e1() * b.e25() - self.e2() * b.e15() + self.e5() * b.e12(),
+            -self.e1() * b.e35() + self.e3() * b.e15() + self.e5() * b.e31(),
+            self.e2() * b.e35() - self.e3() * b.e25() + self.e5() * b.e23(),
+            self.e1() * b.e45() - self.e4() * b.e15() + self.e5() * b.e14(),
+            self.e2() * b.e45() - self.e4() * b.e25() + self.e5() * b.e24(),
+            self.e3() * b.e45() - self.e4() * b.e35() + self.e5() * b.e34(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Vector<F>, Bivector<F>);
+
+// Bivector-Vector
+impl<F: Float> BitXor<Vector<F>> for Bivector<F> {
+    type Output = Trivector<F>;
+    fn bitxor(self, b: Vector<F>) -> Trivector<F> {
+        Trivector::new(
+            self.e12() * b.e3() + self.e31() * b.e2() + self.e23() * b.e1(),
+            self.e12() * b.e4() - self.e14() * b.e2() + self.e24() * b.e1(),
+            self.e31() * b.e4() + self.e14() * b.e3() - self.e34() * b.e1(),
+            self.e23() * b.e4() - self.e24() * b.e3() + self.e34() * b.e2(),
+            self.e12() * b.e5() - self.e15() * b.e2() + self.e25() * b.e1(),
+            self.e31() * b.e5() + self.e15() * b.e3() - self.e35() * b.e1(),
+            self.e23() * b.e5() - self.e25() * b.e3() + self.e35() * b.e2(),
+            self.e14() * b.e5() - self.e15() * b.e4() + self.e45() * b.e1(),
+            self.e24() * b.e5() - self.e25() * b.e4() + self.e45() * b.e2(),
+            self.e34() * b.e5() - self.e35() * b.e4() + self.e45() * b.e3(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Bivector<F>, Vector<F>);
+
+// Vector-Trivector
+impl<F: Float> BitXor<Trivector<F>> for Vector<F> {
+    type Output = Quadvector<F>;
+    fn bitxor(self, b: Trivector<F>) -> Quadvector<F> {
+        Quadvector::new(
+            self.e1() * b.e234() + self.e2() * b.e314() + self.e3() * b.e124()
+                - self.e4() * b.e123(),
+            self.e1() * b.e235() + self.e2() * b.e315() + self.e3() * b.e125()
+                - self.e5() * b.e123(),
+            self.e1() * b.e245() - self.e2() * b.e145() + self.e4() * b.e125()
+                - self.e5() * b.e124(),
+            -self.e1() * b.e345() + self.e3() * b.e145() + self.e4() * b.e315()
+                - self.e5() * b.e314(),
+            self.e2() * b.e345() - self.e3() * b.e245() + self.e4() * b.e235()
+                - self.e5() * b.e234(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Vector<F>, Trivector<F>);
+
+// Trivector-Vector
+impl<F: Float> BitXor<Vector<F>> for Trivector<F> {
+    type Output = Quadvector<F>;
+    fn bitxor(self, b: Vector<F>) -> Quadvector<F> {
+        Quadvector::new(
+            self.e123() * b.e4()
+                - self.e124() * b.e3()
+                - self.e314() * b.e2()
+                - self.e234() * b.e1(),
+            self.e123() * b.e5()
+                - self.e125() * b.e3()
+                - self.e315() * b.e2()
+                - self.e235() * b.e1(),
+            self.e124() * b.e5() - self.e125() * b.e4() + self.e145() * b.e2()
+                - self.e245() * b.e1(),
+            self.e314() * b.e5() - self.e315() * b.e4() - self.e145() * b.e3()
+                + self.e345() * b.e1(),
+            self.e234() * b.e5() - self.e235() * b.e4() + self.e245() * b.e3()
+                - self.e345() * b.e2(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Trivector<F>, Vector<F>);
+
+// Vector-Quadvector
+impl<F: Float> BitXor<Quadvector<F>> for Vector<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Quadvector<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(
+            self.e1() * b.e2345() + self.e2() * b.e3145() + self.e3() * b.e1245()
+                - self.e4() * b.e1235()
+                + self.e5() * b.e1234(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Vector<F>, Quadvector<F>);
+
+// Quadvector-Vector
+impl<F: Float> BitXor<Vector<F>> for Quadvector<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Vector<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(
+            self.e1234() * b.e5() - self.e1235() * b.e4()
+                + self.e1245() * b.e3()
+                + self.e3145() * b.e2()
+                + self.e2345() * b.e1(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Quadvector<F>, Vector<F>);
+
+// Vector-Pseudoscalar
+impl<F: Float> BitXor<Pseudoscalar<F>> for Vector<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Pseudoscalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Vector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Vector
+impl<F: Float> BitXor<Vector<F>> for Pseudoscalar<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Vector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Pseudoscalar<F>, Vector<F>);
+
+// Vector-Versor
+impl<F: Float> BitXor<Versor<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                self.e1() * b.scalar(),
+                self.e2() * b.scalar(),
+                self.e3() * b.scalar(),
+                self.e4() * b.scalar(),
+                self.e5() * b.scalar(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                self.e1() * b.e23() + self.e2() * b.e31() + self.e3() * b.e12(),
+                self.e1() * b.e24() - self.e2() * b.e14() + self.e4() * b.e12(),
+                -self.e1() * b.e34() + self.e3() * b.e14() + self.e4() * b.e31(),
+                self.e2() * b.e34() - self.e3() * b.e24() + self.e4() * b.e23(),
+                self.e1() * b.e25() - self.e2() * b.e15() + self.e5() * b.e12(),
+                -self.e1() * b.e35() + self.e3() * b.e15() + self.e5() * b.e31(),
+                self.e2() * b.e35() - self.e3() * b.e25() + self.e5() * b.e23(),
+                self.e1() * b.e45() - self.e4() * b.e15() + self.e5() * b.e14(),
+                self.e2() * b.e45() - self.e4() * b.e25() + self.e5() * b.e24(),
+                self.e3() * b.e45() - self.e4() * b.e35() + self.e5() * b.e34(),
+            ),
+            Quadvector::zero(),
+            self.e1() * b.e2345() + self.e2() * b.e3145() + self.e3() * b.e1245()
+                - self.e4() * b.e1235()
+                + self.e5() * b.e1234(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Vector<F>, Versor<F>);
+
+// Versor-Vector
+impl<F: Float> BitXor<Vector<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                self.scalar() * b.e1(),
+                self.scalar() * b.e2(),
+                self.scalar() * b.e3(),
+                self.scalar() * b.e4(),
+                self.scalar() * b.e5(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                self.e12() * b.e3() + self.e31() * b.e2() + self.e23() * b.e1(),
+                self.e12() * b.e4() - self.e14() * b.e2() + self.e24() * b.e1(),
+                self.e31() * b.e4() + self.e14() * b.e3() - self.e34() * b.e1(),
+                self.e23() * b.e4() - self.e24() * b.e3() + self.e34() * b.e2(),
+                self.e12() * b.e5() - self.e15() * b.e2() + self.e25() * b.e1(),
+                self.e31() * b.e5() + self.e15() * b.e3() - self.e35() * b.e1(),
+                self.e23() * b.e5() - self.e25() * b.e3() + self.e35() * b.e2(),
+                self.e14() * b.e5() - self.e15() * b.e4() + self.e45() * b.e1(),
+                self.e24() * b.e5() - self.e25() * b.e4() + self.e45() * b.e2(),
+                self.e34() * b.e5() - self.e35() * b.e4() + self.e45() * b.e3(),
+            ),
+            Quadvector::zero(),
+            self.e1234() * b.e5() - self.e1235() * b.e4()
+                + self.e1245() * b.e3()
+                + self.e3145() * b.e2()
+                + self.e2345() * b.e1(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Versor<F>, Vector<F>);
+
+// Vector-Multivector
+impl<F: Float> BitXor<Multivector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                self.e1() * b.scalar(),
+                self.e2() * b.scalar(),
+                self.e3() * b.scalar(),
+                self.e4() * b.scalar(),
+                self.e5() * b.scalar(),
+            ),
+            Bivector::new(
+                self.e1() * b.e2() - self.e2() * b.e1(),
+                -self.e1() * b.e3() + self.e3() * b.e1(),
+                self.e2() * b.e3() - self.e3() * b.e2(),
+                self.e1() * b.e4() - self.e4() * b.e1(),
+                self.e2() * b.e4() - self.e4() * b.e2(),
+                self.e3() * b.e4() - self.e4() * b.e3(),
+                self.e1() * b.e5() - self.e5() * b.e1(),
+                self.e2() * b.e5() - self.e5() * b.e2(),
+                self.e3() * b.e5() - self.e5() * b.e3(),
+                self.e4() * b.e5() - self.e5() * b.e4(),
+            ),
+            Trivector::new(
+                self.e1() * b.e23() + self.e2() * b.e31() + self.e3() * b.e12(),
+                self.e1() * b.e24() - self.e2() * b.e14() + self.e4() * b.e12(),
+                -self.e1() * b.e34() + self.e3() * b.e14() + self.e4() * b.e31(),
+                self.e2() * b.e34() - self.e3() * b.e24() + self.e4() * b.e23(),
+                self.e1() * b.e25() - self.e2() * b.e15() + self.e5() * b.e12(),
+                -self.e1() * b.e35() + self.e3() * b.e15() + self.e5() * b.e31(),
+                self.e2() * b.e35() - self.e3() * b.e25() + self.e5() * b.e23(),
+                self.e1() * b.e45() - self.e4() * b.e15() + self.e5() * b.e14(),
+                self.e2() * b.e45() - self.e4() * b.e25() + self.e5() * b.e24(),
+                self.e3() * b.e45() - self.e4() * b.e35() + self.e5() * b.e34(),
+            ),
+            Quadvector::new(
+                self.e1() * b.e234() + self.e2() * b.e314() + self.e3() * b.e124()
+                    - self.e4() * b.e123(),
+                self.e1() * b.e235() + self.e2() * b.e315() + self.e3() * b.e125()
+                    - self.e5() * b.e123(),
+                self.e1() * b.e245() - self.e2() * b.e145() + self.e4() * b.e125()
+                    - self.e5() * b.e124(),
+                -self.e1() * b.e345() + self.e3() * b.e145() + self.e4() * b.e315()
+                    - self.e5() * b.e314(),
+                self.e2() * b.e345() - self.e3() * b.e245() + self.e4() * b.e235()
+                    - self.e5() * b.e234(),
+            ),
+            self.e1() * b.e2345() + self.e2() * b.e3145() + self.e3() * b.e1245()
+                - self.e4() * b.e1235()
+                + self.e5() * b.e1234(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Vector<F>, Multivector<F>);
+
+// Multivector-Vector
+impl<F: Float> BitXor<Vector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                self.scalar() * b.e1(),
+                self.scalar() * b.e2(),
+                self.scalar() * b.e3(),
+                self.scalar() * b.e4(),
+                self.scalar() * b.e5(),
+            ),
+            Bivector::new(
+                self.e1() * b.e2() - self.e2() * b.e1(),
+                -self.e1() * b.e3() + self.e3() * b.e1(),
+                self.e2() * b.e3() - self.e3() * b.e2(),
+                self.e1() * b.e4() - self.e4() * b.e1(),
+                self.e2() * b.e4() - self.e4() * b.e2(),
+                self.e3() * b.e4() - self.e4() * b.e3(),
+                self.e1() * b.e5() - self.e5() * b.e1(),
+                self.e2() * b.e5() - self.e5() * b.e2(),
+                self.e3() * b.e5() - self.e5() * b.e3(),
+                self.e4() * b.e5() - self.e5() * b.e4(),
+            ),
+            Trivector::new(
+                self.e12() * b.e3() + self.e31() * b.e2() + self.e23() * b.e1(),
+                self.e12() * b.e4() - self.e14() * b.e2() + self.e24() * b.e1(),
+                self.e31() * b.e4() + self.e14() * b.e3() - self.e34() * b.e1(),
+                self.e23() * b.e4() - self.e24() * b.e3() + self.e34() * b.e2(),
+                self.e12() * b.e5() - self.e15() * b.e2() + self.e25() * b.e1(),
+                self.e31() * b.e5() + self.e15() * b.e3() - self.e35() * b.e1(),
+                self.e23() * b.e5() - self.e25() * b.e3() + self.e35() * b.e2(),
+                self.e14() * b.e5() - self.e15() * b.e4() + self.e45() * b.e1(),
+                self.e24() * b.e5() - self.e25() * b.e4() + self.e45() * b.e2(),
+                self.e34() * b.e5() - self.e35() * b.e4() + self.e45() * b.e3(),
+            ),
+            Quadvector::new(
+                self.e123() * b.e4()
+                    - self.e124() * b.e3()
+                    - self.e314() * b.e2()
+                    - self.e234() * b.e1(),
+                self.e123() * b.e5()
+                    - self.e125() * b.e3()
+                    - self.e315() * b.e2()
+                    - self.e235() * b.e1(),
+                self.e124() * b.e5() - self.e125() * b.e4() + self.e145() * b.e2()
+                    - self.e245() * b.e1(),
+                self.e314() * b.e5() - self.e315() * b.e4() - self.e145() * b.e3()
+                    + self.e345() * b.e1(),
+                self.e234() * b.e5() - self.e235() * b.e4() + self.e245() * b.e3()
+                    - self.e345() * b.e2(),
+            ),
+            self.e1234() * b.e5() - self.e1235() * b.e4()
+                + self.e1245() * b.e3()
+                + self.e3145() * b.e2()
+                + self.e2345() * b.e1(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Vector<F>);
+
+// Bivector-Bivector
+impl<F: Float> BitXor for Bivector<F> {
+    type Output = Quadvector<F>;
+    fn bitxor(self, b: Bivector<F>) -> Quadvector<F> {
+        Quadvector::new(
+            self.e12() * b.e34()
+                + self.e31() * b.e24()
+                + self.e23() * b.e14()
+                + self.e14() * b.e23()
+                + self.e24() * b.e31()
+                + self.e34() * b.e12(),
+            self.e12() * b.e35()
+                + self.e31() * b.e25()
+                + self.e23() * b.e15()
+                + self.e15() * b.e23()
+                + self.e25() * b.e31()
+                + self.e35() * b.e12(),
+            self.e12() * b.e45() - self.e14() * b.e25()
+                + self.e24() * b.e15()
+                + self.e15() * b.e24()
+                - self.e25() * b.e14()
+                + self.e45() * b.e12(),
+            self.e31() * b.e45() + self.e14() * b.e35()
+                - self.e34() * b.e15()
+                - self.e15() * b.e34()
+                + self.e35() * b.e14()
+                + self.e45() * b.e31(),
+            self.e23() * b.e45() - self.e24() * b.e35()
+                + self.e34() * b.e25()
+                + self.e25() * b.e34()
+                - self.e35() * b.e24()
+                + self.e45() * b.e23(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Bivector<F>, Bivector<F>);
+
+// Bivector-Trivector
+impl<F: Float> BitXor<Trivector<F>> for Bivector<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Trivector<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(
+            self.e12() * b.e345()
+                + self.e31() * b.e245()
+                + self.e23() * b.e145()
+                + self.e14() * b.e235()
+                + self.e24() * b.e315()
+                + self.e34() * b.e125()
+                - self.e15() * b.e234()
+                - self.e25() * b.e314()
+                - self.e35() * b.e124()
+                + self.e45() * b.e123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Bivector<F>, Trivector<F>);
+
+// Trivector-Bivector
+impl<F: Float> BitXor<Bivector<F>> for Trivector<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Bivector<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(
+            self.e123() * b.e45()
+                - self.e124() * b.e35()
+                - self.e314() * b.e25()
+                - self.e234() * b.e15()
+                + self.e125() * b.e34()
+                + self.e315() * b.e24()
+                + self.e235() * b.e14()
+                + self.e145() * b.e23()
+                + self.e245() * b.e31()
+                + self.e345() * b.e12(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Trivector<F>, Bivector<F>);
+
+// Bivector-Quadvector
+impl<F: Float> BitXor<Quadvector<F>> for Bivector<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Quadvector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Bivector<F>, Quadvector<F>);
+
+// Quadvector-Bivector
+impl<F: Float> BitXor<Bivector<F>> for Quadvector<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Bivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Quadvector<F>, Bivector<F>);
+
+// Bivector-Pseudoscalar
+impl<F: Float> BitXor<Pseudoscalar<F>> for Bivector<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Pseudoscalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Bivector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Bivector
+impl<F: Float> BitXor<Bivector<F>> for Pseudoscalar<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Bivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Pseudoscalar<F>, Bivector<F>);
+
+// Bivector-Versor
+impl<F: Float> BitXor<Versor<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::new(
+                self.e12() * b.scalar(),
+                self.e31() * b.scalar(),
+                self.e23() * b.scalar(),
+                self.e14() * b.scalar(),
+                self.e24() * b.scalar(),
+                self.e34() * b.scalar(),
+                self.e15() * b.scalar(),
+                self.e25() * b.scalar(),
+                self.e35() * b.scalar(),
+                self.e45() * b.scalar(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e12() * b.e34()
+                    + self.e31() * b.e24()
+                    + self.e23() * b.e14()
+                    + self.e14() * b.e23()
+                    + self.e24() * b.e31()
+                    + self.e34() * b.e12(),
+                self.e12() * b.e35()
+                    + self.e31() * b.e25()
+                    + self.e23() * b.e15()
+                    + self.e15() * b.e23()
+                    + self.e25() * b.e31()
+                    + self.e35() * b.e12(),
+                self.e12() * b.e45() - self.e14() * b.e25()
+                    + self.e24() * b.e15()
+                    + self.e15() * b.e24()
+                    - self.e25() * b.e14()
+                    + self.e45() * b.e12(),
+                self.e31() * b.e45() + self.e14() * b.e35()
+                    - self.e34() * b.e15()
+                    - self.e15() * b.e34()
+                    + self.e35() * b.e14()
+                    + self.e45() * b.e31(),
+                self.e23() * b.e45() - self.e24() * b.e35()
+                    + self.e34() * b.e25()
+                    + self.e25() * b.e34()
+                    - self.e35() * b.e24()
+                    + self.e45() * b.e23(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Bivector<F>, Versor<F>);
+
+// Versor-Bivector
+impl<F: Float> BitXor<Bivector<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::new(
+                self.scalar() * b.e12(),
+                self.scalar() * b.e31(),
+                self.scalar() * b.e23(),
+                self.scalar() * b.e14(),
+                self.scalar() * b.e24(),
+                self.scalar() * b.e34(),
+                self.scalar() * b.e15(),
+                self.scalar() * b.e25(),
+                self.scalar() * b.e35(),
+                self.scalar() * b.e45(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e12() * b.e34()
+                    + self.e31() * b.e24()
+                    + self.e23() * b.e14()
+                    + self.e14() * b.e23()
+                    + self.e24() * b.e31()
+                    + self.e34() * b.e12(),
+                self.e12() * b.e35()
+                    + self.e31() * b.e25()
+                    + self.e23() * b.e15()
+                    + self.e15() * b.e23()
+                    + self.e25() * b.e31()
+                    + self.e35() * b.e12(),
+                self.e12() * b.e45() - self.e14() * b.e25()
+                    + self.e24() * b.e15()
+                    + self.e15() * b.e24()
+                    - self.e25() * b.e14()
+                    + self.e45() * b.e12(),
+                self.e31() * b.e45() + self.e14() * b.e35()
+                    - self.e34() * b.e15()
+                    - self.e15() * b.e34()
+                    + self.e35() * b.e14()
+                    + self.e45() * b.e31(),
+                self.e23() * b.e45() - self.e24() * b.e35()
+                    + self.e34() * b.e25()
+                    + self.e25() * b.e34()
+                    - self.e35() * b.e24()
+                    + self.e45() * b.e23(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Versor<F>, Bivector<F>);
+
+// Bivector-Multivector
+impl<F: Float> BitXor<Multivector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::new(
+                self.e12() * b.scalar(),
+                self.e31() * b.scalar(),
+                self.e23() * b.scalar(),
+                self.e14() * b.scalar(),
+                self.e24() * b.scalar(),
+                self.e34() * b.scalar(),
+                self.e15() * b.scalar(),
+                self.e25() * b.scalar(),
+                self.e35() * b.scalar(),
+                self.e45() * b.scalar(),
+            ),
+            Trivector::new(
+                self.e12() * b.e3() + self.e31() * b.e2() + self.e23() * b.e1(),
+                self.e12() * b.e4() - self.e14() * b.e2() + self.e24() * b.e1(),
+                self.e31() * b.e4() + self.e14() * b.e3() - self.e34() * b.e1(),
+                self.e23() * b.e4() - self.e24() * b.e3() + self.e34() * b.e2(),
+                self.e12() * b.e5() - self.e15() * b.e2() + self.e25() * b.e1(),
+                self.e31() * b.e5() + self.e15() * b.e3() - self.e35() * b.e1(),
+                self.e23() * b.e5() - self.e25() * b.e3() + self.e35() * b.e2(),
+                self.e14() * b.e5() - self.e15() * b.e4() + self.e45() * b.e1(),
+                self.e24() * b.e5() - self.e25() * b.e4() + self.e45() * b.e2(),
+                self.e34() * b.e5() - self.e35() * b.e4() + self.e45() * b.e3(),
+            ),
+            Quadvector::new(
+                self.e12() * b.e34()
+                    + self.e31() * b.e24()
+                    + self.e23() * b.e14()
+                    + self.e14() * b.e23()
+                    + self.e24() * b.e31()
+                    + self.e34() * b.e12(),
+                self.e12() * b.e35()
+                    + self.e31() * b.e25()
+                    + self.e23() * b.e15()
+                    + self.e15() * b.e23()
+                    + self.e25() * b.e31()
+                    + self.e35() * b.e12(),
+                self.e12() * b.e45() - self.e14() * b.e25()
+                    + self.e24() * b.e15()
+                    + self.e15() * b.e24()
+                    - self.e25() * b.e14()
+                    + self.e45() * b.e12(),
+                self.e31() * b.e45() + self.e14() * b.e35()
+                    - self.e34() * b.e15()
+                    - self.e15() * b.e34()
+                    + self.e35() * b.e14()
+                    + self.e45() * b.e31(),
+                self.e23() * b.e45() - self.e24() * b.e35()
+                    + self.e34() * b.e25()
+                    + self.e25() * b.e34()
+                    - self.e35() * b.e24()
+                    + self.e45() * b.e23(),
+            ),
+            self.e12() * b.e345()
+                + self.e31() * b.e245()
+                + self.e23() * b.e145()
+                + self.e14() * b.e235()
+                + self.e24() * b.e315()
+                + self.e34() * b.e125()
+                - self.e15() * b.e234()
+                - self.e25() * b.e314()
+                - self.e35() * b.e124()
+                + self.e45() * b.e123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Bivector<F>, Multivector<F>);
+
+// Multivector-Bivector
+impl<F: Float> BitXor<Bivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::new(
+                self.scalar() * b.e12(),
+                self.scalar() * b.e31(),
+                self.scalar() * b.e23(),
+                self.scalar() * b.e14(),
+                self.scalar() * b.e24(),
+                self.scalar() * b.e34(),
+                self.scalar() * b.e15(),
+                self.scalar() * b.e25(),
+                self.scalar() * b.e35(),
+                self.scalar() * b.e45(),
+            ),
+            Trivector::new(
+                self.e1() * b.e23() + self.e2() * b.e31() + self.e3() * b.e12(),
+                self.e1() * b.e24() - self.e2() * b.e14() + self.e4() * b.e12(),
+                -self.e1() * b.e34() + self.e3() * b.e14() + self.e4() * b.e31(),
+                self.e2() * b.e34() - self.e3() * b.e24() + self.e4() * b.e23(),
+                self.e1() * b.e25() - self.e2() * b.e15() + self.e5() * b.e12(),
+                -self.e1() * b.e35() + self.e3() * b.e15() + self.e5() * b.e31(),
+                self.e2() * b.e35() - self.e3() * b.e25() + self.e5() * b.e23(),
+                self.e1() * b.e45() - self.e4() * b.e15() + self.e5() * b.e14(),
+                self.e2() * b.e45() - self.e4() * b.e25() + self.e5() * b.e24(),
+                self.e3() * b.e45() - self.e4() * b.e35() + self.e5() * b.e34(),
+            ),
+            Quadvector::new(
+                self.e12() * b.e34()
+                    + self.e31() * b.e24()
+                    + self.e23() * b.e14()
+                    + self.e14() * b.e23()
+                    + self.e24() * b.e31()
+                    + self.e34() * b.e12(),
+                self.e12() * b.e35()
+                    + self.e31() * b.e25()
+                    + self.e23() * b.e15()
+                    + self.e15() * b.e23()
+                    + self.e25() * b.e31()
+                    + self.e35() * b.e12(),
+                self.e12() * b.e45() - self.e14() * b.e25()
+                    + self.e24() * b.e15()
+                    + self.e15() * b.e24()
+                    - self.e25() * b.e14()
+                    + self.e45() * b.e12(),
+                self.e31() * b.e45() + self.e14() * b.e35()
+                    - self.e34() * b.e15()
+                    - self.e15() * b.e34()
+                    + self.e35() * b.e14()
+                    + self.e45() * b.e31(),
+                self.e23() * b.e45() - self.e24() * b.e35()
+                    + self.e34() * b.e25()
+                    + self.e25() * b.e34()
+                    - self.e35() * b.e24()
+                    + self.e45() * b.e23(),
+            ),
+            self.e123() * b.e45()
+                - self.e124() * b.e35()
+                - self.e314() * b.e25()
+                - self.e234() * b.e15()
+                + self.e125() * b.e34()
+                + self.e315() * b.e24()
+                + self.e235() * b.e14()
+                + self.e145() * b.e23()
+                + self.e245() * b.e31()
+                + self.e345() * b.e12(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Bivector<F>);
+
+// Trivector-Trivector
+impl<F: Float> BitXor for Trivector<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Trivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Trivector<F>, Trivector<F>);
+
+// Trivector-Quadvector
+impl<F: Float> BitXor<Quadvector<F>> for Trivector<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Quadvector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Trivector<F>, Quadvector<F>);
+
+// Quadvector-Trivector
+impl<F: Float> BitXor<Trivector<F>> for Quadvector<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Trivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Quadvector<F>, Trivector<F>);
+
+// Trivector-Pseudoscalar
+impl<F: Float> BitXor<Pseudoscalar<F>> for Trivector<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Pseudoscalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Trivector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Trivector
+impl<F: Float> BitXor<Trivector<F>> for Pseudoscalar<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Trivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Pseudoscalar<F>, Trivector<F>);
+
+// Trivector-Versor
+impl<F: Float> BitXor<Versor<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::new(
+                self.e123() * b.scalar(),
+                self.e124() * b.scalar(),
+                self.e314() * b.scalar(),
+                self.e234() * b.scalar(),
+                self.e125() * b.scalar(),
+                self.e315() * b.scalar(),
+                self.e235() * b.scalar(),
+                self.e145() * b.scalar(),
+                self.e245() * b.scalar(),
+                self.e345() * b.scalar(),
+            ),
+            Quadvector::zero(),
+            self.e123() * b.e45()
+                - self.e124() * b.e35()
+                - self.e314() * b.e25()
+                - self.e234() * b.e15()
+                + self.e125() * b.e34()
+                + self.e315() * b.e24()
+                + self.e235() * b.e14()
+                + self.e145() * b.e23()
+                + self.e245() * b.e31()
+                + self.e345() * b.e12(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Trivector<F>, Versor<F>);
+
+// Versor-Trivector
+impl<F: Float> BitXor<Trivector<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::new(
+                self.scalar() * b.e123(),
+                self.scalar() * b.e124(),
+                self.scalar() * b.e314(),
+                self.scalar() * b.e234(),
+                self.scalar() * b.e125(),
+                self.scalar() * b.e315(),
+                self.scalar() * b.e235(),
+                self.scalar() * b.e145(),
+                self.scalar() * b.e245(),
+                self.scalar() * b.e345(),
+            ),
+            Quadvector::zero(),
+            self.e12() * b.e345()
+                + self.e31() * b.e245()
+                + self.e23() * b.e145()
+                + self.e14() * b.e235()
+                + self.e24() * b.e315()
+                + self.e34() * b.e125()
+                - self.e15() * b.e234()
+                - self.e25() * b.e314()
+                - self.e35() * b.e124()
+                + self.e45() * b.e123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Versor<F>, Trivector<F>);
+
+// Trivector-Multivector
+impl<F: Float> BitXor<Multivector<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::new(
+                self.e123() * b.scalar(),
+                self.e124() * b.scalar(),
+                self.e314() * b.scalar(),
+                self.e234() * b.scalar(),
+                self.e125() * b.scalar(),
+                self.e315() * b.scalar(),
+                self.e235() * b.scalar(),
+                self.e145() * b.scalar(),
+                self.e245() * b.scalar(),
+                self.e345() * b.scalar(),
+            ),
+            Quadvector::new(
+                self.e123() * b.e4()
+                    - self.e124() * b.e3()
+                    - self.e314() * b.e2()
+                    - self.e234() * b.e1(),
+                self.e123() * b.e5()
+                    - self.e125() * b.e3()
+                    - self.e315() * b.e2()
+                    - self.e235() * b.e1(),
+                self.e124() * b.e5() - self.e125() * b.e4() + self.e145() * b.e2()
+                    - self.e245() * b.e1(),
+                self.e314() * b.e5() - self.e315() * b.e4() - self.e145() * b.e3()
+                    + self.e345() * b.e1(),
+                self.e234() * b.e5() - self.e235() * b.e4() + self.e245() * b.e3()
+                    - self.e345() * b.e2(),
+            ),
+            self.e123() * b.e45()
+                - self.e124() * b.e35()
+                - self.e314() * b.e25()
+                - self.e234() * b.e15()
+                + self.e125() * b.e34()
+                + self.e315() * b.e24()
+                + self.e235() * b.e14()
+                + self.e145() * b.e23()
+                + self.e245() * b.e31()
+                + self.e345() * b.e12(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Trivector<F>, Multivector<F>);
+
+// Multivector-Trivector
+impl<F: Float> BitXor<Trivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::new(
+                self.scalar() * b.e123(),
+                self.scalar() * b.e124(),
+                self.scalar() * b.e314(),
+                self.scalar() * b.e234(),
+                self.scalar() * b.e125(),
+                self.scalar() * b.e315(),
+                self.scalar() * b.e235(),
+                self.scalar() * b.e145(),
+                self.scalar() * b.e245(),
+                self.scalar() * b.e345(),
+            ),
+            Quadvector::new(
+                self.e1() * b.e234() + self.e2() * b.e314() + self.e3() * b.e124()
+                    - self.e4() * b.e123(),
+                self.e1() * b.e235() + self.e2() * b.e315() + self.e3() * b.e125()
+                    - self.e5() * b.e123(),
+                self.e1() * b.e245() - self.e2() * b.e145() + self.e4() * b.e125()
+                    - self.e5() * b.e124(),
+                -self.e1() * b.e345() + self.e3() * b.e145() + self.e4() * b.e315()
+                    - self.e5() * b.e314(),
+                self.e2() * b.e345() - self.e3() * b.e245() + self.e4() * b.e235()
+                    - self.e5() * b.e234(),
+            ),
+            self.e12() * b.e345()
+                + self.e31() * b.e245()
+                + self.e23() * b.e145()
+                + self.e14() * b.e235()
+                + self.e24() * b.e315()
+                + self.e34() * b.e125()
+                - self.e15() * b.e234()
+                - self.e25() * b.e314()
+                - self.e35() * b.e124()
+                + self.e45() * b.e123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Trivector<F>);
+
+// Quadvector-Quadvector
+impl<F: Float> BitXor for Quadvector<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Quadvector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Quadvector<F>, Quadvector<F>);
+
+// Quadvector-Pseudoscalar
+impl<F: Float> BitXor<Pseudoscalar<F>> for Quadvector<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Pseudoscalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Quadvector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Quadvector
+impl<F: Float> BitXor<Quadvector<F>> for Pseudoscalar<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Quadvector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Pseudoscalar<F>, Quadvector<F>);
+
+// Quadvector-Versor
+impl<F: Float> BitXor<Versor<F>> for Quadvector<F> {
+    type Output = Quadvector<F>;
+    fn bitxor(self, b: Versor<F>) -> Quadvector<F> {
+        Quadvector::new(
+            self.e1234() * b.scalar(),
+            self.e1235() * b.scalar(),
+            self.e1245() * b.scalar(),
+            self.e3145() * b.scalar(),
+            self.e2345() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Quadvector<F>, Versor<F>);
+
+// Versor-Quadvector
+impl<F: Float> BitXor<Quadvector<F>> for Versor<F> {
+    type Output = Quadvector<F>;
+    fn bitxor(self, b: Quadvector<F>) -> Quadvector<F> {
+        Quadvector::new(
+            self.scalar() * b.e1234(),
+            self.scalar() * b.e1235(),
+            self.scalar() * b.e1245(),
+            self.scalar() * b.e3145(),
+            self.scalar() * b.e2345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Versor<F>, Quadvector<F>);
+
+// Quadvector-Multivector
+impl<F: Float> BitXor<Multivector<F>> for Quadvector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e1234() * b.scalar(),
+                self.e1235() * b.scalar(),
+                self.e1245() * b.scalar(),
+                self.e3145() * b.scalar(),
+                self.e2345() * b.scalar(),
+            ),
+            self.e1234() * b.e5() - self.e1235() * b.e4()
+                + self.e1245() * b.e3()
+                + self.e3145() * b.e2()
+                + self.e2345() * b.e1(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Quadvector<F>, Multivector<F>);
+
+// Multivector-Quadvector
+impl<F: Float> BitXor<Quadvector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Quadvector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::zero(),
+            Quadvector::new(
+                self.scalar() * b.e1234(),
+                self.scalar() * b.e1235(),
+                self.scalar() * b.e1245(),
+                self.scalar() * b.e3145(),
+                self.scalar() * b.e2345(),
+            ),
+            self.e1() * b.e2345() + self.e2() * b.e3145() + self.e3() * b.e1245()
+                - self.e4() * b.e1235()
+                + self.e5() * b.e1234(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Quadvector<F>);
+
+// Pseudoscalar-Pseudoscalar
+impl<F: Float> BitXor for Pseudoscalar<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Pseudoscalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Pseudoscalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Versor
+impl<F: Float> BitXor<Versor<F>> for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Versor<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.e12345() * b.scalar())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Pseudoscalar<F>, Versor<F>);
+
+// Versor-Pseudoscalar
+impl<F: Float> BitXor<Pseudoscalar<F>> for Versor<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Pseudoscalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.scalar() * b.e12345())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Versor<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Multivector
+impl<F: Float> BitXor<Multivector<F>> for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Multivector<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.e12345() * b.scalar())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Pseudoscalar<F>, Multivector<F>);
+
+// Multivector-Pseudoscalar
+impl<F: Float> BitXor<Pseudoscalar<F>> for Multivector<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Pseudoscalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.scalar() * b.e12345())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Pseudoscalar<F>);
+
+// Versor-Versor
+impl<F: Float> BitXor for Versor<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar(),
+            Vector::zero(),
+            Bivector::new(
+                self.scalar() * b.e12() + self.e12() * b.scalar(),
+                self.scalar() * b.e31() + self.e31() * b.scalar(),
+                self.scalar() * b.e23() + self.e23() * b.scalar(),
+                self.scalar() * b.e14() + self.e14() * b.scalar(),
+                self.scalar() * b.e24() + self.e24() * b.scalar(),
+                self.scalar() * b.e34() + self.e34() * b.scalar(),
+                self.scalar() * b.e15() + self.e15() * b.scalar(),
+                self.scalar() * b.e25() + self.e25() * b.scalar(),
+                self.scalar() * b.e35() + self.e35() * b.scalar(),
+                self.scalar() * b.e45() + self.e45() * b.scalar(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.scalar() * b.e1234()
+                    + self.e12() * b.e34()
+                    + self.e31() * b.e24()
+                    + self.e23() * b.e14()
+                    + self.e14() * b.e23()
+                    + self.e24() * b.e31()
+                    + self.e34() * b.e12()
+                    + self.e1234() * b.scalar(),
+                self.scalar() * b.e1235()
+                    + self.e12() * b.e35()
+                    + self.e31() * b.e25()
+                    + self.e23() * b.e15()
+                    + self.e15() * b.e23()
+                    + self.e25() * b.e31()
+                    + self.e35() * b.e12()
+                    + self.e1235() * b.scalar(),
+                self.scalar() * b.e1245() + self.e12() * b.e45() - self.e14() * b.e25()
+                    + self.e24() * b.e15()
+                    + self.e15() * b.e24()
+                    - self.e25() * b.e14()
+                    + self.e45() * b.e12()
+                    + self.e1245() * b.scalar(),
+                self.scalar() * b.e3145() + self.e31() * b.e45() + self.e14() * b.e35()
+                    - self.e34() * b.e15()
+                    - self.e15() * b.e34()
+                    + self.e35() * b.e14()
+                    + self.e45() * b.e31()
+                    + self.e3145() * b.scalar(),
+                self.scalar() * b.e2345() + self.e23() * b.e45() - self.e24() * b.e35()
+                    + self.e34() * b.e25()
+                    + self.e25() * b.e34()
+                    - self.e35() * b.e24()
+                    + self.e45() * b.e23()
+                    + self.e2345() * b.scalar(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Versor<F>, Versor<F>);
+
+// Versor-Multivector
+impl<F: Float> BitXor<Multivector<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar(),
+            Vector::new(
+                self.scalar() * b.e1(),
+                self.scalar() * b.e2(),
+                self.scalar() * b.e3(),
+                self.scalar() * b.e4(),
+                self.scalar() * b.e5(),
+            ),
+            Bivector::new(
+                self.scalar() * b.e12() + self.e12() * b.scalar(),
+                self.scalar() * b.e31() + self.e31() * b.scalar(),
+                self.scalar() * b.e23() + self.e23() * b.scalar(),
+                self.scalar() * b.e14() + self.e14() * b.scalar(),
+                self.scalar() * b.e24() + self.e24() * b.scalar(),
+                self.scalar() * b.e34() + self.e34() * b.scalar(),
+                self.scalar() * b.e15() + self.e15() * b.scalar(),
+                self.scalar() * b.e25() + self.e25() * b.scalar(),
+                self.scalar() * b.e35() + self.e35() * b.scalar(),
+                self.scalar() * b.e45() + self.e45() * b.scalar(),
+            ),
+            Trivector::new(
+                self.scalar() * b.e123()
+                    + self.e12() * b.e3()
+                    + self.e31() * b.e2()
+                    + self.e23() * b.e1(),
+                self.scalar() * b.e124() + self.e12() * b.e4() - self.e14() * b.e2()
+                    + self.e24() * b.e1(),
+                self.scalar() * b.e314() + self.e31() * b.e4() + self.e14() * b.e3()
+                    - self.e34() * b.e1(),
+                self.scalar() * b.e234() + self.e23() * b.e4() - self.e24() * b.e3()
+                    + self.e34() * b.e2(),
+                self.scalar() * b.e125() + self.e12() * b.e5() - self.e15() * b.e2()
+                    + self.e25() * b.e1(),
+                self.scalar() * b.e315() + self.e31() * b.e5() + self.e15() * b.e3()
+                    - self.e35() * b.e1(),
+                self.scalar() * b.e235() + self.e23() * b.e5() - self.e25() * b.e3()
+                    + self.e35() * b.e2(),
+                self.scalar() * b.e145() + self.e14() * b.e5() - self.e15() * b.e4()
+                    + self.e45() * b.e1(),
+                self.scalar() * b.e245() + self.e24() * b.e5() - self.e25() * b.e4()
+                    + self.e45() * b.e2(),
+                self.scalar() * b.e345() + self.e34() * b.e5() - self.e35() * b.e4()
+                    + self.e45() * b.e3(),
+            ),
+            Quadvector::new(
+                self.scalar() * b.e1234()
+                    + self.e12() * b.e34()
+                    + self.e31() * b.e24()
+                    + self.e23() * b.e14()
+                    + self.e14() * b.e23()
+                    + self.e24() * b.e31()
+                    + self.e34() * b.e12()
+                    + self.e1234() * b.scalar(),
+                self.scalar() * b.e1235()
+                    + self.e12() * b.e35()
+                    + self.e31() * b.e25()
+                    + self.e23() * b.e15()
+                    + self.e15() * b.e23()
+                    + self.e25() * b.e31()
+                    + self.e35() * b.e12()
+                    + self.e1235() * b.scalar(),
+                self.scalar() * b.e1245() + self.e12() * b.e45() - self.e14() * b.e25()
+                    + self.e24() * b.e15()
+                    + self.e15() * b.e24()
+                    - self.e25() * b.e14()
+                    + self.e45() * b.e12()
+                    + self.e1245() * b.scalar(),
+                self.scalar() * b.e3145() + self.e31() * b.e45() + self.e14() * b.e35()
+                    - self.e34() * b.e15()
+                    - self.e15() * b.e34()
+                    + self.e35() * b.e14()
+                    + self.e45() * b.e31()
+                    + self.e3145() * b.scalar(),
+                self.scalar() * b.e2345() + self.e23() * b.e45() - self.e24() * b.e35()
+                    + self.e34() * b.e25()
+                    + self.e25() * b.e34()
+                    - self.e35() * b.e24()
+                    + self.e45() * b.e23()
+                    + self.e2345() * b.scalar(),
+            ),
+            self.scalar() * b.e12345()
+                + self.e12() * b.e345()
+                + self.e31() * b.e245()
+                + self.e23() * b.e145()
+                + self.e14() * b.e235()
+                + self.e24() * b.e315()
+                + self.e34() * b.e125()
+                - self.e15() * b.e234()
+                - self.e25() * b.e314()
+                - self.e35() * b.e124()
+                + self.e45() * b.e123()
+                + self.e1234() * b.e5()
+                - self.e1235() * b.e4()
+                + self.e1245() * b.e3()
+                + self.e3145() * b.e2()
+                + self.e2345() * b.e1(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Versor<F>, Multivector<F>);
+
+// Multivector-Versor
+impl<F: Float> BitXor<Versor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar(),
+            Vector::new(
+                self.e1() * b.scalar(),
+                self.e2() * b.scalar(),
+                self.e3() * b.scalar(),
+                self.e4() * b.scalar(),
+                self.e5() * b.scalar(),
+            ),
+            Bivector::new(
+                self.scalar() * b.e12() + self.e12() * b.scalar(),
+                self.scalar() * b.e31() + self.e31() * b.scalar(),
+                self.scalar() * b.e23() + self.e23() * b.scalar(),
+                self.scalar() * b.e14() + self.e14() * b.scalar(),
+                self.scalar() * b.e24() + self.e24() * b.scalar(),
+                self.scalar() * b.e34() + self.e34() * b.scalar(),
+                self.scalar() * b.e15() + self.e15() * b.scalar(),
+                self.scalar() * b.e25() + self.e25() * b.scalar(),
+                self.scalar() * b.e35() + self.e35() * b.scalar(),
+                self.scalar() * b.e45() + self.e45() * b.scalar(),
+            ),
+            Trivector::new(
+                self.e1() * b.e23()
+                    + self.e2() * b.e31()
+                    + self.e3() * b.e12()
+                    + self.e123() * b.scalar(),
+                self.e1() * b.e24() - self.e2() * b.e14()
+                    + self.e4() * b.e12()
+                    + self.e124() * b.scalar(),
+                -self.e1() * b.e34()
+                    + self.e3() * b.e14()
+                    + self.e4() * b.e31()
+                    + self.e314() * b.scalar(),
+                self.e2() * b.e34() - self.e3() * b.e24()
+                    + self.e4() * b.e23()
+                    + self.e234() * b.scalar(),
+                self.e1() * b.e25() - self.e2() * b.e15()
+                    + self.e5() * b.e12()
+                    + self.e125() * b.scalar(),
+                -self.e1() * b.e35()
+                    + self.e3() * b.e15()
+                    + self.e5() * b.e31()
+                    + self.e315() * b.scalar(),
+                self.e2() * b.e35() - self.e3() * b.e25()
+                    + self.e5() * b.e23()
+                    + self.e235() * b.scalar(),
+                self.e1() * b.e45() - self.e4() * b.e15()
+                    + self.e5() * b.e14()
+                    + self.e145() * b.scalar(),
+                self.e2() * b.e45() - self.e4() * b.e25()
+                    + self.e5() * b.e24()
+                    + self.e245() * b.scalar(),
+                self.e3() * b.e45() - self.e4() * b.e35()
+                    + self.e5() * b.e34()
+                    + self.e345() * b.scalar(),
+            ),
+            Quadvector::new(
+                self.scalar() * b.e1234()
+                    + self.e12() * b.e34()
+                    + self.e31() * b.e24()
+                    + self.e23() * b.e14()
+                    + self.e14() * b.e23()
+                    + self.e24() * b.e31()
+                    + self.e34() * b.e12()
+                    + self.e1234() * b.scalar(),
+                self.scalar() * b.e1235()
+                    + self.e12() * b.e35()
+                    + self.e31() * b.e25()
+                    + self.e23() * b.e15()
+                    + self.e15() * b.e23()
+                    + self.e25() * b.e31()
+                    + self.e35() * b.e12()
+                    + self.e1235() * b.scalar(),
+                self.scalar() * b.e1245() + self.e12() * b.e45() - self.e14() * b.e25()
+                    + self.e24() * b.e15()
+                    + self.e15() * b.e24()
+                    - self.e25() * b.e14()
+                    + self.e45() * b.e12()
+                    + self.e1245() * b.scalar(),
+                self.scalar() * b.e3145() + self.e31() * b.e45() + self.e14() * b.e35()
+                    - self.e34() * b.e15()
+                    - self.e15() * b.e34()
+                    + self.e35() * b.e14()
+                    + self.e45() * b.e31()
+                    + self.e3145() * b.scalar(),
+                self.scalar() * b.e2345() + self.e23() * b.e45() - self.e24() * b.e35()
+                    + self.e34() * b.e25()
+                    + self.e25() * b.e34()
+                    - self.e35() * b.e24()
+                    + self.e45() * b.e23()
+                    + self.e2345() * b.scalar(),
+            ),
+            self.e1() * b.e2345() + self.e2() * b.e3145() + self.e3() * b.e1245()
+                - self.e4() * b.e1235()
+                + self.e5() * b.e1234()
+                + self.e123() * b.e45()
+                - self.e124() * b.e35()
+                - self.e314() * b.e25()
+                - self.e234() * b.e15()
+                + self.e125() * b.e34()
+                + self.e315() * b.e24()
+                + self.e235() * b.e14()
+                + self.e145() * b.e23()
+                + self.e245() * b.e31()
+                + self.e345() * b.e12()
+                + self.e12345() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Versor<F>);
+
+// Multivector-Multivector
+impl<F: Float> BitXor for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar(),
+            Vector::new(
+                self.scalar() * b.e1() + self.e1() * b.scalar(),
+                self.scalar() * b.e2() + self.e2() * b.scalar(),
+                self.scalar() * b.e3() + self.e3() * b.scalar(),
+                self.scalar() * b.e4() + self.e4() * b.scalar(),
+                self.scalar() * b.e5() + self.e5() * b.scalar(),
+            ),
+            Bivector::new(
+                self.scalar() * b.e12() + self.e1() * b.e2() - self.e2() * b.e1()
+                    + self.e12() * b.scalar(),
+                self.scalar() * b.e31() - self.e1() * b.e3()
+                    + self.e3() * b.e1()
+                    + self.e31() * b.scalar(),
+                self.scalar() * b.e23() + self.e2() * b.e3() - self.e3() * b.e2()
+                    + self.e23() * b.scalar(),
+                self.scalar() * b.e14() + self.e1() * b.e4() - self.e4() * b.e1()
+                    + self.e14() * b.scalar(),
+                self.scalar() * b.e24() + self.e2() * b.e4() - self.e4() * b.e2()
+                    + self.e24() * b.scalar(),
+                self.scalar() * b.e34() + self.e3() * b.e4() - self.e4() * b.e3()
+                    + self.e34() * b.scalar(),
+                self.scalar() * b.e15() + self.e1() * b.e5() - self.e5() * b.e1()
+                    + self.e15() * b.scalar(),
+                self.scalar() * b.e25() + self.e2() * b.e5() - self.e5() * b.e2()
+                    + self.e25() * b.scalar(),
+                self.scalar() * b.e35() + self.e3() * b.e5() - self.e5() * b.e3()
+                    + self.e35() * b.scalar(),
+                self.scalar() * b.e45() + self.e4() * b.e5() - self.e5() * b.e4()
+                    + self.e45() * b.scalar(),
+            ),
+            Trivector::new(
+                self.scalar() * b.e123()
+                    + self.e1() * b.e23()
+                    + self.e2() * b.e31()
+                    + self.e3() * b.e12()
+                    + self.e12() * b.e3()
+                    + self.e31() * b.e2()
+                    + self.e23() * b.e1()
+                    + self.e123() * b.scalar(),
+                self.scalar() * b.e124() + self.e1() * b.e24() - self.e2() * b.e14()
+                    + self.e4() * b.e12()
+                    + self.e12() * b.e4()
+                    - self.e14() * b.e2()
+                    + self.e24() * b.e1()
+                    + self.e124() * b.scalar(),
+                self.scalar() * b.e314() - self.e1() * b.e34()
+                    + self.e3() * b.e14()
+                    + self.e4() * b.e31()
+                    + self.e31() * b.e4()
+                    + self.e14() * b.e3()
+                    - self.e34() * b.e1()
+                    + self.e314() * b.scalar(),
+                self.scalar() * b.e234() + self.e2() * b.e34() - self.e3() * b.e24()
+                    + self.e4() * b.e23()
+                    + self.e23() * b.e4()
+                    - self.e24() * b.e3()
+                    + self.e34() * b.e2()
+                    + self.e234() * b.scalar(),
+                self.scalar() * b.e125() + self.e1() * b.e25() - self.e2() * b.e15()
+                    + self.e5() * b.e12()
+                    + self.e12() * b.e5()
+                    - self.e15() * b.e2()
+                    + self.e25() * b.e1()
+                    + self.e125() * b.scalar(),
+                self.scalar() * b.e315() - self.e1() * b.e35()
+                    + self.e3() * b.e15()
+                    + self.e5() * b.e31()
+                    + self.e31() * b.e5()
+                    + self.e15() * b.e3()
+                    - self.e35() * b.e1()
+                    + self.e315() * b.scalar(),
+                self.scalar() * b.e235() + self.e2() * b.e35() - self.e3() * b.e25()
+                    + self.e5() * b.e23()
+                    + self.e23() * b.e5()
+                    - self.e25() * b.e3()
+                    + self.e35() * b.e2()
+                    + self.e235() * b.scalar(),
+                self.scalar() * b.e145() + self.e1() * b.e45() - self.e4() * b.e15()
+                    + self.e5() * b.e14()
+                    + self.e14() * b.e5()
+                    - self.e15() * b.e4()
+                    + self.e45() * b.e1()
+                    + self.e145() * b.scalar(),
+                self.scalar() * b.e245() + self.e2() * b.e45() - self.e4() * b.e25()
+                    + self.e5() * b.e24()
+                    + self.e24() * b.e5()
+                    - self.e25() * b.e4()
+                    + self.e45() * b.e2()
+                    + self.e245() * b.scalar(),
+                self.scalar() * b.e345() + self.e3() * b.e45() - self.e4() * b.e35()
+                    + self.e5() * b.e34()
+                    + self.e34() * b.e5()
+                    - self.e35() * b.e4()
+                    + self.e45() * b.e3()
+                    + self.e345() * b.scalar(),
+            ),
+            Quadvector::new(
+                self.scalar() * b.e1234()
+                    + self.e1() * b.e234()
+                    + self.e2() * b.e314()
+                    + self.e3() * b.e124()
+                    - self.e4() * b.e123()
+                    + self.e12() * b.e34()
+                    + self.e31() * b.e24()
+                    + self.e23() * b.e14()
+                    + self.e14() * b.e23()
+                    + self.e24() * b.e31()
+                    + self.e34() * b.e12()
+                    + self.e123() * b.e4()
+                    - self.e124() * b.e3()
+                    - self.e314() * b.e2()
+                    - self.e234() * b.e1()
+                    + self.e1234() * b.scalar(),
+                self.scalar() * b.e1235()
+                    + self.e1() * b.e235()
+                    + self.e2() * b.e315()
+                    + self.e3() * b.e125()
+                    - self.e5() * b.e123()
+                    + self.e12() * b.e35()
+                    + self.e31() * b.e25()
+                    + self.e23() * b.e15()
+                    + self.e15() * b.e23()
+                    + self.e25() * b.e31()
+                    + self.e35() * b.e12()
+                    + self.e123() * b.e5()
+                    - self.e125() * b.e3()
+                    - self.e315() * b.e2()
+                    - self.e235() * b.e1()
+                    + self.e1235() * b.scalar(),
+                self.scalar() * b.e1245() + self.e1() * b.e245() - self.e2() * b.e145()
+                    + self.e4() * b.e125()
+                    - self.e5() * b.e124()
+                    + self.e12() * b.e45()
+                    - self.e14() * b.e25()
+                    + self.e24() * b.e15()
+                    + self.e15() * b.e24()
+                    - self.e25() * b.e14()
+                    + self.e45() * b.e12()
+                    + self.e124() * b.e5()
+                    - self.e125() * b.e4()
+                    + self.e145() * b.e2()
+                    - self.e245() * b.e1()
+                    + self.e1245() * b.scalar(),
+                self.scalar() * b.e3145() - self.e1() * b.e345()
+                    + self.e3() * b.e145()
+                    + self.e4() * b.e315()
+                    - self.e5() * b.e314()
+                    + self.e31() * b.e45()
+                    + self.e14() * b.e35()
+                    - self.e34() * b.e15()
+                    - self.e15() * b.e34()
+                    + self.e35() * b.e14()
+                    + self.e45() * b.e31()
+                    + self.e314() * b.e5()
+                    - self.e315() * b.e4()
+                    - self.e145() * b.e3()
+                    + self.e345() * b.e1()
+                    + self.e3145() * b.scalar(),
+                self.scalar() * b.e2345() + self.e2() * b.e345() - self.e3() * b.e245()
+                    + self.e4() * b.e235()
+                    - self.e5() * b.e234()
+                    + self.e23() * b.e45()
+                    - self.e24() * b.e35()
+                    + self.e34() * b.e25()
+                    + self.e25() * b.e34()
+                    - self.e35() * b.e24()
+                    + self.e45() * b.e23()
+                    + self.e234() * b.e5()
+                    - self.e235() * b.e4()
+                    + self.e245() * b.e3()
+                    - self.e345() * b.e2()
+                    + self.e2345() * b.scalar(),
+            ),
+            self.scalar() * b.e12345()
+                + self.e1() * b.e2345()
+                + self.e2() * b.e3145()
+                + self.e3() * b.e1245()
+                - self.e4() * b.e1235()
+                + self.e5() * b.e1234()
+                + self.e12() * b.e345()
+                + self.e31() * b.e245()
+                + self.e23() * b.e145()
+                + self.e14() * b.e235()
+                + self.e24() * b.e315()
+                + self.e34() * b.e125()
+                - self.e15() * b.e234()
+                - self.e25() * b.e314()
+                - self.e35() * b.e124()
+                + self.e45() * b.e123()
+                + self.e123() * b.e45()
+                - self.e124() * b.e35()
+                - self.e314() * b.e25()
+                - self.e234() * b.e15()
+                + self.e125() * b.e34()
+                + self.e315() * b.e24()
+                + self.e235() * b.e14()
+                + self.e145() * b.e23()
+                + self.e245() * b.e31()
+                + self.e345() * b.e12()
+                + self.e1234() * b.e5()
+                - self.e1235() * b.e4()
+                + self.e1245() * b.e3()
+                + self.e3145() * b.e2()
+                + self.e2345() * b.e1()
+                + self.e12345() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Multivector<F>);
+
+// Test
+#[cfg(test)]
+mod exterior_product {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn vector_vector() {
+        // -3e1+2e2+3e3-4e4+1e5
+        let vector1 = Vector::new(-3.0, 2.0, 3.0, -4.0, 1.0);
+        // 2e1+4e2+1e3+4e4+4e5
+        let vector2 = Vector::new(2.0, 4.0, 1.0, 4.0, 4.0);
+        let res = vector1 ^ vector2;
+        // -16e12+9e31-10e23-4e14+24e24+16e34-14e15+4e25+11e35-20e45
+        assert_relative_eq!(res.e12(), -16.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 9.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), -10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e14(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e24(), 24.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e34(), 16.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e15(), -14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e25(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e35(), 11.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e45(), -20.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_bivector() {
+        // 5e1+4e2-2e3+4e4-4e5
+        let vector1 = Vector::new(5.0, 4.0, -2.0, 4.0, -4.0);
+        // 5e12-1e31-1e23-1e14-4e24+1e34-3e15-1e25+5e35+1e45
+        let bivector2 = Bivector::new(5.0, -1.0, -1.0, -1.0, -4.0, 1.0, -3.0, -1.0, 5.0, 1.0);
+        let res = vector1 ^ bivector2;
+        // -19e123+4e124-7e314-8e234-13e125-15e315+22e235+21e145+24e245-26e345
+        assert_relative_eq!(res.e123(), -19.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e124(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e314(), -7.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e234(), -8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e125(), -13.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e315(), -15.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e235(), 22.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e145(), 21.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e245(), 24.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e345(), -26.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_vector() {
+        // -2e12+4e31-4e23-3e14+3e24+5e34+5e15+5e25+1e35+4e45
+        let bivector1 = Bivector::new(-2.0, 4.0, -4.0, -3.0, 3.0, 5.0, 5.0, 5.0, 1.0, 4.0);
+        // -3e1+1e2+5e3+2e4+5e5
+        let vector2 = Vector::new(-3.0, 1.0, 5.0, 2.0, 5.0);
+        let res = bivector1 ^ vector2;
+        // 6e123-10e124+8e314-18e234-30e125+48e315-44e235-37e145+9e245+43e345
+        assert_relative_eq!(res.e123(), 6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e124(), -10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e314(), 8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e234(), -18.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e125(), -30.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e315(), 48.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e235(), -44.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e145(), -37.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e245(), 9.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e345(), 43.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_bivector() {
+        // 3e12-1e31-4e23+3e14+4e24-4e34+3e15+1e25-4e35-1e45
+        let bivector1 = Bivector::new(3.0, -1.0, -4.0, 3.0, 4.0, -4.0, 3.0, 1.0, -4.0, -1.0);
+        // 3e12-2e31-1e23+5e14-4e24+1e34+2e15-3e25+1e35+2e45
+        let bivector2 = Bivector::new(3.0, -2.0, -1.0, 5.0, -4.0, 1.0, 2.0, -3.0, 1.0, 2.0);
+        let res = bivector1 ^ bivector2;
+        // -36e1234-19e1235+3e1245-12e3145-14e2345
+        assert_relative_eq!(res.e1234(), -36.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1235(), -19.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1245(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3145(), -12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2345(), -14.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_quadvector() {
+        // -1e1+1e2-4e3-2e4+4e5
+        let vector1 = Vector::new(-1.0, 1.0, -4.0, -2.0, 4.0);
+        // 3e1234+1e1235+5e1245-4e3145-3e2345
+        let quadvector2 = Quadvector::new(3.0, 1.0, 5.0, -4.0, -3.0);
+        let res = vector1 ^ quadvector2;
+        // -7e12345
+        assert_relative_eq!(res.e12345(), -7.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn versor_vector() {
+        // 2+5e12+5e31-2e23-3e14-1e24-4e34+5e15+2e25+4e35+4e45-4e1234-4e1235-1e1245+4e3145+4e2345
+        let versor1 = Versor::new(
+            2.0,
+            Bivector::new(5.0, 5.0, -2.0, -3.0, -1.0, -4.0, 5.0, 2.0, 4.0, 4.0),
+            Quadvector::new(-4.0, -4.0, -1.0, 4.0, 4.0),
+        );
+        // 5e1+2e2+2e3+3e4-2e5
+        let vector2 = Vector::new(5.0, 2.0, 2.0, 3.0, -2.0);
+        let res = versor1 ^ vector2;
+        // 10e1+4e2+4e3+6e4-4e5+10e123+16e124+29e314-12e234-10e125-20e315+8e235+11e145+4e245+4e345+46e12345
+        assert_relative_eq!(res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e4(), 6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e5(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e14(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e24(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e34(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e15(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e25(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e35(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e45(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e124(), 16.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e314(), 29.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e234(), -12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e125(), -10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e315(), -20.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e235(), 8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e145(), 11.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e245(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e345(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1234(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1235(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1245(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3145(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2345(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12345(), 46.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_multivector() {
+        // -4-2e1+4e2-4e3-3e4-1e5+2e12-4e31+2e23+3e14-4e24-4e34+2e15-1e25+1e35+5e45-4e123+3e124-2e314-2e234+3e125+4e315-1e235+3e145-2e245-1e345-1e1234-2e1235-4e1245+5e3145+5e2345-3e12345
+        let mvec1 = Multivector::new(
+            -4.0,
+            Vector::new(-2.0, 4.0, -4.0, -3.0, -1.0),
+            Bivector::new(2.0, -4.0, 2.0, 3.0, -4.0, -4.0, 2.0, -1.0, 1.0, 5.0),
+            Trivector::new(-4.0, 3.0, -2.0, -2.0, 3.0, 4.0, -1.0, 3.0, -2.0, -1.0),
+            Quadvector::new(-1.0, -2.0, -4.0, 5.0, 5.0),
+            -3.0,
+        );
+        // -2+5e1+3e2-2e3+1e4+2e5+5e12+1e31-2e23-4e14+5e24-4e34-3e15-1e25+1e35+5e45+1e123-2e124+5e314+5e234+2e125+4e315-2e235+3e145-4e245-4e345+3e1234+3e1235-3e1245-2e3145-2e2345+5e12345
+        let mvec2 = Multivector::new(
+            -2.0,
+            Vector::new(5.0, 3.0, -2.0, 1.0, 2.0),
+            Bivector::new(5.0, 1.0, -2.0, -4.0, 5.0, -4.0, -3.0, -1.0, 1.0, 5.0),
+            Trivector::new(1.0, -2.0, 5.0, 5.0, 2.0, 4.0, -2.0, 3.0, -4.0, -4.0),
+            Quadvector::new(3.0, 3.0, -3.0, -2.0, -2.0),
+            5.0,
+        );
+        let res = mvec1 ^ mvec2;
+        // 8-16e1-20e2+16e3+2e4-6e5-50e12-20e31+8e23+23e14+1e24+14e34+9e15+17e25-16e35-35e45-14e123-34e124-1e314-24e234-12e125-28e315+17e235-4e145+40e245-14e345-37e1234-4e1235+86e1245-56e3145-12e2345+2e12345
+        assert_relative_eq!(res.scalar(), 8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -16.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -20.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 16.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e4(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e5(), -6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), -50.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), -20.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e14(), 23.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e24(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e34(), 14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e15(), 9.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e25(), 17.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e35(), -16.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e45(), -35.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), -14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e124(), -34.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e314(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e234(), -24.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e125(), -12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e315(), -28.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e235(), 17.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e145(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e245(), 40.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e345(), -14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1234(), -37.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1235(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1245(), 86.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3145(), -56.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2345(), -12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12345(), 2.0, max_relative = 0.000001);
+    }
+}
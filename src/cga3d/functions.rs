@@ -0,0 +1,224 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    CGA3DOps, bivector::Bivector, multivector::Multivector, quadvector::Quadvector,
+    trivector::Trivector, vector::Vector, versor::Versor,
+};
+use crate::vga3d;
+
+use num_traits::Float;
+
+/// # Up projection
+/// Embeds a Euclidean vector as a conformal point
+/// $$ P = x + \frac{1}{2} x^2 \mathrm{e}_\infty + \mathrm{e}_0 $$
+pub fn up<F: Float>(vector: vga3d::Vector<F>) -> Vector<F> {
+    let half = F::one() / (F::one() + F::one());
+    let squared = vector.e1() * vector.e1() + vector.e2() * vector.e2() + vector.e3() * vector.e3();
+    Vector::from_null_basis(
+        vector.e1(),
+        vector.e2(),
+        vector.e3(),
+        F::one(),
+        squared * half,
+    )
+}
+
+/// # Down projection
+/// Gets the Euclidean vector of a conformal point.
+/// The point does not need to be normalized, since it is divided by its $\mathrm{e}_0$ part.
+/// $$ x = \frac{P_{123}}{-P \cdot \mathrm{e}_\infty} $$
+/// Points at infinity have no Euclidean position.
+pub fn down<F: Float>(point: Vector<F>) -> Option<vga3d::Vector<F>> {
+    let weight = point.e0();
+    if weight.is_zero() {
+        return None;
+    }
+    Some(vga3d::Vector::new(
+        point.e1() / weight,
+        point.e2() / weight,
+        point.e3() / weight,
+    ))
+}
+
+// Functions
+// Conformal transformation
+// \[ X' = \widetilde{V} X V \]
+pub trait Transformable<V> {
+    type Output;
+    fn transform(self, versor: V) -> Self::Output;
+}
+
+macro_rules! impl_transformable {
+    ($element:ty, $output:ty, $extract:ident) => {
+        // Owned element, owned versor
+        impl<F: Float> Transformable<Versor<F>> for $element {
+            type Output = $output;
+            fn transform(self, versor: Versor<F>) -> Self::Output {
+                (versor.reverse() * self * versor).$extract()
+            }
+        }
+
+        // Owned element, reference versor
+        impl<'v, F: Float> Transformable<&'v Versor<F>> for $element {
+            type Output = $output;
+            fn transform(self, versor: &'v Versor<F>) -> Self::Output {
+                (versor.reverse() * self * *versor).$extract()
+            }
+        }
+
+        // Reference element, owned versor
+        impl<'e, F: Float> Transformable<Versor<F>> for &'e $element {
+            type Output = $output;
+            fn transform(self, versor: Versor<F>) -> Self::Output {
+                (versor.reverse() * *self * versor).$extract()
+            }
+        }
+
+        // Reference element, reference versor
+        impl<'e, 'v, F: Float> Transformable<&'v Versor<F>> for &'e $element {
+            type Output = $output;
+            fn transform(self, versor: &'v Versor<F>) -> Self::Output {
+                (versor.reverse() * *self * *versor).$extract()
+            }
+        }
+    };
+}
+
+// Usage:
+impl_transformable!(Vector<F>, Vector<F>, vector);
+impl_transformable!(Bivector<F>, Bivector<F>, bivector);
+impl_transformable!(Trivector<F>, Trivector<F>, trivector);
+impl_transformable!(Quadvector<F>, Quadvector<F>, quadvector);
+impl_transformable!(Versor<F>, Versor<F>, versor);
+impl_transformable!(Multivector<F>, Multivector<F>, multivector);
+
+#[cfg(test)]
+mod transformation {
+    use super::*;
+    use crate::cga3d::Scalar;
+    use approx::assert_relative_eq;
+    use core::f64::consts::TAU;
+
+    fn assert_vector_eq(res: vga3d::Vector<f64>, expected: vga3d::Vector<f64>) {
+        assert_relative_eq!(res.e1(), expected.e1(), max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), expected.e2(), max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), expected.e3(), max_relative = 0.000001);
+    }
+
+    #[test]
+    fn up_down() {
+        let vector = vga3d::Vector::new(1.0, -2.0, 3.0);
+        let point = up(vector);
+        // Points are null vectors
+        assert_relative_eq!((point | point).0, 0.0, epsilon = 0.000001);
+        assert_relative_eq!(point.e0(), 1.0, max_relative = 0.000001);
+        assert_vector_eq(down(point).unwrap(), vector);
+        // The point is the same after scaling
+        let point = point * Scalar::new(-3.0);
+        assert_vector_eq(down(point).unwrap(), vector);
+        assert_eq!(down(Vector::<f64>::infinity()), None);
+    }
+
+    #[test]
+    fn point_distance() {
+        // The inner product of two points is minus half the squared distance
+        let point1 = up(vga3d::Vector::new(1.0, 2.0, 3.0));
+        let point2 = up(vga3d::Vector::new(4.0, 6.0, 3.0));
+        assert_relative_eq!((point1 | point2).0, -12.5, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn point_translation() {
+        let point = up(vga3d::Vector::new(1.0, 2.0, -0.5));
+        let versor = Versor::from_translation(0.3, -1.0, 2.0);
+        for res in [
+            (&point).transform(&versor),
+            (&point).transform(versor),
+            point.transform(&versor),
+            point.transform(versor),
+        ] {
+            assert_vector_eq(down(res).unwrap(), vga3d::Vector::new(1.3, 1.0, 1.5));
+        }
+    }
+
+    #[test]
+    fn point_dilation() {
+        let point = up(vga3d::Vector::new(1.0, 2.0, -0.5));
+        let versor = Versor::try_from_dilation(2.0).unwrap();
+        let res = down(point.transform(versor)).unwrap();
+        assert_vector_eq(res, vga3d::Vector::new(2.0, 4.0, -1.0));
+    }
+
+    #[test]
+    fn point_transversion() {
+        // A transversion is a translation between two inversions in the unit sphere
+        let vector = vga3d::Vector::new(1.0, 2.0, -0.5);
+        let b = vga3d::Vector::new(0.2, 0.1, -0.3);
+        let invert = |x: vga3d::Vector<f64>| {
+            let squared = x.e1() * x.e1() + x.e2() * x.e2() + x.e3() * x.e3();
+            vga3d::Vector::new(x.e1() / squared, x.e2() / squared, x.e3() / squared)
+        };
+        let expected = invert(invert(vector) + b);
+        let versor = Versor::from_transversion(b.e1(), b.e2(), b.e3());
+        let res = down(up(vector).transform(versor)).unwrap();
+        assert_vector_eq(res, expected);
+    }
+
+    #[test]
+    fn point_rotation() {
+        let rotor = vga3d::Rotor::new(vga3d::Bivector::new(0.3, -0.2, 0.5));
+        let vector = vga3d::Vector::new(1.0, 2.0, -0.5);
+        let expected = vga3d::Rotatable::rotate(vector, rotor);
+        let res = down(up(vector).transform(Versor::from_rotor(rotor))).unwrap();
+        assert_vector_eq(res, expected);
+    }
+
+    #[test]
+    fn versor_composition() {
+        // Rotate a quarter turn around the z-axis and then translate along x
+        let rotation =
+            Versor::from_rotor(vga3d::Rotor::new(vga3d::Bivector::new(TAU / 8.0, 0.0, 0.0)));
+        let translation = Versor::from_translation(3.0, 0.0, 0.0);
+        let point = up(vga3d::Vector::new(1.0, 0.0, 0.0));
+        let res = down(point.transform(rotation * translation)).unwrap();
+        let expected = down(point.transform(rotation).transform(translation)).unwrap();
+        assert_vector_eq(res, expected);
+    }
+
+    #[test]
+    fn sphere_transformation() {
+        // Transforming a sphere is the same as transforming its points
+        let versor = Versor::from_translation(1.0, -2.0, 0.5)
+            * Versor::try_from_dilation(3.0).unwrap()
+            * Versor::from_rotor(vga3d::Rotor::new(vga3d::Bivector::new(0.3, 0.1, -0.4)));
+        let vectors = [
+            vga3d::Vector::new(3.0, 2.0, 3.0),
+            vga3d::Vector::new(1.0, 4.0, 3.0),
+            vga3d::Vector::new(1.0, 2.0, 5.0),
+            vga3d::Vector::new(-1.0, 2.0, 3.0),
+        ];
+        let sphere = Quadvector::sphere(vectors[0], vectors[1], vectors[2], vectors[3]);
+        let res = sphere.transform(versor).dual();
+        let [a, b, c, d] = vectors.map(|vector| down(up(vector).transform(versor)).unwrap());
+        let expected = Quadvector::sphere(a, b, c, d).dual();
+        assert_vector_eq(res.try_center().unwrap(), expected.try_center().unwrap());
+        assert_relative_eq!(res.try_radius().unwrap(), 6.0, max_relative = 0.000001);
+        assert_relative_eq!(expected.try_radius().unwrap(), 6.0, max_relative = 0.000001);
+    }
+}
@@ -0,0 +1,6553 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    bivector::Bivector, multivector::Multivector, pseudoscalar::Pseudoscalar,
+    quadvector::Quadvector, scalar::Scalar, trivector::Trivector, vector::Vector, versor::Versor,
+};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::Mul;
+
+// Geometric Product
+
+// Scalar-Scalar
+impl<F: Float> Mul for Scalar<F> {
+    type Output = F;
+    fn mul(self, b: Scalar<F>) -> F {
+        self.0 * b.0
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Scalar<F>);
+
+// Scalar-Vector
+impl<F: Float> Mul<Vector<F>> for Scalar<F> {
+    type Output = Vector<F>;
+    fn mul(self, b: Vector<F>) -> Vector<F> {
+        Vector::new(
+            self.0 * b.e1(),
+            self.0 * b.e2(),
+            self.0 * b.e3(),
+            self.0 * b.e4(),
+            self.0 * b.e5(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Vector<F>);
+
+// Vector-Scalar
+impl<F: Float> Mul<Scalar<F>> for Vector<F> {
+    type Output = Vector<F>;
+    fn mul(self, b: Scalar<F>) -> Vector<F> {
+        Vector::new(
+            self.e1() * b.0,
+            self.e2() * b.0,
+            self.e3() * b.0,
+            self.e4() * b.0,
+            self.e5() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Vector<F>, Scalar<F>);
+
+// Scalar-Bivector
+impl<F: Float> Mul<Bivector<F>> for Scalar<F> {
+    type Output = Bivector<F>;
+    fn mul(self, b: Bivector<F>) -> Bivector<F> {
+        Bivector::new(
+            self.0 * b.e12(),
+            self.0 * b.e31(),
+            self.0 * b.e23(),
+            self.0 * b.e14(),
+            self.0 * b.e24(),
+            self.0 * b.e34(),
+            self.0 * b.e15(),
+            self.0 * b.e25(),
+            self.0 * b.e35(),
+            self.0 * b.e45(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Bivector<F>);
+
+// Bivector-Scalar
+impl<F: Float> Mul<Scalar<F>> for Bivector<F> {
+    type Output = Bivector<F>;
+    fn mul(self, b: Scalar<F>) -> Bivector<F> {
+        Bivector::new(
+            self.e12() * b.0,
+            self.e31() * b.0,
+            self.e23() * b.0,
+            self.e14() * b.0,
+            self.e24() * b.0,
+            self.e34() * b.0,
+            self.e15() * b.0,
+            self.e25() * b.0,
+            self.e35() * b.0,
+            self.e45() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Bivector<F>, Scalar<F>);
+
+// Scalar-Trivector
+impl<F: Float> Mul<Trivector<F>> for Scalar<F> {
+    type Output = Trivector<F>;
+    fn mul(self, b: Trivector<F>) -> Trivector<F> {
+        Trivector::new(
+            self.0 * b.e123(),
+            self.0 * b.e124(),
+            self.0 * b.e314(),
+            self.0 * b.e234(),
+            self.0 * b.e125(),
+            self.0 * b.e315(),
+            self.0 * b.e235(),
+            self.0 * b.e145(),
+            self.0 * b.e245(),
+            self.0 * b.e345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Trivector<F>);
+
+// Trivector-Scalar
+impl<F: Float> Mul<Scalar<F>> for Trivector<F> {
+    type Output = Trivector<F>;
+    fn mul(self, b: Scalar<F>) -> Trivector<F> {
+        Trivector::new(
+            self.e123() * b.0,
+            self.e124() * b.0,
+            self.e314() * b.0,
+            self.e234() * b.0,
+            self.e125() * b.0,
+            self.e315() * b.0,
+            self.e235() * b.0,
+            self.e145() * b.0,
+            self.e245() * b.0,
+            self.e345() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Trivector<F>, Scalar<F>);
+
+// Scalar-Quadvector
+impl<F: Float> Mul<Quadvector<F>> for Scalar<F> {
+    type Output = Quadvector<F>;
+    fn mul(self, b: Quadvector<F>) -> Quadvector<F> {
+        Quadvector::new(
+            self.0 * b.e1234(),
+            self.0 * b.e1235(),
+            self.0 * b.e1245(),
+            self.0 * b.e3145(),
+            self.0 * b.e2345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Quadvector<F>);
+
+// Quadvector-Scalar
+impl<F: Float> Mul<Scalar<F>> for Quadvector<F> {
+    type Output = Quadvector<F>;
+    fn mul(self, b: Scalar<F>) -> Quadvector<F> {
+        Quadvector::new(
+            self.e1234() * b.0,
+            self.e1235() * b.0,
+            self.e1245() * b.0,
+            self.e3145() * b.0,
+            self.e2345() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Quadvector<F>, Scalar<F>);
+
+// Scalar-Pseudoscalar
+impl<F: Float> Mul<Pseudoscalar<F>> for Scalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn mul(self, b: Pseudoscalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.0 * b.e12345())
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Scalar
+impl<F: Float> Mul<Scalar<F>> for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn mul(self, b: Scalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.e12345() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Scalar<F>);
+
+// Scalar-Versor
+impl<F: Float> Mul<Versor<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 * b.scalar(),
+            Vector::zero(),
+            Bivector::new(
+                self.0 * b.e12(),
+                self.0 * b.e31(),
+                self.0 * b.e23(),
+                self.0 * b.e14(),
+                self.0 * b.e24(),
+                self.0 * b.e34(),
+                self.0 * b.e15(),
+                self.0 * b.e25(),
+                self.0 * b.e35(),
+                self.0 * b.e45(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.0 * b.e1234(),
+                self.0 * b.e1235(),
+                self.0 * b.e1245(),
+                self.0 * b.e3145(),
+                self.0 * b.e2345(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Versor<F>);
+
+// Versor-Scalar
+impl<F: Float> Mul<Scalar<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.0,
+            Vector::zero(),
+            Bivector::new(
+                self.e12() * b.0,
+                self.e31() * b.0,
+                self.e23() * b.0,
+                self.e14() * b.0,
+                self.e24() * b.0,
+                self.e34() * b.0,
+                self.e15() * b.0,
+                self.e25() * b.0,
+                self.e35() * b.0,
+                self.e45() * b.0,
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e1234() * b.0,
+                self.e1235() * b.0,
+                self.e1245() * b.0,
+                self.e3145() * b.0,
+                self.e2345() * b.0,
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Versor<F>, Scalar<F>);
+
+// Scalar-Multivector
+impl<F: Float> Mul<Multivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 * b.scalar(),
+            Vector::new(
+                self.0 * b.e1(),
+                self.0 * b.e2(),
+                self.0 * b.e3(),
+                self.0 * b.e4(),
+                self.0 * b.e5(),
+            ),
+            Bivector::new(
+                self.0 * b.e12(),
+                self.0 * b.e31(),
+                self.0 * b.e23(),
+                self.0 * b.e14(),
+                self.0 * b.e24(),
+                self.0 * b.e34(),
+                self.0 * b.e15(),
+                self.0 * b.e25(),
+                self.0 * b.e35(),
+                self.0 * b.e45(),
+            ),
+            Trivector::new(
+                self.0 * b.e123(),
+                self.0 * b.e124(),
+                self.0 * b.e314(),
+                self.0 * b.e234(),
+                self.0 * b.e125(),
+                self.0 * b.e315(),
+                self.0 * b.e235(),
+                self.0 * b.e145(),
+                self.0 * b.e245(),
+                self.0 * b.e345(),
+            ),
+            Quadvector::new(
+                self.0 * b.e1234(),
+                self.0 * b.e1235(),
+                self.0 * b.e1245(),
+                self.0 * b.e3145(),
+                self.0 * b.e2345(),
+            ),
+            self.0 * b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Multivector<F>);
+
+// Multivector-Scalar
+impl<F: Float> Mul<Scalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.0,
+            Vector::new(
+                self.e1() * b.0,
+                self.e2() * b.0,
+                self.e3() * b.0,
+                self.e4() * b.0,
+                self.e5() * b.0,
+            ),
+            Bivector::new(
+                self.e12() * b.0,
+                self.e31() * b.0,
+                self.e23() * b.0,
+                self.e14() * b.0,
+                self.e24() * b.0,
+                self.e34() * b.0,
+                self.e15() * b.0,
+                self.e25() * b.0,
+                self.e35() * b.0,
+                self.e45() * b.0,
+            ),
+            Trivector::new(
+                self.e123() * b.0,
+                self.e124() * b.0,
+                self.e314() * b.0,
+                self.e234() * b.0,
+                self.e125() * b.0,
+                self.e315() * b.0,
+                self.e235() * b.0,
+                self.e145() * b.0,
+                self.e245() * b.0,
+                self.e345() * b.0,
+            ),
+            Quadvector::new(
+                self.e1234() * b.0,
+                self.e1235() * b.0,
+                self.e1245() * b.0,
+                self.e3145() * b.0,
+                self.e2345() * b.0,
+            ),
+            self.e12345() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Scalar<F>);
+
+// Vector-Vector
+impl<F: Float> Mul for Vector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1() * b.e1() + self.e2() * b.e2() + self.e3() * b.e3() + self.e4() * b.e4()
+                - self.e5() * b.e5(),
+            Vector::zero(),
+            Bivector::new(
+                self.e1() * b.e2() - self.e2() * b.e1(),
+                -self.e1() * b.e3() + self.e3() * b.e1(),
+                self.e2() * b.e3() - self.e3() * b.e2(),
+                self.e1() * b.e4() - self.e4() * b.e1(),
+                self.e2() * b.e4() - self.e4() * b.e2(),
+                self.e3() * b.e4() - self.e4() * b.e3(),
+                self.e1() * b.e5() - self.e5() * b.e1(),
+                self.e2() * b.e5() - self.e5() * b.e2(),
+                self.e3() * b.e5() - self.e5() * b.e3(),
+                self.e4() * b.e5() - self.e5() * b.e4(),
+            ),
+            Trivector::zero(),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Vector<F>, Vector<F>);
+
+// Vector-Bivector
+impl<F: Float> Mul<Bivector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                -self.e2() * b.e12() + self.e3() * b.e31() - self.e4() * b.e14()
+                    + self.e5() * b.e15(),
+                self.e1() * b.e12() - self.e3() * b.e23() - self.e4() * b.e24()
+                    + self.e5() * b.e25(),
+                -self.e1() * b.e31() + self.e2() * b.e23() - self.e4() * b.e34()
+                    + self.e5() * b.e35(),
+                self.e1() * b.e14()
+                    + self.e2() * b.e24()
+                    + self.e3() * b.e34()
+                    + self.e5() * b.e45(),
+                self.e1() * b.e15()
+                    + self.e2() * b.e25()
+                    + self.e3() * b.e35()
+                    + self.e4() * b.e45(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                self.e1() * b.e23() + self.e2() * b.e31() + self.e3() * b.e12(),
+                self.e1() * b.e24() - self.e2() * b.e14() + self.e4() * b.e12(),
+                -self.e1() * b.e34() + self.e3() * b.e14() + self.e4() * b.e31(),
+                self.e2() * b.e34() - self.e3() * b.e24() + self.e4() * b.e23(),
+                self.e1() * b.e25() - self.e2() * b.e15() + self.e5() * b.e12(),
+                -self.e1() * b.e35() + self.e3() * b.e15() + self.e5() * b.e31(),
+                self.e2() * b.e35() - self.e3() * b.e25() + self.e5() * b.e23(),
+                self.e1() * b.e45() - self.e4() * b.e15() + self.e5() * b.e14(),
+                self.e2() * b.e45() - self.e4() * b.e25() + self.e5() * b.e24(),
+                self.e3() * b.e45() - self.e4() * b.e35() + self.e5() * b.e34(),
+            ),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Vector<F>, Bivector<F>);
+
+// Bivector-Vector
+impl<F: Float> Mul<Vector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                self.e12() * b.e2() - self.e31() * b.e3() + self.e14() * b.e4()
+                    - self.e15() * b.e5(),
+                -self.e12() * b.e1() + self.e23() * b.e3() + self.e24() * b.e4()
+                    - self.e25() * b.e5(),
+                self.e31() * b.e1() - self.e23() * b.e2() + self.e34() * b.e4()
+                    - self.e35() * b.e5(),
+                -self.e14() * b.e1()
+                    - self.e24() * b.e2()
+                    - self.e34() * b.e3()
+                    - self.e45() * b.e5(),
+                -self.e15() * b.e1()
+                    - self.e25() * b.e2()
+                    - self.e35() * b.e3()
+                    - self.e45() * b.e4(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                self.e12() * b.e3() + self.e31() * b.e2() + self.e23() * b.e1(),
+                self.e12() * b.e4() - self.e14() * b.e2() + self.e24() * b.e1(),
+                self.e31() * b.e4() + self.e14() * b.e3() - self.e34() * b.e1(),
+                self.e23() * b.e4() - self.e24() * b.e3() + self.e34() * b.e2(),
+                self.e12() * b.e5() - self.e15() * b.e2() + self.e25() * b.e1(),
+                self.e31() * b.e5() + self.e15() * b.e3() - self.e35() * b.e1(),
+                self.e23() * b.e5() - self.e25() * b.e3() + self.e35() * b.e2(),
+                self.e14() * b.e5() - self.e15() * b.e4() + self.e45() * b.e1(),
+                self.e24() * b.e5() - self.e25() * b.e4() + self.e45() * b.e2(),
+                self.e34() * b.e5() - self.e35() * b.e4() + self.e45() * b.e3(),
+            ),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Bivector<F>, Vector<F>);
+
+// Vector-Trivector
+impl<F: Float> Mul<Trivector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::new(
+                self.e3() * b.e123() + self.e4() * b.e124() - self.e5() * b.e125(),
+                self.e2() * b.e123() + self.e4() * b.e314() - self.e5() * b.e315(),
+                self.e1() * b.e123() + self.e4() * b.e234() - self.e5() * b.e235(),
+                -self.e2() * b.e124() + self.e3() * b.e314() - self.e5() * b.e145(),
+                self.e1() * b.e124() - self.e3() * b.e234() - self.e5() * b.e245(),
+                -self.e1() * b.e314() + self.e2() * b.e234() - self.e5() * b.e345(),
+                -self.e2() * b.e125() + self.e3() * b.e315() - self.e4() * b.e145(),
+                self.e1() * b.e125() - self.e3() * b.e235() - self.e4() * b.e245(),
+                -self.e1() * b.e315() + self.e2() * b.e235() - self.e4() * b.e345(),
+                self.e1() * b.e145() + self.e2() * b.e245() + self.e3() * b.e345(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e1() * b.e234() + self.e2() * b.e314() + self.e3() * b.e124()
+                    - self.e4() * b.e123(),
+                self.e1() * b.e235() + self.e2() * b.e315() + self.e3() * b.e125()
+                    - self.e5() * b.e123(),
+                self.e1() * b.e245() - self.e2() * b.e145() + self.e4() * b.e125()
+                    - self.e5() * b.e124(),
+                -self.e1() * b.e345() + self.e3() * b.e145() + self.e4() * b.e315()
+                    - self.e5() * b.e314(),
+                self.e2() * b.e345() - self.e3() * b.e245() + self.e4() * b.e235()
+                    - self.e5() * b.e234(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Vector<F>, Trivector<F>);
+
+// Trivector-Vector
+impl<F: Float> Mul<Vector<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::new(
+                self.e123() * b.e3() + self.e124() * b.e4() - self.e125() * b.e5(),
+                self.e123() * b.e2() + self.e314() * b.e4() - self.e315() * b.e5(),
+                self.e123() * b.e1() + self.e234() * b.e4() - self.e235() * b.e5(),
+                -self.e124() * b.e2() + self.e314() * b.e3() - self.e145() * b.e5(),
+                self.e124() * b.e1() - self.e234() * b.e3() - self.e245() * b.e5(),
+                -self.e314() * b.e1() + self.e234() * b.e2() - self.e345() * b.e5(),
+                -self.e125() * b.e2() + self.e315() * b.e3() - self.e145() * b.e4(),
+                self.e125() * b.e1() - self.e235() * b.e3() - self.e245() * b.e4(),
+                -self.e315() * b.e1() + self.e235() * b.e2() - self.e345() * b.e4(),
+                self.e145() * b.e1() + self.e245() * b.e2() + self.e345() * b.e3(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e123() * b.e4()
+                    - self.e124() * b.e3()
+                    - self.e314() * b.e2()
+                    - self.e234() * b.e1(),
+                self.e123() * b.e5()
+                    - self.e125() * b.e3()
+                    - self.e315() * b.e2()
+                    - self.e235() * b.e1(),
+                self.e124() * b.e5() - self.e125() * b.e4() + self.e145() * b.e2()
+                    - self.e245() * b.e1(),
+                self.e314() * b.e5() - self.e315() * b.e4() - self.e145() * b.e3()
+                    + self.e345() * b.e1(),
+                self.e234() * b.e5() - self.e235() * b.e4() + self.e245() * b.e3()
+                    - self.e345() * b.e2(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Trivector<F>, Vector<F>);
+
+// Vector-Quadvector
+impl<F: Float> Mul<Quadvector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Quadvector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::new(
+                -self.e4() * b.e1234() + self.e5() * b.e1235(),
+                self.e3() * b.e1234() + self.e5() * b.e1245(),
+                self.e2() * b.e1234() + self.e5() * b.e3145(),
+                self.e1() * b.e1234() + self.e5() * b.e2345(),
+                self.e3() * b.e1235() + self.e4() * b.e1245(),
+                self.e2() * b.e1235() + self.e4() * b.e3145(),
+                self.e1() * b.e1235() + self.e4() * b.e2345(),
+                -self.e2() * b.e1245() + self.e3() * b.e3145(),
+                self.e1() * b.e1245() - self.e3() * b.e2345(),
+                -self.e1() * b.e3145() + self.e2() * b.e2345(),
+            ),
+            Quadvector::zero(),
+            self.e1() * b.e2345() + self.e2() * b.e3145() + self.e3() * b.e1245()
+                - self.e4() * b.e1235()
+                + self.e5() * b.e1234(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Vector<F>, Quadvector<F>);
+
+// Quadvector-Vector
+impl<F: Float> Mul<Vector<F>> for Quadvector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::new(
+                self.e1234() * b.e4() - self.e1235() * b.e5(),
+                -self.e1234() * b.e3() - self.e1245() * b.e5(),
+                -self.e1234() * b.e2() - self.e3145() * b.e5(),
+                -self.e1234() * b.e1() - self.e2345() * b.e5(),
+                -self.e1235() * b.e3() - self.e1245() * b.e4(),
+                -self.e1235() * b.e2() - self.e3145() * b.e4(),
+                -self.e1235() * b.e1() - self.e2345() * b.e4(),
+                self.e1245() * b.e2() - self.e3145() * b.e3(),
+                -self.e1245() * b.e1() + self.e2345() * b.e3(),
+                self.e3145() * b.e1() - self.e2345() * b.e2(),
+            ),
+            Quadvector::zero(),
+            self.e1234() * b.e5() - self.e1235() * b.e4()
+                + self.e1245() * b.e3()
+                + self.e3145() * b.e2()
+                + self.e2345() * b.e1(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Quadvector<F>, Vector<F>);
+
+// Vector-Pseudoscalar
+impl<F: Float> Mul<Pseudoscalar<F>> for Vector<F> {
+    type Output = Quadvector<F>;
+    fn mul(self, b: Pseudoscalar<F>) -> Quadvector<F> {
+        Quadvector::new(
+            -self.e5() * b.e12345(),
+            -self.e4() * b.e12345(),
+            self.e3() * b.e12345(),
+            self.e2() * b.e12345(),
+            self.e1() * b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Vector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Vector
+impl<F: Float> Mul<Vector<F>> for Pseudoscalar<F> {
+    type Output = Quadvector<F>;
+    fn mul(self, b: Vector<F>) -> Quadvector<F> {
+        Quadvector::new(
+            -self.e12345() * b.e5(),
+            -self.e12345() * b.e4(),
+            self.e12345() * b.e3(),
+            self.e12345() * b.e2(),
+            self.e12345() * b.e1(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Vector<F>);
+
+// Vector-Versor
+impl<F: Float> Mul<Versor<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                self.e1() * b.scalar() - self.e2() * b.e12() + self.e3() * b.e31()
+                    - self.e4() * b.e14()
+                    + self.e5() * b.e15(),
+                self.e1() * b.e12() + self.e2() * b.scalar()
+                    - self.e3() * b.e23()
+                    - self.e4() * b.e24()
+                    + self.e5() * b.e25(),
+                -self.e1() * b.e31() + self.e2() * b.e23() + self.e3() * b.scalar()
+                    - self.e4() * b.e34()
+                    + self.e5() * b.e35(),
+                self.e1() * b.e14()
+                    + self.e2() * b.e24()
+                    + self.e3() * b.e34()
+                    + self.e4() * b.scalar()
+                    + self.e5() * b.e45(),
+                self.e1() * b.e15()
+                    + self.e2() * b.e25()
+                    + self.e3() * b.e35()
+                    + self.e4() * b.e45()
+                    + self.e5() * b.scalar(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                self.e1() * b.e23() + self.e2() * b.e31() + self.e3() * b.e12()
+                    - self.e4() * b.e1234()
+                    + self.e5() * b.e1235(),
+                self.e1() * b.e24() - self.e2() * b.e14()
+                    + self.e3() * b.e1234()
+                    + self.e4() * b.e12()
+                    + self.e5() * b.e1245(),
+                -self.e1() * b.e34()
+                    + self.e2() * b.e1234()
+                    + self.e3() * b.e14()
+                    + self.e4() * b.e31()
+                    + self.e5() * b.e3145(),
+                self.e1() * b.e1234() + self.e2() * b.e34() - self.e3() * b.e24()
+                    + self.e4() * b.e23()
+                    + self.e5() * b.e2345(),
+                self.e1() * b.e25() - self.e2() * b.e15()
+                    + self.e3() * b.e1235()
+                    + self.e4() * b.e1245()
+                    + self.e5() * b.e12(),
+                -self.e1() * b.e35()
+                    + self.e2() * b.e1235()
+                    + self.e3() * b.e15()
+                    + self.e4() * b.e3145()
+                    + self.e5() * b.e31(),
+                self.e1() * b.e1235() + self.e2() * b.e35() - self.e3() * b.e25()
+                    + self.e4() * b.e2345()
+                    + self.e5() * b.e23(),
+                self.e1() * b.e45() - self.e2() * b.e1245() + self.e3() * b.e3145()
+                    - self.e4() * b.e15()
+                    + self.e5() * b.e14(),
+                self.e1() * b.e1245() + self.e2() * b.e45()
+                    - self.e3() * b.e2345()
+                    - self.e4() * b.e25()
+                    + self.e5() * b.e24(),
+                -self.e1() * b.e3145() + self.e2() * b.e2345() + self.e3() * b.e45()
+                    - self.e4() * b.e35()
+                    + self.e5() * b.e34(),
+            ),
+            Quadvector::zero(),
+            self.e1() * b.e2345() + self.e2() * b.e3145() + self.e3() * b.e1245()
+                - self.e4() * b.e1235()
+                + self.e5() * b.e1234(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Vector<F>, Versor<F>);
+
+// Versor-Vector
+impl<F: Float> Mul<Vector<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                self.scalar() * b.e1() + self.e12() * b.e2() - self.e31() * b.e3()
+                    + self.e14() * b.e4()
+                    - self.e15() * b.e5(),
+                self.scalar() * b.e2() - self.e12() * b.e1()
+                    + self.e23() * b.e3()
+                    + self.e24() * b.e4()
+                    - self.e25() * b.e5(),
+                self.scalar() * b.e3() + self.e31() * b.e1() - self.e23() * b.e2()
+                    + self.e34() * b.e4()
+                    - self.e35() * b.e5(),
+                self.scalar() * b.e4()
+                    - self.e14() * b.e1()
+                    - self.e24() * b.e2()
+                    - self.e34() * b.e3()
+                    - self.e45() * b.e5(),
+                self.scalar() * b.e5()
+                    - self.e15() * b.e1()
+                    - self.e25() * b.e2()
+                    - self.e35() * b.e3()
+                    - self.e45() * b.e4(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                self.e12() * b.e3()
+                    + self.e31() * b.e2()
+                    + self.e23() * b.e1()
+                    + self.e1234() * b.e4()
+                    - self.e1235() * b.e5(),
+                self.e12() * b.e4() - self.e14() * b.e2() + self.e24() * b.e1()
+                    - self.e1234() * b.e3()
+                    - self.e1245() * b.e5(),
+                self.e31() * b.e4() + self.e14() * b.e3()
+                    - self.e34() * b.e1()
+                    - self.e1234() * b.e2()
+                    - self.e3145() * b.e5(),
+                self.e23() * b.e4() - self.e24() * b.e3() + self.e34() * b.e2()
+                    - self.e1234() * b.e1()
+                    - self.e2345() * b.e5(),
+                self.e12() * b.e5() - self.e15() * b.e2() + self.e25() * b.e1()
+                    - self.e1235() * b.e3()
+                    - self.e1245() * b.e4(),
+                self.e31() * b.e5() + self.e15() * b.e3()
+                    - self.e35() * b.e1()
+                    - self.e1235() * b.e2()
+                    - self.e3145() * b.e4(),
+                self.e23() * b.e5() - self.e25() * b.e3() + self.e35() * b.e2()
+                    - self.e1235() * b.e1()
+                    - self.e2345() * b.e4(),
+                self.e14() * b.e5() - self.e15() * b.e4()
+                    + self.e45() * b.e1()
+                    + self.e1245() * b.e2()
+                    - self.e3145() * b.e3(),
+                self.e24() * b.e5() - self.e25() * b.e4() + self.e45() * b.e2()
+                    - self.e1245() * b.e1()
+                    + self.e2345() * b.e3(),
+                self.e34() * b.e5() - self.e35() * b.e4()
+                    + self.e45() * b.e3()
+                    + self.e3145() * b.e1()
+                    - self.e2345() * b.e2(),
+            ),
+            Quadvector::zero(),
+            self.e1234() * b.e5() - self.e1235() * b.e4()
+                + self.e1245() * b.e3()
+                + self.e3145() * b.e2()
+                + self.e2345() * b.e1(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Versor<F>, Vector<F>);
+
+// Vector-Multivector
+impl<F: Float> Mul<Multivector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1() * b.e1() + self.e2() * b.e2() + self.e3() * b.e3() + self.e4() * b.e4()
+                - self.e5() * b.e5(),
+            Vector::new(
+                self.e1() * b.scalar() - self.e2() * b.e12() + self.e3() * b.e31()
+                    - self.e4() * b.e14()
+                    + self.e5() * b.e15(),
+                self.e1() * b.e12() + self.e2() * b.scalar()
+                    - self.e3() * b.e23()
+                    - self.e4() * b.e24()
+                    + self.e5() * b.e25(),
+                -self.e1() * b.e31() + self.e2() * b.e23() + self.e3() * b.scalar()
+                    - self.e4() * b.e34()
+                    + self.e5() * b.e35(),
+                self.e1() * b.e14()
+                    + self.e2() * b.e24()
+                    + self.e3() * b.e34()
+                    + self.e4() * b.scalar()
+                    + self.e5() * b.e45(),
+                self.e1() * b.e15()
+                    + self.e2() * b.e25()
+                    + self.e3() * b.e35()
+                    + self.e4() * b.e45()
+                    + self.e5() * b.scalar(),
+            ),
+            Bivector::new(
+                self.e1() * b.e2() - self.e2() * b.e1()
+                    + self.e3() * b.e123()
+                    + self.e4() * b.e124()
+                    - self.e5() * b.e125(),
+                -self.e1() * b.e3()
+                    + self.e2() * b.e123()
+                    + self.e3() * b.e1()
+                    + self.e4() * b.e314()
+                    - self.e5() * b.e315(),
+                self.e1() * b.e123() + self.e2() * b.e3() - self.e3() * b.e2()
+                    + self.e4() * b.e234()
+                    - self.e5() * b.e235(),
+                self.e1() * b.e4() - self.e2() * b.e124() + self.e3() * b.e314()
+                    - self.e4() * b.e1()
+                    - self.e5() * b.e145(),
+                self.e1() * b.e124() + self.e2() * b.e4()
+                    - self.e3() * b.e234()
+                    - self.e4() * b.e2()
+                    - self.e5() * b.e245(),
+                -self.e1() * b.e314() + self.e2() * b.e234() + self.e3() * b.e4()
+                    - self.e4() * b.e3()
+                    - self.e5() * b.e345(),
+                self.e1() * b.e5() - self.e2() * b.e125() + self.e3() * b.e315()
+                    - self.e4() * b.e145()
+                    - self.e5() * b.e1(),
+                self.e1() * b.e125() + self.e2() * b.e5()
+                    - self.e3() * b.e235()
+                    - self.e4() * b.e245()
+                    - self.e5() * b.e2(),
+                -self.e1() * b.e315() + self.e2() * b.e235() + self.e3() * b.e5()
+                    - self.e4() * b.e345()
+                    - self.e5() * b.e3(),
+                self.e1() * b.e145()
+                    + self.e2() * b.e245()
+                    + self.e3() * b.e345()
+                    + self.e4() * b.e5()
+                    - self.e5() * b.e4(),
+            ),
+            Trivector::new(
+                self.e1() * b.e23() + self.e2() * b.e31() + self.e3() * b.e12()
+                    - self.e4() * b.e1234()
+                    + self.e5() * b.e1235(),
+                self.e1() * b.e24() - self.e2() * b.e14()
+                    + self.e3() * b.e1234()
+                    + self.e4() * b.e12()
+                    + self.e5() * b.e1245(),
+                -self.e1() * b.e34()
+                    + self.e2() * b.e1234()
+                    + self.e3() * b.e14()
+                    + self.e4() * b.e31()
+                    + self.e5() * b.e3145(),
+                self.e1() * b.e1234() + self.e2() * b.e34() - self.e3() * b.e24()
+                    + self.e4() * b.e23()
+                    + self.e5() * b.e2345(),
+                self.e1() * b.e25() - self.e2() * b.e15()
+                    + self.e3() * b.e1235()
+                    + self.e4() * b.e1245()
+                    + self.e5() * b.e12(),
+                -self.e1() * b.e35()
+                    + self.e2() * b.e1235()
+                    + self.e3() * b.e15()
+                    + self.e4() * b.e3145()
+                    + self.e5() * b.e31(),
+                self.e1() * b.e1235() + self.e2() * b.e35() - self.e3() * b.e25()
+                    + self.e4() * b.e2345()
+                    + self.e5() * b.e23(),
+                self.e1() * b.e45() - self.e2() * b.e1245() + self.e3() * b.e3145()
+                    - self.e4() * b.e15()
+                    + self.e5() * b.e14(),
+                self.e1() * b.e1245() + self.e2() * b.e45()
+                    - self.e3() * b.e2345()
+                    - self.e4() * b.e25()
+                    + self.e5() * b.e24(),
+                -self.e1() * b.e3145() + self.e2() * b.e2345() + self.e3() * b.e45()
+                    - self.e4() * b.e35()
+                    + self.e5() * b.e34(),
+            ),
+            Quadvector::new(
+                self.e1() * b.e234() + self.e2() * b.e314() + self.e3() * b.e124()
+                    - self.e4() * b.e123()
+                    - self.e5() * b.e12345(),
+                self.e1() * b.e235() + self.e2() * b.e315() + self.e3() * b.e125()
+                    - self.e4() * b.e12345()
+                    - self.e5() * b.e123(),
+                self.e1() * b.e245() - self.e2() * b.e145()
+                    + self.e3() * b.e12345()
+                    + self.e4() * b.e125()
+                    - self.e5() * b.e124(),
+                -self.e1() * b.e345()
+                    + self.e2() * b.e12345()
+                    + self.e3() * b.e145()
+                    + self.e4() * b.e315()
+                    - self.e5() * b.e314(),
+                self.e1() * b.e12345() + self.e2() * b.e345() - self.e3() * b.e245()
+                    + self.e4() * b.e235()
+                    - self.e5() * b.e234(),
+            ),
+            self.e1() * b.e2345() + self.e2() * b.e3145() + self.e3() * b.e1245()
+                - self.e4() * b.e1235()
+                + self.e5() * b.e1234(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Vector<F>, Multivector<F>);
+
+// Multivector-Vector
+impl<F: Float> Mul<Vector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1() * b.e1() + self.e2() * b.e2() + self.e3() * b.e3() + self.e4() * b.e4()
+                - self.e5() * b.e5(),
+            Vector::new(
+                self.scalar() * b.e1() + self.e12() * b.e2() - self.e31() * b.e3()
+                    + self.e14() * b.e4()
+                    - self.e15() * b.e5(),
+                self.scalar() * b.e2() - self.e12() * b.e1()
+                    + self.e23() * b.e3()
+                    + self.e24() * b.e4()
+                    - self.e25() * b.e5(),
+                self.scalar() * b.e3() + self.e31() * b.e1() - self.e23() * b.e2()
+                    + self.e34() * b.e4()
+                    - self.e35() * b.e5(),
+                self.scalar() * b.e4()
+                    - self.e14() * b.e1()
+                    - self.e24() * b.e2()
+                    - self.e34() * b.e3()
+                    - self.e45() * b.e5(),
+                self.scalar() * b.e5()
+                    - self.e15() * b.e1()
+                    - self.e25() * b.e2()
+                    - self.e35() * b.e3()
+                    - self.e45() * b.e4(),
+            ),
+            Bivector::new(
+                self.e1() * b.e2() - self.e2() * b.e1()
+                    + self.e123() * b.e3()
+                    + self.e124() * b.e4()
+                    - self.e125() * b.e5(),
+                -self.e1() * b.e3()
+                    + self.e3() * b.e1()
+                    + self.e123() * b.e2()
+                    + self.e314() * b.e4()
+                    - self.e315() * b.e5(),
+                self.e2() * b.e3() - self.e3() * b.e2()
+                    + self.e123() * b.e1()
+                    + self.e234() * b.e4()
+                    - self.e235() * b.e5(),
+                self.e1() * b.e4() - self.e4() * b.e1() - self.e124() * b.e2()
+                    + self.e314() * b.e3()
+                    - self.e145() * b.e5(),
+                self.e2() * b.e4() - self.e4() * b.e2() + self.e124() * b.e1()
+                    - self.e234() * b.e3()
+                    - self.e245() * b.e5(),
+                self.e3() * b.e4() - self.e4() * b.e3() - self.e314() * b.e1()
+                    + self.e234() * b.e2()
+                    - self.e345() * b.e5(),
+                self.e1() * b.e5() - self.e5() * b.e1() - self.e125() * b.e2()
+                    + self.e315() * b.e3()
+                    - self.e145() * b.e4(),
+                self.e2() * b.e5() - self.e5() * b.e2() + self.e125() * b.e1()
+                    - self.e235() * b.e3()
+                    - self.e245() * b.e4(),
+                self.e3() * b.e5() - self.e5() * b.e3() - self.e315() * b.e1()
+                    + self.e235() * b.e2()
+                    - self.e345() * b.e4(),
+                self.e4() * b.e5() - self.e5() * b.e4()
+                    + self.e145() * b.e1()
+                    + self.e245() * b.e2()
+                    + self.e345() * b.e3(),
+            ),
+            Trivector::new(
+                self.e12() * b.e3()
+                    + self.e31() * b.e2()
+                    + self.e23() * b.e1()
+                    + self.e1234() * b.e4()
+                    - self.e1235() * b.e5(),
+                self.e12() * b.e4() - self.e14() * b.e2() + self.e24() * b.e1()
+                    - self.e1234() * b.e3()
+                    - self.e1245() * b.e5(),
+                self.e31() * b.e4() + self.e14() * b.e3()
+                    - self.e34() * b.e1()
+                    - self.e1234() * b.e2()
+                    - self.e3145() * b.e5(),
+                self.e23() * b.e4() - self.e24() * b.e3() + self.e34() * b.e2()
+                    - self.e1234() * b.e1()
+                    - self.e2345() * b.e5(),
+                self.e12() * b.e5() - self.e15() * b.e2() + self.e25() * b.e1()
+                    - self.e1235() * b.e3()
+                    - self.e1245() * b.e4(),
+                self.e31() * b.e5() + self.e15() * b.e3()
+                    - self.e35() * b.e1()
+                    - self.e1235() * b.e2()
+                    - self.e3145() * b.e4(),
+                self.e23() * b.e5() - self.e25() * b.e3() + self.e35() * b.e2()
+                    - self.e1235() * b.e1()
+                    - self.e2345() * b.e4(),
+                self.e14() * b.e5() - self.e15() * b.e4()
+                    + self.e45() * b.e1()
+                    + self.e1245() * b.e2()
+                    - self.e3145() * b.e3(),
+                self.e24() * b.e5() - self.e25() * b.e4() + self.e45() * b.e2()
+                    - self.e1245() * b.e1()
+                    + self.e2345() * b.e3(),
+                self.e34() * b.e5() - self.e35() * b.e4()
+                    + self.e45() * b.e3()
+                    + self.e3145() * b.e1()
+                    - self.e2345() * b.e2(),
+            ),
+            Quadvector::new(
+                self.e123() * b.e4()
+                    - self.e124() * b.e3()
+                    - self.e314() * b.e2()
+                    - self.e234() * b.e1()
+                    - self.e12345() * b.e5(),
+                self.e123() * b.e5()
+                    - self.e125() * b.e3()
+                    - self.e315() * b.e2()
+                    - self.e235() * b.e1()
+                    - self.e12345() * b.e4(),
+                self.e124() * b.e5() - self.e125() * b.e4() + self.e145() * b.e2()
+                    - self.e245() * b.e1()
+                    + self.e12345() * b.e3(),
+                self.e314() * b.e5() - self.e315() * b.e4() - self.e145() * b.e3()
+                    + self.e345() * b.e1()
+                    + self.e12345() * b.e2(),
+                self.e234() * b.e5() - self.e235() * b.e4() + self.e245() * b.e3()
+                    - self.e345() * b.e2()
+                    + self.e12345() * b.e1(),
+            ),
+            self.e1234() * b.e5() - self.e1235() * b.e4()
+                + self.e1245() * b.e3()
+                + self.e3145() * b.e2()
+                + self.e2345() * b.e1(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Vector<F>);
+
+// Bivector-Bivector
+impl<F: Float> Mul for Bivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12() * b.e12()
+                - self.e31() * b.e31()
+                - self.e23() * b.e23()
+                - self.e14() * b.e14()
+                - self.e24() * b.e24()
+                - self.e34() * b.e34()
+                + self.e15() * b.e15()
+                + self.e25() * b.e25()
+                + self.e35() * b.e35()
+                + self.e45() * b.e45(),
+            Vector::zero(),
+            Bivector::new(
+                self.e31() * b.e23() - self.e23() * b.e31() - self.e14() * b.e24()
+                    + self.e24() * b.e14()
+                    + self.e15() * b.e25()
+                    - self.e25() * b.e15(),
+                -self.e12() * b.e23() + self.e23() * b.e12() + self.e14() * b.e34()
+                    - self.e34() * b.e14()
+                    - self.e15() * b.e35()
+                    + self.e35() * b.e15(),
+                self.e12() * b.e31() - self.e31() * b.e12() - self.e24() * b.e34()
+                    + self.e34() * b.e24()
+                    + self.e25() * b.e35()
+                    - self.e35() * b.e25(),
+                self.e12() * b.e24() - self.e31() * b.e34() - self.e24() * b.e12()
+                    + self.e34() * b.e31()
+                    + self.e15() * b.e45()
+                    - self.e45() * b.e15(),
+                -self.e12() * b.e14() + self.e23() * b.e34() + self.e14() * b.e12()
+                    - self.e34() * b.e23()
+                    + self.e25() * b.e45()
+                    - self.e45() * b.e25(),
+                self.e31() * b.e14() - self.e23() * b.e24() - self.e14() * b.e31()
+                    + self.e24() * b.e23()
+                    + self.e35() * b.e45()
+                    - self.e45() * b.e35(),
+                self.e12() * b.e25() - self.e31() * b.e35() + self.e14() * b.e45()
+                    - self.e25() * b.e12()
+                    + self.e35() * b.e31()
+                    - self.e45() * b.e14(),
+                -self.e12() * b.e15()
+                    + self.e23() * b.e35()
+                    + self.e24() * b.e45()
+                    + self.e15() * b.e12()
+                    - self.e35() * b.e23()
+                    - self.e45() * b.e24(),
+                self.e31() * b.e15() - self.e23() * b.e25() + self.e34() * b.e45()
+                    - self.e15() * b.e31()
+                    + self.e25() * b.e23()
+                    - self.e45() * b.e34(),
+                -self.e14() * b.e15() - self.e24() * b.e25() - self.e34() * b.e35()
+                    + self.e15() * b.e14()
+                    + self.e25() * b.e24()
+                    + self.e35() * b.e34(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e12() * b.e34()
+                    + self.e31() * b.e24()
+                    + self.e23() * b.e14()
+                    + self.e14() * b.e23()
+                    + self.e24() * b.e31()
+                    + self.e34() * b.e12(),
+                self.e12() * b.e35()
+                    + self.e31() * b.e25()
+                    + self.e23() * b.e15()
+                    + self.e15() * b.e23()
+                    + self.e25() * b.e31()
+                    + self.e35() * b.e12(),
+                self.e12() * b.e45() - self.e14() * b.e25()
+                    + self.e24() * b.e15()
+                    + self.e15() * b.e24()
+                    - self.e25() * b.e14()
+                    + self.e45() * b.e12(),
+                self.e31() * b.e45() + self.e14() * b.e35()
+                    - self.e34() * b.e15()
+                    - self.e15() * b.e34()
+                    + self.e35() * b.e14()
+                    + self.e45() * b.e31(),
+                self.e23() * b.e45() - self.e24() * b.e35()
+                    + self.e34() * b.e25()
+                    + self.e25() * b.e34()
+                    - self.e35() * b.e24()
+                    + self.e45() * b.e23(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Bivector<F>, Bivector<F>);
+
+// Bivector-Trivector
+impl<F: Float> Mul<Trivector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                -self.e23() * b.e123() - self.e24() * b.e124()
+                    + self.e34() * b.e314()
+                    + self.e25() * b.e125()
+                    - self.e35() * b.e315()
+                    + self.e45() * b.e145(),
+                -self.e31() * b.e123() + self.e14() * b.e124()
+                    - self.e34() * b.e234()
+                    - self.e15() * b.e125()
+                    + self.e35() * b.e235()
+                    + self.e45() * b.e245(),
+                -self.e12() * b.e123() - self.e14() * b.e314()
+                    + self.e24() * b.e234()
+                    + self.e15() * b.e315()
+                    - self.e25() * b.e235()
+                    + self.e45() * b.e345(),
+                -self.e12() * b.e124()
+                    - self.e31() * b.e314()
+                    - self.e23() * b.e234()
+                    - self.e15() * b.e145()
+                    - self.e25() * b.e245()
+                    - self.e35() * b.e345(),
+                -self.e12() * b.e125()
+                    - self.e31() * b.e315()
+                    - self.e23() * b.e235()
+                    - self.e14() * b.e145()
+                    - self.e24() * b.e245()
+                    - self.e34() * b.e345(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                self.e14() * b.e234() + self.e24() * b.e314() + self.e34() * b.e124()
+                    - self.e15() * b.e235()
+                    - self.e25() * b.e315()
+                    - self.e35() * b.e125(),
+                self.e31() * b.e234()
+                    - self.e23() * b.e314()
+                    - self.e34() * b.e123()
+                    - self.e15() * b.e245()
+                    + self.e25() * b.e145()
+                    - self.e45() * b.e125(),
+                -self.e12() * b.e234() + self.e23() * b.e124() - self.e24() * b.e123()
+                    + self.e15() * b.e345()
+                    - self.e35() * b.e145()
+                    - self.e45() * b.e315(),
+                self.e12() * b.e314()
+                    - self.e31() * b.e124()
+                    - self.e14() * b.e123()
+                    - self.e25() * b.e345()
+                    + self.e35() * b.e245()
+                    - self.e45() * b.e235(),
+                self.e31() * b.e235() - self.e23() * b.e315() - self.e14() * b.e245()
+                    + self.e24() * b.e145()
+                    - self.e35() * b.e123()
+                    - self.e45() * b.e124(),
+                -self.e12() * b.e235() + self.e23() * b.e125() + self.e14() * b.e345()
+                    - self.e34() * b.e145()
+                    - self.e25() * b.e123()
+                    - self.e45() * b.e314(),
+                self.e12() * b.e315() - self.e31() * b.e125() - self.e24() * b.e345()
+                    + self.e34() * b.e245()
+                    - self.e15() * b.e123()
+                    - self.e45() * b.e234(),
+                self.e12() * b.e245() - self.e31() * b.e345() - self.e24() * b.e125()
+                    + self.e34() * b.e315()
+                    + self.e25() * b.e124()
+                    - self.e35() * b.e314(),
+                -self.e12() * b.e145() + self.e23() * b.e345() + self.e14() * b.e125()
+                    - self.e34() * b.e235()
+                    - self.e15() * b.e124()
+                    + self.e35() * b.e234(),
+                self.e31() * b.e145() - self.e23() * b.e245() - self.e14() * b.e315()
+                    + self.e24() * b.e235()
+                    + self.e15() * b.e314()
+                    - self.e25() * b.e234(),
+            ),
+            Quadvector::zero(),
+            self.e12() * b.e345()
+                + self.e31() * b.e245()
+                + self.e23() * b.e145()
+                + self.e14() * b.e235()
+                + self.e24() * b.e315()
+                + self.e34() * b.e125()
+                - self.e15() * b.e234()
+                - self.e25() * b.e314()
+                - self.e35() * b.e124()
+                + self.e45() * b.e123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Bivector<F>, Trivector<F>);
+
+// Trivector-Bivector
+impl<F: Float> Mul<Bivector<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                -self.e123() * b.e23() - self.e124() * b.e24()
+                    + self.e314() * b.e34()
+                    + self.e125() * b.e25()
+                    - self.e315() * b.e35()
+                    + self.e145() * b.e45(),
+                -self.e123() * b.e31() + self.e124() * b.e14()
+                    - self.e234() * b.e34()
+                    - self.e125() * b.e15()
+                    + self.e235() * b.e35()
+                    + self.e245() * b.e45(),
+                -self.e123() * b.e12() - self.e314() * b.e14()
+                    + self.e234() * b.e24()
+                    + self.e315() * b.e15()
+                    - self.e235() * b.e25()
+                    + self.e345() * b.e45(),
+                -self.e124() * b.e12()
+                    - self.e314() * b.e31()
+                    - self.e234() * b.e23()
+                    - self.e145() * b.e15()
+                    - self.e245() * b.e25()
+                    - self.e345() * b.e35(),
+                -self.e125() * b.e12()
+                    - self.e315() * b.e31()
+                    - self.e235() * b.e23()
+                    - self.e145() * b.e14()
+                    - self.e245() * b.e24()
+                    - self.e345() * b.e34(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                -self.e124() * b.e34() - self.e314() * b.e24() - self.e234() * b.e14()
+                    + self.e125() * b.e35()
+                    + self.e315() * b.e25()
+                    + self.e235() * b.e15(),
+                self.e123() * b.e34() + self.e314() * b.e23() - self.e234() * b.e31()
+                    + self.e125() * b.e45()
+                    - self.e145() * b.e25()
+                    + self.e245() * b.e15(),
+                self.e123() * b.e24() - self.e124() * b.e23()
+                    + self.e234() * b.e12()
+                    + self.e315() * b.e45()
+                    + self.e145() * b.e35()
+                    - self.e345() * b.e15(),
+                self.e123() * b.e14() + self.e124() * b.e31() - self.e314() * b.e12()
+                    + self.e235() * b.e45()
+                    - self.e245() * b.e35()
+                    + self.e345() * b.e25(),
+                self.e123() * b.e35() + self.e124() * b.e45() + self.e315() * b.e23()
+                    - self.e235() * b.e31()
+                    - self.e145() * b.e24()
+                    + self.e245() * b.e14(),
+                self.e123() * b.e25() + self.e314() * b.e45() - self.e125() * b.e23()
+                    + self.e235() * b.e12()
+                    + self.e145() * b.e34()
+                    - self.e345() * b.e14(),
+                self.e123() * b.e15() + self.e234() * b.e45() + self.e125() * b.e31()
+                    - self.e315() * b.e12()
+                    - self.e245() * b.e34()
+                    + self.e345() * b.e24(),
+                -self.e124() * b.e25() + self.e314() * b.e35() + self.e125() * b.e24()
+                    - self.e315() * b.e34()
+                    - self.e245() * b.e12()
+                    + self.e345() * b.e31(),
+                self.e124() * b.e15() - self.e234() * b.e35() - self.e125() * b.e14()
+                    + self.e235() * b.e34()
+                    + self.e145() * b.e12()
+                    - self.e345() * b.e23(),
+                -self.e314() * b.e15() + self.e234() * b.e25() + self.e315() * b.e14()
+                    - self.e235() * b.e24()
+                    - self.e145() * b.e31()
+                    + self.e245() * b.e23(),
+            ),
+            Quadvector::zero(),
+            self.e123() * b.e45()
+                - self.e124() * b.e35()
+                - self.e314() * b.e25()
+                - self.e234() * b.e15()
+                + self.e125() * b.e34()
+                + self.e315() * b.e24()
+                + self.e235() * b.e14()
+                + self.e145() * b.e23()
+                + self.e245() * b.e31()
+                + self.e345() * b.e12(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Trivector<F>, Bivector<F>);
+
+// Bivector-Quadvector
+impl<F: Float> Mul<Quadvector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Quadvector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::new(
+                -self.e34() * b.e1234() + self.e35() * b.e1235() + self.e45() * b.e1245(),
+                -self.e24() * b.e1234() + self.e25() * b.e1235() + self.e45() * b.e3145(),
+                -self.e14() * b.e1234() + self.e15() * b.e1235() + self.e45() * b.e2345(),
+                -self.e23() * b.e1234() - self.e25() * b.e1245() + self.e35() * b.e3145(),
+                -self.e31() * b.e1234() + self.e15() * b.e1245() - self.e35() * b.e2345(),
+                -self.e12() * b.e1234() - self.e15() * b.e3145() + self.e25() * b.e2345(),
+                -self.e23() * b.e1235() - self.e24() * b.e1245() + self.e34() * b.e3145(),
+                -self.e31() * b.e1235() + self.e14() * b.e1245() - self.e34() * b.e2345(),
+                -self.e12() * b.e1235() - self.e14() * b.e3145() + self.e24() * b.e2345(),
+                -self.e12() * b.e1245() - self.e31() * b.e3145() - self.e23() * b.e2345(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e15() * b.e2345() + self.e25() * b.e3145() + self.e35() * b.e1245()
+                    - self.e45() * b.e1235(),
+                self.e14() * b.e2345() + self.e24() * b.e3145() + self.e34() * b.e1245()
+                    - self.e45() * b.e1234(),
+                self.e31() * b.e2345() - self.e23() * b.e3145() - self.e34() * b.e1235()
+                    + self.e35() * b.e1234(),
+                -self.e12() * b.e2345() + self.e23() * b.e1245() - self.e24() * b.e1235()
+                    + self.e25() * b.e1234(),
+                self.e12() * b.e3145() - self.e31() * b.e1245() - self.e14() * b.e1235()
+                    + self.e15() * b.e1234(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Bivector<F>, Quadvector<F>);
+
+// Quadvector-Bivector
+impl<F: Float> Mul<Bivector<F>> for Quadvector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::new(
+                -self.e1234() * b.e34() + self.e1235() * b.e35() + self.e1245() * b.e45(),
+                -self.e1234() * b.e24() + self.e1235() * b.e25() + self.e3145() * b.e45(),
+                -self.e1234() * b.e14() + self.e1235() * b.e15() + self.e2345() * b.e45(),
+                -self.e1234() * b.e23() - self.e1245() * b.e25() + self.e3145() * b.e35(),
+                -self.e1234() * b.e31() + self.e1245() * b.e15() - self.e2345() * b.e35(),
+                -self.e1234() * b.e12() - self.e3145() * b.e15() + self.e2345() * b.e25(),
+                -self.e1235() * b.e23() - self.e1245() * b.e24() + self.e3145() * b.e34(),
+                -self.e1235() * b.e31() + self.e1245() * b.e14() - self.e2345() * b.e34(),
+                -self.e1235() * b.e12() - self.e3145() * b.e14() + self.e2345() * b.e24(),
+                -self.e1245() * b.e12() - self.e3145() * b.e31() - self.e2345() * b.e23(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e1235() * b.e45()
+                    - self.e1245() * b.e35()
+                    - self.e3145() * b.e25()
+                    - self.e2345() * b.e15(),
+                self.e1234() * b.e45()
+                    - self.e1245() * b.e34()
+                    - self.e3145() * b.e24()
+                    - self.e2345() * b.e14(),
+                -self.e1234() * b.e35() + self.e1235() * b.e34() + self.e3145() * b.e23()
+                    - self.e2345() * b.e31(),
+                -self.e1234() * b.e25() + self.e1235() * b.e24() - self.e1245() * b.e23()
+                    + self.e2345() * b.e12(),
+                -self.e1234() * b.e15() + self.e1235() * b.e14() + self.e1245() * b.e31()
+                    - self.e3145() * b.e12(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Quadvector<F>, Bivector<F>);
+
+// Bivector-Pseudoscalar
+impl<F: Float> Mul<Pseudoscalar<F>> for Bivector<F> {
+    type Output = Trivector<F>;
+    fn mul(self, b: Pseudoscalar<F>) -> Trivector<F> {
+        Trivector::new(
+            self.e45() * b.e12345(),
+            -self.e35() * b.e12345(),
+            -self.e25() * b.e12345(),
+            -self.e15() * b.e12345(),
+            -self.e34() * b.e12345(),
+            -self.e24() * b.e12345(),
+            -self.e14() * b.e12345(),
+            -self.e23() * b.e12345(),
+            -self.e31() * b.e12345(),
+            -self.e12() * b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Bivector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Bivector
+impl<F: Float> Mul<Bivector<F>> for Pseudoscalar<F> {
+    type Output = Trivector<F>;
+    fn mul(self, b: Bivector<F>) -> Trivector<F> {
+        Trivector::new(
+            self.e12345() * b.e45(),
+            -self.e12345() * b.e35(),
+            -self.e12345() * b.e25(),
+            -self.e12345() * b.e15(),
+            -self.e12345() * b.e34(),
+            -self.e12345() * b.e24(),
+            -self.e12345() * b.e14(),
+            -self.e12345() * b.e23(),
+            -self.e12345() * b.e31(),
+            -self.e12345() * b.e12(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Bivector<F>);
+
+// Bivector-Versor
+impl<F: Float> Mul<Versor<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12() * b.e12()
+                - self.e31() * b.e31()
+                - self.e23() * b.e23()
+                - self.e14() * b.e14()
+                - self.e24() * b.e24()
+                - self.e34() * b.e34()
+                + self.e15() * b.e15()
+                + self.e25() * b.e25()
+                + self.e35() * b.e35()
+                + self.e45() * b.e45(),
+            Vector::zero(),
+            Bivector::new(
+                self.e12() * b.scalar() + self.e31() * b.e23()
+                    - self.e23() * b.e31()
+                    - self.e14() * b.e24()
+                    + self.e24() * b.e14()
+                    - self.e34() * b.e1234()
+                    + self.e15() * b.e25()
+                    - self.e25() * b.e15()
+                    + self.e35() * b.e1235()
+                    + self.e45() * b.e1245(),
+                -self.e12() * b.e23()
+                    + self.e31() * b.scalar()
+                    + self.e23() * b.e12()
+                    + self.e14() * b.e34()
+                    - self.e24() * b.e1234()
+                    - self.e34() * b.e14()
+                    - self.e15() * b.e35()
+                    + self.e25() * b.e1235()
+                    + self.e35() * b.e15()
+                    + self.e45() * b.e3145(),
+                self.e12() * b.e31() - self.e31() * b.e12() + self.e23() * b.scalar()
+                    - self.e14() * b.e1234()
+                    - self.e24() * b.e34()
+                    + self.e34() * b.e24()
+                    + self.e15() * b.e1235()
+                    + self.e25() * b.e35()
+                    - self.e35() * b.e25()
+                    + self.e45() * b.e2345(),
+                self.e12() * b.e24() - self.e31() * b.e34() - self.e23() * b.e1234()
+                    + self.e14() * b.scalar()
+                    - self.e24() * b.e12()
+                    + self.e34() * b.e31()
+                    + self.e15() * b.e45()
+                    - self.e25() * b.e1245()
+                    + self.e35() * b.e3145()
+                    - self.e45() * b.e15(),
+                -self.e12() * b.e14() - self.e31() * b.e1234()
+                    + self.e23() * b.e34()
+                    + self.e14() * b.e12()
+                    + self.e24() * b.scalar()
+                    - self.e34() * b.e23()
+                    + self.e15() * b.e1245()
+                    + self.e25() * b.e45()
+                    - self.e35() * b.e2345()
+                    - self.e45() * b.e25(),
+                -self.e12() * b.e1234() + self.e31() * b.e14()
+                    - self.e23() * b.e24()
+                    - self.e14() * b.e31()
+                    + self.e24() * b.e23()
+                    + self.e34() * b.scalar()
+                    - self.e15() * b.e3145()
+                    + self.e25() * b.e2345()
+                    + self.e35() * b.e45()
+                    - self.e45() * b.e35(),
+                self.e12() * b.e25() - self.e31() * b.e35() - self.e23() * b.e1235()
+                    + self.e14() * b.e45()
+                    - self.e24() * b.e1245()
+                    + self.e34() * b.e3145()
+                    + self.e15() * b.scalar()
+                    - self.e25() * b.e12()
+                    + self.e35() * b.e31()
+                    - self.e45() * b.e14(),
+                -self.e12() * b.e15() - self.e31() * b.e1235()
+                    + self.e23() * b.e35()
+                    + self.e14() * b.e1245()
+                    + self.e24() * b.e45()
+                    - self.e34() * b.e2345()
+                    + self.e15() * b.e12()
+                    + self.e25() * b.scalar()
+                    - self.e35() * b.e23()
+                    - self.e45() * b.e24(),
+                -self.e12() * b.e1235() + self.e31() * b.e15()
+                    - self.e23() * b.e25()
+                    - self.e14() * b.e3145()
+                    + self.e24() * b.e2345()
+                    + self.e34() * b.e45()
+                    - self.e15() * b.e31()
+                    + self.e25() * b.e23()
+                    + self.e35() * b.scalar()
+                    - self.e45() * b.e34(),
+                -self.e12() * b.e1245()
+                    - self.e31() * b.e3145()
+                    - self.e23() * b.e2345()
+                    - self.e14() * b.e15()
+                    - self.e24() * b.e25()
+                    - self.e34() * b.e35()
+                    + self.e15() * b.e14()
+                    + self.e25() * b.e24()
+                    + self.e35() * b.e34()
+                    + self.e45() * b.scalar(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e12() * b.e34()
+                    + self.e31() * b.e24()
+                    + self.e23() * b.e14()
+                    + self.e14() * b.e23()
+                    + self.e24() * b.e31()
+                    + self.e34() * b.e12()
+                    + self.e15() * b.e2345()
+                    + self.e25() * b.e3145()
+                    + self.e35() * b.e1245()
+                    - self.e45() * b.e1235(),
+                self.e12() * b.e35()
+                    + self.e31() * b.e25()
+                    + self.e23() * b.e15()
+                    + self.e14() * b.e2345()
+                    + self.e24() * b.e3145()
+                    + self.e34() * b.e1245()
+                    + self.e15() * b.e23()
+                    + self.e25() * b.e31()
+                    + self.e35() * b.e12()
+                    - self.e45() * b.e1234(),
+                self.e12() * b.e45() + self.e31() * b.e2345()
+                    - self.e23() * b.e3145()
+                    - self.e14() * b.e25()
+                    + self.e24() * b.e15()
+                    - self.e34() * b.e1235()
+                    + self.e15() * b.e24()
+                    - self.e25() * b.e14()
+                    + self.e35() * b.e1234()
+                    + self.e45() * b.e12(),
+                -self.e12() * b.e2345()
+                    + self.e31() * b.e45()
+                    + self.e23() * b.e1245()
+                    + self.e14() * b.e35()
+                    - self.e24() * b.e1235()
+                    - self.e34() * b.e15()
+                    - self.e15() * b.e34()
+                    + self.e25() * b.e1234()
+                    + self.e35() * b.e14()
+                    + self.e45() * b.e31(),
+                self.e12() * b.e3145() - self.e31() * b.e1245() + self.e23() * b.e45()
+                    - self.e14() * b.e1235()
+                    - self.e24() * b.e35()
+                    + self.e34() * b.e25()
+                    + self.e15() * b.e1234()
+                    + self.e25() * b.e34()
+                    - self.e35() * b.e24()
+                    + self.e45() * b.e23(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Bivector<F>, Versor<F>);
+
+// Versor-Bivector
+impl<F: Float> Mul<Bivector<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12() * b.e12()
+                - self.e31() * b.e31()
+                - self.e23() * b.e23()
+                - self.e14() * b.e14()
+                - self.e24() * b.e24()
+                - self.e34() * b.e34()
+                + self.e15() * b.e15()
+                + self.e25() * b.e25()
+                + self.e35() * b.e35()
+                + self.e45() * b.e45(),
+            Vector::zero(),
+            Bivector::new(
+                self.scalar() * b.e12() + self.e31() * b.e23()
+                    - self.e23() * b.e31()
+                    - self.e14() * b.e24()
+                    + self.e24() * b.e14()
+                    + self.e15() * b.e25()
+                    - self.e25() * b.e15()
+                    - self.e1234() * b.e34()
+                    + self.e1235() * b.e35()
+                    + self.e1245() * b.e45(),
+                self.scalar() * b.e31() - self.e12() * b.e23()
+                    + self.e23() * b.e12()
+                    + self.e14() * b.e34()
+                    - self.e34() * b.e14()
+                    - self.e15() * b.e35()
+                    + self.e35() * b.e15()
+                    - self.e1234() * b.e24()
+                    + self.e1235() * b.e25()
+                    + self.e3145() * b.e45(),
+                self.scalar() * b.e23() + self.e12() * b.e31()
+                    - self.e31() * b.e12()
+                    - self.e24() * b.e34()
+                    + self.e34() * b.e24()
+                    + self.e25() * b.e35()
+                    - self.e35() * b.e25()
+                    - self.e1234() * b.e14()
+                    + self.e1235() * b.e15()
+                    + self.e2345() * b.e45(),
+                self.scalar() * b.e14() + self.e12() * b.e24()
+                    - self.e31() * b.e34()
+                    - self.e24() * b.e12()
+                    + self.e34() * b.e31()
+                    + self.e15() * b.e45()
+                    - self.e45() * b.e15()
+                    - self.e1234() * b.e23()
+                    - self.e1245() * b.e25()
+                    + self.e3145() * b.e35(),
+                self.scalar() * b.e24() - self.e12() * b.e14()
+                    + self.e23() * b.e34()
+                    + self.e14() * b.e12()
+                    - self.e34() * b.e23()
+                    + self.e25() * b.e45()
+                    - self.e45() * b.e25()
+                    - self.e1234() * b.e31()
+                    + self.e1245() * b.e15()
+                    - self.e2345() * b.e35(),
+                self.scalar() * b.e34() + self.e31() * b.e14()
+                    - self.e23() * b.e24()
+                    - self.e14() * b.e31()
+                    + self.e24() * b.e23()
+                    + self.e35() * b.e45()
+                    - self.e45() * b.e35()
+                    - self.e1234() * b.e12()
+                    - self.e3145() * b.e15()
+                    + self.e2345() * b.e25(),
+                self.scalar() * b.e15() + self.e12() * b.e25() - self.e31() * b.e35()
+                    + self.e14() * b.e45()
+                    - self.e25() * b.e12()
+                    + self.e35() * b.e31()
+                    - self.e45() * b.e14()
+                    - self.e1235() * b.e23()
+                    - self.e1245() * b.e24()
+                    + self.e3145() * b.e34(),
+                self.scalar() * b.e25() - self.e12() * b.e15()
+                    + self.e23() * b.e35()
+                    + self.e24() * b.e45()
+                    + self.e15() * b.e12()
+                    - self.e35() * b.e23()
+                    - self.e45() * b.e24()
+                    - self.e1235() * b.e31()
+                    + self.e1245() * b.e14()
+                    - self.e2345() * b.e34(),
+                self.scalar() * b.e35() + self.e31() * b.e15() - self.e23() * b.e25()
+                    + self.e34() * b.e45()
+                    - self.e15() * b.e31()
+                    + self.e25() * b.e23()
+                    - self.e45() * b.e34()
+                    - self.e1235() * b.e12()
+                    - self.e3145() * b.e14()
+                    + self.e2345() * b.e24(),
+                self.scalar() * b.e45()
+                    - self.e14() * b.e15()
+                    - self.e24() * b.e25()
+                    - self.e34() * b.e35()
+                    + self.e15() * b.e14()
+                    + self.e25() * b.e24()
+                    + self.e35() * b.e34()
+                    - self.e1245() * b.e12()
+                    - self.e3145() * b.e31()
+                    - self.e2345() * b.e23(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e12() * b.e34()
+                    + self.e31() * b.e24()
+                    + self.e23() * b.e14()
+                    + self.e14() * b.e23()
+                    + self.e24() * b.e31()
+                    + self.e34() * b.e12()
+                    + self.e1235() * b.e45()
+                    - self.e1245() * b.e35()
+                    - self.e3145() * b.e25()
+                    - self.e2345() * b.e15(),
+                self.e12() * b.e35()
+                    + self.e31() * b.e25()
+                    + self.e23() * b.e15()
+                    + self.e15() * b.e23()
+                    + self.e25() * b.e31()
+                    + self.e35() * b.e12()
+                    + self.e1234() * b.e45()
+                    - self.e1245() * b.e34()
+                    - self.e3145() * b.e24()
+                    - self.e2345() * b.e14(),
+                self.e12() * b.e45() - self.e14() * b.e25()
+                    + self.e24() * b.e15()
+                    + self.e15() * b.e24()
+                    - self.e25() * b.e14()
+                    + self.e45() * b.e12()
+                    - self.e1234() * b.e35()
+                    + self.e1235() * b.e34()
+                    + self.e3145() * b.e23()
+                    - self.e2345() * b.e31(),
+                self.e31() * b.e45() + self.e14() * b.e35()
+                    - self.e34() * b.e15()
+                    - self.e15() * b.e34()
+                    + self.e35() * b.e14()
+                    + self.e45() * b.e31()
+                    - self.e1234() * b.e25()
+                    + self.e1235() * b.e24()
+                    - self.e1245() * b.e23()
+                    + self.e2345() * b.e12(),
+                self.e23() * b.e45() - self.e24() * b.e35()
+                    + self.e34() * b.e25()
+                    + self.e25() * b.e34()
+                    - self.e35() * b.e24()
+                    + self.e45() * b.e23()
+                    - self.e1234() * b.e15()
+                    + self.e1235() * b.e14()
+                    + self.e1245() * b.e31()
+                    - self.e3145() * b.e12(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Versor<F>, Bivector<F>);
+
+// Bivector-Multivector
+impl<F: Float> Mul<Multivector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12() * b.e12()
+                - self.e31() * b.e31()
+                - self.e23() * b.e23()
+                - self.e14() * b.e14()
+                - self.e24() * b.e24()
+                - self.e34() * b.e34()
+                + self.e15() * b.e15()
+                + self.e25() * b.e25()
+                + self.e35() * b.e35()
+                + self.e45() * b.e45(),
+            Vector::new(
+                self.e12() * b.e2() - self.e31() * b.e3() - self.e23() * b.e123()
+                    + self.e14() * b.e4()
+                    - self.e24() * b.e124()
+                    + self.e34() * b.e314()
+                    - self.e15() * b.e5()
+                    + self.e25() * b.e125()
+                    - self.e35() * b.e315()
+                    + self.e45() * b.e145(),
+                -self.e12() * b.e1() - self.e31() * b.e123()
+                    + self.e23() * b.e3()
+                    + self.e14() * b.e124()
+                    + self.e24() * b.e4()
+                    - self.e34() * b.e234()
+                    - self.e15() * b.e125()
+                    - self.e25() * b.e5()
+                    + self.e35() * b.e235()
+                    + self.e45() * b.e245(),
+                -self.e12() * b.e123() + self.e31() * b.e1()
+                    - self.e23() * b.e2()
+                    - self.e14() * b.e314()
+                    + self.e24() * b.e234()
+                    + self.e34() * b.e4()
+                    + self.e15() * b.e315()
+                    - self.e25() * b.e235()
+                    - self.e35() * b.e5()
+                    + self.e45() * b.e345(),
+                -self.e12() * b.e124()
+                    - self.e31() * b.e314()
+                    - self.e23() * b.e234()
+                    - self.e14() * b.e1()
+                    - self.e24() * b.e2()
+                    - self.e34() * b.e3()
+                    - self.e15() * b.e145()
+                    - self.e25() * b.e245()
+                    - self.e35() * b.e345()
+                    - self.e45() * b.e5(),
+                -self.e12() * b.e125()
+                    - self.e31() * b.e315()
+                    - self.e23() * b.e235()
+                    - self.e14() * b.e145()
+                    - self.e24() * b.e245()
+                    - self.e34() * b.e345()
+                    - self.e15() * b.e1()
+                    - self.e25() * b.e2()
+                    - self.e35() * b.e3()
+                    - self.e45() * b.e4(),
+            ),
+            Bivector::new(
+                self.e12() * b.scalar() + self.e31() * b.e23()
+                    - self.e23() * b.e31()
+                    - self.e14() * b.e24()
+                    + self.e24() * b.e14()
+                    - self.e34() * b.e1234()
+                    + self.e15() * b.e25()
+                    - self.e25() * b.e15()
+                    + self.e35() * b.e1235()
+                    + self.e45() * b.e1245(),
+                -self.e12() * b.e23()
+                    + self.e31() * b.scalar()
+                    + self.e23() * b.e12()
+                    + self.e14() * b.e34()
+                    - self.e24() * b.e1234()
+                    - self.e34() * b.e14()
+                    - self.e15() * b.e35()
+                    + self.e25() * b.e1235()
+                    + self.e35() * b.e15()
+                    + self.e45() * b.e3145(),
+                self.e12() * b.e31() - self.e31() * b.e12() + self.e23() * b.scalar()
+                    - self.e14() * b.e1234()
+                    - self.e24() * b.e34()
+                    + self.e34() * b.e24()
+                    + self.e15() * b.e1235()
+                    + self.e25() * b.e35()
+                    - self.e35() * b.e25()
+                    + self.e45() * b.e2345(),
+                self.e12() * b.e24() - self.e31() * b.e34() - self.e23() * b.e1234()
+                    + self.e14() * b.scalar()
+                    - self.e24() * b.e12()
+                    + self.e34() * b.e31()
+                    + self.e15() * b.e45()
+                    - self.e25() * b.e1245()
+                    + self.e35() * b.e3145()
+                    - self.e45() * b.e15(),
+                -self.e12() * b.e14() - self.e31() * b.e1234()
+                    + self.e23() * b.e34()
+                    + self.e14() * b.e12()
+                    + self.e24() * b.scalar()
+                    - self.e34() * b.e23()
+                    + self.e15() * b.e1245()
+                    + self.e25() * b.e45()
+                    - self.e35() * b.e2345()
+                    - self.e45() * b.e25(),
+                -self.e12() * b.e1234() + self.e31() * b.e14()
+                    - self.e23() * b.e24()
+                    - self.e14() * b.e31()
+                    + self.e24() * b.e23()
+                    + self.e34() * b.scalar()
+                    - self.e15() * b.e3145()
+                    + self.e25() * b.e2345()
+                    + self.e35() * b.e45()
+                    - self.e45() * b.e35(),
+                self.e12() * b.e25() - self.e31() * b.e35() - self.e23() * b.e1235()
+                    + self.e14() * b.e45()
+                    - self.e24() * b.e1245()
+                    + self.e34() * b.e3145()
+                    + self.e15() * b.scalar()
+                    - self.e25() * b.e12()
+                    + self.e35() * b.e31()
+                    - self.e45() * b.e14(),
+                -self.e12() * b.e15() - self.e31() * b.e1235()
+                    + self.e23() * b.e35()
+                    + self.e14() * b.e1245()
+                    + self.e24() * b.e45()
+                    - self.e34() * b.e2345()
+                    + self.e15() * b.e12()
+                    + self.e25() * b.scalar()
+                    - self.e35() * b.e23()
+                    - self.e45() * b.e24(),
+                -self.e12() * b.e1235() + self.e31() * b.e15()
+                    - self.e23() * b.e25()
+                    - self.e14() * b.e3145()
+                    + self.e24() * b.e2345()
+                    + self.e34() * b.e45()
+                    - self.e15() * b.e31()
+                    + self.e25() * b.e23()
+                    + self.e35() * b.scalar()
+                    - self.e45() * b.e34(),
+                -self.e12() * b.e1245()
+                    - self.e31() * b.e3145()
+                    - self.e23() * b.e2345()
+                    - self.e14() * b.e15()
+                    - self.e24() * b.e25()
+                    - self.e34() * b.e35()
+                    + self.e15() * b.e14()
+                    + self.e25() * b.e24()
+                    + self.e35() * b.e34()
+                    + self.e45() * b.scalar(),
+            ),
+            Trivector::new(
+                self.e12() * b.e3()
+                    + self.e31() * b.e2()
+                    + self.e23() * b.e1()
+                    + self.e14() * b.e234()
+                    + self.e24() * b.e314()
+                    + self.e34() * b.e124()
+                    - self.e15() * b.e235()
+                    - self.e25() * b.e315()
+                    - self.e35() * b.e125()
+                    + self.e45() * b.e12345(),
+                self.e12() * b.e4() + self.e31() * b.e234()
+                    - self.e23() * b.e314()
+                    - self.e14() * b.e2()
+                    + self.e24() * b.e1()
+                    - self.e34() * b.e123()
+                    - self.e15() * b.e245()
+                    + self.e25() * b.e145()
+                    - self.e35() * b.e12345()
+                    - self.e45() * b.e125(),
+                -self.e12() * b.e234()
+                    + self.e31() * b.e4()
+                    + self.e23() * b.e124()
+                    + self.e14() * b.e3()
+                    - self.e24() * b.e123()
+                    - self.e34() * b.e1()
+                    + self.e15() * b.e345()
+                    - self.e25() * b.e12345()
+                    - self.e35() * b.e145()
+                    - self.e45() * b.e315(),
+                self.e12() * b.e314() - self.e31() * b.e124() + self.e23() * b.e4()
+                    - self.e14() * b.e123()
+                    - self.e24() * b.e3()
+                    + self.e34() * b.e2()
+                    - self.e15() * b.e12345()
+                    - self.e25() * b.e345()
+                    + self.e35() * b.e245()
+                    - self.e45() * b.e235(),
+                self.e12() * b.e5() + self.e31() * b.e235()
+                    - self.e23() * b.e315()
+                    - self.e14() * b.e245()
+                    + self.e24() * b.e145()
+                    - self.e34() * b.e12345()
+                    - self.e15() * b.e2()
+                    + self.e25() * b.e1()
+                    - self.e35() * b.e123()
+                    - self.e45() * b.e124(),
+                -self.e12() * b.e235()
+                    + self.e31() * b.e5()
+                    + self.e23() * b.e125()
+                    + self.e14() * b.e345()
+                    - self.e24() * b.e12345()
+                    - self.e34() * b.e145()
+                    + self.e15() * b.e3()
+                    - self.e25() * b.e123()
+                    - self.e35() * b.e1()
+                    - self.e45() * b.e314(),
+                self.e12() * b.e315() - self.e31() * b.e125() + self.e23() * b.e5()
+                    - self.e14() * b.e12345()
+                    - self.e24() * b.e345()
+                    + self.e34() * b.e245()
+                    - self.e15() * b.e123()
+                    - self.e25() * b.e3()
+                    + self.e35() * b.e2()
+                    - self.e45() * b.e234(),
+                self.e12() * b.e245() - self.e31() * b.e345() - self.e23() * b.e12345()
+                    + self.e14() * b.e5()
+                    - self.e24() * b.e125()
+                    + self.e34() * b.e315()
+                    - self.e15() * b.e4()
+                    + self.e25() * b.e124()
+                    - self.e35() * b.e314()
+                    + self.e45() * b.e1(),
+                -self.e12() * b.e145() - self.e31() * b.e12345()
+                    + self.e23() * b.e345()
+                    + self.e14() * b.e125()
+                    + self.e24() * b.e5()
+                    - self.e34() * b.e235()
+                    - self.e15() * b.e124()
+                    - self.e25() * b.e4()
+                    + self.e35() * b.e234()
+                    + self.e45() * b.e2(),
+                -self.e12() * b.e12345() + self.e31() * b.e145()
+                    - self.e23() * b.e245()
+                    - self.e14() * b.e315()
+                    + self.e24() * b.e235()
+                    + self.e34() * b.e5()
+                    + self.e15() * b.e314()
+                    - self.e25() * b.e234()
+                    - self.e35() * b.e4()
+                    + self.e45() * b.e3(),
+            ),
+            Quadvector::new(
+                self.e12() * b.e34()
+                    + self.e31() * b.e24()
+                    + self.e23() * b.e14()
+                    + self.e14() * b.e23()
+                    + self.e24() * b.e31()
+                    + self.e34() * b.e12()
+                    + self.e15() * b.e2345()
+                    + self.e25() * b.e3145()
+                    + self.e35() * b.e1245()
+                    - self.e45() * b.e1235(),
+                self.e12() * b.e35()
+                    + self.e31() * b.e25()
+                    + self.e23() * b.e15()
+                    + self.e14() * b.e2345()
+                    + self.e24() * b.e3145()
+                    + self.e34() * b.e1245()
+                    + self.e15() * b.e23()
+                    + self.e25() * b.e31()
+                    + self.e35() * b.e12()
+                    - self.e45() * b.e1234(),
+                self.e12() * b.e45() + self.e31() * b.e2345()
+                    - self.e23() * b.e3145()
+                    - self.e14() * b.e25()
+                    + self.e24() * b.e15()
+                    - self.e34() * b.e1235()
+                    + self.e15() * b.e24()
+                    - self.e25() * b.e14()
+                    + self.e35() * b.e1234()
+                    + self.e45() * b.e12(),
+                -self.e12() * b.e2345()
+                    + self.e31() * b.e45()
+                    + self.e23() * b.e1245()
+                    + self.e14() * b.e35()
+                    - self.e24() * b.e1235()
+                    - self.e34() * b.e15()
+                    - self.e15() * b.e34()
+                    + self.e25() * b.e1234()
+                    + self.e35() * b.e14()
+                    + self.e45() * b.e31(),
+                self.e12() * b.e3145() - self.e31() * b.e1245() + self.e23() * b.e45()
+                    - self.e14() * b.e1235()
+                    - self.e24() * b.e35()
+                    + self.e34() * b.e25()
+                    + self.e15() * b.e1234()
+                    + self.e25() * b.e34()
+                    - self.e35() * b.e24()
+                    + self.e45() * b.e23(),
+            ),
+            self.e12() * b.e345()
+                + self.e31() * b.e245()
+                + self.e23() * b.e145()
+                + self.e14() * b.e235()
+                + self.e24() * b.e315()
+                + self.e34() * b.e125()
+                - self.e15() * b.e234()
+                - self.e25() * b.e314()
+                - self.e35() * b.e124()
+                + self.e45() * b.e123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Bivector<F>, Multivector<F>);
+
+// Multivector-Bivector
+impl<F: Float> Mul<Bivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12() * b.e12()
+                - self.e31() * b.e31()
+                - self.e23() * b.e23()
+                - self.e14() * b.e14()
+                - self.e24() * b.e24()
+                - self.e34() * b.e34()
+                + self.e15() * b.e15()
+                + self.e25() * b.e25()
+                + self.e35() * b.e35()
+                + self.e45() * b.e45(),
+            Vector::new(
+                -self.e2() * b.e12() + self.e3() * b.e31() - self.e4() * b.e14()
+                    + self.e5() * b.e15()
+                    - self.e123() * b.e23()
+                    - self.e124() * b.e24()
+                    + self.e314() * b.e34()
+                    + self.e125() * b.e25()
+                    - self.e315() * b.e35()
+                    + self.e145() * b.e45(),
+                self.e1() * b.e12() - self.e3() * b.e23() - self.e4() * b.e24()
+                    + self.e5() * b.e25()
+                    - self.e123() * b.e31()
+                    + self.e124() * b.e14()
+                    - self.e234() * b.e34()
+                    - self.e125() * b.e15()
+                    + self.e235() * b.e35()
+                    + self.e245() * b.e45(),
+                -self.e1() * b.e31() + self.e2() * b.e23() - self.e4() * b.e34()
+                    + self.e5() * b.e35()
+                    - self.e123() * b.e12()
+                    - self.e314() * b.e14()
+                    + self.e234() * b.e24()
+                    + self.e315() * b.e15()
+                    - self.e235() * b.e25()
+                    + self.e345() * b.e45(),
+                self.e1() * b.e14()
+                    + self.e2() * b.e24()
+                    + self.e3() * b.e34()
+                    + self.e5() * b.e45()
+                    - self.e124() * b.e12()
+                    - self.e314() * b.e31()
+                    - self.e234() * b.e23()
+                    - self.e145() * b.e15()
+                    - self.e245() * b.e25()
+                    - self.e345() * b.e35(),
+                self.e1() * b.e15()
+                    + self.e2() * b.e25()
+                    + self.e3() * b.e35()
+                    + self.e4() * b.e45()
+                    - self.e125() * b.e12()
+                    - self.e315() * b.e31()
+                    - self.e235() * b.e23()
+                    - self.e145() * b.e14()
+                    - self.e245() * b.e24()
+                    - self.e345() * b.e34(),
+            ),
+            Bivector::new(
+                self.scalar() * b.e12() + self.e31() * b.e23()
+                    - self.e23() * b.e31()
+                    - self.e14() * b.e24()
+                    + self.e24() * b.e14()
+                    + self.e15() * b.e25()
+                    - self.e25() * b.e15()
+                    - self.e1234() * b.e34()
+                    + self.e1235() * b.e35()
+                    + self.e1245() * b.e45(),
+                self.scalar() * b.e31() - self.e12() * b.e23()
+                    + self.e23() * b.e12()
+                    + self.e14() * b.e34()
+                    - self.e34() * b.e14()
+                    - self.e15() * b.e35()
+                    + self.e35() * b.e15()
+                    - self.e1234() * b.e24()
+                    + self.e1235() * b.e25()
+                    + self.e3145() * b.e45(),
+                self.scalar() * b.e23() + self.e12() * b.e31()
+                    - self.e31() * b.e12()
+                    - self.e24() * b.e34()
+                    + self.e34() * b.e24()
+                    + self.e25() * b.e35()
+                    - self.e35() * b.e25()
+                    - self.e1234() * b.e14()
+                    + self.e1235() * b.e15()
+                    + self.e2345() * b.e45(),
+                self.scalar() * b.e14() + self.e12() * b.e24()
+                    - self.e31() * b.e34()
+                    - self.e24() * b.e12()
+                    + self.e34() * b.e31()
+                    + self.e15() * b.e45()
+                    - self.e45() * b.e15()
+                    - self.e1234() * b.e23()
+                    - self.e1245() * b.e25()
+                    + self.e3145() * b.e35(),
+                self.scalar() * b.e24() - self.e12() * b.e14()
+                    + self.e23() * b.e34()
+                    + self.e14() * b.e12()
+                    - self.e34() * b.e23()
+                    + self.e25() * b.e45()
+                    - self.e45() * b.e25()
+                    - self.e1234() * b.e31()
+                    + self.e1245() * b.e15()
+                    - self.e2345() * b.e35(),
+                self.scalar() * b.e34() + self.e31() * b.e14()
+                    - self.e23() * b.e24()
+                    - self.e14() * b.e31()
+                    + self.e24() * b.e23()
+                    + self.e35() * b.e45()
+                    - self.e45() * b.e35()
+                    - self.e1234() * b.e12()
+                    - self.e3145() * b.e15()
+                    + self.e2345() * b.e25(),
+                self.scalar() * b.e15() + self.e12() * b.e25() - self.e31() * b.e35()
+                    + self.e14() * b.e45()
+                    - self.e25() * b.e12()
+                    + self.e35() * b.e31()
+                    - self.e45() * b.e14()
+                    - self.e1235() * b.e23()
+                    - self.e1245() * b.e24()
+                    + self.e3145() * b.e34(),
+                self.scalar() * b.e25() - self.e12() * b.e15()
+                    + self.e23() * b.e35()
+                    + self.e24() * b.e45()
+                    + self.e15() * b.e12()
+                    - self.e35() * b.e23()
+                    - self.e45() * b.e24()
+                    - self.e1235() * b.e31()
+                    + self.e1245() * b.e14()
+                    - self.e2345() * b.e34(),
+                self.scalar() * b.e35() + self.e31() * b.e15() - self.e23() * b.e25()
+                    + self.e34() * b.e45()
+                    - self.e15() * b.e31()
+                    + self.e25() * b.e23()
+                    - self.e45() * b.e34()
+                    - self.e1235() * b.e12()
+                    - self.e3145() * b.e14()
+                    + self.e2345() * b.e24(),
+                self.scalar() * b.e45()
+                    - self.e14() * b.e15()
+                    - self.e24() * b.e25()
+                    - self.e34() * b.e35()
+                    + self.e15() * b.e14()
+                    + self.e25() * b.e24()
+                    + self.e35() * b.e34()
+                    - self.e1245() * b.e12()
+                    - self.e3145() * b.e31()
+                    - self.e2345() * b.e23(),
+            ),
+            Trivector::new(
+                self.e1() * b.e23() + self.e2() * b.e31() + self.e3() * b.e12()
+                    - self.e124() * b.e34()
+                    - self.e314() * b.e24()
+                    - self.e234() * b.e14()
+                    + self.e125() * b.e35()
+                    + self.e315() * b.e25()
+                    + self.e235() * b.e15()
+                    + self.e12345() * b.e45(),
+                self.e1() * b.e24() - self.e2() * b.e14()
+                    + self.e4() * b.e12()
+                    + self.e123() * b.e34()
+                    + self.e314() * b.e23()
+                    - self.e234() * b.e31()
+                    + self.e125() * b.e45()
+                    - self.e145() * b.e25()
+                    + self.e245() * b.e15()
+                    - self.e12345() * b.e35(),
+                -self.e1() * b.e34()
+                    + self.e3() * b.e14()
+                    + self.e4() * b.e31()
+                    + self.e123() * b.e24()
+                    - self.e124() * b.e23()
+                    + self.e234() * b.e12()
+                    + self.e315() * b.e45()
+                    + self.e145() * b.e35()
+                    - self.e345() * b.e15()
+                    - self.e12345() * b.e25(),
+                self.e2() * b.e34() - self.e3() * b.e24()
+                    + self.e4() * b.e23()
+                    + self.e123() * b.e14()
+                    + self.e124() * b.e31()
+                    - self.e314() * b.e12()
+                    + self.e235() * b.e45()
+                    - self.e245() * b.e35()
+                    + self.e345() * b.e25()
+                    - self.e12345() * b.e15(),
+                self.e1() * b.e25() - self.e2() * b.e15()
+                    + self.e5() * b.e12()
+                    + self.e123() * b.e35()
+                    + self.e124() * b.e45()
+                    + self.e315() * b.e23()
+                    - self.e235() * b.e31()
+                    - self.e145() * b.e24()
+                    + self.e245() * b.e14()
+                    - self.e12345() * b.e34(),
+                -self.e1() * b.e35()
+                    + self.e3() * b.e15()
+                    + self.e5() * b.e31()
+                    + self.e123() * b.e25()
+                    + self.e314() * b.e45()
+                    - self.e125() * b.e23()
+                    + self.e235() * b.e12()
+                    + self.e145() * b.e34()
+                    - self.e345() * b.e14()
+                    - self.e12345() * b.e24(),
+                self.e2() * b.e35() - self.e3() * b.e25()
+                    + self.e5() * b.e23()
+                    + self.e123() * b.e15()
+                    + self.e234() * b.e45()
+                    + self.e125() * b.e31()
+                    - self.e315() * b.e12()
+                    - self.e245() * b.e34()
+                    + self.e345() * b.e24()
+                    - self.e12345() * b.e14(),
+                self.e1() * b.e45() - self.e4() * b.e15() + self.e5() * b.e14()
+                    - self.e124() * b.e25()
+                    + self.e314() * b.e35()
+                    + self.e125() * b.e24()
+                    - self.e315() * b.e34()
+                    - self.e245() * b.e12()
+                    + self.e345() * b.e31()
+                    - self.e12345() * b.e23(),
+                self.e2() * b.e45() - self.e4() * b.e25()
+                    + self.e5() * b.e24()
+                    + self.e124() * b.e15()
+                    - self.e234() * b.e35()
+                    - self.e125() * b.e14()
+                    + self.e235() * b.e34()
+                    + self.e145() * b.e12()
+                    - self.e345() * b.e23()
+                    - self.e12345() * b.e31(),
+                self.e3() * b.e45() - self.e4() * b.e35() + self.e5() * b.e34()
+                    - self.e314() * b.e15()
+                    + self.e234() * b.e25()
+                    + self.e315() * b.e14()
+                    - self.e235() * b.e24()
+                    - self.e145() * b.e31()
+                    + self.e245() * b.e23()
+                    - self.e12345() * b.e12(),
+            ),
+            Quadvector::new(
+                self.e12() * b.e34()
+                    + self.e31() * b.e24()
+                    + self.e23() * b.e14()
+                    + self.e14() * b.e23()
+                    + self.e24() * b.e31()
+                    + self.e34() * b.e12()
+                    + self.e1235() * b.e45()
+                    - self.e1245() * b.e35()
+                    - self.e3145() * b.e25()
+                    - self.e2345() * b.e15(),
+                self.e12() * b.e35()
+                    + self.e31() * b.e25()
+                    + self.e23() * b.e15()
+                    + self.e15() * b.e23()
+                    + self.e25() * b.e31()
+                    + self.e35() * b.e12()
+                    + self.e1234() * b.e45()
+                    - self.e1245() * b.e34()
+                    - self.e3145() * b.e24()
+                    - self.e2345() * b.e14(),
+                self.e12() * b.e45() - self.e14() * b.e25()
+                    + self.e24() * b.e15()
+                    + self.e15() * b.e24()
+                    - self.e25() * b.e14()
+                    + self.e45() * b.e12()
+                    - self.e1234() * b.e35()
+                    + self.e1235() * b.e34()
+                    + self.e3145() * b.e23()
+                    - self.e2345() * b.e31(),
+                self.e31() * b.e45() + self.e14() * b.e35()
+                    - self.e34() * b.e15()
+                    - self.e15() * b.e34()
+                    + self.e35() * b.e14()
+                    + self.e45() * b.e31()
+                    - self.e1234() * b.e25()
+                    + self.e1235() * b.e24()
+                    - self.e1245() * b.e23()
+                    + self.e2345() * b.e12(),
+                self.e23() * b.e45() - self.e24() * b.e35()
+                    + self.e34() * b.e25()
+                    + self.e25() * b.e34()
+                    - self.e35() * b.e24()
+                    + self.e45() * b.e23()
+                    - self.e1234() * b.e15()
+                    + self.e1235() * b.e14()
+                    + self.e1245() * b.e31()
+                    - self.e3145() * b.e12(),
+            ),
+            self.e123() * b.e45()
+                - self.e124() * b.e35()
+                - self.e314() * b.e25()
+                - self.e234() * b.e15()
+                + self.e125() * b.e34()
+                + self.e315() * b.e24()
+                + self.e235() * b.e14()
+                + self.e145() * b.e23()
+                + self.e245() * b.e31()
+                + self.e345() * b.e12(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Bivector<F>);
+
+// Trivector-Trivector
+impl<F: Float> Mul for Trivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e123() * b.e123()
+                - self.e124() * b.e124()
+                - self.e314() * b.e314()
+                - self.e234() * b.e234()
+                + self.e125() * b.e125()
+                + self.e315() * b.e315()
+                + self.e235() * b.e235()
+                + self.e145() * b.e145()
+                + self.e245() * b.e245()
+                + self.e345() * b.e345(),
+            Vector::zero(),
+            Bivector::new(
+                self.e314() * b.e234() - self.e234() * b.e314() - self.e315() * b.e235()
+                    + self.e235() * b.e315()
+                    + self.e145() * b.e245()
+                    - self.e245() * b.e145(),
+                -self.e124() * b.e234() + self.e234() * b.e124() + self.e125() * b.e235()
+                    - self.e235() * b.e125()
+                    - self.e145() * b.e345()
+                    + self.e345() * b.e145(),
+                self.e124() * b.e314() - self.e314() * b.e124() - self.e125() * b.e315()
+                    + self.e315() * b.e125()
+                    + self.e245() * b.e345()
+                    - self.e345() * b.e245(),
+                -self.e123() * b.e234() + self.e234() * b.e123() - self.e125() * b.e245()
+                    + self.e315() * b.e345()
+                    + self.e245() * b.e125()
+                    - self.e345() * b.e315(),
+                -self.e123() * b.e314() + self.e314() * b.e123() + self.e125() * b.e145()
+                    - self.e235() * b.e345()
+                    - self.e145() * b.e125()
+                    + self.e345() * b.e235(),
+                -self.e123() * b.e124() + self.e124() * b.e123() - self.e315() * b.e145()
+                    + self.e235() * b.e245()
+                    + self.e145() * b.e315()
+                    - self.e245() * b.e235(),
+                -self.e123() * b.e235() - self.e124() * b.e245()
+                    + self.e314() * b.e345()
+                    + self.e235() * b.e123()
+                    + self.e245() * b.e124()
+                    - self.e345() * b.e314(),
+                -self.e123() * b.e315() + self.e124() * b.e145() - self.e234() * b.e345()
+                    + self.e315() * b.e123()
+                    - self.e145() * b.e124()
+                    + self.e345() * b.e234(),
+                -self.e123() * b.e125() - self.e314() * b.e145()
+                    + self.e234() * b.e245()
+                    + self.e125() * b.e123()
+                    + self.e145() * b.e314()
+                    - self.e245() * b.e234(),
+                -self.e124() * b.e125() - self.e314() * b.e315() - self.e234() * b.e235()
+                    + self.e125() * b.e124()
+                    + self.e315() * b.e314()
+                    + self.e235() * b.e234(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                -self.e125() * b.e345()
+                    - self.e315() * b.e245()
+                    - self.e235() * b.e145()
+                    - self.e145() * b.e235()
+                    - self.e245() * b.e315()
+                    - self.e345() * b.e125(),
+                -self.e124() * b.e345()
+                    - self.e314() * b.e245()
+                    - self.e234() * b.e145()
+                    - self.e145() * b.e234()
+                    - self.e245() * b.e314()
+                    - self.e345() * b.e124(),
+                self.e123() * b.e345() + self.e314() * b.e235()
+                    - self.e234() * b.e315()
+                    - self.e315() * b.e234()
+                    + self.e235() * b.e314()
+                    + self.e345() * b.e123(),
+                self.e123() * b.e245() - self.e124() * b.e235()
+                    + self.e234() * b.e125()
+                    + self.e125() * b.e234()
+                    - self.e235() * b.e124()
+                    + self.e245() * b.e123(),
+                self.e123() * b.e145() + self.e124() * b.e315()
+                    - self.e314() * b.e125()
+                    - self.e125() * b.e314()
+                    + self.e315() * b.e124()
+                    + self.e145() * b.e123(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Trivector<F>, Trivector<F>);
+
+// Trivector-Quadvector
+impl<F: Float> Mul<Quadvector<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Quadvector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                self.e234() * b.e1234() - self.e235() * b.e1235() - self.e245() * b.e1245()
+                    + self.e345() * b.e3145(),
+                self.e314() * b.e1234() - self.e315() * b.e1235() + self.e145() * b.e1245()
+                    - self.e345() * b.e2345(),
+                self.e124() * b.e1234() - self.e125() * b.e1235() - self.e145() * b.e3145()
+                    + self.e245() * b.e2345(),
+                -self.e123() * b.e1234()
+                    - self.e125() * b.e1245()
+                    - self.e315() * b.e3145()
+                    - self.e235() * b.e2345(),
+                -self.e123() * b.e1235()
+                    - self.e124() * b.e1245()
+                    - self.e314() * b.e3145()
+                    - self.e234() * b.e2345(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                self.e145() * b.e2345() + self.e245() * b.e3145() + self.e345() * b.e1245(),
+                self.e315() * b.e2345() - self.e235() * b.e3145() - self.e345() * b.e1235(),
+                -self.e125() * b.e2345() + self.e235() * b.e1245() - self.e245() * b.e1235(),
+                self.e125() * b.e3145() - self.e315() * b.e1245() - self.e145() * b.e1235(),
+                self.e314() * b.e2345() - self.e234() * b.e3145() - self.e345() * b.e1234(),
+                -self.e124() * b.e2345() + self.e234() * b.e1245() - self.e245() * b.e1234(),
+                self.e124() * b.e3145() - self.e314() * b.e1245() - self.e145() * b.e1234(),
+                -self.e123() * b.e2345() + self.e234() * b.e1235() - self.e235() * b.e1234(),
+                -self.e123() * b.e3145() + self.e314() * b.e1235() - self.e315() * b.e1234(),
+                -self.e123() * b.e1245() + self.e124() * b.e1235() - self.e125() * b.e1234(),
+            ),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Trivector<F>, Quadvector<F>);
+
+// Quadvector-Trivector
+impl<F: Float> Mul<Trivector<F>> for Quadvector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                -self.e1234() * b.e234() + self.e1235() * b.e235() + self.e1245() * b.e245()
+                    - self.e3145() * b.e345(),
+                -self.e1234() * b.e314() + self.e1235() * b.e315() - self.e1245() * b.e145()
+                    + self.e2345() * b.e345(),
+                -self.e1234() * b.e124() + self.e1235() * b.e125() + self.e3145() * b.e145()
+                    - self.e2345() * b.e245(),
+                self.e1234() * b.e123()
+                    + self.e1245() * b.e125()
+                    + self.e3145() * b.e315()
+                    + self.e2345() * b.e235(),
+                self.e1235() * b.e123()
+                    + self.e1245() * b.e124()
+                    + self.e3145() * b.e314()
+                    + self.e2345() * b.e234(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                self.e1245() * b.e345() + self.e3145() * b.e245() + self.e2345() * b.e145(),
+                -self.e1235() * b.e345() - self.e3145() * b.e235() + self.e2345() * b.e315(),
+                -self.e1235() * b.e245() + self.e1245() * b.e235() - self.e2345() * b.e125(),
+                -self.e1235() * b.e145() - self.e1245() * b.e315() + self.e3145() * b.e125(),
+                -self.e1234() * b.e345() - self.e3145() * b.e234() + self.e2345() * b.e314(),
+                -self.e1234() * b.e245() + self.e1245() * b.e234() - self.e2345() * b.e124(),
+                -self.e1234() * b.e145() - self.e1245() * b.e314() + self.e3145() * b.e124(),
+                -self.e1234() * b.e235() + self.e1235() * b.e234() - self.e2345() * b.e123(),
+                -self.e1234() * b.e315() + self.e1235() * b.e314() - self.e3145() * b.e123(),
+                -self.e1234() * b.e125() + self.e1235() * b.e124() - self.e1245() * b.e123(),
+            ),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Quadvector<F>, Trivector<F>);
+
+// Trivector-Pseudoscalar
+impl<F: Float> Mul<Pseudoscalar<F>> for Trivector<F> {
+    type Output = Bivector<F>;
+    fn mul(self, b: Pseudoscalar<F>) -> Bivector<F> {
+        Bivector::new(
+            self.e345() * b.e12345(),
+            self.e245() * b.e12345(),
+            self.e145() * b.e12345(),
+            self.e235() * b.e12345(),
+            self.e315() * b.e12345(),
+            self.e125() * b.e12345(),
+            self.e234() * b.e12345(),
+            self.e314() * b.e12345(),
+            self.e124() * b.e12345(),
+            -self.e123() * b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Trivector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Trivector
+impl<F: Float> Mul<Trivector<F>> for Pseudoscalar<F> {
+    type Output = Bivector<F>;
+    fn mul(self, b: Trivector<F>) -> Bivector<F> {
+        Bivector::new(
+            self.e12345() * b.e345(),
+            self.e12345() * b.e245(),
+            self.e12345() * b.e145(),
+            self.e12345() * b.e235(),
+            self.e12345() * b.e315(),
+            self.e12345() * b.e125(),
+            self.e12345() * b.e234(),
+            self.e12345() * b.e314(),
+            self.e12345() * b.e124(),
+            -self.e12345() * b.e123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Trivector<F>);
+
+// Trivector-Versor
+impl<F: Float> Mul<Versor<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                -self.e123() * b.e23() - self.e124() * b.e24()
+                    + self.e314() * b.e34()
+                    + self.e234() * b.e1234()
+                    + self.e125() * b.e25()
+                    - self.e315() * b.e35()
+                    - self.e235() * b.e1235()
+                    + self.e145() * b.e45()
+                    - self.e245() * b.e1245()
+                    + self.e345() * b.e3145(),
+                -self.e123() * b.e31() + self.e124() * b.e14() + self.e314() * b.e1234()
+                    - self.e234() * b.e34()
+                    - self.e125() * b.e15()
+                    - self.e315() * b.e1235()
+                    + self.e235() * b.e35()
+                    + self.e145() * b.e1245()
+                    + self.e245() * b.e45()
+                    - self.e345() * b.e2345(),
+                -self.e123() * b.e12() + self.e124() * b.e1234() - self.e314() * b.e14()
+                    + self.e234() * b.e24()
+                    - self.e125() * b.e1235()
+                    + self.e315() * b.e15()
+                    - self.e235() * b.e25()
+                    - self.e145() * b.e3145()
+                    + self.e245() * b.e2345()
+                    + self.e345() * b.e45(),
+                -self.e123() * b.e1234()
+                    - self.e124() * b.e12()
+                    - self.e314() * b.e31()
+                    - self.e234() * b.e23()
+                    - self.e125() * b.e1245()
+                    - self.e315() * b.e3145()
+                    - self.e235() * b.e2345()
+                    - self.e145() * b.e15()
+                    - self.e245() * b.e25()
+                    - self.e345() * b.e35(),
+                -self.e123() * b.e1235()
+                    - self.e124() * b.e1245()
+                    - self.e314() * b.e3145()
+                    - self.e234() * b.e2345()
+                    - self.e125() * b.e12()
+                    - self.e315() * b.e31()
+                    - self.e235() * b.e23()
+                    - self.e145() * b.e14()
+                    - self.e245() * b.e24()
+                    - self.e345() * b.e34(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                self.e123() * b.scalar()
+                    - self.e124() * b.e34()
+                    - self.e314() * b.e24()
+                    - self.e234() * b.e14()
+                    + self.e125() * b.e35()
+                    + self.e315() * b.e25()
+                    + self.e235() * b.e15()
+                    + self.e145() * b.e2345()
+                    + self.e245() * b.e3145()
+                    + self.e345() * b.e1245(),
+                self.e123() * b.e34() + self.e124() * b.scalar() + self.e314() * b.e23()
+                    - self.e234() * b.e31()
+                    + self.e125() * b.e45()
+                    + self.e315() * b.e2345()
+                    - self.e235() * b.e3145()
+                    - self.e145() * b.e25()
+                    + self.e245() * b.e15()
+                    - self.e345() * b.e1235(),
+                self.e123() * b.e24() - self.e124() * b.e23()
+                    + self.e314() * b.scalar()
+                    + self.e234() * b.e12()
+                    - self.e125() * b.e2345()
+                    + self.e315() * b.e45()
+                    + self.e235() * b.e1245()
+                    + self.e145() * b.e35()
+                    - self.e245() * b.e1235()
+                    - self.e345() * b.e15(),
+                self.e123() * b.e14() + self.e124() * b.e31() - self.e314() * b.e12()
+                    + self.e234() * b.scalar()
+                    + self.e125() * b.e3145()
+                    - self.e315() * b.e1245()
+                    + self.e235() * b.e45()
+                    - self.e145() * b.e1235()
+                    - self.e245() * b.e35()
+                    + self.e345() * b.e25(),
+                self.e123() * b.e35() + self.e124() * b.e45() + self.e314() * b.e2345()
+                    - self.e234() * b.e3145()
+                    + self.e125() * b.scalar()
+                    + self.e315() * b.e23()
+                    - self.e235() * b.e31()
+                    - self.e145() * b.e24()
+                    + self.e245() * b.e14()
+                    - self.e345() * b.e1234(),
+                self.e123() * b.e25() - self.e124() * b.e2345()
+                    + self.e314() * b.e45()
+                    + self.e234() * b.e1245()
+                    - self.e125() * b.e23()
+                    + self.e315() * b.scalar()
+                    + self.e235() * b.e12()
+                    + self.e145() * b.e34()
+                    - self.e245() * b.e1234()
+                    - self.e345() * b.e14(),
+                self.e123() * b.e15() + self.e124() * b.e3145() - self.e314() * b.e1245()
+                    + self.e234() * b.e45()
+                    + self.e125() * b.e31()
+                    - self.e315() * b.e12()
+                    + self.e235() * b.scalar()
+                    - self.e145() * b.e1234()
+                    - self.e245() * b.e34()
+                    + self.e345() * b.e24(),
+                -self.e123() * b.e2345() - self.e124() * b.e25()
+                    + self.e314() * b.e35()
+                    + self.e234() * b.e1235()
+                    + self.e125() * b.e24()
+                    - self.e315() * b.e34()
+                    - self.e235() * b.e1234()
+                    + self.e145() * b.scalar()
+                    - self.e245() * b.e12()
+                    + self.e345() * b.e31(),
+                -self.e123() * b.e3145() + self.e124() * b.e15() + self.e314() * b.e1235()
+                    - self.e234() * b.e35()
+                    - self.e125() * b.e14()
+                    - self.e315() * b.e1234()
+                    + self.e235() * b.e34()
+                    + self.e145() * b.e12()
+                    + self.e245() * b.scalar()
+                    - self.e345() * b.e23(),
+                -self.e123() * b.e1245() + self.e124() * b.e1235() - self.e314() * b.e15()
+                    + self.e234() * b.e25()
+                    - self.e125() * b.e1234()
+                    + self.e315() * b.e14()
+                    - self.e235() * b.e24()
+                    - self.e145() * b.e31()
+                    + self.e245() * b.e23()
+                    + self.e345() * b.scalar(),
+            ),
+            Quadvector::zero(),
+            self.e123() * b.e45()
+                - self.e124() * b.e35()
+                - self.e314() * b.e25()
+                - self.e234() * b.e15()
+                + self.e125() * b.e34()
+                + self.e315() * b.e24()
+                + self.e235() * b.e14()
+                + self.e145() * b.e23()
+                + self.e245() * b.e31()
+                + self.e345() * b.e12(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Trivector<F>, Versor<F>);
+
+// Versor-Trivector
+impl<F: Float> Mul<Trivector<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                -self.e23() * b.e123() - self.e24() * b.e124()
+                    + self.e34() * b.e314()
+                    + self.e25() * b.e125()
+                    - self.e35() * b.e315()
+                    + self.e45() * b.e145()
+                    - self.e1234() * b.e234()
+                    + self.e1235() * b.e235()
+                    + self.e1245() * b.e245()
+                    - self.e3145() * b.e345(),
+                -self.e31() * b.e123() + self.e14() * b.e124()
+                    - self.e34() * b.e234()
+                    - self.e15() * b.e125()
+                    + self.e35() * b.e235()
+                    + self.e45() * b.e245()
+                    - self.e1234() * b.e314()
+                    + self.e1235() * b.e315()
+                    - self.e1245() * b.e145()
+                    + self.e2345() * b.e345(),
+                -self.e12() * b.e123() - self.e14() * b.e314()
+                    + self.e24() * b.e234()
+                    + self.e15() * b.e315()
+                    - self.e25() * b.e235()
+                    + self.e45() * b.e345()
+                    - self.e1234() * b.e124()
+                    + self.e1235() * b.e125()
+                    + self.e3145() * b.e145()
+                    - self.e2345() * b.e245(),
+                -self.e12() * b.e124()
+                    - self.e31() * b.e314()
+                    - self.e23() * b.e234()
+                    - self.e15() * b.e145()
+                    - self.e25() * b.e245()
+                    - self.e35() * b.e345()
+                    + self.e1234() * b.e123()
+                    + self.e1245() * b.e125()
+                    + self.e3145() * b.e315()
+                    + self.e2345() * b.e235(),
+                -self.e12() * b.e125()
+                    - self.e31() * b.e315()
+                    - self.e23() * b.e235()
+                    - self.e14() * b.e145()
+                    - self.e24() * b.e245()
+                    - self.e34() * b.e345()
+                    + self.e1235() * b.e123()
+                    + self.e1245() * b.e124()
+                    + self.e3145() * b.e314()
+                    + self.e2345() * b.e234(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                self.scalar() * b.e123()
+                    + self.e14() * b.e234()
+                    + self.e24() * b.e314()
+                    + self.e34() * b.e124()
+                    - self.e15() * b.e235()
+                    - self.e25() * b.e315()
+                    - self.e35() * b.e125()
+                    + self.e1245() * b.e345()
+                    + self.e3145() * b.e245()
+                    + self.e2345() * b.e145(),
+                self.scalar() * b.e124() + self.e31() * b.e234()
+                    - self.e23() * b.e314()
+                    - self.e34() * b.e123()
+                    - self.e15() * b.e245()
+                    + self.e25() * b.e145()
+                    - self.e45() * b.e125()
+                    - self.e1235() * b.e345()
+                    - self.e3145() * b.e235()
+                    + self.e2345() * b.e315(),
+                self.scalar() * b.e314() - self.e12() * b.e234() + self.e23() * b.e124()
+                    - self.e24() * b.e123()
+                    + self.e15() * b.e345()
+                    - self.e35() * b.e145()
+                    - self.e45() * b.e315()
+                    - self.e1235() * b.e245()
+                    + self.e1245() * b.e235()
+                    - self.e2345() * b.e125(),
+                self.scalar() * b.e234() + self.e12() * b.e314()
+                    - self.e31() * b.e124()
+                    - self.e14() * b.e123()
+                    - self.e25() * b.e345()
+                    + self.e35() * b.e245()
+                    - self.e45() * b.e235()
+                    - self.e1235() * b.e145()
+                    - self.e1245() * b.e315()
+                    + self.e3145() * b.e125(),
+                self.scalar() * b.e125() + self.e31() * b.e235()
+                    - self.e23() * b.e315()
+                    - self.e14() * b.e245()
+                    + self.e24() * b.e145()
+                    - self.e35() * b.e123()
+                    - self.e45() * b.e124()
+                    - self.e1234() * b.e345()
+                    - self.e3145() * b.e234()
+                    + self.e2345() * b.e314(),
+                self.scalar() * b.e315() - self.e12() * b.e235()
+                    + self.e23() * b.e125()
+                    + self.e14() * b.e345()
+                    - self.e34() * b.e145()
+                    - self.e25() * b.e123()
+                    - self.e45() * b.e314()
+                    - self.e1234() * b.e245()
+                    + self.e1245() * b.e234()
+                    - self.e2345() * b.e124(),
+                self.scalar() * b.e235() + self.e12() * b.e315()
+                    - self.e31() * b.e125()
+                    - self.e24() * b.e345()
+                    + self.e34() * b.e245()
+                    - self.e15() * b.e123()
+                    - self.e45() * b.e234()
+                    - self.e1234() * b.e145()
+                    - self.e1245() * b.e314()
+                    + self.e3145() * b.e124(),
+                self.scalar() * b.e145() + self.e12() * b.e245()
+                    - self.e31() * b.e345()
+                    - self.e24() * b.e125()
+                    + self.e34() * b.e315()
+                    + self.e25() * b.e124()
+                    - self.e35() * b.e314()
+                    - self.e1234() * b.e235()
+                    + self.e1235() * b.e234()
+                    - self.e2345() * b.e123(),
+                self.scalar() * b.e245() - self.e12() * b.e145()
+                    + self.e23() * b.e345()
+                    + self.e14() * b.e125()
+                    - self.e34() * b.e235()
+                    - self.e15() * b.e124()
+                    + self.e35() * b.e234()
+                    - self.e1234() * b.e315()
+                    + self.e1235() * b.e314()
+                    - self.e3145() * b.e123(),
+                self.scalar() * b.e345() + self.e31() * b.e145()
+                    - self.e23() * b.e245()
+                    - self.e14() * b.e315()
+                    + self.e24() * b.e235()
+                    + self.e15() * b.e314()
+                    - self.e25() * b.e234()
+                    - self.e1234() * b.e125()
+                    + self.e1235() * b.e124()
+                    - self.e1245() * b.e123(),
+            ),
+            Quadvector::zero(),
+            self.e12() * b.e345()
+                + self.e31() * b.e245()
+                + self.e23() * b.e145()
+                + self.e14() * b.e235()
+                + self.e24() * b.e315()
+                + self.e34() * b.e125()
+                - self.e15() * b.e234()
+                - self.e25() * b.e314()
+                - self.e35() * b.e124()
+                + self.e45() * b.e123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Versor<F>, Trivector<F>);
+
+// Trivector-Multivector
+impl<F: Float> Mul<Multivector<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e123() * b.e123()
+                - self.e124() * b.e124()
+                - self.e314() * b.e314()
+                - self.e234() * b.e234()
+                + self.e125() * b.e125()
+                + self.e315() * b.e315()
+                + self.e235() * b.e235()
+                + self.e145() * b.e145()
+                + self.e245() * b.e245()
+                + self.e345() * b.e345(),
+            Vector::new(
+                -self.e123() * b.e23() - self.e124() * b.e24()
+                    + self.e314() * b.e34()
+                    + self.e234() * b.e1234()
+                    + self.e125() * b.e25()
+                    - self.e315() * b.e35()
+                    - self.e235() * b.e1235()
+                    + self.e145() * b.e45()
+                    - self.e245() * b.e1245()
+                    + self.e345() * b.e3145(),
+                -self.e123() * b.e31() + self.e124() * b.e14() + self.e314() * b.e1234()
+                    - self.e234() * b.e34()
+                    - self.e125() * b.e15()
+                    - self.e315() * b.e1235()
+                    + self.e235() * b.e35()
+                    + self.e145() * b.e1245()
+                    + self.e245() * b.e45()
+                    - self.e345() * b.e2345(),
+                -self.e123() * b.e12() + self.e124() * b.e1234() - self.e314() * b.e14()
+                    + self.e234() * b.e24()
+                    - self.e125() * b.e1235()
+                    + self.e315() * b.e15()
+                    - self.e235() * b.e25()
+                    - self.e145() * b.e3145()
+                    + self.e245() * b.e2345()
+                    + self.e345() * b.e45(),
+                -self.e123() * b.e1234()
+                    - self.e124() * b.e12()
+                    - self.e314() * b.e31()
+                    - self.e234() * b.e23()
+                    - self.e125() * b.e1245()
+                    - self.e315() * b.e3145()
+                    - self.e235() * b.e2345()
+                    - self.e145() * b.e15()
+                    - self.e245() * b.e25()
+                    - self.e345() * b.e35(),
+                -self.e123() * b.e1235()
+                    - self.e124() * b.e1245()
+                    - self.e314() * b.e3145()
+                    - self.e234() * b.e2345()
+                    - self.e125() * b.e12()
+                    - self.e315() * b.e31()
+                    - self.e235() * b.e23()
+                    - self.e145() * b.e14()
+                    - self.e245() * b.e24()
+                    - self.e345() * b.e34(),
+            ),
+            Bivector::new(
+                self.e123() * b.e3() + self.e124() * b.e4() + self.e314() * b.e234()
+                    - self.e234() * b.e314()
+                    - self.e125() * b.e5()
+                    - self.e315() * b.e235()
+                    + self.e235() * b.e315()
+                    + self.e145() * b.e245()
+                    - self.e245() * b.e145()
+                    + self.e345() * b.e12345(),
+                self.e123() * b.e2() - self.e124() * b.e234()
+                    + self.e314() * b.e4()
+                    + self.e234() * b.e124()
+                    + self.e125() * b.e235()
+                    - self.e315() * b.e5()
+                    - self.e235() * b.e125()
+                    - self.e145() * b.e345()
+                    + self.e245() * b.e12345()
+                    + self.e345() * b.e145(),
+                self.e123() * b.e1() + self.e124() * b.e314() - self.e314() * b.e124()
+                    + self.e234() * b.e4()
+                    - self.e125() * b.e315()
+                    + self.e315() * b.e125()
+                    - self.e235() * b.e5()
+                    + self.e145() * b.e12345()
+                    + self.e245() * b.e345()
+                    - self.e345() * b.e245(),
+                -self.e123() * b.e234() - self.e124() * b.e2()
+                    + self.e314() * b.e3()
+                    + self.e234() * b.e123()
+                    - self.e125() * b.e245()
+                    + self.e315() * b.e345()
+                    + self.e235() * b.e12345()
+                    - self.e145() * b.e5()
+                    + self.e245() * b.e125()
+                    - self.e345() * b.e315(),
+                -self.e123() * b.e314() + self.e124() * b.e1() + self.e314() * b.e123()
+                    - self.e234() * b.e3()
+                    + self.e125() * b.e145()
+                    + self.e315() * b.e12345()
+                    - self.e235() * b.e345()
+                    - self.e145() * b.e125()
+                    - self.e245() * b.e5()
+                    + self.e345() * b.e235(),
+                -self.e123() * b.e124() + self.e124() * b.e123() - self.e314() * b.e1()
+                    + self.e234() * b.e2()
+                    + self.e125() * b.e12345()
+                    - self.e315() * b.e145()
+                    + self.e235() * b.e245()
+                    + self.e145() * b.e315()
+                    - self.e245() * b.e235()
+                    - self.e345() * b.e5(),
+                -self.e123() * b.e235() - self.e124() * b.e245()
+                    + self.e314() * b.e345()
+                    + self.e234() * b.e12345()
+                    - self.e125() * b.e2()
+                    + self.e315() * b.e3()
+                    + self.e235() * b.e123()
+                    - self.e145() * b.e4()
+                    + self.e245() * b.e124()
+                    - self.e345() * b.e314(),
+                -self.e123() * b.e315() + self.e124() * b.e145() + self.e314() * b.e12345()
+                    - self.e234() * b.e345()
+                    + self.e125() * b.e1()
+                    + self.e315() * b.e123()
+                    - self.e235() * b.e3()
+                    - self.e145() * b.e124()
+                    - self.e245() * b.e4()
+                    + self.e345() * b.e234(),
+                -self.e123() * b.e125() + self.e124() * b.e12345() - self.e314() * b.e145()
+                    + self.e234() * b.e245()
+                    + self.e125() * b.e123()
+                    - self.e315() * b.e1()
+                    + self.e235() * b.e2()
+                    + self.e145() * b.e314()
+                    - self.e245() * b.e234()
+                    - self.e345() * b.e4(),
+                -self.e123() * b.e12345()
+                    - self.e124() * b.e125()
+                    - self.e314() * b.e315()
+                    - self.e234() * b.e235()
+                    + self.e125() * b.e124()
+                    + self.e315() * b.e314()
+                    + self.e235() * b.e234()
+                    + self.e145() * b.e1()
+                    + self.e245() * b.e2()
+                    + self.e345() * b.e3(),
+            ),
+            Trivector::new(
+                self.e123() * b.scalar()
+                    - self.e124() * b.e34()
+                    - self.e314() * b.e24()
+                    - self.e234() * b.e14()
+                    + self.e125() * b.e35()
+                    + self.e315() * b.e25()
+                    + self.e235() * b.e15()
+                    + self.e145() * b.e2345()
+                    + self.e245() * b.e3145()
+                    + self.e345() * b.e1245(),
+                self.e123() * b.e34() + self.e124() * b.scalar() + self.e314() * b.e23()
+                    - self.e234() * b.e31()
+                    + self.e125() * b.e45()
+                    + self.e315() * b.e2345()
+                    - self.e235() * b.e3145()
+                    - self.e145() * b.e25()
+                    + self.e245() * b.e15()
+                    - self.e345() * b.e1235(),
+                self.e123() * b.e24() - self.e124() * b.e23()
+                    + self.e314() * b.scalar()
+                    + self.e234() * b.e12()
+                    - self.e125() * b.e2345()
+                    + self.e315() * b.e45()
+                    + self.e235() * b.e1245()
+                    + self.e145() * b.e35()
+                    - self.e245() * b.e1235()
+                    - self.e345() * b.e15(),
+                self.e123() * b.e14() + self.e124() * b.e31() - self.e314() * b.e12()
+                    + self.e234() * b.scalar()
+                    + self.e125() * b.e3145()
+                    - self.e315() * b.e1245()
+                    + self.e235() * b.e45()
+                    - self.e145() * b.e1235()
+                    - self.e245() * b.e35()
+                    + self.e345() * b.e25(),
+                self.e123() * b.e35() + self.e124() * b.e45() + self.e314() * b.e2345()
+                    - self.e234() * b.e3145()
+                    + self.e125() * b.scalar()
+                    + self.e315() * b.e23()
+                    - self.e235() * b.e31()
+                    - self.e145() * b.e24()
+                    + self.e245() * b.e14()
+                    - self.e345() * b.e1234(),
+                self.e123() * b.e25() - self.e124() * b.e2345()
+                    + self.e314() * b.e45()
+                    + self.e234() * b.e1245()
+                    - self.e125() * b.e23()
+                    + self.e315() * b.scalar()
+                    + self.e235() * b.e12()
+                    + self.e145() * b.e34()
+                    - self.e245() * b.e1234()
+                    - self.e345() * b.e14(),
+                self.e123() * b.e15() + self.e124() * b.e3145() - self.e314() * b.e1245()
+                    + self.e234() * b.e45()
+                    + self.e125() * b.e31()
+                    - self.e315() * b.e12()
+                    + self.e235() * b.scalar()
+                    - self.e145() * b.e1234()
+                    - self.e245() * b.e34()
+                    + self.e345() * b.e24(),
+                -self.e123() * b.e2345() - self.e124() * b.e25()
+                    + self.e314() * b.e35()
+                    + self.e234() * b.e1235()
+                    + self.e125() * b.e24()
+                    - self.e315() * b.e34()
+                    - self.e235() * b.e1234()
+                    + self.e145() * b.scalar()
+                    - self.e245() * b.e12()
+                    + self.e345() * b.e31(),
+                -self.e123() * b.e3145() + self.e124() * b.e15() + self.e314() * b.e1235()
+                    - self.e234() * b.e35()
+                    - self.e125() * b.e14()
+                    - self.e315() * b.e1234()
+                    + self.e235() * b.e34()
+                    + self.e145() * b.e12()
+                    + self.e245() * b.scalar()
+                    - self.e345() * b.e23(),
+                -self.e123() * b.e1245() + self.e124() * b.e1235() - self.e314() * b.e15()
+                    + self.e234() * b.e25()
+                    - self.e125() * b.e1234()
+                    + self.e315() * b.e14()
+                    - self.e235() * b.e24()
+                    - self.e145() * b.e31()
+                    + self.e245() * b.e23()
+                    + self.e345() * b.scalar(),
+            ),
+            Quadvector::new(
+                self.e123() * b.e4()
+                    - self.e124() * b.e3()
+                    - self.e314() * b.e2()
+                    - self.e234() * b.e1()
+                    - self.e125() * b.e345()
+                    - self.e315() * b.e245()
+                    - self.e235() * b.e145()
+                    - self.e145() * b.e235()
+                    - self.e245() * b.e315()
+                    - self.e345() * b.e125(),
+                self.e123() * b.e5()
+                    - self.e124() * b.e345()
+                    - self.e314() * b.e245()
+                    - self.e234() * b.e145()
+                    - self.e125() * b.e3()
+                    - self.e315() * b.e2()
+                    - self.e235() * b.e1()
+                    - self.e145() * b.e234()
+                    - self.e245() * b.e314()
+                    - self.e345() * b.e124(),
+                self.e123() * b.e345() + self.e124() * b.e5() + self.e314() * b.e235()
+                    - self.e234() * b.e315()
+                    - self.e125() * b.e4()
+                    - self.e315() * b.e234()
+                    + self.e235() * b.e314()
+                    + self.e145() * b.e2()
+                    - self.e245() * b.e1()
+                    + self.e345() * b.e123(),
+                self.e123() * b.e245() - self.e124() * b.e235()
+                    + self.e314() * b.e5()
+                    + self.e234() * b.e125()
+                    + self.e125() * b.e234()
+                    - self.e315() * b.e4()
+                    - self.e235() * b.e124()
+                    - self.e145() * b.e3()
+                    + self.e245() * b.e123()
+                    + self.e345() * b.e1(),
+                self.e123() * b.e145() + self.e124() * b.e315() - self.e314() * b.e125()
+                    + self.e234() * b.e5()
+                    - self.e125() * b.e314()
+                    + self.e315() * b.e124()
+                    - self.e235() * b.e4()
+                    + self.e145() * b.e123()
+                    + self.e245() * b.e3()
+                    - self.e345() * b.e2(),
+            ),
+            self.e123() * b.e45()
+                - self.e124() * b.e35()
+                - self.e314() * b.e25()
+                - self.e234() * b.e15()
+                + self.e125() * b.e34()
+                + self.e315() * b.e24()
+                + self.e235() * b.e14()
+                + self.e145() * b.e23()
+                + self.e245() * b.e31()
+                + self.e345() * b.e12(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Trivector<F>, Multivector<F>);
+
+// Multivector-Trivector
+impl<F: Float> Mul<Trivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e123() * b.e123()
+                - self.e124() * b.e124()
+                - self.e314() * b.e314()
+                - self.e234() * b.e234()
+                + self.e125() * b.e125()
+                + self.e315() * b.e315()
+                + self.e235() * b.e235()
+                + self.e145() * b.e145()
+                + self.e245() * b.e245()
+                + self.e345() * b.e345(),
+            Vector::new(
+                -self.e23() * b.e123() - self.e24() * b.e124()
+                    + self.e34() * b.e314()
+                    + self.e25() * b.e125()
+                    - self.e35() * b.e315()
+                    + self.e45() * b.e145()
+                    - self.e1234() * b.e234()
+                    + self.e1235() * b.e235()
+                    + self.e1245() * b.e245()
+                    - self.e3145() * b.e345(),
+                -self.e31() * b.e123() + self.e14() * b.e124()
+                    - self.e34() * b.e234()
+                    - self.e15() * b.e125()
+                    + self.e35() * b.e235()
+                    + self.e45() * b.e245()
+                    - self.e1234() * b.e314()
+                    + self.e1235() * b.e315()
+                    - self.e1245() * b.e145()
+                    + self.e2345() * b.e345(),
+                -self.e12() * b.e123() - self.e14() * b.e314()
+                    + self.e24() * b.e234()
+                    + self.e15() * b.e315()
+                    - self.e25() * b.e235()
+                    + self.e45() * b.e345()
+                    - self.e1234() * b.e124()
+                    + self.e1235() * b.e125()
+                    + self.e3145() * b.e145()
+                    - self.e2345() * b.e245(),
+                -self.e12() * b.e124()
+                    - self.e31() * b.e314()
+                    - self.e23() * b.e234()
+                    - self.e15() * b.e145()
+                    - self.e25() * b.e245()
+                    - self.e35() * b.e345()
+                    + self.e1234() * b.e123()
+                    + self.e1245() * b.e125()
+                    + self.e3145() * b.e315()
+                    + self.e2345() * b.e235(),
+                -self.e12() * b.e125()
+                    - self.e31() * b.e315()
+                    - self.e23() * b.e235()
+                    - self.e14() * b.e145()
+                    - self.e24() * b.e245()
+                    - self.e34() * b.e345()
+                    + self.e1235() * b.e123()
+                    + self.e1245() * b.e124()
+                    + self.e3145() * b.e314()
+                    + self.e2345() * b.e234(),
+            ),
+            Bivector::new(
+                self.e3() * b.e123() + self.e4() * b.e124() - self.e5() * b.e125()
+                    + self.e314() * b.e234()
+                    - self.e234() * b.e314()
+                    - self.e315() * b.e235()
+                    + self.e235() * b.e315()
+                    + self.e145() * b.e245()
+                    - self.e245() * b.e145()
+                    + self.e12345() * b.e345(),
+                self.e2() * b.e123() + self.e4() * b.e314()
+                    - self.e5() * b.e315()
+                    - self.e124() * b.e234()
+                    + self.e234() * b.e124()
+                    + self.e125() * b.e235()
+                    - self.e235() * b.e125()
+                    - self.e145() * b.e345()
+                    + self.e345() * b.e145()
+                    + self.e12345() * b.e245(),
+                self.e1() * b.e123() + self.e4() * b.e234() - self.e5() * b.e235()
+                    + self.e124() * b.e314()
+                    - self.e314() * b.e124()
+                    - self.e125() * b.e315()
+                    + self.e315() * b.e125()
+                    + self.e245() * b.e345()
+                    - self.e345() * b.e245()
+                    + self.e12345() * b.e145(),
+                -self.e2() * b.e124() + self.e3() * b.e314()
+                    - self.e5() * b.e145()
+                    - self.e123() * b.e234()
+                    + self.e234() * b.e123()
+                    - self.e125() * b.e245()
+                    + self.e315() * b.e345()
+                    + self.e245() * b.e125()
+                    - self.e345() * b.e315()
+                    + self.e12345() * b.e235(),
+                self.e1() * b.e124()
+                    - self.e3() * b.e234()
+                    - self.e5() * b.e245()
+                    - self.e123() * b.e314()
+                    + self.e314() * b.e123()
+                    + self.e125() * b.e145()
+                    - self.e235() * b.e345()
+                    - self.e145() * b.e125()
+                    + self.e345() * b.e235()
+                    + self.e12345() * b.e315(),
+                -self.e1() * b.e314() + self.e2() * b.e234()
+                    - self.e5() * b.e345()
+                    - self.e123() * b.e124()
+                    + self.e124() * b.e123()
+                    - self.e315() * b.e145()
+                    + self.e235() * b.e245()
+                    + self.e145() * b.e315()
+                    - self.e245() * b.e235()
+                    + self.e12345() * b.e125(),
+                -self.e2() * b.e125() + self.e3() * b.e315()
+                    - self.e4() * b.e145()
+                    - self.e123() * b.e235()
+                    - self.e124() * b.e245()
+                    + self.e314() * b.e345()
+                    + self.e235() * b.e123()
+                    + self.e245() * b.e124()
+                    - self.e345() * b.e314()
+                    + self.e12345() * b.e234(),
+                self.e1() * b.e125()
+                    - self.e3() * b.e235()
+                    - self.e4() * b.e245()
+                    - self.e123() * b.e315()
+                    + self.e124() * b.e145()
+                    - self.e234() * b.e345()
+                    + self.e315() * b.e123()
+                    - self.e145() * b.e124()
+                    + self.e345() * b.e234()
+                    + self.e12345() * b.e314(),
+                -self.e1() * b.e315() + self.e2() * b.e235()
+                    - self.e4() * b.e345()
+                    - self.e123() * b.e125()
+                    - self.e314() * b.e145()
+                    + self.e234() * b.e245()
+                    + self.e125() * b.e123()
+                    + self.e145() * b.e314()
+                    - self.e245() * b.e234()
+                    + self.e12345() * b.e124(),
+                self.e1() * b.e145() + self.e2() * b.e245() + self.e3() * b.e345()
+                    - self.e124() * b.e125()
+                    - self.e314() * b.e315()
+                    - self.e234() * b.e235()
+                    + self.e125() * b.e124()
+                    + self.e315() * b.e314()
+                    + self.e235() * b.e234()
+                    - self.e12345() * b.e123(),
+            ),
+            Trivector::new(
+                self.scalar() * b.e123()
+                    + self.e14() * b.e234()
+                    + self.e24() * b.e314()
+                    + self.e34() * b.e124()
+                    - self.e15() * b.e235()
+                    - self.e25() * b.e315()
+                    - self.e35() * b.e125()
+                    + self.e1245() * b.e345()
+                    + self.e3145() * b.e245()
+                    + self.e2345() * b.e145(),
+                self.scalar() * b.e124() + self.e31() * b.e234()
+                    - self.e23() * b.e314()
+                    - self.e34() * b.e123()
+                    - self.e15() * b.e245()
+                    + self.e25() * b.e145()
+                    - self.e45() * b.e125()
+                    - self.e1235() * b.e345()
+                    - self.e3145() * b.e235()
+                    + self.e2345() * b.e315(),
+                self.scalar() * b.e314() - self.e12() * b.e234() + self.e23() * b.e124()
+                    - self.e24() * b.e123()
+                    + self.e15() * b.e345()
+                    - self.e35() * b.e145()
+                    - self.e45() * b.e315()
+                    - self.e1235() * b.e245()
+                    + self.e1245() * b.e235()
+                    - self.e2345() * b.e125(),
+                self.scalar() * b.e234() + self.e12() * b.e314()
+                    - self.e31() * b.e124()
+                    - self.e14() * b.e123()
+                    - self.e25() * b.e345()
+                    + self.e35() * b.e245()
+                    - self.e45() * b.e235()
+                    - self.e1235() * b.e145()
+                    - self.e1245() * b.e315()
+                    + self.e3145() * b.e125(),
+                self.scalar() * b.e125() + self.e31() * b.e235()
+                    - self.e23() * b.e315()
+                    - self.e14() * b.e245()
+                    + self.e24() * b.e145()
+                    - self.e35() * b.e123()
+                    - self.e45() * b.e124()
+                    - self.e1234() * b.e345()
+                    - self.e3145() * b.e234()
+                    + self.e2345() * b.e314(),
+                self.scalar() * b.e315() - self.e12() * b.e235()
+                    + self.e23() * b.e125()
+                    + self.e14() * b.e345()
+                    - self.e34() * b.e145()
+                    - self.e25() * b.e123()
+                    - self.e45() * b.e314()
+                    - self.e1234() * b.e245()
+                    + self.e1245() * b.e234()
+                    - self.e2345() * b.e124(),
+                self.scalar() * b.e235() + self.e12() * b.e315()
+                    - self.e31() * b.e125()
+                    - self.e24() * b.e345()
+                    + self.e34() * b.e245()
+                    - self.e15() * b.e123()
+                    - self.e45() * b.e234()
+                    - self.e1234() * b.e145()
+                    - self.e1245() * b.e314()
+                    + self.e3145() * b.e124(),
+                self.scalar() * b.e145() + self.e12() * b.e245()
+                    - self.e31() * b.e345()
+                    - self.e24() * b.e125()
+                    + self.e34() * b.e315()
+                    + self.e25() * b.e124()
+                    - self.e35() * b.e314()
+                    - self.e1234() * b.e235()
+                    + self.e1235() * b.e234()
+                    - self.e2345() * b.e123(),
+                self.scalar() * b.e245() - self.e12() * b.e145()
+                    + self.e23() * b.e345()
+                    + self.e14() * b.e125()
+                    - self.e34() * b.e235()
+                    - self.e15() * b.e124()
+                    + self.e35() * b.e234()
+                    - self.e1234() * b.e315()
+                    + self.e1235() * b.e314()
+                    - self.e3145() * b.e123(),
+                self.scalar() * b.e345() + self.e31() * b.e145()
+                    - self.e23() * b.e245()
+                    - self.e14() * b.e315()
+                    + self.e24() * b.e235()
+                    + self.e15() * b.e314()
+                    - self.e25() * b.e234()
+                    - self.e1234() * b.e125()
+                    + self.e1235() * b.e124()
+                    - self.e1245() * b.e123(),
+            ),
+            Quadvector::new(
+                self.e1() * b.e234() + self.e2() * b.e314() + self.e3() * b.e124()
+                    - self.e4() * b.e123()
+                    - self.e125() * b.e345()
+                    - self.e315() * b.e245()
+                    - self.e235() * b.e145()
+                    - self.e145() * b.e235()
+                    - self.e245() * b.e315()
+                    - self.e345() * b.e125(),
+                self.e1() * b.e235() + self.e2() * b.e315() + self.e3() * b.e125()
+                    - self.e5() * b.e123()
+                    - self.e124() * b.e345()
+                    - self.e314() * b.e245()
+                    - self.e234() * b.e145()
+                    - self.e145() * b.e234()
+                    - self.e245() * b.e314()
+                    - self.e345() * b.e124(),
+                self.e1() * b.e245() - self.e2() * b.e145() + self.e4() * b.e125()
+                    - self.e5() * b.e124()
+                    + self.e123() * b.e345()
+                    + self.e314() * b.e235()
+                    - self.e234() * b.e315()
+                    - self.e315() * b.e234()
+                    + self.e235() * b.e314()
+                    + self.e345() * b.e123(),
+                -self.e1() * b.e345() + self.e3() * b.e145() + self.e4() * b.e315()
+                    - self.e5() * b.e314()
+                    + self.e123() * b.e245()
+                    - self.e124() * b.e235()
+                    + self.e234() * b.e125()
+                    + self.e125() * b.e234()
+                    - self.e235() * b.e124()
+                    + self.e245() * b.e123(),
+                self.e2() * b.e345() - self.e3() * b.e245() + self.e4() * b.e235()
+                    - self.e5() * b.e234()
+                    + self.e123() * b.e145()
+                    + self.e124() * b.e315()
+                    - self.e314() * b.e125()
+                    - self.e125() * b.e314()
+                    + self.e315() * b.e124()
+                    + self.e145() * b.e123(),
+            ),
+            self.e12() * b.e345()
+                + self.e31() * b.e245()
+                + self.e23() * b.e145()
+                + self.e14() * b.e235()
+                + self.e24() * b.e315()
+                + self.e34() * b.e125()
+                - self.e15() * b.e234()
+                - self.e25() * b.e314()
+                - self.e35() * b.e124()
+                + self.e45() * b.e123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Trivector<F>);
+
+// Quadvector-Quadvector
+impl<F: Float> Mul for Quadvector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Quadvector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1234() * b.e1234()
+                - self.e1235() * b.e1235()
+                - self.e1245() * b.e1245()
+                - self.e3145() * b.e3145()
+                - self.e2345() * b.e2345(),
+            Vector::zero(),
+            Bivector::new(
+                self.e3145() * b.e2345() - self.e2345() * b.e3145(),
+                -self.e1245() * b.e2345() + self.e2345() * b.e1245(),
+                self.e1245() * b.e3145() - self.e3145() * b.e1245(),
+                -self.e1235() * b.e2345() + self.e2345() * b.e1235(),
+                -self.e1235() * b.e3145() + self.e3145() * b.e1235(),
+                -self.e1235() * b.e1245() + self.e1245() * b.e1235(),
+                -self.e1234() * b.e2345() + self.e2345() * b.e1234(),
+                -self.e1234() * b.e3145() + self.e3145() * b.e1234(),
+                -self.e1234() * b.e1245() + self.e1245() * b.e1234(),
+                self.e1234() * b.e1235() - self.e1235() * b.e1234(),
+            ),
+            Trivector::zero(),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Quadvector<F>, Quadvector<F>);
+
+// Quadvector-Pseudoscalar
+impl<F: Float> Mul<Pseudoscalar<F>> for Quadvector<F> {
+    type Output = Vector<F>;
+    fn mul(self, b: Pseudoscalar<F>) -> Vector<F> {
+        Vector::new(
+            -self.e2345() * b.e12345(),
+            -self.e3145() * b.e12345(),
+            -self.e1245() * b.e12345(),
+            self.e1235() * b.e12345(),
+            self.e1234() * b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Quadvector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Quadvector
+impl<F: Float> Mul<Quadvector<F>> for Pseudoscalar<F> {
+    type Output = Vector<F>;
+    fn mul(self, b: Quadvector<F>) -> Vector<F> {
+        Vector::new(
+            -self.e12345() * b.e2345(),
+            -self.e12345() * b.e3145(),
+            -self.e12345() * b.e1245(),
+            self.e12345() * b.e1235(),
+            self.e12345() * b.e1234(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Quadvector<F>);
+
+// Quadvector-Versor
+impl<F: Float> Mul<Versor<F>> for Quadvector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1234() * b.e1234()
+                - self.e1235() * b.e1235()
+                - self.e1245() * b.e1245()
+                - self.e3145() * b.e3145()
+                - self.e2345() * b.e2345(),
+            Vector::zero(),
+            Bivector::new(
+                -self.e1234() * b.e34()
+                    + self.e1235() * b.e35()
+                    + self.e1245() * b.e45()
+                    + self.e3145() * b.e2345()
+                    - self.e2345() * b.e3145(),
+                -self.e1234() * b.e24() + self.e1235() * b.e25() - self.e1245() * b.e2345()
+                    + self.e3145() * b.e45()
+                    + self.e2345() * b.e1245(),
+                -self.e1234() * b.e14() + self.e1235() * b.e15() + self.e1245() * b.e3145()
+                    - self.e3145() * b.e1245()
+                    + self.e2345() * b.e45(),
+                -self.e1234() * b.e23() - self.e1235() * b.e2345() - self.e1245() * b.e25()
+                    + self.e3145() * b.e35()
+                    + self.e2345() * b.e1235(),
+                -self.e1234() * b.e31() - self.e1235() * b.e3145()
+                    + self.e1245() * b.e15()
+                    + self.e3145() * b.e1235()
+                    - self.e2345() * b.e35(),
+                -self.e1234() * b.e12() - self.e1235() * b.e1245() + self.e1245() * b.e1235()
+                    - self.e3145() * b.e15()
+                    + self.e2345() * b.e25(),
+                -self.e1234() * b.e2345() - self.e1235() * b.e23() - self.e1245() * b.e24()
+                    + self.e3145() * b.e34()
+                    + self.e2345() * b.e1234(),
+                -self.e1234() * b.e3145() - self.e1235() * b.e31()
+                    + self.e1245() * b.e14()
+                    + self.e3145() * b.e1234()
+                    - self.e2345() * b.e34(),
+                -self.e1234() * b.e1245() - self.e1235() * b.e12() + self.e1245() * b.e1234()
+                    - self.e3145() * b.e14()
+                    + self.e2345() * b.e24(),
+                self.e1234() * b.e1235()
+                    - self.e1235() * b.e1234()
+                    - self.e1245() * b.e12()
+                    - self.e3145() * b.e31()
+                    - self.e2345() * b.e23(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e1234() * b.scalar() + self.e1235() * b.e45()
+                    - self.e1245() * b.e35()
+                    - self.e3145() * b.e25()
+                    - self.e2345() * b.e15(),
+                self.e1234() * b.e45() + self.e1235() * b.scalar()
+                    - self.e1245() * b.e34()
+                    - self.e3145() * b.e24()
+                    - self.e2345() * b.e14(),
+                -self.e1234() * b.e35()
+                    + self.e1235() * b.e34()
+                    + self.e1245() * b.scalar()
+                    + self.e3145() * b.e23()
+                    - self.e2345() * b.e31(),
+                -self.e1234() * b.e25() + self.e1235() * b.e24() - self.e1245() * b.e23()
+                    + self.e3145() * b.scalar()
+                    + self.e2345() * b.e12(),
+                -self.e1234() * b.e15() + self.e1235() * b.e14() + self.e1245() * b.e31()
+                    - self.e3145() * b.e12()
+                    + self.e2345() * b.scalar(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Quadvector<F>, Versor<F>);
+
+// Versor-Quadvector
+impl<F: Float> Mul<Quadvector<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Quadvector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1234() * b.e1234()
+                - self.e1235() * b.e1235()
+                - self.e1245() * b.e1245()
+                - self.e3145() * b.e3145()
+                - self.e2345() * b.e2345(),
+            Vector::zero(),
+            Bivector::new(
+                -self.e34() * b.e1234()
+                    + self.e35() * b.e1235()
+                    + self.e45() * b.e1245()
+                    + self.e3145() * b.e2345()
+                    - self.e2345() * b.e3145(),
+                -self.e24() * b.e1234() + self.e25() * b.e1235() + self.e45() * b.e3145()
+                    - self.e1245() * b.e2345()
+                    + self.e2345() * b.e1245(),
+                -self.e14() * b.e1234()
+                    + self.e15() * b.e1235()
+                    + self.e45() * b.e2345()
+                    + self.e1245() * b.e3145()
+                    - self.e3145() * b.e1245(),
+                -self.e23() * b.e1234() - self.e25() * b.e1245() + self.e35() * b.e3145()
+                    - self.e1235() * b.e2345()
+                    + self.e2345() * b.e1235(),
+                -self.e31() * b.e1234() + self.e15() * b.e1245()
+                    - self.e35() * b.e2345()
+                    - self.e1235() * b.e3145()
+                    + self.e3145() * b.e1235(),
+                -self.e12() * b.e1234() - self.e15() * b.e3145() + self.e25() * b.e2345()
+                    - self.e1235() * b.e1245()
+                    + self.e1245() * b.e1235(),
+                -self.e23() * b.e1235() - self.e24() * b.e1245() + self.e34() * b.e3145()
+                    - self.e1234() * b.e2345()
+                    + self.e2345() * b.e1234(),
+                -self.e31() * b.e1235() + self.e14() * b.e1245()
+                    - self.e34() * b.e2345()
+                    - self.e1234() * b.e3145()
+                    + self.e3145() * b.e1234(),
+                -self.e12() * b.e1235() - self.e14() * b.e3145() + self.e24() * b.e2345()
+                    - self.e1234() * b.e1245()
+                    + self.e1245() * b.e1234(),
+                -self.e12() * b.e1245() - self.e31() * b.e3145() - self.e23() * b.e2345()
+                    + self.e1234() * b.e1235()
+                    - self.e1235() * b.e1234(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.scalar() * b.e1234()
+                    + self.e15() * b.e2345()
+                    + self.e25() * b.e3145()
+                    + self.e35() * b.e1245()
+                    - self.e45() * b.e1235(),
+                self.scalar() * b.e1235()
+                    + self.e14() * b.e2345()
+                    + self.e24() * b.e3145()
+                    + self.e34() * b.e1245()
+                    - self.e45() * b.e1234(),
+                self.scalar() * b.e1245() + self.e31() * b.e2345()
+                    - self.e23() * b.e3145()
+                    - self.e34() * b.e1235()
+                    + self.e35() * b.e1234(),
+                self.scalar() * b.e3145() - self.e12() * b.e2345() + self.e23() * b.e1245()
+                    - self.e24() * b.e1235()
+                    + self.e25() * b.e1234(),
+                self.scalar() * b.e2345() + self.e12() * b.e3145()
+                    - self.e31() * b.e1245()
+                    - self.e14() * b.e1235()
+                    + self.e15() * b.e1234(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Versor<F>, Quadvector<F>);
+
+// Quadvector-Multivector
+impl<F: Float> Mul<Multivector<F>> for Quadvector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1234() * b.e1234()
+                - self.e1235() * b.e1235()
+                - self.e1245() * b.e1245()
+                - self.e3145() * b.e3145()
+                - self.e2345() * b.e2345(),
+            Vector::new(
+                -self.e1234() * b.e234() + self.e1235() * b.e235() + self.e1245() * b.e245()
+                    - self.e3145() * b.e345()
+                    - self.e2345() * b.e12345(),
+                -self.e1234() * b.e314() + self.e1235() * b.e315()
+                    - self.e1245() * b.e145()
+                    - self.e3145() * b.e12345()
+                    + self.e2345() * b.e345(),
+                -self.e1234() * b.e124() + self.e1235() * b.e125() - self.e1245() * b.e12345()
+                    + self.e3145() * b.e145()
+                    - self.e2345() * b.e245(),
+                self.e1234() * b.e123()
+                    + self.e1235() * b.e12345()
+                    + self.e1245() * b.e125()
+                    + self.e3145() * b.e315()
+                    + self.e2345() * b.e235(),
+                self.e1234() * b.e12345()
+                    + self.e1235() * b.e123()
+                    + self.e1245() * b.e124()
+                    + self.e3145() * b.e314()
+                    + self.e2345() * b.e234(),
+            ),
+            Bivector::new(
+                -self.e1234() * b.e34()
+                    + self.e1235() * b.e35()
+                    + self.e1245() * b.e45()
+                    + self.e3145() * b.e2345()
+                    - self.e2345() * b.e3145(),
+                -self.e1234() * b.e24() + self.e1235() * b.e25() - self.e1245() * b.e2345()
+                    + self.e3145() * b.e45()
+                    + self.e2345() * b.e1245(),
+                -self.e1234() * b.e14() + self.e1235() * b.e15() + self.e1245() * b.e3145()
+                    - self.e3145() * b.e1245()
+                    + self.e2345() * b.e45(),
+                -self.e1234() * b.e23() - self.e1235() * b.e2345() - self.e1245() * b.e25()
+                    + self.e3145() * b.e35()
+                    + self.e2345() * b.e1235(),
+                -self.e1234() * b.e31() - self.e1235() * b.e3145()
+                    + self.e1245() * b.e15()
+                    + self.e3145() * b.e1235()
+                    - self.e2345() * b.e35(),
+                -self.e1234() * b.e12() - self.e1235() * b.e1245() + self.e1245() * b.e1235()
+                    - self.e3145() * b.e15()
+                    + self.e2345() * b.e25(),
+                -self.e1234() * b.e2345() - self.e1235() * b.e23() - self.e1245() * b.e24()
+                    + self.e3145() * b.e34()
+                    + self.e2345() * b.e1234(),
+                -self.e1234() * b.e3145() - self.e1235() * b.e31()
+                    + self.e1245() * b.e14()
+                    + self.e3145() * b.e1234()
+                    - self.e2345() * b.e34(),
+                -self.e1234() * b.e1245() - self.e1235() * b.e12() + self.e1245() * b.e1234()
+                    - self.e3145() * b.e14()
+                    + self.e2345() * b.e24(),
+                self.e1234() * b.e1235()
+                    - self.e1235() * b.e1234()
+                    - self.e1245() * b.e12()
+                    - self.e3145() * b.e31()
+                    - self.e2345() * b.e23(),
+            ),
+            Trivector::new(
+                self.e1234() * b.e4() - self.e1235() * b.e5()
+                    + self.e1245() * b.e345()
+                    + self.e3145() * b.e245()
+                    + self.e2345() * b.e145(),
+                -self.e1234() * b.e3()
+                    - self.e1235() * b.e345()
+                    - self.e1245() * b.e5()
+                    - self.e3145() * b.e235()
+                    + self.e2345() * b.e315(),
+                -self.e1234() * b.e2() - self.e1235() * b.e245() + self.e1245() * b.e235()
+                    - self.e3145() * b.e5()
+                    - self.e2345() * b.e125(),
+                -self.e1234() * b.e1() - self.e1235() * b.e145() - self.e1245() * b.e315()
+                    + self.e3145() * b.e125()
+                    - self.e2345() * b.e5(),
+                -self.e1234() * b.e345()
+                    - self.e1235() * b.e3()
+                    - self.e1245() * b.e4()
+                    - self.e3145() * b.e234()
+                    + self.e2345() * b.e314(),
+                -self.e1234() * b.e245() - self.e1235() * b.e2() + self.e1245() * b.e234()
+                    - self.e3145() * b.e4()
+                    - self.e2345() * b.e124(),
+                -self.e1234() * b.e145() - self.e1235() * b.e1() - self.e1245() * b.e314()
+                    + self.e3145() * b.e124()
+                    - self.e2345() * b.e4(),
+                -self.e1234() * b.e235() + self.e1235() * b.e234() + self.e1245() * b.e2()
+                    - self.e3145() * b.e3()
+                    - self.e2345() * b.e123(),
+                -self.e1234() * b.e315() + self.e1235() * b.e314()
+                    - self.e1245() * b.e1()
+                    - self.e3145() * b.e123()
+                    + self.e2345() * b.e3(),
+                -self.e1234() * b.e125() + self.e1235() * b.e124() - self.e1245() * b.e123()
+                    + self.e3145() * b.e1()
+                    - self.e2345() * b.e2(),
+            ),
+            Quadvector::new(
+                self.e1234() * b.scalar() + self.e1235() * b.e45()
+                    - self.e1245() * b.e35()
+                    - self.e3145() * b.e25()
+                    - self.e2345() * b.e15(),
+                self.e1234() * b.e45() + self.e1235() * b.scalar()
+                    - self.e1245() * b.e34()
+                    - self.e3145() * b.e24()
+                    - self.e2345() * b.e14(),
+                -self.e1234() * b.e35()
+                    + self.e1235() * b.e34()
+                    + self.e1245() * b.scalar()
+                    + self.e3145() * b.e23()
+                    - self.e2345() * b.e31(),
+                -self.e1234() * b.e25() + self.e1235() * b.e24() - self.e1245() * b.e23()
+                    + self.e3145() * b.scalar()
+                    + self.e2345() * b.e12(),
+                -self.e1234() * b.e15() + self.e1235() * b.e14() + self.e1245() * b.e31()
+                    - self.e3145() * b.e12()
+                    + self.e2345() * b.scalar(),
+            ),
+            self.e1234() * b.e5() - self.e1235() * b.e4()
+                + self.e1245() * b.e3()
+                + self.e3145() * b.e2()
+                + self.e2345() * b.e1(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Quadvector<F>, Multivector<F>);
+
+// Multivector-Quadvector
+impl<F: Float> Mul<Quadvector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Quadvector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1234() * b.e1234()
+                - self.e1235() * b.e1235()
+                - self.e1245() * b.e1245()
+                - self.e3145() * b.e3145()
+                - self.e2345() * b.e2345(),
+            Vector::new(
+                self.e234() * b.e1234() - self.e235() * b.e1235() - self.e245() * b.e1245()
+                    + self.e345() * b.e3145()
+                    - self.e12345() * b.e2345(),
+                self.e314() * b.e1234() - self.e315() * b.e1235() + self.e145() * b.e1245()
+                    - self.e345() * b.e2345()
+                    - self.e12345() * b.e3145(),
+                self.e124() * b.e1234() - self.e125() * b.e1235() - self.e145() * b.e3145()
+                    + self.e245() * b.e2345()
+                    - self.e12345() * b.e1245(),
+                -self.e123() * b.e1234()
+                    - self.e125() * b.e1245()
+                    - self.e315() * b.e3145()
+                    - self.e235() * b.e2345()
+                    + self.e12345() * b.e1235(),
+                -self.e123() * b.e1235()
+                    - self.e124() * b.e1245()
+                    - self.e314() * b.e3145()
+                    - self.e234() * b.e2345()
+                    + self.e12345() * b.e1234(),
+            ),
+            Bivector::new(
+                -self.e34() * b.e1234()
+                    + self.e35() * b.e1235()
+                    + self.e45() * b.e1245()
+                    + self.e3145() * b.e2345()
+                    - self.e2345() * b.e3145(),
+                -self.e24() * b.e1234() + self.e25() * b.e1235() + self.e45() * b.e3145()
+                    - self.e1245() * b.e2345()
+                    + self.e2345() * b.e1245(),
+                -self.e14() * b.e1234()
+                    + self.e15() * b.e1235()
+                    + self.e45() * b.e2345()
+                    + self.e1245() * b.e3145()
+                    - self.e3145() * b.e1245(),
+                -self.e23() * b.e1234() - self.e25() * b.e1245() + self.e35() * b.e3145()
+                    - self.e1235() * b.e2345()
+                    + self.e2345() * b.e1235(),
+                -self.e31() * b.e1234() + self.e15() * b.e1245()
+                    - self.e35() * b.e2345()
+                    - self.e1235() * b.e3145()
+                    + self.e3145() * b.e1235(),
+                -self.e12() * b.e1234() - self.e15() * b.e3145() + self.e25() * b.e2345()
+                    - self.e1235() * b.e1245()
+                    + self.e1245() * b.e1235(),
+                -self.e23() * b.e1235() - self.e24() * b.e1245() + self.e34() * b.e3145()
+                    - self.e1234() * b.e2345()
+                    + self.e2345() * b.e1234(),
+                -self.e31() * b.e1235() + self.e14() * b.e1245()
+                    - self.e34() * b.e2345()
+                    - self.e1234() * b.e3145()
+                    + self.e3145() * b.e1234(),
+                -self.e12() * b.e1235() - self.e14() * b.e3145() + self.e24() * b.e2345()
+                    - self.e1234() * b.e1245()
+                    + self.e1245() * b.e1234(),
+                -self.e12() * b.e1245() - self.e31() * b.e3145() - self.e23() * b.e2345()
+                    + self.e1234() * b.e1235()
+                    - self.e1235() * b.e1234(),
+            ),
+            Trivector::new(
+                -self.e4() * b.e1234()
+                    + self.e5() * b.e1235()
+                    + self.e145() * b.e2345()
+                    + self.e245() * b.e3145()
+                    + self.e345() * b.e1245(),
+                self.e3() * b.e1234() + self.e5() * b.e1245() + self.e315() * b.e2345()
+                    - self.e235() * b.e3145()
+                    - self.e345() * b.e1235(),
+                self.e2() * b.e1234() + self.e5() * b.e3145() - self.e125() * b.e2345()
+                    + self.e235() * b.e1245()
+                    - self.e245() * b.e1235(),
+                self.e1() * b.e1234() + self.e5() * b.e2345() + self.e125() * b.e3145()
+                    - self.e315() * b.e1245()
+                    - self.e145() * b.e1235(),
+                self.e3() * b.e1235() + self.e4() * b.e1245() + self.e314() * b.e2345()
+                    - self.e234() * b.e3145()
+                    - self.e345() * b.e1234(),
+                self.e2() * b.e1235() + self.e4() * b.e3145() - self.e124() * b.e2345()
+                    + self.e234() * b.e1245()
+                    - self.e245() * b.e1234(),
+                self.e1() * b.e1235() + self.e4() * b.e2345() + self.e124() * b.e3145()
+                    - self.e314() * b.e1245()
+                    - self.e145() * b.e1234(),
+                -self.e2() * b.e1245() + self.e3() * b.e3145() - self.e123() * b.e2345()
+                    + self.e234() * b.e1235()
+                    - self.e235() * b.e1234(),
+                self.e1() * b.e1245() - self.e3() * b.e2345() - self.e123() * b.e3145()
+                    + self.e314() * b.e1235()
+                    - self.e315() * b.e1234(),
+                -self.e1() * b.e3145() + self.e2() * b.e2345() - self.e123() * b.e1245()
+                    + self.e124() * b.e1235()
+                    - self.e125() * b.e1234(),
+            ),
+            Quadvector::new(
+                self.scalar() * b.e1234()
+                    + self.e15() * b.e2345()
+                    + self.e25() * b.e3145()
+                    + self.e35() * b.e1245()
+                    - self.e45() * b.e1235(),
+                self.scalar() * b.e1235()
+                    + self.e14() * b.e2345()
+                    + self.e24() * b.e3145()
+                    + self.e34() * b.e1245()
+                    - self.e45() * b.e1234(),
+                self.scalar() * b.e1245() + self.e31() * b.e2345()
+                    - self.e23() * b.e3145()
+                    - self.e34() * b.e1235()
+                    + self.e35() * b.e1234(),
+                self.scalar() * b.e3145() - self.e12() * b.e2345() + self.e23() * b.e1245()
+                    - self.e24() * b.e1235()
+                    + self.e25() * b.e1234(),
+                self.scalar() * b.e2345() + self.e12() * b.e3145()
+                    - self.e31() * b.e1245()
+                    - self.e14() * b.e1235()
+                    + self.e15() * b.e1234(),
+            ),
+            self.e1() * b.e2345() + self.e2() * b.e3145() + self.e3() * b.e1245()
+                - self.e4() * b.e1235()
+                + self.e5() * b.e1234(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Quadvector<F>);
+
+// Pseudoscalar-Pseudoscalar
+impl<F: Float> Mul for Pseudoscalar<F> {
+    type Output = Scalar<F>;
+    fn mul(self, b: Pseudoscalar<F>) -> Scalar<F> {
+        Scalar(-self.e12345() * b.e12345())
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Versor
+impl<F: Float> Mul<Versor<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                -self.e12345() * b.e2345(),
+                -self.e12345() * b.e3145(),
+                -self.e12345() * b.e1245(),
+                self.e12345() * b.e1235(),
+                self.e12345() * b.e1234(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                self.e12345() * b.e45(),
+                -self.e12345() * b.e35(),
+                -self.e12345() * b.e25(),
+                -self.e12345() * b.e15(),
+                -self.e12345() * b.e34(),
+                -self.e12345() * b.e24(),
+                -self.e12345() * b.e14(),
+                -self.e12345() * b.e23(),
+                -self.e12345() * b.e31(),
+                -self.e12345() * b.e12(),
+            ),
+            Quadvector::zero(),
+            self.e12345() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Versor<F>);
+
+// Versor-Pseudoscalar
+impl<F: Float> Mul<Pseudoscalar<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                -self.e2345() * b.e12345(),
+                -self.e3145() * b.e12345(),
+                -self.e1245() * b.e12345(),
+                self.e1235() * b.e12345(),
+                self.e1234() * b.e12345(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                self.e45() * b.e12345(),
+                -self.e35() * b.e12345(),
+                -self.e25() * b.e12345(),
+                -self.e15() * b.e12345(),
+                -self.e34() * b.e12345(),
+                -self.e24() * b.e12345(),
+                -self.e14() * b.e12345(),
+                -self.e23() * b.e12345(),
+                -self.e31() * b.e12345(),
+                -self.e12() * b.e12345(),
+            ),
+            Quadvector::zero(),
+            self.scalar() * b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Versor<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Multivector
+impl<F: Float> Mul<Multivector<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12345() * b.e12345(),
+            Vector::new(
+                -self.e12345() * b.e2345(),
+                -self.e12345() * b.e3145(),
+                -self.e12345() * b.e1245(),
+                self.e12345() * b.e1235(),
+                self.e12345() * b.e1234(),
+            ),
+            Bivector::new(
+                self.e12345() * b.e345(),
+                self.e12345() * b.e245(),
+                self.e12345() * b.e145(),
+                self.e12345() * b.e235(),
+                self.e12345() * b.e315(),
+                self.e12345() * b.e125(),
+                self.e12345() * b.e234(),
+                self.e12345() * b.e314(),
+                self.e12345() * b.e124(),
+                -self.e12345() * b.e123(),
+            ),
+            Trivector::new(
+                self.e12345() * b.e45(),
+                -self.e12345() * b.e35(),
+                -self.e12345() * b.e25(),
+                -self.e12345() * b.e15(),
+                -self.e12345() * b.e34(),
+                -self.e12345() * b.e24(),
+                -self.e12345() * b.e14(),
+                -self.e12345() * b.e23(),
+                -self.e12345() * b.e31(),
+                -self.e12345() * b.e12(),
+            ),
+            Quadvector::new(
+                -self.e12345() * b.e5(),
+                -self.e12345() * b.e4(),
+                self.e12345() * b.e3(),
+                self.e12345() * b.e2(),
+                self.e12345() * b.e1(),
+            ),
+            self.e12345() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Multivector<F>);
+
+// Multivector-Pseudoscalar
+impl<F: Float> Mul<Pseudoscalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12345() * b.e12345(),
+            Vector::new(
+                -self.e2345() * b.e12345(),
+                -self.e3145() * b.e12345(),
+                -self.e1245() * b.e12345(),
+                self.e1235() * b.e12345(),
+                self.e1234() * b.e12345(),
+            ),
+            Bivector::new(
+                self.e345() * b.e12345(),
+                self.e245() * b.e12345(),
+                self.e145() * b.e12345(),
+                self.e235() * b.e12345(),
+                self.e315() * b.e12345(),
+                self.e125() * b.e12345(),
+                self.e234() * b.e12345(),
+                self.e314() * b.e12345(),
+                self.e124() * b.e12345(),
+                -self.e123() * b.e12345(),
+            ),
+            Trivector::new(
+                self.e45() * b.e12345(),
+                -self.e35() * b.e12345(),
+                -self.e25() * b.e12345(),
+                -self.e15() * b.e12345(),
+                -self.e34() * b.e12345(),
+                -self.e24() * b.e12345(),
+                -self.e14() * b.e12345(),
+                -self.e23() * b.e12345(),
+                -self.e31() * b.e12345(),
+                -self.e12() * b.e12345(),
+            ),
+            Quadvector::new(
+                -self.e5() * b.e12345(),
+                -self.e4() * b.e12345(),
+                self.e3() * b.e12345(),
+                self.e2() * b.e12345(),
+                self.e1() * b.e12345(),
+            ),
+            self.scalar() * b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Pseudoscalar<F>);
+
+// Versor-Multivector
+impl<F: Float> Mul<Multivector<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar()
+                - self.e12() * b.e12()
+                - self.e31() * b.e31()
+                - self.e23() * b.e23()
+                - self.e14() * b.e14()
+                - self.e24() * b.e24()
+                - self.e34() * b.e34()
+                + self.e15() * b.e15()
+                + self.e25() * b.e25()
+                + self.e35() * b.e35()
+                + self.e45() * b.e45()
+                + self.e1234() * b.e1234()
+                - self.e1235() * b.e1235()
+                - self.e1245() * b.e1245()
+                - self.e3145() * b.e3145()
+                - self.e2345() * b.e2345(),
+            Vector::new(
+                self.scalar() * b.e1() + self.e12() * b.e2()
+                    - self.e31() * b.e3()
+                    - self.e23() * b.e123()
+                    + self.e14() * b.e4()
+                    - self.e24() * b.e124()
+                    + self.e34() * b.e314()
+                    - self.e15() * b.e5()
+                    + self.e25() * b.e125()
+                    - self.e35() * b.e315()
+                    + self.e45() * b.e145()
+                    - self.e1234() * b.e234()
+                    + self.e1235() * b.e235()
+                    + self.e1245() * b.e245()
+                    - self.e3145() * b.e345()
+                    - self.e2345() * b.e12345(),
+                self.scalar() * b.e2() - self.e12() * b.e1() - self.e31() * b.e123()
+                    + self.e23() * b.e3()
+                    + self.e14() * b.e124()
+                    + self.e24() * b.e4()
+                    - self.e34() * b.e234()
+                    - self.e15() * b.e125()
+                    - self.e25() * b.e5()
+                    + self.e35() * b.e235()
+                    + self.e45() * b.e245()
+                    - self.e1234() * b.e314()
+                    + self.e1235() * b.e315()
+                    - self.e1245() * b.e145()
+                    - self.e3145() * b.e12345()
+                    + self.e2345() * b.e345(),
+                self.scalar() * b.e3() - self.e12() * b.e123() + self.e31() * b.e1()
+                    - self.e23() * b.e2()
+                    - self.e14() * b.e314()
+                    + self.e24() * b.e234()
+                    + self.e34() * b.e4()
+                    + self.e15() * b.e315()
+                    - self.e25() * b.e235()
+                    - self.e35() * b.e5()
+                    + self.e45() * b.e345()
+                    - self.e1234() * b.e124()
+                    + self.e1235() * b.e125()
+                    - self.e1245() * b.e12345()
+                    + self.e3145() * b.e145()
+                    - self.e2345() * b.e245(),
+                self.scalar() * b.e4()
+                    - self.e12() * b.e124()
+                    - self.e31() * b.e314()
+                    - self.e23() * b.e234()
+                    - self.e14() * b.e1()
+                    - self.e24() * b.e2()
+                    - self.e34() * b.e3()
+                    - self.e15() * b.e145()
+                    - self.e25() * b.e245()
+                    - self.e35() * b.e345()
+                    - self.e45() * b.e5()
+                    + self.e1234() * b.e123()
+                    + self.e1235() * b.e12345()
+                    + self.e1245() * b.e125()
+                    + self.e3145() * b.e315()
+                    + self.e2345() * b.e235(),
+                self.scalar() * b.e5()
+                    - self.e12() * b.e125()
+                    - self.e31() * b.e315()
+                    - self.e23() * b.e235()
+                    - self.e14() * b.e145()
+                    - self.e24() * b.e245()
+                    - self.e34() * b.e345()
+                    - self.e15() * b.e1()
+                    - self.e25() * b.e2()
+                    - self.e35() * b.e3()
+                    - self.e45() * b.e4()
+                    + self.e1234() * b.e12345()
+                    + self.e1235() * b.e123()
+                    + self.e1245() * b.e124()
+                    + self.e3145() * b.e314()
+                    + self.e2345() * b.e234(),
+            ),
+            Bivector::new(
+                self.scalar() * b.e12() + self.e12() * b.scalar() + self.e31() * b.e23()
+                    - self.e23() * b.e31()
+                    - self.e14() * b.e24()
+                    + self.e24() * b.e14()
+                    - self.e34() * b.e1234()
+                    + self.e15() * b.e25()
+                    - self.e25() * b.e15()
+                    + self.e35() * b.e1235()
+                    + self.e45() * b.e1245()
+                    - self.e1234() * b.e34()
+                    + self.e1235() * b.e35()
+                    + self.e1245() * b.e45()
+                    + self.e3145() * b.e2345()
+                    - self.e2345() * b.e3145(),
+                self.scalar() * b.e31() - self.e12() * b.e23()
+                    + self.e31() * b.scalar()
+                    + self.e23() * b.e12()
+                    + self.e14() * b.e34()
+                    - self.e24() * b.e1234()
+                    - self.e34() * b.e14()
+                    - self.e15() * b.e35()
+                    + self.e25() * b.e1235()
+                    + self.e35() * b.e15()
+                    + self.e45() * b.e3145()
+                    - self.e1234() * b.e24()
+                    + self.e1235() * b.e25()
+                    - self.e1245() * b.e2345()
+                    + self.e3145() * b.e45()
+                    + self.e2345() * b.e1245(),
+                self.scalar() * b.e23() + self.e12() * b.e31() - self.e31() * b.e12()
+                    + self.e23() * b.scalar()
+                    - self.e14() * b.e1234()
+                    - self.e24() * b.e34()
+                    + self.e34() * b.e24()
+                    + self.e15() * b.e1235()
+                    + self.e25() * b.e35()
+                    - self.e35() * b.e25()
+                    + self.e45() * b.e2345()
+                    - self.e1234() * b.e14()
+                    + self.e1235() * b.e15()
+                    + self.e1245() * b.e3145()
+                    - self.e3145() * b.e1245()
+                    + self.e2345() * b.e45(),
+                self.scalar() * b.e14() + self.e12() * b.e24()
+                    - self.e31() * b.e34()
+                    - self.e23() * b.e1234()
+                    + self.e14() * b.scalar()
+                    - self.e24() * b.e12()
+                    + self.e34() * b.e31()
+                    + self.e15() * b.e45()
+                    - self.e25() * b.e1245()
+                    + self.e35() * b.e3145()
+                    - self.e45() * b.e15()
+                    - self.e1234() * b.e23()
+                    - self.e1235() * b.e2345()
+                    - self.e1245() * b.e25()
+                    + self.e3145() * b.e35()
+                    + self.e2345() * b.e1235(),
+                self.scalar() * b.e24() - self.e12() * b.e14() - self.e31() * b.e1234()
+                    + self.e23() * b.e34()
+                    + self.e14() * b.e12()
+                    + self.e24() * b.scalar()
+                    - self.e34() * b.e23()
+                    + self.e15() * b.e1245()
+                    + self.e25() * b.e45()
+                    - self.e35() * b.e2345()
+                    - self.e45() * b.e25()
+                    - self.e1234() * b.e31()
+                    - self.e1235() * b.e3145()
+                    + self.e1245() * b.e15()
+                    + self.e3145() * b.e1235()
+                    - self.e2345() * b.e35(),
+                self.scalar() * b.e34() - self.e12() * b.e1234() + self.e31() * b.e14()
+                    - self.e23() * b.e24()
+                    - self.e14() * b.e31()
+                    + self.e24() * b.e23()
+                    + self.e34() * b.scalar()
+                    - self.e15() * b.e3145()
+                    + self.e25() * b.e2345()
+                    + self.e35() * b.e45()
+                    - self.e45() * b.e35()
+                    - self.e1234() * b.e12()
+                    - self.e1235() * b.e1245()
+                    + self.e1245() * b.e1235()
+                    - self.e3145() * b.e15()
+                    + self.e2345() * b.e25(),
+                self.scalar() * b.e15() + self.e12() * b.e25()
+                    - self.e31() * b.e35()
+                    - self.e23() * b.e1235()
+                    + self.e14() * b.e45()
+                    - self.e24() * b.e1245()
+                    + self.e34() * b.e3145()
+                    + self.e15() * b.scalar()
+                    - self.e25() * b.e12()
+                    + self.e35() * b.e31()
+                    - self.e45() * b.e14()
+                    - self.e1234() * b.e2345()
+                    - self.e1235() * b.e23()
+                    - self.e1245() * b.e24()
+                    + self.e3145() * b.e34()
+                    + self.e2345() * b.e1234(),
+                self.scalar() * b.e25() - self.e12() * b.e15() - self.e31() * b.e1235()
+                    + self.e23() * b.e35()
+                    + self.e14() * b.e1245()
+                    + self.e24() * b.e45()
+                    - self.e34() * b.e2345()
+                    + self.e15() * b.e12()
+                    + self.e25() * b.scalar()
+                    - self.e35() * b.e23()
+                    - self.e45() * b.e24()
+                    - self.e1234() * b.e3145()
+                    - self.e1235() * b.e31()
+                    + self.e1245() * b.e14()
+                    + self.e3145() * b.e1234()
+                    - self.e2345() * b.e34(),
+                self.scalar() * b.e35() - self.e12() * b.e1235() + self.e31() * b.e15()
+                    - self.e23() * b.e25()
+                    - self.e14() * b.e3145()
+                    + self.e24() * b.e2345()
+                    + self.e34() * b.e45()
+                    - self.e15() * b.e31()
+                    + self.e25() * b.e23()
+                    + self.e35() * b.scalar()
+                    - self.e45() * b.e34()
+                    - self.e1234() * b.e1245()
+                    - self.e1235() * b.e12()
+                    + self.e1245() * b.e1234()
+                    - self.e3145() * b.e14()
+                    + self.e2345() * b.e24(),
+                self.scalar() * b.e45()
+                    - self.e12() * b.e1245()
+                    - self.e31() * b.e3145()
+                    - self.e23() * b.e2345()
+                    - self.e14() * b.e15()
+                    - self.e24() * b.e25()
+                    - self.e34() * b.e35()
+                    + self.e15() * b.e14()
+                    + self.e25() * b.e24()
+                    + self.e35() * b.e34()
+                    + self.e45() * b.scalar()
+                    + self.e1234() * b.e1235()
+                    - self.e1235() * b.e1234()
+                    - self.e1245() * b.e12()
+                    - self.e3145() * b.e31()
+                    - self.e2345() * b.e23(),
+            ),
+            Trivector::new(
+                self.scalar() * b.e123()
+                    + self.e12() * b.e3()
+                    + self.e31() * b.e2()
+                    + self.e23() * b.e1()
+                    + self.e14() * b.e234()
+                    + self.e24() * b.e314()
+                    + self.e34() * b.e124()
+                    - self.e15() * b.e235()
+                    - self.e25() * b.e315()
+                    - self.e35() * b.e125()
+                    + self.e45() * b.e12345()
+                    + self.e1234() * b.e4()
+                    - self.e1235() * b.e5()
+                    + self.e1245() * b.e345()
+                    + self.e3145() * b.e245()
+                    + self.e2345() * b.e145(),
+                self.scalar() * b.e124() + self.e12() * b.e4() + self.e31() * b.e234()
+                    - self.e23() * b.e314()
+                    - self.e14() * b.e2()
+                    + self.e24() * b.e1()
+                    - self.e34() * b.e123()
+                    - self.e15() * b.e245()
+                    + self.e25() * b.e145()
+                    - self.e35() * b.e12345()
+                    - self.e45() * b.e125()
+                    - self.e1234() * b.e3()
+                    - self.e1235() * b.e345()
+                    - self.e1245() * b.e5()
+                    - self.e3145() * b.e235()
+                    + self.e2345() * b.e315(),
+                self.scalar() * b.e314() - self.e12() * b.e234()
+                    + self.e31() * b.e4()
+                    + self.e23() * b.e124()
+                    + self.e14() * b.e3()
+                    - self.e24() * b.e123()
+                    - self.e34() * b.e1()
+                    + self.e15() * b.e345()
+                    - self.e25() * b.e12345()
+                    - self.e35() * b.e145()
+                    - self.e45() * b.e315()
+                    - self.e1234() * b.e2()
+                    - self.e1235() * b.e245()
+                    + self.e1245() * b.e235()
+                    - self.e3145() * b.e5()
+                    - self.e2345() * b.e125(),
+                self.scalar() * b.e234() + self.e12() * b.e314() - self.e31() * b.e124()
+                    + self.e23() * b.e4()
+                    - self.e14() * b.e123()
+                    - self.e24() * b.e3()
+                    + self.e34() * b.e2()
+                    - self.e15() * b.e12345()
+                    - self.e25() * b.e345()
+                    + self.e35() * b.e245()
+                    - self.e45() * b.e235()
+                    - self.e1234() * b.e1()
+                    - self.e1235() * b.e145()
+                    - self.e1245() * b.e315()
+                    + self.e3145() * b.e125()
+                    - self.e2345() * b.e5(),
+                self.scalar() * b.e125() + self.e12() * b.e5() + self.e31() * b.e235()
+                    - self.e23() * b.e315()
+                    - self.e14() * b.e245()
+                    + self.e24() * b.e145()
+                    - self.e34() * b.e12345()
+                    - self.e15() * b.e2()
+                    + self.e25() * b.e1()
+                    - self.e35() * b.e123()
+                    - self.e45() * b.e124()
+                    - self.e1234() * b.e345()
+                    - self.e1235() * b.e3()
+                    - self.e1245() * b.e4()
+                    - self.e3145() * b.e234()
+                    + self.e2345() * b.e314(),
+                self.scalar() * b.e315() - self.e12() * b.e235()
+                    + self.e31() * b.e5()
+                    + self.e23() * b.e125()
+                    + self.e14() * b.e345()
+                    - self.e24() * b.e12345()
+                    - self.e34() * b.e145()
+                    + self.e15() * b.e3()
+                    - self.e25() * b.e123()
+                    - self.e35() * b.e1()
+                    - self.e45() * b.e314()
+                    - self.e1234() * b.e245()
+                    - self.e1235() * b.e2()
+                    + self.e1245() * b.e234()
+                    - self.e3145() * b.e4()
+                    - self.e2345() * b.e124(),
+                self.scalar() * b.e235() + self.e12() * b.e315() - self.e31() * b.e125()
+                    + self.e23() * b.e5()
+                    - self.e14() * b.e12345()
+                    - self.e24() * b.e345()
+                    + self.e34() * b.e245()
+                    - self.e15() * b.e123()
+                    - self.e25() * b.e3()
+                    + self.e35() * b.e2()
+                    - self.e45() * b.e234()
+                    - self.e1234() * b.e145()
+                    - self.e1235() * b.e1()
+                    - self.e1245() * b.e314()
+                    + self.e3145() * b.e124()
+                    - self.e2345() * b.e4(),
+                self.scalar() * b.e145() + self.e12() * b.e245()
+                    - self.e31() * b.e345()
+                    - self.e23() * b.e12345()
+                    + self.e14() * b.e5()
+                    - self.e24() * b.e125()
+                    + self.e34() * b.e315()
+                    - self.e15() * b.e4()
+                    + self.e25() * b.e124()
+                    - self.e35() * b.e314()
+                    + self.e45() * b.e1()
+                    - self.e1234() * b.e235()
+                    + self.e1235() * b.e234()
+                    + self.e1245() * b.e2()
+                    - self.e3145() * b.e3()
+                    - self.e2345() * b.e123(),
+                self.scalar() * b.e245() - self.e12() * b.e145() - self.e31() * b.e12345()
+                    + self.e23() * b.e345()
+                    + self.e14() * b.e125()
+                    + self.e24() * b.e5()
+                    - self.e34() * b.e235()
+                    - self.e15() * b.e124()
+                    - self.e25() * b.e4()
+                    + self.e35() * b.e234()
+                    + self.e45() * b.e2()
+                    - self.e1234() * b.e315()
+                    + self.e1235() * b.e314()
+                    - self.e1245() * b.e1()
+                    - self.e3145() * b.e123()
+                    + self.e2345() * b.e3(),
+                self.scalar() * b.e345() - self.e12() * b.e12345() + self.e31() * b.e145()
+                    - self.e23() * b.e245()
+                    - self.e14() * b.e315()
+                    + self.e24() * b.e235()
+                    + self.e34() * b.e5()
+                    + self.e15() * b.e314()
+                    - self.e25() * b.e234()
+                    - self.e35() * b.e4()
+                    + self.e45() * b.e3()
+                    - self.e1234() * b.e125()
+                    + self.e1235() * b.e124()
+                    - self.e1245() * b.e123()
+                    + self.e3145() * b.e1()
+                    - self.e2345() * b.e2(),
+            ),
+            Quadvector::new(
+                self.scalar() * b.e1234()
+                    + self.e12() * b.e34()
+                    + self.e31() * b.e24()
+                    + self.e23() * b.e14()
+                    + self.e14() * b.e23()
+                    + self.e24() * b.e31()
+                    + self.e34() * b.e12()
+                    + self.e15() * b.e2345()
+                    + self.e25() * b.e3145()
+                    + self.e35() * b.e1245()
+                    - self.e45() * b.e1235()
+                    + self.e1234() * b.scalar()
+                    + self.e1235() * b.e45()
+                    - self.e1245() * b.e35()
+                    - self.e3145() * b.e25()
+                    - self.e2345() * b.e15(),
+                self.scalar() * b.e1235()
+                    + self.e12() * b.e35()
+                    + self.e31() * b.e25()
+                    + self.e23() * b.e15()
+                    + self.e14() * b.e2345()
+                    + self.e24() * b.e3145()
+                    + self.e34() * b.e1245()
+                    + self.e15() * b.e23()
+                    + self.e25() * b.e31()
+                    + self.e35() * b.e12()
+                    - self.e45() * b.e1234()
+                    + self.e1234() * b.e45()
+                    + self.e1235() * b.scalar()
+                    - self.e1245() * b.e34()
+                    - self.e3145() * b.e24()
+                    - self.e2345() * b.e14(),
+                self.scalar() * b.e1245() + self.e12() * b.e45() + self.e31() * b.e2345()
+                    - self.e23() * b.e3145()
+                    - self.e14() * b.e25()
+                    + self.e24() * b.e15()
+                    - self.e34() * b.e1235()
+                    + self.e15() * b.e24()
+                    - self.e25() * b.e14()
+                    + self.e35() * b.e1234()
+                    + self.e45() * b.e12()
+                    - self.e1234() * b.e35()
+                    + self.e1235() * b.e34()
+                    + self.e1245() * b.scalar()
+                    + self.e3145() * b.e23()
+                    - self.e2345() * b.e31(),
+                self.scalar() * b.e3145() - self.e12() * b.e2345()
+                    + self.e31() * b.e45()
+                    + self.e23() * b.e1245()
+                    + self.e14() * b.e35()
+                    - self.e24() * b.e1235()
+                    - self.e34() * b.e15()
+                    - self.e15() * b.e34()
+                    + self.e25() * b.e1234()
+                    + self.e35() * b.e14()
+                    + self.e45() * b.e31()
+                    - self.e1234() * b.e25()
+                    + self.e1235() * b.e24()
+                    - self.e1245() * b.e23()
+                    + self.e3145() * b.scalar()
+                    + self.e2345() * b.e12(),
+                self.scalar() * b.e2345() + self.e12() * b.e3145() - self.e31() * b.e1245()
+                    + self.e23() * b.e45()
+                    - self.e14() * b.e1235()
+                    - self.e24() * b.e35()
+                    + self.e34() * b.e25()
+                    + self.e15() * b.e1234()
+                    + self.e25() * b.e34()
+                    - self.e35() * b.e24()
+                    + self.e45() * b.e23()
+                    - self.e1234() * b.e15()
+                    + self.e1235() * b.e14()
+                    + self.e1245() * b.e31()
+                    - self.e3145() * b.e12()
+                    + self.e2345() * b.scalar(),
+            ),
+            self.scalar() * b.e12345()
+                + self.e12() * b.e345()
+                + self.e31() * b.e245()
+                + self.e23() * b.e145()
+                + self.e14() * b.e235()
+                + self.e24() * b.e315()
+                + self.e34() * b.e125()
+                - self.e15() * b.e234()
+                - self.e25() * b.e314()
+                - self.e35() * b.e124()
+                + self.e45() * b.e123()
+                + self.e1234() * b.e5()
+                - self.e1235() * b.e4()
+                + self.e1245() * b.e3()
+                + self.e3145() * b.e2()
+                + self.e2345() * b.e1(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Versor<F>, Multivector<F>);
+
+// Multivector-Versor
+impl<F: Float> Mul<Versor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar()
+                - self.e12() * b.e12()
+                - self.e31() * b.e31()
+                - self.e23() * b.e23()
+                - self.e14() * b.e14()
+                - self.e24() * b.e24()
+                - self.e34() * b.e34()
+                + self.e15() * b.e15()
+                + self.e25() * b.e25()
+                + self.e35() * b.e35()
+                + self.e45() * b.e45()
+                + self.e1234() * b.e1234()
+                - self.e1235() * b.e1235()
+                - self.e1245() * b.e1245()
+                - self.e3145() * b.e3145()
+                - self.e2345() * b.e2345(),
+            Vector::new(
+                self.e1() * b.scalar() - self.e2() * b.e12() + self.e3() * b.e31()
+                    - self.e4() * b.e14()
+                    + self.e5() * b.e15()
+                    - self.e123() * b.e23()
+                    - self.e124() * b.e24()
+                    + self.e314() * b.e34()
+                    + self.e234() * b.e1234()
+                    + self.e125() * b.e25()
+                    - self.e315() * b.e35()
+                    - self.e235() * b.e1235()
+                    + self.e145() * b.e45()
+                    - self.e245() * b.e1245()
+                    + self.e345() * b.e3145()
+                    - self.e12345() * b.e2345(),
+                self.e1() * b.e12() + self.e2() * b.scalar()
+                    - self.e3() * b.e23()
+                    - self.e4() * b.e24()
+                    + self.e5() * b.e25()
+                    - self.e123() * b.e31()
+                    + self.e124() * b.e14()
+                    + self.e314() * b.e1234()
+                    - self.e234() * b.e34()
+                    - self.e125() * b.e15()
+                    - self.e315() * b.e1235()
+                    + self.e235() * b.e35()
+                    + self.e145() * b.e1245()
+                    + self.e245() * b.e45()
+                    - self.e345() * b.e2345()
+                    - self.e12345() * b.e3145(),
+                -self.e1() * b.e31() + self.e2() * b.e23() + self.e3() * b.scalar()
+                    - self.e4() * b.e34()
+                    + self.e5() * b.e35()
+                    - self.e123() * b.e12()
+                    + self.e124() * b.e1234()
+                    - self.e314() * b.e14()
+                    + self.e234() * b.e24()
+                    - self.e125() * b.e1235()
+                    + self.e315() * b.e15()
+                    - self.e235() * b.e25()
+                    - self.e145() * b.e3145()
+                    + self.e245() * b.e2345()
+                    + self.e345() * b.e45()
+                    - self.e12345() * b.e1245(),
+                self.e1() * b.e14()
+                    + self.e2() * b.e24()
+                    + self.e3() * b.e34()
+                    + self.e4() * b.scalar()
+                    + self.e5() * b.e45()
+                    - self.e123() * b.e1234()
+                    - self.e124() * b.e12()
+                    - self.e314() * b.e31()
+                    - self.e234() * b.e23()
+                    - self.e125() * b.e1245()
+                    - self.e315() * b.e3145()
+                    - self.e235() * b.e2345()
+                    - self.e145() * b.e15()
+                    - self.e245() * b.e25()
+                    - self.e345() * b.e35()
+                    + self.e12345() * b.e1235(),
+                self.e1() * b.e15()
+                    + self.e2() * b.e25()
+                    + self.e3() * b.e35()
+                    + self.e4() * b.e45()
+                    + self.e5() * b.scalar()
+                    - self.e123() * b.e1235()
+                    - self.e124() * b.e1245()
+                    - self.e314() * b.e3145()
+                    - self.e234() * b.e2345()
+                    - self.e125() * b.e12()
+                    - self.e315() * b.e31()
+                    - self.e235() * b.e23()
+                    - self.e145() * b.e14()
+                    - self.e245() * b.e24()
+                    - self.e345() * b.e34()
+                    + self.e12345() * b.e1234(),
+            ),
+            Bivector::new(
+                self.scalar() * b.e12() + self.e12() * b.scalar() + self.e31() * b.e23()
+                    - self.e23() * b.e31()
+                    - self.e14() * b.e24()
+                    + self.e24() * b.e14()
+                    - self.e34() * b.e1234()
+                    + self.e15() * b.e25()
+                    - self.e25() * b.e15()
+                    + self.e35() * b.e1235()
+                    + self.e45() * b.e1245()
+                    - self.e1234() * b.e34()
+                    + self.e1235() * b.e35()
+                    + self.e1245() * b.e45()
+                    + self.e3145() * b.e2345()
+                    - self.e2345() * b.e3145(),
+                self.scalar() * b.e31() - self.e12() * b.e23()
+                    + self.e31() * b.scalar()
+                    + self.e23() * b.e12()
+                    + self.e14() * b.e34()
+                    - self.e24() * b.e1234()
+                    - self.e34() * b.e14()
+                    - self.e15() * b.e35()
+                    + self.e25() * b.e1235()
+                    + self.e35() * b.e15()
+                    + self.e45() * b.e3145()
+                    - self.e1234() * b.e24()
+                    + self.e1235() * b.e25()
+                    - self.e1245() * b.e2345()
+                    + self.e3145() * b.e45()
+                    + self.e2345() * b.e1245(),
+                self.scalar() * b.e23() + self.e12() * b.e31() - self.e31() * b.e12()
+                    + self.e23() * b.scalar()
+                    - self.e14() * b.e1234()
+                    - self.e24() * b.e34()
+                    + self.e34() * b.e24()
+                    + self.e15() * b.e1235()
+                    + self.e25() * b.e35()
+                    - self.e35() * b.e25()
+                    + self.e45() * b.e2345()
+                    - self.e1234() * b.e14()
+                    + self.e1235() * b.e15()
+                    + self.e1245() * b.e3145()
+                    - self.e3145() * b.e1245()
+                    + self.e2345() * b.e45(),
+                self.scalar() * b.e14() + self.e12() * b.e24()
+                    - self.e31() * b.e34()
+                    - self.e23() * b.e1234()
+                    + self.e14() * b.scalar()
+                    - self.e24() * b.e12()
+                    + self.e34() * b.e31()
+                    + self.e15() * b.e45()
+                    - self.e25() * b.e1245()
+                    + self.e35() * b.e3145()
+                    - self.e45() * b.e15()
+                    - self.e1234() * b.e23()
+                    - self.e1235() * b.e2345()
+                    - self.e1245() * b.e25()
+                    + self.e3145() * b.e35()
+                    + self.e2345() * b.e1235(),
+                self.scalar() * b.e24() - self.e12() * b.e14() - self.e31() * b.e1234()
+                    + self.e23() * b.e34()
+                    + self.e14() * b.e12()
+                    + self.e24() * b.scalar()
+                    - self.e34() * b.e23()
+                    + self.e15() * b.e1245()
+                    + self.e25() * b.e45()
+                    - self.e35() * b.e2345()
+                    - self.e45() * b.e25()
+                    - self.e1234() * b.e31()
+                    - self.e1235() * b.e3145()
+                    + self.e1245() * b.e15()
+                    + self.e3145() * b.e1235()
+                    - self.e2345() * b.e35(),
+                self.scalar() * b.e34() - self.e12() * b.e1234() + self.e31() * b.e14()
+                    - self.e23() * b.e24()
+                    - self.e14() * b.e31()
+                    + self.e24() * b.e23()
+                    + self.e34() * b.scalar()
+                    - self.e15() * b.e3145()
+                    + self.e25() * b.e2345()
+                    + self.e35() * b.e45()
+                    - self.e45() * b.e35()
+                    - self.e1234() * b.e12()
+                    - self.e1235() * b.e1245()
+                    + self.e1245() * b.e1235()
+                    - self.e3145() * b.e15()
+                    + self.e2345() * b.e25(),
+                self.scalar() * b.e15() + self.e12() * b.e25()
+                    - self.e31() * b.e35()
+                    - self.e23() * b.e1235()
+                    + self.e14() * b.e45()
+                    - self.e24() * b.e1245()
+                    + self.e34() * b.e3145()
+                    + self.e15() * b.scalar()
+                    - self.e25() * b.e12()
+                    + self.e35() * b.e31()
+                    - self.e45() * b.e14()
+                    - self.e1234() * b.e2345()
+                    - self.e1235() * b.e23()
+                    - self.e1245() * b.e24()
+                    + self.e3145() * b.e34()
+                    + self.e2345() * b.e1234(),
+                self.scalar() * b.e25() - self.e12() * b.e15() - self.e31() * b.e1235()
+                    + self.e23() * b.e35()
+                    + self.e14() * b.e1245()
+                    + self.e24() * b.e45()
+                    - self.e34() * b.e2345()
+                    + self.e15() * b.e12()
+                    + self.e25() * b.scalar()
+                    - self.e35() * b.e23()
+                    - self.e45() * b.e24()
+                    - self.e1234() * b.e3145()
+                    - self.e1235() * b.e31()
+                    + self.e1245() * b.e14()
+                    + self.e3145() * b.e1234()
+                    - self.e2345() * b.e34(),
+                self.scalar() * b.e35() - self.e12() * b.e1235() + self.e31() * b.e15()
+                    - self.e23() * b.e25()
+                    - self.e14() * b.e3145()
+                    + self.e24() * b.e2345()
+                    + self.e34() * b.e45()
+                    - self.e15() * b.e31()
+                    + self.e25() * b.e23()
+                    + self.e35() * b.scalar()
+                    - self.e45() * b.e34()
+                    - self.e1234() * b.e1245()
+                    - self.e1235() * b.e12()
+                    + self.e1245() * b.e1234()
+                    - self.e3145() * b.e14()
+                    + self.e2345() * b.e24(),
+                self.scalar() * b.e45()
+                    - self.e12() * b.e1245()
+                    - self.e31() * b.e3145()
+                    - self.e23() * b.e2345()
+                    - self.e14() * b.e15()
+                    - self.e24() * b.e25()
+                    - self.e34() * b.e35()
+                    + self.e15() * b.e14()
+                    + self.e25() * b.e24()
+                    + self.e35() * b.e34()
+                    + self.e45() * b.scalar()
+                    + self.e1234() * b.e1235()
+                    - self.e1235() * b.e1234()
+                    - self.e1245() * b.e12()
+                    - self.e3145() * b.e31()
+                    - self.e2345() * b.e23(),
+            ),
+            Trivector::new(
+                self.e1() * b.e23() + self.e2() * b.e31() + self.e3() * b.e12()
+                    - self.e4() * b.e1234()
+                    + self.e5() * b.e1235()
+                    + self.e123() * b.scalar()
+                    - self.e124() * b.e34()
+                    - self.e314() * b.e24()
+                    - self.e234() * b.e14()
+                    + self.e125() * b.e35()
+                    + self.e315() * b.e25()
+                    + self.e235() * b.e15()
+                    + self.e145() * b.e2345()
+                    + self.e245() * b.e3145()
+                    + self.e345() * b.e1245()
+                    + self.e12345() * b.e45(),
+                self.e1() * b.e24() - self.e2() * b.e14()
+                    + self.e3() * b.e1234()
+                    + self.e4() * b.e12()
+                    + self.e5() * b.e1245()
+                    + self.e123() * b.e34()
+                    + self.e124() * b.scalar()
+                    + self.e314() * b.e23()
+                    - self.e234() * b.e31()
+                    + self.e125() * b.e45()
+                    + self.e315() * b.e2345()
+                    - self.e235() * b.e3145()
+                    - self.e145() * b.e25()
+                    + self.e245() * b.e15()
+                    - self.e345() * b.e1235()
+                    - self.e12345() * b.e35(),
+                -self.e1() * b.e34()
+                    + self.e2() * b.e1234()
+                    + self.e3() * b.e14()
+                    + self.e4() * b.e31()
+                    + self.e5() * b.e3145()
+                    + self.e123() * b.e24()
+                    - self.e124() * b.e23()
+                    + self.e314() * b.scalar()
+                    + self.e234() * b.e12()
+                    - self.e125() * b.e2345()
+                    + self.e315() * b.e45()
+                    + self.e235() * b.e1245()
+                    + self.e145() * b.e35()
+                    - self.e245() * b.e1235()
+                    - self.e345() * b.e15()
+                    - self.e12345() * b.e25(),
+                self.e1() * b.e1234() + self.e2() * b.e34() - self.e3() * b.e24()
+                    + self.e4() * b.e23()
+                    + self.e5() * b.e2345()
+                    + self.e123() * b.e14()
+                    + self.e124() * b.e31()
+                    - self.e314() * b.e12()
+                    + self.e234() * b.scalar()
+                    + self.e125() * b.e3145()
+                    - self.e315() * b.e1245()
+                    + self.e235() * b.e45()
+                    - self.e145() * b.e1235()
+                    - self.e245() * b.e35()
+                    + self.e345() * b.e25()
+                    - self.e12345() * b.e15(),
+                self.e1() * b.e25() - self.e2() * b.e15()
+                    + self.e3() * b.e1235()
+                    + self.e4() * b.e1245()
+                    + self.e5() * b.e12()
+                    + self.e123() * b.e35()
+                    + self.e124() * b.e45()
+                    + self.e314() * b.e2345()
+                    - self.e234() * b.e3145()
+                    + self.e125() * b.scalar()
+                    + self.e315() * b.e23()
+                    - self.e235() * b.e31()
+                    - self.e145() * b.e24()
+                    + self.e245() * b.e14()
+                    - self.e345() * b.e1234()
+                    - self.e12345() * b.e34(),
+                -self.e1() * b.e35()
+                    + self.e2() * b.e1235()
+                    + self.e3() * b.e15()
+                    + self.e4() * b.e3145()
+                    + self.e5() * b.e31()
+                    + self.e123() * b.e25()
+                    - self.e124() * b.e2345()
+                    + self.e314() * b.e45()
+                    + self.e234() * b.e1245()
+                    - self.e125() * b.e23()
+                    + self.e315() * b.scalar()
+                    + self.e235() * b.e12()
+                    + self.e145() * b.e34()
+                    - self.e245() * b.e1234()
+                    - self.e345() * b.e14()
+                    - self.e12345() * b.e24(),
+                self.e1() * b.e1235() + self.e2() * b.e35() - self.e3() * b.e25()
+                    + self.e4() * b.e2345()
+                    + self.e5() * b.e23()
+                    + self.e123() * b.e15()
+                    + self.e124() * b.e3145()
+                    - self.e314() * b.e1245()
+                    + self.e234() * b.e45()
+                    + self.e125() * b.e31()
+                    - self.e315() * b.e12()
+                    + self.e235() * b.scalar()
+                    - self.e145() * b.e1234()
+                    - self.e245() * b.e34()
+                    + self.e345() * b.e24()
+                    - self.e12345() * b.e14(),
+                self.e1() * b.e45() - self.e2() * b.e1245() + self.e3() * b.e3145()
+                    - self.e4() * b.e15()
+                    + self.e5() * b.e14()
+                    - self.e123() * b.e2345()
+                    - self.e124() * b.e25()
+                    + self.e314() * b.e35()
+                    + self.e234() * b.e1235()
+                    + self.e125() * b.e24()
+                    - self.e315() * b.e34()
+                    - self.e235() * b.e1234()
+                    + self.e145() * b.scalar()
+                    - self.e245() * b.e12()
+                    + self.e345() * b.e31()
+                    - self.e12345() * b.e23(),
+                self.e1() * b.e1245() + self.e2() * b.e45()
+                    - self.e3() * b.e2345()
+                    - self.e4() * b.e25()
+                    + self.e5() * b.e24()
+                    - self.e123() * b.e3145()
+                    + self.e124() * b.e15()
+                    + self.e314() * b.e1235()
+                    - self.e234() * b.e35()
+                    - self.e125() * b.e14()
+                    - self.e315() * b.e1234()
+                    + self.e235() * b.e34()
+                    + self.e145() * b.e12()
+                    + self.e245() * b.scalar()
+                    - self.e345() * b.e23()
+                    - self.e12345() * b.e31(),
+                -self.e1() * b.e3145() + self.e2() * b.e2345() + self.e3() * b.e45()
+                    - self.e4() * b.e35()
+                    + self.e5() * b.e34()
+                    - self.e123() * b.e1245()
+                    + self.e124() * b.e1235()
+                    - self.e314() * b.e15()
+                    + self.e234() * b.e25()
+                    - self.e125() * b.e1234()
+                    + self.e315() * b.e14()
+                    - self.e235() * b.e24()
+                    - self.e145() * b.e31()
+                    + self.e245() * b.e23()
+                    + self.e345() * b.scalar()
+                    - self.e12345() * b.e12(),
+            ),
+            Quadvector::new(
+                self.scalar() * b.e1234()
+                    + self.e12() * b.e34()
+                    + self.e31() * b.e24()
+                    + self.e23() * b.e14()
+                    + self.e14() * b.e23()
+                    + self.e24() * b.e31()
+                    + self.e34() * b.e12()
+                    + self.e15() * b.e2345()
+                    + self.e25() * b.e3145()
+                    + self.e35() * b.e1245()
+                    - self.e45() * b.e1235()
+                    + self.e1234() * b.scalar()
+                    + self.e1235() * b.e45()
+                    - self.e1245() * b.e35()
+                    - self.e3145() * b.e25()
+                    - self.e2345() * b.e15(),
+                self.scalar() * b.e1235()
+                    + self.e12() * b.e35()
+                    + self.e31() * b.e25()
+                    + self.e23() * b.e15()
+                    + self.e14() * b.e2345()
+                    + self.e24() * b.e3145()
+                    + self.e34() * b.e1245()
+                    + self.e15() * b.e23()
+                    + self.e25() * b.e31()
+                    + self.e35() * b.e12()
+                    - self.e45() * b.e1234()
+                    + self.e1234() * b.e45()
+                    + self.e1235() * b.scalar()
+                    - self.e1245() * b.e34()
+                    - self.e3145() * b.e24()
+                    - self.e2345() * b.e14(),
+                self.scalar() * b.e1245() + self.e12() * b.e45() + self.e31() * b.e2345()
+                    - self.e23() * b.e3145()
+                    - self.e14() * b.e25()
+                    + self.e24() * b.e15()
+                    - self.e34() * b.e1235()
+                    + self.e15() * b.e24()
+                    - self.e25() * b.e14()
+                    + self.e35() * b.e1234()
+                    + self.e45() * b.e12()
+                    - self.e1234() * b.e35()
+                    + self.e1235() * b.e34()
+                    + self.e1245() * b.scalar()
+                    + self.e3145() * b.e23()
+                    - self.e2345() * b.e31(),
+                self.scalar() * b.e3145() - self.e12() * b.e2345()
+                    + self.e31() * b.e45()
+                    + self.e23() * b.e1245()
+                    + self.e14() * b.e35()
+                    - self.e24() * b.e1235()
+                    - self.e34() * b.e15()
+                    - self.e15() * b.e34()
+                    + self.e25() * b.e1234()
+                    + self.e35() * b.e14()
+                    + self.e45() * b.e31()
+                    - self.e1234() * b.e25()
+                    + self.e1235() * b.e24()
+                    - self.e1245() * b.e23()
+                    + self.e3145() * b.scalar()
+                    + self.e2345() * b.e12(),
+                self.scalar() * b.e2345() + self.e12() * b.e3145() - self.e31() * b.e1245()
+                    + self.e23() * b.e45()
+                    - self.e14() * b.e1235()
+                    - self.e24() * b.e35()
+                    + self.e34() * b.e25()
+                    + self.e15() * b.e1234()
+                    + self.e25() * b.e34()
+                    - self.e35() * b.e24()
+                    + self.e45() * b.e23()
+                    - self.e1234() * b.e15()
+                    + self.e1235() * b.e14()
+                    + self.e1245() * b.e31()
+                    - self.e3145() * b.e12()
+                    + self.e2345() * b.scalar(),
+            ),
+            self.e1() * b.e2345() + self.e2() * b.e3145() + self.e3() * b.e1245()
+                - self.e4() * b.e1235()
+                + self.e5() * b.e1234()
+                + self.e123() * b.e45()
+                - self.e124() * b.e35()
+                - self.e314() * b.e25()
+                - self.e234() * b.e15()
+                + self.e125() * b.e34()
+                + self.e315() * b.e24()
+                + self.e235() * b.e14()
+                + self.e145() * b.e23()
+                + self.e245() * b.e31()
+                + self.e345() * b.e12()
+                + self.e12345() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Versor<F>);
+
+// Multivector-Multivector
+impl<F: Float> Mul for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar()
+                + self.e1() * b.e1()
+                + self.e2() * b.e2()
+                + self.e3() * b.e3()
+                + self.e4() * b.e4()
+                - self.e5() * b.e5()
+                - self.e12() * b.e12()
+                - self.e31() * b.e31()
+                - self.e23() * b.e23()
+                - self.e14() * b.e14()
+                - self.e24() * b.e24()
+                - self.e34() * b.e34()
+                + self.e15() * b.e15()
+                + self.e25() * b.e25()
+                + self.e35() * b.e35()
+                + self.e45() * b.e45()
+                - self.e123() * b.e123()
+                - self.e124() * b.e124()
+                - self.e314() * b.e314()
+                - self.e234() * b.e234()
+                + self.e125() * b.e125()
+                + self.e315() * b.e315()
+                + self.e235() * b.e235()
+                + self.e145() * b.e145()
+                + self.e245() * b.e245()
+                + self.e345() * b.e345()
+                + self.e1234() * b.e1234()
+                - self.e1235() * b.e1235()
+                - self.e1245() * b.e1245()
+                - self.e3145() * b.e3145()
+                - self.e2345() * b.e2345()
+                - self.e12345() * b.e12345(),
+            Vector::new(
+                self.scalar() * b.e1() + self.e1() * b.scalar() - self.e2() * b.e12()
+                    + self.e3() * b.e31()
+                    - self.e4() * b.e14()
+                    + self.e5() * b.e15()
+                    + self.e12() * b.e2()
+                    - self.e31() * b.e3()
+                    - self.e23() * b.e123()
+                    + self.e14() * b.e4()
+                    - self.e24() * b.e124()
+                    + self.e34() * b.e314()
+                    - self.e15() * b.e5()
+                    + self.e25() * b.e125()
+                    - self.e35() * b.e315()
+                    + self.e45() * b.e145()
+                    - self.e123() * b.e23()
+                    - self.e124() * b.e24()
+                    + self.e314() * b.e34()
+                    + self.e234() * b.e1234()
+                    + self.e125() * b.e25()
+                    - self.e315() * b.e35()
+                    - self.e235() * b.e1235()
+                    + self.e145() * b.e45()
+                    - self.e245() * b.e1245()
+                    + self.e345() * b.e3145()
+                    - self.e1234() * b.e234()
+                    + self.e1235() * b.e235()
+                    + self.e1245() * b.e245()
+                    - self.e3145() * b.e345()
+                    - self.e2345() * b.e12345()
+                    - self.e12345() * b.e2345(),
+                self.scalar() * b.e2() + self.e1() * b.e12() + self.e2() * b.scalar()
+                    - self.e3() * b.e23()
+                    - self.e4() * b.e24()
+                    + self.e5() * b.e25()
+                    - self.e12() * b.e1()
+                    - self.e31() * b.e123()
+                    + self.e23() * b.e3()
+                    + self.e14() * b.e124()
+                    + self.e24() * b.e4()
+                    - self.e34() * b.e234()
+                    - self.e15() * b.e125()
+                    - self.e25() * b.e5()
+                    + self.e35() * b.e235()
+                    + self.e45() * b.e245()
+                    - self.e123() * b.e31()
+                    + self.e124() * b.e14()
+                    + self.e314() * b.e1234()
+                    - self.e234() * b.e34()
+                    - self.e125() * b.e15()
+                    - self.e315() * b.e1235()
+                    + self.e235() * b.e35()
+                    + self.e145() * b.e1245()
+                    + self.e245() * b.e45()
+                    - self.e345() * b.e2345()
+                    - self.e1234() * b.e314()
+                    + self.e1235() * b.e315()
+                    - self.e1245() * b.e145()
+                    - self.e3145() * b.e12345()
+                    + self.e2345() * b.e345()
+                    - self.e12345() * b.e3145(),
+                self.scalar() * b.e3() - self.e1() * b.e31()
+                    + self.e2() * b.e23()
+                    + self.e3() * b.scalar()
+                    - self.e4() * b.e34()
+                    + self.e5() * b.e35()
+                    - self.e12() * b.e123()
+                    + self.e31() * b.e1()
+                    - self.e23() * b.e2()
+                    - self.e14() * b.e314()
+                    + self.e24() * b.e234()
+                    + self.e34() * b.e4()
+                    + self.e15() * b.e315()
+                    - self.e25() * b.e235()
+                    - self.e35() * b.e5()
+                    + self.e45() * b.e345()
+                    - self.e123() * b.e12()
+                    + self.e124() * b.e1234()
+                    - self.e314() * b.e14()
+                    + self.e234() * b.e24()
+                    - self.e125() * b.e1235()
+                    + self.e315() * b.e15()
+                    - self.e235() * b.e25()
+                    - self.e145() * b.e3145()
+                    + self.e245() * b.e2345()
+                    + self.e345() * b.e45()
+                    - self.e1234() * b.e124()
+                    + self.e1235() * b.e125()
+                    - self.e1245() * b.e12345()
+                    + self.e3145() * b.e145()
+                    - self.e2345() * b.e245()
+                    - self.e12345() * b.e1245(),
+                self.scalar() * b.e4()
+                    + self.e1() * b.e14()
+                    + self.e2() * b.e24()
+                    + self.e3() * b.e34()
+                    + self.e4() * b.scalar()
+                    + self.e5() * b.e45()
+                    - self.e12() * b.e124()
+                    - self.e31() * b.e314()
+                    - self.e23() * b.e234()
+                    - self.e14() * b.e1()
+                    - self.e24() * b.e2()
+                    - self.e34() * b.e3()
+                    - self.e15() * b.e145()
+                    - self.e25() * b.e245()
+                    - self.e35() * b.e345()
+                    - self.e45() * b.e5()
+                    - self.e123() * b.e1234()
+                    - self.e124() * b.e12()
+                    - self.e314() * b.e31()
+                    - self.e234() * b.e23()
+                    - self.e125() * b.e1245()
+                    - self.e315() * b.e3145()
+                    - self.e235() * b.e2345()
+                    - self.e145() * b.e15()
+                    - self.e245() * b.e25()
+                    - self.e345() * b.e35()
+                    + self.e1234() * b.e123()
+                    + self.e1235() * b.e12345()
+                    + self.e1245() * b.e125()
+                    + self.e3145() * b.e315()
+                    + self.e2345() * b.e235()
+                    + self.e12345() * b.e1235(),
+                self.scalar() * b.e5()
+                    + self.e1() * b.e15()
+                    + self.e2() * b.e25()
+                    + self.e3() * b.e35()
+                    + self.e4() * b.e45()
+                    + self.e5() * b.scalar()
+                    - self.e12() * b.e125()
+                    - self.e31() * b.e315()
+                    - self.e23() * b.e235()
+                    - self.e14() * b.e145()
+                    - self.e24() * b.e245()
+                    - self.e34() * b.e345()
+                    - self.e15() * b.e1()
+                    - self.e25() * b.e2()
+                    - self.e35() * b.e3()
+                    - self.e45() * b.e4()
+                    - self.e123() * b.e1235()
+                    - self.e124() * b.e1245()
+                    - self.e314() * b.e3145()
+                    - self.e234() * b.e2345()
+                    - self.e125() * b.e12()
+                    - self.e315() * b.e31()
+                    - self.e235() * b.e23()
+                    - self.e145() * b.e14()
+                    - self.e245() * b.e24()
+                    - self.e345() * b.e34()
+                    + self.e1234() * b.e12345()
+                    + self.e1235() * b.e123()
+                    + self.e1245() * b.e124()
+                    + self.e3145() * b.e314()
+                    + self.e2345() * b.e234()
+                    + self.e12345() * b.e1234(),
+            ),
+            Bivector::new(
+                self.scalar() * b.e12() + self.e1() * b.e2() - self.e2() * b.e1()
+                    + self.e3() * b.e123()
+                    + self.e4() * b.e124()
+                    - self.e5() * b.e125()
+                    + self.e12() * b.scalar()
+                    + self.e31() * b.e23()
+                    - self.e23() * b.e31()
+                    - self.e14() * b.e24()
+                    + self.e24() * b.e14()
+                    - self.e34() * b.e1234()
+                    + self.e15() * b.e25()
+                    - self.e25() * b.e15()
+                    + self.e35() * b.e1235()
+                    + self.e45() * b.e1245()
+                    + self.e123() * b.e3()
+                    + self.e124() * b.e4()
+                    + self.e314() * b.e234()
+                    - self.e234() * b.e314()
+                    - self.e125() * b.e5()
+                    - self.e315() * b.e235()
+                    + self.e235() * b.e315()
+                    + self.e145() * b.e245()
+                    - self.e245() * b.e145()
+                    + self.e345() * b.e12345()
+                    - self.e1234() * b.e34()
+                    + self.e1235() * b.e35()
+                    + self.e1245() * b.e45()
+                    + self.e3145() * b.e2345()
+                    - self.e2345() * b.e3145()
+                    + self.e12345() * b.e345(),
+                self.scalar() * b.e31() - self.e1() * b.e3()
+                    + self.e2() * b.e123()
+                    + self.e3() * b.e1()
+                    + self.e4() * b.e314()
+                    - self.e5() * b.e315()
+                    - self.e12() * b.e23()
+                    + self.e31() * b.scalar()
+                    + self.e23() * b.e12()
+                    + self.e14() * b.e34()
+                    - self.e24() * b.e1234()
+                    - self.e34() * b.e14()
+                    - self.e15() * b.e35()
+                    + self.e25() * b.e1235()
+                    + self.e35() * b.e15()
+                    + self.e45() * b.e3145()
+                    + self.e123() * b.e2()
+                    - self.e124() * b.e234()
+                    + self.e314() * b.e4()
+                    + self.e234() * b.e124()
+                    + self.e125() * b.e235()
+                    - self.e315() * b.e5()
+                    - self.e235() * b.e125()
+                    - self.e145() * b.e345()
+                    + self.e245() * b.e12345()
+                    + self.e345() * b.e145()
+                    - self.e1234() * b.e24()
+                    + self.e1235() * b.e25()
+                    - self.e1245() * b.e2345()
+                    + self.e3145() * b.e45()
+                    + self.e2345() * b.e1245()
+                    + self.e12345() * b.e245(),
+                self.scalar() * b.e23() + self.e1() * b.e123() + self.e2() * b.e3()
+                    - self.e3() * b.e2()
+                    + self.e4() * b.e234()
+                    - self.e5() * b.e235()
+                    + self.e12() * b.e31()
+                    - self.e31() * b.e12()
+                    + self.e23() * b.scalar()
+                    - self.e14() * b.e1234()
+                    - self.e24() * b.e34()
+                    + self.e34() * b.e24()
+                    + self.e15() * b.e1235()
+                    + self.e25() * b.e35()
+                    - self.e35() * b.e25()
+                    + self.e45() * b.e2345()
+                    + self.e123() * b.e1()
+                    + self.e124() * b.e314()
+                    - self.e314() * b.e124()
+                    + self.e234() * b.e4()
+                    - self.e125() * b.e315()
+                    + self.e315() * b.e125()
+                    - self.e235() * b.e5()
+                    + self.e145() * b.e12345()
+                    + self.e245() * b.e345()
+                    - self.e345() * b.e245()
+                    - self.e1234() * b.e14()
+                    + self.e1235() * b.e15()
+                    + self.e1245() * b.e3145()
+                    - self.e3145() * b.e1245()
+                    + self.e2345() * b.e45()
+                    + self.e12345() * b.e145(),
+                self.scalar() * b.e14() + self.e1() * b.e4() - self.e2() * b.e124()
+                    + self.e3() * b.e314()
+                    - self.e4() * b.e1()
+                    - self.e5() * b.e145()
+                    + self.e12() * b.e24()
+                    - self.e31() * b.e34()
+                    - self.e23() * b.e1234()
+                    + self.e14() * b.scalar()
+                    - self.e24() * b.e12()
+                    + self.e34() * b.e31()
+                    + self.e15() * b.e45()
+                    - self.e25() * b.e1245()
+                    + self.e35() * b.e3145()
+                    - self.e45() * b.e15()
+                    - self.e123() * b.e234()
+                    - self.e124() * b.e2()
+                    + self.e314() * b.e3()
+                    + self.e234() * b.e123()
+                    - self.e125() * b.e245()
+                    + self.e315() * b.e345()
+                    + self.e235() * b.e12345()
+                    - self.e145() * b.e5()
+                    + self.e245() * b.e125()
+                    - self.e345() * b.e315()
+                    - self.e1234() * b.e23()
+                    - self.e1235() * b.e2345()
+                    - self.e1245() * b.e25()
+                    + self.e3145() * b.e35()
+                    + self.e2345() * b.e1235()
+                    + self.e12345() * b.e235(),
+                self.scalar() * b.e24() + self.e1() * b.e124() + self.e2() * b.e4()
+                    - self.e3() * b.e234()
+                    - self.e4() * b.e2()
+                    - self.e5() * b.e245()
+                    - self.e12() * b.e14()
+                    - self.e31() * b.e1234()
+                    + self.e23() * b.e34()
+                    + self.e14() * b.e12()
+                    + self.e24() * b.scalar()
+                    - self.e34() * b.e23()
+                    + self.e15() * b.e1245()
+                    + self.e25() * b.e45()
+                    - self.e35() * b.e2345()
+                    - self.e45() * b.e25()
+                    - self.e123() * b.e314()
+                    + self.e124() * b.e1()
+                    + self.e314() * b.e123()
+                    - self.e234() * b.e3()
+                    + self.e125() * b.e145()
+                    + self.e315() * b.e12345()
+                    - self.e235() * b.e345()
+                    - self.e145() * b.e125()
+                    - self.e245() * b.e5()
+                    + self.e345() * b.e235()
+                    - self.e1234() * b.e31()
+                    - self.e1235() * b.e3145()
+                    + self.e1245() * b.e15()
+                    + self.e3145() * b.e1235()
+                    - self.e2345() * b.e35()
+                    + self.e12345() * b.e315(),
+                self.scalar() * b.e34() - self.e1() * b.e314()
+                    + self.e2() * b.e234()
+                    + self.e3() * b.e4()
+                    - self.e4() * b.e3()
+                    - self.e5() * b.e345()
+                    - self.e12() * b.e1234()
+                    + self.e31() * b.e14()
+                    - self.e23() * b.e24()
+                    - self.e14() * b.e31()
+                    + self.e24() * b.e23()
+                    + self.e34() * b.scalar()
+                    - self.e15() * b.e3145()
+                    + self.e25() * b.e2345()
+                    + self.e35() * b.e45()
+                    - self.e45() * b.e35()
+                    - self.e123() * b.e124()
+                    + self.e124() * b.e123()
+                    - self.e314() * b.e1()
+                    + self.e234() * b.e2()
+                    + self.e125() * b.e12345()
+                    - self.e315() * b.e145()
+                    + self.e235() * b.e245()
+                    + self.e145() * b.e315()
+                    - self.e245() * b.e235()
+                    - self.e345() * b.e5()
+                    - self.e1234() * b.e12()
+                    - self.e1235() * b.e1245()
+                    + self.e1245() * b.e1235()
+                    - self.e3145() * b.e15()
+                    + self.e2345() * b.e25()
+                    + self.e12345() * b.e125(),
+                self.scalar() * b.e15() + self.e1() * b.e5() - self.e2() * b.e125()
+                    + self.e3() * b.e315()
+                    - self.e4() * b.e145()
+                    - self.e5() * b.e1()
+                    + self.e12() * b.e25()
+                    - self.e31() * b.e35()
+                    - self.e23() * b.e1235()
+                    + self.e14() * b.e45()
+                    - self.e24() * b.e1245()
+                    + self.e34() * b.e3145()
+                    + self.e15() * b.scalar()
+                    - self.e25() * b.e12()
+                    + self.e35() * b.e31()
+                    - self.e45() * b.e14()
+                    - self.e123() * b.e235()
+                    - self.e124() * b.e245()
+                    + self.e314() * b.e345()
+                    + self.e234() * b.e12345()
+                    - self.e125() * b.e2()
+                    + self.e315() * b.e3()
+                    + self.e235() * b.e123()
+                    - self.e145() * b.e4()
+                    + self.e245() * b.e124()
+                    - self.e345() * b.e314()
+                    - self.e1234() * b.e2345()
+                    - self.e1235() * b.e23()
+                    - self.e1245() * b.e24()
+                    + self.e3145() * b.e34()
+                    + self.e2345() * b.e1234()
+                    + self.e12345() * b.e234(),
+                self.scalar() * b.e25() + self.e1() * b.e125() + self.e2() * b.e5()
+                    - self.e3() * b.e235()
+                    - self.e4() * b.e245()
+                    - self.e5() * b.e2()
+                    - self.e12() * b.e15()
+                    - self.e31() * b.e1235()
+                    + self.e23() * b.e35()
+                    + self.e14() * b.e1245()
+                    + self.e24() * b.e45()
+                    - self.e34() * b.e2345()
+                    + self.e15() * b.e12()
+                    + self.e25() * b.scalar()
+                    - self.e35() * b.e23()
+                    - self.e45() * b.e24()
+                    - self.e123() * b.e315()
+                    + self.e124() * b.e145()
+                    + self.e314() * b.e12345()
+                    - self.e234() * b.e345()
+                    + self.e125() * b.e1()
+                    + self.e315() * b.e123()
+                    - self.e235() * b.e3()
+                    - self.e145() * b.e124()
+                    - self.e245() * b.e4()
+                    + self.e345() * b.e234()
+                    - self.e1234() * b.e3145()
+                    - self.e1235() * b.e31()
+                    + self.e1245() * b.e14()
+                    + self.e3145() * b.e1234()
+                    - self.e2345() * b.e34()
+                    + self.e12345() * b.e314(),
+                self.scalar() * b.e35() - self.e1() * b.e315()
+                    + self.e2() * b.e235()
+                    + self.e3() * b.e5()
+                    - self.e4() * b.e345()
+                    - self.e5() * b.e3()
+                    - self.e12() * b.e1235()
+                    + self.e31() * b.e15()
+                    - self.e23() * b.e25()
+                    - self.e14() * b.e3145()
+                    + self.e24() * b.e2345()
+                    + self.e34() * b.e45()
+                    - self.e15() * b.e31()
+                    + self.e25() * b.e23()
+                    + self.e35() * b.scalar()
+                    - self.e45() * b.e34()
+                    - self.e123() * b.e125()
+                    + self.e124() * b.e12345()
+                    - self.e314() * b.e145()
+                    + self.e234() * b.e245()
+                    + self.e125() * b.e123()
+                    - self.e315() * b.e1()
+                    + self.e235() * b.e2()
+                    + self.e145() * b.e314()
+                    - self.e245() * b.e234()
+                    - self.e345() * b.e4()
+                    - self.e1234() * b.e1245()
+                    - self.e1235() * b.e12()
+                    + self.e1245() * b.e1234()
+                    - self.e3145() * b.e14()
+                    + self.e2345() * b.e24()
+                    + self.e12345() * b.e124(),
+                self.scalar() * b.e45()
+                    + self.e1() * b.e145()
+                    + self.e2() * b.e245()
+                    + self.e3() * b.e345()
+                    + self.e4() * b.e5()
+                    - self.e5() * b.e4()
+                    - self.e12() * b.e1245()
+                    - self.e31() * b.e3145()
+                    - self.e23() * b.e2345()
+                    - self.e14() * b.e15()
+                    - self.e24() * b.e25()
+                    - self.e34() * b.e35()
+                    + self.e15() * b.e14()
+                    + self.e25() * b.e24()
+                    + self.e35() * b.e34()
+                    + self.e45() * b.scalar()
+                    - self.e123() * b.e12345()
+                    - self.e124() * b.e125()
+                    - self.e314() * b.e315()
+                    - self.e234() * b.e235()
+                    + self.e125() * b.e124()
+                    + self.e315() * b.e314()
+                    + self.e235() * b.e234()
+                    + self.e145() * b.e1()
+                    + self.e245() * b.e2()
+                    + self.e345() * b.e3()
+                    + self.e1234() * b.e1235()
+                    - self.e1235() * b.e1234()
+                    - self.e1245() * b.e12()
+                    - self.e3145() * b.e31()
+                    - self.e2345() * b.e23()
+                    - self.e12345() * b.e123(),
+            ),
+            Trivector::new(
+                self.scalar() * b.e123()
+                    + self.e1() * b.e23()
+                    + self.e2() * b.e31()
+                    + self.e3() * b.e12()
+                    - self.e4() * b.e1234()
+                    + self.e5() * b.e1235()
+                    + self.e12() * b.e3()
+                    + self.e31() * b.e2()
+                    + self.e23() * b.e1()
+                    + self.e14() * b.e234()
+                    + self.e24() * b.e314()
+                    + self.e34() * b.e124()
+                    - self.e15() * b.e235()
+                    - self.e25() * b.e315()
+                    - self.e35() * b.e125()
+                    + self.e45() * b.e12345()
+                    + self.e123() * b.scalar()
+                    - self.e124() * b.e34()
+                    - self.e314() * b.e24()
+                    - self.e234() * b.e14()
+                    + self.e125() * b.e35()
+                    + self.e315() * b.e25()
+                    + self.e235() * b.e15()
+                    + self.e145() * b.e2345()
+                    + self.e245() * b.e3145()
+                    + self.e345() * b.e1245()
+                    + self.e1234() * b.e4()
+                    - self.e1235() * b.e5()
+                    + self.e1245() * b.e345()
+                    + self.e3145() * b.e245()
+                    + self.e2345() * b.e145()
+                    + self.e12345() * b.e45(),
+                self.scalar() * b.e124() + self.e1() * b.e24() - self.e2() * b.e14()
+                    + self.e3() * b.e1234()
+                    + self.e4() * b.e12()
+                    + self.e5() * b.e1245()
+                    + self.e12() * b.e4()
+                    + self.e31() * b.e234()
+                    - self.e23() * b.e314()
+                    - self.e14() * b.e2()
+                    + self.e24() * b.e1()
+                    - self.e34() * b.e123()
+                    - self.e15() * b.e245()
+                    + self.e25() * b.e145()
+                    - self.e35() * b.e12345()
+                    - self.e45() * b.e125()
+                    + self.e123() * b.e34()
+                    + self.e124() * b.scalar()
+                    + self.e314() * b.e23()
+                    - self.e234() * b.e31()
+                    + self.e125() * b.e45()
+                    + self.e315() * b.e2345()
+                    - self.e235() * b.e3145()
+                    - self.e145() * b.e25()
+                    + self.e245() * b.e15()
+                    - self.e345() * b.e1235()
+                    - self.e1234() * b.e3()
+                    - self.e1235() * b.e345()
+                    - self.e1245() * b.e5()
+                    - self.e3145() * b.e235()
+                    + self.e2345() * b.e315()
+                    - self.e12345() * b.e35(),
+                self.scalar() * b.e314() - self.e1() * b.e34()
+                    + self.e2() * b.e1234()
+                    + self.e3() * b.e14()
+                    + self.e4() * b.e31()
+                    + self.e5() * b.e3145()
+                    - self.e12() * b.e234()
+                    + self.e31() * b.e4()
+                    + self.e23() * b.e124()
+                    + self.e14() * b.e3()
+                    - self.e24() * b.e123()
+                    - self.e34() * b.e1()
+                    + self.e15() * b.e345()
+                    - self.e25() * b.e12345()
+                    - self.e35() * b.e145()
+                    - self.e45() * b.e315()
+                    + self.e123() * b.e24()
+                    - self.e124() * b.e23()
+                    + self.e314() * b.scalar()
+                    + self.e234() * b.e12()
+                    - self.e125() * b.e2345()
+                    + self.e315() * b.e45()
+                    + self.e235() * b.e1245()
+                    + self.e145() * b.e35()
+                    - self.e245() * b.e1235()
+                    - self.e345() * b.e15()
+                    - self.e1234() * b.e2()
+                    - self.e1235() * b.e245()
+                    + self.e1245() * b.e235()
+                    - self.e3145() * b.e5()
+                    - self.e2345() * b.e125()
+                    - self.e12345() * b.e25(),
+                self.scalar() * b.e234() + self.e1() * b.e1234() + self.e2() * b.e34()
+                    - self.e3() * b.e24()
+                    + self.e4() * b.e23()
+                    + self.e5() * b.e2345()
+                    + self.e12() * b.e314()
+                    - self.e31() * b.e124()
+                    + self.e23() * b.e4()
+                    - self.e14() * b.e123()
+                    - self.e24() * b.e3()
+                    + self.e34() * b.e2()
+                    - self.e15() * b.e12345()
+                    - self.e25() * b.e345()
+                    + self.e35() * b.e245()
+                    - self.e45() * b.e235()
+                    + self.e123() * b.e14()
+                    + self.e124() * b.e31()
+                    - self.e314() * b.e12()
+                    + self.e234() * b.scalar()
+                    + self.e125() * b.e3145()
+                    - self.e315() * b.e1245()
+                    + self.e235() * b.e45()
+                    - self.e145() * b.e1235()
+                    - self.e245() * b.e35()
+                    + self.e345() * b.e25()
+                    - self.e1234() * b.e1()
+                    - self.e1235() * b.e145()
+                    - self.e1245() * b.e315()
+                    + self.e3145() * b.e125()
+                    - self.e2345() * b.e5()
+                    - self.e12345() * b.e15(),
+                self.scalar() * b.e125() + self.e1() * b.e25() - self.e2() * b.e15()
+                    + self.e3() * b.e1235()
+                    + self.e4() * b.e1245()
+                    + self.e5() * b.e12()
+                    + self.e12() * b.e5()
+                    + self.e31() * b.e235()
+                    - self.e23() * b.e315()
+                    - self.e14() * b.e245()
+                    + self.e24() * b.e145()
+                    - self.e34() * b.e12345()
+                    - self.e15() * b.e2()
+                    + self.e25() * b.e1()
+                    - self.e35() * b.e123()
+                    - self.e45() * b.e124()
+                    + self.e123() * b.e35()
+                    + self.e124() * b.e45()
+                    + self.e314() * b.e2345()
+                    - self.e234() * b.e3145()
+                    + self.e125() * b.scalar()
+                    + self.e315() * b.e23()
+                    - self.e235() * b.e31()
+                    - self.e145() * b.e24()
+                    + self.e245() * b.e14()
+                    - self.e345() * b.e1234()
+                    - self.e1234() * b.e345()
+                    - self.e1235() * b.e3()
+                    - self.e1245() * b.e4()
+                    - self.e3145() * b.e234()
+                    + self.e2345() * b.e314()
+                    - self.e12345() * b.e34(),
+                self.scalar() * b.e315() - self.e1() * b.e35()
+                    + self.e2() * b.e1235()
+                    + self.e3() * b.e15()
+                    + self.e4() * b.e3145()
+                    + self.e5() * b.e31()
+                    - self.e12() * b.e235()
+                    + self.e31() * b.e5()
+                    + self.e23() * b.e125()
+                    + self.e14() * b.e345()
+                    - self.e24() * b.e12345()
+                    - self.e34() * b.e145()
+                    + self.e15() * b.e3()
+                    - self.e25() * b.e123()
+                    - self.e35() * b.e1()
+                    - self.e45() * b.e314()
+                    + self.e123() * b.e25()
+                    - self.e124() * b.e2345()
+                    + self.e314() * b.e45()
+                    + self.e234() * b.e1245()
+                    - self.e125() * b.e23()
+                    + self.e315() * b.scalar()
+                    + self.e235() * b.e12()
+                    + self.e145() * b.e34()
+                    - self.e245() * b.e1234()
+                    - self.e345() * b.e14()
+                    - self.e1234() * b.e245()
+                    - self.e1235() * b.e2()
+                    + self.e1245() * b.e234()
+                    - self.e3145() * b.e4()
+                    - self.e2345() * b.e124()
+                    - self.e12345() * b.e24(),
+                self.scalar() * b.e235() + self.e1() * b.e1235() + self.e2() * b.e35()
+                    - self.e3() * b.e25()
+                    + self.e4() * b.e2345()
+                    + self.e5() * b.e23()
+                    + self.e12() * b.e315()
+                    - self.e31() * b.e125()
+                    + self.e23() * b.e5()
+                    - self.e14() * b.e12345()
+                    - self.e24() * b.e345()
+                    + self.e34() * b.e245()
+                    - self.e15() * b.e123()
+                    - self.e25() * b.e3()
+                    + self.e35() * b.e2()
+                    - self.e45() * b.e234()
+                    + self.e123() * b.e15()
+                    + self.e124() * b.e3145()
+                    - self.e314() * b.e1245()
+                    + self.e234() * b.e45()
+                    + self.e125() * b.e31()
+                    - self.e315() * b.e12()
+                    + self.e235() * b.scalar()
+                    - self.e145() * b.e1234()
+                    - self.e245() * b.e34()
+                    + self.e345() * b.e24()
+                    - self.e1234() * b.e145()
+                    - self.e1235() * b.e1()
+                    - self.e1245() * b.e314()
+                    + self.e3145() * b.e124()
+                    - self.e2345() * b.e4()
+                    - self.e12345() * b.e14(),
+                self.scalar() * b.e145() + self.e1() * b.e45() - self.e2() * b.e1245()
+                    + self.e3() * b.e3145()
+                    - self.e4() * b.e15()
+                    + self.e5() * b.e14()
+                    + self.e12() * b.e245()
+                    - self.e31() * b.e345()
+                    - self.e23() * b.e12345()
+                    + self.e14() * b.e5()
+                    - self.e24() * b.e125()
+                    + self.e34() * b.e315()
+                    - self.e15() * b.e4()
+                    + self.e25() * b.e124()
+                    - self.e35() * b.e314()
+                    + self.e45() * b.e1()
+                    - self.e123() * b.e2345()
+                    - self.e124() * b.e25()
+                    + self.e314() * b.e35()
+                    + self.e234() * b.e1235()
+                    + self.e125() * b.e24()
+                    - self.e315() * b.e34()
+                    - self.e235() * b.e1234()
+                    + self.e145() * b.scalar()
+                    - self.e245() * b.e12()
+                    + self.e345() * b.e31()
+                    - self.e1234() * b.e235()
+                    + self.e1235() * b.e234()
+                    + self.e1245() * b.e2()
+                    - self.e3145() * b.e3()
+                    - self.e2345() * b.e123()
+                    - self.e12345() * b.e23(),
+                self.scalar() * b.e245() + self.e1() * b.e1245() + self.e2() * b.e45()
+                    - self.e3() * b.e2345()
+                    - self.e4() * b.e25()
+                    + self.e5() * b.e24()
+                    - self.e12() * b.e145()
+                    - self.e31() * b.e12345()
+                    + self.e23() * b.e345()
+                    + self.e14() * b.e125()
+                    + self.e24() * b.e5()
+                    - self.e34() * b.e235()
+                    - self.e15() * b.e124()
+                    - self.e25() * b.e4()
+                    + self.e35() * b.e234()
+                    + self.e45() * b.e2()
+                    - self.e123() * b.e3145()
+                    + self.e124() * b.e15()
+                    + self.e314() * b.e1235()
+                    - self.e234() * b.e35()
+                    - self.e125() * b.e14()
+                    - self.e315() * b.e1234()
+                    + self.e235() * b.e34()
+                    + self.e145() * b.e12()
+                    + self.e245() * b.scalar()
+                    - self.e345() * b.e23()
+                    - self.e1234() * b.e315()
+                    + self.e1235() * b.e314()
+                    - self.e1245() * b.e1()
+                    - self.e3145() * b.e123()
+                    + self.e2345() * b.e3()
+                    - self.e12345() * b.e31(),
+                self.scalar() * b.e345() - self.e1() * b.e3145()
+                    + self.e2() * b.e2345()
+                    + self.e3() * b.e45()
+                    - self.e4() * b.e35()
+                    + self.e5() * b.e34()
+                    - self.e12() * b.e12345()
+                    + self.e31() * b.e145()
+                    - self.e23() * b.e245()
+                    - self.e14() * b.e315()
+                    + self.e24() * b.e235()
+                    + self.e34() * b.e5()
+                    + self.e15() * b.e314()
+                    - self.e25() * b.e234()
+                    - self.e35() * b.e4()
+                    + self.e45() * b.e3()
+                    - self.e123() * b.e1245()
+                    + self.e124() * b.e1235()
+                    - self.e314() * b.e15()
+                    + self.e234() * b.e25()
+                    - self.e125() * b.e1234()
+                    + self.e315() * b.e14()
+                    - self.e235() * b.e24()
+                    - self.e145() * b.e31()
+                    + self.e245() * b.e23()
+                    + self.e345() * b.scalar()
+                    - self.e1234() * b.e125()
+                    + self.e1235() * b.e124()
+                    - self.e1245() * b.e123()
+                    + self.e3145() * b.e1()
+                    - self.e2345() * b.e2()
+                    - self.e12345() * b.e12(),
+            ),
+            Quadvector::new(
+                self.scalar() * b.e1234()
+                    + self.e1() * b.e234()
+                    + self.e2() * b.e314()
+                    + self.e3() * b.e124()
+                    - self.e4() * b.e123()
+                    - self.e5() * b.e12345()
+                    + self.e12() * b.e34()
+                    + self.e31() * b.e24()
+                    + self.e23() * b.e14()
+                    + self.e14() * b.e23()
+                    + self.e24() * b.e31()
+                    + self.e34() * b.e12()
+                    + self.e15() * b.e2345()
+                    + self.e25() * b.e3145()
+                    + self.e35() * b.e1245()
+                    - self.e45() * b.e1235()
+                    + self.e123() * b.e4()
+                    - self.e124() * b.e3()
+                    - self.e314() * b.e2()
+                    - self.e234() * b.e1()
+                    - self.e125() * b.e345()
+                    - self.e315() * b.e245()
+                    - self.e235() * b.e145()
+                    - self.e145() * b.e235()
+                    - self.e245() * b.e315()
+                    - self.e345() * b.e125()
+                    + self.e1234() * b.scalar()
+                    + self.e1235() * b.e45()
+                    - self.e1245() * b.e35()
+                    - self.e3145() * b.e25()
+                    - self.e2345() * b.e15()
+                    - self.e12345() * b.e5(),
+                self.scalar() * b.e1235()
+                    + self.e1() * b.e235()
+                    + self.e2() * b.e315()
+                    + self.e3() * b.e125()
+                    - self.e4() * b.e12345()
+                    - self.e5() * b.e123()
+                    + self.e12() * b.e35()
+                    + self.e31() * b.e25()
+                    + self.e23() * b.e15()
+                    + self.e14() * b.e2345()
+                    + self.e24() * b.e3145()
+                    + self.e34() * b.e1245()
+                    + self.e15() * b.e23()
+                    + self.e25() * b.e31()
+                    + self.e35() * b.e12()
+                    - self.e45() * b.e1234()
+                    + self.e123() * b.e5()
+                    - self.e124() * b.e345()
+                    - self.e314() * b.e245()
+                    - self.e234() * b.e145()
+                    - self.e125() * b.e3()
+                    - self.e315() * b.e2()
+                    - self.e235() * b.e1()
+                    - self.e145() * b.e234()
+                    - self.e245() * b.e314()
+                    - self.e345() * b.e124()
+                    + self.e1234() * b.e45()
+                    + self.e1235() * b.scalar()
+                    - self.e1245() * b.e34()
+                    - self.e3145() * b.e24()
+                    - self.e2345() * b.e14()
+                    - self.e12345() * b.e4(),
+                self.scalar() * b.e1245() + self.e1() * b.e245() - self.e2() * b.e145()
+                    + self.e3() * b.e12345()
+                    + self.e4() * b.e125()
+                    - self.e5() * b.e124()
+                    + self.e12() * b.e45()
+                    + self.e31() * b.e2345()
+                    - self.e23() * b.e3145()
+                    - self.e14() * b.e25()
+                    + self.e24() * b.e15()
+                    - self.e34() * b.e1235()
+                    + self.e15() * b.e24()
+                    - self.e25() * b.e14()
+                    + self.e35() * b.e1234()
+                    + self.e45() * b.e12()
+                    + self.e123() * b.e345()
+                    + self.e124() * b.e5()
+                    + self.e314() * b.e235()
+                    - self.e234() * b.e315()
+                    - self.e125() * b.e4()
+                    - self.e315() * b.e234()
+                    + self.e235() * b.e314()
+                    + self.e145() * b.e2()
+                    - self.e245() * b.e1()
+                    + self.e345() * b.e123()
+                    - self.e1234() * b.e35()
+                    + self.e1235() * b.e34()
+                    + self.e1245() * b.scalar()
+                    + self.e3145() * b.e23()
+                    - self.e2345() * b.e31()
+                    + self.e12345() * b.e3(),
+                self.scalar() * b.e3145() - self.e1() * b.e345()
+                    + self.e2() * b.e12345()
+                    + self.e3() * b.e145()
+                    + self.e4() * b.e315()
+                    - self.e5() * b.e314()
+                    - self.e12() * b.e2345()
+                    + self.e31() * b.e45()
+                    + self.e23() * b.e1245()
+                    + self.e14() * b.e35()
+                    - self.e24() * b.e1235()
+                    - self.e34() * b.e15()
+                    - self.e15() * b.e34()
+                    + self.e25() * b.e1234()
+                    + self.e35() * b.e14()
+                    + self.e45() * b.e31()
+                    + self.e123() * b.e245()
+                    - self.e124() * b.e235()
+                    + self.e314() * b.e5()
+                    + self.e234() * b.e125()
+                    + self.e125() * b.e234()
+                    - self.e315() * b.e4()
+                    - self.e235() * b.e124()
+                    - self.e145() * b.e3()
+                    + self.e245() * b.e123()
+                    + self.e345() * b.e1()
+                    - self.e1234() * b.e25()
+                    + self.e1235() * b.e24()
+                    - self.e1245() * b.e23()
+                    + self.e3145() * b.scalar()
+                    + self.e2345() * b.e12()
+                    + self.e12345() * b.e2(),
+                self.scalar() * b.e2345() + self.e1() * b.e12345() + self.e2() * b.e345()
+                    - self.e3() * b.e245()
+                    + self.e4() * b.e235()
+                    - self.e5() * b.e234()
+                    + self.e12() * b.e3145()
+                    - self.e31() * b.e1245()
+                    + self.e23() * b.e45()
+                    - self.e14() * b.e1235()
+                    - self.e24() * b.e35()
+                    + self.e34() * b.e25()
+                    + self.e15() * b.e1234()
+                    + self.e25() * b.e34()
+                    - self.e35() * b.e24()
+                    + self.e45() * b.e23()
+                    + self.e123() * b.e145()
+                    + self.e124() * b.e315()
+                    - self.e314() * b.e125()
+                    + self.e234() * b.e5()
+                    - self.e125() * b.e314()
+                    + self.e315() * b.e124()
+                    - self.e235() * b.e4()
+                    + self.e145() * b.e123()
+                    + self.e245() * b.e3()
+                    - self.e345() * b.e2()
+                    - self.e1234() * b.e15()
+                    + self.e1235() * b.e14()
+                    + self.e1245() * b.e31()
+                    - self.e3145() * b.e12()
+                    + self.e2345() * b.scalar()
+                    + self.e12345() * b.e1(),
+            ),
+            self.scalar() * b.e12345()
+                + self.e1() * b.e2345()
+                + self.e2() * b.e3145()
+                + self.e3() * b.e1245()
+                - self.e4() * b.e1235()
+                + self.e5() * b.e1234()
+                + self.e12() * b.e345()
+                + self.e31() * b.e245()
+                + self.e23() * b.e145()
+                + self.e14() * b.e235()
+                + self.e24() * b.e315()
+                + self.e34() * b.e125()
+                - self.e15() * b.e234()
+                - self.e25() * b.e314()
+                - self.e35() * b.e124()
+                + self.e45() * b.e123()
+                + self.e123() * b.e45()
+                - self.e124() * b.e35()
+                - self.e314() * b.e25()
+                - self.e234() * b.e15()
+                + self.e125() * b.e34()
+                + self.e315() * b.e24()
+                + self.e235() * b.e14()
+                + self.e145() * b.e23()
+                + self.e245() * b.e31()
+                + self.e345() * b.e12()
+                + self.e1234() * b.e5()
+                - self.e1235() * b.e4()
+                + self.e1245() * b.e3()
+                + self.e3145() * b.e2()
+                + self.e2345() * b.e1()
+                + self.e12345() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Multivector<F>);
+
+// Test
+#[cfg(test)]
+mod geometric_product {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn scalar_vector() {
+        // -4
+        let scalar1 = Scalar::new(-4.0);
+        // -1e1-4e2-2e3+1e4-4e5
+        let vector2 = Vector::new(-1.0, -4.0, -2.0, 1.0, -4.0);
+        let res = scalar1 * vector2;
+        // 4e1+16e2+8e3-4e4+16e5
+        assert_relative_eq!(res.e1(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 16.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e4(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e5(), 16.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_vector() {
+        // 4e1-2e2-4e3+4e4+1e5
+        let vector1 = Vector::new(4.0, -2.0, -4.0, 4.0, 1.0);
+        // 4e1+4e2-1e3-1e4-3e5
+        let vector2 = Vector::new(4.0, 4.0, -1.0, -1.0, -3.0);
+        let res = vector1 * vector2;
+        // 11+24e12-12e31+18e23-20e14-14e24+8e34-16e15+2e25+13e35-11e45
+        assert_relative_eq!(res.scalar(), 11.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e4(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e5(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 24.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), -12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 18.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e14(), -20.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e24(), -14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e34(), 8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e15(), -16.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e25(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e35(), 13.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e45(), -11.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e124(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e314(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e234(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e125(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e315(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e235(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e145(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e245(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e345(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1234(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1235(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1245(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3145(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2345(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12345(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_bivector() {
+        // 1e1+3e2+2e3-3e4+4e5
+        let vector1 = Vector::new(1.0, 3.0, 2.0, -3.0, 4.0);
+        // 3e12-1e31+4e23+1e14-3e24-2e34-2e15+4e25+4e35-2e45
+        let bivector2 = Bivector::new(3.0, -1.0, 4.0, 1.0, -3.0, -2.0, -2.0, 4.0, 4.0, -2.0);
+        let res = vector1 * bivector2;
+        // -16e1+2e2+23e3-20e4+24e5+7e123-15e124+7e314-12e234+22e125-12e315+20e235-4e145-6e245
+        assert_relative_eq!(res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -16.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 23.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e4(), -20.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e5(), 24.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e14(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e24(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e34(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e15(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e25(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e35(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e45(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 7.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e124(), -15.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e314(), 7.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e234(), -12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e125(), 22.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e315(), -12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e235(), 20.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e145(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e245(), -6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e345(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1234(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1235(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1245(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3145(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2345(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12345(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_bivector() {
+        // -1e12+3e31+4e23-3e14-1e24+5e34+2e15-3e25-2e35+5e45
+        let bivector1 = Bivector::new(-1.0, 3.0, 4.0, -3.0, -1.0, 5.0, 2.0, -3.0, -2.0, 5.0);
+        // -1e12-4e31+2e23-2e14+4e24+4e34+1e15+1e25-1e35-3e45
+        let bivector2 = Bivector::new(-1.0, -4.0, 2.0, -2.0, 4.0, 4.0, 1.0, 1.0, -1.0, -3.0);
+        let res = bivector1 * bivector2;
+        // -33+41e12-4e31+36e23-48e14+11e24-25e34+26e15-18e25-34e35-15e45-7e1234+26e1235+2e1245-35e3145-2e2345
+        assert_relative_eq!(res.scalar(), -33.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e4(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e5(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 41.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 36.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e14(), -48.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e24(), 11.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e34(), -25.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e15(), 26.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e25(), -18.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e35(), -34.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e45(), -15.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e124(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e314(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e234(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e125(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e315(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e235(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e145(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e245(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e345(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1234(), -7.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1235(), 26.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1245(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3145(), -35.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2345(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12345(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn trivector_quadvector() {
+        // -1e123+2e124+1e314+5e234+2e125-3e315+1e235+5e145+2e245+5e345
+        let trivector1 = Trivector::new(-1.0, 2.0, 1.0, 5.0, 2.0, -3.0, 1.0, 5.0, 2.0, 5.0);
+        // -4e1234-2e1235+5e1245-1e3145+1e2345
+        let quadvector2 = Quadvector::new(-4.0, -2.0, 5.0, -1.0, 1.0);
+        let res = trivector1 * quadvector2;
+        // -33e1+10e2+3e3-18e4-16e5+28e123+8e124+7e314+23e234+26e125+31e315+13e235-5e145-15e245+9e345
+        assert_relative_eq!(res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -33.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e4(), -18.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e5(), -16.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e14(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e24(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e34(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e15(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e25(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e35(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e45(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 28.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e124(), 8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e314(), 7.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e234(), 23.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e125(), 26.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e315(), 31.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e235(), 13.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e145(), -5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e245(), -15.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e345(), 9.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1234(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1235(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1245(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3145(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2345(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12345(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn versor_vector() {
+        // -3-4e12+4e31+2e23-3e14+1e24+2e34-4e15+1e25+2e35+1e45-3e1234-1e1235-4e1245+5e3145+5e2345
+        let versor1 = Versor::new(
+            -3.0,
+            Bivector::new(-4.0, 4.0, 2.0, -3.0, 1.0, 2.0, -4.0, 1.0, 2.0, 1.0),
+            Quadvector::new(-3.0, -1.0, -4.0, 5.0, 5.0),
+        );
+        // 3e1-1e2+4e3+4e4-1e5
+        let vector2 = Vector::new(3.0, -1.0, 4.0, 4.0, -1.0);
+        let res = versor1 * vector2;
+        // -37e1+28e2+12e3-9e4+4e5-27e123-8e124+16e234+23e125-47e315-25e235+6e145+26e245+14e345+1e12345
+        assert_relative_eq!(res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -37.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 28.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e4(), -9.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e5(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e14(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e24(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e34(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e15(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e25(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e35(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e45(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), -27.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e124(), -8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e314(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e234(), 16.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e125(), 23.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e315(), -47.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e235(), -25.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e145(), 6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e245(), 26.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e345(), 14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1234(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1235(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1245(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3145(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2345(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12345(), 1.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_versor() {
+        // -4-1e1+5e2+4e3-3e4+1e5+3e12-4e31-4e23-4e14+1e24-4e34+1e15-2e25-2e35+4e45-4e123+4e124-2e314-4e234+3e125+1e315-1e235+4e145+5e245+5e345-1e1234-2e1235-4e1245-3e3145+4e2345+4e12345
+        let mvec1 = Multivector::new(
+            -4.0,
+            Vector::new(-1.0, 5.0, 4.0, -3.0, 1.0),
+            Bivector::new(3.0, -4.0, -4.0, -4.0, 1.0, -4.0, 1.0, -2.0, -2.0, 4.0),
+            Trivector::new(-4.0, 4.0, -2.0, -4.0, 3.0, 1.0, -1.0, 4.0, 5.0, 5.0),
+            Quadvector::new(-1.0, -2.0, -4.0, -3.0, 4.0),
+            4.0,
+        );
+        // -4-3e12-4e31-3e23-1e14+5e24-3e34+3e15+4e25-4e35-3e45-1e1234+5e1235-1e1245-2e3145+3e2345
+        let versor2 = Versor::new(
+            -4.0,
+            Bivector::new(-3.0, -4.0, -3.0, -1.0, 5.0, -3.0, 3.0, 4.0, -4.0, -3.0),
+            Quadvector::new(-1.0, 5.0, -1.0, -2.0, 3.0),
+        );
+        let res = mvec1 * versor2;
+        // -44-27e1-52e2-82e3+27e4+38e5+23e12+56e31+2e23+76e14-37e24+54e34+49e15+12e25+56e35-29e45-19e123-28e124-73e314-18e234-36e125+3e315-20e235-25e245-8e345-18e1234-37e1235+50e1245+11e3145-3e2345-21e12345
+        assert_relative_eq!(res.scalar(), -44.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -27.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -52.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), -82.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e4(), 27.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e5(), 38.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 23.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 56.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e14(), 76.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e24(), -37.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e34(), 54.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e15(), 49.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e25(), 12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e35(), 56.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e45(), -29.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), -19.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e124(), -28.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e314(), -73.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e234(), -18.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e125(), -36.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e315(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e235(), -20.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e145(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e245(), -25.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e345(), -8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1234(), -18.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1235(), -37.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1245(), 50.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3145(), 11.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2345(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12345(), -21.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_multivector() {
+        // -3+4e1-1e2+2e3+3e4-2e5+3e12+5e31-4e23-4e14-4e24+5e34+4e15-4e25-4e35-1e45+4e123-2e124+3e314-4e234-2e125-2e315+3e235+3e145-4e245-2e345+4e1234+2e1235+4e1245-3e3145-2e2345+4e12345
+        let mvec1 = Multivector::new(
+            -3.0,
+            Vector::new(4.0, -1.0, 2.0, 3.0, -2.0),
+            Bivector::new(3.0, 5.0, -4.0, -4.0, -4.0, 5.0, 4.0, -4.0, -4.0, -1.0),
+            Trivector::new(4.0, -2.0, 3.0, -4.0, -2.0, -2.0, 3.0, 3.0, -4.0, -2.0),
+            Quadvector::new(4.0, 2.0, 4.0, -3.0, -2.0),
+            4.0,
+        );
+        // -2+3e1-3e2-4e3+5e4+3e5-2e12+3e31+5e23-2e14-4e24+5e34+3e15+4e25-2e35+2e45+2e123+3e124+5e314+4e234-1e125+4e315+4e235+3e145+5e245+2e345-2e1234+2e1235+3e1245+2e3145-1e2345-2e12345
+        let mvec2 = Multivector::new(
+            -2.0,
+            Vector::new(3.0, -3.0, -4.0, 5.0, 3.0),
+            Bivector::new(-2.0, 3.0, 5.0, -2.0, -4.0, 5.0, 3.0, 4.0, -2.0, 2.0),
+            Trivector::new(2.0, 3.0, 5.0, 4.0, -1.0, 4.0, 4.0, 3.0, 5.0, 2.0),
+            Quadvector::new(-2.0, 2.0, 3.0, 2.0, -1.0),
+            -2.0,
+        );
+        let res = mvec1 * mvec2;
+        // -24+37e1-96e2+45e3+21e4-57e5+120e12+17e31+84e23-34e14-14e24-12e34+35e15-59e25+17e35+95e45-50e123-28e124+10e314-94e234+69e125+43e315-21e235-59e145-60e245+44e345+2e1234-7e1235+49e1245-3e3145-37e2345-16e12345
+        assert_relative_eq!(res.scalar(), -24.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 37.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -96.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 45.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e4(), 21.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e5(), -57.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 120.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 17.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 84.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e14(), -34.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e24(), -14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e34(), -12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e15(), 35.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e25(), -59.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e35(), 17.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e45(), 95.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), -50.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e124(), -28.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e314(), 10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e234(), -94.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e125(), 69.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e315(), 43.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e235(), -21.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e145(), -59.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e245(), -60.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e345(), 44.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1234(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1235(), -7.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1245(), 49.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3145(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2345(), -37.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12345(), -16.0, max_relative = 0.000001);
+    }
+}
@@ -0,0 +1,3257 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    bivector::Bivector, multivector::Multivector, pseudoscalar::Pseudoscalar,
+    quadvector::Quadvector, scalar::Scalar, trivector::Trivector, vector::Vector, versor::Versor,
+};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::BitOr;
+
+// Inner Product
+
+// Scalar-Scalar
+impl<F: Float> BitOr for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitor(self, b: Scalar<F>) -> Scalar<F> {
+        Scalar(self.0 * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Scalar<F>);
+
+// Scalar-Vector
+impl<F: Float> BitOr<Vector<F>> for Scalar<F> {
+    type Output = Vector<F>;
+    fn bitor(self, b: Vector<F>) -> Vector<F> {
+        Vector::new(
+            self.0 * b.e1(),
+            self.0 * b.e2(),
+            self.0 * b.e3(),
+            self.0 * b.e4(),
+            self.0 * b.e5(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Vector<F>);
+
+// Vector-Scalar
+impl<F: Float> BitOr<Scalar<F>> for Vector<F> {
+    type Output = Vector<F>;
+    fn bitor(self, b: Scalar<F>) -> Vector<F> {
+        Vector::new(
+            self.e1() * b.0,
+            self.e2() * b.0,
+            self.e3() * b.0,
+            self.e4() * b.0,
+            self.e5() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Vector<F>, Scalar<F>);
+
+// Scalar-Bivector
+impl<F: Float> BitOr<Bivector<F>> for Scalar<F> {
+    type Output = Bivector<F>;
+    fn bitor(self, b: Bivector<F>) -> Bivector<F> {
+        Bivector::new(
+            self.0 * b.e12(),
+            self.0 * b.e31(),
+            self.0 * b.e23(),
+            self.0 * b.e14(),
+            self.0 * b.e24(),
+            self.0 * b.e34(),
+            self.0 * b.e15(),
+            self.0 * b.e25(),
+            self.0 * b.e35(),
+            self.0 * b.e45(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Bivector<F>);
+
+// Bivector-Scalar
+impl<F: Float> BitOr<Scalar<F>> for Bivector<F> {
+    type Output = Bivector<F>;
+    fn bitor(self, b: Scalar<F>) -> Bivector<F> {
+        Bivector::new(
+            self.e12() * b.0,
+            self.e31() * b.0,
+            self.e23() * b.0,
+            self.e14() * b.0,
+            self.e24() * b.0,
+            self.e34() * b.0,
+            self.e15() * b.0,
+            self.e25() * b.0,
+            self.e35() * b.0,
+            self.e45() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Bivector<F>, Scalar<F>);
+
+// Scalar-Trivector
+impl<F: Float> BitOr<Trivector<F>> for Scalar<F> {
+    type Output = Trivector<F>;
+    fn bitor(self, b: Trivector<F>) -> Trivector<F> {
+        Trivector::new(
+            self.0 * b.e123(),
+            self.0 * b.e124(),
+            self.0 * b.e314(),
+            self.0 * b.e234(),
+            self.0 * b.e125(),
+            self.0 * b.e315(),
+            self.0 * b.e235(),
+            self.0 * b.e145(),
+            self.0 * b.e245(),
+            self.0 * b.e345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Trivector<F>);
+
+// Trivector-Scalar
+impl<F: Float> BitOr<Scalar<F>> for Trivector<F> {
+    type Output = Trivector<F>;
+    fn bitor(self, b: Scalar<F>) -> Trivector<F> {
+        Trivector::new(
+            self.e123() * b.0,
+            self.e124() * b.0,
+            self.e314() * b.0,
+            self.e234() * b.0,
+            self.e125() * b.0,
+            self.e315() * b.0,
+            self.e235() * b.0,
+            self.e145() * b.0,
+            self.e245() * b.0,
+            self.e345() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Trivector<F>, Scalar<F>);
+
+// Scalar-Quadvector
+impl<F: Float> BitOr<Quadvector<F>> for Scalar<F> {
+    type Output = Quadvector<F>;
+    fn bitor(self, b: Quadvector<F>) -> Quadvector<F> {
+        Quadvector::new(
+            self.0 * b.e1234(),
+            self.0 * b.e1235(),
+            self.0 * b.e1245(),
+            self.0 * b.e3145(),
+            self.0 * b.e2345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Quadvector<F>);
+
+// Quadvector-Scalar
+impl<F: Float> BitOr<Scalar<F>> for Quadvector<F> {
+    type Output = Quadvector<F>;
+    fn bitor(self, b: Scalar<F>) -> Quadvector<F> {
+        Quadvector::new(
+            self.e1234() * b.0,
+            self.e1235() * b.0,
+            self.e1245() * b.0,
+            self.e3145() * b.0,
+            self.e2345() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Quadvector<F>, Scalar<F>);
+
+// Scalar-Pseudoscalar
+impl<F: Float> BitOr<Pseudoscalar<F>> for Scalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitor(self, b: Pseudoscalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.0 * b.e12345())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Scalar
+impl<F: Float> BitOr<Scalar<F>> for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitor(self, b: Scalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.e12345() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Scalar<F>);
+
+// Scalar-Versor
+impl<F: Float> BitOr<Versor<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 * b.scalar(),
+            Vector::zero(),
+            Bivector::new(
+                self.0 * b.e12(),
+                self.0 * b.e31(),
+                self.0 * b.e23(),
+                self.0 * b.e14(),
+                self.0 * b.e24(),
+                self.0 * b.e34(),
+                self.0 * b.e15(),
+                self.0 * b.e25(),
+                self.0 * b.e35(),
+                self.0 * b.e45(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.0 * b.e1234(),
+                self.0 * b.e1235(),
+                self.0 * b.e1245(),
+                self.0 * b.e3145(),
+                self.0 * b.e2345(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Versor<F>);
+
+// Versor-Scalar
+impl<F: Float> BitOr<Scalar<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.0,
+            Vector::zero(),
+            Bivector::new(
+                self.e12() * b.0,
+                self.e31() * b.0,
+                self.e23() * b.0,
+                self.e14() * b.0,
+                self.e24() * b.0,
+                self.e34() * b.0,
+                self.e15() * b.0,
+                self.e25() * b.0,
+                self.e35() * b.0,
+                self.e45() * b.0,
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e1234() * b.0,
+                self.e1235() * b.0,
+                self.e1245() * b.0,
+                self.e3145() * b.0,
+                self.e2345() * b.0,
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Versor<F>, Scalar<F>);
+
+// Scalar-Multivector
+impl<F: Float> BitOr<Multivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 * b.scalar(),
+            Vector::new(
+                self.0 * b.e1(),
+                self.0 * b.e2(),
+                self.0 * b.e3(),
+                self.0 * b.e4(),
+                self.0 * b.e5(),
+            ),
+            Bivector::new(
+                self.0 * b.e12(),
+                self.0 * b.e31(),
+                self.0 * b.e23(),
+                self.0 * b.e14(),
+                self.0 * b.e24(),
+                self.0 * b.e34(),
+                self.0 * b.e15(),
+                self.0 * b.e25(),
+                self.0 * b.e35(),
+                self.0 * b.e45(),
+            ),
+            Trivector::new(
+                self.0 * b.e123(),
+                self.0 * b.e124(),
+                self.0 * b.e314(),
+                self.0 * b.e234(),
+                self.0 * b.e125(),
+                self.0 * b.e315(),
+                self.0 * b.e235(),
+                self.0 * b.e145(),
+                self.0 * b.e245(),
+                self.0 * b.e345(),
+            ),
+            Quadvector::new(
+                self.0 * b.e1234(),
+                self.0 * b.e1235(),
+                self.0 * b.e1245(),
+                self.0 * b.e3145(),
+                self.0 * b.e2345(),
+            ),
+            self.0 * b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Multivector<F>);
+
+// Multivector-Scalar
+impl<F: Float> BitOr<Scalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.0,
+            Vector::new(
+                self.e1() * b.0,
+                self.e2() * b.0,
+                self.e3() * b.0,
+                self.e4() * b.0,
+                self.e5() * b.0,
+            ),
+            Bivector::new(
+                self.e12() * b.0,
+                self.e31() * b.0,
+                self.e23() * b.0,
+                self.e14() * b.0,
+                self.e24() * b.0,
+                self.e34() * b.0,
+                self.e15() * b.0,
+                self.e25() * b.0,
+                self.e35() * b.0,
+                self.e45() * b.0,
+            ),
+            Trivector::new(
+                self.e123() * b.0,
+                self.e124() * b.0,
+                self.e314() * b.0,
+                self.e234() * b.0,
+                self.e125() * b.0,
+                self.e315() * b.0,
+                self.e235() * b.0,
+                self.e145() * b.0,
+                self.e245() * b.0,
+                self.e345() * b.0,
+            ),
+            Quadvector::new(
+                self.e1234() * b.0,
+                self.e1235() * b.0,
+                self.e1245() * b.0,
+                self.e3145() * b.0,
+                self.e2345() * b.0,
+            ),
+            self.e12345() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Scalar<F>);
+
+// Vector-Vector
+impl<F: Float> BitOr for Vector<F> {
+    type Output = Scalar<F>;
+    fn bitor(self, b: Vector<F>) -> Scalar<F> {
+        Scalar(
+            self.e1() * b.e1() + self.e2() * b.e2() + self.e3() * b.e3() + self.e4() * b.e4()
+                - self.e5() * b.e5(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Vector<F>, Vector<F>);
+
+// Vector-Bivector
+impl<F: Float> BitOr<Bivector<F>> for Vector<F> {
+    type Output = Vector<F>;
+    fn bitor(self, b: Bivector<F>) -> Vector<F> {
+        Vector::new(
+            -self.e2() * b.e12() + self.e3() * b.e31() - self.e4() * b.e14() + self.e5() * b.e15(),
+            self.e1() * b.e12() - self.e3() * b.e23() - self.e4() * b.e24() + self.e5() * b.e25(),
+            -self.e1() * b.e31() + self.e2() * b.e23() - self.e4() * b.e34() + self.e5() * b.e35(),
+            self.e1() * b.e14() + self.e2() * b.e24() + self.e3() * b.e34() + self.e5() * b.e45(),
+            self.e1() * b.e15() + self.e2() * b.e25() + self.e3() * b.e35() + self.e4() * b.e45(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Vector<F>, Bivector<F>);
+
+// Bivector-Vector
+impl<F: Float> BitOr<Vector<F>> for Bivector<F> {
+    type Output = Vector<F>;
+    fn bitor(self, b: Vector<F>) -> Vector<F> {
+        Vector::new(
+            self.e12() * b.e2() - self.e31() * b.e3() + self.e14() * b.e4() - self.e15() * b.e5(),
+            -self.e12() * b.e1() + self.e23() * b.e3() + self.e24() * b.e4() - self.e25() * b.e5(),
+            self.e31() * b.e1() - self.e23() * b.e2() + self.e34() * b.e4() - self.e35() * b.e5(),
+            -self.e14() * b.e1() - self.e24() * b.e2() - self.e34() * b.e3() - self.e45() * b.e5(),
+            -self.e15() * b.e1() - self.e25() * b.e2() - self.e35() * b.e3() - self.e45() * b.e4(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Bivector<F>, Vector<F>);
+
+// Vector-Trivector
+impl<F: Float> BitOr<Trivector<F>> for Vector<F> {
+    type Output = Bivector<F>;
+    fn bitor(self, b: Trivector<F>) -> Bivector<F> {
+        Bivector::new(
+            self.e3() * b.e123() + self.e4() * b.e124() - self.e5() * b.e125(),
+            self.e2() * b.e123() + self.e4() * b.e314() - self.e5() * b.e315(),
+            self.e1() * b.e123() + self.e4() * b.e234() - self.e5() * b.e235(),
+            -self.e2() * b.e124() + self.e3() * b.e314() - self.e5() * b.e145(),
+            self.e1() * b.e124() - self.e3() * b.e234() - self.e5() * b.e245(),
+            -self.e1() * b.e314() + self.e2() * b.e234() - self.e5() * b.e345(),
+            -self.e2() * b.e125() + self.e3() * b.e315() - self.e4() * b.e145(),
+            self.e1() * b.e125() - self.e3() * b.e235() - self.e4() * b.e245(),
+            -self.e1() * b.e315() + self.e2() * b.e235() - self.e4() * b.e345(),
+            self.e1() * b.e145() + self.e2() * b.e245() + self.e3() * b.e345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Vector<F>, Trivector<F>);
+
+// Trivector-Vector
+impl<F: Float> BitOr<Vector<F>> for Trivector<F> {
+    type Output = Bivector<F>;
+    fn bitor(self, b: Vector<F>) -> Bivector<F> {
+        Bivector::new(
+            self.e123() * b.e3() + self.e124() * b.e4() - self.e125() * b.e5(),
+            self.e123() * b.e2() + self.e314() * b.e4() - self.e315() * b.e5(),
+            self.e123() * b.e1() + self.e234() * b.e4() - self.e235() * b.e5(),
+            -self.e124() * b.e2() + self.e314() * b.e3() - self.e145() * b.e5(),
+            self.e124() * b.e1() - self.e234() * b.e3() - self.e245() * b.e5(),
+            -self.e314() * b.e1() + self.e234() * b.e2() - self.e345() * b.e5(),
+            -self.e125() * b.e2() + self.e315() * b.e3() - self.e145() * b.e4(),
+            self.e125() * b.e1() - self.e235() * b.e3() - self.e245() * b.e4(),
+            -self.e315() * b.e1() + self.e235() * b.e2() - self.e345() * b.e4(),
+            self.e145() * b.e1() + self.e245() * b.e2() + self.e345() * b.e3(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Trivector<F>, Vector<F>);
+
+// Vector-Quadvector
+impl<F: Float> BitOr<Quadvector<F>> for Vector<F> {
+    type Output = Trivector<F>;
+    fn bitor(self, b: Quadvector<F>) -> Trivector<F> {
+        Trivector::new(
+            -self.e4() * b.e1234() + self.e5() * b.e1235(),
+            self.e3() * b.e1234() + self.e5() * b.e1245(),
+            self.e2() * b.e1234() + self.e5() * b.e3145(),
+            self.e1() * b.e1234() + self.e5() * b.e2345(),
+            self.e3() * b.e1235() + self.e4() * b.e1245(),
+            self.e2() * b.e1235() + self.e4() * b.e3145(),
+            self.e1() * b.e1235() + self.e4() * b.e2345(),
+            -self.e2() * b.e1245() + self.e3() * b.e3145(),
+            self.e1() * b.e1245() - self.e3() * b.e2345(),
+            -self.e1() * b.e3145() + self.e2() * b.e2345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Vector<F>, Quadvector<F>);
+
+// Quadvector-Vector
+impl<F: Float> BitOr<Vector<F>> for Quadvector<F> {
+    type Output = Trivector<F>;
+    fn bitor(self, b: Vector<F>) -> Trivector<F> {
+        Trivector::new(
+            self.e1234() * b.e4() - self.e1235() * b.e5(),
+            -self.e1234() * b.e3() - self.e1245() * b.e5(),
+            -self.e1234() * b.e2() - self.e3145() * b.e5(),
+            -self.e1234() * b.e1() - self.e2345() * b.e5(),
+            -self.e1235() * b.e3() - self.e1245() * b.e4(),
+            -self.e1235() * b.e2() - self.e3145() * b.e4(),
+            -self.e1235() * b.e1() - self.e2345() * b.e4(),
+            self.e1245() * b.e2() - self.e3145() * b.e3(),
+            -self.e1245() * b.e1() + self.e2345() * b.e3(),
+            self.e3145() * b.e1() - self.e2345() * b.e2(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Quadvector<F>, Vector<F>);
+
+// Vector-Pseudoscalar
+impl<F: Float> BitOr<Pseudoscalar<F>> for Vector<F> {
+    type Output = Quadvector<F>;
+    fn bitor(self, b: Pseudoscalar<F>) -> Quadvector<F> {
+        Quadvector::new(
+            -self.e5() * b.e12345(),
+            -self.e4() * b.e12345(),
+            self.e3() * b.e12345(),
+            self.e2() * b.e12345(),
+            self.e1() * b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Vector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Vector
+impl<F: Float> BitOr<Vector<F>> for Pseudoscalar<F> {
+    type Output = Quadvector<F>;
+    fn bitor(self, b: Vector<F>) -> Quadvector<F> {
+        Quadvector::new(
+            -self.e12345() * b.e5(),
+            -self.e12345() * b.e4(),
+            self.e12345() * b.e3(),
+            self.e12345() * b.e2(),
+            self.e12345() * b.e1(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Vector<F>);
+
+// Vector-Versor
+impl<F: Float> BitOr<Versor<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                self.e1() * b.scalar() - self.e2() * b.e12() + self.e3() * b.e31()
+                    - self.e4() * b.e14()
+                    + self.e5() * b.e15(),
+                self.e1() * b.e12() + self.e2() * b.scalar()
+                    - self.e3() * b.e23()
+                    - self.e4() * b.e24()
+                    + self.e5() * b.e25(),
+                -self.e1() * b.e31() + self.e2() * b.e23() + self.e3() * b.scalar()
+                    - self.e4() * b.e34()
+                    + self.e5() * b.e35(),
+                self.e1() * b.e14()
+                    + self.e2() * b.e24()
+                    + self.e3() * b.e34()
+                    + self.e4() * b.scalar()
+                    + self.e5() * b.e45(),
+                self.e1() * b.e15()
+                    + self.e2() * b.e25()
+                    + self.e3() * b.e35()
+                    + self.e4() * b.e45()
+                    + self.e5() * b.scalar(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                -self.e4() * b.e1234() + self.e5() * b.e1235(),
+                self.e3() * b.e1234() + self.e5() * b.e1245(),
+                self.e2() * b.e1234() + self.e5() * b.e3145(),
+                self.e1() * b.e1234() + self.e5() * b.e2345(),
+                self.e3() * b.e1235() + self.e4() * b.e1245(),
+                self.e2() * b.e1235() + self.e4() * b.e3145(),
+                self.e1() * b.e1235() + self.e4() * b.e2345(),
+                -self.e2() * b.e1245() + self.e3() * b.e3145(),
+                self.e1() * b.e1245() - self.e3() * b.e2345(),
+                -self.e1() * b.e3145() + self.e2() * b.e2345(),
+            ),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Vector<F>, Versor<F>);
+
+// Versor-Vector
+impl<F: Float> BitOr<Vector<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                self.scalar() * b.e1() + self.e12() * b.e2() - self.e31() * b.e3()
+                    + self.e14() * b.e4()
+                    - self.e15() * b.e5(),
+                self.scalar() * b.e2() - self.e12() * b.e1()
+                    + self.e23() * b.e3()
+                    + self.e24() * b.e4()
+                    - self.e25() * b.e5(),
+                self.scalar() * b.e3() + self.e31() * b.e1() - self.e23() * b.e2()
+                    + self.e34() * b.e4()
+                    - self.e35() * b.e5(),
+                self.scalar() * b.e4()
+                    - self.e14() * b.e1()
+                    - self.e24() * b.e2()
+                    - self.e34() * b.e3()
+                    - self.e45() * b.e5(),
+                self.scalar() * b.e5()
+                    - self.e15() * b.e1()
+                    - self.e25() * b.e2()
+                    - self.e35() * b.e3()
+                    - self.e45() * b.e4(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                self.e1234() * b.e4() - self.e1235() * b.e5(),
+                -self.e1234() * b.e3() - self.e1245() * b.e5(),
+                -self.e1234() * b.e2() - self.e3145() * b.e5(),
+                -self.e1234() * b.e1() - self.e2345() * b.e5(),
+                -self.e1235() * b.e3() - self.e1245() * b.e4(),
+                -self.e1235() * b.e2() - self.e3145() * b.e4(),
+                -self.e1235() * b.e1() - self.e2345() * b.e4(),
+                self.e1245() * b.e2() - self.e3145() * b.e3(),
+                -self.e1245() * b.e1() + self.e2345() * b.e3(),
+                self.e3145() * b.e1() - self.e2345() * b.e2(),
+            ),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Versor<F>, Vector<F>);
+
+// Vector-Multivector
+impl<F: Float> BitOr<Multivector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1() * b.e1() + self.e2() * b.e2() + self.e3() * b.e3() + self.e4() * b.e4()
+                - self.e5() * b.e5(),
+            Vector::new(
+                self.e1() * b.scalar() - self.e2() * b.e12() + self.e3() * b.e31()
+                    - self.e4() * b.e14()
+                    + self.e5() * b.e15(),
+                self.e1() * b.e12() + self.e2() * b.scalar()
+                    - self.e3() * b.e23()
+                    - self.e4() * b.e24()
+                    + self.e5() * b.e25(),
+                -self.e1() * b.e31() + self.e2() * b.e23() + self.e3() * b.scalar()
+                    - self.e4() * b.e34()
+                    + self.e5() * b.e35(),
+                self.e1() * b.e14()
+                    + self.e2() * b.e24()
+                    + self.e3() * b.e34()
+                    + self.e4() * b.scalar()
+                    + self.e5() * b.e45(),
+                self.e1() * b.e15()
+                    + self.e2() * b.e25()
+                    + self.e3() * b.e35()
+                    + self.e4() * b.e45()
+                    + self.e5() * b.scalar(),
+            ),
+            Bivector::new(
+                self.e3() * b.e123() + self.e4() * b.e124() - self.e5() * b.e125(),
+                self.e2() * b.e123() + self.e4() * b.e314() - self.e5() * b.e315(),
+                self.e1() * b.e123() + self.e4() * b.e234() - self.e5() * b.e235(),
+                -self.e2() * b.e124() + self.e3() * b.e314() - self.e5() * b.e145(),
+                self.e1() * b.e124() - self.e3() * b.e234() - self.e5() * b.e245(),
+                -self.e1() * b.e314() + self.e2() * b.e234() - self.e5() * b.e345(),
+                -self.e2() * b.e125() + self.e3() * b.e315() - self.e4() * b.e145(),
+                self.e1() * b.e125() - self.e3() * b.e235() - self.e4() * b.e245(),
+                -self.e1() * b.e315() + self.e2() * b.e235() - self.e4() * b.e345(),
+                self.e1() * b.e145() + self.e2() * b.e245() + self.e3() * b.e345(),
+            ),
+            Trivector::new(
+                -self.e4() * b.e1234() + self.e5() * b.e1235(),
+                self.e3() * b.e1234() + self.e5() * b.e1245(),
+                self.e2() * b.e1234() + self.e5() * b.e3145(),
+                self.e1() * b.e1234() + self.e5() * b.e2345(),
+                self.e3() * b.e1235() + self.e4() * b.e1245(),
+                self.e2() * b.e1235() + self.e4() * b.e3145(),
+                self.e1() * b.e1235() + self.e4() * b.e2345(),
+                -self.e2() * b.e1245() + self.e3() * b.e3145(),
+                self.e1() * b.e1245() - self.e3() * b.e2345(),
+                -self.e1() * b.e3145() + self.e2() * b.e2345(),
+            ),
+            Quadvector::new(
+                -self.e5() * b.e12345(),
+                -self.e4() * b.e12345(),
+                self.e3() * b.e12345(),
+                self.e2() * b.e12345(),
+                self.e1() * b.e12345(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Vector<F>, Multivector<F>);
+
+// Multivector-Vector
+impl<F: Float> BitOr<Vector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1() * b.e1() + self.e2() * b.e2() + self.e3() * b.e3() + self.e4() * b.e4()
+                - self.e5() * b.e5(),
+            Vector::new(
+                self.scalar() * b.e1() + self.e12() * b.e2() - self.e31() * b.e3()
+                    + self.e14() * b.e4()
+                    - self.e15() * b.e5(),
+                self.scalar() * b.e2() - self.e12() * b.e1()
+                    + self.e23() * b.e3()
+                    + self.e24() * b.e4()
+                    - self.e25() * b.e5(),
+                self.scalar() * b.e3() + self.e31() * b.e1() - self.e23() * b.e2()
+                    + self.e34() * b.e4()
+                    - self.e35() * b.e5(),
+                self.scalar() * b.e4()
+                    - self.e14() * b.e1()
+                    - self.e24() * b.e2()
+                    - self.e34() * b.e3()
+                    - self.e45() * b.e5(),
+                self.scalar() * b.e5()
+                    - self.e15() * b.e1()
+                    - self.e25() * b.e2()
+                    - self.e35() * b.e3()
+                    - self.e45() * b.e4(),
+            ),
+            Bivector::new(
+                self.e123() * b.e3() + self.e124() * b.e4() - self.e125() * b.e5(),
+                self.e123() * b.e2() + self.e314() * b.e4() - self.e315() * b.e5(),
+                self.e123() * b.e1() + self.e234() * b.e4() - self.e235() * b.e5(),
+                -self.e124() * b.e2() + self.e314() * b.e3() - self.e145() * b.e5(),
+                self.e124() * b.e1() - self.e234() * b.e3() - self.e245() * b.e5(),
+                -self.e314() * b.e1() + self.e234() * b.e2() - self.e345() * b.e5(),
+                -self.e125() * b.e2() + self.e315() * b.e3() - self.e145() * b.e4(),
+                self.e125() * b.e1() - self.e235() * b.e3() - self.e245() * b.e4(),
+                -self.e315() * b.e1() + self.e235() * b.e2() - self.e345() * b.e4(),
+                self.e145() * b.e1() + self.e245() * b.e2() + self.e345() * b.e3(),
+            ),
+            Trivector::new(
+                self.e1234() * b.e4() - self.e1235() * b.e5(),
+                -self.e1234() * b.e3() - self.e1245() * b.e5(),
+                -self.e1234() * b.e2() - self.e3145() * b.e5(),
+                -self.e1234() * b.e1() - self.e2345() * b.e5(),
+                -self.e1235() * b.e3() - self.e1245() * b.e4(),
+                -self.e1235() * b.e2() - self.e3145() * b.e4(),
+                -self.e1235() * b.e1() - self.e2345() * b.e4(),
+                self.e1245() * b.e2() - self.e3145() * b.e3(),
+                -self.e1245() * b.e1() + self.e2345() * b.e3(),
+                self.e3145() * b.e1() - self.e2345() * b.e2(),
+            ),
+            Quadvector::new(
+                -self.e12345() * b.e5(),
+                -self.e12345() * b.e4(),
+                self.e12345() * b.e3(),
+                self.e12345() * b.e2(),
+                self.e12345() * b.e1(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Vector<F>);
+
+// Bivector-Bivector
+impl<F: Float> BitOr for Bivector<F> {
+    type Output = Scalar<F>;
+    fn bitor(self, b: Bivector<F>) -> Scalar<F> {
+        Scalar(
+            -self.e12() * b.e12()
+                - self.e31() * b.e31()
+                - self.e23() * b.e23()
+                - self.e14() * b.e14()
+                - self.e24() * b.e24()
+                - self.e34() * b.e34()
+                + self.e15() * b.e15()
+                + self.e25() * b.e25()
+                + self.e35() * b.e35()
+                + self.e45() * b.e45(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Bivector<F>, Bivector<F>);
+
+// Bivector-Trivector
+impl<F: Float> BitOr<Trivector<F>> for Bivector<F> {
+    type Output = Vector<F>;
+    fn bitor(self, b: Trivector<F>) -> Vector<F> {
+        Vector::new(
+            -self.e23() * b.e123() - self.e24() * b.e124()
+                + self.e34() * b.e314()
+                + self.e25() * b.e125()
+                - self.e35() * b.e315()
+                + self.e45() * b.e145(),
+            -self.e31() * b.e123() + self.e14() * b.e124()
+                - self.e34() * b.e234()
+                - self.e15() * b.e125()
+                + self.e35() * b.e235()
+                + self.e45() * b.e245(),
+            -self.e12() * b.e123() - self.e14() * b.e314()
+                + self.e24() * b.e234()
+                + self.e15() * b.e315()
+                - self.e25() * b.e235()
+                + self.e45() * b.e345(),
+            -self.e12() * b.e124()
+                - self.e31() * b.e314()
+                - self.e23() * b.e234()
+                - self.e15() * b.e145()
+                - self.e25() * b.e245()
+                - self.e35() * b.e345(),
+            -self.e12() * b.e125()
+                - self.e31() * b.e315()
+                - self.e23() * b.e235()
+                - self.e14() * b.e145()
+                - self.e24() * b.e245()
+                - self.e34() * b.e345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Bivector<F>, Trivector<F>);
+
+// Trivector-Bivector
+impl<F: Float> BitOr<Bivector<F>> for Trivector<F> {
+    type Output = Vector<F>;
+    fn bitor(self, b: Bivector<F>) -> Vector<F> {
+        Vector::new(
+            -self.e123() * b.e23() - self.e124() * b.e24()
+                + self.e314() * b.e34()
+                + self.e125() * b.e25()
+                - self.e315() * b.e35()
+                + self.e145() * b.e45(),
+            -self.e123() * b.e31() + self.e124() * b.e14()
+                - self.e234() * b.e34()
+                - self.e125() * b.e15()
+                + self.e235() * b.e35()
+                + self.e245() * b.e45(),
+            -self.e123() * b.e12() - self.e314() * b.e14()
+                + self.e234() * b.e24()
+                + self.e315() * b.e15()
+                - self.e235() * b.e25()
+                + self.e345() * b.e45(),
+            -self.e124() * b.e12()
+                - self.e314() * b.e31()
+                - self.e234() * b.e23()
+                - self.e145() * b.e15()
+                - self.e245() * b.e25()
+                - self.e345() * b.e35(),
+            -self.e125() * b.e12()
+                - self.e315() * b.e31()
+                - self.e235() * b.e23()
+                - self.e145() * b.e14()
+                - self.e245() * b.e24()
+                - self.e345() * b.e34(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Trivector<F>, Bivector<F>);
+
+// Bivector-Quadvector
+impl<F: Float> BitOr<Quadvector<F>> for Bivector<F> {
+    type Output = Bivector<F>;
+    fn bitor(self, b: Quadvector<F>) -> Bivector<F> {
+        Bivector::new(
+            -self.e34() * b.e1234() + self.e35() * b.e1235() + self.e45() * b.e1245(),
+            -self.e24() * b.e1234() + self.e25() * b.e1235() + self.e45() * b.e3145(),
+            -self.e14() * b.e1234() + self.e15() * b.e1235() + self.e45() * b.e2345(),
+            -self.e23() * b.e1234() - self.e25() * b.e1245() + self.e35() * b.e3145(),
+            -self.e31() * b.e1234() + self.e15() * b.e1245() - self.e35() * b.e2345(),
+            -self.e12() * b.e1234() - self.e15() * b.e3145() + self.e25() * b.e2345(),
+            -self.e23() * b.e1235() - self.e24() * b.e1245() + self.e34() * b.e3145(),
+            -self.e31() * b.e1235() + self.e14() * b.e1245() - self.e34() * b.e2345(),
+            -self.e12() * b.e1235() - self.e14() * b.e3145() + self.e24() * b.e2345(),
+            -self.e12() * b.e1245() - self.e31() * b.e3145() - self.e23() * b.e2345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Bivector<F>, Quadvector<F>);
+
+// Quadvector-Bivector
+impl<F: Float> BitOr<Bivector<F>> for Quadvector<F> {
+    type Output = Bivector<F>;
+    fn bitor(self, b: Bivector<F>) -> Bivector<F> {
+        Bivector::new(
+            -self.e1234() * b.e34() + self.e1235() * b.e35() + self.e1245() * b.e45(),
+            -self.e1234() * b.e24() + self.e1235() * b.e25() + self.e3145() * b.e45(),
+            -self.e1234() * b.e14() + self.e1235() * b.e15() + self.e2345() * b.e45(),
+            -self.e1234() * b.e23() - self.e1245() * b.e25() + self.e3145() * b.e35(),
+            -self.e1234() * b.e31() + self.e1245() * b.e15() - self.e2345() * b.e35(),
+            -self.e1234() * b.e12() - self.e3145() * b.e15() + self.e2345() * b.e25(),
+            -self.e1235() * b.e23() - self.e1245() * b.e24() + self.e3145() * b.e34(),
+            -self.e1235() * b.e31() + self.e1245() * b.e14() - self.e2345() * b.e34(),
+            -self.e1235() * b.e12() - self.e3145() * b.e14() + self.e2345() * b.e24(),
+            -self.e1245() * b.e12() - self.e3145() * b.e31() - self.e2345() * b.e23(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Quadvector<F>, Bivector<F>);
+
+// Bivector-Pseudoscalar
+impl<F: Float> BitOr<Pseudoscalar<F>> for Bivector<F> {
+    type Output = Trivector<F>;
+    fn bitor(self, b: Pseudoscalar<F>) -> Trivector<F> {
+        Trivector::new(
+            self.e45() * b.e12345(),
+            -self.e35() * b.e12345(),
+            -self.e25() * b.e12345(),
+            -self.e15() * b.e12345(),
+            -self.e34() * b.e12345(),
+            -self.e24() * b.e12345(),
+            -self.e14() * b.e12345(),
+            -self.e23() * b.e12345(),
+            -self.e31() * b.e12345(),
+            -self.e12() * b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Bivector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Bivector
+impl<F: Float> BitOr<Bivector<F>> for Pseudoscalar<F> {
+    type Output = Trivector<F>;
+    fn bitor(self, b: Bivector<F>) -> Trivector<F> {
+        Trivector::new(
+            self.e12345() * b.e45(),
+            -self.e12345() * b.e35(),
+            -self.e12345() * b.e25(),
+            -self.e12345() * b.e15(),
+            -self.e12345() * b.e34(),
+            -self.e12345() * b.e24(),
+            -self.e12345() * b.e14(),
+            -self.e12345() * b.e23(),
+            -self.e12345() * b.e31(),
+            -self.e12345() * b.e12(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Bivector<F>);
+
+// Bivector-Versor
+impl<F: Float> BitOr<Versor<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12() * b.e12()
+                - self.e31() * b.e31()
+                - self.e23() * b.e23()
+                - self.e14() * b.e14()
+                - self.e24() * b.e24()
+                - self.e34() * b.e34()
+                + self.e15() * b.e15()
+                + self.e25() * b.e25()
+                + self.e35() * b.e35()
+                + self.e45() * b.e45(),
+            Vector::zero(),
+            Bivector::new(
+                self.e12() * b.scalar() - self.e34() * b.e1234()
+                    + self.e35() * b.e1235()
+                    + self.e45() * b.e1245(),
+                self.e31() * b.scalar() - self.e24() * b.e1234()
+                    + self.e25() * b.e1235()
+                    + self.e45() * b.e3145(),
+                self.e23() * b.scalar() - self.e14() * b.e1234()
+                    + self.e15() * b.e1235()
+                    + self.e45() * b.e2345(),
+                -self.e23() * b.e1234() + self.e14() * b.scalar() - self.e25() * b.e1245()
+                    + self.e35() * b.e3145(),
+                -self.e31() * b.e1234() + self.e24() * b.scalar() + self.e15() * b.e1245()
+                    - self.e35() * b.e2345(),
+                -self.e12() * b.e1234() + self.e34() * b.scalar() - self.e15() * b.e3145()
+                    + self.e25() * b.e2345(),
+                -self.e23() * b.e1235() - self.e24() * b.e1245()
+                    + self.e34() * b.e3145()
+                    + self.e15() * b.scalar(),
+                -self.e31() * b.e1235() + self.e14() * b.e1245() - self.e34() * b.e2345()
+                    + self.e25() * b.scalar(),
+                -self.e12() * b.e1235() - self.e14() * b.e3145()
+                    + self.e24() * b.e2345()
+                    + self.e35() * b.scalar(),
+                -self.e12() * b.e1245() - self.e31() * b.e3145() - self.e23() * b.e2345()
+                    + self.e45() * b.scalar(),
+            ),
+            Trivector::zero(),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Bivector<F>, Versor<F>);
+
+// Versor-Bivector
+impl<F: Float> BitOr<Bivector<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12() * b.e12()
+                - self.e31() * b.e31()
+                - self.e23() * b.e23()
+                - self.e14() * b.e14()
+                - self.e24() * b.e24()
+                - self.e34() * b.e34()
+                + self.e15() * b.e15()
+                + self.e25() * b.e25()
+                + self.e35() * b.e35()
+                + self.e45() * b.e45(),
+            Vector::zero(),
+            Bivector::new(
+                self.scalar() * b.e12() - self.e1234() * b.e34()
+                    + self.e1235() * b.e35()
+                    + self.e1245() * b.e45(),
+                self.scalar() * b.e31() - self.e1234() * b.e24()
+                    + self.e1235() * b.e25()
+                    + self.e3145() * b.e45(),
+                self.scalar() * b.e23() - self.e1234() * b.e14()
+                    + self.e1235() * b.e15()
+                    + self.e2345() * b.e45(),
+                self.scalar() * b.e14() - self.e1234() * b.e23() - self.e1245() * b.e25()
+                    + self.e3145() * b.e35(),
+                self.scalar() * b.e24() - self.e1234() * b.e31() + self.e1245() * b.e15()
+                    - self.e2345() * b.e35(),
+                self.scalar() * b.e34() - self.e1234() * b.e12() - self.e3145() * b.e15()
+                    + self.e2345() * b.e25(),
+                self.scalar() * b.e15() - self.e1235() * b.e23() - self.e1245() * b.e24()
+                    + self.e3145() * b.e34(),
+                self.scalar() * b.e25() - self.e1235() * b.e31() + self.e1245() * b.e14()
+                    - self.e2345() * b.e34(),
+                self.scalar() * b.e35() - self.e1235() * b.e12() - self.e3145() * b.e14()
+                    + self.e2345() * b.e24(),
+                self.scalar() * b.e45()
+                    - self.e1245() * b.e12()
+                    - self.e3145() * b.e31()
+                    - self.e2345() * b.e23(),
+            ),
+            Trivector::zero(),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Versor<F>, Bivector<F>);
+
+// Bivector-Multivector
+impl<F: Float> BitOr<Multivector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12() * b.e12()
+                - self.e31() * b.e31()
+                - self.e23() * b.e23()
+                - self.e14() * b.e14()
+                - self.e24() * b.e24()
+                - self.e34() * b.e34()
+                + self.e15() * b.e15()
+                + self.e25() * b.e25()
+                + self.e35() * b.e35()
+                + self.e45() * b.e45(),
+            Vector::new(
+                self.e12() * b.e2() - self.e31() * b.e3() - self.e23() * b.e123()
+                    + self.e14() * b.e4()
+                    - self.e24() * b.e124()
+                    + self.e34() * b.e314()
+                    - self.e15() * b.e5()
+                    + self.e25() * b.e125()
+                    - self.e35() * b.e315()
+                    + self.e45() * b.e145(),
+                -self.e12() * b.e1() - self.e31() * b.e123()
+                    + self.e23() * b.e3()
+                    + self.e14() * b.e124()
+                    + self.e24() * b.e4()
+                    - self.e34() * b.e234()
+                    - self.e15() * b.e125()
+                    - self.e25() * b.e5()
+                    + self.e35() * b.e235()
+                    + self.e45() * b.e245(),
+                -self.e12() * b.e123() + self.e31() * b.e1()
+                    - self.e23() * b.e2()
+                    - self.e14() * b.e314()
+                    + self.e24() * b.e234()
+                    + self.e34() * b.e4()
+                    + self.e15() * b.e315()
+                    - self.e25() * b.e235()
+                    - self.e35() * b.e5()
+                    + self.e45() * b.e345(),
+                -self.e12() * b.e124()
+                    - self.e31() * b.e314()
+                    - self.e23() * b.e234()
+                    - self.e14() * b.e1()
+                    - self.e24() * b.e2()
+                    - self.e34() * b.e3()
+                    - self.e15() * b.e145()
+                    - self.e25() * b.e245()
+                    - self.e35() * b.e345()
+                    - self.e45() * b.e5(),
+                -self.e12() * b.e125()
+                    - self.e31() * b.e315()
+                    - self.e23() * b.e235()
+                    - self.e14() * b.e145()
+                    - self.e24() * b.e245()
+                    - self.e34() * b.e345()
+                    - self.e15() * b.e1()
+                    - self.e25() * b.e2()
+                    - self.e35() * b.e3()
+                    - self.e45() * b.e4(),
+            ),
+            Bivector::new(
+                self.e12() * b.scalar() - self.e34() * b.e1234()
+                    + self.e35() * b.e1235()
+                    + self.e45() * b.e1245(),
+                self.e31() * b.scalar() - self.e24() * b.e1234()
+                    + self.e25() * b.e1235()
+                    + self.e45() * b.e3145(),
+                self.e23() * b.scalar() - self.e14() * b.e1234()
+                    + self.e15() * b.e1235()
+                    + self.e45() * b.e2345(),
+                -self.e23() * b.e1234() + self.e14() * b.scalar() - self.e25() * b.e1245()
+                    + self.e35() * b.e3145(),
+                -self.e31() * b.e1234() + self.e24() * b.scalar() + self.e15() * b.e1245()
+                    - self.e35() * b.e2345(),
+                -self.e12() * b.e1234() + self.e34() * b.scalar() - self.e15() * b.e3145()
+                    + self.e25() * b.e2345(),
+                -self.e23() * b.e1235() - self.e24() * b.e1245()
+                    + self.e34() * b.e3145()
+                    + self.e15() * b.scalar(),
+                -self.e31() * b.e1235() + self.e14() * b.e1245() - self.e34() * b.e2345()
+                    + self.e25() * b.scalar(),
+                -self.e12() * b.e1235() - self.e14() * b.e3145()
+                    + self.e24() * b.e2345()
+                    + self.e35() * b.scalar(),
+                -self.e12() * b.e1245() - self.e31() * b.e3145() - self.e23() * b.e2345()
+                    + self.e45() * b.scalar(),
+            ),
+            Trivector::new(
+                self.e45() * b.e12345(),
+                -self.e35() * b.e12345(),
+                -self.e25() * b.e12345(),
+                -self.e15() * b.e12345(),
+                -self.e34() * b.e12345(),
+                -self.e24() * b.e12345(),
+                -self.e14() * b.e12345(),
+                -self.e23() * b.e12345(),
+                -self.e31() * b.e12345(),
+                -self.e12() * b.e12345(),
+            ),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Bivector<F>, Multivector<F>);
+
+// Multivector-Bivector
+impl<F: Float> BitOr<Bivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12() * b.e12()
+                - self.e31() * b.e31()
+                - self.e23() * b.e23()
+                - self.e14() * b.e14()
+                - self.e24() * b.e24()
+                - self.e34() * b.e34()
+                + self.e15() * b.e15()
+                + self.e25() * b.e25()
+                + self.e35() * b.e35()
+                + self.e45() * b.e45(),
+            Vector::new(
+                -self.e2() * b.e12() + self.e3() * b.e31() - self.e4() * b.e14()
+                    + self.e5() * b.e15()
+                    - self.e123() * b.e23()
+                    - self.e124() * b.e24()
+                    + self.e314() * b.e34()
+                    + self.e125() * b.e25()
+                    - self.e315() * b.e35()
+                    + self.e145() * b.e45(),
+                self.e1() * b.e12() - self.e3() * b.e23() - self.e4() * b.e24()
+                    + self.e5() * b.e25()
+                    - self.e123() * b.e31()
+                    + self.e124() * b.e14()
+                    - self.e234() * b.e34()
+                    - self.e125() * b.e15()
+                    + self.e235() * b.e35()
+                    + self.e245() * b.e45(),
+                -self.e1() * b.e31() + self.e2() * b.e23() - self.e4() * b.e34()
+                    + self.e5() * b.e35()
+                    - self.e123() * b.e12()
+                    - self.e314() * b.e14()
+                    + self.e234() * b.e24()
+                    + self.e315() * b.e15()
+                    - self.e235() * b.e25()
+                    + self.e345() * b.e45(),
+                self.e1() * b.e14()
+                    + self.e2() * b.e24()
+                    + self.e3() * b.e34()
+                    + self.e5() * b.e45()
+                    - self.e124() * b.e12()
+                    - self.e314() * b.e31()
+                    - self.e234() * b.e23()
+                    - self.e145() * b.e15()
+                    - self.e245() * b.e25()
+                    - self.e345() * b.e35(),
+                self.e1() * b.e15()
+                    + self.e2() * b.e25()
+                    + self.e3() * b.e35()
+                    + self.e4() * b.e45()
+                    - self.e125() * b.e12()
+                    - self.e315() * b.e31()
+                    - self.e235() * b.e23()
+                    - self.e145() * b.e14()
+                    - self.e245() * b.e24()
+                    - self.e345() * b.e34(),
+            ),
+            Bivector::new(
+                self.scalar() * b.e12() - self.e1234() * b.e34()
+                    + self.e1235() * b.e35()
+                    + self.e1245() * b.e45(),
+                self.scalar() * b.e31() - self.e1234() * b.e24()
+                    + self.e1235() * b.e25()
+                    + self.e3145() * b.e45(),
+                self.scalar() * b.e23() - self.e1234() * b.e14()
+                    + self.e1235() * b.e15()
+                    + self.e2345() * b.e45(),
+                self.scalar() * b.e14() - self.e1234() * b.e23() - self.e1245() * b.e25()
+                    + self.e3145() * b.e35(),
+                self.scalar() * b.e24() - self.e1234() * b.e31() + self.e1245() * b.e15()
+                    - self.e2345() * b.e35(),
+                self.scalar() * b.e34() - self.e1234() * b.e12() - self.e3145() * b.e15()
+                    + self.e2345() * b.e25(),
+                self.scalar() * b.e15() - self.e1235() * b.e23() - self.e1245() * b.e24()
+                    + self.e3145() * b.e34(),
+                self.scalar() * b.e25() - self.e1235() * b.e31() + self.e1245() * b.e14()
+                    - self.e2345() * b.e34(),
+                self.scalar() * b.e35() - self.e1235() * b.e12() - self.e3145() * b.e14()
+                    + self.e2345() * b.e24(),
+                self.scalar() * b.e45()
+                    - self.e1245() * b.e12()
+                    - self.e3145() * b.e31()
+                    - self.e2345() * b.e23(),
+            ),
+            Trivector::new(
+                self.e12345() * b.e45(),
+                -self.e12345() * b.e35(),
+                -self.e12345() * b.e25(),
+                -self.e12345() * b.e15(),
+                -self.e12345() * b.e34(),
+                -self.e12345() * b.e24(),
+                -self.e12345() * b.e14(),
+                -self.e12345() * b.e23(),
+                -self.e12345() * b.e31(),
+                -self.e12345() * b.e12(),
+            ),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Bivector<F>);
+
+// Trivector-Trivector
+impl<F: Float> BitOr for Trivector<F> {
+    type Output = Scalar<F>;
+    fn bitor(self, b: Trivector<F>) -> Scalar<F> {
+        Scalar(
+            -self.e123() * b.e123()
+                - self.e124() * b.e124()
+                - self.e314() * b.e314()
+                - self.e234() * b.e234()
+                + self.e125() * b.e125()
+                + self.e315() * b.e315()
+                + self.e235() * b.e235()
+                + self.e145() * b.e145()
+                + self.e245() * b.e245()
+                + self.e345() * b.e345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Trivector<F>, Trivector<F>);
+
+// Trivector-Quadvector
+impl<F: Float> BitOr<Quadvector<F>> for Trivector<F> {
+    type Output = Vector<F>;
+    fn bitor(self, b: Quadvector<F>) -> Vector<F> {
+        Vector::new(
+            self.e234() * b.e1234() - self.e235() * b.e1235() - self.e245() * b.e1245()
+                + self.e345() * b.e3145(),
+            self.e314() * b.e1234() - self.e315() * b.e1235() + self.e145() * b.e1245()
+                - self.e345() * b.e2345(),
+            self.e124() * b.e1234() - self.e125() * b.e1235() - self.e145() * b.e3145()
+                + self.e245() * b.e2345(),
+            -self.e123() * b.e1234()
+                - self.e125() * b.e1245()
+                - self.e315() * b.e3145()
+                - self.e235() * b.e2345(),
+            -self.e123() * b.e1235()
+                - self.e124() * b.e1245()
+                - self.e314() * b.e3145()
+                - self.e234() * b.e2345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Trivector<F>, Quadvector<F>);
+
+// Quadvector-Trivector
+impl<F: Float> BitOr<Trivector<F>> for Quadvector<F> {
+    type Output = Vector<F>;
+    fn bitor(self, b: Trivector<F>) -> Vector<F> {
+        Vector::new(
+            -self.e1234() * b.e234() + self.e1235() * b.e235() + self.e1245() * b.e245()
+                - self.e3145() * b.e345(),
+            -self.e1234() * b.e314() + self.e1235() * b.e315() - self.e1245() * b.e145()
+                + self.e2345() * b.e345(),
+            -self.e1234() * b.e124() + self.e1235() * b.e125() + self.e3145() * b.e145()
+                - self.e2345() * b.e245(),
+            self.e1234() * b.e123()
+                + self.e1245() * b.e125()
+                + self.e3145() * b.e315()
+                + self.e2345() * b.e235(),
+            self.e1235() * b.e123()
+                + self.e1245() * b.e124()
+                + self.e3145() * b.e314()
+                + self.e2345() * b.e234(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Quadvector<F>, Trivector<F>);
+
+// Trivector-Pseudoscalar
+impl<F: Float> BitOr<Pseudoscalar<F>> for Trivector<F> {
+    type Output = Bivector<F>;
+    fn bitor(self, b: Pseudoscalar<F>) -> Bivector<F> {
+        Bivector::new(
+            self.e345() * b.e12345(),
+            self.e245() * b.e12345(),
+            self.e145() * b.e12345(),
+            self.e235() * b.e12345(),
+            self.e315() * b.e12345(),
+            self.e125() * b.e12345(),
+            self.e234() * b.e12345(),
+            self.e314() * b.e12345(),
+            self.e124() * b.e12345(),
+            -self.e123() * b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Trivector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Trivector
+impl<F: Float> BitOr<Trivector<F>> for Pseudoscalar<F> {
+    type Output = Bivector<F>;
+    fn bitor(self, b: Trivector<F>) -> Bivector<F> {
+        Bivector::new(
+            self.e12345() * b.e345(),
+            self.e12345() * b.e245(),
+            self.e12345() * b.e145(),
+            self.e12345() * b.e235(),
+            self.e12345() * b.e315(),
+            self.e12345() * b.e125(),
+            self.e12345() * b.e234(),
+            self.e12345() * b.e314(),
+            self.e12345() * b.e124(),
+            -self.e12345() * b.e123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Trivector<F>);
+
+// Trivector-Versor
+impl<F: Float> BitOr<Versor<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                -self.e123() * b.e23() - self.e124() * b.e24()
+                    + self.e314() * b.e34()
+                    + self.e234() * b.e1234()
+                    + self.e125() * b.e25()
+                    - self.e315() * b.e35()
+                    - self.e235() * b.e1235()
+                    + self.e145() * b.e45()
+                    - self.e245() * b.e1245()
+                    + self.e345() * b.e3145(),
+                -self.e123() * b.e31() + self.e124() * b.e14() + self.e314() * b.e1234()
+                    - self.e234() * b.e34()
+                    - self.e125() * b.e15()
+                    - self.e315() * b.e1235()
+                    + self.e235() * b.e35()
+                    + self.e145() * b.e1245()
+                    + self.e245() * b.e45()
+                    - self.e345() * b.e2345(),
+                -self.e123() * b.e12() + self.e124() * b.e1234() - self.e314() * b.e14()
+                    + self.e234() * b.e24()
+                    - self.e125() * b.e1235()
+                    + self.e315() * b.e15()
+                    - self.e235() * b.e25()
+                    - self.e145() * b.e3145()
+                    + self.e245() * b.e2345()
+                    + self.e345() * b.e45(),
+                -self.e123() * b.e1234()
+                    - self.e124() * b.e12()
+                    - self.e314() * b.e31()
+                    - self.e234() * b.e23()
+                    - self.e125() * b.e1245()
+                    - self.e315() * b.e3145()
+                    - self.e235() * b.e2345()
+                    - self.e145() * b.e15()
+                    - self.e245() * b.e25()
+                    - self.e345() * b.e35(),
+                -self.e123() * b.e1235()
+                    - self.e124() * b.e1245()
+                    - self.e314() * b.e3145()
+                    - self.e234() * b.e2345()
+                    - self.e125() * b.e12()
+                    - self.e315() * b.e31()
+                    - self.e235() * b.e23()
+                    - self.e145() * b.e14()
+                    - self.e245() * b.e24()
+                    - self.e345() * b.e34(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                self.e123() * b.scalar(),
+                self.e124() * b.scalar(),
+                self.e314() * b.scalar(),
+                self.e234() * b.scalar(),
+                self.e125() * b.scalar(),
+                self.e315() * b.scalar(),
+                self.e235() * b.scalar(),
+                self.e145() * b.scalar(),
+                self.e245() * b.scalar(),
+                self.e345() * b.scalar(),
+            ),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Trivector<F>, Versor<F>);
+
+// Versor-Trivector
+impl<F: Float> BitOr<Trivector<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                -self.e23() * b.e123() - self.e24() * b.e124()
+                    + self.e34() * b.e314()
+                    + self.e25() * b.e125()
+                    - self.e35() * b.e315()
+                    + self.e45() * b.e145()
+                    - self.e1234() * b.e234()
+                    + self.e1235() * b.e235()
+                    + self.e1245() * b.e245()
+                    - self.e3145() * b.e345(),
+                -self.e31() * b.e123() + self.e14() * b.e124()
+                    - self.e34() * b.e234()
+                    - self.e15() * b.e125()
+                    + self.e35() * b.e235()
+                    + self.e45() * b.e245()
+                    - self.e1234() * b.e314()
+                    + self.e1235() * b.e315()
+                    - self.e1245() * b.e145()
+                    + self.e2345() * b.e345(),
+                -self.e12() * b.e123() - self.e14() * b.e314()
+                    + self.e24() * b.e234()
+                    + self.e15() * b.e315()
+                    - self.e25() * b.e235()
+                    + self.e45() * b.e345()
+                    - self.e1234() * b.e124()
+                    + self.e1235() * b.e125()
+                    + self.e3145() * b.e145()
+                    - self.e2345() * b.e245(),
+                -self.e12() * b.e124()
+                    - self.e31() * b.e314()
+                    - self.e23() * b.e234()
+                    - self.e15() * b.e145()
+                    - self.e25() * b.e245()
+                    - self.e35() * b.e345()
+                    + self.e1234() * b.e123()
+                    + self.e1245() * b.e125()
+                    + self.e3145() * b.e315()
+                    + self.e2345() * b.e235(),
+                -self.e12() * b.e125()
+                    - self.e31() * b.e315()
+                    - self.e23() * b.e235()
+                    - self.e14() * b.e145()
+                    - self.e24() * b.e245()
+                    - self.e34() * b.e345()
+                    + self.e1235() * b.e123()
+                    + self.e1245() * b.e124()
+                    + self.e3145() * b.e314()
+                    + self.e2345() * b.e234(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                self.scalar() * b.e123(),
+                self.scalar() * b.e124(),
+                self.scalar() * b.e314(),
+                self.scalar() * b.e234(),
+                self.scalar() * b.e125(),
+                self.scalar() * b.e315(),
+                self.scalar() * b.e235(),
+                self.scalar() * b.e145(),
+                self.scalar() * b.e245(),
+                self.scalar() * b.e345(),
+            ),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Versor<F>, Trivector<F>);
+
+// Trivector-Multivector
+impl<F: Float> BitOr<Multivector<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e123() * b.e123()
+                - self.e124() * b.e124()
+                - self.e314() * b.e314()
+                - self.e234() * b.e234()
+                + self.e125() * b.e125()
+                + self.e315() * b.e315()
+                + self.e235() * b.e235()
+                + self.e145() * b.e145()
+                + self.e245() * b.e245()
+                + self.e345() * b.e345(),
+            Vector::new(
+                -self.e123() * b.e23() - self.e124() * b.e24()
+                    + self.e314() * b.e34()
+                    + self.e234() * b.e1234()
+                    + self.e125() * b.e25()
+                    - self.e315() * b.e35()
+                    - self.e235() * b.e1235()
+                    + self.e145() * b.e45()
+                    - self.e245() * b.e1245()
+                    + self.e345() * b.e3145(),
+                -self.e123() * b.e31() + self.e124() * b.e14() + self.e314() * b.e1234()
+                    - self.e234() * b.e34()
+                    - self.e125() * b.e15()
+                    - self.e315() * b.e1235()
+                    + self.e235() * b.e35()
+                    + self.e145() * b.e1245()
+                    + self.e245() * b.e45()
+                    - self.e345() * b.e2345(),
+                -self.e123() * b.e12() + self.e124() * b.e1234() - self.e314() * b.e14()
+                    + self.e234() * b.e24()
+                    - self.e125() * b.e1235()
+                    + self.e315() * b.e15()
+                    - self.e235() * b.e25()
+                    - self.e145() * b.e3145()
+                    + self.e245() * b.e2345()
+                    + self.e345() * b.e45(),
+                -self.e123() * b.e1234()
+                    - self.e124() * b.e12()
+                    - self.e314() * b.e31()
+                    - self.e234() * b.e23()
+                    - self.e125() * b.e1245()
+                    - self.e315() * b.e3145()
+                    - self.e235() * b.e2345()
+                    - self.e145() * b.e15()
+                    - self.e245() * b.e25()
+                    - self.e345() * b.e35(),
+                -self.e123() * b.e1235()
+                    - self.e124() * b.e1245()
+                    - self.e314() * b.e3145()
+                    - self.e234() * b.e2345()
+                    - self.e125() * b.e12()
+                    - self.e315() * b.e31()
+                    - self.e235() * b.e23()
+                    - self.e145() * b.e14()
+                    - self.e245() * b.e24()
+                    - self.e345() * b.e34(),
+            ),
+            Bivector::new(
+                self.e123() * b.e3() + self.e124() * b.e4() - self.e125() * b.e5()
+                    + self.e345() * b.e12345(),
+                self.e123() * b.e2() + self.e314() * b.e4() - self.e315() * b.e5()
+                    + self.e245() * b.e12345(),
+                self.e123() * b.e1() + self.e234() * b.e4() - self.e235() * b.e5()
+                    + self.e145() * b.e12345(),
+                -self.e124() * b.e2() + self.e314() * b.e3() + self.e235() * b.e12345()
+                    - self.e145() * b.e5(),
+                self.e124() * b.e1() - self.e234() * b.e3() + self.e315() * b.e12345()
+                    - self.e245() * b.e5(),
+                -self.e314() * b.e1() + self.e234() * b.e2() + self.e125() * b.e12345()
+                    - self.e345() * b.e5(),
+                self.e234() * b.e12345() - self.e125() * b.e2() + self.e315() * b.e3()
+                    - self.e145() * b.e4(),
+                self.e314() * b.e12345() + self.e125() * b.e1()
+                    - self.e235() * b.e3()
+                    - self.e245() * b.e4(),
+                self.e124() * b.e12345() - self.e315() * b.e1() + self.e235() * b.e2()
+                    - self.e345() * b.e4(),
+                -self.e123() * b.e12345()
+                    + self.e145() * b.e1()
+                    + self.e245() * b.e2()
+                    + self.e345() * b.e3(),
+            ),
+            Trivector::new(
+                self.e123() * b.scalar(),
+                self.e124() * b.scalar(),
+                self.e314() * b.scalar(),
+                self.e234() * b.scalar(),
+                self.e125() * b.scalar(),
+                self.e315() * b.scalar(),
+                self.e235() * b.scalar(),
+                self.e145() * b.scalar(),
+                self.e245() * b.scalar(),
+                self.e345() * b.scalar(),
+            ),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Trivector<F>, Multivector<F>);
+
+// Multivector-Trivector
+impl<F: Float> BitOr<Trivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e123() * b.e123()
+                - self.e124() * b.e124()
+                - self.e314() * b.e314()
+                - self.e234() * b.e234()
+                + self.e125() * b.e125()
+                + self.e315() * b.e315()
+                + self.e235() * b.e235()
+                + self.e145() * b.e145()
+                + self.e245() * b.e245()
+                + self.e345() * b.e345(),
+            Vector::new(
+                -self.e23() * b.e123() - self.e24() * b.e124()
+                    + self.e34() * b.e314()
+                    + self.e25() * b.e125()
+                    - self.e35() * b.e315()
+                    + self.e45() * b.e145()
+                    - self.e1234() * b.e234()
+                    + self.e1235() * b.e235()
+                    + self.e1245() * b.e245()
+                    - self.e3145() * b.e345(),
+                -self.e31() * b.e123() + self.e14() * b.e124()
+                    - self.e34() * b.e234()
+                    - self.e15() * b.e125()
+                    + self.e35() * b.e235()
+                    + self.e45() * b.e245()
+                    - self.e1234() * b.e314()
+                    + self.e1235() * b.e315()
+                    - self.e1245() * b.e145()
+                    + self.e2345() * b.e345(),
+                -self.e12() * b.e123() - self.e14() * b.e314()
+                    + self.e24() * b.e234()
+                    + self.e15() * b.e315()
+                    - self.e25() * b.e235()
+                    + self.e45() * b.e345()
+                    - self.e1234() * b.e124()
+                    + self.e1235() * b.e125()
+                    + self.e3145() * b.e145()
+                    - self.e2345() * b.e245(),
+                -self.e12() * b.e124()
+                    - self.e31() * b.e314()
+                    - self.e23() * b.e234()
+                    - self.e15() * b.e145()
+                    - self.e25() * b.e245()
+                    - self.e35() * b.e345()
+                    + self.e1234() * b.e123()
+                    + self.e1245() * b.e125()
+                    + self.e3145() * b.e315()
+                    + self.e2345() * b.e235(),
+                -self.e12() * b.e125()
+                    - self.e31() * b.e315()
+                    - self.e23() * b.e235()
+                    - self.e14() * b.e145()
+                    - self.e24() * b.e245()
+                    - self.e34() * b.e345()
+                    + self.e1235() * b.e123()
+                    + self.e1245() * b.e124()
+                    + self.e3145() * b.e314()
+                    + self.e2345() * b.e234(),
+            ),
+            Bivector::new(
+                self.e3() * b.e123() + self.e4() * b.e124() - self.e5() * b.e125()
+                    + self.e12345() * b.e345(),
+                self.e2() * b.e123() + self.e4() * b.e314() - self.e5() * b.e315()
+                    + self.e12345() * b.e245(),
+                self.e1() * b.e123() + self.e4() * b.e234() - self.e5() * b.e235()
+                    + self.e12345() * b.e145(),
+                -self.e2() * b.e124() + self.e3() * b.e314() - self.e5() * b.e145()
+                    + self.e12345() * b.e235(),
+                self.e1() * b.e124() - self.e3() * b.e234() - self.e5() * b.e245()
+                    + self.e12345() * b.e315(),
+                -self.e1() * b.e314() + self.e2() * b.e234() - self.e5() * b.e345()
+                    + self.e12345() * b.e125(),
+                -self.e2() * b.e125() + self.e3() * b.e315() - self.e4() * b.e145()
+                    + self.e12345() * b.e234(),
+                self.e1() * b.e125() - self.e3() * b.e235() - self.e4() * b.e245()
+                    + self.e12345() * b.e314(),
+                -self.e1() * b.e315() + self.e2() * b.e235() - self.e4() * b.e345()
+                    + self.e12345() * b.e124(),
+                self.e1() * b.e145() + self.e2() * b.e245() + self.e3() * b.e345()
+                    - self.e12345() * b.e123(),
+            ),
+            Trivector::new(
+                self.scalar() * b.e123(),
+                self.scalar() * b.e124(),
+                self.scalar() * b.e314(),
+                self.scalar() * b.e234(),
+                self.scalar() * b.e125(),
+                self.scalar() * b.e315(),
+                self.scalar() * b.e235(),
+                self.scalar() * b.e145(),
+                self.scalar() * b.e245(),
+                self.scalar() * b.e345(),
+            ),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Trivector<F>);
+
+// Quadvector-Quadvector
+impl<F: Float> BitOr for Quadvector<F> {
+    type Output = Scalar<F>;
+    fn bitor(self, b: Quadvector<F>) -> Scalar<F> {
+        Scalar(
+            self.e1234() * b.e1234()
+                - self.e1235() * b.e1235()
+                - self.e1245() * b.e1245()
+                - self.e3145() * b.e3145()
+                - self.e2345() * b.e2345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Quadvector<F>, Quadvector<F>);
+
+// Quadvector-Pseudoscalar
+impl<F: Float> BitOr<Pseudoscalar<F>> for Quadvector<F> {
+    type Output = Vector<F>;
+    fn bitor(self, b: Pseudoscalar<F>) -> Vector<F> {
+        Vector::new(
+            -self.e2345() * b.e12345(),
+            -self.e3145() * b.e12345(),
+            -self.e1245() * b.e12345(),
+            self.e1235() * b.e12345(),
+            self.e1234() * b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Quadvector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Quadvector
+impl<F: Float> BitOr<Quadvector<F>> for Pseudoscalar<F> {
+    type Output = Vector<F>;
+    fn bitor(self, b: Quadvector<F>) -> Vector<F> {
+        Vector::new(
+            -self.e12345() * b.e2345(),
+            -self.e12345() * b.e3145(),
+            -self.e12345() * b.e1245(),
+            self.e12345() * b.e1235(),
+            self.e12345() * b.e1234(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Quadvector<F>);
+
+// Quadvector-Versor
+impl<F: Float> BitOr<Versor<F>> for Quadvector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1234() * b.e1234()
+                - self.e1235() * b.e1235()
+                - self.e1245() * b.e1245()
+                - self.e3145() * b.e3145()
+                - self.e2345() * b.e2345(),
+            Vector::zero(),
+            Bivector::new(
+                -self.e1234() * b.e34() + self.e1235() * b.e35() + self.e1245() * b.e45(),
+                -self.e1234() * b.e24() + self.e1235() * b.e25() + self.e3145() * b.e45(),
+                -self.e1234() * b.e14() + self.e1235() * b.e15() + self.e2345() * b.e45(),
+                -self.e1234() * b.e23() - self.e1245() * b.e25() + self.e3145() * b.e35(),
+                -self.e1234() * b.e31() + self.e1245() * b.e15() - self.e2345() * b.e35(),
+                -self.e1234() * b.e12() - self.e3145() * b.e15() + self.e2345() * b.e25(),
+                -self.e1235() * b.e23() - self.e1245() * b.e24() + self.e3145() * b.e34(),
+                -self.e1235() * b.e31() + self.e1245() * b.e14() - self.e2345() * b.e34(),
+                -self.e1235() * b.e12() - self.e3145() * b.e14() + self.e2345() * b.e24(),
+                -self.e1245() * b.e12() - self.e3145() * b.e31() - self.e2345() * b.e23(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e1234() * b.scalar(),
+                self.e1235() * b.scalar(),
+                self.e1245() * b.scalar(),
+                self.e3145() * b.scalar(),
+                self.e2345() * b.scalar(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Quadvector<F>, Versor<F>);
+
+// Versor-Quadvector
+impl<F: Float> BitOr<Quadvector<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Quadvector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1234() * b.e1234()
+                - self.e1235() * b.e1235()
+                - self.e1245() * b.e1245()
+                - self.e3145() * b.e3145()
+                - self.e2345() * b.e2345(),
+            Vector::zero(),
+            Bivector::new(
+                -self.e34() * b.e1234() + self.e35() * b.e1235() + self.e45() * b.e1245(),
+                -self.e24() * b.e1234() + self.e25() * b.e1235() + self.e45() * b.e3145(),
+                -self.e14() * b.e1234() + self.e15() * b.e1235() + self.e45() * b.e2345(),
+                -self.e23() * b.e1234() - self.e25() * b.e1245() + self.e35() * b.e3145(),
+                -self.e31() * b.e1234() + self.e15() * b.e1245() - self.e35() * b.e2345(),
+                -self.e12() * b.e1234() - self.e15() * b.e3145() + self.e25() * b.e2345(),
+                -self.e23() * b.e1235() - self.e24() * b.e1245() + self.e34() * b.e3145(),
+                -self.e31() * b.e1235() + self.e14() * b.e1245() - self.e34() * b.e2345(),
+                -self.e12() * b.e1235() - self.e14() * b.e3145() + self.e24() * b.e2345(),
+                -self.e12() * b.e1245() - self.e31() * b.e3145() - self.e23() * b.e2345(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.scalar() * b.e1234(),
+                self.scalar() * b.e1235(),
+                self.scalar() * b.e1245(),
+                self.scalar() * b.e3145(),
+                self.scalar() * b.e2345(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Versor<F>, Quadvector<F>);
+
+// Quadvector-Multivector
+impl<F: Float> BitOr<Multivector<F>> for Quadvector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1234() * b.e1234()
+                - self.e1235() * b.e1235()
+                - self.e1245() * b.e1245()
+                - self.e3145() * b.e3145()
+                - self.e2345() * b.e2345(),
+            Vector::new(
+                -self.e1234() * b.e234() + self.e1235() * b.e235() + self.e1245() * b.e245()
+                    - self.e3145() * b.e345()
+                    - self.e2345() * b.e12345(),
+                -self.e1234() * b.e314() + self.e1235() * b.e315()
+                    - self.e1245() * b.e145()
+                    - self.e3145() * b.e12345()
+                    + self.e2345() * b.e345(),
+                -self.e1234() * b.e124() + self.e1235() * b.e125() - self.e1245() * b.e12345()
+                    + self.e3145() * b.e145()
+                    - self.e2345() * b.e245(),
+                self.e1234() * b.e123()
+                    + self.e1235() * b.e12345()
+                    + self.e1245() * b.e125()
+                    + self.e3145() * b.e315()
+                    + self.e2345() * b.e235(),
+                self.e1234() * b.e12345()
+                    + self.e1235() * b.e123()
+                    + self.e1245() * b.e124()
+                    + self.e3145() * b.e314()
+                    + self.e2345() * b.e234(),
+            ),
+            Bivector::new(
+                -self.e1234() * b.e34() + self.e1235() * b.e35() + self.e1245() * b.e45(),
+                -self.e1234() * b.e24() + self.e1235() * b.e25() + self.e3145() * b.e45(),
+                -self.e1234() * b.e14() + self.e1235() * b.e15() + self.e2345() * b.e45(),
+                -self.e1234() * b.e23() - self.e1245() * b.e25() + self.e3145() * b.e35(),
+                -self.e1234() * b.e31() + self.e1245() * b.e15() - self.e2345() * b.e35(),
+                -self.e1234() * b.e12() - self.e3145() * b.e15() + self.e2345() * b.e25(),
+                -self.e1235() * b.e23() - self.e1245() * b.e24() + self.e3145() * b.e34(),
+                -self.e1235() * b.e31() + self.e1245() * b.e14() - self.e2345() * b.e34(),
+                -self.e1235() * b.e12() - self.e3145() * b.e14() + self.e2345() * b.e24(),
+                -self.e1245() * b.e12() - self.e3145() * b.e31() - self.e2345() * b.e23(),
+            ),
+            Trivector::new(
+                self.e1234() * b.e4() - self.e1235() * b.e5(),
+                -self.e1234() * b.e3() - self.e1245() * b.e5(),
+                -self.e1234() * b.e2() - self.e3145() * b.e5(),
+                -self.e1234() * b.e1() - self.e2345() * b.e5(),
+                -self.e1235() * b.e3() - self.e1245() * b.e4(),
+                -self.e1235() * b.e2() - self.e3145() * b.e4(),
+                -self.e1235() * b.e1() - self.e2345() * b.e4(),
+                self.e1245() * b.e2() - self.e3145() * b.e3(),
+                -self.e1245() * b.e1() + self.e2345() * b.e3(),
+                self.e3145() * b.e1() - self.e2345() * b.e2(),
+            ),
+            Quadvector::new(
+                self.e1234() * b.scalar(),
+                self.e1235() * b.scalar(),
+                self.e1245() * b.scalar(),
+                self.e3145() * b.scalar(),
+                self.e2345() * b.scalar(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Quadvector<F>, Multivector<F>);
+
+// Multivector-Quadvector
+impl<F: Float> BitOr<Quadvector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Quadvector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1234() * b.e1234()
+                - self.e1235() * b.e1235()
+                - self.e1245() * b.e1245()
+                - self.e3145() * b.e3145()
+                - self.e2345() * b.e2345(),
+            Vector::new(
+                self.e234() * b.e1234() - self.e235() * b.e1235() - self.e245() * b.e1245()
+                    + self.e345() * b.e3145()
+                    - self.e12345() * b.e2345(),
+                self.e314() * b.e1234() - self.e315() * b.e1235() + self.e145() * b.e1245()
+                    - self.e345() * b.e2345()
+                    - self.e12345() * b.e3145(),
+                self.e124() * b.e1234() - self.e125() * b.e1235() - self.e145() * b.e3145()
+                    + self.e245() * b.e2345()
+                    - self.e12345() * b.e1245(),
+                -self.e123() * b.e1234()
+                    - self.e125() * b.e1245()
+                    - self.e315() * b.e3145()
+                    - self.e235() * b.e2345()
+                    + self.e12345() * b.e1235(),
+                -self.e123() * b.e1235()
+                    - self.e124() * b.e1245()
+                    - self.e314() * b.e3145()
+                    - self.e234() * b.e2345()
+                    + self.e12345() * b.e1234(),
+            ),
+            Bivector::new(
+                -self.e34() * b.e1234() + self.e35() * b.e1235() + self.e45() * b.e1245(),
+                -self.e24() * b.e1234() + self.e25() * b.e1235() + self.e45() * b.e3145(),
+                -self.e14() * b.e1234() + self.e15() * b.e1235() + self.e45() * b.e2345(),
+                -self.e23() * b.e1234() - self.e25() * b.e1245() + self.e35() * b.e3145(),
+                -self.e31() * b.e1234() + self.e15() * b.e1245() - self.e35() * b.e2345(),
+                -self.e12() * b.e1234() - self.e15() * b.e3145() + self.e25() * b.e2345(),
+                -self.e23() * b.e1235() - self.e24() * b.e1245() + self.e34() * b.e3145(),
+                -self.e31() * b.e1235() + self.e14() * b.e1245() - self.e34() * b.e2345(),
+                -self.e12() * b.e1235() - self.e14() * b.e3145() + self.e24() * b.e2345(),
+                -self.e12() * b.e1245() - self.e31() * b.e3145() - self.e23() * b.e2345(),
+            ),
+            Trivector::new(
+                -self.e4() * b.e1234() + self.e5() * b.e1235(),
+                self.e3() * b.e1234() + self.e5() * b.e1245(),
+                self.e2() * b.e1234() + self.e5() * b.e3145(),
+                self.e1() * b.e1234() + self.e5() * b.e2345(),
+                self.e3() * b.e1235() + self.e4() * b.e1245(),
+                self.e2() * b.e1235() + self.e4() * b.e3145(),
+                self.e1() * b.e1235() + self.e4() * b.e2345(),
+                -self.e2() * b.e1245() + self.e3() * b.e3145(),
+                self.e1() * b.e1245() - self.e3() * b.e2345(),
+                -self.e1() * b.e3145() + self.e2() * b.e2345(),
+            ),
+            Quadvector::new(
+                self.scalar() * b.e1234(),
+                self.scalar() * b.e1235(),
+                self.scalar() * b.e1245(),
+                self.scalar() * b.e3145(),
+                self.scalar() * b.e2345(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Quadvector<F>);
+
+// Pseudoscalar-Pseudoscalar
+impl<F: Float> BitOr for Pseudoscalar<F> {
+    type Output = Scalar<F>;
+    fn bitor(self, b: Pseudoscalar<F>) -> Scalar<F> {
+        Scalar(-self.e12345() * b.e12345())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Versor
+impl<F: Float> BitOr<Versor<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                -self.e12345() * b.e2345(),
+                -self.e12345() * b.e3145(),
+                -self.e12345() * b.e1245(),
+                self.e12345() * b.e1235(),
+                self.e12345() * b.e1234(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                self.e12345() * b.e45(),
+                -self.e12345() * b.e35(),
+                -self.e12345() * b.e25(),
+                -self.e12345() * b.e15(),
+                -self.e12345() * b.e34(),
+                -self.e12345() * b.e24(),
+                -self.e12345() * b.e14(),
+                -self.e12345() * b.e23(),
+                -self.e12345() * b.e31(),
+                -self.e12345() * b.e12(),
+            ),
+            Quadvector::zero(),
+            self.e12345() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Versor<F>);
+
+// Versor-Pseudoscalar
+impl<F: Float> BitOr<Pseudoscalar<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                -self.e2345() * b.e12345(),
+                -self.e3145() * b.e12345(),
+                -self.e1245() * b.e12345(),
+                self.e1235() * b.e12345(),
+                self.e1234() * b.e12345(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                self.e45() * b.e12345(),
+                -self.e35() * b.e12345(),
+                -self.e25() * b.e12345(),
+                -self.e15() * b.e12345(),
+                -self.e34() * b.e12345(),
+                -self.e24() * b.e12345(),
+                -self.e14() * b.e12345(),
+                -self.e23() * b.e12345(),
+                -self.e31() * b.e12345(),
+                -self.e12() * b.e12345(),
+            ),
+            Quadvector::zero(),
+            self.scalar() * b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Versor<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Multivector
+impl<F: Float> BitOr<Multivector<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12345() * b.e12345(),
+            Vector::new(
+                -self.e12345() * b.e2345(),
+                -self.e12345() * b.e3145(),
+                -self.e12345() * b.e1245(),
+                self.e12345() * b.e1235(),
+                self.e12345() * b.e1234(),
+            ),
+            Bivector::new(
+                self.e12345() * b.e345(),
+                self.e12345() * b.e245(),
+                self.e12345() * b.e145(),
+                self.e12345() * b.e235(),
+                self.e12345() * b.e315(),
+                self.e12345() * b.e125(),
+                self.e12345() * b.e234(),
+                self.e12345() * b.e314(),
+                self.e12345() * b.e124(),
+                -self.e12345() * b.e123(),
+            ),
+            Trivector::new(
+                self.e12345() * b.e45(),
+                -self.e12345() * b.e35(),
+                -self.e12345() * b.e25(),
+                -self.e12345() * b.e15(),
+                -self.e12345() * b.e34(),
+                -self.e12345() * b.e24(),
+                -self.e12345() * b.e14(),
+                -self.e12345() * b.e23(),
+                -self.e12345() * b.e31(),
+                -self.e12345() * b.e12(),
+            ),
+            Quadvector::new(
+                -self.e12345() * b.e5(),
+                -self.e12345() * b.e4(),
+                self.e12345() * b.e3(),
+                self.e12345() * b.e2(),
+                self.e12345() * b.e1(),
+            ),
+            self.e12345() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Multivector<F>);
+
+// Multivector-Pseudoscalar
+impl<F: Float> BitOr<Pseudoscalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12345() * b.e12345(),
+            Vector::new(
+                -self.e2345() * b.e12345(),
+                -self.e3145() * b.e12345(),
+                -self.e1245() * b.e12345(),
+                self.e1235() * b.e12345(),
+                self.e1234() * b.e12345(),
+            ),
+            Bivector::new(
+                self.e345() * b.e12345(),
+                self.e245() * b.e12345(),
+                self.e145() * b.e12345(),
+                self.e235() * b.e12345(),
+                self.e315() * b.e12345(),
+                self.e125() * b.e12345(),
+                self.e234() * b.e12345(),
+                self.e314() * b.e12345(),
+                self.e124() * b.e12345(),
+                -self.e123() * b.e12345(),
+            ),
+            Trivector::new(
+                self.e45() * b.e12345(),
+                -self.e35() * b.e12345(),
+                -self.e25() * b.e12345(),
+                -self.e15() * b.e12345(),
+                -self.e34() * b.e12345(),
+                -self.e24() * b.e12345(),
+                -self.e14() * b.e12345(),
+                -self.e23() * b.e12345(),
+                -self.e31() * b.e12345(),
+                -self.e12() * b.e12345(),
+            ),
+            Quadvector::new(
+                -self.e5() * b.e12345(),
+                -self.e4() * b.e12345(),
+                self.e3() * b.e12345(),
+                self.e2() * b.e12345(),
+                self.e1() * b.e12345(),
+            ),
+            self.scalar() * b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Pseudoscalar<F>);
+
+// Versor-Versor
+impl<F: Float> BitOr for Versor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar()
+                - self.e12() * b.e12()
+                - self.e31() * b.e31()
+                - self.e23() * b.e23()
+                - self.e14() * b.e14()
+                - self.e24() * b.e24()
+                - self.e34() * b.e34()
+                + self.e15() * b.e15()
+                + self.e25() * b.e25()
+                + self.e35() * b.e35()
+                + self.e45() * b.e45()
+                + self.e1234() * b.e1234()
+                - self.e1235() * b.e1235()
+                - self.e1245() * b.e1245()
+                - self.e3145() * b.e3145()
+                - self.e2345() * b.e2345(),
+            Vector::zero(),
+            Bivector::new(
+                self.scalar() * b.e12() + self.e12() * b.scalar() - self.e34() * b.e1234()
+                    + self.e35() * b.e1235()
+                    + self.e45() * b.e1245()
+                    - self.e1234() * b.e34()
+                    + self.e1235() * b.e35()
+                    + self.e1245() * b.e45(),
+                self.scalar() * b.e31() + self.e31() * b.scalar() - self.e24() * b.e1234()
+                    + self.e25() * b.e1235()
+                    + self.e45() * b.e3145()
+                    - self.e1234() * b.e24()
+                    + self.e1235() * b.e25()
+                    + self.e3145() * b.e45(),
+                self.scalar() * b.e23() + self.e23() * b.scalar() - self.e14() * b.e1234()
+                    + self.e15() * b.e1235()
+                    + self.e45() * b.e2345()
+                    - self.e1234() * b.e14()
+                    + self.e1235() * b.e15()
+                    + self.e2345() * b.e45(),
+                self.scalar() * b.e14() - self.e23() * b.e1234() + self.e14() * b.scalar()
+                    - self.e25() * b.e1245()
+                    + self.e35() * b.e3145()
+                    - self.e1234() * b.e23()
+                    - self.e1245() * b.e25()
+                    + self.e3145() * b.e35(),
+                self.scalar() * b.e24() - self.e31() * b.e1234()
+                    + self.e24() * b.scalar()
+                    + self.e15() * b.e1245()
+                    - self.e35() * b.e2345()
+                    - self.e1234() * b.e31()
+                    + self.e1245() * b.e15()
+                    - self.e2345() * b.e35(),
+                self.scalar() * b.e34() - self.e12() * b.e1234() + self.e34() * b.scalar()
+                    - self.e15() * b.e3145()
+                    + self.e25() * b.e2345()
+                    - self.e1234() * b.e12()
+                    - self.e3145() * b.e15()
+                    + self.e2345() * b.e25(),
+                self.scalar() * b.e15() - self.e23() * b.e1235() - self.e24() * b.e1245()
+                    + self.e34() * b.e3145()
+                    + self.e15() * b.scalar()
+                    - self.e1235() * b.e23()
+                    - self.e1245() * b.e24()
+                    + self.e3145() * b.e34(),
+                self.scalar() * b.e25() - self.e31() * b.e1235() + self.e14() * b.e1245()
+                    - self.e34() * b.e2345()
+                    + self.e25() * b.scalar()
+                    - self.e1235() * b.e31()
+                    + self.e1245() * b.e14()
+                    - self.e2345() * b.e34(),
+                self.scalar() * b.e35() - self.e12() * b.e1235() - self.e14() * b.e3145()
+                    + self.e24() * b.e2345()
+                    + self.e35() * b.scalar()
+                    - self.e1235() * b.e12()
+                    - self.e3145() * b.e14()
+                    + self.e2345() * b.e24(),
+                self.scalar() * b.e45()
+                    - self.e12() * b.e1245()
+                    - self.e31() * b.e3145()
+                    - self.e23() * b.e2345()
+                    + self.e45() * b.scalar()
+                    - self.e1245() * b.e12()
+                    - self.e3145() * b.e31()
+                    - self.e2345() * b.e23(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.scalar() * b.e1234() + self.e1234() * b.scalar(),
+                self.scalar() * b.e1235() + self.e1235() * b.scalar(),
+                self.scalar() * b.e1245() + self.e1245() * b.scalar(),
+                self.scalar() * b.e3145() + self.e3145() * b.scalar(),
+                self.scalar() * b.e2345() + self.e2345() * b.scalar(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Versor<F>, Versor<F>);
+
+// Versor-Multivector
+impl<F: Float> BitOr<Multivector<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar()
+                - self.e12() * b.e12()
+                - self.e31() * b.e31()
+                - self.e23() * b.e23()
+                - self.e14() * b.e14()
+                - self.e24() * b.e24()
+                - self.e34() * b.e34()
+                + self.e15() * b.e15()
+                + self.e25() * b.e25()
+                + self.e35() * b.e35()
+                + self.e45() * b.e45()
+                + self.e1234() * b.e1234()
+                - self.e1235() * b.e1235()
+                - self.e1245() * b.e1245()
+                - self.e3145() * b.e3145()
+                - self.e2345() * b.e2345(),
+            Vector::new(
+                self.scalar() * b.e1() + self.e12() * b.e2()
+                    - self.e31() * b.e3()
+                    - self.e23() * b.e123()
+                    + self.e14() * b.e4()
+                    - self.e24() * b.e124()
+                    + self.e34() * b.e314()
+                    - self.e15() * b.e5()
+                    + self.e25() * b.e125()
+                    - self.e35() * b.e315()
+                    + self.e45() * b.e145()
+                    - self.e1234() * b.e234()
+                    + self.e1235() * b.e235()
+                    + self.e1245() * b.e245()
+                    - self.e3145() * b.e345()
+                    - self.e2345() * b.e12345(),
+                self.scalar() * b.e2() - self.e12() * b.e1() - self.e31() * b.e123()
+                    + self.e23() * b.e3()
+                    + self.e14() * b.e124()
+                    + self.e24() * b.e4()
+                    - self.e34() * b.e234()
+                    - self.e15() * b.e125()
+                    - self.e25() * b.e5()
+                    + self.e35() * b.e235()
+                    + self.e45() * b.e245()
+                    - self.e1234() * b.e314()
+                    + self.e1235() * b.e315()
+                    - self.e1245() * b.e145()
+                    - self.e3145() * b.e12345()
+                    + self.e2345() * b.e345(),
+                self.scalar() * b.e3() - self.e12() * b.e123() + self.e31() * b.e1()
+                    - self.e23() * b.e2()
+                    - self.e14() * b.e314()
+                    + self.e24() * b.e234()
+                    + self.e34() * b.e4()
+                    + self.e15() * b.e315()
+                    - self.e25() * b.e235()
+                    - self.e35() * b.e5()
+                    + self.e45() * b.e345()
+                    - self.e1234() * b.e124()
+                    + self.e1235() * b.e125()
+                    - self.e1245() * b.e12345()
+                    + self.e3145() * b.e145()
+                    - self.e2345() * b.e245(),
+                self.scalar() * b.e4()
+                    - self.e12() * b.e124()
+                    - self.e31() * b.e314()
+                    - self.e23() * b.e234()
+                    - self.e14() * b.e1()
+                    - self.e24() * b.e2()
+                    - self.e34() * b.e3()
+                    - self.e15() * b.e145()
+                    - self.e25() * b.e245()
+                    - self.e35() * b.e345()
+                    - self.e45() * b.e5()
+                    + self.e1234() * b.e123()
+                    + self.e1235() * b.e12345()
+                    + self.e1245() * b.e125()
+                    + self.e3145() * b.e315()
+                    + self.e2345() * b.e235(),
+                self.scalar() * b.e5()
+                    - self.e12() * b.e125()
+                    - self.e31() * b.e315()
+                    - self.e23() * b.e235()
+                    - self.e14() * b.e145()
+                    - self.e24() * b.e245()
+                    - self.e34() * b.e345()
+                    - self.e15() * b.e1()
+                    - self.e25() * b.e2()
+                    - self.e35() * b.e3()
+                    - self.e45() * b.e4()
+                    + self.e1234() * b.e12345()
+                    + self.e1235() * b.e123()
+                    + self.e1245() * b.e124()
+                    + self.e3145() * b.e314()
+                    + self.e2345() * b.e234(),
+            ),
+            Bivector::new(
+                self.scalar() * b.e12() + self.e12() * b.scalar() - self.e34() * b.e1234()
+                    + self.e35() * b.e1235()
+                    + self.e45() * b.e1245()
+                    - self.e1234() * b.e34()
+                    + self.e1235() * b.e35()
+                    + self.e1245() * b.e45(),
+                self.scalar() * b.e31() + self.e31() * b.scalar() - self.e24() * b.e1234()
+                    + self.e25() * b.e1235()
+                    + self.e45() * b.e3145()
+                    - self.e1234() * b.e24()
+                    + self.e1235() * b.e25()
+                    + self.e3145() * b.e45(),
+                self.scalar() * b.e23() + self.e23() * b.scalar() - self.e14() * b.e1234()
+                    + self.e15() * b.e1235()
+                    + self.e45() * b.e2345()
+                    - self.e1234() * b.e14()
+                    + self.e1235() * b.e15()
+                    + self.e2345() * b.e45(),
+                self.scalar() * b.e14() - self.e23() * b.e1234() + self.e14() * b.scalar()
+                    - self.e25() * b.e1245()
+                    + self.e35() * b.e3145()
+                    - self.e1234() * b.e23()
+                    - self.e1245() * b.e25()
+                    + self.e3145() * b.e35(),
+                self.scalar() * b.e24() - self.e31() * b.e1234()
+                    + self.e24() * b.scalar()
+                    + self.e15() * b.e1245()
+                    - self.e35() * b.e2345()
+                    - self.e1234() * b.e31()
+                    + self.e1245() * b.e15()
+                    - self.e2345() * b.e35(),
+                self.scalar() * b.e34() - self.e12() * b.e1234() + self.e34() * b.scalar()
+                    - self.e15() * b.e3145()
+                    + self.e25() * b.e2345()
+                    - self.e1234() * b.e12()
+                    - self.e3145() * b.e15()
+                    + self.e2345() * b.e25(),
+                self.scalar() * b.e15() - self.e23() * b.e1235() - self.e24() * b.e1245()
+                    + self.e34() * b.e3145()
+                    + self.e15() * b.scalar()
+                    - self.e1235() * b.e23()
+                    - self.e1245() * b.e24()
+                    + self.e3145() * b.e34(),
+                self.scalar() * b.e25() - self.e31() * b.e1235() + self.e14() * b.e1245()
+                    - self.e34() * b.e2345()
+                    + self.e25() * b.scalar()
+                    - self.e1235() * b.e31()
+                    + self.e1245() * b.e14()
+                    - self.e2345() * b.e34(),
+                self.scalar() * b.e35() - self.e12() * b.e1235() - self.e14() * b.e3145()
+                    + self.e24() * b.e2345()
+                    + self.e35() * b.scalar()
+                    - self.e1235() * b.e12()
+                    - self.e3145() * b.e14()
+                    + self.e2345() * b.e24(),
+                self.scalar() * b.e45()
+                    - self.e12() * b.e1245()
+                    - self.e31() * b.e3145()
+                    - self.e23() * b.e2345()
+                    + self.e45() * b.scalar()
+                    - self.e1245() * b.e12()
+                    - self.e3145() * b.e31()
+                    - self.e2345() * b.e23(),
+            ),
+            Trivector::new(
+                self.scalar() * b.e123() + self.e45() * b.e12345() + self.e1234() * b.e4()
+                    - self.e1235() * b.e5(),
+                self.scalar() * b.e124()
+                    - self.e35() * b.e12345()
+                    - self.e1234() * b.e3()
+                    - self.e1245() * b.e5(),
+                self.scalar() * b.e314()
+                    - self.e25() * b.e12345()
+                    - self.e1234() * b.e2()
+                    - self.e3145() * b.e5(),
+                self.scalar() * b.e234()
+                    - self.e15() * b.e12345()
+                    - self.e1234() * b.e1()
+                    - self.e2345() * b.e5(),
+                self.scalar() * b.e125()
+                    - self.e34() * b.e12345()
+                    - self.e1235() * b.e3()
+                    - self.e1245() * b.e4(),
+                self.scalar() * b.e315()
+                    - self.e24() * b.e12345()
+                    - self.e1235() * b.e2()
+                    - self.e3145() * b.e4(),
+                self.scalar() * b.e235()
+                    - self.e14() * b.e12345()
+                    - self.e1235() * b.e1()
+                    - self.e2345() * b.e4(),
+                self.scalar() * b.e145() - self.e23() * b.e12345() + self.e1245() * b.e2()
+                    - self.e3145() * b.e3(),
+                self.scalar() * b.e245() - self.e31() * b.e12345() - self.e1245() * b.e1()
+                    + self.e2345() * b.e3(),
+                self.scalar() * b.e345() - self.e12() * b.e12345() + self.e3145() * b.e1()
+                    - self.e2345() * b.e2(),
+            ),
+            Quadvector::new(
+                self.scalar() * b.e1234() + self.e1234() * b.scalar(),
+                self.scalar() * b.e1235() + self.e1235() * b.scalar(),
+                self.scalar() * b.e1245() + self.e1245() * b.scalar(),
+                self.scalar() * b.e3145() + self.e3145() * b.scalar(),
+                self.scalar() * b.e2345() + self.e2345() * b.scalar(),
+            ),
+            self.scalar() * b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Versor<F>, Multivector<F>);
+
+// Multivector-Versor
+impl<F: Float> BitOr<Versor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar()
+                - self.e12() * b.e12()
+                - self.e31() * b.e31()
+                - self.e23() * b.e23()
+                - self.e14() * b.e14()
+                - self.e24() * b.e24()
+                - self.e34() * b.e34()
+                + self.e15() * b.e15()
+                + self.e25() * b.e25()
+                + self.e35() * b.e35()
+                + self.e45() * b.e45()
+                + self.e1234() * b.e1234()
+                - self.e1235() * b.e1235()
+                - self.e1245() * b.e1245()
+                - self.e3145() * b.e3145()
+                - self.e2345() * b.e2345(),
+            Vector::new(
+                self.e1() * b.scalar() - self.e2() * b.e12() + self.e3() * b.e31()
+                    - self.e4() * b.e14()
+                    + self.e5() * b.e15()
+                    - self.e123() * b.e23()
+                    - self.e124() * b.e24()
+                    + self.e314() * b.e34()
+                    + self.e234() * b.e1234()
+                    + self.e125() * b.e25()
+                    - self.e315() * b.e35()
+                    - self.e235() * b.e1235()
+                    + self.e145() * b.e45()
+                    - self.e245() * b.e1245()
+                    + self.e345() * b.e3145()
+                    - self.e12345() * b.e2345(),
+                self.e1() * b.e12() + self.e2() * b.scalar()
+                    - self.e3() * b.e23()
+                    - self.e4() * b.e24()
+                    + self.e5() * b.e25()
+                    - self.e123() * b.e31()
+                    + self.e124() * b.e14()
+                    + self.e314() * b.e1234()
+                    - self.e234() * b.e34()
+                    - self.e125() * b.e15()
+                    - self.e315() * b.e1235()
+                    + self.e235() * b.e35()
+                    + self.e145() * b.e1245()
+                    + self.e245() * b.e45()
+                    - self.e345() * b.e2345()
+                    - self.e12345() * b.e3145(),
+                -self.e1() * b.e31() + self.e2() * b.e23() + self.e3() * b.scalar()
+                    - self.e4() * b.e34()
+                    + self.e5() * b.e35()
+                    - self.e123() * b.e12()
+                    + self.e124() * b.e1234()
+                    - self.e314() * b.e14()
+                    + self.e234() * b.e24()
+                    - self.e125() * b.e1235()
+                    + self.e315() * b.e15()
+                    - self.e235() * b.e25()
+                    - self.e145() * b.e3145()
+                    + self.e245() * b.e2345()
+                    + self.e345() * b.e45()
+                    - self.e12345() * b.e1245(),
+                self.e1() * b.e14()
+                    + self.e2() * b.e24()
+                    + self.e3() * b.e34()
+                    + self.e4() * b.scalar()
+                    + self.e5() * b.e45()
+                    - self.e123() * b.e1234()
+                    - self.e124() * b.e12()
+                    - self.e314() * b.e31()
+                    - self.e234() * b.e23()
+                    - self.e125() * b.e1245()
+                    - self.e315() * b.e3145()
+                    - self.e235() * b.e2345()
+                    - self.e145() * b.e15()
+                    - self.e245() * b.e25()
+                    - self.e345() * b.e35()
+                    + self.e12345() * b.e1235(),
+                self.e1() * b.e15()
+                    + self.e2() * b.e25()
+                    + self.e3() * b.e35()
+                    + self.e4() * b.e45()
+                    + self.e5() * b.scalar()
+                    - self.e123() * b.e1235()
+                    - self.e124() * b.e1245()
+                    - self.e314() * b.e3145()
+                    - self.e234() * b.e2345()
+                    - self.e125() * b.e12()
+                    - self.e315() * b.e31()
+                    - self.e235() * b.e23()
+                    - self.e145() * b.e14()
+                    - self.e245() * b.e24()
+                    - self.e345() * b.e34()
+                    + self.e12345() * b.e1234(),
+            ),
+            Bivector::new(
+                self.scalar() * b.e12() + self.e12() * b.scalar() - self.e34() * b.e1234()
+                    + self.e35() * b.e1235()
+                    + self.e45() * b.e1245()
+                    - self.e1234() * b.e34()
+                    + self.e1235() * b.e35()
+                    + self.e1245() * b.e45(),
+                self.scalar() * b.e31() + self.e31() * b.scalar() - self.e24() * b.e1234()
+                    + self.e25() * b.e1235()
+                    + self.e45() * b.e3145()
+                    - self.e1234() * b.e24()
+                    + self.e1235() * b.e25()
+                    + self.e3145() * b.e45(),
+                self.scalar() * b.e23() + self.e23() * b.scalar() - self.e14() * b.e1234()
+                    + self.e15() * b.e1235()
+                    + self.e45() * b.e2345()
+                    - self.e1234() * b.e14()
+                    + self.e1235() * b.e15()
+                    + self.e2345() * b.e45(),
+                self.scalar() * b.e14() - self.e23() * b.e1234() + self.e14() * b.scalar()
+                    - self.e25() * b.e1245()
+                    + self.e35() * b.e3145()
+                    - self.e1234() * b.e23()
+                    - self.e1245() * b.e25()
+                    + self.e3145() * b.e35(),
+                self.scalar() * b.e24() - self.e31() * b.e1234()
+                    + self.e24() * b.scalar()
+                    + self.e15() * b.e1245()
+                    - self.e35() * b.e2345()
+                    - self.e1234() * b.e31()
+                    + self.e1245() * b.e15()
+                    - self.e2345() * b.e35(),
+                self.scalar() * b.e34() - self.e12() * b.e1234() + self.e34() * b.scalar()
+                    - self.e15() * b.e3145()
+                    + self.e25() * b.e2345()
+                    - self.e1234() * b.e12()
+                    - self.e3145() * b.e15()
+                    + self.e2345() * b.e25(),
+                self.scalar() * b.e15() - self.e23() * b.e1235() - self.e24() * b.e1245()
+                    + self.e34() * b.e3145()
+                    + self.e15() * b.scalar()
+                    - self.e1235() * b.e23()
+                    - self.e1245() * b.e24()
+                    + self.e3145() * b.e34(),
+                self.scalar() * b.e25() - self.e31() * b.e1235() + self.e14() * b.e1245()
+                    - self.e34() * b.e2345()
+                    + self.e25() * b.scalar()
+                    - self.e1235() * b.e31()
+                    + self.e1245() * b.e14()
+                    - self.e2345() * b.e34(),
+                self.scalar() * b.e35() - self.e12() * b.e1235() - self.e14() * b.e3145()
+                    + self.e24() * b.e2345()
+                    + self.e35() * b.scalar()
+                    - self.e1235() * b.e12()
+                    - self.e3145() * b.e14()
+                    + self.e2345() * b.e24(),
+                self.scalar() * b.e45()
+                    - self.e12() * b.e1245()
+                    - self.e31() * b.e3145()
+                    - self.e23() * b.e2345()
+                    + self.e45() * b.scalar()
+                    - self.e1245() * b.e12()
+                    - self.e3145() * b.e31()
+                    - self.e2345() * b.e23(),
+            ),
+            Trivector::new(
+                -self.e4() * b.e1234()
+                    + self.e5() * b.e1235()
+                    + self.e123() * b.scalar()
+                    + self.e12345() * b.e45(),
+                self.e3() * b.e1234() + self.e5() * b.e1245() + self.e124() * b.scalar()
+                    - self.e12345() * b.e35(),
+                self.e2() * b.e1234() + self.e5() * b.e3145() + self.e314() * b.scalar()
+                    - self.e12345() * b.e25(),
+                self.e1() * b.e1234() + self.e5() * b.e2345() + self.e234() * b.scalar()
+                    - self.e12345() * b.e15(),
+                self.e3() * b.e1235() + self.e4() * b.e1245() + self.e125() * b.scalar()
+                    - self.e12345() * b.e34(),
+                self.e2() * b.e1235() + self.e4() * b.e3145() + self.e315() * b.scalar()
+                    - self.e12345() * b.e24(),
+                self.e1() * b.e1235() + self.e4() * b.e2345() + self.e235() * b.scalar()
+                    - self.e12345() * b.e14(),
+                -self.e2() * b.e1245() + self.e3() * b.e3145() + self.e145() * b.scalar()
+                    - self.e12345() * b.e23(),
+                self.e1() * b.e1245() - self.e3() * b.e2345() + self.e245() * b.scalar()
+                    - self.e12345() * b.e31(),
+                -self.e1() * b.e3145() + self.e2() * b.e2345() + self.e345() * b.scalar()
+                    - self.e12345() * b.e12(),
+            ),
+            Quadvector::new(
+                self.scalar() * b.e1234() + self.e1234() * b.scalar(),
+                self.scalar() * b.e1235() + self.e1235() * b.scalar(),
+                self.scalar() * b.e1245() + self.e1245() * b.scalar(),
+                self.scalar() * b.e3145() + self.e3145() * b.scalar(),
+                self.scalar() * b.e2345() + self.e2345() * b.scalar(),
+            ),
+            self.e12345() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Versor<F>);
+
+// Multivector-Multivector
+impl<F: Float> BitOr for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar()
+                + self.e1() * b.e1()
+                + self.e2() * b.e2()
+                + self.e3() * b.e3()
+                + self.e4() * b.e4()
+                - self.e5() * b.e5()
+                - self.e12() * b.e12()
+                - self.e31() * b.e31()
+                - self.e23() * b.e23()
+                - self.e14() * b.e14()
+                - self.e24() * b.e24()
+                - self.e34() * b.e34()
+                + self.e15() * b.e15()
+                + self.e25() * b.e25()
+                + self.e35() * b.e35()
+                + self.e45() * b.e45()
+                - self.e123() * b.e123()
+                - self.e124() * b.e124()
+                - self.e314() * b.e314()
+                - self.e234() * b.e234()
+                + self.e125() * b.e125()
+                + self.e315() * b.e315()
+                + self.e235() * b.e235()
+                + self.e145() * b.e145()
+                + self.e245() * b.e245()
+                + self.e345() * b.e345()
+                + self.e1234() * b.e1234()
+                - self.e1235() * b.e1235()
+                - self.e1245() * b.e1245()
+                - self.e3145() * b.e3145()
+                - self.e2345() * b.e2345()
+                - self.e12345() * b.e12345(),
+            Vector::new(
+                self.scalar() * b.e1() + self.e1() * b.scalar() - self.e2() * b.e12()
+                    + self.e3() * b.e31()
+                    - self.e4() * b.e14()
+                    + self.e5() * b.e15()
+                    + self.e12() * b.e2()
+                    - self.e31() * b.e3()
+                    - self.e23() * b.e123()
+                    + self.e14() * b.e4()
+                    - self.e24() * b.e124()
+                    + self.e34() * b.e314()
+                    - self.e15() * b.e5()
+                    + self.e25() * b.e125()
+                    - self.e35() * b.e315()
+                    + self.e45() * b.e145()
+                    - self.e123() * b.e23()
+                    - self.e124() * b.e24()
+                    + self.e314() * b.e34()
+                    + self.e234() * b.e1234()
+                    + self.e125() * b.e25()
+                    - self.e315() * b.e35()
+                    - self.e235() * b.e1235()
+                    + self.e145() * b.e45()
+                    - self.e245() * b.e1245()
+                    + self.e345() * b.e3145()
+                    - self.e1234() * b.e234()
+                    + self.e1235() * b.e235()
+                    + self.e1245() * b.e245()
+                    - self.e3145() * b.e345()
+                    - self.e2345() * b.e12345()
+                    - self.e12345() * b.e2345(),
+                self.scalar() * b.e2() + self.e1() * b.e12() + self.e2() * b.scalar()
+                    - self.e3() * b.e23()
+                    - self.e4() * b.e24()
+                    + self.e5() * b.e25()
+                    - self.e12() * b.e1()
+                    - self.e31() * b.e123()
+                    + self.e23() * b.e3()
+                    + self.e14() * b.e124()
+                    + self.e24() * b.e4()
+                    - self.e34() * b.e234()
+                    - self.e15() * b.e125()
+                    - self.e25() * b.e5()
+                    + self.e35() * b.e235()
+                    + self.e45() * b.e245()
+                    - self.e123() * b.e31()
+                    + self.e124() * b.e14()
+                    + self.e314() * b.e1234()
+                    - self.e234() * b.e34()
+                    - self.e125() * b.e15()
+                    - self.e315() * b.e1235()
+                    + self.e235() * b.e35()
+                    + self.e145() * b.e1245()
+                    + self.e245() * b.e45()
+                    - self.e345() * b.e2345()
+                    - self.e1234() * b.e314()
+                    + self.e1235() * b.e315()
+                    - self.e1245() * b.e145()
+                    - self.e3145() * b.e12345()
+                    + self.e2345() * b.e345()
+                    - self.e12345() * b.e3145(),
+                self.scalar() * b.e3() - self.e1() * b.e31()
+                    + self.e2() * b.e23()
+                    + self.e3() * b.scalar()
+                    - self.e4() * b.e34()
+                    + self.e5() * b.e35()
+                    - self.e12() * b.e123()
+                    + self.e31() * b.e1()
+                    - self.e23() * b.e2()
+                    - self.e14() * b.e314()
+                    + self.e24() * b.e234()
+                    + self.e34() * b.e4()
+                    + self.e15() * b.e315()
+                    - self.e25() * b.e235()
+                    - self.e35() * b.e5()
+                    + self.e45() * b.e345()
+                    - self.e123() * b.e12()
+                    + self.e124() * b.e1234()
+                    - self.e314() * b.e14()
+                    + self.e234() * b.e24()
+                    - self.e125() * b.e1235()
+                    + self.e315() * b.e15()
+                    - self.e235() * b.e25()
+                    - self.e145() * b.e3145()
+                    + self.e245() * b.e2345()
+                    + self.e345() * b.e45()
+                    - self.e1234() * b.e124()
+                    + self.e1235() * b.e125()
+                    - self.e1245() * b.e12345()
+                    + self.e3145() * b.e145()
+                    - self.e2345() * b.e245()
+                    - self.e12345() * b.e1245(),
+                self.scalar() * b.e4()
+                    + self.e1() * b.e14()
+                    + self.e2() * b.e24()
+                    + self.e3() * b.e34()
+                    + self.e4() * b.scalar()
+                    + self.e5() * b.e45()
+                    - self.e12() * b.e124()
+                    - self.e31() * b.e314()
+                    - self.e23() * b.e234()
+                    - self.e14() * b.e1()
+                    - self.e24() * b.e2()
+                    - self.e34() * b.e3()
+                    - self.e15() * b.e145()
+                    - self.e25() * b.e245()
+                    - self.e35() * b.e345()
+                    - self.e45() * b.e5()
+                    - self.e123() * b.e1234()
+                    - self.e124() * b.e12()
+                    - self.e314() * b.e31()
+                    - self.e234() * b.e23()
+                    - self.e125() * b.e1245()
+                    - self.e315() * b.e3145()
+                    - self.e235() * b.e2345()
+                    - self.e145() * b.e15()
+                    - self.e245() * b.e25()
+                    - self.e345() * b.e35()
+                    + self.e1234() * b.e123()
+                    + self.e1235() * b.e12345()
+                    + self.e1245() * b.e125()
+                    + self.e3145() * b.e315()
+                    + self.e2345() * b.e235()
+                    + self.e12345() * b.e1235(),
+                self.scalar() * b.e5()
+                    + self.e1() * b.e15()
+                    + self.e2() * b.e25()
+                    + self.e3() * b.e35()
+                    + self.e4() * b.e45()
+                    + self.e5() * b.scalar()
+                    - self.e12() * b.e125()
+                    - self.e31() * b.e315()
+                    - self.e23() * b.e235()
+                    - self.e14() * b.e145()
+                    - self.e24() * b.e245()
+                    - self.e34() * b.e345()
+                    - self.e15() * b.e1()
+                    - self.e25() * b.e2()
+                    - self.e35() * b.e3()
+                    - self.e45() * b.e4()
+                    - self.e123() * b.e1235()
+                    - self.e124() * b.e1245()
+                    - self.e314() * b.e3145()
+                    - self.e234() * b.e2345()
+                    - self.e125() * b.e12()
+                    - self.e315() * b.e31()
+                    - self.e235() * b.e23()
+                    - self.e145() * b.e14()
+                    - self.e245() * b.e24()
+                    - self.e345() * b.e34()
+                    + self.e1234() * b.e12345()
+                    + self.e1235() * b.e123()
+                    + self.e1245() * b.e124()
+                    + self.e3145() * b.e314()
+                    + self.e2345() * b.e234()
+                    + self.e12345() * b.e1234(),
+            ),
+            Bivector::new(
+                self.scalar() * b.e12() + self.e3() * b.e123() + self.e4() * b.e124()
+                    - self.e5() * b.e125()
+                    + self.e12() * b.scalar()
+                    - self.e34() * b.e1234()
+                    + self.e35() * b.e1235()
+                    + self.e45() * b.e1245()
+                    + self.e123() * b.e3()
+                    + self.e124() * b.e4()
+                    - self.e125() * b.e5()
+                    + self.e345() * b.e12345()
+                    - self.e1234() * b.e34()
+                    + self.e1235() * b.e35()
+                    + self.e1245() * b.e45()
+                    + self.e12345() * b.e345(),
+                self.scalar() * b.e31() + self.e2() * b.e123() + self.e4() * b.e314()
+                    - self.e5() * b.e315()
+                    + self.e31() * b.scalar()
+                    - self.e24() * b.e1234()
+                    + self.e25() * b.e1235()
+                    + self.e45() * b.e3145()
+                    + self.e123() * b.e2()
+                    + self.e314() * b.e4()
+                    - self.e315() * b.e5()
+                    + self.e245() * b.e12345()
+                    - self.e1234() * b.e24()
+                    + self.e1235() * b.e25()
+                    + self.e3145() * b.e45()
+                    + self.e12345() * b.e245(),
+                self.scalar() * b.e23() + self.e1() * b.e123() + self.e4() * b.e234()
+                    - self.e5() * b.e235()
+                    + self.e23() * b.scalar()
+                    - self.e14() * b.e1234()
+                    + self.e15() * b.e1235()
+                    + self.e45() * b.e2345()
+                    + self.e123() * b.e1()
+                    + self.e234() * b.e4()
+                    - self.e235() * b.e5()
+                    + self.e145() * b.e12345()
+                    - self.e1234() * b.e14()
+                    + self.e1235() * b.e15()
+                    + self.e2345() * b.e45()
+                    + self.e12345() * b.e145(),
+                self.scalar() * b.e14() - self.e2() * b.e124() + self.e3() * b.e314()
+                    - self.e5() * b.e145()
+                    - self.e23() * b.e1234()
+                    + self.e14() * b.scalar()
+                    - self.e25() * b.e1245()
+                    + self.e35() * b.e3145()
+                    - self.e124() * b.e2()
+                    + self.e314() * b.e3()
+                    + self.e235() * b.e12345()
+                    - self.e145() * b.e5()
+                    - self.e1234() * b.e23()
+                    - self.e1245() * b.e25()
+                    + self.e3145() * b.e35()
+                    + self.e12345() * b.e235(),
+                self.scalar() * b.e24() + self.e1() * b.e124()
+                    - self.e3() * b.e234()
+                    - self.e5() * b.e245()
+                    - self.e31() * b.e1234()
+                    + self.e24() * b.scalar()
+                    + self.e15() * b.e1245()
+                    - self.e35() * b.e2345()
+                    + self.e124() * b.e1()
+                    - self.e234() * b.e3()
+                    + self.e315() * b.e12345()
+                    - self.e245() * b.e5()
+                    - self.e1234() * b.e31()
+                    + self.e1245() * b.e15()
+                    - self.e2345() * b.e35()
+                    + self.e12345() * b.e315(),
+                self.scalar() * b.e34() - self.e1() * b.e314() + self.e2() * b.e234()
+                    - self.e5() * b.e345()
+                    - self.e12() * b.e1234()
+                    + self.e34() * b.scalar()
+                    - self.e15() * b.e3145()
+                    + self.e25() * b.e2345()
+                    - self.e314() * b.e1()
+                    + self.e234() * b.e2()
+                    + self.e125() * b.e12345()
+                    - self.e345() * b.e5()
+                    - self.e1234() * b.e12()
+                    - self.e3145() * b.e15()
+                    + self.e2345() * b.e25()
+                    + self.e12345() * b.e125(),
+                self.scalar() * b.e15() - self.e2() * b.e125() + self.e3() * b.e315()
+                    - self.e4() * b.e145()
+                    - self.e23() * b.e1235()
+                    - self.e24() * b.e1245()
+                    + self.e34() * b.e3145()
+                    + self.e15() * b.scalar()
+                    + self.e234() * b.e12345()
+                    - self.e125() * b.e2()
+                    + self.e315() * b.e3()
+                    - self.e145() * b.e4()
+                    - self.e1235() * b.e23()
+                    - self.e1245() * b.e24()
+                    + self.e3145() * b.e34()
+                    + self.e12345() * b.e234(),
+                self.scalar() * b.e25() + self.e1() * b.e125()
+                    - self.e3() * b.e235()
+                    - self.e4() * b.e245()
+                    - self.e31() * b.e1235()
+                    + self.e14() * b.e1245()
+                    - self.e34() * b.e2345()
+                    + self.e25() * b.scalar()
+                    + self.e314() * b.e12345()
+                    + self.e125() * b.e1()
+                    - self.e235() * b.e3()
+                    - self.e245() * b.e4()
+                    - self.e1235() * b.e31()
+                    + self.e1245() * b.e14()
+                    - self.e2345() * b.e34()
+                    + self.e12345() * b.e314(),
+                self.scalar() * b.e35() - self.e1() * b.e315() + self.e2() * b.e235()
+                    - self.e4() * b.e345()
+                    - self.e12() * b.e1235()
+                    - self.e14() * b.e3145()
+                    + self.e24() * b.e2345()
+                    + self.e35() * b.scalar()
+                    + self.e124() * b.e12345()
+                    - self.e315() * b.e1()
+                    + self.e235() * b.e2()
+                    - self.e345() * b.e4()
+                    - self.e1235() * b.e12()
+                    - self.e3145() * b.e14()
+                    + self.e2345() * b.e24()
+                    + self.e12345() * b.e124(),
+                self.scalar() * b.e45()
+                    + self.e1() * b.e145()
+                    + self.e2() * b.e245()
+                    + self.e3() * b.e345()
+                    - self.e12() * b.e1245()
+                    - self.e31() * b.e3145()
+                    - self.e23() * b.e2345()
+                    + self.e45() * b.scalar()
+                    - self.e123() * b.e12345()
+                    + self.e145() * b.e1()
+                    + self.e245() * b.e2()
+                    + self.e345() * b.e3()
+                    - self.e1245() * b.e12()
+                    - self.e3145() * b.e31()
+                    - self.e2345() * b.e23()
+                    - self.e12345() * b.e123(),
+            ),
+            Trivector::new(
+                self.scalar() * b.e123() - self.e4() * b.e1234()
+                    + self.e5() * b.e1235()
+                    + self.e45() * b.e12345()
+                    + self.e123() * b.scalar()
+                    + self.e1234() * b.e4()
+                    - self.e1235() * b.e5()
+                    + self.e12345() * b.e45(),
+                self.scalar() * b.e124() + self.e3() * b.e1234() + self.e5() * b.e1245()
+                    - self.e35() * b.e12345()
+                    + self.e124() * b.scalar()
+                    - self.e1234() * b.e3()
+                    - self.e1245() * b.e5()
+                    - self.e12345() * b.e35(),
+                self.scalar() * b.e314() + self.e2() * b.e1234() + self.e5() * b.e3145()
+                    - self.e25() * b.e12345()
+                    + self.e314() * b.scalar()
+                    - self.e1234() * b.e2()
+                    - self.e3145() * b.e5()
+                    - self.e12345() * b.e25(),
+                self.scalar() * b.e234() + self.e1() * b.e1234() + self.e5() * b.e2345()
+                    - self.e15() * b.e12345()
+                    + self.e234() * b.scalar()
+                    - self.e1234() * b.e1()
+                    - self.e2345() * b.e5()
+                    - self.e12345() * b.e15(),
+                self.scalar() * b.e125() + self.e3() * b.e1235() + self.e4() * b.e1245()
+                    - self.e34() * b.e12345()
+                    + self.e125() * b.scalar()
+                    - self.e1235() * b.e3()
+                    - self.e1245() * b.e4()
+                    - self.e12345() * b.e34(),
+                self.scalar() * b.e315() + self.e2() * b.e1235() + self.e4() * b.e3145()
+                    - self.e24() * b.e12345()
+                    + self.e315() * b.scalar()
+                    - self.e1235() * b.e2()
+                    - self.e3145() * b.e4()
+                    - self.e12345() * b.e24(),
+                self.scalar() * b.e235() + self.e1() * b.e1235() + self.e4() * b.e2345()
+                    - self.e14() * b.e12345()
+                    + self.e235() * b.scalar()
+                    - self.e1235() * b.e1()
+                    - self.e2345() * b.e4()
+                    - self.e12345() * b.e14(),
+                self.scalar() * b.e145() - self.e2() * b.e1245() + self.e3() * b.e3145()
+                    - self.e23() * b.e12345()
+                    + self.e145() * b.scalar()
+                    + self.e1245() * b.e2()
+                    - self.e3145() * b.e3()
+                    - self.e12345() * b.e23(),
+                self.scalar() * b.e245() + self.e1() * b.e1245()
+                    - self.e3() * b.e2345()
+                    - self.e31() * b.e12345()
+                    + self.e245() * b.scalar()
+                    - self.e1245() * b.e1()
+                    + self.e2345() * b.e3()
+                    - self.e12345() * b.e31(),
+                self.scalar() * b.e345() - self.e1() * b.e3145() + self.e2() * b.e2345()
+                    - self.e12() * b.e12345()
+                    + self.e345() * b.scalar()
+                    + self.e3145() * b.e1()
+                    - self.e2345() * b.e2()
+                    - self.e12345() * b.e12(),
+            ),
+            Quadvector::new(
+                self.scalar() * b.e1234() - self.e5() * b.e12345() + self.e1234() * b.scalar()
+                    - self.e12345() * b.e5(),
+                self.scalar() * b.e1235() - self.e4() * b.e12345() + self.e1235() * b.scalar()
+                    - self.e12345() * b.e4(),
+                self.scalar() * b.e1245()
+                    + self.e3() * b.e12345()
+                    + self.e1245() * b.scalar()
+                    + self.e12345() * b.e3(),
+                self.scalar() * b.e3145()
+                    + self.e2() * b.e12345()
+                    + self.e3145() * b.scalar()
+                    + self.e12345() * b.e2(),
+                self.scalar() * b.e2345()
+                    + self.e1() * b.e12345()
+                    + self.e2345() * b.scalar()
+                    + self.e12345() * b.e1(),
+            ),
+            self.scalar() * b.e12345() + self.e12345() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Multivector<F>);
+
+// Test
+#[cfg(test)]
+mod inner_product {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn vector_vector() {
+        // -3e1-4e2+4e3-1e4-4e5
+        let vector1 = Vector::new(-3.0, -4.0, 4.0, -1.0, -4.0);
+        // 1e1-1e2-4e3-1e4+2e5
+        let vector2 = Vector::new(1.0, -1.0, -4.0, -1.0, 2.0);
+        let res = vector1 | vector2;
+        // -6
+        assert_relative_eq!(res.0, -6.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_bivector() {
+        // -3e1-2e2-3e3-3e4+5e5
+        let vector1 = Vector::new(-3.0, -2.0, -3.0, -3.0, 5.0);
+        // 2e12+1e31+3e23+4e14-1e24+4e34-3e15+1e25-3e35+4e45
+        let bivector2 = Bivector::new(2.0, 1.0, 3.0, 4.0, -1.0, 4.0, -3.0, 1.0, -3.0, 4.0);
+        let res = vector1 | bivector2;
+        // -2e1+5e2-6e3-2e4+4e5
+        assert_relative_eq!(res.e1(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), -6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e4(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e5(), 4.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_vector() {
+        // 4e12+1e31-2e23-3e14-4e24+3e34-2e15+4e25+5e35+2e45
+        let bivector1 = Bivector::new(4.0, 1.0, -2.0, -3.0, -4.0, 3.0, -2.0, 4.0, 5.0, 2.0);
+        // -3e1+4e2+1e3+3e4+2e5
+        let vector2 = Vector::new(-3.0, 4.0, 1.0, 3.0, 2.0);
+        let res = bivector1 | vector2;
+        // 10e1-10e2+4e3-33e5
+        assert_relative_eq!(res.e1(), 10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e4(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e5(), -33.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_trivector() {
+        // 2e12-4e31-1e23-2e14-4e24-2e34-3e15-3e25-3e35+3e45
+        let bivector1 = Bivector::new(2.0, -4.0, -1.0, -2.0, -4.0, -2.0, -3.0, -3.0, -3.0, 3.0);
+        // -1e123+1e124+1e314+1e234+4e125+5e315-3e235-4e145-2e245+3e345
+        let trivector2 = Trivector::new(-1.0, 1.0, 1.0, 1.0, 4.0, 5.0, -3.0, -4.0, -2.0, 3.0);
+        let res = bivector1 | trivector2;
+        // -8e1+11e2-15e3-6e4-1e5
+        assert_relative_eq!(res.e1(), -8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 11.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), -15.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e4(), -6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e5(), -1.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn quadvector_vector() {
+        // -4e1234+4e1235+5e1245-4e3145+2e2345
+        let quadvector1 = Quadvector::new(-4.0, 4.0, 5.0, -4.0, 2.0);
+        // -2e1+2e2+2e3-4e4-1e5
+        let vector2 = Vector::new(-2.0, 2.0, 2.0, -4.0, -1.0);
+        let res = quadvector1 | vector2;
+        // 20e123+13e124+4e314-6e234+12e125-24e315+16e235+18e145+14e245+4e345
+        assert_relative_eq!(res.e123(), 20.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e124(), 13.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e314(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e234(), -6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e125(), 12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e315(), -24.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e235(), 16.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e145(), 18.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e245(), 14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e345(), 4.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn versor_bivector() {
+        // -4+3e12+3e31+1e23-2e14+4e24+1e34-4e15-2e25+4e35-4e45-1e1234+4e1235-3e1245-2e3145-3e2345
+        let versor1 = Versor::new(
+            -4.0,
+            Bivector::new(3.0, 3.0, 1.0, -2.0, 4.0, 1.0, -4.0, -2.0, 4.0, -4.0),
+            Quadvector::new(-1.0, 4.0, -3.0, -2.0, -3.0),
+        );
+        // -1e12-1e31+5e23-3e14-4e24+5e34-1e15-3e25-2e35+3e45
+        let bivector2 = Bivector::new(-1.0, -1.0, 5.0, -3.0, -4.0, 5.0, -1.0, -3.0, -2.0, 3.0);
+        let res = versor1 | bivector2;
+        // -4-8e12-18e31-36e23+12e14+12e24-14e34-38e15+40e25+18e35-2e45
+        assert_relative_eq!(res.scalar(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e4(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e5(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), -8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), -18.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), -36.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e14(), 12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e24(), 12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e34(), -14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e15(), -38.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e25(), 40.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e35(), 18.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e45(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e124(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e314(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e234(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e125(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e315(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e235(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e145(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e245(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e345(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1234(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1235(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1245(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3145(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2345(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12345(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_multivector() {
+        // -3+1e1+1e2+5e3-3e4-3e5+1e12-1e31+1e23+5e14-2e24+3e34-3e15+1e25+3e35-3e45+3e123+5e124-4e314-3e234+3e125+2e315+2e235+3e145-3e245-3e345+1e1234-1e1235-1e1245-2e3145-3e2345+1e12345
+        let mvec1 = Multivector::new(
+            -3.0,
+            Vector::new(1.0, 1.0, 5.0, -3.0, -3.0),
+            Bivector::new(1.0, -1.0, 1.0, 5.0, -2.0, 3.0, -3.0, 1.0, 3.0, -3.0),
+            Trivector::new(3.0, 5.0, -4.0, -3.0, 3.0, 2.0, 2.0, 3.0, -3.0, -3.0),
+            Quadvector::new(1.0, -1.0, -1.0, -2.0, -3.0),
+            1.0,
+        );
+        // -2-3e1+4e2+5e3-1e4-4e5-3e12+1e31+5e23+1e14+3e24+4e34-3e15-3e25-4e35+3e45+3e123-4e124-4e314+3e234+4e125+2e315+1e235+4e145+3e245+2e345+5e1234+1e1235-2e1245-2e3145+2e2345-3e12345
+        let mvec2 = Multivector::new(
+            -2.0,
+            Vector::new(-3.0, 4.0, 5.0, -1.0, -4.0),
+            Bivector::new(-3.0, 1.0, 5.0, 1.0, 3.0, 4.0, -3.0, -3.0, -4.0, 3.0),
+            Trivector::new(3.0, -4.0, -4.0, 3.0, 4.0, 2.0, 1.0, 4.0, 3.0, 2.0),
+            Quadvector::new(5.0, 1.0, -2.0, -2.0, 2.0),
+            -3.0,
+        );
+        let res = mvec1 | mvec2;
+        // 24-87e1-50e2+26e3+29e4-49e5+70e12+67e31-64e23-59e14-36e24-49e34+35e15-2e25-2e35-4e45+4e123+37e124+25e314-19e234+2e125-10e315-4e235-22e145-37e245+28e345-22e1234-9e1235-2e1245+11e3145-6e2345+7e12345
+        assert_relative_eq!(res.scalar(), 24.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -87.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -50.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 26.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e4(), 29.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e5(), -49.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 70.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 67.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), -64.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e14(), -59.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e24(), -36.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e34(), -49.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e15(), 35.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e25(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e35(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e45(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e124(), 37.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e314(), 25.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e234(), -19.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e125(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e315(), -10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e235(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e145(), -22.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e245(), -37.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e345(), 28.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1234(), -22.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1235(), -9.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1245(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3145(), 11.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2345(), -6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12345(), 7.0, max_relative = 0.000001);
+    }
+}
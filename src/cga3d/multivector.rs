@@ -0,0 +1,544 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::fmt;
+
+use core::ops::Neg;
+
+use num_traits::Float;
+
+use super::{
+    CGA3DOps, bivector::Bivector, pseudoscalar::Pseudoscalar, quadvector::Quadvector,
+    scalar::Scalar, trivector::Trivector, vector::Vector, versor::Versor,
+};
+
+/// # 3D Conformal Geometric Algebra Multivector
+/// The full 32 component element of $\text{Cl}(4,1,0)$
+/// $$ A = s + v + B + T + Q + I $$
+/// with a scalar, a vector, a bivector, a trivector, a quadvector and a pseudoscalar part.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Multivector<F: Float> {
+    pub(super) scalar: Scalar<F>,
+    pub(super) vector: Vector<F>,
+    pub(super) bivector: Bivector<F>,
+    pub(super) trivector: Trivector<F>,
+    pub(super) quadvector: Quadvector<F>,
+    pub(super) pseudoscalar: Pseudoscalar<F>,
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + fmt::Display> fmt::Display for Multivector<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Multivector {{")?;
+        writeln!(f, "\tscalar: {}", self.scalar())?;
+        writeln!(f, "\tvector: {}", self.vector)?;
+        writeln!(f, "\tbivector: {}", self.bivector)?;
+        writeln!(f, "\ttrivector: {}", self.trivector)?;
+        writeln!(f, "\tquadvector: {}", self.quadvector)?;
+        writeln!(f, "\tpseudoscalar: {}", self.pseudoscalar)?;
+        write!(f, "}}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F: Float + defmt::Format> defmt::Format for Multivector<F> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Multivector {{\n");
+        defmt::write!(f, "\tscalar: {}\n", self.scalar());
+        defmt::write!(f, "\tvector: {}\n", self.vector);
+        defmt::write!(f, "\tbivector: {}\n", self.bivector);
+        defmt::write!(f, "\ttrivector: {}\n", self.trivector);
+        defmt::write!(f, "\tquadvector: {}\n", self.quadvector);
+        defmt::write!(f, "\tpseudoscalar: {}\n", self.pseudoscalar);
+        defmt::write!(f, "}}");
+    }
+}
+
+impl<F: Float> Multivector<F> {
+    /// The zero multivector
+    pub fn zero() -> Self {
+        Multivector {
+            scalar: Scalar::zero(),
+            vector: Vector::zero(),
+            bivector: Bivector::zero(),
+            trivector: Trivector::zero(),
+            quadvector: Quadvector::zero(),
+            pseudoscalar: Pseudoscalar::zero(),
+        }
+    }
+
+    /// Create new multivector from its grades
+    pub fn new(
+        scalar: F,
+        vector: Vector<F>,
+        bivector: Bivector<F>,
+        trivector: Trivector<F>,
+        quadvector: Quadvector<F>,
+        e12345: F,
+    ) -> Self {
+        Multivector {
+            scalar: Scalar(scalar),
+            vector,
+            bivector,
+            trivector,
+            quadvector,
+            pseudoscalar: Pseudoscalar::new(e12345),
+        }
+    }
+
+    /// Get multivector
+    pub fn multivector(self) -> Self {
+        self
+    }
+
+    /// Get the scalar grade
+    pub fn scalar(&self) -> F {
+        self.scalar.0
+    }
+
+    /// Get the vector grade
+    pub fn vector(&self) -> Vector<F> {
+        self.vector
+    }
+
+    /// Get the bivector grade
+    pub fn bivector(&self) -> Bivector<F> {
+        self.bivector
+    }
+
+    /// Get the trivector grade
+    pub fn trivector(&self) -> Trivector<F> {
+        self.trivector
+    }
+
+    /// Get the quadvector grade
+    pub fn quadvector(&self) -> Quadvector<F> {
+        self.quadvector
+    }
+
+    /// Get the pseudoscalar grade
+    pub fn pseudoscalar(&self) -> Pseudoscalar<F> {
+        self.pseudoscalar
+    }
+
+    /// Get the even grades as a versor
+    pub fn versor(&self) -> Versor<F> {
+        Versor::new(self.scalar(), self.bivector, self.quadvector)
+    }
+
+    /// Get the $\mathrm{e}_1$ part of the vector grade
+    pub fn e1(&self) -> F {
+        self.vector.e1()
+    }
+
+    /// Get the $\mathrm{e}_2$ part of the vector grade
+    pub fn e2(&self) -> F {
+        self.vector.e2()
+    }
+
+    /// Get the $\mathrm{e}_3$ part of the vector grade
+    pub fn e3(&self) -> F {
+        self.vector.e3()
+    }
+
+    /// Get the $\mathrm{e}_4$ part of the vector grade
+    pub fn e4(&self) -> F {
+        self.vector.e4()
+    }
+
+    /// Get the $\mathrm{e}_5$ part of the vector grade
+    pub fn e5(&self) -> F {
+        self.vector.e5()
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2$ part of the bivector grade
+    pub fn e12(&self) -> F {
+        self.bivector.e12()
+    }
+
+    /// Get the $\mathrm{e}_3\mathrm{e}_1$ part of the bivector grade
+    pub fn e31(&self) -> F {
+        self.bivector.e31()
+    }
+
+    /// Get the $\mathrm{e}_2\mathrm{e}_3$ part of the bivector grade
+    pub fn e23(&self) -> F {
+        self.bivector.e23()
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_4$ part of the bivector grade
+    pub fn e14(&self) -> F {
+        self.bivector.e14()
+    }
+
+    /// Get the $\mathrm{e}_2\mathrm{e}_4$ part of the bivector grade
+    pub fn e24(&self) -> F {
+        self.bivector.e24()
+    }
+
+    /// Get the $\mathrm{e}_3\mathrm{e}_4$ part of the bivector grade
+    pub fn e34(&self) -> F {
+        self.bivector.e34()
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_5$ part of the bivector grade
+    pub fn e15(&self) -> F {
+        self.bivector.e15()
+    }
+
+    /// Get the $\mathrm{e}_2\mathrm{e}_5$ part of the bivector grade
+    pub fn e25(&self) -> F {
+        self.bivector.e25()
+    }
+
+    /// Get the $\mathrm{e}_3\mathrm{e}_5$ part of the bivector grade
+    pub fn e35(&self) -> F {
+        self.bivector.e35()
+    }
+
+    /// Get the $\mathrm{e}_4\mathrm{e}_5$ part of the bivector grade
+    pub fn e45(&self) -> F {
+        self.bivector.e45()
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2\mathrm{e}_3$ part of the trivector grade
+    pub fn e123(&self) -> F {
+        self.trivector.e123()
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2\mathrm{e}_4$ part of the trivector grade
+    pub fn e124(&self) -> F {
+        self.trivector.e124()
+    }
+
+    /// Get the $\mathrm{e}_3\mathrm{e}_1\mathrm{e}_4$ part of the trivector grade
+    pub fn e314(&self) -> F {
+        self.trivector.e314()
+    }
+
+    /// Get the $\mathrm{e}_2\mathrm{e}_3\mathrm{e}_4$ part of the trivector grade
+    pub fn e234(&self) -> F {
+        self.trivector.e234()
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2\mathrm{e}_5$ part of the trivector grade
+    pub fn e125(&self) -> F {
+        self.trivector.e125()
+    }
+
+    /// Get the $\mathrm{e}_3\mathrm{e}_1\mathrm{e}_5$ part of the trivector grade
+    pub fn e315(&self) -> F {
+        self.trivector.e315()
+    }
+
+    /// Get the $\mathrm{e}_2\mathrm{e}_3\mathrm{e}_5$ part of the trivector grade
+    pub fn e235(&self) -> F {
+        self.trivector.e235()
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_4\mathrm{e}_5$ part of the trivector grade
+    pub fn e145(&self) -> F {
+        self.trivector.e145()
+    }
+
+    /// Get the $\mathrm{e}_2\mathrm{e}_4\mathrm{e}_5$ part of the trivector grade
+    pub fn e245(&self) -> F {
+        self.trivector.e245()
+    }
+
+    /// Get the $\mathrm{e}_3\mathrm{e}_4\mathrm{e}_5$ part of the trivector grade
+    pub fn e345(&self) -> F {
+        self.trivector.e345()
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2\mathrm{e}_3\mathrm{e}_4$ part of the quadvector grade
+    pub fn e1234(&self) -> F {
+        self.quadvector.e1234()
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2\mathrm{e}_3\mathrm{e}_5$ part of the quadvector grade
+    pub fn e1235(&self) -> F {
+        self.quadvector.e1235()
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2\mathrm{e}_4\mathrm{e}_5$ part of the quadvector grade
+    pub fn e1245(&self) -> F {
+        self.quadvector.e1245()
+    }
+
+    /// Get the $\mathrm{e}_3\mathrm{e}_1\mathrm{e}_4\mathrm{e}_5$ part of the quadvector grade
+    pub fn e3145(&self) -> F {
+        self.quadvector.e3145()
+    }
+
+    /// Get the $\mathrm{e}_2\mathrm{e}_3\mathrm{e}_4\mathrm{e}_5$ part of the quadvector grade
+    pub fn e2345(&self) -> F {
+        self.quadvector.e2345()
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2\mathrm{e}_3\mathrm{e}_4\mathrm{e}_5$ part of the pseudoscalar grade
+    pub fn e12345(&self) -> F {
+        self.pseudoscalar.e12345()
+    }
+
+    /// # Dual
+    /// $$ A^* = A I^{-1} $$
+    pub fn dual(self) -> Multivector<F> {
+        self * Pseudoscalar::new(-F::one())
+    }
+
+    /// # Undual
+    /// The inverse of the dual
+    /// $$ A = A^* I $$
+    pub fn undual(self) -> Multivector<F> {
+        self * Pseudoscalar::new(F::one())
+    }
+}
+
+// Negation
+impl<F: Float> Neg for Multivector<F> {
+    type Output = Multivector<F>;
+    fn neg(self) -> Multivector<F> {
+        Multivector {
+            scalar: -self.scalar,
+            vector: -self.vector,
+            bivector: -self.bivector,
+            trivector: -self.trivector,
+            quadvector: -self.quadvector,
+            pseudoscalar: -self.pseudoscalar,
+        }
+    }
+}
+
+impl<F: Float> CGA3DOps<F> for Multivector<F> {
+    fn reverse(self) -> Self {
+        Multivector {
+            scalar: self.scalar,
+            vector: self.vector,
+            bivector: -self.bivector,
+            trivector: -self.trivector,
+            quadvector: self.quadvector,
+            pseudoscalar: self.pseudoscalar,
+        }
+    }
+
+    fn conjugate(self) -> Self {
+        Multivector {
+            scalar: self.scalar,
+            vector: -self.vector,
+            bivector: -self.bivector,
+            trivector: self.trivector,
+            quadvector: self.quadvector,
+            pseudoscalar: -self.pseudoscalar,
+        }
+    }
+
+    fn involute(self) -> Self {
+        Multivector {
+            scalar: self.scalar,
+            vector: -self.vector,
+            bivector: self.bivector,
+            trivector: -self.trivector,
+            quadvector: self.quadvector,
+            pseudoscalar: -self.pseudoscalar,
+        }
+    }
+
+    // \[\|A\|^2=\left| \left< A \widetilde{A} \right>_0 \right|\]
+    fn norm(self) -> Scalar<F> {
+        Scalar(
+            (self.scalar() * self.scalar()
+                + self.e1() * self.e1()
+                + self.e2() * self.e2()
+                + self.e3() * self.e3()
+                + self.e4() * self.e4()
+                - self.e5() * self.e5()
+                + self.e12() * self.e12()
+                + self.e31() * self.e31()
+                + self.e23() * self.e23()
+                + self.e14() * self.e14()
+                + self.e24() * self.e24()
+                + self.e34() * self.e34()
+                - self.e15() * self.e15()
+                - self.e25() * self.e25()
+                - self.e35() * self.e35()
+                - self.e45() * self.e45()
+                + self.e123() * self.e123()
+                + self.e124() * self.e124()
+                + self.e314() * self.e314()
+                + self.e234() * self.e234()
+                - self.e125() * self.e125()
+                - self.e315() * self.e315()
+                - self.e235() * self.e235()
+                - self.e145() * self.e145()
+                - self.e245() * self.e245()
+                - self.e345() * self.e345()
+                + self.e1234() * self.e1234()
+                - self.e1235() * self.e1235()
+                - self.e1245() * self.e1245()
+                - self.e3145() * self.e3145()
+                - self.e2345() * self.e2345()
+                - self.e12345() * self.e12345())
+            .abs()
+            .sqrt(),
+        )
+    }
+
+    fn try_normalize(self) -> Option<Self> {
+        self.norm()
+            .try_inverse()
+            .map(|norm_inverse| self * norm_inverse)
+    }
+}
+
+// Conversions
+// Every grade and the versor is a multivector with the other grades set to zero
+impl<F: Float> From<Scalar<F>> for Multivector<F> {
+    fn from(scalar: Scalar<F>) -> Self {
+        Multivector {
+            scalar,
+            ..Multivector::zero()
+        }
+    }
+}
+
+impl<F: Float> From<Vector<F>> for Multivector<F> {
+    fn from(vector: Vector<F>) -> Self {
+        Multivector {
+            vector,
+            ..Multivector::zero()
+        }
+    }
+}
+
+impl<F: Float> From<Bivector<F>> for Multivector<F> {
+    fn from(bivector: Bivector<F>) -> Self {
+        Multivector {
+            bivector,
+            ..Multivector::zero()
+        }
+    }
+}
+
+impl<F: Float> From<Trivector<F>> for Multivector<F> {
+    fn from(trivector: Trivector<F>) -> Self {
+        Multivector {
+            trivector,
+            ..Multivector::zero()
+        }
+    }
+}
+
+impl<F: Float> From<Quadvector<F>> for Multivector<F> {
+    fn from(quadvector: Quadvector<F>) -> Self {
+        Multivector {
+            quadvector,
+            ..Multivector::zero()
+        }
+    }
+}
+
+impl<F: Float> From<Pseudoscalar<F>> for Multivector<F> {
+    fn from(pseudoscalar: Pseudoscalar<F>) -> Self {
+        Multivector {
+            pseudoscalar,
+            ..Multivector::zero()
+        }
+    }
+}
+
+impl<F: Float> From<Versor<F>> for Multivector<F> {
+    fn from(versor: Versor<F>) -> Self {
+        Multivector {
+            scalar: versor.scalar,
+            bivector: versor.bivector,
+            quadvector: versor.quadvector,
+            ..Multivector::zero()
+        }
+    }
+}
+
+#[cfg(test)]
+mod multivector_ops {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn multivector() -> Multivector<f64> {
+        Multivector::new(
+            1.0,
+            Vector::new(2.0, 3.0, -1.0, 4.0, 2.0),
+            Bivector::new(1.0, -2.0, 3.0, 1.0, 2.0, -1.0, 3.0, 1.0, 2.0, -2.0),
+            Trivector::new(-1.0, 2.0, 1.0, 3.0, -2.0, 1.0, 2.0, 1.0, -3.0, 1.0),
+            Quadvector::new(2.0, 1.0, -1.0, 3.0, 1.0),
+            -2.0,
+        )
+    }
+
+    #[test]
+    fn multivector_norm() {
+        let mvec = multivector();
+        // The norm is the scalar part of A times its reverse
+        let res = (mvec * mvec.reverse()).scalar();
+        assert_relative_eq!(mvec.norm().0, res.abs().sqrt(), max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_from() {
+        // The sum of the converted grades is the multivector
+        let mvec = multivector();
+        let res = Multivector::from(mvec.scalar)
+            + Multivector::from(mvec.vector())
+            + Multivector::from(mvec.bivector())
+            + Multivector::from(mvec.trivector())
+            + Multivector::from(mvec.quadvector())
+            + Multivector::from(mvec.pseudoscalar());
+        assert_eq!(res, mvec);
+        let versor = Versor::new(mvec.scalar(), mvec.bivector(), mvec.quadvector());
+        let res = Multivector::from(versor);
+        assert_eq!(res.scalar(), mvec.scalar());
+        assert_eq!(res.bivector(), mvec.bivector());
+        assert_eq!(res.quadvector(), mvec.quadvector());
+        assert_eq!(res.vector(), Vector::zero());
+        assert_eq!(res.trivector(), Trivector::zero());
+        assert_eq!(res.pseudoscalar(), Pseudoscalar::zero());
+    }
+
+    #[test]
+    fn multivector_reverse() {
+        let mvec = multivector();
+        assert_eq!(mvec.reverse().reverse(), mvec);
+        assert_eq!(mvec.reverse().bivector(), -mvec.bivector());
+        assert_eq!(mvec.reverse().quadvector(), mvec.quadvector());
+        assert_eq!(mvec.conjugate(), mvec.reverse().involute());
+    }
+
+    #[test]
+    fn multivector_dual() {
+        let mvec = multivector();
+        assert_eq!(mvec.dual().undual(), mvec);
+        assert_eq!(mvec.dual().vector(), mvec.quadvector().dual());
+        assert_eq!(mvec.dual().trivector(), mvec.bivector().dual());
+    }
+}
@@ -0,0 +1,284 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::fmt;
+
+use core::ops::Neg;
+
+use num_traits::Float;
+
+use super::{CGA3DOps, pseudoscalar::Pseudoscalar, scalar::Scalar, up, vector::Vector};
+use crate::vga3d;
+
+/// # 3D Conformal Geometric Algebra Quadvector
+/// The quadvectors of the algebra.
+///
+/// The outer product of four points is a sphere.
+/// If one of the points is the point at infinity the sphere is a plane.
+/// The dual of a quadvector is a vector, which is the dual sphere or dual plane.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Quadvector<F: Float> {
+    e1234: F,
+    e1235: F,
+    e1245: F,
+    e3145: F,
+    e2345: F,
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + fmt::Display> fmt::Display for Quadvector<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Quadvector {{")?;
+        write!(f, " {}e1234", self.e1234)?;
+
+        // Add the appropriate sign to the remaining components
+        for (value, name) in [
+            (self.e1235, "e1235"),
+            (self.e1245, "e1245"),
+            (self.e3145, "e3145"),
+            (self.e2345, "e2345"),
+        ] {
+            if value >= F::zero() {
+                write!(f, " + {}{}", value, name)?;
+            } else {
+                write!(f, " - {}{}", value.abs(), name)?;
+            }
+        }
+        write!(f, " }}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F: Float + defmt::Format> defmt::Format for Quadvector<F> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Quadvector {{");
+        defmt::write!(f, " {}e1234", self.e1234);
+
+        // Add the appropriate sign to the remaining components
+        for (value, name) in [
+            (self.e1235, "e1235"),
+            (self.e1245, "e1245"),
+            (self.e3145, "e3145"),
+            (self.e2345, "e2345"),
+        ] {
+            if value >= F::zero() {
+                defmt::write!(f, " + {}{}", value, name);
+            } else {
+                defmt::write!(f, " - {}{}", value.abs(), name);
+            }
+        }
+        defmt::write!(f, " }}");
+    }
+}
+
+impl<F: Float> Quadvector<F> {
+    /// The zero quadvector
+    pub fn zero() -> Self {
+        Self {
+            e1234: F::zero(),
+            e1235: F::zero(),
+            e1245: F::zero(),
+            e3145: F::zero(),
+            e2345: F::zero(),
+        }
+    }
+
+    /// Create new quadvector from linear combination of the basis blades
+    pub fn new(e1234: F, e1235: F, e1245: F, e3145: F, e2345: F) -> Self {
+        Self {
+            e1234,
+            e1235,
+            e1245,
+            e3145,
+            e2345,
+        }
+    }
+
+    /// Create the sphere through four points
+    /// $$ P_a \wedge P_b \wedge P_c \wedge P_d $$
+    pub fn sphere(
+        a: vga3d::Vector<F>,
+        b: vga3d::Vector<F>,
+        c: vga3d::Vector<F>,
+        d: vga3d::Vector<F>,
+    ) -> Self {
+        up(a) ^ up(b) ^ up(c) ^ up(d)
+    }
+
+    /// Create the plane through three points
+    /// $$ P_a \wedge P_b \wedge P_c \wedge \mathrm{e}_\infty $$
+    pub fn plane(a: vga3d::Vector<F>, b: vga3d::Vector<F>, c: vga3d::Vector<F>) -> Self {
+        up(a) ^ up(b) ^ up(c) ^ Vector::infinity()
+    }
+
+    /// Get quadvector
+    pub fn quadvector(self) -> Self {
+        self
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2\mathrm{e}_3\mathrm{e}_4$ part
+    pub fn e1234(&self) -> F {
+        self.e1234
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2\mathrm{e}_3\mathrm{e}_5$ part
+    pub fn e1235(&self) -> F {
+        self.e1235
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2\mathrm{e}_4\mathrm{e}_5$ part
+    pub fn e1245(&self) -> F {
+        self.e1245
+    }
+
+    /// Get the $\mathrm{e}_3\mathrm{e}_1\mathrm{e}_4\mathrm{e}_5$ part
+    pub fn e3145(&self) -> F {
+        self.e3145
+    }
+
+    /// Get the $\mathrm{e}_2\mathrm{e}_3\mathrm{e}_4\mathrm{e}_5$ part
+    pub fn e2345(&self) -> F {
+        self.e2345
+    }
+
+    /// # Dual
+    /// The dual of a quadvector is a vector
+    /// $$ A^* = A I^{-1} $$
+    pub fn dual(self) -> Vector<F> {
+        self * Pseudoscalar::new(-F::one())
+    }
+
+    /// # Undual
+    /// The inverse of the dual
+    /// $$ A = A^* I $$
+    pub fn undual(self) -> Vector<F> {
+        self * Pseudoscalar::new(F::one())
+    }
+}
+
+// Negation
+impl<F: Float> Neg for Quadvector<F> {
+    type Output = Quadvector<F>;
+    fn neg(self) -> Quadvector<F> {
+        Quadvector::new(
+            -self.e1234,
+            -self.e1235,
+            -self.e1245,
+            -self.e3145,
+            -self.e2345,
+        )
+    }
+}
+
+impl<F: Float> CGA3DOps<F> for Quadvector<F> {
+    fn reverse(self) -> Self {
+        self
+    }
+
+    fn conjugate(self) -> Self {
+        self
+    }
+
+    fn involute(self) -> Self {
+        self
+    }
+
+    fn norm(self) -> Scalar<F> {
+        Scalar(
+            (self.e1234 * self.e1234
+                - self.e1235 * self.e1235
+                - self.e1245 * self.e1245
+                - self.e3145 * self.e3145
+                - self.e2345 * self.e2345)
+                .abs()
+                .sqrt(),
+        )
+    }
+
+    fn try_normalize(self) -> Option<Self> {
+        self.norm().try_inverse().map(|norm_inverse| {
+            let norm_inverse = norm_inverse.0;
+            Quadvector::new(
+                self.e1234 * norm_inverse,
+                self.e1235 * norm_inverse,
+                self.e1245 * norm_inverse,
+                self.e3145 * norm_inverse,
+                self.e2345 * norm_inverse,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod quadvector_ops {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn quadvector_sphere() {
+        // Sphere with center (1,2,3) and radius 2
+        let sphere = Quadvector::sphere(
+            vga3d::Vector::new(3.0, 2.0, 3.0),
+            vga3d::Vector::new(1.0, 4.0, 3.0),
+            vga3d::Vector::new(1.0, 2.0, 5.0),
+            vga3d::Vector::new(-1.0, 2.0, 3.0),
+        );
+        let dual_sphere = sphere.dual();
+        assert_relative_eq!(
+            dual_sphere.try_radius().unwrap(),
+            2.0,
+            max_relative = 0.000001
+        );
+        let res = dual_sphere.try_center().unwrap();
+        assert_relative_eq!(res.e1(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 3.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn quadvector_plane() {
+        // The plane z = 2
+        let plane = Quadvector::plane(
+            vga3d::Vector::new(0.0, 0.0, 2.0),
+            vga3d::Vector::new(1.0, 0.0, 2.0),
+            vga3d::Vector::new(0.0, 1.0, 2.0),
+        );
+        let dual_plane = plane.dual();
+        assert_eq!(dual_plane.try_radius(), None);
+        assert_relative_eq!(dual_plane.e1(), 0.0, epsilon = 0.000001);
+        assert_relative_eq!(dual_plane.e2(), 0.0, epsilon = 0.000001);
+        assert_relative_eq!(
+            dual_plane.einf() / dual_plane.e3(),
+            2.0,
+            max_relative = 0.000001
+        );
+    }
+
+    #[test]
+    fn quadvector_dual() {
+        let quadvector = Quadvector::new(1.0, 2.0, 3.0, 4.0, 5.0);
+        assert_eq!(quadvector.dual().undual(), quadvector);
+    }
+}
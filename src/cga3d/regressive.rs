@@ -0,0 +1,2059 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    bivector::Bivector, multivector::Multivector, pseudoscalar::Pseudoscalar,
+    quadvector::Quadvector, scalar::Scalar, trivector::Trivector, vector::Vector, versor::Versor,
+};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::BitAnd;
+
+// Regressive Product
+
+// Scalar-Scalar
+impl<F: Float> BitAnd for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Scalar<F>);
+
+// Scalar-Vector
+impl<F: Float> BitAnd<Vector<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Vector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Vector<F>);
+
+// Vector-Scalar
+impl<F: Float> BitAnd<Scalar<F>> for Vector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Scalar<F>);
+
+// Scalar-Bivector
+impl<F: Float> BitAnd<Bivector<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Bivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Bivector<F>);
+
+// Bivector-Scalar
+impl<F: Float> BitAnd<Scalar<F>> for Bivector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Scalar<F>);
+
+// Scalar-Trivector
+impl<F: Float> BitAnd<Trivector<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Trivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Trivector<F>);
+
+// Trivector-Scalar
+impl<F: Float> BitAnd<Scalar<F>> for Trivector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Trivector<F>, Scalar<F>);
+
+// Scalar-Quadvector
+impl<F: Float> BitAnd<Quadvector<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Quadvector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Quadvector<F>);
+
+// Quadvector-Scalar
+impl<F: Float> BitAnd<Scalar<F>> for Quadvector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Quadvector<F>, Scalar<F>);
+
+// Scalar-Pseudoscalar
+impl<F: Float> BitAnd<Pseudoscalar<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Scalar<F> {
+        Scalar(self.0 * b.e12345())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Scalar
+impl<F: Float> BitAnd<Scalar<F>> for Pseudoscalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Scalar<F>) -> Scalar<F> {
+        Scalar(self.e12345() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Scalar<F>);
+
+// Scalar-Versor
+impl<F: Float> BitAnd<Versor<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Versor<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Versor<F>);
+
+// Versor-Scalar
+impl<F: Float> BitAnd<Scalar<F>> for Versor<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Versor<F>, Scalar<F>);
+
+// Scalar-Multivector
+impl<F: Float> BitAnd<Multivector<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Multivector<F>) -> Scalar<F> {
+        Scalar(self.0 * b.e12345())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Multivector<F>);
+
+// Multivector-Scalar
+impl<F: Float> BitAnd<Scalar<F>> for Multivector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Scalar<F>) -> Scalar<F> {
+        Scalar(self.e12345() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Scalar<F>);
+
+// Vector-Vector
+impl<F: Float> BitAnd for Vector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Vector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Vector<F>);
+
+// Vector-Bivector
+impl<F: Float> BitAnd<Bivector<F>> for Vector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Bivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Bivector<F>);
+
+// Bivector-Vector
+impl<F: Float> BitAnd<Vector<F>> for Bivector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Vector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Vector<F>);
+
+// Vector-Trivector
+impl<F: Float> BitAnd<Trivector<F>> for Vector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Trivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Trivector<F>);
+
+// Trivector-Vector
+impl<F: Float> BitAnd<Vector<F>> for Trivector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Vector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Trivector<F>, Vector<F>);
+
+// Vector-Quadvector
+impl<F: Float> BitAnd<Quadvector<F>> for Vector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Quadvector<F>) -> Scalar<F> {
+        Scalar(
+            self.e1() * b.e2345() + self.e2() * b.e3145() + self.e3() * b.e1245()
+                - self.e4() * b.e1235()
+                + self.e5() * b.e1234(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Quadvector<F>);
+
+// Quadvector-Vector
+impl<F: Float> BitAnd<Vector<F>> for Quadvector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Vector<F>) -> Scalar<F> {
+        Scalar(
+            self.e1234() * b.e5() - self.e1235() * b.e4()
+                + self.e1245() * b.e3()
+                + self.e3145() * b.e2()
+                + self.e2345() * b.e1(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Quadvector<F>, Vector<F>);
+
+// Vector-Pseudoscalar
+impl<F: Float> BitAnd<Pseudoscalar<F>> for Vector<F> {
+    type Output = Vector<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Vector<F> {
+        Vector::new(
+            self.e1() * b.e12345(),
+            self.e2() * b.e12345(),
+            self.e3() * b.e12345(),
+            self.e4() * b.e12345(),
+            self.e5() * b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Vector
+impl<F: Float> BitAnd<Vector<F>> for Pseudoscalar<F> {
+    type Output = Vector<F>;
+    fn bitand(self, b: Vector<F>) -> Vector<F> {
+        Vector::new(
+            self.e12345() * b.e1(),
+            self.e12345() * b.e2(),
+            self.e12345() * b.e3(),
+            self.e12345() * b.e4(),
+            self.e12345() * b.e5(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Vector<F>);
+
+// Vector-Versor
+impl<F: Float> BitAnd<Versor<F>> for Vector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Versor<F>) -> Scalar<F> {
+        Scalar(
+            self.e1() * b.e2345() + self.e2() * b.e3145() + self.e3() * b.e1245()
+                - self.e4() * b.e1235()
+                + self.e5() * b.e1234(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Versor<F>);
+
+// Versor-Vector
+impl<F: Float> BitAnd<Vector<F>> for Versor<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Vector<F>) -> Scalar<F> {
+        Scalar(
+            self.e1234() * b.e5() - self.e1235() * b.e4()
+                + self.e1245() * b.e3()
+                + self.e3145() * b.e2()
+                + self.e2345() * b.e1(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Versor<F>, Vector<F>);
+
+// Vector-Multivector
+impl<F: Float> BitAnd<Multivector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1() * b.e2345() + self.e2() * b.e3145() + self.e3() * b.e1245()
+                - self.e4() * b.e1235()
+                + self.e5() * b.e1234(),
+            Vector::new(
+                self.e1() * b.e12345(),
+                self.e2() * b.e12345(),
+                self.e3() * b.e12345(),
+                self.e4() * b.e12345(),
+                self.e5() * b.e12345(),
+            ),
+            Bivector::zero(),
+            Trivector::zero(),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Multivector<F>);
+
+// Multivector-Vector
+impl<F: Float> BitAnd<Vector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1234() * b.e5() - self.e1235() * b.e4()
+                + self.e1245() * b.e3()
+                + self.e3145() * b.e2()
+                + self.e2345() * b.e1(),
+            Vector::new(
+                self.e12345() * b.e1(),
+                self.e12345() * b.e2(),
+                self.e12345() * b.e3(),
+                self.e12345() * b.e4(),
+                self.e12345() * b.e5(),
+            ),
+            Bivector::zero(),
+            Trivector::zero(),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Vector<F>);
+
+// Bivector-Bivector
+impl<F: Float> BitAnd for Bivector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Bivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Bivector<F>);
+
+// Bivector-Trivector
+impl<F: Float> BitAnd<Trivector<F>> for Bivector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Trivector<F>) -> Scalar<F> {
+        Scalar(
+            self.e12() * b.e345()
+                + self.e31() * b.e245()
+                + self.e23() * b.e145()
+                + self.e14() * b.e235()
+                + self.e24() * b.e315()
+                + self.e34() * b.e125()
+                - self.e15() * b.e234()
+                - self.e25() * b.e314()
+                - self.e35() * b.e124()
+                + self.e45() * b.e123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Trivector<F>);
+
+// Trivector-Bivector
+impl<F: Float> BitAnd<Bivector<F>> for Trivector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Bivector<F>) -> Scalar<F> {
+        Scalar(
+            self.e123() * b.e45()
+                - self.e124() * b.e35()
+                - self.e314() * b.e25()
+                - self.e234() * b.e15()
+                + self.e125() * b.e34()
+                + self.e315() * b.e24()
+                + self.e235() * b.e14()
+                + self.e145() * b.e23()
+                + self.e245() * b.e31()
+                + self.e345() * b.e12(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Trivector<F>, Bivector<F>);
+
+// Bivector-Quadvector
+impl<F: Float> BitAnd<Quadvector<F>> for Bivector<F> {
+    type Output = Vector<F>;
+    fn bitand(self, b: Quadvector<F>) -> Vector<F> {
+        Vector::new(
+            self.e12() * b.e3145() - self.e31() * b.e1245() - self.e14() * b.e1235()
+                + self.e15() * b.e1234(),
+            -self.e12() * b.e2345() + self.e23() * b.e1245() - self.e24() * b.e1235()
+                + self.e25() * b.e1234(),
+            self.e31() * b.e2345() - self.e23() * b.e3145() - self.e34() * b.e1235()
+                + self.e35() * b.e1234(),
+            -self.e14() * b.e2345() - self.e24() * b.e3145() - self.e34() * b.e1245()
+                + self.e45() * b.e1234(),
+            -self.e15() * b.e2345() - self.e25() * b.e3145() - self.e35() * b.e1245()
+                + self.e45() * b.e1235(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Quadvector<F>);
+
+// Quadvector-Bivector
+impl<F: Float> BitAnd<Bivector<F>> for Quadvector<F> {
+    type Output = Vector<F>;
+    fn bitand(self, b: Bivector<F>) -> Vector<F> {
+        Vector::new(
+            -self.e1234() * b.e15() + self.e1235() * b.e14() + self.e1245() * b.e31()
+                - self.e3145() * b.e12(),
+            -self.e1234() * b.e25() + self.e1235() * b.e24() - self.e1245() * b.e23()
+                + self.e2345() * b.e12(),
+            -self.e1234() * b.e35() + self.e1235() * b.e34() + self.e3145() * b.e23()
+                - self.e2345() * b.e31(),
+            -self.e1234() * b.e45()
+                + self.e1245() * b.e34()
+                + self.e3145() * b.e24()
+                + self.e2345() * b.e14(),
+            -self.e1235() * b.e45()
+                + self.e1245() * b.e35()
+                + self.e3145() * b.e25()
+                + self.e2345() * b.e15(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Quadvector<F>, Bivector<F>);
+
+// Bivector-Pseudoscalar
+impl<F: Float> BitAnd<Pseudoscalar<F>> for Bivector<F> {
+    type Output = Bivector<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Bivector<F> {
+        Bivector::new(
+            self.e12() * b.e12345(),
+            self.e31() * b.e12345(),
+            self.e23() * b.e12345(),
+            self.e14() * b.e12345(),
+            self.e24() * b.e12345(),
+            self.e34() * b.e12345(),
+            self.e15() * b.e12345(),
+            self.e25() * b.e12345(),
+            self.e35() * b.e12345(),
+            self.e45() * b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Bivector
+impl<F: Float> BitAnd<Bivector<F>> for Pseudoscalar<F> {
+    type Output = Bivector<F>;
+    fn bitand(self, b: Bivector<F>) -> Bivector<F> {
+        Bivector::new(
+            self.e12345() * b.e12(),
+            self.e12345() * b.e31(),
+            self.e12345() * b.e23(),
+            self.e12345() * b.e14(),
+            self.e12345() * b.e24(),
+            self.e12345() * b.e34(),
+            self.e12345() * b.e15(),
+            self.e12345() * b.e25(),
+            self.e12345() * b.e35(),
+            self.e12345() * b.e45(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Bivector<F>);
+
+// Bivector-Versor
+impl<F: Float> BitAnd<Versor<F>> for Bivector<F> {
+    type Output = Vector<F>;
+    fn bitand(self, b: Versor<F>) -> Vector<F> {
+        Vector::new(
+            self.e12() * b.e3145() - self.e31() * b.e1245() - self.e14() * b.e1235()
+                + self.e15() * b.e1234(),
+            -self.e12() * b.e2345() + self.e23() * b.e1245() - self.e24() * b.e1235()
+                + self.e25() * b.e1234(),
+            self.e31() * b.e2345() - self.e23() * b.e3145() - self.e34() * b.e1235()
+                + self.e35() * b.e1234(),
+            -self.e14() * b.e2345() - self.e24() * b.e3145() - self.e34() * b.e1245()
+                + self.e45() * b.e1234(),
+            -self.e15() * b.e2345() - self.e25() * b.e3145() - self.e35() * b.e1245()
+                + self.e45() * b.e1235(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Versor<F>);
+
+// Versor-Bivector
+impl<F: Float> BitAnd<Bivector<F>> for Versor<F> {
+    type Output = Vector<F>;
+    fn bitand(self, b: Bivector<F>) -> Vector<F> {
+        Vector::new(
+            -self.e1234() * b.e15() + self.e1235() * b.e14() + self.e1245() * b.e31()
+                - self.e3145() * b.e12(),
+            -self.e1234() * b.e25() + self.e1235() * b.e24() - self.e1245() * b.e23()
+                + self.e2345() * b.e12(),
+            -self.e1234() * b.e35() + self.e1235() * b.e34() + self.e3145() * b.e23()
+                - self.e2345() * b.e31(),
+            -self.e1234() * b.e45()
+                + self.e1245() * b.e34()
+                + self.e3145() * b.e24()
+                + self.e2345() * b.e14(),
+            -self.e1235() * b.e45()
+                + self.e1245() * b.e35()
+                + self.e3145() * b.e25()
+                + self.e2345() * b.e15(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Versor<F>, Bivector<F>);
+
+// Bivector-Multivector
+impl<F: Float> BitAnd<Multivector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e12() * b.e345()
+                + self.e31() * b.e245()
+                + self.e23() * b.e145()
+                + self.e14() * b.e235()
+                + self.e24() * b.e315()
+                + self.e34() * b.e125()
+                - self.e15() * b.e234()
+                - self.e25() * b.e314()
+                - self.e35() * b.e124()
+                + self.e45() * b.e123(),
+            Vector::new(
+                self.e12() * b.e3145() - self.e31() * b.e1245() - self.e14() * b.e1235()
+                    + self.e15() * b.e1234(),
+                -self.e12() * b.e2345() + self.e23() * b.e1245() - self.e24() * b.e1235()
+                    + self.e25() * b.e1234(),
+                self.e31() * b.e2345() - self.e23() * b.e3145() - self.e34() * b.e1235()
+                    + self.e35() * b.e1234(),
+                -self.e14() * b.e2345() - self.e24() * b.e3145() - self.e34() * b.e1245()
+                    + self.e45() * b.e1234(),
+                -self.e15() * b.e2345() - self.e25() * b.e3145() - self.e35() * b.e1245()
+                    + self.e45() * b.e1235(),
+            ),
+            Bivector::new(
+                self.e12() * b.e12345(),
+                self.e31() * b.e12345(),
+                self.e23() * b.e12345(),
+                self.e14() * b.e12345(),
+                self.e24() * b.e12345(),
+                self.e34() * b.e12345(),
+                self.e15() * b.e12345(),
+                self.e25() * b.e12345(),
+                self.e35() * b.e12345(),
+                self.e45() * b.e12345(),
+            ),
+            Trivector::zero(),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Multivector<F>);
+
+// Multivector-Bivector
+impl<F: Float> BitAnd<Bivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e123() * b.e45()
+                - self.e124() * b.e35()
+                - self.e314() * b.e25()
+                - self.e234() * b.e15()
+                + self.e125() * b.e34()
+                + self.e315() * b.e24()
+                + self.e235() * b.e14()
+                + self.e145() * b.e23()
+                + self.e245() * b.e31()
+                + self.e345() * b.e12(),
+            Vector::new(
+                -self.e1234() * b.e15() + self.e1235() * b.e14() + self.e1245() * b.e31()
+                    - self.e3145() * b.e12(),
+                -self.e1234() * b.e25() + self.e1235() * b.e24() - self.e1245() * b.e23()
+                    + self.e2345() * b.e12(),
+                -self.e1234() * b.e35() + self.e1235() * b.e34() + self.e3145() * b.e23()
+                    - self.e2345() * b.e31(),
+                -self.e1234() * b.e45()
+                    + self.e1245() * b.e34()
+                    + self.e3145() * b.e24()
+                    + self.e2345() * b.e14(),
+                -self.e1235() * b.e45()
+                    + self.e1245() * b.e35()
+                    + self.e3145() * b.e25()
+                    + self.e2345() * b.e15(),
+            ),
+            Bivector::new(
+                self.e12345() * b.e12(),
+                self.e12345() * b.e31(),
+                self.e12345() * b.e23(),
+                self.e12345() * b.e14(),
+                self.e12345() * b.e24(),
+                self.e12345() * b.e34(),
+                self.e12345() * b.e15(),
+                self.e12345() * b.e25(),
+                self.e12345() * b.e35(),
+                self.e12345() * b.e45(),
+            ),
+            Trivector::zero(),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Bivector<F>);
+
+// Trivector-Trivector
+impl<F: Float> BitAnd for Trivector<F> {
+    type Output = Vector<F>;
+    fn bitand(self, b: Trivector<F>) -> Vector<F> {
+        Vector::new(
+            self.e123() * b.e145() + self.e124() * b.e315()
+                - self.e314() * b.e125()
+                - self.e125() * b.e314()
+                + self.e315() * b.e124()
+                + self.e145() * b.e123(),
+            self.e123() * b.e245() - self.e124() * b.e235()
+                + self.e234() * b.e125()
+                + self.e125() * b.e234()
+                - self.e235() * b.e124()
+                + self.e245() * b.e123(),
+            self.e123() * b.e345() + self.e314() * b.e235()
+                - self.e234() * b.e315()
+                - self.e315() * b.e234()
+                + self.e235() * b.e314()
+                + self.e345() * b.e123(),
+            self.e124() * b.e345()
+                + self.e314() * b.e245()
+                + self.e234() * b.e145()
+                + self.e145() * b.e234()
+                + self.e245() * b.e314()
+                + self.e345() * b.e124(),
+            self.e125() * b.e345()
+                + self.e315() * b.e245()
+                + self.e235() * b.e145()
+                + self.e145() * b.e235()
+                + self.e245() * b.e315()
+                + self.e345() * b.e125(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Trivector<F>, Trivector<F>);
+
+// Trivector-Quadvector
+impl<F: Float> BitAnd<Quadvector<F>> for Trivector<F> {
+    type Output = Bivector<F>;
+    fn bitand(self, b: Quadvector<F>) -> Bivector<F> {
+        Bivector::new(
+            self.e123() * b.e1245() - self.e124() * b.e1235() + self.e125() * b.e1234(),
+            self.e123() * b.e3145() - self.e314() * b.e1235() + self.e315() * b.e1234(),
+            self.e123() * b.e2345() - self.e234() * b.e1235() + self.e235() * b.e1234(),
+            -self.e124() * b.e3145() + self.e314() * b.e1245() + self.e145() * b.e1234(),
+            self.e124() * b.e2345() - self.e234() * b.e1245() + self.e245() * b.e1234(),
+            -self.e314() * b.e2345() + self.e234() * b.e3145() + self.e345() * b.e1234(),
+            -self.e125() * b.e3145() + self.e315() * b.e1245() + self.e145() * b.e1235(),
+            self.e125() * b.e2345() - self.e235() * b.e1245() + self.e245() * b.e1235(),
+            -self.e315() * b.e2345() + self.e235() * b.e3145() + self.e345() * b.e1235(),
+            self.e145() * b.e2345() + self.e245() * b.e3145() + self.e345() * b.e1245(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Trivector<F>, Quadvector<F>);
+
+// Quadvector-Trivector
+impl<F: Float> BitAnd<Trivector<F>> for Quadvector<F> {
+    type Output = Bivector<F>;
+    fn bitand(self, b: Trivector<F>) -> Bivector<F> {
+        Bivector::new(
+            self.e1234() * b.e125() - self.e1235() * b.e124() + self.e1245() * b.e123(),
+            self.e1234() * b.e315() - self.e1235() * b.e314() + self.e3145() * b.e123(),
+            self.e1234() * b.e235() - self.e1235() * b.e234() + self.e2345() * b.e123(),
+            self.e1234() * b.e145() + self.e1245() * b.e314() - self.e3145() * b.e124(),
+            self.e1234() * b.e245() - self.e1245() * b.e234() + self.e2345() * b.e124(),
+            self.e1234() * b.e345() + self.e3145() * b.e234() - self.e2345() * b.e314(),
+            self.e1235() * b.e145() + self.e1245() * b.e315() - self.e3145() * b.e125(),
+            self.e1235() * b.e245() - self.e1245() * b.e235() + self.e2345() * b.e125(),
+            self.e1235() * b.e345() + self.e3145() * b.e235() - self.e2345() * b.e315(),
+            self.e1245() * b.e345() + self.e3145() * b.e245() + self.e2345() * b.e145(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Quadvector<F>, Trivector<F>);
+
+// Trivector-Pseudoscalar
+impl<F: Float> BitAnd<Pseudoscalar<F>> for Trivector<F> {
+    type Output = Trivector<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Trivector<F> {
+        Trivector::new(
+            self.e123() * b.e12345(),
+            self.e124() * b.e12345(),
+            self.e314() * b.e12345(),
+            self.e234() * b.e12345(),
+            self.e125() * b.e12345(),
+            self.e315() * b.e12345(),
+            self.e235() * b.e12345(),
+            self.e145() * b.e12345(),
+            self.e245() * b.e12345(),
+            self.e345() * b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Trivector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Trivector
+impl<F: Float> BitAnd<Trivector<F>> for Pseudoscalar<F> {
+    type Output = Trivector<F>;
+    fn bitand(self, b: Trivector<F>) -> Trivector<F> {
+        Trivector::new(
+            self.e12345() * b.e123(),
+            self.e12345() * b.e124(),
+            self.e12345() * b.e314(),
+            self.e12345() * b.e234(),
+            self.e12345() * b.e125(),
+            self.e12345() * b.e315(),
+            self.e12345() * b.e235(),
+            self.e12345() * b.e145(),
+            self.e12345() * b.e245(),
+            self.e12345() * b.e345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Trivector<F>);
+
+// Trivector-Versor
+impl<F: Float> BitAnd<Versor<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e123() * b.e45()
+                - self.e124() * b.e35()
+                - self.e314() * b.e25()
+                - self.e234() * b.e15()
+                + self.e125() * b.e34()
+                + self.e315() * b.e24()
+                + self.e235() * b.e14()
+                + self.e145() * b.e23()
+                + self.e245() * b.e31()
+                + self.e345() * b.e12(),
+            Vector::zero(),
+            Bivector::new(
+                self.e123() * b.e1245() - self.e124() * b.e1235() + self.e125() * b.e1234(),
+                self.e123() * b.e3145() - self.e314() * b.e1235() + self.e315() * b.e1234(),
+                self.e123() * b.e2345() - self.e234() * b.e1235() + self.e235() * b.e1234(),
+                -self.e124() * b.e3145() + self.e314() * b.e1245() + self.e145() * b.e1234(),
+                self.e124() * b.e2345() - self.e234() * b.e1245() + self.e245() * b.e1234(),
+                -self.e314() * b.e2345() + self.e234() * b.e3145() + self.e345() * b.e1234(),
+                -self.e125() * b.e3145() + self.e315() * b.e1245() + self.e145() * b.e1235(),
+                self.e125() * b.e2345() - self.e235() * b.e1245() + self.e245() * b.e1235(),
+                -self.e315() * b.e2345() + self.e235() * b.e3145() + self.e345() * b.e1235(),
+                self.e145() * b.e2345() + self.e245() * b.e3145() + self.e345() * b.e1245(),
+            ),
+            Trivector::zero(),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Trivector<F>, Versor<F>);
+
+// Versor-Trivector
+impl<F: Float> BitAnd<Trivector<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e12() * b.e345()
+                + self.e31() * b.e245()
+                + self.e23() * b.e145()
+                + self.e14() * b.e235()
+                + self.e24() * b.e315()
+                + self.e34() * b.e125()
+                - self.e15() * b.e234()
+                - self.e25() * b.e314()
+                - self.e35() * b.e124()
+                + self.e45() * b.e123(),
+            Vector::zero(),
+            Bivector::new(
+                self.e1234() * b.e125() - self.e1235() * b.e124() + self.e1245() * b.e123(),
+                self.e1234() * b.e315() - self.e1235() * b.e314() + self.e3145() * b.e123(),
+                self.e1234() * b.e235() - self.e1235() * b.e234() + self.e2345() * b.e123(),
+                self.e1234() * b.e145() + self.e1245() * b.e314() - self.e3145() * b.e124(),
+                self.e1234() * b.e245() - self.e1245() * b.e234() + self.e2345() * b.e124(),
+                self.e1234() * b.e345() + self.e3145() * b.e234() - self.e2345() * b.e314(),
+                self.e1235() * b.e145() + self.e1245() * b.e315() - self.e3145() * b.e125(),
+                self.e1235() * b.e245() - self.e1245() * b.e235() + self.e2345() * b.e125(),
+                self.e1235() * b.e345() + self.e3145() * b.e235() - self.e2345() * b.e315(),
+                self.e1245() * b.e345() + self.e3145() * b.e245() + self.e2345() * b.e145(),
+            ),
+            Trivector::zero(),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Versor<F>, Trivector<F>);
+
+// Trivector-Multivector
+impl<F: Float> BitAnd<Multivector<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e123() * b.e45()
+                - self.e124() * b.e35()
+                - self.e314() * b.e25()
+                - self.e234() * b.e15()
+                + self.e125() * b.e34()
+                + self.e315() * b.e24()
+                + self.e235() * b.e14()
+                + self.e145() * b.e23()
+                + self.e245() * b.e31()
+                + self.e345() * b.e12(),
+            Vector::new(
+                self.e123() * b.e145() + self.e124() * b.e315()
+                    - self.e314() * b.e125()
+                    - self.e125() * b.e314()
+                    + self.e315() * b.e124()
+                    + self.e145() * b.e123(),
+                self.e123() * b.e245() - self.e124() * b.e235()
+                    + self.e234() * b.e125()
+                    + self.e125() * b.e234()
+                    - self.e235() * b.e124()
+                    + self.e245() * b.e123(),
+                self.e123() * b.e345() + self.e314() * b.e235()
+                    - self.e234() * b.e315()
+                    - self.e315() * b.e234()
+                    + self.e235() * b.e314()
+                    + self.e345() * b.e123(),
+                self.e124() * b.e345()
+                    + self.e314() * b.e245()
+                    + self.e234() * b.e145()
+                    + self.e145() * b.e234()
+                    + self.e245() * b.e314()
+                    + self.e345() * b.e124(),
+                self.e125() * b.e345()
+                    + self.e315() * b.e245()
+                    + self.e235() * b.e145()
+                    + self.e145() * b.e235()
+                    + self.e245() * b.e315()
+                    + self.e345() * b.e125(),
+            ),
+            Bivector::new(
+                self.e123() * b.e1245() - self.e124() * b.e1235() + self.e125() * b.e1234(),
+                self.e123() * b.e3145() - self.e314() * b.e1235() + self.e315() * b.e1234(),
+                self.e123() * b.e2345() - self.e234() * b.e1235() + self.e235() * b.e1234(),
+                -self.e124() * b.e3145() + self.e314() * b.e1245() + self.e145() * b.e1234(),
+                self.e124() * b.e2345() - self.e234() * b.e1245() + self.e245() * b.e1234(),
+                -self.e314() * b.e2345() + self.e234() * b.e3145() + self.e345() * b.e1234(),
+                -self.e125() * b.e3145() + self.e315() * b.e1245() + self.e145() * b.e1235(),
+                self.e125() * b.e2345() - self.e235() * b.e1245() + self.e245() * b.e1235(),
+                -self.e315() * b.e2345() + self.e235() * b.e3145() + self.e345() * b.e1235(),
+                self.e145() * b.e2345() + self.e245() * b.e3145() + self.e345() * b.e1245(),
+            ),
+            Trivector::new(
+                self.e123() * b.e12345(),
+                self.e124() * b.e12345(),
+                self.e314() * b.e12345(),
+                self.e234() * b.e12345(),
+                self.e125() * b.e12345(),
+                self.e315() * b.e12345(),
+                self.e235() * b.e12345(),
+                self.e145() * b.e12345(),
+                self.e245() * b.e12345(),
+                self.e345() * b.e12345(),
+            ),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Trivector<F>, Multivector<F>);
+
+// Multivector-Trivector
+impl<F: Float> BitAnd<Trivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e12() * b.e345()
+                + self.e31() * b.e245()
+                + self.e23() * b.e145()
+                + self.e14() * b.e235()
+                + self.e24() * b.e315()
+                + self.e34() * b.e125()
+                - self.e15() * b.e234()
+                - self.e25() * b.e314()
+                - self.e35() * b.e124()
+                + self.e45() * b.e123(),
+            Vector::new(
+                self.e123() * b.e145() + self.e124() * b.e315()
+                    - self.e314() * b.e125()
+                    - self.e125() * b.e314()
+                    + self.e315() * b.e124()
+                    + self.e145() * b.e123(),
+                self.e123() * b.e245() - self.e124() * b.e235()
+                    + self.e234() * b.e125()
+                    + self.e125() * b.e234()
+                    - self.e235() * b.e124()
+                    + self.e245() * b.e123(),
+                self.e123() * b.e345() + self.e314() * b.e235()
+                    - self.e234() * b.e315()
+                    - self.e315() * b.e234()
+                    + self.e235() * b.e314()
+                    + self.e345() * b.e123(),
+                self.e124() * b.e345()
+                    + self.e314() * b.e245()
+                    + self.e234() * b.e145()
+                    + self.e145() * b.e234()
+                    + self.e245() * b.e314()
+                    + self.e345() * b.e124(),
+                self.e125() * b.e345()
+                    + self.e315() * b.e245()
+                    + self.e235() * b.e145()
+                    + self.e145() * b.e235()
+                    + self.e245() * b.e315()
+                    + self.e345() * b.e125(),
+            ),
+            Bivector::new(
+                self.e1234() * b.e125() - self.e1235() * b.e124() + self.e1245() * b.e123(),
+                self.e1234() * b.e315() - self.e1235() * b.e314() + self.e3145() * b.e123(),
+                self.e1234() * b.e235() - self.e1235() * b.e234() + self.e2345() * b.e123(),
+                self.e1234() * b.e145() + self.e1245() * b.e314() - self.e3145() * b.e124(),
+                self.e1234() * b.e245() - self.e1245() * b.e234() + self.e2345() * b.e124(),
+                self.e1234() * b.e345() + self.e3145() * b.e234() - self.e2345() * b.e314(),
+                self.e1235() * b.e145() + self.e1245() * b.e315() - self.e3145() * b.e125(),
+                self.e1235() * b.e245() - self.e1245() * b.e235() + self.e2345() * b.e125(),
+                self.e1235() * b.e345() + self.e3145() * b.e235() - self.e2345() * b.e315(),
+                self.e1245() * b.e345() + self.e3145() * b.e245() + self.e2345() * b.e145(),
+            ),
+            Trivector::new(
+                self.e12345() * b.e123(),
+                self.e12345() * b.e124(),
+                self.e12345() * b.e314(),
+                self.e12345() * b.e234(),
+                self.e12345() * b.e125(),
+                self.e12345() * b.e315(),
+                self.e12345() * b.e235(),
+                self.e12345() * b.e145(),
+                self.e12345() * b.e245(),
+                self.e12345() * b.e345(),
+            ),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Trivector<F>);
+
+// Quadvector-Quadvector
+impl<F: Float> BitAnd for Quadvector<F> {
+    type Output = Trivector<F>;
+    fn bitand(self, b: Quadvector<F>) -> Trivector<F> {
+        Trivector::new(
+            -self.e1234() * b.e1235() + self.e1235() * b.e1234(),
+            -self.e1234() * b.e1245() + self.e1245() * b.e1234(),
+            -self.e1234() * b.e3145() + self.e3145() * b.e1234(),
+            -self.e1234() * b.e2345() + self.e2345() * b.e1234(),
+            -self.e1235() * b.e1245() + self.e1245() * b.e1235(),
+            -self.e1235() * b.e3145() + self.e3145() * b.e1235(),
+            -self.e1235() * b.e2345() + self.e2345() * b.e1235(),
+            self.e1245() * b.e3145() - self.e3145() * b.e1245(),
+            -self.e1245() * b.e2345() + self.e2345() * b.e1245(),
+            self.e3145() * b.e2345() - self.e2345() * b.e3145(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Quadvector<F>, Quadvector<F>);
+
+// Quadvector-Pseudoscalar
+impl<F: Float> BitAnd<Pseudoscalar<F>> for Quadvector<F> {
+    type Output = Quadvector<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Quadvector<F> {
+        Quadvector::new(
+            self.e1234() * b.e12345(),
+            self.e1235() * b.e12345(),
+            self.e1245() * b.e12345(),
+            self.e3145() * b.e12345(),
+            self.e2345() * b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Quadvector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Quadvector
+impl<F: Float> BitAnd<Quadvector<F>> for Pseudoscalar<F> {
+    type Output = Quadvector<F>;
+    fn bitand(self, b: Quadvector<F>) -> Quadvector<F> {
+        Quadvector::new(
+            self.e12345() * b.e1234(),
+            self.e12345() * b.e1235(),
+            self.e12345() * b.e1245(),
+            self.e12345() * b.e3145(),
+            self.e12345() * b.e2345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Quadvector<F>);
+
+// Quadvector-Versor
+impl<F: Float> BitAnd<Versor<F>> for Quadvector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                -self.e1234() * b.e15() + self.e1235() * b.e14() + self.e1245() * b.e31()
+                    - self.e3145() * b.e12(),
+                -self.e1234() * b.e25() + self.e1235() * b.e24() - self.e1245() * b.e23()
+                    + self.e2345() * b.e12(),
+                -self.e1234() * b.e35() + self.e1235() * b.e34() + self.e3145() * b.e23()
+                    - self.e2345() * b.e31(),
+                -self.e1234() * b.e45()
+                    + self.e1245() * b.e34()
+                    + self.e3145() * b.e24()
+                    + self.e2345() * b.e14(),
+                -self.e1235() * b.e45()
+                    + self.e1245() * b.e35()
+                    + self.e3145() * b.e25()
+                    + self.e2345() * b.e15(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                -self.e1234() * b.e1235() + self.e1235() * b.e1234(),
+                -self.e1234() * b.e1245() + self.e1245() * b.e1234(),
+                -self.e1234() * b.e3145() + self.e3145() * b.e1234(),
+                -self.e1234() * b.e2345() + self.e2345() * b.e1234(),
+                -self.e1235() * b.e1245() + self.e1245() * b.e1235(),
+                -self.e1235() * b.e3145() + self.e3145() * b.e1235(),
+                -self.e1235() * b.e2345() + self.e2345() * b.e1235(),
+                self.e1245() * b.e3145() - self.e3145() * b.e1245(),
+                -self.e1245() * b.e2345() + self.e2345() * b.e1245(),
+                self.e3145() * b.e2345() - self.e2345() * b.e3145(),
+            ),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Quadvector<F>, Versor<F>);
+
+// Versor-Quadvector
+impl<F: Float> BitAnd<Quadvector<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Quadvector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                self.e12() * b.e3145() - self.e31() * b.e1245() - self.e14() * b.e1235()
+                    + self.e15() * b.e1234(),
+                -self.e12() * b.e2345() + self.e23() * b.e1245() - self.e24() * b.e1235()
+                    + self.e25() * b.e1234(),
+                self.e31() * b.e2345() - self.e23() * b.e3145() - self.e34() * b.e1235()
+                    + self.e35() * b.e1234(),
+                -self.e14() * b.e2345() - self.e24() * b.e3145() - self.e34() * b.e1245()
+                    + self.e45() * b.e1234(),
+                -self.e15() * b.e2345() - self.e25() * b.e3145() - self.e35() * b.e1245()
+                    + self.e45() * b.e1235(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                -self.e1234() * b.e1235() + self.e1235() * b.e1234(),
+                -self.e1234() * b.e1245() + self.e1245() * b.e1234(),
+                -self.e1234() * b.e3145() + self.e3145() * b.e1234(),
+                -self.e1234() * b.e2345() + self.e2345() * b.e1234(),
+                -self.e1235() * b.e1245() + self.e1245() * b.e1235(),
+                -self.e1235() * b.e3145() + self.e3145() * b.e1235(),
+                -self.e1235() * b.e2345() + self.e2345() * b.e1235(),
+                self.e1245() * b.e3145() - self.e3145() * b.e1245(),
+                -self.e1245() * b.e2345() + self.e2345() * b.e1245(),
+                self.e3145() * b.e2345() - self.e2345() * b.e3145(),
+            ),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Versor<F>, Quadvector<F>);
+
+// Quadvector-Multivector
+impl<F: Float> BitAnd<Multivector<F>> for Quadvector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1234() * b.e5() - self.e1235() * b.e4()
+                + self.e1245() * b.e3()
+                + self.e3145() * b.e2()
+                + self.e2345() * b.e1(),
+            Vector::new(
+                -self.e1234() * b.e15() + self.e1235() * b.e14() + self.e1245() * b.e31()
+                    - self.e3145() * b.e12(),
+                -self.e1234() * b.e25() + self.e1235() * b.e24() - self.e1245() * b.e23()
+                    + self.e2345() * b.e12(),
+                -self.e1234() * b.e35() + self.e1235() * b.e34() + self.e3145() * b.e23()
+                    - self.e2345() * b.e31(),
+                -self.e1234() * b.e45()
+                    + self.e1245() * b.e34()
+                    + self.e3145() * b.e24()
+                    + self.e2345() * b.e14(),
+                -self.e1235() * b.e45()
+                    + self.e1245() * b.e35()
+                    + self.e3145() * b.e25()
+                    + self.e2345() * b.e15(),
+            ),
+            Bivector::new(
+                self.e1234() * b.e125() - self.e1235() * b.e124() + self.e1245() * b.e123(),
+                self.e1234() * b.e315() - self.e1235() * b.e314() + self.e3145() * b.e123(),
+                self.e1234() * b.e235() - self.e1235() * b.e234() + self.e2345() * b.e123(),
+                self.e1234() * b.e145() + self.e1245() * b.e314() - self.e3145() * b.e124(),
+                self.e1234() * b.e245() - self.e1245() * b.e234() + self.e2345() * b.e124(),
+                self.e1234() * b.e345() + self.e3145() * b.e234() - self.e2345() * b.e314(),
+                self.e1235() * b.e145() + self.e1245() * b.e315() - self.e3145() * b.e125(),
+                self.e1235() * b.e245() - self.e1245() * b.e235() + self.e2345() * b.e125(),
+                self.e1235() * b.e345() + self.e3145() * b.e235() - self.e2345() * b.e315(),
+                self.e1245() * b.e345() + self.e3145() * b.e245() + self.e2345() * b.e145(),
+            ),
+            Trivector::new(
+                -self.e1234() * b.e1235() + self.e1235() * b.e1234(),
+                -self.e1234() * b.e1245() + self.e1245() * b.e1234(),
+                -self.e1234() * b.e3145() + self.e3145() * b.e1234(),
+                -self.e1234() * b.e2345() + self.e2345() * b.e1234(),
+                -self.e1235() * b.e1245() + self.e1245() * b.e1235(),
+                -self.e1235() * b.e3145() + self.e3145() * b.e1235(),
+                -self.e1235() * b.e2345() + self.e2345() * b.e1235(),
+                self.e1245() * b.e3145() - self.e3145() * b.e1245(),
+                -self.e1245() * b.e2345() + self.e2345() * b.e1245(),
+                self.e3145() * b.e2345() - self.e2345() * b.e3145(),
+            ),
+            Quadvector::new(
+                self.e1234() * b.e12345(),
+                self.e1235() * b.e12345(),
+                self.e1245() * b.e12345(),
+                self.e3145() * b.e12345(),
+                self.e2345() * b.e12345(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Quadvector<F>, Multivector<F>);
+
+// Multivector-Quadvector
+impl<F: Float> BitAnd<Quadvector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Quadvector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1() * b.e2345() + self.e2() * b.e3145() + self.e3() * b.e1245()
+                - self.e4() * b.e1235()
+                + self.e5() * b.e1234(),
+            Vector::new(
+                self.e12() * b.e3145() - self.e31() * b.e1245() - self.e14() * b.e1235()
+                    + self.e15() * b.e1234(),
+                -self.e12() * b.e2345() + self.e23() * b.e1245() - self.e24() * b.e1235()
+                    + self.e25() * b.e1234(),
+                self.e31() * b.e2345() - self.e23() * b.e3145() - self.e34() * b.e1235()
+                    + self.e35() * b.e1234(),
+                -self.e14() * b.e2345() - self.e24() * b.e3145() - self.e34() * b.e1245()
+                    + self.e45() * b.e1234(),
+                -self.e15() * b.e2345() - self.e25() * b.e3145() - self.e35() * b.e1245()
+                    + self.e45() * b.e1235(),
+            ),
+            Bivector::new(
+                self.e123() * b.e1245() - self.e124() * b.e1235() + self.e125() * b.e1234(),
+                self.e123() * b.e3145() - self.e314() * b.e1235() + self.e315() * b.e1234(),
+                self.e123() * b.e2345() - self.e234() * b.e1235() + self.e235() * b.e1234(),
+                -self.e124() * b.e3145() + self.e314() * b.e1245() + self.e145() * b.e1234(),
+                self.e124() * b.e2345() - self.e234() * b.e1245() + self.e245() * b.e1234(),
+                -self.e314() * b.e2345() + self.e234() * b.e3145() + self.e345() * b.e1234(),
+                -self.e125() * b.e3145() + self.e315() * b.e1245() + self.e145() * b.e1235(),
+                self.e125() * b.e2345() - self.e235() * b.e1245() + self.e245() * b.e1235(),
+                -self.e315() * b.e2345() + self.e235() * b.e3145() + self.e345() * b.e1235(),
+                self.e145() * b.e2345() + self.e245() * b.e3145() + self.e345() * b.e1245(),
+            ),
+            Trivector::new(
+                -self.e1234() * b.e1235() + self.e1235() * b.e1234(),
+                -self.e1234() * b.e1245() + self.e1245() * b.e1234(),
+                -self.e1234() * b.e3145() + self.e3145() * b.e1234(),
+                -self.e1234() * b.e2345() + self.e2345() * b.e1234(),
+                -self.e1235() * b.e1245() + self.e1245() * b.e1235(),
+                -self.e1235() * b.e3145() + self.e3145() * b.e1235(),
+                -self.e1235() * b.e2345() + self.e2345() * b.e1235(),
+                self.e1245() * b.e3145() - self.e3145() * b.e1245(),
+                -self.e1245() * b.e2345() + self.e2345() * b.e1245(),
+                self.e3145() * b.e2345() - self.e2345() * b.e3145(),
+            ),
+            Quadvector::new(
+                self.e12345() * b.e1234(),
+                self.e12345() * b.e1235(),
+                self.e12345() * b.e1245(),
+                self.e12345() * b.e3145(),
+                self.e12345() * b.e2345(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Quadvector<F>);
+
+// Pseudoscalar-Pseudoscalar
+impl<F: Float> BitAnd for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.e12345() * b.e12345())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Versor
+impl<F: Float> BitAnd<Versor<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e12345() * b.scalar(),
+            Vector::zero(),
+            Bivector::new(
+                self.e12345() * b.e12(),
+                self.e12345() * b.e31(),
+                self.e12345() * b.e23(),
+                self.e12345() * b.e14(),
+                self.e12345() * b.e24(),
+                self.e12345() * b.e34(),
+                self.e12345() * b.e15(),
+                self.e12345() * b.e25(),
+                self.e12345() * b.e35(),
+                self.e12345() * b.e45(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e12345() * b.e1234(),
+                self.e12345() * b.e1235(),
+                self.e12345() * b.e1245(),
+                self.e12345() * b.e3145(),
+                self.e12345() * b.e2345(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Versor<F>);
+
+// Versor-Pseudoscalar
+impl<F: Float> BitAnd<Pseudoscalar<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.e12345(),
+            Vector::zero(),
+            Bivector::new(
+                self.e12() * b.e12345(),
+                self.e31() * b.e12345(),
+                self.e23() * b.e12345(),
+                self.e14() * b.e12345(),
+                self.e24() * b.e12345(),
+                self.e34() * b.e12345(),
+                self.e15() * b.e12345(),
+                self.e25() * b.e12345(),
+                self.e35() * b.e12345(),
+                self.e45() * b.e12345(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e1234() * b.e12345(),
+                self.e1235() * b.e12345(),
+                self.e1245() * b.e12345(),
+                self.e3145() * b.e12345(),
+                self.e2345() * b.e12345(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Versor<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Multivector
+impl<F: Float> BitAnd<Multivector<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e12345() * b.scalar(),
+            Vector::new(
+                self.e12345() * b.e1(),
+                self.e12345() * b.e2(),
+                self.e12345() * b.e3(),
+                self.e12345() * b.e4(),
+                self.e12345() * b.e5(),
+            ),
+            Bivector::new(
+                self.e12345() * b.e12(),
+                self.e12345() * b.e31(),
+                self.e12345() * b.e23(),
+                self.e12345() * b.e14(),
+                self.e12345() * b.e24(),
+                self.e12345() * b.e34(),
+                self.e12345() * b.e15(),
+                self.e12345() * b.e25(),
+                self.e12345() * b.e35(),
+                self.e12345() * b.e45(),
+            ),
+            Trivector::new(
+                self.e12345() * b.e123(),
+                self.e12345() * b.e124(),
+                self.e12345() * b.e314(),
+                self.e12345() * b.e234(),
+                self.e12345() * b.e125(),
+                self.e12345() * b.e315(),
+                self.e12345() * b.e235(),
+                self.e12345() * b.e145(),
+                self.e12345() * b.e245(),
+                self.e12345() * b.e345(),
+            ),
+            Quadvector::new(
+                self.e12345() * b.e1234(),
+                self.e12345() * b.e1235(),
+                self.e12345() * b.e1245(),
+                self.e12345() * b.e3145(),
+                self.e12345() * b.e2345(),
+            ),
+            self.e12345() * b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Multivector<F>);
+
+// Multivector-Pseudoscalar
+impl<F: Float> BitAnd<Pseudoscalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.e12345(),
+            Vector::new(
+                self.e1() * b.e12345(),
+                self.e2() * b.e12345(),
+                self.e3() * b.e12345(),
+                self.e4() * b.e12345(),
+                self.e5() * b.e12345(),
+            ),
+            Bivector::new(
+                self.e12() * b.e12345(),
+                self.e31() * b.e12345(),
+                self.e23() * b.e12345(),
+                self.e14() * b.e12345(),
+                self.e24() * b.e12345(),
+                self.e34() * b.e12345(),
+                self.e15() * b.e12345(),
+                self.e25() * b.e12345(),
+                self.e35() * b.e12345(),
+                self.e45() * b.e12345(),
+            ),
+            Trivector::new(
+                self.e123() * b.e12345(),
+                self.e124() * b.e12345(),
+                self.e314() * b.e12345(),
+                self.e234() * b.e12345(),
+                self.e125() * b.e12345(),
+                self.e315() * b.e12345(),
+                self.e235() * b.e12345(),
+                self.e145() * b.e12345(),
+                self.e245() * b.e12345(),
+                self.e345() * b.e12345(),
+            ),
+            Quadvector::new(
+                self.e1234() * b.e12345(),
+                self.e1235() * b.e12345(),
+                self.e1245() * b.e12345(),
+                self.e3145() * b.e12345(),
+                self.e2345() * b.e12345(),
+            ),
+            self.e12345() * b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Pseudoscalar<F>);
+
+// Versor-Versor
+impl<F: Float> BitAnd for Versor<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                self.e12() * b.e3145() - self.e31() * b.e1245() - self.e14() * b.e1235()
+                    + self.e15() * b.e1234()
+                    - self.e1234() * b.e15()
+                    + self.e1235() * b.e14()
+                    + self.e1245() * b.e31()
+                    - self.e3145() * b.e12(),
+                -self.e12() * b.e2345() + self.e23() * b.e1245() - self.e24() * b.e1235()
+                    + self.e25() * b.e1234()
+                    - self.e1234() * b.e25()
+                    + self.e1235() * b.e24()
+                    - self.e1245() * b.e23()
+                    + self.e2345() * b.e12(),
+                self.e31() * b.e2345() - self.e23() * b.e3145() - self.e34() * b.e1235()
+                    + self.e35() * b.e1234()
+                    - self.e1234() * b.e35()
+                    + self.e1235() * b.e34()
+                    + self.e3145() * b.e23()
+                    - self.e2345() * b.e31(),
+                -self.e14() * b.e2345() - self.e24() * b.e3145() - self.e34() * b.e1245()
+                    + self.e45() * b.e1234()
+                    - self.e1234() * b.e45()
+                    + self.e1245() * b.e34()
+                    + self.e3145() * b.e24()
+                    + self.e2345() * b.e14(),
+                -self.e15() * b.e2345() - self.e25() * b.e3145() - self.e35() * b.e1245()
+                    + self.e45() * b.e1235()
+                    - self.e1235() * b.e45()
+                    + self.e1245() * b.e35()
+                    + self.e3145() * b.e25()
+                    + self.e2345() * b.e15(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                -self.e1234() * b.e1235() + self.e1235() * b.e1234(),
+                -self.e1234() * b.e1245() + self.e1245() * b.e1234(),
+                -self.e1234() * b.e3145() + self.e3145() * b.e1234(),
+                -self.e1234() * b.e2345() + self.e2345() * b.e1234(),
+                -self.e1235() * b.e1245() + self.e1245() * b.e1235(),
+                -self.e1235() * b.e3145() + self.e3145() * b.e1235(),
+                -self.e1235() * b.e2345() + self.e2345() * b.e1235(),
+                self.e1245() * b.e3145() - self.e3145() * b.e1245(),
+                -self.e1245() * b.e2345() + self.e2345() * b.e1245(),
+                self.e3145() * b.e2345() - self.e2345() * b.e3145(),
+            ),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Versor<F>, Versor<F>);
+
+// Versor-Multivector
+impl<F: Float> BitAnd<Multivector<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.e12345()
+                + self.e12() * b.e345()
+                + self.e31() * b.e245()
+                + self.e23() * b.e145()
+                + self.e14() * b.e235()
+                + self.e24() * b.e315()
+                + self.e34() * b.e125()
+                - self.e15() * b.e234()
+                - self.e25() * b.e314()
+                - self.e35() * b.e124()
+                + self.e45() * b.e123()
+                + self.e1234() * b.e5()
+                - self.e1235() * b.e4()
+                + self.e1245() * b.e3()
+                + self.e3145() * b.e2()
+                + self.e2345() * b.e1(),
+            Vector::new(
+                self.e12() * b.e3145() - self.e31() * b.e1245() - self.e14() * b.e1235()
+                    + self.e15() * b.e1234()
+                    - self.e1234() * b.e15()
+                    + self.e1235() * b.e14()
+                    + self.e1245() * b.e31()
+                    - self.e3145() * b.e12(),
+                -self.e12() * b.e2345() + self.e23() * b.e1245() - self.e24() * b.e1235()
+                    + self.e25() * b.e1234()
+                    - self.e1234() * b.e25()
+                    + self.e1235() * b.e24()
+                    - self.e1245() * b.e23()
+                    + self.e2345() * b.e12(),
+                self.e31() * b.e2345() - self.e23() * b.e3145() - self.e34() * b.e1235()
+                    + self.e35() * b.e1234()
+                    - self.e1234() * b.e35()
+                    + self.e1235() * b.e34()
+                    + self.e3145() * b.e23()
+                    - self.e2345() * b.e31(),
+                -self.e14() * b.e2345() - self.e24() * b.e3145() - self.e34() * b.e1245()
+                    + self.e45() * b.e1234()
+                    - self.e1234() * b.e45()
+                    + self.e1245() * b.e34()
+                    + self.e3145() * b.e24()
+                    + self.e2345() * b.e14(),
+                -self.e15() * b.e2345() - self.e25() * b.e3145() - self.e35() * b.e1245()
+                    + self.e45() * b.e1235()
+                    - self.e1235() * b.e45()
+                    + self.e1245() * b.e35()
+                    + self.e3145() * b.e25()
+                    + self.e2345() * b.e15(),
+            ),
+            Bivector::new(
+                self.e12() * b.e12345() + self.e1234() * b.e125() - self.e1235() * b.e124()
+                    + self.e1245() * b.e123(),
+                self.e31() * b.e12345() + self.e1234() * b.e315() - self.e1235() * b.e314()
+                    + self.e3145() * b.e123(),
+                self.e23() * b.e12345() + self.e1234() * b.e235() - self.e1235() * b.e234()
+                    + self.e2345() * b.e123(),
+                self.e14() * b.e12345() + self.e1234() * b.e145() + self.e1245() * b.e314()
+                    - self.e3145() * b.e124(),
+                self.e24() * b.e12345() + self.e1234() * b.e245() - self.e1245() * b.e234()
+                    + self.e2345() * b.e124(),
+                self.e34() * b.e12345() + self.e1234() * b.e345() + self.e3145() * b.e234()
+                    - self.e2345() * b.e314(),
+                self.e15() * b.e12345() + self.e1235() * b.e145() + self.e1245() * b.e315()
+                    - self.e3145() * b.e125(),
+                self.e25() * b.e12345() + self.e1235() * b.e245() - self.e1245() * b.e235()
+                    + self.e2345() * b.e125(),
+                self.e35() * b.e12345() + self.e1235() * b.e345() + self.e3145() * b.e235()
+                    - self.e2345() * b.e315(),
+                self.e45() * b.e12345()
+                    + self.e1245() * b.e345()
+                    + self.e3145() * b.e245()
+                    + self.e2345() * b.e145(),
+            ),
+            Trivector::new(
+                -self.e1234() * b.e1235() + self.e1235() * b.e1234(),
+                -self.e1234() * b.e1245() + self.e1245() * b.e1234(),
+                -self.e1234() * b.e3145() + self.e3145() * b.e1234(),
+                -self.e1234() * b.e2345() + self.e2345() * b.e1234(),
+                -self.e1235() * b.e1245() + self.e1245() * b.e1235(),
+                -self.e1235() * b.e3145() + self.e3145() * b.e1235(),
+                -self.e1235() * b.e2345() + self.e2345() * b.e1235(),
+                self.e1245() * b.e3145() - self.e3145() * b.e1245(),
+                -self.e1245() * b.e2345() + self.e2345() * b.e1245(),
+                self.e3145() * b.e2345() - self.e2345() * b.e3145(),
+            ),
+            Quadvector::new(
+                self.e1234() * b.e12345(),
+                self.e1235() * b.e12345(),
+                self.e1245() * b.e12345(),
+                self.e3145() * b.e12345(),
+                self.e2345() * b.e12345(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Versor<F>, Multivector<F>);
+
+// Multivector-Versor
+impl<F: Float> BitAnd<Versor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1() * b.e2345() + self.e2() * b.e3145() + self.e3() * b.e1245()
+                - self.e4() * b.e1235()
+                + self.e5() * b.e1234()
+                + self.e123() * b.e45()
+                - self.e124() * b.e35()
+                - self.e314() * b.e25()
+                - self.e234() * b.e15()
+                + self.e125() * b.e34()
+                + self.e315() * b.e24()
+                + self.e235() * b.e14()
+                + self.e145() * b.e23()
+                + self.e245() * b.e31()
+                + self.e345() * b.e12()
+                + self.e12345() * b.scalar(),
+            Vector::new(
+                self.e12() * b.e3145() - self.e31() * b.e1245() - self.e14() * b.e1235()
+                    + self.e15() * b.e1234()
+                    - self.e1234() * b.e15()
+                    + self.e1235() * b.e14()
+                    + self.e1245() * b.e31()
+                    - self.e3145() * b.e12(),
+                -self.e12() * b.e2345() + self.e23() * b.e1245() - self.e24() * b.e1235()
+                    + self.e25() * b.e1234()
+                    - self.e1234() * b.e25()
+                    + self.e1235() * b.e24()
+                    - self.e1245() * b.e23()
+                    + self.e2345() * b.e12(),
+                self.e31() * b.e2345() - self.e23() * b.e3145() - self.e34() * b.e1235()
+                    + self.e35() * b.e1234()
+                    - self.e1234() * b.e35()
+                    + self.e1235() * b.e34()
+                    + self.e3145() * b.e23()
+                    - self.e2345() * b.e31(),
+                -self.e14() * b.e2345() - self.e24() * b.e3145() - self.e34() * b.e1245()
+                    + self.e45() * b.e1234()
+                    - self.e1234() * b.e45()
+                    + self.e1245() * b.e34()
+                    + self.e3145() * b.e24()
+                    + self.e2345() * b.e14(),
+                -self.e15() * b.e2345() - self.e25() * b.e3145() - self.e35() * b.e1245()
+                    + self.e45() * b.e1235()
+                    - self.e1235() * b.e45()
+                    + self.e1245() * b.e35()
+                    + self.e3145() * b.e25()
+                    + self.e2345() * b.e15(),
+            ),
+            Bivector::new(
+                self.e123() * b.e1245() - self.e124() * b.e1235()
+                    + self.e125() * b.e1234()
+                    + self.e12345() * b.e12(),
+                self.e123() * b.e3145() - self.e314() * b.e1235()
+                    + self.e315() * b.e1234()
+                    + self.e12345() * b.e31(),
+                self.e123() * b.e2345() - self.e234() * b.e1235()
+                    + self.e235() * b.e1234()
+                    + self.e12345() * b.e23(),
+                -self.e124() * b.e3145()
+                    + self.e314() * b.e1245()
+                    + self.e145() * b.e1234()
+                    + self.e12345() * b.e14(),
+                self.e124() * b.e2345() - self.e234() * b.e1245()
+                    + self.e245() * b.e1234()
+                    + self.e12345() * b.e24(),
+                -self.e314() * b.e2345()
+                    + self.e234() * b.e3145()
+                    + self.e345() * b.e1234()
+                    + self.e12345() * b.e34(),
+                -self.e125() * b.e3145()
+                    + self.e315() * b.e1245()
+                    + self.e145() * b.e1235()
+                    + self.e12345() * b.e15(),
+                self.e125() * b.e2345() - self.e235() * b.e1245()
+                    + self.e245() * b.e1235()
+                    + self.e12345() * b.e25(),
+                -self.e315() * b.e2345()
+                    + self.e235() * b.e3145()
+                    + self.e345() * b.e1235()
+                    + self.e12345() * b.e35(),
+                self.e145() * b.e2345()
+                    + self.e245() * b.e3145()
+                    + self.e345() * b.e1245()
+                    + self.e12345() * b.e45(),
+            ),
+            Trivector::new(
+                -self.e1234() * b.e1235() + self.e1235() * b.e1234(),
+                -self.e1234() * b.e1245() + self.e1245() * b.e1234(),
+                -self.e1234() * b.e3145() + self.e3145() * b.e1234(),
+                -self.e1234() * b.e2345() + self.e2345() * b.e1234(),
+                -self.e1235() * b.e1245() + self.e1245() * b.e1235(),
+                -self.e1235() * b.e3145() + self.e3145() * b.e1235(),
+                -self.e1235() * b.e2345() + self.e2345() * b.e1235(),
+                self.e1245() * b.e3145() - self.e3145() * b.e1245(),
+                -self.e1245() * b.e2345() + self.e2345() * b.e1245(),
+                self.e3145() * b.e2345() - self.e2345() * b.e3145(),
+            ),
+            Quadvector::new(
+                self.e12345() * b.e1234(),
+                self.e12345() * b.e1235(),
+                self.e12345() * b.e1245(),
+                self.e12345() * b.e3145(),
+                self.e12345() * b.e2345(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Versor<F>);
+
+// Multivector-Multivector
+impl<F: Float> BitAnd for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.e12345()
+                + self.e1() * b.e2345()
+                + self.e2() * b.e3145()
+                + self.e3() * b.e1245()
+                - self.e4() * b.e1235()
+                + self.e5() * b.e1234()
+                + self.e12() * b.e345()
+                + self.e31() * b.e245()
+                + self.e23() * b.e145()
+                + self.e14() * b.e235()
+                + self.e24() * b.e315()
+                + self.e34() * b.e125()
+                - self.e15() * b.e234()
+                - self.e25() * b.e314()
+                - self.e35() * b.e124()
+                + self.e45() * b.e123()
+                + self.e123() * b.e45()
+                - self.e124() * b.e35()
+                - self.e314() * b.e25()
+                - self.e234() * b.e15()
+                + self.e125() * b.e34()
+                + self.e315() * b.e24()
+                + self.e235() * b.e14()
+                + self.e145() * b.e23()
+                + self.e245() * b.e31()
+                + self.e345() * b.e12()
+                + self.e1234() * b.e5()
+                - self.e1235() * b.e4()
+                + self.e1245() * b.e3()
+                + self.e3145() * b.e2()
+                + self.e2345() * b.e1()
+                + self.e12345() * b.scalar(),
+            Vector::new(
+                self.e1() * b.e12345() + self.e12() * b.e3145()
+                    - self.e31() * b.e1245()
+                    - self.e14() * b.e1235()
+                    + self.e15() * b.e1234()
+                    + self.e123() * b.e145()
+                    + self.e124() * b.e315()
+                    - self.e314() * b.e125()
+                    - self.e125() * b.e314()
+                    + self.e315() * b.e124()
+                    + self.e145() * b.e123()
+                    - self.e1234() * b.e15()
+                    + self.e1235() * b.e14()
+                    + self.e1245() * b.e31()
+                    - self.e3145() * b.e12()
+                    + self.e12345() * b.e1(),
+                self.e2() * b.e12345() - self.e12() * b.e2345() + self.e23() * b.e1245()
+                    - self.e24() * b.e1235()
+                    + self.e25() * b.e1234()
+                    + self.e123() * b.e245()
+                    - self.e124() * b.e235()
+                    + self.e234() * b.e125()
+                    + self.e125() * b.e234()
+                    - self.e235() * b.e124()
+                    + self.e245() * b.e123()
+                    - self.e1234() * b.e25()
+                    + self.e1235() * b.e24()
+                    - self.e1245() * b.e23()
+                    + self.e2345() * b.e12()
+                    + self.e12345() * b.e2(),
+                self.e3() * b.e12345() + self.e31() * b.e2345()
+                    - self.e23() * b.e3145()
+                    - self.e34() * b.e1235()
+                    + self.e35() * b.e1234()
+                    + self.e123() * b.e345()
+                    + self.e314() * b.e235()
+                    - self.e234() * b.e315()
+                    - self.e315() * b.e234()
+                    + self.e235() * b.e314()
+                    + self.e345() * b.e123()
+                    - self.e1234() * b.e35()
+                    + self.e1235() * b.e34()
+                    + self.e3145() * b.e23()
+                    - self.e2345() * b.e31()
+                    + self.e12345() * b.e3(),
+                self.e4() * b.e12345()
+                    - self.e14() * b.e2345()
+                    - self.e24() * b.e3145()
+                    - self.e34() * b.e1245()
+                    + self.e45() * b.e1234()
+                    + self.e124() * b.e345()
+                    + self.e314() * b.e245()
+                    + self.e234() * b.e145()
+                    + self.e145() * b.e234()
+                    + self.e245() * b.e314()
+                    + self.e345() * b.e124()
+                    - self.e1234() * b.e45()
+                    + self.e1245() * b.e34()
+                    + self.e3145() * b.e24()
+                    + self.e2345() * b.e14()
+                    + self.e12345() * b.e4(),
+                self.e5() * b.e12345()
+                    - self.e15() * b.e2345()
+                    - self.e25() * b.e3145()
+                    - self.e35() * b.e1245()
+                    + self.e45() * b.e1235()
+                    + self.e125() * b.e345()
+                    + self.e315() * b.e245()
+                    + self.e235() * b.e145()
+                    + self.e145() * b.e235()
+                    + self.e245() * b.e315()
+                    + self.e345() * b.e125()
+                    - self.e1235() * b.e45()
+                    + self.e1245() * b.e35()
+                    + self.e3145() * b.e25()
+                    + self.e2345() * b.e15()
+                    + self.e12345() * b.e5(),
+            ),
+            Bivector::new(
+                self.e12() * b.e12345() + self.e123() * b.e1245() - self.e124() * b.e1235()
+                    + self.e125() * b.e1234()
+                    + self.e1234() * b.e125()
+                    - self.e1235() * b.e124()
+                    + self.e1245() * b.e123()
+                    + self.e12345() * b.e12(),
+                self.e31() * b.e12345() + self.e123() * b.e3145() - self.e314() * b.e1235()
+                    + self.e315() * b.e1234()
+                    + self.e1234() * b.e315()
+                    - self.e1235() * b.e314()
+                    + self.e3145() * b.e123()
+                    + self.e12345() * b.e31(),
+                self.e23() * b.e12345() + self.e123() * b.e2345() - self.e234() * b.e1235()
+                    + self.e235() * b.e1234()
+                    + self.e1234() * b.e235()
+                    - self.e1235() * b.e234()
+                    + self.e2345() * b.e123()
+                    + self.e12345() * b.e23(),
+                self.e14() * b.e12345() - self.e124() * b.e3145()
+                    + self.e314() * b.e1245()
+                    + self.e145() * b.e1234()
+                    + self.e1234() * b.e145()
+                    + self.e1245() * b.e314()
+                    - self.e3145() * b.e124()
+                    + self.e12345() * b.e14(),
+                self.e24() * b.e12345() + self.e124() * b.e2345() - self.e234() * b.e1245()
+                    + self.e245() * b.e1234()
+                    + self.e1234() * b.e245()
+                    - self.e1245() * b.e234()
+                    + self.e2345() * b.e124()
+                    + self.e12345() * b.e24(),
+                self.e34() * b.e12345() - self.e314() * b.e2345()
+                    + self.e234() * b.e3145()
+                    + self.e345() * b.e1234()
+                    + self.e1234() * b.e345()
+                    + self.e3145() * b.e234()
+                    - self.e2345() * b.e314()
+                    + self.e12345() * b.e34(),
+                self.e15() * b.e12345() - self.e125() * b.e3145()
+                    + self.e315() * b.e1245()
+                    + self.e145() * b.e1235()
+                    + self.e1235() * b.e145()
+                    + self.e1245() * b.e315()
+                    - self.e3145() * b.e125()
+                    + self.e12345() * b.e15(),
+                self.e25() * b.e12345() + self.e125() * b.e2345() - self.e235() * b.e1245()
+                    + self.e245() * b.e1235()
+                    + self.e1235() * b.e245()
+                    - self.e1245() * b.e235()
+                    + self.e2345() * b.e125()
+                    + self.e12345() * b.e25(),
+                self.e35() * b.e12345() - self.e315() * b.e2345()
+                    + self.e235() * b.e3145()
+                    + self.e345() * b.e1235()
+                    + self.e1235() * b.e345()
+                    + self.e3145() * b.e235()
+                    - self.e2345() * b.e315()
+                    + self.e12345() * b.e35(),
+                self.e45() * b.e12345()
+                    + self.e145() * b.e2345()
+                    + self.e245() * b.e3145()
+                    + self.e345() * b.e1245()
+                    + self.e1245() * b.e345()
+                    + self.e3145() * b.e245()
+                    + self.e2345() * b.e145()
+                    + self.e12345() * b.e45(),
+            ),
+            Trivector::new(
+                self.e123() * b.e12345() - self.e1234() * b.e1235()
+                    + self.e1235() * b.e1234()
+                    + self.e12345() * b.e123(),
+                self.e124() * b.e12345() - self.e1234() * b.e1245()
+                    + self.e1245() * b.e1234()
+                    + self.e12345() * b.e124(),
+                self.e314() * b.e12345() - self.e1234() * b.e3145()
+                    + self.e3145() * b.e1234()
+                    + self.e12345() * b.e314(),
+                self.e234() * b.e12345() - self.e1234() * b.e2345()
+                    + self.e2345() * b.e1234()
+                    + self.e12345() * b.e234(),
+                self.e125() * b.e12345() - self.e1235() * b.e1245()
+                    + self.e1245() * b.e1235()
+                    + self.e12345() * b.e125(),
+                self.e315() * b.e12345() - self.e1235() * b.e3145()
+                    + self.e3145() * b.e1235()
+                    + self.e12345() * b.e315(),
+                self.e235() * b.e12345() - self.e1235() * b.e2345()
+                    + self.e2345() * b.e1235()
+                    + self.e12345() * b.e235(),
+                self.e145() * b.e12345() + self.e1245() * b.e3145() - self.e3145() * b.e1245()
+                    + self.e12345() * b.e145(),
+                self.e245() * b.e12345() - self.e1245() * b.e2345()
+                    + self.e2345() * b.e1245()
+                    + self.e12345() * b.e245(),
+                self.e345() * b.e12345() + self.e3145() * b.e2345() - self.e2345() * b.e3145()
+                    + self.e12345() * b.e345(),
+            ),
+            Quadvector::new(
+                self.e1234() * b.e12345() + self.e12345() * b.e1234(),
+                self.e1235() * b.e12345() + self.e12345() * b.e1235(),
+                self.e1245() * b.e12345() + self.e12345() * b.e1245(),
+                self.e3145() * b.e12345() + self.e12345() * b.e3145(),
+                self.e2345() * b.e12345() + self.e12345() * b.e2345(),
+            ),
+            self.e12345() * b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Multivector<F>);
+
+// Test
+#[cfg(test)]
+mod regressive_product {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn quadvector_quadvector() {
+        // 3e1234+4e1235+3e1245-1e3145-1e2345
+        let quadvector1 = Quadvector::new(3.0, 4.0, 3.0, -1.0, -1.0);
+        // 3e1234-4e1235+3e1245+4e3145+4e2345
+        let quadvector2 = Quadvector::new(3.0, -4.0, 3.0, 4.0, 4.0);
+        let res = quadvector1 & quadvector2;
+        // 24e123-15e314-15e234-24e125-12e315-12e235+15e145-15e245
+        assert_relative_eq!(res.e123(), 24.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e124(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e314(), -15.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e234(), -15.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e125(), -24.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e315(), -12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e235(), -12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e145(), 15.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e245(), -15.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e345(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn quadvector_trivector() {
+        // -4e1234+5e1235+2e1245-1e3145-1e2345
+        let quadvector1 = Quadvector::new(-4.0, 5.0, 2.0, -1.0, -1.0);
+        // 4e123+5e124-1e314+3e234+2e125+4e315-2e235+3e145+4e245-4e345
+        let trivector2 = Trivector::new(4.0, 5.0, -1.0, 3.0, 2.0, 4.0, -2.0, 3.0, 4.0, -4.0);
+        let res = quadvector1 & trivector2;
+        // -25e12-15e31-11e23-9e14-27e24+12e34+25e15+22e25-14e35-15e45
+        assert_relative_eq!(res.e12(), -25.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), -15.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), -11.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e14(), -9.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e24(), -27.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e34(), 12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e15(), 25.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e25(), 22.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e35(), -14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e45(), -15.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn trivector_trivector() {
+        // 5e123-1e124+1e314+4e234+4e125+4e315+1e235-4e145-2e245-3e345
+        let trivector1 = Trivector::new(5.0, -1.0, 1.0, 4.0, 4.0, 4.0, 1.0, -4.0, -2.0, -3.0);
+        // -4e123+3e124+1e314-4e234-2e125-1e315-1e235-3e145+2e245-2e345
+        let trivector2 = Trivector::new(-4.0, 3.0, 1.0, -4.0, -2.0, -1.0, -1.0, -3.0, 2.0, -2.0);
+        let res = trivector1 & trivector2;
+        // 12e1-10e2+22e3-3e4+9e5
+        assert_relative_eq!(res.e1(), 12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 22.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e4(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e5(), 9.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_quadvector() {
+        // -1e12-1e31+3e23-1e14+1e24+2e34-1e15+1e25+1e35+2e45
+        let bivector1 = Bivector::new(-1.0, -1.0, 3.0, -1.0, 1.0, 2.0, -1.0, 1.0, 1.0, 2.0);
+        // 4e1234-1e1235+2e1245-4e3145-1e2345
+        let quadvector2 = Quadvector::new(4.0, -1.0, 2.0, -4.0, -1.0);
+        let res = bivector1 & quadvector2;
+        // 1e1+10e2+19e3+7e4-1e5
+        assert_relative_eq!(res.e1(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 19.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e4(), 7.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e5(), -1.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn versor_quadvector() {
+        // 3+3e12+5e31+2e23+2e14+3e24-2e34-1e15+5e25+2e35+2e45-3e1234-1e1235+2e1245-4e3145-3e2345
+        let versor1 = Versor::new(
+            3.0,
+            Bivector::new(3.0, 5.0, 2.0, 2.0, 3.0, -2.0, -1.0, 5.0, 2.0, 2.0),
+            Quadvector::new(-3.0, -1.0, 2.0, -4.0, -3.0),
+        );
+        // 5e1234+3e1235+2e1245-3e3145+1e2345
+        let quadvector2 = Quadvector::new(5.0, 3.0, 2.0, -3.0, 1.0);
+        let res = versor1 & quadvector2;
+        // -30e1+17e2+27e3+21e4+18e5+4e123+16e124-29e314-12e234+8e125-15e315-8e235+2e145-8e245-13e345
+        assert_relative_eq!(res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -30.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 17.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 27.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e4(), 21.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e5(), 18.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e14(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e24(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e34(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e15(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e25(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e35(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e45(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e124(), 16.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e314(), -29.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e234(), -12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e125(), 8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e315(), -15.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e235(), -8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e145(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e245(), -8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e345(), -13.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1234(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1235(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1245(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3145(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2345(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12345(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_multivector() {
+        // -2+1e1+4e2+1e3-2e4+5e5-2e12+5e31+4e23-2e14+4e24-4e34-4e15-2e25+2e35+4e45+4e123-2e124-4e314+4e234-3e125-1e315-2e235-4e145-4e245-3e345+4e1234+3e1235-4e1245+2e3145-1e2345+5e12345
+        let mvec1 = Multivector::new(
+            -2.0,
+            Vector::new(1.0, 4.0, 1.0, -2.0, 5.0),
+            Bivector::new(-2.0, 5.0, 4.0, -2.0, 4.0, -4.0, -4.0, -2.0, 2.0, 4.0),
+            Trivector::new(4.0, -2.0, -4.0, 4.0, -3.0, -1.0, -2.0, -4.0, -4.0, -3.0),
+            Quadvector::new(4.0, 3.0, -4.0, 2.0, -1.0),
+            5.0,
+        );
+        // -3-1e1+3e2+4e3-4e4+5e5+1e12+3e31+4e23-1e14-1e24+4e34-2e15-3e25-1e35+4e45+4e123-1e124-3e314+5e234-1e125-4e315-4e235+3e145-4e245+2e345+5e1234+5e1235+3e1245+3e3145+4e2345+2e12345
+        let mvec2 = Multivector::new(
+            -3.0,
+            Vector::new(-1.0, 3.0, 4.0, -4.0, 5.0),
+            Bivector::new(1.0, 3.0, 4.0, -1.0, -1.0, 4.0, -2.0, -3.0, -1.0, 4.0),
+            Trivector::new(4.0, -1.0, -3.0, 5.0, -1.0, -4.0, -4.0, 3.0, -4.0, 2.0),
+            Quadvector::new(5.0, 5.0, 3.0, 3.0, 4.0),
+            2.0,
+        );
+        let res = mvec1 & mvec2;
+        // 44-51e1-24e2+126e3-10e4+86e5-9e12+53e31-21e23-9e14-32e24+40e34-5e15-72e25-24e35-28e45+23e123-41e124-25e314+12e234-40e125-21e315-41e235-11e145-15e245+15e345+33e1234+31e1235+7e1245+19e3145+18e2345+10e12345
+        assert_relative_eq!(res.scalar(), 44.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -51.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -24.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 126.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e4(), -10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e5(), 86.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), -9.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 53.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), -21.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e14(), -9.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e24(), -32.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e34(), 40.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e15(), -5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e25(), -72.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e35(), -24.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e45(), -28.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 23.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e124(), -41.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e314(), -25.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e234(), 12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e125(), -40.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e315(), -21.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e235(), -41.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e145(), -11.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e245(), -15.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e345(), 15.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1234(), 33.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1235(), 31.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1245(), 7.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3145(), 19.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2345(), 18.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12345(), 10.0, max_relative = 0.000001);
+    }
+}
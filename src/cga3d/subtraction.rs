@@ -0,0 +1,2092 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    bivector::Bivector, multivector::Multivector, pseudoscalar::Pseudoscalar,
+    quadvector::Quadvector, scalar::Scalar, trivector::Trivector, vector::Vector, versor::Versor,
+};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::Sub;
+
+// Subtraction
+
+// Scalar-Scalar
+impl<F: Float> Sub for Scalar<F> {
+    type Output = F;
+    fn sub(self, b: Scalar<F>) -> F {
+        self.0 - b.0
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Scalar<F>, Scalar<F>);
+
+// Scalar-Vector
+impl<F: Float> Sub<Vector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0,
+            Vector::new(-b.e1(), -b.e2(), -b.e3(), -b.e4(), -b.e5()),
+            Bivector::zero(),
+            Trivector::zero(),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Scalar<F>, Vector<F>);
+
+// Vector-Scalar
+impl<F: Float> Sub<Scalar<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.0,
+            Vector::new(self.e1(), self.e2(), self.e3(), self.e4(), self.e5()),
+            Bivector::zero(),
+            Trivector::zero(),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Vector<F>, Scalar<F>);
+
+// Scalar-Bivector
+impl<F: Float> Sub<Bivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0,
+            Vector::zero(),
+            Bivector::new(
+                -b.e12(),
+                -b.e31(),
+                -b.e23(),
+                -b.e14(),
+                -b.e24(),
+                -b.e34(),
+                -b.e15(),
+                -b.e25(),
+                -b.e35(),
+                -b.e45(),
+            ),
+            Trivector::zero(),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Scalar<F>, Bivector<F>);
+
+// Bivector-Scalar
+impl<F: Float> Sub<Scalar<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.0,
+            Vector::zero(),
+            Bivector::new(
+                self.e12(),
+                self.e31(),
+                self.e23(),
+                self.e14(),
+                self.e24(),
+                self.e34(),
+                self.e15(),
+                self.e25(),
+                self.e35(),
+                self.e45(),
+            ),
+            Trivector::zero(),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Bivector<F>, Scalar<F>);
+
+// Scalar-Trivector
+impl<F: Float> Sub<Trivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0,
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::new(
+                -b.e123(),
+                -b.e124(),
+                -b.e314(),
+                -b.e234(),
+                -b.e125(),
+                -b.e315(),
+                -b.e235(),
+                -b.e145(),
+                -b.e245(),
+                -b.e345(),
+            ),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Scalar<F>, Trivector<F>);
+
+// Trivector-Scalar
+impl<F: Float> Sub<Scalar<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.0,
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::new(
+                self.e123(),
+                self.e124(),
+                self.e314(),
+                self.e234(),
+                self.e125(),
+                self.e315(),
+                self.e235(),
+                self.e145(),
+                self.e245(),
+                self.e345(),
+            ),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Trivector<F>, Scalar<F>);
+
+// Scalar-Quadvector
+impl<F: Float> Sub<Quadvector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Quadvector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0,
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::zero(),
+            Quadvector::new(-b.e1234(), -b.e1235(), -b.e1245(), -b.e3145(), -b.e2345()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Scalar<F>, Quadvector<F>);
+
+// Quadvector-Scalar
+impl<F: Float> Sub<Scalar<F>> for Quadvector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.0,
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e1234(),
+                self.e1235(),
+                self.e1245(),
+                self.e3145(),
+                self.e2345(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Quadvector<F>, Scalar<F>);
+
+// Scalar-Pseudoscalar
+impl<F: Float> Sub<Pseudoscalar<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0,
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::zero(),
+            Quadvector::zero(),
+            -b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Scalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Scalar
+impl<F: Float> Sub<Scalar<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.0,
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::zero(),
+            Quadvector::zero(),
+            self.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Pseudoscalar<F>, Scalar<F>);
+
+// Scalar-Versor
+impl<F: Float> Sub<Versor<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 - b.scalar(),
+            Vector::zero(),
+            Bivector::new(
+                -b.e12(),
+                -b.e31(),
+                -b.e23(),
+                -b.e14(),
+                -b.e24(),
+                -b.e34(),
+                -b.e15(),
+                -b.e25(),
+                -b.e35(),
+                -b.e45(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(-b.e1234(), -b.e1235(), -b.e1245(), -b.e3145(), -b.e2345()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Scalar<F>, Versor<F>);
+
+// Versor-Scalar
+impl<F: Float> Sub<Scalar<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() - b.0,
+            Vector::zero(),
+            Bivector::new(
+                self.e12(),
+                self.e31(),
+                self.e23(),
+                self.e14(),
+                self.e24(),
+                self.e34(),
+                self.e15(),
+                self.e25(),
+                self.e35(),
+                self.e45(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e1234(),
+                self.e1235(),
+                self.e1245(),
+                self.e3145(),
+                self.e2345(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Versor<F>, Scalar<F>);
+
+// Scalar-Multivector
+impl<F: Float> Sub<Multivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 - b.scalar(),
+            Vector::new(-b.e1(), -b.e2(), -b.e3(), -b.e4(), -b.e5()),
+            Bivector::new(
+                -b.e12(),
+                -b.e31(),
+                -b.e23(),
+                -b.e14(),
+                -b.e24(),
+                -b.e34(),
+                -b.e15(),
+                -b.e25(),
+                -b.e35(),
+                -b.e45(),
+            ),
+            Trivector::new(
+                -b.e123(),
+                -b.e124(),
+                -b.e314(),
+                -b.e234(),
+                -b.e125(),
+                -b.e315(),
+                -b.e235(),
+                -b.e145(),
+                -b.e245(),
+                -b.e345(),
+            ),
+            Quadvector::new(-b.e1234(), -b.e1235(), -b.e1245(), -b.e3145(), -b.e2345()),
+            -b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Scalar<F>, Multivector<F>);
+
+// Multivector-Scalar
+impl<F: Float> Sub<Scalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() - b.0,
+            Vector::new(self.e1(), self.e2(), self.e3(), self.e4(), self.e5()),
+            Bivector::new(
+                self.e12(),
+                self.e31(),
+                self.e23(),
+                self.e14(),
+                self.e24(),
+                self.e34(),
+                self.e15(),
+                self.e25(),
+                self.e35(),
+                self.e45(),
+            ),
+            Trivector::new(
+                self.e123(),
+                self.e124(),
+                self.e314(),
+                self.e234(),
+                self.e125(),
+                self.e315(),
+                self.e235(),
+                self.e145(),
+                self.e245(),
+                self.e345(),
+            ),
+            Quadvector::new(
+                self.e1234(),
+                self.e1235(),
+                self.e1245(),
+                self.e3145(),
+                self.e2345(),
+            ),
+            self.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Multivector<F>, Scalar<F>);
+
+// Vector-Vector
+impl<F: Float> Sub for Vector<F> {
+    type Output = Vector<F>;
+    fn sub(self, b: Vector<F>) -> Vector<F> {
+        Vector::new(
+            self.e1() - b.e1(),
+            self.e2() - b.e2(),
+            self.e3() - b.e3(),
+            self.e4() - b.e4(),
+            self.e5() - b.e5(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Vector<F>, Vector<F>);
+
+// Vector-Bivector
+impl<F: Float> Sub<Bivector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(self.e1(), self.e2(), self.e3(), self.e4(), self.e5()),
+            Bivector::new(
+                -b.e12(),
+                -b.e31(),
+                -b.e23(),
+                -b.e14(),
+                -b.e24(),
+                -b.e34(),
+                -b.e15(),
+                -b.e25(),
+                -b.e35(),
+                -b.e45(),
+            ),
+            Trivector::zero(),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Vector<F>, Bivector<F>);
+
+// Bivector-Vector
+impl<F: Float> Sub<Vector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(-b.e1(), -b.e2(), -b.e3(), -b.e4(), -b.e5()),
+            Bivector::new(
+                self.e12(),
+                self.e31(),
+                self.e23(),
+                self.e14(),
+                self.e24(),
+                self.e34(),
+                self.e15(),
+                self.e25(),
+                self.e35(),
+                self.e45(),
+            ),
+            Trivector::zero(),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Bivector<F>, Vector<F>);
+
+// Vector-Trivector
+impl<F: Float> Sub<Trivector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(self.e1(), self.e2(), self.e3(), self.e4(), self.e5()),
+            Bivector::zero(),
+            Trivector::new(
+                -b.e123(),
+                -b.e124(),
+                -b.e314(),
+                -b.e234(),
+                -b.e125(),
+                -b.e315(),
+                -b.e235(),
+                -b.e145(),
+                -b.e245(),
+                -b.e345(),
+            ),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Vector<F>, Trivector<F>);
+
+// Trivector-Vector
+impl<F: Float> Sub<Vector<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(-b.e1(), -b.e2(), -b.e3(), -b.e4(), -b.e5()),
+            Bivector::zero(),
+            Trivector::new(
+                self.e123(),
+                self.e124(),
+                self.e314(),
+                self.e234(),
+                self.e125(),
+                self.e315(),
+                self.e235(),
+                self.e145(),
+                self.e245(),
+                self.e345(),
+            ),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Trivector<F>, Vector<F>);
+
+// Vector-Quadvector
+impl<F: Float> Sub<Quadvector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Quadvector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(self.e1(), self.e2(), self.e3(), self.e4(), self.e5()),
+            Bivector::zero(),
+            Trivector::zero(),
+            Quadvector::new(-b.e1234(), -b.e1235(), -b.e1245(), -b.e3145(), -b.e2345()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Vector<F>, Quadvector<F>);
+
+// Quadvector-Vector
+impl<F: Float> Sub<Vector<F>> for Quadvector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(-b.e1(), -b.e2(), -b.e3(), -b.e4(), -b.e5()),
+            Bivector::zero(),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e1234(),
+                self.e1235(),
+                self.e1245(),
+                self.e3145(),
+                self.e2345(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Quadvector<F>, Vector<F>);
+
+// Vector-Pseudoscalar
+impl<F: Float> Sub<Pseudoscalar<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(self.e1(), self.e2(), self.e3(), self.e4(), self.e5()),
+            Bivector::zero(),
+            Trivector::zero(),
+            Quadvector::zero(),
+            -b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Vector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Vector
+impl<F: Float> Sub<Vector<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(-b.e1(), -b.e2(), -b.e3(), -b.e4(), -b.e5()),
+            Bivector::zero(),
+            Trivector::zero(),
+            Quadvector::zero(),
+            self.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Pseudoscalar<F>, Vector<F>);
+
+// Vector-Versor
+impl<F: Float> Sub<Versor<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.scalar(),
+            Vector::new(self.e1(), self.e2(), self.e3(), self.e4(), self.e5()),
+            Bivector::new(
+                -b.e12(),
+                -b.e31(),
+                -b.e23(),
+                -b.e14(),
+                -b.e24(),
+                -b.e34(),
+                -b.e15(),
+                -b.e25(),
+                -b.e35(),
+                -b.e45(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(-b.e1234(), -b.e1235(), -b.e1245(), -b.e3145(), -b.e2345()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Vector<F>, Versor<F>);
+
+// Versor-Vector
+impl<F: Float> Sub<Vector<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Vector::new(-b.e1(), -b.e2(), -b.e3(), -b.e4(), -b.e5()),
+            Bivector::new(
+                self.e12(),
+                self.e31(),
+                self.e23(),
+                self.e14(),
+                self.e24(),
+                self.e34(),
+                self.e15(),
+                self.e25(),
+                self.e35(),
+                self.e45(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e1234(),
+                self.e1235(),
+                self.e1245(),
+                self.e3145(),
+                self.e2345(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Versor<F>, Vector<F>);
+
+// Vector-Multivector
+impl<F: Float> Sub<Multivector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.scalar(),
+            Vector::new(
+                self.e1() - b.e1(),
+                self.e2() - b.e2(),
+                self.e3() - b.e3(),
+                self.e4() - b.e4(),
+                self.e5() - b.e5(),
+            ),
+            Bivector::new(
+                -b.e12(),
+                -b.e31(),
+                -b.e23(),
+                -b.e14(),
+                -b.e24(),
+                -b.e34(),
+                -b.e15(),
+                -b.e25(),
+                -b.e35(),
+                -b.e45(),
+            ),
+            Trivector::new(
+                -b.e123(),
+                -b.e124(),
+                -b.e314(),
+                -b.e234(),
+                -b.e125(),
+                -b.e315(),
+                -b.e235(),
+                -b.e145(),
+                -b.e245(),
+                -b.e345(),
+            ),
+            Quadvector::new(-b.e1234(), -b.e1235(), -b.e1245(), -b.e3145(), -b.e2345()),
+            -b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Vector<F>, Multivector<F>);
+
+// Multivector-Vector
+impl<F: Float> Sub<Vector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Vector::new(
+                self.e1() - b.e1(),
+                self.e2() - b.e2(),
+                self.e3() - b.e3(),
+                self.e4() - b.e4(),
+                self.e5() - b.e5(),
+            ),
+            Bivector::new(
+                self.e12(),
+                self.e31(),
+                self.e23(),
+                self.e14(),
+                self.e24(),
+                self.e34(),
+                self.e15(),
+                self.e25(),
+                self.e35(),
+                self.e45(),
+            ),
+            Trivector::new(
+                self.e123(),
+                self.e124(),
+                self.e314(),
+                self.e234(),
+                self.e125(),
+                self.e315(),
+                self.e235(),
+                self.e145(),
+                self.e245(),
+                self.e345(),
+            ),
+            Quadvector::new(
+                self.e1234(),
+                self.e1235(),
+                self.e1245(),
+                self.e3145(),
+                self.e2345(),
+            ),
+            self.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Multivector<F>, Vector<F>);
+
+// Bivector-Bivector
+impl<F: Float> Sub for Bivector<F> {
+    type Output = Bivector<F>;
+    fn sub(self, b: Bivector<F>) -> Bivector<F> {
+        Bivector::new(
+            self.e12() - b.e12(),
+            self.e31() - b.e31(),
+            self.e23() - b.e23(),
+            self.e14() - b.e14(),
+            self.e24() - b.e24(),
+            self.e34() - b.e34(),
+            self.e15() - b.e15(),
+            self.e25() - b.e25(),
+            self.e35() - b.e35(),
+            self.e45() - b.e45(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Bivector<F>, Bivector<F>);
+
+// Bivector-Trivector
+impl<F: Float> Sub<Trivector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::new(
+                self.e12(),
+                self.e31(),
+                self.e23(),
+                self.e14(),
+                self.e24(),
+                self.e34(),
+                self.e15(),
+                self.e25(),
+                self.e35(),
+                self.e45(),
+            ),
+            Trivector::new(
+                -b.e123(),
+                -b.e124(),
+                -b.e314(),
+                -b.e234(),
+                -b.e125(),
+                -b.e315(),
+                -b.e235(),
+                -b.e145(),
+                -b.e245(),
+                -b.e345(),
+            ),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Bivector<F>, Trivector<F>);
+
+// Trivector-Bivector
+impl<F: Float> Sub<Bivector<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::new(
+                -b.e12(),
+                -b.e31(),
+                -b.e23(),
+                -b.e14(),
+                -b.e24(),
+                -b.e34(),
+                -b.e15(),
+                -b.e25(),
+                -b.e35(),
+                -b.e45(),
+            ),
+            Trivector::new(
+                self.e123(),
+                self.e124(),
+                self.e314(),
+                self.e234(),
+                self.e125(),
+                self.e315(),
+                self.e235(),
+                self.e145(),
+                self.e245(),
+                self.e345(),
+            ),
+            Quadvector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Trivector<F>, Bivector<F>);
+
+// Bivector-Quadvector
+impl<F: Float> Sub<Quadvector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Quadvector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::new(
+                self.e12(),
+                self.e31(),
+                self.e23(),
+                self.e14(),
+                self.e24(),
+                self.e34(),
+                self.e15(),
+                self.e25(),
+                self.e35(),
+                self.e45(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(-b.e1234(), -b.e1235(), -b.e1245(), -b.e3145(), -b.e2345()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Bivector<F>, Quadvector<F>);
+
+// Quadvector-Bivector
+impl<F: Float> Sub<Bivector<F>> for Quadvector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::new(
+                -b.e12(),
+                -b.e31(),
+                -b.e23(),
+                -b.e14(),
+                -b.e24(),
+                -b.e34(),
+                -b.e15(),
+                -b.e25(),
+                -b.e35(),
+                -b.e45(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e1234(),
+                self.e1235(),
+                self.e1245(),
+                self.e3145(),
+                self.e2345(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Quadvector<F>, Bivector<F>);
+
+// Bivector-Pseudoscalar
+impl<F: Float> Sub<Pseudoscalar<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::new(
+                self.e12(),
+                self.e31(),
+                self.e23(),
+                self.e14(),
+                self.e24(),
+                self.e34(),
+                self.e15(),
+                self.e25(),
+                self.e35(),
+                self.e45(),
+            ),
+            Trivector::zero(),
+            Quadvector::zero(),
+            -b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Bivector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Bivector
+impl<F: Float> Sub<Bivector<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::new(
+                -b.e12(),
+                -b.e31(),
+                -b.e23(),
+                -b.e14(),
+                -b.e24(),
+                -b.e34(),
+                -b.e15(),
+                -b.e25(),
+                -b.e35(),
+                -b.e45(),
+            ),
+            Trivector::zero(),
+            Quadvector::zero(),
+            self.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Pseudoscalar<F>, Bivector<F>);
+
+// Bivector-Versor
+impl<F: Float> Sub<Versor<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.scalar(),
+            Vector::zero(),
+            Bivector::new(
+                self.e12() - b.e12(),
+                self.e31() - b.e31(),
+                self.e23() - b.e23(),
+                self.e14() - b.e14(),
+                self.e24() - b.e24(),
+                self.e34() - b.e34(),
+                self.e15() - b.e15(),
+                self.e25() - b.e25(),
+                self.e35() - b.e35(),
+                self.e45() - b.e45(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(-b.e1234(), -b.e1235(), -b.e1245(), -b.e3145(), -b.e2345()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Bivector<F>, Versor<F>);
+
+// Versor-Bivector
+impl<F: Float> Sub<Bivector<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Vector::zero(),
+            Bivector::new(
+                self.e12() - b.e12(),
+                self.e31() - b.e31(),
+                self.e23() - b.e23(),
+                self.e14() - b.e14(),
+                self.e24() - b.e24(),
+                self.e34() - b.e34(),
+                self.e15() - b.e15(),
+                self.e25() - b.e25(),
+                self.e35() - b.e35(),
+                self.e45() - b.e45(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e1234(),
+                self.e1235(),
+                self.e1245(),
+                self.e3145(),
+                self.e2345(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Versor<F>, Bivector<F>);
+
+// Bivector-Multivector
+impl<F: Float> Sub<Multivector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.scalar(),
+            Vector::new(-b.e1(), -b.e2(), -b.e3(), -b.e4(), -b.e5()),
+            Bivector::new(
+                self.e12() - b.e12(),
+                self.e31() - b.e31(),
+                self.e23() - b.e23(),
+                self.e14() - b.e14(),
+                self.e24() - b.e24(),
+                self.e34() - b.e34(),
+                self.e15() - b.e15(),
+                self.e25() - b.e25(),
+                self.e35() - b.e35(),
+                self.e45() - b.e45(),
+            ),
+            Trivector::new(
+                -b.e123(),
+                -b.e124(),
+                -b.e314(),
+                -b.e234(),
+                -b.e125(),
+                -b.e315(),
+                -b.e235(),
+                -b.e145(),
+                -b.e245(),
+                -b.e345(),
+            ),
+            Quadvector::new(-b.e1234(), -b.e1235(), -b.e1245(), -b.e3145(), -b.e2345()),
+            -b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Bivector<F>, Multivector<F>);
+
+// Multivector-Bivector
+impl<F: Float> Sub<Bivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Vector::new(self.e1(), self.e2(), self.e3(), self.e4(), self.e5()),
+            Bivector::new(
+                self.e12() - b.e12(),
+                self.e31() - b.e31(),
+                self.e23() - b.e23(),
+                self.e14() - b.e14(),
+                self.e24() - b.e24(),
+                self.e34() - b.e34(),
+                self.e15() - b.e15(),
+                self.e25() - b.e25(),
+                self.e35() - b.e35(),
+                self.e45() - b.e45(),
+            ),
+            Trivector::new(
+                self.e123(),
+                self.e124(),
+                self.e314(),
+                self.e234(),
+                self.e125(),
+                self.e315(),
+                self.e235(),
+                self.e145(),
+                self.e245(),
+                self.e345(),
+            ),
+            Quadvector::new(
+                self.e1234(),
+                self.e1235(),
+                self.e1245(),
+                self.e3145(),
+                self.e2345(),
+            ),
+            self.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Multivector<F>, Bivector<F>);
+
+// Trivector-Trivector
+impl<F: Float> Sub for Trivector<F> {
+    type Output = Trivector<F>;
+    fn sub(self, b: Trivector<F>) -> Trivector<F> {
+        Trivector::new(
+            self.e123() - b.e123(),
+            self.e124() - b.e124(),
+            self.e314() - b.e314(),
+            self.e234() - b.e234(),
+            self.e125() - b.e125(),
+            self.e315() - b.e315(),
+            self.e235() - b.e235(),
+            self.e145() - b.e145(),
+            self.e245() - b.e245(),
+            self.e345() - b.e345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Trivector<F>, Trivector<F>);
+
+// Trivector-Quadvector
+impl<F: Float> Sub<Quadvector<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Quadvector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::new(
+                self.e123(),
+                self.e124(),
+                self.e314(),
+                self.e234(),
+                self.e125(),
+                self.e315(),
+                self.e235(),
+                self.e145(),
+                self.e245(),
+                self.e345(),
+            ),
+            Quadvector::new(-b.e1234(), -b.e1235(), -b.e1245(), -b.e3145(), -b.e2345()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Trivector<F>, Quadvector<F>);
+
+// Quadvector-Trivector
+impl<F: Float> Sub<Trivector<F>> for Quadvector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::new(
+                -b.e123(),
+                -b.e124(),
+                -b.e314(),
+                -b.e234(),
+                -b.e125(),
+                -b.e315(),
+                -b.e235(),
+                -b.e145(),
+                -b.e245(),
+                -b.e345(),
+            ),
+            Quadvector::new(
+                self.e1234(),
+                self.e1235(),
+                self.e1245(),
+                self.e3145(),
+                self.e2345(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Quadvector<F>, Trivector<F>);
+
+// Trivector-Pseudoscalar
+impl<F: Float> Sub<Pseudoscalar<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::new(
+                self.e123(),
+                self.e124(),
+                self.e314(),
+                self.e234(),
+                self.e125(),
+                self.e315(),
+                self.e235(),
+                self.e145(),
+                self.e245(),
+                self.e345(),
+            ),
+            Quadvector::zero(),
+            -b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Trivector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Trivector
+impl<F: Float> Sub<Trivector<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::new(
+                -b.e123(),
+                -b.e124(),
+                -b.e314(),
+                -b.e234(),
+                -b.e125(),
+                -b.e315(),
+                -b.e235(),
+                -b.e145(),
+                -b.e245(),
+                -b.e345(),
+            ),
+            Quadvector::zero(),
+            self.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Pseudoscalar<F>, Trivector<F>);
+
+// Trivector-Versor
+impl<F: Float> Sub<Versor<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.scalar(),
+            Vector::zero(),
+            Bivector::new(
+                -b.e12(),
+                -b.e31(),
+                -b.e23(),
+                -b.e14(),
+                -b.e24(),
+                -b.e34(),
+                -b.e15(),
+                -b.e25(),
+                -b.e35(),
+                -b.e45(),
+            ),
+            Trivector::new(
+                self.e123(),
+                self.e124(),
+                self.e314(),
+                self.e234(),
+                self.e125(),
+                self.e315(),
+                self.e235(),
+                self.e145(),
+                self.e245(),
+                self.e345(),
+            ),
+            Quadvector::new(-b.e1234(), -b.e1235(), -b.e1245(), -b.e3145(), -b.e2345()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Trivector<F>, Versor<F>);
+
+// Versor-Trivector
+impl<F: Float> Sub<Trivector<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Vector::zero(),
+            Bivector::new(
+                self.e12(),
+                self.e31(),
+                self.e23(),
+                self.e14(),
+                self.e24(),
+                self.e34(),
+                self.e15(),
+                self.e25(),
+                self.e35(),
+                self.e45(),
+            ),
+            Trivector::new(
+                -b.e123(),
+                -b.e124(),
+                -b.e314(),
+                -b.e234(),
+                -b.e125(),
+                -b.e315(),
+                -b.e235(),
+                -b.e145(),
+                -b.e245(),
+                -b.e345(),
+            ),
+            Quadvector::new(
+                self.e1234(),
+                self.e1235(),
+                self.e1245(),
+                self.e3145(),
+                self.e2345(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Versor<F>, Trivector<F>);
+
+// Trivector-Multivector
+impl<F: Float> Sub<Multivector<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.scalar(),
+            Vector::new(-b.e1(), -b.e2(), -b.e3(), -b.e4(), -b.e5()),
+            Bivector::new(
+                -b.e12(),
+                -b.e31(),
+                -b.e23(),
+                -b.e14(),
+                -b.e24(),
+                -b.e34(),
+                -b.e15(),
+                -b.e25(),
+                -b.e35(),
+                -b.e45(),
+            ),
+            Trivector::new(
+                self.e123() - b.e123(),
+                self.e124() - b.e124(),
+                self.e314() - b.e314(),
+                self.e234() - b.e234(),
+                self.e125() - b.e125(),
+                self.e315() - b.e315(),
+                self.e235() - b.e235(),
+                self.e145() - b.e145(),
+                self.e245() - b.e245(),
+                self.e345() - b.e345(),
+            ),
+            Quadvector::new(-b.e1234(), -b.e1235(), -b.e1245(), -b.e3145(), -b.e2345()),
+            -b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Trivector<F>, Multivector<F>);
+
+// Multivector-Trivector
+impl<F: Float> Sub<Trivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Vector::new(self.e1(), self.e2(), self.e3(), self.e4(), self.e5()),
+            Bivector::new(
+                self.e12(),
+                self.e31(),
+                self.e23(),
+                self.e14(),
+                self.e24(),
+                self.e34(),
+                self.e15(),
+                self.e25(),
+                self.e35(),
+                self.e45(),
+            ),
+            Trivector::new(
+                self.e123() - b.e123(),
+                self.e124() - b.e124(),
+                self.e314() - b.e314(),
+                self.e234() - b.e234(),
+                self.e125() - b.e125(),
+                self.e315() - b.e315(),
+                self.e235() - b.e235(),
+                self.e145() - b.e145(),
+                self.e245() - b.e245(),
+                self.e345() - b.e345(),
+            ),
+            Quadvector::new(
+                self.e1234(),
+                self.e1235(),
+                self.e1245(),
+                self.e3145(),
+                self.e2345(),
+            ),
+            self.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Multivector<F>, Trivector<F>);
+
+// Quadvector-Quadvector
+impl<F: Float> Sub for Quadvector<F> {
+    type Output = Quadvector<F>;
+    fn sub(self, b: Quadvector<F>) -> Quadvector<F> {
+        Quadvector::new(
+            self.e1234() - b.e1234(),
+            self.e1235() - b.e1235(),
+            self.e1245() - b.e1245(),
+            self.e3145() - b.e3145(),
+            self.e2345() - b.e2345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Quadvector<F>, Quadvector<F>);
+
+// Quadvector-Pseudoscalar
+impl<F: Float> Sub<Pseudoscalar<F>> for Quadvector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e1234(),
+                self.e1235(),
+                self.e1245(),
+                self.e3145(),
+                self.e2345(),
+            ),
+            -b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Quadvector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Quadvector
+impl<F: Float> Sub<Quadvector<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Quadvector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::zero(),
+            Quadvector::new(-b.e1234(), -b.e1235(), -b.e1245(), -b.e3145(), -b.e2345()),
+            self.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Pseudoscalar<F>, Quadvector<F>);
+
+// Quadvector-Versor
+impl<F: Float> Sub<Versor<F>> for Quadvector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.scalar(),
+            Vector::zero(),
+            Bivector::new(
+                -b.e12(),
+                -b.e31(),
+                -b.e23(),
+                -b.e14(),
+                -b.e24(),
+                -b.e34(),
+                -b.e15(),
+                -b.e25(),
+                -b.e35(),
+                -b.e45(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e1234() - b.e1234(),
+                self.e1235() - b.e1235(),
+                self.e1245() - b.e1245(),
+                self.e3145() - b.e3145(),
+                self.e2345() - b.e2345(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Quadvector<F>, Versor<F>);
+
+// Versor-Quadvector
+impl<F: Float> Sub<Quadvector<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Quadvector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Vector::zero(),
+            Bivector::new(
+                self.e12(),
+                self.e31(),
+                self.e23(),
+                self.e14(),
+                self.e24(),
+                self.e34(),
+                self.e15(),
+                self.e25(),
+                self.e35(),
+                self.e45(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e1234() - b.e1234(),
+                self.e1235() - b.e1235(),
+                self.e1245() - b.e1245(),
+                self.e3145() - b.e3145(),
+                self.e2345() - b.e2345(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Versor<F>, Quadvector<F>);
+
+// Quadvector-Multivector
+impl<F: Float> Sub<Multivector<F>> for Quadvector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.scalar(),
+            Vector::new(-b.e1(), -b.e2(), -b.e3(), -b.e4(), -b.e5()),
+            Bivector::new(
+                -b.e12(),
+                -b.e31(),
+                -b.e23(),
+                -b.e14(),
+                -b.e24(),
+                -b.e34(),
+                -b.e15(),
+                -b.e25(),
+                -b.e35(),
+                -b.e45(),
+            ),
+            Trivector::new(
+                -b.e123(),
+                -b.e124(),
+                -b.e314(),
+                -b.e234(),
+                -b.e125(),
+                -b.e315(),
+                -b.e235(),
+                -b.e145(),
+                -b.e245(),
+                -b.e345(),
+            ),
+            Quadvector::new(
+                self.e1234() - b.e1234(),
+                self.e1235() - b.e1235(),
+                self.e1245() - b.e1245(),
+                self.e3145() - b.e3145(),
+                self.e2345() - b.e2345(),
+            ),
+            -b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Quadvector<F>, Multivector<F>);
+
+// Multivector-Quadvector
+impl<F: Float> Sub<Quadvector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Quadvector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Vector::new(self.e1(), self.e2(), self.e3(), self.e4(), self.e5()),
+            Bivector::new(
+                self.e12(),
+                self.e31(),
+                self.e23(),
+                self.e14(),
+                self.e24(),
+                self.e34(),
+                self.e15(),
+                self.e25(),
+                self.e35(),
+                self.e45(),
+            ),
+            Trivector::new(
+                self.e123(),
+                self.e124(),
+                self.e314(),
+                self.e234(),
+                self.e125(),
+                self.e315(),
+                self.e235(),
+                self.e145(),
+                self.e245(),
+                self.e345(),
+            ),
+            Quadvector::new(
+                self.e1234() - b.e1234(),
+                self.e1235() - b.e1235(),
+                self.e1245() - b.e1245(),
+                self.e3145() - b.e3145(),
+                self.e2345() - b.e2345(),
+            ),
+            self.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Multivector<F>, Quadvector<F>);
+
+// Pseudoscalar-Pseudoscalar
+impl<F: Float> Sub for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn sub(self, b: Pseudoscalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.e12345() - b.e12345())
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Pseudoscalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Versor
+impl<F: Float> Sub<Versor<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.scalar(),
+            Vector::zero(),
+            Bivector::new(
+                -b.e12(),
+                -b.e31(),
+                -b.e23(),
+                -b.e14(),
+                -b.e24(),
+                -b.e34(),
+                -b.e15(),
+                -b.e25(),
+                -b.e35(),
+                -b.e45(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(-b.e1234(), -b.e1235(), -b.e1245(), -b.e3145(), -b.e2345()),
+            self.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Pseudoscalar<F>, Versor<F>);
+
+// Versor-Pseudoscalar
+impl<F: Float> Sub<Pseudoscalar<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Vector::zero(),
+            Bivector::new(
+                self.e12(),
+                self.e31(),
+                self.e23(),
+                self.e14(),
+                self.e24(),
+                self.e34(),
+                self.e15(),
+                self.e25(),
+                self.e35(),
+                self.e45(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e1234(),
+                self.e1235(),
+                self.e1245(),
+                self.e3145(),
+                self.e2345(),
+            ),
+            -b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Versor<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Multivector
+impl<F: Float> Sub<Multivector<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.scalar(),
+            Vector::new(-b.e1(), -b.e2(), -b.e3(), -b.e4(), -b.e5()),
+            Bivector::new(
+                -b.e12(),
+                -b.e31(),
+                -b.e23(),
+                -b.e14(),
+                -b.e24(),
+                -b.e34(),
+                -b.e15(),
+                -b.e25(),
+                -b.e35(),
+                -b.e45(),
+            ),
+            Trivector::new(
+                -b.e123(),
+                -b.e124(),
+                -b.e314(),
+                -b.e234(),
+                -b.e125(),
+                -b.e315(),
+                -b.e235(),
+                -b.e145(),
+                -b.e245(),
+                -b.e345(),
+            ),
+            Quadvector::new(-b.e1234(), -b.e1235(), -b.e1245(), -b.e3145(), -b.e2345()),
+            self.e12345() - b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Pseudoscalar<F>, Multivector<F>);
+
+// Multivector-Pseudoscalar
+impl<F: Float> Sub<Pseudoscalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Vector::new(self.e1(), self.e2(), self.e3(), self.e4(), self.e5()),
+            Bivector::new(
+                self.e12(),
+                self.e31(),
+                self.e23(),
+                self.e14(),
+                self.e24(),
+                self.e34(),
+                self.e15(),
+                self.e25(),
+                self.e35(),
+                self.e45(),
+            ),
+            Trivector::new(
+                self.e123(),
+                self.e124(),
+                self.e314(),
+                self.e234(),
+                self.e125(),
+                self.e315(),
+                self.e235(),
+                self.e145(),
+                self.e245(),
+                self.e345(),
+            ),
+            Quadvector::new(
+                self.e1234(),
+                self.e1235(),
+                self.e1245(),
+                self.e3145(),
+                self.e2345(),
+            ),
+            self.e12345() - b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Multivector<F>, Pseudoscalar<F>);
+
+// Versor-Versor
+impl<F: Float> Sub for Versor<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() - b.scalar(),
+            Vector::zero(),
+            Bivector::new(
+                self.e12() - b.e12(),
+                self.e31() - b.e31(),
+                self.e23() - b.e23(),
+                self.e14() - b.e14(),
+                self.e24() - b.e24(),
+                self.e34() - b.e34(),
+                self.e15() - b.e15(),
+                self.e25() - b.e25(),
+                self.e35() - b.e35(),
+                self.e45() - b.e45(),
+            ),
+            Trivector::zero(),
+            Quadvector::new(
+                self.e1234() - b.e1234(),
+                self.e1235() - b.e1235(),
+                self.e1245() - b.e1245(),
+                self.e3145() - b.e3145(),
+                self.e2345() - b.e2345(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Versor<F>, Versor<F>);
+
+// Versor-Multivector
+impl<F: Float> Sub<Multivector<F>> for Versor<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() - b.scalar(),
+            Vector::new(-b.e1(), -b.e2(), -b.e3(), -b.e4(), -b.e5()),
+            Bivector::new(
+                self.e12() - b.e12(),
+                self.e31() - b.e31(),
+                self.e23() - b.e23(),
+                self.e14() - b.e14(),
+                self.e24() - b.e24(),
+                self.e34() - b.e34(),
+                self.e15() - b.e15(),
+                self.e25() - b.e25(),
+                self.e35() - b.e35(),
+                self.e45() - b.e45(),
+            ),
+            Trivector::new(
+                -b.e123(),
+                -b.e124(),
+                -b.e314(),
+                -b.e234(),
+                -b.e125(),
+                -b.e315(),
+                -b.e235(),
+                -b.e145(),
+                -b.e245(),
+                -b.e345(),
+            ),
+            Quadvector::new(
+                self.e1234() - b.e1234(),
+                self.e1235() - b.e1235(),
+                self.e1245() - b.e1245(),
+                self.e3145() - b.e3145(),
+                self.e2345() - b.e2345(),
+            ),
+            -b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Versor<F>, Multivector<F>);
+
+// Multivector-Versor
+impl<F: Float> Sub<Versor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Versor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() - b.scalar(),
+            Vector::new(self.e1(), self.e2(), self.e3(), self.e4(), self.e5()),
+            Bivector::new(
+                self.e12() - b.e12(),
+                self.e31() - b.e31(),
+                self.e23() - b.e23(),
+                self.e14() - b.e14(),
+                self.e24() - b.e24(),
+                self.e34() - b.e34(),
+                self.e15() - b.e15(),
+                self.e25() - b.e25(),
+                self.e35() - b.e35(),
+                self.e45() - b.e45(),
+            ),
+            Trivector::new(
+                self.e123(),
+                self.e124(),
+                self.e314(),
+                self.e234(),
+                self.e125(),
+                self.e315(),
+                self.e235(),
+                self.e145(),
+                self.e245(),
+                self.e345(),
+            ),
+            Quadvector::new(
+                self.e1234() - b.e1234(),
+                self.e1235() - b.e1235(),
+                self.e1245() - b.e1245(),
+                self.e3145() - b.e3145(),
+                self.e2345() - b.e2345(),
+            ),
+            self.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Multivector<F>, Versor<F>);
+
+// Multivector-Multivector
+impl<F: Float> Sub for Multivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() - b.scalar(),
+            Vector::new(
+                self.e1() - b.e1(),
+                self.e2() - b.e2(),
+                self.e3() - b.e3(),
+                self.e4() - b.e4(),
+                self.e5() - b.e5(),
+            ),
+            Bivector::new(
+                self.e12() - b.e12(),
+                self.e31() - b.e31(),
+                self.e23() - b.e23(),
+                self.e14() - b.e14(),
+                self.e24() - b.e24(),
+                self.e34() - b.e34(),
+                self.e15() - b.e15(),
+                self.e25() - b.e25(),
+                self.e35() - b.e35(),
+                self.e45() - b.e45(),
+            ),
+            Trivector::new(
+                self.e123() - b.e123(),
+                self.e124() - b.e124(),
+                self.e314() - b.e314(),
+                self.e234() - b.e234(),
+                self.e125() - b.e125(),
+                self.e315() - b.e315(),
+                self.e235() - b.e235(),
+                self.e145() - b.e145(),
+                self.e245() - b.e245(),
+                self.e345() - b.e345(),
+            ),
+            Quadvector::new(
+                self.e1234() - b.e1234(),
+                self.e1235() - b.e1235(),
+                self.e1245() - b.e1245(),
+                self.e3145() - b.e3145(),
+                self.e2345() - b.e2345(),
+            ),
+            self.e12345() - b.e12345(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Multivector<F>, Multivector<F>);
+
+// Test
+#[cfg(test)]
+mod subtraction_difference {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn scalar_scalar() {
+        // 3
+        let scalar1 = Scalar::new(3.0);
+        // 4
+        let scalar2 = Scalar::new(4.0);
+        let res = scalar1 - scalar2;
+        // -1
+        assert_relative_eq!(res, -1.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_bivector() {
+        // -3e1+5e2+4e3+4e4-1e5
+        let vector1 = Vector::new(-3.0, 5.0, 4.0, 4.0, -1.0);
+        // 5e12-2e31-3e23-1e14-1e24-2e34-1e15+2e25-4e35+5e45
+        let bivector2 = Bivector::new(5.0, -2.0, -3.0, -1.0, -1.0, -2.0, -1.0, 2.0, -4.0, 5.0);
+        let res = vector1 - bivector2;
+        // -3e1+5e2+4e3+4e4-1e5-5e12+2e31+3e23+1e14+1e24+2e34+1e15-2e25+4e35-5e45
+        assert_relative_eq!(res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e4(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e5(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), -5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e14(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e24(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e34(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e15(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e25(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e35(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e45(), -5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e124(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e314(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e234(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e125(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e315(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e235(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e145(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e245(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e345(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1234(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1235(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1245(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3145(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2345(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12345(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn trivector_trivector() {
+        // 1e123+2e124+1e314+2e234-3e125-3e315-3e235+1e145-1e245+1e345
+        let trivector1 = Trivector::new(1.0, 2.0, 1.0, 2.0, -3.0, -3.0, -3.0, 1.0, -1.0, 1.0);
+        // -4e123+5e124-2e314+1e234+5e125+2e315-3e235+4e145+1e245+1e345
+        let trivector2 = Trivector::new(-4.0, 5.0, -2.0, 1.0, 5.0, 2.0, -3.0, 4.0, 1.0, 1.0);
+        let res = trivector1 - trivector2;
+        // 5e123-3e124+3e314+1e234-8e125-5e315-3e145-2e245
+        assert_relative_eq!(res.e123(), 5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e124(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e314(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e234(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e125(), -8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e315(), -5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e235(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e145(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e245(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e345(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn versor_multivector() {
+        // -3-3e12-1e31-3e23-2e14+5e24+2e34+3e15+2e25+3e35-4e45+5e1234-3e1235+2e1245-1e3145+5e2345
+        let versor1 = Versor::new(
+            -3.0,
+            Bivector::new(-3.0, -1.0, -3.0, -2.0, 5.0, 2.0, 3.0, 2.0, 3.0, -4.0),
+            Quadvector::new(5.0, -3.0, 2.0, -1.0, 5.0),
+        );
+        // 1-3e1-1e2+4e3+5e4+2e5+5e12-2e31+4e23+4e14-3e24+4e34-3e15+1e25+4e35+1e45+4e123+2e124+3e314+1e234+1e125+3e315+1e235-2e145-2e245+2e345-1e1234-4e1235-2e1245-3e3145-2e2345-4e12345
+        let mvec2 = Multivector::new(
+            1.0,
+            Vector::new(-3.0, -1.0, 4.0, 5.0, 2.0),
+            Bivector::new(5.0, -2.0, 4.0, 4.0, -3.0, 4.0, -3.0, 1.0, 4.0, 1.0),
+            Trivector::new(4.0, 2.0, 3.0, 1.0, 1.0, 3.0, 1.0, -2.0, -2.0, 2.0),
+            Quadvector::new(-1.0, -4.0, -2.0, -3.0, -2.0),
+            -4.0,
+        );
+        let res = versor1 - mvec2;
+        // -4+3e1+1e2-4e3-5e4-2e5-8e12+1e31-7e23-6e14+8e24-2e34+6e15+1e25-1e35-5e45-4e123-2e124-3e314-1e234-1e125-3e315-1e235+2e145+2e245-2e345+6e1234+1e1235+4e1245+2e3145+7e2345+4e12345
+        assert_relative_eq!(res.scalar(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e4(), -5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e5(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), -8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), -7.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e14(), -6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e24(), 8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e34(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e15(), 6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e25(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e35(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e45(), -5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e124(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e314(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e234(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e125(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e315(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e235(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e145(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e245(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e345(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1234(), 6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1235(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1245(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3145(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2345(), 7.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12345(), 4.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn pseudoscalar_scalar() {
+        // 5e12345
+        let pseudoscalar1 = Pseudoscalar::new(5.0);
+        // 1
+        let scalar2 = Scalar::new(1.0);
+        let res = pseudoscalar1 - scalar2;
+        // -1+5e12345
+        assert_relative_eq!(res.scalar(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e4(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e5(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e14(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e24(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e34(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e15(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e25(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e35(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e45(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e124(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e314(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e234(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e125(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e315(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e235(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e145(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e245(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e345(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1234(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1235(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1245(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3145(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2345(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12345(), 5.0, max_relative = 0.000001);
+    }
+}
@@ -0,0 +1,344 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::fmt;
+
+use core::ops::Neg;
+
+use num_traits::Float;
+
+use super::{
+    CGA3DOps, bivector::Bivector, pseudoscalar::Pseudoscalar, scalar::Scalar, up, vector::Vector,
+};
+use crate::vga3d;
+
+/// # 3D Conformal Geometric Algebra Trivector
+/// The trivectors of the algebra.
+///
+/// The outer product of three points is a circle.
+/// If one of the points is the point at infinity the circle is a line.
+/// The dual of a point pair is a trivector.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Trivector<F: Float> {
+    e123: F,
+    e124: F,
+    e314: F,
+    e234: F,
+    e125: F,
+    e315: F,
+    e235: F,
+    e145: F,
+    e245: F,
+    e345: F,
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + fmt::Display> fmt::Display for Trivector<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Trivector {{")?;
+        write!(f, " {}e123", self.e123)?;
+
+        // Add the appropriate sign to the remaining components
+        for (value, name) in [
+            (self.e124, "e124"),
+            (self.e314, "e314"),
+            (self.e234, "e234"),
+            (self.e125, "e125"),
+            (self.e315, "e315"),
+            (self.e235, "e235"),
+            (self.e145, "e145"),
+            (self.e245, "e245"),
+            (self.e345, "e345"),
+        ] {
+            if value >= F::zero() {
+                write!(f, " + {}{}", value, name)?;
+            } else {
+                write!(f, " - {}{}", value.abs(), name)?;
+            }
+        }
+        write!(f, " }}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F: Float + defmt::Format> defmt::Format for Trivector<F> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Trivector {{");
+        defmt::write!(f, " {}e123", self.e123);
+
+        // Add the appropriate sign to the remaining components
+        for (value, name) in [
+            (self.e124, "e124"),
+            (self.e314, "e314"),
+            (self.e234, "e234"),
+            (self.e125, "e125"),
+            (self.e315, "e315"),
+            (self.e235, "e235"),
+            (self.e145, "e145"),
+            (self.e245, "e245"),
+            (self.e345, "e345"),
+        ] {
+            if value >= F::zero() {
+                defmt::write!(f, " + {}{}", value, name);
+            } else {
+                defmt::write!(f, " - {}{}", value.abs(), name);
+            }
+        }
+        defmt::write!(f, " }}");
+    }
+}
+
+impl<F: Float> Trivector<F> {
+    /// The zero trivector
+    pub fn zero() -> Self {
+        Self {
+            e123: F::zero(),
+            e124: F::zero(),
+            e314: F::zero(),
+            e234: F::zero(),
+            e125: F::zero(),
+            e315: F::zero(),
+            e235: F::zero(),
+            e145: F::zero(),
+            e245: F::zero(),
+            e345: F::zero(),
+        }
+    }
+
+    /// Create new trivector from linear combination of the basis blades
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        e123: F,
+        e124: F,
+        e314: F,
+        e234: F,
+        e125: F,
+        e315: F,
+        e235: F,
+        e145: F,
+        e245: F,
+        e345: F,
+    ) -> Self {
+        Self {
+            e123,
+            e124,
+            e314,
+            e234,
+            e125,
+            e315,
+            e235,
+            e145,
+            e245,
+            e345,
+        }
+    }
+
+    /// Create the circle through three points
+    /// $$ P_a \wedge P_b \wedge P_c $$
+    pub fn circle(a: vga3d::Vector<F>, b: vga3d::Vector<F>, c: vga3d::Vector<F>) -> Self {
+        up(a) ^ up(b) ^ up(c)
+    }
+
+    /// Create the line through two points
+    /// $$ P_a \wedge P_b \wedge \mathrm{e}_\infty $$
+    pub fn line(a: vga3d::Vector<F>, b: vga3d::Vector<F>) -> Self {
+        up(a) ^ up(b) ^ Vector::infinity()
+    }
+
+    /// Get trivector
+    pub fn trivector(self) -> Self {
+        self
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2\mathrm{e}_3$ part
+    pub fn e123(&self) -> F {
+        self.e123
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2\mathrm{e}_4$ part
+    pub fn e124(&self) -> F {
+        self.e124
+    }
+
+    /// Get the $\mathrm{e}_3\mathrm{e}_1\mathrm{e}_4$ part
+    pub fn e314(&self) -> F {
+        self.e314
+    }
+
+    /// Get the $\mathrm{e}_2\mathrm{e}_3\mathrm{e}_4$ part
+    pub fn e234(&self) -> F {
+        self.e234
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2\mathrm{e}_5$ part
+    pub fn e125(&self) -> F {
+        self.e125
+    }
+
+    /// Get the $\mathrm{e}_3\mathrm{e}_1\mathrm{e}_5$ part
+    pub fn e315(&self) -> F {
+        self.e315
+    }
+
+    /// Get the $\mathrm{e}_2\mathrm{e}_3\mathrm{e}_5$ part
+    pub fn e235(&self) -> F {
+        self.e235
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_4\mathrm{e}_5$ part
+    pub fn e145(&self) -> F {
+        self.e145
+    }
+
+    /// Get the $\mathrm{e}_2\mathrm{e}_4\mathrm{e}_5$ part
+    pub fn e245(&self) -> F {
+        self.e245
+    }
+
+    /// Get the $\mathrm{e}_3\mathrm{e}_4\mathrm{e}_5$ part
+    pub fn e345(&self) -> F {
+        self.e345
+    }
+
+    /// # Dual
+    /// The dual of a trivector is a bivector
+    /// $$ A^* = A I^{-1} $$
+    pub fn dual(self) -> Bivector<F> {
+        self * Pseudoscalar::new(-F::one())
+    }
+
+    /// # Undual
+    /// The inverse of the dual
+    /// $$ A = A^* I $$
+    pub fn undual(self) -> Bivector<F> {
+        self * Pseudoscalar::new(F::one())
+    }
+}
+
+// Negation
+impl<F: Float> Neg for Trivector<F> {
+    type Output = Trivector<F>;
+    fn neg(self) -> Trivector<F> {
+        Trivector::new(
+            -self.e123, -self.e124, -self.e314, -self.e234, -self.e125, -self.e315, -self.e235,
+            -self.e145, -self.e245, -self.e345,
+        )
+    }
+}
+
+impl<F: Float> CGA3DOps<F> for Trivector<F> {
+    fn reverse(self) -> Self {
+        -self
+    }
+
+    fn conjugate(self) -> Self {
+        self
+    }
+
+    fn involute(self) -> Self {
+        -self
+    }
+
+    fn norm(self) -> Scalar<F> {
+        Scalar(
+            (self.e123 * self.e123
+                + self.e124 * self.e124
+                + self.e314 * self.e314
+                + self.e234 * self.e234
+                - self.e125 * self.e125
+                - self.e315 * self.e315
+                - self.e235 * self.e235
+                - self.e145 * self.e145
+                - self.e245 * self.e245
+                - self.e345 * self.e345)
+                .abs()
+                .sqrt(),
+        )
+    }
+
+    fn try_normalize(self) -> Option<Self> {
+        self.norm().try_inverse().map(|norm_inverse| {
+            let norm_inverse = norm_inverse.0;
+            Trivector::new(
+                self.e123 * norm_inverse,
+                self.e124 * norm_inverse,
+                self.e314 * norm_inverse,
+                self.e234 * norm_inverse,
+                self.e125 * norm_inverse,
+                self.e315 * norm_inverse,
+                self.e235 * norm_inverse,
+                self.e145 * norm_inverse,
+                self.e245 * norm_inverse,
+                self.e345 * norm_inverse,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod trivector_ops {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn trivector_circle() {
+        // The unit circle in the xy-plane
+        let circle = Trivector::circle(
+            vga3d::Vector::new(1.0, 0.0, 0.0),
+            vga3d::Vector::new(0.0, 1.0, 0.0),
+            vga3d::Vector::new(-1.0, 0.0, 0.0),
+        );
+        let half_sqrt = 0.5.sqrt();
+        let res = up(vga3d::Vector::new(half_sqrt, -half_sqrt, 0.0)) ^ circle;
+        assert_relative_eq!(res.norm().0, 0.0, epsilon = 0.000001);
+        let res = up(vga3d::Vector::new(0.0, 0.0, 0.0)) ^ circle;
+        assert!(res.norm().0 > 0.1);
+        // A circle does not pass through infinity
+        let res = Vector::infinity() ^ circle;
+        assert!(res.norm().0 > 0.1);
+    }
+
+    #[test]
+    fn trivector_line() {
+        let line = Trivector::line(
+            vga3d::Vector::new(1.0, 2.0, 3.0),
+            vga3d::Vector::new(2.0, 4.0, 4.0),
+        );
+        for point in [
+            up(vga3d::Vector::new(3.0, 6.0, 5.0)),
+            up(vga3d::Vector::new(0.0, 0.0, 2.0)),
+            Vector::infinity(),
+        ] {
+            let res = point ^ line;
+            assert_relative_eq!(res.norm().0, 0.0, epsilon = 0.000001);
+        }
+    }
+
+    #[test]
+    fn trivector_dual() {
+        let trivector = Trivector::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0);
+        assert_eq!(trivector.dual().undual(), trivector);
+    }
+}
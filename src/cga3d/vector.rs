@@ -0,0 +1,330 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::fmt;
+
+use core::ops::Neg;
+
+use num_traits::Float;
+
+use super::{
+    CGA3DOps, down, pseudoscalar::Pseudoscalar, quadvector::Quadvector, scalar::Scalar, up,
+};
+use crate::vga3d;
+
+/// # 3D Conformal Geometric Algebra Vector
+/// The vectors of the algebra
+/// $$v = a \mathrm{e}_1 + b \mathrm{e}_2 + c \mathrm{e}_3 + d \mathrm{e}_4 + e \mathrm{e}_5$$
+/// where $\mathrm{e}_4^2 = 1$ and $\mathrm{e}_5^2 = -1$.
+/// The null basis of the origin and the point at infinity is
+/// $$ \mathrm{e}_0 = \frac{1}{2}\left( \mathrm{e}_5 - \mathrm{e}_4 \right), \quad \mathrm{e}_\infty = \mathrm{e}_4 + \mathrm{e}_5 $$
+///
+/// Points, dual spheres and dual planes are all vectors.
+/// A point is a null vector, see [`up`](super::up) and [`down`](super::down).
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Vector<F: Float> {
+    e1: F,
+    e2: F,
+    e3: F,
+    e4: F,
+    e5: F,
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + fmt::Display> fmt::Display for Vector<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Vector {{")?;
+        write!(f, " {}e1", self.e1)?;
+
+        // Add the appropriate sign to the remaining components
+        for (value, name) in [
+            (self.e2, "e2"),
+            (self.e3, "e3"),
+            (self.e4, "e4"),
+            (self.e5, "e5"),
+        ] {
+            if value >= F::zero() {
+                write!(f, " + {}{}", value, name)?;
+            } else {
+                write!(f, " - {}{}", value.abs(), name)?;
+            }
+        }
+        write!(f, " }}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F: Float + defmt::Format> defmt::Format for Vector<F> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Vector {{");
+        defmt::write!(f, " {}e1", self.e1);
+
+        // Add the appropriate sign to the remaining components
+        for (value, name) in [
+            (self.e2, "e2"),
+            (self.e3, "e3"),
+            (self.e4, "e4"),
+            (self.e5, "e5"),
+        ] {
+            if value >= F::zero() {
+                defmt::write!(f, " + {}{}", value, name);
+            } else {
+                defmt::write!(f, " - {}{}", value.abs(), name);
+            }
+        }
+        defmt::write!(f, " }}");
+    }
+}
+
+impl<F: Float> Vector<F> {
+    /// The zero vector
+    pub fn zero() -> Self {
+        Self {
+            e1: F::zero(),
+            e2: F::zero(),
+            e3: F::zero(),
+            e4: F::zero(),
+            e5: F::zero(),
+        }
+    }
+
+    /// Create new vector from linear combination of the basis blades
+    pub fn new(e1: F, e2: F, e3: F, e4: F, e5: F) -> Self {
+        Self { e1, e2, e3, e4, e5 }
+    }
+
+    /// The conformal point at the origin
+    /// $$ \mathrm{e}_0 = \frac{1}{2}\left( \mathrm{e}_5 - \mathrm{e}_4 \right) $$
+    pub fn origin() -> Self {
+        Self::from_null_basis(F::zero(), F::zero(), F::zero(), F::one(), F::zero())
+    }
+
+    /// The conformal point at infinity
+    /// $$ \mathrm{e}_\infty = \mathrm{e}_4 + \mathrm{e}_5 $$
+    pub fn infinity() -> Self {
+        Self::from_null_basis(F::zero(), F::zero(), F::zero(), F::zero(), F::one())
+    }
+
+    /// Create new vector from the Euclidean part and the null basis
+    /// $$v = a \mathrm{e}_1 + b \mathrm{e}_2 + c \mathrm{e}_3 + d \mathrm{e}_0 + e \mathrm{e}_\infty$$
+    pub fn from_null_basis(e1: F, e2: F, e3: F, e0: F, einf: F) -> Self {
+        let half = F::one() / (F::one() + F::one());
+        Self {
+            e1,
+            e2,
+            e3,
+            e4: einf - e0 * half,
+            e5: einf + e0 * half,
+        }
+    }
+
+    /// Create the dual sphere with a center and a radius
+    /// $$ \sigma = c + \frac{1}{2}\left( c^2 - r^2 \right)\mathrm{e}_\infty + \mathrm{e}_0 $$
+    /// A point lies on the sphere when $P \cdot \sigma = 0$.
+    pub fn dual_sphere(center: vga3d::Vector<F>, radius: F) -> Self {
+        let half = F::one() / (F::one() + F::one());
+        up(center) - Self::infinity() * Scalar(radius * radius * half)
+    }
+
+    /// Create the dual plane $n \cdot x = d$
+    /// $$ \pi = n + d\mathrm{e}_\infty $$
+    /// A point lies on the plane when $P \cdot \pi = 0$.
+    pub fn dual_plane(normal: vga3d::Vector<F>, distance: F) -> Self {
+        Self::from_null_basis(normal.e1(), normal.e2(), normal.e3(), F::zero(), distance)
+    }
+
+    /// Tries to get the center of a dual sphere.
+    /// This is the same as [`down`](super::down), so it is also the position of a point.
+    /// Flat objects have no center.
+    pub fn try_center(self) -> Option<vga3d::Vector<F>> {
+        down(self)
+    }
+
+    /// Tries to get the radius of a dual sphere
+    /// $$ r^2 = \frac{\sigma^2}{\left( \sigma \cdot \mathrm{e}_\infty \right)^2} $$
+    /// Flat objects have no radius and imaginary spheres have a negative $r^2$,
+    /// so both return `None`.
+    /// A point is a sphere with a radius of zero.
+    pub fn try_radius(self) -> Option<F> {
+        let weight = self.e0();
+        if weight.is_zero() {
+            return None;
+        }
+        let squared = self.e1 * self.e1 + self.e2 * self.e2 + self.e3 * self.e3 + self.e4 * self.e4
+            - self.e5 * self.e5;
+        let radius_squared = squared / (weight * weight);
+        if radius_squared < F::zero() {
+            None
+        } else {
+            Some(radius_squared.sqrt())
+        }
+    }
+
+    /// Get the $\mathrm{e}_0$ part in the null basis
+    pub fn e0(&self) -> F {
+        self.e5 - self.e4
+    }
+
+    /// Get the $\mathrm{e}_\infty$ part in the null basis
+    pub fn einf(&self) -> F {
+        (self.e4 + self.e5) / (F::one() + F::one())
+    }
+
+    /// Get vector
+    pub fn vector(self) -> Self {
+        self
+    }
+
+    /// Get the $\mathrm{e}_1$ part
+    pub fn e1(&self) -> F {
+        self.e1
+    }
+
+    /// Get the $\mathrm{e}_2$ part
+    pub fn e2(&self) -> F {
+        self.e2
+    }
+
+    /// Get the $\mathrm{e}_3$ part
+    pub fn e3(&self) -> F {
+        self.e3
+    }
+
+    /// Get the $\mathrm{e}_4$ part
+    pub fn e4(&self) -> F {
+        self.e4
+    }
+
+    /// Get the $\mathrm{e}_5$ part
+    pub fn e5(&self) -> F {
+        self.e5
+    }
+
+    /// # Dual
+    /// The dual of a vector is a quadvector
+    /// $$ A^* = A I^{-1} $$
+    pub fn dual(self) -> Quadvector<F> {
+        self * Pseudoscalar::new(-F::one())
+    }
+
+    /// # Undual
+    /// The inverse of the dual
+    /// $$ A = A^* I $$
+    pub fn undual(self) -> Quadvector<F> {
+        self * Pseudoscalar::new(F::one())
+    }
+}
+
+// Negation
+impl<F: Float> Neg for Vector<F> {
+    type Output = Vector<F>;
+    fn neg(self) -> Vector<F> {
+        Vector::new(-self.e1, -self.e2, -self.e3, -self.e4, -self.e5)
+    }
+}
+
+impl<F: Float> CGA3DOps<F> for Vector<F> {
+    fn reverse(self) -> Self {
+        self
+    }
+
+    fn conjugate(self) -> Self {
+        -self
+    }
+
+    fn involute(self) -> Self {
+        -self
+    }
+
+    fn norm(self) -> Scalar<F> {
+        Scalar(
+            (self.e1 * self.e1 + self.e2 * self.e2 + self.e3 * self.e3 + self.e4 * self.e4
+                - self.e5 * self.e5)
+                .abs()
+                .sqrt(),
+        )
+    }
+
+    fn try_normalize(self) -> Option<Self> {
+        self.norm().try_inverse().map(|norm_inverse| {
+            let norm_inverse = norm_inverse.0;
+            Vector::new(
+                self.e1 * norm_inverse,
+                self.e2 * norm_inverse,
+                self.e3 * norm_inverse,
+                self.e4 * norm_inverse,
+                self.e5 * norm_inverse,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod vector_ops {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn vector_null_basis() {
+        let vector = Vector::from_null_basis(1.0, 2.0, 3.0, 4.0, 5.0);
+        assert_eq!(vector, Vector::new(1.0, 2.0, 3.0, 3.0, 7.0));
+        assert_relative_eq!(vector.e0(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(vector.einf(), 5.0, max_relative = 0.000001);
+        // The origin and infinity are null vectors with e0.einf = -1
+        assert_relative_eq!((Vector::<f64>::origin() | Vector::origin()).0, 0.0);
+        assert_relative_eq!((Vector::<f64>::infinity() | Vector::infinity()).0, 0.0);
+        assert_relative_eq!((Vector::origin() | Vector::<f64>::infinity()).0, -1.0);
+    }
+
+    #[test]
+    fn vector_dual_sphere() {
+        let center = vga3d::Vector::new(1.0, -2.0, 3.0);
+        let sphere = Vector::dual_sphere(center, 2.5);
+        assert_relative_eq!(sphere.try_radius().unwrap(), 2.5, max_relative = 0.000001);
+        let res = sphere.try_center().unwrap();
+        assert_relative_eq!(res.e1(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 3.0, max_relative = 0.000001);
+        // A point on the sphere
+        let point = up(vga3d::Vector::new(1.0, 0.5, 3.0));
+        assert_relative_eq!((point | sphere).0, 0.0, epsilon = 0.000001);
+    }
+
+    #[test]
+    fn vector_dual_plane() {
+        // x + y = 2
+        let plane = Vector::dual_plane(vga3d::Vector::new(1.0, 1.0, 0.0), 2.0);
+        let point = up(vga3d::Vector::new(3.0, -1.0, 7.0));
+        assert_relative_eq!((point | plane).0, 0.0, epsilon = 0.000001);
+        assert_eq!(plane.try_radius(), None);
+        assert_eq!(plane.try_center(), None);
+    }
+
+    #[test]
+    fn vector_dual() {
+        let vector = Vector::new(1.0, 2.0, 3.0, 4.0, 5.0);
+        assert_eq!(vector.dual().undual(), vector);
+    }
+}
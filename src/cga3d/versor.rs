@@ -0,0 +1,657 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::fmt;
+
+use crate::forward_ref_binop;
+use core::ops::{Mul, Neg};
+
+use num_traits::Float;
+
+use super::{CGA3DOps, bivector::Bivector, quadvector::Quadvector, scalar::Scalar};
+use crate::vga3d;
+
+/// # 3D Conformal Geometric Algebra Versor
+///
+/// The even versors of the algebra, which are the conformal transformations
+/// that preserve orientation.
+/// This includes rotations, translations, dilations and transversions.
+/// $$ V = s + B + Q $$
+/// An element $X$ is transformed by the sandwich product
+/// $$ X' = \widetilde{V} X V $$
+/// which is the same order as the rotation with a [`vga3d::Rotor`].
+///
+/// Versors are composed with the geometric product.
+/// The versor $V_1 V_2$ first applies $V_1$ and then $V_2$.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Versor<F: Float> {
+    pub(super) scalar: Scalar<F>,
+    pub(super) bivector: Bivector<F>,
+    pub(super) quadvector: Quadvector<F>,
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + fmt::Display> fmt::Display for Versor<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Versor {{")?;
+        writeln!(f, "\tscalar: {}", self.scalar())?;
+        writeln!(f, "\tbivector: {}", self.bivector)?;
+        writeln!(f, "\tquadvector: {}", self.quadvector)?;
+        write!(f, "}}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F: Float + defmt::Format> defmt::Format for Versor<F> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Versor {{\n");
+        defmt::write!(f, "\tscalar: {}\n", self.scalar());
+        defmt::write!(f, "\tbivector: {}\n", self.bivector);
+        defmt::write!(f, "\tquadvector: {}\n", self.quadvector);
+        defmt::write!(f, "}}");
+    }
+}
+
+impl<F: Float> Versor<F> {
+    /// Create new versor from its grades
+    pub fn new(scalar: F, bivector: Bivector<F>, quadvector: Quadvector<F>) -> Self {
+        Versor {
+            scalar: Scalar(scalar),
+            bivector,
+            quadvector,
+        }
+    }
+
+    /// This is the identity versor.
+    /// Anything transformed with this will return it self.
+    pub fn identity() -> Self {
+        Versor {
+            scalar: Scalar(F::one()),
+            bivector: Bivector::zero(),
+            quadvector: Quadvector::zero(),
+        }
+    }
+
+    /// Creates the translator that moves elements by $t = (x,y,z)$
+    /// $$ T = 1 + \frac{1}{2} t \wedge \mathrm{e}_\infty $$
+    pub fn from_translation(x: F, y: F, z: F) -> Self {
+        let half = F::one() / (F::one() + F::one());
+        let (x, y, z) = (x * half, y * half, z * half);
+        Versor {
+            scalar: Scalar(F::one()),
+            bivector: Bivector::new(F::zero(), F::zero(), F::zero(), x, y, z, x, y, z, F::zero()),
+            quadvector: Quadvector::zero(),
+        }
+    }
+
+    /// Tries to create the dilator that scales elements around the origin.
+    /// $$ D = \cosh\left( \frac{\ln(f)}{2} \right) + \sinh\left( \frac{\ln(f)}{2} \right) \mathrm{e}_4 \mathrm{e}_5 $$
+    /// The factor must be positive.
+    pub fn try_from_dilation(factor: F) -> Option<Self> {
+        if factor <= F::zero() {
+            return None;
+        }
+        let half = F::one() / (F::one() + F::one());
+        let sqrt = factor.sqrt();
+        Some(Versor {
+            scalar: Scalar((sqrt + sqrt.recip()) * half),
+            bivector: Bivector::new(
+                F::zero(),
+                F::zero(),
+                F::zero(),
+                F::zero(),
+                F::zero(),
+                F::zero(),
+                F::zero(),
+                F::zero(),
+                F::zero(),
+                (sqrt - sqrt.recip()) * half,
+            ),
+            quadvector: Quadvector::zero(),
+        })
+    }
+
+    /// Creates the transversion with $b = (x,y,z)$
+    /// $$ K = 1 + b \wedge \mathrm{e}_0 $$
+    /// The transversion is a translation by $b$ between two inversions in the unit sphere.
+    pub fn from_transversion(x: F, y: F, z: F) -> Self {
+        let half = F::one() / (F::one() + F::one());
+        let (x, y, z) = (x * half, y * half, z * half);
+        Versor {
+            scalar: Scalar(F::one()),
+            bivector: Bivector::new(
+                F::zero(),
+                F::zero(),
+                F::zero(),
+                -x,
+                -y,
+                -z,
+                x,
+                y,
+                z,
+                F::zero(),
+            ),
+            quadvector: Quadvector::zero(),
+        }
+    }
+
+    /// Creates the versor that does the same rotation around the origin as a 3D rotor
+    pub fn from_rotor(rotor: vga3d::Rotor<F>) -> Self {
+        Versor {
+            scalar: Scalar(rotor.scalar()),
+            bivector: Bivector::new(
+                rotor.e12(),
+                rotor.e31(),
+                rotor.e23(),
+                F::zero(),
+                F::zero(),
+                F::zero(),
+                F::zero(),
+                F::zero(),
+                F::zero(),
+                F::zero(),
+            ),
+            quadvector: Quadvector::zero(),
+        }
+    }
+
+    /// Get versor
+    pub fn versor(self) -> Self {
+        self
+    }
+
+    /// Get the scalar grade of the versor
+    pub fn scalar(&self) -> F {
+        self.scalar.0
+    }
+
+    /// Get the bivector grade of the versor
+    pub fn bivector(&self) -> Bivector<F> {
+        self.bivector
+    }
+
+    /// Get the quadvector grade of the versor
+    pub fn quadvector(&self) -> Quadvector<F> {
+        self.quadvector
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2$ part of the bivector grade
+    pub fn e12(&self) -> F {
+        self.bivector.e12()
+    }
+
+    /// Get the $\mathrm{e}_3\mathrm{e}_1$ part of the bivector grade
+    pub fn e31(&self) -> F {
+        self.bivector.e31()
+    }
+
+    /// Get the $\mathrm{e}_2\mathrm{e}_3$ part of the bivector grade
+    pub fn e23(&self) -> F {
+        self.bivector.e23()
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_4$ part of the bivector grade
+    pub fn e14(&self) -> F {
+        self.bivector.e14()
+    }
+
+    /// Get the $\mathrm{e}_2\mathrm{e}_4$ part of the bivector grade
+    pub fn e24(&self) -> F {
+        self.bivector.e24()
+    }
+
+    /// Get the $\mathrm{e}_3\mathrm{e}_4$ part of the bivector grade
+    pub fn e34(&self) -> F {
+        self.bivector.e34()
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_5$ part of the bivector grade
+    pub fn e15(&self) -> F {
+        self.bivector.e15()
+    }
+
+    /// Get the $\mathrm{e}_2\mathrm{e}_5$ part of the bivector grade
+    pub fn e25(&self) -> F {
+        self.bivector.e25()
+    }
+
+    /// Get the $\mathrm{e}_3\mathrm{e}_5$ part of the bivector grade
+    pub fn e35(&self) -> F {
+        self.bivector.e35()
+    }
+
+    /// Get the $\mathrm{e}_4\mathrm{e}_5$ part of the bivector grade
+    pub fn e45(&self) -> F {
+        self.bivector.e45()
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2\mathrm{e}_3\mathrm{e}_4$ part of the quadvector grade
+    pub fn e1234(&self) -> F {
+        self.quadvector.e1234()
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2\mathrm{e}_3\mathrm{e}_5$ part of the quadvector grade
+    pub fn e1235(&self) -> F {
+        self.quadvector.e1235()
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2\mathrm{e}_4\mathrm{e}_5$ part of the quadvector grade
+    pub fn e1245(&self) -> F {
+        self.quadvector.e1245()
+    }
+
+    /// Get the $\mathrm{e}_3\mathrm{e}_1\mathrm{e}_4\mathrm{e}_5$ part of the quadvector grade
+    pub fn e3145(&self) -> F {
+        self.quadvector.e3145()
+    }
+
+    /// Get the $\mathrm{e}_2\mathrm{e}_3\mathrm{e}_4\mathrm{e}_5$ part of the quadvector grade
+    pub fn e2345(&self) -> F {
+        self.quadvector.e2345()
+    }
+}
+
+// Negation
+impl<F: Float> Neg for Versor<F> {
+    type Output = Versor<F>;
+    fn neg(self) -> Versor<F> {
+        Versor {
+            scalar: -self.scalar,
+            bivector: -self.bivector,
+            quadvector: -self.quadvector,
+        }
+    }
+}
+
+// Versor-Versor
+// The product of two even versors is an even versor
+// The result is normalized to remove the numerical drift
+impl<F: Float> Mul for Versor<F> {
+    type Output = Versor<F>;
+    fn mul(self, b: Versor<F>) -> Versor<F> {
+        let versor = Versor::new(
+            self.scalar() * b.scalar()
+                - self.e12() * b.e12()
+                - self.e31() * b.e31()
+                - self.e23() * b.e23()
+                - self.e14() * b.e14()
+                - self.e24() * b.e24()
+                - self.e34() * b.e34()
+                + self.e15() * b.e15()
+                + self.e25() * b.e25()
+                + self.e35() * b.e35()
+                + self.e45() * b.e45()
+                + self.e1234() * b.e1234()
+                - self.e1235() * b.e1235()
+                - self.e1245() * b.e1245()
+                - self.e3145() * b.e3145()
+                - self.e2345() * b.e2345(),
+            Bivector::new(
+                self.scalar() * b.e12() + self.e12() * b.scalar() + self.e31() * b.e23()
+                    - self.e23() * b.e31()
+                    - self.e14() * b.e24()
+                    + self.e24() * b.e14()
+                    - self.e34() * b.e1234()
+                    + self.e15() * b.e25()
+                    - self.e25() * b.e15()
+                    + self.e35() * b.e1235()
+                    + self.e45() * b.e1245()
+                    - self.e1234() * b.e34()
+                    + self.e1235() * b.e35()
+                    + self.e1245() * b.e45()
+                    + self.e3145() * b.e2345()
+                    - self.e2345() * b.e3145(),
+                self.scalar() * b.e31() - self.e12() * b.e23()
+                    + self.e31() * b.scalar()
+                    + self.e23() * b.e12()
+                    + self.e14() * b.e34()
+                    - self.e24() * b.e1234()
+                    - self.e34() * b.e14()
+                    - self.e15() * b.e35()
+                    + self.e25() * b.e1235()
+                    + self.e35() * b.e15()
+                    + self.e45() * b.e3145()
+                    - self.e1234() * b.e24()
+                    + self.e1235() * b.e25()
+                    - self.e1245() * b.e2345()
+                    + self.e3145() * b.e45()
+                    + self.e2345() * b.e1245(),
+                self.scalar() * b.e23() + self.e12() * b.e31() - self.e31() * b.e12()
+                    + self.e23() * b.scalar()
+                    - self.e14() * b.e1234()
+                    - self.e24() * b.e34()
+                    + self.e34() * b.e24()
+                    + self.e15() * b.e1235()
+                    + self.e25() * b.e35()
+                    - self.e35() * b.e25()
+                    + self.e45() * b.e2345()
+                    - self.e1234() * b.e14()
+                    + self.e1235() * b.e15()
+                    + self.e1245() * b.e3145()
+                    - self.e3145() * b.e1245()
+                    + self.e2345() * b.e45(),
+                self.scalar() * b.e14() + self.e12() * b.e24()
+                    - self.e31() * b.e34()
+                    - self.e23() * b.e1234()
+                    + self.e14() * b.scalar()
+                    - self.e24() * b.e12()
+                    + self.e34() * b.e31()
+                    + self.e15() * b.e45()
+                    - self.e25() * b.e1245()
+                    + self.e35() * b.e3145()
+                    - self.e45() * b.e15()
+                    - self.e1234() * b.e23()
+                    - self.e1235() * b.e2345()
+                    - self.e1245() * b.e25()
+                    + self.e3145() * b.e35()
+                    + self.e2345() * b.e1235(),
+                self.scalar() * b.e24() - self.e12() * b.e14() - self.e31() * b.e1234()
+                    + self.e23() * b.e34()
+                    + self.e14() * b.e12()
+                    + self.e24() * b.scalar()
+                    - self.e34() * b.e23()
+                    + self.e15() * b.e1245()
+                    + self.e25() * b.e45()
+                    - self.e35() * b.e2345()
+                    - self.e45() * b.e25()
+                    - self.e1234() * b.e31()
+                    - self.e1235() * b.e3145()
+                    + self.e1245() * b.e15()
+                    + self.e3145() * b.e1235()
+                    - self.e2345() * b.e35(),
+                self.scalar() * b.e34() - self.e12() * b.e1234() + self.e31() * b.e14()
+                    - self.e23() * b.e24()
+                    - self.e14() * b.e31()
+                    + self.e24() * b.e23()
+                    + self.e34() * b.scalar()
+                    - self.e15() * b.e3145()
+                    + self.e25() * b.e2345()
+                    + self.e35() * b.e45()
+                    - self.e45() * b.e35()
+                    - self.e1234() * b.e12()
+                    - self.e1235() * b.e1245()
+                    + self.e1245() * b.e1235()
+                    - self.e3145() * b.e15()
+                    + self.e2345() * b.e25(),
+                self.scalar() * b.e15() + self.e12() * b.e25()
+                    - self.e31() * b.e35()
+                    - self.e23() * b.e1235()
+                    + self.e14() * b.e45()
+                    - self.e24() * b.e1245()
+                    + self.e34() * b.e3145()
+                    + self.e15() * b.scalar()
+                    - self.e25() * b.e12()
+                    + self.e35() * b.e31()
+                    - self.e45() * b.e14()
+                    - self.e1234() * b.e2345()
+                    - self.e1235() * b.e23()
+                    - self.e1245() * b.e24()
+                    + self.e3145() * b.e34()
+                    + self.e2345() * b.e1234(),
+                self.scalar() * b.e25() - self.e12() * b.e15() - self.e31() * b.e1235()
+                    + self.e23() * b.e35()
+                    + self.e14() * b.e1245()
+                    + self.e24() * b.e45()
+                    - self.e34() * b.e2345()
+                    + self.e15() * b.e12()
+                    + self.e25() * b.scalar()
+                    - self.e35() * b.e23()
+                    - self.e45() * b.e24()
+                    - self.e1234() * b.e3145()
+                    - self.e1235() * b.e31()
+                    + self.e1245() * b.e14()
+                    + self.e3145() * b.e1234()
+                    - self.e2345() * b.e34(),
+                self.scalar() * b.e35() - self.e12() * b.e1235() + self.e31() * b.e15()
+                    - self.e23() * b.e25()
+                    - self.e14() * b.e3145()
+                    + self.e24() * b.e2345()
+                    + self.e34() * b.e45()
+                    - self.e15() * b.e31()
+                    + self.e25() * b.e23()
+                    + self.e35() * b.scalar()
+                    - self.e45() * b.e34()
+                    - self.e1234() * b.e1245()
+                    - self.e1235() * b.e12()
+                    + self.e1245() * b.e1234()
+                    - self.e3145() * b.e14()
+                    + self.e2345() * b.e24(),
+                self.scalar() * b.e45()
+                    - self.e12() * b.e1245()
+                    - self.e31() * b.e3145()
+                    - self.e23() * b.e2345()
+                    - self.e14() * b.e15()
+                    - self.e24() * b.e25()
+                    - self.e34() * b.e35()
+                    + self.e15() * b.e14()
+                    + self.e25() * b.e24()
+                    + self.e35() * b.e34()
+                    + self.e45() * b.scalar()
+                    + self.e1234() * b.e1235()
+                    - self.e1235() * b.e1234()
+                    - self.e1245() * b.e12()
+                    - self.e3145() * b.e31()
+                    - self.e2345() * b.e23(),
+            ),
+            Quadvector::new(
+                self.scalar() * b.e1234()
+                    + self.e12() * b.e34()
+                    + self.e31() * b.e24()
+                    + self.e23() * b.e14()
+                    + self.e14() * b.e23()
+                    + self.e24() * b.e31()
+                    + self.e34() * b.e12()
+                    + self.e15() * b.e2345()
+                    + self.e25() * b.e3145()
+                    + self.e35() * b.e1245()
+                    - self.e45() * b.e1235()
+                    + self.e1234() * b.scalar()
+                    + self.e1235() * b.e45()
+                    - self.e1245() * b.e35()
+                    - self.e3145() * b.e25()
+                    - self.e2345() * b.e15(),
+                self.scalar() * b.e1235()
+                    + self.e12() * b.e35()
+                    + self.e31() * b.e25()
+                    + self.e23() * b.e15()
+                    + self.e14() * b.e2345()
+                    + self.e24() * b.e3145()
+                    + self.e34() * b.e1245()
+                    + self.e15() * b.e23()
+                    + self.e25() * b.e31()
+                    + self.e35() * b.e12()
+                    - self.e45() * b.e1234()
+                    + self.e1234() * b.e45()
+                    + self.e1235() * b.scalar()
+                    - self.e1245() * b.e34()
+                    - self.e3145() * b.e24()
+                    - self.e2345() * b.e14(),
+                self.scalar() * b.e1245() + self.e12() * b.e45() + self.e31() * b.e2345()
+                    - self.e23() * b.e3145()
+                    - self.e14() * b.e25()
+                    + self.e24() * b.e15()
+                    - self.e34() * b.e1235()
+                    + self.e15() * b.e24()
+                    - self.e25() * b.e14()
+                    + self.e35() * b.e1234()
+                    + self.e45() * b.e12()
+                    - self.e1234() * b.e35()
+                    + self.e1235() * b.e34()
+                    + self.e1245() * b.scalar()
+                    + self.e3145() * b.e23()
+                    - self.e2345() * b.e31(),
+                self.scalar() * b.e3145() - self.e12() * b.e2345()
+                    + self.e31() * b.e45()
+                    + self.e23() * b.e1245()
+                    + self.e14() * b.e35()
+                    - self.e24() * b.e1235()
+                    - self.e34() * b.e15()
+                    - self.e15() * b.e34()
+                    + self.e25() * b.e1234()
+                    + self.e35() * b.e14()
+                    + self.e45() * b.e31()
+                    - self.e1234() * b.e25()
+                    + self.e1235() * b.e24()
+                    - self.e1245() * b.e23()
+                    + self.e3145() * b.scalar()
+                    + self.e2345() * b.e12(),
+                self.scalar() * b.e2345() + self.e12() * b.e3145() - self.e31() * b.e1245()
+                    + self.e23() * b.e45()
+                    - self.e14() * b.e1235()
+                    - self.e24() * b.e35()
+                    + self.e34() * b.e25()
+                    + self.e15() * b.e1234()
+                    + self.e25() * b.e34()
+                    - self.e35() * b.e24()
+                    + self.e45() * b.e23()
+                    - self.e1234() * b.e15()
+                    + self.e1235() * b.e14()
+                    + self.e1245() * b.e31()
+                    - self.e3145() * b.e12()
+                    + self.e2345() * b.scalar(),
+            ),
+        );
+        versor.try_normalize().unwrap_or(versor)
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Versor<F>, Versor<F>);
+
+impl<F: Float> CGA3DOps<F> for Versor<F> {
+    fn reverse(self) -> Self {
+        Versor {
+            scalar: self.scalar,
+            bivector: -self.bivector,
+            quadvector: self.quadvector,
+        }
+    }
+
+    fn conjugate(self) -> Self {
+        Versor {
+            scalar: self.scalar,
+            bivector: -self.bivector,
+            quadvector: self.quadvector,
+        }
+    }
+
+    fn involute(self) -> Self {
+        self
+    }
+
+    // \[\|V\|^2=\left< V \widetilde{V} \right>_0\]
+    fn norm(self) -> Scalar<F> {
+        Scalar(
+            (self.scalar() * self.scalar()
+                + self.e12() * self.e12()
+                + self.e31() * self.e31()
+                + self.e23() * self.e23()
+                + self.e14() * self.e14()
+                + self.e24() * self.e24()
+                + self.e34() * self.e34()
+                - self.e15() * self.e15()
+                - self.e25() * self.e25()
+                - self.e35() * self.e35()
+                - self.e45() * self.e45()
+                + self.e1234() * self.e1234()
+                - self.e1235() * self.e1235()
+                - self.e1245() * self.e1245()
+                - self.e3145() * self.e3145()
+                - self.e2345() * self.e2345())
+            .abs()
+            .sqrt(),
+        )
+    }
+
+    fn try_normalize(self) -> Option<Self> {
+        self.norm().try_inverse().map(|norm_inverse| {
+            let norm_inverse = norm_inverse.0;
+            Versor {
+                scalar: Scalar(self.scalar() * norm_inverse),
+                bivector: Bivector::new(
+                    self.e12() * norm_inverse,
+                    self.e31() * norm_inverse,
+                    self.e23() * norm_inverse,
+                    self.e14() * norm_inverse,
+                    self.e24() * norm_inverse,
+                    self.e34() * norm_inverse,
+                    self.e15() * norm_inverse,
+                    self.e25() * norm_inverse,
+                    self.e35() * norm_inverse,
+                    self.e45() * norm_inverse,
+                ),
+                quadvector: Quadvector::new(
+                    self.e1234() * norm_inverse,
+                    self.e1235() * norm_inverse,
+                    self.e1245() * norm_inverse,
+                    self.e3145() * norm_inverse,
+                    self.e2345() * norm_inverse,
+                ),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod versor_ops {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn versor_translation_composition() {
+        let res =
+            Versor::from_translation(1.0, 2.0, 3.0) * Versor::from_translation(-4.0, 1.0, 0.5);
+        let expected = Versor::from_translation(-3.0, 3.0, 3.5);
+        assert_relative_eq!(res.scalar(), expected.scalar(), max_relative = 0.000001);
+        for (res, expected) in [
+            (res.e14(), expected.e14()),
+            (res.e24(), expected.e24()),
+            (res.e34(), expected.e34()),
+            (res.e15(), expected.e15()),
+            (res.e25(), expected.e25()),
+            (res.e35(), expected.e35()),
+        ] {
+            assert_relative_eq!(res, expected, max_relative = 0.000001);
+        }
+    }
+
+    #[test]
+    fn versor_dilation_composition() {
+        let res = Versor::try_from_dilation(2.0).unwrap() * Versor::try_from_dilation(3.0).unwrap();
+        let expected = Versor::try_from_dilation(6.0).unwrap();
+        assert_relative_eq!(res.scalar(), expected.scalar(), max_relative = 0.000001);
+        assert_relative_eq!(res.e45(), expected.e45(), max_relative = 0.000001);
+        assert_eq!(Versor::try_from_dilation(-1.0), None);
+    }
+
+    #[test]
+    fn versor_norm() {
+        for versor in [
+            Versor::from_translation(1.0, 2.0, 3.0),
+            Versor::try_from_dilation(5.0).unwrap(),
+            Versor::from_transversion(1.0, -2.0, 0.5),
+        ] {
+            assert_relative_eq!(versor.norm().0, 1.0, max_relative = 0.000001);
+        }
+    }
+}
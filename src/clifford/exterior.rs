@@ -0,0 +1,77 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{multivector::Multivector, signature::Signature};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::BitXor;
+
+// Exterior Product
+// \[ A \wedge B \]
+impl<F: Float, S: Signature> BitXor for Multivector<F, S> {
+    type Output = Multivector<F, S>;
+    fn bitxor(self, b: Multivector<F, S>) -> Multivector<F, S> {
+        self.product(b, |a, b| a & b == 0)
+    }
+}
+forward_ref_binop!(impl<F: Float, S: Signature> BitXor, bitxor for Multivector<F, S>, Multivector<F, S>);
+
+// Test
+#[cfg(test)]
+mod exterior_product {
+    use super::*;
+    use crate::clifford::Cl300;
+    use crate::vga3d;
+    use approx::assert_relative_eq;
+
+    // The same multivector in vga3d and in the generic algebra
+    fn multivectors(values: [f64; 8]) -> (vga3d::Multivector<f64>, Multivector<f64, Cl300>) {
+        let [s, e1, e2, e3, e12, e31, e23, e123] = values;
+        (
+            vga3d::Multivector::new_components(s, e1, e2, e3, e12, e31, e23, e123),
+            Multivector::from_coefficients([s, e1, e2, e12, e3, -e31, e23, e123]),
+        )
+    }
+
+    fn assert_multivector_eq(res: Multivector<f64, Cl300>, expected: vga3d::Multivector<f64>) {
+        let expected = [
+            expected.scalar(),
+            expected.e1(),
+            expected.e2(),
+            expected.e12(),
+            expected.e3(),
+            -expected.e31(),
+            expected.e23(),
+            expected.e123(),
+        ];
+        for (res, expected) in res.coefficients().iter().zip(expected) {
+            assert_relative_eq!(*res, expected, max_relative = 0.000001);
+        }
+    }
+
+    #[test]
+    fn multivector_multivector() {
+        let (a, generic_a) = multivectors([1.0, 2.0, -3.0, 4.0, -1.0, 5.0, 2.0, -2.0]);
+        let (b, generic_b) = multivectors([-2.0, 1.0, 3.0, -4.0, 2.0, 1.0, -3.0, 4.0]);
+        assert_multivector_eq(generic_a ^ generic_b, a ^ b);
+        assert_multivector_eq(generic_b ^ generic_a, b ^ a);
+    }
+}
@@ -0,0 +1,141 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{multivector::Multivector, signature::Signature};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::Mul;
+
+// Geometric Product
+// \[ AB \]
+impl<F: Float, S: Signature> Mul for Multivector<F, S> {
+    type Output = Multivector<F, S>;
+    fn mul(self, b: Multivector<F, S>) -> Multivector<F, S> {
+        self.product(b, |_, _| true)
+    }
+}
+forward_ref_binop!(impl<F: Float, S: Signature> Mul, mul for Multivector<F, S>, Multivector<F, S>);
+
+// Test
+#[cfg(test)]
+mod geometric_product {
+    use super::*;
+    use crate::clifford::{Cl020, Cl300};
+    use crate::vga3d;
+    use approx::assert_relative_eq;
+
+    // The same multivector in vga3d and in the generic algebra
+    fn multivectors(values: [f64; 8]) -> (vga3d::Multivector<f64>, Multivector<f64, Cl300>) {
+        let [s, e1, e2, e3, e12, e31, e23, e123] = values;
+        (
+            vga3d::Multivector::new_components(s, e1, e2, e3, e12, e31, e23, e123),
+            Multivector::from_coefficients([s, e1, e2, e12, e3, -e31, e23, e123]),
+        )
+    }
+
+    // The generic multivector of a vga3d multivector
+    fn generic(m: vga3d::Multivector<f64>) -> Multivector<f64, Cl300> {
+        multivectors([
+            m.scalar(),
+            m.e1(),
+            m.e2(),
+            m.e3(),
+            m.e12(),
+            m.e31(),
+            m.e23(),
+            m.e123(),
+        ])
+        .1
+    }
+
+    fn assert_multivector_eq(res: Multivector<f64, Cl300>, expected: vga3d::Multivector<f64>) {
+        let expected = [
+            expected.scalar(),
+            expected.e1(),
+            expected.e2(),
+            expected.e12(),
+            expected.e3(),
+            -expected.e31(),
+            expected.e23(),
+            expected.e123(),
+        ];
+        for (res, expected) in res.coefficients().iter().zip(expected) {
+            assert_relative_eq!(*res, expected, max_relative = 0.000001);
+        }
+    }
+
+    #[test]
+    fn multivector_multivector() {
+        let (a, generic_a) = multivectors([1.0, 2.0, -3.0, 4.0, -1.0, 5.0, 2.0, -2.0]);
+        let (b, generic_b) = multivectors([-2.0, 1.0, 3.0, -4.0, 2.0, 1.0, -3.0, 4.0]);
+        assert_multivector_eq(generic_a * generic_b, a * b);
+        assert_multivector_eq(generic_b * generic_a, b * a);
+    }
+
+    #[test]
+    fn multivector_vector() {
+        let (a, generic_a) = multivectors([1.0, 2.0, -3.0, 4.0, -1.0, 5.0, 2.0, -2.0]);
+        let b = vga3d::Vector::new(1.0, 3.0, -4.0);
+        let generic_b = generic(b.into());
+        assert_multivector_eq(generic_a * generic_b, a * b);
+        assert_multivector_eq(generic_b * generic_a, b * a);
+    }
+
+    #[test]
+    fn multivector_bivector() {
+        let (a, generic_a) = multivectors([1.0, 2.0, -3.0, 4.0, -1.0, 5.0, 2.0, -2.0]);
+        let b = vga3d::Bivector::new(2.0, 1.0, -3.0);
+        let generic_b = generic(b.into());
+        assert_multivector_eq(generic_a * generic_b, a * b);
+        assert_multivector_eq(generic_b * generic_a, b * a);
+    }
+
+    #[test]
+    fn multivector_trivector() {
+        let (a, generic_a) = multivectors([1.0, 2.0, -3.0, 4.0, -1.0, 5.0, 2.0, -2.0]);
+        let b = vga3d::Trivector::new(4.0);
+        let generic_b = generic(b.into());
+        assert_multivector_eq(generic_a * generic_b, a * b);
+        assert_multivector_eq(generic_b * generic_a, b * a);
+    }
+
+    #[test]
+    fn multivector_rotor() {
+        let (a, generic_a) = multivectors([1.0, 2.0, -3.0, 4.0, -1.0, 5.0, 2.0, -2.0]);
+        let b = vga3d::Bivector::new(0.3, -0.2, 0.5).exp();
+        let generic_b = generic(b.into());
+        assert_multivector_eq(generic_a * generic_b, a * b);
+        assert_multivector_eq(generic_b * generic_a, b * a);
+    }
+
+    #[test]
+    fn quaternion_product() {
+        let i = Multivector::<f64, Cl020>::try_from_blade(0b01, 1.0).unwrap();
+        let j = Multivector::<f64, Cl020>::try_from_blade(0b10, 1.0).unwrap();
+        let k = Multivector::<f64, Cl020>::try_from_blade(0b11, 1.0).unwrap();
+        let minus_one = Multivector::from_scalar(-1.0);
+        assert_eq!(i * i, minus_one);
+        assert_eq!(j * j, minus_one);
+        assert_eq!(k * k, minus_one);
+        assert_eq!(i * j, k);
+        assert_eq!(i * j * k, minus_one);
+    }
+}
@@ -0,0 +1,36 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+// The products are implemented through the bit operators
+#![allow(clippy::suspicious_arithmetic_impl)]
+
+mod signature;
+pub use signature::{
+    Cl010, Cl020, Cl030, Cl130, Cl200, Cl201, Cl300, Cl301, Cl410, Signature, grade,
+};
+//
+mod multivector;
+pub use multivector::Multivector;
+
+// Interactions
+mod addition;
+mod exterior;
+mod geometric;
+mod inner;
+mod regressive;
+mod subtraction;
@@ -0,0 +1,405 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::fmt;
+
+use core::marker::PhantomData;
+use core::ops::Neg;
+
+use num_traits::Float;
+
+use super::signature::{Signature, grade};
+
+/// # Generic Clifford Algebra Multivector
+/// A multivector of $\text{Cl}(p,q,r)$ where the signature is given by the type `S`.
+/// $$ A = \sum_{k} a_k \mathrm{e}_k $$
+/// The coefficient of every basis blade is stored with the bitmask of the blade as the index,
+/// so $a_{0b011}$ is the $\mathrm{e}_1\mathrm{e}_2$ part in $\text{Cl}(3,0,0)$.
+///
+/// All products are calculated from the multiplication table of the signature,
+/// see [`Signature::blade_product`].
+/// This makes it slower than the dedicated algebras, but any signature can be used.
+pub struct Multivector<F: Float, S: Signature> {
+    pub(super) coefficients: S::Coefficients<F>,
+    signature: PhantomData<S>,
+}
+
+impl<F: Float, S: Signature> Clone for Multivector<F, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: Float, S: Signature> Copy for Multivector<F, S> {}
+
+impl<F: Float, S: Signature> PartialEq for Multivector<F, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.coefficients() == other.coefficients()
+    }
+}
+
+impl<F: Float, S: Signature> Default for Multivector<F, S> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<F: Float + core::fmt::Debug, S: Signature> core::fmt::Debug for Multivector<F, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("Multivector")
+            .field("p", &S::P)
+            .field("q", &S::Q)
+            .field("r", &S::R)
+            .field("coefficients", &self.coefficients())
+            .finish()
+    }
+}
+
+// The basis vectors are named from zero when the algebra is degenerate
+#[cfg(any(feature = "std", feature = "defmt"))]
+fn first_index<S: Signature>() -> usize {
+    if S::R > 0 { 0 } else { 1 }
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + fmt::Display, S: Signature> fmt::Display for Multivector<F, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Multivector {{")?;
+        write!(f, " {}", self.scalar())?;
+
+        // Add the appropriate sign to the remaining components
+        for (blade, &value) in self.coefficients().iter().enumerate().skip(1) {
+            if value >= F::zero() {
+                write!(f, " + {}e", value)?;
+            } else {
+                write!(f, " - {}e", value.abs())?;
+            }
+            for index in (0..S::DIMENSION).filter(|index| blade & (1 << index) != 0) {
+                write!(f, "{}", index + first_index::<S>())?;
+            }
+        }
+        write!(f, " }}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F: Float + defmt::Format, S: Signature> defmt::Format for Multivector<F, S> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Multivector {{");
+        defmt::write!(f, " {}", self.scalar());
+
+        // Add the appropriate sign to the remaining components
+        for (blade, &value) in self.coefficients().iter().enumerate().skip(1) {
+            if value >= F::zero() {
+                defmt::write!(f, " + {}e", value);
+            } else {
+                defmt::write!(f, " - {}e", value.abs());
+            }
+            for index in (0..S::DIMENSION).filter(|index| blade & (1 << index) != 0) {
+                defmt::write!(f, "{}", index + first_index::<S>());
+            }
+        }
+        defmt::write!(f, " }}");
+    }
+}
+
+impl<F: Float, S: Signature> Multivector<F, S> {
+    /// The zero multivector
+    pub fn zero() -> Self {
+        Self::from_coefficients(S::zero())
+    }
+
+    /// Create new multivector from the coefficients of all basis blades.
+    /// The index of a coefficient is the bitmask of its blade.
+    pub fn from_coefficients(coefficients: S::Coefficients<F>) -> Self {
+        Self {
+            coefficients,
+            signature: PhantomData,
+        }
+    }
+
+    /// Create new multivector with only a scalar part
+    pub fn from_scalar(scalar: F) -> Self {
+        let mut res = Self::zero();
+        res.coefficients.as_mut()[0] = scalar;
+        res
+    }
+
+    /// Tries to create a multivector from a single basis blade given as a bitmask.
+    /// The blade must be part of the algebra.
+    pub fn try_from_blade(blade: usize, value: F) -> Option<Self> {
+        let mut res = Self::zero();
+        *res.coefficients.as_mut().get_mut(blade)? = value;
+        Some(res)
+    }
+
+    /// Tries to create a vector from its components.
+    /// There must be one component for every basis vector.
+    pub fn try_from_vector(components: &[F]) -> Option<Self> {
+        if components.len() != S::DIMENSION {
+            return None;
+        }
+        let mut res = Self::zero();
+        for (index, &value) in components.iter().enumerate() {
+            res.coefficients.as_mut()[1 << index] = value;
+        }
+        Some(res)
+    }
+
+    /// Get multivector
+    pub fn multivector(self) -> Self {
+        self
+    }
+
+    /// Get the coefficients of all basis blades
+    pub fn coefficients(&self) -> &[F] {
+        self.coefficients.as_ref()
+    }
+
+    /// Tries to get the coefficient of a basis blade given as a bitmask
+    pub fn try_blade(&self, blade: usize) -> Option<F> {
+        self.coefficients().get(blade).copied()
+    }
+
+    /// Get the scalar part
+    pub fn scalar(&self) -> F {
+        self.coefficients()[0]
+    }
+
+    /// Get the pseudoscalar part
+    pub fn pseudoscalar(&self) -> F {
+        self.coefficients()[S::BLADES - 1]
+    }
+
+    /// # Grade projection
+    /// $$ \left< A \right>_k $$
+    pub fn grade(self, k: usize) -> Self {
+        self.map_blades(|blade, value| if grade(blade) == k { value } else { F::zero() })
+    }
+
+    /// Scale every coefficient
+    pub fn scale(self, scalar: F) -> Self {
+        self.map_blades(|_, value| value * scalar)
+    }
+
+    /// # Dual
+    /// The right complement, which does not depend on the metric, so it works for degenerate algebras
+    /// $$ A \wedge A^* = \|A\|^2 I $$
+    pub fn dual(self) -> Self {
+        let mut res = Self::zero();
+        for (blade, &value) in self.coefficients().iter().enumerate() {
+            let (sign, complement) = S::right_complement(blade);
+            res.coefficients.as_mut()[complement] = value * F::from(sign).unwrap();
+        }
+        res
+    }
+
+    /// # Undual
+    /// The left complement, which is the inverse of the dual
+    pub fn undual(self) -> Self {
+        let mut res = Self::zero();
+        for (blade, &value) in self.coefficients().iter().enumerate() {
+            let (sign, complement) = S::left_complement(blade);
+            res.coefficients.as_mut()[complement] = value * F::from(sign).unwrap();
+        }
+        res
+    }
+
+    /// Reverse $\widetilde{A}$
+    pub fn reverse(self) -> Self {
+        self.map_grades(|k| k * k.saturating_sub(1) / 2 % 2 == 1)
+    }
+
+    /// Clifford conjugate $\bar{A}$
+    pub fn conjugate(self) -> Self {
+        self.map_grades(|k| k * (k + 1) / 2 % 2 == 1)
+    }
+
+    /// Grade involution $\hat{A}$
+    pub fn involute(self) -> Self {
+        self.map_grades(|k| k % 2 == 1)
+    }
+
+    /// The norm $\|A\| = \sqrt{\left| \left< A \widetilde{A} \right>_0 \right|}$
+    pub fn norm(self) -> F {
+        (self * self.reverse()).scalar().abs().sqrt()
+    }
+
+    /// Scale the multivector to have a norm of one.
+    /// Null multivectors can not be normalized.
+    pub fn try_normalize(self) -> Option<Self> {
+        let norm = self.norm();
+        if norm.is_zero() {
+            None
+        } else {
+            Some(self.scale(norm.recip()))
+        }
+    }
+
+    /// # Inverse
+    /// $$ A^{-1} = \frac{\widetilde{A}}{A \widetilde{A}} $$
+    /// This is only possible when $A \widetilde{A}$ is a non zero scalar,
+    /// which is true for blades and versors.
+    pub fn try_inverse(self) -> Option<Self> {
+        let reverse = self.reverse();
+        let square = self * reverse;
+        let scalar = square.scalar();
+        let is_scalar = square.coefficients()[1..]
+            .iter()
+            .all(|value| value.is_zero());
+        if scalar.is_zero() || !is_scalar {
+            None
+        } else {
+            Some(reverse.scale(scalar.recip()))
+        }
+    }
+
+    // Apply a function to every coefficient together with its blade
+    fn map_blades(self, function: impl Fn(usize, F) -> F) -> Self {
+        let mut res = self;
+        for (blade, value) in res.coefficients.as_mut().iter_mut().enumerate() {
+            *value = function(blade, *value);
+        }
+        res
+    }
+
+    // Negate the grades where the function is true
+    fn map_grades(self, negate: impl Fn(usize) -> bool) -> Self {
+        self.map_blades(|blade, value| if negate(grade(blade)) { -value } else { value })
+    }
+
+    // Sum the products of all pairs of blades that are kept by the filter
+    pub(super) fn product(self, b: Self, keep: impl Fn(usize, usize) -> bool) -> Self {
+        let mut res = Self::zero();
+        for (blade_a, &a) in self.coefficients().iter().enumerate() {
+            if a.is_zero() {
+                continue;
+            }
+            for (blade_b, &b) in b.coefficients().iter().enumerate() {
+                if b.is_zero() || !keep(blade_a, blade_b) {
+                    continue;
+                }
+                let (sign, blade) = S::blade_product(blade_a, blade_b);
+                let value = &mut res.coefficients.as_mut()[blade];
+                match sign {
+                    1 => *value = *value + a * b,
+                    -1 => *value = *value - a * b,
+                    _ => {}
+                }
+            }
+        }
+        res
+    }
+}
+
+// Negation
+impl<F: Float, S: Signature> Neg for Multivector<F, S> {
+    type Output = Multivector<F, S>;
+    fn neg(self) -> Multivector<F, S> {
+        self.map_blades(|_, value| -value)
+    }
+}
+
+#[cfg(test)]
+mod multivector_ops {
+    use super::*;
+    use crate::clifford::{Cl020, Cl130, Cl300, Cl301};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn multivector_construction() {
+        let vector = Multivector::<f64, Cl300>::try_from_vector(&[1.0, 2.0, 3.0]).unwrap();
+        assert_eq!(
+            vector.coefficients(),
+            &[0.0, 1.0, 2.0, 0.0, 3.0, 0.0, 0.0, 0.0]
+        );
+        assert_eq!(vector.try_blade(0b100), Some(3.0));
+        assert_eq!(vector.try_blade(8), None);
+        assert_eq!(
+            Multivector::<f64, Cl300>::try_from_vector(&[1.0, 2.0]),
+            None
+        );
+        assert_eq!(Multivector::<f64, Cl300>::try_from_blade(8, 1.0), None);
+    }
+
+    #[test]
+    fn multivector_grades() {
+        let mut coefficients = [0.0; 8];
+        for (blade, value) in coefficients.iter_mut().enumerate() {
+            *value = blade as f64 + 1.0;
+        }
+        let mvec = Multivector::<f64, Cl300>::from_coefficients(coefficients);
+        assert_eq!(
+            mvec.grade(2).coefficients(),
+            &[0.0, 0.0, 0.0, 4.0, 0.0, 6.0, 7.0, 0.0]
+        );
+        assert_eq!(
+            mvec.reverse().coefficients(),
+            &[1.0, 2.0, 3.0, -4.0, 5.0, -6.0, -7.0, -8.0]
+        );
+        assert_eq!(
+            mvec.conjugate().coefficients(),
+            &[1.0, -2.0, -3.0, -4.0, -5.0, -6.0, -7.0, 8.0]
+        );
+        assert_eq!(
+            mvec.involute().coefficients(),
+            &[1.0, -2.0, -3.0, 4.0, -5.0, 6.0, 7.0, -8.0]
+        );
+        assert_eq!(mvec.dual().undual(), mvec);
+    }
+
+    #[test]
+    fn multivector_norm() {
+        // A timelike vector in the spacetime algebra
+        let vector = Multivector::<f64, Cl130>::try_from_vector(&[5.0, 1.0, 2.0, 2.0]).unwrap();
+        assert_relative_eq!(vector.norm(), 4.0, max_relative = 0.000001);
+        // A null vector
+        let vector = Multivector::<f64, Cl130>::try_from_vector(&[3.0, 0.0, 3.0, 0.0]).unwrap();
+        assert_eq!(vector.try_normalize(), None);
+        // The ideal point has no Euclidean norm
+        let point = Multivector::<f64, Cl301>::try_from_blade(0b1101, 1.0).unwrap();
+        assert_eq!(point.norm(), 0.0);
+    }
+
+    #[test]
+    fn multivector_inverse() {
+        // 1 + 2e12 + 3e13 + 4e23
+        let rotor =
+            Multivector::<f64, Cl300>::from_coefficients([1.0, 0.0, 0.0, 2.0, 0.0, 3.0, 4.0, 0.0]);
+        let res = rotor * rotor.try_inverse().unwrap();
+        assert_relative_eq!(res.scalar(), 1.0, max_relative = 0.000001);
+        for &value in &res.coefficients()[1..] {
+            assert_relative_eq!(value, 0.0, epsilon = 0.000001);
+        }
+        // A vector in the quaternion algebra
+        let vector = Multivector::<f64, Cl020>::try_from_vector(&[2.0, 1.0]).unwrap();
+        let res = vector * vector.try_inverse().unwrap();
+        assert_eq!(res, Multivector::from_scalar(1.0));
+        // 1 + e1 is not invertible in the same way
+        let mvec =
+            Multivector::<f64, Cl300>::from_coefficients([1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(mvec.try_inverse(), None);
+    }
+}
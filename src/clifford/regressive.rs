@@ -0,0 +1,406 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{multivector::Multivector, signature::Signature};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::BitAnd;
+
+/// # Regressive Product
+/// $$ A \vee B = \left( A^* \wedge B^* \right)_* $$
+/// The complements do not depend on the metric, but they are not the duals used by the specialized modules.
+/// The product agrees with `&` of the specialized modules up to the sign, which is opposite for the grade pairs
+/// - [`crate::vga2d`]: vector and vector
+/// - [`crate::vga3d`]: bivector and bivector
+/// - [`crate::pga3d`]: vector and trivector, bivector and trivector in both orders
+/// - [`crate::sta`]: vector and trivector in both orders, trivector and trivector
+/// - [`crate::cga3d`]: bivector and quadvector in both orders, quadvector and quadvector
+///
+/// The signs agree for all grade pairs of [`crate::pga2d`].
+impl<F: Float, S: Signature> BitAnd for Multivector<F, S> {
+    type Output = Multivector<F, S>;
+    fn bitand(self, b: Multivector<F, S>) -> Multivector<F, S> {
+        (self.dual() ^ b.dual()).undual()
+    }
+}
+forward_ref_binop!(impl<F: Float, S: Signature> BitAnd, bitand for Multivector<F, S>, Multivector<F, S>);
+
+// Test
+#[cfg(test)]
+mod regressive_product {
+    use super::*;
+    use crate::clifford::{Cl130, Cl200, Cl300, Cl301, Cl410, Signature};
+    use crate::{cga3d, pga3d, sta, vga2d, vga3d};
+    use approx::assert_relative_eq;
+
+    // The generic multivector from the components named by their indices, like e031
+    // The first index is subtracted, so it is the first bit of the blade
+    fn generic<S: Signature>(components: &[(&str, f64)], first: u32) -> Multivector<f64, S> {
+        let mut res = Multivector::zero();
+        for (name, value) in components {
+            let indices = || {
+                name.chars()
+                    .skip(1)
+                    .map(|c| c.to_digit(10).unwrap() - first)
+            };
+            let blade = indices().fold(0, |blade, i| blade | 1 << i);
+            // The sign from sorting the indices
+            let swaps: usize = indices()
+                .enumerate()
+                .map(|(k, i)| indices().skip(k + 1).filter(|&j| j < i).count())
+                .sum();
+            let sign = if swaps.is_multiple_of(2) { 1.0 } else { -1.0 };
+            res = res + Multivector::try_from_blade(blade, sign * value).unwrap();
+        }
+        res
+    }
+
+    fn assert_multivector_eq<S: Signature>(
+        res: Multivector<f64, S>,
+        expected: Multivector<f64, S>,
+    ) {
+        for (res, expected) in res.coefficients().iter().zip(expected.coefficients()) {
+            assert_relative_eq!(*res, *expected, epsilon = 0.000001);
+        }
+    }
+
+    fn vga2d_generic(m: vga2d::Multivector<f64>) -> Multivector<f64, Cl200> {
+        generic(
+            &[
+                ("e", m.scalar()),
+                ("e1", m.e1()),
+                ("e2", m.e2()),
+                ("e12", m.e12()),
+            ],
+            1,
+        )
+    }
+
+    fn vga3d_generic(m: vga3d::Multivector<f64>) -> Multivector<f64, Cl300> {
+        generic(
+            &[
+                ("e", m.scalar()),
+                ("e1", m.e1()),
+                ("e2", m.e2()),
+                ("e3", m.e3()),
+                ("e12", m.e12()),
+                ("e31", m.e31()),
+                ("e23", m.e23()),
+                ("e123", m.e123()),
+            ],
+            1,
+        )
+    }
+
+    fn pga3d_generic(m: pga3d::Multivector<f64>) -> Multivector<f64, Cl301> {
+        generic(
+            &[
+                ("e", m.scalar()),
+                ("e0", m.e0()),
+                ("e1", m.e1()),
+                ("e2", m.e2()),
+                ("e3", m.e3()),
+                ("e01", m.e01()),
+                ("e02", m.e02()),
+                ("e03", m.e03()),
+                ("e12", m.e12()),
+                ("e31", m.e31()),
+                ("e23", m.e23()),
+                ("e032", m.e032()),
+                ("e013", m.e013()),
+                ("e021", m.e021()),
+                ("e123", m.e123()),
+                ("e0123", m.e0123()),
+            ],
+            0,
+        )
+    }
+
+    fn sta_generic(m: sta::Multivector<f64>) -> Multivector<f64, Cl130> {
+        generic(
+            &[
+                ("e", m.scalar()),
+                ("e0", m.e0()),
+                ("e1", m.e1()),
+                ("e2", m.e2()),
+                ("e3", m.e3()),
+                ("e10", m.e10()),
+                ("e20", m.e20()),
+                ("e30", m.e30()),
+                ("e32", m.e32()),
+                ("e13", m.e13()),
+                ("e21", m.e21()),
+                ("e123", m.e123()),
+                ("e023", m.e023()),
+                ("e031", m.e031()),
+                ("e012", m.e012()),
+                ("e0123", m.e0123()),
+            ],
+            0,
+        )
+    }
+
+    fn cga3d_generic(m: cga3d::Multivector<f64>) -> Multivector<f64, Cl410> {
+        generic(
+            &[
+                ("e", m.scalar()),
+                ("e1", m.e1()),
+                ("e2", m.e2()),
+                ("e3", m.e3()),
+                ("e4", m.e4()),
+                ("e5", m.e5()),
+                ("e12", m.e12()),
+                ("e31", m.e31()),
+                ("e23", m.e23()),
+                ("e14", m.e14()),
+                ("e24", m.e24()),
+                ("e34", m.e34()),
+                ("e15", m.e15()),
+                ("e25", m.e25()),
+                ("e35", m.e35()),
+                ("e45", m.e45()),
+                ("e123", m.e123()),
+                ("e124", m.e124()),
+                ("e314", m.e314()),
+                ("e234", m.e234()),
+                ("e125", m.e125()),
+                ("e315", m.e315()),
+                ("e235", m.e235()),
+                ("e145", m.e145()),
+                ("e245", m.e245()),
+                ("e345", m.e345()),
+                ("e1234", m.e1234()),
+                ("e1235", m.e1235()),
+                ("e1245", m.e1245()),
+                ("e3145", m.e3145()),
+                ("e2345", m.e2345()),
+                ("e12345", m.e12345()),
+            ],
+            1,
+        )
+    }
+
+    // The point in pga3d and in the generic algebra
+    fn points(x: f64, y: f64, z: f64) -> (pga3d::Point<f64>, Multivector<f64, Cl301>) {
+        let mut coefficients = [0.0; 16];
+        // e032 = -e023, e013, e021 = -e012, e123
+        coefficients[0b1101] = -x;
+        coefficients[0b1011] = y;
+        coefficients[0b0111] = -z;
+        coefficients[0b1110] = 1.0;
+        (
+            pga3d::Point::new(x, y, z),
+            Multivector::from_coefficients(coefficients),
+        )
+    }
+
+    #[test]
+    fn point_point() {
+        let (a, generic_a) = points(1.0, 2.0, -3.0);
+        let (b, generic_b) = points(-2.0, 1.0, 4.0);
+        let res = generic_a & generic_b;
+        let expected = a & b;
+        // e01, e02, e03, e12, -e13, e23
+        for (blade, expected) in [
+            (0b0011, expected.e01()),
+            (0b0101, expected.e02()),
+            (0b1001, expected.e03()),
+            (0b0110, expected.e12()),
+            (0b1010, -expected.e31()),
+            (0b1100, expected.e23()),
+        ] {
+            assert_relative_eq!(
+                res.try_blade(blade).unwrap(),
+                expected,
+                max_relative = 0.000001
+            );
+        }
+        assert_eq!(res.grade(2), res);
+    }
+
+    #[test]
+    fn vga2d_multivector_multivector() {
+        let a = vga2d::Multivector::new_components(0.5, 1.0, -2.0, 1.5);
+        let b = vga2d::Multivector::new_components(-1.0, 3.0, 0.5, 2.0);
+        let res = vga2d_generic(a) & vga2d_generic(b);
+        // The vector-vector part of the scalar has the opposite sign, e1 & e2 = 1
+        let opposite = vga2d_generic(vga2d::Multivector::zero() + (a.vector() & b.vector()));
+        assert_multivector_eq(res, vga2d_generic(a & b) - opposite.scale(2.0));
+        // The scalar-bivector, bivector-scalar and vector-vector parts
+        // 0.5 * 2 - 1.5 * 1 + (1 * 0.5 + 2 * 3)
+        assert_relative_eq!(res.scalar(), 6.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vga3d_bivector_bivector() {
+        let a = vga3d::Bivector::new(1.0, -2.0, 0.5);
+        let b = vga3d::Bivector::new(3.0, 0.5, -1.5);
+        let res = vga3d_generic(vga3d::Multivector::zero() + a)
+            & vga3d_generic(vga3d::Multivector::zero() + b);
+        let expected = vga3d_generic(vga3d::Multivector::zero() + (a & b));
+        assert_multivector_eq(res, expected.scale(-1.0));
+    }
+
+    #[test]
+    fn pga3d_line_point() {
+        let line = pga3d::Line::new(0.5, -1.0, 2.0, 1.5, -0.5, 0.25);
+        let point = pga3d::Point::new(1.0, 2.0, -3.0);
+        let line_generic = pga3d_generic(pga3d::Multivector::zero() + line);
+        let point_generic = pga3d_generic(pga3d::Multivector::zero() + point);
+        let expected = pga3d_generic(pga3d::Multivector::zero() + (line & point));
+        assert_multivector_eq(line_generic & point_generic, expected.scale(-1.0));
+        let expected = pga3d_generic(pga3d::Multivector::zero() + (point & line));
+        assert_multivector_eq(point_generic & line_generic, expected.scale(-1.0));
+    }
+
+    #[test]
+    fn pga3d_plane_point() {
+        let plane = pga3d::Plane::new(2.0, 1.0, -0.5, 0.25);
+        let point = pga3d::Point::new(1.0, 2.0, -3.0);
+        let plane_generic = pga3d_generic(pga3d::Multivector::zero() + plane);
+        let point_generic = pga3d_generic(pga3d::Multivector::zero() + point);
+        let expected = pga3d_generic(pga3d::Multivector::zero() + (plane & point));
+        assert_multivector_eq(plane_generic & point_generic, expected.scale(-1.0));
+        let expected = pga3d_generic(pga3d::Multivector::zero() + (point & plane));
+        assert_multivector_eq(point_generic & plane_generic, expected.scale(-1.0));
+    }
+
+    #[test]
+    fn sta_vector_trivector() {
+        let vector = sta::Vector::new(2.0, 1.0, -0.5, 0.25);
+        let trivector = sta::Trivector::new(1.5, -1.0, 0.5, 3.0);
+        let vector_generic = sta_generic(sta::Multivector::zero() + vector);
+        let trivector_generic = sta_generic(sta::Multivector::zero() + trivector);
+        let expected = sta_generic(sta::Multivector::zero() + (vector & trivector));
+        assert_multivector_eq(vector_generic & trivector_generic, expected.scale(-1.0));
+        let expected = sta_generic(sta::Multivector::zero() + (trivector & vector));
+        assert_multivector_eq(trivector_generic & vector_generic, expected.scale(-1.0));
+    }
+
+    #[test]
+    fn sta_trivector_trivector() {
+        let a = sta::Trivector::new(1.5, -1.0, 0.5, 3.0);
+        let b = sta::Trivector::new(-0.5, 2.0, 1.0, -1.5);
+        let res =
+            sta_generic(sta::Multivector::zero() + a) & sta_generic(sta::Multivector::zero() + b);
+        let expected = sta_generic(sta::Multivector::zero() + (a & b));
+        assert_multivector_eq(res, expected.scale(-1.0));
+    }
+
+    #[test]
+    fn sta_multivector_multivector() {
+        let a = sta::Multivector::new(
+            0.5,
+            sta::Vector::new(2.0, 1.0, -0.5, 0.25),
+            sta::Bivector::new(1.0, -2.0, 0.5, 1.5, -1.0, 3.0),
+            sta::Trivector::new(1.5, -1.0, 0.5, 3.0),
+            -0.75,
+        );
+        let b = sta::Multivector::new(
+            -1.0,
+            sta::Vector::new(-1.0, 0.5, 2.0, 1.5),
+            sta::Bivector::new(0.5, 1.0, -1.5, 2.0, 0.25, -0.5),
+            sta::Trivector::new(-0.5, 2.0, 1.0, -1.5),
+            1.25,
+        );
+        let res = sta_generic(a) & sta_generic(b);
+        // The vector-trivector and trivector-trivector parts have the opposite sign
+        let opposite = sta_generic(sta::Multivector::zero() + (a.vector() & b.trivector()))
+            + sta_generic(sta::Multivector::zero() + (a.trivector() & b.vector()))
+            + sta_generic(sta::Multivector::zero() + (a.trivector() & b.trivector()));
+        assert_multivector_eq(res, sta_generic(a & b) - opposite.scale(2.0));
+    }
+
+    fn cga3d_bivector(values: [f64; 10]) -> cga3d::Bivector<f64> {
+        let [e12, e31, e23, e14, e24, e34, e15, e25, e35, e45] = values;
+        cga3d::Bivector::new(e12, e31, e23, e14, e24, e34, e15, e25, e35, e45)
+    }
+
+    #[test]
+    fn cga3d_bivector_quadvector() {
+        let bivector = cga3d_bivector([1.0, -2.0, 0.5, 1.5, -1.0, 3.0, 0.25, 2.0, -0.5, 1.0]);
+        let quadvector = cga3d::Quadvector::new(2.0, 1.0, -0.5, 0.25, 1.5);
+        let bivector_generic = cga3d_generic(cga3d::Multivector::zero() + bivector);
+        let quadvector_generic = cga3d_generic(cga3d::Multivector::zero() + quadvector);
+        let expected = cga3d_generic(cga3d::Multivector::zero() + (bivector & quadvector));
+        assert_multivector_eq(bivector_generic & quadvector_generic, expected.scale(-1.0));
+        let expected = cga3d_generic(cga3d::Multivector::zero() + (quadvector & bivector));
+        assert_multivector_eq(quadvector_generic & bivector_generic, expected.scale(-1.0));
+    }
+
+    #[test]
+    fn cga3d_quadvector_quadvector() {
+        let a = cga3d::Quadvector::new(2.0, 1.0, -0.5, 0.25, 1.5);
+        let b = cga3d::Quadvector::new(-1.0, 0.5, 3.0, 1.5, -2.0);
+        let res = cga3d_generic(cga3d::Multivector::zero() + a)
+            & cga3d_generic(cga3d::Multivector::zero() + b);
+        let expected = cga3d_generic(cga3d::Multivector::zero() + (a & b));
+        assert_multivector_eq(res, expected.scale(-1.0));
+    }
+
+    #[test]
+    fn cga3d_versor_versor() {
+        let a = cga3d::Versor::new(
+            0.5,
+            cga3d_bivector([1.0, -2.0, 0.5, 1.5, -1.0, 3.0, 0.25, 2.0, -0.5, 1.0]),
+            cga3d::Quadvector::new(2.0, 1.0, -0.5, 0.25, 1.5),
+        );
+        let b = cga3d::Versor::new(
+            -1.0,
+            cga3d_bivector([0.5, 1.0, -1.5, 2.0, 0.25, -0.5, 1.0, -1.0, 3.0, 0.75]),
+            cga3d::Quadvector::new(-1.0, 0.5, 3.0, 1.5, -2.0),
+        );
+        let res = cga3d_generic(cga3d::Multivector::zero() + a)
+            & cga3d_generic(cga3d::Multivector::zero() + b);
+        // The bivector-quadvector and quadvector-quadvector parts have the opposite sign
+        let opposite = cga3d_generic(cga3d::Multivector::zero() + (a.bivector() & b.quadvector()))
+            + cga3d_generic(cga3d::Multivector::zero() + (a.quadvector() & b.bivector()))
+            + cga3d_generic(cga3d::Multivector::zero() + (a.quadvector() & b.quadvector()));
+        let expected = cga3d_generic(cga3d::Multivector::zero() + (a & b));
+        assert_multivector_eq(res, expected - opposite.scale(2.0));
+    }
+
+    #[test]
+    fn cga3d_multivector_multivector() {
+        let a = cga3d::Multivector::new(
+            0.5,
+            cga3d::Vector::new(1.0, -0.5, 2.0, 0.25, 1.5),
+            cga3d_bivector([1.0, -2.0, 0.5, 1.5, -1.0, 3.0, 0.25, 2.0, -0.5, 1.0]),
+            cga3d::Trivector::new(0.5, 1.0, -1.5, 2.0, 0.25, -0.5, 1.0, -1.0, 3.0, 0.75),
+            cga3d::Quadvector::new(2.0, 1.0, -0.5, 0.25, 1.5),
+            -0.75,
+        );
+        let b = cga3d::Multivector::new(
+            -1.0,
+            cga3d::Vector::new(-2.0, 1.0, 0.5, 1.5, -0.25),
+            cga3d_bivector([0.5, 1.0, -1.5, 2.0, 0.25, -0.5, 1.0, -1.0, 3.0, 0.75]),
+            cga3d::Trivector::new(1.0, -2.0, 0.5, 1.5, -1.0, 3.0, 0.25, 2.0, -0.5, 1.0),
+            cga3d::Quadvector::new(-1.0, 0.5, 3.0, 1.5, -2.0),
+            1.25,
+        );
+        let res = cga3d_generic(a) & cga3d_generic(b);
+        // The bivector-quadvector and quadvector-quadvector parts have the opposite sign
+        let opposite = cga3d_generic(cga3d::Multivector::zero() + (a.bivector() & b.quadvector()))
+            + cga3d_generic(cga3d::Multivector::zero() + (a.quadvector() & b.bivector()))
+            + cga3d_generic(cga3d::Multivector::zero() + (a.quadvector() & b.quadvector()));
+        assert_multivector_eq(res, cga3d_generic(a & b) - opposite.scale(2.0));
+    }
+}
@@ -0,0 +1,228 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+#![warn(missing_docs)]
+
+use num_traits::Float;
+
+/// # Metric signature of a Clifford algebra
+/// The signature $(p,q,r)$ of $\text{Cl}(p,q,r)$ is the number of basis vectors
+/// that square to $+1$, $-1$ and $0$.
+///
+/// The basis vectors are ordered with the $r$ degenerate vectors first,
+/// then the $p$ positive vectors and last the $q$ negative vectors.
+/// This gives $\mathrm{e}_0$ as the degenerate vector in $\text{Cl}(3,0,1)$ like in [`crate::pga3d`]
+/// and $\mathrm{e}_5$ as the negative vector in $\text{Cl}(4,1,0)$ like in [`crate::cga3d`].
+///
+/// A basis blade is a bitmask, where bit $i$ is set when the blade contains basis vector $i$.
+/// The coefficients of a multivector are stored with the bitmask as the index.
+///
+/// New signatures are made by implementing the trait on a marker type
+/// ```
+/// use ga_lib::clifford::Signature;
+/// use num_traits::Float;
+///
+/// #[derive(Default, Debug, Clone, Copy, PartialEq)]
+/// struct Cl040;
+///
+/// impl Signature for Cl040 {
+///     const P: usize = 0;
+///     const Q: usize = 4;
+///     const R: usize = 0;
+///     type Coefficients<F: Float> = [F; 16];
+///     fn zero<F: Float>() -> [F; 16] {
+///         [F::zero(); 16]
+///     }
+/// }
+/// ```
+pub trait Signature: Default + Clone + Copy + PartialEq {
+    /// Number of basis vectors that square to $+1$
+    const P: usize;
+    /// Number of basis vectors that square to $-1$
+    const Q: usize;
+    /// Number of basis vectors that square to $0$
+    const R: usize;
+    /// Number of basis vectors $n = p + q + r$
+    const DIMENSION: usize = Self::P + Self::Q + Self::R;
+    /// Number of basis blades $2^n$
+    const BLADES: usize = 1 << Self::DIMENSION;
+
+    /// Storage for the coefficients of all $2^n$ basis blades
+    type Coefficients<F: Float>: Clone + Copy + AsRef<[F]> + AsMut<[F]>;
+
+    /// The coefficients of the zero multivector
+    fn zero<F: Float>() -> Self::Coefficients<F>;
+
+    /// The square of a basis vector
+    fn metric(index: usize) -> i8 {
+        if index < Self::R {
+            0
+        } else if index < Self::R + Self::P {
+            1
+        } else {
+            -1
+        }
+    }
+
+    /// # Multiplication table
+    /// The geometric product of two basis blades given as bitmasks.
+    /// The result is the sign and the bitmask of the resulting blade.
+    /// The sign is zero when the blades share a degenerate basis vector.
+    fn blade_product(a: usize, b: usize) -> (i8, usize) {
+        let mut sign = reorder_sign(a, b);
+        let common = a & b;
+        for index in 0..Self::DIMENSION {
+            if common & (1 << index) != 0 {
+                sign *= Self::metric(index);
+            }
+        }
+        (sign, a ^ b)
+    }
+
+    /// # Right complement
+    /// The blade and sign $s$ such that $a \wedge s\,\overline{a} = I$.
+    /// This is the dual of a basis blade and does not depend on the metric.
+    fn right_complement(a: usize) -> (i8, usize) {
+        let complement = (Self::BLADES - 1) ^ a;
+        (reorder_sign(a, complement), complement)
+    }
+
+    /// # Left complement
+    /// The blade and sign $s$ such that $s\,\underline{a} \wedge a = I$.
+    /// This is the inverse of the right complement.
+    fn left_complement(a: usize) -> (i8, usize) {
+        let complement = (Self::BLADES - 1) ^ a;
+        (reorder_sign(complement, a), complement)
+    }
+}
+
+/// The grade of a basis blade given as a bitmask
+pub fn grade(blade: usize) -> usize {
+    blade.count_ones() as usize
+}
+
+// The sign from reordering the product of two blades into canonical order
+fn reorder_sign(a: usize, b: usize) -> i8 {
+    let mut a = a >> 1;
+    let mut swaps = 0;
+    while a != 0 {
+        swaps += (a & b).count_ones();
+        a >>= 1;
+    }
+    if swaps % 2 == 0 { 1 } else { -1 }
+}
+
+macro_rules! signature {
+    ($(#[$meta:meta])* $name:ident, $p:literal, $q:literal, $r:literal) => {
+        $(#[$meta])*
+        #[derive(Default, Debug, Clone, Copy, PartialEq)]
+        pub struct $name;
+
+        impl Signature for $name {
+            const P: usize = $p;
+            const Q: usize = $q;
+            const R: usize = $r;
+            type Coefficients<F: Float> = [F; 1 << ($p + $q + $r)];
+            fn zero<F: Float>() -> Self::Coefficients<F> {
+                [F::zero(); 1 << ($p + $q + $r)]
+            }
+        }
+    };
+}
+
+signature!(
+    /// $\text{Cl}(0,1,0)$ the complex numbers
+    Cl010, 0, 1, 0
+);
+signature!(
+    /// $\text{Cl}(0,2,0)$ the quaternions
+    Cl020, 0, 2, 0
+);
+signature!(
+    /// $\text{Cl}(0,3,0)$
+    Cl030, 0, 3, 0
+);
+signature!(
+    /// $\text{Cl}(2,0,0)$ for the geometry of [`crate::vga2d`]
+    Cl200, 2, 0, 0
+);
+signature!(
+    /// $\text{Cl}(3,0,0)$ for the geometry of [`crate::vga3d`]
+    Cl300, 3, 0, 0
+);
+signature!(
+    /// $\text{Cl}(2,0,1)$ for the geometry of [`crate::pga2d`]
+    Cl201, 2, 0, 1
+);
+signature!(
+    /// $\text{Cl}(3,0,1)$ for the geometry of [`crate::pga3d`]
+    Cl301, 3, 0, 1
+);
+signature!(
+    /// $\text{Cl}(1,3,0)$ the spacetime algebra of [`crate::sta`]
+    Cl130, 1, 3, 0
+);
+signature!(
+    /// $\text{Cl}(4,1,0)$ for the geometry of [`crate::cga3d`]
+    Cl410, 4, 1, 0
+);
+
+#[cfg(test)]
+mod signature_table {
+    use super::*;
+
+    #[test]
+    fn signature_metric() {
+        assert_eq!(Cl301::DIMENSION, 4);
+        assert_eq!(Cl301::BLADES, 16);
+        assert_eq!(Cl301::metric(0), 0);
+        assert_eq!(Cl301::metric(1), 1);
+        assert_eq!(Cl130::metric(0), 1);
+        assert_eq!(Cl130::metric(1), -1);
+        assert_eq!(Cl410::metric(4), -1);
+    }
+
+    #[test]
+    fn signature_blade_product() {
+        // e1e2 e1 = -e2
+        assert_eq!(Cl300::blade_product(0b011, 0b001), (-1, 0b010));
+        // e12 e12 = -1
+        assert_eq!(Cl300::blade_product(0b011, 0b011), (-1, 0b000));
+        // e0 e0 = 0
+        assert_eq!(Cl301::blade_product(0b0001, 0b0011).0, 0);
+        // i j = k for the quaternions
+        assert_eq!(Cl020::blade_product(0b01, 0b10), (1, 0b11));
+        assert_eq!(Cl020::blade_product(0b11, 0b11), (-1, 0b00));
+    }
+
+    #[test]
+    fn signature_complement() {
+        // e1 e23 = e123
+        assert_eq!(Cl300::right_complement(0b001), (1, 0b110));
+        // e12 e3 = e123
+        assert_eq!(Cl300::right_complement(0b011), (1, 0b100));
+        // e2 e31 = e123
+        assert_eq!(Cl300::right_complement(0b010), (-1, 0b101));
+        for blade in 0..Cl410::BLADES {
+            let (sign, complement) = Cl410::right_complement(blade);
+            let (inverse_sign, res) = Cl410::left_complement(complement);
+            assert_eq!(res, blade);
+            assert_eq!(sign * inverse_sign, 1);
+        }
+    }
+}
@@ -0,0 +1,56 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{multivector::Multivector, signature::Signature};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::Sub;
+
+// Subtraction
+// \[ A - B \]
+impl<F: Float, S: Signature> Sub for Multivector<F, S> {
+    type Output = Multivector<F, S>;
+    fn sub(self, b: Multivector<F, S>) -> Multivector<F, S> {
+        self + -b
+    }
+}
+forward_ref_binop!(impl<F: Float, S: Signature> Sub, sub for Multivector<F, S>, Multivector<F, S>);
+
+// Test
+#[cfg(test)]
+mod subtraction_difference {
+    use super::*;
+    use crate::clifford::Cl201;
+
+    #[test]
+    fn multivector_multivector() {
+        let a =
+            Multivector::<f64, Cl201>::from_coefficients([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+        let b = Multivector::<f64, Cl201>::from_coefficients([
+            8.0, -7.0, 6.0, -5.0, 4.0, -3.0, 2.0, -1.0,
+        ]);
+        assert_eq!(
+            (a - b).coefficients(),
+            &[-7.0, 9.0, -3.0, 9.0, 1.0, 9.0, 5.0, 9.0]
+        );
+        assert_eq!(a - a, Multivector::zero());
+    }
+}
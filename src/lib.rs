@@ -38,6 +38,15 @@
 //! ## Inner Product
 //! The inner product $\cdot$ is implemented with the use of the `|` (BitOr) Operator.
 //!
+//! ## Contractions
+//! The left contraction $\rfloor$, right contraction $\lfloor$, scalar product and fat dot $\bullet$
+//! are implemented with the `Contraction` trait in `vga3d`.
+//! The `|` operator is the fat dot.
+//!
+//! ## Commutator Product
+//! The commutator $\frac{1}{2}(AB - BA)$ and anticommutator $\frac{1}{2}(AB + BA)$
+//! are implemented with the `Commutator` and `Anticommutator` traits in `vga3d`.
+//!
 //! ## Exterior Product
 //! The exterior product $\wedge$ is implemented with the use of the `^` (BitXor) Operator.
 //!
@@ -46,8 +55,7 @@
 //!
 //! ## Regressive Product
 //! The regressive product is implemented with the use of the `&` (BitAnd) Operator.
-//!
-//! TODO Not implemented
+//! $$ A \vee B = ( -A\star  \wedge -B\star )\star $$
 //!
 //! # Operations
 //! There is implemented different geometric operations.
@@ -61,12 +69,81 @@
 //! ## Rejection
 //!
 //! ## Reflection
+//!
+//! ## Euler Angles
+//! Rotors are converted to and from the twelve Euler and Tait-Bryan sequences,
+//! intrinsic and extrinsic, with `from_euler_angles` and `to_euler_angles`.
+//!
+//! ## Rotation Matrices
+//! Rotors are converted to and from $3\times3$ rotation matrices with `to_matrix3` and `try_from_matrix3`.
+//! Bivectors are converted to and from skew-symmetric matrices.
+//!
+//! ## Interpolation
+//! Rotors are interpolated with `slerp`, `nlerp` and `squad`.
+//! `RotorSpline` is a cubic spline through a sequence of key rotors.
+//!
+//! ## Kinematics
+//! Rotors are integrated with the angular velocity bivector in the reference frame $\dot{R} = -\frac{1}{2}\overset\Rightarrow{\Omega} R$
+//! with `integrate` and `integrate_with`,
+//! or in the body frame $\dot{R} = -\frac{1}{2} R \overset\Rightarrow{\Omega}$ with `integrate_body` and `integrate_body_with`.
+//! `RotorIntegrator` selects the exponential map, Runge-Kutta 4, Crank-Nicolson or the Cayley transform.
+//!
+//! ## Dual
+//! The dual $A\star = A \overset\Rrightarrow{i}$, undual, Hodge dual and the right and left complements
+//! are implemented with the `Dual` trait in `vga3d`.
+//!
+//! # Attitude
+//! The `attitude` module has tools for the attitude of a satellite built on the vga3d `Rotor`.
+//!
+//! ## Determination
+//! Wahba's problem is solved from weighted vector observations with TRIAD, QUEST and the Davenport q-method.
+//! The loss and the residual of each observation are used to detect bad measurements.
+//!
+//! ## Estimation
+//! `Mekf` is a multiplicative extended Kalman filter that fuses gyro rates with vector observations.
+//! The attitude is a `Rotor`, the error state is a `Bivector` and the covariance is a $3\times3$ array.
+//!
+//! ## Control
+//! `PdController` works on the logarithm of the error rotor,
+//! `SlidingModeController` on the bivector part of the error rotor,
+//! and `BDotController` detumbles with magnetorquers.
+//! The torques are bivectors in the body frame.
+//!
+//! ## Dynamics
+//! The inertia is a `BivectorLinearMap` in `vga3d` from the angular velocity bivector to the angular momentum bivector,
+//! with the principal moments and planes from `try_principal_axes`.
+//! `RigidBodyState` is propagated with the torque and the gyroscopic coupling of Euler's equation,
+//! and the attitude follows the angular velocity with the body frame rotor integrator.
 
 /// Vector Geometric Algebra $\text{Cl}(3,0,0)$
+///
+/// With the `serde` feature the types are serialized as structs with the basis blades as named fields,
+/// `scalar`, `e1`, `e2`, `e3`, `e12`, `e31`, `e23` and `e123`.
+/// Deserializing a `Rotor` or `UnitQuaternion` that does not have unit norm is an error.
 pub mod vga3d;
 
 /// Vector Geometric Algebra $\text{Cl}(2,0,0)$
 pub mod vga2d;
 
+/// Projective Geometric Algebra $\text{Cl}(3,0,1)$
+pub mod pga3d;
+
+/// Projective Geometric Algebra $\text{Cl}(2,0,1)$
+pub mod pga2d;
+
+/// Conformal Geometric Algebra $\text{Cl}(4,1,0)$
+pub mod cga3d;
+
+/// Spacetime Algebra $\text{Cl}(1,3,0)$
+pub mod sta;
+
+/// Generic Clifford algebra $\text{Cl}(p,q,r)$ with the signature as a type parameter
+pub mod clifford;
+
+/// Satellite attitude tools built on the vga3d rotor
+pub mod attitude;
+
 #[macro_use]
 pub(crate) mod macros;
+
+pub(crate) mod linear_algebra;
@@ -0,0 +1,180 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use num_traits::Float;
+
+// Small helpers for 3x3 matrices indexed as [row][column]
+
+pub(crate) fn symmetric<F: Float>(m: [[F; 3]; 3]) -> [[F; 3]; 3] {
+    let mut s = [[F::zero(); 3]; 3];
+    for i in 0..3 {
+        for j in 0..3 {
+            s[i][j] = m[i][j] + m[j][i];
+        }
+    }
+    s
+}
+
+pub(crate) fn multiply<F: Float>(a: [[F; 3]; 3], b: [[F; 3]; 3]) -> [[F; 3]; 3] {
+    let mut m = [[F::zero(); 3]; 3];
+    for i in 0..3 {
+        for j in 0..3 {
+            m[i][j] = a[i][0] * b[0][j] + a[i][1] * b[1][j] + a[i][2] * b[2][j];
+        }
+    }
+    m
+}
+
+pub(crate) fn apply<F: Float>(m: [[F; 3]; 3], v: [F; 3]) -> [F; 3] {
+    [dot(m[0], v), dot(m[1], v), dot(m[2], v)]
+}
+
+pub(crate) fn dot<F: Float>(a: [F; 3], b: [F; 3]) -> F {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+pub(crate) fn determinant<F: Float>(m: [[F; 3]; 3]) -> F {
+    m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+}
+
+pub(crate) fn transpose<F: Float>(m: [[F; 3]; 3]) -> [[F; 3]; 3] {
+    let mut t = [[F::zero(); 3]; 3];
+    for i in 0..3 {
+        for j in 0..3 {
+            t[i][j] = m[j][i];
+        }
+    }
+    t
+}
+
+pub(crate) fn add<F: Float>(a: [[F; 3]; 3], b: [[F; 3]; 3]) -> [[F; 3]; 3] {
+    let mut m = [[F::zero(); 3]; 3];
+    for i in 0..3 {
+        for j in 0..3 {
+            m[i][j] = a[i][j] + b[i][j];
+        }
+    }
+    m
+}
+
+pub(crate) fn scale<F: Float>(m: [[F; 3]; 3], factor: F) -> [[F; 3]; 3] {
+    m.map(|row| row.map(|element| element * factor))
+}
+
+pub(crate) fn identity<F: Float>() -> [[F; 3]; 3] {
+    let mut m = [[F::zero(); 3]; 3];
+    for (i, row) in m.iter_mut().enumerate() {
+        row[i] = F::one();
+    }
+    m
+}
+
+// The inverse from the adjugate
+// Returns None if the matrix is singular
+pub(crate) fn try_inverse<F: Float>(m: [[F; 3]; 3]) -> Option<[[F; 3]; 3]> {
+    let determinant = determinant(m);
+    if determinant == F::zero() || !determinant.is_finite() {
+        return None;
+    }
+    // The cofactor of the element (j, i)
+    let inverse = core::array::from_fn(|i| {
+        core::array::from_fn(|j| {
+            let (r0, r1) = ((j + 1) % 3, (j + 2) % 3);
+            let (c0, c1) = ((i + 1) % 3, (i + 2) % 3);
+            (m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]) / determinant
+        })
+    });
+    Some(inverse)
+}
+
+// Cyclic Jacobi eigenvalue method for a symmetric matrix
+// Returns the eigenvalues and the eigenvectors as the columns of a matrix indexed as [row][column]
+pub(crate) fn symmetric_eigen<F: Float, const N: usize>(
+    mut a: [[F; N]; N],
+) -> ([F; N], [[F; N]; N]) {
+    let two = F::one() + F::one();
+    let mut v = [[F::zero(); N]; N];
+    for (i, row) in v.iter_mut().enumerate() {
+        row[i] = F::one();
+    }
+    for _ in 0..32 {
+        let mut off_diagonal = F::zero();
+        let mut total = F::zero();
+        for (i, row) in a.iter().enumerate() {
+            for (j, element) in row.iter().enumerate() {
+                total = total + *element * *element;
+                if i != j {
+                    off_diagonal = off_diagonal + *element * *element;
+                }
+            }
+        }
+        if off_diagonal <= F::epsilon() * F::epsilon() * total {
+            break;
+        }
+        for p in 0..N {
+            for q in (p + 1)..N {
+                if a[p][q] == F::zero() {
+                    continue;
+                }
+                // The rotation that zeros the element a_pq
+                let theta = (a[q][q] - a[p][p]) / (two * a[p][q]);
+                let t = F::one() / (theta.abs() + (theta * theta + F::one()).sqrt());
+                let t = if theta < F::zero() { -t } else { t };
+                let c = F::one() / (t * t + F::one()).sqrt();
+                let s = t * c;
+                for row in a.iter_mut() {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = c * kp - s * kq;
+                    row[q] = s * kp + c * kq;
+                }
+                let (row_p, row_q) = (a[p], a[q]);
+                a[p] = core::array::from_fn(|k| c * row_p[k] - s * row_q[k]);
+                a[q] = core::array::from_fn(|k| s * row_p[k] + c * row_q[k]);
+                for row in v.iter_mut() {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = c * kp - s * kq;
+                    row[q] = s * kp + c * kq;
+                }
+            }
+        }
+    }
+    (core::array::from_fn(|i| a[i][i]), v)
+}
+
+#[cfg(test)]
+mod jacobi {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn symmetric_eigen_decomposition() {
+        let a = [[4.0, 1.0, -2.0], [1.0, 2.0, 0.5], [-2.0, 0.5, 3.0]];
+        let (values, vectors) = symmetric_eigen(a);
+        for (k, value) in values.iter().enumerate() {
+            // A v = lambda v
+            for (row, a_row) in a.iter().enumerate() {
+                let av =
+                    a_row[0] * vectors[0][k] + a_row[1] * vectors[1][k] + a_row[2] * vectors[2][k];
+                assert_relative_eq!(av, value * vectors[row][k], epsilon = 1e-12);
+            }
+        }
+        assert_relative_eq!(values.iter().sum::<f64>(), 9.0, epsilon = 1e-12);
+    }
+}
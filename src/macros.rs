@@ -49,6 +49,36 @@ macro_rules! forward_ref_binop {
         }
     };
 
+    // Version for when both types share two generic parameters
+    (impl<$param:ident : $bound:path, $param2:ident : $bound2:path> $imp:ident, $method:ident for $t:ty, $t2:ty) => {
+        impl<'a, $param: $bound, $param2: $bound2> $imp<$t2> for &'a $t {
+            type Output = <$t as $imp<$t2>>::Output;
+
+            #[inline]
+            fn $method(self, other: $t2) -> <$t as $imp<$t2>>::Output {
+                $imp::$method(*self, other)
+            }
+        }
+
+        impl<$param: $bound, $param2: $bound2> $imp<&$t2> for $t {
+            type Output = <$t as $imp<$t2>>::Output;
+
+            #[inline]
+            fn $method(self, other: &$t2) -> <$t as $imp<$t2>>::Output {
+                $imp::$method(self, *other)
+            }
+        }
+
+        impl<'a, 'b, $param: $bound, $param2: $bound2> $imp<&'b $t2> for &'a $t {
+            type Output = <$t as $imp<$t2>>::Output;
+
+            #[inline]
+            fn $method(self, other: &'b $t2) -> <$t as $imp<$t2>>::Output {
+                $imp::$method(*self, *other)
+            }
+        }
+    };
+
     // Original version without generics
     (impl $imp:ident, $method:ident for $t:ty, $u:ty) => {
         impl<'a> $imp<$u> for &'a $t {
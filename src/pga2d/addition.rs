@@ -0,0 +1,630 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    line::Line, motor::Motor, multivector::Multivector, point::Point, pseudoscalar::Pseudoscalar,
+    scalar::Scalar,
+};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::Add;
+
+// Addition
+
+// Scalar-Scalar
+impl<F: Float> Add for Scalar<F> {
+    type Output = F;
+    fn add(self, b: Scalar<F>) -> F {
+        self.0 + b.0
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Scalar<F>, Scalar<F>);
+
+// Scalar-Line
+impl<F: Float> Add<Line<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0,
+            Line::new(b.e0(), b.e1(), b.e2()),
+            Point::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Scalar<F>, Line<F>);
+
+// Line-Scalar
+impl<F: Float> Add<Scalar<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            b.0,
+            Line::new(self.e0(), self.e1(), self.e2()),
+            Point::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Line<F>, Scalar<F>);
+
+// Scalar-Point
+impl<F: Float> Add<Point<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0,
+            Line::zero(),
+            Point::new_components(b.e20(), b.e01(), b.e12()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Scalar<F>, Point<F>);
+
+// Point-Scalar
+impl<F: Float> Add<Scalar<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            b.0,
+            Line::zero(),
+            Point::new_components(self.e20(), self.e01(), self.e12()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Point<F>, Scalar<F>);
+
+// Scalar-Pseudoscalar
+impl<F: Float> Add<Pseudoscalar<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(self.0, Line::zero(), Point::zero(), b.e012())
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Scalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Scalar
+impl<F: Float> Add<Scalar<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(b.0, Line::zero(), Point::zero(), self.e012())
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Pseudoscalar<F>, Scalar<F>);
+
+// Scalar-Motor
+impl<F: Float> Add<Motor<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 + b.scalar(),
+            Line::zero(),
+            Point::new_components(b.e20(), b.e01(), b.e12()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Scalar<F>, Motor<F>);
+
+// Motor-Scalar
+impl<F: Float> Add<Scalar<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() + b.0,
+            Line::zero(),
+            Point::new_components(self.e20(), self.e01(), self.e12()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Motor<F>, Scalar<F>);
+
+// Scalar-Multivector
+impl<F: Float> Add<Multivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 + b.scalar(),
+            Line::new(b.e0(), b.e1(), b.e2()),
+            Point::new_components(b.e20(), b.e01(), b.e12()),
+            b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Scalar<F>, Multivector<F>);
+
+// Multivector-Scalar
+impl<F: Float> Add<Scalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() + b.0,
+            Line::new(self.e0(), self.e1(), self.e2()),
+            Point::new_components(self.e20(), self.e01(), self.e12()),
+            self.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Multivector<F>, Scalar<F>);
+
+// Line-Line
+impl<F: Float> Add for Line<F> {
+    type Output = Line<F>;
+    fn add(self, b: Line<F>) -> Line<F> {
+        Line::new(self.e0() + b.e0(), self.e1() + b.e1(), self.e2() + b.e2())
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Line<F>, Line<F>);
+
+// Line-Point
+impl<F: Float> Add<Point<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Line::new(self.e0(), self.e1(), self.e2()),
+            Point::new_components(b.e20(), b.e01(), b.e12()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Line<F>, Point<F>);
+
+// Point-Line
+impl<F: Float> Add<Line<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Line::new(b.e0(), b.e1(), b.e2()),
+            Point::new_components(self.e20(), self.e01(), self.e12()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Point<F>, Line<F>);
+
+// Line-Pseudoscalar
+impl<F: Float> Add<Pseudoscalar<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Line::new(self.e0(), self.e1(), self.e2()),
+            Point::zero(),
+            b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Line<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Line
+impl<F: Float> Add<Line<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Line::new(b.e0(), b.e1(), b.e2()),
+            Point::zero(),
+            self.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Pseudoscalar<F>, Line<F>);
+
+// Line-Motor
+impl<F: Float> Add<Motor<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            b.scalar(),
+            Line::new(self.e0(), self.e1(), self.e2()),
+            Point::new_components(b.e20(), b.e01(), b.e12()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Line<F>, Motor<F>);
+
+// Motor-Line
+impl<F: Float> Add<Line<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Line::new(b.e0(), b.e1(), b.e2()),
+            Point::new_components(self.e20(), self.e01(), self.e12()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Motor<F>, Line<F>);
+
+// Line-Multivector
+impl<F: Float> Add<Multivector<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            b.scalar(),
+            Line::new(self.e0() + b.e0(), self.e1() + b.e1(), self.e2() + b.e2()),
+            Point::new_components(b.e20(), b.e01(), b.e12()),
+            b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Line<F>, Multivector<F>);
+
+// Multivector-Line
+impl<F: Float> Add<Line<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Line::new(self.e0() + b.e0(), self.e1() + b.e1(), self.e2() + b.e2()),
+            Point::new_components(self.e20(), self.e01(), self.e12()),
+            self.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Multivector<F>, Line<F>);
+
+// Point-Point
+impl<F: Float> Add for Point<F> {
+    type Output = Point<F>;
+    fn add(self, b: Point<F>) -> Point<F> {
+        Point::new_components(
+            self.e20() + b.e20(),
+            self.e01() + b.e01(),
+            self.e12() + b.e12(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Point<F>, Point<F>);
+
+// Point-Pseudoscalar
+impl<F: Float> Add<Pseudoscalar<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Line::zero(),
+            Point::new_components(self.e20(), self.e01(), self.e12()),
+            b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Point<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Point
+impl<F: Float> Add<Point<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Line::zero(),
+            Point::new_components(b.e20(), b.e01(), b.e12()),
+            self.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Pseudoscalar<F>, Point<F>);
+
+// Point-Motor
+impl<F: Float> Add<Motor<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            b.scalar(),
+            Line::zero(),
+            Point::new_components(
+                self.e20() + b.e20(),
+                self.e01() + b.e01(),
+                self.e12() + b.e12(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Point<F>, Motor<F>);
+
+// Motor-Point
+impl<F: Float> Add<Point<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Line::zero(),
+            Point::new_components(
+                self.e20() + b.e20(),
+                self.e01() + b.e01(),
+                self.e12() + b.e12(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Motor<F>, Point<F>);
+
+// Point-Multivector
+impl<F: Float> Add<Multivector<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            b.scalar(),
+            Line::new(b.e0(), b.e1(), b.e2()),
+            Point::new_components(
+                self.e20() + b.e20(),
+                self.e01() + b.e01(),
+                self.e12() + b.e12(),
+            ),
+            b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Point<F>, Multivector<F>);
+
+// Multivector-Point
+impl<F: Float> Add<Point<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Line::new(self.e0(), self.e1(), self.e2()),
+            Point::new_components(
+                self.e20() + b.e20(),
+                self.e01() + b.e01(),
+                self.e12() + b.e12(),
+            ),
+            self.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Multivector<F>, Point<F>);
+
+// Pseudoscalar-Pseudoscalar
+impl<F: Float> Add for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn add(self, b: Pseudoscalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.e012() + b.e012())
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Pseudoscalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Motor
+impl<F: Float> Add<Motor<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            b.scalar(),
+            Line::zero(),
+            Point::new_components(b.e20(), b.e01(), b.e12()),
+            self.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Pseudoscalar<F>, Motor<F>);
+
+// Motor-Pseudoscalar
+impl<F: Float> Add<Pseudoscalar<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Line::zero(),
+            Point::new_components(self.e20(), self.e01(), self.e12()),
+            b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Motor<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Multivector
+impl<F: Float> Add<Multivector<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            b.scalar(),
+            Line::new(b.e0(), b.e1(), b.e2()),
+            Point::new_components(b.e20(), b.e01(), b.e12()),
+            self.e012() + b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Pseudoscalar<F>, Multivector<F>);
+
+// Multivector-Pseudoscalar
+impl<F: Float> Add<Pseudoscalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Line::new(self.e0(), self.e1(), self.e2()),
+            Point::new_components(self.e20(), self.e01(), self.e12()),
+            self.e012() + b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Multivector<F>, Pseudoscalar<F>);
+
+// Motor-Motor
+impl<F: Float> Add for Motor<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() + b.scalar(),
+            Line::zero(),
+            Point::new_components(
+                self.e20() + b.e20(),
+                self.e01() + b.e01(),
+                self.e12() + b.e12(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Motor<F>, Motor<F>);
+
+// Motor-Multivector
+impl<F: Float> Add<Multivector<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() + b.scalar(),
+            Line::new(b.e0(), b.e1(), b.e2()),
+            Point::new_components(
+                self.e20() + b.e20(),
+                self.e01() + b.e01(),
+                self.e12() + b.e12(),
+            ),
+            b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Motor<F>, Multivector<F>);
+
+// Multivector-Motor
+impl<F: Float> Add<Motor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() + b.scalar(),
+            Line::new(self.e0(), self.e1(), self.e2()),
+            Point::new_components(
+                self.e20() + b.e20(),
+                self.e01() + b.e01(),
+                self.e12() + b.e12(),
+            ),
+            self.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Multivector<F>, Motor<F>);
+
+// Multivector-Multivector
+impl<F: Float> Add for Multivector<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() + b.scalar(),
+            Line::new(self.e0() + b.e0(), self.e1() + b.e1(), self.e2() + b.e2()),
+            Point::new_components(
+                self.e20() + b.e20(),
+                self.e01() + b.e01(),
+                self.e12() + b.e12(),
+            ),
+            self.e012() + b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Multivector<F>, Multivector<F>);
+
+// Test
+#[cfg(test)]
+mod addition_sum {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn scalar_scalar() {
+        // -4
+        let scalar1 = Scalar::new(-4.0);
+        // -1
+        let scalar2 = Scalar::new(-1.0);
+        let res = scalar1 + scalar2;
+        // -5
+        assert_relative_eq!(res, -5.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn line_line() {
+        // -1e0+3e1+2e2
+        let line1 = Line::new(-1.0, 3.0, 2.0);
+        // -4e0-2e1-4e2
+        let line2 = Line::new(-4.0, -2.0, -4.0);
+        let res = line1 + line2;
+        // -5e0+1e1-2e2
+        assert_relative_eq!(res.e0(), -5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -2.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn line_point() {
+        // -2e0-3e1-2e2
+        let line1 = Line::new(-2.0, -3.0, -2.0);
+        // -3e20-3e01+2e12
+        let point2 = Point::new_components(-3.0, -3.0, 2.0);
+        let res = line1 + point2;
+        // -2e0-3e1-2e2-3e20-3e01+2e12
+        assert_relative_eq!(res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn motor_motor() {
+        // 2-4e20-1e01+1e12
+        let motor1 = Motor::new(2.0, Point::new_components(-4.0, -1.0, 1.0));
+        // 3+2e20-2e01+1e12
+        let motor2 = Motor::new(3.0, Point::new_components(2.0, -2.0, 1.0));
+        let res = motor1 + motor2;
+        // 5-2e20-3e01+2e12
+        assert_relative_eq!(res.scalar(), 5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_line() {
+        // 3-4e0+4e1+4e2+5e20-1e01+1e12-2e012
+        let mvec1 = Multivector::new(
+            3.0,
+            Line::new(-4.0, 4.0, 4.0),
+            Point::new_components(5.0, -1.0, 1.0),
+            -2.0,
+        );
+        // -4e0-3e1-4e2
+        let line2 = Line::new(-4.0, -3.0, -4.0);
+        let res = mvec1 + line2;
+        // 3-8e0+1e1+5e20-1e01+1e12-2e012
+        assert_relative_eq!(res.scalar(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), -8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), 5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), -2.0, max_relative = 0.000001);
+    }
+}
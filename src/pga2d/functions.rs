@@ -0,0 +1,159 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{PGA2DOps, line::Line, motor::Motor, multivector::Multivector, point::Point};
+
+use num_traits::Float;
+
+// Functions
+// Rigid body motion
+// \[ X' = \widetilde{M} X M \]
+pub trait Transformable<M> {
+    type Output;
+    fn transform(self, motor: M) -> Self::Output;
+}
+
+macro_rules! impl_transformable {
+    ($element:ty, $output:ty, $extract:ident) => {
+        // Owned element, owned motor
+        impl<F: Float> Transformable<Motor<F>> for $element {
+            type Output = $output;
+            fn transform(self, motor: Motor<F>) -> Self::Output {
+                (motor.reverse() * self * motor).$extract()
+            }
+        }
+
+        // Owned element, reference motor
+        impl<'m, F: Float> Transformable<&'m Motor<F>> for $element {
+            type Output = $output;
+            fn transform(self, motor: &'m Motor<F>) -> Self::Output {
+                (motor.reverse() * self * *motor).$extract()
+            }
+        }
+
+        // Reference element, owned motor
+        impl<'e, F: Float> Transformable<Motor<F>> for &'e $element {
+            type Output = $output;
+            fn transform(self, motor: Motor<F>) -> Self::Output {
+                (motor.reverse() * *self * motor).$extract()
+            }
+        }
+
+        // Reference element, reference motor
+        impl<'e, 'm, F: Float> Transformable<&'m Motor<F>> for &'e $element {
+            type Output = $output;
+            fn transform(self, motor: &'m Motor<F>) -> Self::Output {
+                (motor.reverse() * *self * *motor).$extract()
+            }
+        }
+    };
+}
+
+// Usage:
+impl_transformable!(Line<F>, Line<F>, line);
+impl_transformable!(Point<F>, Point<F>, point);
+impl_transformable!(Motor<F>, Motor<F>, motor);
+impl_transformable!(Multivector<F>, Multivector<F>, multivector);
+
+#[cfg(test)]
+mod transformation {
+    use super::*;
+    use approx::assert_relative_eq;
+    use core::f64::consts::TAU;
+
+    #[test]
+    fn point_translation() {
+        let point = Point::new(1.0, 2.0);
+        let motor = Motor::from_translation(4.0, 5.0);
+        for res in [
+            (&point).transform(&motor),
+            (&point).transform(motor),
+            point.transform(&motor),
+            point.transform(motor),
+        ] {
+            assert_relative_eq!(res.e20(), 5.0, max_relative = 0.000001);
+            assert_relative_eq!(res.e01(), 7.0, max_relative = 0.000001);
+            assert_relative_eq!(res.e12(), 1.0, max_relative = 0.000001);
+        }
+    }
+
+    #[test]
+    fn point_rotation() {
+        // A quarter turn around (1,1)
+        let motor = Motor::try_from_rotation(TAU / 4.0, Point::new(1.0, 1.0)).unwrap();
+        let res = Point::new(3.0, 1.0).transform(motor);
+        assert_relative_eq!(res.e20(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 1.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn motor_composition() {
+        // Rotate a quarter turn around the origin and then translate along x
+        let rotation = Motor::try_from_rotation(TAU / 4.0, Point::new(0.0, 0.0)).unwrap();
+        let translation = Motor::from_translation(3.0, 0.0);
+        let point = Point::new(1.0, 0.0);
+        let res = point.transform(rotation * translation);
+        let expected = point.transform(rotation).transform(translation);
+        assert_relative_eq!(res.e20(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), expected.e20(), max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), expected.e01(), max_relative = 0.000001);
+    }
+
+    #[test]
+    fn line_transformation() {
+        // Moving the join of two points is the join of the moved points
+        let motor = Motor::try_from_rotation(1.2, Point::new(0.3, -0.2)).unwrap()
+            * Motor::from_translation(1.0, -2.0);
+        let point1 = Point::new(1.0, 2.0);
+        let point2 = Point::new(-1.0, 0.5);
+        let res = (point1 & point2).transform(motor);
+        let expected = point1.transform(motor) & point2.transform(motor);
+        assert_relative_eq!(res.e0(), expected.e0(), max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), expected.e1(), max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), expected.e2(), max_relative = 0.000001);
+    }
+
+    #[test]
+    fn distance_is_preserved() {
+        let motor = Motor::try_from_rotation(-0.7, Point::new(2.0, -1.0)).unwrap()
+            * Motor::from_translation(0.5, 4.0);
+        let point1 = Point::new(1.0, 2.0);
+        let point2 = Point::new(-3.0, 5.0);
+        let res = point1
+            .transform(motor)
+            .try_distance(point2.transform(motor))
+            .unwrap();
+        assert_relative_eq!(res, 5.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_motor() {
+        // A motor made from a rotor rotates points like the rotor rotates vectors
+        let rotor = crate::vga2d::Rotor::new(crate::vga2d::Bivector::new(0.3));
+        let vector = crate::vga2d::Vector::new(1.0, 2.0);
+        let expected = crate::vga2d::Rotatable::rotate(vector, rotor);
+        let res = Point::from_vector(vector)
+            .transform(Motor::from_rotor(rotor))
+            .try_to_vector()
+            .unwrap();
+        assert_relative_eq!(res.e1(), expected.e1(), max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), expected.e2(), max_relative = 0.000001);
+    }
+}
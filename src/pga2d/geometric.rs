@@ -0,0 +1,730 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    line::Line, motor::Motor, multivector::Multivector, point::Point, pseudoscalar::Pseudoscalar,
+    scalar::Scalar,
+};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::Mul;
+
+// Geometric Product
+
+// Scalar-Scalar
+impl<F: Float> Mul for Scalar<F> {
+    type Output = F;
+    fn mul(self, b: Scalar<F>) -> F {
+        self.0 * b.0
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Scalar<F>);
+
+// Scalar-Line
+impl<F: Float> Mul<Line<F>> for Scalar<F> {
+    type Output = Line<F>;
+    fn mul(self, b: Line<F>) -> Line<F> {
+        Line::new(self.0 * b.e0(), self.0 * b.e1(), self.0 * b.e2())
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Line<F>);
+
+// Line-Scalar
+impl<F: Float> Mul<Scalar<F>> for Line<F> {
+    type Output = Line<F>;
+    fn mul(self, b: Scalar<F>) -> Line<F> {
+        Line::new(self.e0() * b.0, self.e1() * b.0, self.e2() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Line<F>, Scalar<F>);
+
+// Scalar-Point
+impl<F: Float> Mul<Point<F>> for Scalar<F> {
+    type Output = Point<F>;
+    fn mul(self, b: Point<F>) -> Point<F> {
+        Point::new_components(self.0 * b.e20(), self.0 * b.e01(), self.0 * b.e12())
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Point<F>);
+
+// Point-Scalar
+impl<F: Float> Mul<Scalar<F>> for Point<F> {
+    type Output = Point<F>;
+    fn mul(self, b: Scalar<F>) -> Point<F> {
+        Point::new_components(self.e20() * b.0, self.e01() * b.0, self.e12() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Point<F>, Scalar<F>);
+
+// Scalar-Pseudoscalar
+impl<F: Float> Mul<Pseudoscalar<F>> for Scalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn mul(self, b: Pseudoscalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.0 * b.e012())
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Scalar
+impl<F: Float> Mul<Scalar<F>> for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn mul(self, b: Scalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.e012() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Scalar<F>);
+
+// Scalar-Motor
+impl<F: Float> Mul<Motor<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 * b.scalar(),
+            Line::zero(),
+            Point::new_components(self.0 * b.e20(), self.0 * b.e01(), self.0 * b.e12()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Motor<F>);
+
+// Motor-Scalar
+impl<F: Float> Mul<Scalar<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.0,
+            Line::zero(),
+            Point::new_components(self.e20() * b.0, self.e01() * b.0, self.e12() * b.0),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Motor<F>, Scalar<F>);
+
+// Scalar-Multivector
+impl<F: Float> Mul<Multivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 * b.scalar(),
+            Line::new(self.0 * b.e0(), self.0 * b.e1(), self.0 * b.e2()),
+            Point::new_components(self.0 * b.e20(), self.0 * b.e01(), self.0 * b.e12()),
+            self.0 * b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Multivector<F>);
+
+// Multivector-Scalar
+impl<F: Float> Mul<Scalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.0,
+            Line::new(self.e0() * b.0, self.e1() * b.0, self.e2() * b.0),
+            Point::new_components(self.e20() * b.0, self.e01() * b.0, self.e12() * b.0),
+            self.e012() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Scalar<F>);
+
+// Line-Line
+impl<F: Float> Mul for Line<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1() * b.e1() + self.e2() * b.e2(),
+            Line::zero(),
+            Point::new_components(
+                -self.e0() * b.e2() + self.e2() * b.e0(),
+                self.e0() * b.e1() - self.e1() * b.e0(),
+                self.e1() * b.e2() - self.e2() * b.e1(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Line<F>, Line<F>);
+
+// Line-Point
+impl<F: Float> Mul<Point<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Line::new(
+                -self.e1() * b.e01() + self.e2() * b.e20(),
+                -self.e2() * b.e12(),
+                self.e1() * b.e12(),
+            ),
+            Point::zero(),
+            self.e0() * b.e12() + self.e1() * b.e20() + self.e2() * b.e01(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Line<F>, Point<F>);
+
+// Point-Line
+impl<F: Float> Mul<Line<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Line::new(
+                -self.e20() * b.e2() + self.e01() * b.e1(),
+                self.e12() * b.e2(),
+                -self.e12() * b.e1(),
+            ),
+            Point::zero(),
+            self.e20() * b.e1() + self.e01() * b.e2() + self.e12() * b.e0(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Point<F>, Line<F>);
+
+// Line-Pseudoscalar
+impl<F: Float> Mul<Pseudoscalar<F>> for Line<F> {
+    type Output = Point<F>;
+    fn mul(self, b: Pseudoscalar<F>) -> Point<F> {
+        Point::new_components(self.e1() * b.e012(), self.e2() * b.e012(), F::zero())
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Line<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Line
+impl<F: Float> Mul<Line<F>> for Pseudoscalar<F> {
+    type Output = Point<F>;
+    fn mul(self, b: Line<F>) -> Point<F> {
+        Point::new_components(self.e012() * b.e1(), self.e012() * b.e2(), F::zero())
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Line<F>);
+
+// Line-Motor
+impl<F: Float> Mul<Motor<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Line::new(
+                self.e0() * b.scalar() - self.e1() * b.e01() + self.e2() * b.e20(),
+                self.e1() * b.scalar() - self.e2() * b.e12(),
+                self.e1() * b.e12() + self.e2() * b.scalar(),
+            ),
+            Point::zero(),
+            self.e0() * b.e12() + self.e1() * b.e20() + self.e2() * b.e01(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Line<F>, Motor<F>);
+
+// Motor-Line
+impl<F: Float> Mul<Line<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Line::new(
+                self.scalar() * b.e0() - self.e20() * b.e2() + self.e01() * b.e1(),
+                self.scalar() * b.e1() + self.e12() * b.e2(),
+                self.scalar() * b.e2() - self.e12() * b.e1(),
+            ),
+            Point::zero(),
+            self.e20() * b.e1() + self.e01() * b.e2() + self.e12() * b.e0(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Motor<F>, Line<F>);
+
+// Line-Multivector
+impl<F: Float> Mul<Multivector<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1() * b.e1() + self.e2() * b.e2(),
+            Line::new(
+                self.e0() * b.scalar() - self.e1() * b.e01() + self.e2() * b.e20(),
+                self.e1() * b.scalar() - self.e2() * b.e12(),
+                self.e1() * b.e12() + self.e2() * b.scalar(),
+            ),
+            Point::new_components(
+                -self.e0() * b.e2() + self.e1() * b.e012() + self.e2() * b.e0(),
+                self.e0() * b.e1() - self.e1() * b.e0() + self.e2() * b.e012(),
+                self.e1() * b.e2() - self.e2() * b.e1(),
+            ),
+            self.e0() * b.e12() + self.e1() * b.e20() + self.e2() * b.e01(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Line<F>, Multivector<F>);
+
+// Multivector-Line
+impl<F: Float> Mul<Line<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1() * b.e1() + self.e2() * b.e2(),
+            Line::new(
+                self.scalar() * b.e0() - self.e20() * b.e2() + self.e01() * b.e1(),
+                self.scalar() * b.e1() + self.e12() * b.e2(),
+                self.scalar() * b.e2() - self.e12() * b.e1(),
+            ),
+            Point::new_components(
+                -self.e0() * b.e2() + self.e2() * b.e0() + self.e012() * b.e1(),
+                self.e0() * b.e1() - self.e1() * b.e0() + self.e012() * b.e2(),
+                self.e1() * b.e2() - self.e2() * b.e1(),
+            ),
+            self.e20() * b.e1() + self.e01() * b.e2() + self.e12() * b.e0(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Line<F>);
+
+// Point-Point
+impl<F: Float> Mul for Point<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12() * b.e12(),
+            Line::zero(),
+            Point::new_components(
+                -self.e01() * b.e12() + self.e12() * b.e01(),
+                self.e20() * b.e12() - self.e12() * b.e20(),
+                F::zero(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Point<F>, Point<F>);
+
+// Point-Pseudoscalar
+impl<F: Float> Mul<Pseudoscalar<F>> for Point<F> {
+    type Output = Line<F>;
+    fn mul(self, b: Pseudoscalar<F>) -> Line<F> {
+        Line::new(-self.e12() * b.e012(), F::zero(), F::zero())
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Point<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Point
+impl<F: Float> Mul<Point<F>> for Pseudoscalar<F> {
+    type Output = Line<F>;
+    fn mul(self, b: Point<F>) -> Line<F> {
+        Line::new(-self.e012() * b.e12(), F::zero(), F::zero())
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Point<F>);
+
+// Point-Motor
+impl<F: Float> Mul<Motor<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12() * b.e12(),
+            Line::zero(),
+            Point::new_components(
+                self.e20() * b.scalar() - self.e01() * b.e12() + self.e12() * b.e01(),
+                self.e20() * b.e12() + self.e01() * b.scalar() - self.e12() * b.e20(),
+                self.e12() * b.scalar(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Point<F>, Motor<F>);
+
+// Motor-Point
+impl<F: Float> Mul<Point<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12() * b.e12(),
+            Line::zero(),
+            Point::new_components(
+                self.scalar() * b.e20() - self.e01() * b.e12() + self.e12() * b.e01(),
+                self.scalar() * b.e01() + self.e20() * b.e12() - self.e12() * b.e20(),
+                self.scalar() * b.e12(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Motor<F>, Point<F>);
+
+// Point-Multivector
+impl<F: Float> Mul<Multivector<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12() * b.e12(),
+            Line::new(
+                -self.e20() * b.e2() + self.e01() * b.e1() - self.e12() * b.e012(),
+                self.e12() * b.e2(),
+                -self.e12() * b.e1(),
+            ),
+            Point::new_components(
+                self.e20() * b.scalar() - self.e01() * b.e12() + self.e12() * b.e01(),
+                self.e20() * b.e12() + self.e01() * b.scalar() - self.e12() * b.e20(),
+                self.e12() * b.scalar(),
+            ),
+            self.e20() * b.e1() + self.e01() * b.e2() + self.e12() * b.e0(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Point<F>, Multivector<F>);
+
+// Multivector-Point
+impl<F: Float> Mul<Point<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12() * b.e12(),
+            Line::new(
+                -self.e1() * b.e01() + self.e2() * b.e20() - self.e012() * b.e12(),
+                -self.e2() * b.e12(),
+                self.e1() * b.e12(),
+            ),
+            Point::new_components(
+                self.scalar() * b.e20() - self.e01() * b.e12() + self.e12() * b.e01(),
+                self.scalar() * b.e01() + self.e20() * b.e12() - self.e12() * b.e20(),
+                self.scalar() * b.e12(),
+            ),
+            self.e0() * b.e12() + self.e1() * b.e20() + self.e2() * b.e01(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Point<F>);
+
+// Pseudoscalar-Pseudoscalar
+impl<F: Float> Mul for Pseudoscalar<F> {
+    type Output = Scalar<F>;
+    fn mul(self, _b: Pseudoscalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Motor
+impl<F: Float> Mul<Motor<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Line::new(-self.e012() * b.e12(), F::zero(), F::zero()),
+            Point::zero(),
+            self.e012() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Motor<F>);
+
+// Motor-Pseudoscalar
+impl<F: Float> Mul<Pseudoscalar<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Line::new(-self.e12() * b.e012(), F::zero(), F::zero()),
+            Point::zero(),
+            self.scalar() * b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Motor<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Multivector
+impl<F: Float> Mul<Multivector<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Line::new(-self.e012() * b.e12(), F::zero(), F::zero()),
+            Point::new_components(self.e012() * b.e1(), self.e012() * b.e2(), F::zero()),
+            self.e012() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Multivector<F>);
+
+// Multivector-Pseudoscalar
+impl<F: Float> Mul<Pseudoscalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Line::new(-self.e12() * b.e012(), F::zero(), F::zero()),
+            Point::new_components(self.e1() * b.e012(), self.e2() * b.e012(), F::zero()),
+            self.scalar() * b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Pseudoscalar<F>);
+
+// Motor-Multivector
+impl<F: Float> Mul<Multivector<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar() - self.e12() * b.e12(),
+            Line::new(
+                self.scalar() * b.e0() - self.e20() * b.e2() + self.e01() * b.e1()
+                    - self.e12() * b.e012(),
+                self.scalar() * b.e1() + self.e12() * b.e2(),
+                self.scalar() * b.e2() - self.e12() * b.e1(),
+            ),
+            Point::new_components(
+                self.scalar() * b.e20() + self.e20() * b.scalar() - self.e01() * b.e12()
+                    + self.e12() * b.e01(),
+                self.scalar() * b.e01() + self.e20() * b.e12() + self.e01() * b.scalar()
+                    - self.e12() * b.e20(),
+                self.scalar() * b.e12() + self.e12() * b.scalar(),
+            ),
+            self.scalar() * b.e012()
+                + self.e20() * b.e1()
+                + self.e01() * b.e2()
+                + self.e12() * b.e0(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Motor<F>, Multivector<F>);
+
+// Multivector-Motor
+impl<F: Float> Mul<Motor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar() - self.e12() * b.e12(),
+            Line::new(
+                self.e0() * b.scalar() - self.e1() * b.e01() + self.e2() * b.e20()
+                    - self.e012() * b.e12(),
+                self.e1() * b.scalar() - self.e2() * b.e12(),
+                self.e1() * b.e12() + self.e2() * b.scalar(),
+            ),
+            Point::new_components(
+                self.scalar() * b.e20() + self.e20() * b.scalar() - self.e01() * b.e12()
+                    + self.e12() * b.e01(),
+                self.scalar() * b.e01() + self.e20() * b.e12() + self.e01() * b.scalar()
+                    - self.e12() * b.e20(),
+                self.scalar() * b.e12() + self.e12() * b.scalar(),
+            ),
+            self.e0() * b.e12()
+                + self.e1() * b.e20()
+                + self.e2() * b.e01()
+                + self.e012() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Motor<F>);
+
+// Multivector-Multivector
+impl<F: Float> Mul for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar() + self.e1() * b.e1() + self.e2() * b.e2()
+                - self.e12() * b.e12(),
+            Line::new(
+                self.scalar() * b.e0() + self.e0() * b.scalar() - self.e1() * b.e01()
+                    + self.e2() * b.e20()
+                    - self.e20() * b.e2()
+                    + self.e01() * b.e1()
+                    - self.e12() * b.e012()
+                    - self.e012() * b.e12(),
+                self.scalar() * b.e1() + self.e1() * b.scalar() - self.e2() * b.e12()
+                    + self.e12() * b.e2(),
+                self.scalar() * b.e2() + self.e1() * b.e12() + self.e2() * b.scalar()
+                    - self.e12() * b.e1(),
+            ),
+            Point::new_components(
+                self.scalar() * b.e20() - self.e0() * b.e2()
+                    + self.e1() * b.e012()
+                    + self.e2() * b.e0()
+                    + self.e20() * b.scalar()
+                    - self.e01() * b.e12()
+                    + self.e12() * b.e01()
+                    + self.e012() * b.e1(),
+                self.scalar() * b.e01() + self.e0() * b.e1() - self.e1() * b.e0()
+                    + self.e2() * b.e012()
+                    + self.e20() * b.e12()
+                    + self.e01() * b.scalar()
+                    - self.e12() * b.e20()
+                    + self.e012() * b.e2(),
+                self.scalar() * b.e12() + self.e1() * b.e2() - self.e2() * b.e1()
+                    + self.e12() * b.scalar(),
+            ),
+            self.scalar() * b.e012()
+                + self.e0() * b.e12()
+                + self.e1() * b.e20()
+                + self.e2() * b.e01()
+                + self.e20() * b.e1()
+                + self.e01() * b.e2()
+                + self.e12() * b.e0()
+                + self.e012() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Multivector<F>);
+
+// Test
+#[cfg(test)]
+mod geometric_product {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn scalar_line() {
+        // -2
+        let scalar1 = Scalar::new(-2.0);
+        // 4e0+4e1-2e2
+        let line2 = Line::new(4.0, 4.0, -2.0);
+        let res = scalar1 * line2;
+        // -8e0-8e1+4e2
+        assert_relative_eq!(res.e0(), -8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 4.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn line_line() {
+        // 3e0+2e1+3e2
+        let line1 = Line::new(3.0, 2.0, 3.0);
+        // 5e0-3e1+5e2
+        let line2 = Line::new(5.0, -3.0, 5.0);
+        let res = line1 * line2;
+        // 9-19e01+19e12
+        assert_relative_eq!(res.scalar(), 9.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), -19.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 19.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn line_point() {
+        // -3e0-3e1+4e2
+        let line1 = Line::new(-3.0, -3.0, 4.0);
+        // 1e20-4e01-1e12
+        let point2 = Point::new_components(1.0, -4.0, -1.0);
+        let res = line1 * point2;
+        // -8e0+4e1+3e2-16e012
+        assert_relative_eq!(res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), -8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), -16.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn point_point() {
+        // 2e20-1e01+1e12
+        let point1 = Point::new_components(2.0, -1.0, 1.0);
+        // -1e20-2e01-2e12
+        let point2 = Point::new_components(-1.0, -2.0, -2.0);
+        let res = point1 * point2;
+        // 2-4e20-3e01
+        assert_relative_eq!(res.scalar(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn motor_point() {
+        // -1+2e20+2e01+3e12
+        let motor1 = Motor::new(-1.0, Point::new_components(2.0, 2.0, 3.0));
+        // -1e20+3e01+4e12
+        let point2 = Point::new_components(-1.0, 3.0, 4.0);
+        let res = motor1 * point2;
+        // -12+2e20+8e01-4e12
+        assert_relative_eq!(res.scalar(), -12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), 8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_motor() {
+        // 5+1e0+3e1-2e2-3e20-2e01-4e12+3e012
+        let mvec1 = Multivector::new(
+            5.0,
+            Line::new(1.0, 3.0, -2.0),
+            Point::new_components(-3.0, -2.0, -4.0),
+            3.0,
+        );
+        // -2-2e20-1e01-2e12
+        let motor2 = Motor::new(-2.0, Point::new_components(-2.0, -1.0, -2.0));
+        let res = mvec1 * motor2;
+        // -18+11e0-10e1-2e2-4e20-3e01-2e12-12e012
+        assert_relative_eq!(res.scalar(), -18.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 11.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), -12.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_multivector() {
+        // -4-1e0+4e1+3e2-2e20+5e01-4e12-1e012
+        let mvec1 = Multivector::new(
+            -4.0,
+            Line::new(-1.0, 4.0, 3.0),
+            Point::new_components(-2.0, 5.0, -4.0),
+            -1.0,
+        );
+        // 5+1e0+2e1+5e2+2e20+5e01+2e12+4e012
+        let mvec2 = Multivector::new(
+            5.0,
+            Line::new(1.0, 2.0, 5.0),
+            Point::new_components(2.0, 5.0, 2.0),
+            4.0,
+        );
+        let res = mvec1 * mvec2;
+        // 11+15e0-14e1+11e2-26e20+10e01-14e12+17e012
+        assert_relative_eq!(res.scalar(), 11.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 15.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 11.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), -26.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), 10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), -14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), 17.0, max_relative = 0.000001);
+    }
+}
@@ -0,0 +1,619 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    line::Line, motor::Motor, multivector::Multivector, point::Point, pseudoscalar::Pseudoscalar,
+    scalar::Scalar,
+};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::BitOr;
+
+// Inner Product
+
+// Scalar-Scalar
+impl<F: Float> BitOr for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitor(self, b: Scalar<F>) -> Scalar<F> {
+        Scalar(self.0 * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Scalar<F>);
+
+// Scalar-Line
+impl<F: Float> BitOr<Line<F>> for Scalar<F> {
+    type Output = Line<F>;
+    fn bitor(self, b: Line<F>) -> Line<F> {
+        Line::new(self.0 * b.e0(), self.0 * b.e1(), self.0 * b.e2())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Line<F>);
+
+// Line-Scalar
+impl<F: Float> BitOr<Scalar<F>> for Line<F> {
+    type Output = Line<F>;
+    fn bitor(self, b: Scalar<F>) -> Line<F> {
+        Line::new(self.e0() * b.0, self.e1() * b.0, self.e2() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Line<F>, Scalar<F>);
+
+// Scalar-Point
+impl<F: Float> BitOr<Point<F>> for Scalar<F> {
+    type Output = Point<F>;
+    fn bitor(self, b: Point<F>) -> Point<F> {
+        Point::new_components(self.0 * b.e20(), self.0 * b.e01(), self.0 * b.e12())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Point<F>);
+
+// Point-Scalar
+impl<F: Float> BitOr<Scalar<F>> for Point<F> {
+    type Output = Point<F>;
+    fn bitor(self, b: Scalar<F>) -> Point<F> {
+        Point::new_components(self.e20() * b.0, self.e01() * b.0, self.e12() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Point<F>, Scalar<F>);
+
+// Scalar-Pseudoscalar
+impl<F: Float> BitOr<Pseudoscalar<F>> for Scalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitor(self, b: Pseudoscalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.0 * b.e012())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Scalar
+impl<F: Float> BitOr<Scalar<F>> for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitor(self, b: Scalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.e012() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Scalar<F>);
+
+// Scalar-Motor
+impl<F: Float> BitOr<Motor<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 * b.scalar(),
+            Line::zero(),
+            Point::new_components(self.0 * b.e20(), self.0 * b.e01(), self.0 * b.e12()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Motor<F>);
+
+// Motor-Scalar
+impl<F: Float> BitOr<Scalar<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.0,
+            Line::zero(),
+            Point::new_components(self.e20() * b.0, self.e01() * b.0, self.e12() * b.0),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Motor<F>, Scalar<F>);
+
+// Scalar-Multivector
+impl<F: Float> BitOr<Multivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 * b.scalar(),
+            Line::new(self.0 * b.e0(), self.0 * b.e1(), self.0 * b.e2()),
+            Point::new_components(self.0 * b.e20(), self.0 * b.e01(), self.0 * b.e12()),
+            self.0 * b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Multivector<F>);
+
+// Multivector-Scalar
+impl<F: Float> BitOr<Scalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.0,
+            Line::new(self.e0() * b.0, self.e1() * b.0, self.e2() * b.0),
+            Point::new_components(self.e20() * b.0, self.e01() * b.0, self.e12() * b.0),
+            self.e012() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Scalar<F>);
+
+// Line-Line
+impl<F: Float> BitOr for Line<F> {
+    type Output = Scalar<F>;
+    fn bitor(self, b: Line<F>) -> Scalar<F> {
+        Scalar(self.e1() * b.e1() + self.e2() * b.e2())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Line<F>, Line<F>);
+
+// Line-Point
+impl<F: Float> BitOr<Point<F>> for Line<F> {
+    type Output = Line<F>;
+    fn bitor(self, b: Point<F>) -> Line<F> {
+        Line::new(
+            -self.e1() * b.e01() + self.e2() * b.e20(),
+            -self.e2() * b.e12(),
+            self.e1() * b.e12(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Line<F>, Point<F>);
+
+// Point-Line
+impl<F: Float> BitOr<Line<F>> for Point<F> {
+    type Output = Line<F>;
+    fn bitor(self, b: Line<F>) -> Line<F> {
+        Line::new(
+            -self.e20() * b.e2() + self.e01() * b.e1(),
+            self.e12() * b.e2(),
+            -self.e12() * b.e1(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Point<F>, Line<F>);
+
+// Line-Pseudoscalar
+impl<F: Float> BitOr<Pseudoscalar<F>> for Line<F> {
+    type Output = Point<F>;
+    fn bitor(self, b: Pseudoscalar<F>) -> Point<F> {
+        Point::new_components(self.e1() * b.e012(), self.e2() * b.e012(), F::zero())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Line<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Line
+impl<F: Float> BitOr<Line<F>> for Pseudoscalar<F> {
+    type Output = Point<F>;
+    fn bitor(self, b: Line<F>) -> Point<F> {
+        Point::new_components(self.e012() * b.e1(), self.e012() * b.e2(), F::zero())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Line<F>);
+
+// Line-Motor
+impl<F: Float> BitOr<Motor<F>> for Line<F> {
+    type Output = Line<F>;
+    fn bitor(self, b: Motor<F>) -> Line<F> {
+        Line::new(
+            self.e0() * b.scalar() - self.e1() * b.e01() + self.e2() * b.e20(),
+            self.e1() * b.scalar() - self.e2() * b.e12(),
+            self.e1() * b.e12() + self.e2() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Line<F>, Motor<F>);
+
+// Motor-Line
+impl<F: Float> BitOr<Line<F>> for Motor<F> {
+    type Output = Line<F>;
+    fn bitor(self, b: Line<F>) -> Line<F> {
+        Line::new(
+            self.scalar() * b.e0() - self.e20() * b.e2() + self.e01() * b.e1(),
+            self.scalar() * b.e1() + self.e12() * b.e2(),
+            self.scalar() * b.e2() - self.e12() * b.e1(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Motor<F>, Line<F>);
+
+// Line-Multivector
+impl<F: Float> BitOr<Multivector<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1() * b.e1() + self.e2() * b.e2(),
+            Line::new(
+                self.e0() * b.scalar() - self.e1() * b.e01() + self.e2() * b.e20(),
+                self.e1() * b.scalar() - self.e2() * b.e12(),
+                self.e1() * b.e12() + self.e2() * b.scalar(),
+            ),
+            Point::new_components(self.e1() * b.e012(), self.e2() * b.e012(), F::zero()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Line<F>, Multivector<F>);
+
+// Multivector-Line
+impl<F: Float> BitOr<Line<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1() * b.e1() + self.e2() * b.e2(),
+            Line::new(
+                self.scalar() * b.e0() - self.e20() * b.e2() + self.e01() * b.e1(),
+                self.scalar() * b.e1() + self.e12() * b.e2(),
+                self.scalar() * b.e2() - self.e12() * b.e1(),
+            ),
+            Point::new_components(self.e012() * b.e1(), self.e012() * b.e2(), F::zero()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Line<F>);
+
+// Point-Point
+impl<F: Float> BitOr for Point<F> {
+    type Output = Scalar<F>;
+    fn bitor(self, b: Point<F>) -> Scalar<F> {
+        Scalar(-self.e12() * b.e12())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Point<F>, Point<F>);
+
+// Point-Pseudoscalar
+impl<F: Float> BitOr<Pseudoscalar<F>> for Point<F> {
+    type Output = Line<F>;
+    fn bitor(self, b: Pseudoscalar<F>) -> Line<F> {
+        Line::new(-self.e12() * b.e012(), F::zero(), F::zero())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Point<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Point
+impl<F: Float> BitOr<Point<F>> for Pseudoscalar<F> {
+    type Output = Line<F>;
+    fn bitor(self, b: Point<F>) -> Line<F> {
+        Line::new(-self.e012() * b.e12(), F::zero(), F::zero())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Point<F>);
+
+// Point-Motor
+impl<F: Float> BitOr<Motor<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12() * b.e12(),
+            Line::zero(),
+            Point::new_components(
+                self.e20() * b.scalar(),
+                self.e01() * b.scalar(),
+                self.e12() * b.scalar(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Point<F>, Motor<F>);
+
+// Motor-Point
+impl<F: Float> BitOr<Point<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12() * b.e12(),
+            Line::zero(),
+            Point::new_components(
+                self.scalar() * b.e20(),
+                self.scalar() * b.e01(),
+                self.scalar() * b.e12(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Motor<F>, Point<F>);
+
+// Point-Multivector
+impl<F: Float> BitOr<Multivector<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12() * b.e12(),
+            Line::new(
+                -self.e20() * b.e2() + self.e01() * b.e1() - self.e12() * b.e012(),
+                self.e12() * b.e2(),
+                -self.e12() * b.e1(),
+            ),
+            Point::new_components(
+                self.e20() * b.scalar(),
+                self.e01() * b.scalar(),
+                self.e12() * b.scalar(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Point<F>, Multivector<F>);
+
+// Multivector-Point
+impl<F: Float> BitOr<Point<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12() * b.e12(),
+            Line::new(
+                -self.e1() * b.e01() + self.e2() * b.e20() - self.e012() * b.e12(),
+                -self.e2() * b.e12(),
+                self.e1() * b.e12(),
+            ),
+            Point::new_components(
+                self.scalar() * b.e20(),
+                self.scalar() * b.e01(),
+                self.scalar() * b.e12(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Point<F>);
+
+// Pseudoscalar-Pseudoscalar
+impl<F: Float> BitOr for Pseudoscalar<F> {
+    type Output = Scalar<F>;
+    fn bitor(self, _b: Pseudoscalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Motor
+impl<F: Float> BitOr<Motor<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Line::new(-self.e012() * b.e12(), F::zero(), F::zero()),
+            Point::zero(),
+            self.e012() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Motor<F>);
+
+// Motor-Pseudoscalar
+impl<F: Float> BitOr<Pseudoscalar<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Line::new(-self.e12() * b.e012(), F::zero(), F::zero()),
+            Point::zero(),
+            self.scalar() * b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Motor<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Multivector
+impl<F: Float> BitOr<Multivector<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Line::new(-self.e012() * b.e12(), F::zero(), F::zero()),
+            Point::new_components(self.e012() * b.e1(), self.e012() * b.e2(), F::zero()),
+            self.e012() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Multivector<F>);
+
+// Multivector-Pseudoscalar
+impl<F: Float> BitOr<Pseudoscalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Line::new(-self.e12() * b.e012(), F::zero(), F::zero()),
+            Point::new_components(self.e1() * b.e012(), self.e2() * b.e012(), F::zero()),
+            self.scalar() * b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Pseudoscalar<F>);
+
+// Motor-Motor
+impl<F: Float> BitOr for Motor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar() - self.e12() * b.e12(),
+            Line::zero(),
+            Point::new_components(
+                self.scalar() * b.e20() + self.e20() * b.scalar(),
+                self.scalar() * b.e01() + self.e01() * b.scalar(),
+                self.scalar() * b.e12() + self.e12() * b.scalar(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Motor<F>, Motor<F>);
+
+// Motor-Multivector
+impl<F: Float> BitOr<Multivector<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar() - self.e12() * b.e12(),
+            Line::new(
+                self.scalar() * b.e0() - self.e20() * b.e2() + self.e01() * b.e1()
+                    - self.e12() * b.e012(),
+                self.scalar() * b.e1() + self.e12() * b.e2(),
+                self.scalar() * b.e2() - self.e12() * b.e1(),
+            ),
+            Point::new_components(
+                self.scalar() * b.e20() + self.e20() * b.scalar(),
+                self.scalar() * b.e01() + self.e01() * b.scalar(),
+                self.scalar() * b.e12() + self.e12() * b.scalar(),
+            ),
+            self.scalar() * b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Motor<F>, Multivector<F>);
+
+// Multivector-Motor
+impl<F: Float> BitOr<Motor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar() - self.e12() * b.e12(),
+            Line::new(
+                self.e0() * b.scalar() - self.e1() * b.e01() + self.e2() * b.e20()
+                    - self.e012() * b.e12(),
+                self.e1() * b.scalar() - self.e2() * b.e12(),
+                self.e1() * b.e12() + self.e2() * b.scalar(),
+            ),
+            Point::new_components(
+                self.scalar() * b.e20() + self.e20() * b.scalar(),
+                self.scalar() * b.e01() + self.e01() * b.scalar(),
+                self.scalar() * b.e12() + self.e12() * b.scalar(),
+            ),
+            self.e012() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Motor<F>);
+
+// Multivector-Multivector
+impl<F: Float> BitOr for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar() + self.e1() * b.e1() + self.e2() * b.e2()
+                - self.e12() * b.e12(),
+            Line::new(
+                self.scalar() * b.e0() + self.e0() * b.scalar() - self.e1() * b.e01()
+                    + self.e2() * b.e20()
+                    - self.e20() * b.e2()
+                    + self.e01() * b.e1()
+                    - self.e12() * b.e012()
+                    - self.e012() * b.e12(),
+                self.scalar() * b.e1() + self.e1() * b.scalar() - self.e2() * b.e12()
+                    + self.e12() * b.e2(),
+                self.scalar() * b.e2() + self.e1() * b.e12() + self.e2() * b.scalar()
+                    - self.e12() * b.e1(),
+            ),
+            Point::new_components(
+                self.scalar() * b.e20()
+                    + self.e1() * b.e012()
+                    + self.e20() * b.scalar()
+                    + self.e012() * b.e1(),
+                self.scalar() * b.e01()
+                    + self.e2() * b.e012()
+                    + self.e01() * b.scalar()
+                    + self.e012() * b.e2(),
+                self.scalar() * b.e12() + self.e12() * b.scalar(),
+            ),
+            self.scalar() * b.e012() + self.e012() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Multivector<F>);
+
+// Test
+#[cfg(test)]
+mod inner_product {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn line_line() {
+        // 4e0-1e1-3e2
+        let line1 = Line::new(4.0, -1.0, -3.0);
+        // -3e0+3e1+4e2
+        let line2 = Line::new(-3.0, 3.0, 4.0);
+        let res = line1 | line2;
+        // -15
+        assert_relative_eq!(res.0, -15.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn line_point() {
+        // 2e0-3e1+4e2
+        let line1 = Line::new(2.0, -3.0, 4.0);
+        // -3e20-2e01-1e12
+        let point2 = Point::new_components(-3.0, -2.0, -1.0);
+        let res = line1 | point2;
+        // -18e0+4e1+3e2
+        assert_relative_eq!(res.e0(), -18.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 3.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn point_point() {
+        // -4e20-4e01-1e12
+        let point1 = Point::new_components(-4.0, -4.0, -1.0);
+        // -3e20-2e01+5e12
+        let point2 = Point::new_components(-3.0, -2.0, 5.0);
+        let res = point1 | point2;
+        // 5
+        assert_relative_eq!(res.0, 5.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn motor_line() {
+        // 1-2e20+3e01+3e12
+        let motor1 = Motor::new(1.0, Point::new_components(-2.0, 3.0, 3.0));
+        // -1e0-1e1-3e2
+        let line2 = Line::new(-1.0, -1.0, -3.0);
+        let res = motor1 | line2;
+        // -10e0-10e1
+        assert_relative_eq!(res.e0(), -10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_multivector() {
+        // 2-1e0-1e1+5e2+4e20+1e01+1e12-1e012
+        let mvec1 = Multivector::new(
+            2.0,
+            Line::new(-1.0, -1.0, 5.0),
+            Point::new_components(4.0, 1.0, 1.0),
+            -1.0,
+        );
+        // 5+4e0+5e1-1e2+5e20+1e01-4e12+4e012
+        let mvec2 = Multivector::new(
+            5.0,
+            Line::new(4.0, 5.0, -1.0),
+            Point::new_components(5.0, 1.0, -4.0),
+            4.0,
+        );
+        let res = mvec1 | mvec2;
+        // 4+30e0+24e1+22e2+21e20+28e01-3e12+3e012
+        assert_relative_eq!(res.scalar(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 30.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 24.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 22.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), 21.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), 28.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), 3.0, max_relative = 0.000001);
+    }
+}
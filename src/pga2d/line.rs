@@ -0,0 +1,244 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::fmt;
+
+use core::ops::Neg;
+
+use num_traits::Float;
+
+use super::{PGA2DOps, point::Point, scalar::Scalar};
+
+/// # 2D Projective Geometric Algebra Line
+/// The vectors of the algebra are lines.
+/// The line $ax+by+c=0$ is
+/// $$\ell = c \mathrm{e}_0 + a \mathrm{e}_1 + b \mathrm{e}_2$$
+/// The line $\mathrm{e}_0$ is the line at infinity.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Line<F: Float> {
+    e0: F,
+    e1: F,
+    e2: F,
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + fmt::Display> fmt::Display for Line<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Line {{")?;
+        write!(f, " {}e0", self.e0)?;
+
+        // Add the appropriate sign to the remaining components
+        for (value, name) in [(self.e1, "e1"), (self.e2, "e2")] {
+            if value >= F::zero() {
+                write!(f, " + {}{}", value, name)?;
+            } else {
+                write!(f, " - {}{}", value.abs(), name)?;
+            }
+        }
+        write!(f, " }}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F: Float + defmt::Format> defmt::Format for Line<F> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Line {{");
+        defmt::write!(f, " {}e0", self.e0);
+
+        // Add the appropriate sign to the remaining components
+        for (value, name) in [(self.e1, "e1"), (self.e2, "e2")] {
+            if value >= F::zero() {
+                defmt::write!(f, " + {}{}", value, name);
+            } else {
+                defmt::write!(f, " - {}{}", value.abs(), name);
+            }
+        }
+        defmt::write!(f, " }}");
+    }
+}
+
+impl<F: Float> Line<F> {
+    /// The zero line
+    pub fn zero() -> Self {
+        Self {
+            e0: F::zero(),
+            e1: F::zero(),
+            e2: F::zero(),
+        }
+    }
+
+    /// Create new line from linear combination of the basis vectors
+    pub fn new(e0: F, e1: F, e2: F) -> Self {
+        Self { e0, e1, e2 }
+    }
+
+    /// Create the line $ax+by+c=0$
+    pub fn from_equation(a: F, b: F, c: F) -> Self {
+        Self {
+            e0: c,
+            e1: a,
+            e2: b,
+        }
+    }
+
+    /// Get line
+    pub fn line(self) -> Self {
+        self
+    }
+
+    /// Get the distance part $\mathrm{e}_0$
+    pub fn e0(&self) -> F {
+        self.e0
+    }
+
+    /// Get the $\mathrm{e}_1$ part of the normal
+    pub fn e1(&self) -> F {
+        self.e1
+    }
+
+    /// Get the $\mathrm{e}_2$ part of the normal
+    pub fn e2(&self) -> F {
+        self.e2
+    }
+
+    /// # Dual
+    /// The dual of a line is a point
+    pub fn dual(self) -> Point<F> {
+        Point::new_components(self.e1, self.e2, self.e0)
+    }
+
+    /// Tries to get the signed angle from this line to another line.
+    /// The angle is in radians and is positive when turning counterclockwise.
+    /// This is not posible if one of the lines is the line at infinity.
+    pub fn try_angle(self, line: Line<F>) -> Option<F> {
+        if self.norm().0.is_zero() || line.norm().0.is_zero() {
+            return None;
+        }
+        Some((self ^ line).e12().atan2((self | line).0))
+    }
+
+    /// Tries to get the signed distance from the line to a point.
+    /// The distance is positive on the side the normal $(a,b)$ points to.
+    /// This is not posible for the line at infinity or for ideal points.
+    pub fn try_signed_distance(self, point: Point<F>) -> Option<F> {
+        let line = self.try_normalize()?;
+        let point = point.try_normalize()?;
+        Some((line ^ point).e012())
+    }
+}
+
+// Negation
+impl<F: Float> Neg for Line<F> {
+    type Output = Line<F>;
+    fn neg(self) -> Line<F> {
+        Line::new(-self.e0, -self.e1, -self.e2)
+    }
+}
+
+impl<F: Float> PGA2DOps<F> for Line<F> {
+    fn reverse(self) -> Self {
+        self
+    }
+
+    fn conjugate(self) -> Self {
+        -self
+    }
+
+    fn involute(self) -> Self {
+        -self
+    }
+
+    // The length of the normal
+    fn norm(self) -> Scalar<F> {
+        Scalar((self.e1 * self.e1 + self.e2 * self.e2).sqrt())
+    }
+
+    fn ideal_norm(self) -> Scalar<F> {
+        Scalar(self.e0.abs())
+    }
+
+    // A normalized line has a unit normal and $\mathrm{e}_0$ is the signed distance to the origin
+    fn try_normalize(self) -> Option<Self> {
+        self.norm().try_inverse().map(|norm_inverse| {
+            let norm_inverse = norm_inverse.0;
+            Line::new(
+                self.e0 * norm_inverse,
+                self.e1 * norm_inverse,
+                self.e2 * norm_inverse,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod line_ops {
+    use super::*;
+    use approx::assert_relative_eq;
+    use core::f64::consts::TAU;
+
+    #[test]
+    fn line_from_equation() {
+        // 2x+3y+4=0
+        let line = Line::from_equation(2.0, 3.0, 4.0);
+        assert_eq!(line, Line::new(4.0, 2.0, 3.0));
+        assert_eq!(line.dual().dual(), line);
+    }
+
+    #[test]
+    fn line_normalize() {
+        // y = 2
+        let line = Line::from_equation(0.0, 3.0, -6.0);
+        let res = line.try_normalize().unwrap();
+        assert_relative_eq!(res.e2(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), -2.0, max_relative = 0.000001);
+        assert_eq!(Line::new(1.0, 0.0, 0.0).try_normalize(), None);
+    }
+
+    #[test]
+    fn line_angle() {
+        // y = 0 and y = x
+        let line1 = Line::from_equation(0.0, 1.0, 0.0);
+        let line2 = Line::from_equation(-1.0, 1.0, 0.0);
+        let res = line1.try_angle(line2).unwrap();
+        assert_relative_eq!(res, TAU / 8.0, max_relative = 0.000001);
+        let res = line2.try_angle(line1).unwrap();
+        assert_relative_eq!(res, -TAU / 8.0, max_relative = 0.000001);
+        assert_eq!(line1.try_angle(Line::new(1.0, 0.0, 0.0)), None);
+    }
+
+    #[test]
+    fn line_signed_distance() {
+        // y = 2
+        let line = Line::from_equation(0.0, 2.0, -4.0);
+        let res = line.try_signed_distance(Point::new(5.0, 5.0)).unwrap();
+        assert_relative_eq!(res, 3.0, max_relative = 0.000001);
+        let res = line.try_signed_distance(Point::new(5.0, -1.0)).unwrap();
+        assert_relative_eq!(res, -3.0, max_relative = 0.000001);
+        assert_eq!(
+            line.try_signed_distance(Point::new_direction(1.0, 0.0)),
+            None
+        );
+    }
+}
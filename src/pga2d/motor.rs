@@ -0,0 +1,266 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::fmt;
+
+use crate::forward_ref_binop;
+use core::ops::{Mul, Neg};
+
+use num_traits::Float;
+
+use super::{PGA2DOps, point::Point, scalar::Scalar};
+use crate::vga2d;
+
+/// # 2D Projective Geometric Algebra Motor
+///
+/// The motor is the rigid body motion object of the projective plane.
+/// Every motion in the plane is a rotation around a point,
+/// where translations are rotations around an ideal point.
+/// $$ M = s + P $$
+/// An element $X$ is moved by the sandwich product
+/// $$ X' = \widetilde{M} X M $$
+/// which is the same order as the rotation with a [`vga2d::Rotor`].
+///
+/// Motors are composed with the geometric product.
+/// The motor $M_1 M_2$ first applies $M_1$ and then $M_2$.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Motor<F: Float> {
+    pub(super) scalar: Scalar<F>,
+    pub(super) point: Point<F>,
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + fmt::Display> fmt::Display for Motor<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Motor {{")?;
+        writeln!(f, "\tscalar: {}", self.scalar())?;
+        writeln!(f, "\tpoint: {}", self.point)?;
+        write!(f, "}}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F: Float + defmt::Format> defmt::Format for Motor<F> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Motor {{\n");
+        defmt::write!(f, "\tscalar: {}\n", self.scalar());
+        defmt::write!(f, "\tpoint: {}\n", self.point);
+        defmt::write!(f, "}}");
+    }
+}
+
+impl<F: Float> Motor<F> {
+    /// Create new motor from its grades
+    pub fn new(scalar: F, point: Point<F>) -> Self {
+        Motor {
+            scalar: Scalar(scalar),
+            point,
+        }
+    }
+
+    /// This is the identity motor.
+    /// Anything moved with this will return it self.
+    pub fn identity() -> Self {
+        Motor {
+            scalar: Scalar(F::one()),
+            point: Point::zero(),
+        }
+    }
+
+    /// Creates the translator that moves elements by $(x,y)$
+    /// $$ T = 1 + \frac{1}{2}\left( x \mathrm{e}_{01} - y \mathrm{e}_{20} \right) $$
+    pub fn from_translation(x: F, y: F) -> Self {
+        let half = F::one() / (F::one() + F::one());
+        Motor {
+            scalar: Scalar(F::one()),
+            point: Point::new_components(-y * half, x * half, F::zero()),
+        }
+    }
+
+    /// Tries to create the motor that rotates around a point.
+    /// The angle must be in radians and a positive angle rotates counterclockwise.
+    /// $$ M = \cos \left( \frac{\theta}{2}  \right) + \sin \left( \frac{\theta}{2} \right) \hat{P} $$
+    /// This is not posible for an ideal point.
+    pub fn try_from_rotation(angle: F, center: Point<F>) -> Option<Self> {
+        let half_angle = angle / (F::one() + F::one());
+        center.try_normalize().map(|center| {
+            let (sin, cos) = half_angle.sin_cos();
+            Motor {
+                scalar: Scalar(cos),
+                point: Point::new_components(center.e20() * sin, center.e01() * sin, sin),
+            }
+        })
+    }
+
+    /// Creates the motor that does the same rotation around the origin as a 2D rotor
+    pub fn from_rotor(rotor: vga2d::Rotor<F>) -> Self {
+        Motor {
+            scalar: Scalar(rotor.scalar()),
+            point: Point::new_components(F::zero(), F::zero(), rotor.e12()),
+        }
+    }
+
+    /// Get motor
+    pub fn motor(self) -> Self {
+        self
+    }
+
+    /// Get the scalar grade of the motor
+    pub fn scalar(&self) -> F {
+        self.scalar.0
+    }
+
+    /// Get the point grade of the motor
+    pub fn point(&self) -> Point<F> {
+        self.point
+    }
+
+    /// Get the $\mathrm{e}_2\mathrm{e}_0$ part of the point grade
+    pub fn e20(&self) -> F {
+        self.point.e20()
+    }
+
+    /// Get the $\mathrm{e}_0\mathrm{e}_1$ part of the point grade
+    pub fn e01(&self) -> F {
+        self.point.e01()
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2$ part of the point grade
+    pub fn e12(&self) -> F {
+        self.point.e12()
+    }
+
+    /// Get the signed angle of rotation of the motor.
+    /// A positive angle rotates counterclockwise and translations have no angle.
+    pub fn get_angle(&self) -> F {
+        let angle = self.e12().atan2(self.scalar());
+        angle + angle
+    }
+}
+
+// Negation
+impl<F: Float> Neg for Motor<F> {
+    type Output = Motor<F>;
+    fn neg(self) -> Motor<F> {
+        Motor {
+            scalar: -self.scalar,
+            point: -self.point,
+        }
+    }
+}
+
+// Motor-Motor
+// The product of two motors is a motor
+// The result is normalized to remove the numerical drift
+impl<F: Float> Mul for Motor<F> {
+    type Output = Motor<F>;
+    fn mul(self, b: Motor<F>) -> Motor<F> {
+        let motor = Motor::new(
+            self.scalar() * b.scalar() - self.e12() * b.e12(),
+            Point::new_components(
+                self.scalar() * b.e20() + self.e20() * b.scalar() - self.e01() * b.e12()
+                    + self.e12() * b.e01(),
+                self.scalar() * b.e01() + self.e20() * b.e12() + self.e01() * b.scalar()
+                    - self.e12() * b.e20(),
+                self.scalar() * b.e12() + self.e12() * b.scalar(),
+            ),
+        );
+        motor.try_normalize().unwrap_or(motor)
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Motor<F>, Motor<F>);
+
+impl<F: Float> PGA2DOps<F> for Motor<F> {
+    fn reverse(self) -> Self {
+        Motor {
+            scalar: self.scalar,
+            point: -self.point,
+        }
+    }
+
+    fn conjugate(self) -> Self {
+        Motor {
+            scalar: self.scalar,
+            point: -self.point,
+        }
+    }
+
+    fn involute(self) -> Self {
+        self
+    }
+
+    // The norm of the rotational part
+    // \[\|M\|^2=\left< M \widetilde{M} \right>_0\]
+    fn norm(self) -> Scalar<F> {
+        Scalar((self.scalar() * self.scalar() + self.e12() * self.e12()).sqrt())
+    }
+
+    // The norm of the translational part
+    fn ideal_norm(self) -> Scalar<F> {
+        Scalar((self.e20() * self.e20() + self.e01() * self.e01()).sqrt())
+    }
+
+    fn try_normalize(self) -> Option<Self> {
+        self.norm().try_inverse().map(|norm_inverse| {
+            let norm_inverse = norm_inverse.0;
+            Motor {
+                scalar: Scalar(self.scalar() * norm_inverse),
+                point: Point::new_components(
+                    self.e20() * norm_inverse,
+                    self.e01() * norm_inverse,
+                    self.e12() * norm_inverse,
+                ),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod motor_ops {
+    use super::*;
+    use approx::assert_relative_eq;
+    use core::f64::consts::TAU;
+
+    #[test]
+    fn motor_angle() {
+        let motor = Motor::try_from_rotation(TAU / 3.0, Point::new(1.0, 2.0)).unwrap();
+        assert_relative_eq!(motor.get_angle(), TAU / 3.0, max_relative = 0.000001);
+        let motor = Motor::from_translation(1.0, 2.0);
+        assert_relative_eq!(motor.get_angle(), 0.0, epsilon = 0.000001);
+        assert_eq!(
+            Motor::try_from_rotation(1.0, Point::new_direction(1.0, 0.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn motor_composition_angle() {
+        let motor1 = Motor::try_from_rotation(0.5, Point::new(1.0, 2.0)).unwrap();
+        let motor2 = Motor::try_from_rotation(0.25, Point::new(-3.0, 1.0)).unwrap();
+        let res = motor1 * motor2;
+        assert_relative_eq!(res.get_angle(), 0.75, max_relative = 0.000001);
+        assert_relative_eq!(res.norm().0, 1.0, max_relative = 0.000001);
+    }
+}
@@ -0,0 +1,241 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::fmt;
+
+use core::ops::Neg;
+
+use num_traits::Float;
+
+use super::{PGA2DOps, line::Line, scalar::Scalar};
+use crate::vga2d;
+
+/// # 2D Projective Geometric Algebra Point
+/// The bivectors of the algebra are points.
+/// The point $(x,y)$ is
+/// $$P = x \mathrm{e}_{20} + y \mathrm{e}_{01} + \mathrm{e}_{12}$$
+/// A point with $\mathrm{e}_{12}=0$ is an ideal point, which is a direction.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Point<F: Float> {
+    e20: F,
+    e01: F,
+    e12: F,
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + fmt::Display> fmt::Display for Point<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Point {{")?;
+        write!(f, " {}e20", self.e20)?;
+
+        // Add the appropriate sign to the remaining components
+        for (value, name) in [(self.e01, "e01"), (self.e12, "e12")] {
+            if value >= F::zero() {
+                write!(f, " + {}{}", value, name)?;
+            } else {
+                write!(f, " - {}{}", value.abs(), name)?;
+            }
+        }
+        write!(f, " }}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F: Float + defmt::Format> defmt::Format for Point<F> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Point {{");
+        defmt::write!(f, " {}e20", self.e20);
+
+        // Add the appropriate sign to the remaining components
+        for (value, name) in [(self.e01, "e01"), (self.e12, "e12")] {
+            if value >= F::zero() {
+                defmt::write!(f, " + {}{}", value, name);
+            } else {
+                defmt::write!(f, " - {}{}", value.abs(), name);
+            }
+        }
+        defmt::write!(f, " }}");
+    }
+}
+
+impl<F: Float> Point<F> {
+    /// The zero point
+    pub fn zero() -> Self {
+        Self {
+            e20: F::zero(),
+            e01: F::zero(),
+            e12: F::zero(),
+        }
+    }
+
+    /// Create the Euclidean point $(x,y)$
+    pub fn new(x: F, y: F) -> Self {
+        Self {
+            e20: x,
+            e01: y,
+            e12: F::one(),
+        }
+    }
+
+    /// Create the ideal point in the direction $(x,y)$
+    pub fn new_direction(x: F, y: F) -> Self {
+        Self {
+            e20: x,
+            e01: y,
+            e12: F::zero(),
+        }
+    }
+
+    /// Create new point from linear combination of the basis bivectors
+    pub fn new_components(e20: F, e01: F, e12: F) -> Self {
+        Self { e20, e01, e12 }
+    }
+
+    /// Create the Euclidean point at the tip of a 2D vector
+    pub fn from_vector(vector: vga2d::Vector<F>) -> Self {
+        Point::new(vector.e1(), vector.e2())
+    }
+
+    /// Get the position of a Euclidean point as a 2D vector.
+    /// This is not posible for ideal points.
+    pub fn try_to_vector(&self) -> Option<vga2d::Vector<F>> {
+        Scalar(self.e12).try_inverse().map(|weight_inverse| {
+            let weight_inverse = weight_inverse.0;
+            vga2d::Vector::new(self.e20 * weight_inverse, self.e01 * weight_inverse)
+        })
+    }
+
+    /// Get point
+    pub fn point(self) -> Self {
+        self
+    }
+
+    /// Get the $\mathrm{e}_2\mathrm{e}_0$ part of the point
+    pub fn e20(&self) -> F {
+        self.e20
+    }
+
+    /// Get the $\mathrm{e}_0\mathrm{e}_1$ part of the point
+    pub fn e01(&self) -> F {
+        self.e01
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2$ part of the point
+    pub fn e12(&self) -> F {
+        self.e12
+    }
+
+    /// # Dual
+    /// The dual of a point is a line
+    pub fn dual(self) -> Line<F> {
+        Line::new(self.e12, self.e20, self.e01)
+    }
+
+    /// Tries to get the distance between two points.
+    /// This is not posible if one of the points is an ideal point.
+    pub fn try_distance(self, point: Point<F>) -> Option<F> {
+        let point1 = self.try_normalize()?;
+        let point2 = point.try_normalize()?;
+        Some((point1 & point2).norm().0)
+    }
+}
+
+// Negation
+impl<F: Float> Neg for Point<F> {
+    type Output = Point<F>;
+    fn neg(self) -> Point<F> {
+        Point::new_components(-self.e20, -self.e01, -self.e12)
+    }
+}
+
+impl<F: Float> PGA2DOps<F> for Point<F> {
+    fn reverse(self) -> Self {
+        -self
+    }
+
+    fn conjugate(self) -> Self {
+        -self
+    }
+
+    fn involute(self) -> Self {
+        self
+    }
+
+    // The weight of the point
+    fn norm(self) -> Scalar<F> {
+        Scalar(self.e12.abs())
+    }
+
+    fn ideal_norm(self) -> Scalar<F> {
+        Scalar((self.e20 * self.e20 + self.e01 * self.e01).sqrt())
+    }
+
+    // A normalized point has $\mathrm{e}_{12}=1$
+    fn try_normalize(self) -> Option<Self> {
+        Scalar(self.e12).try_inverse().map(|weight_inverse| {
+            let weight_inverse = weight_inverse.0;
+            Point::new_components(
+                self.e20 * weight_inverse,
+                self.e01 * weight_inverse,
+                F::one(),
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod point_ops {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn point_new() {
+        let point = Point::new(1.0, 2.0);
+        assert_eq!(point, Point::new_components(1.0, 2.0, 1.0));
+        assert_eq!(point.dual().dual(), point);
+        let direction = Point::new_direction(1.0, 2.0);
+        assert_eq!(direction.e12(), 0.0);
+    }
+
+    #[test]
+    fn point_vector() {
+        let point = Point::new_components(2.0, -6.0, 2.0);
+        let vector = point.try_to_vector().unwrap();
+        assert_relative_eq!(vector.e1(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(vector.e2(), -3.0, max_relative = 0.000001);
+        assert_eq!(Point::from_vector(vector), point.try_normalize().unwrap());
+        assert_eq!(Point::new_direction(1.0, 0.0).try_to_vector(), None);
+    }
+
+    #[test]
+    fn point_distance() {
+        let point1 = Point::new(1.0, 2.0);
+        // The weight of a point does not change the distance
+        let point2 = Point::new_components(8.0, 12.0, 2.0);
+        let res = point1.try_distance(point2).unwrap();
+        assert_relative_eq!(res, 5.0, max_relative = 0.000001);
+        assert_eq!(point1.try_distance(Point::new_direction(1.0, 0.0)), None);
+    }
+}
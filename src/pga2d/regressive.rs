@@ -0,0 +1,613 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    line::Line, motor::Motor, multivector::Multivector, point::Point, pseudoscalar::Pseudoscalar,
+    scalar::Scalar,
+};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::BitAnd;
+
+// Regressive Product
+
+// Scalar-Scalar
+impl<F: Float> BitAnd for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Scalar<F>);
+
+// Scalar-Line
+impl<F: Float> BitAnd<Line<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Line<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Line<F>);
+
+// Line-Scalar
+impl<F: Float> BitAnd<Scalar<F>> for Line<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Line<F>, Scalar<F>);
+
+// Scalar-Point
+impl<F: Float> BitAnd<Point<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Point<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Point<F>);
+
+// Point-Scalar
+impl<F: Float> BitAnd<Scalar<F>> for Point<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Point<F>, Scalar<F>);
+
+// Scalar-Pseudoscalar
+impl<F: Float> BitAnd<Pseudoscalar<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Scalar<F> {
+        Scalar(self.0 * b.e012())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Scalar
+impl<F: Float> BitAnd<Scalar<F>> for Pseudoscalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Scalar<F>) -> Scalar<F> {
+        Scalar(self.e012() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Scalar<F>);
+
+// Scalar-Motor
+impl<F: Float> BitAnd<Motor<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Motor<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Motor<F>);
+
+// Motor-Scalar
+impl<F: Float> BitAnd<Scalar<F>> for Motor<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Motor<F>, Scalar<F>);
+
+// Scalar-Multivector
+impl<F: Float> BitAnd<Multivector<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Multivector<F>) -> Scalar<F> {
+        Scalar(self.0 * b.e012())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Multivector<F>);
+
+// Multivector-Scalar
+impl<F: Float> BitAnd<Scalar<F>> for Multivector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Scalar<F>) -> Scalar<F> {
+        Scalar(self.e012() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Scalar<F>);
+
+// Line-Line
+impl<F: Float> BitAnd for Line<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Line<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Line<F>, Line<F>);
+
+// Line-Point
+impl<F: Float> BitAnd<Point<F>> for Line<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Point<F>) -> Scalar<F> {
+        Scalar(self.e0() * b.e12() + self.e1() * b.e20() + self.e2() * b.e01())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Line<F>, Point<F>);
+
+// Point-Line
+impl<F: Float> BitAnd<Line<F>> for Point<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Line<F>) -> Scalar<F> {
+        Scalar(self.e20() * b.e1() + self.e01() * b.e2() + self.e12() * b.e0())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Point<F>, Line<F>);
+
+// Line-Pseudoscalar
+impl<F: Float> BitAnd<Pseudoscalar<F>> for Line<F> {
+    type Output = Line<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Line<F> {
+        Line::new(
+            self.e0() * b.e012(),
+            self.e1() * b.e012(),
+            self.e2() * b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Line<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Line
+impl<F: Float> BitAnd<Line<F>> for Pseudoscalar<F> {
+    type Output = Line<F>;
+    fn bitand(self, b: Line<F>) -> Line<F> {
+        Line::new(
+            self.e012() * b.e0(),
+            self.e012() * b.e1(),
+            self.e012() * b.e2(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Line<F>);
+
+// Line-Motor
+impl<F: Float> BitAnd<Motor<F>> for Line<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Motor<F>) -> Scalar<F> {
+        Scalar(self.e0() * b.e12() + self.e1() * b.e20() + self.e2() * b.e01())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Line<F>, Motor<F>);
+
+// Motor-Line
+impl<F: Float> BitAnd<Line<F>> for Motor<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Line<F>) -> Scalar<F> {
+        Scalar(self.e20() * b.e1() + self.e01() * b.e2() + self.e12() * b.e0())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Motor<F>, Line<F>);
+
+// Line-Multivector
+impl<F: Float> BitAnd<Multivector<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e0() * b.e12() + self.e1() * b.e20() + self.e2() * b.e01(),
+            Line::new(
+                self.e0() * b.e012(),
+                self.e1() * b.e012(),
+                self.e2() * b.e012(),
+            ),
+            Point::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Line<F>, Multivector<F>);
+
+// Multivector-Line
+impl<F: Float> BitAnd<Line<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e20() * b.e1() + self.e01() * b.e2() + self.e12() * b.e0(),
+            Line::new(
+                self.e012() * b.e0(),
+                self.e012() * b.e1(),
+                self.e012() * b.e2(),
+            ),
+            Point::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Line<F>);
+
+// Point-Point
+impl<F: Float> BitAnd for Point<F> {
+    type Output = Line<F>;
+    fn bitand(self, b: Point<F>) -> Line<F> {
+        Line::new(
+            self.e20() * b.e01() - self.e01() * b.e20(),
+            self.e01() * b.e12() - self.e12() * b.e01(),
+            -self.e20() * b.e12() + self.e12() * b.e20(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Point<F>, Point<F>);
+
+// Point-Pseudoscalar
+impl<F: Float> BitAnd<Pseudoscalar<F>> for Point<F> {
+    type Output = Point<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Point<F> {
+        Point::new_components(
+            self.e20() * b.e012(),
+            self.e01() * b.e012(),
+            self.e12() * b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Point<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Point
+impl<F: Float> BitAnd<Point<F>> for Pseudoscalar<F> {
+    type Output = Point<F>;
+    fn bitand(self, b: Point<F>) -> Point<F> {
+        Point::new_components(
+            self.e012() * b.e20(),
+            self.e012() * b.e01(),
+            self.e012() * b.e12(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Point<F>);
+
+// Point-Motor
+impl<F: Float> BitAnd<Motor<F>> for Point<F> {
+    type Output = Line<F>;
+    fn bitand(self, b: Motor<F>) -> Line<F> {
+        Line::new(
+            self.e20() * b.e01() - self.e01() * b.e20(),
+            self.e01() * b.e12() - self.e12() * b.e01(),
+            -self.e20() * b.e12() + self.e12() * b.e20(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Point<F>, Motor<F>);
+
+// Motor-Point
+impl<F: Float> BitAnd<Point<F>> for Motor<F> {
+    type Output = Line<F>;
+    fn bitand(self, b: Point<F>) -> Line<F> {
+        Line::new(
+            self.e20() * b.e01() - self.e01() * b.e20(),
+            self.e01() * b.e12() - self.e12() * b.e01(),
+            -self.e20() * b.e12() + self.e12() * b.e20(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Motor<F>, Point<F>);
+
+// Point-Multivector
+impl<F: Float> BitAnd<Multivector<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e20() * b.e1() + self.e01() * b.e2() + self.e12() * b.e0(),
+            Line::new(
+                self.e20() * b.e01() - self.e01() * b.e20(),
+                self.e01() * b.e12() - self.e12() * b.e01(),
+                -self.e20() * b.e12() + self.e12() * b.e20(),
+            ),
+            Point::new_components(
+                self.e20() * b.e012(),
+                self.e01() * b.e012(),
+                self.e12() * b.e012(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Point<F>, Multivector<F>);
+
+// Multivector-Point
+impl<F: Float> BitAnd<Point<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e0() * b.e12() + self.e1() * b.e20() + self.e2() * b.e01(),
+            Line::new(
+                self.e20() * b.e01() - self.e01() * b.e20(),
+                self.e01() * b.e12() - self.e12() * b.e01(),
+                -self.e20() * b.e12() + self.e12() * b.e20(),
+            ),
+            Point::new_components(
+                self.e012() * b.e20(),
+                self.e012() * b.e01(),
+                self.e012() * b.e12(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Point<F>);
+
+// Pseudoscalar-Pseudoscalar
+impl<F: Float> BitAnd for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.e012() * b.e012())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Motor
+impl<F: Float> BitAnd<Motor<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e012() * b.scalar(),
+            Line::zero(),
+            Point::new_components(
+                self.e012() * b.e20(),
+                self.e012() * b.e01(),
+                self.e012() * b.e12(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Motor<F>);
+
+// Motor-Pseudoscalar
+impl<F: Float> BitAnd<Pseudoscalar<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.e012(),
+            Line::zero(),
+            Point::new_components(
+                self.e20() * b.e012(),
+                self.e01() * b.e012(),
+                self.e12() * b.e012(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Motor<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Multivector
+impl<F: Float> BitAnd<Multivector<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e012() * b.scalar(),
+            Line::new(
+                self.e012() * b.e0(),
+                self.e012() * b.e1(),
+                self.e012() * b.e2(),
+            ),
+            Point::new_components(
+                self.e012() * b.e20(),
+                self.e012() * b.e01(),
+                self.e012() * b.e12(),
+            ),
+            self.e012() * b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Multivector<F>);
+
+// Multivector-Pseudoscalar
+impl<F: Float> BitAnd<Pseudoscalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.e012(),
+            Line::new(
+                self.e0() * b.e012(),
+                self.e1() * b.e012(),
+                self.e2() * b.e012(),
+            ),
+            Point::new_components(
+                self.e20() * b.e012(),
+                self.e01() * b.e012(),
+                self.e12() * b.e012(),
+            ),
+            self.e012() * b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Pseudoscalar<F>);
+
+// Motor-Motor
+impl<F: Float> BitAnd for Motor<F> {
+    type Output = Line<F>;
+    fn bitand(self, b: Motor<F>) -> Line<F> {
+        Line::new(
+            self.e20() * b.e01() - self.e01() * b.e20(),
+            self.e01() * b.e12() - self.e12() * b.e01(),
+            -self.e20() * b.e12() + self.e12() * b.e20(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Motor<F>, Motor<F>);
+
+// Motor-Multivector
+impl<F: Float> BitAnd<Multivector<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.e012()
+                + self.e20() * b.e1()
+                + self.e01() * b.e2()
+                + self.e12() * b.e0(),
+            Line::new(
+                self.e20() * b.e01() - self.e01() * b.e20(),
+                self.e01() * b.e12() - self.e12() * b.e01(),
+                -self.e20() * b.e12() + self.e12() * b.e20(),
+            ),
+            Point::new_components(
+                self.e20() * b.e012(),
+                self.e01() * b.e012(),
+                self.e12() * b.e012(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Motor<F>, Multivector<F>);
+
+// Multivector-Motor
+impl<F: Float> BitAnd<Motor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e0() * b.e12()
+                + self.e1() * b.e20()
+                + self.e2() * b.e01()
+                + self.e012() * b.scalar(),
+            Line::new(
+                self.e20() * b.e01() - self.e01() * b.e20(),
+                self.e01() * b.e12() - self.e12() * b.e01(),
+                -self.e20() * b.e12() + self.e12() * b.e20(),
+            ),
+            Point::new_components(
+                self.e012() * b.e20(),
+                self.e012() * b.e01(),
+                self.e012() * b.e12(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Motor<F>);
+
+// Multivector-Multivector
+impl<F: Float> BitAnd for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.e012()
+                + self.e0() * b.e12()
+                + self.e1() * b.e20()
+                + self.e2() * b.e01()
+                + self.e20() * b.e1()
+                + self.e01() * b.e2()
+                + self.e12() * b.e0()
+                + self.e012() * b.scalar(),
+            Line::new(
+                self.e0() * b.e012() + self.e20() * b.e01() - self.e01() * b.e20()
+                    + self.e012() * b.e0(),
+                self.e1() * b.e012() + self.e01() * b.e12() - self.e12() * b.e01()
+                    + self.e012() * b.e1(),
+                self.e2() * b.e012() - self.e20() * b.e12()
+                    + self.e12() * b.e20()
+                    + self.e012() * b.e2(),
+            ),
+            Point::new_components(
+                self.e20() * b.e012() + self.e012() * b.e20(),
+                self.e01() * b.e012() + self.e012() * b.e01(),
+                self.e12() * b.e012() + self.e012() * b.e12(),
+            ),
+            self.e012() * b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Multivector<F>);
+
+// Test
+#[cfg(test)]
+mod regressive_product {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn point_point() {
+        // -2e20+2e01-2e12
+        let point1 = Point::new_components(-2.0, 2.0, -2.0);
+        // 5e20-2e01+3e12
+        let point2 = Point::new_components(5.0, -2.0, 3.0);
+        let res = point1 & point2;
+        // -6e0+2e1-4e2
+        assert_relative_eq!(res.e0(), -6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -4.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn point_line() {
+        // 1e20+3e01-4e12
+        let point1 = Point::new_components(1.0, 3.0, -4.0);
+        // 5e0+5e1+5e2
+        let line2 = Line::new(5.0, 5.0, 5.0);
+        let res = point1 & line2;
+        // 0
+        assert_relative_eq!(res.0, 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn line_point() {
+        // -2e0+3e1+4e2
+        let line1 = Line::new(-2.0, 3.0, 4.0);
+        // -1e20+5e01-2e12
+        let point2 = Point::new_components(-1.0, 5.0, -2.0);
+        let res = line1 & point2;
+        // 21
+        assert_relative_eq!(res.0, 21.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn motor_point() {
+        // -3-4e20-2e01+4e12
+        let motor1 = Motor::new(-3.0, Point::new_components(-4.0, -2.0, 4.0));
+        // 5e20-1e01+5e12
+        let point2 = Point::new_components(5.0, -1.0, 5.0);
+        let res = motor1 & point2;
+        // 14e0-6e1+40e2
+        assert_relative_eq!(res.e0(), 14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 40.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_multivector() {
+        // 5-2e0+4e1+3e2+4e20-3e01-1e12-3e012
+        let mvec1 = Multivector::new(
+            5.0,
+            Line::new(-2.0, 4.0, 3.0),
+            Point::new_components(4.0, -3.0, -1.0),
+            -3.0,
+        );
+        // 2+3e0-1e1+5e2+3e20+3e01+3e12-2e012
+        let mvec2 = Multivector::new(
+            2.0,
+            Line::new(3.0, -1.0, 5.0),
+            Point::new_components(3.0, 3.0, 3.0),
+            -2.0,
+        );
+        let res = mvec1 & mvec2;
+        // -23+16e0-11e1-36e2-17e20-3e01-7e12+6e012
+        assert_relative_eq!(res.scalar(), -23.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 16.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -11.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -36.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), -17.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), -7.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), 6.0, max_relative = 0.000001);
+    }
+}
@@ -0,0 +1,630 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    line::Line, motor::Motor, multivector::Multivector, point::Point, pseudoscalar::Pseudoscalar,
+    scalar::Scalar,
+};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::Sub;
+
+// Subtraction
+
+// Scalar-Scalar
+impl<F: Float> Sub for Scalar<F> {
+    type Output = F;
+    fn sub(self, b: Scalar<F>) -> F {
+        self.0 - b.0
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Scalar<F>, Scalar<F>);
+
+// Scalar-Line
+impl<F: Float> Sub<Line<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0,
+            Line::new(-b.e0(), -b.e1(), -b.e2()),
+            Point::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Scalar<F>, Line<F>);
+
+// Line-Scalar
+impl<F: Float> Sub<Scalar<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.0,
+            Line::new(self.e0(), self.e1(), self.e2()),
+            Point::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Line<F>, Scalar<F>);
+
+// Scalar-Point
+impl<F: Float> Sub<Point<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0,
+            Line::zero(),
+            Point::new_components(-b.e20(), -b.e01(), -b.e12()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Scalar<F>, Point<F>);
+
+// Point-Scalar
+impl<F: Float> Sub<Scalar<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.0,
+            Line::zero(),
+            Point::new_components(self.e20(), self.e01(), self.e12()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Point<F>, Scalar<F>);
+
+// Scalar-Pseudoscalar
+impl<F: Float> Sub<Pseudoscalar<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(self.0, Line::zero(), Point::zero(), -b.e012())
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Scalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Scalar
+impl<F: Float> Sub<Scalar<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(-b.0, Line::zero(), Point::zero(), self.e012())
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Pseudoscalar<F>, Scalar<F>);
+
+// Scalar-Motor
+impl<F: Float> Sub<Motor<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 - b.scalar(),
+            Line::zero(),
+            Point::new_components(-b.e20(), -b.e01(), -b.e12()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Scalar<F>, Motor<F>);
+
+// Motor-Scalar
+impl<F: Float> Sub<Scalar<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() - b.0,
+            Line::zero(),
+            Point::new_components(self.e20(), self.e01(), self.e12()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Motor<F>, Scalar<F>);
+
+// Scalar-Multivector
+impl<F: Float> Sub<Multivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 - b.scalar(),
+            Line::new(-b.e0(), -b.e1(), -b.e2()),
+            Point::new_components(-b.e20(), -b.e01(), -b.e12()),
+            -b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Scalar<F>, Multivector<F>);
+
+// Multivector-Scalar
+impl<F: Float> Sub<Scalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() - b.0,
+            Line::new(self.e0(), self.e1(), self.e2()),
+            Point::new_components(self.e20(), self.e01(), self.e12()),
+            self.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Multivector<F>, Scalar<F>);
+
+// Line-Line
+impl<F: Float> Sub for Line<F> {
+    type Output = Line<F>;
+    fn sub(self, b: Line<F>) -> Line<F> {
+        Line::new(self.e0() - b.e0(), self.e1() - b.e1(), self.e2() - b.e2())
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Line<F>, Line<F>);
+
+// Line-Point
+impl<F: Float> Sub<Point<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Line::new(self.e0(), self.e1(), self.e2()),
+            Point::new_components(-b.e20(), -b.e01(), -b.e12()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Line<F>, Point<F>);
+
+// Point-Line
+impl<F: Float> Sub<Line<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Line::new(-b.e0(), -b.e1(), -b.e2()),
+            Point::new_components(self.e20(), self.e01(), self.e12()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Point<F>, Line<F>);
+
+// Line-Pseudoscalar
+impl<F: Float> Sub<Pseudoscalar<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Line::new(self.e0(), self.e1(), self.e2()),
+            Point::zero(),
+            -b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Line<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Line
+impl<F: Float> Sub<Line<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Line::new(-b.e0(), -b.e1(), -b.e2()),
+            Point::zero(),
+            self.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Pseudoscalar<F>, Line<F>);
+
+// Line-Motor
+impl<F: Float> Sub<Motor<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.scalar(),
+            Line::new(self.e0(), self.e1(), self.e2()),
+            Point::new_components(-b.e20(), -b.e01(), -b.e12()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Line<F>, Motor<F>);
+
+// Motor-Line
+impl<F: Float> Sub<Line<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Line::new(-b.e0(), -b.e1(), -b.e2()),
+            Point::new_components(self.e20(), self.e01(), self.e12()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Motor<F>, Line<F>);
+
+// Line-Multivector
+impl<F: Float> Sub<Multivector<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.scalar(),
+            Line::new(self.e0() - b.e0(), self.e1() - b.e1(), self.e2() - b.e2()),
+            Point::new_components(-b.e20(), -b.e01(), -b.e12()),
+            -b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Line<F>, Multivector<F>);
+
+// Multivector-Line
+impl<F: Float> Sub<Line<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Line::new(self.e0() - b.e0(), self.e1() - b.e1(), self.e2() - b.e2()),
+            Point::new_components(self.e20(), self.e01(), self.e12()),
+            self.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Multivector<F>, Line<F>);
+
+// Point-Point
+impl<F: Float> Sub for Point<F> {
+    type Output = Point<F>;
+    fn sub(self, b: Point<F>) -> Point<F> {
+        Point::new_components(
+            self.e20() - b.e20(),
+            self.e01() - b.e01(),
+            self.e12() - b.e12(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Point<F>, Point<F>);
+
+// Point-Pseudoscalar
+impl<F: Float> Sub<Pseudoscalar<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Line::zero(),
+            Point::new_components(self.e20(), self.e01(), self.e12()),
+            -b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Point<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Point
+impl<F: Float> Sub<Point<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Line::zero(),
+            Point::new_components(-b.e20(), -b.e01(), -b.e12()),
+            self.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Pseudoscalar<F>, Point<F>);
+
+// Point-Motor
+impl<F: Float> Sub<Motor<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.scalar(),
+            Line::zero(),
+            Point::new_components(
+                self.e20() - b.e20(),
+                self.e01() - b.e01(),
+                self.e12() - b.e12(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Point<F>, Motor<F>);
+
+// Motor-Point
+impl<F: Float> Sub<Point<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Line::zero(),
+            Point::new_components(
+                self.e20() - b.e20(),
+                self.e01() - b.e01(),
+                self.e12() - b.e12(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Motor<F>, Point<F>);
+
+// Point-Multivector
+impl<F: Float> Sub<Multivector<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.scalar(),
+            Line::new(-b.e0(), -b.e1(), -b.e2()),
+            Point::new_components(
+                self.e20() - b.e20(),
+                self.e01() - b.e01(),
+                self.e12() - b.e12(),
+            ),
+            -b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Point<F>, Multivector<F>);
+
+// Multivector-Point
+impl<F: Float> Sub<Point<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Line::new(self.e0(), self.e1(), self.e2()),
+            Point::new_components(
+                self.e20() - b.e20(),
+                self.e01() - b.e01(),
+                self.e12() - b.e12(),
+            ),
+            self.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Multivector<F>, Point<F>);
+
+// Pseudoscalar-Pseudoscalar
+impl<F: Float> Sub for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn sub(self, b: Pseudoscalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.e012() - b.e012())
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Pseudoscalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Motor
+impl<F: Float> Sub<Motor<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.scalar(),
+            Line::zero(),
+            Point::new_components(-b.e20(), -b.e01(), -b.e12()),
+            self.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Pseudoscalar<F>, Motor<F>);
+
+// Motor-Pseudoscalar
+impl<F: Float> Sub<Pseudoscalar<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Line::zero(),
+            Point::new_components(self.e20(), self.e01(), self.e12()),
+            -b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Motor<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Multivector
+impl<F: Float> Sub<Multivector<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.scalar(),
+            Line::new(-b.e0(), -b.e1(), -b.e2()),
+            Point::new_components(-b.e20(), -b.e01(), -b.e12()),
+            self.e012() - b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Pseudoscalar<F>, Multivector<F>);
+
+// Multivector-Pseudoscalar
+impl<F: Float> Sub<Pseudoscalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Line::new(self.e0(), self.e1(), self.e2()),
+            Point::new_components(self.e20(), self.e01(), self.e12()),
+            self.e012() - b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Multivector<F>, Pseudoscalar<F>);
+
+// Motor-Motor
+impl<F: Float> Sub for Motor<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() - b.scalar(),
+            Line::zero(),
+            Point::new_components(
+                self.e20() - b.e20(),
+                self.e01() - b.e01(),
+                self.e12() - b.e12(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Motor<F>, Motor<F>);
+
+// Motor-Multivector
+impl<F: Float> Sub<Multivector<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() - b.scalar(),
+            Line::new(-b.e0(), -b.e1(), -b.e2()),
+            Point::new_components(
+                self.e20() - b.e20(),
+                self.e01() - b.e01(),
+                self.e12() - b.e12(),
+            ),
+            -b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Motor<F>, Multivector<F>);
+
+// Multivector-Motor
+impl<F: Float> Sub<Motor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() - b.scalar(),
+            Line::new(self.e0(), self.e1(), self.e2()),
+            Point::new_components(
+                self.e20() - b.e20(),
+                self.e01() - b.e01(),
+                self.e12() - b.e12(),
+            ),
+            self.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Multivector<F>, Motor<F>);
+
+// Multivector-Multivector
+impl<F: Float> Sub for Multivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() - b.scalar(),
+            Line::new(self.e0() - b.e0(), self.e1() - b.e1(), self.e2() - b.e2()),
+            Point::new_components(
+                self.e20() - b.e20(),
+                self.e01() - b.e01(),
+                self.e12() - b.e12(),
+            ),
+            self.e012() - b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Multivector<F>, Multivector<F>);
+
+// Test
+#[cfg(test)]
+mod subtraction_difference {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn scalar_scalar() {
+        // 2
+        let scalar1 = Scalar::new(2.0);
+        // -3
+        let scalar2 = Scalar::new(-3.0);
+        let res = scalar1 - scalar2;
+        // 5
+        assert_relative_eq!(res, 5.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn point_point() {
+        // -4e20+1e01+5e12
+        let point1 = Point::new_components(-4.0, 1.0, 5.0);
+        // 2e20+1e01-2e12
+        let point2 = Point::new_components(2.0, 1.0, -2.0);
+        let res = point1 - point2;
+        // -6e20+7e12
+        assert_relative_eq!(res.e20(), -6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 7.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn point_line() {
+        // 2e20-2e01-2e12
+        let point1 = Point::new_components(2.0, -2.0, -2.0);
+        // -3e0+3e1+2e2
+        let line2 = Line::new(-3.0, 3.0, 2.0);
+        let res = point1 - line2;
+        // 3e0-3e1-2e2+2e20-2e01-2e12
+        assert_relative_eq!(res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn motor_multivector() {
+        // -2+3e20+3e01+4e12
+        let motor1 = Motor::new(-2.0, Point::new_components(3.0, 3.0, 4.0));
+        // 4-1e0-2e1+1e2+5e20+1e01-4e12+2e012
+        let mvec2 = Multivector::new(
+            4.0,
+            Line::new(-1.0, -2.0, 1.0),
+            Point::new_components(5.0, 1.0, -4.0),
+            2.0,
+        );
+        let res = motor1 - mvec2;
+        // -6+1e0+2e1-1e2-2e20+2e01+8e12-2e012
+        assert_relative_eq!(res.scalar(), -6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), -2.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn pseudoscalar_scalar() {
+        // -3e012
+        let pseudoscalar1 = Pseudoscalar::new(-3.0);
+        // 2
+        let scalar2 = Scalar::new(2.0);
+        let res = pseudoscalar1 - scalar2;
+        // -2-3e012
+        assert_relative_eq!(res.scalar(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), -3.0, max_relative = 0.000001);
+    }
+}
@@ -0,0 +1,1064 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    line::Line, motor::Motor, multivector::Multivector, plane::Plane, point::Point,
+    pseudoscalar::Pseudoscalar, scalar::Scalar,
+};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::Add;
+
+// Addition
+
+// Scalar-Scalar
+impl<F: Float> Add for Scalar<F> {
+    type Output = F;
+    fn add(self, b: Scalar<F>) -> F {
+        self.0 + b.0
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Scalar<F>, Scalar<F>);
+
+// Scalar-Plane
+impl<F: Float> Add<Plane<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Plane<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0,
+            Plane::new(b.e0(), b.e1(), b.e2(), b.e3()),
+            Line::zero(),
+            Point::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Scalar<F>, Plane<F>);
+
+// Plane-Scalar
+impl<F: Float> Add<Scalar<F>> for Plane<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            b.0,
+            Plane::new(self.e0(), self.e1(), self.e2(), self.e3()),
+            Line::zero(),
+            Point::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Plane<F>, Scalar<F>);
+
+// Scalar-Line
+impl<F: Float> Add<Line<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0,
+            Plane::zero(),
+            Line::new(b.e01(), b.e02(), b.e03(), b.e12(), b.e31(), b.e23()),
+            Point::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Scalar<F>, Line<F>);
+
+// Line-Scalar
+impl<F: Float> Add<Scalar<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            b.0,
+            Plane::zero(),
+            Line::new(
+                self.e01(),
+                self.e02(),
+                self.e03(),
+                self.e12(),
+                self.e31(),
+                self.e23(),
+            ),
+            Point::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Line<F>, Scalar<F>);
+
+// Scalar-Point
+impl<F: Float> Add<Point<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0,
+            Plane::zero(),
+            Line::zero(),
+            Point::new_components(b.e032(), b.e013(), b.e021(), b.e123()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Scalar<F>, Point<F>);
+
+// Point-Scalar
+impl<F: Float> Add<Scalar<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            b.0,
+            Plane::zero(),
+            Line::zero(),
+            Point::new_components(self.e032(), self.e013(), self.e021(), self.e123()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Point<F>, Scalar<F>);
+
+// Scalar-Pseudoscalar
+impl<F: Float> Add<Pseudoscalar<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0,
+            Plane::zero(),
+            Line::zero(),
+            Point::zero(),
+            b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Scalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Scalar
+impl<F: Float> Add<Scalar<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            b.0,
+            Plane::zero(),
+            Line::zero(),
+            Point::zero(),
+            self.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Pseudoscalar<F>, Scalar<F>);
+
+// Scalar-Motor
+impl<F: Float> Add<Motor<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 + b.scalar(),
+            Plane::zero(),
+            Line::new(b.e01(), b.e02(), b.e03(), b.e12(), b.e31(), b.e23()),
+            Point::zero(),
+            b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Scalar<F>, Motor<F>);
+
+// Motor-Scalar
+impl<F: Float> Add<Scalar<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() + b.0,
+            Plane::zero(),
+            Line::new(
+                self.e01(),
+                self.e02(),
+                self.e03(),
+                self.e12(),
+                self.e31(),
+                self.e23(),
+            ),
+            Point::zero(),
+            self.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Motor<F>, Scalar<F>);
+
+// Scalar-Multivector
+impl<F: Float> Add<Multivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 + b.scalar(),
+            Plane::new(b.e0(), b.e1(), b.e2(), b.e3()),
+            Line::new(b.e01(), b.e02(), b.e03(), b.e12(), b.e31(), b.e23()),
+            Point::new_components(b.e032(), b.e013(), b.e021(), b.e123()),
+            b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Scalar<F>, Multivector<F>);
+
+// Multivector-Scalar
+impl<F: Float> Add<Scalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() + b.0,
+            Plane::new(self.e0(), self.e1(), self.e2(), self.e3()),
+            Line::new(
+                self.e01(),
+                self.e02(),
+                self.e03(),
+                self.e12(),
+                self.e31(),
+                self.e23(),
+            ),
+            Point::new_components(self.e032(), self.e013(), self.e021(), self.e123()),
+            self.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Multivector<F>, Scalar<F>);
+
+// Plane-Plane
+impl<F: Float> Add for Plane<F> {
+    type Output = Plane<F>;
+    fn add(self, b: Plane<F>) -> Plane<F> {
+        Plane::new(
+            self.e0() + b.e0(),
+            self.e1() + b.e1(),
+            self.e2() + b.e2(),
+            self.e3() + b.e3(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Plane<F>, Plane<F>);
+
+// Plane-Line
+impl<F: Float> Add<Line<F>> for Plane<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(self.e0(), self.e1(), self.e2(), self.e3()),
+            Line::new(b.e01(), b.e02(), b.e03(), b.e12(), b.e31(), b.e23()),
+            Point::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Plane<F>, Line<F>);
+
+// Line-Plane
+impl<F: Float> Add<Plane<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Plane<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(b.e0(), b.e1(), b.e2(), b.e3()),
+            Line::new(
+                self.e01(),
+                self.e02(),
+                self.e03(),
+                self.e12(),
+                self.e31(),
+                self.e23(),
+            ),
+            Point::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Line<F>, Plane<F>);
+
+// Plane-Point
+impl<F: Float> Add<Point<F>> for Plane<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(self.e0(), self.e1(), self.e2(), self.e3()),
+            Line::zero(),
+            Point::new_components(b.e032(), b.e013(), b.e021(), b.e123()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Plane<F>, Point<F>);
+
+// Point-Plane
+impl<F: Float> Add<Plane<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Plane<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(b.e0(), b.e1(), b.e2(), b.e3()),
+            Line::zero(),
+            Point::new_components(self.e032(), self.e013(), self.e021(), self.e123()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Point<F>, Plane<F>);
+
+// Plane-Pseudoscalar
+impl<F: Float> Add<Pseudoscalar<F>> for Plane<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(self.e0(), self.e1(), self.e2(), self.e3()),
+            Line::zero(),
+            Point::zero(),
+            b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Plane<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Plane
+impl<F: Float> Add<Plane<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Plane<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(b.e0(), b.e1(), b.e2(), b.e3()),
+            Line::zero(),
+            Point::zero(),
+            self.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Pseudoscalar<F>, Plane<F>);
+
+// Plane-Motor
+impl<F: Float> Add<Motor<F>> for Plane<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            b.scalar(),
+            Plane::new(self.e0(), self.e1(), self.e2(), self.e3()),
+            Line::new(b.e01(), b.e02(), b.e03(), b.e12(), b.e31(), b.e23()),
+            Point::zero(),
+            b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Plane<F>, Motor<F>);
+
+// Motor-Plane
+impl<F: Float> Add<Plane<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Plane<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Plane::new(b.e0(), b.e1(), b.e2(), b.e3()),
+            Line::new(
+                self.e01(),
+                self.e02(),
+                self.e03(),
+                self.e12(),
+                self.e31(),
+                self.e23(),
+            ),
+            Point::zero(),
+            self.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Motor<F>, Plane<F>);
+
+// Plane-Multivector
+impl<F: Float> Add<Multivector<F>> for Plane<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            b.scalar(),
+            Plane::new(
+                self.e0() + b.e0(),
+                self.e1() + b.e1(),
+                self.e2() + b.e2(),
+                self.e3() + b.e3(),
+            ),
+            Line::new(b.e01(), b.e02(), b.e03(), b.e12(), b.e31(), b.e23()),
+            Point::new_components(b.e032(), b.e013(), b.e021(), b.e123()),
+            b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Plane<F>, Multivector<F>);
+
+// Multivector-Plane
+impl<F: Float> Add<Plane<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Plane<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Plane::new(
+                self.e0() + b.e0(),
+                self.e1() + b.e1(),
+                self.e2() + b.e2(),
+                self.e3() + b.e3(),
+            ),
+            Line::new(
+                self.e01(),
+                self.e02(),
+                self.e03(),
+                self.e12(),
+                self.e31(),
+                self.e23(),
+            ),
+            Point::new_components(self.e032(), self.e013(), self.e021(), self.e123()),
+            self.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Multivector<F>, Plane<F>);
+
+// Line-Line
+impl<F: Float> Add for Line<F> {
+    type Output = Line<F>;
+    fn add(self, b: Line<F>) -> Line<F> {
+        Line::new(
+            self.e01() + b.e01(),
+            self.e02() + b.e02(),
+            self.e03() + b.e03(),
+            self.e12() + b.e12(),
+            self.e31() + b.e31(),
+            self.e23() + b.e23(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Line<F>, Line<F>);
+
+// Line-Point
+impl<F: Float> Add<Point<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::zero(),
+            Line::new(
+                self.e01(),
+                self.e02(),
+                self.e03(),
+                self.e12(),
+                self.e31(),
+                self.e23(),
+            ),
+            Point::new_components(b.e032(), b.e013(), b.e021(), b.e123()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Line<F>, Point<F>);
+
+// Point-Line
+impl<F: Float> Add<Line<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::zero(),
+            Line::new(b.e01(), b.e02(), b.e03(), b.e12(), b.e31(), b.e23()),
+            Point::new_components(self.e032(), self.e013(), self.e021(), self.e123()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Point<F>, Line<F>);
+
+// Line-Pseudoscalar
+impl<F: Float> Add<Pseudoscalar<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::zero(),
+            Line::new(
+                self.e01(),
+                self.e02(),
+                self.e03(),
+                self.e12(),
+                self.e31(),
+                self.e23(),
+            ),
+            Point::zero(),
+            b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Line<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Line
+impl<F: Float> Add<Line<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::zero(),
+            Line::new(b.e01(), b.e02(), b.e03(), b.e12(), b.e31(), b.e23()),
+            Point::zero(),
+            self.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Pseudoscalar<F>, Line<F>);
+
+// Line-Motor
+impl<F: Float> Add<Motor<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            b.scalar(),
+            Plane::zero(),
+            Line::new(
+                self.e01() + b.e01(),
+                self.e02() + b.e02(),
+                self.e03() + b.e03(),
+                self.e12() + b.e12(),
+                self.e31() + b.e31(),
+                self.e23() + b.e23(),
+            ),
+            Point::zero(),
+            b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Line<F>, Motor<F>);
+
+// Motor-Line
+impl<F: Float> Add<Line<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Plane::zero(),
+            Line::new(
+                self.e01() + b.e01(),
+                self.e02() + b.e02(),
+                self.e03() + b.e03(),
+                self.e12() + b.e12(),
+                self.e31() + b.e31(),
+                self.e23() + b.e23(),
+            ),
+            Point::zero(),
+            self.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Motor<F>, Line<F>);
+
+// Line-Multivector
+impl<F: Float> Add<Multivector<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            b.scalar(),
+            Plane::new(b.e0(), b.e1(), b.e2(), b.e3()),
+            Line::new(
+                self.e01() + b.e01(),
+                self.e02() + b.e02(),
+                self.e03() + b.e03(),
+                self.e12() + b.e12(),
+                self.e31() + b.e31(),
+                self.e23() + b.e23(),
+            ),
+            Point::new_components(b.e032(), b.e013(), b.e021(), b.e123()),
+            b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Line<F>, Multivector<F>);
+
+// Multivector-Line
+impl<F: Float> Add<Line<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Plane::new(self.e0(), self.e1(), self.e2(), self.e3()),
+            Line::new(
+                self.e01() + b.e01(),
+                self.e02() + b.e02(),
+                self.e03() + b.e03(),
+                self.e12() + b.e12(),
+                self.e31() + b.e31(),
+                self.e23() + b.e23(),
+            ),
+            Point::new_components(self.e032(), self.e013(), self.e021(), self.e123()),
+            self.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Multivector<F>, Line<F>);
+
+// Point-Point
+impl<F: Float> Add for Point<F> {
+    type Output = Point<F>;
+    fn add(self, b: Point<F>) -> Point<F> {
+        Point::new_components(
+            self.e032() + b.e032(),
+            self.e013() + b.e013(),
+            self.e021() + b.e021(),
+            self.e123() + b.e123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Point<F>, Point<F>);
+
+// Point-Pseudoscalar
+impl<F: Float> Add<Pseudoscalar<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::zero(),
+            Line::zero(),
+            Point::new_components(self.e032(), self.e013(), self.e021(), self.e123()),
+            b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Point<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Point
+impl<F: Float> Add<Point<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::zero(),
+            Line::zero(),
+            Point::new_components(b.e032(), b.e013(), b.e021(), b.e123()),
+            self.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Pseudoscalar<F>, Point<F>);
+
+// Point-Motor
+impl<F: Float> Add<Motor<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            b.scalar(),
+            Plane::zero(),
+            Line::new(b.e01(), b.e02(), b.e03(), b.e12(), b.e31(), b.e23()),
+            Point::new_components(self.e032(), self.e013(), self.e021(), self.e123()),
+            b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Point<F>, Motor<F>);
+
+// Motor-Point
+impl<F: Float> Add<Point<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Plane::zero(),
+            Line::new(
+                self.e01(),
+                self.e02(),
+                self.e03(),
+                self.e12(),
+                self.e31(),
+                self.e23(),
+            ),
+            Point::new_components(b.e032(), b.e013(), b.e021(), b.e123()),
+            self.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Motor<F>, Point<F>);
+
+// Point-Multivector
+impl<F: Float> Add<Multivector<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            b.scalar(),
+            Plane::new(b.e0(), b.e1(), b.e2(), b.e3()),
+            Line::new(b.e01(), b.e02(), b.e03(), b.e12(), b.e31(), b.e23()),
+            Point::new_components(
+                self.e032() + b.e032(),
+                self.e013() + b.e013(),
+                self.e021() + b.e021(),
+                self.e123() + b.e123(),
+            ),
+            b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Point<F>, Multivector<F>);
+
+// Multivector-Point
+impl<F: Float> Add<Point<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Plane::new(self.e0(), self.e1(), self.e2(), self.e3()),
+            Line::new(
+                self.e01(),
+                self.e02(),
+                self.e03(),
+                self.e12(),
+                self.e31(),
+                self.e23(),
+            ),
+            Point::new_components(
+                self.e032() + b.e032(),
+                self.e013() + b.e013(),
+                self.e021() + b.e021(),
+                self.e123() + b.e123(),
+            ),
+            self.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Multivector<F>, Point<F>);
+
+// Pseudoscalar-Pseudoscalar
+impl<F: Float> Add for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn add(self, b: Pseudoscalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.e0123() + b.e0123())
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Pseudoscalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Motor
+impl<F: Float> Add<Motor<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            b.scalar(),
+            Plane::zero(),
+            Line::new(b.e01(), b.e02(), b.e03(), b.e12(), b.e31(), b.e23()),
+            Point::zero(),
+            self.e0123() + b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Pseudoscalar<F>, Motor<F>);
+
+// Motor-Pseudoscalar
+impl<F: Float> Add<Pseudoscalar<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Plane::zero(),
+            Line::new(
+                self.e01(),
+                self.e02(),
+                self.e03(),
+                self.e12(),
+                self.e31(),
+                self.e23(),
+            ),
+            Point::zero(),
+            self.e0123() + b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Motor<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Multivector
+impl<F: Float> Add<Multivector<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            b.scalar(),
+            Plane::new(b.e0(), b.e1(), b.e2(), b.e3()),
+            Line::new(b.e01(), b.e02(), b.e03(), b.e12(), b.e31(), b.e23()),
+            Point::new_components(b.e032(), b.e013(), b.e021(), b.e123()),
+            self.e0123() + b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Pseudoscalar<F>, Multivector<F>);
+
+// Multivector-Pseudoscalar
+impl<F: Float> Add<Pseudoscalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Plane::new(self.e0(), self.e1(), self.e2(), self.e3()),
+            Line::new(
+                self.e01(),
+                self.e02(),
+                self.e03(),
+                self.e12(),
+                self.e31(),
+                self.e23(),
+            ),
+            Point::new_components(self.e032(), self.e013(), self.e021(), self.e123()),
+            self.e0123() + b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Multivector<F>, Pseudoscalar<F>);
+
+// Motor-Motor
+impl<F: Float> Add for Motor<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() + b.scalar(),
+            Plane::zero(),
+            Line::new(
+                self.e01() + b.e01(),
+                self.e02() + b.e02(),
+                self.e03() + b.e03(),
+                self.e12() + b.e12(),
+                self.e31() + b.e31(),
+                self.e23() + b.e23(),
+            ),
+            Point::zero(),
+            self.e0123() + b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Motor<F>, Motor<F>);
+
+// Motor-Multivector
+impl<F: Float> Add<Multivector<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() + b.scalar(),
+            Plane::new(b.e0(), b.e1(), b.e2(), b.e3()),
+            Line::new(
+                self.e01() + b.e01(),
+                self.e02() + b.e02(),
+                self.e03() + b.e03(),
+                self.e12() + b.e12(),
+                self.e31() + b.e31(),
+                self.e23() + b.e23(),
+            ),
+            Point::new_components(b.e032(), b.e013(), b.e021(), b.e123()),
+            self.e0123() + b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Motor<F>, Multivector<F>);
+
+// Multivector-Motor
+impl<F: Float> Add<Motor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() + b.scalar(),
+            Plane::new(self.e0(), self.e1(), self.e2(), self.e3()),
+            Line::new(
+                self.e01() + b.e01(),
+                self.e02() + b.e02(),
+                self.e03() + b.e03(),
+                self.e12() + b.e12(),
+                self.e31() + b.e31(),
+                self.e23() + b.e23(),
+            ),
+            Point::new_components(self.e032(), self.e013(), self.e021(), self.e123()),
+            self.e0123() + b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Multivector<F>, Motor<F>);
+
+// Multivector-Multivector
+impl<F: Float> Add for Multivector<F> {
+    type Output = Multivector<F>;
+    fn add(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() + b.scalar(),
+            Plane::new(
+                self.e0() + b.e0(),
+                self.e1() + b.e1(),
+                self.e2() + b.e2(),
+                self.e3() + b.e3(),
+            ),
+            Line::new(
+                self.e01() + b.e01(),
+                self.e02() + b.e02(),
+                self.e03() + b.e03(),
+                self.e12() + b.e12(),
+                self.e31() + b.e31(),
+                self.e23() + b.e23(),
+            ),
+            Point::new_components(
+                self.e032() + b.e032(),
+                self.e013() + b.e013(),
+                self.e021() + b.e021(),
+                self.e123() + b.e123(),
+            ),
+            self.e0123() + b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Add, add for Multivector<F>, Multivector<F>);
+
+// Test
+#[cfg(test)]
+mod addition_sum {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn scalar_scalar() {
+        // -4
+        let scalar1 = Scalar::new(-4.0);
+        // 4
+        let scalar2 = Scalar::new(4.0);
+        let res = scalar1 + scalar2;
+        // 0
+        assert_relative_eq!(res, 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn plane_plane() {
+        // -3e0+3e1-2e2-4e3
+        let plane1 = Plane::new(-3.0, 3.0, -2.0, -4.0);
+        // -2e0-3e1+5e2-1e3
+        let plane2 = Plane::new(-2.0, -3.0, 5.0, -1.0);
+        let res = plane1 + plane2;
+        // -5e0+3e2-5e3
+        assert_relative_eq!(res.e0(), -5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), -5.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn line_point() {
+        // -2e01-2e02-4e03-4e12-2e31-1e23
+        let line1 = Line::new(-2.0, -2.0, -4.0, -4.0, -2.0, -1.0);
+        // 5e032+4e013+5e021+3e123
+        let point2 = Point::new_components(5.0, 4.0, 5.0, 3.0);
+        let res = line1 + point2;
+        // -2e01-2e02-4e03-4e12-2e31-1e23+5e032+4e013+5e021+3e123
+        assert_relative_eq!(res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e02(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e03(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e032(), 5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e013(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e021(), 5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn motor_motor() {
+        // -1-3e01-3e02-4e03+3e12+2e31-1e23-1e0123
+        let motor1 = Motor::new(-1.0, Line::new(-3.0, -3.0, -4.0, 3.0, 2.0, -1.0), -1.0);
+        // 2+3e01+2e02-1e03-1e12-1e31+3e23+5e0123
+        let motor2 = Motor::new(2.0, Line::new(3.0, 2.0, -1.0, -1.0, -1.0, 3.0), 5.0);
+        let res = motor1 + motor2;
+        // 1-1e02-5e03+2e12+1e31+2e23+4e0123
+        assert_relative_eq!(res.scalar(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e02(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e03(), -5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e032(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e013(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e021(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), 4.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn scalar_pseudoscalar() {
+        // -4
+        let scalar1 = Scalar::new(-4.0);
+        // -4e0123
+        let pseudoscalar2 = Pseudoscalar::new(-4.0);
+        let res = scalar1 + pseudoscalar2;
+        // -4-4e0123
+        assert_relative_eq!(res.scalar(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e02(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e03(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e032(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e013(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e021(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), -4.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_plane() {
+        // -2+2e0-2e1-1e2-2e3+3e01-4e02-3e03+5e12+4e31-1e23-1e032+3e013+2e021-1e123+2e0123
+        let mvec1 = Multivector::new(
+            -2.0,
+            Plane::new(2.0, -2.0, -1.0, -2.0),
+            Line::new(3.0, -4.0, -3.0, 5.0, 4.0, -1.0),
+            Point::new_components(-1.0, 3.0, 2.0, -1.0),
+            2.0,
+        );
+        // -1e0+4e1-4e2+1e3
+        let plane2 = Plane::new(-1.0, 4.0, -4.0, 1.0);
+        let res = mvec1 + plane2;
+        // -2+1e0+2e1-5e2-1e3+3e01-4e02-3e03+5e12+4e31-1e23-1e032+3e013+2e021-1e123+2e0123
+        assert_relative_eq!(res.scalar(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e02(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e03(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e032(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e013(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e021(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), 2.0, max_relative = 0.000001);
+    }
+}
@@ -0,0 +1,1113 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    line::Line, motor::Motor, multivector::Multivector, plane::Plane, point::Point,
+    pseudoscalar::Pseudoscalar, scalar::Scalar,
+};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::BitXor;
+
+// Exterior Product
+
+// Scalar-Scalar
+impl<F: Float> BitXor for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, b: Scalar<F>) -> Scalar<F> {
+        Scalar(self.0 * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Scalar<F>);
+
+// Scalar-Plane
+impl<F: Float> BitXor<Plane<F>> for Scalar<F> {
+    type Output = Plane<F>;
+    fn bitxor(self, b: Plane<F>) -> Plane<F> {
+        Plane::new(
+            self.0 * b.e0(),
+            self.0 * b.e1(),
+            self.0 * b.e2(),
+            self.0 * b.e3(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Plane<F>);
+
+// Plane-Scalar
+impl<F: Float> BitXor<Scalar<F>> for Plane<F> {
+    type Output = Plane<F>;
+    fn bitxor(self, b: Scalar<F>) -> Plane<F> {
+        Plane::new(
+            self.e0() * b.0,
+            self.e1() * b.0,
+            self.e2() * b.0,
+            self.e3() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Plane<F>, Scalar<F>);
+
+// Scalar-Line
+impl<F: Float> BitXor<Line<F>> for Scalar<F> {
+    type Output = Line<F>;
+    fn bitxor(self, b: Line<F>) -> Line<F> {
+        Line::new(
+            self.0 * b.e01(),
+            self.0 * b.e02(),
+            self.0 * b.e03(),
+            self.0 * b.e12(),
+            self.0 * b.e31(),
+            self.0 * b.e23(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Line<F>);
+
+// Line-Scalar
+impl<F: Float> BitXor<Scalar<F>> for Line<F> {
+    type Output = Line<F>;
+    fn bitxor(self, b: Scalar<F>) -> Line<F> {
+        Line::new(
+            self.e01() * b.0,
+            self.e02() * b.0,
+            self.e03() * b.0,
+            self.e12() * b.0,
+            self.e31() * b.0,
+            self.e23() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Line<F>, Scalar<F>);
+
+// Scalar-Point
+impl<F: Float> BitXor<Point<F>> for Scalar<F> {
+    type Output = Point<F>;
+    fn bitxor(self, b: Point<F>) -> Point<F> {
+        Point::new_components(
+            self.0 * b.e032(),
+            self.0 * b.e013(),
+            self.0 * b.e021(),
+            self.0 * b.e123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Point<F>);
+
+// Point-Scalar
+impl<F: Float> BitXor<Scalar<F>> for Point<F> {
+    type Output = Point<F>;
+    fn bitxor(self, b: Scalar<F>) -> Point<F> {
+        Point::new_components(
+            self.e032() * b.0,
+            self.e013() * b.0,
+            self.e021() * b.0,
+            self.e123() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Point<F>, Scalar<F>);
+
+// Scalar-Pseudoscalar
+impl<F: Float> BitXor<Pseudoscalar<F>> for Scalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Pseudoscalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.0 * b.e0123())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Scalar
+impl<F: Float> BitXor<Scalar<F>> for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Scalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.e0123() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Pseudoscalar<F>, Scalar<F>);
+
+// Scalar-Motor
+impl<F: Float> BitXor<Motor<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 * b.scalar(),
+            Plane::zero(),
+            Line::new(
+                self.0 * b.e01(),
+                self.0 * b.e02(),
+                self.0 * b.e03(),
+                self.0 * b.e12(),
+                self.0 * b.e31(),
+                self.0 * b.e23(),
+            ),
+            Point::zero(),
+            self.0 * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Motor<F>);
+
+// Motor-Scalar
+impl<F: Float> BitXor<Scalar<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.0,
+            Plane::zero(),
+            Line::new(
+                self.e01() * b.0,
+                self.e02() * b.0,
+                self.e03() * b.0,
+                self.e12() * b.0,
+                self.e31() * b.0,
+                self.e23() * b.0,
+            ),
+            Point::zero(),
+            self.e0123() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Motor<F>, Scalar<F>);
+
+// Scalar-Multivector
+impl<F: Float> BitXor<Multivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 * b.scalar(),
+            Plane::new(
+                self.0 * b.e0(),
+                self.0 * b.e1(),
+                self.0 * b.e2(),
+                self.0 * b.e3(),
+            ),
+            Line::new(
+                self.0 * b.e01(),
+                self.0 * b.e02(),
+                self.0 * b.e03(),
+                self.0 * b.e12(),
+                self.0 * b.e31(),
+                self.0 * b.e23(),
+            ),
+            Point::new_components(
+                self.0 * b.e032(),
+                self.0 * b.e013(),
+                self.0 * b.e021(),
+                self.0 * b.e123(),
+            ),
+            self.0 * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Multivector<F>);
+
+// Multivector-Scalar
+impl<F: Float> BitXor<Scalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.0,
+            Plane::new(
+                self.e0() * b.0,
+                self.e1() * b.0,
+                self.e2() * b.0,
+                self.e3() * b.0,
+            ),
+            Line::new(
+                self.e01() * b.0,
+                self.e02() * b.0,
+                self.e03() * b.0,
+                self.e12() * b.0,
+                self.e31() * b.0,
+                self.e23() * b.0,
+            ),
+            Point::new_components(
+                self.e032() * b.0,
+                self.e013() * b.0,
+                self.e021() * b.0,
+                self.e123() * b.0,
+            ),
+            self.e0123() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Scalar<F>);
+
+// Plane-Plane
+impl<F: Float> BitXor for Plane<F> {
+    type Output = Line<F>;
+    fn bitxor(self, b: Plane<F>) -> Line<F> {
+        Line::new(
+            self.e0() * b.e1() - self.e1() * b.e0(),
+            self.e0() * b.e2() - self.e2() * b.e0(),
+            self.e0() * b.e3() - self.e3() * b.e0(),
+            self.e1() * b.e2() - self.e2() * b.e1(),
+            -self.e1() * b.e3() + self.e3() * b.e1(),
+            self.e2() * b.e3() - self.e3() * b.e2(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Plane<F>, Plane<F>);
+
+// Plane-Line
+impl<F: Float> BitXor<Line<F>> for Plane<F> {
+    type Output = Point<F>;
+    fn bitxor(self, b: Line<F>) -> Point<F> {
+        Point::new_components(
+            -self.e0() * b.e23() + self.e2() * b.e03() - self.e3() * b.e02(),
+            -self.e0() * b.e31() - self.e1() * b.e03() + self.e3() * b.e01(),
+            -self.e0() * b.e12() + self.e1() * b.e02() - self.e2() * b.e01(),
+            self.e1() * b.e23() + self.e2() * b.e31() + self.e3() * b.e12(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Plane<F>, Line<F>);
+
+// Line-Plane
+impl<F: Float> BitXor<Plane<F>> for Line<F> {
+    type Output = Point<F>;
+    fn bitxor(self, b: Plane<F>) -> Point<F> {
+        Point::new_components(
+            -self.e02() * b.e3() + self.e03() * b.e2() - self.e23() * b.e0(),
+            self.e01() * b.e3() - self.e03() * b.e1() - self.e31() * b.e0(),
+            -self.e01() * b.e2() + self.e02() * b.e1() - self.e12() * b.e0(),
+            self.e12() * b.e3() + self.e31() * b.e2() + self.e23() * b.e1(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Line<F>, Plane<F>);
+
+// Plane-Point
+impl<F: Float> BitXor<Point<F>> for Plane<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Point<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(
+            self.e0() * b.e123()
+                + self.e1() * b.e032()
+                + self.e2() * b.e013()
+                + self.e3() * b.e021(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Plane<F>, Point<F>);
+
+// Point-Plane
+impl<F: Float> BitXor<Plane<F>> for Point<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Plane<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(
+            -self.e032() * b.e1()
+                - self.e013() * b.e2()
+                - self.e021() * b.e3()
+                - self.e123() * b.e0(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Point<F>, Plane<F>);
+
+// Plane-Pseudoscalar
+impl<F: Float> BitXor<Pseudoscalar<F>> for Plane<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Pseudoscalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Plane<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Plane
+impl<F: Float> BitXor<Plane<F>> for Pseudoscalar<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Plane<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Pseudoscalar<F>, Plane<F>);
+
+// Plane-Motor
+impl<F: Float> BitXor<Motor<F>> for Plane<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(
+                self.e0() * b.scalar(),
+                self.e1() * b.scalar(),
+                self.e2() * b.scalar(),
+                self.e3() * b.scalar(),
+            ),
+            Line::zero(),
+            Point::new_components(
+                -self.e0() * b.e23() + self.e2() * b.e03() - self.e3() * b.e02(),
+                -self.e0() * b.e31() - self.e1() * b.e03() + self.e3() * b.e01(),
+                -self.e0() * b.e12() + self.e1() * b.e02() - self.e2() * b.e01(),
+                self.e1() * b.e23() + self.e2() * b.e31() + self.e3() * b.e12(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Plane<F>, Motor<F>);
+
+// Motor-Plane
+impl<F: Float> BitXor<Plane<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Plane<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(
+                self.scalar() * b.e0(),
+                self.scalar() * b.e1(),
+                self.scalar() * b.e2(),
+                self.scalar() * b.e3(),
+            ),
+            Line::zero(),
+            Point::new_components(
+                -self.e02() * b.e3() + self.e03() * b.e2() - self.e23() * b.e0(),
+                self.e01() * b.e3() - self.e03() * b.e1() - self.e31() * b.e0(),
+                -self.e01() * b.e2() + self.e02() * b.e1() - self.e12() * b.e0(),
+                self.e12() * b.e3() + self.e31() * b.e2() + self.e23() * b.e1(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Motor<F>, Plane<F>);
+
+// Plane-Multivector
+impl<F: Float> BitXor<Multivector<F>> for Plane<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(
+                self.e0() * b.scalar(),
+                self.e1() * b.scalar(),
+                self.e2() * b.scalar(),
+                self.e3() * b.scalar(),
+            ),
+            Line::new(
+                self.e0() * b.e1() - self.e1() * b.e0(),
+                self.e0() * b.e2() - self.e2() * b.e0(),
+                self.e0() * b.e3() - self.e3() * b.e0(),
+                self.e1() * b.e2() - self.e2() * b.e1(),
+                -self.e1() * b.e3() + self.e3() * b.e1(),
+                self.e2() * b.e3() - self.e3() * b.e2(),
+            ),
+            Point::new_components(
+                -self.e0() * b.e23() + self.e2() * b.e03() - self.e3() * b.e02(),
+                -self.e0() * b.e31() - self.e1() * b.e03() + self.e3() * b.e01(),
+                -self.e0() * b.e12() + self.e1() * b.e02() - self.e2() * b.e01(),
+                self.e1() * b.e23() + self.e2() * b.e31() + self.e3() * b.e12(),
+            ),
+            self.e0() * b.e123()
+                + self.e1() * b.e032()
+                + self.e2() * b.e013()
+                + self.e3() * b.e021(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Plane<F>, Multivector<F>);
+
+// Multivector-Plane
+impl<F: Float> BitXor<Plane<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Plane<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(
+                self.scalar() * b.e0(),
+                self.scalar() * b.e1(),
+                self.scalar() * b.e2(),
+                self.scalar() * b.e3(),
+            ),
+            Line::new(
+                self.e0() * b.e1() - self.e1() * b.e0(),
+                self.e0() * b.e2() - self.e2() * b.e0(),
+                self.e0() * b.e3() - self.e3() * b.e0(),
+                self.e1() * b.e2() - self.e2() * b.e1(),
+                -self.e1() * b.e3() + self.e3() * b.e1(),
+                self.e2() * b.e3() - self.e3() * b.e2(),
+            ),
+            Point::new_components(
+                -self.e02() * b.e3() + self.e03() * b.e2() - self.e23() * b.e0(),
+                self.e01() * b.e3() - self.e03() * b.e1() - self.e31() * b.e0(),
+                -self.e01() * b.e2() + self.e02() * b.e1() - self.e12() * b.e0(),
+                self.e12() * b.e3() + self.e31() * b.e2() + self.e23() * b.e1(),
+            ),
+            -self.e032() * b.e1()
+                - self.e013() * b.e2()
+                - self.e021() * b.e3()
+                - self.e123() * b.e0(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Plane<F>);
+
+// Line-Line
+impl<F: Float> BitXor for Line<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Line<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(
+            self.e01() * b.e23()
+                + self.e02() * b.e31()
+                + self.e03() * b.e12()
+                + self.e12() * b.e03()
+                + self.e31() * b.e02()
+                + self.e23() * b.e01(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Line<F>, Line<F>);
+
+// Line-Point
+impl<F: Float> BitXor<Point<F>> for Line<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Point<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Line<F>, Point<F>);
+
+// Point-Line
+impl<F: Float> BitXor<Line<F>> for Point<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Line<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Point<F>, Line<F>);
+
+// Line-Pseudoscalar
+impl<F: Float> BitXor<Pseudoscalar<F>> for Line<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Pseudoscalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Line<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Line
+impl<F: Float> BitXor<Line<F>> for Pseudoscalar<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Line<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Pseudoscalar<F>, Line<F>);
+
+// Line-Motor
+impl<F: Float> BitXor<Motor<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::zero(),
+            Line::new(
+                self.e01() * b.scalar(),
+                self.e02() * b.scalar(),
+                self.e03() * b.scalar(),
+                self.e12() * b.scalar(),
+                self.e31() * b.scalar(),
+                self.e23() * b.scalar(),
+            ),
+            Point::zero(),
+            self.e01() * b.e23()
+                + self.e02() * b.e31()
+                + self.e03() * b.e12()
+                + self.e12() * b.e03()
+                + self.e31() * b.e02()
+                + self.e23() * b.e01(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Line<F>, Motor<F>);
+
+// Motor-Line
+impl<F: Float> BitXor<Line<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::zero(),
+            Line::new(
+                self.scalar() * b.e01(),
+                self.scalar() * b.e02(),
+                self.scalar() * b.e03(),
+                self.scalar() * b.e12(),
+                self.scalar() * b.e31(),
+                self.scalar() * b.e23(),
+            ),
+            Point::zero(),
+            self.e01() * b.e23()
+                + self.e02() * b.e31()
+                + self.e03() * b.e12()
+                + self.e12() * b.e03()
+                + self.e31() * b.e02()
+                + self.e23() * b.e01(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Motor<F>, Line<F>);
+
+// Line-Multivector
+impl<F: Float> BitXor<Multivector<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::zero(),
+            Line::new(
+                self.e01() * b.scalar(),
+                self.e02() * b.scalar(),
+                self.e03() * b.scalar(),
+                self.e12() * b.scalar(),
+                self.e31() * b.scalar(),
+                self.e23() * b.scalar(),
+            ),
+            Point::new_components(
+                -self.e02() * b.e3() + self.e03() * b.e2() - self.e23() * b.e0(),
+                self.e01() * b.e3() - self.e03() * b.e1() - self.e31() * b.e0(),
+                -self.e01() * b.e2() + self.e02() * b.e1() - self.e12() * b.e0(),
+                self.e12() * b.e3() + self.e31() * b.e2() + self.e23() * b.e1(),
+            ),
+            self.e01() * b.e23()
+                + self.e02() * b.e31()
+                + self.e03() * b.e12()
+                + self.e12() * b.e03()
+                + self.e31() * b.e02()
+                + self.e23() * b.e01(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Line<F>, Multivector<F>);
+
+// Multivector-Line
+impl<F: Float> BitXor<Line<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::zero(),
+            Line::new(
+                self.scalar() * b.e01(),
+                self.scalar() * b.e02(),
+                self.scalar() * b.e03(),
+                self.scalar() * b.e12(),
+                self.scalar() * b.e31(),
+                self.scalar() * b.e23(),
+            ),
+            Point::new_components(
+                -self.e0() * b.e23() + self.e2() * b.e03() - self.e3() * b.e02(),
+                -self.e0() * b.e31() - self.e1() * b.e03() + self.e3() * b.e01(),
+                -self.e0() * b.e12() + self.e1() * b.e02() - self.e2() * b.e01(),
+                self.e1() * b.e23() + self.e2() * b.e31() + self.e3() * b.e12(),
+            ),
+            self.e01() * b.e23()
+                + self.e02() * b.e31()
+                + self.e03() * b.e12()
+                + self.e12() * b.e03()
+                + self.e31() * b.e02()
+                + self.e23() * b.e01(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Line<F>);
+
+// Point-Point
+impl<F: Float> BitXor for Point<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Point<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Point<F>, Point<F>);
+
+// Point-Pseudoscalar
+impl<F: Float> BitXor<Pseudoscalar<F>> for Point<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Pseudoscalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Point<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Point
+impl<F: Float> BitXor<Point<F>> for Pseudoscalar<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Point<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Pseudoscalar<F>, Point<F>);
+
+// Point-Motor
+impl<F: Float> BitXor<Motor<F>> for Point<F> {
+    type Output = Point<F>;
+    fn bitxor(self, b: Motor<F>) -> Point<F> {
+        Point::new_components(
+            self.e032() * b.scalar(),
+            self.e013() * b.scalar(),
+            self.e021() * b.scalar(),
+            self.e123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Point<F>, Motor<F>);
+
+// Motor-Point
+impl<F: Float> BitXor<Point<F>> for Motor<F> {
+    type Output = Point<F>;
+    fn bitxor(self, b: Point<F>) -> Point<F> {
+        Point::new_components(
+            self.scalar() * b.e032(),
+            self.scalar() * b.e013(),
+            self.scalar() * b.e021(),
+            self.scalar() * b.e123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Motor<F>, Point<F>);
+
+// Point-Multivector
+impl<F: Float> BitXor<Multivector<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::zero(),
+            Line::zero(),
+            Point::new_components(
+                self.e032() * b.scalar(),
+                self.e013() * b.scalar(),
+                self.e021() * b.scalar(),
+                self.e123() * b.scalar(),
+            ),
+            -self.e032() * b.e1()
+                - self.e013() * b.e2()
+                - self.e021() * b.e3()
+                - self.e123() * b.e0(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Point<F>, Multivector<F>);
+
+// Multivector-Point
+impl<F: Float> BitXor<Point<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::zero(),
+            Line::zero(),
+            Point::new_components(
+                self.scalar() * b.e032(),
+                self.scalar() * b.e013(),
+                self.scalar() * b.e021(),
+                self.scalar() * b.e123(),
+            ),
+            self.e0() * b.e123()
+                + self.e1() * b.e032()
+                + self.e2() * b.e013()
+                + self.e3() * b.e021(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Point<F>);
+
+// Pseudoscalar-Pseudoscalar
+impl<F: Float> BitXor for Pseudoscalar<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Pseudoscalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Pseudoscalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Motor
+impl<F: Float> BitXor<Motor<F>> for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Motor<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.e0123() * b.scalar())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Pseudoscalar<F>, Motor<F>);
+
+// Motor-Pseudoscalar
+impl<F: Float> BitXor<Pseudoscalar<F>> for Motor<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Pseudoscalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.scalar() * b.e0123())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Motor<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Multivector
+impl<F: Float> BitXor<Multivector<F>> for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Multivector<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.e0123() * b.scalar())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Pseudoscalar<F>, Multivector<F>);
+
+// Multivector-Pseudoscalar
+impl<F: Float> BitXor<Pseudoscalar<F>> for Multivector<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Pseudoscalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.scalar() * b.e0123())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Pseudoscalar<F>);
+
+// Motor-Motor
+impl<F: Float> BitXor for Motor<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar(),
+            Plane::zero(),
+            Line::new(
+                self.scalar() * b.e01() + self.e01() * b.scalar(),
+                self.scalar() * b.e02() + self.e02() * b.scalar(),
+                self.scalar() * b.e03() + self.e03() * b.scalar(),
+                self.scalar() * b.e12() + self.e12() * b.scalar(),
+                self.scalar() * b.e31() + self.e31() * b.scalar(),
+                self.scalar() * b.e23() + self.e23() * b.scalar(),
+            ),
+            Point::zero(),
+            self.scalar() * b.e0123()
+                + self.e01() * b.e23()
+                + self.e02() * b.e31()
+                + self.e03() * b.e12()
+                + self.e12() * b.e03()
+                + self.e31() * b.e02()
+                + self.e23() * b.e01()
+                + self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Motor<F>, Motor<F>);
+
+// Motor-Multivector
+impl<F: Float> BitXor<Multivector<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar(),
+            Plane::new(
+                self.scalar() * b.e0(),
+                self.scalar() * b.e1(),
+                self.scalar() * b.e2(),
+                self.scalar() * b.e3(),
+            ),
+            Line::new(
+                self.scalar() * b.e01() + self.e01() * b.scalar(),
+                self.scalar() * b.e02() + self.e02() * b.scalar(),
+                self.scalar() * b.e03() + self.e03() * b.scalar(),
+                self.scalar() * b.e12() + self.e12() * b.scalar(),
+                self.scalar() * b.e31() + self.e31() * b.scalar(),
+                self.scalar() * b.e23() + self.e23() * b.scalar(),
+            ),
+            Point::new_components(
+                self.scalar() * b.e032() - self.e02() * b.e3() + self.e03() * b.e2()
+                    - self.e23() * b.e0(),
+                self.scalar() * b.e013() + self.e01() * b.e3()
+                    - self.e03() * b.e1()
+                    - self.e31() * b.e0(),
+                self.scalar() * b.e021() - self.e01() * b.e2() + self.e02() * b.e1()
+                    - self.e12() * b.e0(),
+                self.scalar() * b.e123()
+                    + self.e12() * b.e3()
+                    + self.e31() * b.e2()
+                    + self.e23() * b.e1(),
+            ),
+            self.scalar() * b.e0123()
+                + self.e01() * b.e23()
+                + self.e02() * b.e31()
+                + self.e03() * b.e12()
+                + self.e12() * b.e03()
+                + self.e31() * b.e02()
+                + self.e23() * b.e01()
+                + self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Motor<F>, Multivector<F>);
+
+// Multivector-Motor
+impl<F: Float> BitXor<Motor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar(),
+            Plane::new(
+                self.e0() * b.scalar(),
+                self.e1() * b.scalar(),
+                self.e2() * b.scalar(),
+                self.e3() * b.scalar(),
+            ),
+            Line::new(
+                self.scalar() * b.e01() + self.e01() * b.scalar(),
+                self.scalar() * b.e02() + self.e02() * b.scalar(),
+                self.scalar() * b.e03() + self.e03() * b.scalar(),
+                self.scalar() * b.e12() + self.e12() * b.scalar(),
+                self.scalar() * b.e31() + self.e31() * b.scalar(),
+                self.scalar() * b.e23() + self.e23() * b.scalar(),
+            ),
+            Point::new_components(
+                -self.e0() * b.e23() + self.e2() * b.e03() - self.e3() * b.e02()
+                    + self.e032() * b.scalar(),
+                -self.e0() * b.e31() - self.e1() * b.e03()
+                    + self.e3() * b.e01()
+                    + self.e013() * b.scalar(),
+                -self.e0() * b.e12() + self.e1() * b.e02() - self.e2() * b.e01()
+                    + self.e021() * b.scalar(),
+                self.e1() * b.e23()
+                    + self.e2() * b.e31()
+                    + self.e3() * b.e12()
+                    + self.e123() * b.scalar(),
+            ),
+            self.scalar() * b.e0123()
+                + self.e01() * b.e23()
+                + self.e02() * b.e31()
+                + self.e03() * b.e12()
+                + self.e12() * b.e03()
+                + self.e31() * b.e02()
+                + self.e23() * b.e01()
+                + self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Motor<F>);
+
+// Multivector-Multivector
+impl<F: Float> BitXor for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar(),
+            Plane::new(
+                self.scalar() * b.e0() + self.e0() * b.scalar(),
+                self.scalar() * b.e1() + self.e1() * b.scalar(),
+                self.scalar() * b.e2() + self.e2() * b.scalar(),
+                self.scalar() * b.e3() + self.e3() * b.scalar(),
+            ),
+            Line::new(
+                self.scalar() * b.e01() + self.e0() * b.e1() - self.e1() * b.e0()
+                    + self.e01() * b.scalar(),
+                self.scalar() * b.e02() + self.e0() * b.e2() - self.e2() * b.e0()
+                    + self.e02() * b.scalar(),
+                self.scalar() * b.e03() + self.e0() * b.e3() - self.e3() * b.e0()
+                    + self.e03() * b.scalar(),
+                self.scalar() * b.e12() + self.e1() * b.e2() - self.e2() * b.e1()
+                    + self.e12() * b.scalar(),
+                self.scalar() * b.e31() - self.e1() * b.e3()
+                    + self.e3() * b.e1()
+                    + self.e31() * b.scalar(),
+                self.scalar() * b.e23() + self.e2() * b.e3() - self.e3() * b.e2()
+                    + self.e23() * b.scalar(),
+            ),
+            Point::new_components(
+                self.scalar() * b.e032() - self.e0() * b.e23() + self.e2() * b.e03()
+                    - self.e3() * b.e02()
+                    - self.e02() * b.e3()
+                    + self.e03() * b.e2()
+                    - self.e23() * b.e0()
+                    + self.e032() * b.scalar(),
+                self.scalar() * b.e013() - self.e0() * b.e31() - self.e1() * b.e03()
+                    + self.e3() * b.e01()
+                    + self.e01() * b.e3()
+                    - self.e03() * b.e1()
+                    - self.e31() * b.e0()
+                    + self.e013() * b.scalar(),
+                self.scalar() * b.e021() - self.e0() * b.e12() + self.e1() * b.e02()
+                    - self.e2() * b.e01()
+                    - self.e01() * b.e2()
+                    + self.e02() * b.e1()
+                    - self.e12() * b.e0()
+                    + self.e021() * b.scalar(),
+                self.scalar() * b.e123()
+                    + self.e1() * b.e23()
+                    + self.e2() * b.e31()
+                    + self.e3() * b.e12()
+                    + self.e12() * b.e3()
+                    + self.e31() * b.e2()
+                    + self.e23() * b.e1()
+                    + self.e123() * b.scalar(),
+            ),
+            self.scalar() * b.e0123()
+                + self.e0() * b.e123()
+                + self.e1() * b.e032()
+                + self.e2() * b.e013()
+                + self.e3() * b.e021()
+                + self.e01() * b.e23()
+                + self.e02() * b.e31()
+                + self.e03() * b.e12()
+                + self.e12() * b.e03()
+                + self.e31() * b.e02()
+                + self.e23() * b.e01()
+                - self.e032() * b.e1()
+                - self.e013() * b.e2()
+                - self.e021() * b.e3()
+                - self.e123() * b.e0()
+                + self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Multivector<F>);
+
+// Test
+#[cfg(test)]
+mod exterior_product {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn plane_plane() {
+        // 4e0-3e1-4e2+1e3
+        let plane1 = Plane::new(4.0, -3.0, -4.0, 1.0);
+        // 3e0+5e1+2e2+3e3
+        let plane2 = Plane::new(3.0, 5.0, 2.0, 3.0);
+        let res = plane1 ^ plane2;
+        // 29e01+20e02+9e03+14e12+14e31-14e23
+        assert_relative_eq!(res.e01(), 29.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e02(), 20.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e03(), 9.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), -14.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn plane_line() {
+        // -1e0+5e1+5e2+2e3
+        let plane1 = Plane::new(-1.0, 5.0, 5.0, 2.0);
+        // -1e01-1e02-1e03+3e12-3e31+1e23
+        let line2 = Line::new(-1.0, -1.0, -1.0, 3.0, -3.0, 1.0);
+        let res = plane1 ^ line2;
+        // -2e032+3e021-4e123
+        assert_relative_eq!(res.e032(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e013(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e021(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), -4.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn line_plane() {
+        // -1e01+2e02+1e03-4e12+2e31+3e23
+        let line1 = Line::new(-1.0, 2.0, 1.0, -4.0, 2.0, 3.0);
+        // 4e0-2e1-4e2-2e3
+        let plane2 = Plane::new(4.0, -2.0, -4.0, -2.0);
+        let res = line1 ^ plane2;
+        // -12e032-4e013+8e021-6e123
+        assert_relative_eq!(res.e032(), -12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e013(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e021(), 8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), -6.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn plane_point() {
+        // -2e0+3e1+4e2-4e3
+        let plane1 = Plane::new(-2.0, 3.0, 4.0, -4.0);
+        // -2e032-1e013+3e021-2e123
+        let point2 = Point::new_components(-2.0, -1.0, 3.0, -2.0);
+        let res = plane1 ^ point2;
+        // -18e0123
+        assert_relative_eq!(res.e0123(), -18.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn line_line() {
+        // 2e01+1e02+2e03+5e12-2e31-3e23
+        let line1 = Line::new(2.0, 1.0, 2.0, 5.0, -2.0, -3.0);
+        // 1e01-1e02+4e03+4e12+3e31+4e23
+        let line2 = Line::new(1.0, -1.0, 4.0, 4.0, 3.0, 4.0);
+        let res = line1 ^ line2;
+        // 38e0123
+        assert_relative_eq!(res.e0123(), 38.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn motor_plane() {
+        // -3-1e01+4e02+5e03+4e12+4e31-1e23+2e0123
+        let motor1 = Motor::new(-3.0, Line::new(-1.0, 4.0, 5.0, 4.0, 4.0, -1.0), 2.0);
+        // 1e0+3e1+5e2+5e3
+        let plane2 = Plane::new(1.0, 3.0, 5.0, 5.0);
+        let res = motor1 ^ plane2;
+        // -3e0-9e1-15e2-15e3+6e032-24e013+13e021+37e123
+        assert_relative_eq!(res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -9.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -15.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), -15.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e02(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e03(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e032(), 6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e013(), -24.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e021(), 13.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 37.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_multivector() {
+        // -2+2e0-4e1-3e2-4e3-3e01+1e02-4e03+3e12-3e31+4e23+3e032+5e013-1e021+3e123+2e0123
+        let mvec1 = Multivector::new(
+            -2.0,
+            Plane::new(2.0, -4.0, -3.0, -4.0),
+            Line::new(-3.0, 1.0, -4.0, 3.0, -3.0, 4.0),
+            Point::new_components(3.0, 5.0, -1.0, 3.0),
+            2.0,
+        );
+        // -3+4e0+4e1+5e2-1e3+5e01-3e02+1e03-3e12-4e31+4e23-3e032+2e013-2e021-1e123-1e0123
+        let mvec2 = Multivector::new(
+            -3.0,
+            Plane::new(4.0, 4.0, 5.0, -1.0),
+            Line::new(5.0, -3.0, 1.0, -3.0, -4.0, 4.0),
+            Point::new_components(-3.0, 2.0, -2.0, -1.0),
+            -1.0,
+        );
+        let res = mvec1 ^ mvec2;
+        // 6-14e0+4e1-1e2+14e3+23e01+25e02+24e03-11e12-3e31+3e23-61e032+4e013+47e021-1e123-14e0123
+        assert_relative_eq!(res.scalar(), 6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), -14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), 23.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e02(), 25.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e03(), 24.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), -11.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e032(), -61.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e013(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e021(), 47.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), -14.0, max_relative = 0.000001);
+    }
+}
@@ -0,0 +1,180 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    PGA3DOps, line::Line, motor::Motor, multivector::Multivector, plane::Plane, point::Point,
+};
+
+use num_traits::Float;
+
+// Functions
+// Rigid body motion
+// \[ X' = \widetilde{M} X M \]
+pub trait Transformable<M> {
+    type Output;
+    fn transform(self, motor: M) -> Self::Output;
+}
+
+macro_rules! impl_transformable {
+    ($element:ty, $output:ty, $extract:ident) => {
+        // Owned element, owned motor
+        impl<F: Float> Transformable<Motor<F>> for $element {
+            type Output = $output;
+            fn transform(self, motor: Motor<F>) -> Self::Output {
+                (motor.reverse() * self * motor).$extract()
+            }
+        }
+
+        // Owned element, reference motor
+        impl<'m, F: Float> Transformable<&'m Motor<F>> for $element {
+            type Output = $output;
+            fn transform(self, motor: &'m Motor<F>) -> Self::Output {
+                (motor.reverse() * self * *motor).$extract()
+            }
+        }
+
+        // Reference element, owned motor
+        impl<'e, F: Float> Transformable<Motor<F>> for &'e $element {
+            type Output = $output;
+            fn transform(self, motor: Motor<F>) -> Self::Output {
+                (motor.reverse() * *self * motor).$extract()
+            }
+        }
+
+        // Reference element, reference motor
+        impl<'e, 'm, F: Float> Transformable<&'m Motor<F>> for &'e $element {
+            type Output = $output;
+            fn transform(self, motor: &'m Motor<F>) -> Self::Output {
+                (motor.reverse() * *self * *motor).$extract()
+            }
+        }
+    };
+}
+
+// Usage:
+impl_transformable!(Plane<F>, Plane<F>, plane);
+impl_transformable!(Line<F>, Line<F>, line);
+impl_transformable!(Point<F>, Point<F>, point);
+impl_transformable!(Motor<F>, Motor<F>, motor);
+impl_transformable!(Multivector<F>, Multivector<F>, multivector);
+
+#[cfg(test)]
+mod transformation {
+    use super::*;
+    use approx::assert_relative_eq;
+    use core::f64::consts::TAU;
+
+    #[test]
+    fn point_translation() {
+        let point = Point::new(1.0, 2.0, 3.0);
+        let motor = Motor::from_translation(4.0, 5.0, 6.0);
+        for res in [
+            (&point).transform(&motor),
+            (&point).transform(motor),
+            point.transform(&motor),
+            point.transform(motor),
+        ] {
+            assert_relative_eq!(res.e032(), 5.0, max_relative = 0.000001);
+            assert_relative_eq!(res.e013(), 7.0, max_relative = 0.000001);
+            assert_relative_eq!(res.e021(), 9.0, max_relative = 0.000001);
+            assert_relative_eq!(res.e123(), 1.0, max_relative = 0.000001);
+        }
+    }
+
+    #[test]
+    fn point_rotation() {
+        // A quarter turn around the z-axis
+        let z_axis = Point::new(0.0, 0.0, 0.0) & Point::new(0.0, 0.0, 1.0);
+        let motor = Motor::try_from_rotation(TAU / 4.0, z_axis).unwrap();
+        let res = Point::new(1.0, 0.0, 5.0).transform(motor);
+        assert_relative_eq!(res.e032(), 0.0, epsilon = 0.000001);
+        assert_relative_eq!(res.e013(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e021(), 5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 1.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn point_rotation_offset_axis() {
+        // A half turn around the line parallel to the z-axis through (1,0,0)
+        let axis = Point::new(1.0, 0.0, 0.0) & Point::new(1.0, 0.0, 1.0);
+        let motor = Motor::try_from_rotation(TAU / 2.0, axis).unwrap();
+        let res = Point::new(0.0, 0.0, 0.0).transform(motor);
+        assert_relative_eq!(res.e032(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e013(), 0.0, epsilon = 0.000001);
+        assert_relative_eq!(res.e021(), 0.0, epsilon = 0.000001);
+        assert_relative_eq!(res.e123(), 1.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn motor_composition() {
+        // Rotate a quarter turn around the z-axis and then translate along x
+        let z_axis = Point::new(0.0, 0.0, 0.0) & Point::new(0.0, 0.0, 1.0);
+        let rotation = Motor::try_from_rotation(TAU / 4.0, z_axis).unwrap();
+        let translation = Motor::from_translation(3.0, 0.0, 0.0);
+        let point = Point::new(1.0, 0.0, 0.0);
+        let res = point.transform(rotation * translation);
+        let expected = point.transform(rotation).transform(translation);
+        assert_relative_eq!(res.e032(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e013(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e032(), expected.e032(), max_relative = 0.000001);
+        assert_relative_eq!(res.e013(), expected.e013(), max_relative = 0.000001);
+        assert_relative_eq!(res.e021(), expected.e021(), epsilon = 0.000001);
+    }
+
+    #[test]
+    fn plane_translation() {
+        // The plane z = 0 moved up by 2 is the plane z = 2
+        let plane = Plane::from_equation(0.0, 0.0, 1.0, 0.0);
+        let res = plane.transform(Motor::from_translation(0.0, 0.0, 2.0));
+        assert_relative_eq!(res.e3(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), -2.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn line_transformation() {
+        // Moving the join of two points is the join of the moved points
+        let axis = Point::new(0.3, -0.2, 0.1) & Point::new(1.0, 2.0, -0.5);
+        let motor =
+            Motor::try_from_rotation(1.2, axis).unwrap() * Motor::from_translation(1.0, -2.0, 0.5);
+        let point1 = Point::new(1.0, 2.0, 3.0);
+        let point2 = Point::new(-1.0, 0.5, 2.0);
+        let res = (point1 & point2).transform(motor);
+        let expected = point1.transform(motor) & point2.transform(motor);
+        assert_relative_eq!(res.e01(), expected.e01(), max_relative = 0.000001);
+        assert_relative_eq!(res.e02(), expected.e02(), max_relative = 0.000001);
+        assert_relative_eq!(res.e03(), expected.e03(), max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), expected.e12(), max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), expected.e31(), max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), expected.e23(), max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_motor() {
+        // A motor made from a rotor rotates points like the rotor rotates vectors
+        let rotor = crate::vga3d::Rotor::new(crate::vga3d::Bivector::new(0.3, -0.4, 0.2));
+        let vector = crate::vga3d::Vector::new(1.0, 2.0, 3.0);
+        let expected = crate::vga3d::Rotatable::rotate(vector, rotor);
+        let res = Point::from_vector(vector)
+            .transform(Motor::from_rotor(rotor))
+            .try_to_vector()
+            .unwrap();
+        assert_relative_eq!(res.e1(), expected.e1(), max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), expected.e2(), max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), expected.e3(), max_relative = 0.000001);
+    }
+}
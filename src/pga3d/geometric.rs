@@ -0,0 +1,1801 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    line::Line, motor::Motor, multivector::Multivector, plane::Plane, point::Point,
+    pseudoscalar::Pseudoscalar, scalar::Scalar,
+};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::Mul;
+
+// Geometric Product
+
+// Scalar-Scalar
+impl<F: Float> Mul for Scalar<F> {
+    type Output = F;
+    fn mul(self, b: Scalar<F>) -> F {
+        self.0 * b.0
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Scalar<F>);
+
+// Scalar-Plane
+impl<F: Float> Mul<Plane<F>> for Scalar<F> {
+    type Output = Plane<F>;
+    fn mul(self, b: Plane<F>) -> Plane<F> {
+        Plane::new(
+            self.0 * b.e0(),
+            self.0 * b.e1(),
+            self.0 * b.e2(),
+            self.0 * b.e3(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Plane<F>);
+
+// Plane-Scalar
+impl<F: Float> Mul<Scalar<F>> for Plane<F> {
+    type Output = Plane<F>;
+    fn mul(self, b: Scalar<F>) -> Plane<F> {
+        Plane::new(
+            self.e0() * b.0,
+            self.e1() * b.0,
+            self.e2() * b.0,
+            self.e3() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Plane<F>, Scalar<F>);
+
+// Scalar-Line
+impl<F: Float> Mul<Line<F>> for Scalar<F> {
+    type Output = Line<F>;
+    fn mul(self, b: Line<F>) -> Line<F> {
+        Line::new(
+            self.0 * b.e01(),
+            self.0 * b.e02(),
+            self.0 * b.e03(),
+            self.0 * b.e12(),
+            self.0 * b.e31(),
+            self.0 * b.e23(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Line<F>);
+
+// Line-Scalar
+impl<F: Float> Mul<Scalar<F>> for Line<F> {
+    type Output = Line<F>;
+    fn mul(self, b: Scalar<F>) -> Line<F> {
+        Line::new(
+            self.e01() * b.0,
+            self.e02() * b.0,
+            self.e03() * b.0,
+            self.e12() * b.0,
+            self.e31() * b.0,
+            self.e23() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Line<F>, Scalar<F>);
+
+// Scalar-Point
+impl<F: Float> Mul<Point<F>> for Scalar<F> {
+    type Output = Point<F>;
+    fn mul(self, b: Point<F>) -> Point<F> {
+        Point::new_components(
+            self.0 * b.e032(),
+            self.0 * b.e013(),
+            self.0 * b.e021(),
+            self.0 * b.e123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Point<F>);
+
+// Point-Scalar
+impl<F: Float> Mul<Scalar<F>> for Point<F> {
+    type Output = Point<F>;
+    fn mul(self, b: Scalar<F>) -> Point<F> {
+        Point::new_components(
+            self.e032() * b.0,
+            self.e013() * b.0,
+            self.e021() * b.0,
+            self.e123() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Point<F>, Scalar<F>);
+
+// Scalar-Pseudoscalar
+impl<F: Float> Mul<Pseudoscalar<F>> for Scalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn mul(self, b: Pseudoscalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.0 * b.e0123())
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Scalar
+impl<F: Float> Mul<Scalar<F>> for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn mul(self, b: Scalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.e0123() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Scalar<F>);
+
+// Scalar-Motor
+impl<F: Float> Mul<Motor<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 * b.scalar(),
+            Plane::zero(),
+            Line::new(
+                self.0 * b.e01(),
+                self.0 * b.e02(),
+                self.0 * b.e03(),
+                self.0 * b.e12(),
+                self.0 * b.e31(),
+                self.0 * b.e23(),
+            ),
+            Point::zero(),
+            self.0 * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Motor<F>);
+
+// Motor-Scalar
+impl<F: Float> Mul<Scalar<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.0,
+            Plane::zero(),
+            Line::new(
+                self.e01() * b.0,
+                self.e02() * b.0,
+                self.e03() * b.0,
+                self.e12() * b.0,
+                self.e31() * b.0,
+                self.e23() * b.0,
+            ),
+            Point::zero(),
+            self.e0123() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Motor<F>, Scalar<F>);
+
+// Scalar-Multivector
+impl<F: Float> Mul<Multivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 * b.scalar(),
+            Plane::new(
+                self.0 * b.e0(),
+                self.0 * b.e1(),
+                self.0 * b.e2(),
+                self.0 * b.e3(),
+            ),
+            Line::new(
+                self.0 * b.e01(),
+                self.0 * b.e02(),
+                self.0 * b.e03(),
+                self.0 * b.e12(),
+                self.0 * b.e31(),
+                self.0 * b.e23(),
+            ),
+            Point::new_components(
+                self.0 * b.e032(),
+                self.0 * b.e013(),
+                self.0 * b.e021(),
+                self.0 * b.e123(),
+            ),
+            self.0 * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Multivector<F>);
+
+// Multivector-Scalar
+impl<F: Float> Mul<Scalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.0,
+            Plane::new(
+                self.e0() * b.0,
+                self.e1() * b.0,
+                self.e2() * b.0,
+                self.e3() * b.0,
+            ),
+            Line::new(
+                self.e01() * b.0,
+                self.e02() * b.0,
+                self.e03() * b.0,
+                self.e12() * b.0,
+                self.e31() * b.0,
+                self.e23() * b.0,
+            ),
+            Point::new_components(
+                self.e032() * b.0,
+                self.e013() * b.0,
+                self.e021() * b.0,
+                self.e123() * b.0,
+            ),
+            self.e0123() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Scalar<F>);
+
+// Plane-Plane
+impl<F: Float> Mul for Plane<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Plane<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1() * b.e1() + self.e2() * b.e2() + self.e3() * b.e3(),
+            Plane::zero(),
+            Line::new(
+                self.e0() * b.e1() - self.e1() * b.e0(),
+                self.e0() * b.e2() - self.e2() * b.e0(),
+                self.e0() * b.e3() - self.e3() * b.e0(),
+                self.e1() * b.e2() - self.e2() * b.e1(),
+                -self.e1() * b.e3() + self.e3() * b.e1(),
+                self.e2() * b.e3() - self.e3() * b.e2(),
+            ),
+            Point::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Plane<F>, Plane<F>);
+
+// Plane-Line
+impl<F: Float> Mul<Line<F>> for Plane<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(
+                -self.e1() * b.e01() - self.e2() * b.e02() - self.e3() * b.e03(),
+                -self.e2() * b.e12() + self.e3() * b.e31(),
+                self.e1() * b.e12() - self.e3() * b.e23(),
+                -self.e1() * b.e31() + self.e2() * b.e23(),
+            ),
+            Line::zero(),
+            Point::new_components(
+                -self.e0() * b.e23() + self.e2() * b.e03() - self.e3() * b.e02(),
+                -self.e0() * b.e31() - self.e1() * b.e03() + self.e3() * b.e01(),
+                -self.e0() * b.e12() + self.e1() * b.e02() - self.e2() * b.e01(),
+                self.e1() * b.e23() + self.e2() * b.e31() + self.e3() * b.e12(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Plane<F>, Line<F>);
+
+// Line-Plane
+impl<F: Float> Mul<Plane<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Plane<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(
+                self.e01() * b.e1() + self.e02() * b.e2() + self.e03() * b.e3(),
+                self.e12() * b.e2() - self.e31() * b.e3(),
+                -self.e12() * b.e1() + self.e23() * b.e3(),
+                self.e31() * b.e1() - self.e23() * b.e2(),
+            ),
+            Line::zero(),
+            Point::new_components(
+                -self.e02() * b.e3() + self.e03() * b.e2() - self.e23() * b.e0(),
+                self.e01() * b.e3() - self.e03() * b.e1() - self.e31() * b.e0(),
+                -self.e01() * b.e2() + self.e02() * b.e1() - self.e12() * b.e0(),
+                self.e12() * b.e3() + self.e31() * b.e2() + self.e23() * b.e1(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Line<F>, Plane<F>);
+
+// Plane-Point
+impl<F: Float> Mul<Point<F>> for Plane<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::zero(),
+            Line::new(
+                -self.e2() * b.e021() + self.e3() * b.e013(),
+                self.e1() * b.e021() - self.e3() * b.e032(),
+                -self.e1() * b.e013() + self.e2() * b.e032(),
+                self.e3() * b.e123(),
+                self.e2() * b.e123(),
+                self.e1() * b.e123(),
+            ),
+            Point::zero(),
+            self.e0() * b.e123()
+                + self.e1() * b.e032()
+                + self.e2() * b.e013()
+                + self.e3() * b.e021(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Plane<F>, Point<F>);
+
+// Point-Plane
+impl<F: Float> Mul<Plane<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Plane<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::zero(),
+            Line::new(
+                self.e013() * b.e3() - self.e021() * b.e2(),
+                -self.e032() * b.e3() + self.e021() * b.e1(),
+                self.e032() * b.e2() - self.e013() * b.e1(),
+                self.e123() * b.e3(),
+                self.e123() * b.e2(),
+                self.e123() * b.e1(),
+            ),
+            Point::zero(),
+            -self.e032() * b.e1()
+                - self.e013() * b.e2()
+                - self.e021() * b.e3()
+                - self.e123() * b.e0(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Point<F>, Plane<F>);
+
+// Plane-Pseudoscalar
+impl<F: Float> Mul<Pseudoscalar<F>> for Plane<F> {
+    type Output = Point<F>;
+    fn mul(self, b: Pseudoscalar<F>) -> Point<F> {
+        Point::new_components(
+            self.e1() * b.e0123(),
+            self.e2() * b.e0123(),
+            self.e3() * b.e0123(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Plane<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Plane
+impl<F: Float> Mul<Plane<F>> for Pseudoscalar<F> {
+    type Output = Point<F>;
+    fn mul(self, b: Plane<F>) -> Point<F> {
+        Point::new_components(
+            -self.e0123() * b.e1(),
+            -self.e0123() * b.e2(),
+            -self.e0123() * b.e3(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Plane<F>);
+
+// Plane-Motor
+impl<F: Float> Mul<Motor<F>> for Plane<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(
+                self.e0() * b.scalar()
+                    - self.e1() * b.e01()
+                    - self.e2() * b.e02()
+                    - self.e3() * b.e03(),
+                self.e1() * b.scalar() - self.e2() * b.e12() + self.e3() * b.e31(),
+                self.e1() * b.e12() + self.e2() * b.scalar() - self.e3() * b.e23(),
+                -self.e1() * b.e31() + self.e2() * b.e23() + self.e3() * b.scalar(),
+            ),
+            Line::zero(),
+            Point::new_components(
+                -self.e0() * b.e23() + self.e1() * b.e0123() + self.e2() * b.e03()
+                    - self.e3() * b.e02(),
+                -self.e0() * b.e31() - self.e1() * b.e03()
+                    + self.e2() * b.e0123()
+                    + self.e3() * b.e01(),
+                -self.e0() * b.e12() + self.e1() * b.e02() - self.e2() * b.e01()
+                    + self.e3() * b.e0123(),
+                self.e1() * b.e23() + self.e2() * b.e31() + self.e3() * b.e12(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Plane<F>, Motor<F>);
+
+// Motor-Plane
+impl<F: Float> Mul<Plane<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Plane<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(
+                self.scalar() * b.e0()
+                    + self.e01() * b.e1()
+                    + self.e02() * b.e2()
+                    + self.e03() * b.e3(),
+                self.scalar() * b.e1() + self.e12() * b.e2() - self.e31() * b.e3(),
+                self.scalar() * b.e2() - self.e12() * b.e1() + self.e23() * b.e3(),
+                self.scalar() * b.e3() + self.e31() * b.e1() - self.e23() * b.e2(),
+            ),
+            Line::zero(),
+            Point::new_components(
+                -self.e02() * b.e3() + self.e03() * b.e2()
+                    - self.e23() * b.e0()
+                    - self.e0123() * b.e1(),
+                self.e01() * b.e3()
+                    - self.e03() * b.e1()
+                    - self.e31() * b.e0()
+                    - self.e0123() * b.e2(),
+                -self.e01() * b.e2() + self.e02() * b.e1()
+                    - self.e12() * b.e0()
+                    - self.e0123() * b.e3(),
+                self.e12() * b.e3() + self.e31() * b.e2() + self.e23() * b.e1(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Motor<F>, Plane<F>);
+
+// Plane-Multivector
+impl<F: Float> Mul<Multivector<F>> for Plane<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1() * b.e1() + self.e2() * b.e2() + self.e3() * b.e3(),
+            Plane::new(
+                self.e0() * b.scalar()
+                    - self.e1() * b.e01()
+                    - self.e2() * b.e02()
+                    - self.e3() * b.e03(),
+                self.e1() * b.scalar() - self.e2() * b.e12() + self.e3() * b.e31(),
+                self.e1() * b.e12() + self.e2() * b.scalar() - self.e3() * b.e23(),
+                -self.e1() * b.e31() + self.e2() * b.e23() + self.e3() * b.scalar(),
+            ),
+            Line::new(
+                self.e0() * b.e1() - self.e1() * b.e0() - self.e2() * b.e021()
+                    + self.e3() * b.e013(),
+                self.e0() * b.e2() + self.e1() * b.e021()
+                    - self.e2() * b.e0()
+                    - self.e3() * b.e032(),
+                self.e0() * b.e3() - self.e1() * b.e013() + self.e2() * b.e032()
+                    - self.e3() * b.e0(),
+                self.e1() * b.e2() - self.e2() * b.e1() + self.e3() * b.e123(),
+                -self.e1() * b.e3() + self.e2() * b.e123() + self.e3() * b.e1(),
+                self.e1() * b.e123() + self.e2() * b.e3() - self.e3() * b.e2(),
+            ),
+            Point::new_components(
+                -self.e0() * b.e23() + self.e1() * b.e0123() + self.e2() * b.e03()
+                    - self.e3() * b.e02(),
+                -self.e0() * b.e31() - self.e1() * b.e03()
+                    + self.e2() * b.e0123()
+                    + self.e3() * b.e01(),
+                -self.e0() * b.e12() + self.e1() * b.e02() - self.e2() * b.e01()
+                    + self.e3() * b.e0123(),
+                self.e1() * b.e23() + self.e2() * b.e31() + self.e3() * b.e12(),
+            ),
+            self.e0() * b.e123()
+                + self.e1() * b.e032()
+                + self.e2() * b.e013()
+                + self.e3() * b.e021(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Plane<F>, Multivector<F>);
+
+// Multivector-Plane
+impl<F: Float> Mul<Plane<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Plane<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1() * b.e1() + self.e2() * b.e2() + self.e3() * b.e3(),
+            Plane::new(
+                self.scalar() * b.e0()
+                    + self.e01() * b.e1()
+                    + self.e02() * b.e2()
+                    + self.e03() * b.e3(),
+                self.scalar() * b.e1() + self.e12() * b.e2() - self.e31() * b.e3(),
+                self.scalar() * b.e2() - self.e12() * b.e1() + self.e23() * b.e3(),
+                self.scalar() * b.e3() + self.e31() * b.e1() - self.e23() * b.e2(),
+            ),
+            Line::new(
+                self.e0() * b.e1() - self.e1() * b.e0() + self.e013() * b.e3()
+                    - self.e021() * b.e2(),
+                self.e0() * b.e2() - self.e2() * b.e0() - self.e032() * b.e3()
+                    + self.e021() * b.e1(),
+                self.e0() * b.e3() - self.e3() * b.e0() + self.e032() * b.e2()
+                    - self.e013() * b.e1(),
+                self.e1() * b.e2() - self.e2() * b.e1() + self.e123() * b.e3(),
+                -self.e1() * b.e3() + self.e3() * b.e1() + self.e123() * b.e2(),
+                self.e2() * b.e3() - self.e3() * b.e2() + self.e123() * b.e1(),
+            ),
+            Point::new_components(
+                -self.e02() * b.e3() + self.e03() * b.e2()
+                    - self.e23() * b.e0()
+                    - self.e0123() * b.e1(),
+                self.e01() * b.e3()
+                    - self.e03() * b.e1()
+                    - self.e31() * b.e0()
+                    - self.e0123() * b.e2(),
+                -self.e01() * b.e2() + self.e02() * b.e1()
+                    - self.e12() * b.e0()
+                    - self.e0123() * b.e3(),
+                self.e12() * b.e3() + self.e31() * b.e2() + self.e23() * b.e1(),
+            ),
+            -self.e032() * b.e1()
+                - self.e013() * b.e2()
+                - self.e021() * b.e3()
+                - self.e123() * b.e0(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Plane<F>);
+
+// Line-Line
+impl<F: Float> Mul for Line<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12() * b.e12() - self.e31() * b.e31() - self.e23() * b.e23(),
+            Plane::zero(),
+            Line::new(
+                -self.e02() * b.e12() + self.e03() * b.e31() + self.e12() * b.e02()
+                    - self.e31() * b.e03(),
+                self.e01() * b.e12() - self.e03() * b.e23() - self.e12() * b.e01()
+                    + self.e23() * b.e03(),
+                -self.e01() * b.e31() + self.e02() * b.e23() + self.e31() * b.e01()
+                    - self.e23() * b.e02(),
+                self.e31() * b.e23() - self.e23() * b.e31(),
+                -self.e12() * b.e23() + self.e23() * b.e12(),
+                self.e12() * b.e31() - self.e31() * b.e12(),
+            ),
+            Point::zero(),
+            self.e01() * b.e23()
+                + self.e02() * b.e31()
+                + self.e03() * b.e12()
+                + self.e12() * b.e03()
+                + self.e31() * b.e02()
+                + self.e23() * b.e01(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Line<F>, Line<F>);
+
+// Line-Point
+impl<F: Float> Mul<Point<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(
+                self.e12() * b.e021() + self.e31() * b.e013() + self.e23() * b.e032(),
+                -self.e23() * b.e123(),
+                -self.e31() * b.e123(),
+                -self.e12() * b.e123(),
+            ),
+            Line::zero(),
+            Point::new_components(
+                -self.e01() * b.e123() + self.e12() * b.e013() - self.e31() * b.e021(),
+                -self.e02() * b.e123() - self.e12() * b.e032() + self.e23() * b.e021(),
+                -self.e03() * b.e123() + self.e31() * b.e032() - self.e23() * b.e013(),
+                F::zero(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Line<F>, Point<F>);
+
+// Point-Line
+impl<F: Float> Mul<Line<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(
+                self.e032() * b.e23() + self.e013() * b.e31() + self.e021() * b.e12(),
+                -self.e123() * b.e23(),
+                -self.e123() * b.e31(),
+                -self.e123() * b.e12(),
+            ),
+            Line::zero(),
+            Point::new_components(
+                -self.e013() * b.e12() + self.e021() * b.e31() + self.e123() * b.e01(),
+                self.e032() * b.e12() - self.e021() * b.e23() + self.e123() * b.e02(),
+                -self.e032() * b.e31() + self.e013() * b.e23() + self.e123() * b.e03(),
+                F::zero(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Point<F>, Line<F>);
+
+// Line-Pseudoscalar
+impl<F: Float> Mul<Pseudoscalar<F>> for Line<F> {
+    type Output = Line<F>;
+    fn mul(self, b: Pseudoscalar<F>) -> Line<F> {
+        Line::new(
+            -self.e23() * b.e0123(),
+            -self.e31() * b.e0123(),
+            -self.e12() * b.e0123(),
+            F::zero(),
+            F::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Line<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Line
+impl<F: Float> Mul<Line<F>> for Pseudoscalar<F> {
+    type Output = Line<F>;
+    fn mul(self, b: Line<F>) -> Line<F> {
+        Line::new(
+            -self.e0123() * b.e23(),
+            -self.e0123() * b.e31(),
+            -self.e0123() * b.e12(),
+            F::zero(),
+            F::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Line<F>);
+
+// Line-Motor
+impl<F: Float> Mul<Motor<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12() * b.e12() - self.e31() * b.e31() - self.e23() * b.e23(),
+            Plane::zero(),
+            Line::new(
+                self.e01() * b.scalar() - self.e02() * b.e12()
+                    + self.e03() * b.e31()
+                    + self.e12() * b.e02()
+                    - self.e31() * b.e03()
+                    - self.e23() * b.e0123(),
+                self.e01() * b.e12() + self.e02() * b.scalar()
+                    - self.e03() * b.e23()
+                    - self.e12() * b.e01()
+                    - self.e31() * b.e0123()
+                    + self.e23() * b.e03(),
+                -self.e01() * b.e31() + self.e02() * b.e23() + self.e03() * b.scalar()
+                    - self.e12() * b.e0123()
+                    + self.e31() * b.e01()
+                    - self.e23() * b.e02(),
+                self.e12() * b.scalar() + self.e31() * b.e23() - self.e23() * b.e31(),
+                -self.e12() * b.e23() + self.e31() * b.scalar() + self.e23() * b.e12(),
+                self.e12() * b.e31() - self.e31() * b.e12() + self.e23() * b.scalar(),
+            ),
+            Point::zero(),
+            self.e01() * b.e23()
+                + self.e02() * b.e31()
+                + self.e03() * b.e12()
+                + self.e12() * b.e03()
+                + self.e31() * b.e02()
+                + self.e23() * b.e01(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Line<F>, Motor<F>);
+
+// Motor-Line
+impl<F: Float> Mul<Line<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12() * b.e12() - self.e31() * b.e31() - self.e23() * b.e23(),
+            Plane::zero(),
+            Line::new(
+                self.scalar() * b.e01() - self.e02() * b.e12()
+                    + self.e03() * b.e31()
+                    + self.e12() * b.e02()
+                    - self.e31() * b.e03()
+                    - self.e0123() * b.e23(),
+                self.scalar() * b.e02() + self.e01() * b.e12()
+                    - self.e03() * b.e23()
+                    - self.e12() * b.e01()
+                    + self.e23() * b.e03()
+                    - self.e0123() * b.e31(),
+                self.scalar() * b.e03() - self.e01() * b.e31()
+                    + self.e02() * b.e23()
+                    + self.e31() * b.e01()
+                    - self.e23() * b.e02()
+                    - self.e0123() * b.e12(),
+                self.scalar() * b.e12() + self.e31() * b.e23() - self.e23() * b.e31(),
+                self.scalar() * b.e31() - self.e12() * b.e23() + self.e23() * b.e12(),
+                self.scalar() * b.e23() + self.e12() * b.e31() - self.e31() * b.e12(),
+            ),
+            Point::zero(),
+            self.e01() * b.e23()
+                + self.e02() * b.e31()
+                + self.e03() * b.e12()
+                + self.e12() * b.e03()
+                + self.e31() * b.e02()
+                + self.e23() * b.e01(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Motor<F>, Line<F>);
+
+// Line-Multivector
+impl<F: Float> Mul<Multivector<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12() * b.e12() - self.e31() * b.e31() - self.e23() * b.e23(),
+            Plane::new(
+                self.e01() * b.e1()
+                    + self.e02() * b.e2()
+                    + self.e03() * b.e3()
+                    + self.e12() * b.e021()
+                    + self.e31() * b.e013()
+                    + self.e23() * b.e032(),
+                self.e12() * b.e2() - self.e31() * b.e3() - self.e23() * b.e123(),
+                -self.e12() * b.e1() - self.e31() * b.e123() + self.e23() * b.e3(),
+                -self.e12() * b.e123() + self.e31() * b.e1() - self.e23() * b.e2(),
+            ),
+            Line::new(
+                self.e01() * b.scalar() - self.e02() * b.e12()
+                    + self.e03() * b.e31()
+                    + self.e12() * b.e02()
+                    - self.e31() * b.e03()
+                    - self.e23() * b.e0123(),
+                self.e01() * b.e12() + self.e02() * b.scalar()
+                    - self.e03() * b.e23()
+                    - self.e12() * b.e01()
+                    - self.e31() * b.e0123()
+                    + self.e23() * b.e03(),
+                -self.e01() * b.e31() + self.e02() * b.e23() + self.e03() * b.scalar()
+                    - self.e12() * b.e0123()
+                    + self.e31() * b.e01()
+                    - self.e23() * b.e02(),
+                self.e12() * b.scalar() + self.e31() * b.e23() - self.e23() * b.e31(),
+                -self.e12() * b.e23() + self.e31() * b.scalar() + self.e23() * b.e12(),
+                self.e12() * b.e31() - self.e31() * b.e12() + self.e23() * b.scalar(),
+            ),
+            Point::new_components(
+                -self.e01() * b.e123() - self.e02() * b.e3()
+                    + self.e03() * b.e2()
+                    + self.e12() * b.e013()
+                    - self.e31() * b.e021()
+                    - self.e23() * b.e0(),
+                self.e01() * b.e3()
+                    - self.e02() * b.e123()
+                    - self.e03() * b.e1()
+                    - self.e12() * b.e032()
+                    - self.e31() * b.e0()
+                    + self.e23() * b.e021(),
+                -self.e01() * b.e2() + self.e02() * b.e1()
+                    - self.e03() * b.e123()
+                    - self.e12() * b.e0()
+                    + self.e31() * b.e032()
+                    - self.e23() * b.e013(),
+                self.e12() * b.e3() + self.e31() * b.e2() + self.e23() * b.e1(),
+            ),
+            self.e01() * b.e23()
+                + self.e02() * b.e31()
+                + self.e03() * b.e12()
+                + self.e12() * b.e03()
+                + self.e31() * b.e02()
+                + self.e23() * b.e01(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Line<F>, Multivector<F>);
+
+// Multivector-Line
+impl<F: Float> Mul<Line<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12() * b.e12() - self.e31() * b.e31() - self.e23() * b.e23(),
+            Plane::new(
+                -self.e1() * b.e01() - self.e2() * b.e02() - self.e3() * b.e03()
+                    + self.e032() * b.e23()
+                    + self.e013() * b.e31()
+                    + self.e021() * b.e12(),
+                -self.e2() * b.e12() + self.e3() * b.e31() - self.e123() * b.e23(),
+                self.e1() * b.e12() - self.e3() * b.e23() - self.e123() * b.e31(),
+                -self.e1() * b.e31() + self.e2() * b.e23() - self.e123() * b.e12(),
+            ),
+            Line::new(
+                self.scalar() * b.e01() - self.e02() * b.e12()
+                    + self.e03() * b.e31()
+                    + self.e12() * b.e02()
+                    - self.e31() * b.e03()
+                    - self.e0123() * b.e23(),
+                self.scalar() * b.e02() + self.e01() * b.e12()
+                    - self.e03() * b.e23()
+                    - self.e12() * b.e01()
+                    + self.e23() * b.e03()
+                    - self.e0123() * b.e31(),
+                self.scalar() * b.e03() - self.e01() * b.e31()
+                    + self.e02() * b.e23()
+                    + self.e31() * b.e01()
+                    - self.e23() * b.e02()
+                    - self.e0123() * b.e12(),
+                self.scalar() * b.e12() + self.e31() * b.e23() - self.e23() * b.e31(),
+                self.scalar() * b.e31() - self.e12() * b.e23() + self.e23() * b.e12(),
+                self.scalar() * b.e23() + self.e12() * b.e31() - self.e31() * b.e12(),
+            ),
+            Point::new_components(
+                -self.e0() * b.e23() + self.e2() * b.e03()
+                    - self.e3() * b.e02()
+                    - self.e013() * b.e12()
+                    + self.e021() * b.e31()
+                    + self.e123() * b.e01(),
+                -self.e0() * b.e31() - self.e1() * b.e03()
+                    + self.e3() * b.e01()
+                    + self.e032() * b.e12()
+                    - self.e021() * b.e23()
+                    + self.e123() * b.e02(),
+                -self.e0() * b.e12() + self.e1() * b.e02()
+                    - self.e2() * b.e01()
+                    - self.e032() * b.e31()
+                    + self.e013() * b.e23()
+                    + self.e123() * b.e03(),
+                self.e1() * b.e23() + self.e2() * b.e31() + self.e3() * b.e12(),
+            ),
+            self.e01() * b.e23()
+                + self.e02() * b.e31()
+                + self.e03() * b.e12()
+                + self.e12() * b.e03()
+                + self.e31() * b.e02()
+                + self.e23() * b.e01(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Line<F>);
+
+// Point-Point
+impl<F: Float> Mul for Point<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e123() * b.e123(),
+            Plane::zero(),
+            Line::new(
+                self.e032() * b.e123() - self.e123() * b.e032(),
+                self.e013() * b.e123() - self.e123() * b.e013(),
+                self.e021() * b.e123() - self.e123() * b.e021(),
+                F::zero(),
+                F::zero(),
+                F::zero(),
+            ),
+            Point::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Point<F>, Point<F>);
+
+// Point-Pseudoscalar
+impl<F: Float> Mul<Pseudoscalar<F>> for Point<F> {
+    type Output = Plane<F>;
+    fn mul(self, b: Pseudoscalar<F>) -> Plane<F> {
+        Plane::new(self.e123() * b.e0123(), F::zero(), F::zero(), F::zero())
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Point<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Point
+impl<F: Float> Mul<Point<F>> for Pseudoscalar<F> {
+    type Output = Plane<F>;
+    fn mul(self, b: Point<F>) -> Plane<F> {
+        Plane::new(-self.e0123() * b.e123(), F::zero(), F::zero(), F::zero())
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Point<F>);
+
+// Point-Motor
+impl<F: Float> Mul<Motor<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(
+                self.e032() * b.e23()
+                    + self.e013() * b.e31()
+                    + self.e021() * b.e12()
+                    + self.e123() * b.e0123(),
+                -self.e123() * b.e23(),
+                -self.e123() * b.e31(),
+                -self.e123() * b.e12(),
+            ),
+            Line::zero(),
+            Point::new_components(
+                self.e032() * b.scalar() - self.e013() * b.e12()
+                    + self.e021() * b.e31()
+                    + self.e123() * b.e01(),
+                self.e032() * b.e12() + self.e013() * b.scalar() - self.e021() * b.e23()
+                    + self.e123() * b.e02(),
+                -self.e032() * b.e31()
+                    + self.e013() * b.e23()
+                    + self.e021() * b.scalar()
+                    + self.e123() * b.e03(),
+                self.e123() * b.scalar(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Point<F>, Motor<F>);
+
+// Motor-Point
+impl<F: Float> Mul<Point<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(
+                self.e12() * b.e021() + self.e31() * b.e013() + self.e23() * b.e032()
+                    - self.e0123() * b.e123(),
+                -self.e23() * b.e123(),
+                -self.e31() * b.e123(),
+                -self.e12() * b.e123(),
+            ),
+            Line::zero(),
+            Point::new_components(
+                self.scalar() * b.e032() - self.e01() * b.e123() + self.e12() * b.e013()
+                    - self.e31() * b.e021(),
+                self.scalar() * b.e013() - self.e02() * b.e123() - self.e12() * b.e032()
+                    + self.e23() * b.e021(),
+                self.scalar() * b.e021() - self.e03() * b.e123() + self.e31() * b.e032()
+                    - self.e23() * b.e013(),
+                self.scalar() * b.e123(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Motor<F>, Point<F>);
+
+// Point-Multivector
+impl<F: Float> Mul<Multivector<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e123() * b.e123(),
+            Plane::new(
+                self.e032() * b.e23()
+                    + self.e013() * b.e31()
+                    + self.e021() * b.e12()
+                    + self.e123() * b.e0123(),
+                -self.e123() * b.e23(),
+                -self.e123() * b.e31(),
+                -self.e123() * b.e12(),
+            ),
+            Line::new(
+                self.e032() * b.e123() + self.e013() * b.e3()
+                    - self.e021() * b.e2()
+                    - self.e123() * b.e032(),
+                -self.e032() * b.e3() + self.e013() * b.e123() + self.e021() * b.e1()
+                    - self.e123() * b.e013(),
+                self.e032() * b.e2() - self.e013() * b.e1() + self.e021() * b.e123()
+                    - self.e123() * b.e021(),
+                self.e123() * b.e3(),
+                self.e123() * b.e2(),
+                self.e123() * b.e1(),
+            ),
+            Point::new_components(
+                self.e032() * b.scalar() - self.e013() * b.e12()
+                    + self.e021() * b.e31()
+                    + self.e123() * b.e01(),
+                self.e032() * b.e12() + self.e013() * b.scalar() - self.e021() * b.e23()
+                    + self.e123() * b.e02(),
+                -self.e032() * b.e31()
+                    + self.e013() * b.e23()
+                    + self.e021() * b.scalar()
+                    + self.e123() * b.e03(),
+                self.e123() * b.scalar(),
+            ),
+            -self.e032() * b.e1()
+                - self.e013() * b.e2()
+                - self.e021() * b.e3()
+                - self.e123() * b.e0(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Point<F>, Multivector<F>);
+
+// Multivector-Point
+impl<F: Float> Mul<Point<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e123() * b.e123(),
+            Plane::new(
+                self.e12() * b.e021() + self.e31() * b.e013() + self.e23() * b.e032()
+                    - self.e0123() * b.e123(),
+                -self.e23() * b.e123(),
+                -self.e31() * b.e123(),
+                -self.e12() * b.e123(),
+            ),
+            Line::new(
+                -self.e2() * b.e021() + self.e3() * b.e013() + self.e032() * b.e123()
+                    - self.e123() * b.e032(),
+                self.e1() * b.e021() - self.e3() * b.e032() + self.e013() * b.e123()
+                    - self.e123() * b.e013(),
+                -self.e1() * b.e013() + self.e2() * b.e032() + self.e021() * b.e123()
+                    - self.e123() * b.e021(),
+                self.e3() * b.e123(),
+                self.e2() * b.e123(),
+                self.e1() * b.e123(),
+            ),
+            Point::new_components(
+                self.scalar() * b.e032() - self.e01() * b.e123() + self.e12() * b.e013()
+                    - self.e31() * b.e021(),
+                self.scalar() * b.e013() - self.e02() * b.e123() - self.e12() * b.e032()
+                    + self.e23() * b.e021(),
+                self.scalar() * b.e021() - self.e03() * b.e123() + self.e31() * b.e032()
+                    - self.e23() * b.e013(),
+                self.scalar() * b.e123(),
+            ),
+            self.e0() * b.e123()
+                + self.e1() * b.e032()
+                + self.e2() * b.e013()
+                + self.e3() * b.e021(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Point<F>);
+
+// Pseudoscalar-Pseudoscalar
+impl<F: Float> Mul for Pseudoscalar<F> {
+    type Output = Scalar<F>;
+    fn mul(self, _b: Pseudoscalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Motor
+impl<F: Float> Mul<Motor<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::zero(),
+            Line::new(
+                -self.e0123() * b.e23(),
+                -self.e0123() * b.e31(),
+                -self.e0123() * b.e12(),
+                F::zero(),
+                F::zero(),
+                F::zero(),
+            ),
+            Point::zero(),
+            self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Motor<F>);
+
+// Motor-Pseudoscalar
+impl<F: Float> Mul<Pseudoscalar<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::zero(),
+            Line::new(
+                -self.e23() * b.e0123(),
+                -self.e31() * b.e0123(),
+                -self.e12() * b.e0123(),
+                F::zero(),
+                F::zero(),
+                F::zero(),
+            ),
+            Point::zero(),
+            self.scalar() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Motor<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Multivector
+impl<F: Float> Mul<Multivector<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(-self.e0123() * b.e123(), F::zero(), F::zero(), F::zero()),
+            Line::new(
+                -self.e0123() * b.e23(),
+                -self.e0123() * b.e31(),
+                -self.e0123() * b.e12(),
+                F::zero(),
+                F::zero(),
+                F::zero(),
+            ),
+            Point::new_components(
+                -self.e0123() * b.e1(),
+                -self.e0123() * b.e2(),
+                -self.e0123() * b.e3(),
+                F::zero(),
+            ),
+            self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Multivector<F>);
+
+// Multivector-Pseudoscalar
+impl<F: Float> Mul<Pseudoscalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(self.e123() * b.e0123(), F::zero(), F::zero(), F::zero()),
+            Line::new(
+                -self.e23() * b.e0123(),
+                -self.e31() * b.e0123(),
+                -self.e12() * b.e0123(),
+                F::zero(),
+                F::zero(),
+                F::zero(),
+            ),
+            Point::new_components(
+                self.e1() * b.e0123(),
+                self.e2() * b.e0123(),
+                self.e3() * b.e0123(),
+                F::zero(),
+            ),
+            self.scalar() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Pseudoscalar<F>);
+
+// Motor-Multivector
+impl<F: Float> Mul<Multivector<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar()
+                - self.e12() * b.e12()
+                - self.e31() * b.e31()
+                - self.e23() * b.e23(),
+            Plane::new(
+                self.scalar() * b.e0()
+                    + self.e01() * b.e1()
+                    + self.e02() * b.e2()
+                    + self.e03() * b.e3()
+                    + self.e12() * b.e021()
+                    + self.e31() * b.e013()
+                    + self.e23() * b.e032()
+                    - self.e0123() * b.e123(),
+                self.scalar() * b.e1() + self.e12() * b.e2()
+                    - self.e31() * b.e3()
+                    - self.e23() * b.e123(),
+                self.scalar() * b.e2() - self.e12() * b.e1() - self.e31() * b.e123()
+                    + self.e23() * b.e3(),
+                self.scalar() * b.e3() - self.e12() * b.e123() + self.e31() * b.e1()
+                    - self.e23() * b.e2(),
+            ),
+            Line::new(
+                self.scalar() * b.e01() + self.e01() * b.scalar() - self.e02() * b.e12()
+                    + self.e03() * b.e31()
+                    + self.e12() * b.e02()
+                    - self.e31() * b.e03()
+                    - self.e23() * b.e0123()
+                    - self.e0123() * b.e23(),
+                self.scalar() * b.e02() + self.e01() * b.e12() + self.e02() * b.scalar()
+                    - self.e03() * b.e23()
+                    - self.e12() * b.e01()
+                    - self.e31() * b.e0123()
+                    + self.e23() * b.e03()
+                    - self.e0123() * b.e31(),
+                self.scalar() * b.e03() - self.e01() * b.e31()
+                    + self.e02() * b.e23()
+                    + self.e03() * b.scalar()
+                    - self.e12() * b.e0123()
+                    + self.e31() * b.e01()
+                    - self.e23() * b.e02()
+                    - self.e0123() * b.e12(),
+                self.scalar() * b.e12() + self.e12() * b.scalar() + self.e31() * b.e23()
+                    - self.e23() * b.e31(),
+                self.scalar() * b.e31() - self.e12() * b.e23()
+                    + self.e31() * b.scalar()
+                    + self.e23() * b.e12(),
+                self.scalar() * b.e23() + self.e12() * b.e31() - self.e31() * b.e12()
+                    + self.e23() * b.scalar(),
+            ),
+            Point::new_components(
+                self.scalar() * b.e032() - self.e01() * b.e123() - self.e02() * b.e3()
+                    + self.e03() * b.e2()
+                    + self.e12() * b.e013()
+                    - self.e31() * b.e021()
+                    - self.e23() * b.e0()
+                    - self.e0123() * b.e1(),
+                self.scalar() * b.e013() + self.e01() * b.e3()
+                    - self.e02() * b.e123()
+                    - self.e03() * b.e1()
+                    - self.e12() * b.e032()
+                    - self.e31() * b.e0()
+                    + self.e23() * b.e021()
+                    - self.e0123() * b.e2(),
+                self.scalar() * b.e021() - self.e01() * b.e2() + self.e02() * b.e1()
+                    - self.e03() * b.e123()
+                    - self.e12() * b.e0()
+                    + self.e31() * b.e032()
+                    - self.e23() * b.e013()
+                    - self.e0123() * b.e3(),
+                self.scalar() * b.e123()
+                    + self.e12() * b.e3()
+                    + self.e31() * b.e2()
+                    + self.e23() * b.e1(),
+            ),
+            self.scalar() * b.e0123()
+                + self.e01() * b.e23()
+                + self.e02() * b.e31()
+                + self.e03() * b.e12()
+                + self.e12() * b.e03()
+                + self.e31() * b.e02()
+                + self.e23() * b.e01()
+                + self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Motor<F>, Multivector<F>);
+
+// Multivector-Motor
+impl<F: Float> Mul<Motor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar()
+                - self.e12() * b.e12()
+                - self.e31() * b.e31()
+                - self.e23() * b.e23(),
+            Plane::new(
+                self.e0() * b.scalar()
+                    - self.e1() * b.e01()
+                    - self.e2() * b.e02()
+                    - self.e3() * b.e03()
+                    + self.e032() * b.e23()
+                    + self.e013() * b.e31()
+                    + self.e021() * b.e12()
+                    + self.e123() * b.e0123(),
+                self.e1() * b.scalar() - self.e2() * b.e12() + self.e3() * b.e31()
+                    - self.e123() * b.e23(),
+                self.e1() * b.e12() + self.e2() * b.scalar()
+                    - self.e3() * b.e23()
+                    - self.e123() * b.e31(),
+                -self.e1() * b.e31() + self.e2() * b.e23() + self.e3() * b.scalar()
+                    - self.e123() * b.e12(),
+            ),
+            Line::new(
+                self.scalar() * b.e01() + self.e01() * b.scalar() - self.e02() * b.e12()
+                    + self.e03() * b.e31()
+                    + self.e12() * b.e02()
+                    - self.e31() * b.e03()
+                    - self.e23() * b.e0123()
+                    - self.e0123() * b.e23(),
+                self.scalar() * b.e02() + self.e01() * b.e12() + self.e02() * b.scalar()
+                    - self.e03() * b.e23()
+                    - self.e12() * b.e01()
+                    - self.e31() * b.e0123()
+                    + self.e23() * b.e03()
+                    - self.e0123() * b.e31(),
+                self.scalar() * b.e03() - self.e01() * b.e31()
+                    + self.e02() * b.e23()
+                    + self.e03() * b.scalar()
+                    - self.e12() * b.e0123()
+                    + self.e31() * b.e01()
+                    - self.e23() * b.e02()
+                    - self.e0123() * b.e12(),
+                self.scalar() * b.e12() + self.e12() * b.scalar() + self.e31() * b.e23()
+                    - self.e23() * b.e31(),
+                self.scalar() * b.e31() - self.e12() * b.e23()
+                    + self.e31() * b.scalar()
+                    + self.e23() * b.e12(),
+                self.scalar() * b.e23() + self.e12() * b.e31() - self.e31() * b.e12()
+                    + self.e23() * b.scalar(),
+            ),
+            Point::new_components(
+                -self.e0() * b.e23() + self.e1() * b.e0123() + self.e2() * b.e03()
+                    - self.e3() * b.e02()
+                    + self.e032() * b.scalar()
+                    - self.e013() * b.e12()
+                    + self.e021() * b.e31()
+                    + self.e123() * b.e01(),
+                -self.e0() * b.e31() - self.e1() * b.e03()
+                    + self.e2() * b.e0123()
+                    + self.e3() * b.e01()
+                    + self.e032() * b.e12()
+                    + self.e013() * b.scalar()
+                    - self.e021() * b.e23()
+                    + self.e123() * b.e02(),
+                -self.e0() * b.e12() + self.e1() * b.e02() - self.e2() * b.e01()
+                    + self.e3() * b.e0123()
+                    - self.e032() * b.e31()
+                    + self.e013() * b.e23()
+                    + self.e021() * b.scalar()
+                    + self.e123() * b.e03(),
+                self.e1() * b.e23()
+                    + self.e2() * b.e31()
+                    + self.e3() * b.e12()
+                    + self.e123() * b.scalar(),
+            ),
+            self.scalar() * b.e0123()
+                + self.e01() * b.e23()
+                + self.e02() * b.e31()
+                + self.e03() * b.e12()
+                + self.e12() * b.e03()
+                + self.e31() * b.e02()
+                + self.e23() * b.e01()
+                + self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Motor<F>);
+
+// Multivector-Multivector
+impl<F: Float> Mul for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar()
+                + self.e1() * b.e1()
+                + self.e2() * b.e2()
+                + self.e3() * b.e3()
+                - self.e12() * b.e12()
+                - self.e31() * b.e31()
+                - self.e23() * b.e23()
+                - self.e123() * b.e123(),
+            Plane::new(
+                self.scalar() * b.e0() + self.e0() * b.scalar()
+                    - self.e1() * b.e01()
+                    - self.e2() * b.e02()
+                    - self.e3() * b.e03()
+                    + self.e01() * b.e1()
+                    + self.e02() * b.e2()
+                    + self.e03() * b.e3()
+                    + self.e12() * b.e021()
+                    + self.e31() * b.e013()
+                    + self.e23() * b.e032()
+                    + self.e032() * b.e23()
+                    + self.e013() * b.e31()
+                    + self.e021() * b.e12()
+                    + self.e123() * b.e0123()
+                    - self.e0123() * b.e123(),
+                self.scalar() * b.e1() + self.e1() * b.scalar() - self.e2() * b.e12()
+                    + self.e3() * b.e31()
+                    + self.e12() * b.e2()
+                    - self.e31() * b.e3()
+                    - self.e23() * b.e123()
+                    - self.e123() * b.e23(),
+                self.scalar() * b.e2() + self.e1() * b.e12() + self.e2() * b.scalar()
+                    - self.e3() * b.e23()
+                    - self.e12() * b.e1()
+                    - self.e31() * b.e123()
+                    + self.e23() * b.e3()
+                    - self.e123() * b.e31(),
+                self.scalar() * b.e3() - self.e1() * b.e31()
+                    + self.e2() * b.e23()
+                    + self.e3() * b.scalar()
+                    - self.e12() * b.e123()
+                    + self.e31() * b.e1()
+                    - self.e23() * b.e2()
+                    - self.e123() * b.e12(),
+            ),
+            Line::new(
+                self.scalar() * b.e01() + self.e0() * b.e1()
+                    - self.e1() * b.e0()
+                    - self.e2() * b.e021()
+                    + self.e3() * b.e013()
+                    + self.e01() * b.scalar()
+                    - self.e02() * b.e12()
+                    + self.e03() * b.e31()
+                    + self.e12() * b.e02()
+                    - self.e31() * b.e03()
+                    - self.e23() * b.e0123()
+                    + self.e032() * b.e123()
+                    + self.e013() * b.e3()
+                    - self.e021() * b.e2()
+                    - self.e123() * b.e032()
+                    - self.e0123() * b.e23(),
+                self.scalar() * b.e02() + self.e0() * b.e2() + self.e1() * b.e021()
+                    - self.e2() * b.e0()
+                    - self.e3() * b.e032()
+                    + self.e01() * b.e12()
+                    + self.e02() * b.scalar()
+                    - self.e03() * b.e23()
+                    - self.e12() * b.e01()
+                    - self.e31() * b.e0123()
+                    + self.e23() * b.e03()
+                    - self.e032() * b.e3()
+                    + self.e013() * b.e123()
+                    + self.e021() * b.e1()
+                    - self.e123() * b.e013()
+                    - self.e0123() * b.e31(),
+                self.scalar() * b.e03() + self.e0() * b.e3() - self.e1() * b.e013()
+                    + self.e2() * b.e032()
+                    - self.e3() * b.e0()
+                    - self.e01() * b.e31()
+                    + self.e02() * b.e23()
+                    + self.e03() * b.scalar()
+                    - self.e12() * b.e0123()
+                    + self.e31() * b.e01()
+                    - self.e23() * b.e02()
+                    + self.e032() * b.e2()
+                    - self.e013() * b.e1()
+                    + self.e021() * b.e123()
+                    - self.e123() * b.e021()
+                    - self.e0123() * b.e12(),
+                self.scalar() * b.e12() + self.e1() * b.e2() - self.e2() * b.e1()
+                    + self.e3() * b.e123()
+                    + self.e12() * b.scalar()
+                    + self.e31() * b.e23()
+                    - self.e23() * b.e31()
+                    + self.e123() * b.e3(),
+                self.scalar() * b.e31() - self.e1() * b.e3()
+                    + self.e2() * b.e123()
+                    + self.e3() * b.e1()
+                    - self.e12() * b.e23()
+                    + self.e31() * b.scalar()
+                    + self.e23() * b.e12()
+                    + self.e123() * b.e2(),
+                self.scalar() * b.e23() + self.e1() * b.e123() + self.e2() * b.e3()
+                    - self.e3() * b.e2()
+                    + self.e12() * b.e31()
+                    - self.e31() * b.e12()
+                    + self.e23() * b.scalar()
+                    + self.e123() * b.e1(),
+            ),
+            Point::new_components(
+                self.scalar() * b.e032() - self.e0() * b.e23()
+                    + self.e1() * b.e0123()
+                    + self.e2() * b.e03()
+                    - self.e3() * b.e02()
+                    - self.e01() * b.e123()
+                    - self.e02() * b.e3()
+                    + self.e03() * b.e2()
+                    + self.e12() * b.e013()
+                    - self.e31() * b.e021()
+                    - self.e23() * b.e0()
+                    + self.e032() * b.scalar()
+                    - self.e013() * b.e12()
+                    + self.e021() * b.e31()
+                    + self.e123() * b.e01()
+                    - self.e0123() * b.e1(),
+                self.scalar() * b.e013() - self.e0() * b.e31() - self.e1() * b.e03()
+                    + self.e2() * b.e0123()
+                    + self.e3() * b.e01()
+                    + self.e01() * b.e3()
+                    - self.e02() * b.e123()
+                    - self.e03() * b.e1()
+                    - self.e12() * b.e032()
+                    - self.e31() * b.e0()
+                    + self.e23() * b.e021()
+                    + self.e032() * b.e12()
+                    + self.e013() * b.scalar()
+                    - self.e021() * b.e23()
+                    + self.e123() * b.e02()
+                    - self.e0123() * b.e2(),
+                self.scalar() * b.e021() - self.e0() * b.e12() + self.e1() * b.e02()
+                    - self.e2() * b.e01()
+                    + self.e3() * b.e0123()
+                    - self.e01() * b.e2()
+                    + self.e02() * b.e1()
+                    - self.e03() * b.e123()
+                    - self.e12() * b.e0()
+                    + self.e31() * b.e032()
+                    - self.e23() * b.e013()
+                    - self.e032() * b.e31()
+                    + self.e013() * b.e23()
+                    + self.e021() * b.scalar()
+                    + self.e123() * b.e03()
+                    - self.e0123() * b.e3(),
+                self.scalar() * b.e123()
+                    + self.e1() * b.e23()
+                    + self.e2() * b.e31()
+                    + self.e3() * b.e12()
+                    + self.e12() * b.e3()
+                    + self.e31() * b.e2()
+                    + self.e23() * b.e1()
+                    + self.e123() * b.scalar(),
+            ),
+            self.scalar() * b.e0123()
+                + self.e0() * b.e123()
+                + self.e1() * b.e032()
+                + self.e2() * b.e013()
+                + self.e3() * b.e021()
+                + self.e01() * b.e23()
+                + self.e02() * b.e31()
+                + self.e03() * b.e12()
+                + self.e12() * b.e03()
+                + self.e31() * b.e02()
+                + self.e23() * b.e01()
+                - self.e032() * b.e1()
+                - self.e013() * b.e2()
+                - self.e021() * b.e3()
+                - self.e123() * b.e0()
+                + self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Multivector<F>);
+
+// Test
+#[cfg(test)]
+mod geometric_product {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn scalar_plane() {
+        // -1
+        let scalar1 = Scalar::new(-1.0);
+        // -1e0-4e1-4e2-2e3
+        let plane2 = Plane::new(-1.0, -4.0, -4.0, -2.0);
+        let res = scalar1 * plane2;
+        // 1e0+4e1+4e2+2e3
+        assert_relative_eq!(res.e0(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 2.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn plane_plane() {
+        // -1e0+5e1-2e2+3e3
+        let plane1 = Plane::new(-1.0, 5.0, -2.0, 3.0);
+        // -3e0+2e1-1e2+4e3
+        let plane2 = Plane::new(-3.0, 2.0, -1.0, 4.0);
+        let res = plane1 * plane2;
+        // 24+13e01-5e02+5e03-1e12-14e31-5e23
+        assert_relative_eq!(res.scalar(), 24.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), 13.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e02(), -5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e03(), 5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), -14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), -5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e032(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e013(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e021(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn plane_line() {
+        // -1e0-1e1-2e2+5e3
+        let plane1 = Plane::new(-1.0, -1.0, -2.0, 5.0);
+        // 3e01+3e02+2e03-4e12+1e31-2e23
+        let line2 = Line::new(3.0, 3.0, 2.0, -4.0, 1.0, -2.0);
+        let res = plane1 * line2;
+        // -1e0-3e1+14e2+5e3-21e032+18e013-1e021-20e123
+        assert_relative_eq!(res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e02(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e03(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e032(), -21.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e013(), 18.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e021(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), -20.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn line_line() {
+        // 3e01+5e02+2e03-4e12+4e31+4e23
+        let line1 = Line::new(3.0, 5.0, 2.0, -4.0, 4.0, 4.0);
+        // -3e01-2e02+4e03+4e12-3e31-4e23
+        let line2 = Line::new(-3.0, -2.0, 4.0, 4.0, -3.0, -4.0);
+        let res = line1 * line2;
+        // 44-34e01+24e02-15e03-4e12-4e23-55e0123
+        assert_relative_eq!(res.scalar(), 44.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), -34.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e02(), 24.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e03(), -15.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e032(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e013(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e021(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), -55.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn point_point() {
+        // -3e032-2e013+1e021+5e123
+        let point1 = Point::new_components(-3.0, -2.0, 1.0, 5.0);
+        // -2e032-1e013-1e021-4e123
+        let point2 = Point::new_components(-2.0, -1.0, -1.0, -4.0);
+        let res = point1 * point2;
+        // 20+22e01+13e02+1e03
+        assert_relative_eq!(res.scalar(), 20.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), 22.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e02(), 13.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e03(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e032(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e013(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e021(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn line_point() {
+        // -3e01+3e02+2e03-2e12-2e31+2e23
+        let line1 = Line::new(-3.0, 3.0, 2.0, -2.0, -2.0, 2.0);
+        // -2e032+5e013-4e021+3e123
+        let point2 = Point::new_components(-2.0, 5.0, -4.0, 3.0);
+        let res = line1 * point2;
+        // -6e0-6e1+6e2+6e3-9e032-21e013-12e021
+        assert_relative_eq!(res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), -6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e02(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e03(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e032(), -9.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e013(), -21.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e021(), -12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn motor_point() {
+        // 5+4e01+1e02-3e03+3e12+5e31+4e23+5e0123
+        let motor1 = Motor::new(5.0, Line::new(4.0, 1.0, -3.0, 3.0, 5.0, 4.0), 5.0);
+        // -1e032+2e013+3e021+5e123
+        let point2 = Point::new_components(-1.0, 2.0, 3.0, 5.0);
+        let res = motor1 * point2;
+        // -10e0-20e1-25e2-15e3-34e032+20e013+17e021+25e123
+        assert_relative_eq!(res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), -10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -20.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -25.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), -15.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e02(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e03(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e032(), -34.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e013(), 20.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e021(), 17.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 25.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_motor() {
+        // 5+2e0+3e1+5e2-2e3+4e01-2e02-4e03+1e12-4e31+1e23+4e032-4e013-3e021+4e123+3e0123
+        let mvec1 = Multivector::new(
+            5.0,
+            Plane::new(2.0, 3.0, 5.0, -2.0),
+            Line::new(4.0, -2.0, -4.0, 1.0, -4.0, 1.0),
+            Point::new_components(4.0, -4.0, -3.0, 4.0),
+            3.0,
+        );
+        // 1+2e01+3e02-4e03-2e12-3e31+2e23+5e0123
+        let motor2 = Motor::new(1.0, Line::new(2.0, 3.0, -4.0, -2.0, -3.0, 2.0), 5.0);
+        let res = mvec1 * motor2;
+        // -7+19e0+11e1+15e2+25e3-2e01+36e02-26e03-14e12-23e31+10e032+45e013-22e021-1e123+36e0123
+        assert_relative_eq!(res.scalar(), -7.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 19.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 11.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 15.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 25.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e02(), 36.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e03(), -26.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), -14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), -23.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e032(), 10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e013(), 45.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e021(), -22.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), 36.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_multivector() {
+        // -3+1e0+4e1+4e2+2e3-2e01-4e02+3e03+5e12-2e31+3e23-2e032+4e013+2e021+3e123+1e0123
+        let mvec1 = Multivector::new(
+            -3.0,
+            Plane::new(1.0, 4.0, 4.0, 2.0),
+            Line::new(-2.0, -4.0, 3.0, 5.0, -2.0, 3.0),
+            Point::new_components(-2.0, 4.0, 2.0, 3.0),
+            1.0,
+        );
+        // -1+3e0-1e1-1e2-2e3+3e01+1e02+1e03+4e12+1e31-2e23-3e032-4e013-4e021-4e123+1e0123
+        let mvec2 = Multivector::new(
+            -1.0,
+            Plane::new(3.0, -1.0, -1.0, -2.0),
+            Line::new(3.0, 1.0, 1.0, 4.0, 1.0, -2.0),
+            Point::new_components(-3.0, -4.0, -4.0, -4.0),
+            1.0,
+        );
+        let res = mvec1 * mvec2;
+        // -9-26e0-6e1+7e2+5e3+24e01-45e02-8e03-30e12+8e31-9e23-41e032+13e013+16e021+2e123-23e0123
+        assert_relative_eq!(res.scalar(), -9.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), -26.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 7.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), 24.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e02(), -45.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e03(), -8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), -30.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), -9.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e032(), -41.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e013(), 13.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e021(), 16.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), -23.0, max_relative = 0.000001);
+    }
+}
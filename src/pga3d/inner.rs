@@ -0,0 +1,1277 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    line::Line, motor::Motor, multivector::Multivector, plane::Plane, point::Point,
+    pseudoscalar::Pseudoscalar, scalar::Scalar,
+};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::BitOr;
+
+// Inner Product
+
+// Scalar-Scalar
+impl<F: Float> BitOr for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitor(self, b: Scalar<F>) -> Scalar<F> {
+        Scalar(self.0 * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Scalar<F>);
+
+// Scalar-Plane
+impl<F: Float> BitOr<Plane<F>> for Scalar<F> {
+    type Output = Plane<F>;
+    fn bitor(self, b: Plane<F>) -> Plane<F> {
+        Plane::new(
+            self.0 * b.e0(),
+            self.0 * b.e1(),
+            self.0 * b.e2(),
+            self.0 * b.e3(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Plane<F>);
+
+// Plane-Scalar
+impl<F: Float> BitOr<Scalar<F>> for Plane<F> {
+    type Output = Plane<F>;
+    fn bitor(self, b: Scalar<F>) -> Plane<F> {
+        Plane::new(
+            self.e0() * b.0,
+            self.e1() * b.0,
+            self.e2() * b.0,
+            self.e3() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Plane<F>, Scalar<F>);
+
+// Scalar-Line
+impl<F: Float> BitOr<Line<F>> for Scalar<F> {
+    type Output = Line<F>;
+    fn bitor(self, b: Line<F>) -> Line<F> {
+        Line::new(
+            self.0 * b.e01(),
+            self.0 * b.e02(),
+            self.0 * b.e03(),
+            self.0 * b.e12(),
+            self.0 * b.e31(),
+            self.0 * b.e23(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Line<F>);
+
+// Line-Scalar
+impl<F: Float> BitOr<Scalar<F>> for Line<F> {
+    type Output = Line<F>;
+    fn bitor(self, b: Scalar<F>) -> Line<F> {
+        Line::new(
+            self.e01() * b.0,
+            self.e02() * b.0,
+            self.e03() * b.0,
+            self.e12() * b.0,
+            self.e31() * b.0,
+            self.e23() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Line<F>, Scalar<F>);
+
+// Scalar-Point
+impl<F: Float> BitOr<Point<F>> for Scalar<F> {
+    type Output = Point<F>;
+    fn bitor(self, b: Point<F>) -> Point<F> {
+        Point::new_components(
+            self.0 * b.e032(),
+            self.0 * b.e013(),
+            self.0 * b.e021(),
+            self.0 * b.e123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Point<F>);
+
+// Point-Scalar
+impl<F: Float> BitOr<Scalar<F>> for Point<F> {
+    type Output = Point<F>;
+    fn bitor(self, b: Scalar<F>) -> Point<F> {
+        Point::new_components(
+            self.e032() * b.0,
+            self.e013() * b.0,
+            self.e021() * b.0,
+            self.e123() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Point<F>, Scalar<F>);
+
+// Scalar-Pseudoscalar
+impl<F: Float> BitOr<Pseudoscalar<F>> for Scalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitor(self, b: Pseudoscalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.0 * b.e0123())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Scalar
+impl<F: Float> BitOr<Scalar<F>> for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitor(self, b: Scalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.e0123() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Scalar<F>);
+
+// Scalar-Motor
+impl<F: Float> BitOr<Motor<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 * b.scalar(),
+            Plane::zero(),
+            Line::new(
+                self.0 * b.e01(),
+                self.0 * b.e02(),
+                self.0 * b.e03(),
+                self.0 * b.e12(),
+                self.0 * b.e31(),
+                self.0 * b.e23(),
+            ),
+            Point::zero(),
+            self.0 * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Motor<F>);
+
+// Motor-Scalar
+impl<F: Float> BitOr<Scalar<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.0,
+            Plane::zero(),
+            Line::new(
+                self.e01() * b.0,
+                self.e02() * b.0,
+                self.e03() * b.0,
+                self.e12() * b.0,
+                self.e31() * b.0,
+                self.e23() * b.0,
+            ),
+            Point::zero(),
+            self.e0123() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Motor<F>, Scalar<F>);
+
+// Scalar-Multivector
+impl<F: Float> BitOr<Multivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 * b.scalar(),
+            Plane::new(
+                self.0 * b.e0(),
+                self.0 * b.e1(),
+                self.0 * b.e2(),
+                self.0 * b.e3(),
+            ),
+            Line::new(
+                self.0 * b.e01(),
+                self.0 * b.e02(),
+                self.0 * b.e03(),
+                self.0 * b.e12(),
+                self.0 * b.e31(),
+                self.0 * b.e23(),
+            ),
+            Point::new_components(
+                self.0 * b.e032(),
+                self.0 * b.e013(),
+                self.0 * b.e021(),
+                self.0 * b.e123(),
+            ),
+            self.0 * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Multivector<F>);
+
+// Multivector-Scalar
+impl<F: Float> BitOr<Scalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.0,
+            Plane::new(
+                self.e0() * b.0,
+                self.e1() * b.0,
+                self.e2() * b.0,
+                self.e3() * b.0,
+            ),
+            Line::new(
+                self.e01() * b.0,
+                self.e02() * b.0,
+                self.e03() * b.0,
+                self.e12() * b.0,
+                self.e31() * b.0,
+                self.e23() * b.0,
+            ),
+            Point::new_components(
+                self.e032() * b.0,
+                self.e013() * b.0,
+                self.e021() * b.0,
+                self.e123() * b.0,
+            ),
+            self.e0123() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Scalar<F>);
+
+// Plane-Plane
+impl<F: Float> BitOr for Plane<F> {
+    type Output = Scalar<F>;
+    fn bitor(self, b: Plane<F>) -> Scalar<F> {
+        Scalar(self.e1() * b.e1() + self.e2() * b.e2() + self.e3() * b.e3())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Plane<F>, Plane<F>);
+
+// Plane-Line
+impl<F: Float> BitOr<Line<F>> for Plane<F> {
+    type Output = Plane<F>;
+    fn bitor(self, b: Line<F>) -> Plane<F> {
+        Plane::new(
+            -self.e1() * b.e01() - self.e2() * b.e02() - self.e3() * b.e03(),
+            -self.e2() * b.e12() + self.e3() * b.e31(),
+            self.e1() * b.e12() - self.e3() * b.e23(),
+            -self.e1() * b.e31() + self.e2() * b.e23(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Plane<F>, Line<F>);
+
+// Line-Plane
+impl<F: Float> BitOr<Plane<F>> for Line<F> {
+    type Output = Plane<F>;
+    fn bitor(self, b: Plane<F>) -> Plane<F> {
+        Plane::new(
+            self.e01() * b.e1() + self.e02() * b.e2() + self.e03() * b.e3(),
+            self.e12() * b.e2() - self.e31() * b.e3(),
+            -self.e12() * b.e1() + self.e23() * b.e3(),
+            self.e31() * b.e1() - self.e23() * b.e2(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Line<F>, Plane<F>);
+
+// Plane-Point
+impl<F: Float> BitOr<Point<F>> for Plane<F> {
+    type Output = Line<F>;
+    fn bitor(self, b: Point<F>) -> Line<F> {
+        Line::new(
+            -self.e2() * b.e021() + self.e3() * b.e013(),
+            self.e1() * b.e021() - self.e3() * b.e032(),
+            -self.e1() * b.e013() + self.e2() * b.e032(),
+            self.e3() * b.e123(),
+            self.e2() * b.e123(),
+            self.e1() * b.e123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Plane<F>, Point<F>);
+
+// Point-Plane
+impl<F: Float> BitOr<Plane<F>> for Point<F> {
+    type Output = Line<F>;
+    fn bitor(self, b: Plane<F>) -> Line<F> {
+        Line::new(
+            self.e013() * b.e3() - self.e021() * b.e2(),
+            -self.e032() * b.e3() + self.e021() * b.e1(),
+            self.e032() * b.e2() - self.e013() * b.e1(),
+            self.e123() * b.e3(),
+            self.e123() * b.e2(),
+            self.e123() * b.e1(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Point<F>, Plane<F>);
+
+// Plane-Pseudoscalar
+impl<F: Float> BitOr<Pseudoscalar<F>> for Plane<F> {
+    type Output = Point<F>;
+    fn bitor(self, b: Pseudoscalar<F>) -> Point<F> {
+        Point::new_components(
+            self.e1() * b.e0123(),
+            self.e2() * b.e0123(),
+            self.e3() * b.e0123(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Plane<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Plane
+impl<F: Float> BitOr<Plane<F>> for Pseudoscalar<F> {
+    type Output = Point<F>;
+    fn bitor(self, b: Plane<F>) -> Point<F> {
+        Point::new_components(
+            -self.e0123() * b.e1(),
+            -self.e0123() * b.e2(),
+            -self.e0123() * b.e3(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Plane<F>);
+
+// Plane-Motor
+impl<F: Float> BitOr<Motor<F>> for Plane<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(
+                self.e0() * b.scalar()
+                    - self.e1() * b.e01()
+                    - self.e2() * b.e02()
+                    - self.e3() * b.e03(),
+                self.e1() * b.scalar() - self.e2() * b.e12() + self.e3() * b.e31(),
+                self.e1() * b.e12() + self.e2() * b.scalar() - self.e3() * b.e23(),
+                -self.e1() * b.e31() + self.e2() * b.e23() + self.e3() * b.scalar(),
+            ),
+            Line::zero(),
+            Point::new_components(
+                self.e1() * b.e0123(),
+                self.e2() * b.e0123(),
+                self.e3() * b.e0123(),
+                F::zero(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Plane<F>, Motor<F>);
+
+// Motor-Plane
+impl<F: Float> BitOr<Plane<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Plane<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(
+                self.scalar() * b.e0()
+                    + self.e01() * b.e1()
+                    + self.e02() * b.e2()
+                    + self.e03() * b.e3(),
+                self.scalar() * b.e1() + self.e12() * b.e2() - self.e31() * b.e3(),
+                self.scalar() * b.e2() - self.e12() * b.e1() + self.e23() * b.e3(),
+                self.scalar() * b.e3() + self.e31() * b.e1() - self.e23() * b.e2(),
+            ),
+            Line::zero(),
+            Point::new_components(
+                -self.e0123() * b.e1(),
+                -self.e0123() * b.e2(),
+                -self.e0123() * b.e3(),
+                F::zero(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Motor<F>, Plane<F>);
+
+// Plane-Multivector
+impl<F: Float> BitOr<Multivector<F>> for Plane<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1() * b.e1() + self.e2() * b.e2() + self.e3() * b.e3(),
+            Plane::new(
+                self.e0() * b.scalar()
+                    - self.e1() * b.e01()
+                    - self.e2() * b.e02()
+                    - self.e3() * b.e03(),
+                self.e1() * b.scalar() - self.e2() * b.e12() + self.e3() * b.e31(),
+                self.e1() * b.e12() + self.e2() * b.scalar() - self.e3() * b.e23(),
+                -self.e1() * b.e31() + self.e2() * b.e23() + self.e3() * b.scalar(),
+            ),
+            Line::new(
+                -self.e2() * b.e021() + self.e3() * b.e013(),
+                self.e1() * b.e021() - self.e3() * b.e032(),
+                -self.e1() * b.e013() + self.e2() * b.e032(),
+                self.e3() * b.e123(),
+                self.e2() * b.e123(),
+                self.e1() * b.e123(),
+            ),
+            Point::new_components(
+                self.e1() * b.e0123(),
+                self.e2() * b.e0123(),
+                self.e3() * b.e0123(),
+                F::zero(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Plane<F>, Multivector<F>);
+
+// Multivector-Plane
+impl<F: Float> BitOr<Plane<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Plane<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1() * b.e1() + self.e2() * b.e2() + self.e3() * b.e3(),
+            Plane::new(
+                self.scalar() * b.e0()
+                    + self.e01() * b.e1()
+                    + self.e02() * b.e2()
+                    + self.e03() * b.e3(),
+                self.scalar() * b.e1() + self.e12() * b.e2() - self.e31() * b.e3(),
+                self.scalar() * b.e2() - self.e12() * b.e1() + self.e23() * b.e3(),
+                self.scalar() * b.e3() + self.e31() * b.e1() - self.e23() * b.e2(),
+            ),
+            Line::new(
+                self.e013() * b.e3() - self.e021() * b.e2(),
+                -self.e032() * b.e3() + self.e021() * b.e1(),
+                self.e032() * b.e2() - self.e013() * b.e1(),
+                self.e123() * b.e3(),
+                self.e123() * b.e2(),
+                self.e123() * b.e1(),
+            ),
+            Point::new_components(
+                -self.e0123() * b.e1(),
+                -self.e0123() * b.e2(),
+                -self.e0123() * b.e3(),
+                F::zero(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Plane<F>);
+
+// Line-Line
+impl<F: Float> BitOr for Line<F> {
+    type Output = Scalar<F>;
+    fn bitor(self, b: Line<F>) -> Scalar<F> {
+        Scalar(-self.e12() * b.e12() - self.e31() * b.e31() - self.e23() * b.e23())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Line<F>, Line<F>);
+
+// Line-Point
+impl<F: Float> BitOr<Point<F>> for Line<F> {
+    type Output = Plane<F>;
+    fn bitor(self, b: Point<F>) -> Plane<F> {
+        Plane::new(
+            self.e12() * b.e021() + self.e31() * b.e013() + self.e23() * b.e032(),
+            -self.e23() * b.e123(),
+            -self.e31() * b.e123(),
+            -self.e12() * b.e123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Line<F>, Point<F>);
+
+// Point-Line
+impl<F: Float> BitOr<Line<F>> for Point<F> {
+    type Output = Plane<F>;
+    fn bitor(self, b: Line<F>) -> Plane<F> {
+        Plane::new(
+            self.e032() * b.e23() + self.e013() * b.e31() + self.e021() * b.e12(),
+            -self.e123() * b.e23(),
+            -self.e123() * b.e31(),
+            -self.e123() * b.e12(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Point<F>, Line<F>);
+
+// Line-Pseudoscalar
+impl<F: Float> BitOr<Pseudoscalar<F>> for Line<F> {
+    type Output = Line<F>;
+    fn bitor(self, b: Pseudoscalar<F>) -> Line<F> {
+        Line::new(
+            -self.e23() * b.e0123(),
+            -self.e31() * b.e0123(),
+            -self.e12() * b.e0123(),
+            F::zero(),
+            F::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Line<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Line
+impl<F: Float> BitOr<Line<F>> for Pseudoscalar<F> {
+    type Output = Line<F>;
+    fn bitor(self, b: Line<F>) -> Line<F> {
+        Line::new(
+            -self.e0123() * b.e23(),
+            -self.e0123() * b.e31(),
+            -self.e0123() * b.e12(),
+            F::zero(),
+            F::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Line<F>);
+
+// Line-Motor
+impl<F: Float> BitOr<Motor<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12() * b.e12() - self.e31() * b.e31() - self.e23() * b.e23(),
+            Plane::zero(),
+            Line::new(
+                self.e01() * b.scalar() - self.e23() * b.e0123(),
+                self.e02() * b.scalar() - self.e31() * b.e0123(),
+                self.e03() * b.scalar() - self.e12() * b.e0123(),
+                self.e12() * b.scalar(),
+                self.e31() * b.scalar(),
+                self.e23() * b.scalar(),
+            ),
+            Point::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Line<F>, Motor<F>);
+
+// Motor-Line
+impl<F: Float> BitOr<Line<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12() * b.e12() - self.e31() * b.e31() - self.e23() * b.e23(),
+            Plane::zero(),
+            Line::new(
+                self.scalar() * b.e01() - self.e0123() * b.e23(),
+                self.scalar() * b.e02() - self.e0123() * b.e31(),
+                self.scalar() * b.e03() - self.e0123() * b.e12(),
+                self.scalar() * b.e12(),
+                self.scalar() * b.e31(),
+                self.scalar() * b.e23(),
+            ),
+            Point::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Motor<F>, Line<F>);
+
+// Line-Multivector
+impl<F: Float> BitOr<Multivector<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12() * b.e12() - self.e31() * b.e31() - self.e23() * b.e23(),
+            Plane::new(
+                self.e01() * b.e1()
+                    + self.e02() * b.e2()
+                    + self.e03() * b.e3()
+                    + self.e12() * b.e021()
+                    + self.e31() * b.e013()
+                    + self.e23() * b.e032(),
+                self.e12() * b.e2() - self.e31() * b.e3() - self.e23() * b.e123(),
+                -self.e12() * b.e1() - self.e31() * b.e123() + self.e23() * b.e3(),
+                -self.e12() * b.e123() + self.e31() * b.e1() - self.e23() * b.e2(),
+            ),
+            Line::new(
+                self.e01() * b.scalar() - self.e23() * b.e0123(),
+                self.e02() * b.scalar() - self.e31() * b.e0123(),
+                self.e03() * b.scalar() - self.e12() * b.e0123(),
+                self.e12() * b.scalar(),
+                self.e31() * b.scalar(),
+                self.e23() * b.scalar(),
+            ),
+            Point::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Line<F>, Multivector<F>);
+
+// Multivector-Line
+impl<F: Float> BitOr<Line<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e12() * b.e12() - self.e31() * b.e31() - self.e23() * b.e23(),
+            Plane::new(
+                -self.e1() * b.e01() - self.e2() * b.e02() - self.e3() * b.e03()
+                    + self.e032() * b.e23()
+                    + self.e013() * b.e31()
+                    + self.e021() * b.e12(),
+                -self.e2() * b.e12() + self.e3() * b.e31() - self.e123() * b.e23(),
+                self.e1() * b.e12() - self.e3() * b.e23() - self.e123() * b.e31(),
+                -self.e1() * b.e31() + self.e2() * b.e23() - self.e123() * b.e12(),
+            ),
+            Line::new(
+                self.scalar() * b.e01() - self.e0123() * b.e23(),
+                self.scalar() * b.e02() - self.e0123() * b.e31(),
+                self.scalar() * b.e03() - self.e0123() * b.e12(),
+                self.scalar() * b.e12(),
+                self.scalar() * b.e31(),
+                self.scalar() * b.e23(),
+            ),
+            Point::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Line<F>);
+
+// Point-Point
+impl<F: Float> BitOr for Point<F> {
+    type Output = Scalar<F>;
+    fn bitor(self, b: Point<F>) -> Scalar<F> {
+        Scalar(-self.e123() * b.e123())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Point<F>, Point<F>);
+
+// Point-Pseudoscalar
+impl<F: Float> BitOr<Pseudoscalar<F>> for Point<F> {
+    type Output = Plane<F>;
+    fn bitor(self, b: Pseudoscalar<F>) -> Plane<F> {
+        Plane::new(self.e123() * b.e0123(), F::zero(), F::zero(), F::zero())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Point<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Point
+impl<F: Float> BitOr<Point<F>> for Pseudoscalar<F> {
+    type Output = Plane<F>;
+    fn bitor(self, b: Point<F>) -> Plane<F> {
+        Plane::new(-self.e0123() * b.e123(), F::zero(), F::zero(), F::zero())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Point<F>);
+
+// Point-Motor
+impl<F: Float> BitOr<Motor<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(
+                self.e032() * b.e23()
+                    + self.e013() * b.e31()
+                    + self.e021() * b.e12()
+                    + self.e123() * b.e0123(),
+                -self.e123() * b.e23(),
+                -self.e123() * b.e31(),
+                -self.e123() * b.e12(),
+            ),
+            Line::zero(),
+            Point::new_components(
+                self.e032() * b.scalar(),
+                self.e013() * b.scalar(),
+                self.e021() * b.scalar(),
+                self.e123() * b.scalar(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Point<F>, Motor<F>);
+
+// Motor-Point
+impl<F: Float> BitOr<Point<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(
+                self.e12() * b.e021() + self.e31() * b.e013() + self.e23() * b.e032()
+                    - self.e0123() * b.e123(),
+                -self.e23() * b.e123(),
+                -self.e31() * b.e123(),
+                -self.e12() * b.e123(),
+            ),
+            Line::zero(),
+            Point::new_components(
+                self.scalar() * b.e032(),
+                self.scalar() * b.e013(),
+                self.scalar() * b.e021(),
+                self.scalar() * b.e123(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Motor<F>, Point<F>);
+
+// Point-Multivector
+impl<F: Float> BitOr<Multivector<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e123() * b.e123(),
+            Plane::new(
+                self.e032() * b.e23()
+                    + self.e013() * b.e31()
+                    + self.e021() * b.e12()
+                    + self.e123() * b.e0123(),
+                -self.e123() * b.e23(),
+                -self.e123() * b.e31(),
+                -self.e123() * b.e12(),
+            ),
+            Line::new(
+                self.e013() * b.e3() - self.e021() * b.e2(),
+                -self.e032() * b.e3() + self.e021() * b.e1(),
+                self.e032() * b.e2() - self.e013() * b.e1(),
+                self.e123() * b.e3(),
+                self.e123() * b.e2(),
+                self.e123() * b.e1(),
+            ),
+            Point::new_components(
+                self.e032() * b.scalar(),
+                self.e013() * b.scalar(),
+                self.e021() * b.scalar(),
+                self.e123() * b.scalar(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Point<F>, Multivector<F>);
+
+// Multivector-Point
+impl<F: Float> BitOr<Point<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e123() * b.e123(),
+            Plane::new(
+                self.e12() * b.e021() + self.e31() * b.e013() + self.e23() * b.e032()
+                    - self.e0123() * b.e123(),
+                -self.e23() * b.e123(),
+                -self.e31() * b.e123(),
+                -self.e12() * b.e123(),
+            ),
+            Line::new(
+                -self.e2() * b.e021() + self.e3() * b.e013(),
+                self.e1() * b.e021() - self.e3() * b.e032(),
+                -self.e1() * b.e013() + self.e2() * b.e032(),
+                self.e3() * b.e123(),
+                self.e2() * b.e123(),
+                self.e1() * b.e123(),
+            ),
+            Point::new_components(
+                self.scalar() * b.e032(),
+                self.scalar() * b.e013(),
+                self.scalar() * b.e021(),
+                self.scalar() * b.e123(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Point<F>);
+
+// Pseudoscalar-Pseudoscalar
+impl<F: Float> BitOr for Pseudoscalar<F> {
+    type Output = Scalar<F>;
+    fn bitor(self, _b: Pseudoscalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Motor
+impl<F: Float> BitOr<Motor<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::zero(),
+            Line::new(
+                -self.e0123() * b.e23(),
+                -self.e0123() * b.e31(),
+                -self.e0123() * b.e12(),
+                F::zero(),
+                F::zero(),
+                F::zero(),
+            ),
+            Point::zero(),
+            self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Motor<F>);
+
+// Motor-Pseudoscalar
+impl<F: Float> BitOr<Pseudoscalar<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::zero(),
+            Line::new(
+                -self.e23() * b.e0123(),
+                -self.e31() * b.e0123(),
+                -self.e12() * b.e0123(),
+                F::zero(),
+                F::zero(),
+                F::zero(),
+            ),
+            Point::zero(),
+            self.scalar() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Motor<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Multivector
+impl<F: Float> BitOr<Multivector<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(-self.e0123() * b.e123(), F::zero(), F::zero(), F::zero()),
+            Line::new(
+                -self.e0123() * b.e23(),
+                -self.e0123() * b.e31(),
+                -self.e0123() * b.e12(),
+                F::zero(),
+                F::zero(),
+                F::zero(),
+            ),
+            Point::new_components(
+                -self.e0123() * b.e1(),
+                -self.e0123() * b.e2(),
+                -self.e0123() * b.e3(),
+                F::zero(),
+            ),
+            self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Multivector<F>);
+
+// Multivector-Pseudoscalar
+impl<F: Float> BitOr<Pseudoscalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(self.e123() * b.e0123(), F::zero(), F::zero(), F::zero()),
+            Line::new(
+                -self.e23() * b.e0123(),
+                -self.e31() * b.e0123(),
+                -self.e12() * b.e0123(),
+                F::zero(),
+                F::zero(),
+                F::zero(),
+            ),
+            Point::new_components(
+                self.e1() * b.e0123(),
+                self.e2() * b.e0123(),
+                self.e3() * b.e0123(),
+                F::zero(),
+            ),
+            self.scalar() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Pseudoscalar<F>);
+
+// Motor-Motor
+impl<F: Float> BitOr for Motor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar()
+                - self.e12() * b.e12()
+                - self.e31() * b.e31()
+                - self.e23() * b.e23(),
+            Plane::zero(),
+            Line::new(
+                self.scalar() * b.e01() + self.e01() * b.scalar()
+                    - self.e23() * b.e0123()
+                    - self.e0123() * b.e23(),
+                self.scalar() * b.e02() + self.e02() * b.scalar()
+                    - self.e31() * b.e0123()
+                    - self.e0123() * b.e31(),
+                self.scalar() * b.e03() + self.e03() * b.scalar()
+                    - self.e12() * b.e0123()
+                    - self.e0123() * b.e12(),
+                self.scalar() * b.e12() + self.e12() * b.scalar(),
+                self.scalar() * b.e31() + self.e31() * b.scalar(),
+                self.scalar() * b.e23() + self.e23() * b.scalar(),
+            ),
+            Point::zero(),
+            self.scalar() * b.e0123() + self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Motor<F>, Motor<F>);
+
+// Motor-Multivector
+impl<F: Float> BitOr<Multivector<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar()
+                - self.e12() * b.e12()
+                - self.e31() * b.e31()
+                - self.e23() * b.e23(),
+            Plane::new(
+                self.scalar() * b.e0()
+                    + self.e01() * b.e1()
+                    + self.e02() * b.e2()
+                    + self.e03() * b.e3()
+                    + self.e12() * b.e021()
+                    + self.e31() * b.e013()
+                    + self.e23() * b.e032()
+                    - self.e0123() * b.e123(),
+                self.scalar() * b.e1() + self.e12() * b.e2()
+                    - self.e31() * b.e3()
+                    - self.e23() * b.e123(),
+                self.scalar() * b.e2() - self.e12() * b.e1() - self.e31() * b.e123()
+                    + self.e23() * b.e3(),
+                self.scalar() * b.e3() - self.e12() * b.e123() + self.e31() * b.e1()
+                    - self.e23() * b.e2(),
+            ),
+            Line::new(
+                self.scalar() * b.e01() + self.e01() * b.scalar()
+                    - self.e23() * b.e0123()
+                    - self.e0123() * b.e23(),
+                self.scalar() * b.e02() + self.e02() * b.scalar()
+                    - self.e31() * b.e0123()
+                    - self.e0123() * b.e31(),
+                self.scalar() * b.e03() + self.e03() * b.scalar()
+                    - self.e12() * b.e0123()
+                    - self.e0123() * b.e12(),
+                self.scalar() * b.e12() + self.e12() * b.scalar(),
+                self.scalar() * b.e31() + self.e31() * b.scalar(),
+                self.scalar() * b.e23() + self.e23() * b.scalar(),
+            ),
+            Point::new_components(
+                self.scalar() * b.e032() - self.e0123() * b.e1(),
+                self.scalar() * b.e013() - self.e0123() * b.e2(),
+                self.scalar() * b.e021() - self.e0123() * b.e3(),
+                self.scalar() * b.e123(),
+            ),
+            self.scalar() * b.e0123() + self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Motor<F>, Multivector<F>);
+
+// Multivector-Motor
+impl<F: Float> BitOr<Motor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar()
+                - self.e12() * b.e12()
+                - self.e31() * b.e31()
+                - self.e23() * b.e23(),
+            Plane::new(
+                self.e0() * b.scalar()
+                    - self.e1() * b.e01()
+                    - self.e2() * b.e02()
+                    - self.e3() * b.e03()
+                    + self.e032() * b.e23()
+                    + self.e013() * b.e31()
+                    + self.e021() * b.e12()
+                    + self.e123() * b.e0123(),
+                self.e1() * b.scalar() - self.e2() * b.e12() + self.e3() * b.e31()
+                    - self.e123() * b.e23(),
+                self.e1() * b.e12() + self.e2() * b.scalar()
+                    - self.e3() * b.e23()
+                    - self.e123() * b.e31(),
+                -self.e1() * b.e31() + self.e2() * b.e23() + self.e3() * b.scalar()
+                    - self.e123() * b.e12(),
+            ),
+            Line::new(
+                self.scalar() * b.e01() + self.e01() * b.scalar()
+                    - self.e23() * b.e0123()
+                    - self.e0123() * b.e23(),
+                self.scalar() * b.e02() + self.e02() * b.scalar()
+                    - self.e31() * b.e0123()
+                    - self.e0123() * b.e31(),
+                self.scalar() * b.e03() + self.e03() * b.scalar()
+                    - self.e12() * b.e0123()
+                    - self.e0123() * b.e12(),
+                self.scalar() * b.e12() + self.e12() * b.scalar(),
+                self.scalar() * b.e31() + self.e31() * b.scalar(),
+                self.scalar() * b.e23() + self.e23() * b.scalar(),
+            ),
+            Point::new_components(
+                self.e1() * b.e0123() + self.e032() * b.scalar(),
+                self.e2() * b.e0123() + self.e013() * b.scalar(),
+                self.e3() * b.e0123() + self.e021() * b.scalar(),
+                self.e123() * b.scalar(),
+            ),
+            self.scalar() * b.e0123() + self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Motor<F>);
+
+// Multivector-Multivector
+impl<F: Float> BitOr for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar()
+                + self.e1() * b.e1()
+                + self.e2() * b.e2()
+                + self.e3() * b.e3()
+                - self.e12() * b.e12()
+                - self.e31() * b.e31()
+                - self.e23() * b.e23()
+                - self.e123() * b.e123(),
+            Plane::new(
+                self.scalar() * b.e0() + self.e0() * b.scalar()
+                    - self.e1() * b.e01()
+                    - self.e2() * b.e02()
+                    - self.e3() * b.e03()
+                    + self.e01() * b.e1()
+                    + self.e02() * b.e2()
+                    + self.e03() * b.e3()
+                    + self.e12() * b.e021()
+                    + self.e31() * b.e013()
+                    + self.e23() * b.e032()
+                    + self.e032() * b.e23()
+                    + self.e013() * b.e31()
+                    + self.e021() * b.e12()
+                    + self.e123() * b.e0123()
+                    - self.e0123() * b.e123(),
+                self.scalar() * b.e1() + self.e1() * b.scalar() - self.e2() * b.e12()
+                    + self.e3() * b.e31()
+                    + self.e12() * b.e2()
+                    - self.e31() * b.e3()
+                    - self.e23() * b.e123()
+                    - self.e123() * b.e23(),
+                self.scalar() * b.e2() + self.e1() * b.e12() + self.e2() * b.scalar()
+                    - self.e3() * b.e23()
+                    - self.e12() * b.e1()
+                    - self.e31() * b.e123()
+                    + self.e23() * b.e3()
+                    - self.e123() * b.e31(),
+                self.scalar() * b.e3() - self.e1() * b.e31()
+                    + self.e2() * b.e23()
+                    + self.e3() * b.scalar()
+                    - self.e12() * b.e123()
+                    + self.e31() * b.e1()
+                    - self.e23() * b.e2()
+                    - self.e123() * b.e12(),
+            ),
+            Line::new(
+                self.scalar() * b.e01() - self.e2() * b.e021()
+                    + self.e3() * b.e013()
+                    + self.e01() * b.scalar()
+                    - self.e23() * b.e0123()
+                    + self.e013() * b.e3()
+                    - self.e021() * b.e2()
+                    - self.e0123() * b.e23(),
+                self.scalar() * b.e02() + self.e1() * b.e021() - self.e3() * b.e032()
+                    + self.e02() * b.scalar()
+                    - self.e31() * b.e0123()
+                    - self.e032() * b.e3()
+                    + self.e021() * b.e1()
+                    - self.e0123() * b.e31(),
+                self.scalar() * b.e03() - self.e1() * b.e013()
+                    + self.e2() * b.e032()
+                    + self.e03() * b.scalar()
+                    - self.e12() * b.e0123()
+                    + self.e032() * b.e2()
+                    - self.e013() * b.e1()
+                    - self.e0123() * b.e12(),
+                self.scalar() * b.e12()
+                    + self.e3() * b.e123()
+                    + self.e12() * b.scalar()
+                    + self.e123() * b.e3(),
+                self.scalar() * b.e31()
+                    + self.e2() * b.e123()
+                    + self.e31() * b.scalar()
+                    + self.e123() * b.e2(),
+                self.scalar() * b.e23()
+                    + self.e1() * b.e123()
+                    + self.e23() * b.scalar()
+                    + self.e123() * b.e1(),
+            ),
+            Point::new_components(
+                self.scalar() * b.e032() + self.e1() * b.e0123() + self.e032() * b.scalar()
+                    - self.e0123() * b.e1(),
+                self.scalar() * b.e013() + self.e2() * b.e0123() + self.e013() * b.scalar()
+                    - self.e0123() * b.e2(),
+                self.scalar() * b.e021() + self.e3() * b.e0123() + self.e021() * b.scalar()
+                    - self.e0123() * b.e3(),
+                self.scalar() * b.e123() + self.e123() * b.scalar(),
+            ),
+            self.scalar() * b.e0123() + self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Multivector<F>);
+
+// Test
+#[cfg(test)]
+mod inner_product {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn plane_plane() {
+        // 4e0+5e1-3e2+4e3
+        let plane1 = Plane::new(4.0, 5.0, -3.0, 4.0);
+        // 5e0+2e1-3e2+3e3
+        let plane2 = Plane::new(5.0, 2.0, -3.0, 3.0);
+        let res = plane1 | plane2;
+        // 31
+        assert_relative_eq!(res.0, 31.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn plane_line() {
+        // 1e0-3e1+5e2+2e3
+        let plane1 = Plane::new(1.0, -3.0, 5.0, 2.0);
+        // -1e01+5e02+4e03-2e12+4e31+4e23
+        let line2 = Line::new(-1.0, 5.0, 4.0, -2.0, 4.0, 4.0);
+        let res = plane1 | line2;
+        // -36e0+18e1-2e2+32e3
+        assert_relative_eq!(res.e0(), -36.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 18.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 32.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn line_point() {
+        // -1e01+4e02-1e03-4e12+4e31-2e23
+        let line1 = Line::new(-1.0, 4.0, -1.0, -4.0, 4.0, -2.0);
+        // -1e032+1e013+5e021+2e123
+        let point2 = Point::new_components(-1.0, 1.0, 5.0, 2.0);
+        let res = line1 | point2;
+        // -14e0+4e1-8e2+8e3
+        assert_relative_eq!(res.e0(), -14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 8.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn point_point() {
+        // 4e032-4e013-2e021-2e123
+        let point1 = Point::new_components(4.0, -4.0, -2.0, -2.0);
+        // 3e032-3e013+4e021+4e123
+        let point2 = Point::new_components(3.0, -3.0, 4.0, 4.0);
+        let res = point1 | point2;
+        // 8
+        assert_relative_eq!(res.0, 8.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn plane_point() {
+        // 3e0+5e1+4e2-1e3
+        let plane1 = Plane::new(3.0, 5.0, 4.0, -1.0);
+        // 2e032-2e013+3e021-4e123
+        let point2 = Point::new_components(2.0, -2.0, 3.0, -4.0);
+        let res = plane1 | point2;
+        // -10e01+17e02+18e03+4e12-16e31-20e23
+        assert_relative_eq!(res.e01(), -10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e02(), 17.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e03(), 18.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), -16.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), -20.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn motor_line() {
+        // 3-2e01+4e02+2e03-3e12+2e31-1e23-2e0123
+        let motor1 = Motor::new(3.0, Line::new(-2.0, 4.0, 2.0, -3.0, 2.0, -1.0), -2.0);
+        // 3e01-4e02+3e03+4e12+5e31+4e23
+        let line2 = Line::new(3.0, -4.0, 3.0, 4.0, 5.0, 4.0);
+        let res = motor1 | line2;
+        // 6+17e01-2e02+17e03+12e12+15e31+12e23
+        assert_relative_eq!(res.scalar(), 6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), 17.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e02(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e03(), 17.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 15.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e032(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e013(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e021(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_multivector() {
+        // -4-4e0-1e1+1e2+3e3+2e01-3e02+3e03-1e12-1e31+1e23-1e032+5e013-2e021-3e123+5e0123
+        let mvec1 = Multivector::new(
+            -4.0,
+            Plane::new(-4.0, -1.0, 1.0, 3.0),
+            Line::new(2.0, -3.0, 3.0, -1.0, -1.0, 1.0),
+            Point::new_components(-1.0, 5.0, -2.0, -3.0),
+            5.0,
+        );
+        // 3+5e0+1e1+3e2+3e3+3e01-2e02+4e03+3e12-1e31-4e23+1e032-1e013+4e021-4e123-1e0123
+        let mvec2 = Multivector::new(
+            3.0,
+            Plane::new(5.0, 1.0, 3.0, 3.0),
+            Line::new(3.0, -2.0, 4.0, 3.0, -1.0, -4.0),
+            Point::new_components(1.0, -1.0, 4.0, -4.0),
+            -1.0,
+        );
+        let res = mvec1 | mvec2;
+        // -7-23e0-21e1-3e2-7e3+29e01-3e02-31e03-36e12-12e31+20e23-11e032+3e013-40e021+7e123+19e0123
+        assert_relative_eq!(res.scalar(), -7.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), -23.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -21.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), -7.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), 29.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e02(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e03(), -31.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), -36.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), -12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 20.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e032(), -11.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e013(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e021(), -40.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 7.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), 19.0, max_relative = 0.000001);
+    }
+}
@@ -0,0 +1,239 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::fmt;
+
+use core::ops::Neg;
+
+use num_traits::Float;
+
+use super::{PGA3DOps, scalar::Scalar};
+
+/// # 3D Projective Geometric Algebra Line
+/// The bivectors of the algebra are lines.
+/// $$\ell = a \mathrm{e}_{01} + b \mathrm{e}_{02} + c \mathrm{e}_{03} + d \mathrm{e}_{12} + e \mathrm{e}_{31} + f \mathrm{e}_{23}$$
+/// The Euclidean part $\mathrm{e}_{12}$, $\mathrm{e}_{31}$, $\mathrm{e}_{23}$ is the direction of the line
+/// and the ideal part $\mathrm{e}_{01}$, $\mathrm{e}_{02}$, $\mathrm{e}_{03}$ is its moment.
+/// The line $\mathrm{e}_{12}$ is the $z$-axis, $\mathrm{e}_{31}$ is the $y$-axis and $\mathrm{e}_{23}$ is the $x$-axis.
+///
+/// A line with no Euclidean part lies at infinity.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Line<F: Float> {
+    e01: F,
+    e02: F,
+    e03: F,
+    e12: F,
+    e31: F,
+    e23: F,
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + fmt::Display> fmt::Display for Line<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Line {{")?;
+        write!(f, " {}e01", self.e01)?;
+
+        // Add the appropriate sign to the remaining components
+        for (value, name) in [
+            (self.e02, "e02"),
+            (self.e03, "e03"),
+            (self.e12, "e12"),
+            (self.e31, "e31"),
+            (self.e23, "e23"),
+        ] {
+            if value >= F::zero() {
+                write!(f, " + {}{}", value, name)?;
+            } else {
+                write!(f, " - {}{}", value.abs(), name)?;
+            }
+        }
+        write!(f, " }}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F: Float + defmt::Format> defmt::Format for Line<F> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Line {{");
+        defmt::write!(f, " {}e01", self.e01);
+
+        // Add the appropriate sign to the remaining components
+        for (value, name) in [
+            (self.e02, "e02"),
+            (self.e03, "e03"),
+            (self.e12, "e12"),
+            (self.e31, "e31"),
+            (self.e23, "e23"),
+        ] {
+            if value >= F::zero() {
+                defmt::write!(f, " + {}{}", value, name);
+            } else {
+                defmt::write!(f, " - {}{}", value.abs(), name);
+            }
+        }
+        defmt::write!(f, " }}");
+    }
+}
+
+impl<F: Float> Line<F> {
+    /// The zero line
+    pub fn zero() -> Self {
+        Self {
+            e01: F::zero(),
+            e02: F::zero(),
+            e03: F::zero(),
+            e12: F::zero(),
+            e31: F::zero(),
+            e23: F::zero(),
+        }
+    }
+
+    /// Create new line from linear combination of the basis bivectors
+    pub fn new(e01: F, e02: F, e03: F, e12: F, e31: F, e23: F) -> Self {
+        Self {
+            e01,
+            e02,
+            e03,
+            e12,
+            e31,
+            e23,
+        }
+    }
+
+    /// Get line
+    pub fn line(self) -> Self {
+        self
+    }
+
+    /// Get the $\mathrm{e}_0\mathrm{e}_1$ part of the line
+    pub fn e01(&self) -> F {
+        self.e01
+    }
+
+    /// Get the $\mathrm{e}_0\mathrm{e}_2$ part of the line
+    pub fn e02(&self) -> F {
+        self.e02
+    }
+
+    /// Get the $\mathrm{e}_0\mathrm{e}_3$ part of the line
+    pub fn e03(&self) -> F {
+        self.e03
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2$ part of the line
+    pub fn e12(&self) -> F {
+        self.e12
+    }
+
+    /// Get the $\mathrm{e}_3\mathrm{e}_1$ part of the line
+    pub fn e31(&self) -> F {
+        self.e31
+    }
+
+    /// Get the $\mathrm{e}_2\mathrm{e}_3$ part of the line
+    pub fn e23(&self) -> F {
+        self.e23
+    }
+
+    /// # Dual
+    /// The dual of a line is the line swapping the Euclidean and ideal parts
+    pub fn dual(self) -> Line<F> {
+        Line::new(self.e23, self.e31, self.e12, self.e03, self.e02, self.e01)
+    }
+}
+
+// Negation
+impl<F: Float> Neg for Line<F> {
+    type Output = Line<F>;
+    fn neg(self) -> Line<F> {
+        Line::new(
+            -self.e01, -self.e02, -self.e03, -self.e12, -self.e31, -self.e23,
+        )
+    }
+}
+
+impl<F: Float> PGA3DOps<F> for Line<F> {
+    fn reverse(self) -> Self {
+        -self
+    }
+
+    fn conjugate(self) -> Self {
+        -self
+    }
+
+    fn involute(self) -> Self {
+        self
+    }
+
+    // The length of the direction
+    fn norm(self) -> Scalar<F> {
+        Scalar((self.e12 * self.e12 + self.e31 * self.e31 + self.e23 * self.e23).sqrt())
+    }
+
+    fn ideal_norm(self) -> Scalar<F> {
+        Scalar((self.e01 * self.e01 + self.e02 * self.e02 + self.e03 * self.e03).sqrt())
+    }
+
+    // A normalized line has a unit direction
+    fn try_normalize(self) -> Option<Self> {
+        self.norm().try_inverse().map(|norm_inverse| {
+            let norm_inverse = norm_inverse.0;
+            Line::new(
+                self.e01 * norm_inverse,
+                self.e02 * norm_inverse,
+                self.e03 * norm_inverse,
+                self.e12 * norm_inverse,
+                self.e31 * norm_inverse,
+                self.e23 * norm_inverse,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod line_ops {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn line_normalize() {
+        let line = Line::new(1.0, 2.0, 3.0, 0.0, 3.0, 4.0);
+        let res = line.try_normalize().unwrap();
+        assert_relative_eq!(res.norm().0, 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), 0.2, max_relative = 0.000001);
+        assert_relative_eq!(line.ideal_norm().0, 14.0.sqrt(), max_relative = 0.000001);
+        assert_eq!(
+            Line::new(1.0, 0.0, 0.0, 0.0, 0.0, 0.0).try_normalize(),
+            None
+        );
+    }
+
+    #[test]
+    fn line_dual() {
+        let line = Line::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+        assert_eq!(line.dual(), Line::new(6.0, 5.0, 4.0, 3.0, 2.0, 1.0));
+        assert_eq!(line.dual().dual(), line);
+    }
+}
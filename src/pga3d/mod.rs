@@ -15,3 +15,63 @@
 //
 // You should have received a copy of the GNU Lesser General Public License
 // along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+// The products are implemented through the bit operators
+#![allow(clippy::suspicious_arithmetic_impl)]
+
+use num_traits::Float;
+
+mod scalar;
+pub use scalar::Scalar;
+//
+mod plane;
+pub use plane::Plane;
+//
+mod line;
+pub use line::Line;
+//
+mod point;
+pub use point::Point;
+//
+mod pseudoscalar;
+pub use pseudoscalar::Pseudoscalar;
+//
+mod multivector;
+pub use multivector::Multivector;
+//
+mod motor;
+pub use motor::Motor;
+
+// Interactions
+mod addition;
+mod exterior;
+mod geometric;
+mod inner;
+mod regressive;
+mod subtraction;
+// Functions
+mod functions;
+pub use functions::Transformable;
+
+/// Operations shared by every element of the projective algebra.
+///
+/// The algebra is degenerate ($\mathrm{e}_0^2=0$), so the norm is split in two.
+/// The (bulk) norm measures the Euclidean part of an element,
+/// and the ideal norm measures the part that lies at infinity.
+pub trait PGA3DOps<F: Float>: Clone {
+    /// Reverse $\widetilde{A}$
+    fn reverse(self) -> Self;
+    /// Clifford conjugate $\bar{A}$
+    fn conjugate(self) -> Self;
+    /// Grade involution $\hat{A}$
+    fn involute(self) -> Self;
+    /// The Euclidean norm $\|A\|$
+    fn norm(self) -> Scalar<F>;
+    /// The ideal norm $\|A\|_\infty$
+    fn ideal_norm(self) -> Scalar<F>;
+    /// Scale the element to have a norm of one.
+    /// Elements that lie completely at infinity can not be normalized.
+    fn try_normalize(self) -> Option<Self>
+    where
+        Self: Sized;
+}
@@ -0,0 +1,337 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::fmt;
+
+use crate::forward_ref_binop;
+use core::ops::{Mul, Neg};
+
+use num_traits::Float;
+
+use super::{PGA3DOps, line::Line, pseudoscalar::Pseudoscalar, scalar::Scalar};
+use crate::vga3d;
+
+/// # 3D Projective Geometric Algebra Motor
+///
+/// The motor is the rigid body motion object in projective geometric algebra.
+/// It is a rotation around a line combined with a translation along it.
+/// $$ M = s + \ell + a \mathrm{e}_{0123} $$
+/// An element $X$ is moved by the sandwich product
+/// $$ X' = \widetilde{M} X M $$
+/// which is the same order as the rotation with a [`vga3d::Rotor`].
+///
+/// Motors are composed with the geometric product.
+/// The motor $M_1 M_2$ first applies $M_1$ and then $M_2$.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Motor<F: Float> {
+    pub(super) scalar: Scalar<F>,
+    pub(super) line: Line<F>,
+    pub(super) pseudoscalar: Pseudoscalar<F>,
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + fmt::Display> fmt::Display for Motor<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Motor {{")?;
+        writeln!(f, "\tscalar: {}", self.scalar())?;
+        writeln!(f, "\tline: {}", self.line)?;
+        writeln!(f, "\tpseudoscalar: {}", self.pseudoscalar)?;
+        write!(f, "}}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F: Float + defmt::Format> defmt::Format for Motor<F> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Motor {{\n");
+        defmt::write!(f, "\tscalar: {}\n", self.scalar());
+        defmt::write!(f, "\tline: {}\n", self.line);
+        defmt::write!(f, "\tpseudoscalar: {}\n", self.pseudoscalar);
+        defmt::write!(f, "}}");
+    }
+}
+
+impl<F: Float> Motor<F> {
+    /// Create new motor from its grades
+    pub fn new(scalar: F, line: Line<F>, e0123: F) -> Self {
+        Motor {
+            scalar: Scalar(scalar),
+            line,
+            pseudoscalar: Pseudoscalar::new(e0123),
+        }
+    }
+
+    /// This is the identity motor.
+    /// Anything moved with this will return it self.
+    pub fn identity() -> Self {
+        Motor {
+            scalar: Scalar(F::one()),
+            line: Line::zero(),
+            pseudoscalar: Pseudoscalar::zero(),
+        }
+    }
+
+    /// Creates the translator that moves elements by $(x,y,z)$
+    /// $$ T = 1 + \frac{1}{2}\left( x \mathrm{e}_{01} + y \mathrm{e}_{02} + z \mathrm{e}_{03} \right) $$
+    pub fn from_translation(x: F, y: F, z: F) -> Self {
+        let half = F::one() / (F::one() + F::one());
+        Motor {
+            scalar: Scalar(F::one()),
+            line: Line::new(
+                x * half,
+                y * half,
+                z * half,
+                F::zero(),
+                F::zero(),
+                F::zero(),
+            ),
+            pseudoscalar: Pseudoscalar::zero(),
+        }
+    }
+
+    /// Tries to create the motor that rotates around a line.
+    /// The angle must be in radians.
+    /// The rotation is counterclockwise when looking against the direction of the line,
+    /// so the line $\mathrm{e}_{12}$ turns the $x$-axis towards the $y$-axis.
+    /// $$ M = \cos \left( \frac{\theta}{2}  \right) + \sin \left( \frac{\theta}{2} \right) \hat{\ell} $$
+    /// This is not posible for a line at infinity.
+    pub fn try_from_rotation(angle: F, axis: Line<F>) -> Option<Self> {
+        let half_angle = angle / (F::one() + F::one());
+        axis.try_normalize().map(|axis| {
+            let (sin, cos) = half_angle.sin_cos();
+            Motor {
+                scalar: Scalar(cos),
+                line: Line::new(
+                    axis.e01() * sin,
+                    axis.e02() * sin,
+                    axis.e03() * sin,
+                    axis.e12() * sin,
+                    axis.e31() * sin,
+                    axis.e23() * sin,
+                ),
+                pseudoscalar: Pseudoscalar::zero(),
+            }
+        })
+    }
+
+    /// Creates the motor that does the same rotation around the origin as a 3D rotor
+    pub fn from_rotor(rotor: vga3d::Rotor<F>) -> Self {
+        Motor {
+            scalar: Scalar(rotor.scalar()),
+            line: Line::new(
+                F::zero(),
+                F::zero(),
+                F::zero(),
+                rotor.e12(),
+                rotor.e31(),
+                rotor.e23(),
+            ),
+            pseudoscalar: Pseudoscalar::zero(),
+        }
+    }
+
+    /// Get motor
+    pub fn motor(self) -> Self {
+        self
+    }
+
+    /// Get the scalar grade of the motor
+    pub fn scalar(&self) -> F {
+        self.scalar.0
+    }
+
+    /// Get the line grade of the motor
+    pub fn line(&self) -> Line<F> {
+        self.line
+    }
+
+    /// Get the pseudoscalar grade of the motor
+    pub fn pseudoscalar(&self) -> Pseudoscalar<F> {
+        self.pseudoscalar
+    }
+
+    /// Get the $\mathrm{e}_0\mathrm{e}_1$ part of the line grade
+    pub fn e01(&self) -> F {
+        self.line.e01()
+    }
+
+    /// Get the $\mathrm{e}_0\mathrm{e}_2$ part of the line grade
+    pub fn e02(&self) -> F {
+        self.line.e02()
+    }
+
+    /// Get the $\mathrm{e}_0\mathrm{e}_3$ part of the line grade
+    pub fn e03(&self) -> F {
+        self.line.e03()
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2$ part of the line grade
+    pub fn e12(&self) -> F {
+        self.line.e12()
+    }
+
+    /// Get the $\mathrm{e}_3\mathrm{e}_1$ part of the line grade
+    pub fn e31(&self) -> F {
+        self.line.e31()
+    }
+
+    /// Get the $\mathrm{e}_2\mathrm{e}_3$ part of the line grade
+    pub fn e23(&self) -> F {
+        self.line.e23()
+    }
+
+    /// Get the $\mathrm{e}_0\mathrm{e}_1\mathrm{e}_2\mathrm{e}_3$ part of the motor
+    pub fn e0123(&self) -> F {
+        self.pseudoscalar.e0123()
+    }
+}
+
+// Negation
+impl<F: Float> Neg for Motor<F> {
+    type Output = Motor<F>;
+    fn neg(self) -> Motor<F> {
+        Motor {
+            scalar: -self.scalar,
+            line: -self.line,
+            pseudoscalar: -self.pseudoscalar,
+        }
+    }
+}
+
+// Motor-Motor
+// The product of two motors is a motor
+// The result is normalized to remove the numerical drift
+impl<F: Float> Mul for Motor<F> {
+    type Output = Motor<F>;
+    fn mul(self, b: Motor<F>) -> Motor<F> {
+        let motor = Motor::new(
+            self.scalar() * b.scalar()
+                - self.e12() * b.e12()
+                - self.e31() * b.e31()
+                - self.e23() * b.e23(),
+            Line::new(
+                self.scalar() * b.e01() + self.e01() * b.scalar() - self.e02() * b.e12()
+                    + self.e03() * b.e31()
+                    + self.e12() * b.e02()
+                    - self.e31() * b.e03()
+                    - self.e23() * b.e0123()
+                    - self.e0123() * b.e23(),
+                self.scalar() * b.e02() + self.e01() * b.e12() + self.e02() * b.scalar()
+                    - self.e03() * b.e23()
+                    - self.e12() * b.e01()
+                    + self.e23() * b.e03()
+                    - self.e31() * b.e0123()
+                    - self.e0123() * b.e31(),
+                self.scalar() * b.e03() - self.e01() * b.e31()
+                    + self.e02() * b.e23()
+                    + self.e03() * b.scalar()
+                    + self.e31() * b.e01()
+                    - self.e23() * b.e02()
+                    - self.e12() * b.e0123()
+                    - self.e0123() * b.e12(),
+                self.scalar() * b.e12() + self.e12() * b.scalar() + self.e31() * b.e23()
+                    - self.e23() * b.e31(),
+                self.scalar() * b.e31() - self.e12() * b.e23()
+                    + self.e31() * b.scalar()
+                    + self.e23() * b.e12(),
+                self.scalar() * b.e23() + self.e12() * b.e31() - self.e31() * b.e12()
+                    + self.e23() * b.scalar(),
+            ),
+            self.scalar() * b.e0123()
+                + self.e01() * b.e23()
+                + self.e02() * b.e31()
+                + self.e03() * b.e12()
+                + self.e12() * b.e03()
+                + self.e31() * b.e02()
+                + self.e23() * b.e01()
+                + self.e0123() * b.scalar(),
+        );
+        motor.try_normalize().unwrap_or(motor)
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Motor<F>, Motor<F>);
+
+impl<F: Float> PGA3DOps<F> for Motor<F> {
+    fn reverse(self) -> Self {
+        Motor {
+            scalar: self.scalar,
+            line: -self.line,
+            pseudoscalar: self.pseudoscalar,
+        }
+    }
+
+    fn conjugate(self) -> Self {
+        Motor {
+            scalar: self.scalar,
+            line: -self.line,
+            pseudoscalar: self.pseudoscalar,
+        }
+    }
+
+    fn involute(self) -> Self {
+        self
+    }
+
+    // The norm of the rotational part
+    // \[\|M\|^2=\left< M \widetilde{M} \right>_0\]
+    fn norm(self) -> Scalar<F> {
+        Scalar(
+            (self.scalar() * self.scalar()
+                + self.e12() * self.e12()
+                + self.e31() * self.e31()
+                + self.e23() * self.e23())
+            .sqrt(),
+        )
+    }
+
+    // The norm of the translational part
+    fn ideal_norm(self) -> Scalar<F> {
+        Scalar(
+            (self.e01() * self.e01()
+                + self.e02() * self.e02()
+                + self.e03() * self.e03()
+                + self.e0123() * self.e0123())
+            .sqrt(),
+        )
+    }
+
+    fn try_normalize(self) -> Option<Self> {
+        self.norm().try_inverse().map(|norm_inverse| {
+            let norm_inverse = norm_inverse.0;
+            Motor {
+                scalar: Scalar(self.scalar() * norm_inverse),
+                line: Line::new(
+                    self.e01() * norm_inverse,
+                    self.e02() * norm_inverse,
+                    self.e03() * norm_inverse,
+                    self.e12() * norm_inverse,
+                    self.e31() * norm_inverse,
+                    self.e23() * norm_inverse,
+                ),
+                pseudoscalar: Pseudoscalar::new(self.e0123() * norm_inverse),
+            }
+        })
+    }
+}
@@ -0,0 +1,436 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::fmt;
+
+use core::ops::Neg;
+
+use num_traits::Float;
+
+use super::{
+    PGA3DOps, line::Line, motor::Motor, plane::Plane, point::Point, pseudoscalar::Pseudoscalar,
+    scalar::Scalar,
+};
+
+/// # 3D Projective Geometric Algebra Multivector
+/// The full 16 component element of $\text{Cl}(3,0,1)$
+/// $$ A = s + p + \ell + P + I $$
+/// with a scalar, a plane, a line, a point and a pseudoscalar part.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Multivector<F: Float> {
+    pub(super) scalar: Scalar<F>,
+    pub(super) plane: Plane<F>,
+    pub(super) line: Line<F>,
+    pub(super) point: Point<F>,
+    pub(super) pseudoscalar: Pseudoscalar<F>,
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + fmt::Display> fmt::Display for Multivector<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Multivector {{")?;
+        writeln!(f, "\tscalar: {}", self.scalar())?;
+        writeln!(f, "\tplane: {}", self.plane)?;
+        writeln!(f, "\tline: {}", self.line)?;
+        writeln!(f, "\tpoint: {}", self.point)?;
+        writeln!(f, "\tpseudoscalar: {}", self.pseudoscalar)?;
+        write!(f, "}}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F: Float + defmt::Format> defmt::Format for Multivector<F> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Multivector {{\n");
+        defmt::write!(f, "\tscalar: {}\n", self.scalar());
+        defmt::write!(f, "\tplane: {}\n", self.plane);
+        defmt::write!(f, "\tline: {}\n", self.line);
+        defmt::write!(f, "\tpoint: {}\n", self.point);
+        defmt::write!(f, "\tpseudoscalar: {}\n", self.pseudoscalar);
+        defmt::write!(f, "}}");
+    }
+}
+
+impl<F: Float> Multivector<F> {
+    /// The zero multivector
+    pub fn zero() -> Self {
+        Multivector {
+            scalar: Scalar::zero(),
+            plane: Plane::zero(),
+            line: Line::zero(),
+            point: Point::zero(),
+            pseudoscalar: Pseudoscalar::zero(),
+        }
+    }
+
+    /// Create new multivector from its grades
+    pub fn new(scalar: F, plane: Plane<F>, line: Line<F>, point: Point<F>, e0123: F) -> Self {
+        Multivector {
+            scalar: Scalar(scalar),
+            plane,
+            line,
+            point,
+            pseudoscalar: Pseudoscalar::new(e0123),
+        }
+    }
+
+    /// Get multivector
+    pub fn multivector(self) -> Self {
+        self
+    }
+
+    /// Get the scalar grade
+    pub fn scalar(&self) -> F {
+        self.scalar.0
+    }
+
+    /// Get the vector grade, which is a plane
+    pub fn plane(&self) -> Plane<F> {
+        self.plane
+    }
+
+    /// Get the bivector grade, which is a line
+    pub fn line(&self) -> Line<F> {
+        self.line
+    }
+
+    /// Get the trivector grade, which is a point
+    pub fn point(&self) -> Point<F> {
+        self.point
+    }
+
+    /// Get the pseudoscalar grade
+    pub fn pseudoscalar(&self) -> Pseudoscalar<F> {
+        self.pseudoscalar
+    }
+
+    /// Get the even grades as a motor
+    pub fn motor(&self) -> Motor<F> {
+        Motor::new(self.scalar(), self.line, self.e0123())
+    }
+
+    /// Get the $\mathrm{e}_0$ part of the plane grade
+    pub fn e0(&self) -> F {
+        self.plane.e0()
+    }
+
+    /// Get the $\mathrm{e}_1$ part of the plane grade
+    pub fn e1(&self) -> F {
+        self.plane.e1()
+    }
+
+    /// Get the $\mathrm{e}_2$ part of the plane grade
+    pub fn e2(&self) -> F {
+        self.plane.e2()
+    }
+
+    /// Get the $\mathrm{e}_3$ part of the plane grade
+    pub fn e3(&self) -> F {
+        self.plane.e3()
+    }
+
+    /// Get the $\mathrm{e}_0\mathrm{e}_1$ part of the line grade
+    pub fn e01(&self) -> F {
+        self.line.e01()
+    }
+
+    /// Get the $\mathrm{e}_0\mathrm{e}_2$ part of the line grade
+    pub fn e02(&self) -> F {
+        self.line.e02()
+    }
+
+    /// Get the $\mathrm{e}_0\mathrm{e}_3$ part of the line grade
+    pub fn e03(&self) -> F {
+        self.line.e03()
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2$ part of the line grade
+    pub fn e12(&self) -> F {
+        self.line.e12()
+    }
+
+    /// Get the $\mathrm{e}_3\mathrm{e}_1$ part of the line grade
+    pub fn e31(&self) -> F {
+        self.line.e31()
+    }
+
+    /// Get the $\mathrm{e}_2\mathrm{e}_3$ part of the line grade
+    pub fn e23(&self) -> F {
+        self.line.e23()
+    }
+
+    /// Get the $\mathrm{e}_0\mathrm{e}_3\mathrm{e}_2$ part of the point grade
+    pub fn e032(&self) -> F {
+        self.point.e032()
+    }
+
+    /// Get the $\mathrm{e}_0\mathrm{e}_1\mathrm{e}_3$ part of the point grade
+    pub fn e013(&self) -> F {
+        self.point.e013()
+    }
+
+    /// Get the $\mathrm{e}_0\mathrm{e}_2\mathrm{e}_1$ part of the point grade
+    pub fn e021(&self) -> F {
+        self.point.e021()
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2\mathrm{e}_3$ part of the point grade
+    pub fn e123(&self) -> F {
+        self.point.e123()
+    }
+
+    /// Get the $\mathrm{e}_0\mathrm{e}_1\mathrm{e}_2\mathrm{e}_3$ part of the pseudoscalar grade
+    pub fn e0123(&self) -> F {
+        self.pseudoscalar.e0123()
+    }
+
+    /// # Dual
+    /// The dual maps every basis blade to its complement.
+    /// Planes become points, lines stay lines and scalars become pseudoscalars.
+    pub fn dual(self) -> Multivector<F> {
+        Multivector {
+            scalar: self.pseudoscalar.dual(),
+            plane: self.point.dual(),
+            line: self.line.dual(),
+            point: self.plane.dual(),
+            pseudoscalar: self.scalar.dual(),
+        }
+    }
+}
+
+// Negation
+impl<F: Float> Neg for Multivector<F> {
+    type Output = Multivector<F>;
+    fn neg(self) -> Multivector<F> {
+        Multivector {
+            scalar: -self.scalar,
+            plane: -self.plane,
+            line: -self.line,
+            point: -self.point,
+            pseudoscalar: -self.pseudoscalar,
+        }
+    }
+}
+
+impl<F: Float> PGA3DOps<F> for Multivector<F> {
+    // Reverse
+    // It follows the patten (Each is a grade)
+    // \[+ + - - + + - - \dots (-1)^{k(k-1)/2}\]
+    fn reverse(self) -> Self {
+        Multivector {
+            scalar: self.scalar,
+            plane: self.plane,
+            line: -self.line,
+            point: -self.point,
+            pseudoscalar: self.pseudoscalar,
+        }
+    }
+
+    // Clifford Conjugation
+    // It follows the patten (Each is a grade)
+    // \[+--+--+\dots(-1)^{k(k+1)/2}\]
+    fn conjugate(self) -> Self {
+        Multivector {
+            scalar: self.scalar,
+            plane: -self.plane,
+            line: -self.line,
+            point: self.point,
+            pseudoscalar: self.pseudoscalar,
+        }
+    }
+
+    // Grade Involution
+    // The follows this patten (Each is a grade)
+    // \[+ - + - + -\dots (-1)^{k}\]
+    fn involute(self) -> Self {
+        Multivector {
+            scalar: self.scalar,
+            plane: -self.plane,
+            line: self.line,
+            point: -self.point,
+            pseudoscalar: self.pseudoscalar,
+        }
+    }
+
+    // The norm of the Euclidean part
+    // \[\|A\|^2=\left< A \widetilde{A} \right>_0\]
+    fn norm(self) -> Scalar<F> {
+        Scalar(
+            (self.scalar() * self.scalar()
+                + self.plane.norm().0 * self.plane.norm().0
+                + self.line.norm().0 * self.line.norm().0
+                + self.e123() * self.e123())
+            .sqrt(),
+        )
+    }
+
+    // The norm of the ideal part, which is the norm of the dual
+    fn ideal_norm(self) -> Scalar<F> {
+        self.dual().norm()
+    }
+
+    fn try_normalize(self) -> Option<Self> {
+        self.norm()
+            .try_inverse()
+            .map(|norm_inverse| self * norm_inverse)
+    }
+}
+
+// Conversions
+// Every grade and the motor is a multivector with the other grades set to zero
+impl<F: Float> From<Scalar<F>> for Multivector<F> {
+    fn from(scalar: Scalar<F>) -> Self {
+        Multivector::new(
+            scalar.0,
+            Plane::zero(),
+            Line::zero(),
+            Point::zero(),
+            F::zero(),
+        )
+    }
+}
+
+impl<F: Float> From<Plane<F>> for Multivector<F> {
+    fn from(plane: Plane<F>) -> Self {
+        Multivector::new(F::zero(), plane, Line::zero(), Point::zero(), F::zero())
+    }
+}
+
+impl<F: Float> From<Line<F>> for Multivector<F> {
+    fn from(line: Line<F>) -> Self {
+        Multivector::new(F::zero(), Plane::zero(), line, Point::zero(), F::zero())
+    }
+}
+
+impl<F: Float> From<Point<F>> for Multivector<F> {
+    fn from(point: Point<F>) -> Self {
+        Multivector::new(F::zero(), Plane::zero(), Line::zero(), point, F::zero())
+    }
+}
+
+impl<F: Float> From<Pseudoscalar<F>> for Multivector<F> {
+    fn from(pseudoscalar: Pseudoscalar<F>) -> Self {
+        Multivector::new(
+            F::zero(),
+            Plane::zero(),
+            Line::zero(),
+            Point::zero(),
+            pseudoscalar.e0123(),
+        )
+    }
+}
+
+impl<F: Float> From<Motor<F>> for Multivector<F> {
+    fn from(motor: Motor<F>) -> Self {
+        Multivector::new(
+            motor.scalar(),
+            Plane::zero(),
+            motor.line(),
+            Point::zero(),
+            motor.e0123(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod multivector_ops {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn multivector_norm() {
+        let multivector = Multivector::new(
+            1.0,
+            Plane::new(7.0, 1.0, 1.0, 1.0),
+            Line::new(7.0, 7.0, 7.0, 1.0, 1.0, 1.0),
+            Point::new_components(7.0, 7.0, 7.0, 1.0),
+            7.0,
+        );
+        assert_relative_eq!(multivector.norm().0, 8.0.sqrt(), max_relative = 0.000001);
+        assert_relative_eq!(
+            multivector.ideal_norm().0,
+            (8.0 * 49.0).sqrt(),
+            max_relative = 0.000001
+        );
+        let res = multivector.try_normalize().unwrap();
+        assert_relative_eq!(res.norm().0, 1.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_from() {
+        let plane = Plane::new(2.0, 3.0, 4.0, 5.0);
+        let line = Line::new(6.0, 7.0, 8.0, 9.0, 10.0, 11.0);
+        let point = Point::new_components(12.0, 13.0, 14.0, 15.0);
+        let (zero_plane, zero_line, zero_point) = (Plane::zero(), Line::zero(), Point::zero());
+        assert_eq!(
+            Multivector::from(Scalar(1.0)),
+            Multivector::new(1.0, zero_plane, zero_line, zero_point, 0.0)
+        );
+        assert_eq!(
+            Multivector::from(plane),
+            Multivector::new(0.0, plane, zero_line, zero_point, 0.0)
+        );
+        assert_eq!(
+            Multivector::from(line),
+            Multivector::new(0.0, zero_plane, line, zero_point, 0.0)
+        );
+        assert_eq!(
+            Multivector::from(point),
+            Multivector::new(0.0, zero_plane, zero_line, point, 0.0)
+        );
+        assert_eq!(
+            Multivector::from(Pseudoscalar::new(16.0)),
+            Multivector::new(0.0, zero_plane, zero_line, zero_point, 16.0)
+        );
+        assert_eq!(
+            Multivector::from(Motor::new(1.0, line, 16.0)),
+            Multivector::new(1.0, zero_plane, line, zero_point, 16.0)
+        );
+    }
+
+    #[test]
+    fn multivector_reverse() {
+        // The reverse of a product is the product of the reverses in opposite order
+        let plane = Plane::new(1.0, 2.0, -1.0, 3.0);
+        let point = Point::new(1.0, -2.0, 4.0);
+        let res = (plane * point).reverse();
+        let expected = point.reverse() * plane.reverse();
+        assert_eq!(res, expected);
+    }
+
+    #[test]
+    fn multivector_dual() {
+        let multivector = Multivector::new(
+            1.0,
+            Plane::new(2.0, 3.0, 4.0, 5.0),
+            Line::new(6.0, 7.0, 8.0, 9.0, 10.0, 11.0),
+            Point::new_components(12.0, 13.0, 14.0, 15.0),
+            16.0,
+        );
+        assert_eq!(multivector.dual().dual(), multivector);
+        assert_eq!(multivector.dual().e123(), 2.0);
+        assert_eq!(multivector.dual().e0123(), 1.0);
+    }
+}
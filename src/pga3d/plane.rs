@@ -0,0 +1,215 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::fmt;
+
+use core::ops::Neg;
+
+use num_traits::Float;
+
+use super::{PGA3DOps, point::Point, scalar::Scalar};
+
+/// # 3D Projective Geometric Algebra Plane
+/// The vectors of the algebra are planes.
+/// The plane $ax+by+cz+d=0$ is
+/// $$p = d \mathrm{e}_0 + a \mathrm{e}_1 + b \mathrm{e}_2 + c \mathrm{e}_3$$
+/// The plane $\mathrm{e}_0$ is the plane at infinity.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Plane<F: Float> {
+    e0: F,
+    e1: F,
+    e2: F,
+    e3: F,
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + fmt::Display> fmt::Display for Plane<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Plane {{")?;
+        write!(f, " {}e0", self.e0)?;
+
+        // Add the appropriate sign to the remaining components
+        for (value, name) in [(self.e1, "e1"), (self.e2, "e2"), (self.e3, "e3")] {
+            if value >= F::zero() {
+                write!(f, " + {}{}", value, name)?;
+            } else {
+                write!(f, " - {}{}", value.abs(), name)?;
+            }
+        }
+        write!(f, " }}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F: Float + defmt::Format> defmt::Format for Plane<F> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Plane {{");
+        defmt::write!(f, " {}e0", self.e0);
+
+        // Add the appropriate sign to the remaining components
+        for (value, name) in [(self.e1, "e1"), (self.e2, "e2"), (self.e3, "e3")] {
+            if value >= F::zero() {
+                defmt::write!(f, " + {}{}", value, name);
+            } else {
+                defmt::write!(f, " - {}{}", value.abs(), name);
+            }
+        }
+        defmt::write!(f, " }}");
+    }
+}
+
+impl<F: Float> Plane<F> {
+    /// The zero plane
+    pub fn zero() -> Self {
+        Self {
+            e0: F::zero(),
+            e1: F::zero(),
+            e2: F::zero(),
+            e3: F::zero(),
+        }
+    }
+
+    /// Create new plane from linear combination of the basis vectors
+    pub fn new(e0: F, e1: F, e2: F, e3: F) -> Self {
+        Self { e0, e1, e2, e3 }
+    }
+
+    /// Create the plane $ax+by+cz+d=0$
+    pub fn from_equation(a: F, b: F, c: F, d: F) -> Self {
+        Self {
+            e0: d,
+            e1: a,
+            e2: b,
+            e3: c,
+        }
+    }
+
+    /// Get plane
+    pub fn plane(self) -> Self {
+        self
+    }
+
+    /// Get the distance part $\mathrm{e}_0$
+    pub fn e0(&self) -> F {
+        self.e0
+    }
+
+    /// Get the $\mathrm{e}_1$ part of the normal
+    pub fn e1(&self) -> F {
+        self.e1
+    }
+
+    /// Get the $\mathrm{e}_2$ part of the normal
+    pub fn e2(&self) -> F {
+        self.e2
+    }
+
+    /// Get the $\mathrm{e}_3$ part of the normal
+    pub fn e3(&self) -> F {
+        self.e3
+    }
+
+    /// # Dual
+    /// The dual of a plane is a point
+    pub fn dual(self) -> Point<F> {
+        Point::new_components(self.e1, self.e2, self.e3, self.e0)
+    }
+}
+
+// Negation
+impl<F: Float> Neg for Plane<F> {
+    type Output = Plane<F>;
+    fn neg(self) -> Plane<F> {
+        Plane::new(-self.e0, -self.e1, -self.e2, -self.e3)
+    }
+}
+
+impl<F: Float> PGA3DOps<F> for Plane<F> {
+    fn reverse(self) -> Self {
+        self
+    }
+
+    fn conjugate(self) -> Self {
+        -self
+    }
+
+    fn involute(self) -> Self {
+        -self
+    }
+
+    // The length of the normal
+    fn norm(self) -> Scalar<F> {
+        Scalar((self.e1 * self.e1 + self.e2 * self.e2 + self.e3 * self.e3).sqrt())
+    }
+
+    fn ideal_norm(self) -> Scalar<F> {
+        Scalar(self.e0.abs())
+    }
+
+    // A normalized plane has a unit normal and $\mathrm{e}_0$ is the signed distance to the origin
+    fn try_normalize(self) -> Option<Self> {
+        self.norm().try_inverse().map(|norm_inverse| {
+            let norm_inverse = norm_inverse.0;
+            Plane::new(
+                self.e0 * norm_inverse,
+                self.e1 * norm_inverse,
+                self.e2 * norm_inverse,
+                self.e3 * norm_inverse,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod plane_ops {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn plane_from_equation() {
+        // 2x+3y-z+4=0
+        let plane = Plane::from_equation(2.0, 3.0, -1.0, 4.0);
+        assert_eq!(plane, Plane::new(4.0, 2.0, 3.0, -1.0));
+    }
+
+    #[test]
+    fn plane_normalize() {
+        // z = 2
+        let plane = Plane::from_equation(0.0, 0.0, 3.0, -6.0);
+        let res = plane.try_normalize().unwrap();
+        assert_relative_eq!(res.e3(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), -2.0, max_relative = 0.000001);
+        assert_eq!(Plane::new(1.0, 0.0, 0.0, 0.0).try_normalize(), None);
+        assert_relative_eq!(plane.ideal_norm().0, 6.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn plane_dual() {
+        let plane = Plane::new(4.0, 1.0, 2.0, 3.0);
+        let dual = plane.dual();
+        assert_eq!(dual, Point::new_components(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(dual.dual(), plane);
+    }
+}
@@ -0,0 +1,250 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::fmt;
+
+use core::ops::Neg;
+
+use num_traits::Float;
+
+use super::{PGA3DOps, plane::Plane, scalar::Scalar};
+use crate::vga3d;
+
+/// # 3D Projective Geometric Algebra Point
+/// The trivectors of the algebra are points.
+/// The point $(x,y,z)$ is
+/// $$P = x \mathrm{e}_{032} + y \mathrm{e}_{013} + z \mathrm{e}_{021} + \mathrm{e}_{123}$$
+/// A point with $\mathrm{e}_{123}=0$ is an ideal point, which is a direction.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Point<F: Float> {
+    e032: F,
+    e013: F,
+    e021: F,
+    e123: F,
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + fmt::Display> fmt::Display for Point<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Point {{")?;
+        write!(f, " {}e032", self.e032)?;
+
+        // Add the appropriate sign to the remaining components
+        for (value, name) in [
+            (self.e013, "e013"),
+            (self.e021, "e021"),
+            (self.e123, "e123"),
+        ] {
+            if value >= F::zero() {
+                write!(f, " + {}{}", value, name)?;
+            } else {
+                write!(f, " - {}{}", value.abs(), name)?;
+            }
+        }
+        write!(f, " }}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F: Float + defmt::Format> defmt::Format for Point<F> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Point {{");
+        defmt::write!(f, " {}e032", self.e032);
+
+        // Add the appropriate sign to the remaining components
+        for (value, name) in [
+            (self.e013, "e013"),
+            (self.e021, "e021"),
+            (self.e123, "e123"),
+        ] {
+            if value >= F::zero() {
+                defmt::write!(f, " + {}{}", value, name);
+            } else {
+                defmt::write!(f, " - {}{}", value.abs(), name);
+            }
+        }
+        defmt::write!(f, " }}");
+    }
+}
+
+impl<F: Float> Point<F> {
+    /// The zero point
+    pub fn zero() -> Self {
+        Self {
+            e032: F::zero(),
+            e013: F::zero(),
+            e021: F::zero(),
+            e123: F::zero(),
+        }
+    }
+
+    /// Create the Euclidean point $(x,y,z)$
+    pub fn new(x: F, y: F, z: F) -> Self {
+        Self {
+            e032: x,
+            e013: y,
+            e021: z,
+            e123: F::one(),
+        }
+    }
+
+    /// Create the ideal point in the direction $(x,y,z)$
+    pub fn new_direction(x: F, y: F, z: F) -> Self {
+        Self {
+            e032: x,
+            e013: y,
+            e021: z,
+            e123: F::zero(),
+        }
+    }
+
+    /// Create new point from linear combination of the basis trivectors
+    pub fn new_components(e032: F, e013: F, e021: F, e123: F) -> Self {
+        Self {
+            e032,
+            e013,
+            e021,
+            e123,
+        }
+    }
+
+    /// Create the Euclidean point at the tip of a 3D vector
+    pub fn from_vector(vector: vga3d::Vector<F>) -> Self {
+        Point::new(vector.e1(), vector.e2(), vector.e3())
+    }
+
+    /// Get the position of a Euclidean point as a 3D vector.
+    /// This is not posible for ideal points.
+    pub fn try_to_vector(&self) -> Option<vga3d::Vector<F>> {
+        Scalar(self.e123).try_inverse().map(|weight_inverse| {
+            let weight_inverse = weight_inverse.0;
+            vga3d::Vector::new(
+                self.e032 * weight_inverse,
+                self.e013 * weight_inverse,
+                self.e021 * weight_inverse,
+            )
+        })
+    }
+
+    /// Get point
+    pub fn point(self) -> Self {
+        self
+    }
+
+    /// Get the $\mathrm{e}_0\mathrm{e}_3\mathrm{e}_2$ part of the point
+    pub fn e032(&self) -> F {
+        self.e032
+    }
+
+    /// Get the $\mathrm{e}_0\mathrm{e}_1\mathrm{e}_3$ part of the point
+    pub fn e013(&self) -> F {
+        self.e013
+    }
+
+    /// Get the $\mathrm{e}_0\mathrm{e}_2\mathrm{e}_1$ part of the point
+    pub fn e021(&self) -> F {
+        self.e021
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2\mathrm{e}_3$ part of the point
+    pub fn e123(&self) -> F {
+        self.e123
+    }
+
+    /// # Dual
+    /// The dual of a point is a plane
+    pub fn dual(self) -> Plane<F> {
+        Plane::new(self.e123, self.e032, self.e013, self.e021)
+    }
+}
+
+// Negation
+impl<F: Float> Neg for Point<F> {
+    type Output = Point<F>;
+    fn neg(self) -> Point<F> {
+        Point::new_components(-self.e032, -self.e013, -self.e021, -self.e123)
+    }
+}
+
+impl<F: Float> PGA3DOps<F> for Point<F> {
+    fn reverse(self) -> Self {
+        -self
+    }
+
+    fn conjugate(self) -> Self {
+        self
+    }
+
+    fn involute(self) -> Self {
+        -self
+    }
+
+    // The weight of the point
+    fn norm(self) -> Scalar<F> {
+        Scalar(self.e123.abs())
+    }
+
+    fn ideal_norm(self) -> Scalar<F> {
+        Scalar((self.e032 * self.e032 + self.e013 * self.e013 + self.e021 * self.e021).sqrt())
+    }
+
+    // A normalized point has $\mathrm{e}_{123}=1$
+    fn try_normalize(self) -> Option<Self> {
+        Scalar(self.e123).try_inverse().map(|weight_inverse| {
+            let weight_inverse = weight_inverse.0;
+            Point::new_components(
+                self.e032 * weight_inverse,
+                self.e013 * weight_inverse,
+                self.e021 * weight_inverse,
+                F::one(),
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod point_ops {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn point_new() {
+        let point = Point::new(1.0, 2.0, 3.0);
+        assert_eq!(point, Point::new_components(1.0, 2.0, 3.0, 1.0));
+        let direction = Point::new_direction(1.0, 2.0, 3.0);
+        assert_eq!(direction.e123(), 0.0);
+    }
+
+    #[test]
+    fn point_vector() {
+        let point = Point::new_components(2.0, 4.0, -6.0, 2.0);
+        let vector = point.try_to_vector().unwrap();
+        assert_relative_eq!(vector.e1(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(vector.e2(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(vector.e3(), -3.0, max_relative = 0.000001);
+        assert_eq!(Point::from_vector(vector), point.try_normalize().unwrap());
+        assert_eq!(Point::new_direction(1.0, 0.0, 0.0).try_to_vector(), None);
+    }
+}
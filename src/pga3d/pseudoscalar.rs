@@ -0,0 +1,121 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::fmt;
+
+use core::ops::Neg;
+
+use num_traits::Float;
+
+use super::{PGA3DOps, scalar::Scalar};
+
+/// # 3D Projective Geometric Algebra Pseudoscalar
+/// $$I = a \mathrm{e}_{0123}$$
+/// The pseudoscalar squares to zero, so it has no Euclidean norm.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Pseudoscalar<F: Float> {
+    e0123: F,
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + fmt::Display> fmt::Display for Pseudoscalar<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Pseudoscalar {{")?;
+        write!(f, " {}e0123", self.e0123)?;
+        write!(f, " }}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F: Float + defmt::Format> defmt::Format for Pseudoscalar<F> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Pseudoscalar {{");
+        defmt::write!(f, " {}e0123", self.e0123);
+        defmt::write!(f, " }}");
+    }
+}
+
+impl<F: Float> Pseudoscalar<F> {
+    /// The zero pseudoscalar
+    pub fn zero() -> Self {
+        Self { e0123: F::zero() }
+    }
+
+    /// Create new pseudoscalar from the scaling of the unit pseudoscalar
+    pub fn new(e0123: F) -> Self {
+        Self { e0123 }
+    }
+
+    /// Get pseudoscalar
+    pub fn pseudoscalar(self) -> Self {
+        self
+    }
+
+    /// Get the $\mathrm{e}_0\mathrm{e}_1\mathrm{e}_2\mathrm{e}_3$ part of the pseudoscalar
+    pub fn e0123(&self) -> F {
+        self.e0123
+    }
+
+    /// # Dual
+    /// The dual of the pseudoscalar is a scalar
+    pub fn dual(self) -> Scalar<F> {
+        Scalar(self.e0123)
+    }
+}
+
+// Negation
+impl<F: Float> Neg for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn neg(self) -> Pseudoscalar<F> {
+        Pseudoscalar::new(-self.e0123)
+    }
+}
+
+impl<F: Float> PGA3DOps<F> for Pseudoscalar<F> {
+    fn reverse(self) -> Self {
+        self
+    }
+
+    fn conjugate(self) -> Self {
+        self
+    }
+
+    fn involute(self) -> Self {
+        self
+    }
+
+    fn norm(self) -> Scalar<F> {
+        Scalar::zero()
+    }
+
+    fn ideal_norm(self) -> Scalar<F> {
+        Scalar(self.e0123.abs())
+    }
+
+    // The pseudoscalar lies at infinity
+    fn try_normalize(self) -> Option<Self> {
+        None
+    }
+}
@@ -0,0 +1,1117 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    line::Line, motor::Motor, multivector::Multivector, plane::Plane, point::Point,
+    pseudoscalar::Pseudoscalar, scalar::Scalar,
+};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::BitAnd;
+
+// Regressive Product
+
+// Scalar-Scalar
+impl<F: Float> BitAnd for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Scalar<F>);
+
+// Scalar-Plane
+impl<F: Float> BitAnd<Plane<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Plane<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Plane<F>);
+
+// Plane-Scalar
+impl<F: Float> BitAnd<Scalar<F>> for Plane<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Plane<F>, Scalar<F>);
+
+// Scalar-Line
+impl<F: Float> BitAnd<Line<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Line<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Line<F>);
+
+// Line-Scalar
+impl<F: Float> BitAnd<Scalar<F>> for Line<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Line<F>, Scalar<F>);
+
+// Scalar-Point
+impl<F: Float> BitAnd<Point<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Point<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Point<F>);
+
+// Point-Scalar
+impl<F: Float> BitAnd<Scalar<F>> for Point<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Point<F>, Scalar<F>);
+
+// Scalar-Pseudoscalar
+impl<F: Float> BitAnd<Pseudoscalar<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Scalar<F> {
+        Scalar(self.0 * b.e0123())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Scalar
+impl<F: Float> BitAnd<Scalar<F>> for Pseudoscalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Scalar<F>) -> Scalar<F> {
+        Scalar(self.e0123() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Scalar<F>);
+
+// Scalar-Motor
+impl<F: Float> BitAnd<Motor<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Motor<F>) -> Scalar<F> {
+        Scalar(self.0 * b.e0123())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Motor<F>);
+
+// Motor-Scalar
+impl<F: Float> BitAnd<Scalar<F>> for Motor<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Scalar<F>) -> Scalar<F> {
+        Scalar(self.e0123() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Motor<F>, Scalar<F>);
+
+// Scalar-Multivector
+impl<F: Float> BitAnd<Multivector<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Multivector<F>) -> Scalar<F> {
+        Scalar(self.0 * b.e0123())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Multivector<F>);
+
+// Multivector-Scalar
+impl<F: Float> BitAnd<Scalar<F>> for Multivector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Scalar<F>) -> Scalar<F> {
+        Scalar(self.e0123() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Scalar<F>);
+
+// Plane-Plane
+impl<F: Float> BitAnd for Plane<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Plane<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Plane<F>, Plane<F>);
+
+// Plane-Line
+impl<F: Float> BitAnd<Line<F>> for Plane<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Line<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Plane<F>, Line<F>);
+
+// Line-Plane
+impl<F: Float> BitAnd<Plane<F>> for Line<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Plane<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Line<F>, Plane<F>);
+
+// Plane-Point
+impl<F: Float> BitAnd<Point<F>> for Plane<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Point<F>) -> Scalar<F> {
+        Scalar(
+            -self.e0() * b.e123()
+                - self.e1() * b.e032()
+                - self.e2() * b.e013()
+                - self.e3() * b.e021(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Plane<F>, Point<F>);
+
+// Point-Plane
+impl<F: Float> BitAnd<Plane<F>> for Point<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Plane<F>) -> Scalar<F> {
+        Scalar(
+            self.e032() * b.e1()
+                + self.e013() * b.e2()
+                + self.e021() * b.e3()
+                + self.e123() * b.e0(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Point<F>, Plane<F>);
+
+// Plane-Pseudoscalar
+impl<F: Float> BitAnd<Pseudoscalar<F>> for Plane<F> {
+    type Output = Plane<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Plane<F> {
+        Plane::new(
+            self.e0() * b.e0123(),
+            self.e1() * b.e0123(),
+            self.e2() * b.e0123(),
+            self.e3() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Plane<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Plane
+impl<F: Float> BitAnd<Plane<F>> for Pseudoscalar<F> {
+    type Output = Plane<F>;
+    fn bitand(self, b: Plane<F>) -> Plane<F> {
+        Plane::new(
+            self.e0123() * b.e0(),
+            self.e0123() * b.e1(),
+            self.e0123() * b.e2(),
+            self.e0123() * b.e3(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Plane<F>);
+
+// Plane-Motor
+impl<F: Float> BitAnd<Motor<F>> for Plane<F> {
+    type Output = Plane<F>;
+    fn bitand(self, b: Motor<F>) -> Plane<F> {
+        Plane::new(
+            self.e0() * b.e0123(),
+            self.e1() * b.e0123(),
+            self.e2() * b.e0123(),
+            self.e3() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Plane<F>, Motor<F>);
+
+// Motor-Plane
+impl<F: Float> BitAnd<Plane<F>> for Motor<F> {
+    type Output = Plane<F>;
+    fn bitand(self, b: Plane<F>) -> Plane<F> {
+        Plane::new(
+            self.e0123() * b.e0(),
+            self.e0123() * b.e1(),
+            self.e0123() * b.e2(),
+            self.e0123() * b.e3(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Motor<F>, Plane<F>);
+
+// Plane-Multivector
+impl<F: Float> BitAnd<Multivector<F>> for Plane<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e0() * b.e123()
+                - self.e1() * b.e032()
+                - self.e2() * b.e013()
+                - self.e3() * b.e021(),
+            Plane::new(
+                self.e0() * b.e0123(),
+                self.e1() * b.e0123(),
+                self.e2() * b.e0123(),
+                self.e3() * b.e0123(),
+            ),
+            Line::zero(),
+            Point::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Plane<F>, Multivector<F>);
+
+// Multivector-Plane
+impl<F: Float> BitAnd<Plane<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Plane<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e032() * b.e1()
+                + self.e013() * b.e2()
+                + self.e021() * b.e3()
+                + self.e123() * b.e0(),
+            Plane::new(
+                self.e0123() * b.e0(),
+                self.e0123() * b.e1(),
+                self.e0123() * b.e2(),
+                self.e0123() * b.e3(),
+            ),
+            Line::zero(),
+            Point::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Plane<F>);
+
+// Line-Line
+impl<F: Float> BitAnd for Line<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Line<F>) -> Scalar<F> {
+        Scalar(
+            self.e01() * b.e23()
+                + self.e02() * b.e31()
+                + self.e03() * b.e12()
+                + self.e12() * b.e03()
+                + self.e31() * b.e02()
+                + self.e23() * b.e01(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Line<F>, Line<F>);
+
+// Line-Point
+impl<F: Float> BitAnd<Point<F>> for Line<F> {
+    type Output = Plane<F>;
+    fn bitand(self, b: Point<F>) -> Plane<F> {
+        Plane::new(
+            self.e01() * b.e032() + self.e02() * b.e013() + self.e03() * b.e021(),
+            -self.e01() * b.e123() + self.e12() * b.e013() - self.e31() * b.e021(),
+            -self.e02() * b.e123() - self.e12() * b.e032() + self.e23() * b.e021(),
+            -self.e03() * b.e123() + self.e31() * b.e032() - self.e23() * b.e013(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Line<F>, Point<F>);
+
+// Point-Line
+impl<F: Float> BitAnd<Line<F>> for Point<F> {
+    type Output = Plane<F>;
+    fn bitand(self, b: Line<F>) -> Plane<F> {
+        Plane::new(
+            self.e032() * b.e01() + self.e013() * b.e02() + self.e021() * b.e03(),
+            self.e013() * b.e12() - self.e021() * b.e31() - self.e123() * b.e01(),
+            -self.e032() * b.e12() + self.e021() * b.e23() - self.e123() * b.e02(),
+            self.e032() * b.e31() - self.e013() * b.e23() - self.e123() * b.e03(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Point<F>, Line<F>);
+
+// Line-Pseudoscalar
+impl<F: Float> BitAnd<Pseudoscalar<F>> for Line<F> {
+    type Output = Line<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Line<F> {
+        Line::new(
+            self.e01() * b.e0123(),
+            self.e02() * b.e0123(),
+            self.e03() * b.e0123(),
+            self.e12() * b.e0123(),
+            self.e31() * b.e0123(),
+            self.e23() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Line<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Line
+impl<F: Float> BitAnd<Line<F>> for Pseudoscalar<F> {
+    type Output = Line<F>;
+    fn bitand(self, b: Line<F>) -> Line<F> {
+        Line::new(
+            self.e0123() * b.e01(),
+            self.e0123() * b.e02(),
+            self.e0123() * b.e03(),
+            self.e0123() * b.e12(),
+            self.e0123() * b.e31(),
+            self.e0123() * b.e23(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Line<F>);
+
+// Line-Motor
+impl<F: Float> BitAnd<Motor<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e01() * b.e23()
+                + self.e02() * b.e31()
+                + self.e03() * b.e12()
+                + self.e12() * b.e03()
+                + self.e31() * b.e02()
+                + self.e23() * b.e01(),
+            Plane::zero(),
+            Line::new(
+                self.e01() * b.e0123(),
+                self.e02() * b.e0123(),
+                self.e03() * b.e0123(),
+                self.e12() * b.e0123(),
+                self.e31() * b.e0123(),
+                self.e23() * b.e0123(),
+            ),
+            Point::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Line<F>, Motor<F>);
+
+// Motor-Line
+impl<F: Float> BitAnd<Line<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e01() * b.e23()
+                + self.e02() * b.e31()
+                + self.e03() * b.e12()
+                + self.e12() * b.e03()
+                + self.e31() * b.e02()
+                + self.e23() * b.e01(),
+            Plane::zero(),
+            Line::new(
+                self.e0123() * b.e01(),
+                self.e0123() * b.e02(),
+                self.e0123() * b.e03(),
+                self.e0123() * b.e12(),
+                self.e0123() * b.e31(),
+                self.e0123() * b.e23(),
+            ),
+            Point::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Motor<F>, Line<F>);
+
+// Line-Multivector
+impl<F: Float> BitAnd<Multivector<F>> for Line<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e01() * b.e23()
+                + self.e02() * b.e31()
+                + self.e03() * b.e12()
+                + self.e12() * b.e03()
+                + self.e31() * b.e02()
+                + self.e23() * b.e01(),
+            Plane::new(
+                self.e01() * b.e032() + self.e02() * b.e013() + self.e03() * b.e021(),
+                -self.e01() * b.e123() + self.e12() * b.e013() - self.e31() * b.e021(),
+                -self.e02() * b.e123() - self.e12() * b.e032() + self.e23() * b.e021(),
+                -self.e03() * b.e123() + self.e31() * b.e032() - self.e23() * b.e013(),
+            ),
+            Line::new(
+                self.e01() * b.e0123(),
+                self.e02() * b.e0123(),
+                self.e03() * b.e0123(),
+                self.e12() * b.e0123(),
+                self.e31() * b.e0123(),
+                self.e23() * b.e0123(),
+            ),
+            Point::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Line<F>, Multivector<F>);
+
+// Multivector-Line
+impl<F: Float> BitAnd<Line<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Line<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e01() * b.e23()
+                + self.e02() * b.e31()
+                + self.e03() * b.e12()
+                + self.e12() * b.e03()
+                + self.e31() * b.e02()
+                + self.e23() * b.e01(),
+            Plane::new(
+                self.e032() * b.e01() + self.e013() * b.e02() + self.e021() * b.e03(),
+                self.e013() * b.e12() - self.e021() * b.e31() - self.e123() * b.e01(),
+                -self.e032() * b.e12() + self.e021() * b.e23() - self.e123() * b.e02(),
+                self.e032() * b.e31() - self.e013() * b.e23() - self.e123() * b.e03(),
+            ),
+            Line::new(
+                self.e0123() * b.e01(),
+                self.e0123() * b.e02(),
+                self.e0123() * b.e03(),
+                self.e0123() * b.e12(),
+                self.e0123() * b.e31(),
+                self.e0123() * b.e23(),
+            ),
+            Point::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Line<F>);
+
+// Point-Point
+impl<F: Float> BitAnd for Point<F> {
+    type Output = Line<F>;
+    fn bitand(self, b: Point<F>) -> Line<F> {
+        Line::new(
+            self.e013() * b.e021() - self.e021() * b.e013(),
+            -self.e032() * b.e021() + self.e021() * b.e032(),
+            self.e032() * b.e013() - self.e013() * b.e032(),
+            -self.e021() * b.e123() + self.e123() * b.e021(),
+            -self.e013() * b.e123() + self.e123() * b.e013(),
+            -self.e032() * b.e123() + self.e123() * b.e032(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Point<F>, Point<F>);
+
+// Point-Pseudoscalar
+impl<F: Float> BitAnd<Pseudoscalar<F>> for Point<F> {
+    type Output = Point<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Point<F> {
+        Point::new_components(
+            self.e032() * b.e0123(),
+            self.e013() * b.e0123(),
+            self.e021() * b.e0123(),
+            self.e123() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Point<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Point
+impl<F: Float> BitAnd<Point<F>> for Pseudoscalar<F> {
+    type Output = Point<F>;
+    fn bitand(self, b: Point<F>) -> Point<F> {
+        Point::new_components(
+            self.e0123() * b.e032(),
+            self.e0123() * b.e013(),
+            self.e0123() * b.e021(),
+            self.e0123() * b.e123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Point<F>);
+
+// Point-Motor
+impl<F: Float> BitAnd<Motor<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(
+                self.e032() * b.e01() + self.e013() * b.e02() + self.e021() * b.e03(),
+                self.e013() * b.e12() - self.e021() * b.e31() - self.e123() * b.e01(),
+                -self.e032() * b.e12() + self.e021() * b.e23() - self.e123() * b.e02(),
+                self.e032() * b.e31() - self.e013() * b.e23() - self.e123() * b.e03(),
+            ),
+            Line::zero(),
+            Point::new_components(
+                self.e032() * b.e0123(),
+                self.e013() * b.e0123(),
+                self.e021() * b.e0123(),
+                self.e123() * b.e0123(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Point<F>, Motor<F>);
+
+// Motor-Point
+impl<F: Float> BitAnd<Point<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Plane::new(
+                self.e01() * b.e032() + self.e02() * b.e013() + self.e03() * b.e021(),
+                -self.e01() * b.e123() + self.e12() * b.e013() - self.e31() * b.e021(),
+                -self.e02() * b.e123() - self.e12() * b.e032() + self.e23() * b.e021(),
+                -self.e03() * b.e123() + self.e31() * b.e032() - self.e23() * b.e013(),
+            ),
+            Line::zero(),
+            Point::new_components(
+                self.e0123() * b.e032(),
+                self.e0123() * b.e013(),
+                self.e0123() * b.e021(),
+                self.e0123() * b.e123(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Motor<F>, Point<F>);
+
+// Point-Multivector
+impl<F: Float> BitAnd<Multivector<F>> for Point<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e032() * b.e1()
+                + self.e013() * b.e2()
+                + self.e021() * b.e3()
+                + self.e123() * b.e0(),
+            Plane::new(
+                self.e032() * b.e01() + self.e013() * b.e02() + self.e021() * b.e03(),
+                self.e013() * b.e12() - self.e021() * b.e31() - self.e123() * b.e01(),
+                -self.e032() * b.e12() + self.e021() * b.e23() - self.e123() * b.e02(),
+                self.e032() * b.e31() - self.e013() * b.e23() - self.e123() * b.e03(),
+            ),
+            Line::new(
+                self.e013() * b.e021() - self.e021() * b.e013(),
+                -self.e032() * b.e021() + self.e021() * b.e032(),
+                self.e032() * b.e013() - self.e013() * b.e032(),
+                -self.e021() * b.e123() + self.e123() * b.e021(),
+                -self.e013() * b.e123() + self.e123() * b.e013(),
+                -self.e032() * b.e123() + self.e123() * b.e032(),
+            ),
+            Point::new_components(
+                self.e032() * b.e0123(),
+                self.e013() * b.e0123(),
+                self.e021() * b.e0123(),
+                self.e123() * b.e0123(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Point<F>, Multivector<F>);
+
+// Multivector-Point
+impl<F: Float> BitAnd<Point<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Point<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e0() * b.e123()
+                - self.e1() * b.e032()
+                - self.e2() * b.e013()
+                - self.e3() * b.e021(),
+            Plane::new(
+                self.e01() * b.e032() + self.e02() * b.e013() + self.e03() * b.e021(),
+                -self.e01() * b.e123() + self.e12() * b.e013() - self.e31() * b.e021(),
+                -self.e02() * b.e123() - self.e12() * b.e032() + self.e23() * b.e021(),
+                -self.e03() * b.e123() + self.e31() * b.e032() - self.e23() * b.e013(),
+            ),
+            Line::new(
+                self.e013() * b.e021() - self.e021() * b.e013(),
+                -self.e032() * b.e021() + self.e021() * b.e032(),
+                self.e032() * b.e013() - self.e013() * b.e032(),
+                -self.e021() * b.e123() + self.e123() * b.e021(),
+                -self.e013() * b.e123() + self.e123() * b.e013(),
+                -self.e032() * b.e123() + self.e123() * b.e032(),
+            ),
+            Point::new_components(
+                self.e0123() * b.e032(),
+                self.e0123() * b.e013(),
+                self.e0123() * b.e021(),
+                self.e0123() * b.e123(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Point<F>);
+
+// Pseudoscalar-Pseudoscalar
+impl<F: Float> BitAnd for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.e0123() * b.e0123())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Motor
+impl<F: Float> BitAnd<Motor<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e0123() * b.scalar(),
+            Plane::zero(),
+            Line::new(
+                self.e0123() * b.e01(),
+                self.e0123() * b.e02(),
+                self.e0123() * b.e03(),
+                self.e0123() * b.e12(),
+                self.e0123() * b.e31(),
+                self.e0123() * b.e23(),
+            ),
+            Point::zero(),
+            self.e0123() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Motor<F>);
+
+// Motor-Pseudoscalar
+impl<F: Float> BitAnd<Pseudoscalar<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.e0123(),
+            Plane::zero(),
+            Line::new(
+                self.e01() * b.e0123(),
+                self.e02() * b.e0123(),
+                self.e03() * b.e0123(),
+                self.e12() * b.e0123(),
+                self.e31() * b.e0123(),
+                self.e23() * b.e0123(),
+            ),
+            Point::zero(),
+            self.e0123() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Motor<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Multivector
+impl<F: Float> BitAnd<Multivector<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e0123() * b.scalar(),
+            Plane::new(
+                self.e0123() * b.e0(),
+                self.e0123() * b.e1(),
+                self.e0123() * b.e2(),
+                self.e0123() * b.e3(),
+            ),
+            Line::new(
+                self.e0123() * b.e01(),
+                self.e0123() * b.e02(),
+                self.e0123() * b.e03(),
+                self.e0123() * b.e12(),
+                self.e0123() * b.e31(),
+                self.e0123() * b.e23(),
+            ),
+            Point::new_components(
+                self.e0123() * b.e032(),
+                self.e0123() * b.e013(),
+                self.e0123() * b.e021(),
+                self.e0123() * b.e123(),
+            ),
+            self.e0123() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Multivector<F>);
+
+// Multivector-Pseudoscalar
+impl<F: Float> BitAnd<Pseudoscalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.e0123(),
+            Plane::new(
+                self.e0() * b.e0123(),
+                self.e1() * b.e0123(),
+                self.e2() * b.e0123(),
+                self.e3() * b.e0123(),
+            ),
+            Line::new(
+                self.e01() * b.e0123(),
+                self.e02() * b.e0123(),
+                self.e03() * b.e0123(),
+                self.e12() * b.e0123(),
+                self.e31() * b.e0123(),
+                self.e23() * b.e0123(),
+            ),
+            Point::new_components(
+                self.e032() * b.e0123(),
+                self.e013() * b.e0123(),
+                self.e021() * b.e0123(),
+                self.e123() * b.e0123(),
+            ),
+            self.e0123() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Pseudoscalar<F>);
+
+// Motor-Motor
+impl<F: Float> BitAnd for Motor<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.e0123()
+                + self.e01() * b.e23()
+                + self.e02() * b.e31()
+                + self.e03() * b.e12()
+                + self.e12() * b.e03()
+                + self.e31() * b.e02()
+                + self.e23() * b.e01()
+                + self.e0123() * b.scalar(),
+            Plane::zero(),
+            Line::new(
+                self.e01() * b.e0123() + self.e0123() * b.e01(),
+                self.e02() * b.e0123() + self.e0123() * b.e02(),
+                self.e03() * b.e0123() + self.e0123() * b.e03(),
+                self.e12() * b.e0123() + self.e0123() * b.e12(),
+                self.e31() * b.e0123() + self.e0123() * b.e31(),
+                self.e23() * b.e0123() + self.e0123() * b.e23(),
+            ),
+            Point::zero(),
+            self.e0123() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Motor<F>, Motor<F>);
+
+// Motor-Multivector
+impl<F: Float> BitAnd<Multivector<F>> for Motor<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.e0123()
+                + self.e01() * b.e23()
+                + self.e02() * b.e31()
+                + self.e03() * b.e12()
+                + self.e12() * b.e03()
+                + self.e31() * b.e02()
+                + self.e23() * b.e01()
+                + self.e0123() * b.scalar(),
+            Plane::new(
+                self.e01() * b.e032()
+                    + self.e02() * b.e013()
+                    + self.e03() * b.e021()
+                    + self.e0123() * b.e0(),
+                -self.e01() * b.e123() + self.e12() * b.e013() - self.e31() * b.e021()
+                    + self.e0123() * b.e1(),
+                -self.e02() * b.e123() - self.e12() * b.e032()
+                    + self.e23() * b.e021()
+                    + self.e0123() * b.e2(),
+                -self.e03() * b.e123() + self.e31() * b.e032() - self.e23() * b.e013()
+                    + self.e0123() * b.e3(),
+            ),
+            Line::new(
+                self.e01() * b.e0123() + self.e0123() * b.e01(),
+                self.e02() * b.e0123() + self.e0123() * b.e02(),
+                self.e03() * b.e0123() + self.e0123() * b.e03(),
+                self.e12() * b.e0123() + self.e0123() * b.e12(),
+                self.e31() * b.e0123() + self.e0123() * b.e31(),
+                self.e23() * b.e0123() + self.e0123() * b.e23(),
+            ),
+            Point::new_components(
+                self.e0123() * b.e032(),
+                self.e0123() * b.e013(),
+                self.e0123() * b.e021(),
+                self.e0123() * b.e123(),
+            ),
+            self.e0123() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Motor<F>, Multivector<F>);
+
+// Multivector-Motor
+impl<F: Float> BitAnd<Motor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Motor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.e0123()
+                + self.e01() * b.e23()
+                + self.e02() * b.e31()
+                + self.e03() * b.e12()
+                + self.e12() * b.e03()
+                + self.e31() * b.e02()
+                + self.e23() * b.e01()
+                + self.e0123() * b.scalar(),
+            Plane::new(
+                self.e0() * b.e0123()
+                    + self.e032() * b.e01()
+                    + self.e013() * b.e02()
+                    + self.e021() * b.e03(),
+                self.e1() * b.e0123() + self.e013() * b.e12()
+                    - self.e021() * b.e31()
+                    - self.e123() * b.e01(),
+                self.e2() * b.e0123() - self.e032() * b.e12() + self.e021() * b.e23()
+                    - self.e123() * b.e02(),
+                self.e3() * b.e0123() + self.e032() * b.e31()
+                    - self.e013() * b.e23()
+                    - self.e123() * b.e03(),
+            ),
+            Line::new(
+                self.e01() * b.e0123() + self.e0123() * b.e01(),
+                self.e02() * b.e0123() + self.e0123() * b.e02(),
+                self.e03() * b.e0123() + self.e0123() * b.e03(),
+                self.e12() * b.e0123() + self.e0123() * b.e12(),
+                self.e31() * b.e0123() + self.e0123() * b.e31(),
+                self.e23() * b.e0123() + self.e0123() * b.e23(),
+            ),
+            Point::new_components(
+                self.e032() * b.e0123(),
+                self.e013() * b.e0123(),
+                self.e021() * b.e0123(),
+                self.e123() * b.e0123(),
+            ),
+            self.e0123() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Motor<F>);
+
+// Multivector-Multivector
+impl<F: Float> BitAnd for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.e0123()
+                - self.e0() * b.e123()
+                - self.e1() * b.e032()
+                - self.e2() * b.e013()
+                - self.e3() * b.e021()
+                + self.e01() * b.e23()
+                + self.e02() * b.e31()
+                + self.e03() * b.e12()
+                + self.e12() * b.e03()
+                + self.e31() * b.e02()
+                + self.e23() * b.e01()
+                + self.e032() * b.e1()
+                + self.e013() * b.e2()
+                + self.e021() * b.e3()
+                + self.e123() * b.e0()
+                + self.e0123() * b.scalar(),
+            Plane::new(
+                self.e0() * b.e0123()
+                    + self.e01() * b.e032()
+                    + self.e02() * b.e013()
+                    + self.e03() * b.e021()
+                    + self.e032() * b.e01()
+                    + self.e013() * b.e02()
+                    + self.e021() * b.e03()
+                    + self.e0123() * b.e0(),
+                self.e1() * b.e0123() - self.e01() * b.e123() + self.e12() * b.e013()
+                    - self.e31() * b.e021()
+                    + self.e013() * b.e12()
+                    - self.e021() * b.e31()
+                    - self.e123() * b.e01()
+                    + self.e0123() * b.e1(),
+                self.e2() * b.e0123() - self.e02() * b.e123() - self.e12() * b.e032()
+                    + self.e23() * b.e021()
+                    - self.e032() * b.e12()
+                    + self.e021() * b.e23()
+                    - self.e123() * b.e02()
+                    + self.e0123() * b.e2(),
+                self.e3() * b.e0123() - self.e03() * b.e123() + self.e31() * b.e032()
+                    - self.e23() * b.e013()
+                    + self.e032() * b.e31()
+                    - self.e013() * b.e23()
+                    - self.e123() * b.e03()
+                    + self.e0123() * b.e3(),
+            ),
+            Line::new(
+                self.e01() * b.e0123() + self.e013() * b.e021() - self.e021() * b.e013()
+                    + self.e0123() * b.e01(),
+                self.e02() * b.e0123() - self.e032() * b.e021()
+                    + self.e021() * b.e032()
+                    + self.e0123() * b.e02(),
+                self.e03() * b.e0123() + self.e032() * b.e013() - self.e013() * b.e032()
+                    + self.e0123() * b.e03(),
+                self.e12() * b.e0123() - self.e021() * b.e123()
+                    + self.e123() * b.e021()
+                    + self.e0123() * b.e12(),
+                self.e31() * b.e0123() - self.e013() * b.e123()
+                    + self.e123() * b.e013()
+                    + self.e0123() * b.e31(),
+                self.e23() * b.e0123() - self.e032() * b.e123()
+                    + self.e123() * b.e032()
+                    + self.e0123() * b.e23(),
+            ),
+            Point::new_components(
+                self.e032() * b.e0123() + self.e0123() * b.e032(),
+                self.e013() * b.e0123() + self.e0123() * b.e013(),
+                self.e021() * b.e0123() + self.e0123() * b.e021(),
+                self.e123() * b.e0123() + self.e0123() * b.e123(),
+            ),
+            self.e0123() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Multivector<F>);
+
+// Test
+#[cfg(test)]
+mod regressive_product {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn point_point() {
+        // 1e032+2e013+3e021-1e123
+        let point1 = Point::new_components(1.0, 2.0, 3.0, -1.0);
+        // 5e032-2e013+1e021-1e123
+        let point2 = Point::new_components(5.0, -2.0, 1.0, -1.0);
+        let res = point1 & point2;
+        // 8e01+14e02-12e03+2e12+4e31-4e23
+        assert_relative_eq!(res.e01(), 8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e02(), 14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e03(), -12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), -4.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn point_line() {
+        // -3e032-4e013+4e021-3e123
+        let point1 = Point::new_components(-3.0, -4.0, 4.0, -3.0);
+        // -2e01+4e02+5e03+4e12+1e31+4e23
+        let line2 = Line::new(-2.0, 4.0, 5.0, 4.0, 1.0, 4.0);
+        let res = point1 & line2;
+        // 10e0-26e1+40e2+28e3
+        assert_relative_eq!(res.e0(), 10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -26.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 40.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 28.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn line_point() {
+        // 4e01+2e02+5e03+2e12+1e31-2e23
+        let line1 = Line::new(4.0, 2.0, 5.0, 2.0, 1.0, -2.0);
+        // -3e032+4e013+5e021+5e123
+        let point2 = Point::new_components(-3.0, 4.0, 5.0, 5.0);
+        let res = line1 & point2;
+        // 21e0-17e1-14e2-20e3
+        assert_relative_eq!(res.e0(), 21.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -17.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), -20.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn point_plane() {
+        // -1e032-4e013+5e021-1e123
+        let point1 = Point::new_components(-1.0, -4.0, 5.0, -1.0);
+        // -1e0+3e1+2e2-2e3
+        let plane2 = Plane::new(-1.0, 3.0, 2.0, -2.0);
+        let res = point1 & plane2;
+        // -20
+        assert_relative_eq!(res.0, -20.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn line_line() {
+        // 3e01-2e02+4e03+2e12+5e31+3e23
+        let line1 = Line::new(3.0, -2.0, 4.0, 2.0, 5.0, 3.0);
+        // -2e01+3e02-4e03+4e12+4e31+1e23
+        let line2 = Line::new(-2.0, 3.0, -4.0, 4.0, 4.0, 1.0);
+        let res = line1 & line2;
+        // 12
+        assert_relative_eq!(res.0, 12.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn motor_point() {
+        // -1+5e01+2e02+4e03-1e12+2e31-2e23+1e0123
+        let motor1 = Motor::new(-1.0, Line::new(5.0, 2.0, 4.0, -1.0, 2.0, -2.0), 1.0);
+        // -3e032+2e013-1e021-1e123
+        let point2 = Point::new_components(-3.0, 2.0, -1.0, -1.0);
+        let res = motor1 & point2;
+        // -15e0+5e1+1e2+2e3-3e032+2e013-1e021-1e123
+        assert_relative_eq!(res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), -15.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e02(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e03(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e032(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e013(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e021(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_multivector() {
+        // -2-2e0+2e1+3e2+3e3+2e01+2e02+3e03-3e12+5e31-3e23-4e032-4e013-4e021-1e123-2e0123
+        let mvec1 = Multivector::new(
+            -2.0,
+            Plane::new(-2.0, 2.0, 3.0, 3.0),
+            Line::new(2.0, 2.0, 3.0, -3.0, 5.0, -3.0),
+            Point::new_components(-4.0, -4.0, -4.0, -1.0),
+            -2.0,
+        );
+        // -4+2e0+3e1-3e2+4e3+4e01+3e02-2e03-3e12+4e31+2e23-1e032-4e013+2e021+2e123+5e0123
+        let mvec2 = Multivector::new(
+            -4.0,
+            Plane::new(2.0, 3.0, -3.0, 4.0),
+            Line::new(4.0, 3.0, -2.0, -3.0, 4.0, 2.0),
+            Point::new_components(-1.0, -4.0, 2.0, 2.0),
+            5.0,
+        );
+        let res = mvec1 & mvec2;
+        // 4-38e0+34e1-9e2-26e3-22e01+16e02+31e03-3e12+29e31-10e23-18e032-12e013-24e021-9e123-10e0123
+        assert_relative_eq!(res.scalar(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), -38.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 34.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -9.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), -26.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e01(), -22.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e02(), 16.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e03(), 31.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 29.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), -10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e032(), -18.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e013(), -12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e021(), -24.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), -9.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), -10.0, max_relative = 0.000001);
+    }
+}
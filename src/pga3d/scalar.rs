@@ -0,0 +1,81 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+#![warn(missing_docs)]
+
+use core::ops::Neg;
+
+use num_traits::Float;
+
+/// # 3D Projective Geometric Algebra Scalar
+/// Wrapper for the Generic Float type
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Scalar<F: Float>(pub(super) F);
+
+impl<F: Float> Scalar<F> {
+    /// The zero scalar
+    pub fn zero() -> Self {
+        Scalar(F::zero())
+    }
+
+    /// New scalar from scalar
+    pub fn new(scalar: F) -> Self {
+        Scalar(scalar)
+    }
+
+    ///  Get scalar
+    pub fn scalar(self) -> F {
+        self.0
+    }
+
+    /// try to inverse a scalar value
+    pub fn try_inverse(self) -> Option<Scalar<F>> {
+        match self.0 {
+            scalar if scalar.is_zero() => None,
+            scalar => Some(Scalar(F::one() / scalar)),
+        }
+    }
+
+    /// # Dual
+    /// The dual of a scalar is the pseudoscalar $\mathrm{e}_{0123}$
+    pub fn dual(self) -> super::Pseudoscalar<F> {
+        super::Pseudoscalar::new(self.0)
+    }
+}
+
+// Negation
+impl<F: Float> Neg for Scalar<F> {
+    type Output = Scalar<F>;
+    fn neg(self) -> Scalar<F> {
+        Scalar(-self.0)
+    }
+}
+
+#[cfg(test)]
+mod scalar_inverse {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn scalar_inverse() {
+        let scalar = Scalar(4.0);
+        let scalar_inverse = scalar.try_inverse().unwrap();
+        assert_relative_eq!(scalar_inverse.scalar(), 0.25, max_relative = 0.000001);
+        assert_eq!(Scalar(0.0).try_inverse(), None);
+    }
+}
@@ -0,0 +1,323 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::fmt;
+
+use core::ops::Neg;
+
+use num_traits::Float;
+
+use super::{
+    Rotatable, STAOps, pseudoscalar::Pseudoscalar, rotor::Rotor, scalar::Scalar, vector::Vector,
+};
+use crate::vga3d;
+
+/// # Spacetime Algebra Bivector
+/// The bivectors of the algebra
+/// $$ F = a \gamma_1\gamma_0 + b \gamma_2\gamma_0 + c \gamma_3\gamma_0 + d \gamma_3\gamma_2 + e \gamma_1\gamma_3 + f \gamma_2\gamma_1 $$
+/// The timelike bivectors $\sigma_k = \gamma_k \gamma_0$ are the relative vectors in the rest frame of $\gamma_0$
+/// and the spacelike bivectors are $I\sigma_k$.
+/// The relative vectors square to one and $\sigma_1\sigma_2\sigma_3 = I$,
+/// so they have the same products as the vectors of [`vga3d`].
+///
+/// The electromagnetic field is a bivector
+/// $$ F = \vec{E} + I\vec{B} $$
+/// The generators of boosts and rotations are also bivectors.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Bivector<F: Float> {
+    e10: F,
+    e20: F,
+    e30: F,
+    e32: F,
+    e13: F,
+    e21: F,
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + fmt::Display> fmt::Display for Bivector<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Bivector {{")?;
+        write!(f, " {}e10", self.e10)?;
+
+        // Add the appropriate sign to the remaining components
+        for (value, name) in [
+            (self.e20, "e20"),
+            (self.e30, "e30"),
+            (self.e32, "e32"),
+            (self.e13, "e13"),
+            (self.e21, "e21"),
+        ] {
+            if value >= F::zero() {
+                write!(f, " + {}{}", value, name)?;
+            } else {
+                write!(f, " - {}{}", value.abs(), name)?;
+            }
+        }
+        write!(f, " }}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F: Float + defmt::Format> defmt::Format for Bivector<F> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Bivector {{");
+        defmt::write!(f, " {}e10", self.e10);
+
+        // Add the appropriate sign to the remaining components
+        for (value, name) in [
+            (self.e20, "e20"),
+            (self.e30, "e30"),
+            (self.e32, "e32"),
+            (self.e13, "e13"),
+            (self.e21, "e21"),
+        ] {
+            if value >= F::zero() {
+                defmt::write!(f, " + {}{}", value, name);
+            } else {
+                defmt::write!(f, " - {}{}", value.abs(), name);
+            }
+        }
+        defmt::write!(f, " }}");
+    }
+}
+
+impl<F: Float> Bivector<F> {
+    /// The zero bivector
+    pub fn zero() -> Self {
+        Self {
+            e10: F::zero(),
+            e20: F::zero(),
+            e30: F::zero(),
+            e32: F::zero(),
+            e13: F::zero(),
+            e21: F::zero(),
+        }
+    }
+
+    /// Create new bivector from linear combination of the basis blades
+    pub fn new(e10: F, e20: F, e30: F, e32: F, e13: F, e21: F) -> Self {
+        Self {
+            e10,
+            e20,
+            e30,
+            e32,
+            e13,
+            e21,
+        }
+    }
+
+    /// Create the electromagnetic field from the electric and the magnetic field in the rest frame of $\gamma_0$
+    /// $$ F = \vec{E} + I\vec{B} $$
+    pub fn from_field(electric: vga3d::Vector<F>, magnetic: vga3d::Vector<F>) -> Self {
+        Self::new(
+            electric.e1(),
+            electric.e2(),
+            electric.e3(),
+            magnetic.e1(),
+            magnetic.e2(),
+            magnetic.e3(),
+        )
+    }
+
+    /// Get the relative vector part in the rest frame of $\gamma_0$, which is the electric field
+    pub fn electric(&self) -> vga3d::Vector<F> {
+        vga3d::Vector::new(self.e10, self.e20, self.e30)
+    }
+
+    /// Get the relative bivector part in the rest frame of $\gamma_0$ as a vector, which is the magnetic field
+    pub fn magnetic(&self) -> vga3d::Vector<F> {
+        vga3d::Vector::new(self.e32, self.e13, self.e21)
+    }
+
+    /// # Spacetime split
+    /// Tries to split the bivector into the electric and the magnetic field measured by an observer
+    /// $$ F = \vec{E} + I\vec{B} $$
+    /// The observer is given by its proper velocity, which must be timelike and point to the future.
+    pub fn try_split(self, observer: Vector<F>) -> Option<(vga3d::Vector<F>, vga3d::Vector<F>)> {
+        let rest = Vector::new(F::one(), F::zero(), F::zero(), F::zero());
+        Rotor::try_from_observers(observer, rest).map(|rotor| {
+            let res = self.rotate(rotor);
+            (res.electric(), res.magnetic())
+        })
+    }
+
+    /// Get bivector
+    pub fn bivector(self) -> Self {
+        self
+    }
+
+    /// Get the $\gamma_1\gamma_0$ part
+    pub fn e10(&self) -> F {
+        self.e10
+    }
+
+    /// Get the $\gamma_2\gamma_0$ part
+    pub fn e20(&self) -> F {
+        self.e20
+    }
+
+    /// Get the $\gamma_3\gamma_0$ part
+    pub fn e30(&self) -> F {
+        self.e30
+    }
+
+    /// Get the $\gamma_3\gamma_2$ part
+    pub fn e32(&self) -> F {
+        self.e32
+    }
+
+    /// Get the $\gamma_1\gamma_3$ part
+    pub fn e13(&self) -> F {
+        self.e13
+    }
+
+    /// Get the $\gamma_2\gamma_1$ part
+    pub fn e21(&self) -> F {
+        self.e21
+    }
+
+    /// # Dual
+    /// The dual of a bivector is a bivector
+    /// $$ A^* = A I^{-1} $$
+    pub fn dual(self) -> Bivector<F> {
+        self * Pseudoscalar::new(-F::one())
+    }
+
+    /// # Undual
+    /// The inverse of the dual
+    /// $$ A = A^* I $$
+    pub fn undual(self) -> Bivector<F> {
+        self * Pseudoscalar::new(F::one())
+    }
+}
+
+// Negation
+impl<F: Float> Neg for Bivector<F> {
+    type Output = Bivector<F>;
+    fn neg(self) -> Bivector<F> {
+        Bivector::new(
+            -self.e10, -self.e20, -self.e30, -self.e32, -self.e13, -self.e21,
+        )
+    }
+}
+
+impl<F: Float> STAOps<F> for Bivector<F> {
+    fn reverse(self) -> Self {
+        -self
+    }
+
+    fn conjugate(self) -> Self {
+        -self
+    }
+
+    fn involute(self) -> Self {
+        self
+    }
+
+    fn norm(self) -> Scalar<F> {
+        Scalar(
+            (self.e32 * self.e32 + self.e13 * self.e13 + self.e21 * self.e21
+                - self.e10 * self.e10
+                - self.e20 * self.e20
+                - self.e30 * self.e30)
+                .abs()
+                .sqrt(),
+        )
+    }
+
+    fn try_normalize(self) -> Option<Self> {
+        self.norm().try_inverse().map(|norm_inverse| {
+            let norm_inverse = norm_inverse.0;
+            Bivector::new(
+                self.e10 * norm_inverse,
+                self.e20 * norm_inverse,
+                self.e30 * norm_inverse,
+                self.e32 * norm_inverse,
+                self.e13 * norm_inverse,
+                self.e21 * norm_inverse,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod bivector_ops {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn bivector_invariants() {
+        // F^2 = E^2 - B^2 + 2 E.B I
+        let field = Bivector::from_field(
+            vga3d::Vector::new(1.0, 2.0, -1.0),
+            vga3d::Vector::new(0.5, -1.0, 3.0),
+        );
+        let res = field * field;
+        assert_relative_eq!(res.scalar(), 6.0 - 10.25, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), 2.0 * -4.5, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_relative_vectors() {
+        // The relative vectors multiply like the vectors of vga3d
+        let a = vga3d::Vector::new(1.0, 2.0, -1.0);
+        let b = vga3d::Vector::new(0.5, -1.0, 3.0);
+        let zero = vga3d::Vector::zero();
+        let res = Bivector::from_field(a, zero) * Bivector::from_field(b, zero);
+        let expected = a * b;
+        assert_relative_eq!(res.scalar(), expected.scalar(), max_relative = 0.000001);
+        assert_relative_eq!(res.e32(), expected.e23(), max_relative = 0.000001);
+        assert_relative_eq!(res.e13(), expected.e31(), max_relative = 0.000001);
+        assert_relative_eq!(res.e21(), expected.e12(), max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_split() {
+        // An electric field seen by an observer moving along x
+        // E' = gamma E, B' = -gamma v x E
+        let speed = 0.6;
+        let gamma = 1.25;
+        let observer = Vector::try_from_velocity(vga3d::Vector::new(speed, 0.0, 0.0)).unwrap();
+        let field = Bivector::from_field(vga3d::Vector::new(0.0, 1.0, 0.0), vga3d::Vector::zero());
+        let (electric, magnetic) = field.try_split(observer).unwrap();
+        assert_relative_eq!(electric.e1(), 0.0, epsilon = 0.000001);
+        assert_relative_eq!(electric.e2(), gamma, max_relative = 0.000001);
+        assert_relative_eq!(electric.e3(), 0.0, epsilon = 0.000001);
+        assert_relative_eq!(magnetic.e1(), 0.0, epsilon = 0.000001);
+        assert_relative_eq!(magnetic.e2(), 0.0, epsilon = 0.000001);
+        assert_relative_eq!(magnetic.e3(), -gamma * speed, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_dual() {
+        let bivector = Bivector::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+        assert_eq!(bivector.dual().undual(), bivector);
+        // The dual of a relative vector is a relative bivector
+        assert_eq!(
+            Bivector::new(1.0, 0.0, 0.0, 0.0, 0.0, 0.0).undual(),
+            Bivector::new(0.0, 0.0, 0.0, 1.0, 0.0, 0.0)
+        );
+    }
+}
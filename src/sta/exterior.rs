@@ -0,0 +1,1114 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    bivector::Bivector, multivector::Multivector, pseudoscalar::Pseudoscalar, rotor::Rotor,
+    scalar::Scalar, trivector::Trivector, vector::Vector,
+};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::BitXor;
+
+// Exterior Product
+
+// Scalar-Scalar
+impl<F: Float> BitXor for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, b: Scalar<F>) -> Scalar<F> {
+        Scalar(self.0 * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Scalar<F>);
+
+// Scalar-Vector
+impl<F: Float> BitXor<Vector<F>> for Scalar<F> {
+    type Output = Vector<F>;
+    fn bitxor(self, b: Vector<F>) -> Vector<F> {
+        Vector::new(
+            self.0 * b.e0(),
+            self.0 * b.e1(),
+            self.0 * b.e2(),
+            self.0 * b.e3(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Vector<F>);
+
+// Vector-Scalar
+impl<F: Float> BitXor<Scalar<F>> for Vector<F> {
+    type Output = Vector<F>;
+    fn bitxor(self, b: Scalar<F>) -> Vector<F> {
+        Vector::new(
+            self.e0() * b.0,
+            self.e1() * b.0,
+            self.e2() * b.0,
+            self.e3() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Vector<F>, Scalar<F>);
+
+// Scalar-Bivector
+impl<F: Float> BitXor<Bivector<F>> for Scalar<F> {
+    type Output = Bivector<F>;
+    fn bitxor(self, b: Bivector<F>) -> Bivector<F> {
+        Bivector::new(
+            self.0 * b.e10(),
+            self.0 * b.e20(),
+            self.0 * b.e30(),
+            self.0 * b.e32(),
+            self.0 * b.e13(),
+            self.0 * b.e21(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Bivector<F>);
+
+// Bivector-Scalar
+impl<F: Float> BitXor<Scalar<F>> for Bivector<F> {
+    type Output = Bivector<F>;
+    fn bitxor(self, b: Scalar<F>) -> Bivector<F> {
+        Bivector::new(
+            self.e10() * b.0,
+            self.e20() * b.0,
+            self.e30() * b.0,
+            self.e32() * b.0,
+            self.e13() * b.0,
+            self.e21() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Bivector<F>, Scalar<F>);
+
+// Scalar-Trivector
+impl<F: Float> BitXor<Trivector<F>> for Scalar<F> {
+    type Output = Trivector<F>;
+    fn bitxor(self, b: Trivector<F>) -> Trivector<F> {
+        Trivector::new(
+            self.0 * b.e123(),
+            self.0 * b.e023(),
+            self.0 * b.e031(),
+            self.0 * b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Trivector<F>);
+
+// Trivector-Scalar
+impl<F: Float> BitXor<Scalar<F>> for Trivector<F> {
+    type Output = Trivector<F>;
+    fn bitxor(self, b: Scalar<F>) -> Trivector<F> {
+        Trivector::new(
+            self.e123() * b.0,
+            self.e023() * b.0,
+            self.e031() * b.0,
+            self.e012() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Trivector<F>, Scalar<F>);
+
+// Scalar-Pseudoscalar
+impl<F: Float> BitXor<Pseudoscalar<F>> for Scalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Pseudoscalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.0 * b.e0123())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Scalar
+impl<F: Float> BitXor<Scalar<F>> for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Scalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.e0123() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Pseudoscalar<F>, Scalar<F>);
+
+// Scalar-Rotor
+impl<F: Float> BitXor<Rotor<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 * b.scalar(),
+            Vector::zero(),
+            Bivector::new(
+                self.0 * b.e10(),
+                self.0 * b.e20(),
+                self.0 * b.e30(),
+                self.0 * b.e32(),
+                self.0 * b.e13(),
+                self.0 * b.e21(),
+            ),
+            Trivector::zero(),
+            self.0 * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Rotor<F>);
+
+// Rotor-Scalar
+impl<F: Float> BitXor<Scalar<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.0,
+            Vector::zero(),
+            Bivector::new(
+                self.e10() * b.0,
+                self.e20() * b.0,
+                self.e30() * b.0,
+                self.e32() * b.0,
+                self.e13() * b.0,
+                self.e21() * b.0,
+            ),
+            Trivector::zero(),
+            self.e0123() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Rotor<F>, Scalar<F>);
+
+// Scalar-Multivector
+impl<F: Float> BitXor<Multivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 * b.scalar(),
+            Vector::new(
+                self.0 * b.e0(),
+                self.0 * b.e1(),
+                self.0 * b.e2(),
+                self.0 * b.e3(),
+            ),
+            Bivector::new(
+                self.0 * b.e10(),
+                self.0 * b.e20(),
+                self.0 * b.e30(),
+                self.0 * b.e32(),
+                self.0 * b.e13(),
+                self.0 * b.e21(),
+            ),
+            Trivector::new(
+                self.0 * b.e123(),
+                self.0 * b.e023(),
+                self.0 * b.e031(),
+                self.0 * b.e012(),
+            ),
+            self.0 * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Multivector<F>);
+
+// Multivector-Scalar
+impl<F: Float> BitXor<Scalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.0,
+            Vector::new(
+                self.e0() * b.0,
+                self.e1() * b.0,
+                self.e2() * b.0,
+                self.e3() * b.0,
+            ),
+            Bivector::new(
+                self.e10() * b.0,
+                self.e20() * b.0,
+                self.e30() * b.0,
+                self.e32() * b.0,
+                self.e13() * b.0,
+                self.e21() * b.0,
+            ),
+            Trivector::new(
+                self.e123() * b.0,
+                self.e023() * b.0,
+                self.e031() * b.0,
+                self.e012() * b.0,
+            ),
+            self.e0123() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Scalar<F>);
+
+// Vector-Vector
+impl<F: Float> BitXor for Vector<F> {
+    type Output = Bivector<F>;
+    fn bitxor(self, b: Vector<F>) -> Bivector<F> {
+        Bivector::new(
+            -self.e0() * b.e1() + self.e1() * b.e0(),
+            -self.e0() * b.e2() + self.e2() * b.e0(),
+            -self.e0() * b.e3() + self.e3() * b.e0(),
+            -self.e2() * b.e3() + self.e3() * b.e2(),
+            self.e1() * b.e3() - self.e3() * b.e1(),
+            -self.e1() * b.e2() + self.e2() * b.e1(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Vector<F>, Vector<F>);
+
+// Vector-Bivector
+impl<F: Float> BitXor<Bivector<F>> for Vector<F> {
+    type Output = Trivector<F>;
+    fn bitxor(self, b: Bivector<F>) -> Trivector<F> {
+        Trivector::new(
+            -self.e1() * b.e32() - self.e2() * b.e13() - self.e3() * b.e21(),
+            -self.e0() * b.e32() + self.e2() * b.e30() - self.e3() * b.e20(),
+            -self.e0() * b.e13() - self.e1() * b.e30() + self.e3() * b.e10(),
+            -self.e0() * b.e21() + self.e1() * b.e20() - self.e2() * b.e10(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Vector<F>, Bivector<F>);
+
+// Bivector-Vector
+impl<F: Float> BitXor<Vector<F>> for Bivector<F> {
+    type Output = Trivector<F>;
+    fn bitxor(self, b: Vector<F>) -> Trivector<F> {
+        Trivector::new(
+            -self.e32() * b.e1() - self.e13() * b.e2() - self.e21() * b.e3(),
+            -self.e20() * b.e3() + self.e30() * b.e2() - self.e32() * b.e0(),
+            self.e10() * b.e3() - self.e30() * b.e1() - self.e13() * b.e0(),
+            -self.e10() * b.e2() + self.e20() * b.e1() - self.e21() * b.e0(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Bivector<F>, Vector<F>);
+
+// Vector-Trivector
+impl<F: Float> BitXor<Trivector<F>> for Vector<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Trivector<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(
+            self.e0() * b.e123()
+                - self.e1() * b.e023()
+                - self.e2() * b.e031()
+                - self.e3() * b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Vector<F>, Trivector<F>);
+
+// Trivector-Vector
+impl<F: Float> BitXor<Vector<F>> for Trivector<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Vector<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(
+            -self.e123() * b.e0()
+                + self.e023() * b.e1()
+                + self.e031() * b.e2()
+                + self.e012() * b.e3(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Trivector<F>, Vector<F>);
+
+// Vector-Pseudoscalar
+impl<F: Float> BitXor<Pseudoscalar<F>> for Vector<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Pseudoscalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Vector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Vector
+impl<F: Float> BitXor<Vector<F>> for Pseudoscalar<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Vector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Pseudoscalar<F>, Vector<F>);
+
+// Vector-Rotor
+impl<F: Float> BitXor<Rotor<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                self.e0() * b.scalar(),
+                self.e1() * b.scalar(),
+                self.e2() * b.scalar(),
+                self.e3() * b.scalar(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                -self.e1() * b.e32() - self.e2() * b.e13() - self.e3() * b.e21(),
+                -self.e0() * b.e32() + self.e2() * b.e30() - self.e3() * b.e20(),
+                -self.e0() * b.e13() - self.e1() * b.e30() + self.e3() * b.e10(),
+                -self.e0() * b.e21() + self.e1() * b.e20() - self.e2() * b.e10(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Vector<F>, Rotor<F>);
+
+// Rotor-Vector
+impl<F: Float> BitXor<Vector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                self.scalar() * b.e0(),
+                self.scalar() * b.e1(),
+                self.scalar() * b.e2(),
+                self.scalar() * b.e3(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                -self.e32() * b.e1() - self.e13() * b.e2() - self.e21() * b.e3(),
+                -self.e20() * b.e3() + self.e30() * b.e2() - self.e32() * b.e0(),
+                self.e10() * b.e3() - self.e30() * b.e1() - self.e13() * b.e0(),
+                -self.e10() * b.e2() + self.e20() * b.e1() - self.e21() * b.e0(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Rotor<F>, Vector<F>);
+
+// Vector-Multivector
+impl<F: Float> BitXor<Multivector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                self.e0() * b.scalar(),
+                self.e1() * b.scalar(),
+                self.e2() * b.scalar(),
+                self.e3() * b.scalar(),
+            ),
+            Bivector::new(
+                -self.e0() * b.e1() + self.e1() * b.e0(),
+                -self.e0() * b.e2() + self.e2() * b.e0(),
+                -self.e0() * b.e3() + self.e3() * b.e0(),
+                -self.e2() * b.e3() + self.e3() * b.e2(),
+                self.e1() * b.e3() - self.e3() * b.e1(),
+                -self.e1() * b.e2() + self.e2() * b.e1(),
+            ),
+            Trivector::new(
+                -self.e1() * b.e32() - self.e2() * b.e13() - self.e3() * b.e21(),
+                -self.e0() * b.e32() + self.e2() * b.e30() - self.e3() * b.e20(),
+                -self.e0() * b.e13() - self.e1() * b.e30() + self.e3() * b.e10(),
+                -self.e0() * b.e21() + self.e1() * b.e20() - self.e2() * b.e10(),
+            ),
+            self.e0() * b.e123()
+                - self.e1() * b.e023()
+                - self.e2() * b.e031()
+                - self.e3() * b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Vector<F>, Multivector<F>);
+
+// Multivector-Vector
+impl<F: Float> BitXor<Vector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                self.scalar() * b.e0(),
+                self.scalar() * b.e1(),
+                self.scalar() * b.e2(),
+                self.scalar() * b.e3(),
+            ),
+            Bivector::new(
+                -self.e0() * b.e1() + self.e1() * b.e0(),
+                -self.e0() * b.e2() + self.e2() * b.e0(),
+                -self.e0() * b.e3() + self.e3() * b.e0(),
+                -self.e2() * b.e3() + self.e3() * b.e2(),
+                self.e1() * b.e3() - self.e3() * b.e1(),
+                -self.e1() * b.e2() + self.e2() * b.e1(),
+            ),
+            Trivector::new(
+                -self.e32() * b.e1() - self.e13() * b.e2() - self.e21() * b.e3(),
+                -self.e20() * b.e3() + self.e30() * b.e2() - self.e32() * b.e0(),
+                self.e10() * b.e3() - self.e30() * b.e1() - self.e13() * b.e0(),
+                -self.e10() * b.e2() + self.e20() * b.e1() - self.e21() * b.e0(),
+            ),
+            -self.e123() * b.e0()
+                + self.e023() * b.e1()
+                + self.e031() * b.e2()
+                + self.e012() * b.e3(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Vector<F>);
+
+// Bivector-Bivector
+impl<F: Float> BitXor for Bivector<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Bivector<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(
+            self.e10() * b.e32()
+                + self.e20() * b.e13()
+                + self.e30() * b.e21()
+                + self.e32() * b.e10()
+                + self.e13() * b.e20()
+                + self.e21() * b.e30(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Bivector<F>, Bivector<F>);
+
+// Bivector-Trivector
+impl<F: Float> BitXor<Trivector<F>> for Bivector<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Trivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Bivector<F>, Trivector<F>);
+
+// Trivector-Bivector
+impl<F: Float> BitXor<Bivector<F>> for Trivector<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Bivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Trivector<F>, Bivector<F>);
+
+// Bivector-Pseudoscalar
+impl<F: Float> BitXor<Pseudoscalar<F>> for Bivector<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Pseudoscalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Bivector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Bivector
+impl<F: Float> BitXor<Bivector<F>> for Pseudoscalar<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Bivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Pseudoscalar<F>, Bivector<F>);
+
+// Bivector-Rotor
+impl<F: Float> BitXor<Rotor<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::new(
+                self.e10() * b.scalar(),
+                self.e20() * b.scalar(),
+                self.e30() * b.scalar(),
+                self.e32() * b.scalar(),
+                self.e13() * b.scalar(),
+                self.e21() * b.scalar(),
+            ),
+            Trivector::zero(),
+            self.e10() * b.e32()
+                + self.e20() * b.e13()
+                + self.e30() * b.e21()
+                + self.e32() * b.e10()
+                + self.e13() * b.e20()
+                + self.e21() * b.e30(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Bivector<F>, Rotor<F>);
+
+// Rotor-Bivector
+impl<F: Float> BitXor<Bivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::new(
+                self.scalar() * b.e10(),
+                self.scalar() * b.e20(),
+                self.scalar() * b.e30(),
+                self.scalar() * b.e32(),
+                self.scalar() * b.e13(),
+                self.scalar() * b.e21(),
+            ),
+            Trivector::zero(),
+            self.e10() * b.e32()
+                + self.e20() * b.e13()
+                + self.e30() * b.e21()
+                + self.e32() * b.e10()
+                + self.e13() * b.e20()
+                + self.e21() * b.e30(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Rotor<F>, Bivector<F>);
+
+// Bivector-Multivector
+impl<F: Float> BitXor<Multivector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::new(
+                self.e10() * b.scalar(),
+                self.e20() * b.scalar(),
+                self.e30() * b.scalar(),
+                self.e32() * b.scalar(),
+                self.e13() * b.scalar(),
+                self.e21() * b.scalar(),
+            ),
+            Trivector::new(
+                -self.e32() * b.e1() - self.e13() * b.e2() - self.e21() * b.e3(),
+                -self.e20() * b.e3() + self.e30() * b.e2() - self.e32() * b.e0(),
+                self.e10() * b.e3() - self.e30() * b.e1() - self.e13() * b.e0(),
+                -self.e10() * b.e2() + self.e20() * b.e1() - self.e21() * b.e0(),
+            ),
+            self.e10() * b.e32()
+                + self.e20() * b.e13()
+                + self.e30() * b.e21()
+                + self.e32() * b.e10()
+                + self.e13() * b.e20()
+                + self.e21() * b.e30(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Bivector<F>, Multivector<F>);
+
+// Multivector-Bivector
+impl<F: Float> BitXor<Bivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::new(
+                self.scalar() * b.e10(),
+                self.scalar() * b.e20(),
+                self.scalar() * b.e30(),
+                self.scalar() * b.e32(),
+                self.scalar() * b.e13(),
+                self.scalar() * b.e21(),
+            ),
+            Trivector::new(
+                -self.e1() * b.e32() - self.e2() * b.e13() - self.e3() * b.e21(),
+                -self.e0() * b.e32() + self.e2() * b.e30() - self.e3() * b.e20(),
+                -self.e0() * b.e13() - self.e1() * b.e30() + self.e3() * b.e10(),
+                -self.e0() * b.e21() + self.e1() * b.e20() - self.e2() * b.e10(),
+            ),
+            self.e10() * b.e32()
+                + self.e20() * b.e13()
+                + self.e30() * b.e21()
+                + self.e32() * b.e10()
+                + self.e13() * b.e20()
+                + self.e21() * b.e30(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Bivector<F>);
+
+// Trivector-Trivector
+impl<F: Float> BitXor for Trivector<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Trivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Trivector<F>, Trivector<F>);
+
+// Trivector-Pseudoscalar
+impl<F: Float> BitXor<Pseudoscalar<F>> for Trivector<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Pseudoscalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Trivector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Trivector
+impl<F: Float> BitXor<Trivector<F>> for Pseudoscalar<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Trivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Pseudoscalar<F>, Trivector<F>);
+
+// Trivector-Rotor
+impl<F: Float> BitXor<Rotor<F>> for Trivector<F> {
+    type Output = Trivector<F>;
+    fn bitxor(self, b: Rotor<F>) -> Trivector<F> {
+        Trivector::new(
+            self.e123() * b.scalar(),
+            self.e023() * b.scalar(),
+            self.e031() * b.scalar(),
+            self.e012() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Trivector<F>, Rotor<F>);
+
+// Rotor-Trivector
+impl<F: Float> BitXor<Trivector<F>> for Rotor<F> {
+    type Output = Trivector<F>;
+    fn bitxor(self, b: Trivector<F>) -> Trivector<F> {
+        Trivector::new(
+            self.scalar() * b.e123(),
+            self.scalar() * b.e023(),
+            self.scalar() * b.e031(),
+            self.scalar() * b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Rotor<F>, Trivector<F>);
+
+// Trivector-Multivector
+impl<F: Float> BitXor<Multivector<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::new(
+                self.e123() * b.scalar(),
+                self.e023() * b.scalar(),
+                self.e031() * b.scalar(),
+                self.e012() * b.scalar(),
+            ),
+            -self.e123() * b.e0()
+                + self.e023() * b.e1()
+                + self.e031() * b.e2()
+                + self.e012() * b.e3(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Trivector<F>, Multivector<F>);
+
+// Multivector-Trivector
+impl<F: Float> BitXor<Trivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::new(
+                self.scalar() * b.e123(),
+                self.scalar() * b.e023(),
+                self.scalar() * b.e031(),
+                self.scalar() * b.e012(),
+            ),
+            self.e0() * b.e123()
+                - self.e1() * b.e023()
+                - self.e2() * b.e031()
+                - self.e3() * b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Trivector<F>);
+
+// Pseudoscalar-Pseudoscalar
+impl<F: Float> BitXor for Pseudoscalar<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Pseudoscalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Pseudoscalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Rotor
+impl<F: Float> BitXor<Rotor<F>> for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Rotor<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.e0123() * b.scalar())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Pseudoscalar<F>, Rotor<F>);
+
+// Rotor-Pseudoscalar
+impl<F: Float> BitXor<Pseudoscalar<F>> for Rotor<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Pseudoscalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.scalar() * b.e0123())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Rotor<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Multivector
+impl<F: Float> BitXor<Multivector<F>> for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Multivector<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.e0123() * b.scalar())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Pseudoscalar<F>, Multivector<F>);
+
+// Multivector-Pseudoscalar
+impl<F: Float> BitXor<Pseudoscalar<F>> for Multivector<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitxor(self, b: Pseudoscalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.scalar() * b.e0123())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Pseudoscalar<F>);
+
+// Rotor-Rotor
+impl<F: Float> BitXor for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar(),
+            Vector::zero(),
+            Bivector::new(
+                self.scalar() * b.e10() + self.e10() * b.scalar(),
+                self.scalar() * b.e20() + self.e20() * b.scalar(),
+                self.scalar() * b.e30() + self.e30() * b.scalar(),
+                self.scalar() * b.e32() + self.e32() * b.scalar(),
+                self.scalar() * b.e13() + self.e13() * b.scalar(),
+                self.scalar() * b.e21() + self.e21() * b.scalar(),
+            ),
+            Trivector::zero(),
+            self.scalar() * b.e0123()
+                + self.e10() * b.e32()
+                + self.e20() * b.e13()
+                + self.e30() * b.e21()
+                + self.e32() * b.e10()
+                + self.e13() * b.e20()
+                + self.e21() * b.e30()
+                + self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Rotor<F>, Rotor<F>);
+
+// Rotor-Multivector
+impl<F: Float> BitXor<Multivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar(),
+            Vector::new(
+                self.scalar() * b.e0(),
+                self.scalar() * b.e1(),
+                self.scalar() * b.e2(),
+                self.scalar() * b.e3(),
+            ),
+            Bivector::new(
+                self.scalar() * b.e10() + self.e10() * b.scalar(),
+                self.scalar() * b.e20() + self.e20() * b.scalar(),
+                self.scalar() * b.e30() + self.e30() * b.scalar(),
+                self.scalar() * b.e32() + self.e32() * b.scalar(),
+                self.scalar() * b.e13() + self.e13() * b.scalar(),
+                self.scalar() * b.e21() + self.e21() * b.scalar(),
+            ),
+            Trivector::new(
+                self.scalar() * b.e123()
+                    - self.e32() * b.e1()
+                    - self.e13() * b.e2()
+                    - self.e21() * b.e3(),
+                self.scalar() * b.e023() - self.e20() * b.e3() + self.e30() * b.e2()
+                    - self.e32() * b.e0(),
+                self.scalar() * b.e031() + self.e10() * b.e3()
+                    - self.e30() * b.e1()
+                    - self.e13() * b.e0(),
+                self.scalar() * b.e012() - self.e10() * b.e2() + self.e20() * b.e1()
+                    - self.e21() * b.e0(),
+            ),
+            self.scalar() * b.e0123()
+                + self.e10() * b.e32()
+                + self.e20() * b.e13()
+                + self.e30() * b.e21()
+                + self.e32() * b.e10()
+                + self.e13() * b.e20()
+                + self.e21() * b.e30()
+                + self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Rotor<F>, Multivector<F>);
+
+// Multivector-Rotor
+impl<F: Float> BitXor<Rotor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar(),
+            Vector::new(
+                self.e0() * b.scalar(),
+                self.e1() * b.scalar(),
+                self.e2() * b.scalar(),
+                self.e3() * b.scalar(),
+            ),
+            Bivector::new(
+                self.scalar() * b.e10() + self.e10() * b.scalar(),
+                self.scalar() * b.e20() + self.e20() * b.scalar(),
+                self.scalar() * b.e30() + self.e30() * b.scalar(),
+                self.scalar() * b.e32() + self.e32() * b.scalar(),
+                self.scalar() * b.e13() + self.e13() * b.scalar(),
+                self.scalar() * b.e21() + self.e21() * b.scalar(),
+            ),
+            Trivector::new(
+                -self.e1() * b.e32() - self.e2() * b.e13() - self.e3() * b.e21()
+                    + self.e123() * b.scalar(),
+                -self.e0() * b.e32() + self.e2() * b.e30() - self.e3() * b.e20()
+                    + self.e023() * b.scalar(),
+                -self.e0() * b.e13() - self.e1() * b.e30()
+                    + self.e3() * b.e10()
+                    + self.e031() * b.scalar(),
+                -self.e0() * b.e21() + self.e1() * b.e20() - self.e2() * b.e10()
+                    + self.e012() * b.scalar(),
+            ),
+            self.scalar() * b.e0123()
+                + self.e10() * b.e32()
+                + self.e20() * b.e13()
+                + self.e30() * b.e21()
+                + self.e32() * b.e10()
+                + self.e13() * b.e20()
+                + self.e21() * b.e30()
+                + self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Rotor<F>);
+
+// Multivector-Multivector
+impl<F: Float> BitXor for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar(),
+            Vector::new(
+                self.scalar() * b.e0() + self.e0() * b.scalar(),
+                self.scalar() * b.e1() + self.e1() * b.scalar(),
+                self.scalar() * b.e2() + self.e2() * b.scalar(),
+                self.scalar() * b.e3() + self.e3() * b.scalar(),
+            ),
+            Bivector::new(
+                self.scalar() * b.e10() - self.e0() * b.e1()
+                    + self.e1() * b.e0()
+                    + self.e10() * b.scalar(),
+                self.scalar() * b.e20() - self.e0() * b.e2()
+                    + self.e2() * b.e0()
+                    + self.e20() * b.scalar(),
+                self.scalar() * b.e30() - self.e0() * b.e3()
+                    + self.e3() * b.e0()
+                    + self.e30() * b.scalar(),
+                self.scalar() * b.e32() - self.e2() * b.e3()
+                    + self.e3() * b.e2()
+                    + self.e32() * b.scalar(),
+                self.scalar() * b.e13() + self.e1() * b.e3() - self.e3() * b.e1()
+                    + self.e13() * b.scalar(),
+                self.scalar() * b.e21() - self.e1() * b.e2()
+                    + self.e2() * b.e1()
+                    + self.e21() * b.scalar(),
+            ),
+            Trivector::new(
+                self.scalar() * b.e123()
+                    - self.e1() * b.e32()
+                    - self.e2() * b.e13()
+                    - self.e3() * b.e21()
+                    - self.e32() * b.e1()
+                    - self.e13() * b.e2()
+                    - self.e21() * b.e3()
+                    + self.e123() * b.scalar(),
+                self.scalar() * b.e023() - self.e0() * b.e32() + self.e2() * b.e30()
+                    - self.e3() * b.e20()
+                    - self.e20() * b.e3()
+                    + self.e30() * b.e2()
+                    - self.e32() * b.e0()
+                    + self.e023() * b.scalar(),
+                self.scalar() * b.e031() - self.e0() * b.e13() - self.e1() * b.e30()
+                    + self.e3() * b.e10()
+                    + self.e10() * b.e3()
+                    - self.e30() * b.e1()
+                    - self.e13() * b.e0()
+                    + self.e031() * b.scalar(),
+                self.scalar() * b.e012() - self.e0() * b.e21() + self.e1() * b.e20()
+                    - self.e2() * b.e10()
+                    - self.e10() * b.e2()
+                    + self.e20() * b.e1()
+                    - self.e21() * b.e0()
+                    + self.e012() * b.scalar(),
+            ),
+            self.scalar() * b.e0123() + self.e0() * b.e123()
+                - self.e1() * b.e023()
+                - self.e2() * b.e031()
+                - self.e3() * b.e012()
+                + self.e10() * b.e32()
+                + self.e20() * b.e13()
+                + self.e30() * b.e21()
+                + self.e32() * b.e10()
+                + self.e13() * b.e20()
+                + self.e21() * b.e30()
+                - self.e123() * b.e0()
+                + self.e023() * b.e1()
+                + self.e031() * b.e2()
+                + self.e012() * b.e3()
+                + self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Multivector<F>);
+
+// Test
+#[cfg(test)]
+mod exterior_product {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn vector_vector() {
+        // 3e0+4e1+5e2+4e3
+        let vector1 = Vector::new(3.0, 4.0, 5.0, 4.0);
+        // -1e0+1e1-3e2-1e3
+        let vector2 = Vector::new(-1.0, 1.0, -3.0, -1.0);
+        let res = vector1 ^ vector2;
+        // -7e10+4e20-1e30-7e32-8e13+17e21
+        assert_relative_eq!(res.e10(), -7.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e30(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e32(), -7.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e13(), -8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e21(), 17.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_bivector() {
+        // 5e0-2e1-3e2+4e3
+        let vector1 = Vector::new(5.0, -2.0, -3.0, 4.0);
+        // 5e10+1e20-4e30-2e32+1e13+5e21
+        let bivector2 = Bivector::new(5.0, 1.0, -4.0, -2.0, 1.0, 5.0);
+        let res = vector1 ^ bivector2;
+        // -21e123+18e023+7e031-12e012
+        assert_relative_eq!(res.e123(), -21.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e023(), 18.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e031(), 7.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), -12.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_vector() {
+        // 2e10+4e20-1e30+4e32-4e13-4e21
+        let bivector1 = Bivector::new(2.0, 4.0, -1.0, 4.0, -4.0, -4.0);
+        // -3e0+4e1+1e2-1e3
+        let vector2 = Vector::new(-3.0, 4.0, 1.0, -1.0);
+        let res = bivector1 ^ vector2;
+        // -16e123+15e023-10e031+2e012
+        assert_relative_eq!(res.e123(), -16.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e023(), 15.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e031(), -10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), 2.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_bivector() {
+        // 2e10-2e20-4e30+2e32-4e13-3e21
+        let bivector1 = Bivector::new(2.0, -2.0, -4.0, 2.0, -4.0, -3.0);
+        // 2e10-2e20+3e30-3e32-4e13+4e21
+        let bivector2 = Bivector::new(2.0, -2.0, 3.0, -3.0, -4.0, 4.0);
+        let res = bivector1 ^ bivector2;
+        // -11e0123
+        assert_relative_eq!(res.e0123(), -11.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_trivector() {
+        // 1e0+3e1-3e2-4e3
+        let vector1 = Vector::new(1.0, 3.0, -3.0, -4.0);
+        // -3e123+1e023+3e031-3e012
+        let trivector2 = Trivector::new(-3.0, 1.0, 3.0, -3.0);
+        let res = vector1 ^ trivector2;
+        // -9e0123
+        assert_relative_eq!(res.e0123(), -9.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_vector() {
+        // 1-1e10+1e20+5e30+3e32+1e13-2e21-2e0123
+        let rotor1 = Rotor::new(1.0, Bivector::new(-1.0, 1.0, 5.0, 3.0, 1.0, -2.0), -2.0);
+        // 3e0+3e1-1e2-3e3
+        let vector2 = Vector::new(3.0, 3.0, -1.0, -3.0);
+        let res = rotor1 ^ vector2;
+        // 3e0+3e1-1e2-3e3-14e123-11e023-15e031+8e012
+        assert_relative_eq!(res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e10(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e30(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e32(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e13(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e21(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), -14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e023(), -11.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e031(), -15.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), 8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_multivector() {
+        // 4-3e0-3e1-1e2-4e3+1e10+1e20+1e30+1e32-4e13+3e21+5e123+2e023+3e031-2e012+2e0123
+        let mvec1 = Multivector::new(
+            4.0,
+            Vector::new(-3.0, -3.0, -1.0, -4.0),
+            Bivector::new(1.0, 1.0, 1.0, 1.0, -4.0, 3.0),
+            Trivector::new(5.0, 2.0, 3.0, -2.0),
+            2.0,
+        );
+        // 1-2e0+2e1+2e2-2e3+2e10+4e20-2e30+4e32-2e13-1e21+3e123+5e023-3e031-4e012-2e0123
+        let mvec2 = Multivector::new(
+            1.0,
+            Vector::new(-2.0, 2.0, 2.0, -2.0),
+            Bivector::new(2.0, 4.0, -2.0, 4.0, -2.0, -1.0),
+            Trivector::new(3.0, 5.0, -3.0, -4.0),
+            -2.0,
+        );
+        let res = mvec1 ^ mvec2;
+        // 4-11e0+5e1+7e2-12e3+21e10+25e20-5e30+7e32+2e13+3e21+35e123+58e023-41e031-25e012-14e0123
+        assert_relative_eq!(res.scalar(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), -11.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 7.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), -12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e10(), 21.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), 25.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e30(), -5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e32(), 7.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e13(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e21(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 35.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e023(), 58.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e031(), -41.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), -25.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), -14.0, max_relative = 0.000001);
+    }
+}
@@ -0,0 +1,144 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    STAOps, bivector::Bivector, multivector::Multivector, rotor::Rotor, trivector::Trivector,
+    vector::Vector,
+};
+
+use num_traits::Float;
+
+// Functions
+// Lorentz transformation
+// \[ X' = \widetilde{R} X R \]
+pub trait Rotatable<R> {
+    type Output;
+    fn rotate(self, rotor: R) -> Self::Output;
+}
+
+macro_rules! impl_rotatable {
+    ($element:ty, $output:ty, $extract:ident) => {
+        // Owned element, owned rotor
+        impl<F: Float> Rotatable<Rotor<F>> for $element {
+            type Output = $output;
+            fn rotate(self, rotor: Rotor<F>) -> Self::Output {
+                (rotor.reverse() * self * rotor).$extract()
+            }
+        }
+
+        // Owned element, reference rotor
+        impl<'r, F: Float> Rotatable<&'r Rotor<F>> for $element {
+            type Output = $output;
+            fn rotate(self, rotor: &'r Rotor<F>) -> Self::Output {
+                (rotor.reverse() * self * *rotor).$extract()
+            }
+        }
+
+        // Reference element, owned rotor
+        impl<'e, F: Float> Rotatable<Rotor<F>> for &'e $element {
+            type Output = $output;
+            fn rotate(self, rotor: Rotor<F>) -> Self::Output {
+                (rotor.reverse() * *self * rotor).$extract()
+            }
+        }
+
+        // Reference element, reference rotor
+        impl<'e, 'r, F: Float> Rotatable<&'r Rotor<F>> for &'e $element {
+            type Output = $output;
+            fn rotate(self, rotor: &'r Rotor<F>) -> Self::Output {
+                (rotor.reverse() * *self * *rotor).$extract()
+            }
+        }
+    };
+}
+
+// Usage:
+impl_rotatable!(Vector<F>, Vector<F>, vector);
+impl_rotatable!(Bivector<F>, Bivector<F>, bivector);
+impl_rotatable!(Trivector<F>, Trivector<F>, trivector);
+impl_rotatable!(Rotor<F>, Rotor<F>, rotor);
+impl_rotatable!(Multivector<F>, Multivector<F>, multivector);
+
+#[cfg(test)]
+mod lorentz_transformation {
+    use super::*;
+    use crate::vga3d;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn vector_boost() {
+        // An observer at rest gets the velocity of the boost
+        let rest = Vector::new(1.0, 0.0, 0.0, 0.0);
+        let velocity = vga3d::Vector::new(0.3, -0.2, 0.5);
+        let rotor = Rotor::try_from_velocity(velocity).unwrap();
+        let expected = Vector::try_from_velocity(velocity).unwrap();
+        for res in [
+            (&rest).rotate(&rotor),
+            (&rest).rotate(rotor),
+            rest.rotate(&rotor),
+            rest.rotate(rotor),
+        ] {
+            assert_relative_eq!(res.e0(), expected.e0(), max_relative = 0.000001);
+            assert_relative_eq!(res.e1(), expected.e1(), max_relative = 0.000001);
+            assert_relative_eq!(res.e2(), expected.e2(), max_relative = 0.000001);
+            assert_relative_eq!(res.e3(), expected.e3(), max_relative = 0.000001);
+        }
+    }
+
+    #[test]
+    fn velocity_addition() {
+        // Collinear velocities add relativistically
+        // w = (u + v) / (1 + uv)
+        let rotor = Rotor::try_from_velocity(vga3d::Vector::new(0.5, 0.0, 0.0)).unwrap()
+            * Rotor::try_from_velocity(vga3d::Vector::new(0.5, 0.0, 0.0)).unwrap();
+        let res = Vector::new(1.0, 0.0, 0.0, 0.0)
+            .rotate(rotor)
+            .try_velocity()
+            .unwrap();
+        assert_relative_eq!(res.e1(), 0.8, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_rotation() {
+        // A spatial rotation rotates the position like the 3D rotor
+        let rotor = vga3d::Rotor::new(vga3d::Bivector::new(0.3, -0.2, 0.5));
+        let position = vga3d::Vector::new(1.0, 2.0, -0.5);
+        let expected = vga3d::Rotatable::rotate(position, rotor);
+        let res = Vector::from_event(3.0, position).rotate(Rotor::from_rotor(rotor));
+        assert_relative_eq!(res.time(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), expected.e1(), max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), expected.e2(), max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), expected.e3(), max_relative = 0.000001);
+    }
+
+    #[test]
+    fn field_invariants() {
+        // The invariants of the electromagnetic field are the same in all frames
+        let field = Bivector::from_field(
+            vga3d::Vector::new(1.0, 2.0, -1.0),
+            vga3d::Vector::new(0.5, -1.0, 3.0),
+        );
+        let rotor = Rotor::try_from_velocity(vga3d::Vector::new(0.3, 0.6, -0.2)).unwrap()
+            * Rotor::from_rotor(vga3d::Rotor::new(vga3d::Bivector::new(0.1, 0.7, -0.3)));
+        let expected = field * field;
+        let res = field.rotate(rotor);
+        let res = res * res;
+        assert_relative_eq!(res.scalar(), expected.scalar(), max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), expected.e0123(), max_relative = 0.000001);
+    }
+}
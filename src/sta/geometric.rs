@@ -0,0 +1,2070 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    bivector::Bivector, multivector::Multivector, pseudoscalar::Pseudoscalar, rotor::Rotor,
+    scalar::Scalar, trivector::Trivector, vector::Vector,
+};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::Mul;
+
+// Geometric Product
+
+// Scalar-Scalar
+impl<F: Float> Mul for Scalar<F> {
+    type Output = F;
+    fn mul(self, b: Scalar<F>) -> F {
+        self.0 * b.0
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Scalar<F>);
+
+// Scalar-Vector
+impl<F: Float> Mul<Vector<F>> for Scalar<F> {
+    type Output = Vector<F>;
+    fn mul(self, b: Vector<F>) -> Vector<F> {
+        Vector::new(
+            self.0 * b.e0(),
+            self.0 * b.e1(),
+            self.0 * b.e2(),
+            self.0 * b.e3(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Vector<F>);
+
+// Vector-Scalar
+impl<F: Float> Mul<Scalar<F>> for Vector<F> {
+    type Output = Vector<F>;
+    fn mul(self, b: Scalar<F>) -> Vector<F> {
+        Vector::new(
+            self.e0() * b.0,
+            self.e1() * b.0,
+            self.e2() * b.0,
+            self.e3() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Vector<F>, Scalar<F>);
+
+// Scalar-Bivector
+impl<F: Float> Mul<Bivector<F>> for Scalar<F> {
+    type Output = Bivector<F>;
+    fn mul(self, b: Bivector<F>) -> Bivector<F> {
+        Bivector::new(
+            self.0 * b.e10(),
+            self.0 * b.e20(),
+            self.0 * b.e30(),
+            self.0 * b.e32(),
+            self.0 * b.e13(),
+            self.0 * b.e21(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Bivector<F>);
+
+// Bivector-Scalar
+impl<F: Float> Mul<Scalar<F>> for Bivector<F> {
+    type Output = Bivector<F>;
+    fn mul(self, b: Scalar<F>) -> Bivector<F> {
+        Bivector::new(
+            self.e10() * b.0,
+            self.e20() * b.0,
+            self.e30() * b.0,
+            self.e32() * b.0,
+            self.e13() * b.0,
+            self.e21() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Bivector<F>, Scalar<F>);
+
+// Scalar-Trivector
+impl<F: Float> Mul<Trivector<F>> for Scalar<F> {
+    type Output = Trivector<F>;
+    fn mul(self, b: Trivector<F>) -> Trivector<F> {
+        Trivector::new(
+            self.0 * b.e123(),
+            self.0 * b.e023(),
+            self.0 * b.e031(),
+            self.0 * b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Trivector<F>);
+
+// Trivector-Scalar
+impl<F: Float> Mul<Scalar<F>> for Trivector<F> {
+    type Output = Trivector<F>;
+    fn mul(self, b: Scalar<F>) -> Trivector<F> {
+        Trivector::new(
+            self.e123() * b.0,
+            self.e023() * b.0,
+            self.e031() * b.0,
+            self.e012() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Trivector<F>, Scalar<F>);
+
+// Scalar-Pseudoscalar
+impl<F: Float> Mul<Pseudoscalar<F>> for Scalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn mul(self, b: Pseudoscalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.0 * b.e0123())
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Scalar
+impl<F: Float> Mul<Scalar<F>> for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn mul(self, b: Scalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.e0123() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Scalar<F>);
+
+// Scalar-Rotor
+impl<F: Float> Mul<Rotor<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 * b.scalar(),
+            Vector::zero(),
+            Bivector::new(
+                self.0 * b.e10(),
+                self.0 * b.e20(),
+                self.0 * b.e30(),
+                self.0 * b.e32(),
+                self.0 * b.e13(),
+                self.0 * b.e21(),
+            ),
+            Trivector::zero(),
+            self.0 * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Rotor<F>);
+
+// Rotor-Scalar
+impl<F: Float> Mul<Scalar<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.0,
+            Vector::zero(),
+            Bivector::new(
+                self.e10() * b.0,
+                self.e20() * b.0,
+                self.e30() * b.0,
+                self.e32() * b.0,
+                self.e13() * b.0,
+                self.e21() * b.0,
+            ),
+            Trivector::zero(),
+            self.e0123() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Rotor<F>, Scalar<F>);
+
+// Scalar-Multivector
+impl<F: Float> Mul<Multivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 * b.scalar(),
+            Vector::new(
+                self.0 * b.e0(),
+                self.0 * b.e1(),
+                self.0 * b.e2(),
+                self.0 * b.e3(),
+            ),
+            Bivector::new(
+                self.0 * b.e10(),
+                self.0 * b.e20(),
+                self.0 * b.e30(),
+                self.0 * b.e32(),
+                self.0 * b.e13(),
+                self.0 * b.e21(),
+            ),
+            Trivector::new(
+                self.0 * b.e123(),
+                self.0 * b.e023(),
+                self.0 * b.e031(),
+                self.0 * b.e012(),
+            ),
+            self.0 * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Multivector<F>);
+
+// Multivector-Scalar
+impl<F: Float> Mul<Scalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.0,
+            Vector::new(
+                self.e0() * b.0,
+                self.e1() * b.0,
+                self.e2() * b.0,
+                self.e3() * b.0,
+            ),
+            Bivector::new(
+                self.e10() * b.0,
+                self.e20() * b.0,
+                self.e30() * b.0,
+                self.e32() * b.0,
+                self.e13() * b.0,
+                self.e21() * b.0,
+            ),
+            Trivector::new(
+                self.e123() * b.0,
+                self.e023() * b.0,
+                self.e031() * b.0,
+                self.e012() * b.0,
+            ),
+            self.e0123() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Scalar<F>);
+
+// Vector-Vector
+impl<F: Float> Mul for Vector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e0() * b.e0() - self.e1() * b.e1() - self.e2() * b.e2() - self.e3() * b.e3(),
+            Vector::zero(),
+            Bivector::new(
+                -self.e0() * b.e1() + self.e1() * b.e0(),
+                -self.e0() * b.e2() + self.e2() * b.e0(),
+                -self.e0() * b.e3() + self.e3() * b.e0(),
+                -self.e2() * b.e3() + self.e3() * b.e2(),
+                self.e1() * b.e3() - self.e3() * b.e1(),
+                -self.e1() * b.e2() + self.e2() * b.e1(),
+            ),
+            Trivector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Vector<F>, Vector<F>);
+
+// Vector-Bivector
+impl<F: Float> Mul<Bivector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                -self.e1() * b.e10() - self.e2() * b.e20() - self.e3() * b.e30(),
+                -self.e0() * b.e10() - self.e2() * b.e21() + self.e3() * b.e13(),
+                -self.e0() * b.e20() + self.e1() * b.e21() - self.e3() * b.e32(),
+                -self.e0() * b.e30() - self.e1() * b.e13() + self.e2() * b.e32(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                -self.e1() * b.e32() - self.e2() * b.e13() - self.e3() * b.e21(),
+                -self.e0() * b.e32() + self.e2() * b.e30() - self.e3() * b.e20(),
+                -self.e0() * b.e13() - self.e1() * b.e30() + self.e3() * b.e10(),
+                -self.e0() * b.e21() + self.e1() * b.e20() - self.e2() * b.e10(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Vector<F>, Bivector<F>);
+
+// Bivector-Vector
+impl<F: Float> Mul<Vector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                self.e10() * b.e1() + self.e20() * b.e2() + self.e30() * b.e3(),
+                self.e10() * b.e0() - self.e13() * b.e3() + self.e21() * b.e2(),
+                self.e20() * b.e0() + self.e32() * b.e3() - self.e21() * b.e1(),
+                self.e30() * b.e0() - self.e32() * b.e2() + self.e13() * b.e1(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                -self.e32() * b.e1() - self.e13() * b.e2() - self.e21() * b.e3(),
+                -self.e20() * b.e3() + self.e30() * b.e2() - self.e32() * b.e0(),
+                self.e10() * b.e3() - self.e30() * b.e1() - self.e13() * b.e0(),
+                -self.e10() * b.e2() + self.e20() * b.e1() - self.e21() * b.e0(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Bivector<F>, Vector<F>);
+
+// Vector-Trivector
+impl<F: Float> Mul<Trivector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::new(
+                self.e2() * b.e012() - self.e3() * b.e031(),
+                -self.e1() * b.e012() + self.e3() * b.e023(),
+                self.e1() * b.e031() - self.e2() * b.e023(),
+                -self.e0() * b.e023() + self.e1() * b.e123(),
+                -self.e0() * b.e031() + self.e2() * b.e123(),
+                -self.e0() * b.e012() + self.e3() * b.e123(),
+            ),
+            Trivector::zero(),
+            self.e0() * b.e123()
+                - self.e1() * b.e023()
+                - self.e2() * b.e031()
+                - self.e3() * b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Vector<F>, Trivector<F>);
+
+// Trivector-Vector
+impl<F: Float> Mul<Vector<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::new(
+                -self.e031() * b.e3() + self.e012() * b.e2(),
+                self.e023() * b.e3() - self.e012() * b.e1(),
+                -self.e023() * b.e2() + self.e031() * b.e1(),
+                self.e123() * b.e1() - self.e023() * b.e0(),
+                self.e123() * b.e2() - self.e031() * b.e0(),
+                self.e123() * b.e3() - self.e012() * b.e0(),
+            ),
+            Trivector::zero(),
+            -self.e123() * b.e0()
+                + self.e023() * b.e1()
+                + self.e031() * b.e2()
+                + self.e012() * b.e3(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Trivector<F>, Vector<F>);
+
+// Vector-Pseudoscalar
+impl<F: Float> Mul<Pseudoscalar<F>> for Vector<F> {
+    type Output = Trivector<F>;
+    fn mul(self, b: Pseudoscalar<F>) -> Trivector<F> {
+        Trivector::new(
+            self.e0() * b.e0123(),
+            self.e1() * b.e0123(),
+            self.e2() * b.e0123(),
+            self.e3() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Vector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Vector
+impl<F: Float> Mul<Vector<F>> for Pseudoscalar<F> {
+    type Output = Trivector<F>;
+    fn mul(self, b: Vector<F>) -> Trivector<F> {
+        Trivector::new(
+            -self.e0123() * b.e0(),
+            -self.e0123() * b.e1(),
+            -self.e0123() * b.e2(),
+            -self.e0123() * b.e3(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Vector<F>);
+
+// Vector-Rotor
+impl<F: Float> Mul<Rotor<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                self.e0() * b.scalar()
+                    - self.e1() * b.e10()
+                    - self.e2() * b.e20()
+                    - self.e3() * b.e30(),
+                -self.e0() * b.e10() + self.e1() * b.scalar() - self.e2() * b.e21()
+                    + self.e3() * b.e13(),
+                -self.e0() * b.e20() + self.e1() * b.e21() + self.e2() * b.scalar()
+                    - self.e3() * b.e32(),
+                -self.e0() * b.e30() - self.e1() * b.e13()
+                    + self.e2() * b.e32()
+                    + self.e3() * b.scalar(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                self.e0() * b.e0123()
+                    - self.e1() * b.e32()
+                    - self.e2() * b.e13()
+                    - self.e3() * b.e21(),
+                -self.e0() * b.e32() + self.e1() * b.e0123() + self.e2() * b.e30()
+                    - self.e3() * b.e20(),
+                -self.e0() * b.e13() - self.e1() * b.e30()
+                    + self.e2() * b.e0123()
+                    + self.e3() * b.e10(),
+                -self.e0() * b.e21() + self.e1() * b.e20() - self.e2() * b.e10()
+                    + self.e3() * b.e0123(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Vector<F>, Rotor<F>);
+
+// Rotor-Vector
+impl<F: Float> Mul<Vector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                self.scalar() * b.e0()
+                    + self.e10() * b.e1()
+                    + self.e20() * b.e2()
+                    + self.e30() * b.e3(),
+                self.scalar() * b.e1() + self.e10() * b.e0() - self.e13() * b.e3()
+                    + self.e21() * b.e2(),
+                self.scalar() * b.e2() + self.e20() * b.e0() + self.e32() * b.e3()
+                    - self.e21() * b.e1(),
+                self.scalar() * b.e3() + self.e30() * b.e0() - self.e32() * b.e2()
+                    + self.e13() * b.e1(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                -self.e32() * b.e1()
+                    - self.e13() * b.e2()
+                    - self.e21() * b.e3()
+                    - self.e0123() * b.e0(),
+                -self.e20() * b.e3() + self.e30() * b.e2()
+                    - self.e32() * b.e0()
+                    - self.e0123() * b.e1(),
+                self.e10() * b.e3()
+                    - self.e30() * b.e1()
+                    - self.e13() * b.e0()
+                    - self.e0123() * b.e2(),
+                -self.e10() * b.e2() + self.e20() * b.e1()
+                    - self.e21() * b.e0()
+                    - self.e0123() * b.e3(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Rotor<F>, Vector<F>);
+
+// Vector-Multivector
+impl<F: Float> Mul<Multivector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e0() * b.e0() - self.e1() * b.e1() - self.e2() * b.e2() - self.e3() * b.e3(),
+            Vector::new(
+                self.e0() * b.scalar()
+                    - self.e1() * b.e10()
+                    - self.e2() * b.e20()
+                    - self.e3() * b.e30(),
+                -self.e0() * b.e10() + self.e1() * b.scalar() - self.e2() * b.e21()
+                    + self.e3() * b.e13(),
+                -self.e0() * b.e20() + self.e1() * b.e21() + self.e2() * b.scalar()
+                    - self.e3() * b.e32(),
+                -self.e0() * b.e30() - self.e1() * b.e13()
+                    + self.e2() * b.e32()
+                    + self.e3() * b.scalar(),
+            ),
+            Bivector::new(
+                -self.e0() * b.e1() + self.e1() * b.e0() + self.e2() * b.e012()
+                    - self.e3() * b.e031(),
+                -self.e0() * b.e2() - self.e1() * b.e012()
+                    + self.e2() * b.e0()
+                    + self.e3() * b.e023(),
+                -self.e0() * b.e3() + self.e1() * b.e031() - self.e2() * b.e023()
+                    + self.e3() * b.e0(),
+                -self.e0() * b.e023() + self.e1() * b.e123() - self.e2() * b.e3()
+                    + self.e3() * b.e2(),
+                -self.e0() * b.e031() + self.e1() * b.e3() + self.e2() * b.e123()
+                    - self.e3() * b.e1(),
+                -self.e0() * b.e012() - self.e1() * b.e2()
+                    + self.e2() * b.e1()
+                    + self.e3() * b.e123(),
+            ),
+            Trivector::new(
+                self.e0() * b.e0123()
+                    - self.e1() * b.e32()
+                    - self.e2() * b.e13()
+                    - self.e3() * b.e21(),
+                -self.e0() * b.e32() + self.e1() * b.e0123() + self.e2() * b.e30()
+                    - self.e3() * b.e20(),
+                -self.e0() * b.e13() - self.e1() * b.e30()
+                    + self.e2() * b.e0123()
+                    + self.e3() * b.e10(),
+                -self.e0() * b.e21() + self.e1() * b.e20() - self.e2() * b.e10()
+                    + self.e3() * b.e0123(),
+            ),
+            self.e0() * b.e123()
+                - self.e1() * b.e023()
+                - self.e2() * b.e031()
+                - self.e3() * b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Vector<F>, Multivector<F>);
+
+// Multivector-Vector
+impl<F: Float> Mul<Vector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e0() * b.e0() - self.e1() * b.e1() - self.e2() * b.e2() - self.e3() * b.e3(),
+            Vector::new(
+                self.scalar() * b.e0()
+                    + self.e10() * b.e1()
+                    + self.e20() * b.e2()
+                    + self.e30() * b.e3(),
+                self.scalar() * b.e1() + self.e10() * b.e0() - self.e13() * b.e3()
+                    + self.e21() * b.e2(),
+                self.scalar() * b.e2() + self.e20() * b.e0() + self.e32() * b.e3()
+                    - self.e21() * b.e1(),
+                self.scalar() * b.e3() + self.e30() * b.e0() - self.e32() * b.e2()
+                    + self.e13() * b.e1(),
+            ),
+            Bivector::new(
+                -self.e0() * b.e1() + self.e1() * b.e0() - self.e031() * b.e3()
+                    + self.e012() * b.e2(),
+                -self.e0() * b.e2() + self.e2() * b.e0() + self.e023() * b.e3()
+                    - self.e012() * b.e1(),
+                -self.e0() * b.e3() + self.e3() * b.e0() - self.e023() * b.e2()
+                    + self.e031() * b.e1(),
+                -self.e2() * b.e3() + self.e3() * b.e2() + self.e123() * b.e1()
+                    - self.e023() * b.e0(),
+                self.e1() * b.e3() - self.e3() * b.e1() + self.e123() * b.e2()
+                    - self.e031() * b.e0(),
+                -self.e1() * b.e2() + self.e2() * b.e1() + self.e123() * b.e3()
+                    - self.e012() * b.e0(),
+            ),
+            Trivector::new(
+                -self.e32() * b.e1()
+                    - self.e13() * b.e2()
+                    - self.e21() * b.e3()
+                    - self.e0123() * b.e0(),
+                -self.e20() * b.e3() + self.e30() * b.e2()
+                    - self.e32() * b.e0()
+                    - self.e0123() * b.e1(),
+                self.e10() * b.e3()
+                    - self.e30() * b.e1()
+                    - self.e13() * b.e0()
+                    - self.e0123() * b.e2(),
+                -self.e10() * b.e2() + self.e20() * b.e1()
+                    - self.e21() * b.e0()
+                    - self.e0123() * b.e3(),
+            ),
+            -self.e123() * b.e0()
+                + self.e023() * b.e1()
+                + self.e031() * b.e2()
+                + self.e012() * b.e3(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Vector<F>);
+
+// Bivector-Bivector
+impl<F: Float> Mul for Bivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e10() * b.e10() + self.e20() * b.e20() + self.e30() * b.e30()
+                - self.e32() * b.e32()
+                - self.e13() * b.e13()
+                - self.e21() * b.e21(),
+            Vector::zero(),
+            Bivector::new(
+                -self.e20() * b.e21() + self.e30() * b.e13() - self.e13() * b.e30()
+                    + self.e21() * b.e20(),
+                self.e10() * b.e21() - self.e30() * b.e32() + self.e32() * b.e30()
+                    - self.e21() * b.e10(),
+                -self.e10() * b.e13() + self.e20() * b.e32() - self.e32() * b.e20()
+                    + self.e13() * b.e10(),
+                self.e20() * b.e30() - self.e30() * b.e20() - self.e13() * b.e21()
+                    + self.e21() * b.e13(),
+                -self.e10() * b.e30() + self.e30() * b.e10() + self.e32() * b.e21()
+                    - self.e21() * b.e32(),
+                self.e10() * b.e20() - self.e20() * b.e10() - self.e32() * b.e13()
+                    + self.e13() * b.e32(),
+            ),
+            Trivector::zero(),
+            self.e10() * b.e32()
+                + self.e20() * b.e13()
+                + self.e30() * b.e21()
+                + self.e32() * b.e10()
+                + self.e13() * b.e20()
+                + self.e21() * b.e30(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Bivector<F>, Bivector<F>);
+
+// Bivector-Trivector
+impl<F: Float> Mul<Trivector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                self.e32() * b.e023() + self.e13() * b.e031() + self.e21() * b.e012(),
+                self.e20() * b.e012() - self.e30() * b.e031() + self.e32() * b.e123(),
+                -self.e10() * b.e012() + self.e30() * b.e023() + self.e13() * b.e123(),
+                self.e10() * b.e031() - self.e20() * b.e023() + self.e21() * b.e123(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                self.e10() * b.e023() + self.e20() * b.e031() + self.e30() * b.e012(),
+                self.e10() * b.e123() - self.e13() * b.e012() + self.e21() * b.e031(),
+                self.e20() * b.e123() + self.e32() * b.e012() - self.e21() * b.e023(),
+                self.e30() * b.e123() - self.e32() * b.e031() + self.e13() * b.e023(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Bivector<F>, Trivector<F>);
+
+// Trivector-Bivector
+impl<F: Float> Mul<Bivector<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                self.e023() * b.e32() + self.e031() * b.e13() + self.e012() * b.e21(),
+                self.e123() * b.e32() - self.e031() * b.e30() + self.e012() * b.e20(),
+                self.e123() * b.e13() + self.e023() * b.e30() - self.e012() * b.e10(),
+                self.e123() * b.e21() - self.e023() * b.e20() + self.e031() * b.e10(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                -self.e023() * b.e10() - self.e031() * b.e20() - self.e012() * b.e30(),
+                -self.e123() * b.e10() - self.e031() * b.e21() + self.e012() * b.e13(),
+                -self.e123() * b.e20() + self.e023() * b.e21() - self.e012() * b.e32(),
+                -self.e123() * b.e30() - self.e023() * b.e13() + self.e031() * b.e32(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Trivector<F>, Bivector<F>);
+
+// Bivector-Pseudoscalar
+impl<F: Float> Mul<Pseudoscalar<F>> for Bivector<F> {
+    type Output = Bivector<F>;
+    fn mul(self, b: Pseudoscalar<F>) -> Bivector<F> {
+        Bivector::new(
+            -self.e32() * b.e0123(),
+            -self.e13() * b.e0123(),
+            -self.e21() * b.e0123(),
+            self.e10() * b.e0123(),
+            self.e20() * b.e0123(),
+            self.e30() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Bivector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Bivector
+impl<F: Float> Mul<Bivector<F>> for Pseudoscalar<F> {
+    type Output = Bivector<F>;
+    fn mul(self, b: Bivector<F>) -> Bivector<F> {
+        Bivector::new(
+            -self.e0123() * b.e32(),
+            -self.e0123() * b.e13(),
+            -self.e0123() * b.e21(),
+            self.e0123() * b.e10(),
+            self.e0123() * b.e20(),
+            self.e0123() * b.e30(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Bivector<F>);
+
+// Bivector-Rotor
+impl<F: Float> Mul<Rotor<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e10() * b.e10() + self.e20() * b.e20() + self.e30() * b.e30()
+                - self.e32() * b.e32()
+                - self.e13() * b.e13()
+                - self.e21() * b.e21(),
+            Vector::zero(),
+            Bivector::new(
+                self.e10() * b.scalar() - self.e20() * b.e21() + self.e30() * b.e13()
+                    - self.e32() * b.e0123()
+                    - self.e13() * b.e30()
+                    + self.e21() * b.e20(),
+                self.e10() * b.e21() + self.e20() * b.scalar() - self.e30() * b.e32()
+                    + self.e32() * b.e30()
+                    - self.e13() * b.e0123()
+                    - self.e21() * b.e10(),
+                -self.e10() * b.e13() + self.e20() * b.e32() + self.e30() * b.scalar()
+                    - self.e32() * b.e20()
+                    + self.e13() * b.e10()
+                    - self.e21() * b.e0123(),
+                self.e10() * b.e0123() + self.e20() * b.e30() - self.e30() * b.e20()
+                    + self.e32() * b.scalar()
+                    - self.e13() * b.e21()
+                    + self.e21() * b.e13(),
+                -self.e10() * b.e30()
+                    + self.e20() * b.e0123()
+                    + self.e30() * b.e10()
+                    + self.e32() * b.e21()
+                    + self.e13() * b.scalar()
+                    - self.e21() * b.e32(),
+                self.e10() * b.e20() - self.e20() * b.e10() + self.e30() * b.e0123()
+                    - self.e32() * b.e13()
+                    + self.e13() * b.e32()
+                    + self.e21() * b.scalar(),
+            ),
+            Trivector::zero(),
+            self.e10() * b.e32()
+                + self.e20() * b.e13()
+                + self.e30() * b.e21()
+                + self.e32() * b.e10()
+                + self.e13() * b.e20()
+                + self.e21() * b.e30(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Bivector<F>, Rotor<F>);
+
+// Rotor-Bivector
+impl<F: Float> Mul<Bivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e10() * b.e10() + self.e20() * b.e20() + self.e30() * b.e30()
+                - self.e32() * b.e32()
+                - self.e13() * b.e13()
+                - self.e21() * b.e21(),
+            Vector::zero(),
+            Bivector::new(
+                self.scalar() * b.e10() - self.e20() * b.e21() + self.e30() * b.e13()
+                    - self.e13() * b.e30()
+                    + self.e21() * b.e20()
+                    - self.e0123() * b.e32(),
+                self.scalar() * b.e20() + self.e10() * b.e21() - self.e30() * b.e32()
+                    + self.e32() * b.e30()
+                    - self.e21() * b.e10()
+                    - self.e0123() * b.e13(),
+                self.scalar() * b.e30() - self.e10() * b.e13() + self.e20() * b.e32()
+                    - self.e32() * b.e20()
+                    + self.e13() * b.e10()
+                    - self.e0123() * b.e21(),
+                self.scalar() * b.e32() + self.e20() * b.e30()
+                    - self.e30() * b.e20()
+                    - self.e13() * b.e21()
+                    + self.e21() * b.e13()
+                    + self.e0123() * b.e10(),
+                self.scalar() * b.e13() - self.e10() * b.e30()
+                    + self.e30() * b.e10()
+                    + self.e32() * b.e21()
+                    - self.e21() * b.e32()
+                    + self.e0123() * b.e20(),
+                self.scalar() * b.e21() + self.e10() * b.e20()
+                    - self.e20() * b.e10()
+                    - self.e32() * b.e13()
+                    + self.e13() * b.e32()
+                    + self.e0123() * b.e30(),
+            ),
+            Trivector::zero(),
+            self.e10() * b.e32()
+                + self.e20() * b.e13()
+                + self.e30() * b.e21()
+                + self.e32() * b.e10()
+                + self.e13() * b.e20()
+                + self.e21() * b.e30(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Rotor<F>, Bivector<F>);
+
+// Bivector-Multivector
+impl<F: Float> Mul<Multivector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e10() * b.e10() + self.e20() * b.e20() + self.e30() * b.e30()
+                - self.e32() * b.e32()
+                - self.e13() * b.e13()
+                - self.e21() * b.e21(),
+            Vector::new(
+                self.e10() * b.e1()
+                    + self.e20() * b.e2()
+                    + self.e30() * b.e3()
+                    + self.e32() * b.e023()
+                    + self.e13() * b.e031()
+                    + self.e21() * b.e012(),
+                self.e10() * b.e0() + self.e20() * b.e012() - self.e30() * b.e031()
+                    + self.e32() * b.e123()
+                    - self.e13() * b.e3()
+                    + self.e21() * b.e2(),
+                -self.e10() * b.e012()
+                    + self.e20() * b.e0()
+                    + self.e30() * b.e023()
+                    + self.e32() * b.e3()
+                    + self.e13() * b.e123()
+                    - self.e21() * b.e1(),
+                self.e10() * b.e031() - self.e20() * b.e023() + self.e30() * b.e0()
+                    - self.e32() * b.e2()
+                    + self.e13() * b.e1()
+                    + self.e21() * b.e123(),
+            ),
+            Bivector::new(
+                self.e10() * b.scalar() - self.e20() * b.e21() + self.e30() * b.e13()
+                    - self.e32() * b.e0123()
+                    - self.e13() * b.e30()
+                    + self.e21() * b.e20(),
+                self.e10() * b.e21() + self.e20() * b.scalar() - self.e30() * b.e32()
+                    + self.e32() * b.e30()
+                    - self.e13() * b.e0123()
+                    - self.e21() * b.e10(),
+                -self.e10() * b.e13() + self.e20() * b.e32() + self.e30() * b.scalar()
+                    - self.e32() * b.e20()
+                    + self.e13() * b.e10()
+                    - self.e21() * b.e0123(),
+                self.e10() * b.e0123() + self.e20() * b.e30() - self.e30() * b.e20()
+                    + self.e32() * b.scalar()
+                    - self.e13() * b.e21()
+                    + self.e21() * b.e13(),
+                -self.e10() * b.e30()
+                    + self.e20() * b.e0123()
+                    + self.e30() * b.e10()
+                    + self.e32() * b.e21()
+                    + self.e13() * b.scalar()
+                    - self.e21() * b.e32(),
+                self.e10() * b.e20() - self.e20() * b.e10() + self.e30() * b.e0123()
+                    - self.e32() * b.e13()
+                    + self.e13() * b.e32()
+                    + self.e21() * b.scalar(),
+            ),
+            Trivector::new(
+                self.e10() * b.e023() + self.e20() * b.e031() + self.e30() * b.e012()
+                    - self.e32() * b.e1()
+                    - self.e13() * b.e2()
+                    - self.e21() * b.e3(),
+                self.e10() * b.e123() - self.e20() * b.e3() + self.e30() * b.e2()
+                    - self.e32() * b.e0()
+                    - self.e13() * b.e012()
+                    + self.e21() * b.e031(),
+                self.e10() * b.e3() + self.e20() * b.e123() - self.e30() * b.e1()
+                    + self.e32() * b.e012()
+                    - self.e13() * b.e0()
+                    - self.e21() * b.e023(),
+                -self.e10() * b.e2() + self.e20() * b.e1() + self.e30() * b.e123()
+                    - self.e32() * b.e031()
+                    + self.e13() * b.e023()
+                    - self.e21() * b.e0(),
+            ),
+            self.e10() * b.e32()
+                + self.e20() * b.e13()
+                + self.e30() * b.e21()
+                + self.e32() * b.e10()
+                + self.e13() * b.e20()
+                + self.e21() * b.e30(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Bivector<F>, Multivector<F>);
+
+// Multivector-Bivector
+impl<F: Float> Mul<Bivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e10() * b.e10() + self.e20() * b.e20() + self.e30() * b.e30()
+                - self.e32() * b.e32()
+                - self.e13() * b.e13()
+                - self.e21() * b.e21(),
+            Vector::new(
+                -self.e1() * b.e10() - self.e2() * b.e20() - self.e3() * b.e30()
+                    + self.e023() * b.e32()
+                    + self.e031() * b.e13()
+                    + self.e012() * b.e21(),
+                -self.e0() * b.e10() - self.e2() * b.e21()
+                    + self.e3() * b.e13()
+                    + self.e123() * b.e32()
+                    - self.e031() * b.e30()
+                    + self.e012() * b.e20(),
+                -self.e0() * b.e20() + self.e1() * b.e21() - self.e3() * b.e32()
+                    + self.e123() * b.e13()
+                    + self.e023() * b.e30()
+                    - self.e012() * b.e10(),
+                -self.e0() * b.e30() - self.e1() * b.e13()
+                    + self.e2() * b.e32()
+                    + self.e123() * b.e21()
+                    - self.e023() * b.e20()
+                    + self.e031() * b.e10(),
+            ),
+            Bivector::new(
+                self.scalar() * b.e10() - self.e20() * b.e21() + self.e30() * b.e13()
+                    - self.e13() * b.e30()
+                    + self.e21() * b.e20()
+                    - self.e0123() * b.e32(),
+                self.scalar() * b.e20() + self.e10() * b.e21() - self.e30() * b.e32()
+                    + self.e32() * b.e30()
+                    - self.e21() * b.e10()
+                    - self.e0123() * b.e13(),
+                self.scalar() * b.e30() - self.e10() * b.e13() + self.e20() * b.e32()
+                    - self.e32() * b.e20()
+                    + self.e13() * b.e10()
+                    - self.e0123() * b.e21(),
+                self.scalar() * b.e32() + self.e20() * b.e30()
+                    - self.e30() * b.e20()
+                    - self.e13() * b.e21()
+                    + self.e21() * b.e13()
+                    + self.e0123() * b.e10(),
+                self.scalar() * b.e13() - self.e10() * b.e30()
+                    + self.e30() * b.e10()
+                    + self.e32() * b.e21()
+                    - self.e21() * b.e32()
+                    + self.e0123() * b.e20(),
+                self.scalar() * b.e21() + self.e10() * b.e20()
+                    - self.e20() * b.e10()
+                    - self.e32() * b.e13()
+                    + self.e13() * b.e32()
+                    + self.e0123() * b.e30(),
+            ),
+            Trivector::new(
+                -self.e1() * b.e32()
+                    - self.e2() * b.e13()
+                    - self.e3() * b.e21()
+                    - self.e023() * b.e10()
+                    - self.e031() * b.e20()
+                    - self.e012() * b.e30(),
+                -self.e0() * b.e32() + self.e2() * b.e30()
+                    - self.e3() * b.e20()
+                    - self.e123() * b.e10()
+                    - self.e031() * b.e21()
+                    + self.e012() * b.e13(),
+                -self.e0() * b.e13() - self.e1() * b.e30() + self.e3() * b.e10()
+                    - self.e123() * b.e20()
+                    + self.e023() * b.e21()
+                    - self.e012() * b.e32(),
+                -self.e0() * b.e21() + self.e1() * b.e20()
+                    - self.e2() * b.e10()
+                    - self.e123() * b.e30()
+                    - self.e023() * b.e13()
+                    + self.e031() * b.e32(),
+            ),
+            self.e10() * b.e32()
+                + self.e20() * b.e13()
+                + self.e30() * b.e21()
+                + self.e32() * b.e10()
+                + self.e13() * b.e20()
+                + self.e21() * b.e30(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Bivector<F>);
+
+// Trivector-Trivector
+impl<F: Float> Mul for Trivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e123() * b.e123()
+                - self.e023() * b.e023()
+                - self.e031() * b.e031()
+                - self.e012() * b.e012(),
+            Vector::zero(),
+            Bivector::new(
+                -self.e123() * b.e023() + self.e023() * b.e123(),
+                -self.e123() * b.e031() + self.e031() * b.e123(),
+                -self.e123() * b.e012() + self.e012() * b.e123(),
+                -self.e031() * b.e012() + self.e012() * b.e031(),
+                self.e023() * b.e012() - self.e012() * b.e023(),
+                -self.e023() * b.e031() + self.e031() * b.e023(),
+            ),
+            Trivector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Trivector<F>, Trivector<F>);
+
+// Trivector-Pseudoscalar
+impl<F: Float> Mul<Pseudoscalar<F>> for Trivector<F> {
+    type Output = Vector<F>;
+    fn mul(self, b: Pseudoscalar<F>) -> Vector<F> {
+        Vector::new(
+            -self.e123() * b.e0123(),
+            -self.e023() * b.e0123(),
+            -self.e031() * b.e0123(),
+            -self.e012() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Trivector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Trivector
+impl<F: Float> Mul<Trivector<F>> for Pseudoscalar<F> {
+    type Output = Vector<F>;
+    fn mul(self, b: Trivector<F>) -> Vector<F> {
+        Vector::new(
+            self.e0123() * b.e123(),
+            self.e0123() * b.e023(),
+            self.e0123() * b.e031(),
+            self.e0123() * b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Trivector<F>);
+
+// Trivector-Rotor
+impl<F: Float> Mul<Rotor<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                -self.e123() * b.e0123()
+                    + self.e023() * b.e32()
+                    + self.e031() * b.e13()
+                    + self.e012() * b.e21(),
+                self.e123() * b.e32() - self.e023() * b.e0123() - self.e031() * b.e30()
+                    + self.e012() * b.e20(),
+                self.e123() * b.e13() + self.e023() * b.e30()
+                    - self.e031() * b.e0123()
+                    - self.e012() * b.e10(),
+                self.e123() * b.e21() - self.e023() * b.e20() + self.e031() * b.e10()
+                    - self.e012() * b.e0123(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                self.e123() * b.scalar()
+                    - self.e023() * b.e10()
+                    - self.e031() * b.e20()
+                    - self.e012() * b.e30(),
+                -self.e123() * b.e10() + self.e023() * b.scalar() - self.e031() * b.e21()
+                    + self.e012() * b.e13(),
+                -self.e123() * b.e20() + self.e023() * b.e21() + self.e031() * b.scalar()
+                    - self.e012() * b.e32(),
+                -self.e123() * b.e30() - self.e023() * b.e13()
+                    + self.e031() * b.e32()
+                    + self.e012() * b.scalar(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Trivector<F>, Rotor<F>);
+
+// Rotor-Trivector
+impl<F: Float> Mul<Trivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                self.e32() * b.e023()
+                    + self.e13() * b.e031()
+                    + self.e21() * b.e012()
+                    + self.e0123() * b.e123(),
+                self.e20() * b.e012() - self.e30() * b.e031()
+                    + self.e32() * b.e123()
+                    + self.e0123() * b.e023(),
+                -self.e10() * b.e012()
+                    + self.e30() * b.e023()
+                    + self.e13() * b.e123()
+                    + self.e0123() * b.e031(),
+                self.e10() * b.e031() - self.e20() * b.e023()
+                    + self.e21() * b.e123()
+                    + self.e0123() * b.e012(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                self.scalar() * b.e123()
+                    + self.e10() * b.e023()
+                    + self.e20() * b.e031()
+                    + self.e30() * b.e012(),
+                self.scalar() * b.e023() + self.e10() * b.e123() - self.e13() * b.e012()
+                    + self.e21() * b.e031(),
+                self.scalar() * b.e031() + self.e20() * b.e123() + self.e32() * b.e012()
+                    - self.e21() * b.e023(),
+                self.scalar() * b.e012() + self.e30() * b.e123() - self.e32() * b.e031()
+                    + self.e13() * b.e023(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Rotor<F>, Trivector<F>);
+
+// Trivector-Multivector
+impl<F: Float> Mul<Multivector<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e123() * b.e123()
+                - self.e023() * b.e023()
+                - self.e031() * b.e031()
+                - self.e012() * b.e012(),
+            Vector::new(
+                -self.e123() * b.e0123()
+                    + self.e023() * b.e32()
+                    + self.e031() * b.e13()
+                    + self.e012() * b.e21(),
+                self.e123() * b.e32() - self.e023() * b.e0123() - self.e031() * b.e30()
+                    + self.e012() * b.e20(),
+                self.e123() * b.e13() + self.e023() * b.e30()
+                    - self.e031() * b.e0123()
+                    - self.e012() * b.e10(),
+                self.e123() * b.e21() - self.e023() * b.e20() + self.e031() * b.e10()
+                    - self.e012() * b.e0123(),
+            ),
+            Bivector::new(
+                -self.e123() * b.e023() + self.e023() * b.e123() - self.e031() * b.e3()
+                    + self.e012() * b.e2(),
+                -self.e123() * b.e031() + self.e023() * b.e3() + self.e031() * b.e123()
+                    - self.e012() * b.e1(),
+                -self.e123() * b.e012() - self.e023() * b.e2()
+                    + self.e031() * b.e1()
+                    + self.e012() * b.e123(),
+                self.e123() * b.e1() - self.e023() * b.e0() - self.e031() * b.e012()
+                    + self.e012() * b.e031(),
+                self.e123() * b.e2() + self.e023() * b.e012()
+                    - self.e031() * b.e0()
+                    - self.e012() * b.e023(),
+                self.e123() * b.e3() - self.e023() * b.e031() + self.e031() * b.e023()
+                    - self.e012() * b.e0(),
+            ),
+            Trivector::new(
+                self.e123() * b.scalar()
+                    - self.e023() * b.e10()
+                    - self.e031() * b.e20()
+                    - self.e012() * b.e30(),
+                -self.e123() * b.e10() + self.e023() * b.scalar() - self.e031() * b.e21()
+                    + self.e012() * b.e13(),
+                -self.e123() * b.e20() + self.e023() * b.e21() + self.e031() * b.scalar()
+                    - self.e012() * b.e32(),
+                -self.e123() * b.e30() - self.e023() * b.e13()
+                    + self.e031() * b.e32()
+                    + self.e012() * b.scalar(),
+            ),
+            -self.e123() * b.e0()
+                + self.e023() * b.e1()
+                + self.e031() * b.e2()
+                + self.e012() * b.e3(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Trivector<F>, Multivector<F>);
+
+// Multivector-Trivector
+impl<F: Float> Mul<Trivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e123() * b.e123()
+                - self.e023() * b.e023()
+                - self.e031() * b.e031()
+                - self.e012() * b.e012(),
+            Vector::new(
+                self.e32() * b.e023()
+                    + self.e13() * b.e031()
+                    + self.e21() * b.e012()
+                    + self.e0123() * b.e123(),
+                self.e20() * b.e012() - self.e30() * b.e031()
+                    + self.e32() * b.e123()
+                    + self.e0123() * b.e023(),
+                -self.e10() * b.e012()
+                    + self.e30() * b.e023()
+                    + self.e13() * b.e123()
+                    + self.e0123() * b.e031(),
+                self.e10() * b.e031() - self.e20() * b.e023()
+                    + self.e21() * b.e123()
+                    + self.e0123() * b.e012(),
+            ),
+            Bivector::new(
+                self.e2() * b.e012() - self.e3() * b.e031() - self.e123() * b.e023()
+                    + self.e023() * b.e123(),
+                -self.e1() * b.e012() + self.e3() * b.e023() - self.e123() * b.e031()
+                    + self.e031() * b.e123(),
+                self.e1() * b.e031() - self.e2() * b.e023() - self.e123() * b.e012()
+                    + self.e012() * b.e123(),
+                -self.e0() * b.e023() + self.e1() * b.e123() - self.e031() * b.e012()
+                    + self.e012() * b.e031(),
+                -self.e0() * b.e031() + self.e2() * b.e123() + self.e023() * b.e012()
+                    - self.e012() * b.e023(),
+                -self.e0() * b.e012() + self.e3() * b.e123() - self.e023() * b.e031()
+                    + self.e031() * b.e023(),
+            ),
+            Trivector::new(
+                self.scalar() * b.e123()
+                    + self.e10() * b.e023()
+                    + self.e20() * b.e031()
+                    + self.e30() * b.e012(),
+                self.scalar() * b.e023() + self.e10() * b.e123() - self.e13() * b.e012()
+                    + self.e21() * b.e031(),
+                self.scalar() * b.e031() + self.e20() * b.e123() + self.e32() * b.e012()
+                    - self.e21() * b.e023(),
+                self.scalar() * b.e012() + self.e30() * b.e123() - self.e32() * b.e031()
+                    + self.e13() * b.e023(),
+            ),
+            self.e0() * b.e123()
+                - self.e1() * b.e023()
+                - self.e2() * b.e031()
+                - self.e3() * b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Trivector<F>);
+
+// Pseudoscalar-Pseudoscalar
+impl<F: Float> Mul for Pseudoscalar<F> {
+    type Output = Scalar<F>;
+    fn mul(self, b: Pseudoscalar<F>) -> Scalar<F> {
+        Scalar(-self.e0123() * b.e0123())
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Rotor
+impl<F: Float> Mul<Rotor<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e0123() * b.e0123(),
+            Vector::zero(),
+            Bivector::new(
+                -self.e0123() * b.e32(),
+                -self.e0123() * b.e13(),
+                -self.e0123() * b.e21(),
+                self.e0123() * b.e10(),
+                self.e0123() * b.e20(),
+                self.e0123() * b.e30(),
+            ),
+            Trivector::zero(),
+            self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Rotor<F>);
+
+// Rotor-Pseudoscalar
+impl<F: Float> Mul<Pseudoscalar<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e0123() * b.e0123(),
+            Vector::zero(),
+            Bivector::new(
+                -self.e32() * b.e0123(),
+                -self.e13() * b.e0123(),
+                -self.e21() * b.e0123(),
+                self.e10() * b.e0123(),
+                self.e20() * b.e0123(),
+                self.e30() * b.e0123(),
+            ),
+            Trivector::zero(),
+            self.scalar() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Rotor<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Multivector
+impl<F: Float> Mul<Multivector<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e0123() * b.e0123(),
+            Vector::new(
+                self.e0123() * b.e123(),
+                self.e0123() * b.e023(),
+                self.e0123() * b.e031(),
+                self.e0123() * b.e012(),
+            ),
+            Bivector::new(
+                -self.e0123() * b.e32(),
+                -self.e0123() * b.e13(),
+                -self.e0123() * b.e21(),
+                self.e0123() * b.e10(),
+                self.e0123() * b.e20(),
+                self.e0123() * b.e30(),
+            ),
+            Trivector::new(
+                -self.e0123() * b.e0(),
+                -self.e0123() * b.e1(),
+                -self.e0123() * b.e2(),
+                -self.e0123() * b.e3(),
+            ),
+            self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Pseudoscalar<F>, Multivector<F>);
+
+// Multivector-Pseudoscalar
+impl<F: Float> Mul<Pseudoscalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e0123() * b.e0123(),
+            Vector::new(
+                -self.e123() * b.e0123(),
+                -self.e023() * b.e0123(),
+                -self.e031() * b.e0123(),
+                -self.e012() * b.e0123(),
+            ),
+            Bivector::new(
+                -self.e32() * b.e0123(),
+                -self.e13() * b.e0123(),
+                -self.e21() * b.e0123(),
+                self.e10() * b.e0123(),
+                self.e20() * b.e0123(),
+                self.e30() * b.e0123(),
+            ),
+            Trivector::new(
+                self.e0() * b.e0123(),
+                self.e1() * b.e0123(),
+                self.e2() * b.e0123(),
+                self.e3() * b.e0123(),
+            ),
+            self.scalar() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Pseudoscalar<F>);
+
+// Rotor-Multivector
+impl<F: Float> Mul<Multivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar()
+                + self.e10() * b.e10()
+                + self.e20() * b.e20()
+                + self.e30() * b.e30()
+                - self.e32() * b.e32()
+                - self.e13() * b.e13()
+                - self.e21() * b.e21()
+                - self.e0123() * b.e0123(),
+            Vector::new(
+                self.scalar() * b.e0()
+                    + self.e10() * b.e1()
+                    + self.e20() * b.e2()
+                    + self.e30() * b.e3()
+                    + self.e32() * b.e023()
+                    + self.e13() * b.e031()
+                    + self.e21() * b.e012()
+                    + self.e0123() * b.e123(),
+                self.scalar() * b.e1() + self.e10() * b.e0() + self.e20() * b.e012()
+                    - self.e30() * b.e031()
+                    + self.e32() * b.e123()
+                    - self.e13() * b.e3()
+                    + self.e21() * b.e2()
+                    + self.e0123() * b.e023(),
+                self.scalar() * b.e2() - self.e10() * b.e012()
+                    + self.e20() * b.e0()
+                    + self.e30() * b.e023()
+                    + self.e32() * b.e3()
+                    + self.e13() * b.e123()
+                    - self.e21() * b.e1()
+                    + self.e0123() * b.e031(),
+                self.scalar() * b.e3() + self.e10() * b.e031() - self.e20() * b.e023()
+                    + self.e30() * b.e0()
+                    - self.e32() * b.e2()
+                    + self.e13() * b.e1()
+                    + self.e21() * b.e123()
+                    + self.e0123() * b.e012(),
+            ),
+            Bivector::new(
+                self.scalar() * b.e10() + self.e10() * b.scalar() - self.e20() * b.e21()
+                    + self.e30() * b.e13()
+                    - self.e32() * b.e0123()
+                    - self.e13() * b.e30()
+                    + self.e21() * b.e20()
+                    - self.e0123() * b.e32(),
+                self.scalar() * b.e20() + self.e10() * b.e21() + self.e20() * b.scalar()
+                    - self.e30() * b.e32()
+                    + self.e32() * b.e30()
+                    - self.e13() * b.e0123()
+                    - self.e21() * b.e10()
+                    - self.e0123() * b.e13(),
+                self.scalar() * b.e30() - self.e10() * b.e13()
+                    + self.e20() * b.e32()
+                    + self.e30() * b.scalar()
+                    - self.e32() * b.e20()
+                    + self.e13() * b.e10()
+                    - self.e21() * b.e0123()
+                    - self.e0123() * b.e21(),
+                self.scalar() * b.e32() + self.e10() * b.e0123() + self.e20() * b.e30()
+                    - self.e30() * b.e20()
+                    + self.e32() * b.scalar()
+                    - self.e13() * b.e21()
+                    + self.e21() * b.e13()
+                    + self.e0123() * b.e10(),
+                self.scalar() * b.e13() - self.e10() * b.e30()
+                    + self.e20() * b.e0123()
+                    + self.e30() * b.e10()
+                    + self.e32() * b.e21()
+                    + self.e13() * b.scalar()
+                    - self.e21() * b.e32()
+                    + self.e0123() * b.e20(),
+                self.scalar() * b.e21() + self.e10() * b.e20() - self.e20() * b.e10()
+                    + self.e30() * b.e0123()
+                    - self.e32() * b.e13()
+                    + self.e13() * b.e32()
+                    + self.e21() * b.scalar()
+                    + self.e0123() * b.e30(),
+            ),
+            Trivector::new(
+                self.scalar() * b.e123()
+                    + self.e10() * b.e023()
+                    + self.e20() * b.e031()
+                    + self.e30() * b.e012()
+                    - self.e32() * b.e1()
+                    - self.e13() * b.e2()
+                    - self.e21() * b.e3()
+                    - self.e0123() * b.e0(),
+                self.scalar() * b.e023() + self.e10() * b.e123() - self.e20() * b.e3()
+                    + self.e30() * b.e2()
+                    - self.e32() * b.e0()
+                    - self.e13() * b.e012()
+                    + self.e21() * b.e031()
+                    - self.e0123() * b.e1(),
+                self.scalar() * b.e031() + self.e10() * b.e3() + self.e20() * b.e123()
+                    - self.e30() * b.e1()
+                    + self.e32() * b.e012()
+                    - self.e13() * b.e0()
+                    - self.e21() * b.e023()
+                    - self.e0123() * b.e2(),
+                self.scalar() * b.e012() - self.e10() * b.e2()
+                    + self.e20() * b.e1()
+                    + self.e30() * b.e123()
+                    - self.e32() * b.e031()
+                    + self.e13() * b.e023()
+                    - self.e21() * b.e0()
+                    - self.e0123() * b.e3(),
+            ),
+            self.scalar() * b.e0123()
+                + self.e10() * b.e32()
+                + self.e20() * b.e13()
+                + self.e30() * b.e21()
+                + self.e32() * b.e10()
+                + self.e13() * b.e20()
+                + self.e21() * b.e30()
+                + self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Rotor<F>, Multivector<F>);
+
+// Multivector-Rotor
+impl<F: Float> Mul<Rotor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar()
+                + self.e10() * b.e10()
+                + self.e20() * b.e20()
+                + self.e30() * b.e30()
+                - self.e32() * b.e32()
+                - self.e13() * b.e13()
+                - self.e21() * b.e21()
+                - self.e0123() * b.e0123(),
+            Vector::new(
+                self.e0() * b.scalar()
+                    - self.e1() * b.e10()
+                    - self.e2() * b.e20()
+                    - self.e3() * b.e30()
+                    - self.e123() * b.e0123()
+                    + self.e023() * b.e32()
+                    + self.e031() * b.e13()
+                    + self.e012() * b.e21(),
+                -self.e0() * b.e10() + self.e1() * b.scalar() - self.e2() * b.e21()
+                    + self.e3() * b.e13()
+                    + self.e123() * b.e32()
+                    - self.e023() * b.e0123()
+                    - self.e031() * b.e30()
+                    + self.e012() * b.e20(),
+                -self.e0() * b.e20() + self.e1() * b.e21() + self.e2() * b.scalar()
+                    - self.e3() * b.e32()
+                    + self.e123() * b.e13()
+                    + self.e023() * b.e30()
+                    - self.e031() * b.e0123()
+                    - self.e012() * b.e10(),
+                -self.e0() * b.e30() - self.e1() * b.e13()
+                    + self.e2() * b.e32()
+                    + self.e3() * b.scalar()
+                    + self.e123() * b.e21()
+                    - self.e023() * b.e20()
+                    + self.e031() * b.e10()
+                    - self.e012() * b.e0123(),
+            ),
+            Bivector::new(
+                self.scalar() * b.e10() + self.e10() * b.scalar() - self.e20() * b.e21()
+                    + self.e30() * b.e13()
+                    - self.e32() * b.e0123()
+                    - self.e13() * b.e30()
+                    + self.e21() * b.e20()
+                    - self.e0123() * b.e32(),
+                self.scalar() * b.e20() + self.e10() * b.e21() + self.e20() * b.scalar()
+                    - self.e30() * b.e32()
+                    + self.e32() * b.e30()
+                    - self.e13() * b.e0123()
+                    - self.e21() * b.e10()
+                    - self.e0123() * b.e13(),
+                self.scalar() * b.e30() - self.e10() * b.e13()
+                    + self.e20() * b.e32()
+                    + self.e30() * b.scalar()
+                    - self.e32() * b.e20()
+                    + self.e13() * b.e10()
+                    - self.e21() * b.e0123()
+                    - self.e0123() * b.e21(),
+                self.scalar() * b.e32() + self.e10() * b.e0123() + self.e20() * b.e30()
+                    - self.e30() * b.e20()
+                    + self.e32() * b.scalar()
+                    - self.e13() * b.e21()
+                    + self.e21() * b.e13()
+                    + self.e0123() * b.e10(),
+                self.scalar() * b.e13() - self.e10() * b.e30()
+                    + self.e20() * b.e0123()
+                    + self.e30() * b.e10()
+                    + self.e32() * b.e21()
+                    + self.e13() * b.scalar()
+                    - self.e21() * b.e32()
+                    + self.e0123() * b.e20(),
+                self.scalar() * b.e21() + self.e10() * b.e20() - self.e20() * b.e10()
+                    + self.e30() * b.e0123()
+                    - self.e32() * b.e13()
+                    + self.e13() * b.e32()
+                    + self.e21() * b.scalar()
+                    + self.e0123() * b.e30(),
+            ),
+            Trivector::new(
+                self.e0() * b.e0123()
+                    - self.e1() * b.e32()
+                    - self.e2() * b.e13()
+                    - self.e3() * b.e21()
+                    + self.e123() * b.scalar()
+                    - self.e023() * b.e10()
+                    - self.e031() * b.e20()
+                    - self.e012() * b.e30(),
+                -self.e0() * b.e32() + self.e1() * b.e0123() + self.e2() * b.e30()
+                    - self.e3() * b.e20()
+                    - self.e123() * b.e10()
+                    + self.e023() * b.scalar()
+                    - self.e031() * b.e21()
+                    + self.e012() * b.e13(),
+                -self.e0() * b.e13() - self.e1() * b.e30()
+                    + self.e2() * b.e0123()
+                    + self.e3() * b.e10()
+                    - self.e123() * b.e20()
+                    + self.e023() * b.e21()
+                    + self.e031() * b.scalar()
+                    - self.e012() * b.e32(),
+                -self.e0() * b.e21() + self.e1() * b.e20() - self.e2() * b.e10()
+                    + self.e3() * b.e0123()
+                    - self.e123() * b.e30()
+                    - self.e023() * b.e13()
+                    + self.e031() * b.e32()
+                    + self.e012() * b.scalar(),
+            ),
+            self.scalar() * b.e0123()
+                + self.e10() * b.e32()
+                + self.e20() * b.e13()
+                + self.e30() * b.e21()
+                + self.e32() * b.e10()
+                + self.e13() * b.e20()
+                + self.e21() * b.e30()
+                + self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Rotor<F>);
+
+// Multivector-Multivector
+impl<F: Float> Mul for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar() + self.e0() * b.e0()
+                - self.e1() * b.e1()
+                - self.e2() * b.e2()
+                - self.e3() * b.e3()
+                + self.e10() * b.e10()
+                + self.e20() * b.e20()
+                + self.e30() * b.e30()
+                - self.e32() * b.e32()
+                - self.e13() * b.e13()
+                - self.e21() * b.e21()
+                + self.e123() * b.e123()
+                - self.e023() * b.e023()
+                - self.e031() * b.e031()
+                - self.e012() * b.e012()
+                - self.e0123() * b.e0123(),
+            Vector::new(
+                self.scalar() * b.e0() + self.e0() * b.scalar()
+                    - self.e1() * b.e10()
+                    - self.e2() * b.e20()
+                    - self.e3() * b.e30()
+                    + self.e10() * b.e1()
+                    + self.e20() * b.e2()
+                    + self.e30() * b.e3()
+                    + self.e32() * b.e023()
+                    + self.e13() * b.e031()
+                    + self.e21() * b.e012()
+                    - self.e123() * b.e0123()
+                    + self.e023() * b.e32()
+                    + self.e031() * b.e13()
+                    + self.e012() * b.e21()
+                    + self.e0123() * b.e123(),
+                self.scalar() * b.e1() - self.e0() * b.e10() + self.e1() * b.scalar()
+                    - self.e2() * b.e21()
+                    + self.e3() * b.e13()
+                    + self.e10() * b.e0()
+                    + self.e20() * b.e012()
+                    - self.e30() * b.e031()
+                    + self.e32() * b.e123()
+                    - self.e13() * b.e3()
+                    + self.e21() * b.e2()
+                    + self.e123() * b.e32()
+                    - self.e023() * b.e0123()
+                    - self.e031() * b.e30()
+                    + self.e012() * b.e20()
+                    + self.e0123() * b.e023(),
+                self.scalar() * b.e2() - self.e0() * b.e20()
+                    + self.e1() * b.e21()
+                    + self.e2() * b.scalar()
+                    - self.e3() * b.e32()
+                    - self.e10() * b.e012()
+                    + self.e20() * b.e0()
+                    + self.e30() * b.e023()
+                    + self.e32() * b.e3()
+                    + self.e13() * b.e123()
+                    - self.e21() * b.e1()
+                    + self.e123() * b.e13()
+                    + self.e023() * b.e30()
+                    - self.e031() * b.e0123()
+                    - self.e012() * b.e10()
+                    + self.e0123() * b.e031(),
+                self.scalar() * b.e3() - self.e0() * b.e30() - self.e1() * b.e13()
+                    + self.e2() * b.e32()
+                    + self.e3() * b.scalar()
+                    + self.e10() * b.e031()
+                    - self.e20() * b.e023()
+                    + self.e30() * b.e0()
+                    - self.e32() * b.e2()
+                    + self.e13() * b.e1()
+                    + self.e21() * b.e123()
+                    + self.e123() * b.e21()
+                    - self.e023() * b.e20()
+                    + self.e031() * b.e10()
+                    - self.e012() * b.e0123()
+                    + self.e0123() * b.e012(),
+            ),
+            Bivector::new(
+                self.scalar() * b.e10() - self.e0() * b.e1()
+                    + self.e1() * b.e0()
+                    + self.e2() * b.e012()
+                    - self.e3() * b.e031()
+                    + self.e10() * b.scalar()
+                    - self.e20() * b.e21()
+                    + self.e30() * b.e13()
+                    - self.e32() * b.e0123()
+                    - self.e13() * b.e30()
+                    + self.e21() * b.e20()
+                    - self.e123() * b.e023()
+                    + self.e023() * b.e123()
+                    - self.e031() * b.e3()
+                    + self.e012() * b.e2()
+                    - self.e0123() * b.e32(),
+                self.scalar() * b.e20() - self.e0() * b.e2() - self.e1() * b.e012()
+                    + self.e2() * b.e0()
+                    + self.e3() * b.e023()
+                    + self.e10() * b.e21()
+                    + self.e20() * b.scalar()
+                    - self.e30() * b.e32()
+                    + self.e32() * b.e30()
+                    - self.e13() * b.e0123()
+                    - self.e21() * b.e10()
+                    - self.e123() * b.e031()
+                    + self.e023() * b.e3()
+                    + self.e031() * b.e123()
+                    - self.e012() * b.e1()
+                    - self.e0123() * b.e13(),
+                self.scalar() * b.e30() - self.e0() * b.e3() + self.e1() * b.e031()
+                    - self.e2() * b.e023()
+                    + self.e3() * b.e0()
+                    - self.e10() * b.e13()
+                    + self.e20() * b.e32()
+                    + self.e30() * b.scalar()
+                    - self.e32() * b.e20()
+                    + self.e13() * b.e10()
+                    - self.e21() * b.e0123()
+                    - self.e123() * b.e012()
+                    - self.e023() * b.e2()
+                    + self.e031() * b.e1()
+                    + self.e012() * b.e123()
+                    - self.e0123() * b.e21(),
+                self.scalar() * b.e32() - self.e0() * b.e023() + self.e1() * b.e123()
+                    - self.e2() * b.e3()
+                    + self.e3() * b.e2()
+                    + self.e10() * b.e0123()
+                    + self.e20() * b.e30()
+                    - self.e30() * b.e20()
+                    + self.e32() * b.scalar()
+                    - self.e13() * b.e21()
+                    + self.e21() * b.e13()
+                    + self.e123() * b.e1()
+                    - self.e023() * b.e0()
+                    - self.e031() * b.e012()
+                    + self.e012() * b.e031()
+                    + self.e0123() * b.e10(),
+                self.scalar() * b.e13() - self.e0() * b.e031()
+                    + self.e1() * b.e3()
+                    + self.e2() * b.e123()
+                    - self.e3() * b.e1()
+                    - self.e10() * b.e30()
+                    + self.e20() * b.e0123()
+                    + self.e30() * b.e10()
+                    + self.e32() * b.e21()
+                    + self.e13() * b.scalar()
+                    - self.e21() * b.e32()
+                    + self.e123() * b.e2()
+                    + self.e023() * b.e012()
+                    - self.e031() * b.e0()
+                    - self.e012() * b.e023()
+                    + self.e0123() * b.e20(),
+                self.scalar() * b.e21() - self.e0() * b.e012() - self.e1() * b.e2()
+                    + self.e2() * b.e1()
+                    + self.e3() * b.e123()
+                    + self.e10() * b.e20()
+                    - self.e20() * b.e10()
+                    + self.e30() * b.e0123()
+                    - self.e32() * b.e13()
+                    + self.e13() * b.e32()
+                    + self.e21() * b.scalar()
+                    + self.e123() * b.e3()
+                    - self.e023() * b.e031()
+                    + self.e031() * b.e023()
+                    - self.e012() * b.e0()
+                    + self.e0123() * b.e30(),
+            ),
+            Trivector::new(
+                self.scalar() * b.e123() + self.e0() * b.e0123()
+                    - self.e1() * b.e32()
+                    - self.e2() * b.e13()
+                    - self.e3() * b.e21()
+                    + self.e10() * b.e023()
+                    + self.e20() * b.e031()
+                    + self.e30() * b.e012()
+                    - self.e32() * b.e1()
+                    - self.e13() * b.e2()
+                    - self.e21() * b.e3()
+                    + self.e123() * b.scalar()
+                    - self.e023() * b.e10()
+                    - self.e031() * b.e20()
+                    - self.e012() * b.e30()
+                    - self.e0123() * b.e0(),
+                self.scalar() * b.e023() - self.e0() * b.e32()
+                    + self.e1() * b.e0123()
+                    + self.e2() * b.e30()
+                    - self.e3() * b.e20()
+                    + self.e10() * b.e123()
+                    - self.e20() * b.e3()
+                    + self.e30() * b.e2()
+                    - self.e32() * b.e0()
+                    - self.e13() * b.e012()
+                    + self.e21() * b.e031()
+                    - self.e123() * b.e10()
+                    + self.e023() * b.scalar()
+                    - self.e031() * b.e21()
+                    + self.e012() * b.e13()
+                    - self.e0123() * b.e1(),
+                self.scalar() * b.e031() - self.e0() * b.e13() - self.e1() * b.e30()
+                    + self.e2() * b.e0123()
+                    + self.e3() * b.e10()
+                    + self.e10() * b.e3()
+                    + self.e20() * b.e123()
+                    - self.e30() * b.e1()
+                    + self.e32() * b.e012()
+                    - self.e13() * b.e0()
+                    - self.e21() * b.e023()
+                    - self.e123() * b.e20()
+                    + self.e023() * b.e21()
+                    + self.e031() * b.scalar()
+                    - self.e012() * b.e32()
+                    - self.e0123() * b.e2(),
+                self.scalar() * b.e012() - self.e0() * b.e21() + self.e1() * b.e20()
+                    - self.e2() * b.e10()
+                    + self.e3() * b.e0123()
+                    - self.e10() * b.e2()
+                    + self.e20() * b.e1()
+                    + self.e30() * b.e123()
+                    - self.e32() * b.e031()
+                    + self.e13() * b.e023()
+                    - self.e21() * b.e0()
+                    - self.e123() * b.e30()
+                    - self.e023() * b.e13()
+                    + self.e031() * b.e32()
+                    + self.e012() * b.scalar()
+                    - self.e0123() * b.e3(),
+            ),
+            self.scalar() * b.e0123() + self.e0() * b.e123()
+                - self.e1() * b.e023()
+                - self.e2() * b.e031()
+                - self.e3() * b.e012()
+                + self.e10() * b.e32()
+                + self.e20() * b.e13()
+                + self.e30() * b.e21()
+                + self.e32() * b.e10()
+                + self.e13() * b.e20()
+                + self.e21() * b.e30()
+                - self.e123() * b.e0()
+                + self.e023() * b.e1()
+                + self.e031() * b.e2()
+                + self.e012() * b.e3()
+                + self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Multivector<F>);
+
+// Test
+#[cfg(test)]
+mod geometric_product {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn scalar_vector() {
+        // 2
+        let scalar1 = Scalar::new(2.0);
+        // 2e0-4e1+4e2+2e3
+        let vector2 = Vector::new(2.0, -4.0, 4.0, 2.0);
+        let res = scalar1 * vector2;
+        // 4e0-8e1+8e2+4e3
+        assert_relative_eq!(res.e0(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 4.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_vector() {
+        // 5e0+3e1-2e2-2e3
+        let vector1 = Vector::new(5.0, 3.0, -2.0, -2.0);
+        // -3e0+3e1+2e2+2e3
+        let vector2 = Vector::new(-3.0, 3.0, 2.0, 2.0);
+        let res = vector1 * vector2;
+        // -16-24e10-4e20-4e30+12e13-12e21
+        assert_relative_eq!(res.scalar(), -16.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e10(), -24.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e30(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e32(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e13(), 12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e21(), -12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e023(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e031(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_bivector() {
+        // -2e0-1e1-3e2+5e3
+        let vector1 = Vector::new(-2.0, -1.0, -3.0, 5.0);
+        // 5e10-4e20-3e30+1e32+1e13+5e21
+        let bivector2 = Bivector::new(5.0, -4.0, -3.0, 1.0, 1.0, 5.0);
+        let res = vector1 * bivector2;
+        // 8e0+30e1-18e2-8e3-21e123+31e023+24e031+29e012
+        assert_relative_eq!(res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 30.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -18.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), -8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e10(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e30(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e32(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e13(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e21(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), -21.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e023(), 31.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e031(), 24.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), 29.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_bivector() {
+        // -3e10+5e20+1e30+2e32-4e13-3e21
+        let bivector1 = Bivector::new(-3.0, 5.0, 1.0, 2.0, -4.0, -3.0);
+        // -3e10-1e20+3e30+1e32-4e13-2e21
+        let bivector2 = Bivector::new(-3.0, -1.0, 3.0, 1.0, -4.0, -2.0);
+        let res = bivector1 * bivector2;
+        // -17+21e10+2e20+7e30+20e32+5e13+22e21-36e0123
+        assert_relative_eq!(res.scalar(), -17.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e10(), 21.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e30(), 7.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e32(), 20.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e13(), 5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e21(), 22.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e023(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e031(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), -36.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn trivector_pseudoscalar() {
+        // -4e123+4e023+4e031-4e012
+        let trivector1 = Trivector::new(-4.0, 4.0, 4.0, -4.0);
+        // 1e0123
+        let pseudoscalar2 = Pseudoscalar::new(1.0);
+        let res = trivector1 * pseudoscalar2;
+        // 4e0-4e1-4e2+4e3
+        assert_relative_eq!(res.e0(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 4.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_vector() {
+        // -1+3e10-1e20+3e30-1e32+1e13-2e21+5e0123
+        let rotor1 = Rotor::new(-1.0, Bivector::new(3.0, -1.0, 3.0, -1.0, 1.0, -2.0), 5.0);
+        // 2e0+4e1+3e2-1e3
+        let vector2 = Vector::new(2.0, 4.0, 3.0, -1.0);
+        let res = rotor1 * vector2;
+        // 4e0-3e1+4e2+14e3-11e123-10e023-32e031-4e012
+        assert_relative_eq!(res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e10(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e30(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e32(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e13(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e21(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), -11.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e023(), -10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e031(), -32.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_rotor() {
+        // 2+3e0-2e1-3e2-4e3+1e10+3e20-1e30-4e32+1e13+2e21-3e123+5e023-3e031+2e012-4e0123
+        let mvec1 = Multivector::new(
+            2.0,
+            Vector::new(3.0, -2.0, -3.0, -4.0),
+            Bivector::new(1.0, 3.0, -1.0, -4.0, 1.0, 2.0),
+            Trivector::new(-3.0, 5.0, -3.0, 2.0),
+            -4.0,
+        );
+        // -2-1e10+2e20+5e30-2e32+5e13+2e21+3e0123
+        let rotor2 = Rotor::new(-2.0, Bivector::new(-1.0, 2.0, 5.0, -2.0, 5.0, 2.0), 3.0);
+        let res = mvec1 * rotor2;
+        // -9+6e0+3e1+9e2-10e3-12e10-3e20+10e30+36e32+1e13+35e123-4e023+16e031-33e012+41e0123
+        assert_relative_eq!(res.scalar(), -9.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 9.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), -10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e10(), -12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e30(), 10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e32(), 36.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e13(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e21(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 35.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e023(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e031(), 16.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), -33.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), 41.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_multivector() {
+        // -3+4e0+4e1+5e2+3e3-4e10+1e20-1e30-2e32+4e13-4e21+5e123-3e023-1e031-2e012-4e0123
+        let mvec1 = Multivector::new(
+            -3.0,
+            Vector::new(4.0, 4.0, 5.0, 3.0),
+            Bivector::new(-4.0, 1.0, -1.0, -2.0, 4.0, -4.0),
+            Trivector::new(5.0, -3.0, -1.0, -2.0),
+            -4.0,
+        );
+        // -3-4e0+4e1+4e2+1e3+2e10+5e20+3e30+4e32+3e13+2e21+5e123+3e023+1e031+5e012-2e0123
+        let mvec2 = Multivector::new(
+            -3.0,
+            Vector::new(-4.0, 4.0, 4.0, 1.0),
+            Bivector::new(2.0, 5.0, 3.0, 4.0, 3.0, 2.0),
+            Trivector::new(5.0, 3.0, 1.0, 5.0),
+            -2.0,
+        );
+        let res = mvec1 * mvec2;
+        // -11-106e0-46e1-16e3-66e10-52e20-26e30+8e32-1e13-8e21-94e123-79e023-12e031-6e012-3e0123
+        assert_relative_eq!(res.scalar(), -11.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), -106.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -46.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), -16.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e10(), -66.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), -52.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e30(), -26.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e32(), 8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e13(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e21(), -8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), -94.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e023(), -79.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e031(), -12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), -6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), -3.0, max_relative = 0.000001);
+    }
+}
@@ -0,0 +1,1493 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    bivector::Bivector, multivector::Multivector, pseudoscalar::Pseudoscalar, rotor::Rotor,
+    scalar::Scalar, trivector::Trivector, vector::Vector,
+};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::BitOr;
+
+// Inner Product
+
+// Scalar-Scalar
+impl<F: Float> BitOr for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitor(self, b: Scalar<F>) -> Scalar<F> {
+        Scalar(self.0 * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Scalar<F>);
+
+// Scalar-Vector
+impl<F: Float> BitOr<Vector<F>> for Scalar<F> {
+    type Output = Vector<F>;
+    fn bitor(self, b: Vector<F>) -> Vector<F> {
+        Vector::new(
+            self.0 * b.e0(),
+            self.0 * b.e1(),
+            self.0 * b.e2(),
+            self.0 * b.e3(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Vector<F>);
+
+// Vector-Scalar
+impl<F: Float> BitOr<Scalar<F>> for Vector<F> {
+    type Output = Vector<F>;
+    fn bitor(self, b: Scalar<F>) -> Vector<F> {
+        Vector::new(
+            self.e0() * b.0,
+            self.e1() * b.0,
+            self.e2() * b.0,
+            self.e3() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Vector<F>, Scalar<F>);
+
+// Scalar-Bivector
+impl<F: Float> BitOr<Bivector<F>> for Scalar<F> {
+    type Output = Bivector<F>;
+    fn bitor(self, b: Bivector<F>) -> Bivector<F> {
+        Bivector::new(
+            self.0 * b.e10(),
+            self.0 * b.e20(),
+            self.0 * b.e30(),
+            self.0 * b.e32(),
+            self.0 * b.e13(),
+            self.0 * b.e21(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Bivector<F>);
+
+// Bivector-Scalar
+impl<F: Float> BitOr<Scalar<F>> for Bivector<F> {
+    type Output = Bivector<F>;
+    fn bitor(self, b: Scalar<F>) -> Bivector<F> {
+        Bivector::new(
+            self.e10() * b.0,
+            self.e20() * b.0,
+            self.e30() * b.0,
+            self.e32() * b.0,
+            self.e13() * b.0,
+            self.e21() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Bivector<F>, Scalar<F>);
+
+// Scalar-Trivector
+impl<F: Float> BitOr<Trivector<F>> for Scalar<F> {
+    type Output = Trivector<F>;
+    fn bitor(self, b: Trivector<F>) -> Trivector<F> {
+        Trivector::new(
+            self.0 * b.e123(),
+            self.0 * b.e023(),
+            self.0 * b.e031(),
+            self.0 * b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Trivector<F>);
+
+// Trivector-Scalar
+impl<F: Float> BitOr<Scalar<F>> for Trivector<F> {
+    type Output = Trivector<F>;
+    fn bitor(self, b: Scalar<F>) -> Trivector<F> {
+        Trivector::new(
+            self.e123() * b.0,
+            self.e023() * b.0,
+            self.e031() * b.0,
+            self.e012() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Trivector<F>, Scalar<F>);
+
+// Scalar-Pseudoscalar
+impl<F: Float> BitOr<Pseudoscalar<F>> for Scalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitor(self, b: Pseudoscalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.0 * b.e0123())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Scalar
+impl<F: Float> BitOr<Scalar<F>> for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitor(self, b: Scalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.e0123() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Scalar<F>);
+
+// Scalar-Rotor
+impl<F: Float> BitOr<Rotor<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 * b.scalar(),
+            Vector::zero(),
+            Bivector::new(
+                self.0 * b.e10(),
+                self.0 * b.e20(),
+                self.0 * b.e30(),
+                self.0 * b.e32(),
+                self.0 * b.e13(),
+                self.0 * b.e21(),
+            ),
+            Trivector::zero(),
+            self.0 * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Rotor<F>);
+
+// Rotor-Scalar
+impl<F: Float> BitOr<Scalar<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.0,
+            Vector::zero(),
+            Bivector::new(
+                self.e10() * b.0,
+                self.e20() * b.0,
+                self.e30() * b.0,
+                self.e32() * b.0,
+                self.e13() * b.0,
+                self.e21() * b.0,
+            ),
+            Trivector::zero(),
+            self.e0123() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Rotor<F>, Scalar<F>);
+
+// Scalar-Multivector
+impl<F: Float> BitOr<Multivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 * b.scalar(),
+            Vector::new(
+                self.0 * b.e0(),
+                self.0 * b.e1(),
+                self.0 * b.e2(),
+                self.0 * b.e3(),
+            ),
+            Bivector::new(
+                self.0 * b.e10(),
+                self.0 * b.e20(),
+                self.0 * b.e30(),
+                self.0 * b.e32(),
+                self.0 * b.e13(),
+                self.0 * b.e21(),
+            ),
+            Trivector::new(
+                self.0 * b.e123(),
+                self.0 * b.e023(),
+                self.0 * b.e031(),
+                self.0 * b.e012(),
+            ),
+            self.0 * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Multivector<F>);
+
+// Multivector-Scalar
+impl<F: Float> BitOr<Scalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.0,
+            Vector::new(
+                self.e0() * b.0,
+                self.e1() * b.0,
+                self.e2() * b.0,
+                self.e3() * b.0,
+            ),
+            Bivector::new(
+                self.e10() * b.0,
+                self.e20() * b.0,
+                self.e30() * b.0,
+                self.e32() * b.0,
+                self.e13() * b.0,
+                self.e21() * b.0,
+            ),
+            Trivector::new(
+                self.e123() * b.0,
+                self.e023() * b.0,
+                self.e031() * b.0,
+                self.e012() * b.0,
+            ),
+            self.e0123() * b.0,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Scalar<F>);
+
+// Vector-Vector
+impl<F: Float> BitOr for Vector<F> {
+    type Output = Scalar<F>;
+    fn bitor(self, b: Vector<F>) -> Scalar<F> {
+        Scalar(self.e0() * b.e0() - self.e1() * b.e1() - self.e2() * b.e2() - self.e3() * b.e3())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Vector<F>, Vector<F>);
+
+// Vector-Bivector
+impl<F: Float> BitOr<Bivector<F>> for Vector<F> {
+    type Output = Vector<F>;
+    fn bitor(self, b: Bivector<F>) -> Vector<F> {
+        Vector::new(
+            -self.e1() * b.e10() - self.e2() * b.e20() - self.e3() * b.e30(),
+            -self.e0() * b.e10() - self.e2() * b.e21() + self.e3() * b.e13(),
+            -self.e0() * b.e20() + self.e1() * b.e21() - self.e3() * b.e32(),
+            -self.e0() * b.e30() - self.e1() * b.e13() + self.e2() * b.e32(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Vector<F>, Bivector<F>);
+
+// Bivector-Vector
+impl<F: Float> BitOr<Vector<F>> for Bivector<F> {
+    type Output = Vector<F>;
+    fn bitor(self, b: Vector<F>) -> Vector<F> {
+        Vector::new(
+            self.e10() * b.e1() + self.e20() * b.e2() + self.e30() * b.e3(),
+            self.e10() * b.e0() - self.e13() * b.e3() + self.e21() * b.e2(),
+            self.e20() * b.e0() + self.e32() * b.e3() - self.e21() * b.e1(),
+            self.e30() * b.e0() - self.e32() * b.e2() + self.e13() * b.e1(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Bivector<F>, Vector<F>);
+
+// Vector-Trivector
+impl<F: Float> BitOr<Trivector<F>> for Vector<F> {
+    type Output = Bivector<F>;
+    fn bitor(self, b: Trivector<F>) -> Bivector<F> {
+        Bivector::new(
+            self.e2() * b.e012() - self.e3() * b.e031(),
+            -self.e1() * b.e012() + self.e3() * b.e023(),
+            self.e1() * b.e031() - self.e2() * b.e023(),
+            -self.e0() * b.e023() + self.e1() * b.e123(),
+            -self.e0() * b.e031() + self.e2() * b.e123(),
+            -self.e0() * b.e012() + self.e3() * b.e123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Vector<F>, Trivector<F>);
+
+// Trivector-Vector
+impl<F: Float> BitOr<Vector<F>> for Trivector<F> {
+    type Output = Bivector<F>;
+    fn bitor(self, b: Vector<F>) -> Bivector<F> {
+        Bivector::new(
+            -self.e031() * b.e3() + self.e012() * b.e2(),
+            self.e023() * b.e3() - self.e012() * b.e1(),
+            -self.e023() * b.e2() + self.e031() * b.e1(),
+            self.e123() * b.e1() - self.e023() * b.e0(),
+            self.e123() * b.e2() - self.e031() * b.e0(),
+            self.e123() * b.e3() - self.e012() * b.e0(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Trivector<F>, Vector<F>);
+
+// Vector-Pseudoscalar
+impl<F: Float> BitOr<Pseudoscalar<F>> for Vector<F> {
+    type Output = Trivector<F>;
+    fn bitor(self, b: Pseudoscalar<F>) -> Trivector<F> {
+        Trivector::new(
+            self.e0() * b.e0123(),
+            self.e1() * b.e0123(),
+            self.e2() * b.e0123(),
+            self.e3() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Vector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Vector
+impl<F: Float> BitOr<Vector<F>> for Pseudoscalar<F> {
+    type Output = Trivector<F>;
+    fn bitor(self, b: Vector<F>) -> Trivector<F> {
+        Trivector::new(
+            -self.e0123() * b.e0(),
+            -self.e0123() * b.e1(),
+            -self.e0123() * b.e2(),
+            -self.e0123() * b.e3(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Vector<F>);
+
+// Vector-Rotor
+impl<F: Float> BitOr<Rotor<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                self.e0() * b.scalar()
+                    - self.e1() * b.e10()
+                    - self.e2() * b.e20()
+                    - self.e3() * b.e30(),
+                -self.e0() * b.e10() + self.e1() * b.scalar() - self.e2() * b.e21()
+                    + self.e3() * b.e13(),
+                -self.e0() * b.e20() + self.e1() * b.e21() + self.e2() * b.scalar()
+                    - self.e3() * b.e32(),
+                -self.e0() * b.e30() - self.e1() * b.e13()
+                    + self.e2() * b.e32()
+                    + self.e3() * b.scalar(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                self.e0() * b.e0123(),
+                self.e1() * b.e0123(),
+                self.e2() * b.e0123(),
+                self.e3() * b.e0123(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Vector<F>, Rotor<F>);
+
+// Rotor-Vector
+impl<F: Float> BitOr<Vector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                self.scalar() * b.e0()
+                    + self.e10() * b.e1()
+                    + self.e20() * b.e2()
+                    + self.e30() * b.e3(),
+                self.scalar() * b.e1() + self.e10() * b.e0() - self.e13() * b.e3()
+                    + self.e21() * b.e2(),
+                self.scalar() * b.e2() + self.e20() * b.e0() + self.e32() * b.e3()
+                    - self.e21() * b.e1(),
+                self.scalar() * b.e3() + self.e30() * b.e0() - self.e32() * b.e2()
+                    + self.e13() * b.e1(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                -self.e0123() * b.e0(),
+                -self.e0123() * b.e1(),
+                -self.e0123() * b.e2(),
+                -self.e0123() * b.e3(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Rotor<F>, Vector<F>);
+
+// Vector-Multivector
+impl<F: Float> BitOr<Multivector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e0() * b.e0() - self.e1() * b.e1() - self.e2() * b.e2() - self.e3() * b.e3(),
+            Vector::new(
+                self.e0() * b.scalar()
+                    - self.e1() * b.e10()
+                    - self.e2() * b.e20()
+                    - self.e3() * b.e30(),
+                -self.e0() * b.e10() + self.e1() * b.scalar() - self.e2() * b.e21()
+                    + self.e3() * b.e13(),
+                -self.e0() * b.e20() + self.e1() * b.e21() + self.e2() * b.scalar()
+                    - self.e3() * b.e32(),
+                -self.e0() * b.e30() - self.e1() * b.e13()
+                    + self.e2() * b.e32()
+                    + self.e3() * b.scalar(),
+            ),
+            Bivector::new(
+                self.e2() * b.e012() - self.e3() * b.e031(),
+                -self.e1() * b.e012() + self.e3() * b.e023(),
+                self.e1() * b.e031() - self.e2() * b.e023(),
+                -self.e0() * b.e023() + self.e1() * b.e123(),
+                -self.e0() * b.e031() + self.e2() * b.e123(),
+                -self.e0() * b.e012() + self.e3() * b.e123(),
+            ),
+            Trivector::new(
+                self.e0() * b.e0123(),
+                self.e1() * b.e0123(),
+                self.e2() * b.e0123(),
+                self.e3() * b.e0123(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Vector<F>, Multivector<F>);
+
+// Multivector-Vector
+impl<F: Float> BitOr<Vector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e0() * b.e0() - self.e1() * b.e1() - self.e2() * b.e2() - self.e3() * b.e3(),
+            Vector::new(
+                self.scalar() * b.e0()
+                    + self.e10() * b.e1()
+                    + self.e20() * b.e2()
+                    + self.e30() * b.e3(),
+                self.scalar() * b.e1() + self.e10() * b.e0() - self.e13() * b.e3()
+                    + self.e21() * b.e2(),
+                self.scalar() * b.e2() + self.e20() * b.e0() + self.e32() * b.e3()
+                    - self.e21() * b.e1(),
+                self.scalar() * b.e3() + self.e30() * b.e0() - self.e32() * b.e2()
+                    + self.e13() * b.e1(),
+            ),
+            Bivector::new(
+                -self.e031() * b.e3() + self.e012() * b.e2(),
+                self.e023() * b.e3() - self.e012() * b.e1(),
+                -self.e023() * b.e2() + self.e031() * b.e1(),
+                self.e123() * b.e1() - self.e023() * b.e0(),
+                self.e123() * b.e2() - self.e031() * b.e0(),
+                self.e123() * b.e3() - self.e012() * b.e0(),
+            ),
+            Trivector::new(
+                -self.e0123() * b.e0(),
+                -self.e0123() * b.e1(),
+                -self.e0123() * b.e2(),
+                -self.e0123() * b.e3(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Vector<F>);
+
+// Bivector-Bivector
+impl<F: Float> BitOr for Bivector<F> {
+    type Output = Scalar<F>;
+    fn bitor(self, b: Bivector<F>) -> Scalar<F> {
+        Scalar(
+            self.e10() * b.e10() + self.e20() * b.e20() + self.e30() * b.e30()
+                - self.e32() * b.e32()
+                - self.e13() * b.e13()
+                - self.e21() * b.e21(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Bivector<F>, Bivector<F>);
+
+// Bivector-Trivector
+impl<F: Float> BitOr<Trivector<F>> for Bivector<F> {
+    type Output = Vector<F>;
+    fn bitor(self, b: Trivector<F>) -> Vector<F> {
+        Vector::new(
+            self.e32() * b.e023() + self.e13() * b.e031() + self.e21() * b.e012(),
+            self.e20() * b.e012() - self.e30() * b.e031() + self.e32() * b.e123(),
+            -self.e10() * b.e012() + self.e30() * b.e023() + self.e13() * b.e123(),
+            self.e10() * b.e031() - self.e20() * b.e023() + self.e21() * b.e123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Bivector<F>, Trivector<F>);
+
+// Trivector-Bivector
+impl<F: Float> BitOr<Bivector<F>> for Trivector<F> {
+    type Output = Vector<F>;
+    fn bitor(self, b: Bivector<F>) -> Vector<F> {
+        Vector::new(
+            self.e023() * b.e32() + self.e031() * b.e13() + self.e012() * b.e21(),
+            self.e123() * b.e32() - self.e031() * b.e30() + self.e012() * b.e20(),
+            self.e123() * b.e13() + self.e023() * b.e30() - self.e012() * b.e10(),
+            self.e123() * b.e21() - self.e023() * b.e20() + self.e031() * b.e10(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Trivector<F>, Bivector<F>);
+
+// Bivector-Pseudoscalar
+impl<F: Float> BitOr<Pseudoscalar<F>> for Bivector<F> {
+    type Output = Bivector<F>;
+    fn bitor(self, b: Pseudoscalar<F>) -> Bivector<F> {
+        Bivector::new(
+            -self.e32() * b.e0123(),
+            -self.e13() * b.e0123(),
+            -self.e21() * b.e0123(),
+            self.e10() * b.e0123(),
+            self.e20() * b.e0123(),
+            self.e30() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Bivector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Bivector
+impl<F: Float> BitOr<Bivector<F>> for Pseudoscalar<F> {
+    type Output = Bivector<F>;
+    fn bitor(self, b: Bivector<F>) -> Bivector<F> {
+        Bivector::new(
+            -self.e0123() * b.e32(),
+            -self.e0123() * b.e13(),
+            -self.e0123() * b.e21(),
+            self.e0123() * b.e10(),
+            self.e0123() * b.e20(),
+            self.e0123() * b.e30(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Bivector<F>);
+
+// Bivector-Rotor
+impl<F: Float> BitOr<Rotor<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e10() * b.e10() + self.e20() * b.e20() + self.e30() * b.e30()
+                - self.e32() * b.e32()
+                - self.e13() * b.e13()
+                - self.e21() * b.e21(),
+            Vector::zero(),
+            Bivector::new(
+                self.e10() * b.scalar() - self.e32() * b.e0123(),
+                self.e20() * b.scalar() - self.e13() * b.e0123(),
+                self.e30() * b.scalar() - self.e21() * b.e0123(),
+                self.e10() * b.e0123() + self.e32() * b.scalar(),
+                self.e20() * b.e0123() + self.e13() * b.scalar(),
+                self.e30() * b.e0123() + self.e21() * b.scalar(),
+            ),
+            Trivector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Bivector<F>, Rotor<F>);
+
+// Rotor-Bivector
+impl<F: Float> BitOr<Bivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e10() * b.e10() + self.e20() * b.e20() + self.e30() * b.e30()
+                - self.e32() * b.e32()
+                - self.e13() * b.e13()
+                - self.e21() * b.e21(),
+            Vector::zero(),
+            Bivector::new(
+                self.scalar() * b.e10() - self.e0123() * b.e32(),
+                self.scalar() * b.e20() - self.e0123() * b.e13(),
+                self.scalar() * b.e30() - self.e0123() * b.e21(),
+                self.scalar() * b.e32() + self.e0123() * b.e10(),
+                self.scalar() * b.e13() + self.e0123() * b.e20(),
+                self.scalar() * b.e21() + self.e0123() * b.e30(),
+            ),
+            Trivector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Rotor<F>, Bivector<F>);
+
+// Bivector-Multivector
+impl<F: Float> BitOr<Multivector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e10() * b.e10() + self.e20() * b.e20() + self.e30() * b.e30()
+                - self.e32() * b.e32()
+                - self.e13() * b.e13()
+                - self.e21() * b.e21(),
+            Vector::new(
+                self.e10() * b.e1()
+                    + self.e20() * b.e2()
+                    + self.e30() * b.e3()
+                    + self.e32() * b.e023()
+                    + self.e13() * b.e031()
+                    + self.e21() * b.e012(),
+                self.e10() * b.e0() + self.e20() * b.e012() - self.e30() * b.e031()
+                    + self.e32() * b.e123()
+                    - self.e13() * b.e3()
+                    + self.e21() * b.e2(),
+                -self.e10() * b.e012()
+                    + self.e20() * b.e0()
+                    + self.e30() * b.e023()
+                    + self.e32() * b.e3()
+                    + self.e13() * b.e123()
+                    - self.e21() * b.e1(),
+                self.e10() * b.e031() - self.e20() * b.e023() + self.e30() * b.e0()
+                    - self.e32() * b.e2()
+                    + self.e13() * b.e1()
+                    + self.e21() * b.e123(),
+            ),
+            Bivector::new(
+                self.e10() * b.scalar() - self.e32() * b.e0123(),
+                self.e20() * b.scalar() - self.e13() * b.e0123(),
+                self.e30() * b.scalar() - self.e21() * b.e0123(),
+                self.e10() * b.e0123() + self.e32() * b.scalar(),
+                self.e20() * b.e0123() + self.e13() * b.scalar(),
+                self.e30() * b.e0123() + self.e21() * b.scalar(),
+            ),
+            Trivector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Bivector<F>, Multivector<F>);
+
+// Multivector-Bivector
+impl<F: Float> BitOr<Bivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e10() * b.e10() + self.e20() * b.e20() + self.e30() * b.e30()
+                - self.e32() * b.e32()
+                - self.e13() * b.e13()
+                - self.e21() * b.e21(),
+            Vector::new(
+                -self.e1() * b.e10() - self.e2() * b.e20() - self.e3() * b.e30()
+                    + self.e023() * b.e32()
+                    + self.e031() * b.e13()
+                    + self.e012() * b.e21(),
+                -self.e0() * b.e10() - self.e2() * b.e21()
+                    + self.e3() * b.e13()
+                    + self.e123() * b.e32()
+                    - self.e031() * b.e30()
+                    + self.e012() * b.e20(),
+                -self.e0() * b.e20() + self.e1() * b.e21() - self.e3() * b.e32()
+                    + self.e123() * b.e13()
+                    + self.e023() * b.e30()
+                    - self.e012() * b.e10(),
+                -self.e0() * b.e30() - self.e1() * b.e13()
+                    + self.e2() * b.e32()
+                    + self.e123() * b.e21()
+                    - self.e023() * b.e20()
+                    + self.e031() * b.e10(),
+            ),
+            Bivector::new(
+                self.scalar() * b.e10() - self.e0123() * b.e32(),
+                self.scalar() * b.e20() - self.e0123() * b.e13(),
+                self.scalar() * b.e30() - self.e0123() * b.e21(),
+                self.scalar() * b.e32() + self.e0123() * b.e10(),
+                self.scalar() * b.e13() + self.e0123() * b.e20(),
+                self.scalar() * b.e21() + self.e0123() * b.e30(),
+            ),
+            Trivector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Bivector<F>);
+
+// Trivector-Trivector
+impl<F: Float> BitOr for Trivector<F> {
+    type Output = Scalar<F>;
+    fn bitor(self, b: Trivector<F>) -> Scalar<F> {
+        Scalar(
+            self.e123() * b.e123()
+                - self.e023() * b.e023()
+                - self.e031() * b.e031()
+                - self.e012() * b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Trivector<F>, Trivector<F>);
+
+// Trivector-Pseudoscalar
+impl<F: Float> BitOr<Pseudoscalar<F>> for Trivector<F> {
+    type Output = Vector<F>;
+    fn bitor(self, b: Pseudoscalar<F>) -> Vector<F> {
+        Vector::new(
+            -self.e123() * b.e0123(),
+            -self.e023() * b.e0123(),
+            -self.e031() * b.e0123(),
+            -self.e012() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Trivector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Trivector
+impl<F: Float> BitOr<Trivector<F>> for Pseudoscalar<F> {
+    type Output = Vector<F>;
+    fn bitor(self, b: Trivector<F>) -> Vector<F> {
+        Vector::new(
+            self.e0123() * b.e123(),
+            self.e0123() * b.e023(),
+            self.e0123() * b.e031(),
+            self.e0123() * b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Trivector<F>);
+
+// Trivector-Rotor
+impl<F: Float> BitOr<Rotor<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                -self.e123() * b.e0123()
+                    + self.e023() * b.e32()
+                    + self.e031() * b.e13()
+                    + self.e012() * b.e21(),
+                self.e123() * b.e32() - self.e023() * b.e0123() - self.e031() * b.e30()
+                    + self.e012() * b.e20(),
+                self.e123() * b.e13() + self.e023() * b.e30()
+                    - self.e031() * b.e0123()
+                    - self.e012() * b.e10(),
+                self.e123() * b.e21() - self.e023() * b.e20() + self.e031() * b.e10()
+                    - self.e012() * b.e0123(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                self.e123() * b.scalar(),
+                self.e023() * b.scalar(),
+                self.e031() * b.scalar(),
+                self.e012() * b.scalar(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Trivector<F>, Rotor<F>);
+
+// Rotor-Trivector
+impl<F: Float> BitOr<Trivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                self.e32() * b.e023()
+                    + self.e13() * b.e031()
+                    + self.e21() * b.e012()
+                    + self.e0123() * b.e123(),
+                self.e20() * b.e012() - self.e30() * b.e031()
+                    + self.e32() * b.e123()
+                    + self.e0123() * b.e023(),
+                -self.e10() * b.e012()
+                    + self.e30() * b.e023()
+                    + self.e13() * b.e123()
+                    + self.e0123() * b.e031(),
+                self.e10() * b.e031() - self.e20() * b.e023()
+                    + self.e21() * b.e123()
+                    + self.e0123() * b.e012(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                self.scalar() * b.e123(),
+                self.scalar() * b.e023(),
+                self.scalar() * b.e031(),
+                self.scalar() * b.e012(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Rotor<F>, Trivector<F>);
+
+// Trivector-Multivector
+impl<F: Float> BitOr<Multivector<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e123() * b.e123()
+                - self.e023() * b.e023()
+                - self.e031() * b.e031()
+                - self.e012() * b.e012(),
+            Vector::new(
+                -self.e123() * b.e0123()
+                    + self.e023() * b.e32()
+                    + self.e031() * b.e13()
+                    + self.e012() * b.e21(),
+                self.e123() * b.e32() - self.e023() * b.e0123() - self.e031() * b.e30()
+                    + self.e012() * b.e20(),
+                self.e123() * b.e13() + self.e023() * b.e30()
+                    - self.e031() * b.e0123()
+                    - self.e012() * b.e10(),
+                self.e123() * b.e21() - self.e023() * b.e20() + self.e031() * b.e10()
+                    - self.e012() * b.e0123(),
+            ),
+            Bivector::new(
+                -self.e031() * b.e3() + self.e012() * b.e2(),
+                self.e023() * b.e3() - self.e012() * b.e1(),
+                -self.e023() * b.e2() + self.e031() * b.e1(),
+                self.e123() * b.e1() - self.e023() * b.e0(),
+                self.e123() * b.e2() - self.e031() * b.e0(),
+                self.e123() * b.e3() - self.e012() * b.e0(),
+            ),
+            Trivector::new(
+                self.e123() * b.scalar(),
+                self.e023() * b.scalar(),
+                self.e031() * b.scalar(),
+                self.e012() * b.scalar(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Trivector<F>, Multivector<F>);
+
+// Multivector-Trivector
+impl<F: Float> BitOr<Trivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e123() * b.e123()
+                - self.e023() * b.e023()
+                - self.e031() * b.e031()
+                - self.e012() * b.e012(),
+            Vector::new(
+                self.e32() * b.e023()
+                    + self.e13() * b.e031()
+                    + self.e21() * b.e012()
+                    + self.e0123() * b.e123(),
+                self.e20() * b.e012() - self.e30() * b.e031()
+                    + self.e32() * b.e123()
+                    + self.e0123() * b.e023(),
+                -self.e10() * b.e012()
+                    + self.e30() * b.e023()
+                    + self.e13() * b.e123()
+                    + self.e0123() * b.e031(),
+                self.e10() * b.e031() - self.e20() * b.e023()
+                    + self.e21() * b.e123()
+                    + self.e0123() * b.e012(),
+            ),
+            Bivector::new(
+                self.e2() * b.e012() - self.e3() * b.e031(),
+                -self.e1() * b.e012() + self.e3() * b.e023(),
+                self.e1() * b.e031() - self.e2() * b.e023(),
+                -self.e0() * b.e023() + self.e1() * b.e123(),
+                -self.e0() * b.e031() + self.e2() * b.e123(),
+                -self.e0() * b.e012() + self.e3() * b.e123(),
+            ),
+            Trivector::new(
+                self.scalar() * b.e123(),
+                self.scalar() * b.e023(),
+                self.scalar() * b.e031(),
+                self.scalar() * b.e012(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Trivector<F>);
+
+// Pseudoscalar-Pseudoscalar
+impl<F: Float> BitOr for Pseudoscalar<F> {
+    type Output = Scalar<F>;
+    fn bitor(self, b: Pseudoscalar<F>) -> Scalar<F> {
+        Scalar(-self.e0123() * b.e0123())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Rotor
+impl<F: Float> BitOr<Rotor<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e0123() * b.e0123(),
+            Vector::zero(),
+            Bivector::new(
+                -self.e0123() * b.e32(),
+                -self.e0123() * b.e13(),
+                -self.e0123() * b.e21(),
+                self.e0123() * b.e10(),
+                self.e0123() * b.e20(),
+                self.e0123() * b.e30(),
+            ),
+            Trivector::zero(),
+            self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Rotor<F>);
+
+// Rotor-Pseudoscalar
+impl<F: Float> BitOr<Pseudoscalar<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e0123() * b.e0123(),
+            Vector::zero(),
+            Bivector::new(
+                -self.e32() * b.e0123(),
+                -self.e13() * b.e0123(),
+                -self.e21() * b.e0123(),
+                self.e10() * b.e0123(),
+                self.e20() * b.e0123(),
+                self.e30() * b.e0123(),
+            ),
+            Trivector::zero(),
+            self.scalar() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Rotor<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Multivector
+impl<F: Float> BitOr<Multivector<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e0123() * b.e0123(),
+            Vector::new(
+                self.e0123() * b.e123(),
+                self.e0123() * b.e023(),
+                self.e0123() * b.e031(),
+                self.e0123() * b.e012(),
+            ),
+            Bivector::new(
+                -self.e0123() * b.e32(),
+                -self.e0123() * b.e13(),
+                -self.e0123() * b.e21(),
+                self.e0123() * b.e10(),
+                self.e0123() * b.e20(),
+                self.e0123() * b.e30(),
+            ),
+            Trivector::new(
+                -self.e0123() * b.e0(),
+                -self.e0123() * b.e1(),
+                -self.e0123() * b.e2(),
+                -self.e0123() * b.e3(),
+            ),
+            self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Pseudoscalar<F>, Multivector<F>);
+
+// Multivector-Pseudoscalar
+impl<F: Float> BitOr<Pseudoscalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e0123() * b.e0123(),
+            Vector::new(
+                -self.e123() * b.e0123(),
+                -self.e023() * b.e0123(),
+                -self.e031() * b.e0123(),
+                -self.e012() * b.e0123(),
+            ),
+            Bivector::new(
+                -self.e32() * b.e0123(),
+                -self.e13() * b.e0123(),
+                -self.e21() * b.e0123(),
+                self.e10() * b.e0123(),
+                self.e20() * b.e0123(),
+                self.e30() * b.e0123(),
+            ),
+            Trivector::new(
+                self.e0() * b.e0123(),
+                self.e1() * b.e0123(),
+                self.e2() * b.e0123(),
+                self.e3() * b.e0123(),
+            ),
+            self.scalar() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Pseudoscalar<F>);
+
+// Rotor-Rotor
+impl<F: Float> BitOr for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar()
+                + self.e10() * b.e10()
+                + self.e20() * b.e20()
+                + self.e30() * b.e30()
+                - self.e32() * b.e32()
+                - self.e13() * b.e13()
+                - self.e21() * b.e21()
+                - self.e0123() * b.e0123(),
+            Vector::zero(),
+            Bivector::new(
+                self.scalar() * b.e10() + self.e10() * b.scalar()
+                    - self.e32() * b.e0123()
+                    - self.e0123() * b.e32(),
+                self.scalar() * b.e20() + self.e20() * b.scalar()
+                    - self.e13() * b.e0123()
+                    - self.e0123() * b.e13(),
+                self.scalar() * b.e30() + self.e30() * b.scalar()
+                    - self.e21() * b.e0123()
+                    - self.e0123() * b.e21(),
+                self.scalar() * b.e32()
+                    + self.e10() * b.e0123()
+                    + self.e32() * b.scalar()
+                    + self.e0123() * b.e10(),
+                self.scalar() * b.e13()
+                    + self.e20() * b.e0123()
+                    + self.e13() * b.scalar()
+                    + self.e0123() * b.e20(),
+                self.scalar() * b.e21()
+                    + self.e30() * b.e0123()
+                    + self.e21() * b.scalar()
+                    + self.e0123() * b.e30(),
+            ),
+            Trivector::zero(),
+            self.scalar() * b.e0123() + self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Rotor<F>, Rotor<F>);
+
+// Rotor-Multivector
+impl<F: Float> BitOr<Multivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar()
+                + self.e10() * b.e10()
+                + self.e20() * b.e20()
+                + self.e30() * b.e30()
+                - self.e32() * b.e32()
+                - self.e13() * b.e13()
+                - self.e21() * b.e21()
+                - self.e0123() * b.e0123(),
+            Vector::new(
+                self.scalar() * b.e0()
+                    + self.e10() * b.e1()
+                    + self.e20() * b.e2()
+                    + self.e30() * b.e3()
+                    + self.e32() * b.e023()
+                    + self.e13() * b.e031()
+                    + self.e21() * b.e012()
+                    + self.e0123() * b.e123(),
+                self.scalar() * b.e1() + self.e10() * b.e0() + self.e20() * b.e012()
+                    - self.e30() * b.e031()
+                    + self.e32() * b.e123()
+                    - self.e13() * b.e3()
+                    + self.e21() * b.e2()
+                    + self.e0123() * b.e023(),
+                self.scalar() * b.e2() - self.e10() * b.e012()
+                    + self.e20() * b.e0()
+                    + self.e30() * b.e023()
+                    + self.e32() * b.e3()
+                    + self.e13() * b.e123()
+                    - self.e21() * b.e1()
+                    + self.e0123() * b.e031(),
+                self.scalar() * b.e3() + self.e10() * b.e031() - self.e20() * b.e023()
+                    + self.e30() * b.e0()
+                    - self.e32() * b.e2()
+                    + self.e13() * b.e1()
+                    + self.e21() * b.e123()
+                    + self.e0123() * b.e012(),
+            ),
+            Bivector::new(
+                self.scalar() * b.e10() + self.e10() * b.scalar()
+                    - self.e32() * b.e0123()
+                    - self.e0123() * b.e32(),
+                self.scalar() * b.e20() + self.e20() * b.scalar()
+                    - self.e13() * b.e0123()
+                    - self.e0123() * b.e13(),
+                self.scalar() * b.e30() + self.e30() * b.scalar()
+                    - self.e21() * b.e0123()
+                    - self.e0123() * b.e21(),
+                self.scalar() * b.e32()
+                    + self.e10() * b.e0123()
+                    + self.e32() * b.scalar()
+                    + self.e0123() * b.e10(),
+                self.scalar() * b.e13()
+                    + self.e20() * b.e0123()
+                    + self.e13() * b.scalar()
+                    + self.e0123() * b.e20(),
+                self.scalar() * b.e21()
+                    + self.e30() * b.e0123()
+                    + self.e21() * b.scalar()
+                    + self.e0123() * b.e30(),
+            ),
+            Trivector::new(
+                self.scalar() * b.e123() - self.e0123() * b.e0(),
+                self.scalar() * b.e023() - self.e0123() * b.e1(),
+                self.scalar() * b.e031() - self.e0123() * b.e2(),
+                self.scalar() * b.e012() - self.e0123() * b.e3(),
+            ),
+            self.scalar() * b.e0123() + self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Rotor<F>, Multivector<F>);
+
+// Multivector-Rotor
+impl<F: Float> BitOr<Rotor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar()
+                + self.e10() * b.e10()
+                + self.e20() * b.e20()
+                + self.e30() * b.e30()
+                - self.e32() * b.e32()
+                - self.e13() * b.e13()
+                - self.e21() * b.e21()
+                - self.e0123() * b.e0123(),
+            Vector::new(
+                self.e0() * b.scalar()
+                    - self.e1() * b.e10()
+                    - self.e2() * b.e20()
+                    - self.e3() * b.e30()
+                    - self.e123() * b.e0123()
+                    + self.e023() * b.e32()
+                    + self.e031() * b.e13()
+                    + self.e012() * b.e21(),
+                -self.e0() * b.e10() + self.e1() * b.scalar() - self.e2() * b.e21()
+                    + self.e3() * b.e13()
+                    + self.e123() * b.e32()
+                    - self.e023() * b.e0123()
+                    - self.e031() * b.e30()
+                    + self.e012() * b.e20(),
+                -self.e0() * b.e20() + self.e1() * b.e21() + self.e2() * b.scalar()
+                    - self.e3() * b.e32()
+                    + self.e123() * b.e13()
+                    + self.e023() * b.e30()
+                    - self.e031() * b.e0123()
+                    - self.e012() * b.e10(),
+                -self.e0() * b.e30() - self.e1() * b.e13()
+                    + self.e2() * b.e32()
+                    + self.e3() * b.scalar()
+                    + self.e123() * b.e21()
+                    - self.e023() * b.e20()
+                    + self.e031() * b.e10()
+                    - self.e012() * b.e0123(),
+            ),
+            Bivector::new(
+                self.scalar() * b.e10() + self.e10() * b.scalar()
+                    - self.e32() * b.e0123()
+                    - self.e0123() * b.e32(),
+                self.scalar() * b.e20() + self.e20() * b.scalar()
+                    - self.e13() * b.e0123()
+                    - self.e0123() * b.e13(),
+                self.scalar() * b.e30() + self.e30() * b.scalar()
+                    - self.e21() * b.e0123()
+                    - self.e0123() * b.e21(),
+                self.scalar() * b.e32()
+                    + self.e10() * b.e0123()
+                    + self.e32() * b.scalar()
+                    + self.e0123() * b.e10(),
+                self.scalar() * b.e13()
+                    + self.e20() * b.e0123()
+                    + self.e13() * b.scalar()
+                    + self.e0123() * b.e20(),
+                self.scalar() * b.e21()
+                    + self.e30() * b.e0123()
+                    + self.e21() * b.scalar()
+                    + self.e0123() * b.e30(),
+            ),
+            Trivector::new(
+                self.e0() * b.e0123() + self.e123() * b.scalar(),
+                self.e1() * b.e0123() + self.e023() * b.scalar(),
+                self.e2() * b.e0123() + self.e031() * b.scalar(),
+                self.e3() * b.e0123() + self.e012() * b.scalar(),
+            ),
+            self.scalar() * b.e0123() + self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Rotor<F>);
+
+// Multivector-Multivector
+impl<F: Float> BitOr for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.scalar() + self.e0() * b.e0()
+                - self.e1() * b.e1()
+                - self.e2() * b.e2()
+                - self.e3() * b.e3()
+                + self.e10() * b.e10()
+                + self.e20() * b.e20()
+                + self.e30() * b.e30()
+                - self.e32() * b.e32()
+                - self.e13() * b.e13()
+                - self.e21() * b.e21()
+                + self.e123() * b.e123()
+                - self.e023() * b.e023()
+                - self.e031() * b.e031()
+                - self.e012() * b.e012()
+                - self.e0123() * b.e0123(),
+            Vector::new(
+                self.scalar() * b.e0() + self.e0() * b.scalar()
+                    - self.e1() * b.e10()
+                    - self.e2() * b.e20()
+                    - self.e3() * b.e30()
+                    + self.e10() * b.e1()
+                    + self.e20() * b.e2()
+                    + self.e30() * b.e3()
+                    + self.e32() * b.e023()
+                    + self.e13() * b.e031()
+                    + self.e21() * b.e012()
+                    - self.e123() * b.e0123()
+                    + self.e023() * b.e32()
+                    + self.e031() * b.e13()
+                    + self.e012() * b.e21()
+                    + self.e0123() * b.e123(),
+                self.scalar() * b.e1() - self.e0() * b.e10() + self.e1() * b.scalar()
+                    - self.e2() * b.e21()
+                    + self.e3() * b.e13()
+                    + self.e10() * b.e0()
+                    + self.e20() * b.e012()
+                    - self.e30() * b.e031()
+                    + self.e32() * b.e123()
+                    - self.e13() * b.e3()
+                    + self.e21() * b.e2()
+                    + self.e123() * b.e32()
+                    - self.e023() * b.e0123()
+                    - self.e031() * b.e30()
+                    + self.e012() * b.e20()
+                    + self.e0123() * b.e023(),
+                self.scalar() * b.e2() - self.e0() * b.e20()
+                    + self.e1() * b.e21()
+                    + self.e2() * b.scalar()
+                    - self.e3() * b.e32()
+                    - self.e10() * b.e012()
+                    + self.e20() * b.e0()
+                    + self.e30() * b.e023()
+                    + self.e32() * b.e3()
+                    + self.e13() * b.e123()
+                    - self.e21() * b.e1()
+                    + self.e123() * b.e13()
+                    + self.e023() * b.e30()
+                    - self.e031() * b.e0123()
+                    - self.e012() * b.e10()
+                    + self.e0123() * b.e031(),
+                self.scalar() * b.e3() - self.e0() * b.e30() - self.e1() * b.e13()
+                    + self.e2() * b.e32()
+                    + self.e3() * b.scalar()
+                    + self.e10() * b.e031()
+                    - self.e20() * b.e023()
+                    + self.e30() * b.e0()
+                    - self.e32() * b.e2()
+                    + self.e13() * b.e1()
+                    + self.e21() * b.e123()
+                    + self.e123() * b.e21()
+                    - self.e023() * b.e20()
+                    + self.e031() * b.e10()
+                    - self.e012() * b.e0123()
+                    + self.e0123() * b.e012(),
+            ),
+            Bivector::new(
+                self.scalar() * b.e10() + self.e2() * b.e012() - self.e3() * b.e031()
+                    + self.e10() * b.scalar()
+                    - self.e32() * b.e0123()
+                    - self.e031() * b.e3()
+                    + self.e012() * b.e2()
+                    - self.e0123() * b.e32(),
+                self.scalar() * b.e20() - self.e1() * b.e012()
+                    + self.e3() * b.e023()
+                    + self.e20() * b.scalar()
+                    - self.e13() * b.e0123()
+                    + self.e023() * b.e3()
+                    - self.e012() * b.e1()
+                    - self.e0123() * b.e13(),
+                self.scalar() * b.e30() + self.e1() * b.e031() - self.e2() * b.e023()
+                    + self.e30() * b.scalar()
+                    - self.e21() * b.e0123()
+                    - self.e023() * b.e2()
+                    + self.e031() * b.e1()
+                    - self.e0123() * b.e21(),
+                self.scalar() * b.e32() - self.e0() * b.e023()
+                    + self.e1() * b.e123()
+                    + self.e10() * b.e0123()
+                    + self.e32() * b.scalar()
+                    + self.e123() * b.e1()
+                    - self.e023() * b.e0()
+                    + self.e0123() * b.e10(),
+                self.scalar() * b.e13() - self.e0() * b.e031()
+                    + self.e2() * b.e123()
+                    + self.e20() * b.e0123()
+                    + self.e13() * b.scalar()
+                    + self.e123() * b.e2()
+                    - self.e031() * b.e0()
+                    + self.e0123() * b.e20(),
+                self.scalar() * b.e21() - self.e0() * b.e012()
+                    + self.e3() * b.e123()
+                    + self.e30() * b.e0123()
+                    + self.e21() * b.scalar()
+                    + self.e123() * b.e3()
+                    - self.e012() * b.e0()
+                    + self.e0123() * b.e30(),
+            ),
+            Trivector::new(
+                self.scalar() * b.e123() + self.e0() * b.e0123() + self.e123() * b.scalar()
+                    - self.e0123() * b.e0(),
+                self.scalar() * b.e023() + self.e1() * b.e0123() + self.e023() * b.scalar()
+                    - self.e0123() * b.e1(),
+                self.scalar() * b.e031() + self.e2() * b.e0123() + self.e031() * b.scalar()
+                    - self.e0123() * b.e2(),
+                self.scalar() * b.e012() + self.e3() * b.e0123() + self.e012() * b.scalar()
+                    - self.e0123() * b.e3(),
+            ),
+            self.scalar() * b.e0123() + self.e0123() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Multivector<F>);
+
+// Test
+#[cfg(test)]
+mod inner_product {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn vector_vector() {
+        // -4e0-1e1-1e2-3e3
+        let vector1 = Vector::new(-4.0, -1.0, -1.0, -3.0);
+        // 1e0+5e1+3e2-2e3
+        let vector2 = Vector::new(1.0, 5.0, 3.0, -2.0);
+        let res = vector1 | vector2;
+        // -2
+        assert_relative_eq!(res.0, -2.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_bivector() {
+        // 3e0+4e1-3e2+1e3
+        let vector1 = Vector::new(3.0, 4.0, -3.0, 1.0);
+        // 5e10+5e20-4e30-3e32+3e13-2e21
+        let bivector2 = Bivector::new(5.0, 5.0, -4.0, -3.0, 3.0, -2.0);
+        let res = vector1 | bivector2;
+        // -1e0-18e1-20e2+9e3
+        assert_relative_eq!(res.e0(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -18.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -20.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 9.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_vector() {
+        // 4e10+1e20-4e30+3e32+1e13-4e21
+        let bivector1 = Bivector::new(4.0, 1.0, -4.0, 3.0, 1.0, -4.0);
+        // -1e0-1e1+2e2-2e3
+        let vector2 = Vector::new(-1.0, -1.0, 2.0, -2.0);
+        let res = bivector1 | vector2;
+        // 6e0-10e1-11e2-3e3
+        assert_relative_eq!(res.e0(), 6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -11.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), -3.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_trivector() {
+        // -2e10-1e20+1e30-3e32-1e13+5e21
+        let bivector1 = Bivector::new(-2.0, -1.0, 1.0, -3.0, -1.0, 5.0);
+        // 1e123+5e023-1e031-4e012
+        let trivector2 = Trivector::new(1.0, 5.0, -1.0, -4.0);
+        let res = bivector1 | trivector2;
+        // -34e0+2e1-4e2+12e3
+        assert_relative_eq!(res.e0(), -34.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 12.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn trivector_vector() {
+        // 1e123+1e023-2e031+2e012
+        let trivector1 = Trivector::new(1.0, 1.0, -2.0, 2.0);
+        // -3e0+4e1-2e2+1e3
+        let vector2 = Vector::new(-3.0, 4.0, -2.0, 1.0);
+        let res = trivector1 | vector2;
+        // -2e10-7e20-6e30+7e32-8e13+7e21
+        assert_relative_eq!(res.e10(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), -7.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e30(), -6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e32(), 7.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e13(), -8.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e21(), 7.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_bivector() {
+        // 5-2e10-3e20-2e30+4e32-3e13-2e21+3e0123
+        let rotor1 = Rotor::new(5.0, Bivector::new(-2.0, -3.0, -2.0, 4.0, -3.0, -2.0), 3.0);
+        // -4e10+2e20-1e30-2e32-3e13+3e21
+        let bivector2 = Bivector::new(-4.0, 2.0, -1.0, -2.0, -3.0, 3.0);
+        let res = rotor1 | bivector2;
+        // 9-14e10+19e20-14e30-22e32-9e13+12e21
+        assert_relative_eq!(res.scalar(), 9.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e10(), -14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), 19.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e30(), -14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e32(), -22.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e13(), -9.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e21(), 12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e023(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e031(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_multivector() {
+        // -4-3e0+2e1-2e2-2e3+1e10-1e20-4e30+5e32-4e13+1e21+5e123-4e023-3e031+1e012-1e0123
+        let mvec1 = Multivector::new(
+            -4.0,
+            Vector::new(-3.0, 2.0, -2.0, -2.0),
+            Bivector::new(1.0, -1.0, -4.0, 5.0, -4.0, 1.0),
+            Trivector::new(5.0, -4.0, -3.0, 1.0),
+            -1.0,
+        );
+        // 5-2e0+3e1-4e2+4e3+5e10-2e20-2e30-3e32-3e13+4e21+3e123-2e023-1e031+5e012+4e0123
+        let mvec2 = Multivector::new(
+            5.0,
+            Vector::new(-2.0, 3.0, -4.0, 4.0),
+            Bivector::new(5.0, -2.0, -2.0, -3.0, -3.0, 4.0),
+            Trivector::new(3.0, -2.0, -1.0, 5.0),
+            4.0,
+        );
+        let res = mvec1 | mvec2;
+        // -3-33e0+38e1+13e2-16e3-42e10-9e20-43e30+43e32-45e13+6e21-1e123-1e023-23e031-19e012-21e0123
+        assert_relative_eq!(res.scalar(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), -33.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 38.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 13.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), -16.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e10(), -42.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), -9.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e30(), -43.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e32(), 43.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e13(), -45.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e21(), 6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e023(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e031(), -23.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), -19.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), -21.0, max_relative = 0.000001);
+    }
+}
@@ -0,0 +1,74 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+// The products are implemented through the bit operators
+#![allow(clippy::suspicious_arithmetic_impl)]
+
+use num_traits::Float;
+
+mod scalar;
+pub use scalar::Scalar;
+//
+mod vector;
+pub use vector::Vector;
+//
+mod bivector;
+pub use bivector::Bivector;
+//
+mod trivector;
+pub use trivector::Trivector;
+//
+mod pseudoscalar;
+pub use pseudoscalar::Pseudoscalar;
+//
+mod multivector;
+pub use multivector::Multivector;
+//
+mod rotor;
+pub use rotor::Rotor;
+
+// Interactions
+mod addition;
+mod exterior;
+mod geometric;
+mod inner;
+mod regressive;
+mod subtraction;
+// Functions
+mod functions;
+pub use functions::Rotatable;
+
+/// Operations shared by every element of the spacetime algebra.
+///
+/// The metric is not positive definite ($\gamma_1^2=\gamma_2^2=\gamma_3^2=-1$),
+/// so the norm is taken from the absolute value of the squared norm.
+pub trait STAOps<F: Float>: Clone {
+    /// Reverse $\widetilde{A}$
+    fn reverse(self) -> Self;
+    /// Clifford conjugate $\bar{A}$
+    fn conjugate(self) -> Self;
+    /// Grade involution $\hat{A}$
+    fn involute(self) -> Self;
+    /// The norm $\|A\| = \sqrt{\left| \left< A \widetilde{A} \right>_0 \right|}$
+    fn norm(self) -> Scalar<F>;
+    /// Scale the element to have a norm of one.
+    /// Null elements like light rays can not be normalized.
+    fn try_normalize(self) -> Option<Self>
+    where
+        Self: Sized;
+}
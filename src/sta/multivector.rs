@@ -0,0 +1,419 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::fmt;
+
+use core::ops::Neg;
+
+use num_traits::Float;
+
+use super::{
+    STAOps, bivector::Bivector, pseudoscalar::Pseudoscalar, rotor::Rotor, scalar::Scalar,
+    trivector::Trivector, vector::Vector,
+};
+
+/// # Spacetime Algebra Multivector
+/// The full 16 component element of $\text{Cl}(1,3,0)$
+/// $$ A = s + v + F + T + I $$
+/// with a scalar, a vector, a bivector, a trivector and a pseudoscalar part.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Multivector<F: Float> {
+    pub(super) scalar: Scalar<F>,
+    pub(super) vector: Vector<F>,
+    pub(super) bivector: Bivector<F>,
+    pub(super) trivector: Trivector<F>,
+    pub(super) pseudoscalar: Pseudoscalar<F>,
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + fmt::Display> fmt::Display for Multivector<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Multivector {{")?;
+        writeln!(f, "\tscalar: {}", self.scalar())?;
+        writeln!(f, "\tvector: {}", self.vector)?;
+        writeln!(f, "\tbivector: {}", self.bivector)?;
+        writeln!(f, "\ttrivector: {}", self.trivector)?;
+        writeln!(f, "\tpseudoscalar: {}", self.pseudoscalar)?;
+        write!(f, "}}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F: Float + defmt::Format> defmt::Format for Multivector<F> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Multivector {{\n");
+        defmt::write!(f, "\tscalar: {}\n", self.scalar());
+        defmt::write!(f, "\tvector: {}\n", self.vector);
+        defmt::write!(f, "\tbivector: {}\n", self.bivector);
+        defmt::write!(f, "\ttrivector: {}\n", self.trivector);
+        defmt::write!(f, "\tpseudoscalar: {}\n", self.pseudoscalar);
+        defmt::write!(f, "}}");
+    }
+}
+
+impl<F: Float> Multivector<F> {
+    /// The zero multivector
+    pub fn zero() -> Self {
+        Multivector {
+            scalar: Scalar::zero(),
+            vector: Vector::zero(),
+            bivector: Bivector::zero(),
+            trivector: Trivector::zero(),
+            pseudoscalar: Pseudoscalar::zero(),
+        }
+    }
+
+    /// Create new multivector from its grades
+    pub fn new(
+        scalar: F,
+        vector: Vector<F>,
+        bivector: Bivector<F>,
+        trivector: Trivector<F>,
+        e0123: F,
+    ) -> Self {
+        Multivector {
+            scalar: Scalar(scalar),
+            vector,
+            bivector,
+            trivector,
+            pseudoscalar: Pseudoscalar::new(e0123),
+        }
+    }
+
+    /// Get multivector
+    pub fn multivector(self) -> Self {
+        self
+    }
+
+    /// Get the scalar grade
+    pub fn scalar(&self) -> F {
+        self.scalar.0
+    }
+
+    /// Get the vector grade
+    pub fn vector(&self) -> Vector<F> {
+        self.vector
+    }
+
+    /// Get the bivector grade
+    pub fn bivector(&self) -> Bivector<F> {
+        self.bivector
+    }
+
+    /// Get the trivector grade
+    pub fn trivector(&self) -> Trivector<F> {
+        self.trivector
+    }
+
+    /// Get the pseudoscalar grade
+    pub fn pseudoscalar(&self) -> Pseudoscalar<F> {
+        self.pseudoscalar
+    }
+
+    /// Get the even grades as a rotor
+    pub fn rotor(&self) -> Rotor<F> {
+        Rotor::new(self.scalar(), self.bivector, self.e0123())
+    }
+
+    /// Get the $\gamma_0$ part of the vector grade
+    pub fn e0(&self) -> F {
+        self.vector.e0()
+    }
+
+    /// Get the $\gamma_1$ part of the vector grade
+    pub fn e1(&self) -> F {
+        self.vector.e1()
+    }
+
+    /// Get the $\gamma_2$ part of the vector grade
+    pub fn e2(&self) -> F {
+        self.vector.e2()
+    }
+
+    /// Get the $\gamma_3$ part of the vector grade
+    pub fn e3(&self) -> F {
+        self.vector.e3()
+    }
+
+    /// Get the $\gamma_1\gamma_0$ part of the bivector grade
+    pub fn e10(&self) -> F {
+        self.bivector.e10()
+    }
+
+    /// Get the $\gamma_2\gamma_0$ part of the bivector grade
+    pub fn e20(&self) -> F {
+        self.bivector.e20()
+    }
+
+    /// Get the $\gamma_3\gamma_0$ part of the bivector grade
+    pub fn e30(&self) -> F {
+        self.bivector.e30()
+    }
+
+    /// Get the $\gamma_3\gamma_2$ part of the bivector grade
+    pub fn e32(&self) -> F {
+        self.bivector.e32()
+    }
+
+    /// Get the $\gamma_1\gamma_3$ part of the bivector grade
+    pub fn e13(&self) -> F {
+        self.bivector.e13()
+    }
+
+    /// Get the $\gamma_2\gamma_1$ part of the bivector grade
+    pub fn e21(&self) -> F {
+        self.bivector.e21()
+    }
+
+    /// Get the $\gamma_1\gamma_2\gamma_3$ part of the trivector grade
+    pub fn e123(&self) -> F {
+        self.trivector.e123()
+    }
+
+    /// Get the $\gamma_0\gamma_2\gamma_3$ part of the trivector grade
+    pub fn e023(&self) -> F {
+        self.trivector.e023()
+    }
+
+    /// Get the $\gamma_0\gamma_3\gamma_1$ part of the trivector grade
+    pub fn e031(&self) -> F {
+        self.trivector.e031()
+    }
+
+    /// Get the $\gamma_0\gamma_1\gamma_2$ part of the trivector grade
+    pub fn e012(&self) -> F {
+        self.trivector.e012()
+    }
+
+    /// Get the $\gamma_0\gamma_1\gamma_2\gamma_3$ part of the pseudoscalar grade
+    pub fn e0123(&self) -> F {
+        self.pseudoscalar.e0123()
+    }
+
+    /// # Dual
+    /// $$ A^* = A I^{-1} $$
+    pub fn dual(self) -> Multivector<F> {
+        self * Pseudoscalar::new(-F::one())
+    }
+
+    /// # Undual
+    /// The inverse of the dual
+    /// $$ A = A^* I $$
+    pub fn undual(self) -> Multivector<F> {
+        self * Pseudoscalar::new(F::one())
+    }
+}
+
+// Negation
+impl<F: Float> Neg for Multivector<F> {
+    type Output = Multivector<F>;
+    fn neg(self) -> Multivector<F> {
+        Multivector {
+            scalar: -self.scalar,
+            vector: -self.vector,
+            bivector: -self.bivector,
+            trivector: -self.trivector,
+            pseudoscalar: -self.pseudoscalar,
+        }
+    }
+}
+
+impl<F: Float> STAOps<F> for Multivector<F> {
+    fn reverse(self) -> Self {
+        Multivector {
+            scalar: self.scalar,
+            vector: self.vector,
+            bivector: -self.bivector,
+            trivector: -self.trivector,
+            pseudoscalar: self.pseudoscalar,
+        }
+    }
+
+    fn conjugate(self) -> Self {
+        Multivector {
+            scalar: self.scalar,
+            vector: -self.vector,
+            bivector: -self.bivector,
+            trivector: self.trivector,
+            pseudoscalar: self.pseudoscalar,
+        }
+    }
+
+    fn involute(self) -> Self {
+        Multivector {
+            scalar: self.scalar,
+            vector: -self.vector,
+            bivector: self.bivector,
+            trivector: -self.trivector,
+            pseudoscalar: self.pseudoscalar,
+        }
+    }
+
+    // \[\|A\|^2=\left| \left< A \widetilde{A} \right>_0 \right|\]
+    fn norm(self) -> Scalar<F> {
+        Scalar(
+            (self.scalar() * self.scalar() + self.e0() * self.e0()
+                - self.e1() * self.e1()
+                - self.e2() * self.e2()
+                - self.e3() * self.e3()
+                - self.e10() * self.e10()
+                - self.e20() * self.e20()
+                - self.e30() * self.e30()
+                + self.e32() * self.e32()
+                + self.e13() * self.e13()
+                + self.e21() * self.e21()
+                - self.e123() * self.e123()
+                + self.e023() * self.e023()
+                + self.e031() * self.e031()
+                + self.e012() * self.e012()
+                - self.e0123() * self.e0123())
+            .abs()
+            .sqrt(),
+        )
+    }
+
+    fn try_normalize(self) -> Option<Self> {
+        self.norm()
+            .try_inverse()
+            .map(|norm_inverse| self * norm_inverse)
+    }
+}
+
+// Conversions
+// Every grade and the rotor is a multivector with the other grades set to zero
+impl<F: Float> From<Scalar<F>> for Multivector<F> {
+    fn from(scalar: Scalar<F>) -> Self {
+        Multivector {
+            scalar,
+            ..Multivector::zero()
+        }
+    }
+}
+
+impl<F: Float> From<Vector<F>> for Multivector<F> {
+    fn from(vector: Vector<F>) -> Self {
+        Multivector {
+            vector,
+            ..Multivector::zero()
+        }
+    }
+}
+
+impl<F: Float> From<Bivector<F>> for Multivector<F> {
+    fn from(bivector: Bivector<F>) -> Self {
+        Multivector {
+            bivector,
+            ..Multivector::zero()
+        }
+    }
+}
+
+impl<F: Float> From<Trivector<F>> for Multivector<F> {
+    fn from(trivector: Trivector<F>) -> Self {
+        Multivector {
+            trivector,
+            ..Multivector::zero()
+        }
+    }
+}
+
+impl<F: Float> From<Pseudoscalar<F>> for Multivector<F> {
+    fn from(pseudoscalar: Pseudoscalar<F>) -> Self {
+        Multivector {
+            pseudoscalar,
+            ..Multivector::zero()
+        }
+    }
+}
+
+impl<F: Float> From<Rotor<F>> for Multivector<F> {
+    fn from(rotor: Rotor<F>) -> Self {
+        Multivector {
+            scalar: rotor.scalar,
+            bivector: rotor.bivector,
+            pseudoscalar: rotor.pseudoscalar,
+            ..Multivector::zero()
+        }
+    }
+}
+
+#[cfg(test)]
+mod multivector_ops {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn multivector() -> Multivector<f64> {
+        Multivector::new(
+            1.0,
+            Vector::new(2.0, 3.0, -1.0, 4.0),
+            Bivector::new(1.0, -2.0, 3.0, 1.0, 2.0, -1.0),
+            Trivector::new(-1.0, 2.0, 1.0, 3.0),
+            -2.0,
+        )
+    }
+
+    #[test]
+    fn multivector_norm() {
+        let mvec = multivector();
+        // The norm is the scalar part of A times its reverse
+        let res = (mvec * mvec.reverse()).scalar();
+        assert_relative_eq!(mvec.norm().0, res.abs().sqrt(), max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_from() {
+        // The sum of the converted grades is the multivector
+        let mvec = multivector();
+        let res = Multivector::from(mvec.scalar)
+            + Multivector::from(mvec.vector())
+            + Multivector::from(mvec.bivector())
+            + Multivector::from(mvec.trivector())
+            + Multivector::from(mvec.pseudoscalar());
+        assert_eq!(res, mvec);
+        let rotor = Rotor::new(mvec.scalar(), mvec.bivector(), mvec.pseudoscalar().e0123());
+        let res = Multivector::from(rotor);
+        assert_eq!(res.scalar(), mvec.scalar());
+        assert_eq!(res.bivector(), mvec.bivector());
+        assert_eq!(res.pseudoscalar(), mvec.pseudoscalar());
+        assert_eq!(res.vector(), Vector::zero());
+        assert_eq!(res.trivector(), Trivector::zero());
+    }
+
+    #[test]
+    fn multivector_reverse() {
+        let mvec = multivector();
+        assert_eq!(mvec.reverse().reverse(), mvec);
+        assert_eq!(mvec.reverse().bivector(), -mvec.bivector());
+        assert_eq!(mvec.conjugate(), mvec.reverse().involute());
+    }
+
+    #[test]
+    fn multivector_dual() {
+        let mvec = multivector();
+        assert_eq!(mvec.dual().undual(), mvec);
+        assert_eq!(mvec.dual().vector(), mvec.trivector().dual());
+        assert_eq!(mvec.dual().bivector(), mvec.bivector().dual());
+    }
+}
@@ -0,0 +1,145 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::fmt;
+
+use core::ops::Neg;
+
+use num_traits::Float;
+
+use super::{STAOps, scalar::Scalar};
+
+/// # Spacetime Algebra Pseudoscalar
+/// $$I = a \gamma_0\gamma_1\gamma_2\gamma_3$$
+/// The unit pseudoscalar squares to $-1$ and commutes with the even elements.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Pseudoscalar<F: Float> {
+    e0123: F,
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + fmt::Display> fmt::Display for Pseudoscalar<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Pseudoscalar {{")?;
+        write!(f, " {}e0123", self.e0123)?;
+        write!(f, " }}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F: Float + defmt::Format> defmt::Format for Pseudoscalar<F> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Pseudoscalar {{");
+        defmt::write!(f, " {}e0123", self.e0123);
+        defmt::write!(f, " }}");
+    }
+}
+
+impl<F: Float> Pseudoscalar<F> {
+    /// The zero pseudoscalar
+    pub fn zero() -> Self {
+        Self { e0123: F::zero() }
+    }
+
+    /// Create new pseudoscalar from the scaling of the unit pseudoscalar
+    pub fn new(e0123: F) -> Self {
+        Self { e0123 }
+    }
+
+    /// Get pseudoscalar
+    pub fn pseudoscalar(self) -> Self {
+        self
+    }
+
+    /// Get the $\gamma_0\gamma_1\gamma_2\gamma_3$ part of the pseudoscalar
+    pub fn e0123(&self) -> F {
+        self.e0123
+    }
+
+    /// # Dual
+    /// The dual of the pseudoscalar is a scalar
+    /// $$ A^* = A I^{-1} $$
+    pub fn dual(self) -> Scalar<F> {
+        self * Pseudoscalar::new(-F::one())
+    }
+
+    /// # Undual
+    /// The inverse of the dual
+    /// $$ A = A^* I $$
+    pub fn undual(self) -> Scalar<F> {
+        self * Pseudoscalar::new(F::one())
+    }
+}
+
+// Negation
+impl<F: Float> Neg for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn neg(self) -> Pseudoscalar<F> {
+        Pseudoscalar::new(-self.e0123)
+    }
+}
+
+impl<F: Float> STAOps<F> for Pseudoscalar<F> {
+    fn reverse(self) -> Self {
+        self
+    }
+
+    fn conjugate(self) -> Self {
+        self
+    }
+
+    fn involute(self) -> Self {
+        self
+    }
+
+    fn norm(self) -> Scalar<F> {
+        Scalar((-self.e0123 * self.e0123).abs().sqrt())
+    }
+
+    fn try_normalize(self) -> Option<Self> {
+        self.norm().try_inverse().map(|norm_inverse| {
+            let norm_inverse = norm_inverse.0;
+            Pseudoscalar::new(self.e0123 * norm_inverse)
+        })
+    }
+}
+
+#[cfg(test)]
+mod pseudoscalar_ops {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn pseudoscalar_square() {
+        let pseudoscalar = Pseudoscalar::new(2.0);
+        assert_relative_eq!(
+            (pseudoscalar * pseudoscalar).0,
+            -4.0,
+            max_relative = 0.000001
+        );
+        assert_relative_eq!(pseudoscalar.dual().0, 2.0, max_relative = 0.000001);
+        assert_relative_eq!(pseudoscalar.norm().0, 2.0, max_relative = 0.000001);
+    }
+}
@@ -0,0 +1,1098 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    bivector::Bivector, multivector::Multivector, pseudoscalar::Pseudoscalar, rotor::Rotor,
+    scalar::Scalar, trivector::Trivector, vector::Vector,
+};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::BitAnd;
+
+// Regressive Product
+
+// Scalar-Scalar
+impl<F: Float> BitAnd for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Scalar<F>);
+
+// Scalar-Vector
+impl<F: Float> BitAnd<Vector<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Vector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Vector<F>);
+
+// Vector-Scalar
+impl<F: Float> BitAnd<Scalar<F>> for Vector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Scalar<F>);
+
+// Scalar-Bivector
+impl<F: Float> BitAnd<Bivector<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Bivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Bivector<F>);
+
+// Bivector-Scalar
+impl<F: Float> BitAnd<Scalar<F>> for Bivector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Scalar<F>);
+
+// Scalar-Trivector
+impl<F: Float> BitAnd<Trivector<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Trivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Trivector<F>);
+
+// Trivector-Scalar
+impl<F: Float> BitAnd<Scalar<F>> for Trivector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Trivector<F>, Scalar<F>);
+
+// Scalar-Pseudoscalar
+impl<F: Float> BitAnd<Pseudoscalar<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Scalar<F> {
+        Scalar(self.0 * b.e0123())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Scalar
+impl<F: Float> BitAnd<Scalar<F>> for Pseudoscalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Scalar<F>) -> Scalar<F> {
+        Scalar(self.e0123() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Scalar<F>);
+
+// Scalar-Rotor
+impl<F: Float> BitAnd<Rotor<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Rotor<F>) -> Scalar<F> {
+        Scalar(self.0 * b.e0123())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Rotor<F>);
+
+// Rotor-Scalar
+impl<F: Float> BitAnd<Scalar<F>> for Rotor<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Scalar<F>) -> Scalar<F> {
+        Scalar(self.e0123() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Rotor<F>, Scalar<F>);
+
+// Scalar-Multivector
+impl<F: Float> BitAnd<Multivector<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Multivector<F>) -> Scalar<F> {
+        Scalar(self.0 * b.e0123())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Multivector<F>);
+
+// Multivector-Scalar
+impl<F: Float> BitAnd<Scalar<F>> for Multivector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Scalar<F>) -> Scalar<F> {
+        Scalar(self.e0123() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Scalar<F>);
+
+// Vector-Vector
+impl<F: Float> BitAnd for Vector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Vector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Vector<F>);
+
+// Vector-Bivector
+impl<F: Float> BitAnd<Bivector<F>> for Vector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Bivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Bivector<F>);
+
+// Bivector-Vector
+impl<F: Float> BitAnd<Vector<F>> for Bivector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Vector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Vector<F>);
+
+// Vector-Trivector
+impl<F: Float> BitAnd<Trivector<F>> for Vector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Trivector<F>) -> Scalar<F> {
+        Scalar(
+            -self.e0() * b.e123()
+                + self.e1() * b.e023()
+                + self.e2() * b.e031()
+                + self.e3() * b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Trivector<F>);
+
+// Trivector-Vector
+impl<F: Float> BitAnd<Vector<F>> for Trivector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Vector<F>) -> Scalar<F> {
+        Scalar(
+            self.e123() * b.e0()
+                - self.e023() * b.e1()
+                - self.e031() * b.e2()
+                - self.e012() * b.e3(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Trivector<F>, Vector<F>);
+
+// Vector-Pseudoscalar
+impl<F: Float> BitAnd<Pseudoscalar<F>> for Vector<F> {
+    type Output = Vector<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Vector<F> {
+        Vector::new(
+            self.e0() * b.e0123(),
+            self.e1() * b.e0123(),
+            self.e2() * b.e0123(),
+            self.e3() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Vector
+impl<F: Float> BitAnd<Vector<F>> for Pseudoscalar<F> {
+    type Output = Vector<F>;
+    fn bitand(self, b: Vector<F>) -> Vector<F> {
+        Vector::new(
+            self.e0123() * b.e0(),
+            self.e0123() * b.e1(),
+            self.e0123() * b.e2(),
+            self.e0123() * b.e3(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Vector<F>);
+
+// Vector-Rotor
+impl<F: Float> BitAnd<Rotor<F>> for Vector<F> {
+    type Output = Vector<F>;
+    fn bitand(self, b: Rotor<F>) -> Vector<F> {
+        Vector::new(
+            self.e0() * b.e0123(),
+            self.e1() * b.e0123(),
+            self.e2() * b.e0123(),
+            self.e3() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Rotor<F>);
+
+// Rotor-Vector
+impl<F: Float> BitAnd<Vector<F>> for Rotor<F> {
+    type Output = Vector<F>;
+    fn bitand(self, b: Vector<F>) -> Vector<F> {
+        Vector::new(
+            self.e0123() * b.e0(),
+            self.e0123() * b.e1(),
+            self.e0123() * b.e2(),
+            self.e0123() * b.e3(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Rotor<F>, Vector<F>);
+
+// Vector-Multivector
+impl<F: Float> BitAnd<Multivector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e0() * b.e123()
+                + self.e1() * b.e023()
+                + self.e2() * b.e031()
+                + self.e3() * b.e012(),
+            Vector::new(
+                self.e0() * b.e0123(),
+                self.e1() * b.e0123(),
+                self.e2() * b.e0123(),
+                self.e3() * b.e0123(),
+            ),
+            Bivector::zero(),
+            Trivector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Multivector<F>);
+
+// Multivector-Vector
+impl<F: Float> BitAnd<Vector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e123() * b.e0()
+                - self.e023() * b.e1()
+                - self.e031() * b.e2()
+                - self.e012() * b.e3(),
+            Vector::new(
+                self.e0123() * b.e0(),
+                self.e0123() * b.e1(),
+                self.e0123() * b.e2(),
+                self.e0123() * b.e3(),
+            ),
+            Bivector::zero(),
+            Trivector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Vector<F>);
+
+// Bivector-Bivector
+impl<F: Float> BitAnd for Bivector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Bivector<F>) -> Scalar<F> {
+        Scalar(
+            self.e10() * b.e32()
+                + self.e20() * b.e13()
+                + self.e30() * b.e21()
+                + self.e32() * b.e10()
+                + self.e13() * b.e20()
+                + self.e21() * b.e30(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Bivector<F>);
+
+// Bivector-Trivector
+impl<F: Float> BitAnd<Trivector<F>> for Bivector<F> {
+    type Output = Vector<F>;
+    fn bitand(self, b: Trivector<F>) -> Vector<F> {
+        Vector::new(
+            -self.e10() * b.e023() - self.e20() * b.e031() - self.e30() * b.e012(),
+            -self.e10() * b.e123() + self.e13() * b.e012() - self.e21() * b.e031(),
+            -self.e20() * b.e123() - self.e32() * b.e012() + self.e21() * b.e023(),
+            -self.e30() * b.e123() + self.e32() * b.e031() - self.e13() * b.e023(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Trivector<F>);
+
+// Trivector-Bivector
+impl<F: Float> BitAnd<Bivector<F>> for Trivector<F> {
+    type Output = Vector<F>;
+    fn bitand(self, b: Bivector<F>) -> Vector<F> {
+        Vector::new(
+            -self.e023() * b.e10() - self.e031() * b.e20() - self.e012() * b.e30(),
+            -self.e123() * b.e10() - self.e031() * b.e21() + self.e012() * b.e13(),
+            -self.e123() * b.e20() + self.e023() * b.e21() - self.e012() * b.e32(),
+            -self.e123() * b.e30() - self.e023() * b.e13() + self.e031() * b.e32(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Trivector<F>, Bivector<F>);
+
+// Bivector-Pseudoscalar
+impl<F: Float> BitAnd<Pseudoscalar<F>> for Bivector<F> {
+    type Output = Bivector<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Bivector<F> {
+        Bivector::new(
+            self.e10() * b.e0123(),
+            self.e20() * b.e0123(),
+            self.e30() * b.e0123(),
+            self.e32() * b.e0123(),
+            self.e13() * b.e0123(),
+            self.e21() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Bivector
+impl<F: Float> BitAnd<Bivector<F>> for Pseudoscalar<F> {
+    type Output = Bivector<F>;
+    fn bitand(self, b: Bivector<F>) -> Bivector<F> {
+        Bivector::new(
+            self.e0123() * b.e10(),
+            self.e0123() * b.e20(),
+            self.e0123() * b.e30(),
+            self.e0123() * b.e32(),
+            self.e0123() * b.e13(),
+            self.e0123() * b.e21(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Bivector<F>);
+
+// Bivector-Rotor
+impl<F: Float> BitAnd<Rotor<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e10() * b.e32()
+                + self.e20() * b.e13()
+                + self.e30() * b.e21()
+                + self.e32() * b.e10()
+                + self.e13() * b.e20()
+                + self.e21() * b.e30(),
+            Vector::zero(),
+            Bivector::new(
+                self.e10() * b.e0123(),
+                self.e20() * b.e0123(),
+                self.e30() * b.e0123(),
+                self.e32() * b.e0123(),
+                self.e13() * b.e0123(),
+                self.e21() * b.e0123(),
+            ),
+            Trivector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Rotor<F>);
+
+// Rotor-Bivector
+impl<F: Float> BitAnd<Bivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e10() * b.e32()
+                + self.e20() * b.e13()
+                + self.e30() * b.e21()
+                + self.e32() * b.e10()
+                + self.e13() * b.e20()
+                + self.e21() * b.e30(),
+            Vector::zero(),
+            Bivector::new(
+                self.e0123() * b.e10(),
+                self.e0123() * b.e20(),
+                self.e0123() * b.e30(),
+                self.e0123() * b.e32(),
+                self.e0123() * b.e13(),
+                self.e0123() * b.e21(),
+            ),
+            Trivector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Rotor<F>, Bivector<F>);
+
+// Bivector-Multivector
+impl<F: Float> BitAnd<Multivector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e10() * b.e32()
+                + self.e20() * b.e13()
+                + self.e30() * b.e21()
+                + self.e32() * b.e10()
+                + self.e13() * b.e20()
+                + self.e21() * b.e30(),
+            Vector::new(
+                -self.e10() * b.e023() - self.e20() * b.e031() - self.e30() * b.e012(),
+                -self.e10() * b.e123() + self.e13() * b.e012() - self.e21() * b.e031(),
+                -self.e20() * b.e123() - self.e32() * b.e012() + self.e21() * b.e023(),
+                -self.e30() * b.e123() + self.e32() * b.e031() - self.e13() * b.e023(),
+            ),
+            Bivector::new(
+                self.e10() * b.e0123(),
+                self.e20() * b.e0123(),
+                self.e30() * b.e0123(),
+                self.e32() * b.e0123(),
+                self.e13() * b.e0123(),
+                self.e21() * b.e0123(),
+            ),
+            Trivector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Multivector<F>);
+
+// Multivector-Bivector
+impl<F: Float> BitAnd<Bivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e10() * b.e32()
+                + self.e20() * b.e13()
+                + self.e30() * b.e21()
+                + self.e32() * b.e10()
+                + self.e13() * b.e20()
+                + self.e21() * b.e30(),
+            Vector::new(
+                -self.e023() * b.e10() - self.e031() * b.e20() - self.e012() * b.e30(),
+                -self.e123() * b.e10() - self.e031() * b.e21() + self.e012() * b.e13(),
+                -self.e123() * b.e20() + self.e023() * b.e21() - self.e012() * b.e32(),
+                -self.e123() * b.e30() - self.e023() * b.e13() + self.e031() * b.e32(),
+            ),
+            Bivector::new(
+                self.e0123() * b.e10(),
+                self.e0123() * b.e20(),
+                self.e0123() * b.e30(),
+                self.e0123() * b.e32(),
+                self.e0123() * b.e13(),
+                self.e0123() * b.e21(),
+            ),
+            Trivector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Bivector<F>);
+
+// Trivector-Trivector
+impl<F: Float> BitAnd for Trivector<F> {
+    type Output = Bivector<F>;
+    fn bitand(self, b: Trivector<F>) -> Bivector<F> {
+        Bivector::new(
+            self.e031() * b.e012() - self.e012() * b.e031(),
+            -self.e023() * b.e012() + self.e012() * b.e023(),
+            self.e023() * b.e031() - self.e031() * b.e023(),
+            -self.e123() * b.e023() + self.e023() * b.e123(),
+            -self.e123() * b.e031() + self.e031() * b.e123(),
+            -self.e123() * b.e012() + self.e012() * b.e123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Trivector<F>, Trivector<F>);
+
+// Trivector-Pseudoscalar
+impl<F: Float> BitAnd<Pseudoscalar<F>> for Trivector<F> {
+    type Output = Trivector<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Trivector<F> {
+        Trivector::new(
+            self.e123() * b.e0123(),
+            self.e023() * b.e0123(),
+            self.e031() * b.e0123(),
+            self.e012() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Trivector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Trivector
+impl<F: Float> BitAnd<Trivector<F>> for Pseudoscalar<F> {
+    type Output = Trivector<F>;
+    fn bitand(self, b: Trivector<F>) -> Trivector<F> {
+        Trivector::new(
+            self.e0123() * b.e123(),
+            self.e0123() * b.e023(),
+            self.e0123() * b.e031(),
+            self.e0123() * b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Trivector<F>);
+
+// Trivector-Rotor
+impl<F: Float> BitAnd<Rotor<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                -self.e023() * b.e10() - self.e031() * b.e20() - self.e012() * b.e30(),
+                -self.e123() * b.e10() - self.e031() * b.e21() + self.e012() * b.e13(),
+                -self.e123() * b.e20() + self.e023() * b.e21() - self.e012() * b.e32(),
+                -self.e123() * b.e30() - self.e023() * b.e13() + self.e031() * b.e32(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                self.e123() * b.e0123(),
+                self.e023() * b.e0123(),
+                self.e031() * b.e0123(),
+                self.e012() * b.e0123(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Trivector<F>, Rotor<F>);
+
+// Rotor-Trivector
+impl<F: Float> BitAnd<Trivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(
+                -self.e10() * b.e023() - self.e20() * b.e031() - self.e30() * b.e012(),
+                -self.e10() * b.e123() + self.e13() * b.e012() - self.e21() * b.e031(),
+                -self.e20() * b.e123() - self.e32() * b.e012() + self.e21() * b.e023(),
+                -self.e30() * b.e123() + self.e32() * b.e031() - self.e13() * b.e023(),
+            ),
+            Bivector::zero(),
+            Trivector::new(
+                self.e0123() * b.e123(),
+                self.e0123() * b.e023(),
+                self.e0123() * b.e031(),
+                self.e0123() * b.e012(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Rotor<F>, Trivector<F>);
+
+// Trivector-Multivector
+impl<F: Float> BitAnd<Multivector<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e123() * b.e0()
+                - self.e023() * b.e1()
+                - self.e031() * b.e2()
+                - self.e012() * b.e3(),
+            Vector::new(
+                -self.e023() * b.e10() - self.e031() * b.e20() - self.e012() * b.e30(),
+                -self.e123() * b.e10() - self.e031() * b.e21() + self.e012() * b.e13(),
+                -self.e123() * b.e20() + self.e023() * b.e21() - self.e012() * b.e32(),
+                -self.e123() * b.e30() - self.e023() * b.e13() + self.e031() * b.e32(),
+            ),
+            Bivector::new(
+                self.e031() * b.e012() - self.e012() * b.e031(),
+                -self.e023() * b.e012() + self.e012() * b.e023(),
+                self.e023() * b.e031() - self.e031() * b.e023(),
+                -self.e123() * b.e023() + self.e023() * b.e123(),
+                -self.e123() * b.e031() + self.e031() * b.e123(),
+                -self.e123() * b.e012() + self.e012() * b.e123(),
+            ),
+            Trivector::new(
+                self.e123() * b.e0123(),
+                self.e023() * b.e0123(),
+                self.e031() * b.e0123(),
+                self.e012() * b.e0123(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Trivector<F>, Multivector<F>);
+
+// Multivector-Trivector
+impl<F: Float> BitAnd<Trivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -self.e0() * b.e123()
+                + self.e1() * b.e023()
+                + self.e2() * b.e031()
+                + self.e3() * b.e012(),
+            Vector::new(
+                -self.e10() * b.e023() - self.e20() * b.e031() - self.e30() * b.e012(),
+                -self.e10() * b.e123() + self.e13() * b.e012() - self.e21() * b.e031(),
+                -self.e20() * b.e123() - self.e32() * b.e012() + self.e21() * b.e023(),
+                -self.e30() * b.e123() + self.e32() * b.e031() - self.e13() * b.e023(),
+            ),
+            Bivector::new(
+                self.e031() * b.e012() - self.e012() * b.e031(),
+                -self.e023() * b.e012() + self.e012() * b.e023(),
+                self.e023() * b.e031() - self.e031() * b.e023(),
+                -self.e123() * b.e023() + self.e023() * b.e123(),
+                -self.e123() * b.e031() + self.e031() * b.e123(),
+                -self.e123() * b.e012() + self.e012() * b.e123(),
+            ),
+            Trivector::new(
+                self.e0123() * b.e123(),
+                self.e0123() * b.e023(),
+                self.e0123() * b.e031(),
+                self.e0123() * b.e012(),
+            ),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Trivector<F>);
+
+// Pseudoscalar-Pseudoscalar
+impl<F: Float> BitAnd for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.e0123() * b.e0123())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Rotor
+impl<F: Float> BitAnd<Rotor<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e0123() * b.scalar(),
+            Vector::zero(),
+            Bivector::new(
+                self.e0123() * b.e10(),
+                self.e0123() * b.e20(),
+                self.e0123() * b.e30(),
+                self.e0123() * b.e32(),
+                self.e0123() * b.e13(),
+                self.e0123() * b.e21(),
+            ),
+            Trivector::zero(),
+            self.e0123() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Rotor<F>);
+
+// Rotor-Pseudoscalar
+impl<F: Float> BitAnd<Pseudoscalar<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.e0123(),
+            Vector::zero(),
+            Bivector::new(
+                self.e10() * b.e0123(),
+                self.e20() * b.e0123(),
+                self.e30() * b.e0123(),
+                self.e32() * b.e0123(),
+                self.e13() * b.e0123(),
+                self.e21() * b.e0123(),
+            ),
+            Trivector::zero(),
+            self.e0123() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Rotor<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Multivector
+impl<F: Float> BitAnd<Multivector<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e0123() * b.scalar(),
+            Vector::new(
+                self.e0123() * b.e0(),
+                self.e0123() * b.e1(),
+                self.e0123() * b.e2(),
+                self.e0123() * b.e3(),
+            ),
+            Bivector::new(
+                self.e0123() * b.e10(),
+                self.e0123() * b.e20(),
+                self.e0123() * b.e30(),
+                self.e0123() * b.e32(),
+                self.e0123() * b.e13(),
+                self.e0123() * b.e21(),
+            ),
+            Trivector::new(
+                self.e0123() * b.e123(),
+                self.e0123() * b.e023(),
+                self.e0123() * b.e031(),
+                self.e0123() * b.e012(),
+            ),
+            self.e0123() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Pseudoscalar<F>, Multivector<F>);
+
+// Multivector-Pseudoscalar
+impl<F: Float> BitAnd<Pseudoscalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.e0123(),
+            Vector::new(
+                self.e0() * b.e0123(),
+                self.e1() * b.e0123(),
+                self.e2() * b.e0123(),
+                self.e3() * b.e0123(),
+            ),
+            Bivector::new(
+                self.e10() * b.e0123(),
+                self.e20() * b.e0123(),
+                self.e30() * b.e0123(),
+                self.e32() * b.e0123(),
+                self.e13() * b.e0123(),
+                self.e21() * b.e0123(),
+            ),
+            Trivector::new(
+                self.e123() * b.e0123(),
+                self.e023() * b.e0123(),
+                self.e031() * b.e0123(),
+                self.e012() * b.e0123(),
+            ),
+            self.e0123() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Pseudoscalar<F>);
+
+// Rotor-Rotor
+impl<F: Float> BitAnd for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.e0123()
+                + self.e10() * b.e32()
+                + self.e20() * b.e13()
+                + self.e30() * b.e21()
+                + self.e32() * b.e10()
+                + self.e13() * b.e20()
+                + self.e21() * b.e30()
+                + self.e0123() * b.scalar(),
+            Vector::zero(),
+            Bivector::new(
+                self.e10() * b.e0123() + self.e0123() * b.e10(),
+                self.e20() * b.e0123() + self.e0123() * b.e20(),
+                self.e30() * b.e0123() + self.e0123() * b.e30(),
+                self.e32() * b.e0123() + self.e0123() * b.e32(),
+                self.e13() * b.e0123() + self.e0123() * b.e13(),
+                self.e21() * b.e0123() + self.e0123() * b.e21(),
+            ),
+            Trivector::zero(),
+            self.e0123() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Rotor<F>, Rotor<F>);
+
+// Rotor-Multivector
+impl<F: Float> BitAnd<Multivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.e0123()
+                + self.e10() * b.e32()
+                + self.e20() * b.e13()
+                + self.e30() * b.e21()
+                + self.e32() * b.e10()
+                + self.e13() * b.e20()
+                + self.e21() * b.e30()
+                + self.e0123() * b.scalar(),
+            Vector::new(
+                -self.e10() * b.e023() - self.e20() * b.e031() - self.e30() * b.e012()
+                    + self.e0123() * b.e0(),
+                -self.e10() * b.e123() + self.e13() * b.e012() - self.e21() * b.e031()
+                    + self.e0123() * b.e1(),
+                -self.e20() * b.e123() - self.e32() * b.e012()
+                    + self.e21() * b.e023()
+                    + self.e0123() * b.e2(),
+                -self.e30() * b.e123() + self.e32() * b.e031() - self.e13() * b.e023()
+                    + self.e0123() * b.e3(),
+            ),
+            Bivector::new(
+                self.e10() * b.e0123() + self.e0123() * b.e10(),
+                self.e20() * b.e0123() + self.e0123() * b.e20(),
+                self.e30() * b.e0123() + self.e0123() * b.e30(),
+                self.e32() * b.e0123() + self.e0123() * b.e32(),
+                self.e13() * b.e0123() + self.e0123() * b.e13(),
+                self.e21() * b.e0123() + self.e0123() * b.e21(),
+            ),
+            Trivector::new(
+                self.e0123() * b.e123(),
+                self.e0123() * b.e023(),
+                self.e0123() * b.e031(),
+                self.e0123() * b.e012(),
+            ),
+            self.e0123() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Rotor<F>, Multivector<F>);
+
+// Multivector-Rotor
+impl<F: Float> BitAnd<Rotor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.e0123()
+                + self.e10() * b.e32()
+                + self.e20() * b.e13()
+                + self.e30() * b.e21()
+                + self.e32() * b.e10()
+                + self.e13() * b.e20()
+                + self.e21() * b.e30()
+                + self.e0123() * b.scalar(),
+            Vector::new(
+                self.e0() * b.e0123()
+                    - self.e023() * b.e10()
+                    - self.e031() * b.e20()
+                    - self.e012() * b.e30(),
+                self.e1() * b.e0123() - self.e123() * b.e10() - self.e031() * b.e21()
+                    + self.e012() * b.e13(),
+                self.e2() * b.e0123() - self.e123() * b.e20() + self.e023() * b.e21()
+                    - self.e012() * b.e32(),
+                self.e3() * b.e0123() - self.e123() * b.e30() - self.e023() * b.e13()
+                    + self.e031() * b.e32(),
+            ),
+            Bivector::new(
+                self.e10() * b.e0123() + self.e0123() * b.e10(),
+                self.e20() * b.e0123() + self.e0123() * b.e20(),
+                self.e30() * b.e0123() + self.e0123() * b.e30(),
+                self.e32() * b.e0123() + self.e0123() * b.e32(),
+                self.e13() * b.e0123() + self.e0123() * b.e13(),
+                self.e21() * b.e0123() + self.e0123() * b.e21(),
+            ),
+            Trivector::new(
+                self.e123() * b.e0123(),
+                self.e023() * b.e0123(),
+                self.e031() * b.e0123(),
+                self.e012() * b.e0123(),
+            ),
+            self.e0123() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Rotor<F>);
+
+// Multivector-Multivector
+impl<F: Float> BitAnd for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.e0123() - self.e0() * b.e123()
+                + self.e1() * b.e023()
+                + self.e2() * b.e031()
+                + self.e3() * b.e012()
+                + self.e10() * b.e32()
+                + self.e20() * b.e13()
+                + self.e30() * b.e21()
+                + self.e32() * b.e10()
+                + self.e13() * b.e20()
+                + self.e21() * b.e30()
+                + self.e123() * b.e0()
+                - self.e023() * b.e1()
+                - self.e031() * b.e2()
+                - self.e012() * b.e3()
+                + self.e0123() * b.scalar(),
+            Vector::new(
+                self.e0() * b.e0123()
+                    - self.e10() * b.e023()
+                    - self.e20() * b.e031()
+                    - self.e30() * b.e012()
+                    - self.e023() * b.e10()
+                    - self.e031() * b.e20()
+                    - self.e012() * b.e30()
+                    + self.e0123() * b.e0(),
+                self.e1() * b.e0123() - self.e10() * b.e123() + self.e13() * b.e012()
+                    - self.e21() * b.e031()
+                    - self.e123() * b.e10()
+                    - self.e031() * b.e21()
+                    + self.e012() * b.e13()
+                    + self.e0123() * b.e1(),
+                self.e2() * b.e0123() - self.e20() * b.e123() - self.e32() * b.e012()
+                    + self.e21() * b.e023()
+                    - self.e123() * b.e20()
+                    + self.e023() * b.e21()
+                    - self.e012() * b.e32()
+                    + self.e0123() * b.e2(),
+                self.e3() * b.e0123() - self.e30() * b.e123() + self.e32() * b.e031()
+                    - self.e13() * b.e023()
+                    - self.e123() * b.e30()
+                    - self.e023() * b.e13()
+                    + self.e031() * b.e32()
+                    + self.e0123() * b.e3(),
+            ),
+            Bivector::new(
+                self.e10() * b.e0123() + self.e031() * b.e012() - self.e012() * b.e031()
+                    + self.e0123() * b.e10(),
+                self.e20() * b.e0123() - self.e023() * b.e012()
+                    + self.e012() * b.e023()
+                    + self.e0123() * b.e20(),
+                self.e30() * b.e0123() + self.e023() * b.e031() - self.e031() * b.e023()
+                    + self.e0123() * b.e30(),
+                self.e32() * b.e0123() - self.e123() * b.e023()
+                    + self.e023() * b.e123()
+                    + self.e0123() * b.e32(),
+                self.e13() * b.e0123() - self.e123() * b.e031()
+                    + self.e031() * b.e123()
+                    + self.e0123() * b.e13(),
+                self.e21() * b.e0123() - self.e123() * b.e012()
+                    + self.e012() * b.e123()
+                    + self.e0123() * b.e21(),
+            ),
+            Trivector::new(
+                self.e123() * b.e0123() + self.e0123() * b.e123(),
+                self.e023() * b.e0123() + self.e0123() * b.e023(),
+                self.e031() * b.e0123() + self.e0123() * b.e031(),
+                self.e012() * b.e0123() + self.e0123() * b.e012(),
+            ),
+            self.e0123() * b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Multivector<F>);
+
+// Test
+#[cfg(test)]
+mod regressive_product {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn trivector_trivector() {
+        // 5e123+5e023+5e031+3e012
+        let trivector1 = Trivector::new(5.0, 5.0, 5.0, 3.0);
+        // 4e123-1e023-4e031+3e012
+        let trivector2 = Trivector::new(4.0, -1.0, -4.0, 3.0);
+        let res = trivector1 & trivector2;
+        // 27e10-18e20-15e30+25e32+40e13-3e21
+        assert_relative_eq!(res.e10(), 27.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), -18.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e30(), -15.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e32(), 25.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e13(), 40.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e21(), -3.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn trivector_bivector() {
+        // 3e123-1e023+5e031+3e012
+        let trivector1 = Trivector::new(3.0, -1.0, 5.0, 3.0);
+        // 2e10-3e20+5e30-4e32-1e13-2e21
+        let bivector2 = Bivector::new(2.0, -3.0, 5.0, -4.0, -1.0, -2.0);
+        let res = trivector1 & bivector2;
+        // 2e0+1e1+23e2-36e3
+        assert_relative_eq!(res.e0(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 23.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), -36.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_bivector() {
+        // 5e10-4e20+5e30-3e32+2e13+3e21
+        let bivector1 = Bivector::new(5.0, -4.0, 5.0, -3.0, 2.0, 3.0);
+        // 4e10+1e20+5e30+4e32+3e13+1e21
+        let bivector2 = Bivector::new(4.0, 1.0, 5.0, 4.0, 3.0, 1.0);
+        let res = bivector1 & bivector2;
+        // 18
+        assert_relative_eq!(res.0, 18.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_trivector() {
+        // -2e0-3e1-4e2+1e3
+        let vector1 = Vector::new(-2.0, -3.0, -4.0, 1.0);
+        // -1e123+3e023+1e031-1e012
+        let trivector2 = Trivector::new(-1.0, 3.0, 1.0, -1.0);
+        let res = vector1 & trivector2;
+        // -16
+        assert_relative_eq!(res.0, -16.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_trivector() {
+        // 1+5e10+3e20-1e30-2e32-4e13-1e21-4e0123
+        let rotor1 = Rotor::new(1.0, Bivector::new(5.0, 3.0, -1.0, -2.0, -4.0, -1.0), -4.0);
+        // -4e123+4e023+3e031-3e012
+        let trivector2 = Trivector::new(-4.0, 4.0, 3.0, -3.0);
+        let res = rotor1 & trivector2;
+        // -32e0+35e1+2e2+6e3+16e123-16e023-12e031+12e012
+        assert_relative_eq!(res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), -32.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 35.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e10(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e30(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e32(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e13(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e21(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 16.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e023(), -16.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e031(), -12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), 12.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_multivector() {
+        // 5+4e0-2e1+1e2-4e3+4e10-1e20-2e30-1e32+1e13-3e21-1e123+5e023-2e031+4e012+5e0123
+        let mvec1 = Multivector::new(
+            5.0,
+            Vector::new(4.0, -2.0, 1.0, -4.0),
+            Bivector::new(4.0, -1.0, -2.0, -1.0, 1.0, -3.0),
+            Trivector::new(-1.0, 5.0, -2.0, 4.0),
+            5.0,
+        );
+        // -2+1e0-4e1-4e2+4e3-4e10+2e20+3e30+1e32-2e13+2e21+1e123+3e023+4e031-3e012-2e0123
+        let mvec2 = Multivector::new(
+            -2.0,
+            Vector::new(1.0, -4.0, -4.0, 4.0),
+            Bivector::new(-4.0, 2.0, 3.0, 1.0, -2.0, 2.0),
+            Trivector::new(1.0, 3.0, 4.0, -3.0),
+            -2.0,
+        );
+        let res = mvec1 & mvec2;
+        // -20-5e0-19e1-25e2+34e3-38e10+39e20+45e30+15e32-10e13+17e21+7e123+5e023+24e031-23e012-10e0123
+        assert_relative_eq!(res.scalar(), -20.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), -5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -19.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -25.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 34.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e10(), -38.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), 39.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e30(), 45.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e32(), 15.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e13(), -10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e21(), 17.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 7.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e023(), 5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e031(), 24.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), -23.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), -10.0, max_relative = 0.000001);
+    }
+}
@@ -0,0 +1,436 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::fmt;
+
+use crate::forward_ref_binop;
+use core::ops::{Mul, Neg};
+
+use num_traits::Float;
+
+use super::{
+    STAOps, bivector::Bivector, pseudoscalar::Pseudoscalar, scalar::Scalar, vector::Vector,
+};
+use crate::vga3d;
+
+/// # Spacetime Algebra Rotor
+///
+/// The rotors are the Lorentz transformations, which are the boosts and the spatial rotations.
+/// $$ R = s + F + I $$
+/// An element $X$ is transformed by the sandwich product
+/// $$ X' = \widetilde{R} X R $$
+/// which is the same order as the rotation with a [`vga3d::Rotor`].
+///
+/// Rotors are composed with the geometric product.
+/// The rotor $R_1 R_2$ first applies $R_1$ and then $R_2$.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Rotor<F: Float> {
+    pub(super) scalar: Scalar<F>,
+    pub(super) bivector: Bivector<F>,
+    pub(super) pseudoscalar: Pseudoscalar<F>,
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + fmt::Display> fmt::Display for Rotor<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Rotor {{")?;
+        writeln!(f, "\tscalar: {}", self.scalar())?;
+        writeln!(f, "\tbivector: {}", self.bivector)?;
+        writeln!(f, "\tpseudoscalar: {}", self.pseudoscalar)?;
+        write!(f, "}}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F: Float + defmt::Format> defmt::Format for Rotor<F> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Rotor {{\n");
+        defmt::write!(f, "\tscalar: {}\n", self.scalar());
+        defmt::write!(f, "\tbivector: {}\n", self.bivector);
+        defmt::write!(f, "\tpseudoscalar: {}\n", self.pseudoscalar);
+        defmt::write!(f, "}}");
+    }
+}
+
+impl<F: Float> Rotor<F> {
+    /// Create new rotor from its grades
+    pub fn new(scalar: F, bivector: Bivector<F>, e0123: F) -> Self {
+        Rotor {
+            scalar: Scalar(scalar),
+            bivector,
+            pseudoscalar: Pseudoscalar::new(e0123),
+        }
+    }
+
+    /// This is the identity rotor.
+    /// Anything transformed with this will return it self.
+    pub fn identity() -> Self {
+        Rotor {
+            scalar: Scalar(F::one()),
+            bivector: Bivector::zero(),
+            pseudoscalar: Pseudoscalar::zero(),
+        }
+    }
+
+    /// Tries to create the boost with a rapidity $\phi$ along a direction $\hat{n}$ in the rest frame of $\gamma_0$
+    /// $$ R = \cosh\left( \frac{\phi}{2} \right) - \sinh\left( \frac{\phi}{2} \right) \hat{n} $$
+    /// where $\hat{n}$ is a relative vector.
+    /// The boost gives an observer at rest the velocity $\tanh(\phi) \hat{n}$.
+    /// The direction must not be zero.
+    pub fn try_from_boost(rapidity: F, direction: vga3d::Vector<F>) -> Option<Self> {
+        let length = (direction.e1() * direction.e1()
+            + direction.e2() * direction.e2()
+            + direction.e3() * direction.e3())
+        .sqrt();
+        if length.is_zero() {
+            return None;
+        }
+        let half_rapidity = rapidity / (F::one() + F::one());
+        let factor = -half_rapidity.sinh() / length;
+        Some(Rotor {
+            scalar: Scalar(half_rapidity.cosh()),
+            bivector: Bivector::new(
+                direction.e1() * factor,
+                direction.e2() * factor,
+                direction.e3() * factor,
+                F::zero(),
+                F::zero(),
+                F::zero(),
+            ),
+            pseudoscalar: Pseudoscalar::zero(),
+        })
+    }
+
+    /// Tries to create the boost that gives an observer at rest a velocity.
+    /// The speed must be less than the speed of light.
+    /// $$ \phi = \tanh^{-1}\left( \left| \vec{v} \right| \right) $$
+    pub fn try_from_velocity(velocity: vga3d::Vector<F>) -> Option<Self> {
+        let speed = (velocity.e1() * velocity.e1()
+            + velocity.e2() * velocity.e2()
+            + velocity.e3() * velocity.e3())
+        .sqrt();
+        if speed >= F::one() {
+            return None;
+        }
+        if speed.is_zero() {
+            return Some(Self::identity());
+        }
+        Self::try_from_boost(speed.atanh(), velocity)
+    }
+
+    /// Tries to create the boost that takes one observer to another
+    /// $$ R = \frac{1 + u v}{\sqrt{2\left( 1 + u \cdot v \right)}} $$
+    /// so that $\widetilde{R} u R = v$.
+    /// Both observers must be timelike and point to the future.
+    pub fn try_from_observers(from: Vector<F>, to: Vector<F>) -> Option<Self> {
+        let is_future = |vector: Vector<F>| {
+            let position = vector.position();
+            let length = (position.e1() * position.e1()
+                + position.e2() * position.e2()
+                + position.e3() * position.e3())
+            .sqrt();
+            vector.time() > length
+        };
+        if !is_future(from) || !is_future(to) {
+            return None;
+        }
+        let from = from.try_normalize()?;
+        let to = to.try_normalize()?;
+        let product = from * to;
+        let norm_inverse = ((F::one() + F::one()) * (F::one() + product.scalar()))
+            .sqrt()
+            .recip();
+        Some(Rotor {
+            scalar: Scalar((F::one() + product.scalar()) * norm_inverse),
+            bivector: product.bivector() * Scalar(norm_inverse),
+            pseudoscalar: Pseudoscalar::zero(),
+        })
+    }
+
+    /// Creates the rotor that does the same spatial rotation as a 3D rotor
+    pub fn from_rotor(rotor: vga3d::Rotor<F>) -> Self {
+        Rotor {
+            scalar: Scalar(rotor.scalar()),
+            bivector: Bivector::new(
+                F::zero(),
+                F::zero(),
+                F::zero(),
+                rotor.e23(),
+                rotor.e31(),
+                rotor.e12(),
+            ),
+            pseudoscalar: Pseudoscalar::zero(),
+        }
+    }
+
+    /// Get rotor
+    pub fn rotor(self) -> Self {
+        self
+    }
+
+    /// Get the scalar grade of the rotor
+    pub fn scalar(&self) -> F {
+        self.scalar.0
+    }
+
+    /// Get the bivector grade of the rotor
+    pub fn bivector(&self) -> Bivector<F> {
+        self.bivector
+    }
+
+    /// Get the pseudoscalar grade of the rotor
+    pub fn pseudoscalar(&self) -> Pseudoscalar<F> {
+        self.pseudoscalar
+    }
+
+    /// Get the $\gamma_1\gamma_0$ part of the bivector grade
+    pub fn e10(&self) -> F {
+        self.bivector.e10()
+    }
+
+    /// Get the $\gamma_2\gamma_0$ part of the bivector grade
+    pub fn e20(&self) -> F {
+        self.bivector.e20()
+    }
+
+    /// Get the $\gamma_3\gamma_0$ part of the bivector grade
+    pub fn e30(&self) -> F {
+        self.bivector.e30()
+    }
+
+    /// Get the $\gamma_3\gamma_2$ part of the bivector grade
+    pub fn e32(&self) -> F {
+        self.bivector.e32()
+    }
+
+    /// Get the $\gamma_1\gamma_3$ part of the bivector grade
+    pub fn e13(&self) -> F {
+        self.bivector.e13()
+    }
+
+    /// Get the $\gamma_2\gamma_1$ part of the bivector grade
+    pub fn e21(&self) -> F {
+        self.bivector.e21()
+    }
+
+    /// Get the $\gamma_0\gamma_1\gamma_2\gamma_3$ part of the pseudoscalar grade
+    pub fn e0123(&self) -> F {
+        self.pseudoscalar.e0123()
+    }
+}
+
+// Negation
+impl<F: Float> Neg for Rotor<F> {
+    type Output = Rotor<F>;
+    fn neg(self) -> Rotor<F> {
+        Rotor {
+            scalar: -self.scalar,
+            bivector: -self.bivector,
+            pseudoscalar: -self.pseudoscalar,
+        }
+    }
+}
+
+// Rotor-Rotor
+// The product of two rotors is a rotor
+// The result is normalized to remove the numerical drift
+impl<F: Float> Mul for Rotor<F> {
+    type Output = Rotor<F>;
+    fn mul(self, b: Rotor<F>) -> Rotor<F> {
+        let rotor = Rotor::new(
+            self.scalar() * b.scalar()
+                + self.e10() * b.e10()
+                + self.e20() * b.e20()
+                + self.e30() * b.e30()
+                - self.e32() * b.e32()
+                - self.e13() * b.e13()
+                - self.e21() * b.e21()
+                - self.e0123() * b.e0123(),
+            Bivector::new(
+                self.scalar() * b.e10() + self.e10() * b.scalar() - self.e20() * b.e21()
+                    + self.e30() * b.e13()
+                    - self.e32() * b.e0123()
+                    - self.e13() * b.e30()
+                    + self.e21() * b.e20()
+                    - self.e0123() * b.e32(),
+                self.scalar() * b.e20() + self.e10() * b.e21() + self.e20() * b.scalar()
+                    - self.e30() * b.e32()
+                    + self.e32() * b.e30()
+                    - self.e13() * b.e0123()
+                    - self.e21() * b.e10()
+                    - self.e0123() * b.e13(),
+                self.scalar() * b.e30() - self.e10() * b.e13()
+                    + self.e20() * b.e32()
+                    + self.e30() * b.scalar()
+                    - self.e32() * b.e20()
+                    + self.e13() * b.e10()
+                    - self.e21() * b.e0123()
+                    - self.e0123() * b.e21(),
+                self.scalar() * b.e32() + self.e10() * b.e0123() + self.e20() * b.e30()
+                    - self.e30() * b.e20()
+                    + self.e32() * b.scalar()
+                    - self.e13() * b.e21()
+                    + self.e21() * b.e13()
+                    + self.e0123() * b.e10(),
+                self.scalar() * b.e13() - self.e10() * b.e30()
+                    + self.e20() * b.e0123()
+                    + self.e30() * b.e10()
+                    + self.e32() * b.e21()
+                    + self.e13() * b.scalar()
+                    - self.e21() * b.e32()
+                    + self.e0123() * b.e20(),
+                self.scalar() * b.e21() + self.e10() * b.e20() - self.e20() * b.e10()
+                    + self.e30() * b.e0123()
+                    - self.e32() * b.e13()
+                    + self.e13() * b.e32()
+                    + self.e21() * b.scalar()
+                    + self.e0123() * b.e30(),
+            ),
+            self.scalar() * b.e0123()
+                + self.e10() * b.e32()
+                + self.e20() * b.e13()
+                + self.e30() * b.e21()
+                + self.e32() * b.e10()
+                + self.e13() * b.e20()
+                + self.e21() * b.e30()
+                + self.e0123() * b.scalar(),
+        );
+        rotor.try_normalize().unwrap_or(rotor)
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Rotor<F>, Rotor<F>);
+
+impl<F: Float> STAOps<F> for Rotor<F> {
+    fn reverse(self) -> Self {
+        Rotor {
+            scalar: self.scalar,
+            bivector: -self.bivector,
+            pseudoscalar: self.pseudoscalar,
+        }
+    }
+
+    fn conjugate(self) -> Self {
+        Rotor {
+            scalar: self.scalar,
+            bivector: -self.bivector,
+            pseudoscalar: self.pseudoscalar,
+        }
+    }
+
+    fn involute(self) -> Self {
+        self
+    }
+
+    // \[\|R\|^2=\left< R \widetilde{R} \right>_0\]
+    fn norm(self) -> Scalar<F> {
+        Scalar(
+            (self.scalar() * self.scalar()
+                - self.e10() * self.e10()
+                - self.e20() * self.e20()
+                - self.e30() * self.e30()
+                + self.e32() * self.e32()
+                + self.e13() * self.e13()
+                + self.e21() * self.e21()
+                - self.e0123() * self.e0123())
+            .abs()
+            .sqrt(),
+        )
+    }
+
+    fn try_normalize(self) -> Option<Self> {
+        self.norm().try_inverse().map(|norm_inverse| {
+            let norm_inverse = norm_inverse.0;
+            Rotor {
+                scalar: Scalar(self.scalar() * norm_inverse),
+                bivector: Bivector::new(
+                    self.e10() * norm_inverse,
+                    self.e20() * norm_inverse,
+                    self.e30() * norm_inverse,
+                    self.e32() * norm_inverse,
+                    self.e13() * norm_inverse,
+                    self.e21() * norm_inverse,
+                ),
+                pseudoscalar: Pseudoscalar::new(self.e0123() * norm_inverse),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod rotor_ops {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn rotor_boost_composition() {
+        // Collinear boosts add their rapidities
+        let res = Rotor::try_from_boost(0.3, vga3d::Vector::new(1.0, 2.0, 0.0)).unwrap()
+            * Rotor::try_from_boost(0.5, vga3d::Vector::new(2.0, 4.0, 0.0)).unwrap();
+        let expected = Rotor::try_from_boost(0.8, vga3d::Vector::new(1.0, 2.0, 0.0)).unwrap();
+        assert_relative_eq!(res.scalar(), expected.scalar(), max_relative = 0.000001);
+        assert_relative_eq!(res.e10(), expected.e10(), max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), expected.e20(), max_relative = 0.000001);
+        assert_eq!(
+            Rotor::try_from_boost(0.3, vga3d::Vector::<f64>::zero()),
+            None
+        );
+    }
+
+    #[test]
+    fn rotor_velocity() {
+        let rotor = Rotor::try_from_velocity(vga3d::Vector::new(0.0, 0.0, 0.8)).unwrap();
+        assert_relative_eq!(rotor.norm().0, 1.0, max_relative = 0.000001);
+        assert_relative_eq!(
+            rotor.scalar(),
+            (0.5 * 3.0.ln()).cosh(),
+            max_relative = 0.000001
+        );
+        assert_eq!(
+            Rotor::try_from_velocity(vga3d::Vector::new(0.0, 0.0, 1.0)),
+            None
+        );
+        assert_eq!(
+            Rotor::try_from_velocity(vga3d::Vector::new(0.0, 0.0, 0.0)),
+            Some(Rotor::identity())
+        );
+    }
+
+    #[test]
+    fn rotor_observers() {
+        let from = Vector::try_from_velocity(vga3d::Vector::new(0.3, -0.2, 0.5)).unwrap();
+        let to = Vector::try_from_velocity(vga3d::Vector::new(-0.1, 0.4, 0.0)).unwrap();
+        let rotor = Rotor::try_from_observers(from, to).unwrap();
+        let res = rotor.reverse() * from * rotor;
+        assert_relative_eq!(res.e0(), to.e0(), max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), to.e1(), max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), to.e2(), max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), to.e3(), epsilon = 0.000001);
+        // A spacelike observer is not possible
+        let spacelike = Vector::new(1.0, 2.0, 0.0, 0.0);
+        assert_eq!(Rotor::try_from_observers(spacelike, to), None);
+        // Neither is an observer going back in time
+        assert_eq!(Rotor::try_from_observers(-from, to), None);
+    }
+}
@@ -0,0 +1,1038 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    bivector::Bivector, multivector::Multivector, pseudoscalar::Pseudoscalar, rotor::Rotor,
+    scalar::Scalar, trivector::Trivector, vector::Vector,
+};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::Sub;
+
+// Subtraction
+
+// Scalar-Scalar
+impl<F: Float> Sub for Scalar<F> {
+    type Output = F;
+    fn sub(self, b: Scalar<F>) -> F {
+        self.0 - b.0
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Scalar<F>, Scalar<F>);
+
+// Scalar-Vector
+impl<F: Float> Sub<Vector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0,
+            Vector::new(-b.e0(), -b.e1(), -b.e2(), -b.e3()),
+            Bivector::zero(),
+            Trivector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Scalar<F>, Vector<F>);
+
+// Vector-Scalar
+impl<F: Float> Sub<Scalar<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.0,
+            Vector::new(self.e0(), self.e1(), self.e2(), self.e3()),
+            Bivector::zero(),
+            Trivector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Vector<F>, Scalar<F>);
+
+// Scalar-Bivector
+impl<F: Float> Sub<Bivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0,
+            Vector::zero(),
+            Bivector::new(-b.e10(), -b.e20(), -b.e30(), -b.e32(), -b.e13(), -b.e21()),
+            Trivector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Scalar<F>, Bivector<F>);
+
+// Bivector-Scalar
+impl<F: Float> Sub<Scalar<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.0,
+            Vector::zero(),
+            Bivector::new(
+                self.e10(),
+                self.e20(),
+                self.e30(),
+                self.e32(),
+                self.e13(),
+                self.e21(),
+            ),
+            Trivector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Bivector<F>, Scalar<F>);
+
+// Scalar-Trivector
+impl<F: Float> Sub<Trivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0,
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::new(-b.e123(), -b.e023(), -b.e031(), -b.e012()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Scalar<F>, Trivector<F>);
+
+// Trivector-Scalar
+impl<F: Float> Sub<Scalar<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.0,
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::new(self.e123(), self.e023(), self.e031(), self.e012()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Trivector<F>, Scalar<F>);
+
+// Scalar-Pseudoscalar
+impl<F: Float> Sub<Pseudoscalar<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0,
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::zero(),
+            -b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Scalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Scalar
+impl<F: Float> Sub<Scalar<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.0,
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::zero(),
+            self.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Pseudoscalar<F>, Scalar<F>);
+
+// Scalar-Rotor
+impl<F: Float> Sub<Rotor<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 - b.scalar(),
+            Vector::zero(),
+            Bivector::new(-b.e10(), -b.e20(), -b.e30(), -b.e32(), -b.e13(), -b.e21()),
+            Trivector::zero(),
+            -b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Scalar<F>, Rotor<F>);
+
+// Rotor-Scalar
+impl<F: Float> Sub<Scalar<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() - b.0,
+            Vector::zero(),
+            Bivector::new(
+                self.e10(),
+                self.e20(),
+                self.e30(),
+                self.e32(),
+                self.e13(),
+                self.e21(),
+            ),
+            Trivector::zero(),
+            self.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Rotor<F>, Scalar<F>);
+
+// Scalar-Multivector
+impl<F: Float> Sub<Multivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 - b.scalar(),
+            Vector::new(-b.e0(), -b.e1(), -b.e2(), -b.e3()),
+            Bivector::new(-b.e10(), -b.e20(), -b.e30(), -b.e32(), -b.e13(), -b.e21()),
+            Trivector::new(-b.e123(), -b.e023(), -b.e031(), -b.e012()),
+            -b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Scalar<F>, Multivector<F>);
+
+// Multivector-Scalar
+impl<F: Float> Sub<Scalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() - b.0,
+            Vector::new(self.e0(), self.e1(), self.e2(), self.e3()),
+            Bivector::new(
+                self.e10(),
+                self.e20(),
+                self.e30(),
+                self.e32(),
+                self.e13(),
+                self.e21(),
+            ),
+            Trivector::new(self.e123(), self.e023(), self.e031(), self.e012()),
+            self.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Multivector<F>, Scalar<F>);
+
+// Vector-Vector
+impl<F: Float> Sub for Vector<F> {
+    type Output = Vector<F>;
+    fn sub(self, b: Vector<F>) -> Vector<F> {
+        Vector::new(
+            self.e0() - b.e0(),
+            self.e1() - b.e1(),
+            self.e2() - b.e2(),
+            self.e3() - b.e3(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Vector<F>, Vector<F>);
+
+// Vector-Bivector
+impl<F: Float> Sub<Bivector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(self.e0(), self.e1(), self.e2(), self.e3()),
+            Bivector::new(-b.e10(), -b.e20(), -b.e30(), -b.e32(), -b.e13(), -b.e21()),
+            Trivector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Vector<F>, Bivector<F>);
+
+// Bivector-Vector
+impl<F: Float> Sub<Vector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(-b.e0(), -b.e1(), -b.e2(), -b.e3()),
+            Bivector::new(
+                self.e10(),
+                self.e20(),
+                self.e30(),
+                self.e32(),
+                self.e13(),
+                self.e21(),
+            ),
+            Trivector::zero(),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Bivector<F>, Vector<F>);
+
+// Vector-Trivector
+impl<F: Float> Sub<Trivector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(self.e0(), self.e1(), self.e2(), self.e3()),
+            Bivector::zero(),
+            Trivector::new(-b.e123(), -b.e023(), -b.e031(), -b.e012()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Vector<F>, Trivector<F>);
+
+// Trivector-Vector
+impl<F: Float> Sub<Vector<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(-b.e0(), -b.e1(), -b.e2(), -b.e3()),
+            Bivector::zero(),
+            Trivector::new(self.e123(), self.e023(), self.e031(), self.e012()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Trivector<F>, Vector<F>);
+
+// Vector-Pseudoscalar
+impl<F: Float> Sub<Pseudoscalar<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(self.e0(), self.e1(), self.e2(), self.e3()),
+            Bivector::zero(),
+            Trivector::zero(),
+            -b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Vector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Vector
+impl<F: Float> Sub<Vector<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::new(-b.e0(), -b.e1(), -b.e2(), -b.e3()),
+            Bivector::zero(),
+            Trivector::zero(),
+            self.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Pseudoscalar<F>, Vector<F>);
+
+// Vector-Rotor
+impl<F: Float> Sub<Rotor<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.scalar(),
+            Vector::new(self.e0(), self.e1(), self.e2(), self.e3()),
+            Bivector::new(-b.e10(), -b.e20(), -b.e30(), -b.e32(), -b.e13(), -b.e21()),
+            Trivector::zero(),
+            -b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Vector<F>, Rotor<F>);
+
+// Rotor-Vector
+impl<F: Float> Sub<Vector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Vector::new(-b.e0(), -b.e1(), -b.e2(), -b.e3()),
+            Bivector::new(
+                self.e10(),
+                self.e20(),
+                self.e30(),
+                self.e32(),
+                self.e13(),
+                self.e21(),
+            ),
+            Trivector::zero(),
+            self.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Rotor<F>, Vector<F>);
+
+// Vector-Multivector
+impl<F: Float> Sub<Multivector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.scalar(),
+            Vector::new(
+                self.e0() - b.e0(),
+                self.e1() - b.e1(),
+                self.e2() - b.e2(),
+                self.e3() - b.e3(),
+            ),
+            Bivector::new(-b.e10(), -b.e20(), -b.e30(), -b.e32(), -b.e13(), -b.e21()),
+            Trivector::new(-b.e123(), -b.e023(), -b.e031(), -b.e012()),
+            -b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Vector<F>, Multivector<F>);
+
+// Multivector-Vector
+impl<F: Float> Sub<Vector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Vector::new(
+                self.e0() - b.e0(),
+                self.e1() - b.e1(),
+                self.e2() - b.e2(),
+                self.e3() - b.e3(),
+            ),
+            Bivector::new(
+                self.e10(),
+                self.e20(),
+                self.e30(),
+                self.e32(),
+                self.e13(),
+                self.e21(),
+            ),
+            Trivector::new(self.e123(), self.e023(), self.e031(), self.e012()),
+            self.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Multivector<F>, Vector<F>);
+
+// Bivector-Bivector
+impl<F: Float> Sub for Bivector<F> {
+    type Output = Bivector<F>;
+    fn sub(self, b: Bivector<F>) -> Bivector<F> {
+        Bivector::new(
+            self.e10() - b.e10(),
+            self.e20() - b.e20(),
+            self.e30() - b.e30(),
+            self.e32() - b.e32(),
+            self.e13() - b.e13(),
+            self.e21() - b.e21(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Bivector<F>, Bivector<F>);
+
+// Bivector-Trivector
+impl<F: Float> Sub<Trivector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::new(
+                self.e10(),
+                self.e20(),
+                self.e30(),
+                self.e32(),
+                self.e13(),
+                self.e21(),
+            ),
+            Trivector::new(-b.e123(), -b.e023(), -b.e031(), -b.e012()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Bivector<F>, Trivector<F>);
+
+// Trivector-Bivector
+impl<F: Float> Sub<Bivector<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::new(-b.e10(), -b.e20(), -b.e30(), -b.e32(), -b.e13(), -b.e21()),
+            Trivector::new(self.e123(), self.e023(), self.e031(), self.e012()),
+            F::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Trivector<F>, Bivector<F>);
+
+// Bivector-Pseudoscalar
+impl<F: Float> Sub<Pseudoscalar<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::new(
+                self.e10(),
+                self.e20(),
+                self.e30(),
+                self.e32(),
+                self.e13(),
+                self.e21(),
+            ),
+            Trivector::zero(),
+            -b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Bivector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Bivector
+impl<F: Float> Sub<Bivector<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::new(-b.e10(), -b.e20(), -b.e30(), -b.e32(), -b.e13(), -b.e21()),
+            Trivector::zero(),
+            self.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Pseudoscalar<F>, Bivector<F>);
+
+// Bivector-Rotor
+impl<F: Float> Sub<Rotor<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.scalar(),
+            Vector::zero(),
+            Bivector::new(
+                self.e10() - b.e10(),
+                self.e20() - b.e20(),
+                self.e30() - b.e30(),
+                self.e32() - b.e32(),
+                self.e13() - b.e13(),
+                self.e21() - b.e21(),
+            ),
+            Trivector::zero(),
+            -b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Bivector<F>, Rotor<F>);
+
+// Rotor-Bivector
+impl<F: Float> Sub<Bivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Vector::zero(),
+            Bivector::new(
+                self.e10() - b.e10(),
+                self.e20() - b.e20(),
+                self.e30() - b.e30(),
+                self.e32() - b.e32(),
+                self.e13() - b.e13(),
+                self.e21() - b.e21(),
+            ),
+            Trivector::zero(),
+            self.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Rotor<F>, Bivector<F>);
+
+// Bivector-Multivector
+impl<F: Float> Sub<Multivector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.scalar(),
+            Vector::new(-b.e0(), -b.e1(), -b.e2(), -b.e3()),
+            Bivector::new(
+                self.e10() - b.e10(),
+                self.e20() - b.e20(),
+                self.e30() - b.e30(),
+                self.e32() - b.e32(),
+                self.e13() - b.e13(),
+                self.e21() - b.e21(),
+            ),
+            Trivector::new(-b.e123(), -b.e023(), -b.e031(), -b.e012()),
+            -b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Bivector<F>, Multivector<F>);
+
+// Multivector-Bivector
+impl<F: Float> Sub<Bivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Vector::new(self.e0(), self.e1(), self.e2(), self.e3()),
+            Bivector::new(
+                self.e10() - b.e10(),
+                self.e20() - b.e20(),
+                self.e30() - b.e30(),
+                self.e32() - b.e32(),
+                self.e13() - b.e13(),
+                self.e21() - b.e21(),
+            ),
+            Trivector::new(self.e123(), self.e023(), self.e031(), self.e012()),
+            self.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Multivector<F>, Bivector<F>);
+
+// Trivector-Trivector
+impl<F: Float> Sub for Trivector<F> {
+    type Output = Trivector<F>;
+    fn sub(self, b: Trivector<F>) -> Trivector<F> {
+        Trivector::new(
+            self.e123() - b.e123(),
+            self.e023() - b.e023(),
+            self.e031() - b.e031(),
+            self.e012() - b.e012(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Trivector<F>, Trivector<F>);
+
+// Trivector-Pseudoscalar
+impl<F: Float> Sub<Pseudoscalar<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::new(self.e123(), self.e023(), self.e031(), self.e012()),
+            -b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Trivector<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Trivector
+impl<F: Float> Sub<Trivector<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            Vector::zero(),
+            Bivector::zero(),
+            Trivector::new(-b.e123(), -b.e023(), -b.e031(), -b.e012()),
+            self.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Pseudoscalar<F>, Trivector<F>);
+
+// Trivector-Rotor
+impl<F: Float> Sub<Rotor<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.scalar(),
+            Vector::zero(),
+            Bivector::new(-b.e10(), -b.e20(), -b.e30(), -b.e32(), -b.e13(), -b.e21()),
+            Trivector::new(self.e123(), self.e023(), self.e031(), self.e012()),
+            -b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Trivector<F>, Rotor<F>);
+
+// Rotor-Trivector
+impl<F: Float> Sub<Trivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Vector::zero(),
+            Bivector::new(
+                self.e10(),
+                self.e20(),
+                self.e30(),
+                self.e32(),
+                self.e13(),
+                self.e21(),
+            ),
+            Trivector::new(-b.e123(), -b.e023(), -b.e031(), -b.e012()),
+            self.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Rotor<F>, Trivector<F>);
+
+// Trivector-Multivector
+impl<F: Float> Sub<Multivector<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.scalar(),
+            Vector::new(-b.e0(), -b.e1(), -b.e2(), -b.e3()),
+            Bivector::new(-b.e10(), -b.e20(), -b.e30(), -b.e32(), -b.e13(), -b.e21()),
+            Trivector::new(
+                self.e123() - b.e123(),
+                self.e023() - b.e023(),
+                self.e031() - b.e031(),
+                self.e012() - b.e012(),
+            ),
+            -b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Trivector<F>, Multivector<F>);
+
+// Multivector-Trivector
+impl<F: Float> Sub<Trivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Vector::new(self.e0(), self.e1(), self.e2(), self.e3()),
+            Bivector::new(
+                self.e10(),
+                self.e20(),
+                self.e30(),
+                self.e32(),
+                self.e13(),
+                self.e21(),
+            ),
+            Trivector::new(
+                self.e123() - b.e123(),
+                self.e023() - b.e023(),
+                self.e031() - b.e031(),
+                self.e012() - b.e012(),
+            ),
+            self.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Multivector<F>, Trivector<F>);
+
+// Pseudoscalar-Pseudoscalar
+impl<F: Float> Sub for Pseudoscalar<F> {
+    type Output = Pseudoscalar<F>;
+    fn sub(self, b: Pseudoscalar<F>) -> Pseudoscalar<F> {
+        Pseudoscalar::new(self.e0123() - b.e0123())
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Pseudoscalar<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Rotor
+impl<F: Float> Sub<Rotor<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.scalar(),
+            Vector::zero(),
+            Bivector::new(-b.e10(), -b.e20(), -b.e30(), -b.e32(), -b.e13(), -b.e21()),
+            Trivector::zero(),
+            self.e0123() - b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Pseudoscalar<F>, Rotor<F>);
+
+// Rotor-Pseudoscalar
+impl<F: Float> Sub<Pseudoscalar<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Vector::zero(),
+            Bivector::new(
+                self.e10(),
+                self.e20(),
+                self.e30(),
+                self.e32(),
+                self.e13(),
+                self.e21(),
+            ),
+            Trivector::zero(),
+            self.e0123() - b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Rotor<F>, Pseudoscalar<F>);
+
+// Pseudoscalar-Multivector
+impl<F: Float> Sub<Multivector<F>> for Pseudoscalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            -b.scalar(),
+            Vector::new(-b.e0(), -b.e1(), -b.e2(), -b.e3()),
+            Bivector::new(-b.e10(), -b.e20(), -b.e30(), -b.e32(), -b.e13(), -b.e21()),
+            Trivector::new(-b.e123(), -b.e023(), -b.e031(), -b.e012()),
+            self.e0123() - b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Pseudoscalar<F>, Multivector<F>);
+
+// Multivector-Pseudoscalar
+impl<F: Float> Sub<Pseudoscalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Pseudoscalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Vector::new(self.e0(), self.e1(), self.e2(), self.e3()),
+            Bivector::new(
+                self.e10(),
+                self.e20(),
+                self.e30(),
+                self.e32(),
+                self.e13(),
+                self.e21(),
+            ),
+            Trivector::new(self.e123(), self.e023(), self.e031(), self.e012()),
+            self.e0123() - b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Multivector<F>, Pseudoscalar<F>);
+
+// Rotor-Rotor
+impl<F: Float> Sub for Rotor<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() - b.scalar(),
+            Vector::zero(),
+            Bivector::new(
+                self.e10() - b.e10(),
+                self.e20() - b.e20(),
+                self.e30() - b.e30(),
+                self.e32() - b.e32(),
+                self.e13() - b.e13(),
+                self.e21() - b.e21(),
+            ),
+            Trivector::zero(),
+            self.e0123() - b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Rotor<F>, Rotor<F>);
+
+// Rotor-Multivector
+impl<F: Float> Sub<Multivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() - b.scalar(),
+            Vector::new(-b.e0(), -b.e1(), -b.e2(), -b.e3()),
+            Bivector::new(
+                self.e10() - b.e10(),
+                self.e20() - b.e20(),
+                self.e30() - b.e30(),
+                self.e32() - b.e32(),
+                self.e13() - b.e13(),
+                self.e21() - b.e21(),
+            ),
+            Trivector::new(-b.e123(), -b.e023(), -b.e031(), -b.e012()),
+            self.e0123() - b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Rotor<F>, Multivector<F>);
+
+// Multivector-Rotor
+impl<F: Float> Sub<Rotor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() - b.scalar(),
+            Vector::new(self.e0(), self.e1(), self.e2(), self.e3()),
+            Bivector::new(
+                self.e10() - b.e10(),
+                self.e20() - b.e20(),
+                self.e30() - b.e30(),
+                self.e32() - b.e32(),
+                self.e13() - b.e13(),
+                self.e21() - b.e21(),
+            ),
+            Trivector::new(self.e123(), self.e023(), self.e031(), self.e012()),
+            self.e0123() - b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Multivector<F>, Rotor<F>);
+
+// Multivector-Multivector
+impl<F: Float> Sub for Multivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() - b.scalar(),
+            Vector::new(
+                self.e0() - b.e0(),
+                self.e1() - b.e1(),
+                self.e2() - b.e2(),
+                self.e3() - b.e3(),
+            ),
+            Bivector::new(
+                self.e10() - b.e10(),
+                self.e20() - b.e20(),
+                self.e30() - b.e30(),
+                self.e32() - b.e32(),
+                self.e13() - b.e13(),
+                self.e21() - b.e21(),
+            ),
+            Trivector::new(
+                self.e123() - b.e123(),
+                self.e023() - b.e023(),
+                self.e031() - b.e031(),
+                self.e012() - b.e012(),
+            ),
+            self.e0123() - b.e0123(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Multivector<F>, Multivector<F>);
+
+// Test
+#[cfg(test)]
+mod subtraction_difference {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn scalar_scalar() {
+        // -2
+        let scalar1 = Scalar::new(-2.0);
+        // 3
+        let scalar2 = Scalar::new(3.0);
+        let res = scalar1 - scalar2;
+        // -5
+        assert_relative_eq!(res, -5.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_bivector() {
+        // 1e0+5e1-2e2-2e3
+        let vector1 = Vector::new(1.0, 5.0, -2.0, -2.0);
+        // -2e10+4e20+1e30-1e32-2e13-4e21
+        let bivector2 = Bivector::new(-2.0, 4.0, 1.0, -1.0, -2.0, -4.0);
+        let res = vector1 - bivector2;
+        // 1e0+5e1-2e2-2e3+2e10-4e20-1e30+1e32+2e13+4e21
+        assert_relative_eq!(res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e10(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), -4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e30(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e32(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e13(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e21(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e023(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e031(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn trivector_trivector() {
+        // 4e123+4e023-1e031+1e012
+        let trivector1 = Trivector::new(4.0, 4.0, -1.0, 1.0);
+        // -1e123+1e023-4e031-2e012
+        let trivector2 = Trivector::new(-1.0, 1.0, -4.0, -2.0);
+        let res = trivector1 - trivector2;
+        // 5e123+3e023+3e031+3e012
+        assert_relative_eq!(res.e123(), 5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e023(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e031(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), 3.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_multivector() {
+        // -2+5e10-4e20+2e30+4e32+1e13-1e21+4e0123
+        let rotor1 = Rotor::new(-2.0, Bivector::new(5.0, -4.0, 2.0, 4.0, 1.0, -1.0), 4.0);
+        // 4-2e0+5e1-3e2-1e3-2e10+2e20+4e30+5e32+1e13-3e21+1e123+5e023-3e031-1e012-2e0123
+        let mvec2 = Multivector::new(
+            4.0,
+            Vector::new(-2.0, 5.0, -3.0, -1.0),
+            Bivector::new(-2.0, 2.0, 4.0, 5.0, 1.0, -3.0),
+            Trivector::new(1.0, 5.0, -3.0, -1.0),
+            -2.0,
+        );
+        let res = rotor1 - mvec2;
+        // -6+2e0-5e1+3e2+1e3+7e10-6e20-2e30-1e32+2e21-1e123-5e023+3e031+1e012+6e0123
+        assert_relative_eq!(res.scalar(), -6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e10(), 7.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), -6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e30(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e32(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e13(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e21(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e023(), -5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e031(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), 6.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn pseudoscalar_scalar() {
+        // -3e0123
+        let pseudoscalar1 = Pseudoscalar::new(-3.0);
+        // -3
+        let scalar2 = Scalar::new(-3.0);
+        let res = pseudoscalar1 - scalar2;
+        // 3-3e0123
+        assert_relative_eq!(res.scalar(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e10(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e20(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e30(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e32(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e13(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e21(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e023(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e031(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e012(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e0123(), -3.0, max_relative = 0.000001);
+    }
+}
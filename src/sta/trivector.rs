@@ -0,0 +1,205 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::fmt;
+
+use core::ops::Neg;
+
+use num_traits::Float;
+
+use super::{STAOps, pseudoscalar::Pseudoscalar, scalar::Scalar, vector::Vector};
+
+/// # Spacetime Algebra Trivector
+/// The trivectors of the algebra
+/// $$ T = a \gamma_1\gamma_2\gamma_3 + b \gamma_0\gamma_2\gamma_3 + c \gamma_0\gamma_3\gamma_1 + d \gamma_0\gamma_1\gamma_2 $$
+/// The trivectors are the duals of the vectors.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Trivector<F: Float> {
+    e123: F,
+    e023: F,
+    e031: F,
+    e012: F,
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + fmt::Display> fmt::Display for Trivector<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Trivector {{")?;
+        write!(f, " {}e123", self.e123)?;
+
+        // Add the appropriate sign to the remaining components
+        for (value, name) in [
+            (self.e023, "e023"),
+            (self.e031, "e031"),
+            (self.e012, "e012"),
+        ] {
+            if value >= F::zero() {
+                write!(f, " + {}{}", value, name)?;
+            } else {
+                write!(f, " - {}{}", value.abs(), name)?;
+            }
+        }
+        write!(f, " }}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F: Float + defmt::Format> defmt::Format for Trivector<F> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Trivector {{");
+        defmt::write!(f, " {}e123", self.e123);
+
+        // Add the appropriate sign to the remaining components
+        for (value, name) in [
+            (self.e023, "e023"),
+            (self.e031, "e031"),
+            (self.e012, "e012"),
+        ] {
+            if value >= F::zero() {
+                defmt::write!(f, " + {}{}", value, name);
+            } else {
+                defmt::write!(f, " - {}{}", value.abs(), name);
+            }
+        }
+        defmt::write!(f, " }}");
+    }
+}
+
+impl<F: Float> Trivector<F> {
+    /// The zero trivector
+    pub fn zero() -> Self {
+        Self {
+            e123: F::zero(),
+            e023: F::zero(),
+            e031: F::zero(),
+            e012: F::zero(),
+        }
+    }
+
+    /// Create new trivector from linear combination of the basis blades
+    pub fn new(e123: F, e023: F, e031: F, e012: F) -> Self {
+        Self {
+            e123,
+            e023,
+            e031,
+            e012,
+        }
+    }
+
+    /// Get trivector
+    pub fn trivector(self) -> Self {
+        self
+    }
+
+    /// Get the $\gamma_1\gamma_2\gamma_3$ part
+    pub fn e123(&self) -> F {
+        self.e123
+    }
+
+    /// Get the $\gamma_0\gamma_2\gamma_3$ part
+    pub fn e023(&self) -> F {
+        self.e023
+    }
+
+    /// Get the $\gamma_0\gamma_3\gamma_1$ part
+    pub fn e031(&self) -> F {
+        self.e031
+    }
+
+    /// Get the $\gamma_0\gamma_1\gamma_2$ part
+    pub fn e012(&self) -> F {
+        self.e012
+    }
+
+    /// # Dual
+    /// The dual of a trivector is a vector
+    /// $$ A^* = A I^{-1} $$
+    pub fn dual(self) -> Vector<F> {
+        self * Pseudoscalar::new(-F::one())
+    }
+
+    /// # Undual
+    /// The inverse of the dual
+    /// $$ A = A^* I $$
+    pub fn undual(self) -> Vector<F> {
+        self * Pseudoscalar::new(F::one())
+    }
+}
+
+// Negation
+impl<F: Float> Neg for Trivector<F> {
+    type Output = Trivector<F>;
+    fn neg(self) -> Trivector<F> {
+        Trivector::new(-self.e123, -self.e023, -self.e031, -self.e012)
+    }
+}
+
+impl<F: Float> STAOps<F> for Trivector<F> {
+    fn reverse(self) -> Self {
+        -self
+    }
+
+    fn conjugate(self) -> Self {
+        self
+    }
+
+    fn involute(self) -> Self {
+        -self
+    }
+
+    fn norm(self) -> Scalar<F> {
+        Scalar(
+            (self.e023 * self.e023 + self.e031 * self.e031 + self.e012 * self.e012
+                - self.e123 * self.e123)
+                .abs()
+                .sqrt(),
+        )
+    }
+
+    fn try_normalize(self) -> Option<Self> {
+        self.norm().try_inverse().map(|norm_inverse| {
+            let norm_inverse = norm_inverse.0;
+            Trivector::new(
+                self.e123 * norm_inverse,
+                self.e023 * norm_inverse,
+                self.e031 * norm_inverse,
+                self.e012 * norm_inverse,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod trivector_ops {
+    use super::*;
+
+    #[test]
+    fn trivector_dual() {
+        let trivector = Trivector::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(trivector.dual().undual(), trivector);
+        // The trivectors are named after the undual of the vectors
+        assert_eq!(Vector::new(1.0, 2.0, 3.0, 4.0).undual(), trivector);
+    }
+}
@@ -0,0 +1,308 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::fmt;
+
+use core::ops::Neg;
+
+use num_traits::Float;
+
+use super::{
+    Rotatable, STAOps, pseudoscalar::Pseudoscalar, rotor::Rotor, scalar::Scalar,
+    trivector::Trivector,
+};
+use crate::vga3d;
+
+/// # Spacetime Algebra Vector
+/// The vectors of the algebra are events and four-vectors
+/// $$x = t \gamma_0 + x \gamma_1 + y \gamma_2 + z \gamma_3$$
+/// where $\gamma_0^2 = 1$ and $\gamma_1^2 = \gamma_2^2 = \gamma_3^2 = -1$.
+/// The speed of light is one.
+///
+/// A vector is timelike when $x^2 > 0$, lightlike when $x^2 = 0$ and spacelike when $x^2 < 0$.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Vector<F: Float> {
+    e0: F,
+    e1: F,
+    e2: F,
+    e3: F,
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + fmt::Display> fmt::Display for Vector<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Vector {{")?;
+        write!(f, " {}e0", self.e0)?;
+
+        // Add the appropriate sign to the remaining components
+        for (value, name) in [(self.e1, "e1"), (self.e2, "e2"), (self.e3, "e3")] {
+            if value >= F::zero() {
+                write!(f, " + {}{}", value, name)?;
+            } else {
+                write!(f, " - {}{}", value.abs(), name)?;
+            }
+        }
+        write!(f, " }}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F: Float + defmt::Format> defmt::Format for Vector<F> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Vector {{");
+        defmt::write!(f, " {}e0", self.e0);
+
+        // Add the appropriate sign to the remaining components
+        for (value, name) in [(self.e1, "e1"), (self.e2, "e2"), (self.e3, "e3")] {
+            if value >= F::zero() {
+                defmt::write!(f, " + {}{}", value, name);
+            } else {
+                defmt::write!(f, " - {}{}", value.abs(), name);
+            }
+        }
+        defmt::write!(f, " }}");
+    }
+}
+
+impl<F: Float> Vector<F> {
+    /// The zero vector
+    pub fn zero() -> Self {
+        Self {
+            e0: F::zero(),
+            e1: F::zero(),
+            e2: F::zero(),
+            e3: F::zero(),
+        }
+    }
+
+    /// Create new vector from linear combination of the basis blades
+    pub fn new(e0: F, e1: F, e2: F, e3: F) -> Self {
+        Self { e0, e1, e2, e3 }
+    }
+
+    /// Create the event at a time and a position in the rest frame of $\gamma_0$
+    /// $$ x = t\gamma_0 + \vec{x} \gamma_0 $$
+    pub fn from_event(time: F, position: vga3d::Vector<F>) -> Self {
+        Self::new(time, position.e1(), position.e2(), position.e3())
+    }
+
+    /// Tries to create the proper velocity of an observer from its velocity in the rest frame of $\gamma_0$
+    /// $$ u = \gamma \left( 1 + \vec{v} \right) \gamma_0 ,\quad \gamma = \frac{1}{\sqrt{1 - v^2}} $$
+    /// The speed must be less than the speed of light.
+    pub fn try_from_velocity(velocity: vga3d::Vector<F>) -> Option<Self> {
+        let speed_squared = velocity.e1() * velocity.e1()
+            + velocity.e2() * velocity.e2()
+            + velocity.e3() * velocity.e3();
+        if speed_squared >= F::one() {
+            return None;
+        }
+        let gamma = (F::one() - speed_squared).sqrt().recip();
+        Some(Self::new(
+            gamma,
+            gamma * velocity.e1(),
+            gamma * velocity.e2(),
+            gamma * velocity.e3(),
+        ))
+    }
+
+    /// Get the time in the rest frame of $\gamma_0$
+    pub fn time(&self) -> F {
+        self.e0
+    }
+
+    /// Get the position in the rest frame of $\gamma_0$
+    pub fn position(&self) -> vga3d::Vector<F> {
+        vga3d::Vector::new(self.e1, self.e2, self.e3)
+    }
+
+    /// Tries to get the velocity in the rest frame of $\gamma_0$
+    /// $$ \vec{v} = \frac{\vec{x}}{t} $$
+    pub fn try_velocity(&self) -> Option<vga3d::Vector<F>> {
+        if self.e0.is_zero() {
+            return None;
+        }
+        Some(vga3d::Vector::new(
+            self.e1 / self.e0,
+            self.e2 / self.e0,
+            self.e3 / self.e0,
+        ))
+    }
+
+    /// # Spacetime split
+    /// Tries to split the vector into the time and the position measured by an observer
+    /// $$ x u = x \cdot u + x \wedge u $$
+    /// The observer is given by its proper velocity, which must be timelike and point to the future.
+    pub fn try_split(self, observer: Vector<F>) -> Option<(F, vga3d::Vector<F>)> {
+        let rest = Vector::new(F::one(), F::zero(), F::zero(), F::zero());
+        Rotor::try_from_observers(observer, rest).map(|rotor| {
+            let res = self.rotate(rotor);
+            (res.time(), res.position())
+        })
+    }
+
+    /// Get vector
+    pub fn vector(self) -> Self {
+        self
+    }
+
+    /// Get the $\gamma_0$ part
+    pub fn e0(&self) -> F {
+        self.e0
+    }
+
+    /// Get the $\gamma_1$ part
+    pub fn e1(&self) -> F {
+        self.e1
+    }
+
+    /// Get the $\gamma_2$ part
+    pub fn e2(&self) -> F {
+        self.e2
+    }
+
+    /// Get the $\gamma_3$ part
+    pub fn e3(&self) -> F {
+        self.e3
+    }
+
+    /// # Dual
+    /// The dual of a vector is a trivector
+    /// $$ A^* = A I^{-1} $$
+    pub fn dual(self) -> Trivector<F> {
+        self * Pseudoscalar::new(-F::one())
+    }
+
+    /// # Undual
+    /// The inverse of the dual
+    /// $$ A = A^* I $$
+    pub fn undual(self) -> Trivector<F> {
+        self * Pseudoscalar::new(F::one())
+    }
+}
+
+// Negation
+impl<F: Float> Neg for Vector<F> {
+    type Output = Vector<F>;
+    fn neg(self) -> Vector<F> {
+        Vector::new(-self.e0, -self.e1, -self.e2, -self.e3)
+    }
+}
+
+impl<F: Float> STAOps<F> for Vector<F> {
+    fn reverse(self) -> Self {
+        self
+    }
+
+    fn conjugate(self) -> Self {
+        -self
+    }
+
+    fn involute(self) -> Self {
+        -self
+    }
+
+    fn norm(self) -> Scalar<F> {
+        Scalar(
+            (self.e0 * self.e0 - self.e1 * self.e1 - self.e2 * self.e2 - self.e3 * self.e3)
+                .abs()
+                .sqrt(),
+        )
+    }
+
+    fn try_normalize(self) -> Option<Self> {
+        self.norm().try_inverse().map(|norm_inverse| {
+            let norm_inverse = norm_inverse.0;
+            Vector::new(
+                self.e0 * norm_inverse,
+                self.e1 * norm_inverse,
+                self.e2 * norm_inverse,
+                self.e3 * norm_inverse,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod vector_ops {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn vector_interval() {
+        // Timelike, lightlike and spacelike
+        let vector = Vector::from_event(5.0, vga3d::Vector::new(1.0, 2.0, 2.0));
+        assert_relative_eq!((vector | vector).0, 16.0, max_relative = 0.000001);
+        assert_relative_eq!(vector.norm().0, 4.0, max_relative = 0.000001);
+        let vector = Vector::from_event(3.0, vga3d::Vector::new(0.0, 3.0, 0.0));
+        assert_relative_eq!((vector | vector).0, 0.0);
+        assert_eq!(vector.try_normalize(), None);
+        let vector = Vector::from_event(1.0, vga3d::Vector::new(0.0, 3.0, 0.0));
+        assert_relative_eq!((vector | vector).0, -8.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_velocity() {
+        let velocity = vga3d::Vector::new(0.3, -0.4, 0.0);
+        let vector = Vector::try_from_velocity(velocity).unwrap();
+        assert_relative_eq!(vector.norm().0, 1.0, max_relative = 0.000001);
+        assert_relative_eq!(vector.time(), 1.0 / 0.75.sqrt(), max_relative = 0.000001);
+        let res = vector.try_velocity().unwrap();
+        assert_relative_eq!(res.e1(), 0.3, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -0.4, max_relative = 0.000001);
+        assert_eq!(
+            Vector::try_from_velocity(vga3d::Vector::new(0.6, 0.8, 0.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn vector_split() {
+        // Lorentz transformation along x
+        // t' = gamma (t - v x)
+        // x' = gamma (x - v t)
+        let speed = 0.6;
+        let gamma = 1.25;
+        let observer = Vector::try_from_velocity(vga3d::Vector::new(speed, 0.0, 0.0)).unwrap();
+        let event = Vector::from_event(2.0, vga3d::Vector::new(3.0, 1.0, -1.0));
+        let (time, position) = event.try_split(observer).unwrap();
+        assert_relative_eq!(time, gamma * (2.0 - speed * 3.0), max_relative = 0.000001);
+        assert_relative_eq!(
+            position.e1(),
+            gamma * (3.0 - speed * 2.0),
+            max_relative = 0.000001
+        );
+        assert_relative_eq!(position.e2(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(position.e3(), -1.0, max_relative = 0.000001);
+        // The interval is the same for all observers
+        let interval = time * time - position.e1() * position.e1() - 2.0;
+        assert_relative_eq!(interval, (event | event).0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_dual() {
+        let vector = Vector::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(vector.dual().undual(), vector);
+    }
+}
@@ -0,0 +1,220 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::fmt;
+
+use core::ops::Neg;
+
+use num_traits::Float;
+
+use super::{VGA2DOps, VGA2DOpsRef, scalar::Scalar};
+
+/// # 2D Vector Geometric Algebra Bivector
+/// This is the oriented area of the plane.
+/// $$\overset\Rightarrow{b}=b \mathrm{e}_1\mathrm{e}_2$$
+///
+/// In 2D the bivector is also the pseudoscalar $\overset\Rightarrow{i}$ of the algebra.
+/// It squares to $-1$, like the imaginary unit of the complex numbers.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Bivector<F: Float> {
+    e12: F,
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + fmt::Display> fmt::Display for Bivector<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Bivector {{")?;
+        write!(f, " {}e12", self.e12)?;
+        write!(f, " }}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F: Float + defmt::Format> defmt::Format for Bivector<F> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Bivector {{");
+        defmt::write!(f, " {}e12", self.e12);
+        defmt::write!(f, " }}");
+    }
+}
+
+impl<F: Float> Bivector<F> {
+    /// The zero bivector
+    pub fn zero() -> Self {
+        Self { e12: F::zero() }
+    }
+
+    /// Create new bivector from the scaling of the unit bivector
+    pub fn new(e12: F) -> Self {
+        Self { e12 }
+    }
+
+    /// Get bivector
+    pub fn bivector(self) -> Self {
+        self
+    }
+
+    /// The scaling factor for unit bivector $\mathrm{e}_1\mathrm{e}_2$
+    pub fn e12(&self) -> F {
+        self.e12
+    }
+}
+
+#[cfg(test)]
+mod new {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let bivec = Bivector::new(2.0);
+        assert_eq!(bivec.e12, 2.0);
+    }
+}
+
+// Negation
+impl<F: Float> Neg for Bivector<F> {
+    type Output = Bivector<F>;
+    fn neg(self) -> Bivector<F> {
+        Bivector::new(-self.e12)
+    }
+}
+
+impl<F: Float> Bivector<F> {
+    /// # Dual
+    /// In VGA 2D, the dual is the multiplication with the pseudoscalar
+    /// $$ \overset\Rightarrow{b} \overset\Rightarrow{i} = -b $$
+    pub fn dual(self) -> F {
+        -self.e12
+    }
+}
+
+impl<F: Float> VGA2DOps<F> for Bivector<F> {
+    // There is only one element.
+    // The norm is the absolute value of e1e2
+    fn norm(self) -> Scalar<F> {
+        Scalar(self.e12().abs())
+    }
+
+    // Inverse
+    // \[A^{-1}=\frac{A^\dag}{\left< A A^\dag \right>}\]
+    fn try_inverse(self) -> Option<Self> {
+        let norm_squared = (self * self.reverse()).0;
+        Scalar(norm_squared)
+            .try_inverse()
+            .map(|scalar_inverse| self.reverse() * scalar_inverse)
+    }
+
+    // Reverse
+    // It follows the patten (Each is a grade)
+    // \[+ + - - + + - - \dots (-1)^{k(k-1)/2}\]
+    fn reverse(self) -> Self {
+        -self
+    }
+
+    // Clifford Conjugation
+    // It follows the patten (Each is a grade)
+    // \[+ - - + - - +\dots(-1)^{k(k+1)/2}\]
+    fn conjugate(self) -> Self {
+        -self
+    }
+
+    // Grade Involution
+    // The follows this patten (Each is a grade)
+    // \[+ - + - + -\dots (-1)^{k}\]
+    fn involute(self) -> Self {
+        self
+    }
+}
+
+impl<F: Float> VGA2DOpsRef<F> for Bivector<F> {
+    fn norm(&self) -> Scalar<F> {
+        Scalar(self.e12().abs())
+    }
+
+    // Inverse
+    // \[A^{-1}=\frac{A^\dag}{\left< A A^\dag \right>}\]
+    fn try_inverse(&self) -> Option<Self> {
+        let norm_squared = (self * self.reverse()).0;
+        Scalar(norm_squared)
+            .try_inverse()
+            .map(|scalar_inverse| self.reverse() * scalar_inverse)
+    }
+
+    // Reverse
+    // It follows the patten (Each is a grade)
+    // \[+ + - - + + - - \dots (-1)^{k(k-1)/2}\]
+    fn reverse(&self) -> Self {
+        -(*self)
+    }
+
+    // Clifford Conjugation
+    // It follows the patten (Each is a grade)
+    // \[+ - - + - - +\dots(-1)^{k(k+1)/2}\]
+    fn conjugate(&self) -> Self {
+        -(*self)
+    }
+
+    // Grade Involution
+    // The follows this patten (Each is a grade)
+    // \[+ - + - + -\dots (-1)^{k}\]
+    fn involute(&self) -> Self {
+        *self
+    }
+}
+
+#[cfg(test)]
+mod bivector_ops {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn bivector_norm() {
+        let bivector = Bivector::new(-3.0);
+        assert_relative_eq!(bivector.norm().scalar(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(
+            VGA2DOpsRef::norm(&bivector).scalar(),
+            3.0,
+            max_relative = 0.000001
+        );
+        assert_relative_eq!(bivector.reverse().e12(), 3.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_inverse() {
+        let bivector = Bivector::new(2.0);
+        let inverse = match bivector.try_inverse() {
+            Some(inverse) => inverse,
+            None => Bivector::zero(),
+        };
+        // (2e12)^{-1} = -0.5e12
+        assert_relative_eq!(inverse.e12(), -0.5, max_relative = 0.000001);
+        assert_relative_eq!((bivector * inverse).0, 1.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_dual() {
+        let bivector = Bivector::new(2.0);
+        assert_relative_eq!(bivector.dual(), -2.0, max_relative = 0.000001);
+    }
+}
@@ -0,0 +1,363 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    bivector::Bivector, multivector::Multivector, rotor::Rotor, scalar::Scalar, vector::Vector,
+};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::BitXor;
+
+// Exterior Product
+// \[ \left< A_r B_s \right>_{r+s} \]
+// In 2D there are no grades above the bivector, so many of the products are zero.
+
+// Scalar-Scalar
+// \[ a\wedge b\]
+impl<F: Float> BitXor for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, b: Scalar<F>) -> Scalar<F> {
+        Scalar(self.0 * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Scalar<F>);
+
+// Scalar-Vector
+// \[ a\wedge \vec{b}\]
+impl<F: Float> BitXor<Vector<F>> for Scalar<F> {
+    type Output = Vector<F>;
+    fn bitxor(self, b: Vector<F>) -> Vector<F> {
+        self * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Vector<F>);
+
+// Vector-Scalar
+// \[ \vec{a}\wedge b\]
+impl<F: Float> BitXor<Scalar<F>> for Vector<F> {
+    type Output = Vector<F>;
+    fn bitxor(self, b: Scalar<F>) -> Vector<F> {
+        self * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Vector<F>, Scalar<F>);
+
+// Scalar-Bivector
+// \[ a\wedge \overset\Rightarrow{b}\]
+impl<F: Float> BitXor<Bivector<F>> for Scalar<F> {
+    type Output = Bivector<F>;
+    fn bitxor(self, b: Bivector<F>) -> Bivector<F> {
+        self * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Bivector<F>);
+
+// Bivector-Scalar
+// \[ \overset\Rightarrow{a}\wedge b\]
+impl<F: Float> BitXor<Scalar<F>> for Bivector<F> {
+    type Output = Bivector<F>;
+    fn bitxor(self, b: Scalar<F>) -> Bivector<F> {
+        self * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Bivector<F>, Scalar<F>);
+
+// Scalar-Multivector
+// \[ a\wedge B\]
+impl<F: Float> BitXor<Multivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Multivector<F>) -> Multivector<F> {
+        self * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Multivector<F>);
+
+// Multivector-Scalar
+// \[ A\wedge b\]
+impl<F: Float> BitXor<Scalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Scalar<F>) -> Multivector<F> {
+        self * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Scalar<F>);
+
+// Scalar-Rotor
+// \[ a\wedge R\]
+impl<F: Float> BitXor<Rotor<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Rotor<F>) -> Multivector<F> {
+        self * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Rotor<F>);
+
+// Rotor-Scalar
+// \[ R\wedge b\]
+impl<F: Float> BitXor<Scalar<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Scalar<F>) -> Multivector<F> {
+        self * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Rotor<F>, Scalar<F>);
+
+// Vector-Vector
+// \[ \vec{a}\wedge \vec{b}\]
+impl<F: Float> BitXor for Vector<F> {
+    type Output = Bivector<F>;
+    fn bitxor(self, b: Vector<F>) -> Bivector<F> {
+        Bivector::new(self.e1() * b.e2() - self.e2() * b.e1())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Vector<F>, Vector<F>);
+
+// Vector-Bivector
+// \[ \vec{a}\wedge \overset\Rightarrow{b}\]
+impl<F: Float> BitXor<Bivector<F>> for Vector<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Bivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Vector<F>, Bivector<F>);
+
+// Bivector-Vector
+// \[ \overset\Rightarrow{a}\wedge \vec{b}\]
+impl<F: Float> BitXor<Vector<F>> for Bivector<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Vector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Bivector<F>, Vector<F>);
+
+// Vector-Multivector
+// \[ \vec{a}\wedge B\]
+impl<F: Float> BitXor<Multivector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector {
+            scalar: Scalar::zero(),
+            vector: self * b.scalar,
+            bivector: self ^ b.vector,
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Vector<F>, Multivector<F>);
+
+// Multivector-Vector
+// \[ A\wedge \vec{b}\]
+impl<F: Float> BitXor<Vector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Vector<F>) -> Multivector<F> {
+        Multivector {
+            scalar: Scalar::zero(),
+            vector: self.scalar * b,
+            bivector: self.vector ^ b,
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Vector<F>);
+
+// Vector-Rotor
+// \[ \vec{a}\wedge R\]
+impl<F: Float> BitXor<Rotor<F>> for Vector<F> {
+    type Output = Vector<F>;
+    fn bitxor(self, b: Rotor<F>) -> Vector<F> {
+        self * b.scalar
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Vector<F>, Rotor<F>);
+
+// Rotor-Vector
+// \[ R\wedge \vec{b}\]
+impl<F: Float> BitXor<Vector<F>> for Rotor<F> {
+    type Output = Vector<F>;
+    fn bitxor(self, b: Vector<F>) -> Vector<F> {
+        self.scalar * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Rotor<F>, Vector<F>);
+
+// Bivector-Bivector
+// \[ \overset\Rightarrow{a}\wedge \overset\Rightarrow{b}\]
+impl<F: Float> BitXor for Bivector<F> {
+    type Output = Scalar<F>;
+    fn bitxor(self, _b: Bivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Bivector<F>, Bivector<F>);
+
+// Bivector-Multivector
+// \[ \overset\Rightarrow{a}\wedge B\]
+impl<F: Float> BitXor<Multivector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector {
+            scalar: Scalar::zero(),
+            vector: Vector::zero(),
+            bivector: self * b.scalar,
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Bivector<F>, Multivector<F>);
+
+// Multivector-Bivector
+// \[ A\wedge \overset\Rightarrow{b}\]
+impl<F: Float> BitXor<Bivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector {
+            scalar: Scalar::zero(),
+            vector: Vector::zero(),
+            bivector: self.scalar * b,
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Bivector<F>);
+
+// Bivector-Rotor
+// \[ \overset\Rightarrow{a}\wedge R\]
+impl<F: Float> BitXor<Rotor<F>> for Bivector<F> {
+    type Output = Bivector<F>;
+    fn bitxor(self, b: Rotor<F>) -> Bivector<F> {
+        self * b.scalar
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Bivector<F>, Rotor<F>);
+
+// Rotor-Bivector
+// \[ R\wedge \overset\Rightarrow{b}\]
+impl<F: Float> BitXor<Bivector<F>> for Rotor<F> {
+    type Output = Bivector<F>;
+    fn bitxor(self, b: Bivector<F>) -> Bivector<F> {
+        self.scalar * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Rotor<F>, Bivector<F>);
+
+// Multivector-Multivector
+// \[ A \wedge B = \left <A B \right>_{a+b} \]
+impl<F: Float> BitXor for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector {
+            scalar: Scalar(self.scalar() * b.scalar()),
+            vector: (self.scalar * b.vector) + (self.vector * b.scalar),
+            bivector: (self.scalar * b.bivector)
+                + (self.bivector * b.scalar)
+                + (self.vector ^ b.vector),
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Multivector<F>);
+
+// Multivector-Rotor
+// \[ A\wedge R\]
+impl<F: Float> BitXor<Rotor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector {
+            scalar: Scalar(self.scalar() * b.scalar()),
+            vector: self.vector * b.scalar,
+            bivector: (self.scalar * b.bivector) + (self.bivector * b.scalar),
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Rotor<F>);
+
+// Rotor-Multivector
+// \[ R\wedge B\]
+impl<F: Float> BitXor<Multivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector {
+            scalar: Scalar(self.scalar() * b.scalar()),
+            vector: self.scalar * b.vector,
+            bivector: (self.scalar * b.bivector) + (self.bivector * b.scalar),
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Rotor<F>, Multivector<F>);
+
+// Rotor-Rotor
+// \[ R_1\wedge R_2\]
+impl<F: Float> BitXor for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector {
+            scalar: Scalar(self.scalar() * b.scalar()),
+            vector: Vector::zero(),
+            bivector: (self.scalar * b.bivector) + (self.bivector * b.scalar),
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Rotor<F>, Rotor<F>);
+
+// Test
+#[cfg(test)]
+mod exterior_product {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn vector_vector() {
+        // 3e1+5e2
+        let vector1 = Vector::new(3.0, 5.0);
+        // 2e1+e2
+        let vector2 = Vector::new(2.0, 1.0);
+        let res = vector1 ^ vector2;
+        // -7e12
+        assert_relative_eq!(res.e12(), -7.0, max_relative = 0.000001);
+        assert_relative_eq!((vector2 ^ vector1).e12(), 7.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_bivector() {
+        let res = Vector::new(3.0, 5.0) ^ Bivector::new(2.0);
+        assert_relative_eq!(res.0, 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_multivector() {
+        // 1+2e1+3e2+4e12
+        let mvec1 = Multivector::new_components(1.0, 2.0, 3.0, 4.0);
+        // 5+6e1+7e2+8e12
+        let mvec2 = Multivector::new_components(5.0, 6.0, 7.0, 8.0);
+        let res = mvec1 ^ mvec2;
+        // 5+16e1+22e2+24e12
+        assert_relative_eq!(res.scalar(), 5.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 16.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 22.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 24.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_vector() {
+        let rotor = Rotor::new(Bivector::new(0.3));
+        let vector = Vector::new(3.0, 5.0);
+        let res = rotor ^ vector;
+        assert_relative_eq!(res.e1(), 3.0 * rotor.scalar(), max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 5.0 * rotor.scalar(), max_relative = 0.000001);
+    }
+}
@@ -0,0 +1,386 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{VGA2DOps, bivector::Bivector, multivector::Multivector, rotor::Rotor, vector::Vector};
+
+use num_traits::Float;
+
+// Functions
+// Rotation
+pub trait Rotatable<R> {
+    type Output;
+    fn rotate(self, rotor: R) -> Self::Output;
+}
+
+macro_rules! impl_rotatable {
+    ($vec:ty, $output:ty, $extract:ident) => {
+        // Owned vector, owned rotor
+        impl<F: Float> Rotatable<Rotor<F>> for $vec {
+            type Output = $output;
+            fn rotate(self, rotor: Rotor<F>) -> Self::Output {
+                (rotor.reverse() * self * rotor).$extract()
+            }
+        }
+
+        // Owned vector, reference rotor
+        impl<'r, F: Float> Rotatable<&'r Rotor<F>> for $vec {
+            type Output = $output;
+            fn rotate(self, rotor: &'r Rotor<F>) -> Self::Output {
+                (rotor.reverse() * self * *rotor).$extract()
+            }
+        }
+
+        // Reference vector, owned rotor
+        impl<'v, F: Float> Rotatable<Rotor<F>> for &'v $vec {
+            type Output = $output;
+            fn rotate(self, rotor: Rotor<F>) -> Self::Output {
+                (rotor.reverse() * *self * rotor).$extract()
+            }
+        }
+
+        // Reference vector, reference rotor
+        impl<'v, 'r, F: Float> Rotatable<&'r Rotor<F>> for &'v $vec {
+            type Output = $output;
+            fn rotate(self, rotor: &'r Rotor<F>) -> Self::Output {
+                (rotor.reverse() * *self * *rotor).$extract()
+            }
+        }
+    };
+}
+
+// Usage:
+impl_rotatable!(Vector<F>, Vector<F>, vector);
+impl_rotatable!(Bivector<F>, Bivector<F>, bivector);
+impl_rotatable!(Multivector<F>, Multivector<F>, multivector);
+
+#[cfg(test)]
+mod rotation {
+    use super::*;
+    use approx::assert_relative_eq;
+    use core::f32::consts::TAU;
+
+    #[test]
+    fn vec_rot_quarter_e1e2() {
+        let vector = Vector::new(3.0, 0.0);
+        let angle = TAU / 4.0;
+        let rotor = Rotor::new(Bivector::new(angle / 2.0));
+        let vector_rot_ref1 = (&vector).rotate(&rotor);
+        let vector_rot_ref2 = (&vector).rotate(rotor);
+        let vector_rot_ref3 = vector.rotate(&rotor);
+        let vector_rot = vector.rotate(rotor);
+
+        for res in [
+            vector_rot_ref1,
+            vector_rot_ref2,
+            vector_rot_ref3,
+            vector_rot,
+        ] {
+            assert_relative_eq!(res.e1(), 0.0, epsilon = 0.000001);
+            assert_relative_eq!(res.e2(), 3.0, max_relative = 0.000001);
+        }
+    }
+
+    #[test]
+    fn vector() {
+        // 3e1+4e2 rotated a third of a turn
+        let vector = Vector::new(3.0, 4.0);
+        let angle = TAU / 3.0;
+        let rotor = Rotor::new(Bivector::new(angle / 2.0));
+        let res = vector.rotate(rotor);
+        let (sin, cos) = angle.sin_cos();
+        assert_relative_eq!(res.e1(), 3.0 * cos - 4.0 * sin, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 3.0 * sin + 4.0 * cos, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector() {
+        // The plane is invariant under rotation
+        let bivector = Bivector::new(2.5);
+        let rotor = Rotor::new(Bivector::new(0.7));
+        let res = bivector.rotate(rotor);
+        assert_relative_eq!(res.e12(), 2.5, max_relative = 0.000001);
+    }
+}
+
+pub trait HasVector<F: Float> {
+    fn vector(&self) -> Vector<F>;
+}
+
+impl<F: Float> HasVector<F> for Vector<F> {
+    fn vector(&self) -> Vector<F> {
+        *self
+    }
+}
+
+impl<F: Float> HasVector<F> for Multivector<F> {
+    fn vector(&self) -> Vector<F> {
+        self.vector
+    }
+}
+
+pub trait HasBivector<F: Float> {
+    fn bivector(&self) -> Bivector<F>;
+}
+
+impl<F: Float> HasBivector<F> for Bivector<F> {
+    fn bivector(&self) -> Bivector<F> {
+        *self
+    }
+}
+
+impl<F: Float> HasBivector<F> for Multivector<F> {
+    fn bivector(&self) -> Bivector<F> {
+        self.bivector
+    }
+}
+
+pub trait HasMultivector<F: Float> {
+    fn multivector(&self) -> Multivector<F>;
+}
+
+impl<F: Float> HasMultivector<F> for Multivector<F> {
+    fn multivector(&self) -> Multivector<F> {
+        *self
+    }
+}
+
+pub trait Projectable<T, F: Float> {
+    type Output;
+
+    fn try_project(self, target: T) -> Option<Self::Output>
+    where
+        T: VGA2DOps<F> + Copy;
+}
+
+// For vectors
+impl<T, F: Float> Projectable<T, F> for Vector<F>
+where
+    T: VGA2DOps<F> + Copy,
+    Vector<F>: core::ops::BitOr<T>,
+    <Vector<F> as core::ops::BitOr<T>>::Output: core::ops::Mul<T>,
+    <<Vector<F> as core::ops::BitOr<T>>::Output as core::ops::Mul<T>>::Output: HasVector<F>,
+{
+    type Output = Vector<F>;
+    fn try_project(self, b: T) -> Option<Self::Output> {
+        b.try_inverse()
+            .map(|b_inverse| ((self | b) * b_inverse).vector())
+    }
+}
+
+impl<T, F: Float> Projectable<T, F> for Bivector<F>
+where
+    T: VGA2DOps<F> + Copy,
+    Bivector<F>: core::ops::BitOr<T>,
+    <Bivector<F> as core::ops::BitOr<T>>::Output: core::ops::Mul<T>,
+    <<Bivector<F> as core::ops::BitOr<T>>::Output as core::ops::Mul<T>>::Output: HasBivector<F>,
+{
+    type Output = Bivector<F>;
+    fn try_project(self, b: T) -> Option<Self::Output> {
+        b.try_inverse()
+            .map(|b_inverse| ((self | b) * b_inverse).bivector())
+    }
+}
+
+impl<T, F: Float> Projectable<T, F> for Multivector<F>
+where
+    T: VGA2DOps<F> + Copy,
+    Multivector<F>: core::ops::BitOr<T>,
+    <Multivector<F> as core::ops::BitOr<T>>::Output: core::ops::Mul<T>,
+    <<Multivector<F> as core::ops::BitOr<T>>::Output as core::ops::Mul<T>>::Output:
+        HasMultivector<F>,
+{
+    type Output = Multivector<F>;
+    fn try_project(self, b: T) -> Option<Self::Output> {
+        b.try_inverse()
+            .map(|b_inverse| ((self | b) * b_inverse).multivector())
+    }
+}
+
+#[cfg(test)]
+mod projection {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn vector_vector() {
+        let vector1 = Vector::new(2.0, 3.0);
+        let vector2 = Vector::new(-2.0, 4.0);
+        let res = vector1.try_project(vector2).unwrap();
+        // (8/20)(-2e1+4e2)
+        assert_relative_eq!(res.e1(), -0.8, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 1.6, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_bivector() {
+        // Every vector lies in the plane
+        let vector = Vector::new(2.0, 3.0);
+        let bivector = Bivector::new(4.0);
+        let res = vector.try_project(bivector).unwrap();
+        assert_relative_eq!(res.e1(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 3.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_zero() {
+        let vector = Vector::new(2.0, 3.0);
+        assert!(vector.try_project(Vector::zero()).is_none());
+    }
+}
+
+pub trait Rejectable<T, F: Float> {
+    type Output;
+
+    fn try_reject(self, target: T) -> Option<Self::Output>
+    where
+        T: VGA2DOps<F> + Copy;
+}
+
+impl<T, F: Float> Rejectable<T, F> for Vector<F>
+where
+    T: VGA2DOps<F> + Copy,
+    Vector<F>: core::ops::BitXor<T>,
+    <Vector<F> as core::ops::BitXor<T>>::Output: core::ops::Mul<T>,
+    <<Vector<F> as core::ops::BitXor<T>>::Output as core::ops::Mul<T>>::Output: HasVector<F>,
+{
+    type Output = Vector<F>;
+    fn try_reject(self, b: T) -> Option<Self::Output> {
+        b.try_inverse()
+            .map(|b_inverse| ((self ^ b) * b_inverse).vector())
+    }
+}
+
+impl<T, F: Float> Rejectable<T, F> for Bivector<F>
+where
+    T: VGA2DOps<F> + Copy,
+    Bivector<F>: core::ops::BitXor<T>,
+    <Bivector<F> as core::ops::BitXor<T>>::Output: core::ops::Mul<T>,
+    <<Bivector<F> as core::ops::BitXor<T>>::Output as core::ops::Mul<T>>::Output: HasBivector<F>,
+{
+    type Output = Bivector<F>;
+    fn try_reject(self, b: T) -> Option<Self::Output> {
+        b.try_inverse()
+            .map(|b_inverse| ((self ^ b) * b_inverse).bivector())
+    }
+}
+
+impl<T, F: Float> Rejectable<T, F> for Multivector<F>
+where
+    T: VGA2DOps<F> + Copy,
+    Multivector<F>: core::ops::BitXor<T>,
+    <Multivector<F> as core::ops::BitXor<T>>::Output: core::ops::Mul<T>,
+    <<Multivector<F> as core::ops::BitXor<T>>::Output as core::ops::Mul<T>>::Output:
+        HasMultivector<F>,
+{
+    type Output = Multivector<F>;
+    fn try_reject(self, b: T) -> Option<Self::Output> {
+        b.try_inverse()
+            .map(|b_inverse| ((self ^ b) * b_inverse).multivector())
+    }
+}
+
+#[cfg(test)]
+mod rejection {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn vector_vector() {
+        let vector1 = Vector::new(2.0, 3.0);
+        let vector2 = Vector::new(-2.0, 4.0);
+        let res = vector1.try_reject(vector2).unwrap();
+        // (2e1+3e2)-(-0.8e1+1.6e2)
+        assert_relative_eq!(res.e1(), 2.8, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 1.4, max_relative = 0.000001);
+    }
+}
+
+pub trait Reflectable<T, F: Float> {
+    type Output;
+
+    fn try_reflect(self, target: T) -> Option<Self::Output>
+    where
+        T: VGA2DOps<F> + Copy;
+}
+
+impl<T, F: Float> Reflectable<T, F> for Vector<F>
+where
+    T: VGA2DOps<F> + Copy,
+    T: core::ops::Mul<Vector<F>>, // T can multiply a vector
+    <T as core::ops::Mul<Vector<F>>>::Output: core::ops::Mul<T>, // Result can multiply T
+    <<T as core::ops::Mul<Vector<F>>>::Output as core::ops::Mul<T>>::Output: HasVector<F>, // Result has vector method
+{
+    type Output = Vector<F>;
+    fn try_reflect(self, b: T) -> Option<Self::Output> {
+        b.try_inverse()
+            .map(|b_inverse| ((b_inverse * self) * b).vector())
+    }
+}
+
+impl<T, F: Float> Reflectable<T, F> for Bivector<F>
+where
+    T: VGA2DOps<F> + Copy,
+    T: core::ops::Mul<Bivector<F>>, // T can multiply a bivector
+    <T as core::ops::Mul<Bivector<F>>>::Output: core::ops::Mul<T>, // Result can multiply T
+    <<T as core::ops::Mul<Bivector<F>>>::Output as core::ops::Mul<T>>::Output: HasBivector<F>, // Result has bivector method
+{
+    type Output = Bivector<F>;
+    fn try_reflect(self, b: T) -> Option<Self::Output> {
+        b.try_inverse()
+            .map(|b_inverse| ((b_inverse * self) * b).bivector())
+    }
+}
+
+impl<T, F: Float> Reflectable<T, F> for Multivector<F>
+where
+    T: VGA2DOps<F> + Copy,
+    T: core::ops::Mul<Multivector<F>>, // T can multiply a multivector
+    <T as core::ops::Mul<Multivector<F>>>::Output: core::ops::Mul<T>, // Result can multiply T
+    <<T as core::ops::Mul<Multivector<F>>>::Output as core::ops::Mul<T>>::Output: HasMultivector<F>, // Result has multivector method
+{
+    type Output = Multivector<F>;
+    fn try_reflect(self, b: T) -> Option<Self::Output> {
+        b.try_inverse()
+            .map(|b_inverse| ((b_inverse * self) * b).multivector())
+    }
+}
+
+#[cfg(test)]
+mod reflection {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn vector_vector() {
+        let vector1 = Vector::new(2.0, 3.0);
+        let vector2 = Vector::new(-2.0, 4.0);
+        let res = vector1.try_reflect(vector2).unwrap();
+        let test = vector1.try_project(vector2).unwrap() - vector1.try_reject(vector2).unwrap();
+        assert_relative_eq!(res.e1(), test.e1(), max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), test.e2(), max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_e1() {
+        // Reflecting in the e1 line flips the e2 component
+        let vector = Vector::new(2.0, 3.0);
+        let res = vector.try_reflect(Vector::new(1.0, 0.0)).unwrap();
+        assert_relative_eq!(res.e1(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -3.0, max_relative = 0.000001);
+    }
+}
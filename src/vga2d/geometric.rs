@@ -0,0 +1,416 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    bivector::Bivector, multivector::Multivector, rotor::Rotor, scalar::Scalar, vector::Vector,
+};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::Mul;
+
+// Geometric Product
+// Scalar-Scalar
+impl<F: Float> Mul for Scalar<F> {
+    type Output = F;
+    fn mul(self, b: Scalar<F>) -> F {
+        self.0 * b.0
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Scalar<F>);
+
+// Scalar-Vector
+// \[ s\vec{b}\]
+impl<F: Float> Mul<Vector<F>> for Scalar<F> {
+    type Output = Vector<F>;
+    fn mul(self, b: Vector<F>) -> Vector<F> {
+        Vector::new(self.0 * b.e1(), self.0 * b.e2())
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Vector<F>);
+
+// Vector-Scalar
+// \[ \vec{a}s\]
+impl<F: Float> Mul<Scalar<F>> for Vector<F> {
+    type Output = Vector<F>;
+    fn mul(self, b: Scalar<F>) -> Vector<F> {
+        Vector::new(self.e1() * b.0, self.e2() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Vector<F>, Scalar<F>);
+
+// Scalar-Bivector
+// \[ s\overset\Rightarrow{b}\]
+impl<F: Float> Mul<Bivector<F>> for Scalar<F> {
+    type Output = Bivector<F>;
+    fn mul(self, b: Bivector<F>) -> Bivector<F> {
+        Bivector::new(self.0 * b.e12())
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Bivector<F>);
+
+// Bivector-Scalar
+// \[ \overset\Rightarrow{a}s\]
+impl<F: Float> Mul<Scalar<F>> for Bivector<F> {
+    type Output = Bivector<F>;
+    fn mul(self, b: Scalar<F>) -> Bivector<F> {
+        Bivector::new(self.e12() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Bivector<F>, Scalar<F>);
+
+// Scalar-Multivector
+// \[ sB\]
+impl<F: Float> Mul<Multivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(self.0 * b.scalar(), self * b.vector(), self * b.bivector())
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Multivector<F>);
+
+// Multivector-Scalar
+// \[ As\]
+impl<F: Float> Mul<Scalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(self.scalar() * b.0, self.vector() * b, self.bivector() * b)
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Scalar<F>);
+
+// Scalar-Rotor
+// \[ sR\]
+impl<F: Float> Mul<Rotor<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(self.0 * b.scalar(), Vector::zero(), self * b.bivector())
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Scalar<F>, Rotor<F>);
+
+// Rotor-Scalar
+// \[ Rs\]
+impl<F: Float> Mul<Scalar<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(self.scalar() * b.0, Vector::zero(), self.bivector() * b)
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Rotor<F>, Scalar<F>);
+
+// Vector-Vector
+// \[ \vec{a} \vec{b} = \vec{a} \cdot \vec{b} +   \vec{a} \wedge \vec{b} \]
+impl<F: Float> Mul for Vector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.e1() * b.e1() + self.e2() * b.e2(),
+            Vector::zero(),
+            Bivector::new(self.e1() * b.e2() - self.e2() * b.e1()),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Vector<F>, Vector<F>);
+
+// Vector-Bivector
+// \[ \vec{a}\overset\Rightarrow{b} = \vec{a} \cdot \overset\Rightarrow{b} \]
+impl<F: Float> Mul<Bivector<F>> for Vector<F> {
+    type Output = Vector<F>;
+    fn mul(self, b: Bivector<F>) -> Vector<F> {
+        Vector::new(-self.e2() * b.e12(), self.e1() * b.e12())
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Vector<F>, Bivector<F>);
+
+// Bivector-Vector
+// \[ \overset\Rightarrow{a}\vec{b} = \overset\Rightarrow{a} \cdot \vec{b} \]
+impl<F: Float> Mul<Vector<F>> for Bivector<F> {
+    type Output = Vector<F>;
+    fn mul(self, b: Vector<F>) -> Vector<F> {
+        Vector::new(self.e12() * b.e2(), -self.e12() * b.e1())
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Bivector<F>, Vector<F>);
+
+// Vector-Multivector
+// \[ \vec{a}B\]
+impl<F: Float> Mul<Multivector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        let vector = self * b.scalar + self * b.bivector;
+        (self * b.vector) + vector
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Vector<F>, Multivector<F>);
+
+// Multivector-Vector
+// \[ A\vec{b}\]
+impl<F: Float> Mul<Vector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Vector<F>) -> Multivector<F> {
+        let vector = self.scalar * b + self.bivector * b;
+        (self.vector * b) + vector
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Vector<F>);
+
+// Vector-Rotor
+// \[ \vec{a}R\]
+impl<F: Float> Mul<Rotor<F>> for Vector<F> {
+    type Output = Vector<F>;
+    fn mul(self, b: Rotor<F>) -> Vector<F> {
+        Vector::new(
+            self.e1() * b.scalar() - self.e2() * b.e12(),
+            self.e2() * b.scalar() + self.e1() * b.e12(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Vector<F>, Rotor<F>);
+
+// Rotor-Vector
+// \[ R\vec{b}\]
+impl<F: Float> Mul<Vector<F>> for Rotor<F> {
+    type Output = Vector<F>;
+    fn mul(self, b: Vector<F>) -> Vector<F> {
+        Vector::new(
+            self.scalar() * b.e1() + self.e12() * b.e2(),
+            self.scalar() * b.e2() - self.e12() * b.e1(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Rotor<F>, Vector<F>);
+
+// Bivector-Bivector
+// \[ \overset\Rightarrow{a}\overset\Rightarrow{b} = \overset\Rightarrow{a} \cdot \overset\Rightarrow{b} \]
+impl<F: Float> Mul for Bivector<F> {
+    type Output = Scalar<F>;
+    fn mul(self, b: Bivector<F>) -> Scalar<F> {
+        Scalar(-self.e12() * b.e12())
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Bivector<F>, Bivector<F>);
+
+// Bivector-Multivector
+// \[ \overset\Rightarrow{a}B\]
+impl<F: Float> Mul<Multivector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector {
+            scalar: self * b.bivector,
+            vector: self * b.vector,
+            bivector: self * b.scalar,
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Bivector<F>, Multivector<F>);
+
+// Multivector-Bivector
+// \[ A\overset\Rightarrow{b}\]
+impl<F: Float> Mul<Bivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector {
+            scalar: self.bivector * b,
+            vector: self.vector * b,
+            bivector: self.scalar * b,
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Bivector<F>);
+
+// Bivector-Rotor
+// \[ \overset\Rightarrow{a}R\]
+impl<F: Float> Mul<Rotor<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector {
+            scalar: self * b.bivector,
+            vector: Vector::zero(),
+            bivector: self * b.scalar,
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Bivector<F>, Rotor<F>);
+
+// Rotor-Bivector
+// \[ R\overset\Rightarrow{b}\]
+impl<F: Float> Mul<Bivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector {
+            scalar: self.bivector * b,
+            vector: Vector::zero(),
+            bivector: self.scalar * b,
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Rotor<F>, Bivector<F>);
+
+// Multivector-Multivector
+impl<F: Float> Mul for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new_components(
+            self.scalar() * b.scalar() + self.e1() * b.e1() + self.e2() * b.e2()
+                - self.e12() * b.e12(),
+            self.scalar() * b.e1() + self.e1() * b.scalar() - self.e2() * b.e12()
+                + self.e12() * b.e2(),
+            self.scalar() * b.e2() + self.e2() * b.scalar() + self.e1() * b.e12()
+                - self.e12() * b.e1(),
+            self.scalar() * b.e12() + self.e12() * b.scalar() + self.e1() * b.e2()
+                - self.e2() * b.e1(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Multivector<F>);
+
+// Multivector-Rotor
+// \[ AR\]
+impl<F: Float> Mul<Rotor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new_components(
+            self.scalar() * b.scalar() - self.e12() * b.e12(),
+            self.e1() * b.scalar() - self.e2() * b.e12(),
+            self.e2() * b.scalar() + self.e1() * b.e12(),
+            self.scalar() * b.e12() + self.e12() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Multivector<F>, Rotor<F>);
+
+// Rotor-Multivector
+// \[ RB\]
+impl<F: Float> Mul<Multivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn mul(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new_components(
+            self.scalar() * b.scalar() - self.e12() * b.e12(),
+            self.scalar() * b.e1() + self.e12() * b.e2(),
+            self.scalar() * b.e2() - self.e12() * b.e1(),
+            self.scalar() * b.e12() + self.e12() * b.scalar(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Rotor<F>, Multivector<F>);
+
+// Test
+#[cfg(test)]
+mod geometric_product {
+    use super::*;
+    use approx::assert_relative_eq;
+    use core::f32::consts::TAU;
+
+    #[test]
+    fn vector_vector() {
+        // 3e1+5e2
+        let vector1 = Vector::new(3.0, 5.0);
+        // 2e1+e2
+        let vector2 = Vector::new(2.0, 1.0);
+        let res = vector1 * vector2;
+        // 11-7e12
+        assert_relative_eq!(res.scalar(), 11.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), -7.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_bivector() {
+        // 3e1+5e2
+        let vector = Vector::new(3.0, 5.0);
+        // 2e12
+        let bivector = Bivector::new(2.0);
+        let res = vector * bivector;
+        // -10e1+6e2
+        assert_relative_eq!(res.e1(), -10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 6.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_vector() {
+        // 2e12
+        let bivector = Bivector::new(2.0);
+        // 3e1+5e2
+        let vector = Vector::new(3.0, 5.0);
+        let res = bivector * vector;
+        // 10e1-6e2
+        assert_relative_eq!(res.e1(), 10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -6.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_bivector() {
+        let res = Bivector::new(2.0) * Bivector::new(3.0);
+        assert_relative_eq!(res.0, -6.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_multivector() {
+        // 1+2e1+3e2+4e12
+        let mvec1 = Multivector::new_components(1.0, 2.0, 3.0, 4.0);
+        // 5+6e1+7e2+8e12
+        let mvec2 = Multivector::new_components(5.0, 6.0, 7.0, 8.0);
+        let res = mvec1 * mvec2;
+        // 6+20e1+14e2+24e12
+        assert_relative_eq!(res.scalar(), 6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 20.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 24.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn grades_match_multivector() {
+        // Every product between grades is a special case of the multivector product
+        let vector = Vector::new(3.0, -5.0);
+        let bivector = Bivector::new(2.5);
+        let rotor = Rotor::new(Bivector::new(TAU / 10.0));
+        let mvec = Multivector::new_components(1.0, 2.0, 3.0, 4.0);
+        let vector_mvec = Multivector::new(0.0, vector, Bivector::zero());
+        let bivector_mvec = Multivector::new(0.0, Vector::zero(), bivector);
+        let rotor_mvec = Multivector::new(rotor.scalar(), Vector::zero(), rotor.bivector());
+
+        let pairs = [
+            (vector * mvec, vector_mvec * mvec),
+            (mvec * vector, mvec * vector_mvec),
+            (bivector * mvec, bivector_mvec * mvec),
+            (mvec * bivector, mvec * bivector_mvec),
+            (rotor * mvec, rotor_mvec * mvec),
+            (mvec * rotor, mvec * rotor_mvec),
+            (bivector * rotor, bivector_mvec * rotor_mvec),
+            (rotor * bivector, rotor_mvec * bivector_mvec),
+            (
+                Multivector::new(0.0, vector * rotor, Bivector::zero()),
+                vector_mvec * rotor_mvec,
+            ),
+            (
+                Multivector::new(0.0, rotor * vector, Bivector::zero()),
+                rotor_mvec * vector_mvec,
+            ),
+        ];
+        for (res, expected) in pairs {
+            assert_relative_eq!(res.scalar(), expected.scalar(), epsilon = 0.00001);
+            assert_relative_eq!(res.e1(), expected.e1(), epsilon = 0.00001);
+            assert_relative_eq!(res.e2(), expected.e2(), epsilon = 0.00001);
+            assert_relative_eq!(res.e12(), expected.e12(), epsilon = 0.00001);
+        }
+    }
+}
@@ -0,0 +1,365 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    bivector::Bivector, multivector::Multivector, rotor::Rotor, scalar::Scalar, vector::Vector,
+};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::BitOr;
+
+// Inner Product
+// \[ \left< A_r B_s \right>_{|r-s|} \]
+
+// Scalar-Scalar
+// \[ a \cdot b\]
+impl<F: Float> BitOr for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitor(self, b: Scalar<F>) -> Scalar<F> {
+        Scalar(self.0 * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Scalar<F>);
+
+// Scalar-Vector
+// \[ a \cdot \vec{b}\]
+impl<F: Float> BitOr<Vector<F>> for Scalar<F> {
+    type Output = Vector<F>;
+    fn bitor(self, b: Vector<F>) -> Vector<F> {
+        self * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Vector<F>);
+
+// Vector-Scalar
+// \[ \vec{a} \cdot b\]
+impl<F: Float> BitOr<Scalar<F>> for Vector<F> {
+    type Output = Vector<F>;
+    fn bitor(self, b: Scalar<F>) -> Vector<F> {
+        self * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Vector<F>, Scalar<F>);
+
+// Scalar-Bivector
+// \[ a \cdot \overset\Rightarrow{b}\]
+impl<F: Float> BitOr<Bivector<F>> for Scalar<F> {
+    type Output = Bivector<F>;
+    fn bitor(self, b: Bivector<F>) -> Bivector<F> {
+        self * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Bivector<F>);
+
+// Bivector-Scalar
+// \[ \overset\Rightarrow{a} \cdot b\]
+impl<F: Float> BitOr<Scalar<F>> for Bivector<F> {
+    type Output = Bivector<F>;
+    fn bitor(self, b: Scalar<F>) -> Bivector<F> {
+        self * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Bivector<F>, Scalar<F>);
+
+// Scalar-Multivector
+// \[ a \cdot B\]
+impl<F: Float> BitOr<Multivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        self * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Multivector<F>);
+
+// Multivector-Scalar
+// \[ A \cdot b\]
+impl<F: Float> BitOr<Scalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Scalar<F>) -> Multivector<F> {
+        self * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Scalar<F>);
+
+// Scalar-Rotor
+// \[ a \cdot R\]
+impl<F: Float> BitOr<Rotor<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Rotor<F>) -> Multivector<F> {
+        self * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Rotor<F>);
+
+// Rotor-Scalar
+// \[ R \cdot b\]
+impl<F: Float> BitOr<Scalar<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Scalar<F>) -> Multivector<F> {
+        self * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Rotor<F>, Scalar<F>);
+
+// Vector-Vector
+// \[ \vec{a} \cdot \vec{b}\]
+impl<F: Float> BitOr for Vector<F> {
+    type Output = Scalar<F>;
+    fn bitor(self, b: Vector<F>) -> Scalar<F> {
+        Scalar(self.e1() * b.e1() + self.e2() * b.e2())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Vector<F>, Vector<F>);
+
+// Vector-Bivector
+// \[ \vec{a} \cdot \overset\Rightarrow{b}\]
+impl<F: Float> BitOr<Bivector<F>> for Vector<F> {
+    type Output = Vector<F>;
+    fn bitor(self, b: Bivector<F>) -> Vector<F> {
+        self * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Vector<F>, Bivector<F>);
+
+// Bivector-Vector
+// \[ \overset\Rightarrow{a} \cdot \vec{b}\]
+impl<F: Float> BitOr<Vector<F>> for Bivector<F> {
+    type Output = Vector<F>;
+    fn bitor(self, b: Vector<F>) -> Vector<F> {
+        self * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Bivector<F>, Vector<F>);
+
+// Vector-Multivector
+// \[ \vec{a} \cdot B\]
+impl<F: Float> BitOr<Multivector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector {
+            scalar: self | b.vector,
+            vector: (self * b.scalar) + (self | b.bivector),
+            bivector: Bivector::zero(),
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Vector<F>, Multivector<F>);
+
+// Multivector-Vector
+// \[ A \cdot \vec{b}\]
+impl<F: Float> BitOr<Vector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Vector<F>) -> Multivector<F> {
+        Multivector {
+            scalar: self.vector | b,
+            vector: (self.scalar * b) + (self.bivector | b),
+            bivector: Bivector::zero(),
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Vector<F>);
+
+// Vector-Rotor
+// \[ \vec{a} \cdot R\]
+// Every part of the geometric product is a grade lowering part
+impl<F: Float> BitOr<Rotor<F>> for Vector<F> {
+    type Output = Vector<F>;
+    fn bitor(self, b: Rotor<F>) -> Vector<F> {
+        self * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Vector<F>, Rotor<F>);
+
+// Rotor-Vector
+// \[ R \cdot \vec{b}\]
+impl<F: Float> BitOr<Vector<F>> for Rotor<F> {
+    type Output = Vector<F>;
+    fn bitor(self, b: Vector<F>) -> Vector<F> {
+        self * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Rotor<F>, Vector<F>);
+
+// Bivector-Bivector
+// \[ \overset\Rightarrow{a} \cdot \overset\Rightarrow{b}\]
+impl<F: Float> BitOr for Bivector<F> {
+    type Output = Scalar<F>;
+    fn bitor(self, b: Bivector<F>) -> Scalar<F> {
+        self * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Bivector<F>, Bivector<F>);
+
+// Bivector-Multivector
+// \[ \overset\Rightarrow{a} \cdot B\]
+impl<F: Float> BitOr<Multivector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        self * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Bivector<F>, Multivector<F>);
+
+// Multivector-Bivector
+// \[ A \cdot \overset\Rightarrow{b}\]
+impl<F: Float> BitOr<Bivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Bivector<F>) -> Multivector<F> {
+        self * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Bivector<F>);
+
+// Bivector-Rotor
+// \[ \overset\Rightarrow{a} \cdot R\]
+impl<F: Float> BitOr<Rotor<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Rotor<F>) -> Multivector<F> {
+        self * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Bivector<F>, Rotor<F>);
+
+// Rotor-Bivector
+// \[ R \cdot \overset\Rightarrow{b}\]
+impl<F: Float> BitOr<Bivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Bivector<F>) -> Multivector<F> {
+        self * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Rotor<F>, Bivector<F>);
+
+// Multivector-Multivector
+// The only part of the geometric product that is not in the inner product is the exterior product between the vectors.
+// \[ A \cdot B = AB - \vec{a}\wedge\vec{b} \]
+impl<F: Float> BitOr for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        let product = self * b;
+        Multivector {
+            scalar: product.scalar,
+            vector: product.vector,
+            bivector: product.bivector - (self.vector ^ b.vector),
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Multivector<F>);
+
+// Multivector-Rotor
+// \[ A \cdot R\]
+impl<F: Float> BitOr<Rotor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Rotor<F>) -> Multivector<F> {
+        self * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Rotor<F>);
+
+// Rotor-Multivector
+// \[ R \cdot B\]
+impl<F: Float> BitOr<Multivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Multivector<F>) -> Multivector<F> {
+        self * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Rotor<F>, Multivector<F>);
+
+// Rotor-Rotor
+// \[ R_1 \cdot R_2\]
+impl<F: Float> BitOr for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector {
+            scalar: Scalar(self.scalar() * b.scalar() + (self.bivector | b.bivector).0),
+            vector: Vector::zero(),
+            bivector: (self.scalar | b.bivector) + (self.bivector | b.scalar),
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Rotor<F>, Rotor<F>);
+
+// Test
+#[cfg(test)]
+mod inner_product {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn vector_vector() {
+        // 3e1+5e2
+        let vector1 = Vector::new(3.0, 5.0);
+        // 2e1+e2
+        let vector2 = Vector::new(2.0, 1.0);
+        assert_relative_eq!((vector1 | vector2).0, 11.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_bivector() {
+        // 3e1+5e2
+        let vector = Vector::new(3.0, 5.0);
+        // 2e12
+        let bivector = Bivector::new(2.0);
+        let res = vector | bivector;
+        // -10e1+6e2
+        assert_relative_eq!(res.e1(), -10.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 6.0, max_relative = 0.000001);
+        // The result is orthogonal to the vector
+        assert_relative_eq!((res | vector).0, 0.0, epsilon = 0.000001);
+    }
+
+    #[test]
+    fn vector_multivector() {
+        // 3e1+5e2
+        let vector = Vector::new(3.0, 5.0);
+        // 1+2e1+3e2+4e12
+        let mvec = Multivector::new_components(1.0, 2.0, 3.0, 4.0);
+        let res = vector | mvec;
+        // 21-17e1+17e2
+        assert_relative_eq!(res.scalar(), 21.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -17.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 17.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_multivector() {
+        // 1+2e1+3e2+4e12
+        let mvec1 = Multivector::new_components(1.0, 2.0, 3.0, 4.0);
+        // 5+6e1+7e2+8e12
+        let mvec2 = Multivector::new_components(5.0, 6.0, 7.0, 8.0);
+        let res = mvec1 | mvec2;
+        // 6+20e1+14e2+28e12
+        assert_relative_eq!(res.scalar(), 6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 20.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 14.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 28.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_rotor() {
+        let rotor = Rotor::<f64>::identity();
+        let res = rotor | rotor;
+        assert_relative_eq!(res.scalar(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 0.0, max_relative = 0.000001);
+    }
+}
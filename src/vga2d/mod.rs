@@ -15,3 +15,88 @@
 //
 // You should have received a copy of the GNU Lesser General Public License
 // along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+// The products are implemented through the bit operators
+#![allow(clippy::suspicious_arithmetic_impl)]
+
+use core::ops::Mul;
+use num_traits::Float;
+
+mod scalar;
+pub use scalar::Scalar;
+//
+mod vector;
+pub use vector::Vector;
+//
+mod bivector;
+pub use bivector::Bivector;
+//
+mod multivector;
+pub use multivector::Multivector;
+//
+mod rotor;
+pub use rotor::Rotor;
+
+// Interactions
+mod addition;
+mod exterior;
+mod geometric;
+mod inner;
+mod regressive;
+mod subtraction;
+// Functions
+mod functions;
+pub use functions::{Projectable, Reflectable, Rejectable, Rotatable};
+
+pub trait VGA2DOps<F: Float>: Clone {
+    /// Reverse $\widetilde{A}$
+    fn reverse(self) -> Self;
+    /// Clifford conjugate $\bar{A}$
+    fn conjugate(self) -> Self;
+    /// Grade involution $\hat{A}$
+    fn involute(self) -> Self;
+    /// The norm $\|A\| = \sqrt{\left< A \widetilde{A} \right>_0}$
+    fn norm(self) -> Scalar<F>;
+
+    /// Scale the element to have a norm of one $\frac{A}{\|A\|}$
+    fn try_normalize(self) -> Option<Self>
+    where
+        Self: Sized,
+        Self: Mul<scalar::Scalar<F>, Output = Self>,
+    {
+        self.clone()
+            .norm()
+            .try_inverse()
+            .map(|norm_inverse| self * norm_inverse)
+    }
+
+    /// The inverse $A^{-1}$ with $A A^{-1} = 1$, if it exists
+    fn try_inverse(self) -> Option<Self>
+    where
+        Self: Sized;
+}
+
+pub trait VGA2DOpsRef<F: Float> {
+    /// Reverse $\widetilde{A}$
+    fn reverse(&self) -> Self;
+    /// Clifford conjugate $\bar{A}$
+    fn conjugate(&self) -> Self;
+    /// Grade involution $\hat{A}$
+    fn involute(&self) -> Self;
+    /// The norm $\|A\| = \sqrt{\left< A \widetilde{A} \right>_0}$
+    fn norm(&self) -> Scalar<F>;
+    /// Scale the element to have a norm of one $\frac{A}{\|A\|}$
+    fn try_normalize(&self) -> Option<Self>
+    where
+        Self: Sized,
+        for<'a> &'a Self: Mul<scalar::Scalar<F>, Output = Self>,
+    {
+        self.norm()
+            .try_inverse()
+            .map(|norm_inverse| self * norm_inverse)
+    }
+    /// The inverse $A^{-1}$ with $A A^{-1} = 1$, if it exists
+    fn try_inverse(&self) -> Option<Self>
+    where
+        Self: Sized;
+}
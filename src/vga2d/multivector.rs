@@ -0,0 +1,352 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::fmt;
+
+use core::ops::Neg;
+
+use num_traits::Float;
+
+use super::{Scalar, VGA2DOps, VGA2DOpsRef, bivector::Bivector, rotor::Rotor, vector::Vector};
+
+/// # 2D Vector Geometric Algebra Multivector
+/// Geometric algebra is the studie of multivectors
+///
+/// A multivector is a sum of all grades in the algebra
+/// $$ M = \text{scalar} + \vec{v} + \overset\Rightarrow{b} $$
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Multivector<F: Float> {
+    pub(super) scalar: Scalar<F>,
+    pub(super) vector: Vector<F>,
+    pub(super) bivector: Bivector<F>,
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + fmt::Display> fmt::Display for Multivector<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Multivector {{")?;
+        writeln!(f, "\tscalar: {}", self.scalar.0)?;
+        writeln!(f, "\tvector: {}", self.vector)?;
+        writeln!(f, "\tbivector: {}", self.bivector)?;
+        write!(f, "}}")
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F: Float + defmt::Format> defmt::Format for Multivector<F> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Multivector {{\n");
+        defmt::write!(f, "\tscalar: {}\n", self.scalar.scalar());
+        defmt::write!(f, "\tvector: {}\n", self.vector);
+        defmt::write!(f, "\tbivector: {}\n", self.bivector);
+        defmt::write!(f, "}}")
+    }
+}
+
+impl<F: Float> Multivector<F> {
+    /// The zero multivector
+    pub fn zero() -> Self {
+        Self {
+            scalar: Scalar::zero(),
+            vector: Vector::zero(),
+            bivector: Bivector::zero(),
+        }
+    }
+
+    /// Create new multivector from its grades
+    pub fn new(scalar: F, vector: Vector<F>, bivector: Bivector<F>) -> Self {
+        Self {
+            scalar: Scalar(scalar),
+            vector,
+            bivector,
+        }
+    }
+
+    /// Create new multivector from the scaling of each unit blade
+    pub fn new_components(scalar: F, e1: F, e2: F, e12: F) -> Self {
+        Self {
+            scalar: Scalar(scalar),
+            vector: Vector::new(e1, e2),
+            bivector: Bivector::new(e12),
+        }
+    }
+
+    /// Get multivector
+    pub fn multivector(self) -> Self {
+        self
+    }
+
+    /// Get the scalar grade
+    pub fn scalar(&self) -> F {
+        self.scalar.0
+    }
+
+    /// Get the vector grade
+    pub fn vector(&self) -> Vector<F> {
+        self.vector
+    }
+
+    /// Get the $\mathrm{e}_1$ part of the vector grade
+    pub fn e1(&self) -> F {
+        self.vector.e1()
+    }
+
+    /// Get the $\mathrm{e}_2$ part of the vector grade
+    pub fn e2(&self) -> F {
+        self.vector.e2()
+    }
+
+    /// Get the bivector grade
+    pub fn bivector(&self) -> Bivector<F> {
+        self.bivector
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2$ part of the bivector grade
+    pub fn e12(&self) -> F {
+        self.bivector.e12()
+    }
+}
+
+impl<F: Float> Neg for Multivector<F> {
+    type Output = Multivector<F>;
+    fn neg(self) -> Multivector<F> {
+        Multivector {
+            scalar: Scalar(-self.scalar.0),
+            vector: -self.vector,
+            bivector: -self.bivector,
+        }
+    }
+}
+
+impl<F: Float> VGA2DOps<F> for Multivector<F> {
+    // Reverse
+    // It follows the patten (Each is a grade)
+    // \[+ + - - + + - - \dots (-1)^{k(k-1)/2}\]
+    fn reverse(self) -> Self {
+        Multivector {
+            scalar: self.scalar,
+            vector: self.vector,
+            bivector: -self.bivector,
+        }
+    }
+
+    // Clifford Conjugation
+    // It follows the patten (Each is a grade)
+    // \[+--+--+\dots(-1)^{k(k+1)/2}\]
+    fn conjugate(self) -> Self {
+        Multivector {
+            scalar: self.scalar,
+            vector: -self.vector,
+            bivector: -self.bivector,
+        }
+    }
+
+    // Grade Involution
+    // The follows this patten (Each is a grade)
+    // \[+ - + - + -\dots (-1)^{k}\]
+    fn involute(self) -> Self {
+        Multivector {
+            scalar: self.scalar,
+            vector: -self.vector,
+            bivector: self.bivector,
+        }
+    }
+
+    // Inverse
+    // In 2D the product of a multivector and its Clifford conjugate is a scalar
+    // \[A^{-1}=\frac{\bar{A}}{A \bar{A}}\]
+    fn try_inverse(self) -> Option<Self> {
+        let conjugate = self.conjugate();
+        let determinant = (self * conjugate).scalar();
+        Scalar(determinant)
+            .try_inverse()
+            .map(|scalar_inverse| conjugate * scalar_inverse)
+    }
+
+    // the norm of a multivector |A|
+    // \[|A|^2=\left< A^\dag A \right>_0\]
+    fn norm(self) -> Scalar<F> {
+        Scalar((self.reverse() * self).scalar().sqrt())
+    }
+}
+
+impl<F: Float> VGA2DOpsRef<F> for Multivector<F> {
+    // Reverse
+    // It follows the patten (Each is a grade)
+    // \[+ + - - + + - - \dots (-1)^{k(k-1)/2}\]
+    fn reverse(&self) -> Self {
+        Multivector {
+            scalar: self.scalar,
+            vector: self.vector,
+            bivector: -self.bivector,
+        }
+    }
+
+    // Clifford Conjugation
+    // It follows the patten (Each is a grade)
+    // \[+--+--+\dots(-1)^{k(k+1)/2}\]
+    fn conjugate(&self) -> Self {
+        Multivector {
+            scalar: self.scalar,
+            vector: -self.vector,
+            bivector: -self.bivector,
+        }
+    }
+
+    // Grade Involution
+    // The follows this patten (Each is a grade)
+    // \[+ - + - + -\dots (-1)^{k}\]
+    fn involute(&self) -> Self {
+        Multivector {
+            scalar: self.scalar,
+            vector: -self.vector,
+            bivector: self.bivector,
+        }
+    }
+
+    // Inverse
+    // In 2D the product of a multivector and its Clifford conjugate is a scalar
+    // \[A^{-1}=\frac{\bar{A}}{A \bar{A}}\]
+    fn try_inverse(&self) -> Option<Self> {
+        let conjugate = self.conjugate();
+        let determinant = (self * conjugate).scalar();
+        Scalar(determinant)
+            .try_inverse()
+            .map(|scalar_inverse| conjugate * scalar_inverse)
+    }
+
+    // the norm of a multivector |A|
+    // \[|A|^2=\left< A^\dag A \right>_0\]
+    fn norm(&self) -> Scalar<F> {
+        Scalar((self.reverse() * self).scalar().sqrt())
+    }
+}
+
+// Dual
+// In VGA 2D, the dual is the multiplication with the pseudoscalar
+// \[ A \overset\Rightarrow{i} \]
+impl<F: Float> Multivector<F> {
+    /// # Dual
+    /// The right multiplication with the unit pseudoscalar
+    /// $$ A \overset\Rightarrow{i} $$
+    pub fn dual(self) -> Multivector<F> {
+        let scalar = self.bivector().dual();
+        let vector = self.vector().dual();
+        let bivector = Bivector::new(self.scalar());
+        Multivector::new(scalar, vector, bivector)
+    }
+}
+
+// Conversions
+// Every grade and the rotor is a multivector with the other grades set to zero
+impl<F: Float> From<Scalar<F>> for Multivector<F> {
+    fn from(scalar: Scalar<F>) -> Self {
+        Multivector::new(scalar.0, Vector::zero(), Bivector::zero())
+    }
+}
+
+impl<F: Float> From<Vector<F>> for Multivector<F> {
+    fn from(vector: Vector<F>) -> Self {
+        Multivector::new(F::zero(), vector, Bivector::zero())
+    }
+}
+
+impl<F: Float> From<Bivector<F>> for Multivector<F> {
+    fn from(bivector: Bivector<F>) -> Self {
+        Multivector::new(F::zero(), Vector::zero(), bivector)
+    }
+}
+
+impl<F: Float> From<Rotor<F>> for Multivector<F> {
+    fn from(rotor: Rotor<F>) -> Self {
+        Multivector::new(rotor.scalar(), Vector::zero(), rotor.bivector())
+    }
+}
+
+#[cfg(test)]
+mod multivector_ops {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn multivector_norm() {
+        let multivector = Multivector::new_components(2.0, -1.0, 4.0, 2.0);
+        // sqrt(4 + 1 + 16 + 4)
+        assert_relative_eq!(multivector.norm().scalar(), 5.0, max_relative = 0.000001);
+        assert_relative_eq!(
+            VGA2DOpsRef::norm(&multivector).scalar(),
+            5.0,
+            max_relative = 0.000001
+        );
+        assert_relative_eq!(
+            multivector.reverse().norm().scalar(),
+            5.0,
+            max_relative = 0.000001
+        );
+    }
+
+    #[test]
+    fn multivector_inverse() {
+        let multivector = Multivector::new_components(2.0, -1.0, 4.0, 3.0);
+        let inverse = match multivector.try_inverse() {
+            Some(inverse) => inverse,
+            None => Multivector::zero(),
+        };
+        let identity = multivector * inverse;
+        assert_relative_eq!(identity.scalar(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(identity.e1(), 0.0, epsilon = 0.000001);
+        assert_relative_eq!(identity.e2(), 0.0, epsilon = 0.000001);
+        assert_relative_eq!(identity.e12(), 0.0, epsilon = 0.000001);
+    }
+
+    #[test]
+    fn multivector_from() {
+        assert_eq!(
+            Multivector::from(Scalar::new(2.0)),
+            Multivector::new_components(2.0, 0.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            Multivector::from(Vector::new(-1.0, 4.0)),
+            Multivector::new_components(0.0, -1.0, 4.0, 0.0)
+        );
+        assert_eq!(
+            Multivector::from(Bivector::new(3.0)),
+            Multivector::new_components(0.0, 0.0, 0.0, 3.0)
+        );
+        let rotor = Rotor::new(Bivector::new(0.3));
+        assert_eq!(
+            Multivector::from(rotor),
+            Multivector::new_components(rotor.scalar(), 0.0, 0.0, rotor.bivector().e12())
+        );
+    }
+
+    #[test]
+    fn multivector_dual() {
+        let multivector = Multivector::new_components(1.0, 2.0, 3.0, 4.0);
+        let dual = multivector.dual();
+        let product = multivector * Bivector::new(1.0);
+        assert_relative_eq!(dual.scalar(), product.scalar(), max_relative = 0.000001);
+        assert_relative_eq!(dual.e1(), product.e1(), max_relative = 0.000001);
+        assert_relative_eq!(dual.e2(), product.e2(), max_relative = 0.000001);
+        assert_relative_eq!(dual.e12(), product.e12(), max_relative = 0.000001);
+    }
+}
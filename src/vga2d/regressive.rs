@@ -0,0 +1,390 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    bivector::Bivector, multivector::Multivector, rotor::Rotor, scalar::Scalar, vector::Vector,
+};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::BitAnd;
+
+// Regressive Product
+// \[ A \vee B = \left( (A I^{-1}) \wedge (B I^{-1}) \right) I \]
+// The grade of the result is r+s-2, so only products involving a bivector or two vectors survive.
+
+// Scalar-Scalar
+// \[ a\vee b\]
+impl<F: Float> BitAnd for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Scalar<F>);
+
+// Scalar-Vector
+// \[ a\vee \vec{b}\]
+impl<F: Float> BitAnd<Vector<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Vector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Vector<F>);
+
+// Vector-Scalar
+// \[ \vec{a}\vee b\]
+impl<F: Float> BitAnd<Scalar<F>> for Vector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Scalar<F>);
+
+// Scalar-Bivector
+// \[ a\vee \overset\Rightarrow{b}\]
+impl<F: Float> BitAnd<Bivector<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Bivector<F>) -> Scalar<F> {
+        Scalar(self.0 * b.e12())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Bivector<F>);
+
+// Bivector-Scalar
+// \[ \overset\Rightarrow{a}\vee b\]
+impl<F: Float> BitAnd<Scalar<F>> for Bivector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Scalar<F>) -> Scalar<F> {
+        Scalar(self.e12() * b.0)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Scalar<F>);
+
+// Scalar-Multivector
+// \[ a\vee B\]
+impl<F: Float> BitAnd<Multivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector {
+            scalar: self & b.bivector,
+            vector: Vector::zero(),
+            bivector: Bivector::zero(),
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Multivector<F>);
+
+// Multivector-Scalar
+// \[ A\vee b\]
+impl<F: Float> BitAnd<Scalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector {
+            scalar: self.bivector & b,
+            vector: Vector::zero(),
+            bivector: Bivector::zero(),
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Scalar<F>);
+
+// Scalar-Rotor
+// \[ a\vee R\]
+impl<F: Float> BitAnd<Rotor<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Rotor<F>) -> Scalar<F> {
+        self & b.bivector
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Rotor<F>);
+
+// Rotor-Scalar
+// \[ R\vee b\]
+impl<F: Float> BitAnd<Scalar<F>> for Rotor<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Scalar<F>) -> Scalar<F> {
+        self.bivector & b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Rotor<F>, Scalar<F>);
+
+// Vector-Vector
+// \[ \vec{a}\vee \vec{b}\]
+impl<F: Float> BitAnd for Vector<F> {
+    type Output = Scalar<F>;
+    fn bitand(self, b: Vector<F>) -> Scalar<F> {
+        Scalar(self.e2() * b.e1() - self.e1() * b.e2())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Vector<F>);
+
+// Vector-Bivector
+// \[ \vec{a}\vee \overset\Rightarrow{b}\]
+impl<F: Float> BitAnd<Bivector<F>> for Vector<F> {
+    type Output = Vector<F>;
+    fn bitand(self, b: Bivector<F>) -> Vector<F> {
+        self * Scalar(b.e12())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Bivector<F>);
+
+// Bivector-Vector
+// \[ \overset\Rightarrow{a}\vee \vec{b}\]
+impl<F: Float> BitAnd<Vector<F>> for Bivector<F> {
+    type Output = Vector<F>;
+    fn bitand(self, b: Vector<F>) -> Vector<F> {
+        Scalar(self.e12()) * b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Vector<F>);
+
+// Vector-Multivector
+// \[ \vec{a}\vee B\]
+impl<F: Float> BitAnd<Multivector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector {
+            scalar: self & b.vector,
+            vector: self & b.bivector,
+            bivector: Bivector::zero(),
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Multivector<F>);
+
+// Multivector-Vector
+// \[ A\vee \vec{b}\]
+impl<F: Float> BitAnd<Vector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Vector<F>) -> Multivector<F> {
+        Multivector {
+            scalar: self.vector & b,
+            vector: self.bivector & b,
+            bivector: Bivector::zero(),
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Vector<F>);
+
+// Vector-Rotor
+// \[ \vec{a}\vee R\]
+impl<F: Float> BitAnd<Rotor<F>> for Vector<F> {
+    type Output = Vector<F>;
+    fn bitand(self, b: Rotor<F>) -> Vector<F> {
+        self & b.bivector
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Rotor<F>);
+
+// Rotor-Vector
+// \[ R\vee \vec{b}\]
+impl<F: Float> BitAnd<Vector<F>> for Rotor<F> {
+    type Output = Vector<F>;
+    fn bitand(self, b: Vector<F>) -> Vector<F> {
+        self.bivector & b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Rotor<F>, Vector<F>);
+
+// Bivector-Bivector
+// \[ \overset\Rightarrow{a}\vee \overset\Rightarrow{b}\]
+impl<F: Float> BitAnd for Bivector<F> {
+    type Output = Bivector<F>;
+    fn bitand(self, b: Bivector<F>) -> Bivector<F> {
+        Bivector::new(self.e12() * b.e12())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Bivector<F>);
+
+// Bivector-Multivector
+// \[ \overset\Rightarrow{a}\vee B\]
+impl<F: Float> BitAnd<Multivector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector {
+            scalar: self & b.scalar,
+            vector: self & b.vector,
+            bivector: self & b.bivector,
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Multivector<F>);
+
+// Multivector-Bivector
+// \[ A\vee \overset\Rightarrow{b}\]
+impl<F: Float> BitAnd<Bivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector {
+            scalar: self.scalar & b,
+            vector: self.vector & b,
+            bivector: self.bivector & b,
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Bivector<F>);
+
+// Bivector-Rotor
+// \[ \overset\Rightarrow{a}\vee R\]
+impl<F: Float> BitAnd<Rotor<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector {
+            scalar: self & b.scalar,
+            vector: Vector::zero(),
+            bivector: self & b.bivector,
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Rotor<F>);
+
+// Rotor-Bivector
+// \[ R\vee \overset\Rightarrow{b}\]
+impl<F: Float> BitAnd<Bivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector {
+            scalar: self.scalar & b,
+            vector: Vector::zero(),
+            bivector: self.bivector & b,
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Rotor<F>, Bivector<F>);
+
+// Multivector-Multivector
+// \[ A \vee B\]
+impl<F: Float> BitAnd for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector {
+            scalar: Scalar(
+                (self.scalar & b.bivector).0
+                    + (self.bivector & b.scalar).0
+                    + (self.vector & b.vector).0,
+            ),
+            vector: (self.vector & b.bivector) + (self.bivector & b.vector),
+            bivector: self.bivector & b.bivector,
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Multivector<F>);
+
+// Multivector-Rotor
+// \[ A\vee R\]
+impl<F: Float> BitAnd<Rotor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector {
+            scalar: Scalar((self.scalar & b.bivector).0 + (self.bivector & b.scalar).0),
+            vector: self.vector & b.bivector,
+            bivector: self.bivector & b.bivector,
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Rotor<F>);
+
+// Rotor-Multivector
+// \[ R\vee B\]
+impl<F: Float> BitAnd<Multivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector {
+            scalar: Scalar((self.scalar & b.bivector).0 + (self.bivector & b.scalar).0),
+            vector: self.bivector & b.vector,
+            bivector: self.bivector & b.bivector,
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Rotor<F>, Multivector<F>);
+
+// Rotor-Rotor
+// \[ R_1\vee R_2\]
+impl<F: Float> BitAnd for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitand(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector {
+            scalar: Scalar((self.scalar & b.bivector).0 + (self.bivector & b.scalar).0),
+            vector: Vector::zero(),
+            bivector: self.bivector & b.bivector,
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Rotor<F>, Rotor<F>);
+
+// Test
+#[cfg(test)]
+mod regressive_product {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn vector_vector() {
+        // 3e1+5e2
+        let vector1 = Vector::new(3.0, 5.0);
+        // 2e1+e2
+        let vector2 = Vector::new(2.0, 1.0);
+        let res = vector1 & vector2;
+        // 7
+        assert_relative_eq!(res.0, 7.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_vector() {
+        // 2e12
+        let bivector = Bivector::new(2.0);
+        // 3e1+5e2
+        let vector = Vector::new(3.0, 5.0);
+        let res = bivector & vector;
+        // 6e1+10e2
+        assert_relative_eq!(res.e1(), 6.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 10.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_multivector() {
+        // 1+2e1+3e2+4e12
+        let mvec1 = Multivector::new_components(1.0, 2.0, 3.0, 4.0);
+        // 5+6e1+7e2+8e12
+        let mvec2 = Multivector::new_components(5.0, 6.0, 7.0, 8.0);
+        let res = mvec1 & mvec2;
+        // 32+40e1+52e2+32e12
+        assert_relative_eq!(res.scalar(), 32.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 40.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 52.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 32.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_multivector() {
+        let rotor = Rotor::new(Bivector::new(0.4));
+        // 1+2e1+3e2+4e12
+        let mvec = Multivector::new_components(1.0, 2.0, 3.0, 4.0);
+        let res = rotor & mvec;
+        let expected = Multivector::new_components(rotor.scalar(), 0.0, 0.0, rotor.e12()) & mvec;
+        assert_relative_eq!(res.scalar(), expected.scalar(), max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), expected.e1(), max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), expected.e2(), max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), expected.e12(), max_relative = 0.000001);
+    }
+}
@@ -0,0 +1,340 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::fmt;
+
+use crate::forward_ref_binop;
+use core::ops::Mul;
+
+use num_traits::Float;
+
+use super::{VGA2DOps, VGA2DOpsRef, bivector::Bivector, scalar::Scalar};
+
+/// # 2D Vector Geometric Algebra Rotor
+///
+/// The rotor is the rotation object in Geometric Algebra
+/// $$ R\left (\frac{\theta}{2} \right ) = \mathrm{e}^{ \mathrm{e}_1\mathrm{e}_2 \frac{\theta}{2}} = \cos \left( \frac{\theta}{2}  \right) + \sin \left( \frac{\theta}{2} \right)\mathrm{e}_1\mathrm{e}_2 $$
+/// In 2D there is only one plane of rotation, so the rotor is a unit complex number.
+/// The norm of a rotor is always 1
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Rotor<F: Float> {
+    pub(super) scalar: Scalar<F>,
+    pub(super) bivector: Bivector<F>,
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + fmt::Display> fmt::Display for Rotor<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Rotor {{")?;
+        writeln!(f, "\tscalar: {}", self.scalar())?;
+        writeln!(f, "\tbivector: {}", self.bivector)?;
+        write!(f, "}}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F: Float + defmt::Format> defmt::Format for Rotor<F> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Rotor {{\n");
+        defmt::write!(f, "\tscalar: {}\n", self.scalar());
+        defmt::write!(f, "\tbivector: {}\n", self.bivector);
+        defmt::write!(f, "}}");
+    }
+}
+
+impl<F: Float> Rotor<F> {
+    /// Creates new rotor from an angle bivector $\overset\Rightarrow{\theta}$.
+    /// The angle must be in radians and must be half the rotational angle.
+    /// The sign of the bivector is the direction of rotation.
+    pub fn new(half_angle_bivector: Bivector<F>) -> Self {
+        let half_angle = half_angle_bivector.e12();
+        Rotor {
+            scalar: Scalar(half_angle.cos()),
+            bivector: Bivector::new(half_angle.sin()),
+        }
+    }
+
+    /// Tries to creates new rotor from plane of rotation and angle of rotationen
+    /// The plane of rotation is a bivector
+    /// The direction of rotation is given by the orientation of the bivector
+    /// The angle is half of the rotation angle
+    pub fn try_new_from_half_angle_plane(
+        half_angle: F,
+        rotation_plane: Bivector<F>,
+    ) -> Option<Self> {
+        match rotation_plane.e12() {
+            orientation if orientation.is_zero() => None,
+            orientation => Some(Rotor {
+                scalar: Scalar(half_angle.cos()),
+                bivector: Bivector::new(half_angle.sin() * orientation.signum()),
+            }),
+        }
+    }
+
+    /// This is the identity Rotor.
+    /// Anything rotated with this (0.0 rad) will return it self.
+    /// cos(0.0) = 1.0
+    /// sin(0.0) = 0.0
+    pub fn identity() -> Self {
+        Rotor {
+            scalar: Scalar(F::one()),
+            bivector: Bivector::zero(),
+        }
+    }
+
+    /// Get the scalar grade of the rotor
+    pub fn scalar(&self) -> F {
+        self.scalar.0
+    }
+
+    /// Get the bivector grade of the rotor
+    pub fn bivector(&self) -> Bivector<F> {
+        self.bivector
+    }
+
+    /// Get the $\mathrm{e}_1\mathrm{e}_2$ part of the bivector grade of the rotor
+    pub fn e12(&self) -> F {
+        self.bivector.e12()
+    }
+
+    /// Get the signed half angle of the rotor.
+    /// A positive angle rotates $\mathrm{e}_1$ towards $\mathrm{e}_2$.
+    pub fn get_half_angle(&self) -> Scalar<F> {
+        Scalar(self.e12().atan2(self.scalar()))
+    }
+
+    /// Get the orientation of the plane of rotation of the rotor
+    /// This is not posible for the identity rotor
+    pub fn try_get_rotation_plane(&self) -> Option<Bivector<F>> {
+        match self.e12() {
+            e12 if e12.is_zero() => None,
+            e12 => Some(Bivector::new(e12.signum())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod rotor_new {
+    use super::*;
+    use approx::assert_relative_eq;
+    use core::f32::consts::TAU;
+
+    #[test]
+    fn new() {
+        let angle = TAU / 4.0;
+        let rotor = Rotor::new(Bivector::new(angle / 2.0));
+        assert_relative_eq!(rotor.scalar(), 0.70710677, max_relative = 0.000001);
+        assert_relative_eq!(rotor.e12(), 0.70710677, max_relative = 0.000001);
+        assert_relative_eq!(rotor.norm().scalar(), 1.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn new_from_plane() {
+        let angle = TAU / 4.0;
+        let rotor = match Rotor::try_new_from_half_angle_plane(angle / 2.0, Bivector::new(-3.0)) {
+            Some(rotor) => rotor,
+            None => Rotor::identity(),
+        };
+        assert_relative_eq!(rotor.scalar(), 0.70710677, max_relative = 0.000001);
+        assert_relative_eq!(rotor.e12(), -0.70710677, max_relative = 0.000001);
+        assert_eq!(
+            Rotor::try_new_from_half_angle_plane(angle, Bivector::zero()),
+            None
+        );
+    }
+
+    #[test]
+    fn get_angle() {
+        let angle = -TAU / 3.0;
+        let rotor = Rotor::new(Bivector::new(angle / 2.0));
+        assert_relative_eq!(
+            rotor.get_half_angle().scalar(),
+            angle / 2.0,
+            max_relative = 0.000001
+        );
+        assert_eq!(rotor.try_get_rotation_plane(), Some(Bivector::new(-1.0)));
+        assert_eq!(Rotor::<f32>::identity().try_get_rotation_plane(), None);
+    }
+}
+
+/// # Geometric Product
+/// The geometric product of two rotors is another rotor
+/// $$ R_1 R_2 = R_3$$
+impl<F: Float> Mul for Rotor<F> {
+    type Output = Rotor<F>;
+
+    fn mul(self: Rotor<F>, b: Rotor<F>) -> Rotor<F> {
+        let scalar = self.scalar() * b.scalar() - self.e12() * b.e12();
+        let e12 = self.scalar() * b.e12() + self.e12() * b.scalar();
+
+        // Normalize to get rid of float errors
+        let norm = (scalar * scalar + e12 * e12).sqrt();
+        Rotor {
+            scalar: Scalar(scalar / norm),
+            bivector: Bivector::new(e12 / norm),
+        }
+    }
+}
+forward_ref_binop!(impl<F:Float> Mul, mul for Rotor<F>, Rotor<F>);
+
+#[cfg(test)]
+mod rotor_geo {
+    use super::*;
+    use approx::assert_relative_eq;
+    use core::f32::consts::TAU;
+
+    #[test]
+    fn rotor_rotor_geo() {
+        // Two quarter turns is a half turn
+        let rotor = Rotor::new(Bivector::new(TAU / 8.0));
+        let res = rotor * rotor;
+        assert_relative_eq!(res.scalar(), 0.0, epsilon = 0.000001);
+        assert_relative_eq!(res.e12(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(
+            res.get_half_angle().scalar(),
+            TAU / 4.0,
+            max_relative = 0.000001
+        );
+    }
+}
+
+impl<F: Float> VGA2DOps<F> for Rotor<F> {
+    // \[ |R|^2=\left< R^\dag R \right>_0 \]
+    fn norm(self) -> Scalar<F> {
+        Scalar((self.scalar() * self.scalar() + self.e12() * self.e12()).sqrt())
+    }
+
+    // Inverse
+    // \[A^{-1}=\frac{A^\dag}{\left< A A^\dag \right>}\]
+    fn try_inverse(self) -> Option<Rotor<F>> {
+        let norm_squared = self.scalar() * self.scalar() + self.e12() * self.e12();
+        Scalar(norm_squared)
+            .try_inverse()
+            .map(|scalar_inverse| Rotor {
+                scalar: Scalar(self.scalar() * scalar_inverse.0),
+                bivector: self.reverse().bivector * scalar_inverse,
+            })
+    }
+
+    // Reverse
+    // It follows the patten (Each is a grade)
+    // \[+ + - - + + - - \dots (-1)^{k(k-1)/2}\]
+    fn reverse(self) -> Self {
+        Rotor {
+            scalar: self.scalar,
+            bivector: -self.bivector,
+        }
+    }
+
+    // Clifford Conjugation
+    // It follows the patten (Each is a grade)
+    // \[+--+--+\dots(-1)^{k(k+1)/2}\]
+    fn conjugate(self) -> Self {
+        Rotor {
+            scalar: self.scalar,
+            bivector: -self.bivector,
+        }
+    }
+
+    // Grade Involution
+    // The follows this patten (Each is a grade)
+    // \[+ - + - + -\dots (-1)^{k}\]
+    fn involute(self) -> Self {
+        self
+    }
+}
+
+impl<F: Float> VGA2DOpsRef<F> for Rotor<F> {
+    fn norm(&self) -> Scalar<F> {
+        Scalar((self.scalar() * self.scalar() + self.e12() * self.e12()).sqrt())
+    }
+
+    // Inverse
+    // \[A^{-1}=\frac{A^\dag}{\left< A A^\dag \right>}\]
+    fn try_inverse(&self) -> Option<Rotor<F>> {
+        let norm_squared = self.scalar() * self.scalar() + self.e12() * self.e12();
+        Scalar(norm_squared)
+            .try_inverse()
+            .map(|scalar_inverse| Rotor {
+                scalar: Scalar(self.scalar() * scalar_inverse.0),
+                bivector: self.reverse().bivector * scalar_inverse,
+            })
+    }
+
+    // Reverse
+    // It follows the patten (Each is a grade)
+    // \[+ + - - + + - - \dots (-1)^{k(k-1)/2}\]
+    fn reverse(&self) -> Self {
+        Rotor {
+            scalar: self.scalar,
+            bivector: -self.bivector,
+        }
+    }
+
+    // Clifford Conjugation
+    // It follows the patten (Each is a grade)
+    // \[+--+--+\dots(-1)^{k(k+1)/2}\]
+    fn conjugate(&self) -> Self {
+        Rotor {
+            scalar: self.scalar,
+            bivector: -self.bivector,
+        }
+    }
+
+    // Grade Involution
+    // The follows this patten (Each is a grade)
+    // \[+ - + - + -\dots (-1)^{k}\]
+    fn involute(&self) -> Self {
+        *self
+    }
+}
+
+#[cfg(test)]
+mod rotor_ops {
+    use super::*;
+    use approx::assert_relative_eq;
+    use core::f32::consts::TAU;
+
+    #[test]
+    fn rotor_inverse() {
+        let rotor = Rotor::new(Bivector::new(TAU / 7.0));
+        let inverse = match rotor.try_inverse() {
+            Some(inverse) => inverse,
+            None => Rotor::identity(),
+        };
+        let res = rotor * inverse;
+        assert_relative_eq!(res.scalar(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 0.0, epsilon = 0.000001);
+        assert_relative_eq!(
+            inverse.scalar(),
+            rotor.reverse().scalar(),
+            max_relative = 0.000001
+        );
+        assert_relative_eq!(
+            inverse.e12(),
+            rotor.reverse().e12(),
+            max_relative = 0.000001
+        );
+    }
+}
@@ -0,0 +1,83 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+#![warn(missing_docs)]
+
+use core::ops::Neg;
+
+use num_traits::Float;
+
+/// # 2D Vector Geometric Algebra Scalar
+/// Wrapper for the Generic Float type
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Scalar<F: Float>(pub(super) F);
+
+impl<F: Float> Scalar<F> {
+    /// The zero scalar
+    pub fn zero() -> Self {
+        Scalar(F::zero())
+    }
+
+    /// New scalar from scalar
+    pub fn new(scalar: F) -> Self {
+        Scalar(scalar)
+    }
+
+    ///  Get scalar
+    pub fn scalar(self) -> F {
+        self.0
+    }
+
+    /// try to inverse a scalar value
+    pub fn try_inverse(self) -> Option<Scalar<F>> {
+        match self.0 {
+            scalar if scalar.is_zero() => None,
+            scalar => Some(Scalar(F::one() / scalar)),
+        }
+    }
+}
+
+// Negation
+impl<F: Float> Neg for Scalar<F> {
+    type Output = Scalar<F>;
+    fn neg(self) -> Scalar<F> {
+        Scalar(-self.0)
+    }
+}
+
+#[cfg(test)]
+mod scalar_inverse {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn scalar_inverse() {
+        let scalar = Scalar(4.0);
+        let scalar_inverse = match scalar.try_inverse() {
+            Some(scalar) => scalar,
+            None => Scalar(0.0),
+        };
+
+        assert_relative_eq!(scalar_inverse.scalar(), 1.0 / 4.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn scalar_zero_inverse() {
+        let scalar = Scalar(0.0);
+        assert_eq!(scalar.try_inverse(), None);
+    }
+}
@@ -0,0 +1,330 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    bivector::Bivector, multivector::Multivector, rotor::Rotor, scalar::Scalar, vector::Vector,
+};
+
+use num_traits::Float;
+
+use crate::forward_ref_binop;
+
+use core::ops::Sub;
+
+// Scalar-Scalar
+// \[ a-b\]
+impl<F: Float> Sub for Scalar<F> {
+    type Output = F;
+    fn sub(self, b: Scalar<F>) -> F {
+        self.0 - b.0
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Scalar<F>, Scalar<F>);
+
+// Scalar-Vector
+// \[ a-\vec{b}\]
+impl<F: Float> Sub<Vector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(self.0, -b, Bivector::zero())
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Scalar<F>, Vector<F>);
+
+// Vector-Scalar
+// \[ \vec{a}-b\]
+impl<F: Float> Sub<Scalar<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(-b.0, self, Bivector::zero())
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Vector<F>, Scalar<F>);
+
+// Scalar-Bivector
+// \[ a-\overset\Rightarrow{b}\]
+impl<F: Float> Sub<Bivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(self.0, Vector::zero(), -b)
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Scalar<F>, Bivector<F>);
+
+// Bivector-Scalar
+// \[ \overset\Rightarrow{a}-b\]
+impl<F: Float> Sub<Scalar<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(-b.0, Vector::zero(), self)
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Bivector<F>, Scalar<F>);
+
+// Scalar-Multivector
+// \[ a-B\]
+impl<F: Float> Sub<Multivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(self.0 - b.scalar(), -b.vector(), -b.bivector())
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Scalar<F>, Multivector<F>);
+
+// Multivector-Scalar
+// \[ A-b\]
+impl<F: Float> Sub<Scalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(self.scalar() - b.0, self.vector(), self.bivector())
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Multivector<F>, Scalar<F>);
+
+// Scalar-Rotor
+// \[ a-R\]
+impl<F: Float> Sub<Rotor<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(self.0 - b.scalar(), Vector::zero(), -b.bivector())
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Scalar<F>, Rotor<F>);
+
+// Rotor-Scalar
+// \[ R-b\]
+impl<F: Float> Sub<Scalar<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(self.scalar() - b.0, Vector::zero(), self.bivector())
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Rotor<F>, Scalar<F>);
+
+// Vector-Vector
+// \[ \vec{a}-\vec{b}\]
+impl<F: Float> Sub for Vector<F> {
+    type Output = Vector<F>;
+    fn sub(self, b: Vector<F>) -> Vector<F> {
+        Vector::new(self.e1() - b.e1(), self.e2() - b.e2())
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Vector<F>, Vector<F>);
+
+// Vector-Bivector
+// \[ \vec{a}-\overset\Rightarrow{b}\]
+impl<F: Float> Sub<Bivector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(F::zero(), self, -b)
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Vector<F>, Bivector<F>);
+
+// Bivector-Vector
+// \[ \overset\Rightarrow{a}-\vec{b}\]
+impl<F: Float> Sub<Vector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(F::zero(), -b, self)
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Bivector<F>, Vector<F>);
+
+// Vector-Multivector
+// \[ \vec{a}-B\]
+impl<F: Float> Sub<Multivector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(-b.scalar(), self - b.vector(), -b.bivector())
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Vector<F>, Multivector<F>);
+
+// Multivector-Vector
+// \[ A-\vec{b}\]
+impl<F: Float> Sub<Vector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(self.scalar(), self.vector() - b, self.bivector())
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Multivector<F>, Vector<F>);
+
+// Vector-Rotor
+// \[ \vec{a}-R\]
+impl<F: Float> Sub<Rotor<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(-b.scalar(), self, -b.bivector())
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Vector<F>, Rotor<F>);
+
+// Rotor-Vector
+// \[ R-\vec{b}\]
+impl<F: Float> Sub<Vector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(self.scalar(), -b, self.bivector())
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Rotor<F>, Vector<F>);
+
+// Bivector-Bivector
+// \[ \overset\Rightarrow{a}-\overset\Rightarrow{b}\]
+impl<F: Float> Sub for Bivector<F> {
+    type Output = Bivector<F>;
+    fn sub(self, b: Bivector<F>) -> Bivector<F> {
+        Bivector::new(self.e12() - b.e12())
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Bivector<F>, Bivector<F>);
+
+// Bivector-Multivector
+// \[ \overset\Rightarrow{a}-B\]
+impl<F: Float> Sub<Multivector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(-b.scalar(), -b.vector(), self - b.bivector())
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Bivector<F>, Multivector<F>);
+
+// Multivector-Bivector
+// \[ A-\overset\Rightarrow{b}\]
+impl<F: Float> Sub<Bivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(self.scalar(), self.vector(), self.bivector() - b)
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Multivector<F>, Bivector<F>);
+
+// Bivector-Rotor
+// \[ \overset\Rightarrow{a}-R\]
+impl<F: Float> Sub<Rotor<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(-b.scalar(), Vector::zero(), self - b.bivector())
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Bivector<F>, Rotor<F>);
+
+// Rotor-Bivector
+// \[ R-\overset\Rightarrow{b}\]
+impl<F: Float> Sub<Bivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(self.scalar(), Vector::zero(), self.bivector() - b)
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Rotor<F>, Bivector<F>);
+
+// Multivector-Multivector
+// \[ A-B\]
+impl<F: Float> Sub for Multivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() - b.scalar(),
+            self.vector() - b.vector(),
+            self.bivector() - b.bivector(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Multivector<F>, Multivector<F>);
+
+// Multivector-Rotor
+// \[ A-R\]
+impl<F: Float> Sub<Rotor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() - b.scalar(),
+            self.vector(),
+            self.bivector() - b.bivector(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Multivector<F>, Rotor<F>);
+
+// Rotor-Multivector
+// \[ R-B\]
+impl<F: Float> Sub<Multivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() - b.scalar(),
+            -b.vector(),
+            self.bivector() - b.bivector(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Rotor<F>, Multivector<F>);
+
+// Rotor-Rotor
+// \[ R-R\]
+impl<F: Float> Sub for Rotor<F> {
+    type Output = Multivector<F>;
+    fn sub(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() - b.scalar(),
+            Vector::zero(),
+            self.bivector() - b.bivector(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> Sub, sub for Rotor<F>, Rotor<F>);
+
+// Test
+#[cfg(test)]
+mod subtraction_difference {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn vector_vector() {
+        let res = Vector::new(3.0, 5.0) - Vector::new(2.0, -1.0);
+        assert_relative_eq!(res.e1(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 6.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn scalar_vector() {
+        let res = Scalar(2.0) - Vector::new(3.0, 5.0);
+        assert_relative_eq!(res.scalar(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -5.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_multivector() {
+        let mvec = Multivector::new_components(1.0, 2.0, 3.0, 4.0);
+        let res = mvec - mvec;
+        assert_eq!(res, Multivector::zero());
+    }
+
+    #[test]
+    fn rotor_bivector() {
+        let rotor = Rotor::identity();
+        let res = rotor - Bivector::new(2.0);
+        assert_relative_eq!(res.scalar(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), -2.0, max_relative = 0.000001);
+    }
+}
@@ -0,0 +1,261 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+#![warn(missing_docs)]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+use std::fmt;
+
+use core::ops::Neg;
+
+use num_traits::Float;
+
+use super::{VGA2DOps, VGA2DOpsRef, scalar::Scalar};
+
+/// # 2D Vector Geometric Algebra Vector
+/// This is the same vector as in $\mathbb{R}^2$
+/// $$\vec{v}=v_1 \mathrm{e}_1 + v_2 \mathrm{e}_2$$
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Vector<F: Float> {
+    e1: F,
+    e2: F,
+}
+
+#[cfg(feature = "std")]
+impl<F: Float + fmt::Display> fmt::Display for Vector<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Vector {{")?;
+        write!(f, " {}e1", self.e1)?;
+
+        // For e2 component, add appropriate sign
+        if self.e2 >= F::zero() {
+            write!(f, " + {}e2", self.e2)?;
+        } else {
+            write!(f, " - {}e2", self.e2.abs())?;
+        }
+        write!(f, " }}")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F: Float + defmt::Format> defmt::Format for Vector<F> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Vector {{");
+        defmt::write!(f, " {}e1", self.e1);
+
+        // For e2 component, add appropriate sign
+        if self.e2 >= F::zero() {
+            defmt::write!(f, " + {}e2", self.e2);
+        } else {
+            defmt::write!(f, " - {}e2", self.e2.abs());
+        }
+        defmt::write!(f, " }}");
+    }
+}
+
+impl<F: Float> Vector<F> {
+    /// The zero vector
+    pub fn zero() -> Self {
+        Self {
+            e1: F::zero(),
+            e2: F::zero(),
+        }
+    }
+
+    /// Create new vector from linear combination of unit vectors
+    pub fn new(e1: F, e2: F) -> Self {
+        Self { e1, e2 }
+    }
+
+    /// Get vector
+    pub fn vector(self) -> Self {
+        self
+    }
+
+    // Vector components
+    /// Get unit scaling factor for $\mathrm{e}_1$
+    pub fn e1(&self) -> F {
+        self.e1
+    }
+
+    /// Get unit scaling factor for $\mathrm{e}_2$
+    pub fn e2(&self) -> F {
+        self.e2
+    }
+}
+
+#[cfg(test)]
+mod vector_new {
+    use super::*;
+
+    #[test]
+    fn vector_new() {
+        let vec = Vector::new(2.0, 1.0);
+        assert_eq!(vec.e1, 2.0);
+        assert_eq!(vec.e2, 1.0);
+    }
+}
+
+// Negation
+impl<F: Float> Neg for Vector<F> {
+    type Output = Vector<F>;
+    fn neg(self) -> Vector<F> {
+        Vector::new(-self.e1, -self.e2)
+    }
+}
+
+impl<F: Float> Vector<F> {
+    /// # Dual
+    /// In VGA 2D, the dual is the multiplication with the pseudoscalar
+    /// $$ \vec{v} \overset\Rightarrow{i} = \vec{u} $$
+    /// The dual of a vector is the vector turned a quarter turn from $\mathrm{e}_1$ towards $\mathrm{e}_2$
+    pub fn dual(self) -> Vector<F> {
+        Vector::new(-self.e2, self.e1)
+    }
+}
+
+#[cfg(test)]
+mod vector_dual {
+    use super::*;
+    #[test]
+    fn vector_dual() {
+        let vector = Vector::new(1.0, 2.0);
+        let dual = vector.dual();
+        assert_eq!(dual.e1(), -2.0);
+        assert_eq!(dual.e2(), 1.0);
+    }
+}
+
+impl<F: Float> VGA2DOps<F> for Vector<F> {
+    fn norm(self) -> Scalar<F> {
+        Scalar(((self.e1() * self.e1()) + (self.e2() * self.e2())).sqrt())
+    }
+
+    // Inverse
+    // \[A^{-1}=\frac{A^\dag}{\left< A A^\dag \right>}\]
+    fn try_inverse(self) -> Option<Self> {
+        let norm_squared = (self * self.reverse()).scalar();
+        Scalar(norm_squared)
+            .try_inverse()
+            .map(|scalar_inverse| self.reverse() * scalar_inverse)
+    }
+
+    // Reverse
+    // It follows the patten (Each is a grade)
+    // \[+ + - - + + - - \dots (-1)^{k(k-1)/2}\]
+    fn reverse(self) -> Self {
+        self
+    }
+
+    // Clifford Conjugation
+    // It follows the patten (Each is a grade)
+    // \[+--+--+\dots(-1)^{k(k+1)/2}\]
+    fn conjugate(self) -> Self {
+        -self
+    }
+
+    // Grade Involution
+    // The follows this patten (Each is a grade)
+    // \[+ - + - + -\dots (-1)^{k}\]
+    fn involute(self) -> Self {
+        -self
+    }
+}
+
+impl<F: Float> VGA2DOpsRef<F> for Vector<F> {
+    fn norm(&self) -> Scalar<F> {
+        Scalar(((self.e1() * self.e1()) + (self.e2() * self.e2())).sqrt())
+    }
+
+    // Inverse
+    // \[A^{-1}=\frac{A^\dag}{\left< A A^\dag \right>}\]
+    fn try_inverse(&self) -> Option<Self> {
+        let norm_squared = (self * self.reverse()).scalar();
+        Scalar(norm_squared)
+            .try_inverse()
+            .map(|scalar_inverse| self.reverse() * scalar_inverse)
+    }
+
+    // Reverse
+    // It follows the patten (Each is a grade)
+    // \[+ + - - + + - - \dots (-1)^{k(k-1)/2}\]
+    fn reverse(&self) -> Self {
+        *self
+    }
+
+    // Clifford Conjugation
+    // It follows the patten (Each is a grade)
+    // \[+--+--+\dots(-1)^{k(k+1)/2}\]
+    fn conjugate(&self) -> Self {
+        -(*self)
+    }
+
+    // Grade Involution
+    // The follows this patten (Each is a grade)
+    // \[+ - + - + -\dots (-1)^{k}\]
+    fn involute(&self) -> Self {
+        -(*self)
+    }
+}
+
+#[cfg(test)]
+mod vector_ops {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn vector_norm() {
+        // 3e1+4e2
+        let vector = Vector::new(3.0, 4.0);
+        assert_relative_eq!(vector.norm().scalar(), 5.0, max_relative = 0.000001);
+        assert_relative_eq!(
+            VGA2DOpsRef::norm(&vector).scalar(),
+            5.0,
+            max_relative = 0.000001
+        );
+    }
+
+    #[test]
+    fn vector_inverse() {
+        // 3e1+4e2
+        let vector = Vector::new(3.0, 4.0);
+        let inverse = match vector.try_inverse() {
+            Some(inverse) => inverse,
+            None => Vector::zero(),
+        };
+        // v^{-1} = v / |v|^2
+        assert_relative_eq!(inverse.e1(), 0.12, max_relative = 0.000001);
+        assert_relative_eq!(inverse.e2(), 0.16, max_relative = 0.000001);
+        assert_relative_eq!((vector * inverse).scalar(), 1.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_normalize() {
+        let vector = Vector::new(3.0, 4.0);
+        let unit = match vector.try_normalize() {
+            Some(unit) => unit,
+            None => Vector::zero(),
+        };
+        assert_relative_eq!(unit.e1(), 0.6, max_relative = 0.000001);
+        assert_relative_eq!(unit.e2(), 0.8, max_relative = 0.000001);
+        assert_eq!(Vector::<f32>::zero().try_normalize(), None);
+    }
+}
@@ -35,8 +35,8 @@ use libm::sqrtf;
 use num_traits::Float;
 
 use super::{
-    multivector::Multivector, scalar::Scalar, trivector::Trivector, vector::Vector, VGA3DOps,
-    VGA3DOpsRef,
+    multivector::Multivector, rotor::Rotor, scalar::Scalar, trivector::Trivector, vector::Vector,
+    VGA3DOps, VGA3DOpsRef,
 };
 
 /// # 3D Vector Geometric Algebra Bivector
@@ -45,7 +45,11 @@ use super::{
 ///
 /// This is the correct way to represent an axial vector.
 /// The two are confused because the bivector is the dual of the vector.
+///
+/// The layout is `#[repr(C)]` and the same as `[F; 3]` with the components in the order
+/// $\mathrm{e}_{12}$, $\mathrm{e}_{31}$, $\mathrm{e}_{23}$.
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
 pub struct Bivector<F: Float> {
     e12: F,
     e31: F,
@@ -218,16 +222,129 @@ mod bivector_cross {
 }
 
 impl<F: Float> Bivector<F> {
-    /// # Dual
-    /// In VGA 3D, the dual is the unit pseudoscalar $\overset\Rrightarrow{i}$
-    ///
-    /// $$ \overset\Rightarrow{b} \overset\Rrightarrow{i} = -\vec{v} $$
-    ///
-    /// Vector and bivectors in 3D VGA follows this pattern. Going up, going down
-    ///
-    /// $$ \text{scalar}, \mathrm{e}_1,\mathrm{e}_2,\mathrm{e}_3,\mathrm{e}_3\star, \mathrm{e}_2\star, \mathrm{e}_1\star, \text{scalar}\star $$
-    pub fn dual(self) -> Vector<F> {
-        Vector::new(-self.e23, -self.e31, -self.e12)
+    /// # Exponential
+    /// The exponential of a bivector is a rotor.
+    /// The bivector is the half angle bivector $\overset\Rightarrow{\theta}$
+    /// $$ \mathrm{e}^{\overset\Rightarrow{\theta}} = \cos \left( \left| \overset\Rightarrow{\theta} \right| \right) + \frac{\sin \left( \left| \overset\Rightarrow{\theta} \right| \right)}{\left| \overset\Rightarrow{\theta} \right|}\overset\Rightarrow{\theta} $$
+    /// Close to zero the Taylor series $\frac{\sin(x)}{x} \approx 1 - \frac{x^2}{6}$ is used.
+    pub fn exp(self) -> Rotor<F> {
+        let half_angle = self.norm().scalar();
+        let six = F::one() + F::one() + F::one() + F::one() + F::one() + F::one();
+
+        let sinc = match half_angle {
+            half_angle if half_angle < F::epsilon().sqrt() => {
+                F::one() - half_angle * half_angle / six
+            }
+            _ => half_angle.sin() / half_angle,
+        };
+        Rotor {
+            scalar: Scalar(half_angle.cos()),
+            bivector: self * Scalar(sinc),
+        }
+    }
+}
+
+#[cfg(test)]
+mod bivector_exp {
+    use super::*;
+    use approx::assert_relative_eq;
+    use core::f32::consts::TAU;
+
+    #[test]
+    fn exp() {
+        // e^{(TAU/8) e12} = cos(TAU/8) + sin(TAU/8)e12
+        let bivector = Bivector::new(TAU / 8.0, 0.0, 0.0);
+        let rotor = bivector.exp();
+        assert_relative_eq!(rotor.scalar(), 0.70710677, max_relative = 0.000001);
+        assert_relative_eq!(rotor.e12(), 0.70710677, max_relative = 0.000001);
+        assert_relative_eq!(rotor.e31(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(rotor.e23(), 0.0, max_relative = 0.000001);
+    }
+    #[test]
+    fn exp_plane() {
+        // 0.70710677+0.52522576e12+0.26261288e31-0.3939193e23
+        let rotation_plane = Bivector::new(4.0, 2.0, -3.0);
+        let bivector = rotation_plane * Scalar(TAU / 8.0 / rotation_plane.norm().scalar());
+        let rotor = bivector.exp();
+        assert_relative_eq!(rotor.scalar(), 0.70710677, max_relative = 0.000001);
+        assert_relative_eq!(rotor.e12(), 0.52522576, max_relative = 0.000001);
+        assert_relative_eq!(rotor.e31(), 0.26261288, max_relative = 0.000001);
+        assert_relative_eq!(rotor.e23(), -0.3939193, max_relative = 0.000001);
+    }
+    #[test]
+    fn exp_zero() {
+        // e^0 = 1
+        let rotor = Bivector::<f32>::zero().exp();
+        assert_relative_eq!(rotor.scalar(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(rotor.bivector().norm().scalar(), 0.0, max_relative = 0.000001);
+    }
+    #[test]
+    fn exp_small() {
+        // e^{10^{-5} e23} = 1 + 10^{-5} e23
+        let rotor = Bivector::new(0.0, 0.0, 0.00001).exp();
+        assert_relative_eq!(rotor.scalar(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(rotor.e23(), 0.00001, max_relative = 0.000001);
+    }
+}
+
+impl<F: Float> Bivector<F> {
+    /// # Skew-Symmetric Matrix
+    /// The bivector as a $3\times3$ skew-symmetric matrix, indexed as `[row][column]`.
+    /// The matrix acting on a vector is the left contraction with the bivector
+    /// $$ K\vec{v} = \vec{v} \rfloor \overset\Rightarrow{b} = \vec{\omega} \times \vec{v} $$
+    /// where $\vec{\omega} = -\overset\Rightarrow{b}\star$ is the axis of the bivector.
+    pub fn to_skew_matrix3(self) -> [[F; 3]; 3] {
+        let zero = F::zero();
+        [
+            [zero, -self.e12, self.e31],
+            [self.e12, zero, -self.e23],
+            [-self.e31, self.e23, zero],
+        ]
+    }
+
+    /// Creates the bivector from the skew-symmetric part of a $3\times3$ matrix, indexed as `[row][column]`.
+    /// $$ \frac{1}{2} \left( M - M^T \right) $$
+    pub fn from_skew_matrix3(m: [[F; 3]; 3]) -> Self {
+        let half = F::one() / (F::one() + F::one());
+        Bivector::new(
+            (m[1][0] - m[0][1]) * half,
+            (m[0][2] - m[2][0]) * half,
+            (m[2][1] - m[1][2]) * half,
+        )
+    }
+}
+
+#[cfg(test)]
+mod bivector_skew_matrix {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn to_skew_matrix3() {
+        // K v = v _| B
+        let bivector = Bivector::new(2.0, 1.0, 6.0);
+        let vector = Vector::new(3.0, 5.0, 4.0);
+        let m = bivector.to_skew_matrix3();
+        let res = vector | bivector;
+        let components = [vector.e1(), vector.e2(), vector.e3()];
+        let product: [f32; 3] = core::array::from_fn(|i| {
+            m[i].iter().zip(components.iter()).map(|(a, b)| a * b).sum()
+        });
+        assert_relative_eq!(product[0], res.e1(), max_relative = 0.000001);
+        assert_relative_eq!(product[1], res.e2(), max_relative = 0.000001);
+        assert_relative_eq!(product[2], res.e3(), max_relative = 0.000001);
+    }
+    #[test]
+    fn from_skew_matrix3() {
+        // The skew-symmetric part gives back the bivector
+        let bivector = Bivector::new(2.0, 1.0, 6.0);
+        let mut m = bivector.to_skew_matrix3();
+        m[0][1] += 3.0;
+        m[1][0] += 3.0;
+        let res = Bivector::from_skew_matrix3(m);
+        assert_relative_eq!(res.e12(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 6.0, max_relative = 0.000001);
     }
 }
 
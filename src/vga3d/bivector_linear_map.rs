@@ -0,0 +1,301 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{Rotatable, VGA3DOps, bivector::Bivector, rotor::Rotor};
+use crate::linear_algebra::{determinant, multiply, symmetric_eigen, transpose, try_inverse};
+
+use num_traits::Float;
+
+/// # Bivector Linear Map
+/// A linear map from bivectors to bivectors, like the inertia of a rigid body
+/// $$ \overset\Rightarrow{L} = I\left(\overset\Rightarrow{\Omega}\right) $$
+/// The map is a $3\times3$ matrix over the $\mathrm{e}_{12}$, $\mathrm{e}_{31}$, $\mathrm{e}_{23}$ basis,
+/// indexed as `[row][column]`.
+/// The columns are the images of the basis bivectors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BivectorLinearMap<F: Float> {
+    matrix: [[F; 3]; 3],
+}
+
+/// # Principal Axes
+/// The decomposition of a symmetric bivector linear map into its principal planes
+/// $$ I\left(\overset\Rightarrow{b}\right) = \sum_k \lambda_k \left(\overset\Rightarrow{p}_k \cdot \overset\Rightarrow{b}\right) \overset\Rightarrow{p}_k $$
+/// The moments $\lambda_k$ are in increasing order, and each is paired with the plane $\overset\Rightarrow{p}_k$ of the same index.
+/// The planes are the $\mathrm{e}_{12}$, $\mathrm{e}_{31}$, $\mathrm{e}_{23}$ basis bivectors in that order rotated with the rotor,
+/// $\overset\Rightarrow{p}_k = R^\dag \mathrm{e}_k R$.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PrincipalAxes<F: Float> {
+    moments: [F; 3],
+    rotor: Rotor<F>,
+}
+
+impl<F: Float> PrincipalAxes<F> {
+    /// The principal moments in increasing order
+    pub fn moments(&self) -> [F; 3] {
+        self.moments
+    }
+
+    /// The rotor that rotates the basis bivectors onto the principal planes
+    pub fn rotor(&self) -> Rotor<F> {
+        self.rotor
+    }
+
+    /// The unit principal planes in the order of the moments
+    pub fn planes(&self) -> [Bivector<F>; 3] {
+        basis().map(|bivector| bivector.rotate(self.rotor))
+    }
+}
+
+impl<F: Float> BivectorLinearMap<F> {
+    /// Creates a new map from the matrix over the $\mathrm{e}_{12}$, $\mathrm{e}_{31}$, $\mathrm{e}_{23}$ basis
+    pub fn new(matrix: [[F; 3]; 3]) -> Self {
+        BivectorLinearMap { matrix }
+    }
+
+    /// Creates a diagonal map, like the inertia in the principal planes
+    pub fn from_diagonal(diagonal: [F; 3]) -> Self {
+        let mut matrix = [[F::zero(); 3]; 3];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            row[i] = diagonal[i];
+        }
+        BivectorLinearMap { matrix }
+    }
+
+    /// The identity map
+    pub fn identity() -> Self {
+        BivectorLinearMap::from_diagonal([F::one(); 3])
+    }
+
+    /// The matrix over the $\mathrm{e}_{12}$, $\mathrm{e}_{31}$, $\mathrm{e}_{23}$ basis
+    pub fn matrix(&self) -> [[F; 3]; 3] {
+        self.matrix
+    }
+
+    /// # Application
+    /// Applies the map to a bivector
+    pub fn apply(&self, bivector: Bivector<F>) -> Bivector<F> {
+        let b = [bivector.e12(), bivector.e31(), bivector.e23()];
+        let row = |i: usize| {
+            self.matrix[i][0] * b[0] + self.matrix[i][1] * b[1] + self.matrix[i][2] * b[2]
+        };
+        Bivector::new(row(0), row(1), row(2))
+    }
+
+    /// # Composition
+    /// The map that applies `b` first and then this map
+    pub fn compose(&self, b: BivectorLinearMap<F>) -> Self {
+        BivectorLinearMap {
+            matrix: multiply(self.matrix, b.matrix),
+        }
+    }
+
+    /// The transposed map
+    pub fn transpose(&self) -> Self {
+        BivectorLinearMap {
+            matrix: transpose(self.matrix),
+        }
+    }
+
+    /// The determinant of the map
+    pub fn determinant(&self) -> F {
+        determinant(self.matrix)
+    }
+
+    /// # Inverse
+    /// Tries to invert the map from the adjugate.
+    /// Returns `None` if the map is singular.
+    pub fn try_inverse(&self) -> Option<Self> {
+        try_inverse(self.matrix).map(|matrix| BivectorLinearMap { matrix })
+    }
+
+    /// # Rotation
+    /// The map in a rotated frame
+    /// $$ I'\left(\overset\Rightarrow{b}\right) = R^\dag I\left(R \overset\Rightarrow{b} R^\dag\right) R $$
+    pub fn rotate(&self, rotor: Rotor<F>) -> Self {
+        let reverse = rotor.reverse();
+        let columns = basis().map(|bivector| self.apply(bivector.rotate(reverse)).rotate(rotor));
+        let matrix = core::array::from_fn(|i| {
+            core::array::from_fn(|j| {
+                let column = columns[j];
+                [column.e12(), column.e31(), column.e23()][i]
+            })
+        });
+        BivectorLinearMap { matrix }
+    }
+
+    /// # Principal Axes
+    /// Tries to decompose a symmetric map into its principal moments and planes.
+    /// The eigenvalue problem is solved with Jacobi rotations.
+    ///
+    /// Returns `None` if the map is not symmetric.
+    pub fn try_principal_axes(&self) -> Option<PrincipalAxes<F>> {
+        let m = self.matrix;
+        let tolerance = F::epsilon().sqrt();
+        let scale = m
+            .iter()
+            .flatten()
+            .fold(F::zero(), |max, element| max.max(element.abs()));
+        for (i, j) in [(0, 1), (0, 2), (1, 2)] {
+            if (m[i][j] - m[j][i]).abs() > tolerance * scale {
+                return None;
+            }
+        }
+        let (values, vectors) = symmetric_eigen(m);
+        // Sort the moments in increasing order
+        let mut order = [0, 1, 2];
+        order.sort_unstable_by(|&a, &b| {
+            values[a]
+                .partial_cmp(&values[b])
+                .unwrap_or(core::cmp::Ordering::Equal)
+        });
+        let moments = order.map(|k| values[k]);
+        let mut planes = order.map(|k| [vectors[0][k], vectors[1][k], vectors[2][k]]);
+        // A right handed set of planes
+        let [a, b, c] = planes;
+        let handedness = a[0] * (b[1] * c[2] - b[2] * c[1]) - a[1] * (b[0] * c[2] - b[2] * c[0])
+            + a[2] * (b[0] * c[1] - b[1] * c[0]);
+        if handedness < F::zero() {
+            planes[2] = c.map(|element| -element);
+        }
+        // The axial vector of the plane e12 is e3, so the vector matrix is the reversed bivector matrix
+        let rotation = core::array::from_fn(|i| core::array::from_fn(|j| planes[2 - j][2 - i]));
+        Some(PrincipalAxes {
+            moments,
+            rotor: Rotor::try_from_matrix3(rotation)?,
+        })
+    }
+}
+
+fn basis<F: Float>() -> [Bivector<F>; 3] {
+    let (zero, one) = (F::zero(), F::one());
+    [
+        Bivector::new(one, zero, zero),
+        Bivector::new(zero, one, zero),
+        Bivector::new(zero, zero, one),
+    ]
+}
+
+#[cfg(test)]
+mod bivector_map {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn assert_map_eq(a: BivectorLinearMap<f64>, b: BivectorLinearMap<f64>) {
+        for (row_a, row_b) in a.matrix().iter().zip(b.matrix().iter()) {
+            for (element_a, element_b) in row_a.iter().zip(row_b.iter()) {
+                assert_relative_eq!(element_a, element_b, epsilon = 1e-10);
+            }
+        }
+    }
+
+    fn inertia() -> BivectorLinearMap<f64> {
+        BivectorLinearMap::new([[4.0, 0.3, -0.2], [0.3, 3.0, 0.1], [-0.2, 0.1, 2.0]])
+    }
+
+    #[test]
+    fn apply() {
+        let map = BivectorLinearMap::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        let res = map.apply(Bivector::new(1.0, 0.0, -1.0));
+        assert_eq!(res, Bivector::new(-2.0, -2.0, -2.0));
+        // The columns are the images of the basis
+        assert_eq!(
+            map.apply(Bivector::new(0.0, 1.0, 0.0)),
+            Bivector::new(2.0, 5.0, 8.0)
+        );
+        let diagonal = BivectorLinearMap::from_diagonal([2.0, 3.0, 4.0]);
+        assert_eq!(
+            diagonal.apply(Bivector::new(1.0, 1.0, 1.0)),
+            Bivector::new(2.0, 3.0, 4.0)
+        );
+    }
+    #[test]
+    fn inverse() {
+        let map = inertia();
+        let inverse = map.try_inverse().unwrap();
+        assert_map_eq(map.compose(inverse), BivectorLinearMap::identity());
+        assert_map_eq(inverse.compose(map), BivectorLinearMap::identity());
+        let singular = BivectorLinearMap::new([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 1.0]]);
+        assert_eq!(singular.try_inverse(), None);
+    }
+    #[test]
+    fn compose() {
+        let a = inertia();
+        let b = BivectorLinearMap::new([[0.0, 1.0, 0.0], [-1.0, 0.0, 2.0], [0.5, 0.0, 1.0]]);
+        let bivector = Bivector::new(0.3, -0.7, 1.1);
+        let res = a.compose(b).apply(bivector);
+        let expected = a.apply(b.apply(bivector));
+        assert_relative_eq!(res.e12(), expected.e12(), epsilon = 1e-12);
+        assert_relative_eq!(res.e31(), expected.e31(), epsilon = 1e-12);
+        assert_relative_eq!(res.e23(), expected.e23(), epsilon = 1e-12);
+        assert_eq!(b.transpose().transpose(), b);
+        assert_relative_eq!(
+            a.compose(b).determinant(),
+            a.determinant() * b.determinant()
+        );
+    }
+    #[test]
+    fn rotate() {
+        // The rotated map commutes with the rotation
+        let map = inertia();
+        let rotor = Bivector::new(0.3, -0.2, 0.5).exp();
+        let bivector = Bivector::new(0.3, -0.7, 1.1);
+        let res = map.rotate(rotor).apply(bivector.rotate(rotor));
+        let expected = map.apply(bivector).rotate(rotor);
+        assert_relative_eq!(res.e12(), expected.e12(), epsilon = 1e-12);
+        assert_relative_eq!(res.e31(), expected.e31(), epsilon = 1e-12);
+        assert_relative_eq!(res.e23(), expected.e23(), epsilon = 1e-12);
+    }
+    #[test]
+    fn principal_axes() {
+        let map = inertia();
+        let axes = map.try_principal_axes().unwrap();
+        let moments = axes.moments();
+        assert!(moments[0] <= moments[1] && moments[1] <= moments[2]);
+        assert_relative_eq!(moments.iter().sum::<f64>(), 9.0, epsilon = 1e-12);
+        // The principal planes are eigenbivectors
+        for (moment, plane) in moments.iter().zip(axes.planes()) {
+            let res = map.apply(plane);
+            assert_relative_eq!(res.e12(), moment * plane.e12(), epsilon = 1e-10);
+            assert_relative_eq!(res.e31(), moment * plane.e31(), epsilon = 1e-10);
+            assert_relative_eq!(res.e23(), moment * plane.e23(), epsilon = 1e-10);
+        }
+        // The diagonal map rotated back is the map
+        let diagonal = BivectorLinearMap::from_diagonal(moments);
+        assert_map_eq(diagonal.rotate(axes.rotor()), map);
+    }
+    #[test]
+    fn principal_axes_rotated() {
+        // A diagonal inertia in a rotated frame is recovered
+        let rotor = Bivector::new(-0.4, 0.1, 0.6).exp();
+        let map = BivectorLinearMap::from_diagonal([1.0, 2.0, 3.0]).rotate(rotor);
+        let axes = map.try_principal_axes().unwrap();
+        assert_relative_eq!(axes.moments()[0], 1.0, epsilon = 1e-10);
+        assert_relative_eq!(axes.moments()[1], 2.0, epsilon = 1e-10);
+        assert_relative_eq!(axes.moments()[2], 3.0, epsilon = 1e-10);
+        let res = axes.rotor().closest(rotor);
+        assert_relative_eq!(res.scalar(), rotor.scalar(), epsilon = 1e-10);
+        assert_relative_eq!(res.e12(), rotor.e12(), epsilon = 1e-10);
+        assert_relative_eq!(res.e31(), rotor.e31(), epsilon = 1e-10);
+        assert_relative_eq!(res.e23(), rotor.e23(), epsilon = 1e-10);
+    }
+    #[test]
+    fn principal_axes_not_symmetric() {
+        let map = BivectorLinearMap::new([[1.0, 2.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+        assert_eq!(map.try_principal_axes(), None);
+    }
+}
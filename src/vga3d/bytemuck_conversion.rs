@@ -0,0 +1,158 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    bivector::Bivector, multivector::Multivector, rotor::Rotor, scalar::Scalar,
+    trivector::Trivector, vector::Vector,
+};
+
+use num_traits::Float;
+
+use bytemuck::{Pod, Zeroable};
+
+// Every grade type is #[repr(C)] and only contains fields of type F or other
+// grade types, so there is no padding and all bit patterns of F are valid.
+macro_rules! impl_bytemuck {
+    ($type:ident, $components:literal) => {
+        // SAFETY: #[repr(C)] with only F fields, all zero is a valid F.
+        unsafe impl<F: Float + Zeroable> Zeroable for $type<F> {}
+        // SAFETY: #[repr(C)] with only F fields and no padding.
+        unsafe impl<F: Float + Pod> Pod for $type<F> {}
+
+        impl<F: Float + Pod> $type<F> {
+            #[doc = concat!("View a slice of `", stringify!($type), "` as bytes")]
+            pub fn slice_as_bytes(slice: &[Self]) -> &[u8] {
+                bytemuck::cast_slice(slice)
+            }
+            #[doc = concat!("View bytes as a slice of `", stringify!($type), "`")]
+            ///
+            /// Returns `None` if the bytes are not aligned or the length is not a multiple of the size
+            pub fn try_slice_from_bytes(bytes: &[u8]) -> Option<&[Self]> {
+                bytemuck::try_cast_slice(bytes).ok()
+            }
+            #[doc = concat!("View a slice of `", stringify!($type), "` as its ", $components, " components each")]
+            pub fn slice_as_components(slice: &[Self]) -> &[F] {
+                bytemuck::cast_slice(slice)
+            }
+            #[doc = concat!("View components as a slice of `", stringify!($type), "` with ", $components, " components each")]
+            ///
+            /// Returns `None` if the length is not a multiple of the number of components
+            pub fn try_slice_from_components(components: &[F]) -> Option<&[Self]> {
+                bytemuck::try_cast_slice(components).ok()
+            }
+            #[doc = concat!("View a mutable slice of `", stringify!($type), "` as its components")]
+            pub fn slice_as_components_mut(slice: &mut [Self]) -> &mut [F] {
+                bytemuck::cast_slice_mut(slice)
+            }
+            #[doc = concat!("View mutable components as a slice of `", stringify!($type), "`")]
+            ///
+            /// Returns `None` if the length is not a multiple of the number of components
+            pub fn try_slice_from_components_mut(components: &mut [F]) -> Option<&mut [Self]> {
+                bytemuck::try_cast_slice_mut(components).ok()
+            }
+        }
+    };
+}
+
+impl_bytemuck!(Scalar, "1");
+impl_bytemuck!(Vector, "3");
+impl_bytemuck!(Bivector, "3");
+impl_bytemuck!(Trivector, "1");
+impl_bytemuck!(Multivector, "8");
+impl_bytemuck!(Rotor, "4");
+
+#[cfg(test)]
+mod bytemuck_cast {
+    use super::*;
+    use core::mem::{align_of, size_of};
+
+    #[test]
+    fn layout() {
+        assert_eq!(size_of::<Scalar<f32>>(), size_of::<[f32; 1]>());
+        assert_eq!(size_of::<Vector<f32>>(), size_of::<[f32; 3]>());
+        assert_eq!(size_of::<Bivector<f32>>(), size_of::<[f32; 3]>());
+        assert_eq!(size_of::<Trivector<f32>>(), size_of::<[f32; 1]>());
+        assert_eq!(size_of::<Multivector<f32>>(), size_of::<[f32; 8]>());
+        assert_eq!(size_of::<Rotor<f32>>(), size_of::<[f32; 4]>());
+        assert_eq!(size_of::<Multivector<f64>>(), size_of::<[f64; 8]>());
+        assert_eq!(align_of::<Multivector<f64>>(), align_of::<f64>());
+    }
+    #[test]
+    fn zeroed() {
+        assert_eq!(Vector::<f64>::zeroed(), Vector::zero());
+        assert_eq!(Rotor::<f32>::zeroed().scalar(), 0.0);
+    }
+    #[test]
+    fn vector() {
+        let vectors = [Vector::new(1.0, 2.0, 3.0), Vector::new(4.0, 5.0, 6.0)];
+        let components = Vector::slice_as_components(&vectors);
+        assert_eq!(components, [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(
+            Vector::try_slice_from_components(components),
+            Some(&vectors[..])
+        );
+        assert_eq!(Vector::try_slice_from_components(&components[..5]), None);
+    }
+    #[test]
+    fn bivector() {
+        let bivectors = [Bivector::new(1.0, 2.0, 3.0)];
+        let components = Bivector::slice_as_components(&bivectors);
+        assert_eq!(components, [1.0, 2.0, 3.0]);
+        assert_eq!(bivectors[0].e12(), components[0]);
+        assert_eq!(bivectors[0].e31(), components[1]);
+        assert_eq!(bivectors[0].e23(), components[2]);
+    }
+    #[test]
+    fn multivector() {
+        let multivector = Multivector::new_components(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let components = Multivector::slice_as_components(core::slice::from_ref(&multivector));
+        assert_eq!(components[0], multivector.scalar());
+        assert_eq!(components[1], multivector.e1());
+        assert_eq!(components[2], multivector.e2());
+        assert_eq!(components[3], multivector.e3());
+        assert_eq!(components[4], multivector.e12());
+        assert_eq!(components[5], multivector.e31());
+        assert_eq!(components[6], multivector.e23());
+        assert_eq!(components[7], multivector.e123());
+    }
+    #[test]
+    fn rotor() {
+        let rotor = Bivector::new(0.3, -0.2, 0.5).exp();
+        let components = Rotor::slice_as_components(core::slice::from_ref(&rotor));
+        assert_eq!(
+            components,
+            [rotor.scalar(), rotor.e12(), rotor.e31(), rotor.e23()]
+        );
+    }
+    #[test]
+    fn bytes() {
+        let vectors = [Vector::new(1.0f32, 2.0, 3.0), Vector::new(4.0, 5.0, 6.0)];
+        let bytes = Vector::slice_as_bytes(&vectors);
+        assert_eq!(bytes.len(), 2 * 3 * 4);
+        assert_eq!(bytes[..4], 1.0f32.to_ne_bytes());
+        assert_eq!(Vector::try_slice_from_bytes(bytes), Some(&vectors[..]));
+        assert_eq!(Vector::<f32>::try_slice_from_bytes(&bytes[..8]), None);
+    }
+    #[test]
+    fn components_mut() {
+        let mut components = [1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0];
+        let rotors = Rotor::try_slice_from_components_mut(&mut components).unwrap();
+        rotors[1] = Rotor::default();
+        assert_eq!(components, [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+    }
+}
@@ -0,0 +1,926 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    bivector::Bivector, multivector::Multivector, rotor::Rotor, scalar::Scalar,
+    trivector::Trivector, vector::Vector,
+};
+
+use num_traits::Float;
+
+/// # Commutator Product
+/// $$ A \times B = \frac{1}{2}(AB - BA) $$
+/// Scalars and the pseudoscalar commute with everything in 3D,
+/// so their commutator is always zero.
+pub trait Commutator<Rhs = Self> {
+    type Output;
+    fn commutator(self, b: Rhs) -> Self::Output;
+}
+
+/// # Anticommutator Product
+/// $$ A \,\bar{\times}\, B = \frac{1}{2}(AB + BA) $$
+pub trait Anticommutator<Rhs = Self> {
+    type Output;
+    fn anticommutator(self, b: Rhs) -> Self::Output;
+}
+
+// Commutator
+
+// Scalar-Scalar
+// scalars commute
+impl<F: Float> Commutator<Scalar<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn commutator(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+
+// Scalar-Vector
+// scalars and vectors commute
+impl<F: Float> Commutator<Vector<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn commutator(self, _b: Vector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+
+// Scalar-Bivector
+// scalars and bivectors commute
+impl<F: Float> Commutator<Bivector<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn commutator(self, _b: Bivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+
+// Scalar-Trivector
+// scalars and trivectors commute
+impl<F: Float> Commutator<Trivector<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn commutator(self, _b: Trivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+
+// Scalar-Multivector
+// scalars and multivectors commute
+impl<F: Float> Commutator<Multivector<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn commutator(self, _b: Multivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+
+// Scalar-Rotor
+// scalars and rotors commute
+impl<F: Float> Commutator<Rotor<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn commutator(self, _b: Rotor<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+
+// Vector-Scalar
+// vectors and scalars commute
+impl<F: Float> Commutator<Scalar<F>> for Vector<F> {
+    type Output = Scalar<F>;
+    fn commutator(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+
+// Vector-Vector
+// \[ \vec{a} \times \vec{b} \]
+impl<F: Float> Commutator<Vector<F>> for Vector<F> {
+    type Output = Bivector<F>;
+    fn commutator(self, b: Vector<F>) -> Bivector<F> {
+        self ^ b
+    }
+}
+
+// Vector-Bivector
+// \[ \vec{a} \times \overset\Rightarrow{b} \]
+impl<F: Float> Commutator<Bivector<F>> for Vector<F> {
+    type Output = Vector<F>;
+    fn commutator(self, b: Bivector<F>) -> Vector<F> {
+        self | b
+    }
+}
+
+// Vector-Trivector
+// vectors and trivectors commute
+impl<F: Float> Commutator<Trivector<F>> for Vector<F> {
+    type Output = Scalar<F>;
+    fn commutator(self, _b: Trivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+
+// Vector-Multivector
+// \[ \vec{a} \times B \]
+impl<F: Float> Commutator<Multivector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn commutator(self, b: Multivector<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = Multivector::from(self);
+        let rhs = b;
+        (lhs * rhs - rhs * lhs) * Scalar(half)
+    }
+}
+
+// Vector-Rotor
+// \[ \vec{a} \times R \]
+impl<F: Float> Commutator<Rotor<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn commutator(self, b: Rotor<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = Multivector::from(self);
+        let rhs = Multivector::from(b);
+        (lhs * rhs - rhs * lhs) * Scalar(half)
+    }
+}
+
+// Bivector-Scalar
+// bivectors and scalars commute
+impl<F: Float> Commutator<Scalar<F>> for Bivector<F> {
+    type Output = Scalar<F>;
+    fn commutator(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+
+// Bivector-Vector
+// \[ \overset\Rightarrow{a} \times \vec{b} \]
+impl<F: Float> Commutator<Vector<F>> for Bivector<F> {
+    type Output = Vector<F>;
+    fn commutator(self, b: Vector<F>) -> Vector<F> {
+        self | b
+    }
+}
+
+// Bivector-Bivector
+// \[ \overset\Rightarrow{a} \times \overset\Rightarrow{b} \]
+impl<F: Float> Commutator<Bivector<F>> for Bivector<F> {
+    type Output = Bivector<F>;
+    fn commutator(self, b: Bivector<F>) -> Bivector<F> {
+        self.cross(b)
+    }
+}
+
+// Bivector-Trivector
+// bivectors and trivectors commute
+impl<F: Float> Commutator<Trivector<F>> for Bivector<F> {
+    type Output = Scalar<F>;
+    fn commutator(self, _b: Trivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+
+// Bivector-Multivector
+// \[ \overset\Rightarrow{a} \times B \]
+impl<F: Float> Commutator<Multivector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn commutator(self, b: Multivector<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = Multivector::from(self);
+        let rhs = b;
+        (lhs * rhs - rhs * lhs) * Scalar(half)
+    }
+}
+
+// Bivector-Rotor
+// \[ \overset\Rightarrow{a} \times R \]
+impl<F: Float> Commutator<Rotor<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn commutator(self, b: Rotor<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = Multivector::from(self);
+        let rhs = Multivector::from(b);
+        (lhs * rhs - rhs * lhs) * Scalar(half)
+    }
+}
+
+// Trivector-Scalar
+// trivectors and scalars commute
+impl<F: Float> Commutator<Scalar<F>> for Trivector<F> {
+    type Output = Scalar<F>;
+    fn commutator(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+
+// Trivector-Vector
+// trivectors and vectors commute
+impl<F: Float> Commutator<Vector<F>> for Trivector<F> {
+    type Output = Scalar<F>;
+    fn commutator(self, _b: Vector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+
+// Trivector-Bivector
+// trivectors and bivectors commute
+impl<F: Float> Commutator<Bivector<F>> for Trivector<F> {
+    type Output = Scalar<F>;
+    fn commutator(self, _b: Bivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+
+// Trivector-Trivector
+// trivectors commute
+impl<F: Float> Commutator<Trivector<F>> for Trivector<F> {
+    type Output = Scalar<F>;
+    fn commutator(self, _b: Trivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+
+// Trivector-Multivector
+// trivectors and multivectors commute
+impl<F: Float> Commutator<Multivector<F>> for Trivector<F> {
+    type Output = Scalar<F>;
+    fn commutator(self, _b: Multivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+
+// Trivector-Rotor
+// trivectors and rotors commute
+impl<F: Float> Commutator<Rotor<F>> for Trivector<F> {
+    type Output = Scalar<F>;
+    fn commutator(self, _b: Rotor<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+
+// Multivector-Scalar
+// multivectors and scalars commute
+impl<F: Float> Commutator<Scalar<F>> for Multivector<F> {
+    type Output = Scalar<F>;
+    fn commutator(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+
+// Multivector-Vector
+// \[ A \times \vec{b} \]
+impl<F: Float> Commutator<Vector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn commutator(self, b: Vector<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = self;
+        let rhs = Multivector::from(b);
+        (lhs * rhs - rhs * lhs) * Scalar(half)
+    }
+}
+
+// Multivector-Bivector
+// \[ A \times \overset\Rightarrow{b} \]
+impl<F: Float> Commutator<Bivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn commutator(self, b: Bivector<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = self;
+        let rhs = Multivector::from(b);
+        (lhs * rhs - rhs * lhs) * Scalar(half)
+    }
+}
+
+// Multivector-Trivector
+// multivectors and trivectors commute
+impl<F: Float> Commutator<Trivector<F>> for Multivector<F> {
+    type Output = Scalar<F>;
+    fn commutator(self, _b: Trivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+
+// Multivector-Multivector
+// \[ A \times B \]
+impl<F: Float> Commutator<Multivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn commutator(self, b: Multivector<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = self;
+        let rhs = b;
+        (lhs * rhs - rhs * lhs) * Scalar(half)
+    }
+}
+
+// Multivector-Rotor
+// \[ A \times R \]
+impl<F: Float> Commutator<Rotor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn commutator(self, b: Rotor<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = self;
+        let rhs = Multivector::from(b);
+        (lhs * rhs - rhs * lhs) * Scalar(half)
+    }
+}
+
+// Rotor-Scalar
+// rotors and scalars commute
+impl<F: Float> Commutator<Scalar<F>> for Rotor<F> {
+    type Output = Scalar<F>;
+    fn commutator(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+
+// Rotor-Vector
+// \[ R \times \vec{b} \]
+impl<F: Float> Commutator<Vector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn commutator(self, b: Vector<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = Multivector::from(self);
+        let rhs = Multivector::from(b);
+        (lhs * rhs - rhs * lhs) * Scalar(half)
+    }
+}
+
+// Rotor-Bivector
+// \[ R \times \overset\Rightarrow{b} \]
+impl<F: Float> Commutator<Bivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn commutator(self, b: Bivector<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = Multivector::from(self);
+        let rhs = Multivector::from(b);
+        (lhs * rhs - rhs * lhs) * Scalar(half)
+    }
+}
+
+// Rotor-Trivector
+// rotors and trivectors commute
+impl<F: Float> Commutator<Trivector<F>> for Rotor<F> {
+    type Output = Scalar<F>;
+    fn commutator(self, _b: Trivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+}
+
+// Rotor-Multivector
+// \[ R \times B \]
+impl<F: Float> Commutator<Multivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn commutator(self, b: Multivector<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = Multivector::from(self);
+        let rhs = b;
+        (lhs * rhs - rhs * lhs) * Scalar(half)
+    }
+}
+
+// Rotor-Rotor
+// \[ R \times R \]
+impl<F: Float> Commutator<Rotor<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn commutator(self, b: Rotor<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = Multivector::from(self);
+        let rhs = Multivector::from(b);
+        (lhs * rhs - rhs * lhs) * Scalar(half)
+    }
+}
+
+// Anticommutator
+
+// Scalar-Scalar
+// \[ s \,\bar{\times}\, s \]
+impl<F: Float> Anticommutator<Scalar<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn anticommutator(self, b: Scalar<F>) -> Scalar<F> {
+        Scalar(self.0 * b.0)
+    }
+}
+
+// Scalar-Vector
+// \[ s \,\bar{\times}\, \vec{b} \]
+impl<F: Float> Anticommutator<Vector<F>> for Scalar<F> {
+    type Output = Vector<F>;
+    fn anticommutator(self, b: Vector<F>) -> Vector<F> {
+        self * b
+    }
+}
+
+// Scalar-Bivector
+// \[ s \,\bar{\times}\, \overset\Rightarrow{b} \]
+impl<F: Float> Anticommutator<Bivector<F>> for Scalar<F> {
+    type Output = Bivector<F>;
+    fn anticommutator(self, b: Bivector<F>) -> Bivector<F> {
+        self * b
+    }
+}
+
+// Scalar-Trivector
+// \[ s \,\bar{\times}\, \overset\Rrightarrow{b} \]
+impl<F: Float> Anticommutator<Trivector<F>> for Scalar<F> {
+    type Output = Trivector<F>;
+    fn anticommutator(self, b: Trivector<F>) -> Trivector<F> {
+        self * b
+    }
+}
+
+// Scalar-Multivector
+// \[ s \,\bar{\times}\, B \]
+impl<F: Float> Anticommutator<Multivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn anticommutator(self, b: Multivector<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = Multivector::from(self);
+        let rhs = b;
+        (lhs * rhs + rhs * lhs) * Scalar(half)
+    }
+}
+
+// Scalar-Rotor
+// \[ s \,\bar{\times}\, R \]
+impl<F: Float> Anticommutator<Rotor<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn anticommutator(self, b: Rotor<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = Multivector::from(self);
+        let rhs = Multivector::from(b);
+        (lhs * rhs + rhs * lhs) * Scalar(half)
+    }
+}
+
+// Vector-Scalar
+// \[ \vec{a} \,\bar{\times}\, s \]
+impl<F: Float> Anticommutator<Scalar<F>> for Vector<F> {
+    type Output = Vector<F>;
+    fn anticommutator(self, b: Scalar<F>) -> Vector<F> {
+        self * b
+    }
+}
+
+// Vector-Vector
+// \[ \vec{a} \,\bar{\times}\, \vec{b} \]
+impl<F: Float> Anticommutator<Vector<F>> for Vector<F> {
+    type Output = Scalar<F>;
+    fn anticommutator(self, b: Vector<F>) -> Scalar<F> {
+        self | b
+    }
+}
+
+// Vector-Bivector
+// \[ \vec{a} \,\bar{\times}\, \overset\Rightarrow{b} \]
+impl<F: Float> Anticommutator<Bivector<F>> for Vector<F> {
+    type Output = Trivector<F>;
+    fn anticommutator(self, b: Bivector<F>) -> Trivector<F> {
+        self ^ b
+    }
+}
+
+// Vector-Trivector
+// \[ \vec{a} \,\bar{\times}\, \overset\Rrightarrow{b} \]
+impl<F: Float> Anticommutator<Trivector<F>> for Vector<F> {
+    type Output = Bivector<F>;
+    fn anticommutator(self, b: Trivector<F>) -> Bivector<F> {
+        self | b
+    }
+}
+
+// Vector-Multivector
+// \[ \vec{a} \,\bar{\times}\, B \]
+impl<F: Float> Anticommutator<Multivector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn anticommutator(self, b: Multivector<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = Multivector::from(self);
+        let rhs = b;
+        (lhs * rhs + rhs * lhs) * Scalar(half)
+    }
+}
+
+// Vector-Rotor
+// \[ \vec{a} \,\bar{\times}\, R \]
+impl<F: Float> Anticommutator<Rotor<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn anticommutator(self, b: Rotor<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = Multivector::from(self);
+        let rhs = Multivector::from(b);
+        (lhs * rhs + rhs * lhs) * Scalar(half)
+    }
+}
+
+// Bivector-Scalar
+// \[ \overset\Rightarrow{a} \,\bar{\times}\, s \]
+impl<F: Float> Anticommutator<Scalar<F>> for Bivector<F> {
+    type Output = Bivector<F>;
+    fn anticommutator(self, b: Scalar<F>) -> Bivector<F> {
+        self * b
+    }
+}
+
+// Bivector-Vector
+// \[ \overset\Rightarrow{a} \,\bar{\times}\, \vec{b} \]
+impl<F: Float> Anticommutator<Vector<F>> for Bivector<F> {
+    type Output = Trivector<F>;
+    fn anticommutator(self, b: Vector<F>) -> Trivector<F> {
+        self ^ b
+    }
+}
+
+// Bivector-Bivector
+// \[ \overset\Rightarrow{a} \,\bar{\times}\, \overset\Rightarrow{b} \]
+impl<F: Float> Anticommutator<Bivector<F>> for Bivector<F> {
+    type Output = Scalar<F>;
+    fn anticommutator(self, b: Bivector<F>) -> Scalar<F> {
+        self | b
+    }
+}
+
+// Bivector-Trivector
+// \[ \overset\Rightarrow{a} \,\bar{\times}\, \overset\Rrightarrow{b} \]
+impl<F: Float> Anticommutator<Trivector<F>> for Bivector<F> {
+    type Output = Vector<F>;
+    fn anticommutator(self, b: Trivector<F>) -> Vector<F> {
+        self | b
+    }
+}
+
+// Bivector-Multivector
+// \[ \overset\Rightarrow{a} \,\bar{\times}\, B \]
+impl<F: Float> Anticommutator<Multivector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn anticommutator(self, b: Multivector<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = Multivector::from(self);
+        let rhs = b;
+        (lhs * rhs + rhs * lhs) * Scalar(half)
+    }
+}
+
+// Bivector-Rotor
+// \[ \overset\Rightarrow{a} \,\bar{\times}\, R \]
+impl<F: Float> Anticommutator<Rotor<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn anticommutator(self, b: Rotor<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = Multivector::from(self);
+        let rhs = Multivector::from(b);
+        (lhs * rhs + rhs * lhs) * Scalar(half)
+    }
+}
+
+// Trivector-Scalar
+// \[ \overset\Rrightarrow{a} \,\bar{\times}\, s \]
+impl<F: Float> Anticommutator<Scalar<F>> for Trivector<F> {
+    type Output = Trivector<F>;
+    fn anticommutator(self, b: Scalar<F>) -> Trivector<F> {
+        self * b
+    }
+}
+
+// Trivector-Vector
+// \[ \overset\Rrightarrow{a} \,\bar{\times}\, \vec{b} \]
+impl<F: Float> Anticommutator<Vector<F>> for Trivector<F> {
+    type Output = Bivector<F>;
+    fn anticommutator(self, b: Vector<F>) -> Bivector<F> {
+        self | b
+    }
+}
+
+// Trivector-Bivector
+// \[ \overset\Rrightarrow{a} \,\bar{\times}\, \overset\Rightarrow{b} \]
+impl<F: Float> Anticommutator<Bivector<F>> for Trivector<F> {
+    type Output = Vector<F>;
+    fn anticommutator(self, b: Bivector<F>) -> Vector<F> {
+        self | b
+    }
+}
+
+// Trivector-Trivector
+// \[ \overset\Rrightarrow{a} \,\bar{\times}\, \overset\Rrightarrow{b} \]
+impl<F: Float> Anticommutator<Trivector<F>> for Trivector<F> {
+    type Output = Scalar<F>;
+    fn anticommutator(self, b: Trivector<F>) -> Scalar<F> {
+        self | b
+    }
+}
+
+// Trivector-Multivector
+// \[ \overset\Rrightarrow{a} \,\bar{\times}\, B \]
+impl<F: Float> Anticommutator<Multivector<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn anticommutator(self, b: Multivector<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = Multivector::from(self);
+        let rhs = b;
+        (lhs * rhs + rhs * lhs) * Scalar(half)
+    }
+}
+
+// Trivector-Rotor
+// \[ \overset\Rrightarrow{a} \,\bar{\times}\, R \]
+impl<F: Float> Anticommutator<Rotor<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn anticommutator(self, b: Rotor<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = Multivector::from(self);
+        let rhs = Multivector::from(b);
+        (lhs * rhs + rhs * lhs) * Scalar(half)
+    }
+}
+
+// Multivector-Scalar
+// \[ A \,\bar{\times}\, s \]
+impl<F: Float> Anticommutator<Scalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn anticommutator(self, b: Scalar<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = self;
+        let rhs = Multivector::from(b);
+        (lhs * rhs + rhs * lhs) * Scalar(half)
+    }
+}
+
+// Multivector-Vector
+// \[ A \,\bar{\times}\, \vec{b} \]
+impl<F: Float> Anticommutator<Vector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn anticommutator(self, b: Vector<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = self;
+        let rhs = Multivector::from(b);
+        (lhs * rhs + rhs * lhs) * Scalar(half)
+    }
+}
+
+// Multivector-Bivector
+// \[ A \,\bar{\times}\, \overset\Rightarrow{b} \]
+impl<F: Float> Anticommutator<Bivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn anticommutator(self, b: Bivector<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = self;
+        let rhs = Multivector::from(b);
+        (lhs * rhs + rhs * lhs) * Scalar(half)
+    }
+}
+
+// Multivector-Trivector
+// \[ A \,\bar{\times}\, \overset\Rrightarrow{b} \]
+impl<F: Float> Anticommutator<Trivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn anticommutator(self, b: Trivector<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = self;
+        let rhs = Multivector::from(b);
+        (lhs * rhs + rhs * lhs) * Scalar(half)
+    }
+}
+
+// Multivector-Multivector
+// \[ A \,\bar{\times}\, B \]
+impl<F: Float> Anticommutator<Multivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn anticommutator(self, b: Multivector<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = self;
+        let rhs = b;
+        (lhs * rhs + rhs * lhs) * Scalar(half)
+    }
+}
+
+// Multivector-Rotor
+// \[ A \,\bar{\times}\, R \]
+impl<F: Float> Anticommutator<Rotor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn anticommutator(self, b: Rotor<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = self;
+        let rhs = Multivector::from(b);
+        (lhs * rhs + rhs * lhs) * Scalar(half)
+    }
+}
+
+// Rotor-Scalar
+// \[ R \,\bar{\times}\, s \]
+impl<F: Float> Anticommutator<Scalar<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn anticommutator(self, b: Scalar<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = Multivector::from(self);
+        let rhs = Multivector::from(b);
+        (lhs * rhs + rhs * lhs) * Scalar(half)
+    }
+}
+
+// Rotor-Vector
+// \[ R \,\bar{\times}\, \vec{b} \]
+impl<F: Float> Anticommutator<Vector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn anticommutator(self, b: Vector<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = Multivector::from(self);
+        let rhs = Multivector::from(b);
+        (lhs * rhs + rhs * lhs) * Scalar(half)
+    }
+}
+
+// Rotor-Bivector
+// \[ R \,\bar{\times}\, \overset\Rightarrow{b} \]
+impl<F: Float> Anticommutator<Bivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn anticommutator(self, b: Bivector<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = Multivector::from(self);
+        let rhs = Multivector::from(b);
+        (lhs * rhs + rhs * lhs) * Scalar(half)
+    }
+}
+
+// Rotor-Trivector
+// \[ R \,\bar{\times}\, \overset\Rrightarrow{b} \]
+impl<F: Float> Anticommutator<Trivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn anticommutator(self, b: Trivector<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = Multivector::from(self);
+        let rhs = Multivector::from(b);
+        (lhs * rhs + rhs * lhs) * Scalar(half)
+    }
+}
+
+// Rotor-Multivector
+// \[ R \,\bar{\times}\, B \]
+impl<F: Float> Anticommutator<Multivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn anticommutator(self, b: Multivector<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = Multivector::from(self);
+        let rhs = b;
+        (lhs * rhs + rhs * lhs) * Scalar(half)
+    }
+}
+
+// Rotor-Rotor
+// \[ R \,\bar{\times}\, R \]
+impl<F: Float> Anticommutator<Rotor<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn anticommutator(self, b: Rotor<F>) -> Multivector<F> {
+        let half = F::one() / (F::one() + F::one());
+        let lhs = Multivector::from(self);
+        let rhs = Multivector::from(b);
+        (lhs * rhs + rhs * lhs) * Scalar(half)
+    }
+}
+
+#[cfg(test)]
+mod commutator_product {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn vector_vector() {
+        // 3e1+5e2+4e3
+        let vector1 = Vector::new(3.0, 5.0, 4.0);
+        // 2e1+e2+6e3
+        let vector2 = Vector::new(2.0, 1.0, 6.0);
+        let commutator = vector1.commutator(vector2);
+        let anticommutator = vector1.anticommutator(vector2);
+        // -7e12-10e31+26e23
+        assert_relative_eq!(commutator.e12(), -7.0, max_relative = 0.000001);
+        assert_relative_eq!(commutator.e31(), -10.0, max_relative = 0.000001);
+        assert_relative_eq!(commutator.e23(), 26.0, max_relative = 0.000001);
+        // 35
+        assert_relative_eq!(anticommutator.0, 35.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_bivector() {
+        // 3e1+5e2+4e3
+        let vector = Vector::new(3.0, 5.0, 4.0);
+        // 2e12+e31+6e23
+        let bivector = Bivector::new(2.0, 1.0, 6.0);
+        let commutator = vector.commutator(bivector);
+        let anticommutator = vector.anticommutator(bivector);
+        // -6e1-18e2+27e3
+        assert_relative_eq!(commutator.e1(), -6.0, max_relative = 0.000001);
+        assert_relative_eq!(commutator.e2(), -18.0, max_relative = 0.000001);
+        assert_relative_eq!(commutator.e3(), 27.0, max_relative = 0.000001);
+        // 31e123
+        assert_relative_eq!(anticommutator.e123(), 31.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_bivector() {
+        // 3e12+5e31+4e23
+        let bivector1 = Bivector::new(3.0, 5.0, 4.0);
+        // 2e12+e31+6e23
+        let bivector2 = Bivector::new(2.0, 1.0, 6.0);
+        let commutator = bivector1.commutator(bivector2);
+        // 26e12-10e31-7e23
+        assert_relative_eq!(commutator.e12(), 26.0, max_relative = 0.000001);
+        assert_relative_eq!(commutator.e31(), -10.0, max_relative = 0.000001);
+        assert_relative_eq!(commutator.e23(), -7.0, max_relative = 0.000001);
+        // -(6+5+24)
+        assert_relative_eq!(
+            bivector1.anticommutator(bivector2).0,
+            -35.0,
+            max_relative = 0.000001
+        );
+    }
+
+    #[test]
+    fn vector_trivector() {
+        // 3e1+5e2+4e3
+        let vector = Vector::new(3.0, 5.0, 4.0);
+        // 2e123
+        let trivector = Trivector::new(2.0);
+        let anticommutator = vector.anticommutator(trivector);
+        // 0
+        assert_relative_eq!(vector.commutator(trivector).0, 0.0, max_relative = 0.000001);
+        // 8e12+10e31+6e23
+        assert_relative_eq!(anticommutator.e12(), 8.0, max_relative = 0.000001);
+        assert_relative_eq!(anticommutator.e31(), 10.0, max_relative = 0.000001);
+        assert_relative_eq!(anticommutator.e23(), 6.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn scalar_bivector() {
+        let scalar = Scalar(3.0);
+        // 2e12+e31+6e23
+        let bivector = Bivector::new(2.0, 1.0, 6.0);
+        let anticommutator = bivector.anticommutator(scalar);
+        // 0
+        assert_relative_eq!(scalar.commutator(bivector).0, 0.0, max_relative = 0.000001);
+        // 6e12+3e31+18e23
+        assert_relative_eq!(anticommutator.e12(), 6.0, max_relative = 0.000001);
+        assert_relative_eq!(anticommutator.e31(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(anticommutator.e23(), 18.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_vector() {
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        // 3e1+5e2+4e3
+        let vector = Vector::new(3.0, 5.0, 4.0);
+        let commutator = rotor.commutator(vector);
+        let anticommutator = rotor.anticommutator(vector);
+        // 0.5e1+0.5e2-e3
+        assert_relative_eq!(commutator.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(commutator.e1(), 0.5, max_relative = 0.000001);
+        assert_relative_eq!(commutator.e2(), 0.5, max_relative = 0.000001);
+        assert_relative_eq!(commutator.e3(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(commutator.e123(), 0.0, max_relative = 0.000001);
+        // 1.5e1+2.5e2+2e3+6e123
+        assert_relative_eq!(anticommutator.e1(), 1.5, max_relative = 0.000001);
+        assert_relative_eq!(anticommutator.e2(), 2.5, max_relative = 0.000001);
+        assert_relative_eq!(anticommutator.e3(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(anticommutator.e123(), 6.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_multivector() {
+        // ( 5.0 + 8.0e1 + 7.0e2 + 3.0e3 + 2.0e12 + 8.0e31 + 2.0e23 + 1.0e123 )
+        let mvec1 = Multivector::new_components(5.0, 8.0, 7.0, 3.0, 2.0, 8.0, 2.0, 1.0);
+        // -4-2e1-4e2-9e3-2e12-1e31-7e23-1e123
+        let mvec2 = Multivector::new_components(-4.0, -2.0, -4.0, -9.0, -2.0, -1.0, -7.0, -1.0);
+        let commutator = mvec1.commutator(mvec2);
+        let anticommutator = mvec1.anticommutator(mvec2);
+        // 75e1-9e2-49e3-72e12+76e31-37e23
+        assert_relative_eq!(commutator.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(commutator.e1(), 75.0, max_relative = 0.000001);
+        assert_relative_eq!(commutator.e2(), -9.0, max_relative = 0.000001);
+        assert_relative_eq!(commutator.e3(), -49.0, max_relative = 0.000001);
+        assert_relative_eq!(commutator.e12(), -72.0, max_relative = 0.000001);
+        assert_relative_eq!(commutator.e31(), 76.0, max_relative = 0.000001);
+        assert_relative_eq!(commutator.e23(), -37.0, max_relative = 0.000001);
+        assert_relative_eq!(commutator.e123(), 0.0, max_relative = 0.000001);
+        // -64-33e1-39e2-53e3-30e12-48e31-53e23-132e123
+        assert_relative_eq!(anticommutator.scalar(), -64.0, max_relative = 0.000001);
+        assert_relative_eq!(anticommutator.e1(), -33.0, max_relative = 0.000001);
+        assert_relative_eq!(anticommutator.e2(), -39.0, max_relative = 0.000001);
+        assert_relative_eq!(anticommutator.e3(), -53.0, max_relative = 0.000001);
+        assert_relative_eq!(anticommutator.e12(), -30.0, max_relative = 0.000001);
+        assert_relative_eq!(anticommutator.e31(), -48.0, max_relative = 0.000001);
+        assert_relative_eq!(anticommutator.e23(), -53.0, max_relative = 0.000001);
+        assert_relative_eq!(anticommutator.e123(), -132.0, max_relative = 0.000001);
+    }
+}
@@ -0,0 +1,1065 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    bivector::Bivector, multivector::Multivector, rotor::Rotor, scalar::Scalar,
+    trivector::Trivector, vector::Vector,
+};
+
+use num_traits::Float;
+
+/// # Contraction Products
+/// The inner products found in the literature (Dorst, Fontijne and Mann).
+/// For two blades $A$ of grade $a$ and $B$ of grade $b$
+/// $$ A \rfloor B = \left< A B \right>_{b-a} \quad A \lfloor B = \left< A B \right>_{a-b} $$
+/// $$ A * B = \left< A B \right>_{0} \quad A \bullet B = \left< A B \right>_{|a-b|} $$
+/// The contractions are zero when the grade would be negative.
+/// The fat dot is the inner product implemented by the `|` operator.
+/// The results are extended linearly to multivectors and rotors.
+pub trait Contraction<F: Float, Rhs = Self> {
+    type Output;
+
+    /// Left contraction
+    /// $$ A \rfloor B $$
+    fn left_contraction(self, b: Rhs) -> Self::Output;
+
+    /// Right contraction
+    /// $$ A \lfloor B $$
+    fn right_contraction(self, b: Rhs) -> Self::Output;
+
+    /// Scalar product
+    /// $$ A * B = \left< A B \right>_0 $$
+    fn scalar_product(self, b: Rhs) -> Scalar<F>;
+
+    /// Fat dot
+    /// $$ A \bullet B $$
+    fn fat_dot(self, b: Rhs) -> Self::Output;
+}
+
+/// # Inner Product Selection
+/// Selects which inner product [`Projectable`](super::Projectable) and
+/// [`Rejectable`](super::Rejectable) use.
+pub trait InnerProduct<F: Float, A, B> {
+    /// The selected inner product of `a` and `b` as a multivector
+    fn product(a: A, b: B) -> Multivector<F>;
+}
+
+/// The fat dot $A \bullet B$, the inner product of the `|` operator
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct FatDot;
+
+/// The left contraction $A \rfloor B$
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct LeftContraction;
+
+/// The right contraction $A \lfloor B$
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct RightContraction;
+
+impl<F: Float, A, B> InnerProduct<F, A, B> for FatDot
+where
+    A: Contraction<F, B>,
+    A::Output: Into<Multivector<F>>,
+{
+    fn product(a: A, b: B) -> Multivector<F> {
+        a.fat_dot(b).into()
+    }
+}
+
+impl<F: Float, A, B> InnerProduct<F, A, B> for LeftContraction
+where
+    A: Contraction<F, B>,
+    A::Output: Into<Multivector<F>>,
+{
+    fn product(a: A, b: B) -> Multivector<F> {
+        a.left_contraction(b).into()
+    }
+}
+
+impl<F: Float, A, B> InnerProduct<F, A, B> for RightContraction
+where
+    A: Contraction<F, B>,
+    A::Output: Into<Multivector<F>>,
+{
+    fn product(a: A, b: B) -> Multivector<F> {
+        a.right_contraction(b).into()
+    }
+}
+
+// Scalar-Scalar
+// \[ s \rfloor s \]
+impl<F: Float> Contraction<F, Scalar<F>> for Scalar<F> {
+    type Output = Scalar<F>;
+    fn left_contraction(self, b: Scalar<F>) -> Self::Output {
+        Scalar(self.0 * b.0)
+    }
+
+    fn right_contraction(self, b: Scalar<F>) -> Self::Output {
+        Scalar(self.0 * b.0)
+    }
+
+    fn scalar_product(self, b: Scalar<F>) -> Scalar<F> {
+        Scalar(self.0 * b.0)
+    }
+
+    fn fat_dot(self, b: Scalar<F>) -> Self::Output {
+        Scalar(self.0 * b.0)
+    }
+}
+
+// Scalar-Vector
+// \[ s \rfloor \vec{b} \]
+impl<F: Float> Contraction<F, Vector<F>> for Scalar<F> {
+    type Output = Vector<F>;
+    fn left_contraction(self, b: Vector<F>) -> Self::Output {
+        self * b
+    }
+
+    fn right_contraction(self, _b: Vector<F>) -> Self::Output {
+        Vector::zero()
+    }
+
+    fn scalar_product(self, _b: Vector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+
+    fn fat_dot(self, b: Vector<F>) -> Self::Output {
+        self * b
+    }
+}
+
+// Scalar-Bivector
+// \[ s \rfloor \overset\Rightarrow{b} \]
+impl<F: Float> Contraction<F, Bivector<F>> for Scalar<F> {
+    type Output = Bivector<F>;
+    fn left_contraction(self, b: Bivector<F>) -> Self::Output {
+        self * b
+    }
+
+    fn right_contraction(self, _b: Bivector<F>) -> Self::Output {
+        Bivector::zero()
+    }
+
+    fn scalar_product(self, _b: Bivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+
+    fn fat_dot(self, b: Bivector<F>) -> Self::Output {
+        self * b
+    }
+}
+
+// Scalar-Trivector
+// \[ s \rfloor \overset\Rrightarrow{b} \]
+impl<F: Float> Contraction<F, Trivector<F>> for Scalar<F> {
+    type Output = Trivector<F>;
+    fn left_contraction(self, b: Trivector<F>) -> Self::Output {
+        self * b
+    }
+
+    fn right_contraction(self, _b: Trivector<F>) -> Self::Output {
+        Trivector::zero()
+    }
+
+    fn scalar_product(self, _b: Trivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+
+    fn fat_dot(self, b: Trivector<F>) -> Self::Output {
+        self * b
+    }
+}
+
+// Scalar-Multivector
+// \[ s \rfloor B \]
+impl<F: Float> Contraction<F, Multivector<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn left_contraction(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::from(self).left_contraction(b)
+    }
+
+    fn right_contraction(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::from(self).right_contraction(b)
+    }
+
+    fn scalar_product(self, b: Multivector<F>) -> Scalar<F> {
+        Multivector::from(self).scalar_product(b)
+    }
+
+    fn fat_dot(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::from(self).fat_dot(b)
+    }
+}
+
+// Scalar-Rotor
+// \[ s \rfloor R \]
+impl<F: Float> Contraction<F, Rotor<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn left_contraction(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::from(self).left_contraction(Multivector::from(b))
+    }
+
+    fn right_contraction(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::from(self).right_contraction(Multivector::from(b))
+    }
+
+    fn scalar_product(self, b: Rotor<F>) -> Scalar<F> {
+        Multivector::from(self).scalar_product(Multivector::from(b))
+    }
+
+    fn fat_dot(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::from(self).fat_dot(Multivector::from(b))
+    }
+}
+
+// Vector-Scalar
+// \[ \vec{a} \rfloor s \]
+impl<F: Float> Contraction<F, Scalar<F>> for Vector<F> {
+    type Output = Vector<F>;
+    fn left_contraction(self, _b: Scalar<F>) -> Self::Output {
+        Vector::zero()
+    }
+
+    fn right_contraction(self, b: Scalar<F>) -> Self::Output {
+        self * b
+    }
+
+    fn scalar_product(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+
+    fn fat_dot(self, b: Scalar<F>) -> Self::Output {
+        self * b
+    }
+}
+
+// Vector-Vector
+// \[ \vec{a} \rfloor \vec{b} \]
+impl<F: Float> Contraction<F, Vector<F>> for Vector<F> {
+    type Output = Scalar<F>;
+    fn left_contraction(self, b: Vector<F>) -> Self::Output {
+        self | b
+    }
+
+    fn right_contraction(self, b: Vector<F>) -> Self::Output {
+        self | b
+    }
+
+    fn scalar_product(self, b: Vector<F>) -> Scalar<F> {
+        self | b
+    }
+
+    fn fat_dot(self, b: Vector<F>) -> Self::Output {
+        self | b
+    }
+}
+
+// Vector-Bivector
+// \[ \vec{a} \rfloor \overset\Rightarrow{b} \]
+impl<F: Float> Contraction<F, Bivector<F>> for Vector<F> {
+    type Output = Vector<F>;
+    fn left_contraction(self, b: Bivector<F>) -> Self::Output {
+        self | b
+    }
+
+    fn right_contraction(self, _b: Bivector<F>) -> Self::Output {
+        Vector::zero()
+    }
+
+    fn scalar_product(self, _b: Bivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+
+    fn fat_dot(self, b: Bivector<F>) -> Self::Output {
+        self | b
+    }
+}
+
+// Vector-Trivector
+// \[ \vec{a} \rfloor \overset\Rrightarrow{b} \]
+impl<F: Float> Contraction<F, Trivector<F>> for Vector<F> {
+    type Output = Bivector<F>;
+    fn left_contraction(self, b: Trivector<F>) -> Self::Output {
+        self | b
+    }
+
+    fn right_contraction(self, _b: Trivector<F>) -> Self::Output {
+        Bivector::zero()
+    }
+
+    fn scalar_product(self, _b: Trivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+
+    fn fat_dot(self, b: Trivector<F>) -> Self::Output {
+        self | b
+    }
+}
+
+// Vector-Multivector
+// \[ \vec{a} \rfloor B \]
+impl<F: Float> Contraction<F, Multivector<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn left_contraction(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::from(self).left_contraction(b)
+    }
+
+    fn right_contraction(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::from(self).right_contraction(b)
+    }
+
+    fn scalar_product(self, b: Multivector<F>) -> Scalar<F> {
+        Multivector::from(self).scalar_product(b)
+    }
+
+    fn fat_dot(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::from(self).fat_dot(b)
+    }
+}
+
+// Vector-Rotor
+// \[ \vec{a} \rfloor R \]
+impl<F: Float> Contraction<F, Rotor<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn left_contraction(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::from(self).left_contraction(Multivector::from(b))
+    }
+
+    fn right_contraction(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::from(self).right_contraction(Multivector::from(b))
+    }
+
+    fn scalar_product(self, b: Rotor<F>) -> Scalar<F> {
+        Multivector::from(self).scalar_product(Multivector::from(b))
+    }
+
+    fn fat_dot(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::from(self).fat_dot(Multivector::from(b))
+    }
+}
+
+// Bivector-Scalar
+// \[ \overset\Rightarrow{a} \rfloor s \]
+impl<F: Float> Contraction<F, Scalar<F>> for Bivector<F> {
+    type Output = Bivector<F>;
+    fn left_contraction(self, _b: Scalar<F>) -> Self::Output {
+        Bivector::zero()
+    }
+
+    fn right_contraction(self, b: Scalar<F>) -> Self::Output {
+        self * b
+    }
+
+    fn scalar_product(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+
+    fn fat_dot(self, b: Scalar<F>) -> Self::Output {
+        self * b
+    }
+}
+
+// Bivector-Vector
+// \[ \overset\Rightarrow{a} \rfloor \vec{b} \]
+impl<F: Float> Contraction<F, Vector<F>> for Bivector<F> {
+    type Output = Vector<F>;
+    fn left_contraction(self, _b: Vector<F>) -> Self::Output {
+        Vector::zero()
+    }
+
+    fn right_contraction(self, b: Vector<F>) -> Self::Output {
+        self | b
+    }
+
+    fn scalar_product(self, _b: Vector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+
+    fn fat_dot(self, b: Vector<F>) -> Self::Output {
+        self | b
+    }
+}
+
+// Bivector-Bivector
+// \[ \overset\Rightarrow{a} \rfloor \overset\Rightarrow{b} \]
+impl<F: Float> Contraction<F, Bivector<F>> for Bivector<F> {
+    type Output = Scalar<F>;
+    fn left_contraction(self, b: Bivector<F>) -> Self::Output {
+        self | b
+    }
+
+    fn right_contraction(self, b: Bivector<F>) -> Self::Output {
+        self | b
+    }
+
+    fn scalar_product(self, b: Bivector<F>) -> Scalar<F> {
+        self | b
+    }
+
+    fn fat_dot(self, b: Bivector<F>) -> Self::Output {
+        self | b
+    }
+}
+
+// Bivector-Trivector
+// \[ \overset\Rightarrow{a} \rfloor \overset\Rrightarrow{b} \]
+impl<F: Float> Contraction<F, Trivector<F>> for Bivector<F> {
+    type Output = Vector<F>;
+    fn left_contraction(self, b: Trivector<F>) -> Self::Output {
+        self | b
+    }
+
+    fn right_contraction(self, _b: Trivector<F>) -> Self::Output {
+        Vector::zero()
+    }
+
+    fn scalar_product(self, _b: Trivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+
+    fn fat_dot(self, b: Trivector<F>) -> Self::Output {
+        self | b
+    }
+}
+
+// Bivector-Multivector
+// \[ \overset\Rightarrow{a} \rfloor B \]
+impl<F: Float> Contraction<F, Multivector<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn left_contraction(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::from(self).left_contraction(b)
+    }
+
+    fn right_contraction(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::from(self).right_contraction(b)
+    }
+
+    fn scalar_product(self, b: Multivector<F>) -> Scalar<F> {
+        Multivector::from(self).scalar_product(b)
+    }
+
+    fn fat_dot(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::from(self).fat_dot(b)
+    }
+}
+
+// Bivector-Rotor
+// \[ \overset\Rightarrow{a} \rfloor R \]
+impl<F: Float> Contraction<F, Rotor<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn left_contraction(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::from(self).left_contraction(Multivector::from(b))
+    }
+
+    fn right_contraction(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::from(self).right_contraction(Multivector::from(b))
+    }
+
+    fn scalar_product(self, b: Rotor<F>) -> Scalar<F> {
+        Multivector::from(self).scalar_product(Multivector::from(b))
+    }
+
+    fn fat_dot(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::from(self).fat_dot(Multivector::from(b))
+    }
+}
+
+// Trivector-Scalar
+// \[ \overset\Rrightarrow{a} \rfloor s \]
+impl<F: Float> Contraction<F, Scalar<F>> for Trivector<F> {
+    type Output = Trivector<F>;
+    fn left_contraction(self, _b: Scalar<F>) -> Self::Output {
+        Trivector::zero()
+    }
+
+    fn right_contraction(self, b: Scalar<F>) -> Self::Output {
+        self * b
+    }
+
+    fn scalar_product(self, _b: Scalar<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+
+    fn fat_dot(self, b: Scalar<F>) -> Self::Output {
+        self * b
+    }
+}
+
+// Trivector-Vector
+// \[ \overset\Rrightarrow{a} \rfloor \vec{b} \]
+impl<F: Float> Contraction<F, Vector<F>> for Trivector<F> {
+    type Output = Bivector<F>;
+    fn left_contraction(self, _b: Vector<F>) -> Self::Output {
+        Bivector::zero()
+    }
+
+    fn right_contraction(self, b: Vector<F>) -> Self::Output {
+        self | b
+    }
+
+    fn scalar_product(self, _b: Vector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+
+    fn fat_dot(self, b: Vector<F>) -> Self::Output {
+        self | b
+    }
+}
+
+// Trivector-Bivector
+// \[ \overset\Rrightarrow{a} \rfloor \overset\Rightarrow{b} \]
+impl<F: Float> Contraction<F, Bivector<F>> for Trivector<F> {
+    type Output = Vector<F>;
+    fn left_contraction(self, _b: Bivector<F>) -> Self::Output {
+        Vector::zero()
+    }
+
+    fn right_contraction(self, b: Bivector<F>) -> Self::Output {
+        self | b
+    }
+
+    fn scalar_product(self, _b: Bivector<F>) -> Scalar<F> {
+        Scalar::zero()
+    }
+
+    fn fat_dot(self, b: Bivector<F>) -> Self::Output {
+        self | b
+    }
+}
+
+// Trivector-Trivector
+// \[ \overset\Rrightarrow{a} \rfloor \overset\Rrightarrow{b} \]
+impl<F: Float> Contraction<F, Trivector<F>> for Trivector<F> {
+    type Output = Scalar<F>;
+    fn left_contraction(self, b: Trivector<F>) -> Self::Output {
+        self | b
+    }
+
+    fn right_contraction(self, b: Trivector<F>) -> Self::Output {
+        self | b
+    }
+
+    fn scalar_product(self, b: Trivector<F>) -> Scalar<F> {
+        self | b
+    }
+
+    fn fat_dot(self, b: Trivector<F>) -> Self::Output {
+        self | b
+    }
+}
+
+// Trivector-Multivector
+// \[ \overset\Rrightarrow{a} \rfloor B \]
+impl<F: Float> Contraction<F, Multivector<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn left_contraction(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::from(self).left_contraction(b)
+    }
+
+    fn right_contraction(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::from(self).right_contraction(b)
+    }
+
+    fn scalar_product(self, b: Multivector<F>) -> Scalar<F> {
+        Multivector::from(self).scalar_product(b)
+    }
+
+    fn fat_dot(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::from(self).fat_dot(b)
+    }
+}
+
+// Trivector-Rotor
+// \[ \overset\Rrightarrow{a} \rfloor R \]
+impl<F: Float> Contraction<F, Rotor<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn left_contraction(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::from(self).left_contraction(Multivector::from(b))
+    }
+
+    fn right_contraction(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::from(self).right_contraction(Multivector::from(b))
+    }
+
+    fn scalar_product(self, b: Rotor<F>) -> Scalar<F> {
+        Multivector::from(self).scalar_product(Multivector::from(b))
+    }
+
+    fn fat_dot(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::from(self).fat_dot(Multivector::from(b))
+    }
+}
+
+// Multivector-Scalar
+// \[ A \rfloor s \]
+impl<F: Float> Contraction<F, Scalar<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn left_contraction(self, b: Scalar<F>) -> Multivector<F> {
+        self.left_contraction(Multivector::from(b))
+    }
+
+    fn right_contraction(self, b: Scalar<F>) -> Multivector<F> {
+        self.right_contraction(Multivector::from(b))
+    }
+
+    fn scalar_product(self, b: Scalar<F>) -> Scalar<F> {
+        self.scalar_product(Multivector::from(b))
+    }
+
+    fn fat_dot(self, b: Scalar<F>) -> Multivector<F> {
+        self.fat_dot(Multivector::from(b))
+    }
+}
+
+// Multivector-Vector
+// \[ A \rfloor \vec{b} \]
+impl<F: Float> Contraction<F, Vector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn left_contraction(self, b: Vector<F>) -> Multivector<F> {
+        self.left_contraction(Multivector::from(b))
+    }
+
+    fn right_contraction(self, b: Vector<F>) -> Multivector<F> {
+        self.right_contraction(Multivector::from(b))
+    }
+
+    fn scalar_product(self, b: Vector<F>) -> Scalar<F> {
+        self.scalar_product(Multivector::from(b))
+    }
+
+    fn fat_dot(self, b: Vector<F>) -> Multivector<F> {
+        self.fat_dot(Multivector::from(b))
+    }
+}
+
+// Multivector-Bivector
+// \[ A \rfloor \overset\Rightarrow{b} \]
+impl<F: Float> Contraction<F, Bivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn left_contraction(self, b: Bivector<F>) -> Multivector<F> {
+        self.left_contraction(Multivector::from(b))
+    }
+
+    fn right_contraction(self, b: Bivector<F>) -> Multivector<F> {
+        self.right_contraction(Multivector::from(b))
+    }
+
+    fn scalar_product(self, b: Bivector<F>) -> Scalar<F> {
+        self.scalar_product(Multivector::from(b))
+    }
+
+    fn fat_dot(self, b: Bivector<F>) -> Multivector<F> {
+        self.fat_dot(Multivector::from(b))
+    }
+}
+
+// Multivector-Trivector
+// \[ A \rfloor \overset\Rrightarrow{b} \]
+impl<F: Float> Contraction<F, Trivector<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn left_contraction(self, b: Trivector<F>) -> Multivector<F> {
+        self.left_contraction(Multivector::from(b))
+    }
+
+    fn right_contraction(self, b: Trivector<F>) -> Multivector<F> {
+        self.right_contraction(Multivector::from(b))
+    }
+
+    fn scalar_product(self, b: Trivector<F>) -> Scalar<F> {
+        self.scalar_product(Multivector::from(b))
+    }
+
+    fn fat_dot(self, b: Trivector<F>) -> Multivector<F> {
+        self.fat_dot(Multivector::from(b))
+    }
+}
+
+// Multivector-Multivector
+// \[ A \rfloor B = \sum_{a,b} \left< A \right>_a \rfloor \left< B \right>_b \]
+impl<F: Float> Contraction<F> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn left_contraction(self, b: Multivector<F>) -> Multivector<F> {
+        let scalar = self.scalar_product(b).0;
+        let vector = self.scalar.left_contraction(b.vector)
+            + self.vector.left_contraction(b.bivector)
+            + self.bivector.left_contraction(b.trivector);
+        let bivector =
+            self.scalar.left_contraction(b.bivector) + self.vector.left_contraction(b.trivector);
+        let trivector = self.scalar.left_contraction(b.trivector);
+        Multivector::new(scalar, vector, bivector, trivector)
+    }
+
+    fn right_contraction(self, b: Multivector<F>) -> Multivector<F> {
+        let scalar = self.scalar_product(b).0;
+        let vector = self.vector.right_contraction(b.scalar)
+            + self.bivector.right_contraction(b.vector)
+            + self.trivector.right_contraction(b.bivector);
+        let bivector =
+            self.bivector.right_contraction(b.scalar) + self.trivector.right_contraction(b.vector);
+        let trivector = self.trivector.right_contraction(b.scalar);
+        Multivector::new(scalar, vector, bivector, trivector)
+    }
+
+    fn scalar_product(self, b: Multivector<F>) -> Scalar<F> {
+        Scalar(
+            self.scalar.scalar_product(b.scalar).0
+                + self.vector.scalar_product(b.vector).0
+                + self.bivector.scalar_product(b.bivector).0
+                + self.trivector.scalar_product(b.trivector).0,
+        )
+    }
+
+    fn fat_dot(self, b: Multivector<F>) -> Multivector<F> {
+        self | b
+    }
+}
+
+// Multivector-Rotor
+// \[ A \rfloor R \]
+impl<F: Float> Contraction<F, Rotor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn left_contraction(self, b: Rotor<F>) -> Multivector<F> {
+        self.left_contraction(Multivector::from(b))
+    }
+
+    fn right_contraction(self, b: Rotor<F>) -> Multivector<F> {
+        self.right_contraction(Multivector::from(b))
+    }
+
+    fn scalar_product(self, b: Rotor<F>) -> Scalar<F> {
+        self.scalar_product(Multivector::from(b))
+    }
+
+    fn fat_dot(self, b: Rotor<F>) -> Multivector<F> {
+        self.fat_dot(Multivector::from(b))
+    }
+}
+
+// Rotor-Scalar
+// \[ R \rfloor s \]
+impl<F: Float> Contraction<F, Scalar<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn left_contraction(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::from(self).left_contraction(Multivector::from(b))
+    }
+
+    fn right_contraction(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::from(self).right_contraction(Multivector::from(b))
+    }
+
+    fn scalar_product(self, b: Scalar<F>) -> Scalar<F> {
+        Multivector::from(self).scalar_product(Multivector::from(b))
+    }
+
+    fn fat_dot(self, b: Scalar<F>) -> Multivector<F> {
+        Multivector::from(self).fat_dot(Multivector::from(b))
+    }
+}
+
+// Rotor-Vector
+// \[ R \rfloor \vec{b} \]
+impl<F: Float> Contraction<F, Vector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn left_contraction(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::from(self).left_contraction(Multivector::from(b))
+    }
+
+    fn right_contraction(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::from(self).right_contraction(Multivector::from(b))
+    }
+
+    fn scalar_product(self, b: Vector<F>) -> Scalar<F> {
+        Multivector::from(self).scalar_product(Multivector::from(b))
+    }
+
+    fn fat_dot(self, b: Vector<F>) -> Multivector<F> {
+        Multivector::from(self).fat_dot(Multivector::from(b))
+    }
+}
+
+// Rotor-Bivector
+// \[ R \rfloor \overset\Rightarrow{b} \]
+impl<F: Float> Contraction<F, Bivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn left_contraction(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::from(self).left_contraction(Multivector::from(b))
+    }
+
+    fn right_contraction(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::from(self).right_contraction(Multivector::from(b))
+    }
+
+    fn scalar_product(self, b: Bivector<F>) -> Scalar<F> {
+        Multivector::from(self).scalar_product(Multivector::from(b))
+    }
+
+    fn fat_dot(self, b: Bivector<F>) -> Multivector<F> {
+        Multivector::from(self).fat_dot(Multivector::from(b))
+    }
+}
+
+// Rotor-Trivector
+// \[ R \rfloor \overset\Rrightarrow{b} \]
+impl<F: Float> Contraction<F, Trivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn left_contraction(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::from(self).left_contraction(Multivector::from(b))
+    }
+
+    fn right_contraction(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::from(self).right_contraction(Multivector::from(b))
+    }
+
+    fn scalar_product(self, b: Trivector<F>) -> Scalar<F> {
+        Multivector::from(self).scalar_product(Multivector::from(b))
+    }
+
+    fn fat_dot(self, b: Trivector<F>) -> Multivector<F> {
+        Multivector::from(self).fat_dot(Multivector::from(b))
+    }
+}
+
+// Rotor-Multivector
+// \[ R \rfloor B \]
+impl<F: Float> Contraction<F, Multivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn left_contraction(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::from(self).left_contraction(b)
+    }
+
+    fn right_contraction(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::from(self).right_contraction(b)
+    }
+
+    fn scalar_product(self, b: Multivector<F>) -> Scalar<F> {
+        Multivector::from(self).scalar_product(b)
+    }
+
+    fn fat_dot(self, b: Multivector<F>) -> Multivector<F> {
+        Multivector::from(self).fat_dot(b)
+    }
+}
+
+// Rotor-Rotor
+// \[ R \rfloor R \]
+impl<F: Float> Contraction<F, Rotor<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn left_contraction(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::from(self).left_contraction(Multivector::from(b))
+    }
+
+    fn right_contraction(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::from(self).right_contraction(Multivector::from(b))
+    }
+
+    fn scalar_product(self, b: Rotor<F>) -> Scalar<F> {
+        Multivector::from(self).scalar_product(Multivector::from(b))
+    }
+
+    fn fat_dot(self, b: Rotor<F>) -> Multivector<F> {
+        Multivector::from(self).fat_dot(Multivector::from(b))
+    }
+}
+
+#[cfg(test)]
+mod contraction_product {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn vector_vector() {
+        // 3e1+5e2+4e3
+        let vector1 = Vector::new(3.0, 5.0, 4.0);
+        // 2e1+e2+6e3
+        let vector2 = Vector::new(2.0, 1.0, 6.0);
+        // 35
+        assert_relative_eq!(
+            vector1.left_contraction(vector2).0,
+            35.0,
+            max_relative = 0.000001
+        );
+        assert_relative_eq!(
+            vector1.right_contraction(vector2).0,
+            35.0,
+            max_relative = 0.000001
+        );
+        assert_relative_eq!(
+            vector1.scalar_product(vector2).0,
+            35.0,
+            max_relative = 0.000001
+        );
+        assert_relative_eq!(vector1.fat_dot(vector2).0, 35.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_bivector() {
+        // 3e1+5e2+4e3
+        let vector = Vector::new(3.0, 5.0, 4.0);
+        // 3e12+5e31+4e23
+        let bivector = Bivector::new(3.0, 5.0, 4.0);
+        let left = vector.left_contraction(bivector);
+        let right = vector.right_contraction(bivector);
+        // 5e1-7e2+5e3
+        assert_relative_eq!(left.e1(), 5.0, max_relative = 0.000001);
+        assert_relative_eq!(left.e2(), -7.0, max_relative = 0.000001);
+        assert_relative_eq!(left.e3(), 5.0, max_relative = 0.000001);
+        // 0
+        assert_relative_eq!(right.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(right.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(right.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(
+            vector.scalar_product(bivector).0,
+            0.0,
+            max_relative = 0.000001
+        );
+    }
+
+    #[test]
+    fn bivector_vector() {
+        // 3e12+5e31+4e23
+        let bivector = Bivector::new(3.0, 5.0, 4.0);
+        // 3e1+5e2+4e3
+        let vector = Vector::new(3.0, 5.0, 4.0);
+        let left = bivector.left_contraction(vector);
+        let right = bivector.right_contraction(vector);
+        // 0
+        assert_relative_eq!(left.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(left.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(left.e3(), 0.0, max_relative = 0.000001);
+        // -5e1+7e2-5e3
+        assert_relative_eq!(right.e1(), -5.0, max_relative = 0.000001);
+        assert_relative_eq!(right.e2(), 7.0, max_relative = 0.000001);
+        assert_relative_eq!(right.e3(), -5.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn scalar_vector() {
+        let scalar = Scalar(2.0);
+        // 3e1+5e2+4e3
+        let vector = Vector::new(3.0, 5.0, 4.0);
+        let left = scalar.left_contraction(vector);
+        let right = scalar.right_contraction(vector);
+        let fat = vector.fat_dot(scalar);
+        // 6e1+10e2+8e3
+        assert_relative_eq!(left.e1(), 6.0, max_relative = 0.000001);
+        assert_relative_eq!(left.e2(), 10.0, max_relative = 0.000001);
+        assert_relative_eq!(left.e3(), 8.0, max_relative = 0.000001);
+        // 0
+        assert_relative_eq!(right.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(right.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(right.e3(), 0.0, max_relative = 0.000001);
+        // 6e1+10e2+8e3
+        assert_relative_eq!(fat.e1(), 6.0, max_relative = 0.000001);
+        assert_relative_eq!(fat.e2(), 10.0, max_relative = 0.000001);
+        assert_relative_eq!(fat.e3(), 8.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_trivector() {
+        // 3e12+5e31+4e23
+        let bivector = Bivector::new(3.0, 5.0, 4.0);
+        // 2e123
+        let trivector = Trivector::new(2.0);
+        let left = bivector.left_contraction(trivector);
+        let right = trivector.left_contraction(bivector);
+        // -8e1-10e2-6e3
+        assert_relative_eq!(left.e1(), -8.0, max_relative = 0.000001);
+        assert_relative_eq!(left.e2(), -10.0, max_relative = 0.000001);
+        assert_relative_eq!(left.e3(), -6.0, max_relative = 0.000001);
+        // 0
+        assert_relative_eq!(right.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(right.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(right.e3(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_bivector() {
+        // 2e12+e31+6e23
+        let bivector1 = Bivector::new(2.0, 1.0, 6.0);
+        // 3e12+5e31+4e23
+        let bivector2 = Bivector::new(3.0, 5.0, 4.0);
+        // -35
+        assert_relative_eq!(
+            bivector1.scalar_product(bivector2).0,
+            -35.0,
+            max_relative = 0.000001
+        );
+        assert_relative_eq!(
+            bivector1.left_contraction(bivector2).0,
+            -35.0,
+            max_relative = 0.000001
+        );
+    }
+
+    #[test]
+    fn rotor_vector() {
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        // 3e1+5e2+4e3
+        let vector = Vector::new(3.0, 5.0, 4.0);
+        let left = rotor.left_contraction(vector);
+        let right = rotor.right_contraction(vector);
+        // 1.5e1+2.5e2+2e3
+        assert_relative_eq!(left.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(left.e1(), 1.5, max_relative = 0.000001);
+        assert_relative_eq!(left.e2(), 2.5, max_relative = 0.000001);
+        assert_relative_eq!(left.e3(), 2.0, max_relative = 0.000001);
+        // 0.5e1+0.5e2-e3
+        assert_relative_eq!(right.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(right.e1(), 0.5, max_relative = 0.000001);
+        assert_relative_eq!(right.e2(), 0.5, max_relative = 0.000001);
+        assert_relative_eq!(right.e3(), -1.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_multivector() {
+        // ( 5.0 + 8.0e1 + 7.0e2 + 3.0e3 + 2.0e12 + 8.0e31 + 2.0e23 + 1.0e123 )
+        let mvec1 = Multivector::new_components(5.0, 8.0, 7.0, 3.0, 2.0, 8.0, 2.0, 1.0);
+        // -4-2e1-4e2-9e3-2e12-1e31-7e23-1e123
+        let mvec2 = Multivector::new_components(-4.0, -2.0, -4.0, -9.0, -2.0, -1.0, -7.0, -1.0);
+        let left = mvec1.left_contraction(mvec2);
+        let right = mvec1.right_contraction(mvec2);
+        // -64+3e1-7e2-84e3-13e12-12e31-43e23-5e123
+        assert_relative_eq!(left.scalar(), -64.0, max_relative = 0.000001);
+        assert_relative_eq!(left.e1(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(left.e2(), -7.0, max_relative = 0.000001);
+        assert_relative_eq!(left.e3(), -84.0, max_relative = 0.000001);
+        assert_relative_eq!(left.e12(), -13.0, max_relative = 0.000001);
+        assert_relative_eq!(left.e31(), -12.0, max_relative = 0.000001);
+        assert_relative_eq!(left.e23(), -43.0, max_relative = 0.000001);
+        assert_relative_eq!(left.e123(), -5.0, max_relative = 0.000001);
+        // -64+39e1-41e2-18e3-17e12-36e31-10e23-4e123
+        assert_relative_eq!(right.scalar(), -64.0, max_relative = 0.000001);
+        assert_relative_eq!(right.e1(), 39.0, max_relative = 0.000001);
+        assert_relative_eq!(right.e2(), -41.0, max_relative = 0.000001);
+        assert_relative_eq!(right.e3(), -18.0, max_relative = 0.000001);
+        assert_relative_eq!(right.e12(), -17.0, max_relative = 0.000001);
+        assert_relative_eq!(right.e31(), -36.0, max_relative = 0.000001);
+        assert_relative_eq!(right.e23(), -10.0, max_relative = 0.000001);
+        assert_relative_eq!(right.e123(), -4.0, max_relative = 0.000001);
+        // -64
+        assert_relative_eq!(
+            mvec1.scalar_product(mvec2).0,
+            -64.0,
+            max_relative = 0.000001
+        );
+    }
+}
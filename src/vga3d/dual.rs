@@ -0,0 +1,345 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    bivector::Bivector, multivector::Multivector, rotor::Rotor, scalar::Scalar,
+    trivector::Trivector, vector::Vector,
+};
+
+use num_traits::Float;
+
+/// # Dual
+/// In VGA 3D, the dual is the multiplication with the unit pseudoscalar $\overset\Rrightarrow{i}$
+/// $$ A\star = A \overset\Rrightarrow{i} $$
+/// Vector and bivectors in 3D VGA follows this pattern. Going up, going down
+/// $$ \text{scalar}, \mathrm{e}_1,\mathrm{e}_2,\mathrm{e}_3,\mathrm{e}_3\star, \mathrm{e}_2\star, \mathrm{e}_1\star, \text{scalar}\star $$
+/// The complements are defined on the basis blades.
+/// In 3D VGA the Hodge dual, the right complement and the left complement are equal.
+pub trait Dual {
+    type Output;
+
+    /// Dual
+    /// $$ A\star = A \overset\Rrightarrow{i} $$
+    fn dual(self) -> Self::Output;
+
+    /// Undual, the inverse of the dual
+    /// $$ A = A\star \overset\Rrightarrow{i}^{-1} $$
+    fn undual(self) -> Self::Output;
+
+    /// Hodge dual
+    /// $$ \star A = \tilde{A} \overset\Rrightarrow{i} $$
+    fn hodge(self) -> Self::Output;
+
+    /// Right complement
+    /// $$ A \wedge \overline{A} = \overset\Rrightarrow{i} $$
+    fn right_complement(self) -> Self::Output;
+
+    /// Left complement
+    /// $$ \underline{A} \wedge A = \overset\Rrightarrow{i} $$
+    fn left_complement(self) -> Self::Output;
+}
+
+// Scalar
+// $$ s \overset\Rrightarrow{i} $$
+impl<F: Float> Dual for Scalar<F> {
+    type Output = Trivector<F>;
+
+    fn dual(self) -> Trivector<F> {
+        Trivector::new(self.0)
+    }
+
+    fn undual(self) -> Trivector<F> {
+        Trivector::new(-self.0)
+    }
+
+    fn hodge(self) -> Trivector<F> {
+        Trivector::new(self.0)
+    }
+
+    fn right_complement(self) -> Trivector<F> {
+        Trivector::new(self.0)
+    }
+
+    fn left_complement(self) -> Trivector<F> {
+        Trivector::new(self.0)
+    }
+}
+
+// Vector
+// $$ \vec{v} \overset\Rrightarrow{i} = \overset\Rightarrow{b} $$
+impl<F: Float> Dual for Vector<F> {
+    type Output = Bivector<F>;
+
+    fn dual(self) -> Bivector<F> {
+        Bivector::new(self.e3(), self.e2(), self.e1())
+    }
+
+    fn undual(self) -> Bivector<F> {
+        Bivector::new(-self.e3(), -self.e2(), -self.e1())
+    }
+
+    fn hodge(self) -> Bivector<F> {
+        Bivector::new(self.e3(), self.e2(), self.e1())
+    }
+
+    fn right_complement(self) -> Bivector<F> {
+        Bivector::new(self.e3(), self.e2(), self.e1())
+    }
+
+    fn left_complement(self) -> Bivector<F> {
+        Bivector::new(self.e3(), self.e2(), self.e1())
+    }
+}
+
+// Bivector
+// $$ \overset\Rightarrow{b} \overset\Rrightarrow{i} = -\vec{v} $$
+impl<F: Float> Dual for Bivector<F> {
+    type Output = Vector<F>;
+
+    fn dual(self) -> Vector<F> {
+        Vector::new(-self.e23(), -self.e31(), -self.e12())
+    }
+
+    fn undual(self) -> Vector<F> {
+        Vector::new(self.e23(), self.e31(), self.e12())
+    }
+
+    fn hodge(self) -> Vector<F> {
+        Vector::new(self.e23(), self.e31(), self.e12())
+    }
+
+    fn right_complement(self) -> Vector<F> {
+        Vector::new(self.e23(), self.e31(), self.e12())
+    }
+
+    fn left_complement(self) -> Vector<F> {
+        Vector::new(self.e23(), self.e31(), self.e12())
+    }
+}
+
+// Trivector
+// $$ \overset\Rrightarrow{t} \overset\Rrightarrow{i} = -s $$
+impl<F: Float> Dual for Trivector<F> {
+    type Output = Scalar<F>;
+
+    fn dual(self) -> Scalar<F> {
+        Scalar(-self.e123())
+    }
+
+    fn undual(self) -> Scalar<F> {
+        Scalar(self.e123())
+    }
+
+    fn hodge(self) -> Scalar<F> {
+        Scalar(self.e123())
+    }
+
+    fn right_complement(self) -> Scalar<F> {
+        Scalar(self.e123())
+    }
+
+    fn left_complement(self) -> Scalar<F> {
+        Scalar(self.e123())
+    }
+}
+
+// Multivector
+// Each grade is mapped to its dual grade
+// \[ \text{scalar},\mathrm{e}_1,\,\mathrm{e}_2,\,\mathrm{e}_3,\,\mathrm{e}_3\star,\,\mathrm{e}_2\star,\,\mathrm{e}_1\star,\, \text{scalar} \star \]
+impl<F: Float> Dual for Multivector<F> {
+    type Output = Multivector<F>;
+
+    fn dual(self) -> Multivector<F> {
+        let scalar = self.trivector.dual().0;
+        let vector = self.bivector.dual();
+        let bivector = self.vector.dual();
+        let trivector = self.scalar.dual();
+        Multivector::new(scalar, vector, bivector, trivector)
+    }
+
+    fn undual(self) -> Multivector<F> {
+        let scalar = self.trivector.undual().0;
+        let vector = self.bivector.undual();
+        let bivector = self.vector.undual();
+        let trivector = self.scalar.undual();
+        Multivector::new(scalar, vector, bivector, trivector)
+    }
+
+    fn hodge(self) -> Multivector<F> {
+        let scalar = self.trivector.hodge().0;
+        let vector = self.bivector.hodge();
+        let bivector = self.vector.hodge();
+        let trivector = self.scalar.hodge();
+        Multivector::new(scalar, vector, bivector, trivector)
+    }
+
+    fn right_complement(self) -> Multivector<F> {
+        let scalar = self.trivector.right_complement().0;
+        let vector = self.bivector.right_complement();
+        let bivector = self.vector.right_complement();
+        let trivector = self.scalar.right_complement();
+        Multivector::new(scalar, vector, bivector, trivector)
+    }
+
+    fn left_complement(self) -> Multivector<F> {
+        let scalar = self.trivector.left_complement().0;
+        let vector = self.bivector.left_complement();
+        let bivector = self.vector.left_complement();
+        let trivector = self.scalar.left_complement();
+        Multivector::new(scalar, vector, bivector, trivector)
+    }
+}
+
+// Rotor
+// The dual of a rotor is a vector and a trivector
+impl<F: Float> Dual for Rotor<F> {
+    type Output = Multivector<F>;
+
+    fn dual(self) -> Multivector<F> {
+        Multivector::from(self).dual()
+    }
+
+    fn undual(self) -> Multivector<F> {
+        Multivector::from(self).undual()
+    }
+
+    fn hodge(self) -> Multivector<F> {
+        Multivector::from(self).hodge()
+    }
+
+    fn right_complement(self) -> Multivector<F> {
+        Multivector::from(self).right_complement()
+    }
+
+    fn left_complement(self) -> Multivector<F> {
+        Multivector::from(self).left_complement()
+    }
+}
+
+#[cfg(test)]
+mod dual_product {
+    use super::*;
+    use crate::vga3d::VGA3DOps;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn scalar_dual() {
+        // 2 i = 2e123
+        let scalar = Scalar(2.0);
+        let res = scalar.dual();
+        assert_relative_eq!(res.e123(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.undual().0, 2.0, max_relative = 0.000001);
+    }
+    #[test]
+    fn vector_dual() {
+        // (2e1+3e2+4e3) i = 4e12+3e31+2e23
+        let vector = Vector::new(2.0, 3.0, 4.0);
+        let res = vector.dual();
+        let geometric = vector * Trivector::new(1.0);
+        assert_relative_eq!(res.e12(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 2.0, max_relative = 0.000001);
+        assert_eq!(res, geometric);
+    }
+    #[test]
+    fn bivector_dual() {
+        // (4e12+3e31+2e23) i = -2e1-3e2-4e3
+        let bivector = Bivector::new(4.0, 3.0, 2.0);
+        let res = bivector.dual();
+        let geometric = bivector * Trivector::new(1.0);
+        assert_relative_eq!(res.e1(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), -4.0, max_relative = 0.000001);
+        assert_eq!(res, geometric);
+    }
+    #[test]
+    fn trivector_dual() {
+        // 5e123 i = -5
+        let trivector = Trivector::new(5.0);
+        let res = trivector.dual();
+        assert_relative_eq!(res.0, -5.0, max_relative = 0.000001);
+    }
+    #[test]
+    fn multivector_dual() {
+        // A i
+        let multivector = Multivector::new_components(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let res = multivector.dual();
+        let geometric = multivector * Trivector::new(1.0);
+        assert_eq!(res, geometric);
+    }
+    #[test]
+    fn rotor_dual() {
+        // R i
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        let res = rotor.dual();
+        let geometric = rotor * Trivector::new(1.0);
+        assert_eq!(res, geometric);
+    }
+    #[test]
+    fn multivector_undual() {
+        // (A i) i^{-1} = A
+        let multivector = Multivector::new_components(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let res = multivector.dual().undual();
+        assert_eq!(res, multivector);
+        let res = multivector.undual().dual();
+        assert_eq!(res, multivector);
+    }
+    #[test]
+    fn multivector_hodge() {
+        // \star A = \tilde{A} i
+        let multivector = Multivector::new_components(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let res = multivector.hodge();
+        let geometric = multivector.reverse() * Trivector::new(1.0);
+        assert_eq!(res, geometric);
+    }
+    #[test]
+    fn vector_complement() {
+        // e1 ^ e23 = e123, e23 ^ e1 = e123
+        let vector = Vector::new(1.0, 0.0, 0.0);
+        let right = vector ^ vector.right_complement();
+        let left = vector.left_complement() ^ vector;
+        assert_relative_eq!(right.e123(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(left.e123(), 1.0, max_relative = 0.000001);
+    }
+    #[test]
+    fn bivector_complement() {
+        // e31 ^ e2 = e123, e2 ^ e31 = e123
+        let bivector = Bivector::new(0.0, 1.0, 0.0);
+        let right = bivector ^ bivector.right_complement();
+        let left = bivector.left_complement() ^ bivector;
+        assert_relative_eq!(right.e123(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(left.e123(), 1.0, max_relative = 0.000001);
+    }
+    #[test]
+    fn trivector_complement() {
+        // e123 ^ 1 = e123
+        let trivector = Trivector::new(1.0);
+        let right = trivector.right_complement();
+        let left = trivector.left_complement();
+        assert_relative_eq!(right.0, 1.0, max_relative = 0.000001);
+        assert_relative_eq!(left.0, 1.0, max_relative = 0.000001);
+        assert_relative_eq!(
+            Scalar(1.0).right_complement().e123(),
+            1.0,
+            max_relative = 0.000001
+        );
+    }
+}
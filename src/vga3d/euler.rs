@@ -0,0 +1,376 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{bivector::Bivector, rotor::Rotor};
+
+use num_traits::Float;
+
+/// # Euler Sequence
+/// The axes of the three elementary rotations.
+/// The axis $x$ is $\mathrm{e}_1$, the rotation in the $\mathrm{e}_2\mathrm{e}_3$ plane.
+/// The axis $y$ is $\mathrm{e}_2$, the rotation in the $\mathrm{e}_3\mathrm{e}_1$ plane.
+/// The axis $z$ is $\mathrm{e}_3$, the rotation in the $\mathrm{e}_1\mathrm{e}_2$ plane.
+///
+/// The six Tait-Bryan sequences use three different axes.
+/// The six proper Euler sequences repeat the first axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EulerSequence {
+    /// Tait-Bryan $x$-$y$-$z$
+    Xyz,
+    /// Tait-Bryan $x$-$z$-$y$
+    Xzy,
+    /// Tait-Bryan $y$-$x$-$z$
+    Yxz,
+    /// Tait-Bryan $y$-$z$-$x$
+    Yzx,
+    /// Tait-Bryan $z$-$x$-$y$
+    Zxy,
+    /// Tait-Bryan $z$-$y$-$x$, yaw, pitch and roll for intrinsic rotations
+    Zyx,
+    /// Proper Euler $x$-$y$-$x$
+    Xyx,
+    /// Proper Euler $x$-$z$-$x$
+    Xzx,
+    /// Proper Euler $y$-$x$-$y$
+    Yxy,
+    /// Proper Euler $y$-$z$-$y$
+    Yzy,
+    /// Proper Euler $z$-$x$-$z$
+    Zxz,
+    /// Proper Euler $z$-$y$-$z$
+    Zyz,
+}
+
+impl EulerSequence {
+    // The axis indices, x = 0, y = 1, z = 2
+    fn axes(self) -> [usize; 3] {
+        match self {
+            EulerSequence::Xyz => [0, 1, 2],
+            EulerSequence::Xzy => [0, 2, 1],
+            EulerSequence::Yxz => [1, 0, 2],
+            EulerSequence::Yzx => [1, 2, 0],
+            EulerSequence::Zxy => [2, 0, 1],
+            EulerSequence::Zyx => [2, 1, 0],
+            EulerSequence::Xyx => [0, 1, 0],
+            EulerSequence::Xzx => [0, 2, 0],
+            EulerSequence::Yxy => [1, 0, 1],
+            EulerSequence::Yzy => [1, 2, 1],
+            EulerSequence::Zxz => [2, 0, 2],
+            EulerSequence::Zyz => [2, 1, 2],
+        }
+    }
+}
+
+/// # Euler Frame
+/// The frame the axes of the elementary rotations are fixed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EulerFrame {
+    /// The axes rotate with the body, each rotation is about the axis of the rotated frame.
+    Intrinsic,
+    /// The axes are fixed in the reference frame.
+    Extrinsic,
+}
+
+/// # Euler Angles
+/// The three angles in radians in the order of the sequence.
+/// The first and third angle are in $[-\pi, \pi]$.
+/// The second angle is in $[-\frac{\pi}{2}, \frac{\pi}{2}]$ for Tait-Bryan sequences and $[0, \pi]$ for proper Euler sequences.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EulerAngles<F: Float> {
+    /// The angles are unique.
+    Angles([F; 3]),
+    /// Gimbal lock, the first and third axes are aligned.
+    /// Only the sum or difference of the first and third angle is defined,
+    /// the third angle is set to zero.
+    GimbalLock([F; 3]),
+}
+
+impl<F: Float> EulerAngles<F> {
+    /// The three angles
+    pub fn angles(&self) -> [F; 3] {
+        match self {
+            EulerAngles::Angles(angles) => *angles,
+            EulerAngles::GimbalLock(angles) => *angles,
+        }
+    }
+
+    /// True if the angles are in gimbal lock
+    pub fn is_gimbal_lock(&self) -> bool {
+        matches!(self, EulerAngles::GimbalLock(_))
+    }
+}
+
+// The rotor of the elementary rotation about an axis
+// \[ R_x(\theta) = \mathrm{e}^{\mathrm{e}_2\mathrm{e}_3\frac{\theta}{2}} \]
+fn elementary_rotor<F: Float>(axis: usize, angle: F) -> Rotor<F> {
+    let half_angle = angle / (F::one() + F::one());
+    match axis {
+        0 => Bivector::new(F::zero(), F::zero(), half_angle),
+        1 => Bivector::new(F::zero(), half_angle, F::zero()),
+        _ => Bivector::new(half_angle, F::zero(), F::zero()),
+    }
+    .exp()
+}
+
+impl<F: Float> Rotor<F> {
+    /// # From Euler Angles
+    /// Creates the rotor from three angles in radians in the order of the sequence.
+    /// The first rotation is applied first
+    /// $$ R = R_1(\theta_1) R_2(\theta_2) R_3(\theta_3) $$
+    /// For intrinsic rotations the axes of the rotated frame are used, this is the same as
+    /// the extrinsic rotations in the reversed order
+    /// $$ R = R_3(\theta_3) R_2(\theta_2) R_1(\theta_1) $$
+    pub fn from_euler_angles(angles: [F; 3], sequence: EulerSequence, frame: EulerFrame) -> Self {
+        let axes = sequence.axes();
+        let first = elementary_rotor(axes[0], angles[0]);
+        let second = elementary_rotor(axes[1], angles[1]);
+        let third = elementary_rotor(axes[2], angles[2]);
+        match frame {
+            EulerFrame::Extrinsic => first * second * third,
+            EulerFrame::Intrinsic => third * second * first,
+        }
+    }
+
+    /// # To Euler Angles
+    /// The three angles in radians in the order of the sequence.
+    /// The general method of Bernardes and Viollet is used for all twelve sequences.
+    /// At gimbal lock `EulerAngles::GimbalLock` is returned with the third angle set to zero.
+    pub fn to_euler_angles(self, sequence: EulerSequence, frame: EulerFrame) -> EulerAngles<F> {
+        let one = F::one();
+        let two = one + one;
+        let pi = (-one).acos();
+
+        // The intrinsic sequence is the extrinsic sequence reversed
+        let [first, j, third] = sequence.axes();
+        let (i, k) = match frame {
+            EulerFrame::Extrinsic => (first, third),
+            EulerFrame::Intrinsic => (third, first),
+        };
+
+        // Proper Euler sequences uses the missing axis
+        let proper = i == k;
+        let k = match proper {
+            true => 3 - i - j,
+            false => k,
+        };
+        // Even or odd permutation of the axes
+        let sign = match (i + 1) % 3 == j {
+            true => one,
+            false => -one,
+        };
+
+        // The quaternion components of the rotor
+        let quaternion = [self.e23(), self.e31(), self.e12()];
+        let w = self.scalar();
+        let (a, b, c, d) = match proper {
+            true => (w, quaternion[i], quaternion[j], quaternion[k] * sign),
+            false => (
+                w - quaternion[j],
+                quaternion[i] + quaternion[k] * sign,
+                quaternion[j] + w,
+                quaternion[k] * sign - quaternion[i],
+            ),
+        };
+
+        let mut angles = [F::zero(); 3];
+        angles[1] = two * c.hypot(d).atan2(a.hypot(b));
+        let half_sum = b.atan2(a);
+        let half_difference = d.atan2(c);
+
+        // At gimbal lock the angle that becomes the third angle is set to zero
+        // The first and third angle is computed in reversed order for intrinsic rotations
+        let tolerance = F::epsilon().sqrt();
+        let gimbal_lock = match (angles[1], frame) {
+            (angle, EulerFrame::Extrinsic) if angle.abs() <= tolerance => {
+                angles[0] = two * half_sum;
+                true
+            }
+            (angle, EulerFrame::Intrinsic) if angle.abs() <= tolerance => {
+                angles[2] = two * half_sum;
+                true
+            }
+            (angle, EulerFrame::Extrinsic) if (angle - pi).abs() <= tolerance => {
+                angles[0] = -two * half_difference;
+                true
+            }
+            (angle, EulerFrame::Intrinsic) if (angle - pi).abs() <= tolerance => {
+                angles[2] = two * half_difference;
+                true
+            }
+            _ => {
+                angles[0] = half_sum - half_difference;
+                angles[2] = half_sum + half_difference;
+                false
+            }
+        };
+
+        // Tait-Bryan angles
+        if !proper {
+            angles[2] = angles[2] * sign;
+            angles[1] = angles[1] - pi / two;
+        }
+
+        if frame == EulerFrame::Intrinsic {
+            angles.swap(0, 2);
+        }
+
+        // Wrap the angles to [-pi, pi]
+        for angle in angles.iter_mut() {
+            if *angle < -pi {
+                *angle = *angle + two * pi;
+            } else if *angle > pi {
+                *angle = *angle - two * pi;
+            }
+        }
+
+        match gimbal_lock {
+            true => EulerAngles::GimbalLock(angles),
+            false => EulerAngles::Angles(angles),
+        }
+    }
+}
+
+#[cfg(test)]
+mod euler_angles {
+    use super::*;
+    use crate::vga3d::{Rotatable, Vector};
+    use approx::assert_relative_eq;
+    use core::f32::consts::TAU;
+
+    const SEQUENCES: [EulerSequence; 12] = [
+        EulerSequence::Xyz,
+        EulerSequence::Xzy,
+        EulerSequence::Yxz,
+        EulerSequence::Yzx,
+        EulerSequence::Zxy,
+        EulerSequence::Zyx,
+        EulerSequence::Xyx,
+        EulerSequence::Xzx,
+        EulerSequence::Yxy,
+        EulerSequence::Yzy,
+        EulerSequence::Zxz,
+        EulerSequence::Zyz,
+    ];
+    const FRAMES: [EulerFrame; 2] = [EulerFrame::Intrinsic, EulerFrame::Extrinsic];
+
+    // R and -R is the same rotation
+    fn assert_rotation_eq(a: Rotor<f32>, b: Rotor<f32>) {
+        let sign = match a.scalar() * b.scalar()
+            + a.e12() * b.e12()
+            + a.e31() * b.e31()
+            + a.e23() * b.e23()
+        {
+            dot if dot < 0.0 => -1.0,
+            _ => 1.0,
+        };
+        assert_relative_eq!(a.scalar(), sign * b.scalar(), epsilon = 0.0001);
+        assert_relative_eq!(a.e12(), sign * b.e12(), epsilon = 0.0001);
+        assert_relative_eq!(a.e31(), sign * b.e31(), epsilon = 0.0001);
+        assert_relative_eq!(a.e23(), sign * b.e23(), epsilon = 0.0001);
+    }
+
+    fn is_proper(sequence: EulerSequence) -> bool {
+        let axes = sequence.axes();
+        axes[0] == axes[2]
+    }
+
+    #[test]
+    fn yaw_pitch_roll() {
+        // Intrinsic z-y-x with a quarter turn yaw rotates e1 to e2
+        let rotor = Rotor::from_euler_angles(
+            [TAU / 4.0, 0.0, 0.0],
+            EulerSequence::Zyx,
+            EulerFrame::Intrinsic,
+        );
+        let res = Vector::new(1.0, 0.0, 0.0).rotate(rotor);
+        assert_relative_eq!(res.e1(), 0.0, epsilon = 0.00001);
+        assert_relative_eq!(res.e2(), 1.0, epsilon = 0.00001);
+        assert_relative_eq!(res.e3(), 0.0, epsilon = 0.00001);
+
+        // Yaw then pitch, the pitch is about the rotated y axis
+        let rotor = Rotor::from_euler_angles(
+            [TAU / 4.0, TAU / 4.0, 0.0],
+            EulerSequence::Zyx,
+            EulerFrame::Intrinsic,
+        );
+        let res = Vector::new(1.0, 0.0, 0.0).rotate(rotor);
+        assert_relative_eq!(res.e1(), 0.0, epsilon = 0.00001);
+        assert_relative_eq!(res.e2(), 0.0, epsilon = 0.00001);
+        assert_relative_eq!(res.e3(), -1.0, epsilon = 0.00001);
+    }
+    #[test]
+    fn intrinsic_extrinsic() {
+        // Intrinsic x-y-z is extrinsic z-y-x with the angles reversed
+        let intrinsic =
+            Rotor::from_euler_angles([0.3, -0.5, 1.1], EulerSequence::Xyz, EulerFrame::Intrinsic);
+        let extrinsic =
+            Rotor::from_euler_angles([1.1, -0.5, 0.3], EulerSequence::Zyx, EulerFrame::Extrinsic);
+        assert_rotation_eq(intrinsic, extrinsic);
+    }
+    #[test]
+    fn round_trip() {
+        // All sequences and frames give back the angles
+        for sequence in SEQUENCES {
+            for frame in FRAMES {
+                let angles = match is_proper(sequence) {
+                    true => [0.3, 1.2, -2.1],
+                    false => [0.3, -1.2, 2.1],
+                };
+                let rotor = Rotor::from_euler_angles(angles, sequence, frame);
+                let res = rotor.to_euler_angles(sequence, frame);
+                assert!(!res.is_gimbal_lock());
+                for (res, angle) in res.angles().iter().zip(angles.iter()) {
+                    assert_relative_eq!(*res, *angle, epsilon = 0.0001);
+                }
+            }
+        }
+    }
+    #[test]
+    fn round_trip_rotor() {
+        // All sequences and frames give back the rotor
+        let rotor = Bivector::new(0.3, -0.2, 0.5).exp();
+        for sequence in SEQUENCES {
+            for frame in FRAMES {
+                let angles = rotor.to_euler_angles(sequence, frame).angles();
+                let res = Rotor::from_euler_angles(angles, sequence, frame);
+                assert_rotation_eq(res, rotor);
+            }
+        }
+    }
+    #[test]
+    fn gimbal_lock() {
+        // The second angle aligns the first and third axes
+        for sequence in SEQUENCES {
+            for frame in FRAMES {
+                let locks = match is_proper(sequence) {
+                    true => [0.0, TAU / 2.0],
+                    false => [TAU / 4.0, -TAU / 4.0],
+                };
+                for lock in locks {
+                    let rotor = Rotor::from_euler_angles([0.4, lock, -0.7], sequence, frame);
+                    let res = rotor.to_euler_angles(sequence, frame);
+                    assert!(res.is_gimbal_lock());
+                    let angles = res.angles();
+                    assert!(angles.iter().all(|angle| angle.is_finite()));
+                    assert_relative_eq!(angles[2], 0.0);
+                    assert_rotation_eq(Rotor::from_euler_angles(angles, sequence, frame), rotor);
+                }
+            }
+        }
+    }
+}
@@ -119,25 +119,35 @@ use core::ops::BitXor;
 // }
 // forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, F);
 
-// // Scalar-Rotor
-// // \[ s\wedge R\]
-// impl<F:Float> BitXor<Rotor<F>> for F {
-//     type Output = Multivector<F>;
-//     fn bitxor(self: F, b: Rotor<F>) -> Multivector<F> {
-//         self * b
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitXor, bitxor for F, Rotor<F>);
+// Scalar-Rotor
+// \[ s\wedge R\]
+impl<F: Float> BitXor<Rotor<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self: Scalar<F>, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 * b.scalar(),
+            Vector::zero(),
+            self * b.bivector(),
+            Trivector::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Scalar<F>, Rotor<F>);
 
-// //Rotor-Scalar
-// // \[ R\wedge b\]
-// impl<F:Float> BitXor<F> for Rotor<F> {
-//     type Output = Multivector<F>;
-//     fn bitxor(self: Rotor<F>, b: F) -> Multivector<F> {
-//         self * b
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitXor, bitxor for Rotor<F>, F);
+// Rotor-Scalar
+// \[ R\wedge b\]
+impl<F: Float> BitXor<Scalar<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self: Rotor<F>, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.0,
+            Vector::zero(),
+            self.bivector() * b,
+            Trivector::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Rotor<F>, Scalar<F>);
 
 // Vector-Vector
 impl<F: Float> BitXor for Vector<F> {
@@ -222,35 +232,35 @@ impl<F: Float> BitXor<Vector<F>> for Multivector<F> {
 }
 forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Vector<F>);
 
-// // Vector-Rotor
-// // \[ \vec{a} \wedge R\]
-// impl<F:Float> BitXor<Rotor<F>> for Vector<F> {
-//     type Output = Multivector<F>;
-//     fn bitxor(self: Vector<F>, b: Rotor<F>) -> Multivector<F> {
-//         Multivector::new(
-//             F::zero(),
-//             self ^ b.scalar(),
-//             Bivector::zero(),
-//             self ^ b.bivector(),
-//         )
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitXor, bitxor for Vector<F>, Rotor<F>);
+// Vector-Rotor
+// \[ \vec{a} \wedge R\]
+impl<F: Float> BitXor<Rotor<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self: Vector<F>, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            self * b.scalar,
+            Bivector::zero(),
+            self ^ b.bivector(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Vector<F>, Rotor<F>);
 
-// // Rotor-Vector
-// // \[ R\wedge \vec{b}\]
-// impl<F:Float> BitXor<Vector<F>> for Rotor<F> {
-//     type Output = Multivector<F>;
-//     fn bitxor(self: Rotor<F>, b: Vector<F>) -> Multivector<F> {
-//         Multivector::new(
-//             F::zero(),
-//             self.scalar() ^ b,
-//             Bivector::zero(),
-//             self.bivector() ^ b,
-//         )
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitXor, bitxor for Rotor<F>, Vector<F>);
+// Rotor-Vector
+// \[ R\wedge \vec{b}\]
+impl<F: Float> BitXor<Vector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self: Rotor<F>, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            self.scalar * b,
+            Bivector::zero(),
+            self.bivector() ^ b,
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Rotor<F>, Vector<F>);
 
 // Bivector-Bivector
 // \[ \overset\Rightarrow{a} \wedge \overset\Rightarrow{b} = \left <\overset\Rightarrow{a} \overset\Rightarrow{b} \right>_4 \]
@@ -312,35 +322,35 @@ impl<F: Float> BitXor<Bivector<F>> for Multivector<F> {
 }
 forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Bivector<F>);
 
-// // Bivector-Rotor
-// // \[ \overset\Rightarrow{a}\wedge R\]
-// impl<F:Float> BitXor<Rotor<F>> for Bivector<F> {
-//     type Output = Multivector<F>;
-//     fn bitxor(self: Bivector<F>, b: Rotor<F>) -> Multivector<F> {
-//         Multivector::new(
-//             self ^ b.bivector(),
-//             Vector::zero(),
-//             self * b.scalar(),
-//             Trivector::zero(),
-//         )
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitXor, bitxor for Bivector<F>, Rotor<F>);
+// Bivector-Rotor
+// \[ \overset\Rightarrow{a}\wedge R\]
+impl<F: Float> BitXor<Rotor<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self: Bivector<F>, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            (self ^ b.bivector()).0,
+            Vector::zero(),
+            self * b.scalar,
+            Trivector::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Bivector<F>, Rotor<F>);
 
-// // Rotor-Bivector
-// // \[ R\wedge \overset\Rightarrow{b}\]
-// impl<F:Float> BitXor<Bivector<F>> for Rotor<F> {
-//     type Output = Multivector<F>;
-//     fn bitxor(self: Rotor<F>, b: Bivector<F>) -> Multivector<F> {
-//         Multivector::new(
-//             self.bivector() ^ b,
-//             Vector::zero(),
-//             self.scalar() * b,
-//             Trivector::zero(),
-//         )
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitXor, bitxor for Rotor<F>, Bivector<F>);
+// Rotor-Bivector
+// \[ R\wedge \overset\Rightarrow{b}\]
+impl<F: Float> BitXor<Bivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self: Rotor<F>, b: Bivector<F>) -> Multivector<F> {
+        Multivector::new(
+            (self.bivector() ^ b).0,
+            Vector::zero(),
+            self.scalar * b,
+            Trivector::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Rotor<F>, Bivector<F>);
 
 // Trivector-Trivector
 // \[ \overset\Rrightarrow{a} \wedge \overset\Rrightarrow{b} = \left <\overset\Rrightarrow{a} \overset\Rrightarrow{b} \right>_4 \]
@@ -383,34 +393,33 @@ impl<F: Float> BitXor<Trivector<F>> for Multivector<F> {
 }
 forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Trivector<F>);
 
-// // Trivector-Rotor
-// // \[ \overset\Rrightarrow{a}\wedge R\]
-// impl<F:Float> BitXor<Rotor<F>> for Trivector<F> {
-//     type Output = Multivector<F>;
-//     fn bitxor(self: Trivector<F>, b: Rotor<F>) -> Multivector<F> {
-//         let scalar = self ^ b.bivector();
-//         let vector = Vector::zero();
-//         let bivector = Bivector::zero();
-//         let trivector = self ^ b.scalar();
-//         Multivector::new(scalar, vector, bivector, trivector)
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitXor, bitxor for Trivector<F>, Rotor<F>);
+// Trivector-Rotor
+// \[ \overset\Rrightarrow{a}\wedge R\]
+impl<F: Float> BitXor<Rotor<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self: Trivector<F>, b: Rotor<F>) -> Multivector<F> {
+        let scalar = (self ^ b.bivector()).0;
+        let vector = Vector::zero();
+        let bivector = Bivector::zero();
+        let trivector = self * b.scalar;
+        Multivector::new(scalar, vector, bivector, trivector)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Trivector<F>, Rotor<F>);
 
-// // Rotor-Trivector
-// // \[ R\wedge \overset\Rrightarrow{b}\]
-// impl<F:Float> BitXor<Trivector<F>> for Rotor<F> {
-//     type Output = Multivector<F>;
-//     fn bitxor(self: Rotor<F>, b: Trivector<F>) -> Multivector<F> {
-//         Multivector::new(
-//             self.bivector() ^ b,
-//             Vector::zero(),
-//             Bivector::zero(),
-//             self.scalar() ^ b,
-//         )
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitXor, bitxor for Rotor<F>, Trivector<F>);
+// Rotor-Trivector
+// \[ R\wedge \overset\Rrightarrow{b}\]
+impl<F: Float> BitXor<Trivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self: Rotor<F>, b: Trivector<F>) -> Multivector<F> {
+        let scalar = (self.bivector() ^ b).0;
+        let vector = Vector::zero();
+        let bivector = Bivector::zero();
+        let trivector = self.scalar * b;
+        Multivector::new(scalar, vector, bivector, trivector)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Rotor<F>, Trivector<F>);
 
 // Multivector-Multivector
 // \[ A \wedge B = \left <A B \right>_{a+b} \]
@@ -424,51 +433,39 @@ impl<F: Float> BitXor for Multivector<F> {
     }
 }
 
-// // Multivector-Rotor
-// // \[ A\wedge R\]
-// impl<F:Float> BitXor<Rotor<F>> for Multivector<F> {
-//     type Output = Multivector<F>;
-//     fn bitxor(self: Multivector<F>, b: Rotor<F>) -> Multivector<F> {
-//         let scalar = (self.scalar() * b.scalar())
-//             + (self.bivector() ^ b.bivector())
-//             + (self.trivector() ^ b.bivector());
-//         let vector = self.vector() ^ b.scalar();
-//         let bivector = (self.bivector() ^ b.scalar()) + (self.scalar() ^ b.bivector());
-//         let trivector = (self.trivector() ^ b.scalar()) + (self.vector() ^ b.bivector());
-//         Multivector::new(scalar, vector, bivector, trivector)
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Rotor<F>);
+// Multivector-Rotor
+// \[ A\wedge R\]
+impl<F: Float> BitXor<Rotor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self: Multivector<F>, b: Rotor<F>) -> Multivector<F> {
+        (self * b.scalar) + (self ^ b.bivector())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Multivector<F>, Rotor<F>);
 
-// // Rotor-Multivector
-// // \[ R\wedge B\]
-// impl<F:Float> BitXor<Multivector<F>> for Rotor<F> {
-//     type Output = Multivector<F>;
-//     fn bitxor(self: Rotor<F>, b: Multivector<F>) -> Multivector<F> {
-//         let scalar = (self.scalar() * b.scalar())
-//             + (self.bivector() ^ b.bivector())
-//             + (self.bivector() ^ b.trivector());
-//         let vector = self.scalar() ^ b.vector();
-//         let bivector = (self.scalar() ^ b.bivector()) + (self.bivector() ^ b.scalar());
-//         let trivector = (self.scalar() ^ b.trivector()) + (self.bivector() ^ b.vector());
-//         Multivector::new(scalar, vector, bivector, trivector)
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitXor, bitxor for Rotor<F>, Multivector<F>);
+// Rotor-Multivector
+// \[ R\wedge B\]
+impl<F: Float> BitXor<Multivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self: Rotor<F>, b: Multivector<F>) -> Multivector<F> {
+        (self.scalar * b) + (self.bivector() ^ b)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Rotor<F>, Multivector<F>);
 
-// // Rotor-Rotor
-// // \[ R_1 \wedge R_2\]
-// impl<F:Float> BitXor for Rotor<F> {
-//     type Output = Multivector<F>;
-//     fn bitxor(self: Rotor<F>, b: Rotor<F>) -> Multivector<F> {
-//         let scalar = (self.scalar() * b.scalar()) + (self.bivector() ^ b.bivector());
-//         let vector = Vector::zero();
-//         let bivector = (self.scalar() ^ b.bivector()) + (self.bivector() ^ b.scalar());
-//         let trivector = Trivector::zero();
-//         Multivector::new(scalar, vector, bivector, trivector)
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitXor, bitxor for Rotor<F>, Rotor<F>);
+// Rotor-Rotor
+// \[ R_1 \wedge R_2\]
+impl<F: Float> BitXor for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitxor(self: Rotor<F>, b: Rotor<F>) -> Multivector<F> {
+        let scalar = (self.scalar * b.scalar) + (self.bivector() ^ b.bivector()).0;
+        let vector = Vector::zero();
+        let bivector = (self.scalar * b.bivector()) + (self.bivector() * b.scalar);
+        let trivector = Trivector::zero();
+        Multivector::new(scalar, vector, bivector, trivector)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitXor, bitxor for Rotor<F>, Rotor<F>);
 
 #[cfg(test)]
 mod exterior_product {
@@ -734,4 +731,238 @@ mod exterior_product {
         assert_relative_eq!(mvec_res.e23(), 59.0, max_relative = 0.000001);
         assert_relative_eq!(mvec_res.e123(), 169.0, max_relative = 0.000001);
     }
+
+    #[test]
+    fn scalar_rotor() {
+        // 3
+        let scalar = Scalar(3.0);
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        let mvec_res = scalar ^ rotor;
+        // 1.5+1.5e12+1.5e31+1.5e23
+        assert_relative_eq!(mvec_res.scalar(), 1.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e12(), 1.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e31(), 1.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e23(), 1.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_scalar() {
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        // 3
+        let scalar = Scalar(3.0);
+        let mvec_res = rotor ^ scalar;
+        // 1.5+1.5e12+1.5e31+1.5e23
+        assert_relative_eq!(mvec_res.scalar(), 1.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e12(), 1.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e31(), 1.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e23(), 1.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_rotor() {
+        // 3e1+5e2+4e3
+        let vector = Vector::new(3.0, 5.0, 4.0);
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        let mvec_res = vector ^ rotor;
+        // 1.5e1+2.5e2+2e3+6e123
+        assert_relative_eq!(mvec_res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e1(), 1.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e2(), 2.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e3(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e12(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e31(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e23(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e123(), 6.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_vector() {
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        // 3e1+5e2+4e3
+        let vector = Vector::new(3.0, 5.0, 4.0);
+        let mvec_res = rotor ^ vector;
+        // 1.5e1+2.5e2+2e3+6e123
+        assert_relative_eq!(mvec_res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e1(), 1.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e2(), 2.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e3(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e12(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e31(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e23(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e123(), 6.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_rotor() {
+        // 2e12+e31+6e23
+        let bivector = Bivector::new(2.0, 1.0, 6.0);
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        let mvec_res = bivector ^ rotor;
+        // e12+0.5e31+3e23
+        assert_relative_eq!(mvec_res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e12(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e31(), 0.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e23(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_bivector() {
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        // 2e12+e31+6e23
+        let bivector = Bivector::new(2.0, 1.0, 6.0);
+        let mvec_res = rotor ^ bivector;
+        // e12+0.5e31+3e23
+        assert_relative_eq!(mvec_res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e12(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e31(), 0.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e23(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn trivector_rotor() {
+        // 2e123
+        let trivector = Trivector::new(2.0);
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        let mvec_res = trivector ^ rotor;
+        // e123
+        assert_relative_eq!(mvec_res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e12(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e31(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e23(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e123(), 1.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_trivector() {
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        // 2e123
+        let trivector = Trivector::new(2.0);
+        let mvec_res = rotor ^ trivector;
+        // e123
+        assert_relative_eq!(mvec_res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e12(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e31(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e23(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e123(), 1.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_rotor() {
+        // ( 5.0 + 8.0e1 + 7.0e2 + 3.0e3 + 2.0e12 + 8.0e31 + 2.0e23 + 1.0e123 )
+        let mvec = Multivector::new_components(5.0, 8.0, 7.0, 3.0, 2.0, 8.0, 2.0, 1.0);
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        let mvec_res = mvec ^ rotor;
+        // 2.5+4e1+3.5e2+1.5e3+3.5e12+6.5e31+3.5e23+9.5e123
+        assert_relative_eq!(mvec_res.scalar(), 2.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e1(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e2(), 3.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e3(), 1.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e12(), 3.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e31(), 6.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e23(), 3.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e123(), 9.5, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_multivector() {
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        // ( 5.0 + 8.0e1 + 7.0e2 + 3.0e3 + 2.0e12 + 8.0e31 + 2.0e23 + 1.0e123 )
+        let mvec = Multivector::new_components(5.0, 8.0, 7.0, 3.0, 2.0, 8.0, 2.0, 1.0);
+        let mvec_res = rotor ^ mvec;
+        // 2.5+4e1+3.5e2+1.5e3+3.5e12+6.5e31+3.5e23+9.5e123
+        assert_relative_eq!(mvec_res.scalar(), 2.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e1(), 4.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e2(), 3.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e3(), 1.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e12(), 3.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e31(), 6.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e23(), 3.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e123(), 9.5, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_rotor() {
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor1 = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        // 0.6+0.8e12
+        let rotor2 = Rotor {
+            scalar: Scalar(0.6),
+            bivector: Bivector::new(0.8, 0.0, 0.0),
+        };
+        let mvec_res = rotor1 ^ rotor2;
+        // 0.3+0.7e12+0.3e31+0.3e23
+        assert_relative_eq!(mvec_res.scalar(), 0.3, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e12(), 0.7, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e31(), 0.3, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e23(), 0.3, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e123(), 0.0, max_relative = 0.000001);
+    }
 }
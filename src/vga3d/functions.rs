@@ -21,7 +21,7 @@
 
 use super::{
     bivector::Bivector, multivector::Multivector, rotor::Rotor, scalar::Scalar,
-    trivector::Trivector, vector::Vector, VGA3DOps, VGA3DOpsRef,
+    trivector::Trivector, vector::Vector, InnerProduct, VGA3DOps, VGA3DOpsRef,
 };
 
 use num_traits::Float;
@@ -247,6 +247,15 @@ pub trait Projectable<T, F: Float> {
     fn try_project(self, target: T) -> Option<Self::Output>
     where
         T: VGA3DOps<F> + Copy;
+
+    /// Projection with a chosen inner product `P`
+    /// $$ A_\parallel = (A \rfloor B)B^{-1} $$
+    fn try_project_with<P>(self, target: T) -> Option<Self::Output>
+    where
+        T: VGA3DOps<F> + Copy,
+        P: InnerProduct<F, Self, T>,
+        Multivector<F>: core::ops::Mul<T, Output = Multivector<F>>,
+        Self: Sized;
 }
 
 // For vectors
@@ -264,6 +273,15 @@ where
             Some(b_inverse) => Some(((self | b) * (b_inverse)).vector()),
         }
     }
+
+    fn try_project_with<P>(self, b: T) -> Option<Self::Output>
+    where
+        P: InnerProduct<F, Self, T>,
+        Multivector<F>: core::ops::Mul<T, Output = Multivector<F>>,
+    {
+        b.try_inverse()
+            .map(|b_inverse| (P::product(self, b) * b_inverse).vector())
+    }
 }
 
 impl<T, F: Float> Projectable<T, F> for Bivector<F>
@@ -280,6 +298,15 @@ where
             Some(b_inverse) => Some(((self | b) * (b_inverse)).bivector()),
         }
     }
+
+    fn try_project_with<P>(self, b: T) -> Option<Self::Output>
+    where
+        P: InnerProduct<F, Self, T>,
+        Multivector<F>: core::ops::Mul<T, Output = Multivector<F>>,
+    {
+        b.try_inverse()
+            .map(|b_inverse| (P::product(self, b) * b_inverse).bivector())
+    }
 }
 
 impl<T, F: Float> Projectable<T, F> for Trivector<F>
@@ -296,6 +323,15 @@ where
             Some(b_inverse) => Some(((self | b) * (b_inverse)).trivector()),
         }
     }
+
+    fn try_project_with<P>(self, b: T) -> Option<Self::Output>
+    where
+        P: InnerProduct<F, Self, T>,
+        Multivector<F>: core::ops::Mul<T, Output = Multivector<F>>,
+    {
+        b.try_inverse()
+            .map(|b_inverse| (P::product(self, b) * b_inverse).trivector())
+    }
 }
 
 impl<T, F: Float> Projectable<T, F> for Multivector<F>
@@ -313,14 +349,68 @@ where
             Some(b_inverse) => Some(((self | b) * (b_inverse)).multivector()),
         }
     }
+
+    fn try_project_with<P>(self, b: T) -> Option<Self::Output>
+    where
+        P: InnerProduct<F, Self, T>,
+        Multivector<F>: core::ops::Mul<T, Output = Multivector<F>>,
+    {
+        b.try_inverse()
+            .map(|b_inverse| (P::product(self, b) * b_inverse).multivector())
+    }
 }
 
 #[cfg(test)]
 mod projection {
     use super::*;
+    use crate::vga3d::{Dual, LeftContraction, RightContraction};
     use approx::assert_relative_eq;
     use core::f32::consts::TAU;
 
+    #[test]
+    fn vector_bivector_left_contraction() {
+        // 2e1+3e3
+        let vector = Vector::new(2.0, 0.0, 3.0);
+        // -2e12+4e23
+        let bivector = Bivector::new(-2.0, 0.0, 4.0);
+        // 1.6e1+3.2e3
+        let res = match vector.try_project_with::<LeftContraction>(bivector) {
+            None => vector,
+            Some(vec_res) => vec_res,
+        };
+
+        assert_relative_eq!(res.e1(), 1.6, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 3.2, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_vector_contraction() {
+        let bivector = Bivector::new(-2.0, 5.0, -4.0);
+        let vector = Vector::new(3.0, 4.0, 0.0);
+        // The left contraction of a bivector onto a vector is zero
+        let left = match bivector.try_project_with::<LeftContraction>(vector) {
+            None => bivector,
+            Some(bivector_res) => bivector_res,
+        };
+        assert_relative_eq!(left.e12(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(left.e31(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(left.e23(), 0.0, max_relative = 0.000001);
+
+        // The right contraction agrees with the fat dot
+        let right = match bivector.try_project_with::<RightContraction>(vector) {
+            None => bivector,
+            Some(bivector_res) => bivector_res,
+        };
+        let fat = match bivector.try_project(vector) {
+            None => bivector,
+            Some(bivector_res) => bivector_res,
+        };
+        assert_relative_eq!(right.e12(), fat.e12(), max_relative = 0.000001);
+        assert_relative_eq!(right.e31(), fat.e31(), max_relative = 0.000001);
+        assert_relative_eq!(right.e23(), fat.e23(), max_relative = 0.000001);
+    }
+
     #[test]
     fn vector_vector() {
         let vector1 = Vector::new(2.0, 0.0, 3.0);
@@ -415,6 +505,15 @@ pub trait Rejectable<T, F: Float> {
     fn try_reject(self, target: T) -> Option<Self::Output>
     where
         T: VGA3DOps<F> + Copy;
+
+    /// Rejection with a chosen inner product `P`
+    /// $$ A_\perp = A - (A \rfloor B)B^{-1} $$
+    fn try_reject_with<P>(self, target: T) -> Option<Self::Output>
+    where
+        T: VGA3DOps<F> + Copy,
+        P: InnerProduct<F, Self, T>,
+        Multivector<F>: core::ops::Mul<T, Output = Multivector<F>>,
+        Self: Sized;
 }
 
 // For vectors
@@ -432,6 +531,15 @@ where
             Some(b_inverse) => Some(((self ^ b) * (b_inverse)).vector()),
         }
     }
+
+    fn try_reject_with<P>(self, b: T) -> Option<Self::Output>
+    where
+        P: InnerProduct<F, Self, T>,
+        Multivector<F>: core::ops::Mul<T, Output = Multivector<F>>,
+    {
+        b.try_inverse()
+            .map(|b_inverse| self - (P::product(self, b) * b_inverse).vector())
+    }
 }
 
 impl<T, F: Float> Rejectable<T, F> for Bivector<F>
@@ -448,6 +556,15 @@ where
             Some(b_inverse) => Some(((self ^ b) * b_inverse).bivector()),
         }
     }
+
+    fn try_reject_with<P>(self, b: T) -> Option<Self::Output>
+    where
+        P: InnerProduct<F, Self, T>,
+        Multivector<F>: core::ops::Mul<T, Output = Multivector<F>>,
+    {
+        b.try_inverse()
+            .map(|b_inverse| self - (P::product(self, b) * b_inverse).bivector())
+    }
 }
 
 impl<T, F: Float> Rejectable<T, F> for Trivector<F>
@@ -464,6 +581,15 @@ where
             Some(b_inverse) => Some(((self ^ b) * b_inverse).trivector()),
         }
     }
+
+    fn try_reject_with<P>(self, b: T) -> Option<Self::Output>
+    where
+        P: InnerProduct<F, Self, T>,
+        Multivector<F>: core::ops::Mul<T, Output = Multivector<F>>,
+    {
+        b.try_inverse()
+            .map(|b_inverse| self - (P::product(self, b) * b_inverse).trivector())
+    }
 }
 
 impl<T, F: Float> Rejectable<T, F> for Multivector<F>
@@ -481,14 +607,53 @@ where
             Some(b_inverse) => Some(((self ^ b) * b_inverse).multivector()),
         }
     }
+
+    fn try_reject_with<P>(self, b: T) -> Option<Self::Output>
+    where
+        P: InnerProduct<F, Self, T>,
+        Multivector<F>: core::ops::Mul<T, Output = Multivector<F>>,
+    {
+        b.try_inverse()
+            .map(|b_inverse| self - (P::product(self, b) * b_inverse).multivector())
+    }
 }
 
 #[cfg(test)]
 mod rejection {
     use super::*;
+    use crate::vga3d::{FatDot, LeftContraction};
     use approx::assert_relative_eq;
     use core::f32::consts::TAU;
 
+    #[test]
+    fn vector_bivector_contraction() {
+        // 2e1+3e3
+        let vector = Vector::new(2.0, 0.0, 3.0);
+        // -2e12+4e23
+        let bivector = Bivector::new(-2.0, 0.0, 4.0);
+        // 0.4e1-0.2e3
+        let left = match vector.try_reject_with::<LeftContraction>(bivector) {
+            None => vector,
+            Some(vec_res) => vec_res,
+        };
+        let fat = match vector.try_reject_with::<FatDot>(bivector) {
+            None => vector,
+            Some(vec_res) => vec_res,
+        };
+        let exterior = match vector.try_reject(bivector) {
+            None => vector,
+            Some(vec_res) => vec_res,
+        };
+
+        assert_relative_eq!(left.e1(), 0.4, max_relative = 0.000001);
+        assert_relative_eq!(left.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(left.e3(), -0.2, max_relative = 0.000001);
+        assert_relative_eq!(fat.e1(), 0.4, max_relative = 0.000001);
+        assert_relative_eq!(fat.e3(), -0.2, max_relative = 0.000001);
+        assert_relative_eq!(exterior.e1(), 0.4, max_relative = 0.000001);
+        assert_relative_eq!(exterior.e3(), -0.2, max_relative = 0.000001);
+    }
+
     // #[test]
     // fn vector_vector() {
     //     let vector1 = Vector::new(2.0, 0.0, 3.0);
@@ -417,7 +417,7 @@ impl<F: Float> Mul<Bivector<F>> for Multivector<F> {
                 self.scalar() * b.e31() + self.e23() * b.e12() - self.e12() * b.e23(),
                 self.scalar() * b.e23() + self.e12() * b.e31() - self.e31() * b.e12(),
             ),
-            Trivector::new(self.e3() * b.e23() + self.e2() * b.e31() + self.e1() * b.e23()),
+            Trivector::new(self.e3() * b.e12() + self.e2() * b.e31() + self.e1() * b.e23()),
         )
     }
 }
@@ -0,0 +1,126 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{bivector::Bivector, rotor::Rotor, scalar::Scalar, vector::Vector};
+
+use glam::{DQuat, DVec3, Quat, Vec3};
+
+// glam is not generic over the float, the conversions are implemented for f32 and f64
+macro_rules! impl_glam {
+    ($float:ty, $vec3:ty, $quat:ty) => {
+        // Vector
+        // \[ \vec{v} = v_1\mathrm{e}_1 + v_2\mathrm{e}_2 + v_3\mathrm{e}_3 \]
+        impl From<Vector<$float>> for $vec3 {
+            fn from(vector: Vector<$float>) -> Self {
+                <$vec3>::new(vector.e1(), vector.e2(), vector.e3())
+            }
+        }
+
+        impl From<$vec3> for Vector<$float> {
+            fn from(vector: $vec3) -> Self {
+                Vector::new(vector.x, vector.y, vector.z)
+            }
+        }
+
+        // Bivector
+        // The axial vector of the bivector
+        // \[ -\overset\Rightarrow{b}\star = b_{23}\mathrm{e}_1 + b_{31}\mathrm{e}_2 + b_{12}\mathrm{e}_3 \]
+        impl From<Bivector<$float>> for $vec3 {
+            fn from(bivector: Bivector<$float>) -> Self {
+                <$vec3>::new(bivector.e23(), bivector.e31(), bivector.e12())
+            }
+        }
+
+        impl From<$vec3> for Bivector<$float> {
+            fn from(vector: $vec3) -> Self {
+                Bivector::new(vector.z, vector.y, vector.x)
+            }
+        }
+
+        // Rotor
+        // The unit quaternion with the axis of the rotor
+        // \[ q = s + b_{23}\mathrm{i} + b_{31}\mathrm{j} + b_{12}\mathrm{k} \]
+        impl From<Rotor<$float>> for $quat {
+            fn from(rotor: Rotor<$float>) -> Self {
+                <$quat>::from_xyzw(rotor.e23(), rotor.e31(), rotor.e12(), rotor.scalar())
+            }
+        }
+
+        impl From<$quat> for Rotor<$float> {
+            fn from(quaternion: $quat) -> Self {
+                let [x, y, z, w] = quaternion.to_array();
+                Rotor {
+                    scalar: Scalar(w),
+                    bivector: Bivector::new(z, y, x),
+                }
+            }
+        }
+    };
+}
+
+impl_glam!(f32, Vec3, Quat);
+impl_glam!(f64, DVec3, DQuat);
+
+#[cfg(test)]
+mod glam_into {
+    use super::*;
+    use crate::vga3d::Rotatable;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn vector() {
+        let vector = Vector::new(3.0, 5.0, 4.0);
+        let res = Vec3::from(vector);
+        assert_eq!(res, Vec3::new(3.0, 5.0, 4.0));
+        assert_eq!(Vector::from(res), vector);
+        let vector = Vector::new(3.0_f64, 5.0, 4.0);
+        assert_eq!(Vector::from(DVec3::from(vector)), vector);
+    }
+    #[test]
+    fn bivector() {
+        // The e12 plane has the axis e3
+        let bivector = Bivector::new(2.0, 1.0, 6.0);
+        let res = Vec3::from(bivector);
+        assert_eq!(res, Vec3::new(6.0, 1.0, 2.0));
+        assert_eq!(Bivector::from(res), bivector);
+    }
+    #[test]
+    fn quat() {
+        // The rotated vector is the same for the rotor and the quaternion
+        let rotor = Bivector::new(0.3, -0.2, 0.5).exp();
+        let vector = Vector::new(3.0, 5.0, 4.0);
+        let quaternion = Quat::from(rotor);
+        let res = Vector::from(quaternion * Vec3::from(vector));
+        let rotated = vector.rotate(rotor);
+        assert_relative_eq!(res.e1(), rotated.e1(), max_relative = 0.00001);
+        assert_relative_eq!(res.e2(), rotated.e2(), max_relative = 0.00001);
+        assert_relative_eq!(res.e3(), rotated.e3(), max_relative = 0.00001);
+        assert_eq!(Rotor::from(quaternion), rotor);
+    }
+    #[test]
+    fn dquat() {
+        // A quarter turn around e3 is the rotor in the e12 plane
+        let quaternion = DQuat::from_rotation_z(core::f64::consts::TAU / 4.0);
+        let rotor = Rotor::from(quaternion);
+        let res = Vector::new(1.0, 0.0, 0.0).rotate(rotor);
+        assert_relative_eq!(res.e1(), 0.0, epsilon = 0.000001);
+        assert_relative_eq!(res.e2(), 1.0, epsilon = 0.000001);
+        assert_relative_eq!(res.e3(), 0.0, epsilon = 0.000001);
+        assert_eq!(DQuat::from(rotor), quaternion);
+    }
+}
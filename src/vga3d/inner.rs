@@ -123,25 +123,35 @@ use core::ops::BitOr;
 // }
 // forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector, F);
 
-// // Scalar-Rotor
-// // \[ s \cdot R\]
-// impl<F:Float> BitOr<Rotor> for F {
-//     type Output = Multivector;
-//     fn bitor(self: F, b: Rotor) -> Multivector {
-//         self * b
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitOr, bitor for F, Rotor);
+// Scalar-Rotor
+// \[ s \cdot R\]
+impl<F: Float> BitOr<Rotor<F>> for Scalar<F> {
+    type Output = Multivector<F>;
+    fn bitor(self: Scalar<F>, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            self.0 * b.scalar(),
+            Vector::zero(),
+            self * b.bivector(),
+            Trivector::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Scalar<F>, Rotor<F>);
 
-// //Rotor-Scalar
-// // \[ R \cdot b\]
-// impl<F:Float> BitOr<F> for Rotor {
-//     type Output = Multivector;
-//     fn bitor(self: Rotor, b: F) -> Multivector {
-//         self * b
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitOr, bitor for Rotor, F);
+// Rotor-Scalar
+// \[ R \cdot b\]
+impl<F: Float> BitOr<Scalar<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self: Rotor<F>, b: Scalar<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar() * b.0,
+            Vector::zero(),
+            self.bivector() * b,
+            Trivector::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Rotor<F>, Scalar<F>);
 
 // Vector-Vector
 // \[ \vec{u} \cdot \vec{v} = u_1 \cdot v_1 + u_2 \cdot v_2 + u_3 \cdot v_3 \]
@@ -229,35 +239,35 @@ impl<F: Float> BitOr<Vector<F>> for Multivector<F> {
 }
 forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Vector<F>);
 
-// // Vector-Rotor
-// // \[ \vec{a} \cdot R\]
-// impl<F:Float> BitOr<Rotor> for Vector<F> {
-//     type Output = Multivector<F>;
-//     fn bitor(self: Vector<F>, b: Rotor) -> Multivector<F> {
-//         Multivector::new(
-//             F::zero(),
-//             (self * b.scalar()) + (self | b.bivector()),
-//             Bivector::zero(),
-//             Trivector::zero(),
-//         )
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitOr, bitor for Vector<F>, Rotor);
-
-// // Rotor-Vector
-// // \[ R \cdot \vec{b}\]
-// impl<F:Float> BitOr<Vector<F>> for Rotor {
-//     type Output = Multivector<F>;
-//     fn bitor(self: Rotor, b: Vector<F>) -> Multivector<F> {
-//         Multivector::new(
-//             F::zero(),
-//             (self.scalar() * b) + (self.bivector() | b),
-//             Bivector::zero(),
-//             Trivector::zero(),
-//         )
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitOr, bitor for Rotor, Vector<F>);
+// Vector-Rotor
+// \[ \vec{a} \cdot R\]
+impl<F: Float> BitOr<Rotor<F>> for Vector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self: Vector<F>, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            (self * b.scalar) + (self | b.bivector()),
+            Bivector::zero(),
+            Trivector::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Vector<F>, Rotor<F>);
+
+// Rotor-Vector
+// \[ R \cdot \vec{b}\]
+impl<F: Float> BitOr<Vector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self: Rotor<F>, b: Vector<F>) -> Multivector<F> {
+        Multivector::new(
+            F::zero(),
+            (self.scalar * b) + (self.bivector() | b),
+            Bivector::zero(),
+            Trivector::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Rotor<F>, Vector<F>);
 
 // Bivector-Bivector
 // \[ \overset\Rightarrow{a} \cdot \overset\Rightarrow{b} = \left <\overset\Rightarrow{a} \overset\Rightarrow{b} \right>_0 \]
@@ -327,33 +337,33 @@ impl<F: Float> BitOr<Bivector<F>> for Multivector<F> {
 }
 forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Bivector<F>);
 
-// // Bivector-Rotor
-// // \[ \overset\Rightarrow{a} \cdot R\]
-// impl<F:Float> BitOr<Rotor> for Bivector<F> {
-//     type Output = Multivector<F>;
-//     fn bitor(self: Bivector<F>, b: Rotor) -> Multivector<F> {
-//         let scalar = self | b.bivector();
-//         let vector = Vector::zero();
-//         let bivector = self | b.scalar();
-//         let trivector = Trivector::zero();
-//         Multivector::new(scalar, vector, bivector, trivector)
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitOr, bitor for Bivector<F>, Rotor);
-
-// // Rotor-Bivector
-// // \[ R \cdot \overset\Rightarrow{b}\]
-// impl<F:Float> BitOr<Bivector<F>> for Rotor {
-//     type Output = Multivector<F>;
-//     fn bitor(self: Rotor, b: Bivector<F>) -> Multivector<F> {
-//         let scalar = self.bivector() | b;
-//         let vector = Vector::zero();
-//         let bivector = self.scalar() | b;
-//         let trivector = Trivector::zero();
-//         Multivector::new(scalar, vector, bivector, trivector)
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitOr, bitor for Rotor, Bivector<F>);
+// Bivector-Rotor
+// \[ \overset\Rightarrow{a} \cdot R\]
+impl<F: Float> BitOr<Rotor<F>> for Bivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self: Bivector<F>, b: Rotor<F>) -> Multivector<F> {
+        let scalar = (self | b.bivector()).0;
+        let vector = Vector::zero();
+        let bivector = self * b.scalar;
+        let trivector = Trivector::zero();
+        Multivector::new(scalar, vector, bivector, trivector)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Bivector<F>, Rotor<F>);
+
+// Rotor-Bivector
+// \[ R \cdot \overset\Rightarrow{b}\]
+impl<F: Float> BitOr<Bivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self: Rotor<F>, b: Bivector<F>) -> Multivector<F> {
+        let scalar = (self.bivector() | b).0;
+        let vector = Vector::zero();
+        let bivector = self.scalar * b;
+        let trivector = Trivector::zero();
+        Multivector::new(scalar, vector, bivector, trivector)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Rotor<F>, Bivector<F>);
 
 // Trivector-Trivector
 // In 3D there the geometric product of two trivectors is there inner product
@@ -395,33 +405,33 @@ impl<F: Float> BitOr<Trivector<F>> for Multivector<F> {
 }
 forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Trivector<F>);
 
-// // Trivector-Rotor
-// // \[ \overset\Rrightarrow{a} \cdot R\]
-// impl<F:Float> BitOr<Rotor> for Trivector<F> {
-//     type Output = Multivector<F>;
-//     fn bitor(self: Trivector<F>, b: Rotor) -> Multivector<F> {
-//         let scalar = F::zero();
-//         let vector = self | b.bivector();
-//         let bivector = Bivector::zero();
-//         let trivector = self | b.scalar();
-//         Multivector::new(scalar, vector, bivector, trivector)
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitOr, bitor for Trivector<F>, Rotor);
-
-// // Rotor-Trivector
-// // \[ R \cdot \overset\Rrightarrow{b}\]
-// impl<F:Float> BitOr<Trivector<F>> for Rotor {
-//     type Output = Multivector<F>;
-//     fn bitor(self: Rotor, b: Trivector<F>) -> Multivector<F> {
-//         let scalar = F::zero();
-//         let vector = self.bivector() | b;
-//         let bivector = Bivector::zero();
-//         let trivector = self.scalar() | b;
-//         Multivector::new(scalar, vector, bivector, trivector)
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitOr, bitor for Rotor, Trivector<F>);
+// Trivector-Rotor
+// \[ \overset\Rrightarrow{a} \cdot R\]
+impl<F: Float> BitOr<Rotor<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self: Trivector<F>, b: Rotor<F>) -> Multivector<F> {
+        let scalar = F::zero();
+        let vector = self | b.bivector();
+        let bivector = Bivector::zero();
+        let trivector = self * b.scalar;
+        Multivector::new(scalar, vector, bivector, trivector)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Trivector<F>, Rotor<F>);
+
+// Rotor-Trivector
+// \[ R \cdot \overset\Rrightarrow{b}\]
+impl<F: Float> BitOr<Trivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self: Rotor<F>, b: Trivector<F>) -> Multivector<F> {
+        let scalar = F::zero();
+        let vector = self.bivector() | b;
+        let bivector = Bivector::zero();
+        let trivector = self.scalar * b;
+        Multivector::new(scalar, vector, bivector, trivector)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Rotor<F>, Trivector<F>);
 
 // Multivector-Multivector
 // // \[ A \cdot B = \left <A B \right>_{|a-b|} \]
@@ -436,51 +446,39 @@ impl<F: Float> BitOr for Multivector<F> {
 }
 forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Multivector<F>);
 
-// // Multivector-Rotor
-// // \[ A \cdot R\]
-// impl<F:Float> BitOr<Rotor> for Multivector {
-//     type Output = Multivector;
-//     fn bitor(self: Multivector, b: Rotor) -> Multivector {
-//         let scalar = (self.scalar() * b.scalar()) + (self.bivector() | b.bivector());
-//         let vector = (self.vector() | b.scalar())
-//             + (self.vector() | b.bivector())
-//             + (self.trivector() | b.bivector());
-//         let bivector = (self.bivector() | b.scalar()) + (self.scalar() | b.bivector());
-//         let trivector = self.trivector() | b.scalar();
-//         Multivector::new(scalar, vector, bivector, trivector)
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector, Rotor);
+// Multivector-Rotor
+// \[ A \cdot R\]
+impl<F: Float> BitOr<Rotor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitor(self: Multivector<F>, b: Rotor<F>) -> Multivector<F> {
+        (self * b.scalar) + (self | b.bivector())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Multivector<F>, Rotor<F>);
 
-// // Rotor-Multivector
-// // \[ R \cdot B\]
-// impl<F:Float> BitOr<Multivector> for Rotor {
-//     type Output = Multivector;
-//     fn bitor(self: Rotor, b: Multivector) -> Multivector {
-//         let scalar = (self.scalar() * b.scalar()) + (self.bivector() | b.bivector());
-//         let vector = (self.scalar() ^ b.vector())
-//             + (self.bivector() | b.vector())
-//             + (self.bivector() | b.trivector());
-//         let bivector = (self.scalar() | b.bivector()) + (self.bivector() | b.scalar());
-//         let trivector = self.scalar() | b.trivector();
-//         Multivector::new(scalar, vector, bivector, trivector)
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitOr, bitor for Rotor, Multivector);
+// Rotor-Multivector
+// \[ R \cdot B\]
+impl<F: Float> BitOr<Multivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self: Rotor<F>, b: Multivector<F>) -> Multivector<F> {
+        (self.scalar * b) + (self.bivector() | b)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Rotor<F>, Multivector<F>);
 
-// // Inner Product
-// // \[ R_1 \cdot R_2\]
-// impl<F:Float> BitOr for Rotor {
-//     type Output = Multivector;
-//     fn bitor(self: Rotor, b: Rotor) -> Multivector {
-//         let scalar = (self.scalar() * b.scalar()) + (self.bivector() | b.bivector());
-//         let vector = Vector::zero();
-//         let bivector = (self.scalar() | b.bivector()) + (self.bivector() | b.scalar());
-//         let trivector = Trivector::zero();
-//         Multivector::new(scalar, vector, bivector, trivector)
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitOr, bitor for Rotor, Rotor);
+// Rotor-Rotor
+// \[ R_1 \cdot R_2\]
+impl<F: Float> BitOr for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitor(self: Rotor<F>, b: Rotor<F>) -> Multivector<F> {
+        let scalar = (self.scalar * b.scalar) + (self.bivector() | b.bivector()).0;
+        let vector = Vector::zero();
+        let bivector = (self.scalar * b.bivector()) + (self.bivector() * b.scalar);
+        let trivector = Trivector::zero();
+        Multivector::new(scalar, vector, bivector, trivector)
+    }
+}
+forward_ref_binop!(impl<F:Float> BitOr, bitor for Rotor<F>, Rotor<F>);
 
 #[cfg(test)]
 mod inner_product {
@@ -734,4 +732,238 @@ mod inner_product {
         assert_relative_eq!(mvec_res.e23(), 61.0, max_relative = 0.000001);
         assert_relative_eq!(mvec_res.e123(), 44.0, max_relative = 0.000001);
     }
+
+    #[test]
+    fn scalar_rotor() {
+        // 3
+        let scalar = Scalar(3.0);
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        let mvec_res = scalar | rotor;
+        // 1.5+1.5e12+1.5e31+1.5e23
+        assert_relative_eq!(mvec_res.scalar(), 1.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e12(), 1.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e31(), 1.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e23(), 1.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_scalar() {
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        // 3
+        let scalar = Scalar(3.0);
+        let mvec_res = rotor | scalar;
+        // 1.5+1.5e12+1.5e31+1.5e23
+        assert_relative_eq!(mvec_res.scalar(), 1.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e12(), 1.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e31(), 1.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e23(), 1.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_rotor() {
+        // 3e1+5e2+4e3
+        let vector = Vector::new(3.0, 5.0, 4.0);
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        let mvec_res = vector | rotor;
+        // e1+2e2+3e3
+        assert_relative_eq!(mvec_res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e1(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e2(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e3(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e12(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e31(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e23(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_vector() {
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        // 3e1+5e2+4e3
+        let vector = Vector::new(3.0, 5.0, 4.0);
+        let mvec_res = rotor | vector;
+        // 2e1+3e2+e3
+        assert_relative_eq!(mvec_res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e1(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e2(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e3(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e12(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e31(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e23(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_rotor() {
+        // 2e12+e31+6e23
+        let bivector = Bivector::new(2.0, 1.0, 6.0);
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        let mvec_res = bivector | rotor;
+        // -4.5+e12+0.5e31+3e23
+        assert_relative_eq!(mvec_res.scalar(), -4.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e12(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e31(), 0.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e23(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_bivector() {
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        // 2e12+e31+6e23
+        let bivector = Bivector::new(2.0, 1.0, 6.0);
+        let mvec_res = rotor | bivector;
+        // -4.5+e12+0.5e31+3e23
+        assert_relative_eq!(mvec_res.scalar(), -4.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e12(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e31(), 0.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e23(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn trivector_rotor() {
+        // 2e123
+        let trivector = Trivector::new(2.0);
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        let mvec_res = trivector | rotor;
+        // -e1-e2-e3+e123
+        assert_relative_eq!(mvec_res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e1(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e2(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e3(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e12(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e31(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e23(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e123(), 1.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_trivector() {
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        // 2e123
+        let trivector = Trivector::new(2.0);
+        let mvec_res = rotor | trivector;
+        // -e1-e2-e3+e123
+        assert_relative_eq!(mvec_res.scalar(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e1(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e2(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e3(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e12(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e31(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e23(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e123(), 1.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_rotor() {
+        // ( 5.0 + 8.0e1 + 7.0e2 + 3.0e3 + 2.0e12 + 8.0e31 + 2.0e23 + 1.0e123 )
+        let mvec = Multivector::new_components(5.0, 8.0, 7.0, 3.0, 2.0, 8.0, 2.0, 1.0);
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        let mvec_res = mvec | rotor;
+        // -3.5+1.5e1+5.5e2+0.5e3+3.5e12+6.5e31+3.5e23+0.5e123
+        assert_relative_eq!(mvec_res.scalar(), -3.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e1(), 1.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e2(), 5.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e3(), 0.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e12(), 3.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e31(), 6.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e23(), 3.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e123(), 0.5, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_multivector() {
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        // ( 5.0 + 8.0e1 + 7.0e2 + 3.0e3 + 2.0e12 + 8.0e31 + 2.0e23 + 1.0e123 )
+        let mvec = Multivector::new_components(5.0, 8.0, 7.0, 3.0, 2.0, 8.0, 2.0, 1.0);
+        let mvec_res = rotor | mvec;
+        // -3.5+5.5e1+0.5e2+1.5e3+3.5e12+6.5e31+3.5e23+0.5e123
+        assert_relative_eq!(mvec_res.scalar(), -3.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e1(), 5.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e2(), 0.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e3(), 1.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e12(), 3.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e31(), 6.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e23(), 3.5, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e123(), 0.5, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_rotor() {
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor1 = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        // 0.6+0.8e12
+        let rotor2 = Rotor {
+            scalar: Scalar(0.6),
+            bivector: Bivector::new(0.8, 0.0, 0.0),
+        };
+        let mvec_res = rotor1 | rotor2;
+        // -0.1+0.7e12+0.3e31+0.3e23
+        assert_relative_eq!(mvec_res.scalar(), -0.1, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e12(), 0.7, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e31(), 0.3, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e23(), 0.3, max_relative = 0.000001);
+        assert_relative_eq!(mvec_res.e123(), 0.0, max_relative = 0.000001);
+    }
 }
@@ -0,0 +1,323 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{VGA3DOps, bivector::Bivector, rotor::Rotor, scalar::Scalar};
+
+use num_traits::Float;
+
+impl<F: Float> Rotor<F> {
+    /// The scalar product of the rotors seen as 4D unit vectors
+    /// $$ \left< A^\dag B \right>_0 $$
+    fn dot(self, b: Rotor<F>) -> F {
+        self.scalar() * b.scalar()
+            + self.e12() * b.e12()
+            + self.e31() * b.e31()
+            + self.e23() * b.e23()
+    }
+
+    /// # Shortest Path
+    /// $R$ and $-R$ is the same rotation.
+    /// Returns the one of $\pm B$ closest to the rotor, so the interpolation takes the shortest path.
+    pub fn closest(self, b: Rotor<F>) -> Rotor<F> {
+        match self.dot(b) {
+            dot if dot < F::zero() => Rotor {
+                scalar: Scalar(-b.scalar()),
+                bivector: -b.bivector(),
+            },
+            _ => b,
+        }
+    }
+
+    // Slerp without the shortest path
+    // \[ A \left( A^\dag B \right)^t \]
+    fn slerp_unchecked(self, b: Rotor<F>, t: F) -> Rotor<F> {
+        let difference = self.reverse() * b;
+        self * (difference.log() * Scalar(t)).exp()
+    }
+
+    /// # Spherical Linear Interpolation
+    /// Interpolates with constant angular velocity from the rotor at $t=0$ to $B$ at $t=1$.
+    /// $$ \operatorname{slerp}(A,B,t) = A \left( A^\dag B \right)^t = A\mathrm{e}^{t \log \left( A^\dag B \right)} $$
+    /// The shortest path is used.
+    pub fn slerp(self, b: Rotor<F>, t: F) -> Rotor<F> {
+        self.slerp_unchecked(self.closest(b), t)
+    }
+
+    /// # Normalized Linear Interpolation
+    /// Interpolates linearly and normalizes the result.
+    /// The angular velocity is not constant, but it is cheaper than slerp.
+    /// $$ \operatorname{nlerp}(A,B,t) = \frac{(1-t)A + tB}{\left| (1-t)A + tB \right|} $$
+    /// The shortest path is used.
+    pub fn nlerp(self, b: Rotor<F>, t: F) -> Rotor<F> {
+        let b = self.closest(b);
+        let s = F::one() - t;
+        let scalar = self.scalar() * s + b.scalar() * t;
+        let bivector = self.bivector() * Scalar(s) + b.bivector() * Scalar(t);
+        let norm = (scalar * scalar
+            + bivector.e12() * bivector.e12()
+            + bivector.e31() * bivector.e31()
+            + bivector.e23() * bivector.e23())
+        .sqrt();
+        match Scalar(norm).try_inverse() {
+            None => self,
+            Some(norm_inverse) => Rotor {
+                scalar: Scalar(scalar * norm_inverse.0),
+                bivector: bivector * norm_inverse,
+            },
+        }
+    }
+
+    /// # Squad Control Point
+    /// The inner control point of the key rotor $R_i$ for spherical quadrangle interpolation.
+    /// $$ S_i = R_i \mathrm{e}^{-\frac{\log \left( R_i^\dag R_{i+1} \right) + \log \left( R_i^\dag R_{i-1} \right)}{4}} $$
+    pub fn squad_control_point(previous: Rotor<F>, current: Rotor<F>, next: Rotor<F>) -> Rotor<F> {
+        let four = F::one() + F::one() + F::one() + F::one();
+        let current_reverse = current.reverse();
+        let log_next = (current_reverse * current.closest(next)).log();
+        let log_previous = (current_reverse * current.closest(previous)).log();
+        current * ((log_next + log_previous) * Scalar(-F::one() / four)).exp()
+    }
+
+    /// # Spherical Quadrangle Interpolation
+    /// Interpolates from the rotor at $t=0$ to $B$ at $t=1$ with the control points $S_A$ and $S_B$.
+    /// $$ \operatorname{squad}(A,S_A,S_B,B,t) = \operatorname{slerp}\left(\operatorname{slerp}(A,B,t),\operatorname{slerp}(S_A,S_B,t),2t(1-t)\right) $$
+    pub fn squad(self, control_a: Rotor<F>, control_b: Rotor<F>, b: Rotor<F>, t: F) -> Rotor<F> {
+        let two = F::one() + F::one();
+        let path = self.slerp_unchecked(b, t);
+        let control = control_a.slerp_unchecked(control_b, t);
+        path.slerp_unchecked(control, two * t * (F::one() - t))
+    }
+}
+
+/// # Rotor Spline
+/// A cubic spline through a sequence of key rotors.
+/// The keys are placed at $t = 0, 1, \ldots, n-1$.
+/// The shortest path between neighbouring keys is always used.
+///
+/// - `squad` interpolates the keys and is $C^1$ continuous.
+/// - `b_spline` is the cumulative cubic B-spline. It is $C^2$ continuous but only approximates the keys.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RotorSpline<'a, F: Float> {
+    keys: &'a [Rotor<F>],
+}
+
+impl<'a, F: Float> RotorSpline<'a, F> {
+    /// Tries to create a spline through the keys.
+    /// At least two keys are needed.
+    pub fn try_new(keys: &'a [Rotor<F>]) -> Option<Self> {
+        match keys.len() {
+            len if len < 2 => None,
+            _ => Some(RotorSpline { keys }),
+        }
+    }
+
+    /// The key rotors of the spline
+    pub fn keys(&self) -> &'a [Rotor<F>] {
+        self.keys
+    }
+
+    // The key at index, the ends are repeated
+    fn key(&self, index: isize) -> Rotor<F> {
+        let last = self.keys.len() as isize - 1;
+        self.keys[index.clamp(0, last) as usize]
+    }
+
+    // The segment and the local parameter of the segment
+    // t is clamped to [0, n-1]
+    fn segment(&self, t: F) -> (isize, F) {
+        let last = self.keys.len() - 1;
+        let t = t.max(F::zero()).min(F::from(last).unwrap());
+        let index = t.floor().to_usize().unwrap_or(0).min(last - 1);
+        (index as isize, t - F::from(index).unwrap())
+    }
+
+    /// # Squad Spline
+    /// The rotor at $t$, passing through the key $R_i$ at $t=i$.
+    pub fn squad(&self, t: F) -> Rotor<F> {
+        let (index, u) = self.segment(t);
+
+        // Align the keys of the segment to the shortest path
+        let current = self.key(index);
+        let previous = current.closest(self.key(index - 1));
+        let next = current.closest(self.key(index + 1));
+        let after = next.closest(self.key(index + 2));
+
+        let control_current = Rotor::squad_control_point(previous, current, next);
+        let control_next = Rotor::squad_control_point(current, next, after);
+        current.squad(control_current, control_next, next, u)
+    }
+
+    /// # Cumulative Cubic B-Spline
+    /// The rotor at $t$ with the cumulative basis functions $\tilde{B}_j$
+    /// $$ R(t) = R_{i-1} \prod_{j=1}^{3} \mathrm{e}^{\tilde{B}_j(u) \log \left( R_{i+j-2}^\dag R_{i+j-1} \right)} $$
+    /// $$ \tilde{B}_1 = \frac{5 + 3u - 3u^2 + u^3}{6} \quad \tilde{B}_2 = \frac{1 + 3u + 3u^2 - 2u^3}{6} \quad \tilde{B}_3 = \frac{u^3}{6} $$
+    pub fn b_spline(&self, t: F) -> Rotor<F> {
+        let (index, u) = self.segment(t);
+        let one = F::one();
+        let two = one + one;
+        let three = two + one;
+        let five = three + two;
+        let six = three + three;
+        let u2 = u * u;
+        let u3 = u2 * u;
+        let basis = [
+            (five + three * u - three * u2 + u3) / six,
+            (one + three * u + three * u2 - two * u3) / six,
+            u3 / six,
+        ];
+
+        let mut previous = self.key(index - 1);
+        let mut rotor = previous;
+        for (j, weight) in basis.iter().enumerate() {
+            let key = previous.closest(self.key(index + j as isize));
+            let difference: Bivector<F> = (previous.reverse() * key).log();
+            rotor = rotor * (difference * Scalar(*weight)).exp();
+            previous = key;
+        }
+        rotor
+    }
+}
+
+#[cfg(test)]
+mod rotor_interpolation {
+    use super::*;
+    use approx::assert_relative_eq;
+    use core::f32::consts::TAU;
+
+    fn assert_rotor_eq(a: Rotor<f32>, b: Rotor<f32>) {
+        assert_relative_eq!(a.scalar(), b.scalar(), epsilon = 0.00001);
+        assert_relative_eq!(a.e12(), b.e12(), epsilon = 0.00001);
+        assert_relative_eq!(a.e31(), b.e31(), epsilon = 0.00001);
+        assert_relative_eq!(a.e23(), b.e23(), epsilon = 0.00001);
+    }
+
+    #[test]
+    fn slerp() {
+        // Halfway between the identity and a quarter turn is an eighth turn
+        let a = Rotor::identity();
+        let b = Bivector::new(TAU / 8.0, 0.0, 0.0).exp();
+        assert_rotor_eq(a.slerp(b, 0.0), a);
+        assert_rotor_eq(a.slerp(b, 1.0), b);
+        assert_rotor_eq(a.slerp(b, 0.5), Bivector::new(TAU / 16.0, 0.0, 0.0).exp());
+    }
+    #[test]
+    fn slerp_plane() {
+        // A quarter of the way between two rotors in different planes
+        let a = Bivector::new(0.3, -0.2, 0.5).exp();
+        let b = Bivector::new(-0.4, 0.1, 0.2).exp();
+        let res = a.slerp(b, 0.25);
+        // 0.8908492+0.11451876e12-0.12092395e31+0.42267077e23
+        assert_relative_eq!(res.scalar(), 0.8908492, max_relative = 0.00001);
+        assert_relative_eq!(res.e12(), 0.11451876, max_relative = 0.00001);
+        assert_relative_eq!(res.e31(), -0.12092395, max_relative = 0.00001);
+        assert_relative_eq!(res.e23(), 0.42267077, max_relative = 0.00001);
+    }
+    #[test]
+    fn slerp_shortest_path() {
+        // -B is the same rotation as B
+        let a = Rotor::identity();
+        let b = Bivector::new(TAU / 8.0, 0.0, 0.0).exp();
+        let minus_b = Rotor {
+            scalar: Scalar(-b.scalar()),
+            bivector: -b.bivector(),
+        };
+        assert_rotor_eq(a.slerp(minus_b, 0.5), a.slerp(b, 0.5));
+        assert_rotor_eq(a.closest(minus_b), b);
+    }
+    #[test]
+    fn nlerp() {
+        // Halfway between two rotors nlerp equals slerp
+        let a = Bivector::new(0.3, -0.2, 0.5).exp();
+        let b = Bivector::new(-0.4, 0.1, 0.2).exp();
+        assert_rotor_eq(a.nlerp(b, 0.5), a.slerp(b, 0.5));
+        assert_rotor_eq(a.nlerp(b, 0.0), a);
+        assert_rotor_eq(a.nlerp(b, 1.0), b);
+        assert_relative_eq!(a.nlerp(b, 0.3).norm().scalar(), 1.0, max_relative = 0.00001);
+    }
+    #[test]
+    fn squad_geodesic() {
+        // Evenly spaced keys in one plane gives the slerp
+        let keys = [
+            Bivector::new(0.0, 0.0, 0.0).exp(),
+            Bivector::new(0.0, 0.2, 0.0).exp(),
+            Bivector::new(0.0, 0.4, 0.0).exp(),
+            Bivector::new(0.0, 0.6, 0.0).exp(),
+        ];
+        let spline = match RotorSpline::try_new(&keys) {
+            Some(spline) => spline,
+            None => panic!("spline needs two keys"),
+        };
+        assert_rotor_eq(spline.squad(1.5), Bivector::new(0.0, 0.3, 0.0).exp());
+        assert_rotor_eq(spline.b_spline(1.5), Bivector::new(0.0, 0.3, 0.0).exp());
+        assert_rotor_eq(spline.b_spline(2.0), keys[2]);
+    }
+    #[test]
+    fn squad_keys() {
+        // The squad spline passes through the keys
+        let keys = [
+            Bivector::new(0.3, -0.2, 0.5).exp(),
+            Bivector::new(-0.4, 0.1, 0.2).exp(),
+            Bivector::new(0.1, 0.7, -0.3).exp(),
+            Bivector::new(0.6, 0.0, 0.1).exp(),
+        ];
+        let spline = match RotorSpline::try_new(&keys) {
+            Some(spline) => spline,
+            None => panic!("spline needs two keys"),
+        };
+        for (i, key) in keys.iter().enumerate() {
+            assert_rotor_eq(spline.squad(i as f32), *key);
+        }
+        // Outside the keys the ends are used
+        assert_rotor_eq(spline.squad(-1.0), keys[0]);
+        assert_rotor_eq(spline.squad(5.0), keys[3]);
+    }
+    #[test]
+    fn spline_continuity() {
+        // The velocity is continuous across a key
+        let keys = [
+            Bivector::new(0.3, -0.2, 0.5).exp(),
+            Bivector::new(-0.4, 0.1, 0.2).exp(),
+            Bivector::new(0.1, 0.7, -0.3).exp(),
+            Bivector::new(0.6, 0.0, 0.1).exp(),
+        ];
+        let spline = match RotorSpline::try_new(&keys) {
+            Some(spline) => spline,
+            None => panic!("spline needs two keys"),
+        };
+        let h = 0.001;
+        let velocity = |f: &dyn Fn(f32) -> Rotor<f32>, t: f32| (f(t).reverse() * f(t + h)).log();
+        let squad = |t: f32| spline.squad(t);
+        let before = velocity(&squad, 1.0 - h);
+        let after = velocity(&squad, 1.0);
+        assert_relative_eq!(before.e12(), after.e12(), epsilon = 0.0001);
+        assert_relative_eq!(before.e31(), after.e31(), epsilon = 0.0001);
+        assert_relative_eq!(before.e23(), after.e23(), epsilon = 0.0001);
+        let b_spline = |t: f32| spline.b_spline(t);
+        let before = velocity(&b_spline, 2.0 - h);
+        let after = velocity(&b_spline, 2.0);
+        assert_relative_eq!(before.e12(), after.e12(), epsilon = 0.0001);
+        assert_relative_eq!(before.e31(), after.e31(), epsilon = 0.0001);
+        assert_relative_eq!(before.e23(), after.e23(), epsilon = 0.0001);
+    }
+    #[test]
+    fn spline_keys() {
+        // A spline needs two keys
+        let keys = [Rotor::<f32>::identity()];
+        assert_eq!(RotorSpline::try_new(&keys), None);
+    }
+}
@@ -0,0 +1,358 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+    VGA3DOps, bivector::Bivector, multivector::Multivector, rotor::Rotor, scalar::Scalar,
+    trivector::Trivector, vector::Vector,
+};
+
+use num_traits::Float;
+
+/// # Rotor Integrator
+/// The method used to integrate the rotor kinematics with the angular velocity bivector $\overset\Rightarrow{\Omega}$
+/// $$ \dot{R} = -\frac{1}{2}\overset\Rightarrow{\Omega} R $$
+/// The rotor rotates vectors as $R^\dag \vec{v} R$, so $\overset\Rightarrow{\Omega}$ is in the reference frame.
+/// A rate in the body frame, like a gyro measurement, is integrated with [`Rotor::integrate_body`]
+/// $$ \dot{R} = -\frac{1}{2} R \overset\Rightarrow{\Omega}_b,\quad \overset\Rightarrow{\Omega} = R \overset\Rightarrow{\Omega}_b R^\dag $$
+/// All methods renormalize the rotor after each step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RotorIntegrator {
+    /// Exponential map, exact for a constant angular velocity.
+    /// A varying angular velocity is sampled at the midpoint of the step.
+    /// $$ R_{n+1} = \mathrm{e}^{-\frac{h}{2}\overset\Rightarrow{\Omega}} R_n $$
+    Exponential,
+    /// Classic fourth order Runge-Kutta
+    RungeKutta4,
+    /// Crank-Nicolson, the trapezoidal rule with the angular velocity at both ends of the step.
+    /// $$ R_{n+1} = \left(1 + \frac{h}{4}\overset\Rightarrow{\Omega}_{n+1}\right)^{-1}\left(1 - \frac{h}{4}\overset\Rightarrow{\Omega}_n\right) R_n $$
+    CrankNicolson,
+    /// Cayley transform of the angular velocity at the midpoint of the step.
+    /// It is the same as Crank-Nicolson for a constant angular velocity.
+    /// $$ R_{n+1} = \left(1 + \frac{h}{4}\overset\Rightarrow{\Omega}\right)^{-1}\left(1 - \frac{h}{4}\overset\Rightarrow{\Omega}\right) R_n $$
+    Cayley,
+}
+
+// \[ \dot{R} = -\frac{1}{2}\overset\Rightarrow{\Omega} R \]
+fn kinematics<F: Float>(angular_velocity: Bivector<F>, rotor: Multivector<F>) -> Multivector<F> {
+    let half = F::one() / (F::one() + F::one());
+    angular_velocity * rotor * Scalar(-half)
+}
+
+// \[ 1 - \overset\Rightarrow{b} \]
+fn one_minus<F: Float>(bivector: Bivector<F>) -> Multivector<F> {
+    Multivector::new(F::one(), Vector::zero(), -bivector, Trivector::zero())
+}
+
+// The even part of the multivector with unit norm
+fn renormalize<F: Float>(multivector: Multivector<F>) -> Rotor<F> {
+    let bivector = multivector.bivector();
+    let norm = (multivector.scalar() * multivector.scalar()
+        + bivector.e12() * bivector.e12()
+        + bivector.e31() * bivector.e31()
+        + bivector.e23() * bivector.e23())
+    .sqrt();
+    match Scalar(norm).try_inverse() {
+        None => Rotor::identity(),
+        Some(norm_inverse) => Rotor {
+            scalar: Scalar(multivector.scalar() * norm_inverse.0),
+            bivector: bivector * norm_inverse,
+        },
+    }
+}
+
+impl<F: Float> Rotor<F> {
+    /// # Integrate
+    /// Integrates the rotor one time step $h$ with a constant angular velocity bivector in the reference frame
+    /// $$ \dot{R} = -\frac{1}{2}\overset\Rightarrow{\Omega} R $$
+    /// The exact solution is
+    /// $$ R(t+h) = \mathrm{e}^{-\frac{h}{2}\overset\Rightarrow{\Omega}} R(t) $$
+    pub fn integrate(
+        self,
+        angular_velocity: Bivector<F>,
+        dt: F,
+        integrator: RotorIntegrator,
+    ) -> Rotor<F> {
+        self.integrate_with(|_| angular_velocity, F::zero(), dt, integrator)
+    }
+
+    /// # Integrate with a varying Angular Velocity
+    /// Integrates the rotor from $t$ to $t+h$ with the angular velocity bivector in the reference frame
+    /// given as a function of time
+    /// $$ \dot{R} = -\frac{1}{2}\overset\Rightarrow{\Omega}(t) R $$
+    pub fn integrate_with<G: FnMut(F) -> Bivector<F>>(
+        self,
+        mut angular_velocity: G,
+        t: F,
+        dt: F,
+        integrator: RotorIntegrator,
+    ) -> Rotor<F> {
+        let two = F::one() + F::one();
+        let half = F::one() / two;
+        let quarter = half * half;
+        let rotor = Multivector::from(self);
+        let next = match integrator {
+            RotorIntegrator::Exponential => {
+                let step = (angular_velocity(t + dt * half) * Scalar(-dt * half)).exp();
+                Multivector::from(step) * rotor
+            }
+            RotorIntegrator::RungeKutta4 => {
+                let midpoint = angular_velocity(t + dt * half);
+                let k1 = kinematics(angular_velocity(t), rotor);
+                let k2 = kinematics(midpoint, rotor + k1 * Scalar(dt * half));
+                let k3 = kinematics(midpoint, rotor + k2 * Scalar(dt * half));
+                let k4 = kinematics(angular_velocity(t + dt), rotor + k3 * Scalar(dt));
+                rotor + (k1 + (k2 + k3) * Scalar(two) + k4) * Scalar(dt / (two + two + two))
+            }
+            // The inverse (1+b)^{-1} = (1-b)/(1+|b|^2), the scale is removed by the renormalization
+            RotorIntegrator::CrankNicolson => {
+                let start = angular_velocity(t) * Scalar(dt * quarter);
+                let end = angular_velocity(t + dt) * Scalar(dt * quarter);
+                one_minus(end) * one_minus(start) * rotor
+            }
+            RotorIntegrator::Cayley => {
+                let midpoint = angular_velocity(t + dt * half) * Scalar(dt * quarter);
+                one_minus(midpoint) * one_minus(midpoint) * rotor
+            }
+        };
+        renormalize(next)
+    }
+
+    /// # Integrate in the Body Frame
+    /// Integrates the rotor one time step $h$ with a constant angular velocity bivector in the body frame
+    /// $$ \dot{R} = -\frac{1}{2} R \overset\Rightarrow{\Omega} $$
+    /// The exact solution is
+    /// $$ R(t+h) = R(t) \mathrm{e}^{-\frac{h}{2}\overset\Rightarrow{\Omega}} $$
+    pub fn integrate_body(
+        self,
+        angular_velocity: Bivector<F>,
+        dt: F,
+        integrator: RotorIntegrator,
+    ) -> Rotor<F> {
+        self.integrate_body_with(|_| angular_velocity, F::zero(), dt, integrator)
+    }
+
+    /// # Integrate in the Body Frame with a varying Angular Velocity
+    /// Integrates the rotor from $t$ to $t+h$ with the angular velocity bivector in the body frame
+    /// given as a function of time
+    /// $$ \dot{R} = -\frac{1}{2} R \overset\Rightarrow{\Omega}(t) $$
+    pub fn integrate_body_with<G: FnMut(F) -> Bivector<F>>(
+        self,
+        mut angular_velocity: G,
+        t: F,
+        dt: F,
+        integrator: RotorIntegrator,
+    ) -> Rotor<F> {
+        // The reverse follows the reference frame kinematics with the opposite rate
+        // \[ \dot{R}^\dag = -\frac{1}{2}\left(-\overset\Rightarrow{\Omega}\right) R^\dag \]
+        self.reverse()
+            .integrate_with(|t| -angular_velocity(t), t, dt, integrator)
+            .reverse()
+    }
+}
+
+#[cfg(test)]
+mod rotor_kinematics {
+    use super::*;
+    use crate::vga3d::Rotatable;
+    use approx::assert_relative_eq;
+
+    fn assert_rotor_eq(a: Rotor<f64>, b: Rotor<f64>, epsilon: f64) {
+        assert_relative_eq!(a.scalar(), b.scalar(), epsilon = epsilon);
+        assert_relative_eq!(a.e12(), b.e12(), epsilon = epsilon);
+        assert_relative_eq!(a.e31(), b.e31(), epsilon = epsilon);
+        assert_relative_eq!(a.e23(), b.e23(), epsilon = epsilon);
+    }
+
+    fn assert_vector_eq(a: Vector<f64>, b: Vector<f64>, epsilon: f64) {
+        assert_relative_eq!(a.e1(), b.e1(), epsilon = epsilon);
+        assert_relative_eq!(a.e2(), b.e2(), epsilon = epsilon);
+        assert_relative_eq!(a.e3(), b.e3(), epsilon = epsilon);
+    }
+
+    fn propagate(
+        rotor: Rotor<f64>,
+        angular_velocity: fn(f64) -> Bivector<f64>,
+        duration: f64,
+        steps: usize,
+        integrator: RotorIntegrator,
+    ) -> Rotor<f64> {
+        let dt = duration / steps as f64;
+        (0..steps).fold(rotor, |rotor, step| {
+            rotor.integrate_with(angular_velocity, step as f64 * dt, dt, integrator)
+        })
+    }
+
+    fn constant_rate(_t: f64) -> Bivector<f64> {
+        Bivector::new(0.3, -0.2, 0.5)
+    }
+
+    // The rate in a fixed plane, so the closed form is the exponential of the integral
+    fn varying_rate(t: f64) -> Bivector<f64> {
+        Bivector::new(0.3, -0.2, 0.5) * Scalar(t.cos())
+    }
+
+    fn closed_form(rotor: Rotor<f64>, angle: f64) -> Rotor<f64> {
+        (Bivector::new(0.3, -0.2, 0.5) * Scalar(-0.5 * angle)).exp() * rotor
+    }
+
+    #[test]
+    fn direction() {
+        // A positive rate in the e12 plane rotates e1 towards -e2
+        let rotor = Rotor::identity().integrate(
+            Bivector::new(1.0, 0.0, 0.0),
+            0.2,
+            RotorIntegrator::Exponential,
+        );
+        let res = Vector::new(1.0, 0.0, 0.0).rotate(rotor);
+        assert_relative_eq!(res.e1(), 0.2f64.cos(), epsilon = 1e-12);
+        assert_relative_eq!(res.e2(), -(0.2f64.sin()), epsilon = 1e-12);
+        assert_relative_eq!(res.e3(), 0.0, epsilon = 1e-12);
+    }
+    #[test]
+    fn rotated_vector() {
+        // The rate rotates the reference frame before the start attitude
+        let start = Bivector::new(0.1, 0.4, -0.3).exp();
+        let vector = Vector::new(1.0, -2.0, 0.5);
+        let res = propagate(start, constant_rate, 2.0, 200, RotorIntegrator::RungeKutta4);
+        let expected = vector
+            .rotate((constant_rate(0.0) * Scalar(-0.5 * 2.0)).exp())
+            .rotate(start);
+        assert_vector_eq(vector.rotate(res), expected, 1e-10);
+        assert_vector_eq(vector.rotate(closed_form(start, 2.0)), expected, 1e-12);
+    }
+    #[test]
+    fn body_rotated_vector() {
+        // The rate rotates the body frame after the start attitude
+        let start = Bivector::new(0.1, 0.4, -0.3).exp();
+        let vector = Vector::new(1.0, -2.0, 0.5);
+        let res = (0..200).fold(start, |rotor, _| {
+            rotor.integrate_body(constant_rate(0.0), 0.01, RotorIntegrator::RungeKutta4)
+        });
+        let expected = vector
+            .rotate(start)
+            .rotate((constant_rate(0.0) * Scalar(-0.5 * 2.0)).exp());
+        assert_vector_eq(vector.rotate(res), expected, 1e-10);
+    }
+    #[test]
+    fn body_frame() {
+        // The body rate is the reference rate rotated into the body frame
+        let start = Bivector::new(0.1, 0.4, -0.3).exp();
+        let body_rate = constant_rate(0.0);
+        let reference_rate = body_rate.rotate(start.reverse());
+        for integrator in [
+            RotorIntegrator::Exponential,
+            RotorIntegrator::RungeKutta4,
+            RotorIntegrator::CrankNicolson,
+            RotorIntegrator::Cayley,
+        ] {
+            let res = start.integrate_body(body_rate, 0.1, integrator);
+            let expected = start.integrate(reference_rate, 0.1, integrator);
+            assert_rotor_eq(res, expected, 1e-12);
+        }
+    }
+    #[test]
+    fn exponential() {
+        let start = Bivector::new(0.1, 0.4, -0.3).exp();
+        let res = propagate(start, constant_rate, 10.0, 7, RotorIntegrator::Exponential);
+        assert_rotor_eq(res, closed_form(start, 10.0), 1e-12);
+    }
+    #[test]
+    fn runge_kutta4() {
+        let start = Bivector::new(0.1, 0.4, -0.3).exp();
+        let res = propagate(
+            start,
+            constant_rate,
+            10.0,
+            1000,
+            RotorIntegrator::RungeKutta4,
+        );
+        assert_rotor_eq(res, closed_form(start, 10.0), 1e-10);
+    }
+    #[test]
+    fn crank_nicolson() {
+        let start = Bivector::new(0.1, 0.4, -0.3).exp();
+        let res = propagate(
+            start,
+            constant_rate,
+            10.0,
+            1000,
+            RotorIntegrator::CrankNicolson,
+        );
+        assert_rotor_eq(res, closed_form(start, 10.0), 1e-4);
+    }
+    #[test]
+    fn cayley() {
+        // Crank-Nicolson and Cayley are the same for a constant rate
+        let start = Bivector::new(0.1, 0.4, -0.3).exp();
+        let res = propagate(start, constant_rate, 10.0, 1000, RotorIntegrator::Cayley);
+        assert_rotor_eq(res, closed_form(start, 10.0), 1e-4);
+        let crank_nicolson = propagate(
+            start,
+            constant_rate,
+            10.0,
+            1000,
+            RotorIntegrator::CrankNicolson,
+        );
+        assert_rotor_eq(res, crank_nicolson, 1e-12);
+    }
+    #[test]
+    fn varying_rate_closed_form() {
+        // The integral of cos(t) is sin(t)
+        let start = Bivector::new(0.1, 0.4, -0.3).exp();
+        let expected = closed_form(start, 3.0f64.sin());
+        let res = propagate(start, varying_rate, 3.0, 300, RotorIntegrator::RungeKutta4);
+        assert_rotor_eq(res, expected, 1e-10);
+        let res = propagate(start, varying_rate, 3.0, 3000, RotorIntegrator::Exponential);
+        assert_rotor_eq(res, expected, 1e-6);
+        let res = propagate(
+            start,
+            varying_rate,
+            3.0,
+            3000,
+            RotorIntegrator::CrankNicolson,
+        );
+        assert_rotor_eq(res, expected, 1e-6);
+        let res = propagate(start, varying_rate, 3.0, 3000, RotorIntegrator::Cayley);
+        assert_rotor_eq(res, expected, 1e-6);
+    }
+    #[test]
+    fn second_order() {
+        // Halving the step reduces the error by four
+        let start = Bivector::new(0.1, 0.4, -0.3).exp();
+        let expected = closed_form(start, 10.0);
+        let error = |steps| {
+            let res = propagate(start, constant_rate, 10.0, steps, RotorIntegrator::Cayley);
+            (res.bivector() - expected.bivector()).norm().0
+        };
+        assert_relative_eq!(error(200) / error(400), 4.0, epsilon = 0.05);
+    }
+    #[test]
+    fn unit_norm() {
+        // A large step still gives a unit rotor
+        let start = Bivector::new(0.1, 0.4, -0.3).exp();
+        for integrator in [
+            RotorIntegrator::Exponential,
+            RotorIntegrator::RungeKutta4,
+            RotorIntegrator::CrankNicolson,
+            RotorIntegrator::Cayley,
+        ] {
+            let res = propagate(start, constant_rate, 1000.0, 100, integrator);
+            assert_relative_eq!(res.norm().0, 1.0, epsilon = 1e-12);
+        }
+    }
+}
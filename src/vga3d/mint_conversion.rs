@@ -0,0 +1,118 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{bivector::Bivector, rotor::Rotor, scalar::Scalar, vector::Vector};
+
+use num_traits::Float;
+
+use mint::{Quaternion, Vector3};
+
+// Vector
+// \[ \vec{v} = v_1\mathrm{e}_1 + v_2\mathrm{e}_2 + v_3\mathrm{e}_3 \]
+impl<F: Float> From<Vector<F>> for Vector3<F> {
+    fn from(vector: Vector<F>) -> Self {
+        Vector3 {
+            x: vector.e1(),
+            y: vector.e2(),
+            z: vector.e3(),
+        }
+    }
+}
+
+impl<F: Float> From<Vector3<F>> for Vector<F> {
+    fn from(vector: Vector3<F>) -> Self {
+        Vector::new(vector.x, vector.y, vector.z)
+    }
+}
+
+// Bivector
+// The axial vector of the bivector
+// \[ -\overset\Rightarrow{b}\star = b_{23}\mathrm{e}_1 + b_{31}\mathrm{e}_2 + b_{12}\mathrm{e}_3 \]
+impl<F: Float> From<Bivector<F>> for Vector3<F> {
+    fn from(bivector: Bivector<F>) -> Self {
+        Vector3 {
+            x: bivector.e23(),
+            y: bivector.e31(),
+            z: bivector.e12(),
+        }
+    }
+}
+
+impl<F: Float> From<Vector3<F>> for Bivector<F> {
+    fn from(vector: Vector3<F>) -> Self {
+        Bivector::new(vector.z, vector.y, vector.x)
+    }
+}
+
+// Rotor
+// The unit quaternion with the axis of the rotor
+// \[ q = s + b_{23}\mathrm{i} + b_{31}\mathrm{j} + b_{12}\mathrm{k} \]
+impl<F: Float> From<Rotor<F>> for Quaternion<F> {
+    fn from(rotor: Rotor<F>) -> Self {
+        Quaternion {
+            v: Vector3 {
+                x: rotor.e23(),
+                y: rotor.e31(),
+                z: rotor.e12(),
+            },
+            s: rotor.scalar(),
+        }
+    }
+}
+
+impl<F: Float> From<Quaternion<F>> for Rotor<F> {
+    fn from(quaternion: Quaternion<F>) -> Self {
+        Rotor {
+            scalar: Scalar(quaternion.s),
+            bivector: Bivector::new(quaternion.v.z, quaternion.v.y, quaternion.v.x),
+        }
+    }
+}
+
+#[cfg(test)]
+mod mint_into {
+    use super::*;
+
+    #[test]
+    fn vector() {
+        let vector = Vector::new(3.0, 5.0, 4.0);
+        let res = Vector3::from(vector);
+        assert_eq!(res, Vector3::from([3.0, 5.0, 4.0]));
+        assert_eq!(Vector::from(res), vector);
+    }
+    #[test]
+    fn bivector() {
+        // The e12 plane has the axis e3
+        let bivector = Bivector::new(2.0, 1.0, 6.0);
+        let res = Vector3::from(bivector);
+        assert_eq!(res, Vector3::from([6.0, 1.0, 2.0]));
+        assert_eq!(Bivector::from(res), bivector);
+    }
+    #[test]
+    fn quaternion() {
+        // 0.5 + 0.3i + 0.2j + 0.1k
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.1, 0.2, 0.3),
+        };
+        let res = Quaternion::from(rotor);
+        assert_eq!(res.s, 0.5);
+        assert_eq!(res.v, Vector3::from([0.3, 0.2, 0.1]));
+        assert_eq!(Rotor::from(res), rotor);
+    }
+}
@@ -31,6 +31,9 @@ pub use vector::Vector;
 mod bivector;
 pub use bivector::Bivector;
 //
+mod bivector_linear_map;
+pub use bivector_linear_map::{BivectorLinearMap, PrincipalAxes};
+//
 mod trivector;
 pub use trivector::Trivector;
 //
@@ -48,12 +51,35 @@ pub use quaternion::Quaternion;
 //
 #[cfg(feature = "quaternion")]
 pub use quaternion::UnitQuaternion;
+//
+#[cfg(feature = "nalgebra")]
+mod nalgebra_conversion;
+#[cfg(feature = "glam")]
+mod glam_conversion;
+#[cfg(feature = "mint")]
+mod mint_conversion;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "bytemuck")]
+mod bytemuck_conversion;
 
 // Interactions
 mod addition;
+mod commutator;
+pub use commutator::{Anticommutator, Commutator};
+mod contraction;
+pub use contraction::{Contraction, FatDot, InnerProduct, LeftContraction, RightContraction};
+mod dual;
+pub use dual::Dual;
+mod euler;
+pub use euler::{EulerAngles, EulerFrame, EulerSequence};
 mod exterior;
 mod geometric;
 mod inner;
+mod interpolation;
+pub use interpolation::RotorSpline;
+mod kinematics;
+pub use kinematics::RotorIntegrator;
 mod regressive;
 mod subtraction;
 // Functions
@@ -62,7 +88,6 @@ pub use functions::{Projectable, Reflectable, Rejectable, Rotatable};
 
 pub trait VGA3DOps<F: Float>: Clone {
     fn reverse(self) -> Self;
-    // fn dual(self) -> Self;
     fn conjugate(self) -> Self;
     fn involute(self) -> Self;
     fn norm(self) -> Scalar<F>;
@@ -86,7 +111,6 @@ pub trait VGA3DOps<F: Float>: Clone {
 
 pub trait VGA3DOpsRef<F: Float> {
     fn reverse(&self) -> Self;
-    // fn dual(&&self) -> Self;
     fn conjugate(&self) -> Self;
     fn involute(&self) -> Self;
     fn norm(&self) -> Scalar<F>;
@@ -27,7 +27,8 @@ use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Index, IndexMut, Mul, Neg, Not,
 use num_traits::Float;
 
 use super::{
-    bivector::Bivector, trivector::Trivector, vector::Vector, Scalar, VGA3DOps, VGA3DOpsRef,
+    bivector::Bivector, rotor::Rotor, trivector::Trivector, vector::Vector, Scalar, VGA3DOps,
+    VGA3DOpsRef,
 };
 
 #[cfg(feature = "std")]
@@ -43,7 +44,11 @@ use defmt::Format;
 ///
 /// A multivector is a sum of all grades in the algebra
 /// $$ M = \text{scalar} + \vec{v} + \overset\Rightarrow{b} + \overset\Rrightarrow{t} $$
+///
+/// The layout is `#[repr(C)]` and the same as `[F; 8]` with the components in the order
+/// scalar, $\mathrm{e}_1$, $\mathrm{e}_2$, $\mathrm{e}_3$, $\mathrm{e}_{12}$, $\mathrm{e}_{31}$, $\mathrm{e}_{23}$, $\mathrm{e}_{123}$.
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
 pub struct Multivector<F: Float> {
     pub(super) scalar: Scalar<F>,
     pub(super) vector: Vector<F>,
@@ -333,16 +338,35 @@ impl<F: Float> VGA3DOpsRef<F> for Multivector<F> {
     }
 }
 
-// Dual
-// In VGA 3D, the dual is the pseudoscalar
-// \[ \text{scalar},\mathrm{e}_1,\,\mathrm{e}_2,\,\mathrm{e}_3,\,\mathrm{e}_3\star,\,\mathrm{e}_2\star,\,\mathrm{e}_1\star,\, \text{scalar} \star \]
-impl<F: Float> Multivector<F> {
-    pub fn dual(self) -> Multivector<F> {
-        let scalar = self.trivector().dual();
-        let vector = self.bivector().dual();
-        let bivector = self.vector().dual();
-        let trivector = Trivector::new(self.scalar());
-        Multivector::new(scalar, vector, bivector, trivector)
+// Conversions
+// Every grade and the rotor is a multivector with the other grades set to zero
+impl<F: Float> From<Scalar<F>> for Multivector<F> {
+    fn from(scalar: Scalar<F>) -> Self {
+        Multivector::new(scalar.0, Vector::zero(), Bivector::zero(), Trivector::zero())
+    }
+}
+
+impl<F: Float> From<Vector<F>> for Multivector<F> {
+    fn from(vector: Vector<F>) -> Self {
+        Multivector::new(F::zero(), vector, Bivector::zero(), Trivector::zero())
+    }
+}
+
+impl<F: Float> From<Bivector<F>> for Multivector<F> {
+    fn from(bivector: Bivector<F>) -> Self {
+        Multivector::new(F::zero(), Vector::zero(), bivector, Trivector::zero())
+    }
+}
+
+impl<F: Float> From<Trivector<F>> for Multivector<F> {
+    fn from(trivector: Trivector<F>) -> Self {
+        Multivector::new(F::zero(), Vector::zero(), Bivector::zero(), trivector)
+    }
+}
+
+impl<F: Float> From<Rotor<F>> for Multivector<F> {
+    fn from(rotor: Rotor<F>) -> Self {
+        Multivector::new(rotor.scalar(), Vector::zero(), rotor.bivector(), Trivector::zero())
     }
 }
 mod quaternion {
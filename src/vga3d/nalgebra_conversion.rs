@@ -0,0 +1,204 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{bivector::Bivector, multivector::Multivector, rotor::Rotor, vector::Vector};
+
+use num_traits::Float;
+
+use nalgebra::{
+    Matrix3, Quaternion, RealField, Rotation3, SVector, Scalar, UnitQuaternion, Vector3,
+};
+
+// Vector
+// \[ \vec{v} = v_1\mathrm{e}_1 + v_2\mathrm{e}_2 + v_3\mathrm{e}_3 \]
+impl<F: Float + Scalar> From<Vector<F>> for Vector3<F> {
+    fn from(vector: Vector<F>) -> Self {
+        Vector3::new(vector.e1(), vector.e2(), vector.e3())
+    }
+}
+
+impl<F: Float + Scalar> From<Vector3<F>> for Vector<F> {
+    fn from(vector: Vector3<F>) -> Self {
+        Vector::new(vector.x, vector.y, vector.z)
+    }
+}
+
+// Rotor
+// The unit quaternion with the axis of the rotor
+// \[ q = s + b_{23}\mathrm{i} + b_{31}\mathrm{j} + b_{12}\mathrm{k} \]
+impl<F: Float + RealField> From<Rotor<F>> for UnitQuaternion<F> {
+    fn from(rotor: Rotor<F>) -> Self {
+        UnitQuaternion::new_unchecked(Quaternion::new(
+            rotor.scalar(),
+            rotor.e23(),
+            rotor.e31(),
+            rotor.e12(),
+        ))
+    }
+}
+
+impl<F: Float + RealField> From<UnitQuaternion<F>> for Rotor<F> {
+    fn from(quaternion: UnitQuaternion<F>) -> Self {
+        Rotor {
+            scalar: super::Scalar(quaternion.w),
+            bivector: Bivector::new(quaternion.k, quaternion.j, quaternion.i),
+        }
+    }
+}
+
+// The rotation matrix of the rotor
+impl<F: Float + RealField> From<Rotor<F>> for Rotation3<F> {
+    fn from(rotor: Rotor<F>) -> Self {
+        let m = rotor.to_matrix3();
+        Rotation3::from_matrix_unchecked(Matrix3::from_fn(|i, j| m[i][j]))
+    }
+}
+
+// A Rotation3 is a rotation matrix, so the rotor is extracted directly
+impl<F: Float + RealField> From<Rotation3<F>> for Rotor<F> {
+    fn from(rotation: Rotation3<F>) -> Self {
+        let m = rotation.matrix();
+        Rotor::from_rotation_matrix(core::array::from_fn(|i| {
+            core::array::from_fn(|j| m[(i, j)])
+        }))
+    }
+}
+
+// Bivector
+// The skew-symmetric matrix of the bivector
+impl<F: Float + Scalar> From<Bivector<F>> for Matrix3<F> {
+    fn from(bivector: Bivector<F>) -> Self {
+        let m = bivector.to_skew_matrix3();
+        Matrix3::from_fn(|i, j| m[i][j])
+    }
+}
+
+impl<F: Float + Scalar> From<Matrix3<F>> for Bivector<F> {
+    fn from(m: Matrix3<F>) -> Self {
+        Bivector::from_skew_matrix3(core::array::from_fn(|i| {
+            core::array::from_fn(|j| m[(i, j)])
+        }))
+    }
+}
+
+// Multivector
+// The components in the order
+// \[ 1, \mathrm{e}_1, \mathrm{e}_2, \mathrm{e}_3, \mathrm{e}_{12}, \mathrm{e}_{31}, \mathrm{e}_{23}, \mathrm{e}_{123} \]
+impl<F: Float + Scalar> From<Multivector<F>> for SVector<F, 8> {
+    fn from(multivector: Multivector<F>) -> Self {
+        SVector::<F, 8>::from_column_slice(&[
+            multivector.scalar(),
+            multivector.e1(),
+            multivector.e2(),
+            multivector.e3(),
+            multivector.e12(),
+            multivector.e31(),
+            multivector.e23(),
+            multivector.e123(),
+        ])
+    }
+}
+
+impl<F: Float + Scalar> From<SVector<F, 8>> for Multivector<F> {
+    fn from(vector: SVector<F, 8>) -> Self {
+        Multivector::new_components(
+            vector[0], vector[1], vector[2], vector[3], vector[4], vector[5], vector[6], vector[7],
+        )
+    }
+}
+
+#[cfg(test)]
+mod nalgebra_into {
+    use super::*;
+    use crate::vga3d::Rotatable;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn vector() {
+        let vector = Vector::new(3.0, 5.0, 4.0);
+        let res = Vector3::from(vector);
+        assert_eq!(res, Vector3::new(3.0, 5.0, 4.0));
+        assert_eq!(Vector::from(res), vector);
+    }
+    #[test]
+    fn unit_quaternion() {
+        // The rotated vector is the same for the rotor and the unit quaternion
+        let rotor = Bivector::new(0.3, -0.2, 0.5).exp();
+        let vector = Vector::new(3.0, 5.0, 4.0);
+        let quaternion = UnitQuaternion::from(rotor);
+        let res = Vector::from(quaternion * Vector3::from(vector));
+        let rotated = vector.rotate(rotor);
+        assert_relative_eq!(res.e1(), rotated.e1(), max_relative = 0.00001);
+        assert_relative_eq!(res.e2(), rotated.e2(), max_relative = 0.00001);
+        assert_relative_eq!(res.e3(), rotated.e3(), max_relative = 0.00001);
+        assert_eq!(Rotor::from(quaternion), rotor);
+    }
+    #[test]
+    fn unit_quaternion_axis_angle() {
+        // A quarter turn around e3 is the rotor in the e12 plane
+        let quaternion =
+            UnitQuaternion::from_axis_angle(&Vector3::z_axis(), core::f32::consts::TAU / 4.0);
+        let rotor = Rotor::from(quaternion);
+        let res = Vector::new(1.0, 0.0, 0.0).rotate(rotor);
+        assert_relative_eq!(res.e1(), 0.0, epsilon = 0.00001);
+        assert_relative_eq!(res.e2(), 1.0, epsilon = 0.00001);
+        assert_relative_eq!(res.e3(), 0.0, epsilon = 0.00001);
+    }
+    #[test]
+    fn rotation() {
+        // The rotated vector is the same for the rotor and the rotation matrix
+        let rotor = Bivector::new(-0.4, 0.1, 0.2).exp();
+        let vector = Vector::new(-2.0, 1.0, 6.0);
+        let rotation = Rotation3::from(rotor);
+        let res = Vector::from(rotation * Vector3::from(vector));
+        let rotated = vector.rotate(rotor);
+        assert_relative_eq!(res.e1(), rotated.e1(), max_relative = 0.00001);
+        assert_relative_eq!(res.e2(), rotated.e2(), max_relative = 0.00001);
+        assert_relative_eq!(res.e3(), rotated.e3(), max_relative = 0.00001);
+
+        let res = Rotor::from(rotation);
+        assert_relative_eq!(res.scalar(), rotor.scalar(), max_relative = 0.00001);
+        assert_relative_eq!(res.e12(), rotor.e12(), max_relative = 0.00001);
+        assert_relative_eq!(res.e31(), rotor.e31(), max_relative = 0.00001);
+        assert_relative_eq!(res.e23(), rotor.e23(), max_relative = 0.00001);
+
+        // nalgebra agrees with the unit quaternion
+        let res = UnitQuaternion::from(rotor).to_rotation_matrix();
+        assert_relative_eq!(res.matrix(), rotation.matrix(), epsilon = 0.00001);
+    }
+    #[test]
+    fn bivector() {
+        // The skew-symmetric matrix is the cross product with the axis
+        let bivector = Bivector::new(2.0, 1.0, 6.0);
+        let vector = Vector::new(3.0, 5.0, 4.0);
+        let m = Matrix3::from(bivector);
+        let res = Vector::from(m * Vector3::from(vector));
+        let axis = Vector3::new(bivector.e23(), bivector.e31(), bivector.e12());
+        assert_eq!(m, axis.cross_matrix());
+        assert_eq!(res, vector | bivector);
+        assert_eq!(Bivector::from(m), bivector);
+    }
+    #[test]
+    fn multivector() {
+        let multivector = Multivector::new_components(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let res = SVector::<f32, 8>::from(multivector);
+        assert_eq!(res[4], 5.0);
+        assert_eq!(res[7], 8.0);
+        assert_eq!(Multivector::from(res), multivector);
+    }
+}
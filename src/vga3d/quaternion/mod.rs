@@ -24,3 +24,6 @@ pub use quaternion::Quaternion;
 //
 mod unit_quaternion;
 pub use unit_quaternion::UnitQuaternion;
+//
+#[cfg(feature = "serde")]
+mod serialize;
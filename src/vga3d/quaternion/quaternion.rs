@@ -377,7 +377,7 @@ forward_ref_binop!(impl<F:Float> Sub, sub for Quaternion<F>, Quaternion<F>);
 mod quaternion {
     use core::f32::consts::TAU;
 
-    use crate::vga3d::Rotatable;
+    use crate::vga3d::{Dual, Rotatable};
 
     use super::*;
     use approx::assert_relative_eq;
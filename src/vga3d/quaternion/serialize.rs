@@ -0,0 +1,120 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Serde
+//! The quaternions are serialized as structs with the named fields `scalar`, `e1`, `e2` and `e3`.
+//! A unit quaternion must have unit norm, deserializing a unit quaternion that is not normalized is an error.
+
+use super::{Quaternion, UnitQuaternion};
+use crate::vga3d::{Scalar, Vector, serialize::is_unit};
+
+use num_traits::Float;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Quaternion")]
+struct QuaternionFields<F> {
+    scalar: F,
+    e1: F,
+    e2: F,
+    e3: F,
+}
+
+// Quaternion
+impl<F: Float + Serialize> Serialize for Quaternion<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        QuaternionFields {
+            scalar: self.scalar(),
+            e1: self.e1(),
+            e2: self.e2(),
+            e3: self.e3(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, F: Float + Deserialize<'de>> Deserialize<'de> for Quaternion<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields = QuaternionFields::deserialize(deserializer)?;
+        Ok(Quaternion::new(
+            fields.scalar,
+            Vector::new(fields.e1, fields.e2, fields.e3),
+        ))
+    }
+}
+
+// Unit Quaternion
+impl<F: Float + Serialize> Serialize for UnitQuaternion<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        QuaternionFields {
+            scalar: self.scalar(),
+            e1: self.e1(),
+            e2: self.e2(),
+            e3: self.e3(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, F: Float + Deserialize<'de>> Deserialize<'de> for UnitQuaternion<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields = QuaternionFields::deserialize(deserializer)?;
+        match is_unit([fields.scalar, fields.e1, fields.e2, fields.e3]) {
+            false => Err(D::Error::custom(
+                "the unit quaternion does not have unit norm",
+            )),
+            true => Ok(UnitQuaternion {
+                scalar: Scalar(fields.scalar),
+                vector: Vector::new(fields.e1, fields.e2, fields.e3),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod serde_fields {
+    use super::*;
+
+    #[test]
+    fn quaternion() {
+        let quaternion = Quaternion::new(2.0, Vector::new(4.0, -3.0, 7.0));
+        let json = serde_json::to_string(&quaternion).unwrap();
+        assert_eq!(json, r#"{"scalar":2.0,"e1":4.0,"e2":-3.0,"e3":7.0}"#);
+        assert_eq!(
+            serde_json::from_str::<Quaternion<f64>>(&json).unwrap(),
+            quaternion
+        );
+    }
+    #[test]
+    fn unit_quaternion() {
+        let unit = UnitQuaternion {
+            scalar: Scalar(0.5),
+            vector: Vector::new(0.5, -0.5, 0.5),
+        };
+        let json = serde_json::to_string(&unit).unwrap();
+        assert_eq!(json, r#"{"scalar":0.5,"e1":0.5,"e2":-0.5,"e3":0.5}"#);
+        assert_eq!(
+            serde_json::from_str::<UnitQuaternion<f64>>(&json).unwrap(),
+            unit
+        );
+        // The norm of the unit quaternion is not one
+        let json = r#"{"scalar":2.0,"e1":4.0,"e2":-3.0,"e3":7.0}"#;
+        assert!(serde_json::from_str::<UnitQuaternion<f64>>(json).is_err());
+    }
+}
@@ -20,8 +20,8 @@
 use crate::forward_ref_binop;
 use crate::vga3d::Scalar;
 use crate::vga3d::{
-    bivector::Bivector, multivector::Multivector, trivector::Trivector, vector::Vector, Rotor,
-    VGA3DOps, VGA3DOpsRef,
+    bivector::Bivector, multivector::Multivector, trivector::Trivector, vector::Vector,
+    EulerAngles, EulerFrame, EulerSequence, Rotor, VGA3DOps, VGA3DOpsRef,
 };
 
 use core::ops::Mul;
@@ -199,6 +199,20 @@ impl<F: Float> Rotor<F> {
     }
 }
 
+impl<F: Float> UnitQuaternion<F> {
+    /// New Unit Quaternion from three Euler angles in radians in the order of the sequence.
+    /// The unit quaternion is the same rotation as `Rotor::from_euler_angles`.
+    pub fn from_euler_angles(angles: [F; 3], sequence: EulerSequence, frame: EulerFrame) -> Self {
+        Rotor::from_euler_angles(angles, sequence, frame).to_unit_quaternion()
+    }
+
+    /// The three Euler angles in radians in the order of the sequence.
+    /// At gimbal lock `EulerAngles::GimbalLock` is returned with the third angle set to zero.
+    pub fn to_euler_angles(self, sequence: EulerSequence, frame: EulerFrame) -> EulerAngles<F> {
+        self.to_rotor().to_euler_angles(sequence, frame)
+    }
+}
+
 impl<F: Float> Mul for UnitQuaternion<F> {
     type Output = UnitQuaternion<F>;
     /// Unit Quaternion multiplication
@@ -327,7 +341,7 @@ impl<F: Float> RotatableQuaternion<F> for Vector<F> {
 mod unit_quaternion {
     use core::f32::consts::TAU;
 
-    use crate::vga3d::{quaternion::Quaternion, Rotatable};
+    use crate::vga3d::{quaternion::Quaternion, Dual, Rotatable};
 
     use super::*;
     use approx::assert_relative_eq;
@@ -458,4 +472,28 @@ mod unit_quaternion {
         assert_relative_eq!(res_unit.e2(), res_rotor.e2(), max_relative = 0.000001);
         assert_relative_eq!(res_unit.e3(), res_rotor.e3(), max_relative = 0.000001);
     }
+
+    #[test]
+    fn unit_quaternion_euler_angles() {
+        // nalgebra uses roll, pitch and yaw, the extrinsic x-y-z sequence
+        let (roll, pitch, yaw) = (0.3, -0.7, 2.1);
+        let q = UnitQuaternion::from_euler_angles(
+            [roll, pitch, yaw],
+            EulerSequence::Xyz,
+            EulerFrame::Extrinsic,
+        );
+        let nalgebra_q = nalgebra::UnitQuaternion::from_euler_angles(roll, pitch, yaw);
+
+        assert_relative_eq!(q.scalar(), nalgebra_q.w, max_relative = 0.00001);
+        assert_relative_eq!(q.e1(), nalgebra_q.i, max_relative = 0.00001);
+        assert_relative_eq!(q.e2(), nalgebra_q.j, max_relative = 0.00001);
+        assert_relative_eq!(q.e3(), nalgebra_q.k, max_relative = 0.00001);
+
+        // Intrinsic z-y-x is yaw, pitch and roll
+        let angles = q.to_euler_angles(EulerSequence::Zyx, EulerFrame::Intrinsic);
+        assert!(!angles.is_gimbal_lock());
+        assert_relative_eq!(angles.angles()[0], yaw, max_relative = 0.00001);
+        assert_relative_eq!(angles.angles()[1], pitch, max_relative = 0.00001);
+        assert_relative_eq!(angles.angles()[2], roll, max_relative = 0.00001);
+    }
 }
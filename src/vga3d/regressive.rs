@@ -20,7 +20,7 @@
 #![allow(dead_code)]
 
 use super::{
-    bivector::Bivector, multivector::Multivector, rotor::Rotor, scalar::Scalar,
+    bivector::Bivector, dual::Dual, multivector::Multivector, rotor::Rotor, scalar::Scalar,
     trivector::Trivector, vector::Vector,
 };
 
@@ -33,128 +33,115 @@ use core::ops::BitAnd;
 /// # Regressive Product
 /// $$ A \vee B = ( -A\star  \wedge -B\star )\star $$
 
-// /// Scalar-Vector
-// impl<F:Float> BitAnd<Vector<F>> for F {
-//     type Output = Vector<F>;
-//     fn bitand(self, b: Vector<F>) -> Vector<F> {
-//         let e1 = b.e1() * self;
-//         let e2 = b.e2() * self;
-//         let e3 = b.e3() * self;
-//         Vector::new(e1, e2, e3)
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitAnd, bitand for F, Vector<F>);
-
-// /// Vector-Scalar
-// impl<F:Float> BitAnd<F> for Vector<F> {
-//     type Output = Vector<F>;
-//     fn bitand(self, b: F) -> Vector<F> {
-//         let e1 = self.e1() * b;
-//         let e2 = self.e2() * b;
-//         let e3 = self.e3() * b;
-//         Vector::new(e1, e2, e3)
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>,F);
-
-// /// Scalar-Bivector
-// impl<F:Float> BitAnd<Bivector<F>> for F {
-//     type Output = Bivector<F>;
-//     fn bitand(self, b: Bivector<F>) -> Bivector<F> {
-//         let e12 = self * b.e12();
-//         let e31 = self * b.e31();
-//         let e23 = self * b.e23();
-//         Bivector::new(e12, e31, e23)
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitAnd, bitand for F, Bivector<F>);
-
-// // Bivector-Scalar
-// impl<F:Float> BitAnd<F> for Bivector<F> {
-//     type Output = Bivector<F>;
-//     fn bitand(self, b: F) -> Bivector<F> {
-//         let e12 = self.e12() * b;
-//         let e31 = self.e31() * b;
-//         let e23 = self.e23() * b;
-//         Bivector::new(e12, e31, e23)
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>,F);
-
-// // Scalar-Trivector
-// impl<F:Float> BitAnd<Trivector<F>> for F {
-//     type Output = Trivector<F>;
-//     fn bitand(self, b: Trivector<F>) -> Trivector<F> {
-//         let e123 = self * b.e123();
-//         Trivector::new(e123)
-//     }
-// }
-
-// // Trivector-Scalar
-// impl<F:Float> BitAnd<F> for Trivector<F> {
-//     type Output = Trivector<F>;
-//     fn bitand(self, b: F) -> Trivector<F> {
-//         let e123 = self.e123() * b;
-//         Trivector::new(e123)
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitAnd, bitand for F, Trivector<F>);
-
-// // Scalar-Multivector
-// impl<F:Float> BitAnd<Multivector<F>> for F {
-//     type Output = Multivector<F>;
-//     fn bitand(self, b: Multivector<F>) -> Multivector<F> {
-//         let scalar = self * b.scalar();
-//         let vector = self * b.vector();
-//         let bivector = self * b.bivector();
-//         let trivector = self * b.trivector();
-//         Multivector::new(scalar, vector, bivector, trivector)
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitAnd, bitand for Trivector<F>,F);
-
-// // Multivector-Scalar
-// impl<F:Float> BitAnd<F> for Multivector<F> {
-//     type Output = Multivector<F>;
-//     fn bitand(self, b: F) -> Multivector<F> {
-//         let scalar = self.scalar() * b;
-//         let vector = self.vector() * b;
-//         let bivector = self.bivector() * b;
-//         let trivector = self.trivector() * b;
-//         Multivector::new(scalar, vector, bivector, trivector)
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitAnd, bitand for F, Multivector<F>);
-
-// // Scalar-Rotor
-// // \[ s R\]
-// impl<F:Float> BitAnd<Rotor<F>> for F {
-//     type Output = Multivector<F>;
-//     fn bitand(self: F, b: Rotor<F>) -> Multivector<F> {
-//         Multivector::new(
-//             self * b.scalar(),
-//             Vector::zero(),
-//             self * b.bivector(),
-//             Trivector::zero(),
-//         )
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitAnd, bitand for Rotor<F>, F);
-
-// //Rotor-Scalar
-// // \[ R b\]
-// impl<F:Float> BitAnd<F> for Rotor<F> {
-//     type Output = Multivector<F>;
-//     fn bitand(self: Rotor<F>, b: F) -> Multivector<F> {
-//         Multivector::new(
-//             self.scalar() * b,
-//             Vector::zero(),
-//             self.bivector() * b,
-//             Trivector::zero(),
-//         )
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitAnd, bitand for F, Rotor<F>);
+// Scalar-Scalar
+// the regressive product of two scalars is zero in 3D
+impl<F: Float> BitAnd for Scalar<F> {
+    type Output = F;
+    fn bitand(self: Scalar<F>, _b: Scalar<F>) -> F {
+        F::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Scalar<F>);
+
+// Scalar-Vector
+// $$ s \vee \vec{b} = 0 $$
+impl<F: Float> BitAnd<Vector<F>> for Scalar<F> {
+    type Output = F;
+    fn bitand(self: Scalar<F>, _b: Vector<F>) -> F {
+        F::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Vector<F>);
+
+// Vector-Scalar
+// $$ \vec{a} \vee s = 0 $$
+impl<F: Float> BitAnd<Scalar<F>> for Vector<F> {
+    type Output = F;
+    fn bitand(self: Vector<F>, _b: Scalar<F>) -> F {
+        F::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Scalar<F>);
+
+// Scalar-Bivector
+// $$ s \vee \overset\Rightarrow{b} = 0 $$
+impl<F: Float> BitAnd<Bivector<F>> for Scalar<F> {
+    type Output = F;
+    fn bitand(self: Scalar<F>, _b: Bivector<F>) -> F {
+        F::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Bivector<F>);
+
+// Bivector-Scalar
+// $$ \overset\Rightarrow{a} \vee s = 0 $$
+impl<F: Float> BitAnd<Scalar<F>> for Bivector<F> {
+    type Output = F;
+    fn bitand(self: Bivector<F>, _b: Scalar<F>) -> F {
+        F::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Scalar<F>);
+
+// Scalar-Trivector
+// $$ s \vee \overset\Rrightarrow{b} $$
+impl<F: Float> BitAnd<Trivector<F>> for Scalar<F> {
+    type Output = F;
+    fn bitand(self: Scalar<F>, b: Trivector<F>) -> F {
+        (Trivector::new(-self.0) * Scalar(-b.dual().0)).dual().0
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Trivector<F>);
+
+// Trivector-Scalar
+// $$ \overset\Rrightarrow{a} \vee s $$
+impl<F: Float> BitAnd<Scalar<F>> for Trivector<F> {
+    type Output = F;
+    fn bitand(self: Trivector<F>, b: Scalar<F>) -> F {
+        (Scalar(-self.dual().0) * Trivector::new(-b.0)).dual().0
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Trivector<F>, Scalar<F>);
+
+// Scalar-Multivector
+// $$ s \vee B $$
+impl<F: Float> BitAnd<Multivector<F>> for Scalar<F> {
+    type Output = F;
+    fn bitand(self: Scalar<F>, b: Multivector<F>) -> F {
+        self & b.trivector()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Multivector<F>);
+
+// Multivector-Scalar
+// $$ A \vee s $$
+impl<F: Float> BitAnd<Scalar<F>> for Multivector<F> {
+    type Output = F;
+    fn bitand(self: Multivector<F>, b: Scalar<F>) -> F {
+        self.trivector() & b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Scalar<F>);
+
+// Scalar-Rotor
+// $$ s \vee R = 0 $$
+impl<F: Float> BitAnd<Rotor<F>> for Scalar<F> {
+    type Output = F;
+    fn bitand(self: Scalar<F>, _b: Rotor<F>) -> F {
+        F::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Scalar<F>, Rotor<F>);
+
+// Rotor-Scalar
+// $$ R \vee s = 0 $$
+impl<F: Float> BitAnd<Scalar<F>> for Rotor<F> {
+    type Output = F;
+    fn bitand(self: Rotor<F>, _b: Scalar<F>) -> F {
+        F::zero()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Rotor<F>, Scalar<F>);
 
 // Vector-Vector
 // the regressive product does not exsist for vectors
@@ -172,7 +159,7 @@ forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Vector<F>);
 impl<F: Float> BitAnd<Bivector<F>> for Vector<F> {
     type Output = F;
     fn bitand(self: Vector<F>, b: Bivector<F>) -> F {
-        (-self.dual() ^ -b.dual()).dual()
+        (-self.dual() ^ -b.dual()).dual().0
     }
 }
 forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Bivector<F>);
@@ -182,7 +169,7 @@ forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Bivector<F>);
 impl<F: Float> BitAnd<Vector<F>> for Bivector<F> {
     type Output = F;
     fn bitand(self: Bivector<F>, b: Vector<F>) -> F {
-        (-self.dual() ^ -b.dual()).dual()
+        (-self.dual() ^ -b.dual()).dual().0
     }
 }
 forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Vector<F>);
@@ -192,7 +179,7 @@ forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Vector<F>);
 impl<F: Float> BitAnd<Trivector<F>> for Vector<F> {
     type Output = Vector<F>;
     fn bitand(self: Vector<F>, b: Trivector<F>) -> Vector<F> {
-        (-self.dual() * Scalar(-b.dual())).dual()
+        (-self.dual() * Scalar(-b.dual().0)).dual()
     }
 }
 forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Trivector<F>);
@@ -202,7 +189,7 @@ forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Trivector<F>);
 impl<F: Float> BitAnd<Vector<F>> for Trivector<F> {
     type Output = Vector<F>;
     fn bitand(self: Trivector<F>, b: Vector<F>) -> Vector<F> {
-        (Scalar(-self.dual()) * -b.dual()).dual()
+        (Scalar(-self.dual().0) * -b.dual()).dual()
     }
 }
 forward_ref_binop!(impl<F:Float> BitAnd, bitand for Trivector<F>, Vector<F>);
@@ -227,33 +214,25 @@ impl<F: Float> BitAnd<Vector<F>> for Multivector<F> {
 }
 forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Vector<F>);
 
-// // Vector-Rotor
-// // \[ \vec{a}R\]
-// impl<F:Float> BitAnd<Rotor<F>> for Vector<F> {
-//     type Output = Multivector<F>;
-//     fn bitand(self: Vector<F>, b: Rotor<F>) -> Multivector<F> {
-//         let scalar = F::zero();
-//         let vector = self * b.scalar();
-//         let bivector = Bivector::zero();
-//         let trivector = Trivector::zero();
-//         Multivector::new(scalar, vector, bivector, trivector) + self * b.bivector()
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitAnd, bitand for Rotor<F>, Vector<F>);
-
-// // Rotor-Vector
-// // \[ \overset\Rrightarrow{a}\vec{b}\]
-// impl<F:Float> BitAnd<Vector<F>> for Rotor<F> {
-//     type Output = Multivector<F>;
-//     fn bitand(self: Rotor<F>, b: Vector<F>) -> Multivector<F> {
-//         let scalar = F::zero();
-//         let vector = b * self.scalar();
-//         let bivector = Bivector::zero();
-//         let trivector = Trivector::zero();
-//         Multivector::new(scalar, vector, bivector, trivector) + self.bivector() * b
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Rotor<F>);
+// Vector-Rotor
+// $$ \vec{a} \vee R $$
+impl<F: Float> BitAnd<Rotor<F>> for Vector<F> {
+    type Output = F;
+    fn bitand(self: Vector<F>, b: Rotor<F>) -> F {
+        self & b.bivector()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Vector<F>, Rotor<F>);
+
+// Rotor-Vector
+// $$ R \vee \vec{b} $$
+impl<F: Float> BitAnd<Vector<F>> for Rotor<F> {
+    type Output = F;
+    fn bitand(self: Rotor<F>, b: Vector<F>) -> F {
+        self.bivector() & b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Rotor<F>, Vector<F>);
 
 // Bivector-Bivector
 // $$ \overset\Rightarrow{a} \vee \overset\Rightarrow{b} $$
@@ -270,7 +249,7 @@ forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Bivector<F>);
 impl<F: Float> BitAnd<Trivector<F>> for Bivector<F> {
     type Output = Bivector<F>;
     fn bitand(self: Bivector<F>, b: Trivector<F>) -> Bivector<F> {
-        (-self.dual() * Scalar(-b.dual())).dual()
+        (-self.dual() * Scalar(-b.dual().0)).dual()
     }
 }
 forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Trivector<F>);
@@ -280,7 +259,7 @@ forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Trivector<F>);
 impl<F: Float> BitAnd<Bivector<F>> for Trivector<F> {
     type Output = Bivector<F>;
     fn bitand(self: Trivector<F>, b: Bivector<F>) -> Bivector<F> {
-        (Scalar(-self.dual()) * -b.dual()).dual()
+        (Scalar(-self.dual().0) * -b.dual()).dual()
     }
 }
 forward_ref_binop!(impl<F:Float> BitAnd, bitand for Trivector<F>, Bivector<F>);
@@ -305,32 +284,32 @@ impl<F: Float> BitAnd<Bivector<F>> for Multivector<F> {
 }
 forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Bivector<F>);
 
-// // Bivector-Rotor
-// // \[ \vec{a}R\]
-// impl<F:Float> BitAnd<Rotor<F>> for Bivector<F> {
-//     type Output = Multivector<F>;
-//     fn bitand(self: Bivector<F>, b: Rotor<F>) -> Multivector<F> {
-//         self * b.scalar() + self * b.bivector()
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Rotor<F>);
-
-// // Rotor-Bivector
-// // \[ \overset\Rrightarrow{a}\vec{b}\]
-// impl<F:Float> BitAnd<Bivector<F>> for Rotor<F> {
-//     type Output = Multivector<F>;
-//     fn bitand(self: Rotor<F>, b: Bivector<F>) -> Multivector<F> {
-//         self.scalar() * b + self.bivector() * b
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitAnd, bitand for Rotor<F>, Bivector<F>);
+// Bivector-Rotor
+// $$ \overset\Rightarrow{a} \vee R $$
+impl<F: Float> BitAnd<Rotor<F>> for Bivector<F> {
+    type Output = Vector<F>;
+    fn bitand(self: Bivector<F>, b: Rotor<F>) -> Vector<F> {
+        self & b.bivector()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Bivector<F>, Rotor<F>);
+
+// Rotor-Bivector
+// $$ R \vee \overset\Rightarrow{b} $$
+impl<F: Float> BitAnd<Bivector<F>> for Rotor<F> {
+    type Output = Vector<F>;
+    fn bitand(self: Rotor<F>, b: Bivector<F>) -> Vector<F> {
+        self.bivector() & b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Rotor<F>, Bivector<F>);
 
 // Trivector-Trivector
 // \[ \overset\Rrightarrow{a} \vee \overset\Rrightarrow{b}\]
 impl<F: Float> BitAnd for Trivector<F> {
     type Output = Trivector<F>;
     fn bitand(self: Trivector<F>, b: Trivector<F>) -> Trivector<F> {
-        Trivector::new((-self.dual()) * (-b.dual()))
+        Trivector::new((-self.dual().0) * (-b.dual().0))
     }
 }
 forward_ref_binop!(impl<F:Float> BitAnd, bitand for Trivector<F>, Trivector<F>);
@@ -340,7 +319,7 @@ forward_ref_binop!(impl<F:Float> BitAnd, bitand for Trivector<F>, Trivector<F>);
 impl<F: Float> BitAnd<Multivector<F>> for Trivector<F> {
     type Output = Multivector<F>;
     fn bitand(self: Trivector<F>, b: Multivector<F>) -> Multivector<F> {
-        (Scalar(-self.dual()) * -b.dual()).dual()
+        (Scalar(-self.dual().0) * -b.dual()).dual()
     }
 }
 forward_ref_binop!(impl<F:Float> BitAnd, bitand for Trivector<F>,Multivector<F>);
@@ -350,38 +329,40 @@ forward_ref_binop!(impl<F:Float> BitAnd, bitand for Trivector<F>,Multivector<F>)
 impl<F: Float> BitAnd<Trivector<F>> for Multivector<F> {
     type Output = Multivector<F>;
     fn bitand(self: Multivector<F>, b: Trivector<F>) -> Multivector<F> {
-        (-self.dual() * Scalar(-b.dual())).dual()
+        (-self.dual() * Scalar(-b.dual().0)).dual()
     }
 }
 forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Trivector<F>);
 
-// // Trivector-Rotor
-// // \[ \vec{a}R\]
-// impl<F:Float> BitAnd<Rotor<F>> for Trivector<F> {
-//     type Output = Multivector<F>;
-//     fn bitand(self: Trivector<F>, b: Rotor<F>) -> Multivector<F> {
-//         let scalar = F::zero();
-//         let vector = self * b.bivector();
-//         let bivector = Bivector::zero();
-//         let trivector = self * b.scalar();
-//         Multivector::new(scalar, vector, bivector, trivector)
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitAnd, bitand for Trivector<F>,Rotor<F>);
-
-// // Rotor-Trivector
-// // \[ \overset\Rrightarrow{a}\vec{b}\]
-// impl<F:Float> BitAnd<Trivector<F>> for Rotor<F> {
-//     type Output = Multivector<F>;
-//     fn bitand(self: Rotor<F>, b: Trivector<F>) -> Multivector<F> {
-//         let scalar = F::zero();
-//         let vector = self.bivector() * b;
-//         let bivector = Bivector::zero();
-//         let trivector = b * self.scalar();
-//         Multivector::new(scalar, vector, bivector, trivector)
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitAnd, bitand for Rotor<F>, Trivector<F>);
+// Trivector-Rotor
+// $$ \overset\Rrightarrow{a} \vee R $$
+impl<F: Float> BitAnd<Rotor<F>> for Trivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self: Trivector<F>, b: Rotor<F>) -> Multivector<F> {
+        Multivector::new(
+            self & b.scalar,
+            Vector::zero(),
+            self & b.bivector(),
+            Trivector::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Trivector<F>, Rotor<F>);
+
+// Rotor-Trivector
+// $$ R \vee \overset\Rrightarrow{b} $$
+impl<F: Float> BitAnd<Trivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitand(self: Rotor<F>, b: Trivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar & b,
+            Vector::zero(),
+            self.bivector() & b,
+            Trivector::zero(),
+        )
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Rotor<F>, Trivector<F>);
 
 // Multivector-Multivector
 // \[ A \vee B\]
@@ -393,25 +374,40 @@ impl<F: Float> BitAnd for Multivector<F> {
 }
 forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Multivector<F>);
 
-// // Multivector-Rotor
-// // \[ \vec{a}R\]
-// impl<F:Float> BitAnd<Rotor<F>> for Multivector<F> {
-//     type Output = Multivector<F>;
-//     fn bitand(self: Multivector<F>, b: Rotor<F>) -> Multivector<F> {
-//         (self * b.scalar()) + (self * b.bivector())
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Rotor<F>);
-
-// // Rotor-Multivector
-// // \[ \overset\Rrightarrow{a}\vec{b}\]
-// impl<F:Float> BitAnd<Multivector<F>> for Rotor<F> {
-//     type Output = Multivector<F>;
-//     fn bitand(self: Rotor<F>, b: Multivector<F>) -> Multivector<F> {
-//         (self.scalar() * b) + (self.bivector() * b)
-//     }
-// }
-// forward_ref_binop!(impl<F:Float> BitAnd, bitand for Rotor<F>, Multivector<F>);
+// Multivector-Rotor
+// $$ A \vee R $$
+impl<F: Float> BitAnd<Rotor<F>> for Multivector<F> {
+    type Output = Multivector<F>;
+    fn bitand(self: Multivector<F>, b: Rotor<F>) -> Multivector<F> {
+        self & Multivector::new(b.scalar(), Vector::zero(), b.bivector(), Trivector::zero())
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Multivector<F>, Rotor<F>);
+
+// Rotor-Multivector
+// $$ R \vee B $$
+impl<F: Float> BitAnd<Multivector<F>> for Rotor<F> {
+    type Output = Multivector<F>;
+    fn bitand(self: Rotor<F>, b: Multivector<F>) -> Multivector<F> {
+        Multivector::new(
+            self.scalar(),
+            Vector::zero(),
+            self.bivector(),
+            Trivector::zero(),
+        ) & b
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Rotor<F>, Multivector<F>);
+
+// Rotor-Rotor
+// $$ R_1 \vee R_2 $$
+impl<F: Float> BitAnd for Rotor<F> {
+    type Output = Vector<F>;
+    fn bitand(self: Rotor<F>, b: Rotor<F>) -> Vector<F> {
+        self.bivector() & b.bivector()
+    }
+}
+forward_ref_binop!(impl<F:Float> BitAnd, bitand for Rotor<F>, Rotor<F>);
 
 // Test
 #[cfg(test)]
@@ -644,4 +640,294 @@ mod regressive_product {
         assert_relative_eq!(res.e23(), -9.0, max_relative = 0.000001);
         assert_relative_eq!(res.e123(), -1.0, max_relative = 0.000001);
     }
+
+    #[test]
+    fn scalar_scalar() {
+        // 3
+        let scalar1 = Scalar(3.0);
+        // 2
+        let scalar2 = Scalar(2.0);
+        let res = scalar1 & scalar2;
+        // 0
+        assert_relative_eq!(res, 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn scalar_vector() {
+        // 3
+        let scalar = Scalar(3.0);
+        // 3e1+5e2+4e3
+        let vector = Vector::new(3.0, 5.0, 4.0);
+        let res = scalar & vector;
+        // 0
+        assert_relative_eq!(res, 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_scalar() {
+        // 3e1+5e2+4e3
+        let vector = Vector::new(3.0, 5.0, 4.0);
+        // 3
+        let scalar = Scalar(3.0);
+        let res = vector & scalar;
+        // 0
+        assert_relative_eq!(res, 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn scalar_bivector() {
+        // 3
+        let scalar = Scalar(3.0);
+        // 2e12+e31+6e23
+        let bivector = Bivector::new(2.0, 1.0, 6.0);
+        let res = scalar & bivector;
+        // 0
+        assert_relative_eq!(res, 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_scalar() {
+        // 2e12+e31+6e23
+        let bivector = Bivector::new(2.0, 1.0, 6.0);
+        // 3
+        let scalar = Scalar(3.0);
+        let res = bivector & scalar;
+        // 0
+        assert_relative_eq!(res, 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn scalar_rotor() {
+        // 3
+        let scalar = Scalar(3.0);
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        let res = scalar & rotor;
+        // 0
+        assert_relative_eq!(res, 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_scalar() {
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        // 3
+        let scalar = Scalar(3.0);
+        let res = rotor & scalar;
+        // 0
+        assert_relative_eq!(res, 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn scalar_trivector() {
+        // 3
+        let scalar = Scalar(3.0);
+        // 2e123
+        let trivector = Trivector::new(2.0);
+        let res = scalar & trivector;
+        // 6
+        assert_relative_eq!(res, 6.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn trivector_scalar() {
+        // 2e123
+        let trivector = Trivector::new(2.0);
+        // 3
+        let scalar = Scalar(3.0);
+        let res = trivector & scalar;
+        // 6
+        assert_relative_eq!(res, 6.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn scalar_multivector() {
+        // 3
+        let scalar = Scalar(3.0);
+        // ( 5.0 + 8.0e1 + 7.0e2 + 3.0e3 + 2.0e12 + 8.0e31 + 2.0e23 + 1.0e123 )
+        let mvec = Multivector::new_components(5.0, 8.0, 7.0, 3.0, 2.0, 8.0, 2.0, 1.0);
+        let res = scalar & mvec;
+        // 3
+        assert_relative_eq!(res, 3.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_scalar() {
+        // ( 5.0 + 8.0e1 + 7.0e2 + 3.0e3 + 2.0e12 + 8.0e31 + 2.0e23 + 1.0e123 )
+        let mvec = Multivector::new_components(5.0, 8.0, 7.0, 3.0, 2.0, 8.0, 2.0, 1.0);
+        // 3
+        let scalar = Scalar(3.0);
+        let res = mvec & scalar;
+        // 3
+        assert_relative_eq!(res, 3.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn vector_rotor() {
+        // 3e1+5e2+4e3
+        let vector = Vector::new(3.0, 5.0, 4.0);
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        let res = vector & rotor;
+        // 6
+        assert_relative_eq!(res, 6.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_vector() {
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        // 3e1+5e2+4e3
+        let vector = Vector::new(3.0, 5.0, 4.0);
+        let res = rotor & vector;
+        // 6
+        assert_relative_eq!(res, 6.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn bivector_rotor() {
+        // 2e12+e31+6e23
+        let bivector = Bivector::new(2.0, 1.0, 6.0);
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        let res = bivector & rotor;
+        // 0.5e1+2e2-2.5e3
+        assert_relative_eq!(res.e1(), 0.5, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), -2.5, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_bivector() {
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        // 2e12+e31+6e23
+        let bivector = Bivector::new(2.0, 1.0, 6.0);
+        let res = rotor & bivector;
+        // -0.5e1-2e2+2.5e3
+        assert_relative_eq!(res.e1(), -0.5, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), -2.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 2.5, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn trivector_rotor() {
+        // 2e123
+        let trivector = Trivector::new(2.0);
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        let res = trivector & rotor;
+        // 1+e12+e31+e23
+        assert_relative_eq!(res.scalar(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_trivector() {
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        // 2e123
+        let trivector = Trivector::new(2.0);
+        let res = rotor & trivector;
+        // 1+e12+e31+e23
+        assert_relative_eq!(res.scalar(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn multivector_rotor() {
+        // ( 5.0 + 8.0e1 + 7.0e2 + 3.0e3 + 2.0e12 + 8.0e31 + 2.0e23 + 1.0e123 )
+        let mvec = Multivector::new_components(5.0, 8.0, 7.0, 3.0, 2.0, 8.0, 2.0, 1.0);
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        let res = mvec & rotor;
+        // 9.5-3e1+3e3+0.5e12+0.5e31+0.5e23
+        assert_relative_eq!(res.scalar(), 9.5, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 0.5, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 0.5, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 0.5, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_multivector() {
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        // ( 5.0 + 8.0e1 + 7.0e2 + 3.0e3 + 2.0e12 + 8.0e31 + 2.0e23 + 1.0e123 )
+        let mvec = Multivector::new_components(5.0, 8.0, 7.0, 3.0, 2.0, 8.0, 2.0, 1.0);
+        let res = rotor & mvec;
+        // 9.5+3e1-3e3+0.5e12+0.5e31+0.5e23
+        assert_relative_eq!(res.scalar(), 9.5, max_relative = 0.000001);
+        assert_relative_eq!(res.e1(), 3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), -3.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 0.5, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 0.5, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 0.5, max_relative = 0.000001);
+        assert_relative_eq!(res.e123(), 0.0, max_relative = 0.000001);
+    }
+
+    #[test]
+    fn rotor_rotor() {
+        // 0.5+0.5e12+0.5e31+0.5e23
+        let rotor1 = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        // 0.6+0.8e12
+        let rotor2 = Rotor {
+            scalar: Scalar(0.6),
+            bivector: Bivector::new(0.8, 0.0, 0.0),
+        };
+        let res = rotor1 & rotor2;
+        // -0.4e1+0.4e2
+        assert_relative_eq!(res.e1(), -0.4, max_relative = 0.000001);
+        assert_relative_eq!(res.e2(), 0.4, max_relative = 0.000001);
+        assert_relative_eq!(res.e3(), 0.0, max_relative = 0.000001);
+    }
 }
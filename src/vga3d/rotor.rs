@@ -27,6 +27,7 @@ use std::fmt;
 use defmt::Format;
 
 use crate::forward_ref_binop;
+use crate::linear_algebra::{add, identity, multiply, scale, transpose};
 use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Index, IndexMut, Mul, Neg, Not, Sub};
 
 use num_traits::Float;
@@ -43,7 +44,11 @@ use super::{
 /// The rotor is the rotation object in Geometric Algebra
 /// $$ R\left (\frac{\theta}{2},\overset\Rightarrow{b} \right ) = \mathrm{e}^{ \overset\Rightarrow{b} \frac{\theta}{2}} = \cos \left( \frac{\theta}{2}  \right) + \sin \left( \frac{\theta}{2} \right)(b_1 \mathrm{e}_1\mathrm{e}_2 + b_2 \mathrm{e}_3\mathrm{e}_1 + b_3 \mathrm{e}_2\mathrm{e}_3) $$
 /// The norm of a rotor is always 1
+///
+/// The layout is `#[repr(C)]` and the same as `[F; 4]` with the components in the order
+/// scalar, $\mathrm{e}_{12}$, $\mathrm{e}_{31}$, $\mathrm{e}_{23}$.
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
 pub struct Rotor<F: Float> {
     pub(super) scalar: Scalar<F>,
     pub(super) bivector: Bivector<F>,
@@ -78,25 +83,26 @@ impl<F: Float + defmt::Format> defmt::Format for Rotor<F> {
 impl<F: Float> Rotor<F> {
     /// Creates new rotor from an angle bivector $\overset\Rightarrow{\theta}$.
     /// The angle must be in radians and must be half the rotational angle.
+    /// $$ R = \mathrm{e}^{\overset\Rightarrow{\theta}} $$
     pub fn new(half_angle_bivector: Bivector<F>) -> Self {
-        let half_angle = half_angle_bivector.norm().scalar();
-
-        // The only time a angle bivectors norm is zero is when the angle is zero
-        // cos(0.0) = 1.0
-        // sin(0.0) = 0.0
-        if half_angle == F::zero() {
-            return Rotor {
-                scalar: Scalar(F::one()),
-                bivector: Bivector::new(F::zero(), F::zero(), F::zero()),
-            };
-        }
-        // Normilize the rotation plane
-        // multiply by the sin of the half angle
-        let bi_pre = Scalar(half_angle.sin() / half_angle);
-        Rotor {
-            scalar: Scalar(half_angle.cos()),
-            bivector: half_angle_bivector * bi_pre,
-        }
+        half_angle_bivector.exp()
+    }
+
+    /// Tries to create a rotor from its scalar and bivector parts
+    /// The parts are normalized so the rotor has unit norm
+    /// $$ R = \frac{s + \overset\Rightarrow{b}}{\left| s + \overset\Rightarrow{b} \right|} $$
+    /// Returns `None` if both parts are zero.
+    pub fn try_from_parts(scalar: F, bivector: Bivector<F>) -> Option<Self> {
+        let norm = (scalar * scalar
+            + bivector.e12() * bivector.e12()
+            + bivector.e31() * bivector.e31()
+            + bivector.e23() * bivector.e23())
+        .sqrt();
+        let norm_inverse = Scalar(norm).try_inverse()?;
+        Some(Rotor {
+            scalar: Scalar(scalar * norm_inverse.0),
+            bivector: bivector * norm_inverse,
+        })
     }
 
     /// Tries to creates new rotor from plane of rotation and angle of rotationen
@@ -158,18 +164,40 @@ impl<F: Float> Rotor<F> {
     }
 
     /// Get the angle of the rotor
+    /// $$ \frac{\theta}{2} = \operatorname{atan2} \left( \left| \overset\Rightarrow{b} \right|, s \right) $$
     pub fn get_half_angle(&self) -> Scalar<F> {
-        Scalar(self.scalar().acos())
+        Scalar(self.bivector.norm().scalar().atan2(self.scalar()))
     }
 
     /// Get the plane of rotation of the rotor
     /// This is not always posible
     /// sin(0) = sin(tau/2) = 0
-    pub fn try_get_rotation_plane(&self) -> Bivector<F> {
-        let sin = Scalar(self.get_half_angle().scalar().sin());
-        match sin.try_inverse() {
-            None => Bivector::zero(),
-            Some(sin_inverse) => self.bivector * sin_inverse,
+    ///
+    /// Returns `None` at the half angles $0$ and $\pi$, where the plane is not defined.
+    pub fn try_get_rotation_plane(&self) -> Option<Bivector<F>> {
+        self.bivector
+            .norm()
+            .try_inverse()
+            .map(|norm_inverse| self.bivector * norm_inverse)
+    }
+
+    /// # Logarithm
+    /// The logarithm of a rotor is the half angle bivector $\overset\Rightarrow{\theta}$
+    /// $$ \log(R) = \frac{\operatorname{atan2} \left( \left| \overset\Rightarrow{b} \right|, s \right)}{\left| \overset\Rightarrow{b} \right|}\overset\Rightarrow{b} $$
+    /// Close to the identity the Taylor series $\frac{\operatorname{atan}(x)}{x} \approx 1 - \frac{x^2}{3}$ is used.
+    /// At $R=-1$ the plane of rotation is not defined and the $\mathrm{e}_1\mathrm{e}_2$ plane is used.
+    pub fn log(self) -> Bivector<F> {
+        let sin = self.bivector.norm().scalar();
+        let cos = self.scalar();
+        let three = F::one() + F::one() + F::one();
+
+        match sin {
+            sin if sin < F::epsilon().sqrt() && cos > F::zero() => {
+                let tan = sin / cos;
+                self.bivector * Scalar((F::one() - tan * tan / three) / cos)
+            }
+            sin if sin.is_zero() => Bivector::new((-F::one()).acos(), F::zero(), F::zero()),
+            _ => self.bivector * Scalar(sin.atan2(cos) / sin),
         }
     }
 }
@@ -222,7 +250,10 @@ mod rotor {
             Some(rotor) => rotor,
             None => Rotor::identity(),
         };
-        let rotor_rotation_plane = rotor.try_get_rotation_plane();
+        let rotor_rotation_plane = match rotor.try_get_rotation_plane() {
+            Some(rotation_plane) => rotation_plane,
+            None => Bivector::zero(),
+        };
 
         assert_relative_eq!(
             rotor_rotation_plane.e12() * norm.scalar(),
@@ -242,6 +273,558 @@ mod rotor {
     }
 }
 
+#[cfg(test)]
+mod rotor_log {
+    use super::*;
+    use approx::assert_relative_eq;
+    use core::f32::consts::TAU;
+
+    #[test]
+    fn log() {
+        // log(0.70710677+0.52522576e12+0.26261288e31-0.3939193e23)
+        let rotation_plane = Bivector::new(4.0, 2.0, -3.0);
+        let bivector = rotation_plane * Scalar(TAU / 8.0 / rotation_plane.norm().scalar());
+        let res = Rotor::new(bivector).log();
+        assert_relative_eq!(res.e12(), bivector.e12(), max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), bivector.e31(), max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), bivector.e23(), max_relative = 0.000001);
+    }
+    #[test]
+    fn log_identity() {
+        // log(1) = 0
+        let res = Rotor::<f32>::identity().log();
+        assert_relative_eq!(res.norm().scalar(), 0.0, max_relative = 0.000001);
+    }
+    #[test]
+    fn log_small() {
+        // log(e^{10^{-5} e31}) = 10^{-5} e31
+        let bivector = Bivector::new(0.0, 0.00001, 0.0);
+        let res = bivector.exp().log();
+        assert_relative_eq!(res.e12(), 0.0, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 0.00001, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 0.0, max_relative = 0.000001);
+    }
+    #[test]
+    fn log_half_turn() {
+        // A rotation of TAU/2 has the half angle TAU/4
+        // log(e23) = TAU/4 e23
+        let rotor = Rotor {
+            scalar: Scalar(0.0),
+            bivector: Bivector::new(0.0, 0.0, 1.0),
+        };
+        let res = rotor.log();
+        assert_relative_eq!(res.e23(), TAU / 4.0, max_relative = 0.000001);
+        assert_relative_eq!(rotor.get_half_angle().scalar(), TAU / 4.0, max_relative = 0.000001);
+    }
+    #[test]
+    fn log_full_turn() {
+        // e^{log(-1)} = -1
+        let rotor = Rotor {
+            scalar: Scalar(-1.0),
+            bivector: Bivector::zero(),
+        };
+        let res = rotor.log().exp();
+        assert_relative_eq!(res.scalar(), -1.0, max_relative = 0.000001);
+        assert_relative_eq!(res.bivector().norm().scalar(), 0.0, epsilon = 0.000001);
+        assert_eq!(rotor.try_get_rotation_plane(), None);
+    }
+    #[test]
+    fn exp_log() {
+        // e^{log(R)} = R
+        let rotor = Rotor {
+            scalar: Scalar(-0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        let res = rotor.log().exp();
+        assert_relative_eq!(res.scalar(), -0.5, max_relative = 0.000001);
+        assert_relative_eq!(res.e12(), 0.5, max_relative = 0.000001);
+        assert_relative_eq!(res.e31(), 0.5, max_relative = 0.000001);
+        assert_relative_eq!(res.e23(), 0.5, max_relative = 0.000001);
+    }
+}
+
+impl<F: Float> Rotor<F> {
+    /// Tries to create the rotor that rotates the direction of $\vec{a}$ onto the direction of $\vec{b}$
+    /// $$ R = \frac{1 + \hat{a}\hat{b}}{\left| 1 + \hat{a}\hat{b} \right|} $$
+    /// The rotation is in the plane $\vec{a}\wedge\vec{b}$.
+    /// When the vectors are antiparallel the plane of rotation is not defined,
+    /// the plane spanned by $\vec{a}$ and the basis vector least aligned with it is used.
+    /// Returns `None` if one of the vectors is zero.
+    pub fn try_from_vectors(a: Vector<F>, b: Vector<F>) -> Option<Self> {
+        let a = a.try_normalize()?;
+        let b = b.try_normalize()?;
+        let two = F::one() + F::one();
+
+        // |1 + ab|^2 = 2 + 2 a.b
+        let cos = (a | b).0;
+        let norm = (two + two * cos).sqrt();
+        match norm {
+            norm if norm > F::epsilon().sqrt() => Some(Rotor {
+                scalar: Scalar((F::one() + cos) / norm),
+                bivector: (a ^ b) * Scalar(F::one() / norm),
+            }),
+            _ => {
+                // Half a turn in a plane containing a
+                let (e1, e2, e3) = (a.e1().abs(), a.e2().abs(), a.e3().abs());
+                let axis = match (e1, e2, e3) {
+                    (e1, e2, e3) if e1 <= e2 && e1 <= e3 => {
+                        Vector::new(F::one(), F::zero(), F::zero())
+                    }
+                    (_, e2, e3) if e2 <= e3 => Vector::new(F::zero(), F::one(), F::zero()),
+                    _ => Vector::new(F::zero(), F::zero(), F::one()),
+                };
+                Some(Rotor {
+                    scalar: Scalar(F::zero()),
+                    bivector: (a ^ axis).try_normalize()?,
+                })
+            }
+        }
+    }
+
+    /// Tries to create the rotor that rotates the frame $\{\vec{a}_1,\vec{a}_2,\vec{a}_3\}$
+    /// onto the frame $\{\vec{b}_1,\vec{b}_2,\vec{b}_3\}$
+    /// $$ \vec{b}_k = R^\dag \vec{a}_k R $$
+    /// When the frames are not orthonormal, or not related by a rotation,
+    /// the best-fit rotor in the least squares sense is used
+    /// $$ \min_R \sum_k \left| \vec{b}_k - R^\dag \vec{a}_k R \right|^2 $$
+    /// Returns `None` if the frames are degenerate or of opposite handedness.
+    pub fn try_from_frames(from: [Vector<F>; 3], to: [Vector<F>; 3]) -> Option<Self> {
+        // Correlation matrix
+        // \[ M = \sum_k \vec{b}_k \vec{a}_k^T \]
+        let mut correlation = [[F::zero(); 3]; 3];
+        for (a, b) in from.iter().zip(to.iter()) {
+            let (a, b) = ([a.e1(), a.e2(), a.e3()], [b.e1(), b.e2(), b.e3()]);
+            for (row, b_i) in correlation.iter_mut().zip(b.iter()) {
+                for (element, a_j) in row.iter_mut().zip(a.iter()) {
+                    *element = *element + *b_i * *a_j;
+                }
+            }
+        }
+        let rotation = try_orthogonalize(correlation)?;
+        Some(Rotor::from_rotation_matrix(rotation))
+    }
+
+    /// Tries to create the rotor that looks in the forward direction
+    /// $\mathrm{e}_3$ is rotated onto the forward direction
+    /// and $\mathrm{e}_2$ is rotated onto the up direction made orthogonal to the forward direction.
+    /// $\mathrm{e}_1$ is rotated onto the right direction $\hat{u}\times\hat{f}$.
+    /// Returns `None` if a direction is zero or the up direction is parallel to the forward direction.
+    pub fn try_look_at(forward: Vector<F>, up: Vector<F>) -> Option<Self> {
+        let forward = forward.try_normalize()?;
+        // Reject the forward direction from up
+        let up = (up - forward * (up | forward)).try_normalize()?;
+        let right = up.cross(forward);
+        Some(Rotor::from_rotation_matrix([
+            [right.e1(), up.e1(), forward.e1()],
+            [right.e2(), up.e2(), forward.e2()],
+            [right.e3(), up.e3(), forward.e3()],
+        ]))
+    }
+
+    /// # Rotation Matrix
+    /// The $3\times3$ rotation matrix of the rotor, indexed as `[row][column]`.
+    /// The columns are the rotated basis vectors
+    /// $$ M_{ij} = \mathrm{e}_i \cdot \left( R^\dag \mathrm{e}_j R \right) $$
+    /// The rotated vector is $M\vec{v}$.
+    /// The direction cosine matrix of the rotated frame is the transpose $M^T$.
+    pub fn to_matrix3(self) -> [[F; 3]; 3] {
+        let one = F::one();
+        let two = one + one;
+        let (w, x, y, z) = (self.scalar(), self.e23(), self.e31(), self.e12());
+        [
+            [
+                one - two * (y * y + z * z),
+                two * (x * y - w * z),
+                two * (x * z + w * y),
+            ],
+            [
+                two * (x * y + w * z),
+                one - two * (x * x + z * z),
+                two * (y * z - w * x),
+            ],
+            [
+                two * (x * z - w * y),
+                two * (y * z + w * x),
+                one - two * (x * x + y * y),
+            ],
+        ]
+    }
+
+    /// Tries to create the rotor from a $3\times3$ rotation matrix, indexed as `[row][column]`.
+    /// The columns are the rotated basis vectors, see `to_matrix3`.
+    ///
+    /// The columns must be orthonormal within a tolerance of $0.05$
+    /// $$ \left| M^T M - I \right|_{ij} \le 0.05 $$
+    /// The matrix is then made orthonormal with the closest rotation matrix,
+    /// so small errors from integration or floating point arithmetic are removed.
+    /// The rotor is extracted with Shepperd's method.
+    /// Returns `None` if the matrix is a reflection, $\det M \le 0$, or is not close to a rotation.
+    pub fn try_from_matrix3(m: [[F; 3]; 3]) -> Option<Self> {
+        let tolerance = F::from(0.05)?;
+        let gram = add(multiply(transpose(m), m), scale(identity(), -F::one()));
+        if !gram.iter().flatten().all(|element| element.abs() <= tolerance) {
+            return None;
+        }
+        let rotor = Rotor::from_rotation_matrix(try_orthogonalize(m)?);
+        let norm_inverse = rotor.norm().try_inverse()?;
+        Some(Rotor {
+            scalar: Scalar(rotor.scalar() * norm_inverse.0),
+            bivector: rotor.bivector * norm_inverse,
+        })
+    }
+
+    // Rotor from a rotation matrix with Shepperd's method
+    // The columns are the rotated basis vectors R^\dag e_j R
+    // The largest of the four diagonal terms is used to avoid division by small numbers
+    pub(super) fn from_rotation_matrix(m: [[F; 3]; 3]) -> Self {
+        let one = F::one();
+        let four = one + one + one + one;
+        let half = one / (one + one);
+        let trace = m[0][0] + m[1][1] + m[2][2];
+
+        // (scalar, e23, e31, e12)
+        let (scalar, e23, e31, e12) = match trace {
+            trace if trace >= m[0][0] && trace >= m[1][1] && trace >= m[2][2] => {
+                let scalar = (one + trace).sqrt() * half;
+                let s = one / (four * scalar);
+                (
+                    scalar,
+                    (m[2][1] - m[1][2]) * s,
+                    (m[0][2] - m[2][0]) * s,
+                    (m[1][0] - m[0][1]) * s,
+                )
+            }
+            _ if m[0][0] >= m[1][1] && m[0][0] >= m[2][2] => {
+                let e23 = (one + m[0][0] - m[1][1] - m[2][2]).sqrt() * half;
+                let s = one / (four * e23);
+                (
+                    (m[2][1] - m[1][2]) * s,
+                    e23,
+                    (m[0][1] + m[1][0]) * s,
+                    (m[0][2] + m[2][0]) * s,
+                )
+            }
+            _ if m[1][1] >= m[2][2] => {
+                let e31 = (one - m[0][0] + m[1][1] - m[2][2]).sqrt() * half;
+                let s = one / (four * e31);
+                (
+                    (m[0][2] - m[2][0]) * s,
+                    (m[0][1] + m[1][0]) * s,
+                    e31,
+                    (m[1][2] + m[2][1]) * s,
+                )
+            }
+            _ => {
+                let e12 = (one - m[0][0] - m[1][1] + m[2][2]).sqrt() * half;
+                let s = one / (four * e12);
+                (
+                    (m[1][0] - m[0][1]) * s,
+                    (m[0][2] + m[2][0]) * s,
+                    (m[1][2] + m[2][1]) * s,
+                    e12,
+                )
+            }
+        };
+        Rotor {
+            scalar: Scalar(scalar),
+            bivector: Bivector::new(e12, e31, e23),
+        }
+    }
+}
+
+// The closest rotation matrix to m, the orthogonal polar factor
+// Newton iteration
+// \[ X_{k+1} = \frac{1}{2}\left( X_k + X_k^{-T} \right) \]
+fn try_orthogonalize<F: Float>(m: [[F; 3]; 3]) -> Option<[[F; 3]; 3]> {
+    let half = F::one() / (F::one() + F::one());
+    let mut x = m;
+    for _ in 0..32 {
+        // The cofactor matrix is the inverse transpose times the determinant
+        let rows = [
+            Vector::new(x[0][0], x[0][1], x[0][2]),
+            Vector::new(x[1][0], x[1][1], x[1][2]),
+            Vector::new(x[2][0], x[2][1], x[2][2]),
+        ];
+        let cofactor = [
+            rows[1].cross(rows[2]),
+            rows[2].cross(rows[0]),
+            rows[0].cross(rows[1]),
+        ];
+        let determinant = (rows[0] | cofactor[0]).0;
+        if determinant <= F::zero() || !determinant.is_finite() {
+            return None;
+        }
+
+        let mut difference = F::zero();
+        for (row, cofactor) in x.iter_mut().zip(cofactor.iter()) {
+            let cofactor = [cofactor.e1(), cofactor.e2(), cofactor.e3()];
+            for (element, c) in row.iter_mut().zip(cofactor.iter()) {
+                let next = (*element + *c / determinant) * half;
+                difference = difference.max((next - *element).abs());
+                *element = next;
+            }
+        }
+        if difference <= F::epsilon() {
+            break;
+        }
+    }
+    Some(x)
+}
+
+#[cfg(test)]
+mod rotor_constructors {
+    use super::*;
+    use crate::vga3d::Rotatable;
+    use approx::assert_relative_eq;
+    use core::f32::consts::TAU;
+
+    fn assert_vector_eq(a: Vector<f32>, b: Vector<f32>) {
+        assert_relative_eq!(a.e1(), b.e1(), epsilon = 0.00001);
+        assert_relative_eq!(a.e2(), b.e2(), epsilon = 0.00001);
+        assert_relative_eq!(a.e3(), b.e3(), epsilon = 0.00001);
+    }
+
+    #[test]
+    fn from_parts() {
+        let rotor = Rotor::try_from_parts(2.0, Bivector::new(0.0, 0.0, 2.0)).unwrap();
+        assert_relative_eq!(rotor.scalar(), 0.5f32.sqrt(), epsilon = 0.00001);
+        assert_relative_eq!(rotor.e23(), 0.5f32.sqrt(), epsilon = 0.00001);
+        assert_relative_eq!(rotor.norm().0, 1.0, epsilon = 0.00001);
+        assert_eq!(Rotor::try_from_parts(0.0, Bivector::zero()), None);
+    }
+    #[test]
+    fn from_vectors() {
+        // 2e1+3e2+4e3 is rotated onto the direction of -e1+5e2+e3
+        let a = Vector::new(2.0, 3.0, 4.0);
+        let b = Vector::new(-1.0, 5.0, 1.0);
+        let rotor = match Rotor::try_from_vectors(a, b) {
+            Some(rotor) => rotor,
+            None => Rotor::identity(),
+        };
+        let res = a.rotate(rotor);
+        assert_vector_eq(res, b * Scalar(a.norm().0 / b.norm().0));
+        assert_relative_eq!(rotor.norm().0, 1.0, max_relative = 0.00001);
+    }
+    #[test]
+    fn from_vectors_quarter_turn() {
+        // e1 to e2 is a quarter turn in the e12 plane
+        let rotor =
+            match Rotor::try_from_vectors(Vector::new(1.0, 0.0, 0.0), Vector::new(0.0, 1.0, 0.0)) {
+                Some(rotor) => rotor,
+                None => Rotor::identity(),
+            };
+        assert_relative_eq!(rotor.scalar(), (TAU / 8.0).cos(), max_relative = 0.00001);
+        assert_relative_eq!(rotor.e12(), (TAU / 8.0).sin(), max_relative = 0.00001);
+    }
+    #[test]
+    fn from_vectors_antiparallel() {
+        // Half a turn in a plane containing a
+        let a = Vector::new(1.0, 2.0, -0.5);
+        let b = Vector::new(-2.0, -4.0, 1.0);
+        let rotor = match Rotor::try_from_vectors(a, b) {
+            Some(rotor) => rotor,
+            None => Rotor::identity(),
+        };
+        assert_vector_eq(a.rotate(rotor), -a);
+        assert_relative_eq!(rotor.norm().0, 1.0, max_relative = 0.00001);
+        assert_eq!(Rotor::try_from_vectors(a, Vector::zero()), None);
+    }
+    #[test]
+    fn from_frames() {
+        // The rotated frame gives back the rotor
+        let rotor = Bivector::new(0.3, -0.2, 0.5).exp();
+        let from = [
+            Vector::new(1.0, 0.0, 0.0),
+            Vector::new(0.0, 1.0, 0.0),
+            Vector::new(0.0, 0.0, 1.0),
+        ];
+        let to = from.map(|vector| vector.rotate(rotor));
+        let res = match Rotor::try_from_frames(from, to) {
+            Some(rotor) => rotor,
+            None => Rotor::identity(),
+        };
+        assert_relative_eq!(res.scalar(), rotor.scalar(), epsilon = 0.00001);
+        assert_relative_eq!(res.e12(), rotor.e12(), epsilon = 0.00001);
+        assert_relative_eq!(res.e31(), rotor.e31(), epsilon = 0.00001);
+        assert_relative_eq!(res.e23(), rotor.e23(), epsilon = 0.00001);
+    }
+    #[test]
+    fn from_frames_half_turn() {
+        // Half a turn in the e31 plane
+        let rotor = Bivector::new(0.0, TAU / 4.0, 0.0).exp();
+        let from = [
+            Vector::new(2.0, 1.0, 0.0),
+            Vector::new(0.0, 1.0, 1.0),
+            Vector::new(1.0, 0.0, 3.0),
+        ];
+        let to = from.map(|vector| vector.rotate(rotor));
+        let res = match Rotor::try_from_frames(from, to) {
+            Some(rotor) => rotor,
+            None => Rotor::identity(),
+        };
+        for vector in from {
+            assert_vector_eq(vector.rotate(res), vector.rotate(rotor));
+        }
+    }
+    #[test]
+    fn from_frames_best_fit() {
+        // A scaled and sheared frame gives the closest rotation
+        let rotor = Bivector::new(-0.4, 0.1, 0.2).exp();
+        let from = [
+            Vector::new(1.0, 0.0, 0.0),
+            Vector::new(0.0, 1.0, 0.0),
+            Vector::new(0.0, 0.0, 1.0),
+        ];
+        let to = [
+            Vector::new(2.0, 0.0, 0.0).rotate(rotor),
+            Vector::new(0.0, 2.0, 0.0).rotate(rotor),
+            Vector::new(0.0, 0.0, 2.0).rotate(rotor),
+        ];
+        let res = match Rotor::try_from_frames(from, to) {
+            Some(rotor) => rotor,
+            None => Rotor::identity(),
+        };
+        assert_relative_eq!(res.scalar(), rotor.scalar(), epsilon = 0.00001);
+        assert_relative_eq!(res.e12(), rotor.e12(), epsilon = 0.00001);
+        assert_relative_eq!(res.e31(), rotor.e31(), epsilon = 0.00001);
+        assert_relative_eq!(res.e23(), rotor.e23(), epsilon = 0.00001);
+    }
+    #[test]
+    fn from_frames_degenerate() {
+        // Coplanar and mirrored frames
+        let from = [
+            Vector::new(1.0, 0.0, 0.0),
+            Vector::new(0.0, 1.0, 0.0),
+            Vector::new(0.0, 0.0, 1.0),
+        ];
+        let coplanar = [
+            Vector::new(1.0, 0.0, 0.0),
+            Vector::new(0.0, 1.0, 0.0),
+            Vector::new(1.0, 1.0, 0.0),
+        ];
+        let mirrored = [
+            Vector::new(1.0, 0.0, 0.0),
+            Vector::new(0.0, 1.0, 0.0),
+            Vector::new(0.0, 0.0, -1.0),
+        ];
+        assert_eq!(Rotor::try_from_frames(from, coplanar), None);
+        assert_eq!(Rotor::try_from_frames(from, mirrored), None);
+    }
+    #[test]
+    fn look_at() {
+        // Looking along e1 with e3 up
+        let forward = Vector::new(3.0, 0.0, 0.0);
+        let up = Vector::new(0.5, 0.0, 2.0);
+        let rotor = match Rotor::try_look_at(forward, up) {
+            Some(rotor) => rotor,
+            None => Rotor::identity(),
+        };
+        assert_vector_eq(
+            Vector::new(0.0, 0.0, 1.0).rotate(rotor),
+            Vector::new(1.0, 0.0, 0.0),
+        );
+        assert_vector_eq(
+            Vector::new(0.0, 1.0, 0.0).rotate(rotor),
+            Vector::new(0.0, 0.0, 1.0),
+        );
+        assert_vector_eq(
+            Vector::new(1.0, 0.0, 0.0).rotate(rotor),
+            Vector::new(0.0, 1.0, 0.0),
+        );
+        assert_eq!(Rotor::try_look_at(forward, forward), None);
+    }
+}
+
+#[cfg(test)]
+mod rotor_matrix {
+    use super::*;
+    use crate::vga3d::Rotatable;
+    use approx::assert_relative_eq;
+    use core::f32::consts::TAU;
+
+    fn assert_rotor_eq(a: Rotor<f32>, b: Rotor<f32>) {
+        assert_relative_eq!(a.scalar(), b.scalar(), epsilon = 0.00001);
+        assert_relative_eq!(a.e12(), b.e12(), epsilon = 0.00001);
+        assert_relative_eq!(a.e31(), b.e31(), epsilon = 0.00001);
+        assert_relative_eq!(a.e23(), b.e23(), epsilon = 0.00001);
+    }
+
+    #[test]
+    fn to_matrix3() {
+        // The columns are the rotated basis vectors
+        let rotor = Bivector::new(0.3, -0.2, 0.5).exp();
+        let m = rotor.to_matrix3();
+        let basis = [
+            Vector::new(1.0, 0.0, 0.0),
+            Vector::new(0.0, 1.0, 0.0),
+            Vector::new(0.0, 0.0, 1.0),
+        ];
+        for (j, vector) in basis.iter().enumerate() {
+            let res = vector.rotate(rotor);
+            assert_relative_eq!(m[0][j], res.e1(), epsilon = 0.00001);
+            assert_relative_eq!(m[1][j], res.e2(), epsilon = 0.00001);
+            assert_relative_eq!(m[2][j], res.e3(), epsilon = 0.00001);
+        }
+    }
+    #[test]
+    fn quarter_turn() {
+        // A quarter turn in the e12 plane
+        let rotor = Bivector::new(TAU / 8.0, 0.0, 0.0).exp();
+        let m = rotor.to_matrix3();
+        let res = [[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]];
+        for (row, res_row) in m.iter().zip(res.iter()) {
+            for (element, res_element) in row.iter().zip(res_row.iter()) {
+                assert_relative_eq!(*element, *res_element, epsilon = 0.00001);
+            }
+        }
+    }
+    #[test]
+    fn try_from_matrix3() {
+        // The rotor is extracted for all branches of Shepperd's method
+        let rotors = [
+            Bivector::new(0.3, -0.2, 0.5).exp(),
+            Bivector::new(0.0, 0.0, 1.5).exp(),
+            Bivector::new(0.1, 1.5, 0.0).exp(),
+            Bivector::new(1.5, 0.0, 0.1).exp(),
+        ];
+        for rotor in rotors {
+            let res = Rotor::try_from_matrix3(rotor.to_matrix3()).unwrap();
+            assert_rotor_eq(res, rotor);
+        }
+    }
+    #[test]
+    fn try_from_matrix3_orthonormalize() {
+        // A rotation matrix times a symmetric stretch close to the identity
+        // gives the rotor of the rotation matrix
+        let rotor = Bivector::new(-0.4, 0.1, 0.2).exp();
+        let rotation = rotor.to_matrix3();
+        let stretch = [[1.01, 0.002, 0.0], [0.002, 0.99, -0.001], [0.0, -0.001, 1.0]];
+        let m: [[f32; 3]; 3] = core::array::from_fn(|i| {
+            core::array::from_fn(|j| (0..3).map(|k| rotation[i][k] * stretch[k][j]).sum())
+        });
+        let res = Rotor::try_from_matrix3(m).unwrap();
+        assert_rotor_eq(res, rotor);
+        assert_relative_eq!(res.norm().0, 1.0, max_relative = 0.00001);
+    }
+    #[test]
+    fn try_from_matrix3_reflection() {
+        // A rotation matrix with a reflected column has a negative determinant
+        let rotation = Bivector::new(0.3, -0.2, 0.5).exp().to_matrix3();
+        let m = rotation.map(|row| [row[0], row[1], -row[2]]);
+        assert!(Rotor::try_from_matrix3(m).is_none());
+        let m = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, -1.0]];
+        assert!(Rotor::try_from_matrix3(m).is_none());
+    }
+    #[test]
+    fn try_from_matrix3_not_orthonormal() {
+        // A shear is not close to a rotation
+        let m = [[1.0, 0.5, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+        assert!(Rotor::try_from_matrix3(m).is_none());
+        let m = [[f32::NAN, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+        assert!(Rotor::try_from_matrix3(m).is_none());
+    }
+}
+
 /// # Geometric Product
 /// The geometric product of two rotors is another rotor
 /// $$ R_1 R_2 = R_3$$
@@ -256,8 +839,8 @@ impl<F: Float> Mul for Rotor<F> {
         // Normelize
         let norm = (scalar * scalar + (bivector * bivector.reverse()).scalar()).sqrt();
         Rotor {
-            scalar: Scalar(scalar * norm),
-            bivector: bivector * Scalar(norm),
+            scalar: Scalar(scalar / norm),
+            bivector: bivector * Scalar(F::one() / norm),
         }
     }
 }
@@ -292,6 +875,13 @@ mod rotor_geo {
         assert_relative_eq!(res_rotor.e31(), 0.5, max_relative = 0.000001);
         assert_relative_eq!(res_rotor.e23(), 0.5, max_relative = 0.000001);
     }
+    #[test]
+    fn rotor_rotor_geo_norm() {
+        // The rounding errors do not grow over many products
+        let step = Bivector::new(0.01, 0.02, -0.03).exp();
+        let res_rotor = (0..100000).fold(Rotor::identity(), |rotor, _| rotor * step);
+        assert_relative_eq!(res_rotor.norm().0, 1.0, max_relative = 0.000001);
+    }
 }
 
 impl<F: Float> VGA3DOps<F> for Rotor<F> {
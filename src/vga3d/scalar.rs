@@ -39,8 +39,11 @@ use super::{
 
 /// # 3D Vector Geometric Algebra Scalar
 /// Wrapper for the Generic Float type
-#[derive(Default, Debug, Clone, Copy, PartialEq)]
 /// Scalar struct
+///
+/// The layout is `#[repr(C)]` and the same as a single `F`.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
 pub struct Scalar<F: Float>(pub(super) F);
 
 impl<F: Float> Scalar<F> {
@@ -0,0 +1,283 @@
+// ga_lib is a rust library that implements different geometric algbras.
+// Copyright (C) 2025 Rasmus Enevoldsen
+//
+// This file is part of ga_lib.
+//
+// ga_lib is free software: you can redistribute it and/or modify it under the
+// terms of the GNU Lesser General Public License as published by the Free
+// Software Foundation, either version 3 of the License, or (at your option) any
+// later version.
+//
+// ga_lib is distributed in the hope that it will be useful, but WITHOUT ANY
+// WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU Lesser General Public License for more
+// details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with ga_lib. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Serde
+//! The types are serialized as structs with the basis blades as named fields.
+//!
+//! | Type          | Fields                                                      |
+//! |---------------|-------------------------------------------------------------|
+//! | `Scalar`      | `scalar`                                                    |
+//! | `Vector`      | `e1`, `e2`, `e3`                                            |
+//! | `Bivector`    | `e12`, `e31`, `e23`                                         |
+//! | `Trivector`   | `e123`                                                      |
+//! | `Multivector` | `scalar`, `e1`, `e2`, `e3`, `e12`, `e31`, `e23`, `e123`     |
+//! | `Rotor`       | `scalar`, `e12`, `e31`, `e23`                               |
+//!
+//! A rotor must have unit norm, deserializing a rotor that is not normalized is an error.
+
+use super::{
+    bivector::Bivector, multivector::Multivector, rotor::Rotor, scalar::Scalar,
+    trivector::Trivector, vector::Vector,
+};
+
+use num_traits::Float;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Scalar")]
+struct ScalarFields<F> {
+    scalar: F,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Vector")]
+struct VectorFields<F> {
+    e1: F,
+    e2: F,
+    e3: F,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Bivector")]
+struct BivectorFields<F> {
+    e12: F,
+    e31: F,
+    e23: F,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Trivector")]
+struct TrivectorFields<F> {
+    e123: F,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Multivector")]
+struct MultivectorFields<F> {
+    scalar: F,
+    e1: F,
+    e2: F,
+    e3: F,
+    e12: F,
+    e31: F,
+    e23: F,
+    e123: F,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Rotor")]
+struct RotorFields<F> {
+    scalar: F,
+    e12: F,
+    e31: F,
+    e23: F,
+}
+
+// Test if the squared norm is one
+// \[ \left| |R|^2 - 1 \right| \le \sqrt{\epsilon} \]
+pub(super) fn is_unit<F: Float>(components: [F; 4]) -> bool {
+    let norm_squared = components
+        .iter()
+        .fold(F::zero(), |sum, component| sum + *component * *component);
+    (norm_squared - F::one()).abs() <= F::epsilon().sqrt()
+}
+
+// Scalar
+impl<F: Float + Serialize> Serialize for Scalar<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ScalarFields { scalar: self.0 }.serialize(serializer)
+    }
+}
+
+impl<'de, F: Float + Deserialize<'de>> Deserialize<'de> for Scalar<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields = ScalarFields::deserialize(deserializer)?;
+        Ok(Scalar(fields.scalar))
+    }
+}
+
+// Vector
+impl<F: Float + Serialize> Serialize for Vector<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        VectorFields {
+            e1: self.e1(),
+            e2: self.e2(),
+            e3: self.e3(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, F: Float + Deserialize<'de>> Deserialize<'de> for Vector<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields = VectorFields::deserialize(deserializer)?;
+        Ok(Vector::new(fields.e1, fields.e2, fields.e3))
+    }
+}
+
+// Bivector
+impl<F: Float + Serialize> Serialize for Bivector<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        BivectorFields {
+            e12: self.e12(),
+            e31: self.e31(),
+            e23: self.e23(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, F: Float + Deserialize<'de>> Deserialize<'de> for Bivector<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields = BivectorFields::deserialize(deserializer)?;
+        Ok(Bivector::new(fields.e12, fields.e31, fields.e23))
+    }
+}
+
+// Trivector
+impl<F: Float + Serialize> Serialize for Trivector<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        TrivectorFields { e123: self.e123() }.serialize(serializer)
+    }
+}
+
+impl<'de, F: Float + Deserialize<'de>> Deserialize<'de> for Trivector<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields = TrivectorFields::deserialize(deserializer)?;
+        Ok(Trivector::new(fields.e123))
+    }
+}
+
+// Multivector
+impl<F: Float + Serialize> Serialize for Multivector<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MultivectorFields {
+            scalar: self.scalar(),
+            e1: self.e1(),
+            e2: self.e2(),
+            e3: self.e3(),
+            e12: self.e12(),
+            e31: self.e31(),
+            e23: self.e23(),
+            e123: self.e123(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, F: Float + Deserialize<'de>> Deserialize<'de> for Multivector<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields = MultivectorFields::deserialize(deserializer)?;
+        Ok(Multivector::new_components(
+            fields.scalar,
+            fields.e1,
+            fields.e2,
+            fields.e3,
+            fields.e12,
+            fields.e31,
+            fields.e23,
+            fields.e123,
+        ))
+    }
+}
+
+// Rotor
+impl<F: Float + Serialize> Serialize for Rotor<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        RotorFields {
+            scalar: self.scalar(),
+            e12: self.e12(),
+            e31: self.e31(),
+            e23: self.e23(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, F: Float + Deserialize<'de>> Deserialize<'de> for Rotor<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields = RotorFields::deserialize(deserializer)?;
+        match is_unit([fields.scalar, fields.e12, fields.e31, fields.e23]) {
+            false => Err(D::Error::custom("the rotor does not have unit norm")),
+            true => Ok(Rotor {
+                scalar: Scalar(fields.scalar),
+                bivector: Bivector::new(fields.e12, fields.e31, fields.e23),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod serde_fields {
+    use super::*;
+
+    #[test]
+    fn vector() {
+        let vector = Vector::new(3.0, 5.0, 4.0);
+        let json = serde_json::to_string(&vector).unwrap();
+        assert_eq!(json, r#"{"e1":3.0,"e2":5.0,"e3":4.0}"#);
+        assert_eq!(serde_json::from_str::<Vector<f64>>(&json).unwrap(), vector);
+    }
+    #[test]
+    fn scalar_bivector_trivector() {
+        let json = serde_json::to_string(&Scalar(2.0)).unwrap();
+        assert_eq!(json, r#"{"scalar":2.0}"#);
+        let bivector = Bivector::new(2.0, 1.0, 6.0);
+        let json = serde_json::to_string(&bivector).unwrap();
+        assert_eq!(json, r#"{"e12":2.0,"e31":1.0,"e23":6.0}"#);
+        assert_eq!(
+            serde_json::from_str::<Bivector<f64>>(&json).unwrap(),
+            bivector
+        );
+        let json = serde_json::to_string(&Trivector::new(7.0)).unwrap();
+        assert_eq!(json, r#"{"e123":7.0}"#);
+    }
+    #[test]
+    fn multivector() {
+        let multivector = Multivector::new_components(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let json = serde_json::to_string(&multivector).unwrap();
+        assert_eq!(
+            json,
+            r#"{"scalar":1.0,"e1":2.0,"e2":3.0,"e3":4.0,"e12":5.0,"e31":6.0,"e23":7.0,"e123":8.0}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Multivector<f64>>(&json).unwrap(),
+            multivector
+        );
+    }
+    #[test]
+    fn rotor() {
+        let rotor = Rotor {
+            scalar: Scalar(0.5),
+            bivector: Bivector::new(0.5, 0.5, 0.5),
+        };
+        let json = serde_json::to_string(&rotor).unwrap();
+        assert_eq!(json, r#"{"scalar":0.5,"e12":0.5,"e31":0.5,"e23":0.5}"#);
+        assert_eq!(serde_json::from_str::<Rotor<f64>>(&json).unwrap(), rotor);
+    }
+    #[test]
+    fn rotor_not_unit() {
+        // The norm of the rotor is not one
+        let json = r#"{"scalar":1.0,"e12":0.5,"e31":0.0,"e23":0.0}"#;
+        assert!(serde_json::from_str::<Rotor<f64>>(json).is_err());
+        // A missing blade is an error
+        let json = r#"{"scalar":1.0,"e12":0.0,"e31":0.0}"#;
+        assert!(serde_json::from_str::<Rotor<f64>>(json).is_err());
+    }
+}
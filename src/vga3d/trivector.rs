@@ -36,7 +36,10 @@ use num_traits::Float;
 use super::{bivector::Bivector, scalar::Scalar, vector::Vector, VGA3DOps, VGA3DOpsRef};
 
 /// 3D Vector Geometric Algebra Bivector
+///
+/// The layout is `#[repr(C)]` and the same as a single `F` with the component $\mathrm{e}_{123}$.
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
 pub struct Trivector<F: Float> {
     e123: F,
 }
@@ -139,17 +142,6 @@ mod trivector_cross {
     }
 }
 
-impl<F: Float> Trivector<F> {
-    /// Dual
-    /// In VGA 3D, the dual is the pseudoscalar
-    /// $$ \overset\Rightarrow{b} \overset\Rrightarrow{i} = -\vec{v} $$
-    /// vector and bivectors in 3D VGA follows this pattern. Going up, going down
-    /// $$ \mathrm{e}_1,\,\mathrm{e}_2,\,\mathrm{e}_3,\,\mathrm{e}_3\star,\,\mathrm{e}_2\star,\,\mathrm{e}_1\star $$
-    pub fn dual(self) -> F {
-        -self.e123
-    }
-}
-
 impl<F: Float> VGA3DOps<F> for Trivector<F> {
     // There is only one element.
     // The norm is the absolute value of e1e2e3
@@ -41,7 +41,11 @@ use super::{
 /// # 3D Vector Geometric Algebra Vector
 /// This is the same vector as in $\mathbb{R}^3$
 /// $$\vec{v}=v_1 \mathrm{e}_1 + v_2 \mathrm{e}_2 + v_3 \mathrm{e}_3$$
+///
+/// The layout is `#[repr(C)]` and the same as `[F; 3]` with the components in the order
+/// $\mathrm{e}_1$, $\mathrm{e}_2$, $\mathrm{e}_3$.
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
 pub struct Vector<F: Float> {
     e1: F,
     e2: F,
@@ -187,20 +191,10 @@ mod vector_cross {
     }
 }
 
-impl<F: Float> Vector<F> {
-    /// # Dual
-    /// In VGA 3D, the dual is the pseudoscalar
-    /// $$ \vec{v} \overset\Rrightarrow{i} = \overset\Rightarrow{b} $$
-    /// vector and bivectors in 3D VGA follows this pattern. Going up, going down
-    /// $$\text{scalar}, \mathrm{e}_1,\mathrm{e}_2,\mathrm{e}_3,\mathrm{e}_3\star,\mathrm{e}_2\star,\mathrm{e}_1\star, \text{scalar}\star $$
-    pub fn dual(self) -> Bivector<F> {
-        Bivector::new(self.e3, self.e2, self.e1)
-    }
-}
-
 #[cfg(test)]
 mod vector_dual {
     use super::*;
+    use crate::vga3d::Dual;
     #[test]
     fn vector_to_bivector() {
         let vector = Vector::new(1.0, 2.0, 3.0);